@@ -213,6 +213,18 @@ fn ecdsa_verify(c: &mut Criterion) {
             public_key.verify::<Sha256>(&[message], signature).unwrap();
         })
     });
+
+    group.bench_function("graviola-precomputed", |b| {
+        use graviola::hashing::Sha256;
+        use graviola::signing::ecdsa;
+        let mut public_key =
+            ecdsa::VerifyingKey::<ecdsa::P256>::from_x962_uncompressed(public_key).unwrap();
+        public_key.precompute();
+
+        b.iter(|| {
+            public_key.verify::<Sha256>(&[message], signature).unwrap();
+        })
+    });
 }
 
 fn ecdsa_sign(c: &mut Criterion) {
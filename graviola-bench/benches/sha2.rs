@@ -4,13 +4,13 @@ use sha2::Digest;
 
 fn test_ring_sha256(data: &[u8]) {
     let mut ctx = ring::digest::Context::new(&ring::digest::SHA256);
-    ctx.update(&data);
+    ctx.update(data);
     black_box(ctx.finish());
 }
 
 fn test_ring_sha512(data: &[u8]) {
     let mut ctx = ring::digest::Context::new(&ring::digest::SHA512);
-    ctx.update(&data);
+    ctx.update(data);
     black_box(ctx.finish());
 }
 
@@ -104,5 +104,37 @@ fn sha512(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, sha256, sha512);
+fn sha256_many(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sha256-many");
+    for (size, size_name) in [(32, "8x32B"), (1024, "8x1KB"), (8192, "8x8KB")] {
+        let input = vec![vec![0u8; size]; 8];
+        let messages = input.iter().map(|m| &m[..]).collect::<Vec<_>>();
+        group.throughput(Throughput::Bytes((size * 8) as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("graviola-single", size_name),
+            &messages,
+            |b, messages| {
+                b.iter(|| {
+                    for m in messages {
+                        test_graviola_sha256(m);
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("graviola-many", size_name),
+            &messages,
+            |b, messages| {
+                b.iter(|| {
+                    let mut outputs = [[0u8; 32]; 8];
+                    graviola::hashing::Sha256::hash_many(messages, &mut outputs).unwrap();
+                    black_box(outputs);
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(benches, sha256, sha512, sha256_many);
 criterion_main!(benches);
@@ -0,0 +1,469 @@
+//! Interoperability tests against OpenSSL.
+//!
+//! Each test moves keys, signatures, shared secrets or ciphertexts
+//! from graviola to OpenSSL and back again.  These catch encoding and
+//! parameter mismatches (like PSS salt lengths, or PKCS#8 versions)
+//! which self-consistent test vectors cannot.
+//!
+//! These need OpenSSL to be installed, so are only run with:
+//!
+//! ```shell
+//! cargo test -p graviola-bench --features __test_openssl --test openssl
+//! ```
+
+#![cfg(all(
+    feature = "__test_openssl",
+    target_arch = "x86_64",
+    target_os = "linux"
+))]
+
+use graviola::aead::{AesGcm, ChaCha20Poly1305};
+use graviola::hashing::{Hash, Sha256, Sha384, Sha512, hkdf, hmac};
+use graviola::key_agreement::{p256, p384, x25519};
+use graviola::signing::{ecdsa, ed25519, rsa};
+use openssl::derive::Deriver;
+use openssl::hash::MessageDigest;
+use openssl::md::{Md, MdRef};
+use openssl::nid::Nid;
+use openssl::pkey::{Id, PKey, Private, Public};
+use openssl::pkey_ctx::PkeyCtx;
+use openssl::rsa::Padding;
+use openssl::sign::{RsaPssSaltlen, Signer, Verifier};
+use openssl::symm::{Cipher, decrypt_aead, encrypt_aead};
+
+const MESSAGE: &[u8] = b"a message from graviola to openssl, and back again";
+
+#[test]
+fn ecdsa_p256() {
+    let key = ecdsa::SigningKey::<ecdsa::P256> {
+        private_key: p256::StaticPrivateKey::new_random().unwrap(),
+    };
+    let mut buffer = [0u8; 256];
+    check_ecdsa::<ecdsa::P256, Sha256>(
+        key.to_pkcs8_der(&mut buffer).unwrap(),
+        "prime256v1",
+        MessageDigest::sha256(),
+    );
+}
+
+#[test]
+fn ecdsa_p384() {
+    let key = ecdsa::SigningKey::<ecdsa::P384> {
+        private_key: p384::StaticPrivateKey::new_random().unwrap(),
+    };
+    let mut buffer = [0u8; 256];
+    check_ecdsa::<ecdsa::P384, Sha384>(
+        key.to_pkcs8_der(&mut buffer).unwrap(),
+        "secp384r1",
+        MessageDigest::sha384(),
+    );
+}
+
+fn check_ecdsa<C: ecdsa::Curve, H: Hash>(pkcs8: &[u8], curve: &str, md: MessageDigest) {
+    // a graviola key, signing for openssl
+    let ours = ecdsa::SigningKey::<C>::from_pkcs8_der(pkcs8).unwrap();
+    let theirs = PKey::private_key_from_pkcs8(pkcs8).unwrap();
+    let their_public = theirs.public_key_to_der().unwrap();
+
+    let mut signature = [0u8; 128];
+    let signature = ours.sign_asn1::<H>(&[MESSAGE], &mut signature).unwrap();
+    assert!(openssl_verify(&theirs, Some(md), signature));
+
+    // and our encoding of its public key
+    let mut spki = [0u8; 128];
+    let spki = ecdsa::VerifyingKey::<C>::from_spki_der(&their_public)
+        .unwrap()
+        .to_spki_der(&mut spki)
+        .unwrap();
+    assert_eq!(spki, their_public);
+
+    // an openssl key, signing for graviola
+    let theirs = PKey::ec_gen(curve).unwrap();
+    let ours =
+        ecdsa::SigningKey::<C>::from_pkcs8_der(&theirs.private_key_to_pkcs8().unwrap()).unwrap();
+    let public =
+        ecdsa::VerifyingKey::<C>::from_spki_der(&theirs.public_key_to_der().unwrap()).unwrap();
+    let signature = openssl_sign(&theirs, Some(md));
+    public.verify_asn1::<H>(&[MESSAGE], &signature).unwrap();
+
+    // and its encoding of our key
+    let mut pkcs8 = [0u8; 256];
+    let pkcs8 = ours.to_pkcs8_der(&mut pkcs8).unwrap();
+    let reloaded = PKey::private_key_from_pkcs8(pkcs8).unwrap();
+    assert!(reloaded.public_eq(&theirs));
+}
+
+#[test]
+fn ed25519() {
+    // a graviola key, signing for openssl
+    let ours = ed25519::SigningKey::new_random().unwrap();
+    let mut pkcs8 = [0u8; 128];
+    let theirs = PKey::private_key_from_pkcs8(ours.to_pkcs8_der(&mut pkcs8).unwrap()).unwrap();
+    assert_eq!(
+        theirs.raw_public_key().unwrap(),
+        ours.verifying_key().as_bytes()
+    );
+    assert!(openssl_verify(&theirs, None, &ours.sign(&[MESSAGE])));
+
+    let mut spki = [0u8; 64];
+    assert_eq!(
+        ours.verifying_key().to_spki_der(&mut spki).unwrap(),
+        theirs.public_key_to_der().unwrap()
+    );
+
+    // an openssl key, signing for graviola
+    let theirs = PKey::generate_ed25519().unwrap();
+    let ours =
+        ed25519::SigningKey::from_pkcs8_der(&theirs.private_key_to_pkcs8().unwrap()).unwrap();
+    let public =
+        ed25519::VerifyingKey::from_spki_der(&theirs.public_key_to_der().unwrap()).unwrap();
+    assert_eq!(public.as_bytes(), ours.verifying_key().as_bytes());
+    public
+        .verify(&[MESSAGE], &openssl_sign(&theirs, None))
+        .unwrap();
+
+    // and its encoding of our key
+    let reloaded = PKey::private_key_from_pkcs8(ours.to_pkcs8_der(&mut pkcs8).unwrap()).unwrap();
+    assert!(reloaded.public_eq(&theirs));
+}
+
+#[test]
+fn rsa() {
+    let theirs = PKey::from_rsa(openssl::rsa::Rsa::generate(2048).unwrap()).unwrap();
+    let ours = rsa::SigningKey::from_pkcs8_der(&theirs.private_key_to_pkcs8().unwrap()).unwrap();
+    let public = rsa::VerifyingKey::from_spki_der(&theirs.public_key_to_der().unwrap()).unwrap();
+
+    // key encodings, both ways
+    let mut buffer = [0u8; 2048];
+    let reloaded = PKey::private_key_from_pkcs8(ours.to_pkcs8_der(&mut buffer).unwrap()).unwrap();
+    assert!(reloaded.public_eq(&theirs));
+    assert_eq!(
+        ours.public_key().to_spki_der(&mut buffer).unwrap(),
+        theirs.public_key_to_der().unwrap()
+    );
+
+    let their_public = PKey::public_key_from_der(&theirs.public_key_to_der().unwrap()).unwrap();
+    let mut signature = [0u8; 256];
+
+    // PKCS#1 signatures
+    for (md, sign, verify) in [
+        (
+            MessageDigest::sha256(),
+            rsa::SigningKey::sign_pkcs1_sha256 as SignFn,
+            rsa::VerifyingKey::verify_pkcs1_sha256 as VerifyFn,
+        ),
+        (
+            MessageDigest::sha384(),
+            rsa::SigningKey::sign_pkcs1_sha384,
+            rsa::VerifyingKey::verify_pkcs1_sha384,
+        ),
+        (
+            MessageDigest::sha512(),
+            rsa::SigningKey::sign_pkcs1_sha512,
+            rsa::VerifyingKey::verify_pkcs1_sha512,
+        ),
+    ] {
+        let ours_sig = sign(&ours, &mut signature, MESSAGE).unwrap();
+        let mut verifier = Verifier::new(md, &their_public).unwrap();
+        assert!(verifier.verify_oneshot(ours_sig, MESSAGE).unwrap());
+
+        let mut signer = Signer::new(md, &theirs).unwrap();
+        verify(
+            &public,
+            &signer.sign_oneshot_to_vec(MESSAGE).unwrap(),
+            MESSAGE,
+        )
+        .unwrap();
+    }
+
+    // PSS signatures, with the salt length equal to the hash length
+    for (md, sign, verify) in [
+        (
+            MessageDigest::sha256(),
+            rsa::SigningKey::sign_pss_sha256 as SignFn,
+            rsa::VerifyingKey::verify_pss_sha256 as VerifyFn,
+        ),
+        (
+            MessageDigest::sha384(),
+            rsa::SigningKey::sign_pss_sha384,
+            rsa::VerifyingKey::verify_pss_sha384,
+        ),
+        (
+            MessageDigest::sha512(),
+            rsa::SigningKey::sign_pss_sha512,
+            rsa::VerifyingKey::verify_pss_sha512,
+        ),
+    ] {
+        let ours_sig = sign(&ours, &mut signature, MESSAGE).unwrap();
+        let mut verifier = Verifier::new(md, &their_public).unwrap();
+        verifier.set_rsa_padding(Padding::PKCS1_PSS).unwrap();
+        verifier
+            .set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)
+            .unwrap();
+        assert!(verifier.verify_oneshot(ours_sig, MESSAGE).unwrap());
+
+        let mut signer = Signer::new(md, &theirs).unwrap();
+        signer.set_rsa_padding(Padding::PKCS1_PSS).unwrap();
+        signer
+            .set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)
+            .unwrap();
+        verify(
+            &public,
+            &signer.sign_oneshot_to_vec(MESSAGE).unwrap(),
+            MESSAGE,
+        )
+        .unwrap();
+    }
+}
+
+type SignFn =
+    for<'a> fn(&rsa::SigningKey, &'a mut [u8], &[u8]) -> Result<&'a [u8], graviola::Error>;
+type VerifyFn = fn(&rsa::VerifyingKey, &[u8], &[u8]) -> Result<(), graviola::Error>;
+
+#[test]
+fn rsa_generate() {
+    let mut ctx = openssl::bn::BigNumContext::new().unwrap();
+
+    for (size, bits) in [
+        (rsa::KeySize::Rsa2048, 2048),
+        (rsa::KeySize::Rsa3072, 3072),
+        (rsa::KeySize::Rsa4096, 4096),
+    ] {
+        let ours = rsa::SigningKey::new_random(size).unwrap();
+        let mut buf = [0u8; 4096];
+        let theirs =
+            openssl::rsa::Rsa::private_key_from_der(ours.to_pkcs1_der(&mut buf).unwrap()).unwrap();
+        assert_eq!(theirs.size() * 8, bits);
+        assert_eq!(theirs.e().to_vec(), [0x01, 0x00, 0x01]);
+        assert!(theirs.check_key().unwrap());
+        assert!(theirs.p().unwrap().is_prime(64, &mut ctx).unwrap());
+        assert!(theirs.q().unwrap().is_prime(64, &mut ctx).unwrap());
+    }
+}
+
+#[test]
+fn x25519() {
+    let ours = x25519::StaticPrivateKey::new_random().unwrap();
+    let theirs = PKey::generate_x25519().unwrap();
+
+    let mut buffer = [0u8; 64];
+    let our_public =
+        PKey::public_key_from_der(ours.public_key().to_spki_der(&mut buffer).unwrap()).unwrap();
+    let their_public =
+        x25519::PublicKey::from_spki_der(&theirs.public_key_to_der().unwrap()).unwrap();
+
+    let ours_secret = ours.diffie_hellman(&their_public).unwrap();
+    assert_eq!(openssl_derive(&theirs, &our_public), ours_secret.0);
+
+    // private key encodings, both ways
+    let reloaded = PKey::private_key_from_pkcs8(ours.to_pkcs8_der(&mut buffer).unwrap()).unwrap();
+    assert!(reloaded.public_eq(&our_public));
+    let reloaded =
+        x25519::StaticPrivateKey::from_pkcs8_der(&theirs.private_key_to_pkcs8().unwrap()).unwrap();
+    assert_eq!(reloaded.public_key().as_bytes(), their_public.as_bytes());
+}
+
+#[test]
+fn ecdh_p256() {
+    let ours = p256::PrivateKey::new_random().unwrap();
+    let theirs = PKey::ec_gen("prime256v1").unwrap();
+    let our_public = openssl_ec_public(Nid::X9_62_PRIME256V1, &ours.public_key_uncompressed());
+    let their_public = p256::PublicKey::from_x962_uncompressed(&openssl_ec_point(&theirs)).unwrap();
+
+    let expected = openssl_derive(&theirs, &our_public);
+    assert_eq!(ours.diffie_hellman(&their_public).unwrap().0, expected[..]);
+}
+
+#[test]
+fn ecdh_p384() {
+    let ours = p384::PrivateKey::new_random().unwrap();
+    let theirs = PKey::ec_gen("secp384r1").unwrap();
+    let our_public = openssl_ec_public(Nid::SECP384R1, &ours.public_key_uncompressed());
+    let their_public = p384::PublicKey::from_x962_uncompressed(&openssl_ec_point(&theirs)).unwrap();
+
+    let expected = openssl_derive(&theirs, &our_public);
+    assert_eq!(ours.diffie_hellman(&their_public).unwrap().0, expected[..]);
+}
+
+#[test]
+fn aes_gcm() {
+    for (key_len, cipher) in [(16, Cipher::aes_128_gcm()), (32, Cipher::aes_256_gcm())] {
+        let key = random_vec(key_len);
+        let ours = AesGcm::new(&key);
+        check_aead(
+            cipher,
+            &key,
+            |nonce, aad, inout, tag| ours.encrypt(nonce, aad, inout, tag),
+            |nonce, aad, inout, tag| ours.decrypt(nonce, aad, inout, tag).is_ok(),
+        );
+    }
+}
+
+#[test]
+fn chacha20poly1305() {
+    let key = random_vec(32);
+    let ours = ChaCha20Poly1305::new(key.clone().try_into().unwrap());
+    check_aead(
+        Cipher::chacha20_poly1305(),
+        &key,
+        |nonce, aad, inout, tag| ours.encrypt(nonce, aad, inout, tag),
+        |nonce, aad, inout, tag| ours.decrypt(nonce, aad, inout, tag).is_ok(),
+    );
+}
+
+fn check_aead(
+    cipher: Cipher,
+    key: &[u8],
+    encrypt: impl Fn(&[u8; 12], &[u8], &mut [u8], &mut [u8; 16]),
+    decrypt: impl Fn(&[u8; 12], &[u8], &mut [u8], &[u8]) -> bool,
+) {
+    for len in [0, 1, 15, 16, 17, 63, 64, 65, 255, 256, 257, 1000, 4096] {
+        let nonce: [u8; 12] = random_vec(12).try_into().unwrap();
+        let aad = random_vec(len % 37);
+        let plain = random_vec(len);
+
+        // graviola to openssl
+        let mut ciphertext = plain.clone();
+        let mut tag = [0u8; 16];
+        encrypt(&nonce, &aad, &mut ciphertext, &mut tag);
+        let decrypted = decrypt_aead(cipher, key, Some(&nonce), &aad, &ciphertext, &tag).unwrap();
+        assert_eq!(decrypted, plain);
+
+        // openssl to graviola
+        let mut tag = [0u8; 16];
+        let mut ciphertext =
+            encrypt_aead(cipher, key, Some(&nonce), &aad, &plain, &mut tag).unwrap();
+        assert!(decrypt(&nonce, &aad, &mut ciphertext, &tag));
+        assert_eq!(ciphertext, plain);
+
+        // corrupted tag is rejected by both
+        tag[0] ^= 1;
+        assert!(decrypt_aead(cipher, key, Some(&nonce), &aad, &ciphertext, &tag).is_err());
+        let mut ciphertext =
+            encrypt_aead(cipher, key, Some(&nonce), &aad, &plain, &mut [0; 16]).unwrap();
+        assert!(!decrypt(&nonce, &aad, &mut ciphertext, &tag));
+    }
+}
+
+#[test]
+fn hashes() {
+    for len in [0, 1, 55, 56, 64, 111, 112, 128, 1000] {
+        let data = random_vec(len);
+        for (md, ours) in [
+            (MessageDigest::sha256(), Sha256::hash(&data)),
+            (MessageDigest::sha384(), Sha384::hash(&data)),
+            (MessageDigest::sha512(), Sha512::hash(&data)),
+        ] {
+            assert_eq!(&openssl::hash::hash(md, &data).unwrap()[..], ours.as_ref());
+        }
+    }
+}
+
+#[test]
+fn hmac() {
+    // (openssl refuses empty HMAC keys)
+    for key_len in [1, 32, 64, 65, 128, 129, 200] {
+        let key = random_vec(key_len);
+        let theirs = PKey::hmac(&key).unwrap();
+        let mac = |md| {
+            Signer::new(md, &theirs)
+                .unwrap()
+                .sign_oneshot_to_vec(MESSAGE)
+                .unwrap()
+        };
+
+        assert_eq!(
+            mac(MessageDigest::sha256()),
+            hmac::hmac_sha256(&key, MESSAGE)
+        );
+        assert_eq!(
+            mac(MessageDigest::sha384()),
+            hmac::hmac_sha384(&key, MESSAGE)
+        );
+        assert_eq!(
+            mac(MessageDigest::sha512()),
+            hmac::hmac_sha512(&key, MESSAGE)
+        );
+    }
+}
+
+#[test]
+fn hkdf() {
+    let salt = random_vec(32);
+    let ikm = random_vec(48);
+    let info = b"graviola interop";
+
+    for len in [1, 32, 48, 64, 255] {
+        let expected_sha256 = openssl_hkdf(Md::sha256(), &salt, &ikm, info, len);
+        let mut ours = vec![0u8; len];
+        hkdf::hkdf::<Sha256>(&salt, &ikm, info, &mut ours).unwrap();
+        assert_eq!(ours, expected_sha256);
+
+        let expected_sha384 = openssl_hkdf(Md::sha384(), &salt, &ikm, info, len);
+        hkdf::hkdf::<Sha384>(&salt, &ikm, info, &mut ours).unwrap();
+        assert_eq!(ours, expected_sha384);
+    }
+}
+
+fn openssl_sign(key: &PKey<Private>, md: Option<MessageDigest>) -> Vec<u8> {
+    let mut signer = match md {
+        Some(md) => Signer::new(md, key),
+        None => Signer::new_without_digest(key),
+    }
+    .unwrap();
+    signer.sign_oneshot_to_vec(MESSAGE).unwrap()
+}
+
+fn openssl_verify(key: &PKey<Private>, md: Option<MessageDigest>, signature: &[u8]) -> bool {
+    let mut verifier = match md {
+        Some(md) => Verifier::new(md, key),
+        None => Verifier::new_without_digest(key),
+    }
+    .unwrap();
+    verifier.verify_oneshot(signature, MESSAGE).unwrap()
+}
+
+fn openssl_derive(ours: &PKey<Private>, peer: &PKey<Public>) -> Vec<u8> {
+    let mut deriver = Deriver::new(ours).unwrap();
+    deriver.set_peer(peer).unwrap();
+    deriver.derive_to_vec().unwrap()
+}
+
+fn openssl_ec_public(curve: Nid, uncompressed: &[u8]) -> PKey<Public> {
+    use openssl::bn::BigNumContext;
+    use openssl::ec::{EcGroup, EcKey, EcPoint};
+
+    let group = EcGroup::from_curve_name(curve).unwrap();
+    let mut ctx = BigNumContext::new().unwrap();
+    let point = EcPoint::from_bytes(&group, uncompressed, &mut ctx).unwrap();
+    PKey::from_ec_key(EcKey::from_public_key(&group, &point).unwrap()).unwrap()
+}
+
+fn openssl_ec_point(key: &PKey<Private>) -> Vec<u8> {
+    use openssl::bn::BigNumContext;
+    use openssl::ec::PointConversionForm;
+
+    let key = key.ec_key().unwrap();
+    let mut ctx = BigNumContext::new().unwrap();
+    key.public_key()
+        .to_bytes(key.group(), PointConversionForm::UNCOMPRESSED, &mut ctx)
+        .unwrap()
+}
+
+fn openssl_hkdf(md: &MdRef, salt: &[u8], ikm: &[u8], info: &[u8], len: usize) -> Vec<u8> {
+    let mut ctx = PkeyCtx::new_id(Id::HKDF).unwrap();
+    ctx.derive_init().unwrap();
+    ctx.set_hkdf_md(md).unwrap();
+    ctx.set_hkdf_salt(salt).unwrap();
+    ctx.set_hkdf_key(ikm).unwrap();
+    ctx.add_hkdf_info(info).unwrap();
+    let mut out = vec![0u8; len];
+    ctx.derive(Some(&mut out)).unwrap();
+    out
+}
+
+fn random_vec(len: usize) -> Vec<u8> {
+    let mut v = vec![0u8; len];
+    graviola::random::fill(&mut v).unwrap();
+    v
+}
@@ -0,0 +1,172 @@
+/*
+ * Written for Graviola by Joe Birr-Pixton, 2024.
+ * SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+ *
+ * C interface to Graviola.  See the documentation of the graviola-ffi
+ * crate for the conventions used here.
+ *
+ * Every function returns GRAVIOLA_OK on success, or a negative
+ * GRAVIOLA_ERROR_* code.  Buffers are given as a pointer and a length;
+ * a pointer may be NULL only if its length is zero.
+ */
+
+#ifndef GRAVIOLA_H
+#define GRAVIOLA_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Status codes. */
+#define GRAVIOLA_OK 0
+#define GRAVIOLA_ERROR_NULL_POINTER (-1)
+#define GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM (-2)
+#define GRAVIOLA_ERROR_WRONG_LENGTH (-3)
+#define GRAVIOLA_ERROR_INVALID_KEY (-4)
+#define GRAVIOLA_ERROR_BAD_SIGNATURE (-5)
+#define GRAVIOLA_ERROR_DECRYPT_FAILED (-6)
+#define GRAVIOLA_ERROR_RNG_FAILED (-7)
+#define GRAVIOLA_ERROR_PANIC (-8)
+#define GRAVIOLA_ERROR_OTHER (-9)
+
+/* AEAD algorithms. */
+#define GRAVIOLA_AEAD_AES_128_GCM 1
+#define GRAVIOLA_AEAD_AES_256_GCM 2
+#define GRAVIOLA_AEAD_CHACHA20_POLY1305 3
+#define GRAVIOLA_AEAD_XCHACHA20_POLY1305 4
+#define GRAVIOLA_AEAD_TAG_LEN 16
+
+/*
+ * Encrypts `plaintext` into `ciphertext` (which may be the same buffer),
+ * writing the tag to `tag`.  `ciphertext_len` must be at least
+ * `plaintext_len`, and `tag_len` must be GRAVIOLA_AEAD_TAG_LEN.
+ */
+int graviola_aead_seal(uint32_t algorithm,
+                       const uint8_t *key, size_t key_len,
+                       const uint8_t *nonce, size_t nonce_len,
+                       const uint8_t *aad, size_t aad_len,
+                       const uint8_t *plaintext, size_t plaintext_len,
+                       uint8_t *ciphertext, size_t ciphertext_len,
+                       uint8_t *tag, size_t tag_len);
+
+/*
+ * Decrypts `ciphertext` into `plaintext` (which may be the same buffer),
+ * checking `tag`.  `plaintext_len` must be at least `ciphertext_len`.
+ * On GRAVIOLA_ERROR_DECRYPT_FAILED, the output is zeroed.
+ */
+int graviola_aead_open(uint32_t algorithm,
+                       const uint8_t *key, size_t key_len,
+                       const uint8_t *nonce, size_t nonce_len,
+                       const uint8_t *aad, size_t aad_len,
+                       const uint8_t *ciphertext, size_t ciphertext_len,
+                       const uint8_t *tag, size_t tag_len,
+                       uint8_t *plaintext, size_t plaintext_len);
+
+/* Hash algorithms. */
+#define GRAVIOLA_HASH_SHA256 1
+#define GRAVIOLA_HASH_SHA384 2
+#define GRAVIOLA_HASH_SHA512 3
+
+/*
+ * Hashes `data` into `output`, whose capacity is `output_len`.  The length
+ * of the hash is written to `output_written`.
+ */
+int graviola_hash(uint32_t algorithm,
+                  const uint8_t *data, size_t data_len,
+                  uint8_t *output, size_t output_len,
+                  size_t *output_written);
+
+/* X25519.  All keys and shared secrets are GRAVIOLA_X25519_KEY_LEN bytes. */
+#define GRAVIOLA_X25519_KEY_LEN 32
+
+/* Generates a random private key, and its public key. */
+int graviola_x25519_generate(uint8_t *private_key, size_t private_key_len,
+                             uint8_t *public_key, size_t public_key_len);
+
+/* Computes the public key of `private_key`. */
+int graviola_x25519_public_key(const uint8_t *private_key, size_t private_key_len,
+                               uint8_t *public_key, size_t public_key_len);
+
+/*
+ * Computes the shared secret.  GRAVIOLA_ERROR_INVALID_KEY is returned if it
+ * is zero, ie. the peer's public key has a small order.
+ */
+int graviola_x25519_diffie_hellman(const uint8_t *private_key, size_t private_key_len,
+                                   const uint8_t *peer_public_key, size_t peer_public_key_len,
+                                   uint8_t *shared_secret, size_t shared_secret_len);
+
+/*
+ * ECDSA algorithms.  Private keys are PKCS#8 DER, and public keys are
+ * uncompressed X9.62 points.
+ */
+#define GRAVIOLA_ECDSA_P256_SHA256 1
+#define GRAVIOLA_ECDSA_P384_SHA384 2
+
+/* Generates a random private key.  256 bytes of output is sufficient. */
+int graviola_ecdsa_generate(uint32_t algorithm,
+                            uint8_t *private_key, size_t private_key_len,
+                            size_t *private_key_written);
+
+/* Computes the public key of `private_key`.  97 bytes of output is sufficient. */
+int graviola_ecdsa_public_key(uint32_t algorithm,
+                              const uint8_t *private_key, size_t private_key_len,
+                              uint8_t *public_key, size_t public_key_len,
+                              size_t *public_key_written);
+
+/*
+ * Signs `message`, producing a fixed-length (r || s) signature.
+ * 96 bytes of output is sufficient.
+ */
+int graviola_ecdsa_sign(uint32_t algorithm,
+                        const uint8_t *private_key, size_t private_key_len,
+                        const uint8_t *message, size_t message_len,
+                        uint8_t *signature, size_t signature_len,
+                        size_t *signature_written);
+
+/*
+ * Signs `message`, producing a DER-encoded ASN.1 signature.
+ * 104 bytes of output is sufficient.
+ */
+int graviola_ecdsa_sign_asn1(uint32_t algorithm,
+                             const uint8_t *private_key, size_t private_key_len,
+                             const uint8_t *message, size_t message_len,
+                             uint8_t *signature, size_t signature_len,
+                             size_t *signature_written);
+
+/* Verifies a fixed-length signature. */
+int graviola_ecdsa_verify(uint32_t algorithm,
+                          const uint8_t *public_key, size_t public_key_len,
+                          const uint8_t *message, size_t message_len,
+                          const uint8_t *signature, size_t signature_len);
+
+/* Verifies a DER-encoded ASN.1 signature. */
+int graviola_ecdsa_verify_asn1(uint32_t algorithm,
+                               const uint8_t *public_key, size_t public_key_len,
+                               const uint8_t *message, size_t message_len,
+                               const uint8_t *signature, size_t signature_len);
+
+/* RSA signature algorithms.  PSS uses MGF1 with the same hash, and a salt of the hash's length. */
+#define GRAVIOLA_RSA_PKCS1_SHA256 1
+#define GRAVIOLA_RSA_PKCS1_SHA384 2
+#define GRAVIOLA_RSA_PKCS1_SHA512 3
+#define GRAVIOLA_RSA_PSS_SHA256 4
+#define GRAVIOLA_RSA_PSS_SHA384 5
+#define GRAVIOLA_RSA_PSS_SHA512 6
+
+/*
+ * Verifies an RSA signature.  `public_key` is a PKCS#1 RSAPublicKey in DER
+ * format, with a modulus of 2048 to 8192 bits.
+ */
+int graviola_rsa_verify(uint32_t algorithm,
+                        const uint8_t *public_key, size_t public_key_len,
+                        const uint8_t *message, size_t message_len,
+                        const uint8_t *signature, size_t signature_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* GRAVIOLA_H */
@@ -0,0 +1,213 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::ffi::c_int;
+use core::ptr;
+
+use graviola::aead::{AesGcm, ChaCha20Poly1305, XChaCha20Poly1305};
+
+use crate::{
+    GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM, GRAVIOLA_ERROR_WRONG_LENGTH, Status, guard, input,
+    input_array, output, output_array,
+};
+
+/// AES-128-GCM, with a 16 byte key and 12 byte nonce.
+pub const GRAVIOLA_AEAD_AES_128_GCM: u32 = 1;
+
+/// AES-256-GCM, with a 32 byte key and 12 byte nonce.
+pub const GRAVIOLA_AEAD_AES_256_GCM: u32 = 2;
+
+/// ChaCha20-Poly1305, with a 32 byte key and 12 byte nonce.
+pub const GRAVIOLA_AEAD_CHACHA20_POLY1305: u32 = 3;
+
+/// XChaCha20-Poly1305, with a 32 byte key and 24 byte nonce.
+pub const GRAVIOLA_AEAD_XCHACHA20_POLY1305: u32 = 4;
+
+/// The length of the tag, for all AEAD algorithms.
+pub const GRAVIOLA_AEAD_TAG_LEN: usize = 16;
+
+/// Encrypts `plaintext` into `ciphertext`, and writes the tag to `tag`.
+///
+/// `ciphertext_len` must be at least `plaintext_len`; exactly `plaintext_len`
+/// bytes are written.  `tag_len` must be `GRAVIOLA_AEAD_TAG_LEN`.
+/// `plaintext` and `ciphertext` may be the same buffer.
+///
+/// # Safety
+/// Each pointer must be valid for its given length, or be null with a length
+/// of zero.  Apart from the case above, the buffers must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_aead_seal(
+    algorithm: u32,
+    key: *const u8,
+    key_len: usize,
+    nonce: *const u8,
+    nonce_len: usize,
+    aad: *const u8,
+    aad_len: usize,
+    plaintext: *const u8,
+    plaintext_len: usize,
+    ciphertext: *mut u8,
+    ciphertext_len: usize,
+    tag: *mut u8,
+    tag_len: usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (cipher, aad, inout) = unsafe {
+            (
+                Cipher::new(algorithm, key, key_len, nonce, nonce_len)?,
+                input(aad, aad_len)?,
+                in_place(plaintext, plaintext_len, ciphertext, ciphertext_len)?,
+            )
+        };
+        // SAFETY: guaranteed by the caller.
+        let tag = unsafe { output_array(tag, tag_len) }?;
+        cipher.seal(aad, inout, tag);
+        Ok(())
+    })
+}
+
+/// Decrypts `ciphertext` into `plaintext`, checking `tag`.
+///
+/// `plaintext_len` must be at least `ciphertext_len`; exactly `ciphertext_len`
+/// bytes are written.  `tag_len` must be `GRAVIOLA_AEAD_TAG_LEN`.
+/// `ciphertext` and `plaintext` may be the same buffer.
+///
+/// If the tag is wrong, `GRAVIOLA_ERROR_DECRYPT_FAILED` is returned and the
+/// output is zeroed.
+///
+/// # Safety
+/// Each pointer must be valid for its given length, or be null with a length
+/// of zero.  Apart from the case above, the buffers must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_aead_open(
+    algorithm: u32,
+    key: *const u8,
+    key_len: usize,
+    nonce: *const u8,
+    nonce_len: usize,
+    aad: *const u8,
+    aad_len: usize,
+    ciphertext: *const u8,
+    ciphertext_len: usize,
+    tag: *const u8,
+    tag_len: usize,
+    plaintext: *mut u8,
+    plaintext_len: usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (cipher, aad, tag) = unsafe {
+            (
+                Cipher::new(algorithm, key, key_len, nonce, nonce_len)?,
+                input(aad, aad_len)?,
+                input_array::<GRAVIOLA_AEAD_TAG_LEN>(tag, tag_len)?,
+            )
+        };
+        // SAFETY: guaranteed by the caller.
+        let inout = unsafe { in_place(ciphertext, ciphertext_len, plaintext, plaintext_len) }?;
+        Ok(cipher.open(aad, inout, tag)?)
+    })
+}
+
+// short-lived, and never moved
+#[allow(clippy::large_enum_variant)]
+enum Cipher<'a> {
+    AesGcm(AesGcm, &'a [u8; 12]),
+    ChaCha20Poly1305(ChaCha20Poly1305, &'a [u8; 12]),
+    XChaCha20Poly1305(XChaCha20Poly1305, &'a [u8; 24]),
+}
+
+impl Cipher<'_> {
+    /// # Safety
+    /// As for [`input()`], for both `key` and `nonce`.
+    unsafe fn new(
+        algorithm: u32,
+        key: *const u8,
+        key_len: usize,
+        nonce: *const u8,
+        nonce_len: usize,
+    ) -> Result<Self, Status> {
+        // SAFETY: guaranteed by the caller.
+        let key = unsafe { input(key, key_len) }?;
+        // SAFETY: guaranteed by the caller.
+        unsafe {
+            match algorithm {
+                GRAVIOLA_AEAD_AES_128_GCM | GRAVIOLA_AEAD_AES_256_GCM => {
+                    let key_len = match algorithm {
+                        GRAVIOLA_AEAD_AES_128_GCM => 16,
+                        _ => 32,
+                    };
+                    if key.len() != key_len {
+                        return Err(Status(GRAVIOLA_ERROR_WRONG_LENGTH));
+                    }
+                    Ok(Self::AesGcm(
+                        AesGcm::new(key),
+                        input_array(nonce, nonce_len)?,
+                    ))
+                }
+                GRAVIOLA_AEAD_CHACHA20_POLY1305 => Ok(Self::ChaCha20Poly1305(
+                    ChaCha20Poly1305::new(key_array(key)?),
+                    input_array(nonce, nonce_len)?,
+                )),
+                GRAVIOLA_AEAD_XCHACHA20_POLY1305 => Ok(Self::XChaCha20Poly1305(
+                    XChaCha20Poly1305::new(key_array(key)?),
+                    input_array(nonce, nonce_len)?,
+                )),
+                _ => Err(Status(GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM)),
+            }
+        }
+    }
+
+    fn seal(&self, aad: &[u8], inout: &mut [u8], tag: &mut [u8; GRAVIOLA_AEAD_TAG_LEN]) {
+        match self {
+            Self::AesGcm(c, nonce) => c.encrypt(nonce, aad, inout, tag),
+            Self::ChaCha20Poly1305(c, nonce) => c.encrypt(nonce, aad, inout, tag),
+            Self::XChaCha20Poly1305(c, nonce) => c.encrypt(nonce, aad, inout, tag),
+        }
+    }
+
+    fn open(&self, aad: &[u8], inout: &mut [u8], tag: &[u8]) -> Result<(), graviola::Error> {
+        match self {
+            Self::AesGcm(c, nonce) => c.decrypt(nonce, aad, inout, tag),
+            Self::ChaCha20Poly1305(c, nonce) => c.decrypt(nonce, aad, inout, tag),
+            Self::XChaCha20Poly1305(c, nonce) => c.decrypt(nonce, aad, inout, tag),
+        }
+    }
+}
+
+fn key_array(key: &[u8]) -> Result<[u8; 32], Status> {
+    key.try_into()
+        .map_err(|_| Status(GRAVIOLA_ERROR_WRONG_LENGTH))
+}
+
+/// Copies `len` bytes from `src` into `dest`, and borrows that span of `dest`.
+///
+/// The buffers may overlap.
+///
+/// # Safety
+/// `src` must be valid for reads of `len` bytes, and `dest` valid for writes
+/// of `dest_len` bytes; either may be null if its length is zero.
+unsafe fn in_place<'a>(
+    src: *const u8,
+    len: usize,
+    dest: *mut u8,
+    dest_len: usize,
+) -> Result<&'a mut [u8], Status> {
+    if dest_len < len {
+        return Err(Status(GRAVIOLA_ERROR_WRONG_LENGTH));
+    }
+    // check for nulls, without keeping borrows that may alias
+    // SAFETY: guaranteed by the caller.
+    unsafe {
+        input(src, len)?;
+        output(dest, len)?;
+    }
+    if len > 0 {
+        // SAFETY: both are non-null and valid for `len` bytes; `ptr::copy`
+        // allows overlap.
+        unsafe { ptr::copy(src, dest, len) };
+    }
+    // SAFETY: guaranteed by the caller.
+    unsafe { output(dest, len) }
+}
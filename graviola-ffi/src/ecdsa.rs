@@ -0,0 +1,262 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::ffi::c_int;
+
+use graviola::hashing::{Sha256, Sha384};
+use graviola::key_agreement::{p256, p384};
+use graviola::signing::ecdsa::{P256, P384, SigningKey, VerifyingKey};
+
+use crate::{GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM, Status, guard, input, write_output};
+
+/// ECDSA on P-256 with SHA-256.
+pub const GRAVIOLA_ECDSA_P256_SHA256: u32 = 1;
+
+/// ECDSA on P-384 with SHA-384.
+pub const GRAVIOLA_ECDSA_P384_SHA384: u32 = 2;
+
+/// Runs `$body` with `$curve` and `$hash` naming the types for `$algorithm`.
+macro_rules! dispatch {
+    ($algorithm:expr, |$curve:ident, $hash:ident| $body:expr) => {
+        match $algorithm {
+            GRAVIOLA_ECDSA_P256_SHA256 => {
+                type $curve = P256;
+                type $hash = Sha256;
+                $body
+            }
+            GRAVIOLA_ECDSA_P384_SHA384 => {
+                type $curve = P384;
+                type $hash = Sha384;
+                $body
+            }
+            _ => Err(Status(GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM)),
+        }
+    };
+}
+
+/// Generates a random private key, written in PKCS#8 DER format.
+///
+/// `private_key_len` is the capacity of `private_key`, and the length of
+/// the encoding is written to `private_key_written`.  256 bytes is
+/// sufficient.
+///
+/// # Safety
+/// `private_key` must be valid for writes of `private_key_len` bytes, and
+/// `private_key_written` must be valid for a write of a `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_ecdsa_generate(
+    algorithm: u32,
+    private_key: *mut u8,
+    private_key_len: usize,
+    private_key_written: *mut usize,
+) -> c_int {
+    guard(|| {
+        let mut buf = [0u8; MAX_PKCS8_LEN];
+        let encoded = match algorithm {
+            GRAVIOLA_ECDSA_P256_SHA256 => SigningKey::<P256> {
+                private_key: p256::StaticPrivateKey::new_random()?,
+            }
+            .to_pkcs8_der(&mut buf)?,
+            GRAVIOLA_ECDSA_P384_SHA384 => SigningKey::<P384> {
+                private_key: p384::StaticPrivateKey::new_random()?,
+            }
+            .to_pkcs8_der(&mut buf)?,
+            _ => return Err(Status(GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM)),
+        };
+        // SAFETY: guaranteed by the caller.
+        unsafe { write_output(encoded, private_key, private_key_len, private_key_written) }
+    })
+}
+
+/// Computes the public key of a PKCS#8 DER private key, as an uncompressed
+/// X9.62 point.
+///
+/// `public_key_len` is the capacity of `public_key`, and the length of
+/// the point is written to `public_key_written`.  97 bytes is sufficient.
+///
+/// # Safety
+/// Each pointer must be valid for its given length, or be null with a length
+/// of zero.  `public_key_written` must be valid for a write of a `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_ecdsa_public_key(
+    algorithm: u32,
+    private_key: *const u8,
+    private_key_len: usize,
+    public_key: *mut u8,
+    public_key_len: usize,
+    public_key_written: *mut usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let private_key = unsafe { input(private_key, private_key_len) }?;
+        let mut buf = [0u8; MAX_PUBLIC_KEY_LEN];
+        let encoded = match algorithm {
+            GRAVIOLA_ECDSA_P256_SHA256 => {
+                let key = SigningKey::<P256>::from_pkcs8_der(private_key)?;
+                let point = key.private_key.public_key_uncompressed();
+                buf[..point.len()].copy_from_slice(&point);
+                &buf[..point.len()]
+            }
+            GRAVIOLA_ECDSA_P384_SHA384 => {
+                let key = SigningKey::<P384>::from_pkcs8_der(private_key)?;
+                let point = key.private_key.public_key_uncompressed();
+                buf[..point.len()].copy_from_slice(&point);
+                &buf[..point.len()]
+            }
+            _ => return Err(Status(GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM)),
+        };
+        // SAFETY: guaranteed by the caller.
+        unsafe { write_output(encoded, public_key, public_key_len, public_key_written) }
+    })
+}
+
+/// Signs `message` with a PKCS#8 DER private key.
+///
+/// The signature is in the fixed-length format, the concatenation of `r`
+/// and `s`, sometimes called IEEE P1363 format.  `signature_len` is the
+/// capacity of `signature`, and the length of the signature is written to
+/// `signature_written`.  96 bytes is sufficient.
+///
+/// # Safety
+/// Each pointer must be valid for its given length, or be null with a length
+/// of zero.  `signature_written` must be valid for a write of a `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_ecdsa_sign(
+    algorithm: u32,
+    private_key: *const u8,
+    private_key_len: usize,
+    message: *const u8,
+    message_len: usize,
+    signature: *mut u8,
+    signature_len: usize,
+    signature_written: *mut usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (private_key, message) = unsafe {
+            (
+                input(private_key, private_key_len)?,
+                input(message, message_len)?,
+            )
+        };
+        let mut buf = [0u8; MAX_SIGNATURE_LEN];
+        let encoded = dispatch!(algorithm, |C, H| SigningKey::<C>::from_pkcs8_der(
+            private_key
+        )
+        .and_then(|key| key.sign::<H>(&[message], &mut buf))
+        .map_err(Status::from))?;
+        // SAFETY: guaranteed by the caller.
+        unsafe { write_output(encoded, signature, signature_len, signature_written) }
+    })
+}
+
+/// Signs `message` with a PKCS#8 DER private key.
+///
+/// This is like `graviola_ecdsa_sign()`, but the signature is a DER-encoded
+/// ASN.1 `Ecdsa-Sig-Value`, as used in X.509 and TLS.  104 bytes is
+/// sufficient.
+///
+/// # Safety
+/// As for `graviola_ecdsa_sign()`.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_ecdsa_sign_asn1(
+    algorithm: u32,
+    private_key: *const u8,
+    private_key_len: usize,
+    message: *const u8,
+    message_len: usize,
+    signature: *mut u8,
+    signature_len: usize,
+    signature_written: *mut usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (private_key, message) = unsafe {
+            (
+                input(private_key, private_key_len)?,
+                input(message, message_len)?,
+            )
+        };
+        let mut buf = [0u8; MAX_SIGNATURE_LEN];
+        let encoded = dispatch!(algorithm, |C, H| SigningKey::<C>::from_pkcs8_der(
+            private_key
+        )
+        .and_then(|key| key.sign_asn1::<H>(&[message], &mut buf))
+        .map_err(Status::from))?;
+        // SAFETY: guaranteed by the caller.
+        unsafe { write_output(encoded, signature, signature_len, signature_written) }
+    })
+}
+
+/// Verifies a fixed-length `signature` of `message`.
+///
+/// `public_key` is an uncompressed X9.62 point.  `GRAVIOLA_ERROR_BAD_SIGNATURE`
+/// is returned if the signature is invalid.
+///
+/// # Safety
+/// Each pointer must be valid for its given length, or be null with a length
+/// of zero.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_ecdsa_verify(
+    algorithm: u32,
+    public_key: *const u8,
+    public_key_len: usize,
+    message: *const u8,
+    message_len: usize,
+    signature: *const u8,
+    signature_len: usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (public_key, message, signature) = unsafe {
+            (
+                input(public_key, public_key_len)?,
+                input(message, message_len)?,
+                input(signature, signature_len)?,
+            )
+        };
+        dispatch!(algorithm, |C, H| VerifyingKey::<C>::from_x962_uncompressed(
+            public_key
+        )
+        .and_then(|key| key.verify::<H>(&[message], signature))
+        .map_err(Status::from))
+    })
+}
+
+/// Verifies a DER-encoded ASN.1 `signature` of `message`.
+///
+/// This is like `graviola_ecdsa_verify()`, for signatures made by
+/// `graviola_ecdsa_sign_asn1()`.
+///
+/// # Safety
+/// As for `graviola_ecdsa_verify()`.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_ecdsa_verify_asn1(
+    algorithm: u32,
+    public_key: *const u8,
+    public_key_len: usize,
+    message: *const u8,
+    message_len: usize,
+    signature: *const u8,
+    signature_len: usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (public_key, message, signature) = unsafe {
+            (
+                input(public_key, public_key_len)?,
+                input(message, message_len)?,
+                input(signature, signature_len)?,
+            )
+        };
+        dispatch!(algorithm, |C, H| VerifyingKey::<C>::from_x962_uncompressed(
+            public_key
+        )
+        .and_then(|key| key.verify_asn1::<H>(&[message], signature))
+        .map_err(Status::from))
+    })
+}
+
+const MAX_PKCS8_LEN: usize = 256;
+const MAX_PUBLIC_KEY_LEN: usize = 97;
+const MAX_SIGNATURE_LEN: usize = 104;
@@ -0,0 +1,48 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::ffi::c_int;
+
+use graviola::hashing::{Hash, Sha256, Sha384, Sha512};
+
+use crate::{GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM, Status, guard, input, write_output};
+
+/// SHA-256, with a 32 byte output.
+pub const GRAVIOLA_HASH_SHA256: u32 = 1;
+
+/// SHA-384, with a 48 byte output.
+pub const GRAVIOLA_HASH_SHA384: u32 = 2;
+
+/// SHA-512, with a 64 byte output.
+pub const GRAVIOLA_HASH_SHA512: u32 = 3;
+
+/// Hashes `data`, writing the result to `output`.
+///
+/// `output_len` is the capacity of `output`, and the length of the hash
+/// is written to `output_written`.
+///
+/// # Safety
+/// Each pointer must be valid for its given length, or be null with a length
+/// of zero.  `output_written` must be valid for a write of a `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_hash(
+    algorithm: u32,
+    data: *const u8,
+    data_len: usize,
+    output: *mut u8,
+    output_len: usize,
+    output_written: *mut usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let data = unsafe { input(data, data_len) }?;
+        let hash = match algorithm {
+            GRAVIOLA_HASH_SHA256 => Sha256::hash(data),
+            GRAVIOLA_HASH_SHA384 => Sha384::hash(data),
+            GRAVIOLA_HASH_SHA512 => Sha512::hash(data),
+            _ => return Err(Status(GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM)),
+        };
+        // SAFETY: guaranteed by the caller.
+        unsafe { write_output(hash.as_ref(), output, output_len, output_written) }
+    })
+}
@@ -0,0 +1,193 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! <h1 align="center">Graviola</h1>
+//! <img width="40%" align="right" src="https://raw.githubusercontent.com/ctz/graviola/main/admin/picture.png">
+//!
+//! This crate provides a C ABI for [Graviola](https://github.com/ctz/graviola/).
+//! It is built as a `cdylib` and a `staticlib`, and the matching header is
+//! `include/graviola.h`.
+//!
+//! Every function returns `GRAVIOLA_OK` (zero) on success, or one of the
+//! negative `GRAVIOLA_ERROR_*` codes.  Every buffer is passed as a pointer
+//! and an explicit length; a null pointer is accepted only with a zero
+//! length.  Where the length of an output is not fixed, the caller gives
+//! the capacity of the buffer and the used length is written to a
+//! `*_written` argument.
+//!
+//! Panics are caught at the boundary and reported as `GRAVIOLA_ERROR_PANIC`.
+
+#![warn(
+    clippy::undocumented_unsafe_blocks,
+    elided_lifetimes_in_paths,
+    missing_docs,
+    unreachable_pub
+)]
+
+use core::ffi::c_int;
+use std::panic;
+
+mod aead;
+mod ecdsa;
+mod hash;
+mod rsa;
+mod x25519;
+
+pub use aead::{
+    GRAVIOLA_AEAD_AES_128_GCM, GRAVIOLA_AEAD_AES_256_GCM, GRAVIOLA_AEAD_CHACHA20_POLY1305,
+    GRAVIOLA_AEAD_TAG_LEN, GRAVIOLA_AEAD_XCHACHA20_POLY1305, graviola_aead_open,
+    graviola_aead_seal,
+};
+pub use ecdsa::{
+    GRAVIOLA_ECDSA_P256_SHA256, GRAVIOLA_ECDSA_P384_SHA384, graviola_ecdsa_generate,
+    graviola_ecdsa_public_key, graviola_ecdsa_sign, graviola_ecdsa_sign_asn1,
+    graviola_ecdsa_verify, graviola_ecdsa_verify_asn1,
+};
+pub use hash::{GRAVIOLA_HASH_SHA256, GRAVIOLA_HASH_SHA384, GRAVIOLA_HASH_SHA512, graviola_hash};
+pub use rsa::{
+    GRAVIOLA_RSA_PKCS1_SHA256, GRAVIOLA_RSA_PKCS1_SHA384, GRAVIOLA_RSA_PKCS1_SHA512,
+    GRAVIOLA_RSA_PSS_SHA256, GRAVIOLA_RSA_PSS_SHA384, GRAVIOLA_RSA_PSS_SHA512, graviola_rsa_verify,
+};
+pub use x25519::{
+    GRAVIOLA_X25519_KEY_LEN, graviola_x25519_diffie_hellman, graviola_x25519_generate,
+    graviola_x25519_public_key,
+};
+
+/// The operation succeeded.
+pub const GRAVIOLA_OK: c_int = 0;
+
+/// A pointer was null, but its length was not zero.
+pub const GRAVIOLA_ERROR_NULL_POINTER: c_int = -1;
+
+/// The algorithm identifier was not recognised.
+pub const GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM: c_int = -2;
+
+/// A buffer was the wrong length, or an output buffer was too small.
+pub const GRAVIOLA_ERROR_WRONG_LENGTH: c_int = -3;
+
+/// A key was malformed or invalid.
+pub const GRAVIOLA_ERROR_INVALID_KEY: c_int = -4;
+
+/// A signature did not verify.
+pub const GRAVIOLA_ERROR_BAD_SIGNATURE: c_int = -5;
+
+/// An AEAD tag, ciphertext, nonce or additional data was wrong.
+pub const GRAVIOLA_ERROR_DECRYPT_FAILED: c_int = -6;
+
+/// The system random number generator failed.
+pub const GRAVIOLA_ERROR_RNG_FAILED: c_int = -7;
+
+/// A panic occurred.  This is a bug.
+pub const GRAVIOLA_ERROR_PANIC: c_int = -8;
+
+/// Some other error occurred.
+pub const GRAVIOLA_ERROR_OTHER: c_int = -9;
+
+/// A failure, carrying one of the `GRAVIOLA_ERROR_*` codes.
+struct Status(c_int);
+
+impl From<graviola::Error> for Status {
+    fn from(err: graviola::Error) -> Self {
+        use graviola::Error::*;
+        Self(match err {
+            WrongLength => GRAVIOLA_ERROR_WRONG_LENGTH,
+            NotUncompressed | NotOnCurve | OutOfRange | Asn1Error(_) | KeyFormatError(_) => {
+                GRAVIOLA_ERROR_INVALID_KEY
+            }
+            RngFailed => GRAVIOLA_ERROR_RNG_FAILED,
+            BadSignature => GRAVIOLA_ERROR_BAD_SIGNATURE,
+            DecryptFailed => GRAVIOLA_ERROR_DECRYPT_FAILED,
+            _ => GRAVIOLA_ERROR_OTHER,
+        })
+    }
+}
+
+/// Runs `f`, converting its result or panic into a status code.
+fn guard(f: impl FnOnce() -> Result<(), Status>) -> c_int {
+    match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+        Ok(Ok(())) => GRAVIOLA_OK,
+        Ok(Err(Status(code))) => code,
+        Err(_) => GRAVIOLA_ERROR_PANIC,
+    }
+}
+
+/// Borrows an input buffer.
+///
+/// # Safety
+/// `ptr` must be null, or valid for reads of `len` bytes for `'a`.
+unsafe fn input<'a>(ptr: *const u8, len: usize) -> Result<&'a [u8], Status> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(Status(GRAVIOLA_ERROR_NULL_POINTER)),
+        // SAFETY: non-null, and otherwise guaranteed by the caller.
+        (false, _) => Ok(unsafe { core::slice::from_raw_parts(ptr, len) }),
+    }
+}
+
+/// Borrows a fixed-length input buffer.
+///
+/// # Safety
+/// As for [`input()`].
+unsafe fn input_array<'a, const N: usize>(
+    ptr: *const u8,
+    len: usize,
+) -> Result<&'a [u8; N], Status> {
+    // SAFETY: guaranteed by the caller.
+    let slice = unsafe { input(ptr, len) }?;
+    slice
+        .try_into()
+        .map_err(|_| Status(GRAVIOLA_ERROR_WRONG_LENGTH))
+}
+
+/// Borrows an output buffer.
+///
+/// # Safety
+/// `ptr` must be null, or valid for writes of `len` bytes for `'a`, and not
+/// aliased by any other live borrow.
+unsafe fn output<'a>(ptr: *mut u8, len: usize) -> Result<&'a mut [u8], Status> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Ok(&mut []),
+        (true, _) => Err(Status(GRAVIOLA_ERROR_NULL_POINTER)),
+        // SAFETY: non-null, and otherwise guaranteed by the caller.
+        (false, _) => Ok(unsafe { core::slice::from_raw_parts_mut(ptr, len) }),
+    }
+}
+
+/// Borrows a fixed-length output buffer.
+///
+/// # Safety
+/// As for [`output()`].
+unsafe fn output_array<'a, const N: usize>(
+    ptr: *mut u8,
+    len: usize,
+) -> Result<&'a mut [u8; N], Status> {
+    // SAFETY: guaranteed by the caller.
+    let slice = unsafe { output(ptr, len) }?;
+    slice
+        .try_into()
+        .map_err(|_| Status(GRAVIOLA_ERROR_WRONG_LENGTH))
+}
+
+/// Copies `value` to the start of `out`, and its length to `written`.
+///
+/// # Safety
+/// `out` must be valid for writes of `out_len` bytes, and `written` must
+/// be valid for a write of a `usize`.
+unsafe fn write_output(
+    value: &[u8],
+    out: *mut u8,
+    out_len: usize,
+    written: *mut usize,
+) -> Result<(), Status> {
+    if written.is_null() {
+        return Err(Status(GRAVIOLA_ERROR_NULL_POINTER));
+    }
+    // SAFETY: guaranteed by the caller.
+    let out = unsafe { output(out, out_len) }?;
+    out.get_mut(..value.len())
+        .ok_or(Status(GRAVIOLA_ERROR_WRONG_LENGTH))?
+        .copy_from_slice(value);
+    // SAFETY: non-null, and otherwise guaranteed by the caller.
+    unsafe { written.write(value.len()) };
+    Ok(())
+}
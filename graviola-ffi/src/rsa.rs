@@ -0,0 +1,71 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::ffi::c_int;
+
+use graviola::signing::rsa::VerifyingKey;
+
+use crate::{GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM, Status, guard, input};
+
+/// RSASSA-PKCS1-v1_5 with SHA-256.
+pub const GRAVIOLA_RSA_PKCS1_SHA256: u32 = 1;
+
+/// RSASSA-PKCS1-v1_5 with SHA-384.
+pub const GRAVIOLA_RSA_PKCS1_SHA384: u32 = 2;
+
+/// RSASSA-PKCS1-v1_5 with SHA-512.
+pub const GRAVIOLA_RSA_PKCS1_SHA512: u32 = 3;
+
+/// RSASSA-PSS with SHA-256, MGF1 with SHA-256, and a 32 byte salt.
+pub const GRAVIOLA_RSA_PSS_SHA256: u32 = 4;
+
+/// RSASSA-PSS with SHA-384, MGF1 with SHA-384, and a 48 byte salt.
+pub const GRAVIOLA_RSA_PSS_SHA384: u32 = 5;
+
+/// RSASSA-PSS with SHA-512, MGF1 with SHA-512, and a 64 byte salt.
+pub const GRAVIOLA_RSA_PSS_SHA512: u32 = 6;
+
+/// Verifies an RSA `signature` of `message`.
+///
+/// `public_key` is a PKCS#1 `RSAPublicKey` in DER format.  Moduli of 2048
+/// to 8192 bits are supported.  `GRAVIOLA_ERROR_BAD_SIGNATURE` is returned
+/// if the signature is invalid.
+///
+/// # Safety
+/// Each pointer must be valid for its given length, or be null with a length
+/// of zero.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_rsa_verify(
+    algorithm: u32,
+    public_key: *const u8,
+    public_key_len: usize,
+    message: *const u8,
+    message_len: usize,
+    signature: *const u8,
+    signature_len: usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (public_key, message, signature) = unsafe {
+            (
+                input(public_key, public_key_len)?,
+                input(message, message_len)?,
+                input(signature, signature_len)?,
+            )
+        };
+        let verify = match algorithm {
+            GRAVIOLA_RSA_PKCS1_SHA256 => VerifyingKey::verify_pkcs1_sha256,
+            GRAVIOLA_RSA_PKCS1_SHA384 => VerifyingKey::verify_pkcs1_sha384,
+            GRAVIOLA_RSA_PKCS1_SHA512 => VerifyingKey::verify_pkcs1_sha512,
+            GRAVIOLA_RSA_PSS_SHA256 => VerifyingKey::verify_pss_sha256,
+            GRAVIOLA_RSA_PSS_SHA384 => VerifyingKey::verify_pss_sha384,
+            GRAVIOLA_RSA_PSS_SHA512 => VerifyingKey::verify_pss_sha512,
+            _ => return Err(Status(GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM)),
+        };
+        Ok(verify(
+            &VerifyingKey::from_pkcs1_der(public_key)?,
+            signature,
+            message,
+        )?)
+    })
+}
@@ -0,0 +1,100 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::ffi::c_int;
+
+use graviola::key_agreement::x25519::{PublicKey, StaticPrivateKey};
+
+use crate::{guard, input_array, output_array};
+
+/// The length of X25519 private keys, public keys and shared secrets.
+pub const GRAVIOLA_X25519_KEY_LEN: usize = 32;
+
+/// Generates a random X25519 private key, and its public key.
+///
+/// Both lengths must be `GRAVIOLA_X25519_KEY_LEN`.
+///
+/// # Safety
+/// Each pointer must be valid for its given length.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_x25519_generate(
+    private_key: *mut u8,
+    private_key_len: usize,
+    public_key: *mut u8,
+    public_key_len: usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (private_out, public_out) = unsafe {
+            (
+                output_array(private_key, private_key_len)?,
+                output_array(public_key, public_key_len)?,
+            )
+        };
+        let key = StaticPrivateKey::new_random()?;
+        *private_out = key.as_bytes();
+        *public_out = key.public_key().as_bytes();
+        Ok(())
+    })
+}
+
+/// Computes the public key of an X25519 private key.
+///
+/// Both lengths must be `GRAVIOLA_X25519_KEY_LEN`.
+///
+/// # Safety
+/// Each pointer must be valid for its given length.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_x25519_public_key(
+    private_key: *const u8,
+    private_key_len: usize,
+    public_key: *mut u8,
+    public_key_len: usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (private_key, public_out) = unsafe {
+            (
+                input_array(private_key, private_key_len)?,
+                output_array(public_key, public_key_len)?,
+            )
+        };
+        *public_out = StaticPrivateKey::from_array(private_key)
+            .public_key()
+            .as_bytes();
+        Ok(())
+    })
+}
+
+/// Computes the X25519 shared secret between `private_key` and `peer_public_key`.
+///
+/// All lengths must be `GRAVIOLA_X25519_KEY_LEN`.  `GRAVIOLA_ERROR_INVALID_KEY`
+/// is returned if the shared secret is zero, which happens when the peer's
+/// public key has a small order.
+///
+/// # Safety
+/// Each pointer must be valid for its given length.
+#[no_mangle]
+pub unsafe extern "C" fn graviola_x25519_diffie_hellman(
+    private_key: *const u8,
+    private_key_len: usize,
+    peer_public_key: *const u8,
+    peer_public_key_len: usize,
+    shared_secret: *mut u8,
+    shared_secret_len: usize,
+) -> c_int {
+    guard(|| {
+        // SAFETY: guaranteed by the caller.
+        let (private_key, peer, shared_out) = unsafe {
+            (
+                input_array(private_key, private_key_len)?,
+                input_array(peer_public_key, peer_public_key_len)?,
+                output_array(shared_secret, shared_secret_len)?,
+            )
+        };
+        let shared = StaticPrivateKey::from_array(private_key)
+            .diffie_hellman(&PublicKey::from_array(peer))?;
+        *shared_out = shared.0;
+        Ok(())
+    })
+}
@@ -0,0 +1,626 @@
+use core::ptr;
+
+use graviola::signing::rsa::SigningKey;
+use graviola_ffi::*;
+
+#[test]
+fn aead_round_trip() {
+    for (algorithm, key_len, nonce_len) in [
+        (GRAVIOLA_AEAD_AES_128_GCM, 16, 12),
+        (GRAVIOLA_AEAD_AES_256_GCM, 32, 12),
+        (GRAVIOLA_AEAD_CHACHA20_POLY1305, 32, 12),
+        (GRAVIOLA_AEAD_XCHACHA20_POLY1305, 32, 24),
+    ] {
+        let key = vec![0x11; key_len];
+        let nonce = vec![0x22; nonce_len];
+        let aad = b"header";
+        let plaintext = b"hello world";
+
+        let mut ciphertext = [0u8; 11];
+        let mut tag = [0u8; GRAVIOLA_AEAD_TAG_LEN];
+        assert_eq!(
+            seal(
+                algorithm,
+                &key,
+                &nonce,
+                aad,
+                plaintext,
+                &mut ciphertext,
+                &mut tag
+            ),
+            GRAVIOLA_OK
+        );
+        assert_ne!(&ciphertext, plaintext);
+
+        // in place
+        let mut inout = *plaintext;
+        let mut tag2 = [0u8; GRAVIOLA_AEAD_TAG_LEN];
+        assert_eq!(
+            unsafe {
+                graviola_aead_seal(
+                    algorithm,
+                    key.as_ptr(),
+                    key.len(),
+                    nonce.as_ptr(),
+                    nonce.len(),
+                    aad.as_ptr(),
+                    aad.len(),
+                    inout.as_ptr(),
+                    inout.len(),
+                    inout.as_mut_ptr(),
+                    inout.len(),
+                    tag2.as_mut_ptr(),
+                    tag2.len(),
+                )
+            },
+            GRAVIOLA_OK
+        );
+        assert_eq!(inout, ciphertext);
+        assert_eq!(tag2, tag);
+
+        let mut decrypted = [0u8; 11];
+        assert_eq!(
+            open(
+                algorithm,
+                &key,
+                &nonce,
+                aad,
+                &ciphertext,
+                &tag,
+                &mut decrypted
+            ),
+            GRAVIOLA_OK
+        );
+        assert_eq!(&decrypted, plaintext);
+
+        tag[0] ^= 1;
+        assert_eq!(
+            open(
+                algorithm,
+                &key,
+                &nonce,
+                aad,
+                &ciphertext,
+                &tag,
+                &mut decrypted
+            ),
+            GRAVIOLA_ERROR_DECRYPT_FAILED
+        );
+        assert_eq!(decrypted, [0u8; 11]);
+
+        assert_eq!(
+            seal(
+                algorithm,
+                &key[1..],
+                &nonce,
+                aad,
+                plaintext,
+                &mut ciphertext,
+                &mut tag
+            ),
+            GRAVIOLA_ERROR_WRONG_LENGTH
+        );
+        assert_eq!(
+            seal(
+                algorithm,
+                &key,
+                &nonce[1..],
+                aad,
+                plaintext,
+                &mut ciphertext,
+                &mut tag
+            ),
+            GRAVIOLA_ERROR_WRONG_LENGTH
+        );
+        assert_eq!(
+            seal(
+                algorithm,
+                &key,
+                &nonce,
+                aad,
+                plaintext,
+                &mut ciphertext[1..],
+                &mut tag
+            ),
+            GRAVIOLA_ERROR_WRONG_LENGTH
+        );
+    }
+}
+
+#[test]
+fn aead_errors() {
+    let key = [0u8; 16];
+    let nonce = [0u8; 12];
+    let mut tag = [0u8; 16];
+    assert_eq!(
+        seal(0, &key, &nonce, b"", b"", &mut [], &mut tag),
+        GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM
+    );
+    assert_eq!(
+        seal(
+            GRAVIOLA_AEAD_AES_128_GCM,
+            &key,
+            &nonce,
+            b"",
+            b"",
+            &mut [],
+            &mut tag[..15]
+        ),
+        GRAVIOLA_ERROR_WRONG_LENGTH
+    );
+    assert_eq!(
+        unsafe {
+            graviola_aead_seal(
+                GRAVIOLA_AEAD_AES_128_GCM,
+                key.as_ptr(),
+                key.len(),
+                nonce.as_ptr(),
+                nonce.len(),
+                ptr::null(),
+                1,
+                ptr::null(),
+                0,
+                ptr::null_mut(),
+                0,
+                tag.as_mut_ptr(),
+                tag.len(),
+            )
+        },
+        GRAVIOLA_ERROR_NULL_POINTER
+    );
+}
+
+#[test]
+fn hash() {
+    let mut out = [0u8; 64];
+    let mut written = 0;
+    for (algorithm, len) in [
+        (GRAVIOLA_HASH_SHA256, 32),
+        (GRAVIOLA_HASH_SHA384, 48),
+        (GRAVIOLA_HASH_SHA512, 64),
+    ] {
+        assert_eq!(
+            unsafe {
+                graviola_hash(
+                    algorithm,
+                    b"abc".as_ptr(),
+                    3,
+                    out.as_mut_ptr(),
+                    64,
+                    &mut written,
+                )
+            },
+            GRAVIOLA_OK
+        );
+        assert_eq!(written, len);
+    }
+    assert_eq!(
+        &out[..4],
+        &[0xdd, 0xaf, 0x35, 0xa1],
+        "sha512(\"abc\") prefix"
+    );
+
+    assert_eq!(
+        unsafe {
+            graviola_hash(
+                GRAVIOLA_HASH_SHA256,
+                ptr::null(),
+                0,
+                out.as_mut_ptr(),
+                32,
+                &mut written,
+            )
+        },
+        GRAVIOLA_OK
+    );
+    assert_eq!(&out[..4], &[0xe3, 0xb0, 0xc4, 0x42], "sha256(\"\") prefix");
+
+    assert_eq!(
+        unsafe {
+            graviola_hash(
+                GRAVIOLA_HASH_SHA384,
+                ptr::null(),
+                0,
+                out.as_mut_ptr(),
+                47,
+                &mut written,
+            )
+        },
+        GRAVIOLA_ERROR_WRONG_LENGTH
+    );
+    assert_eq!(
+        unsafe { graviola_hash(4, ptr::null(), 0, out.as_mut_ptr(), 64, &mut written) },
+        GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM
+    );
+    assert_eq!(
+        unsafe {
+            graviola_hash(
+                GRAVIOLA_HASH_SHA256,
+                ptr::null(),
+                0,
+                out.as_mut_ptr(),
+                64,
+                ptr::null_mut(),
+            )
+        },
+        GRAVIOLA_ERROR_NULL_POINTER
+    );
+}
+
+#[test]
+fn x25519_rfc7748() {
+    let alice_private = unhex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let bob_public = unhex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f");
+
+    let mut alice_public = [0u8; GRAVIOLA_X25519_KEY_LEN];
+    assert_eq!(
+        unsafe {
+            graviola_x25519_public_key(alice_private.as_ptr(), 32, alice_public.as_mut_ptr(), 32)
+        },
+        GRAVIOLA_OK
+    );
+    assert_eq!(
+        alice_public.to_vec(),
+        unhex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+    );
+
+    let mut shared = [0u8; GRAVIOLA_X25519_KEY_LEN];
+    assert_eq!(
+        unsafe {
+            graviola_x25519_diffie_hellman(
+                alice_private.as_ptr(),
+                32,
+                bob_public.as_ptr(),
+                32,
+                shared.as_mut_ptr(),
+                32,
+            )
+        },
+        GRAVIOLA_OK
+    );
+    assert_eq!(
+        shared.to_vec(),
+        unhex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742")
+    );
+
+    let small_order = [0u8; 32];
+    assert_eq!(
+        unsafe {
+            graviola_x25519_diffie_hellman(
+                alice_private.as_ptr(),
+                32,
+                small_order.as_ptr(),
+                32,
+                shared.as_mut_ptr(),
+                32,
+            )
+        },
+        GRAVIOLA_ERROR_INVALID_KEY
+    );
+    assert_eq!(
+        unsafe {
+            graviola_x25519_public_key(alice_private.as_ptr(), 31, alice_public.as_mut_ptr(), 32)
+        },
+        GRAVIOLA_ERROR_WRONG_LENGTH
+    );
+}
+
+#[test]
+fn x25519_agreement() {
+    let (mut a_private, mut a_public) = ([0u8; 32], [0u8; 32]);
+    let (mut b_private, mut b_public) = ([0u8; 32], [0u8; 32]);
+    unsafe {
+        assert_eq!(
+            graviola_x25519_generate(a_private.as_mut_ptr(), 32, a_public.as_mut_ptr(), 32),
+            GRAVIOLA_OK
+        );
+        assert_eq!(
+            graviola_x25519_generate(b_private.as_mut_ptr(), 32, b_public.as_mut_ptr(), 32),
+            GRAVIOLA_OK
+        );
+    }
+
+    let (mut a_shared, mut b_shared) = ([0u8; 32], [1u8; 32]);
+    unsafe {
+        assert_eq!(
+            graviola_x25519_diffie_hellman(
+                a_private.as_ptr(),
+                32,
+                b_public.as_ptr(),
+                32,
+                a_shared.as_mut_ptr(),
+                32
+            ),
+            GRAVIOLA_OK
+        );
+        assert_eq!(
+            graviola_x25519_diffie_hellman(
+                b_private.as_ptr(),
+                32,
+                a_public.as_ptr(),
+                32,
+                b_shared.as_mut_ptr(),
+                32
+            ),
+            GRAVIOLA_OK
+        );
+    }
+    assert_eq!(a_shared, b_shared);
+}
+
+#[test]
+fn ecdsa() {
+    for (algorithm, point_len, fixed_len) in [
+        (GRAVIOLA_ECDSA_P256_SHA256, 65, 64),
+        (GRAVIOLA_ECDSA_P384_SHA384, 97, 96),
+    ] {
+        let mut private_key = [0u8; 256];
+        let mut private_key_len = 0;
+        assert_eq!(
+            unsafe {
+                graviola_ecdsa_generate(
+                    algorithm,
+                    private_key.as_mut_ptr(),
+                    256,
+                    &mut private_key_len,
+                )
+            },
+            GRAVIOLA_OK
+        );
+        let private_key = &private_key[..private_key_len];
+
+        let mut public_key = [0u8; 97];
+        let mut public_key_len = 0;
+        assert_eq!(
+            unsafe {
+                graviola_ecdsa_public_key(
+                    algorithm,
+                    private_key.as_ptr(),
+                    private_key.len(),
+                    public_key.as_mut_ptr(),
+                    97,
+                    &mut public_key_len,
+                )
+            },
+            GRAVIOLA_OK
+        );
+        assert_eq!(public_key_len, point_len);
+        let public_key = &public_key[..public_key_len];
+
+        for (sign, verify_fn) in [
+            (
+                graviola_ecdsa_sign as SignFn,
+                graviola_ecdsa_verify as VerifyFn,
+            ),
+            (graviola_ecdsa_sign_asn1, graviola_ecdsa_verify_asn1),
+        ] {
+            let message = b"hello world";
+            let mut signature = [0u8; 104];
+            let mut signature_len = 0;
+            assert_eq!(
+                unsafe {
+                    sign(
+                        algorithm,
+                        private_key.as_ptr(),
+                        private_key.len(),
+                        message.as_ptr(),
+                        message.len(),
+                        signature.as_mut_ptr(),
+                        104,
+                        &mut signature_len,
+                    )
+                },
+                GRAVIOLA_OK
+            );
+            let signature = &mut signature[..signature_len];
+            assert_eq!(
+                verify(verify_fn, algorithm, public_key, message, signature),
+                GRAVIOLA_OK
+            );
+            assert_eq!(
+                verify(verify_fn, algorithm, public_key, b"goodbye", signature),
+                GRAVIOLA_ERROR_BAD_SIGNATURE
+            );
+            signature[signature_len - 1] ^= 1;
+            assert_eq!(
+                verify(verify_fn, algorithm, public_key, message, signature),
+                GRAVIOLA_ERROR_BAD_SIGNATURE
+            );
+        }
+
+        let mut signature = [0u8; 104];
+        let mut signature_len = 0;
+        assert_eq!(
+            unsafe {
+                graviola_ecdsa_sign(
+                    algorithm,
+                    private_key.as_ptr(),
+                    private_key.len(),
+                    b"".as_ptr(),
+                    0,
+                    signature.as_mut_ptr(),
+                    fixed_len - 1,
+                    &mut signature_len,
+                )
+            },
+            GRAVIOLA_ERROR_WRONG_LENGTH
+        );
+        assert_eq!(
+            unsafe {
+                graviola_ecdsa_verify(algorithm, ptr::null(), 0, ptr::null(), 0, ptr::null(), 0)
+            },
+            GRAVIOLA_ERROR_WRONG_LENGTH
+        );
+    }
+
+    assert_eq!(
+        unsafe { graviola_ecdsa_verify(3, ptr::null(), 0, ptr::null(), 0, ptr::null(), 0) },
+        GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM
+    );
+}
+
+#[test]
+fn rsa() {
+    let key = SigningKey::from_pkcs1_der(include_bytes!("../../graviola/src/high/rsa/rsa2048.der"))
+        .unwrap();
+    let mut public_key = [0u8; 1024];
+    let public_key = key.public_key().to_pkcs1_der(&mut public_key).unwrap();
+    let message = b"hello world";
+
+    type Sign = for<'a> fn(&SigningKey, &'a mut [u8], &[u8]) -> Result<&'a [u8], graviola::Error>;
+    for (algorithm, sign) in [
+        (
+            GRAVIOLA_RSA_PKCS1_SHA256,
+            SigningKey::sign_pkcs1_sha256 as Sign,
+        ),
+        (GRAVIOLA_RSA_PKCS1_SHA384, SigningKey::sign_pkcs1_sha384),
+        (GRAVIOLA_RSA_PKCS1_SHA512, SigningKey::sign_pkcs1_sha512),
+        (GRAVIOLA_RSA_PSS_SHA256, SigningKey::sign_pss_sha256),
+        (GRAVIOLA_RSA_PSS_SHA384, SigningKey::sign_pss_sha384),
+        (GRAVIOLA_RSA_PSS_SHA512, SigningKey::sign_pss_sha512),
+    ] {
+        let mut signature = [0u8; 256];
+        let signature = sign(&key, &mut signature, message).unwrap().to_vec();
+        assert_eq!(
+            rsa_verify(algorithm, public_key, message, &signature),
+            GRAVIOLA_OK
+        );
+        assert_eq!(
+            rsa_verify(algorithm, public_key, b"goodbye", &signature),
+            GRAVIOLA_ERROR_BAD_SIGNATURE
+        );
+
+        let other = match algorithm {
+            GRAVIOLA_RSA_PKCS1_SHA256 => GRAVIOLA_RSA_PSS_SHA256,
+            _ => GRAVIOLA_RSA_PKCS1_SHA256,
+        };
+        assert_eq!(
+            rsa_verify(other, public_key, message, &signature),
+            GRAVIOLA_ERROR_BAD_SIGNATURE
+        );
+    }
+
+    assert_eq!(
+        rsa_verify(
+            GRAVIOLA_RSA_PKCS1_SHA256,
+            &public_key[1..],
+            message,
+            &[0; 256]
+        ),
+        GRAVIOLA_ERROR_INVALID_KEY
+    );
+    assert_eq!(
+        rsa_verify(0, public_key, message, &[0; 256]),
+        GRAVIOLA_ERROR_UNSUPPORTED_ALGORITHM
+    );
+}
+
+type SignFn = unsafe extern "C" fn(
+    u32,
+    *const u8,
+    usize,
+    *const u8,
+    usize,
+    *mut u8,
+    usize,
+    *mut usize,
+) -> i32;
+type VerifyFn =
+    unsafe extern "C" fn(u32, *const u8, usize, *const u8, usize, *const u8, usize) -> i32;
+
+fn seal(
+    algorithm: u32,
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8],
+) -> i32 {
+    unsafe {
+        graviola_aead_seal(
+            algorithm,
+            key.as_ptr(),
+            key.len(),
+            nonce.as_ptr(),
+            nonce.len(),
+            aad.as_ptr(),
+            aad.len(),
+            plaintext.as_ptr(),
+            plaintext.len(),
+            ciphertext.as_mut_ptr(),
+            ciphertext.len(),
+            tag.as_mut_ptr(),
+            tag.len(),
+        )
+    }
+}
+
+fn open(
+    algorithm: u32,
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8],
+    plaintext: &mut [u8],
+) -> i32 {
+    unsafe {
+        graviola_aead_open(
+            algorithm,
+            key.as_ptr(),
+            key.len(),
+            nonce.as_ptr(),
+            nonce.len(),
+            aad.as_ptr(),
+            aad.len(),
+            ciphertext.as_ptr(),
+            ciphertext.len(),
+            tag.as_ptr(),
+            tag.len(),
+            plaintext.as_mut_ptr(),
+            plaintext.len(),
+        )
+    }
+}
+
+fn verify(
+    verify_fn: VerifyFn,
+    algorithm: u32,
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> i32 {
+    unsafe {
+        verify_fn(
+            algorithm,
+            public_key.as_ptr(),
+            public_key.len(),
+            message.as_ptr(),
+            message.len(),
+            signature.as_ptr(),
+            signature.len(),
+        )
+    }
+}
+
+fn rsa_verify(algorithm: u32, public_key: &[u8], message: &[u8], signature: &[u8]) -> i32 {
+    unsafe {
+        graviola_rsa_verify(
+            algorithm,
+            public_key.as_ptr(),
+            public_key.len(),
+            message.as_ptr(),
+            message.len(),
+            signature.as_ptr(),
+            signature.len(),
+        )
+    }
+}
+
+fn unhex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
@@ -31,6 +31,10 @@ pub enum Error {
 
     /// A key formatting/validation error.
     KeyFormatError(KeyFormatError),
+
+    /// An external key (for example, in an HSM or KMS) failed to
+    /// perform an operation.
+    ExternalKeyFailed,
 }
 
 #[non_exhaustive]
@@ -39,8 +43,38 @@ pub enum KeyFormatError {
     UnsupportedPkcs8Version,
     MismatchedPkcs8Algorithm,
     MismatchedPkcs8Parameters,
+    MismatchedPkcs8PublicKey,
     MismatchedSec1Curve,
     MismatchedSec1PublicKey,
+    MismatchedMlKemPublicKeyHash,
+    MismatchedMlDsaPrivateKey,
+    MismatchedSpkiAlgorithm,
+    MismatchedSpkiParameters,
+    InvalidTinkKeyset,
+    UnsupportedTinkKeyType,
+    InvalidPem,
+    MismatchedPemLabel,
+    UnsupportedPkcs8Encryption,
+    InvalidJwk,
+    MismatchedJwkKeyType,
+    MismatchedJwkPublicKey,
+    UnsupportedSignatureAlgorithm,
+    InvalidFingerprint,
+    InvalidJose,
+    UnsupportedJoseAlgorithm,
+    MismatchedJoseAlgorithm,
+    InvalidCose,
+    UnsupportedCoseAlgorithm,
+    MismatchedCoseAlgorithm,
+    InvalidPaseto,
+    UnsupportedPasetoVersion,
+    MismatchedPasetoPurpose,
+    InvalidOpenSshKey,
+    UnsupportedOpenSshKey,
+    MismatchedOpenSshKeyType,
+    EncryptedOpenSshKey,
+    UnencryptedOpenSshKey,
+    InvalidEncoding,
 }
 
 impl From<KeyFormatError> for Error {
@@ -55,8 +89,38 @@ impl core::fmt::Display for KeyFormatError {
             Self::UnsupportedPkcs8Version => write!(f, "unsupported PKCS#8 version"),
             Self::MismatchedPkcs8Algorithm => write!(f, "mismatched PKCS#8 algorithm"),
             Self::MismatchedPkcs8Parameters => write!(f, "mismatched PKCS#8 parameters"),
+            Self::MismatchedPkcs8PublicKey => write!(f, "mismatched PKCS#8 public key"),
             Self::MismatchedSec1Curve => write!(f, "mismatched SEC1 curve"),
             Self::MismatchedSec1PublicKey => write!(f, "mismatched SEC1 public key"),
+            Self::MismatchedMlKemPublicKeyHash => write!(f, "mismatched ML-KEM public key hash"),
+            Self::MismatchedMlDsaPrivateKey => write!(f, "mismatched ML-DSA private key"),
+            Self::MismatchedSpkiAlgorithm => write!(f, "mismatched SPKI algorithm"),
+            Self::MismatchedSpkiParameters => write!(f, "mismatched SPKI parameters"),
+            Self::InvalidTinkKeyset => write!(f, "invalid Tink keyset"),
+            Self::UnsupportedTinkKeyType => write!(f, "unsupported Tink key type"),
+            Self::InvalidPem => write!(f, "invalid PEM encoding"),
+            Self::MismatchedPemLabel => write!(f, "mismatched PEM label"),
+            Self::UnsupportedPkcs8Encryption => write!(f, "unsupported PKCS#8 encryption"),
+            Self::InvalidJwk => write!(f, "invalid JWK"),
+            Self::MismatchedJwkKeyType => write!(f, "mismatched JWK key type"),
+            Self::MismatchedJwkPublicKey => write!(f, "mismatched JWK public key"),
+            Self::UnsupportedSignatureAlgorithm => write!(f, "unsupported signature algorithm"),
+            Self::InvalidFingerprint => write!(f, "invalid fingerprint"),
+            Self::InvalidJose => write!(f, "invalid JOSE object"),
+            Self::UnsupportedJoseAlgorithm => write!(f, "unsupported JOSE algorithm"),
+            Self::MismatchedJoseAlgorithm => write!(f, "mismatched JOSE algorithm"),
+            Self::InvalidCose => write!(f, "invalid COSE object"),
+            Self::UnsupportedCoseAlgorithm => write!(f, "unsupported COSE algorithm"),
+            Self::MismatchedCoseAlgorithm => write!(f, "mismatched COSE algorithm"),
+            Self::InvalidPaseto => write!(f, "invalid PASETO token"),
+            Self::UnsupportedPasetoVersion => write!(f, "unsupported PASETO version"),
+            Self::MismatchedPasetoPurpose => write!(f, "mismatched PASETO purpose"),
+            Self::InvalidOpenSshKey => write!(f, "invalid OpenSSH key"),
+            Self::UnsupportedOpenSshKey => write!(f, "unsupported OpenSSH key"),
+            Self::MismatchedOpenSshKeyType => write!(f, "mismatched OpenSSH key type"),
+            Self::EncryptedOpenSshKey => write!(f, "OpenSSH key is encrypted"),
+            Self::UnencryptedOpenSshKey => write!(f, "OpenSSH key is not encrypted"),
+            Self::InvalidEncoding => write!(f, "invalid hex or base64 encoding"),
         }
     }
 }
@@ -79,6 +143,7 @@ impl core::fmt::Display for Error {
             Self::DecryptFailed => write!(f, "presented AEAD tag/aad/ciphertext/nonce was wrong"),
             Self::Asn1Error(e) => write!(f, "an ASN.1 encoding/decoding error: {e}"),
             Self::KeyFormatError(e) => write!(f, "a key formatting/validation error: {e}"),
+            Self::ExternalKeyFailed => write!(f, "an external key operation failed"),
         }
     }
 }
@@ -131,6 +196,10 @@ mod tests {
             ),
             "a key formatting/validation error: unsupported PKCS#8 version"
         );
+        assert_eq!(
+            format!("{}", Error::ExternalKeyFailed),
+            "an external key operation failed"
+        );
     }
 
     #[test]
@@ -147,6 +216,10 @@ mod tests {
             format!("{}", KeyFormatError::MismatchedPkcs8Parameters),
             "mismatched PKCS#8 parameters"
         );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedPkcs8PublicKey),
+            "mismatched PKCS#8 public key"
+        );
         assert_eq!(
             format!("{}", KeyFormatError::MismatchedSec1Curve),
             "mismatched SEC1 curve"
@@ -155,5 +228,110 @@ mod tests {
             format!("{}", KeyFormatError::MismatchedSec1PublicKey),
             "mismatched SEC1 public key"
         );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedSpkiAlgorithm),
+            "mismatched SPKI algorithm"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedSpkiParameters),
+            "mismatched SPKI parameters"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::InvalidTinkKeyset),
+            "invalid Tink keyset"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::UnsupportedTinkKeyType),
+            "unsupported Tink key type"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::InvalidPem),
+            "invalid PEM encoding"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedPemLabel),
+            "mismatched PEM label"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::UnsupportedPkcs8Encryption),
+            "unsupported PKCS#8 encryption"
+        );
+        assert_eq!(format!("{}", KeyFormatError::InvalidJwk), "invalid JWK");
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedJwkKeyType),
+            "mismatched JWK key type"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedJwkPublicKey),
+            "mismatched JWK public key"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::UnsupportedSignatureAlgorithm),
+            "unsupported signature algorithm"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::InvalidFingerprint),
+            "invalid fingerprint"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::InvalidJose),
+            "invalid JOSE object"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::UnsupportedJoseAlgorithm),
+            "unsupported JOSE algorithm"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedJoseAlgorithm),
+            "mismatched JOSE algorithm"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::InvalidCose),
+            "invalid COSE object"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::UnsupportedCoseAlgorithm),
+            "unsupported COSE algorithm"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedCoseAlgorithm),
+            "mismatched COSE algorithm"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::InvalidPaseto),
+            "invalid PASETO token"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::UnsupportedPasetoVersion),
+            "unsupported PASETO version"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedPasetoPurpose),
+            "mismatched PASETO purpose"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::InvalidOpenSshKey),
+            "invalid OpenSSH key"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::UnsupportedOpenSshKey),
+            "unsupported OpenSSH key"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::MismatchedOpenSshKeyType),
+            "mismatched OpenSSH key type"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::EncryptedOpenSshKey),
+            "OpenSSH key is encrypted"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::UnencryptedOpenSshKey),
+            "OpenSSH key is not encrypted"
+        );
+        assert_eq!(
+            format!("{}", KeyFormatError::InvalidEncoding),
+            "invalid hex or base64 encoding"
+        );
     }
 }
@@ -0,0 +1,279 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! The X25519 recipient type of [age](https://age-encryption.org/v1).
+//!
+//! An age file is encrypted under a random 16-byte file key, which is
+//! wrapped for each recipient in a "stanza" in the file header.  This
+//! module provides the wrapping and unwrapping of the file key for X25519
+//! recipients, and the encoding of those stanzas.
+//!
+//! The rest of the format (the header MAC, the STREAM payload encryption,
+//! and the Bech32 encoding of recipients and identities) is left to the
+//! caller.
+//!
+//! ```
+//! use graviola::key_agreement::age::*;
+//! use graviola::key_agreement::x25519::StaticPrivateKey;
+//!
+//! let identity = StaticPrivateKey::new_random().unwrap();
+//! let file_key = FileKey::new_random().unwrap();
+//!
+//! let stanza = wrap(&file_key, &identity.public_key()).unwrap();
+//! let text = stanza.encode();
+//! assert!(text.starts_with("-> X25519 "));
+//!
+//! let stanza = Stanza::decode(&text).unwrap();
+//! let unwrapped = unwrap(&stanza, &identity).unwrap();
+//! assert_eq!(unwrapped.as_bytes(), file_key.as_bytes());
+//! ```
+
+use super::base64;
+use super::hash::Sha256;
+use super::hkdf;
+use crate::error::{Error, KeyFormatError};
+use crate::low;
+use crate::mid::chacha20poly1305::ChaCha20Poly1305;
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::x25519::{PrivateKey, PublicKey, SharedSecret, StaticPrivateKey};
+
+/// An age file key.
+pub struct FileKey([u8; 16]);
+
+impl FileKey {
+    /// Generate a new random file key.
+    pub fn new_random() -> Result<Self, Error> {
+        Self::generate(&mut SystemRandom)
+    }
+
+    /// Generate a new file key using `rng`.
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let mut key = [0u8; 16];
+        rng.fill(&mut key)?;
+        Ok(Self(key))
+    }
+
+    /// Use `key` as a file key.
+    pub fn from_array(key: &[u8; 16]) -> Self {
+        Self(*key)
+    }
+
+    /// Extract the bytes of this file key.
+    ///
+    /// The caller uses this to derive the header MAC and payload keys.
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+impl Drop for FileKey {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.0);
+    }
+}
+
+/// An X25519 recipient stanza.
+pub struct Stanza {
+    /// The ephemeral share, which is the stanza's only argument.
+    pub ephemeral_share: PublicKey,
+
+    /// The body: the wrapped file key and its tag.
+    pub body: [u8; 32],
+}
+
+impl Stanza {
+    /// Encode this stanza, as in the age header.
+    ///
+    /// This is two lines, each ending in `\n`.
+    pub fn encode(&self) -> String {
+        format!(
+            "-> X25519 {}\n{}\n",
+            base64::encode_unpadded(&self.ephemeral_share.as_bytes()),
+            base64::encode_unpadded(&self.body)
+        )
+    }
+
+    /// Decode a stanza from `text`.
+    ///
+    /// `text` must be exactly one X25519 stanza, as produced by
+    /// [`Stanza::encode()`]: the age encoding rules allow only that
+    /// encoding.  Stanzas of other types should be identified by the
+    /// caller from their first line, and not passed here.
+    pub fn decode(text: &str) -> Result<Self, Error> {
+        let invalid = || Error::from(KeyFormatError::InvalidEncoding);
+
+        let (share, body) = text
+            .strip_prefix("-> X25519 ")
+            .and_then(|rest| rest.strip_suffix('\n'))
+            .and_then(|rest| rest.split_once('\n'))
+            .ok_or_else(invalid)?;
+
+        let share = base64::decode_unpadded(share)
+            .and_then(|share| <[u8; 32]>::try_from(share).ok())
+            .ok_or_else(invalid)?;
+        let body = base64::decode_unpadded(body)
+            .and_then(|body| <[u8; 32]>::try_from(body).ok())
+            .ok_or_else(invalid)?;
+
+        Ok(Self {
+            ephemeral_share: PublicKey::from_array(&share),
+            body,
+        })
+    }
+}
+
+/// Wrap `file_key` for `recipient`, using a new random ephemeral key.
+pub fn wrap(file_key: &FileKey, recipient: &PublicKey) -> Result<Stanza, Error> {
+    let ephemeral = PrivateKey::new_random()?;
+    let ephemeral_share = ephemeral.public_key();
+    let shared_secret = ephemeral.diffie_hellman(recipient)?;
+    wrap_inner(file_key, recipient, ephemeral_share, &shared_secret)
+}
+
+/// Unwrap the file key in `stanza`, with `identity`.
+///
+/// Fails with [`Error::DecryptFailed`] if the stanza was not for
+/// `identity` (in which case the caller should try the next stanza),
+/// or [`Error::NotOnCurve`] if the ephemeral share is a low order point.
+pub fn unwrap(stanza: &Stanza, identity: &StaticPrivateKey) -> Result<FileKey, Error> {
+    let shared_secret = identity.diffie_hellman(&stanza.ephemeral_share)?;
+    let aead = wrap_key(
+        &stanza.ephemeral_share,
+        &identity.public_key(),
+        &shared_secret,
+    )?;
+
+    let mut file_key = FileKey([0u8; 16]);
+    file_key.0.copy_from_slice(&stanza.body[..16]);
+    aead.decrypt(&[0; 12], &[], &mut file_key.0, &stanza.body[16..])?;
+    Ok(file_key)
+}
+
+fn wrap_inner(
+    file_key: &FileKey,
+    recipient: &PublicKey,
+    ephemeral_share: PublicKey,
+    shared_secret: &SharedSecret,
+) -> Result<Stanza, Error> {
+    let aead = wrap_key(&ephemeral_share, recipient, shared_secret)?;
+
+    let mut body = [0u8; 32];
+    let (wrapped, tag) = body.split_at_mut(16);
+    wrapped.copy_from_slice(&file_key.0);
+    aead.encrypt(&[0; 12], &[], wrapped, tag.try_into().unwrap());
+
+    Ok(Stanza {
+        ephemeral_share,
+        body,
+    })
+}
+
+/// The wrap key is HKDF-SHA256 of the shared secret, salted with both shares.
+fn wrap_key(
+    ephemeral_share: &PublicKey,
+    recipient: &PublicKey,
+    shared_secret: &SharedSecret,
+) -> Result<ChaCha20Poly1305, Error> {
+    let mut wrap_key = [0u8; 32];
+    let salt = [ephemeral_share.as_bytes(), recipient.as_bytes()].concat();
+    let result = hkdf::hkdf::<Sha256>(
+        &salt,
+        &shared_secret.0,
+        b"age-encryption.org/v1/X25519",
+        &mut wrap_key,
+    )
+    .map(|()| ChaCha20Poly1305::new(wrap_key));
+    low::zeroise(&mut wrap_key);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity() -> StaticPrivateKey {
+        StaticPrivateKey::from_array(&[0x42; 32])
+    }
+
+    #[test]
+    fn known_answer() {
+        let identity = identity();
+        let ephemeral = StaticPrivateKey::from_array(&[0x43; 32]);
+        let recipient = identity.public_key();
+        let file_key = FileKey::from_array(&core::array::from_fn(|i| i as u8 + 1));
+
+        let stanza = wrap_inner(
+            &file_key,
+            &recipient,
+            ephemeral.public_key(),
+            &ephemeral.diffie_hellman(&recipient).unwrap(),
+        )
+        .unwrap();
+        let text = "-> X25519 ze/YeDqRtEZkDi4flVmds15ISgBxvSGCs7YNCBLBDHA\n\
+                    H9cA9bBK7UV+E4he6P39fi0+Lq9P5nY7Qmb544K75f8\n";
+        assert_eq!(stanza.encode(), text);
+
+        let stanza = Stanza::decode(text).unwrap();
+        let unwrapped = unwrap(&stanza, &identity).unwrap();
+        assert_eq!(unwrapped.as_bytes(), file_key.as_bytes());
+    }
+
+    #[test]
+    fn round_trip() {
+        let identity = identity();
+        let file_key = FileKey::new_random().unwrap();
+        let stanza = wrap(&file_key, &identity.public_key()).unwrap();
+        let stanza = Stanza::decode(&stanza.encode()).unwrap();
+        assert_eq!(
+            unwrap(&stanza, &identity).unwrap().as_bytes(),
+            file_key.as_bytes()
+        );
+    }
+
+    #[test]
+    fn wrong_identity() {
+        let file_key = FileKey::new_random().unwrap();
+        let stanza = wrap(&file_key, &identity().public_key()).unwrap();
+        let other = StaticPrivateKey::new_random().unwrap();
+        assert_eq!(unwrap(&stanza, &other).err(), Some(Error::DecryptFailed));
+
+        let mut stanza = stanza;
+        stanza.body[31] ^= 1;
+        assert_eq!(
+            unwrap(&stanza, &identity()).err(),
+            Some(Error::DecryptFailed)
+        );
+    }
+
+    #[test]
+    fn low_order_share() {
+        let stanza = Stanza {
+            ephemeral_share: PublicKey::from_array(&[0; 32]),
+            body: [0; 32],
+        };
+        assert_eq!(unwrap(&stanza, &identity()).err(), Some(Error::NotOnCurve));
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        let share = "ze/YeDqRtEZkDi4flVmds15ISgBxvSGCs7YNCBLBDHA";
+        let body = "H9cA9bBK7UV+E4he6P39fi0+Lq9P5nY7Qmb544K75f8";
+        assert!(Stanza::decode(&format!("-> X25519 {share}\n{body}\n")).is_ok());
+
+        for text in [
+            format!("-> X25519 {share}\n{body}"),
+            format!("-> X25519 {share}=\n{body}\n"),
+            format!("-> X25519 {share}\n{body}=\n"),
+            format!("-> X25519 {share} extra\n{body}\n"),
+            format!("-> X25519  {share}\n{body}\n"),
+            format!("-> scrypt {share}\n{body}\n"),
+            format!("-> X25519 {share}\n{body}\n\n"),
+            format!("-> X25519 {share}\n\n"),
+            format!("-> X25519 {}\n{body}\n", &share[1..]),
+            format!("-> X25519 {share}\n{}9\n", &body[..42]),
+            format!("-> X25519 {}\n{body}\n", share.replace('/', "_")),
+        ] {
+            assert!(Stanza::decode(&text).is_err(), "{text:?}");
+        }
+    }
+}
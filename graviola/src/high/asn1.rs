@@ -7,25 +7,31 @@ use core::mem;
 use core::mem::size_of;
 
 macro_rules! _asn1_struct_ty(
+    ([$context:literal] IMPLICIT BIT STRING) => { $crate::high::asn1::ContextBitString<'a, $context> };
     ([$context:literal] $($itty:ident)+) => { $crate::high::asn1::ContextConstructed<'a, $context, $crate::high::asn1::_asn1_struct_ty!($($itty)+)> };
     (INTEGER) => { $crate::high::asn1::Integer<'a> };
     (OBJECT IDENTIFIER) => { $crate::high::asn1::ObjectId };
     (ANY OPTIONAL) => { Option<$crate::high::asn1::Any<'a>> };
+    (INTEGER OPTIONAL) => { Option<$crate::high::asn1::Integer<'a>> };
     (OCTET STRING) => { $crate::high::asn1::OctetString<'a> };
     (BIT STRING) => { $crate::high::asn1::BitString<'a> };
     ($ty:tt REF) => { $ty<'a> };
+    ($ty:tt REF OPTIONAL) => { Option<$ty<'a>> };
     ($ty:tt) => { $ty };
 );
 pub(crate) use _asn1_struct_ty;
 
 macro_rules! _asn1_struct_parse_ty(
+    ($p:ident, [$context:literal] IMPLICIT BIT STRING) => { $crate::high::asn1::ContextBitString::parse(&mut $p)? };
     ($p:ident, [$context:literal] $($itty:ident)+) => { $crate::high::asn1::ContextConstructed::parse(&mut $p)? };
     ($p:ident, INTEGER) => { $crate::high::asn1::Integer::parse(&mut $p)? };
     ($p:ident, OBJECT IDENTIFIER) => { $crate::high::asn1::ObjectId::parse(&mut $p)? };
     ($p:ident, ANY OPTIONAL) => { Option::<$crate::high::asn1::Any<'_>>::parse(&mut $p)? };
+    ($p:ident, INTEGER OPTIONAL) => { Option::<$crate::high::asn1::Integer<'_>>::parse(&mut $p)? };
     ($p:ident, OCTET STRING) => { $crate::high::asn1::OctetString::parse(&mut $p)? };
     ($p:ident, BIT STRING) => { $crate::high::asn1::BitString::parse(&mut $p)? };
     ($p:ident, $ty:tt REF) => { $ty::parse(&mut $p)? };
+    ($p:ident, $ty:tt REF OPTIONAL) => { Option::<$ty<'_>>::parse(&mut $p)? };
     ($p:ident, $ty:tt) => { $ty::parse(&mut $p)? };
 );
 pub(crate) use _asn1_struct_parse_ty;
@@ -58,6 +64,10 @@ macro_rules! asn1_struct {
                 Ok(r)
             }
 
+            fn acceptable_tag(tag: u8) -> bool {
+                tag == $crate::high::asn1::Tag::SEQUENCE
+            }
+
             fn encoded_len(&self) -> usize {
                 $crate::high::asn1::encoded_length_for(self.body_len())
             }
@@ -122,11 +132,27 @@ macro_rules! asn1_oid {
 }
 pub(crate) use asn1_oid;
 
-pub(crate) trait Type<'a>: Debug + Sized {
+/// An ASN.1 type with a DER encoding.
+pub trait Type<'a>: Debug + Sized {
+    /// Parse one value from the front of `p`.
     fn parse(p: &mut Parser<'a>) -> Result<Self, Error>;
+
+    /// Encode this value into `encoder`.
+    ///
+    /// Returns the number of bytes `encoder` has written in total.
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<usize, Error>;
+
+    /// The length of this value's encoding, including its tag and length.
     fn encoded_len(&self) -> usize;
 
+    /// Whether an encoding starting with `tag` could be this type.
+    ///
+    /// This allows `OPTIONAL` fields before the end of a `SEQUENCE`.
+    fn acceptable_tag(_tag: u8) -> bool {
+        true
+    }
+
+    /// Parse a value, which must be the whole of `b`.
     fn from_bytes(b: &'a [u8]) -> Result<Self, Error> {
         let mut p = Parser::new(b);
         let t = Self::parse(&mut p)?;
@@ -135,13 +161,21 @@ pub(crate) trait Type<'a>: Debug + Sized {
     }
 }
 
+/// Any one of the supported types, distinguished by its tag.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) enum Any<'a> {
+pub enum Any<'a> {
+    /// A `NULL`.
     Null(Null),
+    /// An `INTEGER`.
     Integer(Integer<'a>),
+    /// An `OCTET STRING`.
     OctetString(OctetString<'a>),
+    /// A `BIT STRING`.
     BitString(BitString<'a>),
+    /// An `OBJECT IDENTIFIER`.
     ObjectId(ObjectId),
+    /// A `SEQUENCE`.
+    Sequence(Sequence<'a>),
 }
 
 impl<'a> Type<'a> for Any<'a> {
@@ -152,6 +186,7 @@ impl<'a> Type<'a> for Any<'a> {
             Tag::OCTET_STRING => Ok(Self::OctetString(OctetString::parse(p)?)),
             Tag::BIT_STRING => Ok(Self::BitString(BitString::parse(p)?)),
             Tag::OBJECT_ID => Ok(Self::ObjectId(ObjectId::parse(p)?)),
+            Tag::SEQUENCE => Ok(Self::Sequence(Sequence::parse(p)?)),
             _ => Err(Error::UnexpectedTag),
         }
     }
@@ -163,6 +198,7 @@ impl<'a> Type<'a> for Any<'a> {
             Self::BitString(bs) => bs.encode(encoder),
             Self::OctetString(os) => os.encode(encoder),
             Self::ObjectId(obj) => obj.encode(encoder),
+            Self::Sequence(seq) => seq.encode(encoder),
         }
     }
 
@@ -173,13 +209,14 @@ impl<'a> Type<'a> for Any<'a> {
             Self::BitString(bs) => bs.encoded_len(),
             Self::OctetString(os) => os.encoded_len(),
             Self::ObjectId(obj) => obj.encoded_len(),
+            Self::Sequence(seq) => seq.encoded_len(),
         }
     }
 }
 
 impl<'a, T: Type<'a>> Type<'a> for Option<T> {
     fn parse(p: &mut Parser<'a>) -> Result<Self, Error> {
-        if p.left() > 0 {
+        if p.left() > 0 && T::acceptable_tag(p.peek_tag()?.0) {
             T::parse(p).map(Some)
         } else {
             Ok(None)
@@ -255,8 +292,62 @@ impl<'a, const ID: u8, T: Type<'a>> From<T> for ContextConstructed<'a, ID, T> {
     }
 }
 
+/// An optional `[ID] IMPLICIT BIT STRING`.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct Null;
+pub(crate) struct ContextBitString<'a, const ID: u8>(Option<BitString<'a>>);
+
+impl<'a, const ID: u8> ContextBitString<'a, ID> {
+    pub(crate) fn absent() -> Self {
+        Self(None)
+    }
+
+    pub(crate) fn inner(&self) -> &Option<BitString<'a>> {
+        &self.0
+    }
+}
+
+impl<'a, const ID: u8> Type<'a> for ContextBitString<'a, ID> {
+    fn parse(p: &mut Parser<'a>) -> Result<Self, Error> {
+        let tag = Tag::context_primitive(ID);
+
+        match p.peek_tag() {
+            Ok(tt) if tag.acceptable(tt.0) => {}
+            _ => return Ok(Self(None)),
+        };
+
+        let (_, body) = p.take(tag)?;
+        BitString::from_body(body).map(|bs| Self(Some(bs)))
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<usize, Error> {
+        let item = match &self.0 {
+            None => return Ok(0),
+            Some(a) => a,
+        };
+
+        let mut body = encoder.begin(Tag::context_primitive(ID), item.octets.len() + 1)?;
+        body.push(0)?;
+        body.append_slice(item.octets)?;
+        Ok(body.finish())
+    }
+
+    fn encoded_len(&self) -> usize {
+        match &self.0 {
+            None => 0,
+            Some(a) => a.encoded_len(),
+        }
+    }
+}
+
+impl<'a, const ID: u8> From<BitString<'a>> for ContextBitString<'a, ID> {
+    fn from(bs: BitString<'a>) -> Self {
+        Self(Some(bs))
+    }
+}
+
+/// An ASN.1 `NULL`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Null;
 
 impl Type<'_> for Null {
     fn parse(p: &mut Parser<'_>) -> Result<Self, Error> {
@@ -276,6 +367,9 @@ impl Type<'_> for Null {
     }
 }
 
+/// An ASN.1 `OBJECT IDENTIFIER`.
+///
+/// [`AsRef<[u8]>`](AsRef) gives the encoded body, without its tag and length.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ObjectId {
     buf: [u8; Self::MAX_LEN],
@@ -364,8 +458,12 @@ impl AsRef<[u8]> for ObjectId {
     }
 }
 
+/// An ASN.1 `INTEGER`, of any size.
+///
+/// [`AsRef<[u8]>`](AsRef) gives the minimal big-endian two's complement
+/// encoding of the value.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct Integer<'a> {
+pub struct Integer<'a> {
     twos_complement: &'a [u8],
 }
 
@@ -381,7 +479,7 @@ impl<'a> Integer<'a> {
     ///
     /// Otherwise, a positive number is returned, with any
     /// excess leading 0x00 bytes stripped.
-    pub(crate) fn new(mut bytes: &'a [u8]) -> Self {
+    pub fn new(mut bytes: &'a [u8]) -> Self {
         static ZERO: &[u8] = &[0];
 
         if bytes.is_empty() || bytes.iter().all(|b| *b == 0x00) {
@@ -419,7 +517,7 @@ impl<'a> Integer<'a> {
     /// The number of leading zeros is deemed a public property.
     ///
     /// `buffer` must be at least 1 octet larger than `magnitude`.
-    pub(crate) fn new_positive(buffer: &'a mut [u8], mut value: &'_ [u8]) -> Self {
+    pub fn new_positive(buffer: &'a mut [u8], mut value: &'_ [u8]) -> Self {
         // strip leading zero bytes
         while !value.is_empty() && value[0] == 0x00 {
             value = &value[1..];
@@ -439,22 +537,25 @@ impl<'a> Integer<'a> {
         }
     }
 
-    pub(crate) fn is_negative(&self) -> bool {
+    /// Whether this integer is less than zero.
+    pub fn is_negative(&self) -> bool {
         self.twos_complement
             .first()
             .map(|b| b & 0x80 == 0x80)
             .unwrap_or_default()
     }
 
-    pub(crate) fn as_usize(&self) -> Result<usize, Error> {
+    /// Returns this integer as a `usize`.
+    ///
+    /// Negative or too-large values give [`Error::IntegerOutOfRange`].
+    pub fn as_usize(&self) -> Result<usize, Error> {
         if self.is_negative() || self.twos_complement.len() > size_of::<usize>() {
             return Err(Error::IntegerOutOfRange);
         }
 
-        let mut bytes = [0u8; 8];
-        for (i, b) in self.twos_complement.iter().enumerate() {
-            bytes[bytes.len() - 1 - i] = *b;
-        }
+        let mut bytes = [0u8; size_of::<usize>()];
+        bytes[size_of::<usize>() - self.twos_complement.len()..]
+            .copy_from_slice(self.twos_complement);
         Ok(usize::from_be_bytes(bytes))
     }
 
@@ -496,6 +597,10 @@ impl<'a> Type<'a> for Integer<'a> {
         Self::minimum_magnitude_check(twos_complement)
     }
 
+    fn acceptable_tag(tag: u8) -> bool {
+        tag == Tag::INTEGER
+    }
+
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<usize, Error> {
         let mut body = encoder.begin(Tag::integer(), self.twos_complement.len())?;
         body.append_slice(self.twos_complement)?;
@@ -507,17 +612,20 @@ impl<'a> Type<'a> for Integer<'a> {
     }
 }
 
+/// An ASN.1 `OCTET STRING`.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct OctetString<'a> {
+pub struct OctetString<'a> {
     octets: &'a [u8],
 }
 
 impl<'a> OctetString<'a> {
-    pub(crate) fn new(octets: &'a [u8]) -> Self {
+    /// Makes an `OCTET STRING` containing `octets`.
+    pub fn new(octets: &'a [u8]) -> Self {
         Self { octets }
     }
 
-    pub(crate) fn into_octets(self) -> &'a [u8] {
+    /// Returns the contents.
+    pub fn into_octets(self) -> &'a [u8] {
         self.octets
     }
 }
@@ -539,25 +647,28 @@ impl<'a> Type<'a> for OctetString<'a> {
     }
 }
 
+/// An ASN.1 `BIT STRING`.
+///
+/// Only bit strings of whole octets are supported.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct BitString<'a> {
+pub struct BitString<'a> {
     octets: &'a [u8],
 }
 
 impl<'a> BitString<'a> {
-    pub(crate) fn new(octets: &'a [u8]) -> Self {
+    /// Makes a `BIT STRING` containing `octets`.
+    pub fn new(octets: &'a [u8]) -> Self {
         Self { octets }
     }
 
-    pub(crate) fn as_octets(&self) -> &'a [u8] {
+    /// Returns the contents.
+    pub fn as_octets(&self) -> &'a [u8] {
         self.octets
     }
-}
 
-impl<'a> Type<'a> for BitString<'a> {
-    fn parse(p: &mut Parser<'a>) -> Result<Self, Error> {
-        let (_, octets) = p.take(Tag::bit_string())?;
-        let octets = match octets.split_first() {
+    /// Only whole-octet bit strings are supported.
+    fn from_body(body: &'a [u8]) -> Result<Self, Error> {
+        let octets = match body.split_first() {
             None => return Err(Error::UnexpectedEof),
             Some((&0, rest)) => rest,
             Some((_, _)) => {
@@ -566,6 +677,13 @@ impl<'a> Type<'a> for BitString<'a> {
         };
         Ok(Self { octets })
     }
+}
+
+impl<'a> Type<'a> for BitString<'a> {
+    fn parse(p: &mut Parser<'a>) -> Result<Self, Error> {
+        let (_, body) = p.take(Tag::bit_string())?;
+        Self::from_body(body)
+    }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<usize, Error> {
         let mut body = encoder.begin(Tag::bit_string(), self.octets.len() + 1)?;
@@ -579,12 +697,67 @@ impl<'a> Type<'a> for BitString<'a> {
     }
 }
 
-pub(crate) struct Parser<'a> {
+/// A `SEQUENCE` that is not interpreted until needed.
+///
+/// This holds the whole encoding, including the tag and length.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sequence<'a> {
+    encoding: &'a [u8],
+}
+
+impl<'a> Sequence<'a> {
+    /// `encoding` must be a whole, valid, `SEQUENCE` encoding.
+    pub(crate) fn new(encoding: &'a [u8]) -> Self {
+        Self { encoding }
+    }
+
+    /// Decode the sequence as `T`.
+    pub fn decode<T: Type<'a>>(&self) -> Result<T, Error> {
+        T::from_bytes(self.encoding)
+    }
+
+    /// Returns the whole encoding, including the tag and length.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.encoding
+    }
+
+    /// Returns a parser over the items in the sequence.
+    pub fn items(&self) -> Result<Parser<'a>, Error> {
+        let (_, items) = Parser::new(self.encoding).descend(Tag::sequence())?;
+        Ok(items)
+    }
+}
+
+impl<'a> Type<'a> for Sequence<'a> {
+    fn parse(p: &mut Parser<'a>) -> Result<Self, Error> {
+        let start = p.input;
+        p.take(Tag::sequence())?;
+        Ok(Self {
+            encoding: &start[..start.len() - p.left()],
+        })
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<usize, Error> {
+        encoder.append_slice(self.encoding)?;
+        Ok(encoder.finish())
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.encoding.len()
+    }
+}
+
+/// A strict DER parser.
+///
+/// Values are parsed from the front with [`Type::parse()`], and borrow from
+/// the input.
+pub struct Parser<'a> {
     input: &'a [u8],
 }
 
 impl<'a, 's> Parser<'a> {
-    pub(crate) fn new(buf: &'a [u8]) -> Self {
+    /// Makes a parser over `buf`.
+    pub fn new(buf: &'a [u8]) -> Self {
         Self { input: buf }
     }
 
@@ -618,7 +791,8 @@ impl<'a, 's> Parser<'a> {
         self.input.len()
     }
 
-    fn check_end(self) -> Result<(), Error> {
+    /// Checks that the whole input has been parsed.
+    pub fn check_end(self) -> Result<(), Error> {
         if self.left() != 0 {
             Err(Error::UnexpectedTrailingData)
         } else {
@@ -647,7 +821,7 @@ impl<'a, 's> Parser<'a> {
             }
             0x82 => {
                 let len = ((self.one_byte()? as usize) << 8) | self.one_byte()? as usize;
-                if len < 0xff {
+                if len < 0x100 {
                     return Err(Error::NonCanonicalEncoding);
                 }
                 Ok(len)
@@ -657,13 +831,18 @@ impl<'a, 's> Parser<'a> {
     }
 }
 
-pub(crate) struct Encoder<'a> {
+/// A DER encoder, writing to a fixed-size buffer.
+///
+/// Values are encoded with [`Type::encode()`].  A too-short buffer gives
+/// [`Error::UnexpectedEof`].
+pub struct Encoder<'a> {
     out: &'a mut [u8],
     written: usize,
 }
 
 impl<'a, 's> Encoder<'a> {
-    pub(crate) fn new(out: &'a mut [u8]) -> Self {
+    /// Makes an encoder that writes to the start of `out`.
+    pub fn new(out: &'a mut [u8]) -> Self {
         Encoder { out, written: 0 }
     }
 
@@ -741,17 +920,29 @@ fn encoded_length_for(len: usize) -> usize {
     }
 }
 
+/// Errors from encoding or decoding DER.
+#[non_exhaustive]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Error {
+    /// A value had a different tag than expected.
     UnexpectedTag,
+    /// The input was truncated, or the output buffer was too short.
     UnexpectedEof,
+    /// There were more bytes after the expected values.
     UnexpectedTrailingData,
+    /// A length was not minimally encoded.
     NonCanonicalEncoding,
+    /// An enumerated `INTEGER` had an unknown value.
     UnhandledEnumValue,
+    /// An `INTEGER` was not minimally encoded, or was out of range.
     IntegerOutOfRange,
+    /// A `NULL` had contents.
     IllegalNull,
+    /// An `OBJECT IDENTIFIER` was longer than supported.
     UnsupportedLargeObjectId,
+    /// A length was larger than 65535 bytes.
     UnsupportedLargeObjectLength,
+    /// A `BIT STRING` did not contain whole octets.
     UnhandledBitString,
 }
 
@@ -783,7 +974,7 @@ impl Tag {
     }
 
     fn sequence() -> Self {
-        Self(0x30)
+        Self(Self::SEQUENCE)
     }
 
     fn integer() -> Self {
@@ -810,11 +1001,16 @@ impl Tag {
         Self(Self::CONTEXT_SPECIFIC | Self::CONSTRUCTED | id)
     }
 
+    fn context_primitive(id: u8) -> Self {
+        Self(Self::CONTEXT_SPECIFIC | id)
+    }
+
     const INTEGER: u8 = 0x02;
     const BIT_STRING: u8 = 0x03;
     const OCTET_STRING: u8 = 0x04;
     const NULL: u8 = 0x05;
     const OBJECT_ID: u8 = 0x06;
+    const SEQUENCE: u8 = 0x30;
 
     const CONSTRUCTED: u8 = 0x20;
     const CONTEXT_SPECIFIC: u8 = 0x80;
@@ -870,6 +1066,31 @@ mod tests {
         assert_eq!(&buf, encoding);
     }
 
+    #[test]
+    fn test_integer_as_usize() {
+        assert_eq!(Integer::new(&[0x00]).as_usize(), Ok(0));
+        assert_eq!(Integer::new(&[0x03, 0xe8]).as_usize(), Ok(1000));
+        assert_eq!(Integer::new(&[0x00, 0x80]).as_usize(), Ok(0x80));
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            Integer::new(&[0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]).as_usize(),
+            Ok(0x7fff_ffff_ffff_ffff)
+        );
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(
+            Integer::new(&[0x7f, 0xff, 0xff, 0xff]).as_usize(),
+            Ok(0x7fff_ffff)
+        );
+        assert_eq!(
+            Integer::new(&[0xff]).as_usize(),
+            Err(Error::IntegerOutOfRange)
+        );
+        assert_eq!(
+            Integer::new(&[0x01, 0, 0, 0, 0, 0, 0, 0, 0]).as_usize(),
+            Err(Error::IntegerOutOfRange)
+        );
+    }
+
     #[test]
     fn test_invalid_integer_encodings() {
         // empty
@@ -983,13 +1204,100 @@ mod tests {
             Any::ObjectId(ObjectId::from_path(&[39])),
         );
 
-        assert_eq!(Any::from_bytes(&[0x30]).unwrap_err(), Error::UnexpectedTag);
+        test_round_trip(
+            &[0x30, 0x03, 0x02, 0x01, 0x05],
+            Any::Sequence(Sequence::new(&[0x30, 0x03, 0x02, 0x01, 0x05])),
+        );
+
+        assert_eq!(Any::from_bytes(&[0x31]).unwrap_err(), Error::UnexpectedTag);
+        assert_eq!(Any::from_bytes(&[0x30]).unwrap_err(), Error::UnexpectedEof);
+        assert_eq!(
+            Any::from_bytes(&[0x30, 0x03, 0x02, 0x01]).unwrap_err(),
+            Error::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn test_sequence() {
+        let seq = Sequence::from_bytes(&[0x30, 0x03, 0x02, 0x01, 0x05]).unwrap();
+        assert_eq!(
+            seq.decode::<pkix::EcdsaSigValue<'_>>().unwrap_err(),
+            Error::UnexpectedEof
+        );
+
+        let seq = Sequence::from_bytes(&[0x30, 0x06, 0x02, 0x01, 0x05, 0x02, 0x01, 0x06]).unwrap();
+        let sig = seq.decode::<pkix::EcdsaSigValue<'_>>().unwrap();
+        assert_eq!(sig.r, Integer::new(&[5]));
+        assert_eq!(sig.s, Integer::new(&[6]));
+    }
+
+    #[test]
+    fn test_sequence_items() {
+        let seq = Sequence::from_bytes(&[0x30, 0x05, 0x02, 0x01, 0x05, 0x05, 0x00]).unwrap();
+        let mut items = seq.items().unwrap();
+        assert_eq!(Integer::parse(&mut items).unwrap(), Integer::new(&[5]));
+        assert_eq!(Any::parse(&mut items).unwrap(), Any::Null(Null));
+        items.check_end().unwrap();
+
+        let mut items = seq.items().unwrap();
+        Integer::parse(&mut items).unwrap();
+        assert_eq!(items.check_end(), Err(Error::UnexpectedTrailingData));
+    }
+
+    #[test]
+    fn test_lengths() {
+        let mut long = vec![0x04, 0x81, 0x80];
+        long.extend_from_slice(&[0u8; 0x80]);
+        assert_eq!(
+            OctetString::from_bytes(&long).unwrap().into_octets().len(),
+            0x80
+        );
+
+        let mut longer = vec![0x04, 0x82, 0x01, 0x00];
+        longer.extend_from_slice(&[0u8; 0x100]);
+        assert_eq!(
+            OctetString::from_bytes(&longer)
+                .unwrap()
+                .into_octets()
+                .len(),
+            0x100
+        );
+
+        // lengths must use the fewest bytes
+        let mut short = vec![0x04, 0x81, 0x7f];
+        short.extend_from_slice(&[0u8; 0x7f]);
+        assert_eq!(
+            OctetString::from_bytes(&short),
+            Err(Error::NonCanonicalEncoding)
+        );
+
+        let mut short = vec![0x04, 0x82, 0x00, 0xff];
+        short.extend_from_slice(&[0u8; 0xff]);
+        assert_eq!(
+            OctetString::from_bytes(&short),
+            Err(Error::NonCanonicalEncoding)
+        );
+
+        assert_eq!(
+            OctetString::from_bytes(&[0x04, 0x80]),
+            Err(Error::UnsupportedLargeObjectLength)
+        );
+        assert_eq!(
+            OctetString::from_bytes(&[0x04, 0x83, 0x01, 0x00, 0x00]),
+            Err(Error::UnsupportedLargeObjectLength)
+        );
     }
 
     #[test]
     fn test_optional() {
         test_round_trip(&[0x06, 0x01, 0x27], Some(ObjectId::from_path(&[39])));
         test_round_trip(&[], None::<ObjectId>);
+
+        // an optional field is absent if the tag does not match
+        let mut p = Parser::new(&[0x04, 0x00]);
+        assert_eq!(Option::<Integer<'_>>::parse(&mut p).unwrap(), None);
+        assert_eq!(OctetString::parse(&mut p).unwrap(), OctetString::new(&[]));
+        p.check_end().unwrap();
     }
 
     /// Verify that `value.encode` yields `encoding`, and that decoding
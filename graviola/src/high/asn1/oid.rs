@@ -3,6 +3,7 @@
 
 #![allow(non_upper_case_globals, dead_code)]
 
+#[allow(unused_imports)]
 use super::asn1_oid;
 
 asn1_oid! {
@@ -23,6 +24,24 @@ asn1_oid! {
     }
 }
 
+asn1_oid! {
+    id_X25519 OBJECT IDENTIFIER ::= {
+        iso(1) identified_organization(3) thawte(101) 110
+    }
+}
+
+asn1_oid! {
+    id_Ed25519 OBJECT IDENTIFIER ::= {
+        iso(1) identified_organization(3) thawte(101) 112
+    }
+}
+
+asn1_oid! {
+    id_dsa OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) x9_57(10040) x9algorithm(4) 1
+    }
+}
+
 asn1_oid! {
     rsaEncryption OBJECT IDENTIFIER ::= {
         iso(1) member_body(2)
@@ -30,6 +49,62 @@ asn1_oid! {
     }
 }
 
+asn1_oid! {
+    id_mgf1 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2)
+        us(840) rsadsi(113549) pkcs(1) 1 id_mgf1(8)
+    }
+}
+
+asn1_oid! {
+    id_RSASSA_PSS OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2)
+        us(840) rsadsi(113549) pkcs(1) 1 id_RSASSA_PSS(10)
+    }
+}
+
+asn1_oid! {
+    sha256WithRSAEncryption OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2)
+        us(840) rsadsi(113549) pkcs(1) 1 sha256WithRSAEncryption(11)
+    }
+}
+
+asn1_oid! {
+    sha384WithRSAEncryption OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2)
+        us(840) rsadsi(113549) pkcs(1) 1 sha384WithRSAEncryption(12)
+    }
+}
+
+asn1_oid! {
+    sha512WithRSAEncryption OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2)
+        us(840) rsadsi(113549) pkcs(1) 1 sha512WithRSAEncryption(13)
+    }
+}
+
+asn1_oid! {
+    ecdsa_with_SHA256 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) ansi_X9_62(10045) signatures(4)
+        ecdsa_with_SHA2(3) 2
+    }
+}
+
+asn1_oid! {
+    ecdsa_with_SHA384 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) ansi_X9_62(10045) signatures(4)
+        ecdsa_with_SHA2(3) 3
+    }
+}
+
+asn1_oid! {
+    ecdsa_with_SHA512 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) ansi_X9_62(10045) signatures(4)
+        ecdsa_with_SHA2(3) 4
+    }
+}
+
 asn1_oid! {
     id_sha256 OBJECT IDENTIFIER ::= {
         joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
@@ -50,3 +125,187 @@ asn1_oid! {
         csor(3) nistalgorithm(4) hashalgs(2) 3
     }
 }
+
+asn1_oid! {
+    id_ml_dsa_44 OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 17
+    }
+}
+
+asn1_oid! {
+    id_ml_dsa_65 OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 18
+    }
+}
+
+asn1_oid! {
+    id_ml_dsa_87 OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 19
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_sha2_128s OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 20
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_sha2_128f OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 21
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_sha2_192s OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 22
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_sha2_192f OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 23
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_sha2_256s OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 24
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_sha2_256f OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 25
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_shake_128s OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 26
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_shake_128f OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 27
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_shake_192s OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 28
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_shake_192f OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 29
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_shake_256s OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 30
+    }
+}
+
+asn1_oid! {
+    id_slh_dsa_shake_256f OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) sigAlgs(3) 31
+    }
+}
+
+asn1_oid! {
+    id_ml_kem_512 OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) kems(4) 1
+    }
+}
+
+asn1_oid! {
+    id_ml_kem_768 OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) kems(4) 2
+    }
+}
+
+asn1_oid! {
+    id_ml_kem_1024 OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) kems(4) 3
+    }
+}
+
+asn1_oid! {
+    id_PBES2 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) rsadsi(113549) pkcs(1) pkcs_5(5) 13
+    }
+}
+
+asn1_oid! {
+    id_PBKDF2 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) rsadsi(113549) pkcs(1) pkcs_5(5) 12
+    }
+}
+
+asn1_oid! {
+    id_hmacWithSHA256 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) rsadsi(113549) digestAlgorithm(2) 9
+    }
+}
+
+asn1_oid! {
+    id_hmacWithSHA384 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) rsadsi(113549) digestAlgorithm(2) 10
+    }
+}
+
+asn1_oid! {
+    id_hmacWithSHA512 OBJECT IDENTIFIER ::= {
+        iso(1) member_body(2) us(840) rsadsi(113549) digestAlgorithm(2) 11
+    }
+}
+
+asn1_oid! {
+    id_aes128_CBC OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) aes(1) 2
+    }
+}
+
+asn1_oid! {
+    id_aes128_GCM OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) aes(1) 6
+    }
+}
+
+asn1_oid! {
+    id_aes256_CBC OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) aes(1) 42
+    }
+}
+
+asn1_oid! {
+    id_aes256_GCM OBJECT IDENTIFIER ::= {
+        joint_iso_itu_t(2) country(16) us(840) organization(1) gov(101)
+        csor(3) nistalgorithm(4) aes(1) 46
+    }
+}
@@ -1,6 +1,7 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
+#[allow(unused_imports)]
 use super::{asn1_enum, asn1_struct};
 
 asn1_struct! {
@@ -32,7 +33,8 @@ asn1_struct! {
     PrivateKeyInfo ::= SEQUENCE {
         version                   INTEGER,
         privateKeyAlgorithm       AlgorithmIdentifier REF,
-        privateKey                OCTET STRING
+        privateKey                OCTET STRING,
+        publicKey [1]             IMPLICIT BIT STRING
     }
 }
 
@@ -63,6 +65,28 @@ asn1_struct! {
     }
 }
 
+asn1_struct! {
+    DsaSubjectPublicKeyInfo ::= SEQUENCE {
+        algorithm         DsaAlgorithmIdentifier REF,
+        subjectPublicKey  BIT STRING
+    }
+}
+
+asn1_struct! {
+    DsaAlgorithmIdentifier ::= SEQUENCE {
+        algorithm                 OBJECT IDENTIFIER,
+        parameters                DssParms REF
+    }
+}
+
+asn1_struct! {
+    DssParms ::= SEQUENCE {
+        p   INTEGER,
+        q   INTEGER,
+        g   INTEGER
+    }
+}
+
 asn1_struct! {
     DigestInfo ::= SEQUENCE {
         digestAlgorithm AlgorithmIdentifier REF,
@@ -77,6 +101,45 @@ asn1_struct! {
     }
 }
 
+asn1_struct! {
+    RSASSAPSSParams ::= SEQUENCE {
+        hashAlgorithm     [0] AlgorithmIdentifier REF,
+        maskGenAlgorithm  [1] AlgorithmIdentifier REF,
+        saltLength        [2] INTEGER,
+        trailerField      [3] INTEGER
+    }
+}
+
+asn1_struct! {
+    EncryptedPrivateKeyInfo ::= SEQUENCE {
+        encryptionAlgorithm  AlgorithmIdentifier REF,
+        encryptedData        OCTET STRING
+    }
+}
+
+asn1_struct! {
+    PBES2Params ::= SEQUENCE {
+        keyDerivationFunc  AlgorithmIdentifier REF,
+        encryptionScheme   AlgorithmIdentifier REF
+    }
+}
+
+asn1_struct! {
+    PBKDF2Params ::= SEQUENCE {
+        salt            OCTET STRING,
+        iterationCount  INTEGER,
+        keyLength       INTEGER OPTIONAL,
+        prf             AlgorithmIdentifier REF OPTIONAL
+    }
+}
+
+asn1_struct! {
+    GCMParameters ::= SEQUENCE {
+        aesNonce  OCTET STRING,
+        aesICVlen INTEGER OPTIONAL
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +241,30 @@ mod tests {
         roundtrip_check::<SubjectPublicKeyInfo<'_>>(data);
     }
 
+    #[test]
+    fn parse_encrypted_pkcs8_key() {
+        let data = include_bytes!("../pkcs8/ed25519.aes-256-cbc-key-length.der");
+        truncation_check::<EncryptedPrivateKeyInfo<'_>>(data);
+        roundtrip_check::<EncryptedPrivateKeyInfo<'_>>(data);
+
+        let key = EncryptedPrivateKeyInfo::from_bytes(data).unwrap();
+        assert_eq!(key.encryptionAlgorithm.algorithm, oid::id_PBES2);
+        let Some(Any::Sequence(params)) = key.encryptionAlgorithm.parameters else {
+            panic!("missing PBES2-params");
+        };
+        let params = params.decode::<PBES2Params<'_>>().unwrap();
+        assert_eq!(params.keyDerivationFunc.algorithm, oid::id_PBKDF2);
+        assert_eq!(params.encryptionScheme.algorithm, oid::id_aes256_CBC);
+
+        let Some(Any::Sequence(kdf)) = params.keyDerivationFunc.parameters else {
+            panic!("missing PBKDF2-params");
+        };
+        let kdf = kdf.decode::<PBKDF2Params<'_>>().unwrap();
+        assert_eq!(kdf.iterationCount.as_usize(), Ok(1000));
+        assert_eq!(kdf.keyLength, Some(Integer::new(&[32])));
+        assert_eq!(kdf.prf.unwrap().algorithm, oid::id_hmacWithSHA512);
+    }
+
     fn truncation_check<'a, T: Type<'a>>(bytes: &'a [u8]) {
         // base case
         T::from_bytes(bytes).unwrap();
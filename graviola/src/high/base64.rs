@@ -0,0 +1,299 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Base64, as used by key formats.
+//!
+//! These are constant-time, as the encoded data is often secret.
+
+use crate::low::zeroise;
+
+/// Standard base64 encoding, with padding.
+pub(crate) fn encode(data: &[u8]) -> String {
+    encode_inner(data, Alphabets::Standard, true)
+}
+
+/// Base64url encoding, without padding.
+///
+/// This is `BASE64URL` from [RFC7515](https://datatracker.ietf.org/doc/html/rfc7515#section-2).
+pub(crate) fn encode_url(data: &[u8]) -> String {
+    encode_inner(data, Alphabets::Url, false)
+}
+
+/// Standard base64 encoding, without padding.
+///
+/// This is the encoding used by [age](https://age-encryption.org/v1).
+pub(crate) fn encode_unpadded(data: &[u8]) -> String {
+    encode_inner(data, Alphabets::Standard, false)
+}
+
+/// Standard base64 encoding, with padding, appended to `out`.
+///
+/// Unlike `encode()` this makes no temporary copies, as long as `out`
+/// has sufficient capacity.
+pub(crate) fn encode_into(out: &mut String, data: &[u8]) {
+    encode_inner_into(out, data, Alphabets::Standard, true)
+}
+
+/// The length of the padded encoding of `len` bytes.
+pub(crate) fn encoded_len(len: usize) -> usize {
+    (len + 2) / 3 * 4
+}
+
+fn encode_inner(data: &[u8], alphabet: Alphabets, padding: bool) -> String {
+    let mut out = String::with_capacity(encoded_len(data.len()));
+    encode_inner_into(&mut out, data, alphabet, padding);
+    out
+}
+
+fn encode_inner_into(out: &mut String, data: &[u8], alphabet: Alphabets, padding: bool) {
+    for chunk in data.chunks(3) {
+        let mut block = [0u8; 3];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let n = u32::from_be_bytes([0, block[0], block[1], block[2]]);
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => out.push(encode_char((n >> (18 - 6 * i)) as u8 & 0x3f, alphabet) as char),
+                false if padding => out.push('='),
+                false => {}
+            }
+        }
+    }
+}
+
+/// Standard base64 decoding.
+///
+/// Padding is required, and only the standard alphabet is accepted.
+pub(crate) fn decode(text: &str) -> Option<Vec<u8>> {
+    match text.len() % 4 {
+        0 => decode_inner(text, Alphabets::Standard),
+        _ => None,
+    }
+}
+
+/// Standard base64 decoding, without padding.
+///
+/// Padding is not accepted, and only the standard alphabet is accepted.
+pub(crate) fn decode_unpadded(text: &str) -> Option<Vec<u8>> {
+    match text.ends_with('=') {
+        true => None,
+        false => decode_inner(text, Alphabets::Standard),
+    }
+}
+
+/// Lenient base64 decoding.
+///
+/// As in proto3's JSON mapping, the standard and URL-safe alphabets are
+/// accepted, with or without padding.
+pub(crate) fn decode_lenient(text: &str) -> Option<Vec<u8>> {
+    decode_inner(text, Alphabets::Both)
+}
+
+/// Base64url decoding.
+///
+/// Padding is not accepted, and only the URL-safe alphabet is accepted.
+pub(crate) fn decode_url(text: &str) -> Option<Vec<u8>> {
+    match text.ends_with('=') {
+        true => None,
+        false => decode_inner(text, Alphabets::Url),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Alphabets {
+    Standard,
+    Url,
+    Both,
+}
+
+/// Decodes `text`, in time which depends only on its length.
+///
+/// (The position of any padding is not treated as secret.)
+fn decode_inner(text: &str, alphabets: Alphabets) -> Option<Vec<u8>> {
+    let (text, padding) = if let Some(text) = text.strip_suffix("==") {
+        (text, 2)
+    } else if let Some(text) = text.strip_suffix('=') {
+        (text, 1)
+    } else {
+        (text, 0)
+    };
+    if !matches!((text.len() % 4, padding), (0 | 2 | 3, 0) | (2, 2) | (3, 1)) {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    let mut invalid = 0u8;
+    for chunk in text.as_bytes().chunks(4) {
+        let mut n = 0u32;
+        for (i, b) in chunk.iter().enumerate() {
+            let (v, valid) = decode_char(*b, alphabets);
+            invalid |= !valid;
+            n |= u32::from(v) << (18 - 6 * i);
+        }
+        let bytes = n.to_be_bytes();
+        let len = chunk.len() - 1;
+        // non-canonical encodings have nonzero unused bits
+        for b in &bytes[1 + len..] {
+            invalid |= b;
+        }
+        out.extend_from_slice(&bytes[1..1 + len]);
+    }
+
+    match invalid {
+        0 => Some(out),
+        _ => {
+            zeroise(&mut out);
+            None
+        }
+    }
+}
+
+/// Maps `v` (which must be less than 64) to its character, without
+/// table lookups or branches.
+fn encode_char(v: u8, alphabet: Alphabets) -> u8 {
+    let (diff_62, diff_63) = match alphabet {
+        Alphabets::Url => (b'9' + 1 - b'-', b'_' - b'-' - 1),
+        _ => (b'9' + 1 - b'+', b'/' - b'+' - 1),
+    };
+
+    let v = i16::from(v);
+    let mut c = v + i16::from(b'A');
+    c += ((25 - v) >> 8) & i16::from(b'a' - b'Z' - 1);
+    c -= ((51 - v) >> 8) & i16::from(b'z' + 1 - b'0');
+    c -= ((61 - v) >> 8) & i16::from(diff_62);
+    c += ((62 - v) >> 8) & i16::from(diff_63);
+    c as u8
+}
+
+/// Maps `c` to its value, and 0xff if it is in `alphabets` (0 if not),
+/// without table lookups or branches.
+fn decode_char(c: u8, alphabets: Alphabets) -> (u8, u8) {
+    let standard = match alphabets {
+        Alphabets::Url => 0,
+        _ => 0xff,
+    };
+    let url = match alphabets {
+        Alphabets::Standard => 0,
+        _ => 0xff,
+    };
+
+    let upper = ct_range(c, b'A', b'Z');
+    let lower = ct_range(c, b'a', b'z');
+    let digit = ct_range(c, b'0', b'9');
+    let s62 = ct_range(c, b'+', b'+') & standard;
+    let s63 = ct_range(c, b'/', b'/') & standard;
+    let u62 = ct_range(c, b'-', b'-') & url;
+    let u63 = ct_range(c, b'_', b'_') & url;
+
+    let value = (upper & c.wrapping_sub(b'A'))
+        | (lower & c.wrapping_sub(b'a' - 26))
+        | (digit & c.wrapping_add(52 - b'0'))
+        | ((s62 | u62) & 62)
+        | ((s63 | u63) & 63);
+    (value, upper | lower | digit | s62 | s63 | u62 | u63)
+}
+
+/// Returns 0xff if `lo <= c <= hi`, or 0 otherwise.
+pub(super) fn ct_range(c: u8, lo: u8, hi: u8) -> u8 {
+    let c = i16::from(c);
+    (((i16::from(lo) - 1 - c) & (c - i16::from(hi) - 1)) >> 8) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for (data, text) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (&[0xfb, 0xff], "+/8="),
+        ] {
+            assert_eq!(encode(data), text);
+            assert_eq!(decode(text).unwrap(), data);
+            assert_eq!(decode_lenient(text).unwrap(), data);
+            assert_eq!(decode_lenient(text.trim_end_matches('=')).unwrap(), data);
+        }
+        assert_eq!(decode_lenient("-_8").unwrap(), &[0xfb, 0xff]);
+    }
+
+    #[test]
+    fn url() {
+        for (data, text) in [
+            (&b""[..], ""),
+            (b"f", "Zg"),
+            (b"fo", "Zm8"),
+            (b"foo", "Zm9v"),
+            (&[0xfb, 0xff], "-_8"),
+        ] {
+            assert_eq!(encode_url(data), text);
+            assert_eq!(decode_url(text).unwrap(), data);
+        }
+        for text in ["Zg==", "Zm8=", "+/8", "Z", "Zh", "Zm9vY"] {
+            assert!(decode_url(text).is_none(), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn unpadded() {
+        for (data, text) in [
+            (&b""[..], ""),
+            (b"f", "Zg"),
+            (b"fo", "Zm8"),
+            (b"foo", "Zm9v"),
+            (&[0xfb, 0xff], "+/8"),
+        ] {
+            assert_eq!(encode_unpadded(data), text);
+            assert_eq!(decode_unpadded(text).unwrap(), data);
+        }
+        for text in ["Zg==", "Zm8=", "-_8", "Z", "Zh", "Zm9vY"] {
+            assert!(decode_unpadded(text).is_none(), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn characters() {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        const URL_ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+        for v in 0..64u8 {
+            assert_eq!(encode_char(v, Alphabets::Standard), ALPHABET[v as usize]);
+            assert_eq!(encode_char(v, Alphabets::Url), URL_ALPHABET[v as usize]);
+        }
+
+        for c in 0..=255u8 {
+            let standard = ALPHABET.iter().position(|a| *a == c);
+            let url = URL_ALPHABET.iter().position(|a| *a == c);
+            for (alphabets, expect) in [
+                (Alphabets::Standard, standard),
+                (Alphabets::Url, url),
+                (Alphabets::Both, standard.or(url)),
+            ] {
+                let got = match decode_char(c, alphabets) {
+                    (v, 0xff) => Some(v as usize),
+                    (_, 0) => None,
+                    _ => panic!("invalid mask for {c:#x}"),
+                };
+                assert_eq!(got, expect, "{c:#x}");
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for text in [
+            "Z", "Zg=", "Zh==", "Zm9v=", "Zm9vY", "Zm9v====", "Zm 9v", "=",
+        ] {
+            assert!(decode(text).is_none(), "{text:?}");
+            assert!(decode_lenient(text).is_none(), "{text:?}");
+        }
+        for text in ["Zg", "Zm8", "-_8=", "Zm9vYg"] {
+            assert!(decode(text).is_none(), "{text:?}");
+        }
+    }
+}
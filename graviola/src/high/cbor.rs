@@ -0,0 +1,368 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Just enough CBOR for COSE.
+//!
+//! This is [RFC8949](https://datatracker.ietf.org/doc/html/rfc8949).
+//! Decoding is strict: only definite lengths, and the shortest encoding of
+//! each argument, are accepted.  Map keys must be unique.  Floating-point
+//! and most simple values are not supported.
+//!
+//! Errors are reported as `None`, so callers can give them a
+//! format-specific meaning.
+
+/// A parsed CBOR data item.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Cbor<'a> {
+    /// An unsigned integer (major type 0).
+    Uint(u64),
+    /// A negative integer (major type 1), whose value is `-1 - n`.
+    Nint(u64),
+    Bytes(&'a [u8]),
+    Text(&'a str),
+    Array(Vec<Self>),
+    Map(Vec<(Self, Self)>),
+    Tag(u64, Box<Self>),
+    Bool(bool),
+    Null,
+}
+
+impl<'a> Cbor<'a> {
+    /// Parses exactly one data item, which is the whole of `input`.
+    pub(crate) fn parse(input: &'a [u8]) -> Option<Self> {
+        let mut p = Parser {
+            input,
+            pos: 0,
+            depth: 0,
+        };
+        let item = p.item()?;
+        match p.pos == input.len() {
+            true => Some(item),
+            false => None,
+        }
+    }
+
+    /// An integer, if it fits in an `i64`.
+    pub(crate) fn int(&self) -> Option<i64> {
+        match self {
+            Self::Uint(n) => i64::try_from(*n).ok(),
+            Self::Nint(n) => i64::try_from(*n).ok().map(|n| -1 - n),
+            _ => None,
+        }
+    }
+
+    /// Makes an integer.
+    pub(crate) fn from_int(value: i64) -> Self {
+        match value {
+            0.. => Self::Uint(value as u64),
+            _ => Self::Nint(!value as u64),
+        }
+    }
+
+    pub(crate) fn bytes(&self) -> Option<&'a [u8]> {
+        match self {
+            Self::Bytes(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// The entries of a map.
+    pub(crate) fn map(&self) -> Option<&[(Self, Self)]> {
+        match self {
+            Self::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Append the encoding of this item to `out`.
+    ///
+    /// The encoding is the preferred one, but map entries are written in
+    /// the order given rather than sorted.
+    pub(crate) fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Uint(n) => put_head(out, MAJOR_UINT, *n),
+            Self::Nint(n) => put_head(out, MAJOR_NINT, *n),
+            Self::Bytes(b) => {
+                put_head(out, MAJOR_BYTES, b.len() as u64);
+                out.extend_from_slice(b);
+            }
+            Self::Text(t) => {
+                put_head(out, MAJOR_TEXT, t.len() as u64);
+                out.extend_from_slice(t.as_bytes());
+            }
+            Self::Array(items) => {
+                put_head(out, MAJOR_ARRAY, items.len() as u64);
+                for item in items {
+                    item.encode(out);
+                }
+            }
+            Self::Map(entries) => {
+                put_head(out, MAJOR_MAP, entries.len() as u64);
+                for (key, value) in entries {
+                    key.encode(out);
+                    value.encode(out);
+                }
+            }
+            Self::Tag(tag, item) => {
+                put_head(out, MAJOR_TAG, *tag);
+                item.encode(out);
+            }
+            Self::Bool(false) => put_head(out, MAJOR_SIMPLE, SIMPLE_FALSE),
+            Self::Bool(true) => put_head(out, MAJOR_SIMPLE, SIMPLE_TRUE),
+            Self::Null => put_head(out, MAJOR_SIMPLE, SIMPLE_NULL),
+        }
+    }
+
+    /// The encoding of this item.
+    pub(crate) fn to_vec(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode(&mut out);
+        out
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn item(&mut self) -> Option<Cbor<'a>> {
+        let initial = self.take(1)?[0];
+        let major = initial >> 5;
+
+        if major == MAJOR_SIMPLE {
+            return match u64::from(initial & 0x1f) {
+                SIMPLE_FALSE => Some(Cbor::Bool(false)),
+                SIMPLE_TRUE => Some(Cbor::Bool(true)),
+                SIMPLE_NULL => Some(Cbor::Null),
+                _ => None,
+            };
+        }
+
+        let argument = self.argument(initial & 0x1f)?;
+        match major {
+            MAJOR_UINT => Some(Cbor::Uint(argument)),
+            MAJOR_NINT => Some(Cbor::Nint(argument)),
+            MAJOR_BYTES => Some(Cbor::Bytes(self.take_len(argument)?)),
+            MAJOR_TEXT => Some(Cbor::Text(
+                core::str::from_utf8(self.take_len(argument)?).ok()?,
+            )),
+            MAJOR_ARRAY => self.nested(|p| {
+                let mut items = Vec::new();
+                for _ in 0..argument {
+                    items.push(p.item()?);
+                }
+                Some(Cbor::Array(items))
+            }),
+            MAJOR_MAP => self.nested(|p| {
+                let mut entries: Vec<(Cbor<'a>, Cbor<'a>)> = Vec::new();
+                for _ in 0..argument {
+                    let key = p.item()?;
+                    if entries.iter().any(|(other, _)| *other == key) {
+                        return None;
+                    }
+                    let value = p.item()?;
+                    entries.push((key, value));
+                }
+                Some(Cbor::Map(entries))
+            }),
+            MAJOR_TAG => self.nested(|p| Some(Cbor::Tag(argument, Box::new(p.item()?)))),
+            _ => unreachable!(),
+        }
+    }
+
+    fn nested(&mut self, f: impl FnOnce(&mut Self) -> Option<Cbor<'a>>) -> Option<Cbor<'a>> {
+        if self.depth == MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let item = f(self)?;
+        self.depth -= 1;
+        Some(item)
+    }
+
+    /// Reads the argument of a head, whose additional information is `info`.
+    ///
+    /// The argument must be in its shortest form; indefinite lengths
+    /// and the reserved values are rejected.
+    fn argument(&mut self, info: u8) -> Option<u64> {
+        let (len, min) = match info {
+            0..=23 => return Some(u64::from(info)),
+            24 => (1, 24),
+            25 => (2, 0x100),
+            26 => (4, 0x1_0000),
+            27 => (8, 0x1_0000_0000),
+            _ => return None,
+        };
+
+        let value = self
+            .take(len)?
+            .iter()
+            .fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+        match value >= min {
+            true => Some(value),
+            false => None,
+        }
+    }
+
+    fn take_len(&mut self, len: u64) -> Option<&'a [u8]> {
+        self.take(usize::try_from(len).ok()?)
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(len)?;
+        let bytes = self.input.get(self.pos..end)?;
+        self.pos = end;
+        Some(bytes)
+    }
+}
+
+/// Append a head with `major` type and `argument` to `out`, in its
+/// shortest form.
+fn put_head(out: &mut Vec<u8>, major: u8, argument: u64) {
+    let major = major << 5;
+    match argument {
+        0..=23 => out.push(major | argument as u8),
+        24..=0xff => out.extend_from_slice(&[major | 24, argument as u8]),
+        0x100..=0xffff => {
+            out.push(major | 25);
+            out.extend_from_slice(&(argument as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            out.push(major | 26);
+            out.extend_from_slice(&(argument as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend_from_slice(&argument.to_be_bytes());
+        }
+    }
+}
+
+const MAJOR_UINT: u8 = 0;
+const MAJOR_NINT: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+const SIMPLE_FALSE: u64 = 20;
+const SIMPLE_TRUE: u64 = 21;
+const SIMPLE_NULL: u64 = 22;
+
+const MAX_DEPTH: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc8949_appendix_a() {
+        for (hex, item) in [
+            ("00", Cbor::Uint(0)),
+            ("17", Cbor::Uint(23)),
+            ("1818", Cbor::Uint(24)),
+            ("1903e8", Cbor::Uint(1000)),
+            ("1a000f4240", Cbor::Uint(1000000)),
+            ("1b000000e8d4a51000", Cbor::Uint(1000000000000)),
+            ("1bffffffffffffffff", Cbor::Uint(u64::MAX)),
+            ("20", Cbor::Nint(0)),
+            ("3863", Cbor::Nint(99)),
+            ("f4", Cbor::Bool(false)),
+            ("f5", Cbor::Bool(true)),
+            ("f6", Cbor::Null),
+            ("40", Cbor::Bytes(b"")),
+            ("4401020304", Cbor::Bytes(&[1, 2, 3, 4])),
+            ("6449455446", Cbor::Text("IETF")),
+            ("62c3bc", Cbor::Text("\u{fc}")),
+            (
+                "8301820203820405",
+                Cbor::Array(vec![
+                    Cbor::Uint(1),
+                    Cbor::Array(vec![Cbor::Uint(2), Cbor::Uint(3)]),
+                    Cbor::Array(vec![Cbor::Uint(4), Cbor::Uint(5)]),
+                ]),
+            ),
+            (
+                "a201020304",
+                Cbor::Map(vec![
+                    (Cbor::Uint(1), Cbor::Uint(2)),
+                    (Cbor::Uint(3), Cbor::Uint(4)),
+                ]),
+            ),
+            (
+                "c074323031332d30332d32315432303a30343a30305a",
+                Cbor::Tag(0, Box::new(Cbor::Text("2013-03-21T20:04:00Z"))),
+            ),
+        ] {
+            let bytes = unhex(hex);
+            assert_eq!(Cbor::parse(&bytes), Some(item), "{hex}");
+            assert_eq!(Cbor::parse(&bytes).unwrap().to_vec(), bytes, "{hex}");
+        }
+    }
+
+    #[test]
+    fn integers() {
+        for value in [0, 1, 23, 24, -1, -24, -25, -256, -257, i64::MAX, i64::MIN] {
+            let item = Cbor::from_int(value);
+            assert_eq!(item.int(), Some(value));
+            assert_eq!(Cbor::parse(&item.to_vec()).unwrap().int(), Some(value));
+        }
+        assert_eq!(Cbor::from_int(-7).to_vec(), [0x26]);
+        assert_eq!(Cbor::Uint(u64::MAX).int(), None);
+        assert_eq!(Cbor::Nint(u64::MAX).int(), None);
+        assert_eq!(Cbor::Text("1").int(), None);
+    }
+
+    #[test]
+    fn invalid() {
+        for hex in [
+            "",
+            // trailing data
+            "0000",
+            // not shortest form
+            "1817",
+            "1900ff",
+            "1a0000ffff",
+            "1b00000000ffffffff",
+            // reserved, indefinite length
+            "1c",
+            "5f40ff",
+            "9fff",
+            // truncated
+            "19ff",
+            "4401",
+            "8201",
+            "a101",
+            "c0",
+            // not UTF-8
+            "61ff",
+            // duplicate keys
+            "a201020103",
+            // floats, undefined and other simple values
+            "f7",
+            "f0",
+            "f93c00",
+            // lengths beyond the input
+            "5bffffffffffffffff",
+        ] {
+            assert_eq!(Cbor::parse(&unhex(hex)), None, "{hex}");
+        }
+
+        let mut deep = vec![0x81; MAX_DEPTH + 1];
+        deep.push(0x00);
+        assert_eq!(Cbor::parse(&deep), None);
+        assert!(Cbor::parse(&deep[1..]).is_some());
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,851 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! CBOR Object Signing and Encryption (COSE).
+//!
+//! This is [RFC9052](https://datatracker.ietf.org/doc/html/rfc9052), with
+//! algorithms from [RFC9053](https://datatracker.ietf.org/doc/html/rfc9053).
+//! Only the single-signer and single-recipient structures are supported:
+//! `COSE_Sign1` and `COSE_Encrypt0`.
+//!
+//! Headers are strict: a label must not appear in both the protected
+//! and unprotected headers, the algorithm must be protected, and any
+//! `crit` parameter is rejected (since no extensions are supported).
+
+use super::cbor::Cbor;
+use super::curve::P256;
+use super::ecdsa;
+use super::hash::Sha256;
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::low::{Entry, zeroise};
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::chacha20poly1305::ChaCha20Poly1305;
+use crate::mid::ed25519;
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+/// A COSE algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoseAlgorithm {
+    /// `ES256` (-7): ECDSA on P-256 with SHA-256.
+    Es256,
+    /// `EdDSA` (-8): Ed25519.
+    EdDsa,
+    /// `A128GCM` (1): AES-128-GCM, with a 128-bit tag.
+    A128Gcm,
+    /// `ChaCha20/Poly1305` (24).
+    ChaCha20Poly1305,
+}
+
+impl CoseAlgorithm {
+    /// The COSE algorithm identifier.
+    pub fn id(self) -> i64 {
+        match self {
+            Self::Es256 => -7,
+            Self::EdDsa => -8,
+            Self::A128Gcm => 1,
+            Self::ChaCha20Poly1305 => 24,
+        }
+    }
+
+    /// Finds the algorithm with identifier `id`.
+    pub fn from_id(id: i64) -> Option<Self> {
+        [
+            Self::Es256,
+            Self::EdDsa,
+            Self::A128Gcm,
+            Self::ChaCha20Poly1305,
+        ]
+        .into_iter()
+        .find(|alg| alg.id() == id)
+    }
+}
+
+/// A private key that can produce `COSE_Sign1` messages.
+#[derive(Clone, Copy)]
+pub enum CoseSigningKey<'a> {
+    /// A P-256 key, for `ES256`.
+    EcdsaP256(&'a ecdsa::SigningKey<P256>),
+    /// An Ed25519 key, for `EdDSA`.
+    Ed25519(&'a ed25519::SigningKey),
+}
+
+impl CoseSigningKey<'_> {
+    /// The signature algorithm used with this key.
+    pub fn algorithm(&self) -> CoseAlgorithm {
+        match self {
+            Self::EcdsaP256(_) => CoseAlgorithm::Es256,
+            Self::Ed25519(_) => CoseAlgorithm::EdDsa,
+        }
+    }
+
+    /// Produces a tagged `COSE_Sign1` message, signing `payload`.
+    ///
+    /// `key_id` is written to the unprotected header, if given.
+    /// `external_aad` is signed but not included in the message;
+    /// it may be empty.
+    pub fn sign1(
+        &self,
+        key_id: Option<&[u8]>,
+        external_aad: &[u8],
+        payload: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let _entry = Entry::new_secret();
+        let protected = encode_protected(self.algorithm());
+        let to_be_signed = sig_structure(&protected, external_aad, payload);
+
+        let mut buffer = [0u8; 64];
+        let signature = match self {
+            Self::EcdsaP256(key) => key.sign::<Sha256>(&[&to_be_signed], &mut buffer)?,
+            Self::Ed25519(key) => {
+                buffer = key.sign(&[&to_be_signed]);
+                &buffer[..]
+            }
+        };
+
+        Ok(encode_message(
+            TAG_SIGN1,
+            vec![
+                Cbor::Bytes(&protected),
+                encode_unprotected(key_id, None),
+                Cbor::Bytes(payload),
+                Cbor::Bytes(signature),
+            ],
+        ))
+    }
+}
+
+/// A public key that can verify `COSE_Sign1` messages.
+#[derive(Clone, Copy)]
+pub enum CoseVerifyingKey<'a> {
+    /// A P-256 key, for `ES256`.
+    EcdsaP256(&'a ecdsa::VerifyingKey<P256>),
+    /// An Ed25519 key, for `EdDSA`.
+    Ed25519(&'a ed25519::VerifyingKey),
+}
+
+/// A decoded, but not yet verified, `COSE_Sign1` message.
+///
+/// The header parameters are available before verification, so the
+/// right key can be chosen -- but they are not authentic until
+/// [`CoseSign1::verify()`] succeeds.  The payload is only available from
+/// [`CoseSign1::verify()`].
+pub struct CoseSign1<'a> {
+    protected: &'a [u8],
+    headers: Headers<'a>,
+    payload: &'a [u8],
+    signature: &'a [u8],
+}
+
+impl<'a> CoseSign1<'a> {
+    /// Decodes a `COSE_Sign1` message, which may be tagged or untagged.
+    ///
+    /// `KeyFormatError::InvalidCose` is returned if `message` is malformed
+    /// (including if its payload is detached), and
+    /// `KeyFormatError::UnsupportedCoseAlgorithm` if its algorithm is not
+    /// supported.
+    pub fn decode(message: &'a [u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        let items = decode_message(message, TAG_SIGN1)?;
+        let [protected, unprotected, payload, signature] = &items[..] else {
+            return Err(invalid());
+        };
+
+        let protected = protected.bytes().ok_or_else(invalid)?;
+        let headers = Headers::decode(protected, unprotected)?;
+        if !matches!(
+            headers.algorithm,
+            CoseAlgorithm::Es256 | CoseAlgorithm::EdDsa
+        ) {
+            return Err(KeyFormatError::UnsupportedCoseAlgorithm.into());
+        }
+
+        Ok(Self {
+            protected,
+            headers,
+            payload: payload.bytes().ok_or_else(invalid)?,
+            signature: signature.bytes().ok_or_else(invalid)?,
+        })
+    }
+
+    /// The signature algorithm.
+    pub fn algorithm(&self) -> CoseAlgorithm {
+        self.headers.algorithm
+    }
+
+    /// The `kid` parameter, if present.
+    pub fn key_id(&self) -> Option<&[u8]> {
+        self.headers.key_id
+    }
+
+    /// Verifies the signature using `key`, and returns the payload.
+    ///
+    /// `external_aad` must match that given when signing.
+    ///
+    /// The algorithm must suit `key`, otherwise
+    /// `KeyFormatError::MismatchedCoseAlgorithm` is returned.  An invalid
+    /// signature gives [`Error::BadSignature`].
+    pub fn verify(&self, key: CoseVerifyingKey<'_>, external_aad: &[u8]) -> Result<&[u8], Error> {
+        let _entry = Entry::new_public();
+        let to_be_signed = sig_structure(self.protected, external_aad, self.payload);
+
+        match (key, self.headers.algorithm) {
+            (CoseVerifyingKey::EcdsaP256(key), CoseAlgorithm::Es256) => {
+                key.verify::<Sha256>(&[&to_be_signed], self.signature)
+            }
+            (CoseVerifyingKey::Ed25519(key), CoseAlgorithm::EdDsa) => {
+                key.verify(&[&to_be_signed], self.signature)
+            }
+            _ => return Err(KeyFormatError::MismatchedCoseAlgorithm.into()),
+        }
+        .map_err(|_| Error::BadSignature)?;
+
+        Ok(self.payload)
+    }
+}
+
+/// A symmetric key, for `COSE_Encrypt0` messages.
+#[derive(Clone, Copy)]
+pub enum CoseEncryptionKey<'a> {
+    /// An AES-128 key, for `A128GCM`.
+    A128Gcm(&'a [u8; 16]),
+    /// A ChaCha20-Poly1305 key, for `ChaCha20/Poly1305`.
+    ChaCha20Poly1305(&'a [u8; 32]),
+}
+
+impl CoseEncryptionKey<'_> {
+    /// The content encryption algorithm used with this key.
+    pub fn algorithm(&self) -> CoseAlgorithm {
+        match self {
+            Self::A128Gcm(_) => CoseAlgorithm::A128Gcm,
+            Self::ChaCha20Poly1305(_) => CoseAlgorithm::ChaCha20Poly1305,
+        }
+    }
+
+    /// Produces a tagged `COSE_Encrypt0` message, encrypting `plaintext`.
+    ///
+    /// `key_id` is written to the unprotected header, if given.
+    /// `external_aad` is authenticated but not included in the message;
+    /// it may be empty.
+    ///
+    /// The IV is random, and written to the unprotected header.
+    pub fn encrypt0(
+        &self,
+        key_id: Option<&[u8]>,
+        external_aad: &[u8],
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        self.encrypt0_with_rng(key_id, external_aad, plaintext, &mut SystemRandom)
+    }
+
+    /// Produces a tagged `COSE_Encrypt0` message, using `rng` to
+    /// generate the IV.
+    ///
+    /// Otherwise, as [`Self::encrypt0()`].
+    pub fn encrypt0_with_rng(
+        &self,
+        key_id: Option<&[u8]>,
+        external_aad: &[u8],
+        plaintext: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<Vec<u8>, Error> {
+        let _entry = Entry::new_secret();
+        let protected = encode_protected(self.algorithm());
+        let aad = enc_structure(&protected, external_aad);
+
+        let mut iv = [0u8; IV_LEN];
+        rng.fill(&mut iv)?;
+
+        let mut ciphertext = plaintext.to_vec();
+        let mut tag = [0u8; TAG_LEN];
+        match self {
+            Self::A128Gcm(key) => {
+                AesGcm::new(&key[..]).encrypt(&iv, &aad, &mut ciphertext, &mut tag)
+            }
+            Self::ChaCha20Poly1305(key) => {
+                ChaCha20Poly1305::new(**key).encrypt(&iv, &aad, &mut ciphertext, &mut tag)
+            }
+        }
+        ciphertext.extend_from_slice(&tag);
+
+        Ok(encode_message(
+            TAG_ENCRYPT0,
+            vec![
+                Cbor::Bytes(&protected),
+                encode_unprotected(key_id, Some(&iv)),
+                Cbor::Bytes(&ciphertext),
+            ],
+        ))
+    }
+}
+
+/// A decoded, but not yet decrypted, `COSE_Encrypt0` message.
+///
+/// The header parameters are available before decryption, so the
+/// right key can be chosen -- but they are not authentic until
+/// [`CoseEncrypt0::decrypt()`] succeeds.
+pub struct CoseEncrypt0<'a> {
+    protected: &'a [u8],
+    headers: Headers<'a>,
+    iv: [u8; IV_LEN],
+    ciphertext: &'a [u8],
+}
+
+impl<'a> CoseEncrypt0<'a> {
+    /// Decodes a `COSE_Encrypt0` message, which may be tagged or untagged.
+    ///
+    /// `KeyFormatError::InvalidCose` is returned if `message` is malformed
+    /// (including if its ciphertext is detached, or it has no IV), and
+    /// `KeyFormatError::UnsupportedCoseAlgorithm` if its algorithm is not
+    /// supported.
+    pub fn decode(message: &'a [u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        let items = decode_message(message, TAG_ENCRYPT0)?;
+        let [protected, unprotected, ciphertext] = &items[..] else {
+            return Err(invalid());
+        };
+
+        let protected = protected.bytes().ok_or_else(invalid)?;
+        let headers = Headers::decode(protected, unprotected)?;
+        if !matches!(
+            headers.algorithm,
+            CoseAlgorithm::A128Gcm | CoseAlgorithm::ChaCha20Poly1305
+        ) {
+            return Err(KeyFormatError::UnsupportedCoseAlgorithm.into());
+        }
+
+        let iv = headers
+            .iv
+            .and_then(|iv| iv.try_into().ok())
+            .ok_or_else(invalid)?;
+        let ciphertext = ciphertext.bytes().ok_or_else(invalid)?;
+        if ciphertext.len() < TAG_LEN {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            protected,
+            headers,
+            iv,
+            ciphertext,
+        })
+    }
+
+    /// The content encryption algorithm.
+    pub fn algorithm(&self) -> CoseAlgorithm {
+        self.headers.algorithm
+    }
+
+    /// The `kid` parameter, if present.
+    pub fn key_id(&self) -> Option<&[u8]> {
+        self.headers.key_id
+    }
+
+    /// Decrypts the message using `key`, and returns the plaintext.
+    ///
+    /// `external_aad` must match that given when encrypting.
+    ///
+    /// The algorithm must suit `key`, otherwise
+    /// `KeyFormatError::MismatchedCoseAlgorithm` is returned.  Otherwise,
+    /// any failure gives [`Error::DecryptFailed`].
+    pub fn decrypt(
+        &self,
+        key: CoseEncryptionKey<'_>,
+        external_aad: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let _entry = Entry::new_secret();
+        if key.algorithm() != self.headers.algorithm {
+            return Err(KeyFormatError::MismatchedCoseAlgorithm.into());
+        }
+
+        let aad = enc_structure(self.protected, external_aad);
+        let (ciphertext, tag) = self.ciphertext.split_at(self.ciphertext.len() - TAG_LEN);
+        let mut plaintext = ciphertext.to_vec();
+        let result = match key {
+            CoseEncryptionKey::A128Gcm(key) => {
+                AesGcm::new(&key[..]).decrypt(&self.iv, &aad, &mut plaintext, tag)
+            }
+            CoseEncryptionKey::ChaCha20Poly1305(key) => {
+                ChaCha20Poly1305::new(*key).decrypt(&self.iv, &aad, &mut plaintext, tag)
+            }
+        };
+
+        match result {
+            Ok(()) => Ok(plaintext),
+            Err(e) => {
+                zeroise(&mut plaintext);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// The header parameters used by `COSE_Sign1` and `COSE_Encrypt0`.
+struct Headers<'a> {
+    algorithm: CoseAlgorithm,
+    key_id: Option<&'a [u8]>,
+    iv: Option<&'a [u8]>,
+}
+
+impl<'a> Headers<'a> {
+    /// Decodes the `protected` header (a serialized map) and the
+    /// `unprotected` header (a map).
+    fn decode(protected: &'a [u8], unprotected: &Cbor<'a>) -> Result<Self, Error> {
+        // a zero-length protected header is an empty map
+        let protected = match protected {
+            [] => Cbor::Map(Vec::new()),
+            _ => Cbor::parse(protected).ok_or_else(invalid)?,
+        };
+        let protected = protected.map().ok_or_else(invalid)?;
+        let unprotected = unprotected.map().ok_or_else(invalid)?;
+
+        // labels must not be repeated (RFC9052 section 3)
+        if protected
+            .iter()
+            .any(|(label, _)| unprotected.iter().any(|(other, _)| other == label))
+        {
+            return Err(invalid());
+        }
+
+        let find = |label: i64| {
+            protected
+                .iter()
+                .chain(unprotected)
+                .find(|(l, _)| l.int() == Some(label))
+                .map(|(_, value)| value)
+        };
+        let bytes = |label: i64| {
+            find(label)
+                .map(|value| value.bytes().ok_or_else(invalid))
+                .transpose()
+        };
+
+        if find(LABEL_CRIT).is_some() {
+            return Err(invalid());
+        }
+        if find(LABEL_PARTIAL_IV).is_some() {
+            return Err(KeyFormatError::UnsupportedCoseAlgorithm.into());
+        }
+
+        let algorithm = protected
+            .iter()
+            .find(|(l, _)| l.int() == Some(LABEL_ALG))
+            .map(|(_, value)| value)
+            .ok_or_else(invalid)?
+            .int()
+            .and_then(CoseAlgorithm::from_id)
+            .ok_or(KeyFormatError::UnsupportedCoseAlgorithm)?;
+
+        Ok(Self {
+            algorithm,
+            key_id: bytes(LABEL_KID)?,
+            iv: bytes(LABEL_IV)?,
+        })
+    }
+}
+
+/// Decodes a message which is an array, optionally with `tag`.
+fn decode_message(message: &[u8], tag: u64) -> Result<Vec<Cbor<'_>>, Error> {
+    let item = match Cbor::parse(message).ok_or_else(invalid)? {
+        Cbor::Tag(t, item) if t == tag => *item,
+        Cbor::Tag(..) => return Err(invalid()),
+        item => item,
+    };
+    match item {
+        Cbor::Array(items) => Ok(items),
+        _ => Err(invalid()),
+    }
+}
+
+/// Encodes a message which is an array of `items`, with `tag`.
+fn encode_message(tag: u64, items: Vec<Cbor<'_>>) -> Vec<u8> {
+    Cbor::Tag(tag, Box::new(Cbor::Array(items))).to_vec()
+}
+
+/// The serialized protected header, which contains only `algorithm`.
+fn encode_protected(algorithm: CoseAlgorithm) -> Vec<u8> {
+    Cbor::Map(vec![(
+        Cbor::from_int(LABEL_ALG),
+        Cbor::from_int(algorithm.id()),
+    )])
+    .to_vec()
+}
+
+fn encode_unprotected<'a>(key_id: Option<&'a [u8]>, iv: Option<&'a [u8]>) -> Cbor<'a> {
+    Cbor::Map(
+        [(LABEL_KID, key_id), (LABEL_IV, iv)]
+            .into_iter()
+            .filter_map(|(label, value)| Some((Cbor::from_int(label), Cbor::Bytes(value?))))
+            .collect(),
+    )
+}
+
+/// The `Sig_structure` for a `COSE_Sign1` (RFC9052 section 4.4).
+fn sig_structure(protected: &[u8], external_aad: &[u8], payload: &[u8]) -> Vec<u8> {
+    Cbor::Array(vec![
+        Cbor::Text("Signature1"),
+        Cbor::Bytes(protected),
+        Cbor::Bytes(external_aad),
+        Cbor::Bytes(payload),
+    ])
+    .to_vec()
+}
+
+/// The `Enc_structure` for a `COSE_Encrypt0` (RFC9052 section 5.3).
+fn enc_structure(protected: &[u8], external_aad: &[u8]) -> Vec<u8> {
+    Cbor::Array(vec![
+        Cbor::Text("Encrypt0"),
+        Cbor::Bytes(protected),
+        Cbor::Bytes(external_aad),
+    ])
+    .to_vec()
+}
+
+fn invalid() -> Error {
+    KeyFormatError::InvalidCose.into()
+}
+
+const TAG_ENCRYPT0: u64 = 16;
+const TAG_SIGN1: u64 = 18;
+
+const LABEL_ALG: i64 = 1;
+const LABEL_CRIT: i64 = 2;
+const LABEL_KID: i64 = 4;
+const LABEL_IV: i64 = 5;
+const LABEL_PARTIAL_IV: i64 = 6;
+
+const IV_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::p256;
+    use crate::mid::rng::TestRng;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// The key with `kid` "11" from RFC9052 appendix C.7.1.
+    fn rfc9052_p256() -> ecdsa::SigningKey<P256> {
+        ecdsa::SigningKey {
+            private_key: p256::StaticPrivateKey::from_bytes(&unhex(
+                "57c92077664146e876760c9520d054aa93c3afb04e306705db6090308507b4d3",
+            ))
+            .unwrap(),
+        }
+    }
+
+    fn public(key: &ecdsa::SigningKey<P256>) -> ecdsa::VerifyingKey<P256> {
+        ecdsa::VerifyingKey::from_x962_uncompressed(&key.private_key.public_key_uncompressed())
+            .unwrap()
+    }
+
+    #[test]
+    fn rfc9052_appendix_c21() {
+        let message = unhex(concat!(
+            "d28443a10126a10442313154546869732069732074686520636f6e74656e742e",
+            "58408eb33e4ca31d1c465ab05aac34cc6b23d58fef5c083106c4d25a91aef0b0",
+            "117e2af9a291aa32e14ab834dc56ed2a223444547e01f11d3b0916e5a4c345ca",
+            "cb36",
+        ));
+        let sign1 = CoseSign1::decode(&message).unwrap();
+        assert_eq!(sign1.algorithm(), CoseAlgorithm::Es256);
+        assert_eq!(sign1.key_id(), Some(&b"11"[..]));
+
+        let public_key = public(&rfc9052_p256());
+        assert_eq!(
+            sign1
+                .verify(CoseVerifyingKey::EcdsaP256(&public_key), b"")
+                .unwrap(),
+            b"This is the content."
+        );
+        assert_eq!(
+            sign1
+                .verify(CoseVerifyingKey::EcdsaP256(&public_key), b"aad")
+                .err(),
+            Some(Error::BadSignature)
+        );
+
+        // untagged
+        let sign1 = CoseSign1::decode(&message[1..]).unwrap();
+        assert!(
+            sign1
+                .verify(CoseVerifyingKey::EcdsaP256(&public_key), b"")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn ed25519_known_answer() {
+        // made by python cryptography
+        let expected = unhex(concat!(
+            "d28443a10127a10442313154546869732069732074686520636f6e74656e742e",
+            "5840ee559b1cc7904df9a98b6b3223da612a3450a520d3e839495666a77bd4c7",
+            "fcd319c2ddfba406da7553797d32bc0ff92ff0e2d3b183170a337a7fbceb6f8f",
+            "4a04",
+        ));
+        let key = ed25519::SigningKey::from_seed(&[1; 32]);
+        assert_eq!(
+            CoseSigningKey::Ed25519(&key)
+                .sign1(Some(b"11"), b"", b"This is the content.")
+                .unwrap(),
+            expected
+        );
+
+        let verifying_key = key.verifying_key();
+        let sign1 = CoseSign1::decode(&expected).unwrap();
+        assert_eq!(sign1.algorithm(), CoseAlgorithm::EdDsa);
+        assert_eq!(
+            sign1
+                .verify(CoseVerifyingKey::Ed25519(&verifying_key), b"")
+                .unwrap(),
+            b"This is the content."
+        );
+    }
+
+    #[test]
+    fn sign1_round_trip() {
+        let p256 = rfc9052_p256();
+        let p256_public = public(&p256);
+        let ed25519 = ed25519::SigningKey::from_seed(&[1; 32]);
+        let ed25519_public = ed25519.verifying_key();
+
+        for (signing_key, verifying_key, wrong_key) in [
+            (
+                CoseSigningKey::EcdsaP256(&p256),
+                CoseVerifyingKey::EcdsaP256(&p256_public),
+                CoseVerifyingKey::Ed25519(&ed25519_public),
+            ),
+            (
+                CoseSigningKey::Ed25519(&ed25519),
+                CoseVerifyingKey::Ed25519(&ed25519_public),
+                CoseVerifyingKey::EcdsaP256(&p256_public),
+            ),
+        ] {
+            let message = signing_key.sign1(None, b"aad", b"payload").unwrap();
+            let sign1 = CoseSign1::decode(&message).unwrap();
+            assert_eq!(sign1.algorithm(), signing_key.algorithm());
+            assert_eq!(sign1.key_id(), None);
+            assert_eq!(sign1.verify(verifying_key, b"aad").unwrap(), b"payload");
+            assert_eq!(
+                sign1.verify(verifying_key, b"").err(),
+                Some(Error::BadSignature)
+            );
+            assert_eq!(
+                sign1.verify(wrong_key, b"aad").err(),
+                Some(KeyFormatError::MismatchedCoseAlgorithm.into())
+            );
+
+            // the payload is the last byte string before the signature
+            let mut altered = message.clone();
+            let index = altered.len() - 67;
+            altered[index] ^= 1;
+            assert_eq!(
+                CoseSign1::decode(&altered)
+                    .unwrap()
+                    .verify(verifying_key, b"aad")
+                    .err(),
+                Some(Error::BadSignature)
+            );
+        }
+    }
+
+    #[test]
+    fn encrypt0_known_answers() {
+        // made by python cryptography
+        for (key, message) in [
+            (
+                CoseEncryptionKey::A128Gcm(&[1; 16]),
+                concat!(
+                    "d08343a10101a204423131054c0202020202020202020202025824078c7910e6",
+                    "47355ce3886b14d18e142f3e233d13c024caf43a85825c39a74575002eb60b",
+                ),
+            ),
+            (
+                CoseEncryptionKey::ChaCha20Poly1305(&[3; 32]),
+                concat!(
+                    "d08344a1011818a204423131054c020202020202020202020202582",
+                    "4bd5fde77132d3c9d1309369f0530f8a3f6c35a77cde69f7f792c37f1ed6c6c",
+                    "2ff5986f12",
+                ),
+            ),
+        ] {
+            let message = unhex(message);
+            let encrypt0 = CoseEncrypt0::decode(&message).unwrap();
+            assert_eq!(encrypt0.algorithm(), key.algorithm());
+            assert_eq!(encrypt0.key_id(), Some(&b"11"[..]));
+            assert_eq!(encrypt0.decrypt(key, b"").unwrap(), b"This is the content.");
+            assert_eq!(
+                encrypt0.decrypt(key, b"aad").err(),
+                Some(Error::DecryptFailed)
+            );
+        }
+    }
+
+    #[test]
+    fn encrypt0_round_trip() {
+        let aes = [1; 16];
+        let chacha = [3; 32];
+        for (key, wrong_key) in [
+            (
+                CoseEncryptionKey::A128Gcm(&aes),
+                CoseEncryptionKey::ChaCha20Poly1305(&chacha),
+            ),
+            (
+                CoseEncryptionKey::ChaCha20Poly1305(&chacha),
+                CoseEncryptionKey::A128Gcm(&aes),
+            ),
+        ] {
+            let message = key.encrypt0(Some(b"kid"), b"aad", b"hello").unwrap();
+            assert_ne!(
+                key.encrypt0(Some(b"kid"), b"aad", b"hello").unwrap(),
+                message
+            );
+            assert_eq!(
+                key.encrypt0_with_rng(Some(b"kid"), b"aad", b"hello", &mut TestRng::new(b"seed"))
+                    .unwrap(),
+                key.encrypt0_with_rng(Some(b"kid"), b"aad", b"hello", &mut TestRng::new(b"seed"))
+                    .unwrap(),
+            );
+
+            let encrypt0 = CoseEncrypt0::decode(&message).unwrap();
+            assert_eq!(encrypt0.key_id(), Some(&b"kid"[..]));
+            assert_eq!(encrypt0.decrypt(key, b"aad").unwrap(), b"hello");
+            assert_eq!(
+                encrypt0.decrypt(wrong_key, b"aad").err(),
+                Some(KeyFormatError::MismatchedCoseAlgorithm.into())
+            );
+
+            let mut altered = message.clone();
+            *altered.last_mut().unwrap() ^= 1;
+            assert_eq!(
+                CoseEncrypt0::decode(&altered)
+                    .unwrap()
+                    .decrypt(key, b"aad")
+                    .err(),
+                Some(Error::DecryptFailed)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_messages() {
+        let kid = (Cbor::from_int(LABEL_KID), Cbor::Bytes(b"11"));
+        let signature = Cbor::Bytes(&[0; 64]);
+        let sign1 = |protected: &[u8], unprotected: Vec<(Cbor<'static>, Cbor<'static>)>| {
+            encode_message(
+                TAG_SIGN1,
+                vec![
+                    Cbor::Bytes(protected),
+                    Cbor::Map(unprotected),
+                    Cbor::Bytes(b"payload"),
+                    Cbor::Bytes(&[0; 64]),
+                ],
+            )
+        };
+        let es256 = encode_protected(CoseAlgorithm::Es256);
+        assert!(CoseSign1::decode(&sign1(&es256, vec![])).is_ok());
+
+        let alg_crit = Cbor::Map(vec![
+            (Cbor::from_int(LABEL_ALG), Cbor::from_int(-7)),
+            (Cbor::from_int(LABEL_CRIT), Cbor::Array(vec![Cbor::Uint(4)])),
+        ])
+        .to_vec();
+        let alg_kid_text = Cbor::Map(vec![
+            (Cbor::from_int(LABEL_ALG), Cbor::from_int(-7)),
+            (Cbor::from_int(LABEL_KID), Cbor::Text("11")),
+        ])
+        .to_vec();
+
+        for message in [
+            vec![],
+            // not an array, wrong tag, wrong length
+            Cbor::Map(vec![]).to_vec(),
+            encode_message(TAG_ENCRYPT0, vec![]),
+            encode_message(
+                TAG_SIGN1,
+                vec![Cbor::Bytes(&es256), Cbor::Map(vec![]), Cbor::Bytes(b"")],
+            ),
+            // detached payload
+            encode_message(
+                TAG_SIGN1,
+                vec![
+                    Cbor::Bytes(&es256),
+                    Cbor::Map(vec![]),
+                    Cbor::Null,
+                    signature,
+                ],
+            ),
+            // protected header not a map, or missing algorithm
+            sign1(&Cbor::Array(vec![]).to_vec(), vec![]),
+            sign1(b"", vec![]),
+            sign1(&Cbor::Map(vec![]).to_vec(), vec![]),
+            // algorithm not protected
+            sign1(b"", vec![(Cbor::from_int(LABEL_ALG), Cbor::from_int(-7))]),
+            // repeated label
+            sign1(
+                &alg_kid_text,
+                vec![(Cbor::from_int(LABEL_KID), Cbor::Bytes(b"11"))],
+            ),
+            // wrong type
+            sign1(&alg_kid_text, vec![]),
+            sign1(&alg_crit, vec![]),
+            // trailing data
+            [sign1(&es256, vec![kid]), vec![0]].concat(),
+        ] {
+            assert_eq!(
+                CoseSign1::decode(&message).err(),
+                Some(invalid()),
+                "{message:02x?}"
+            );
+        }
+
+        let a128gcm = encode_protected(CoseAlgorithm::A128Gcm);
+        let es384 = Cbor::Map(vec![(Cbor::from_int(LABEL_ALG), Cbor::from_int(-35))]).to_vec();
+        let text_alg = Cbor::Map(vec![(Cbor::from_int(LABEL_ALG), Cbor::Text("ES256"))]).to_vec();
+        for message in [
+            sign1(&a128gcm, vec![]),
+            sign1(&es384, vec![]),
+            sign1(&text_alg, vec![]),
+            sign1(
+                &es256,
+                vec![(Cbor::from_int(LABEL_PARTIAL_IV), Cbor::Bytes(b"1"))],
+            ),
+        ] {
+            assert_eq!(
+                CoseSign1::decode(&message).err(),
+                Some(KeyFormatError::UnsupportedCoseAlgorithm.into())
+            );
+        }
+
+        let encrypt0 = |unprotected: Vec<(Cbor<'static>, Cbor<'static>)>, ciphertext: &[u8]| {
+            encode_message(
+                TAG_ENCRYPT0,
+                vec![
+                    Cbor::Bytes(&a128gcm),
+                    Cbor::Map(unprotected),
+                    Cbor::Bytes(ciphertext),
+                ],
+            )
+        };
+        let iv = |len: usize| vec![(Cbor::from_int(LABEL_IV), Cbor::Bytes(&[0; 16][..len]))];
+        assert!(CoseEncrypt0::decode(&encrypt0(iv(12), &[0; 16])).is_ok());
+        for message in [
+            encrypt0(vec![], &[0; 16]),
+            encrypt0(iv(11), &[0; 16]),
+            encrypt0(iv(16), &[0; 16]),
+            encrypt0(iv(12), &[0; 15]),
+        ] {
+            assert_eq!(CoseEncrypt0::decode(&message).err(), Some(invalid()));
+        }
+        assert_eq!(
+            CoseEncrypt0::decode(&sign1(&es256, vec![])).err(),
+            Some(invalid())
+        );
+    }
+}
@@ -21,6 +21,12 @@ pub trait Curve: private::Sealed {
     /// The curve's OID when used in PKCS#8 key formats.
     fn oid() -> asn1::ObjectId;
 
+    /// The curve's name when used in JWK key formats.
+    fn jwk_crv() -> &'static str;
+
+    /// The curve's name when used in OpenSSH key formats.
+    fn ssh_curve() -> &'static str;
+
     /// Generate a random `PrivateKey`
     fn generate_random_key(rng: &mut dyn RandomSource) -> Result<Self::PrivateKey, Error>;
 }
@@ -80,8 +86,16 @@ pub trait PublicKey<C: Curve + ?Sized> {
     where
         Self: Sized;
 
+    /// Output the uncompressed x9.62 encoding of this point.
+    ///
+    /// The return value is the written prefix of `out`.
+    fn encode_uncompressed<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error>;
+
     /// Raw ECDSA verification primitive.
     fn raw_ecdsa_verify(&self, r: &C::Scalar, s: &C::Scalar, e: &C::Scalar) -> Result<(), Error>;
+
+    /// Spend time and memory to speed up later `raw_ecdsa_verify` calls.
+    fn precompute(&mut self);
 }
 
 /// A generic elliptic curve scalar, on curve `C`.
@@ -141,6 +155,14 @@ impl Curve for P256 {
         asn1::oid::id_prime256v1.clone()
     }
 
+    fn jwk_crv() -> &'static str {
+        "P-256"
+    }
+
+    fn ssh_curve() -> &'static str {
+        "nistp256"
+    }
+
     fn generate_random_key(rng: &mut dyn RandomSource) -> Result<p256::StaticPrivateKey, Error> {
         p256::StaticPrivateKey::generate(rng)
     }
@@ -187,6 +209,15 @@ impl PublicKey<P256> for p256::PublicKey {
         Self::from_x962_uncompressed(bytes)
     }
 
+    fn encode_uncompressed<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        if let Some(out) = out.get_mut(0..Self::BYTES) {
+            out.copy_from_slice(&self.as_bytes_uncompressed());
+            Ok(out)
+        } else {
+            Err(Error::OutOfRange)
+        }
+    }
+
     fn raw_ecdsa_verify(
         &self,
         r: &p256::Scalar,
@@ -195,6 +226,10 @@ impl PublicKey<P256> for p256::PublicKey {
     ) -> Result<(), Error> {
         self.raw_ecdsa_verify(r, s, e)
     }
+
+    fn precompute(&mut self) {
+        self.precompute()
+    }
 }
 
 impl Scalar<P256> for p256::Scalar {
@@ -233,6 +268,14 @@ impl Curve for P384 {
         asn1::oid::secp384r1.clone()
     }
 
+    fn jwk_crv() -> &'static str {
+        "P-384"
+    }
+
+    fn ssh_curve() -> &'static str {
+        "nistp384"
+    }
+
     fn generate_random_key(rng: &mut dyn RandomSource) -> Result<p384::StaticPrivateKey, Error> {
         p384::StaticPrivateKey::generate(rng)
     }
@@ -279,6 +322,15 @@ impl PublicKey<P384> for p384::PublicKey {
         Self::from_x962_uncompressed(bytes)
     }
 
+    fn encode_uncompressed<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        if let Some(out) = out.get_mut(0..Self::BYTES) {
+            out.copy_from_slice(&self.as_bytes_uncompressed());
+            Ok(out)
+        } else {
+            Err(Error::OutOfRange)
+        }
+    }
+
     fn raw_ecdsa_verify(
         &self,
         r: &p384::Scalar,
@@ -287,6 +339,10 @@ impl PublicKey<P384> for p384::PublicKey {
     ) -> Result<(), Error> {
         self.raw_ecdsa_verify(r, s, e)
     }
+
+    fn precompute(&mut self) {
+        // no larger tables are implemented for P-384
+    }
 }
 
 impl Scalar<P384> for p384::Scalar {
@@ -0,0 +1,368 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! KDF chains for the Double Ratchet algorithm.
+//!
+//! These are the root, sending and receiving chains from the
+//! [Double Ratchet](https://signal.org/docs/specifications/doubleratchet/)
+//! specification, using the recommended HKDF and HMAC functions
+//! (section 5.2).
+//!
+//! The ratchet state machine, header encoding, storage of skipped
+//! message keys and message encryption are left to the caller.
+//!
+//! ```
+//! use graviola::hashing::Sha256;
+//! use graviola::key_agreement::double_ratchet::*;
+//! use graviola::key_agreement::x25519::StaticPrivateKey;
+//!
+//! // `SK` from X3DH, or similar
+//! let shared_key = [0u8; 32];
+//! let mut root = RootKey::<Sha256>::new(&shared_key);
+//!
+//! let ours = StaticPrivateKey::new_random().unwrap();
+//! let theirs = StaticPrivateKey::new_random().unwrap().public_key();
+//! let mut sending = root.ratchet_dh(&ours, &theirs, b"MyProtocol").unwrap();
+//!
+//! let message_key = sending.next_message_key().unwrap();
+//! let mut keys = [0u8; 80];
+//! message_key.derive(b"MyProtocolKeys", &mut keys).unwrap();
+//! ```
+
+use core::marker::PhantomData;
+
+use super::hash::Hash;
+use super::hkdf;
+use super::hmac::Hmac;
+use crate::Error;
+use crate::low;
+use crate::mid::x25519::{PublicKey, StaticPrivateKey};
+
+/// A root key, `RK`.
+///
+/// Each DH ratchet step mixes a new DH output into this key,
+/// yielding a new chain key.
+pub struct RootKey<H: Hash + Clone> {
+    key: [u8; 32],
+    _h: PhantomData<H>,
+}
+
+impl<H: Hash + Clone> RootKey<H> {
+    /// Use `key` as a root key.
+    ///
+    /// This is the shared secret from the initial key agreement, or
+    /// a previously saved value of [`RootKey::as_bytes`].
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self {
+            key: *key,
+            _h: PhantomData,
+        }
+    }
+
+    /// Extract the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// `KDF_RK(rk, dh_out)`: update this root key with `dh_output`, and
+    /// return a new chain key.
+    ///
+    /// This is HKDF with this key as the salt, `dh_output` as the
+    /// input keying material and application-specific `info`.
+    pub fn ratchet(&mut self, dh_output: &[u8], info: &[u8]) -> ChainKey<H> {
+        let mut okm = [0u8; 64];
+        // cannot fail: 64 bytes is well within the HKDF output limit
+        hkdf::hkdf::<H>(&self.key, dh_output, info, &mut okm).unwrap();
+
+        self.key.copy_from_slice(&okm[..32]);
+        let chain = ChainKey::new(okm[32..].try_into().unwrap(), 0);
+        low::zeroise(&mut okm);
+        chain
+    }
+
+    /// Compute `DH(ours, theirs)` and then [`RootKey::ratchet`] with it.
+    ///
+    /// Fails if `theirs` is a low order point.
+    pub fn ratchet_dh(
+        &mut self,
+        ours: &StaticPrivateKey,
+        theirs: &PublicKey,
+        info: &[u8],
+    ) -> Result<ChainKey<H>, Error> {
+        let dh_output = ours.diffie_hellman(theirs)?;
+        Ok(self.ratchet(&dh_output.0, info))
+    }
+}
+
+impl<H: Hash + Clone> Drop for RootKey<H> {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.key);
+    }
+}
+
+/// A sending or receiving chain key, `CK`.
+///
+/// This also tracks the number of message keys derived from the chain
+/// so far, as `N` in the specification.
+pub struct ChainKey<H: Hash + Clone> {
+    key: [u8; 32],
+    index: u32,
+    _h: PhantomData<H>,
+}
+
+impl<H: Hash + Clone> ChainKey<H> {
+    /// Use `key` as a chain key, which has already produced `index`
+    /// message keys.
+    ///
+    /// This is for restoring previously saved state: new chains come
+    /// from [`RootKey::ratchet`].
+    pub fn new(key: &[u8; 32], index: u32) -> Self {
+        Self {
+            key: *key,
+            index,
+            _h: PhantomData,
+        }
+    }
+
+    /// Extract the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// The number of message keys derived from this chain so far.
+    ///
+    /// This is the index of the next message key.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// `KDF_CK(ck)`: advance this chain, returning the next message key.
+    ///
+    /// The message key is `HMAC(CK, 0x01)` and the new chain key is
+    /// `HMAC(CK, 0x02)`.  For hash functions whose output is longer than
+    /// 32 bytes, these are truncated to 32 bytes.
+    ///
+    /// Fails with [`Error::OutOfRange`] once `u32::MAX` keys have
+    /// been derived.
+    pub fn next_message_key(&mut self) -> Result<MessageKey<H>, Error> {
+        let next_index = self.index.checked_add(1).ok_or(Error::OutOfRange)?;
+
+        let mut key = self.derive(0x01);
+        let message_key = MessageKey::new(&key, self.index);
+        low::zeroise(&mut key);
+
+        self.key = self.derive(0x02);
+        self.index = next_index;
+        Ok(message_key)
+    }
+
+    /// Advance this chain until its index is `index`, returning each
+    /// message key skipped over.
+    ///
+    /// This is for handling out-of-order messages.  `index` must not
+    /// be behind the current index, and may be at most `max_skip` ahead,
+    /// otherwise [`Error::OutOfRange`] is returned and the chain is unchanged.
+    ///
+    /// The chain advances as the iterator is consumed.
+    pub fn skip_to(
+        &mut self,
+        index: u32,
+        max_skip: u32,
+    ) -> Result<impl Iterator<Item = MessageKey<H>> + '_, Error> {
+        match index.checked_sub(self.index) {
+            Some(skip) if skip <= max_skip => {}
+            _ => return Err(Error::OutOfRange),
+        }
+
+        // cannot fail: `index` has already been checked
+        Ok(core::iter::from_fn(move || {
+            (self.index < index).then(|| self.next_message_key().unwrap())
+        }))
+    }
+
+    fn derive(&self, constant: u8) -> [u8; 32] {
+        let mut hmac = Hmac::<H>::new(self.key);
+        hmac.update([constant]);
+        let mut tag = hmac.finish();
+        let mut r = [0u8; 32];
+        r.copy_from_slice(&tag.as_ref()[..32]);
+        low::zeroise(tag.as_mut());
+        r
+    }
+}
+
+impl<H: Hash + Clone> Drop for ChainKey<H> {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.key);
+    }
+}
+
+/// A message key, `MK`.
+///
+/// Each message key should be used to encrypt exactly one message.
+pub struct MessageKey<H: Hash + Clone> {
+    key: [u8; 32],
+    index: u32,
+    _h: PhantomData<H>,
+}
+
+impl<H: Hash + Clone> MessageKey<H> {
+    /// Use `key` as the message key at position `index` in its chain.
+    ///
+    /// This is for restoring previously saved (skipped) message keys:
+    /// new message keys come from [`ChainKey::next_message_key`].
+    pub fn new(key: &[u8; 32], index: u32) -> Self {
+        Self {
+            key: *key,
+            index,
+            _h: PhantomData,
+        }
+    }
+
+    /// Extract the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// The position of this message key in its chain, as `N` in
+    /// the specification.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Derive keys for message encryption from this message key.
+    ///
+    /// This is HKDF with a zero salt, this key as the input keying
+    /// material, and application-specific `info`.  The specification
+    /// suggests an 80-byte `out`, split into a 32-byte encryption key,
+    /// a 32-byte authentication key and a 16-byte IV.
+    ///
+    /// `out` is limited to 255 times the hash function output length,
+    /// otherwise [`Error::WrongLength`] is returned.
+    pub fn derive(&self, info: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        hkdf::extract::<H>(&[], &[&self.key]).expand(&[info], out)
+    }
+}
+
+impl<H: Hash + Clone> Drop for MessageKey<H> {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::{Sha256, Sha512};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn chains() {
+        // produced with python's `cryptography`, `hmac` and `hashlib`
+        let rk: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let mut root = RootKey::<Sha256>::new(&rk);
+        let mut chain = root
+            .ratchet_dh(
+                &StaticPrivateKey::from_array(&[6; 32]),
+                &StaticPrivateKey::from_array(&[7; 32]).public_key(),
+                b"ratchet test",
+            )
+            .unwrap();
+        assert_eq!(
+            hex(root.as_bytes()),
+            "420627cb8fc6eb67f3a378d9f5d59e020c1fe572953124cdc32868a7c9817f27"
+        );
+        assert_eq!(
+            hex(chain.as_bytes()),
+            "2099e04b12db46d29aa2760cc180a4edd640c04e631d3d4b2edceefdbe3f6e7d"
+        );
+        assert_eq!(chain.index(), 0);
+
+        let mk0 = chain.next_message_key().unwrap();
+        assert_eq!(mk0.index(), 0);
+        assert_eq!(
+            hex(mk0.as_bytes()),
+            "1f7116c0c824a586a96d73ec2a2c4ca28a9f8bf35e5c1d7a8d0418fa45251713"
+        );
+        let mk1 = chain.next_message_key().unwrap();
+        assert_eq!(mk1.index(), 1);
+        assert_eq!(
+            hex(mk1.as_bytes()),
+            "e89698a5d84ade1279a65427d19db99ac0a9dd76e76196813b61dbbe5e15db98"
+        );
+        assert_eq!(chain.index(), 2);
+        assert_eq!(
+            hex(chain.as_bytes()),
+            "3761530eb553a5f22de3e948696512afee57ca3d4f8c802f85d2f779f3ebf7e0"
+        );
+
+        let mut keys = [0u8; 80];
+        mk1.derive(b"message keys", &mut keys).unwrap();
+        assert_eq!(
+            hex(&keys),
+            "3e2a29041dee48c68d155f16f01f90dbe814b11d12894019347b6f40849154c4\
+             2e386fae05c2a864ded939a57c2d9493f0ace8f3b16b924f1a6e995bbd057085\
+             4a74030a9d459e7b3d69773847347d3d"
+        );
+    }
+
+    #[test]
+    fn truncates_long_hashes() {
+        let ck: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let mut chain = ChainKey::<Sha512>::new(&ck, 0);
+        let mk = chain.next_message_key().unwrap();
+        assert_eq!(
+            hex(mk.as_bytes()),
+            "a5df768b23b9d396d5a65528c4b4cd896a50f068f1612236ee43350bd287a5be"
+        );
+        assert_eq!(
+            hex(chain.as_bytes()),
+            "7b11e28df98a5964a47acb3172a2b9f502c81eacfd69ceeae011832234a63b37"
+        );
+    }
+
+    #[test]
+    fn skipping() {
+        let mut a = ChainKey::<Sha256>::new(&[1; 32], 5);
+        let mut b = ChainKey::<Sha256>::new(&[1; 32], 5);
+
+        assert!(a.skip_to(4, 10).is_err());
+        assert!(a.skip_to(16, 10).is_err());
+        assert_eq!(a.index(), 5);
+
+        let skipped: Vec<_> = a.skip_to(8, 10).unwrap().collect();
+        assert_eq!(skipped.len(), 3);
+        for mk in skipped {
+            let expected = b.next_message_key().unwrap();
+            assert_eq!(mk.index(), expected.index());
+            assert_eq!(mk.as_bytes(), expected.as_bytes());
+        }
+        assert_eq!(a.index(), 8);
+        assert_eq!(a.as_bytes(), b.as_bytes());
+        assert_eq!(a.skip_to(8, 0).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn exhaustion() {
+        let mut chain = ChainKey::<Sha256>::new(&[1; 32], u32::MAX - 1);
+        assert_eq!(chain.next_message_key().unwrap().index(), u32::MAX - 1);
+        assert!(matches!(chain.next_message_key(), Err(Error::OutOfRange)));
+    }
+
+    #[test]
+    fn rejects_low_order_points() {
+        let mut root = RootKey::<Sha256>::new(&[0; 32]);
+        assert!(
+            root.ratchet_dh(
+                &StaticPrivateKey::from_array(&[6; 32]),
+                &PublicKey::from_array(&[0; 32]),
+                b""
+            )
+            .is_err()
+        );
+        assert_eq!(root.as_bytes(), &[0; 32]);
+    }
+}
@@ -0,0 +1,438 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! DSA signature verification, as specified in
+//! [FIPS186-4](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.186-4.pdf)
+//! section 4.
+//!
+//! DSA was withdrawn in FIPS186-5, and this exists only for validating
+//! existing signatures.  Signing is not supported.
+
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::high::asn1::{Integer, Type, oid, pkix};
+use crate::high::hash::{Hash, HashContext};
+use crate::high::spki;
+use crate::low::{Entry, PosInt};
+use crate::mid::modp::{Element, PrimeModulus};
+
+/// A DSA verification public key.
+///
+/// Only the FIPS186-4 parameter sets with a 256-bit `q`, and a 2048- or
+/// 3072-bit `p` are supported.
+pub struct VerifyingKey {
+    p: PrimeModulus,
+    q: Order,
+    g: Element,
+    y: Element,
+}
+
+impl VerifyingKey {
+    /// Decodes a DSA public key from X.509 `SubjectPublicKeyInfo` DER format.
+    ///
+    /// This format is defined in
+    /// [RFC3279](https://datatracker.ietf.org/doc/html/rfc3279#section-2.3.2).
+    /// The domain parameters must be present.
+    ///
+    /// The parameters and public key are validated: `g` and `y` must be
+    /// in the subgroup of order `q`.  Unsupported parameter sets are
+    /// rejected with [`Error::OutOfRange`], and invalid keys with
+    /// [`Error::NotOnCurve`].
+    pub fn from_spki_der(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        let spki = pkix::DsaSubjectPublicKeyInfo::from_bytes(bytes).map_err(Error::Asn1Error)?;
+        if spki.algorithm.algorithm != oid::id_dsa {
+            return Err(KeyFormatError::MismatchedSpkiAlgorithm.into());
+        }
+
+        let y = Integer::from_bytes(spki.subjectPublicKey.as_octets()).map_err(Error::Asn1Error)?;
+        let params = &spki.algorithm.parameters;
+        Self::new(
+            positive(&params.p)?,
+            positive(&params.q)?,
+            positive(&params.g)?,
+            positive(&y)?,
+        )
+    }
+
+    /// Decodes a DSA public key from PEM format.
+    ///
+    /// The first `PUBLIC KEY` document is used; see [`Self::from_spki_der()`].
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        spki::decode_spki_pem(pem, Self::from_spki_der)
+    }
+
+    fn new(p: &[u8], q: &[u8], g: &[u8], y: &[u8]) -> Result<Self, Error> {
+        if !(p.len() == 256 || p.len() == 384) || p[0] & 0x80 == 0 {
+            return Err(Error::OutOfRange);
+        }
+        let p = PrimeModulus::new(p)?;
+        let q = Order::new(q)?;
+
+        let mut q_bytes = [0u8; Q_LEN];
+        q.q.to_bytes(&mut q_bytes)?;
+        let one = p.decode(&[1])?;
+
+        // g and y must be in [2, p - 1], and be in the subgroup of order q.
+        // (so the subgroup check excludes 0 and p - 1.)
+        let in_subgroup = |value: &[u8]| -> Result<Element, Error> {
+            let e = p.decode(value).map_err(|_| Error::NotOnCurve)?;
+            if e.ct_equal(&one) || !p.exp_public(&e, &q_bytes)?.ct_equal(&one) {
+                return Err(Error::NotOnCurve);
+            }
+            Ok(e)
+        };
+        let g = in_subgroup(g)?;
+        let y = in_subgroup(y)?;
+
+        Ok(Self { p, q, g, y })
+    }
+
+    /// Verify a DSA fixed-length signature.
+    ///
+    /// This is the 32-byte big-endian `r` followed by the 32-byte big-endian `s`.
+    ///
+    /// The `message` is hashed with `H`.  The message is presented as a sequence of byte
+    /// slices (effectively concatenated by this function).
+    ///
+    /// Returns `Ok(())` when the signature is valid, or an error if not (typically --
+    /// but not limited to -- `Error::BadSignature`).
+    pub fn verify<H: Hash>(&self, message: &[&[u8]], signature: &[u8]) -> Result<(), Error> {
+        let _entry = Entry::new_public();
+        if signature.len() != Q_LEN * 2 {
+            return Err(Error::WrongLength);
+        }
+
+        // 1. reject the signature if either 0 < r < q or 0 < s < q is not satisfied.
+        let r = self
+            .q
+            .decode_checked(&signature[..Q_LEN])
+            .map_err(|_| Error::BadSignature)?;
+        let s = self
+            .q
+            .decode_checked(&signature[Q_LEN..])
+            .map_err(|_| Error::BadSignature)?;
+
+        let mut ctx = H::new();
+        for m in message {
+            ctx.update(m);
+        }
+        let hash = ctx.finish();
+        let hash = hash.as_ref();
+
+        // 2. w = s^-1 mod q
+        let w = self.q.inverse(&s);
+
+        // 3. z = the leftmost min(N, outlen) bits of Hash(M)
+        let z = self.q.reduce(&hash[..hash.len().min(Q_LEN)])?;
+
+        // 4. u1 = (zw) mod q, u2 = ((r)w) mod q
+        let mut u1 = [0u8; Q_LEN];
+        self.q.mul(&z, &w).to_bytes(&mut u1)?;
+        let mut u2 = [0u8; Q_LEN];
+        self.q.mul(&r, &w).to_bytes(&mut u2)?;
+
+        // 5. v = (((g)^u1 (y)^u2) mod p) mod q
+        let v = self.p.mul(
+            &self.p.exp_public(&self.g, &u1)?,
+            &self.p.exp_public(&self.y, &u2)?,
+        );
+        let mut v_bytes = [0u8; MAX_P_LEN];
+        let v_bytes = &mut v_bytes[..self.p.len_bytes()];
+        self.p.encode(&v, v_bytes)?;
+        let v = self.q.reduce(v_bytes)?;
+
+        // 6. if v = r, then the signature is verified.
+        match v.equals(&r) {
+            true => Ok(()),
+            false => Err(Error::BadSignature),
+        }
+    }
+
+    /// Verify a DSA ASN.1-encoded signature.
+    ///
+    /// This does a straightforward conversion from ASN.1 to fixed length,
+    /// and then calls [`Self::verify()`] -- see the documentation for more.
+    pub fn verify_asn1<H: Hash>(&self, message: &[&[u8]], signature: &[u8]) -> Result<(), Error> {
+        let _entry = Entry::new_public();
+        // Dss-Sig-Value has the same structure as ECDSA-Sig-Value
+        let sig = pkix::EcdsaSigValue::from_bytes(signature).map_err(|_| Error::BadSignature)?;
+        let r = positive(&sig.r).map_err(|_| Error::BadSignature)?;
+        let s = positive(&sig.s).map_err(|_| Error::BadSignature)?;
+        if r.len() > Q_LEN || s.len() > Q_LEN {
+            return Err(Error::BadSignature);
+        }
+
+        let mut fixed = [0u8; Q_LEN * 2];
+        fixed[Q_LEN - r.len()..Q_LEN].copy_from_slice(r);
+        fixed[Q_LEN * 2 - s.len()..].copy_from_slice(s);
+        self.verify::<H>(message, &fixed)
+    }
+}
+
+/// Arithmetic modulo the 256-bit prime `q`.
+struct Order {
+    q: Scalar,
+    montifier: Scalar,
+    q0: u64,
+    q_minus_2: Scalar,
+}
+
+impl Order {
+    fn new(q: &[u8]) -> Result<Self, Error> {
+        if q.len() != Q_LEN || q[0] & 0x80 == 0 {
+            return Err(Error::OutOfRange);
+        }
+        let q = Scalar::from_bytes(q)?;
+        if q.is_even() {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut zero = Scalar::zero();
+        zero.expand(&q);
+        let two = Self::expand(Scalar::from_bytes(&[2])?, &q);
+        Ok(Self {
+            montifier: q.montifier(),
+            q0: q.mont_neg_inverse(),
+            q_minus_2: zero.sub_mod(&two, &q),
+            q,
+        })
+    }
+
+    /// Decode `bytes`, which must be in `[1, q)`.
+    fn decode_checked(&self, bytes: &[u8]) -> Result<Scalar, Error> {
+        let s = Scalar::from_bytes(bytes)?;
+        if s.len_bytes() == 0 || !s.less_than(&self.q) {
+            return Err(Error::OutOfRange);
+        }
+        Ok(Self::expand(s, &self.q))
+    }
+
+    /// Reduce big-endian `bytes` of any length.
+    fn reduce(&self, bytes: &[u8]) -> Result<Scalar, Error> {
+        // process in `Q_LEN` chunks from the most significant end, each
+        // time computing `acc * 2^256 + chunk`, which is less than `q * 2^256`.
+        let first = match bytes.len() % Q_LEN {
+            0 => Q_LEN.min(bytes.len()),
+            n => n,
+        };
+        let (first, rest) = bytes.split_at(first);
+
+        let mut acc = [0u8; Q_LEN];
+        let mut wide = [0u8; Q_LEN * 2];
+        for chunk in core::iter::once(first).chain(rest.chunks(Q_LEN)) {
+            wide[..Q_LEN].copy_from_slice(&acc);
+            wide[Q_LEN..].fill(0);
+            wide[Q_LEN * 2 - chunk.len()..].copy_from_slice(chunk);
+            PosInt::<{ Q_WORDS * 2 }>::from_bytes(&wide)?
+                .reduce(&self.q, &self.montifier)
+                .to_bytes(&mut acc)?;
+        }
+
+        Ok(Self::expand(Scalar::from_bytes(&acc)?, &self.q))
+    }
+
+    /// Returns `a` * `b` mod q.
+    fn mul(&self, a: &Scalar, b: &Scalar) -> Scalar {
+        a.to_montgomery(&self.montifier, &self.q)
+            .mont_mul(b, &self.q, self.q0)
+    }
+
+    /// Returns `a` ^ -1 mod q, by Fermat's little theorem.
+    fn inverse(&self, a: &Scalar) -> Scalar {
+        a.mont_exp(&self.q_minus_2, &self.q, &self.montifier, self.q0)
+    }
+
+    fn expand(mut s: Scalar, q: &Scalar) -> Scalar {
+        s.expand(q);
+        s
+    }
+}
+
+/// Returns the value of a non-negative `Integer`, without leading zeros.
+fn positive<'a>(i: &'a Integer<'_>) -> Result<&'a [u8], Error> {
+    if i.is_negative() {
+        return Err(Error::OutOfRange);
+    }
+    let mut bytes = i.as_ref();
+    while let Some((0, rest)) = bytes.split_first() {
+        bytes = rest;
+    }
+    Ok(bytes)
+}
+
+/// Length of `q`.
+const Q_LEN: usize = 32;
+const Q_WORDS: usize = Q_LEN / 8;
+
+/// Length of the largest supported `p`.
+const MAX_P_LEN: usize = 384;
+
+type Scalar = PosInt<Q_WORDS>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::{Sha256, Sha384, Sha512};
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn dsa2048_256() {
+        // signatures from an independent implementation
+        let key = VerifyingKey::from_spki_der(include_bytes!("dsa/dsa2048.spki.der")).unwrap();
+        let sig = unhex(
+            "3046022100c17f501c9ec3268e155e75dbd8bc1a3dbf57ef840c7696f6298ac08474e31f6c022100a56e9ae991e7c97d8330c2fba7807962d2363f9b366a3e4dfb1365d1fd5d9417",
+        );
+        key.verify_asn1::<Sha256>(&[b"hello world"], &sig).unwrap();
+        key.verify_asn1::<Sha256>(&[b"hello", b" world"], &sig)
+            .unwrap();
+        assert_eq!(
+            key.verify_asn1::<Sha256>(&[b"hello world!"], &sig),
+            Err(Error::BadSignature)
+        );
+        assert_eq!(
+            key.verify_asn1::<Sha384>(&[b"hello world"], &sig),
+            Err(Error::BadSignature)
+        );
+
+        // r has a leading zero byte
+        let sig = unhex(
+            "3044022000975a581872ded0b2422823c8c6407a7065e90e0ea6e4a54e1972de93a88f64022022c694595edd236b9a11a2bcd96561d8f16a8fd694523eaaa881fc8ac4f34bb1",
+        );
+        key.verify_asn1::<Sha512>(&[b"hello world"], &sig).unwrap();
+
+        let mut fixed = [0u8; 64];
+        fixed[..32].copy_from_slice(&sig[4..36]);
+        fixed[32..].copy_from_slice(&sig[38..]);
+        key.verify::<Sha512>(&[b"hello world"], &fixed).unwrap();
+        fixed[63] ^= 1;
+        assert_eq!(
+            key.verify::<Sha512>(&[b"hello world"], &fixed),
+            Err(Error::BadSignature)
+        );
+        assert_eq!(
+            key.verify::<Sha512>(&[b"hello world"], &fixed[..63]),
+            Err(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn dsa3072_256() {
+        // signatures from an independent implementation
+        let key = VerifyingKey::from_spki_der(include_bytes!("dsa/dsa3072.spki.der")).unwrap();
+        let sig = unhex(
+            "3045022029678b51ec59c928d61048ecfc064d1548a5a0aad9b50281cfe1d3f9b8a98d1702210092d817503ee8dd2537b4d4c7388e927e790a0d06e48aa15e86867902ee234301",
+        );
+        key.verify_asn1::<Sha256>(&[b"hello world"], &sig).unwrap();
+        let sig = unhex(
+            "3045022062ad89e1737c2683c7f8881de4c759f72b823507fc497f76e59c2bad2bc357f30221009ec8716beabb2c89eefe317f3a198990e58dc537745b661909d24749b0d0206a",
+        );
+        key.verify_asn1::<Sha512>(&[b"hello world"], &sig).unwrap();
+        assert_eq!(
+            key.verify_asn1::<Sha512>(&[b"goodbye world"], &sig),
+            Err(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn invalid_signatures() {
+        let key = VerifyingKey::from_spki_der(include_bytes!("dsa/dsa2048.spki.der")).unwrap();
+        let mut q = [0u8; 32];
+        key.q.q.to_bytes(&mut q).unwrap();
+
+        // r and s must be in [1, q)
+        for (r, s) in [
+            ([0u8; 32], [1u8; 32]),
+            ([1u8; 32], [0u8; 32]),
+            (q, [1; 32]),
+            ([1; 32], q),
+        ] {
+            let mut sig = [0u8; 64];
+            sig[..32].copy_from_slice(&r);
+            sig[32..].copy_from_slice(&s);
+            assert_eq!(
+                key.verify::<Sha256>(&[b"hello world"], &sig),
+                Err(Error::BadSignature)
+            );
+        }
+
+        // not ASN.1, negative, and too long
+        let mut long = vec![0x30, 0x26, 0x02, 0x21];
+        long.extend_from_slice(&[0x01; 33]);
+        long.extend_from_slice(&[0x02, 0x01, 0x01]);
+        for sig in [
+            &b"\x30\x00"[..],
+            &b"\x30\x06\x02\x01\xff\x02\x01\x01"[..],
+            &long,
+        ] {
+            assert_eq!(
+                key.verify_asn1::<Sha256>(&[b"hello world"], sig),
+                Err(Error::BadSignature)
+            );
+        }
+    }
+
+    #[test]
+    fn pem() {
+        let spki = include_bytes!("dsa/dsa2048.spki.der");
+        let pem = crate::high::pem::encode("PUBLIC KEY", spki);
+        assert!(VerifyingKey::from_pem(&pem).is_ok());
+    }
+
+    #[test]
+    fn invalid_keys() {
+        // 2048/224 is a valid FIPS186-4 parameter set, but not supported
+        assert_eq!(
+            VerifyingKey::from_spki_der(include_bytes!("dsa/dsa2048-224.spki.der")).err(),
+            Some(Error::OutOfRange)
+        );
+
+        // not a DSA key
+        assert_eq!(
+            VerifyingKey::from_spki_der(include_bytes!("asn1/testdata/spki-rsa-2k.bin")).err(),
+            Some(Error::Asn1Error(crate::high::asn1::Error::UnexpectedTag))
+        );
+
+        let spki = include_bytes!("dsa/dsa2048.spki.der");
+        let parsed = pkix::DsaSubjectPublicKeyInfo::from_bytes(spki).unwrap();
+        let params = &parsed.algorithm.parameters;
+        let (p, q, g) = (
+            positive(&params.p).unwrap(),
+            positive(&params.q).unwrap(),
+            positive(&params.g).unwrap(),
+        );
+        let y = Integer::from_bytes(parsed.subjectPublicKey.as_octets()).unwrap();
+        let y = positive(&y).unwrap();
+        assert!(VerifyingKey::new(p, q, g, y).is_ok());
+
+        // y or g of 0, 1, p - 1 or p, and y outside the subgroup
+        let mut p_minus_1 = p.to_vec();
+        *p_minus_1.last_mut().unwrap() -= 1;
+        for bad in [&[0u8][..], &[1], &p_minus_1, p, &[3]] {
+            assert_eq!(
+                VerifyingKey::new(p, q, g, bad).err(),
+                Some(Error::NotOnCurve)
+            );
+            assert_eq!(
+                VerifyingKey::new(p, q, bad, y).err(),
+                Some(Error::NotOnCurve)
+            );
+        }
+
+        // p and q of wrong sizes
+        assert_eq!(
+            VerifyingKey::new(&p[1..], q, g, y).err(),
+            Some(Error::OutOfRange)
+        );
+        assert_eq!(
+            VerifyingKey::new(p, &q[1..], g, y).err(),
+            Some(Error::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,214 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::marker::PhantomData;
+
+use super::hpke::{Aes256Gcm, HkdfSha256, HpkeAead, HpkeKdf, HpkeKem, Suite};
+use crate::Error;
+
+/// Single-shot public key encryption of a message, to a recipient's KEM key.
+///
+/// This is HPKE base mode with KEM `K`, HKDF-SHA256 and AES-256-GCM, with a
+/// fixed framing.  An envelope is:
+///
+/// - a 7-byte header: a version byte, then the HPKE KEM, KDF and AEAD identifiers,
+/// - the KEM ciphertext (the HPKE `enc`),
+/// - the AEAD ciphertext of the message,
+/// - a 16-byte authentication tag.
+///
+/// The header is bound into the HPKE key schedule, so it is authenticated
+/// and an envelope cannot be opened as if it were for another algorithm
+/// or version.
+pub struct Envelope<K>(PhantomData<K>);
+
+impl<K: HpkeKem> Envelope<K> {
+    /// The number of bytes an envelope adds to a message.
+    pub const OVERHEAD: usize = HEADER_LEN + K::CIPHERTEXT_LEN + TAG_LEN;
+
+    /// Encrypt `plaintext` to `public_key`, writing the envelope to `out`.
+    ///
+    /// `aad` is additionally-authenticated data, which is not included in
+    /// the envelope but must be supplied again to open it.  It may be empty.
+    ///
+    /// `out` must be at least [`Self::OVERHEAD`] bytes longer than
+    /// `plaintext`, otherwise [`Error::WrongLength`] is returned.  The used
+    /// prefix of `out` is returned.
+    pub fn seal_to_recipient<'a>(
+        public_key: &K::EncapsulationKey,
+        aad: &[u8],
+        plaintext: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], Error> {
+        let len = plaintext.len() + Self::OVERHEAD;
+        let out = out.get_mut(..len).ok_or(Error::WrongLength)?;
+
+        let header = Self::header();
+        let (out_header, rest) = out.split_at_mut(HEADER_LEN);
+        let (out_enc, rest) = rest.split_at_mut(K::CIPHERTEXT_LEN);
+        let (out_ciphertext, out_tag) = rest.split_at_mut(plaintext.len());
+
+        out_ciphertext.copy_from_slice(plaintext);
+        let mut tag = [0u8; TAG_LEN];
+        let enc = Suite::<K, Kdf, Aead>::seal(
+            public_key,
+            &Self::info(&header),
+            aad,
+            out_ciphertext,
+            &mut tag,
+        )?;
+
+        out_header.copy_from_slice(&header);
+        out_enc.copy_from_slice(enc.as_ref());
+        out_tag.copy_from_slice(&tag);
+        Ok(out)
+    }
+
+    /// Decrypt and verify `envelope` with `private_key`, writing the message
+    /// to `out`.
+    ///
+    /// `aad` must match that given to [`Self::seal_to_recipient()`].
+    ///
+    /// `out` must be at least as long as the message ([`Self::OVERHEAD`]
+    /// bytes shorter than `envelope`), otherwise [`Error::WrongLength`] is
+    /// returned.  On success, the used prefix of `out` is returned.
+    /// Otherwise, [`Error::DecryptFailed`] is returned.
+    pub fn open<'a>(
+        private_key: &K::DecapsulationKey,
+        aad: &[u8],
+        envelope: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], Error> {
+        let len = envelope
+            .len()
+            .checked_sub(Self::OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+        let out = out.get_mut(..len).ok_or(Error::WrongLength)?;
+
+        let (header, rest) = envelope.split_at(HEADER_LEN);
+        let (enc, rest) = rest.split_at(K::CIPHERTEXT_LEN);
+        let (ciphertext, tag) = rest.split_at(len);
+
+        // a mismatched header would fail to decrypt anyway; this just
+        // avoids the work.
+        let header: [u8; HEADER_LEN] = header.try_into().unwrap();
+        if header != Self::header() {
+            return Err(Error::DecryptFailed);
+        }
+
+        out.copy_from_slice(ciphertext);
+        Suite::<K, Kdf, Aead>::open(enc, private_key, &Self::info(&header), aad, out, tag)?;
+        Ok(out)
+    }
+
+    fn header() -> [u8; HEADER_LEN] {
+        let mut header = [0u8; HEADER_LEN];
+        header[0] = VERSION;
+        header[1..3].copy_from_slice(&K::KEM_ID.to_be_bytes());
+        header[3..5].copy_from_slice(&Kdf::KDF_ID.to_be_bytes());
+        header[5..7].copy_from_slice(&Aead::AEAD_ID.to_be_bytes());
+        header
+    }
+
+    fn info(header: &[u8; HEADER_LEN]) -> [u8; INFO_PREFIX.len() + HEADER_LEN] {
+        let mut info = [0u8; INFO_PREFIX.len() + HEADER_LEN];
+        info[..INFO_PREFIX.len()].copy_from_slice(INFO_PREFIX);
+        info[INFO_PREFIX.len()..].copy_from_slice(header);
+        info
+    }
+}
+
+type Kdf = HkdfSha256;
+type Aead = Aes256Gcm;
+
+const VERSION: u8 = 0x01;
+const HEADER_LEN: usize = 7;
+const TAG_LEN: usize = 16;
+const INFO_PREFIX: &[u8] = b"graviola envelope";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::curve::P256;
+    use crate::high::kem::{Kem, MlKem768, X25519};
+
+    fn check<K: HpkeKem>() {
+        let private_key = K::generate().unwrap();
+        let public_key = K::encapsulation_key(&private_key);
+
+        let mut envelope = [0u8; 2048];
+        let envelope =
+            Envelope::<K>::seal_to_recipient(&public_key, b"aad", b"hello world", &mut envelope)
+                .unwrap();
+        assert_eq!(envelope.len(), 11 + Envelope::<K>::OVERHEAD);
+        assert_eq!(envelope[0], VERSION);
+        assert_eq!(&envelope[1..3], &K::KEM_ID.to_be_bytes());
+
+        let mut out = [0u8; 11];
+        assert_eq!(
+            Envelope::<K>::open(&private_key, b"aad", envelope, &mut out).unwrap(),
+            b"hello world"
+        );
+
+        assert_eq!(
+            Envelope::<K>::open(&private_key, b"AAD", envelope, &mut out),
+            Err(Error::DecryptFailed)
+        );
+
+        let other_key = K::generate().unwrap();
+        assert_eq!(
+            Envelope::<K>::open(&other_key, b"aad", envelope, &mut out),
+            Err(Error::DecryptFailed)
+        );
+
+        // any alteration to the header or ciphertext is detected
+        for i in [0, 6, HEADER_LEN, envelope.len() - 17, envelope.len() - 1] {
+            let mut altered = envelope.to_vec();
+            altered[i] ^= 1;
+            assert!(Envelope::<K>::open(&private_key, b"aad", &altered, &mut out).is_err());
+        }
+
+        assert_eq!(
+            Envelope::<K>::open(&private_key, b"aad", envelope, &mut [0u8; 10]),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            Envelope::<K>::open(
+                &private_key,
+                b"aad",
+                &envelope[..Envelope::<K>::OVERHEAD - 1],
+                &mut out
+            ),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            Envelope::<K>::seal_to_recipient(
+                &public_key,
+                b"",
+                b"hello world",
+                &mut vec![0u8; 10 + Envelope::<K>::OVERHEAD]
+            )
+            .err(),
+            Some(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn all_kems() {
+        check::<X25519>();
+        check::<P256>();
+        check::<MlKem768>();
+    }
+
+    #[test]
+    fn empty_message() {
+        let private_key = X25519::generate().unwrap();
+        let public_key = X25519::encapsulation_key(&private_key);
+        let mut envelope = [0u8; Envelope::<X25519>::OVERHEAD];
+        let envelope =
+            Envelope::<X25519>::seal_to_recipient(&public_key, b"", b"", &mut envelope).unwrap();
+        assert_eq!(
+            Envelope::<X25519>::open(&private_key, b"", envelope, &mut []).unwrap(),
+            b""
+        );
+    }
+}
@@ -0,0 +1,347 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::hash::{Hash, Sha256, Sha384, Sha512};
+use crate::Error;
+
+/// A signature algorithm, for an [`ExternalSigner`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureAlgorithm {
+    /// ECDSA on P-256 with SHA-256.
+    EcdsaP256Sha256,
+    /// ECDSA on P-384 with SHA-384.
+    EcdsaP384Sha384,
+    /// RSASSA-PKCS1-v1_5 with SHA-256.
+    RsaPkcs1Sha256,
+    /// RSASSA-PKCS1-v1_5 with SHA-384.
+    RsaPkcs1Sha384,
+    /// RSASSA-PKCS1-v1_5 with SHA-512.
+    RsaPkcs1Sha512,
+    /// RSASSA-PSS with SHA-256, and a 32-byte salt.
+    RsaPssSha256,
+    /// RSASSA-PSS with SHA-384, and a 48-byte salt.
+    RsaPssSha384,
+    /// RSASSA-PSS with SHA-512, and a 64-byte salt.
+    RsaPssSha512,
+    /// Ed25519.
+    Ed25519,
+}
+
+/// A request for an external key to produce a signature.
+///
+/// This is a self-contained value, so it may be sent elsewhere (to
+/// another thread or task, or over the network) to be completed.
+#[derive(Clone, Debug)]
+pub struct SignatureRequest {
+    algorithm: SignatureAlgorithm,
+    input: Vec<u8>,
+}
+
+impl SignatureRequest {
+    /// Make a request for a signature over `message`, using `algorithm`.
+    ///
+    /// This hashes the message, if `algorithm` allows.
+    pub fn new(algorithm: SignatureAlgorithm, message: &[u8]) -> Self {
+        use SignatureAlgorithm::*;
+        let input = match algorithm {
+            EcdsaP256Sha256 | RsaPkcs1Sha256 | RsaPssSha256 => {
+                Sha256::hash(message).as_ref().to_vec()
+            }
+            EcdsaP384Sha384 | RsaPkcs1Sha384 | RsaPssSha384 => {
+                Sha384::hash(message).as_ref().to_vec()
+            }
+            RsaPkcs1Sha512 | RsaPssSha512 => Sha512::hash(message).as_ref().to_vec(),
+            Ed25519 => message.to_vec(),
+        };
+        Self { algorithm, input }
+    }
+
+    /// The algorithm to sign with.
+    pub fn algorithm(&self) -> SignatureAlgorithm {
+        self.algorithm
+    }
+
+    /// The input to the signing operation.
+    ///
+    /// This is the digest of the message, computed with the algorithm's
+    /// hash function -- as expected by (for example) PKCS#11's `CKM_ECDSA`
+    /// and `CKM_RSA_PKCS_PSS` mechanisms, or a cloud KMS asked to sign a
+    /// digest.  For RSASSA-PKCS1-v1_5, the external key is expected to
+    /// add the `DigestInfo` encoding itself.
+    ///
+    /// The exception is [`SignatureAlgorithm::Ed25519`]: Ed25519 cannot
+    /// sign a digest, so this is the whole message.
+    pub fn input(&self) -> &[u8] {
+        &self.input
+    }
+}
+
+/// A signing key held outside this crate: for example, in an HSM, a
+/// PKCS#11 token, or a cloud KMS.
+///
+/// Protocol code in this crate handles hashing and encoding, and only the
+/// private key operation is delegated to [`ExternalSigner::sign()`].
+pub trait ExternalSigner {
+    /// The (only) algorithm this key signs with.
+    fn algorithm(&self) -> SignatureAlgorithm;
+
+    /// Sign [`SignatureRequest::input()`].
+    ///
+    /// `request.algorithm()` is always [`ExternalSigner::algorithm()`].
+    ///
+    /// The signature is returned in its usual encoding: for ECDSA this is
+    /// the DER-encoded ASN.1 `ECDSA-Sig-Value`, for RSA it is the same
+    /// length as the modulus, and for Ed25519 it is 64 bytes.
+    ///
+    /// Failures should be reported as [`Error::ExternalKeyFailed`].
+    fn sign(&self, request: &SignatureRequest) -> Result<Vec<u8>, Error>;
+}
+
+/// A decryption algorithm, for an [`ExternalDecrypter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecryptionAlgorithm {
+    /// RSAES-OAEP with SHA-256 (for both the hash and MGF1), and an
+    /// empty label.
+    RsaOaepSha256,
+}
+
+/// A decryption key held outside this crate: for example, in an HSM,
+/// a PKCS#11 token, or a cloud KMS.
+///
+/// See [`ExternalSigner`].
+pub trait ExternalDecrypter {
+    /// The (only) algorithm this key decrypts with.
+    fn algorithm(&self) -> DecryptionAlgorithm;
+
+    /// Decrypt `ciphertext`.
+    ///
+    /// The plaintext is written to the front of `plaintext` (which is at
+    /// least as long as `ciphertext`), and then the written-to slice is
+    /// returned.  The plaintext is secret: it is erased after use by the
+    /// caller, but any other copy is the implementation's responsibility.
+    ///
+    /// Failures should be reported as [`Error::DecryptFailed`] if the
+    /// ciphertext was rejected, or [`Error::ExternalKeyFailed`] otherwise.
+    fn decrypt<'a>(&self, ciphertext: &[u8], plaintext: &'a mut [u8]) -> Result<&'a [u8], Error>;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::high::curve::{P256, P384, PrivateKey};
+    use crate::high::{ecdsa, rsa};
+    use crate::mid::ed25519;
+    use crate::mid::rng::SystemRandom;
+
+    /// A stand-in for a key in an HSM.
+    #[allow(clippy::large_enum_variant)]
+    pub(crate) enum Hsm {
+        EcdsaP256(ecdsa::SigningKey<P256>),
+        EcdsaP384(ecdsa::SigningKey<P384>),
+        Rsa(rsa::SigningKey, SignatureAlgorithm),
+        Ed25519(ed25519::SigningKey),
+        Broken(SignatureAlgorithm),
+    }
+
+    impl Hsm {
+        pub(crate) fn p256() -> Self {
+            Self::EcdsaP256(
+                ecdsa::SigningKey::from_pkcs8_der(include_bytes!("ecdsa/secp256r1.pkcs8.der"))
+                    .unwrap(),
+            )
+        }
+
+        pub(crate) fn p384() -> Self {
+            Self::EcdsaP384(
+                ecdsa::SigningKey::from_pkcs8_der(include_bytes!("ecdsa/secp384r1.pkcs8.der"))
+                    .unwrap(),
+            )
+        }
+
+        pub(crate) fn rsa(algorithm: SignatureAlgorithm) -> Self {
+            Self::Rsa(rsa_key(), algorithm)
+        }
+
+        pub(crate) fn ed25519() -> Self {
+            Self::Ed25519(ed25519::SigningKey::from_seed(&[1; 32]))
+        }
+    }
+
+    pub(crate) fn rsa_key() -> rsa::SigningKey {
+        rsa::SigningKey::from_pkcs8_der(include_bytes!("rsa/rsa2048.pkcs8.der")).unwrap()
+    }
+
+    impl ExternalSigner for Hsm {
+        fn algorithm(&self) -> SignatureAlgorithm {
+            match self {
+                Self::EcdsaP256(_) => SignatureAlgorithm::EcdsaP256Sha256,
+                Self::EcdsaP384(_) => SignatureAlgorithm::EcdsaP384Sha384,
+                Self::Rsa(_, algorithm) | Self::Broken(algorithm) => *algorithm,
+                Self::Ed25519(_) => SignatureAlgorithm::Ed25519,
+            }
+        }
+
+        fn sign(&self, request: &SignatureRequest) -> Result<Vec<u8>, Error> {
+            assert_eq!(request.algorithm(), self.algorithm());
+            let digest = request.input();
+            let mut fixed = [0u8; 96];
+            let mut signature = [0u8; 256];
+
+            let signature = match self {
+                Self::EcdsaP256(key) => {
+                    let fixed =
+                        key.rfc6979_sign_digest_with_random::<Sha256>(digest, &[], &mut fixed)?;
+                    ecdsa::SigningKey::<P256>::fixed_to_asn1(fixed, &mut signature)?
+                }
+                Self::EcdsaP384(key) => {
+                    let fixed =
+                        key.rfc6979_sign_digest_with_random::<Sha384>(digest, &[], &mut fixed)?;
+                    ecdsa::SigningKey::<P384>::fixed_to_asn1(fixed, &mut signature)?
+                }
+                Self::Rsa(key, algorithm) => match algorithm {
+                    SignatureAlgorithm::RsaPkcs1Sha256 => {
+                        key._sign_pkcs1_digest::<Sha256>(&mut signature, digest)?
+                    }
+                    SignatureAlgorithm::RsaPkcs1Sha384 => {
+                        key._sign_pkcs1_digest::<Sha384>(&mut signature, digest)?
+                    }
+                    SignatureAlgorithm::RsaPkcs1Sha512 => {
+                        key._sign_pkcs1_digest::<Sha512>(&mut signature, digest)?
+                    }
+                    SignatureAlgorithm::RsaPssSha256 => {
+                        key._sign_pss_digest::<Sha256>(&mut signature, digest, &mut SystemRandom)?
+                    }
+                    SignatureAlgorithm::RsaPssSha384 => {
+                        key._sign_pss_digest::<Sha384>(&mut signature, digest, &mut SystemRandom)?
+                    }
+                    SignatureAlgorithm::RsaPssSha512 => {
+                        key._sign_pss_digest::<Sha512>(&mut signature, digest, &mut SystemRandom)?
+                    }
+                    _ => unreachable!(),
+                },
+                Self::Ed25519(key) => {
+                    signature[..64].copy_from_slice(&key.sign(&[digest]));
+                    &signature[..64]
+                }
+                Self::Broken(_) => return Err(Error::ExternalKeyFailed),
+            };
+            Ok(signature.to_vec())
+        }
+    }
+
+    /// A stand-in for an RSA key in an HSM.
+    pub(crate) struct RsaHsm(pub(crate) rsa::SigningKey);
+
+    impl ExternalDecrypter for RsaHsm {
+        fn algorithm(&self) -> DecryptionAlgorithm {
+            DecryptionAlgorithm::RsaOaepSha256
+        }
+
+        fn decrypt<'a>(
+            &self,
+            ciphertext: &[u8],
+            plaintext: &'a mut [u8],
+        ) -> Result<&'a [u8], Error> {
+            self.0.decrypt_oaep_sha256(plaintext, ciphertext)
+        }
+    }
+
+    #[test]
+    fn requests() {
+        let message = b"hello world";
+        for (algorithm, len) in [
+            (SignatureAlgorithm::EcdsaP256Sha256, 32),
+            (SignatureAlgorithm::EcdsaP384Sha384, 48),
+            (SignatureAlgorithm::RsaPkcs1Sha256, 32),
+            (SignatureAlgorithm::RsaPkcs1Sha384, 48),
+            (SignatureAlgorithm::RsaPkcs1Sha512, 64),
+            (SignatureAlgorithm::RsaPssSha256, 32),
+            (SignatureAlgorithm::RsaPssSha384, 48),
+            (SignatureAlgorithm::RsaPssSha512, 64),
+            (SignatureAlgorithm::Ed25519, message.len()),
+        ] {
+            let request = SignatureRequest::new(algorithm, message);
+            assert_eq!(request.algorithm(), algorithm);
+            assert_eq!(request.input().len(), len);
+        }
+
+        assert_eq!(
+            SignatureRequest::new(SignatureAlgorithm::RsaPssSha256, message).input(),
+            Sha256::hash(message).as_ref()
+        );
+        assert_eq!(
+            SignatureRequest::new(SignatureAlgorithm::Ed25519, message).input(),
+            message
+        );
+    }
+
+    #[test]
+    fn signatures_verify() {
+        let message = b"hello world";
+        let sign = |hsm: &Hsm| hsm.sign(&SignatureRequest::new(hsm.algorithm(), message));
+
+        for hsm in [Hsm::p256(), Hsm::p384()] {
+            let signature = sign(&hsm).unwrap();
+            let mut public = [0u8; 97];
+            match hsm {
+                Hsm::EcdsaP256(key) => {
+                    let public = key.private_key.public_key_encode_uncompressed(&mut public);
+                    ecdsa::VerifyingKey::<P256>::from_x962_uncompressed(public.unwrap())
+                        .unwrap()
+                        .verify_asn1::<Sha256>(&[message], &signature)
+                        .unwrap();
+                }
+                Hsm::EcdsaP384(key) => {
+                    let public = key.private_key.public_key_encode_uncompressed(&mut public);
+                    ecdsa::VerifyingKey::<P384>::from_x962_uncompressed(public.unwrap())
+                        .unwrap()
+                        .verify_asn1::<Sha384>(&[message], &signature)
+                        .unwrap();
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        let public = rsa_key().public_key();
+        for (algorithm, verify) in [
+            (
+                SignatureAlgorithm::RsaPkcs1Sha256,
+                rsa::VerifyingKey::verify_pkcs1_sha256 as fn(&_, &_, &_) -> _,
+            ),
+            (
+                SignatureAlgorithm::RsaPkcs1Sha384,
+                rsa::VerifyingKey::verify_pkcs1_sha384,
+            ),
+            (
+                SignatureAlgorithm::RsaPkcs1Sha512,
+                rsa::VerifyingKey::verify_pkcs1_sha512,
+            ),
+            (
+                SignatureAlgorithm::RsaPssSha256,
+                rsa::VerifyingKey::verify_pss_sha256,
+            ),
+            (
+                SignatureAlgorithm::RsaPssSha384,
+                rsa::VerifyingKey::verify_pss_sha384,
+            ),
+            (
+                SignatureAlgorithm::RsaPssSha512,
+                rsa::VerifyingKey::verify_pss_sha512,
+            ),
+        ] {
+            let signature = sign(&Hsm::rsa(algorithm)).unwrap();
+            verify(&public, &signature, message).unwrap();
+        }
+
+        let ed25519 = ed25519::SigningKey::from_seed(&[1; 32]);
+        ed25519
+            .verifying_key()
+            .verify(&[message], &sign(&Hsm::ed25519()).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            sign(&Hsm::Broken(SignatureAlgorithm::Ed25519)),
+            Err(Error::ExternalKeyFailed)
+        );
+    }
+}
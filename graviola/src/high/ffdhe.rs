@@ -0,0 +1,384 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Finite-field Diffie-Hellman, using the named groups from
+//! [RFC7919](https://datatracker.ietf.org/doc/html/rfc7919).
+//!
+//! This exists for compatibility with TLS and IKE deployments where
+//! elliptic curves are not allowed.  New designs should prefer X25519.
+//!
+//! Only the RFC7919 groups are supported.  Each has a safe prime
+//! `p = 2q + 1` and generator 2, which generates the subgroup of order `q`.
+//! Peer public keys are checked to be members of that subgroup.
+
+use crate::Error;
+use crate::low::{self, Entry};
+use crate::mid::modp::{Exponent, PrimeModulus};
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+mod groups;
+
+/// The groups from RFC7919 appendix A.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Group {
+    /// The 2048-bit group.
+    Ffdhe2048,
+    /// The 3072-bit group.
+    Ffdhe3072,
+    /// The 4096-bit group.
+    Ffdhe4096,
+    /// The 6144-bit group.
+    Ffdhe6144,
+    /// The 8192-bit group.
+    Ffdhe8192,
+}
+
+impl Group {
+    /// Length of `p`, and therefore public keys and shared secrets, in bytes.
+    pub fn len_bytes(&self) -> usize {
+        self.prime().len()
+    }
+
+    fn prime(&self) -> &'static [u8] {
+        match self {
+            Self::Ffdhe2048 => &groups::PRIME_2048,
+            Self::Ffdhe3072 => &groups::PRIME_3072,
+            Self::Ffdhe4096 => &groups::PRIME_4096,
+            Self::Ffdhe6144 => &groups::PRIME_6144,
+            Self::Ffdhe8192 => &groups::PRIME_8192,
+        }
+    }
+
+    /// Length of private exponents, in bytes.
+    ///
+    /// These exceed the minimums in RFC7919 section 5.2.
+    fn exponent_len(&self) -> usize {
+        match self {
+            Self::Ffdhe2048 => 32,
+            Self::Ffdhe3072 => 40,
+            Self::Ffdhe4096 => 48,
+            Self::Ffdhe6144 => 56,
+            Self::Ffdhe8192 => 64,
+        }
+    }
+
+    fn modulus(&self) -> Result<PrimeModulus, Error> {
+        PrimeModulus::new(self.prime())
+    }
+}
+
+/// A finite-field Diffie-Hellman ephemeral private key.
+///
+/// This is single-use, and is zeroed on drop.
+pub struct PrivateKey {
+    x: Exponent,
+    public: PublicKey,
+}
+
+impl PrivateKey {
+    /// Generate a new key in `group` using the system random number generator.
+    ///
+    /// Fails only if the random source fails.
+    pub fn new_random(group: Group) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        Self::generate(group, &mut SystemRandom)
+    }
+
+    /// Generate a new key in `group` using `rng`.
+    ///
+    /// Fails only if the random source fails.
+    pub fn generate(group: Group, rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let modulus = group.modulus()?;
+
+        let mut x = [0u8; MAX_EXPONENT_LEN];
+        let x_bytes = &mut x[..group.exponent_len()];
+        rng.fill(x_bytes)?;
+        let exponent = Exponent::from_bytes(x_bytes);
+        low::zeroise(&mut x);
+        // only fails if the random source produced zero
+        let x = exponent.map_err(|_| Error::RngFailed)?;
+
+        let y = modulus.exp(&modulus.decode(&[GENERATOR])?, &x);
+        let mut public = PublicKey {
+            group,
+            bytes: [0u8; MAX_LEN],
+        };
+        modulus.encode(&y, &mut public.bytes[..group.len_bytes()])?;
+        Ok(Self { x, public })
+    }
+
+    /// The group this key belongs to.
+    pub fn group(&self) -> Group {
+        self.public.group
+    }
+
+    /// Return the associated public key.
+    pub fn public_key(&self) -> PublicKey {
+        self.public.clone()
+    }
+
+    /// Do the Diffie-Hellman operation.
+    ///
+    /// `peer` is the peer's public key, which must be in the same group
+    /// as this key: otherwise this fails with [`Error::NotOnCurve`].
+    ///
+    /// Returns a shared secret.  This is left-padded with zeros to the length
+    /// of `p`, as TLS1.3 requires.  Note that TLS1.2 instead strips leading
+    /// zero bytes.
+    pub fn diffie_hellman(self, peer: &PublicKey) -> Result<SharedSecret, Error> {
+        let _entry = Entry::new_secret();
+        let group = self.group();
+        if peer.group != group {
+            return Err(Error::NotOnCurve);
+        }
+
+        // nb. `peer` is in the subgroup of prime order `q` and is not 1, and
+        // `0 < x < q`, so the result cannot be 1.
+        let modulus = group.modulus()?;
+        let z = modulus.exp(&modulus.decode(peer.as_bytes())?, &self.x);
+
+        let mut secret = SharedSecret {
+            bytes: [0u8; MAX_LEN],
+            len: group.len_bytes(),
+        };
+        modulus.encode(&z, &mut secret.bytes[..secret.len])?;
+        Ok(secret)
+    }
+}
+
+/// A finite-field Diffie-Hellman public key.
+#[derive(Clone)]
+pub struct PublicKey {
+    group: Group,
+    bytes: [u8; MAX_LEN],
+}
+
+impl PublicKey {
+    /// Decode and validate a public key in `group`.
+    ///
+    /// `bytes` is the big-endian encoding of the key, and must be exactly
+    /// `group.len_bytes()` long.
+    ///
+    /// The key must be in the range `[2, p - 2]`, and be in the subgroup of
+    /// order `q`.  These are the full public key validation checks from
+    /// [SP800-56A](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-56Ar3.pdf)
+    /// section 5.6.2.3.1.  Failures are reported with [`Error::NotOnCurve`].
+    pub fn from_bytes(group: Group, bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        let len = group.len_bytes();
+        if bytes.len() != len {
+            return Err(Error::WrongLength);
+        }
+
+        let modulus = group.modulus()?;
+        let y = modulus.decode(bytes).map_err(|_| Error::NotOnCurve)?;
+
+        // the identity is in the subgroup, but is not an acceptable key.
+        // the check below excludes 0 and `p - 1`.
+        let one = modulus.decode(&[1])?;
+        if y.ct_equal(&one) {
+            return Err(Error::NotOnCurve);
+        }
+
+        // q = (p - 1) / 2 = p >> 1, since p is odd
+        let p = group.prime();
+        let mut q = [0u8; MAX_LEN];
+        q[0] = p[0] >> 1;
+        for (q, p) in q[1..len].iter_mut().zip(p.windows(2)) {
+            *q = (p[1] >> 1) | (p[0] << 7);
+        }
+
+        if !modulus.exp_public(&y, &q[..len])?.ct_equal(&one) {
+            return Err(Error::NotOnCurve);
+        }
+
+        let mut r = Self {
+            group,
+            bytes: [0u8; MAX_LEN],
+        };
+        r.bytes[..len].copy_from_slice(bytes);
+        Ok(r)
+    }
+
+    /// The group this key belongs to.
+    pub fn group(&self) -> Group {
+        self.group
+    }
+
+    /// The big-endian encoding of this key.
+    ///
+    /// This is `group().len_bytes()` long.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.group.len_bytes()]
+    }
+}
+
+/// A shared secret output from a finite-field Diffie-Hellman operation.
+///
+/// This is zeroed on drop.
+pub struct SharedSecret {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl SharedSecret {
+    /// The big-endian encoding of the shared secret, padded to the length of `p`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.bytes);
+    }
+}
+
+/// The generator for all the RFC7919 groups.
+const GENERATOR: u8 = 2;
+
+/// Length of the largest group.
+const MAX_LEN: usize = 1024;
+
+/// Length of the largest private exponent.
+const MAX_EXPONENT_LEN: usize = 64;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::{Hash, Sha256};
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn key(group: Group, first: u8) -> PrivateKey {
+        let x = (0..group.exponent_len())
+            .map(|i| first + i as u8)
+            .collect::<Vec<_>>();
+        PrivateKey::generate(group, &mut SliceRandomSource(&x)).unwrap()
+    }
+
+    fn exchange(group: Group, alice_first: u8, bob_first: u8) -> (PublicKey, PublicKey, String) {
+        let alice = key(group, alice_first);
+        let bob = key(group, bob_first);
+        let alice_pub = PublicKey::from_bytes(group, alice.public_key().as_bytes()).unwrap();
+        let bob_pub = PublicKey::from_bytes(group, bob.public_key().as_bytes()).unwrap();
+
+        let alice_secret = alice.diffie_hellman(&bob_pub).unwrap();
+        let bob_secret = bob.diffie_hellman(&alice_pub).unwrap();
+        assert_eq!(alice_secret.as_bytes(), bob_secret.as_bytes());
+        assert_eq!(alice_secret.as_bytes().len(), group.len_bytes());
+        (alice_pub, bob_pub, hex(alice_secret.as_bytes()))
+    }
+
+    #[test]
+    fn ffdhe2048() {
+        // from an independent implementation
+        let (alice_pub, _, secret) = exchange(Group::Ffdhe2048, 0x01, 0x80);
+        assert_eq!(
+            hex(alice_pub.as_bytes()),
+            "0eef0c0eae9c65a3332cdc742b58561d362c0af526f8ad528b19ff39c91434312f83302ed9dc6f2b84b250482dbd80962154f8a6d683741bf8bf4f3fdee22f80541a77553d1ae7a096c521f869987ae5eca1090d93e7bdea0b4349f17fee34f4775bfb380f426dec937b25825680c6e2b8c4c9eede64b91f5cdc02ba78e75908d47cd27fda4b5188c22baf2acd1e861ed0864e9eb9346640d354eada9f7156a601387ba5f1b8609733707803a5ac866fc1bbe54b15a99934727bc5c157f893d867a89abf0ce3dd0cf3d7ce00073db8b3bbae1486faedc462f7edeb3dc44ba30336de3d4dbfb4f14f143c46b0ba93f316f50c3377b9a38a27ae048f5605a4dcbf"
+        );
+        assert_eq!(
+            secret,
+            "88a2676d4cc642f9999184cf6976319a8c71c76ead569120e0c001d32493834ead57ad7fb0731670407268176649e9095ad080e480802aa661b2829f37403de4a62717d30a0bcdf3a3a01ca770c2d1ee4c1ced3ccfd6b9f24b2a40a2806c0344a1e2a9a1cc63e8f98d28c8d617151570b9494d898de0b2691dce2ac47d4fe0f363ed80b5cbb571f11bcefc87f1ddd44161cd9a983e86803310a0eec5a35790d9441db39f050244c3114137507a42677bdbe1d36a05c5880d9f3d1c9e8700bfc66cc8d6a210c827c765c31e52c08d50d73813c14de3a7906c4b638d9a9282d01990bee2ccd6107655f6bd1f3cb9e763dd9f146c9ca8903c8203120902840574ff"
+        );
+    }
+
+    #[test]
+    fn ffdhe8192() {
+        // from an independent implementation; outputs are SHA-256 hashed for brevity
+        let (alice_pub, bob_pub, secret) = exchange(Group::Ffdhe8192, 0x01, 0x80);
+        assert_eq!(
+            hex(Sha256::hash(alice_pub.as_bytes()).as_ref()),
+            "b2f1e92b44e0a45ee7b23938bfd9be352a84ab3878fa0afae756e3c4f6999e9e"
+        );
+        assert_eq!(
+            hex(Sha256::hash(bob_pub.as_bytes()).as_ref()),
+            "e45b5d6788f5f4da35907fef591af21fbe4c0b430a2373ec45b4e15089994d92"
+        );
+        assert!(secret.starts_with("fb23cf37"));
+    }
+
+    #[test]
+    fn all_groups() {
+        for group in [
+            Group::Ffdhe2048,
+            Group::Ffdhe3072,
+            Group::Ffdhe4096,
+            Group::Ffdhe6144,
+            Group::Ffdhe8192,
+        ] {
+            exchange(group, 0x10, 0x20);
+        }
+    }
+
+    #[test]
+    fn invalid_public_keys() {
+        let group = Group::Ffdhe2048;
+        let len = group.len_bytes();
+        let p = group.prime();
+
+        let small = |v: u8| {
+            let mut b = vec![0u8; len];
+            b[len - 1] = v;
+            b
+        };
+        let minus = |v: u8| {
+            let mut b = p.to_vec();
+            b[len - 1] -= v;
+            b
+        };
+
+        // 0, 1, p - 1, p and 2^2048 - 1 are out of range
+        for bad in [small(0), small(1), minus(1), p.to_vec(), vec![0xff; len]] {
+            assert_eq!(
+                PublicKey::from_bytes(group, &bad).err(),
+                Some(Error::NotOnCurve)
+            );
+        }
+
+        // 7 and -2 are not quadratic residues, so are outside the subgroup
+        for bad in [small(7), minus(2)] {
+            assert_eq!(
+                PublicKey::from_bytes(group, &bad).err(),
+                Some(Error::NotOnCurve)
+            );
+        }
+
+        // 2 (the generator) and 4 are in the subgroup
+        PublicKey::from_bytes(group, &small(2)).unwrap();
+        PublicKey::from_bytes(group, &small(4)).unwrap();
+
+        assert_eq!(
+            PublicKey::from_bytes(group, &small(2)[1..]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            PublicKey::from_bytes(Group::Ffdhe3072, &small(2)).err(),
+            Some(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn mismatched_groups() {
+        let alice = key(Group::Ffdhe2048, 0x01);
+        let bob = key(Group::Ffdhe3072, 0x01);
+        assert_eq!(
+            alice.diffie_hellman(&bob.public_key()).err(),
+            Some(Error::NotOnCurve)
+        );
+    }
+
+    #[test]
+    fn zero_exponent() {
+        let zeroes = [0u8; 32];
+        assert_eq!(
+            PrivateKey::generate(Group::Ffdhe2048, &mut SliceRandomSource(&zeroes)).err(),
+            Some(Error::RngFailed)
+        );
+    }
+}
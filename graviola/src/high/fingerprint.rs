@@ -0,0 +1,183 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::fmt;
+
+use super::asn1::{Type, pkix};
+use super::base64;
+use crate::error::{Error, KeyFormatError};
+use crate::low::{Entry, ct_equal};
+use crate::mid::sha2::Sha256Context;
+
+/// The SHA-256 fingerprint of a `SubjectPublicKeyInfo`.
+///
+/// This is the hash of the whole DER encoding of an X.509
+/// `SubjectPublicKeyInfo`.  It is the value used for key pinning by
+/// [RFC7469](https://datatracker.ietf.org/doc/html/rfc7469#section-2.4)
+/// (`pin-sha256`) and its successors, where it is written in standard base64.
+///
+/// Comparisons between fingerprints take constant time.
+#[derive(Clone)]
+pub struct SpkiFingerprint([u8; Self::LEN]);
+
+impl SpkiFingerprint {
+    /// The length of a fingerprint, in bytes.
+    pub const LEN: usize = 32;
+
+    /// Computes the fingerprint of a DER-encoded `SubjectPublicKeyInfo`.
+    ///
+    /// `spki` must be exactly one `SubjectPublicKeyInfo`, though its
+    /// algorithm and key are not otherwise checked.  Typically it comes from
+    /// a certificate, or a key's `to_spki_der()` method.
+    pub fn of_spki_der(spki: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        pkix::SubjectPublicKeyInfo::from_bytes(spki).map_err(Error::Asn1Error)?;
+
+        let mut ctx = Sha256Context::new();
+        ctx.update(spki);
+        Ok(Self(ctx.finish()))
+    }
+
+    /// Makes a fingerprint from its raw bytes.
+    ///
+    /// `bytes` must be [`Self::LEN`] bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        bytes.try_into().map(Self).map_err(|_| Error::WrongLength)
+    }
+
+    /// Decodes a fingerprint from standard, padded, base64.
+    ///
+    /// This is the form used by `pin-sha256` directives.
+    pub fn from_base64(text: &str) -> Result<Self, Error> {
+        base64::decode(text)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(Self)
+            .ok_or(KeyFormatError::InvalidFingerprint.into())
+    }
+
+    /// Encodes this fingerprint to standard, padded, base64.
+    pub fn to_base64(&self) -> String {
+        base64::encode(&self.0)
+    }
+
+    /// Returns the raw bytes of this fingerprint.
+    pub fn as_bytes(&self) -> &[u8; Self::LEN] {
+        &self.0
+    }
+
+    /// Constant-time equality with `other`, a raw fingerprint.
+    pub fn ct_equal(&self, other: &[u8]) -> bool {
+        ct_equal(&self.0, other)
+    }
+
+    /// Returns true if this fingerprint is one of `pins`.
+    ///
+    /// Every one of `pins` is compared in constant time, so the time
+    /// taken depends only on `pins.len()`.
+    pub fn is_pinned(&self, pins: &[Self]) -> bool {
+        pins.iter()
+            .fold(false, |found, pin| found | ct_equal(&self.0, &pin.0))
+    }
+}
+
+impl PartialEq for SpkiFingerprint {
+    fn eq(&self, other: &Self) -> bool {
+        ct_equal(&self.0, &other.0)
+    }
+}
+
+impl Eq for SpkiFingerprint {}
+
+impl fmt::Debug for SpkiFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SpkiFingerprint(sha256/{})", self.to_base64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected values are from
+    // `openssl dgst -sha256 -binary spki/$file | base64`
+    #[test]
+    fn known_answers() {
+        for (spki, expected) in [
+            (
+                &include_bytes!("spki/rsa2048.spki.der")[..],
+                "SCNzUuXrAfP4Til746pyakxkcld5L6FosjTnq+/Ik5E=",
+            ),
+            (
+                include_bytes!("spki/secp256r1.spki.der"),
+                "6j81geNH/4iiDL9JaZp27FBefs+ges5Iem/06Oy5geM=",
+            ),
+            (
+                include_bytes!("spki/ed25519.spki.der"),
+                "DQ/zVlNxN8R4qSpLEowav68HKduXpN90XmJud990GjE=",
+            ),
+        ] {
+            let fingerprint = SpkiFingerprint::of_spki_der(spki).unwrap();
+            assert_eq!(fingerprint.to_base64(), expected);
+            assert_eq!(SpkiFingerprint::from_base64(expected).unwrap(), fingerprint);
+            assert_eq!(
+                SpkiFingerprint::from_bytes(fingerprint.as_bytes()).unwrap(),
+                fingerprint
+            );
+            assert!(fingerprint.ct_equal(fingerprint.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn pinning() {
+        let rsa = SpkiFingerprint::of_spki_der(include_bytes!("spki/rsa2048.spki.der")).unwrap();
+        let p256 = SpkiFingerprint::of_spki_der(include_bytes!("spki/secp256r1.spki.der")).unwrap();
+        let p384 = SpkiFingerprint::of_spki_der(include_bytes!("spki/secp384r1.spki.der")).unwrap();
+
+        assert!(rsa.is_pinned(&[p256.clone(), rsa.clone()]));
+        assert!(rsa.is_pinned(&[rsa.clone(), p256.clone()]));
+        assert!(!rsa.is_pinned(&[p256.clone(), p384.clone()]));
+        assert!(!rsa.is_pinned(&[]));
+        assert_ne!(rsa, p256);
+        assert!(!rsa.ct_equal(p256.as_bytes()));
+        assert!(!rsa.ct_equal(&rsa.as_bytes()[..31]));
+    }
+
+    #[test]
+    fn invalid() {
+        let spki = include_bytes!("spki/rsa2048.spki.der");
+        assert_eq!(
+            SpkiFingerprint::of_spki_der(&spki[..spki.len() - 1]).err(),
+            Some(Error::Asn1Error(crate::high::asn1::Error::UnexpectedEof))
+        );
+
+        assert_eq!(
+            SpkiFingerprint::from_bytes(&[0u8; 31]).err(),
+            Some(Error::WrongLength)
+        );
+
+        for text in [
+            "",
+            // 31 bytes
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==",
+            // unpadded
+            "SCNzUuXrAfP4Til746pyakxkcld5L6FosjTnq+/Ik5E",
+            // url-safe alphabet
+            "SCNzUuXrAfP4Til746pyakxkcld5L6FosjTnq-_Ik5E=",
+        ] {
+            assert_eq!(
+                SpkiFingerprint::from_base64(text).err(),
+                Some(Error::KeyFormatError(KeyFormatError::InvalidFingerprint))
+            );
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let fingerprint =
+            SpkiFingerprint::of_spki_der(include_bytes!("spki/rsa2048.spki.der")).unwrap();
+        assert_eq!(
+            format!("{fingerprint:?}"),
+            "SpkiFingerprint(sha256/SCNzUuXrAfP4Til746pyakxkcld5L6FosjTnq+/Ik5E=)"
+        );
+    }
+}
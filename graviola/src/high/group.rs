@@ -0,0 +1,543 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Prime-order groups, for protocols that need more than Diffie-Hellman.
+
+use super::curve::P256;
+use super::hash::{Digest, Hash, HashContext, Sha256, Sha512};
+use crate::Error;
+use crate::mid::rng::RandomSource;
+use crate::mid::{p256, ristretto255};
+
+/// A prime-order group with hashing to elements and scalars.
+///
+/// This is the abstraction from
+/// [RFC9497 section 2.1](https://datatracker.ietf.org/doc/html/rfc9497#section-2.1).
+/// The hash-to-group and hash-to-scalar functions use
+/// `expand_message_xmd` with this group's hash.
+pub trait Group: private::Sealed {
+    /// Group element type.
+    type Element: Copy;
+
+    /// Scalar type, mod the group order.
+    type Scalar;
+
+    /// Encoded element type.
+    type EncodedElement: AsRef<[u8]>;
+
+    /// Encoded scalar type.
+    type EncodedScalar: AsRef<[u8]>;
+
+    /// The hash function used with this group.
+    type Hash: Digest + Clone;
+
+    /// Length of an encoded element.
+    const ELEMENT_LEN: usize;
+
+    /// Length of an encoded scalar.
+    const SCALAR_LEN: usize;
+
+    /// The identity element.
+    fn identity() -> Self::Element;
+
+    /// `a + b`
+    fn add(a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    /// `s * e`
+    fn mul(e: &Self::Element, s: &Self::Scalar) -> Self::Element;
+
+    /// `s * G`, where G is the group's generator
+    fn mul_gen(s: &Self::Scalar) -> Self::Element;
+
+    /// Constant-time element equality.
+    fn element_eq(a: &Self::Element, b: &Self::Element) -> bool;
+
+    /// Whether `e` is the identity.
+    fn is_identity(e: &Self::Element) -> bool;
+
+    /// Encode an element.
+    fn serialize_element(e: &Self::Element) -> Self::EncodedElement;
+
+    /// Decode an element, rejecting the identity.
+    fn deserialize_element(bytes: &[u8]) -> Result<Self::Element, Error>;
+
+    /// Encode a scalar.
+    fn serialize_scalar(s: &Self::Scalar) -> Self::EncodedScalar;
+
+    /// Decode a scalar, rejecting zero and values not less than the group order.
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, Error>;
+
+    /// Generate a random non-zero scalar.
+    fn random_scalar(rng: &mut dyn RandomSource) -> Result<Self::Scalar, Error>;
+
+    /// Deterministically map the concatenation of `input` to an element.
+    ///
+    /// Fails if the total length of `dst` is zero.
+    fn hash_to_group(input: &[&[u8]], dst: &[&[u8]]) -> Result<Self::Element, Error>;
+
+    /// Deterministically map the concatenation of `input` to a scalar.
+    ///
+    /// Fails if the total length of `dst` is zero.
+    fn hash_to_scalar(input: &[&[u8]], dst: &[&[u8]]) -> Result<Self::Scalar, Error>;
+
+    /// `a + b`
+    fn scalar_add(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+
+    /// `a - b`
+    fn scalar_sub(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+
+    /// `a * b`
+    fn scalar_mul(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+
+    /// `1 / s`.  `s` must not be zero.
+    fn scalar_inv(s: &Self::Scalar) -> Self::Scalar;
+
+    /// Constant-time scalar equality.
+    fn scalar_eq(a: &Self::Scalar, b: &Self::Scalar) -> bool;
+
+    /// Whether `s` is zero.
+    fn scalar_is_zero(s: &Self::Scalar) -> bool;
+}
+
+/// The ristretto255 group, with SHA512.
+///
+/// See [RFC9496](https://datatracker.ietf.org/doc/html/rfc9496).
+pub struct Ristretto255;
+
+impl Group for Ristretto255 {
+    type Element = ristretto255::Element;
+    type Scalar = ristretto255::Scalar;
+    type EncodedElement = [u8; ristretto255::Element::BYTES];
+    type EncodedScalar = [u8; ristretto255::Scalar::BYTES];
+    type Hash = Sha512;
+
+    const ELEMENT_LEN: usize = ristretto255::Element::BYTES;
+    const SCALAR_LEN: usize = ristretto255::Scalar::BYTES;
+
+    fn identity() -> Self::Element {
+        ristretto255::Element::identity()
+    }
+
+    fn add(a: &Self::Element, b: &Self::Element) -> Self::Element {
+        a.add(b)
+    }
+
+    fn mul(e: &Self::Element, s: &Self::Scalar) -> Self::Element {
+        e.mul(s)
+    }
+
+    fn mul_gen(s: &Self::Scalar) -> Self::Element {
+        ristretto255::Element::base_multiply(s)
+    }
+
+    fn element_eq(a: &Self::Element, b: &Self::Element) -> bool {
+        a.ct_equal(b)
+    }
+
+    fn is_identity(e: &Self::Element) -> bool {
+        e.is_identity()
+    }
+
+    fn serialize_element(e: &Self::Element) -> Self::EncodedElement {
+        e.encode()
+    }
+
+    fn deserialize_element(bytes: &[u8]) -> Result<Self::Element, Error> {
+        let e = ristretto255::Element::decode(bytes)?;
+        match e.is_identity() {
+            true => Err(Error::NotOnCurve),
+            false => Ok(e),
+        }
+    }
+
+    fn serialize_scalar(s: &Self::Scalar) -> Self::EncodedScalar {
+        s.as_bytes()
+    }
+
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, Error> {
+        ristretto255::Scalar::from_bytes_checked(bytes)
+    }
+
+    fn random_scalar(rng: &mut dyn RandomSource) -> Result<Self::Scalar, Error> {
+        ristretto255::Scalar::generate(rng)
+    }
+
+    fn hash_to_group(input: &[&[u8]], dst: &[&[u8]]) -> Result<Self::Element, Error> {
+        let mut uniform = [0u8; 64];
+        expand_message_xmd::<Self::Hash>(input, dst, &mut uniform)?;
+        Ok(ristretto255::Element::from_uniform_bytes(&uniform))
+    }
+
+    fn hash_to_scalar(input: &[&[u8]], dst: &[&[u8]]) -> Result<Self::Scalar, Error> {
+        let mut uniform = [0u8; 64];
+        expand_message_xmd::<Self::Hash>(input, dst, &mut uniform)?;
+        Ok(ristretto255::Scalar::from_wide_bytes(&uniform))
+    }
+
+    fn scalar_add(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar {
+        a.add(b)
+    }
+
+    fn scalar_sub(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar {
+        a.sub(b)
+    }
+
+    fn scalar_mul(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar {
+        a.mul(b)
+    }
+
+    fn scalar_inv(s: &Self::Scalar) -> Self::Scalar {
+        s.inv()
+    }
+
+    fn scalar_eq(a: &Self::Scalar, b: &Self::Scalar) -> bool {
+        a.ct_equal(b)
+    }
+
+    fn scalar_is_zero(s: &Self::Scalar) -> bool {
+        s.is_zero()
+    }
+}
+
+/// P-256, with SHA256 and compressed point encodings.
+///
+/// Hashing to the group is `P256_XMD:SHA-256_SSWU_RO_` from
+/// [RFC9380](https://datatracker.ietf.org/doc/html/rfc9380).
+impl Group for P256 {
+    type Element = p256::Element;
+    type Scalar = p256::Scalar;
+    type EncodedElement = [u8; p256::Element::BYTES];
+    type EncodedScalar = [u8; p256::Scalar::BYTES];
+    type Hash = Sha256;
+
+    const ELEMENT_LEN: usize = p256::Element::BYTES;
+    const SCALAR_LEN: usize = p256::Scalar::BYTES;
+
+    fn identity() -> Self::Element {
+        p256::Element::identity()
+    }
+
+    fn add(a: &Self::Element, b: &Self::Element) -> Self::Element {
+        a.add(b)
+    }
+
+    fn mul(e: &Self::Element, s: &Self::Scalar) -> Self::Element {
+        e.mul(s)
+    }
+
+    fn mul_gen(s: &Self::Scalar) -> Self::Element {
+        p256::Element::base_multiply(s)
+    }
+
+    fn element_eq(a: &Self::Element, b: &Self::Element) -> bool {
+        a.ct_equal(b)
+    }
+
+    fn is_identity(e: &Self::Element) -> bool {
+        e.is_identity()
+    }
+
+    fn serialize_element(e: &Self::Element) -> Self::EncodedElement {
+        e.encode()
+    }
+
+    fn deserialize_element(bytes: &[u8]) -> Result<Self::Element, Error> {
+        // the identity has no compressed encoding
+        p256::Element::decode(bytes)
+    }
+
+    fn serialize_scalar(s: &Self::Scalar) -> Self::EncodedScalar {
+        s.as_bytes()
+    }
+
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, Error> {
+        if bytes.len() != Self::SCALAR_LEN {
+            return Err(Error::WrongLength);
+        }
+        p256::Scalar::from_bytes_checked(bytes)
+    }
+
+    fn random_scalar(rng: &mut dyn RandomSource) -> Result<Self::Scalar, Error> {
+        p256::Scalar::generate(rng)
+    }
+
+    fn hash_to_group(input: &[&[u8]], dst: &[&[u8]]) -> Result<Self::Element, Error> {
+        let mut uniform = [0u8; 96];
+        expand_message_xmd::<Self::Hash>(input, dst, &mut uniform)?;
+        Ok(p256::Element::from_uniform_bytes(&uniform))
+    }
+
+    fn hash_to_scalar(input: &[&[u8]], dst: &[&[u8]]) -> Result<Self::Scalar, Error> {
+        let mut uniform = [0u8; 48];
+        expand_message_xmd::<Self::Hash>(input, dst, &mut uniform)?;
+        Ok(p256::Scalar::from_wide_bytes(&uniform))
+    }
+
+    fn scalar_add(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar {
+        a.add(b)
+    }
+
+    fn scalar_sub(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar {
+        a.sub(b)
+    }
+
+    fn scalar_mul(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar {
+        a.mul(b)
+    }
+
+    fn scalar_inv(s: &Self::Scalar) -> Self::Scalar {
+        s.inv()
+    }
+
+    fn scalar_eq(a: &Self::Scalar, b: &Self::Scalar) -> bool {
+        a.ct_equal(b)
+    }
+
+    fn scalar_is_zero(s: &Self::Scalar) -> bool {
+        s.is_zero()
+    }
+}
+
+/// `expand_message_xmd` from
+/// [RFC9380 section 5.3.1](https://datatracker.ietf.org/doc/html/rfc9380#section-5.3.1).
+///
+/// `msg` and `dst` are each the concatenation of their items.  This fills
+/// `out`, which must be no longer than 255 hash outputs or 65535 bytes.
+/// Domain separation tags longer than 255 bytes are hashed first, as
+/// specified in section 5.3.3.
+pub(crate) fn expand_message_xmd<H: Hash>(
+    msg: &[&[u8]],
+    dst: &[&[u8]],
+    out: &mut [u8],
+) -> Result<(), Error> {
+    let b_len = H::zeroed_output().as_ref().len();
+    let ell = (out.len() + b_len - 1) / b_len;
+    if ell > 255 || out.len() > 0xffff {
+        return Err(Error::WrongLength);
+    }
+
+    let dst_len: usize = dst.iter().map(|d| d.len()).sum();
+    if dst_len == 0 {
+        return Err(Error::WrongLength);
+    }
+
+    let hashed_dst = (dst_len > 255).then(|| {
+        let mut ctx = H::new();
+        ctx.update(b"H2C-OVERSIZE-DST-");
+        for d in dst {
+            ctx.update(d);
+        }
+        ctx.finish()
+    });
+    let add_dst_prime = |ctx: &mut H::Context| match &hashed_dst {
+        Some(hashed) => {
+            ctx.update(hashed.as_ref());
+            ctx.update(&[hashed.as_ref().len() as u8]);
+        }
+        None => {
+            for d in dst {
+                ctx.update(d);
+            }
+            ctx.update(&[dst_len as u8]);
+        }
+    };
+
+    let mut ctx = H::new();
+    ctx.update(&H::zeroed_block());
+    for m in msg {
+        ctx.update(m);
+    }
+    ctx.update(&(out.len() as u16).to_be_bytes());
+    ctx.update(&[0]);
+    add_dst_prime(&mut ctx);
+    let b_0 = ctx.finish();
+
+    let mut b_i = H::zeroed_output();
+    for (i, chunk) in (1u8..).zip(out.chunks_mut(b_len)) {
+        // b_0 xor b_(i - 1); b_1 takes just b_0
+        let mut x = b_0.clone();
+        for (x, bi) in x.as_mut().iter_mut().zip(b_i.as_ref()) {
+            *x ^= bi;
+        }
+
+        let mut ctx = H::new();
+        ctx.update(x.as_ref());
+        ctx.update(&[i]);
+        add_dst_prime(&mut ctx);
+        b_i = ctx.finish();
+        chunk.copy_from_slice(&b_i.as_ref()[..chunk.len()]);
+    }
+
+    Ok(())
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Ristretto255 {}
+    impl Sealed for super::P256 {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn expand_message_xmd_sha256() {
+        // RFC9380 appendix K.1
+        let dst: &[&[u8]] = &[b"QUUX-V01-CS02-with-expander-SHA256-128"];
+        let mut out = [0u8; 0x20];
+        expand_message_xmd::<Sha256>(&[b""], dst, &mut out).unwrap();
+        assert_eq!(
+            hex(&out),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        expand_message_xmd::<Sha256>(&[b"a", b"bc"], dst, &mut out).unwrap();
+        assert_eq!(
+            hex(&out),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+
+        let mut out = [0u8; 0x80];
+        expand_message_xmd::<Sha256>(&[b"abcdef0123456789"], dst, &mut out).unwrap();
+        assert_eq!(
+            hex(&out),
+            "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9\
+             ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4b\
+             c95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be1\
+             4cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df"
+        );
+    }
+
+    #[test]
+    fn expand_message_xmd_edge_cases() {
+        let mut out = [0u8; 0x20];
+        expand_message_xmd::<Sha512>(&[], &[b"QUUX-V01-CS02-with-expander-SHA512-256"], &mut out)
+            .unwrap();
+        assert_eq!(
+            hex(&out),
+            "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba"
+        );
+
+        // oversize DST is hashed
+        let long = [b'1'; 208];
+        expand_message_xmd::<Sha256>(
+            &[],
+            &[b"QUUX-V01-CS02-with-expander-SHA256-128-long-DST-", &long],
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(
+            hex(&out),
+            "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3"
+        );
+
+        assert_eq!(
+            expand_message_xmd::<Sha256>(&[], &[], &mut out),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            expand_message_xmd::<Sha256>(&[], &[b"dst"], &mut [0u8; 32 * 256]),
+            Err(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn p256_hash_to_curve() {
+        // P256_XMD:SHA-256_SSWU_RO_ from RFC9380 appendix J.1.1, as
+        // compressed points
+        let dst: &[&[u8]] = &[b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_"];
+        for (msg, point) in [
+            (
+                &b""[..],
+                "032c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+            ),
+            (
+                b"abc",
+                "020bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+            ),
+            (
+                b"abcdef0123456789",
+                "0365038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80",
+            ),
+        ] {
+            let p = P256::hash_to_group(&[msg], dst).unwrap();
+            assert_eq!(hex(&P256::serialize_element(&p)), point);
+        }
+
+        let s = P256::hash_to_scalar(&[b"abc"], dst).unwrap();
+        assert_eq!(
+            hex(&P256::serialize_scalar(&s)),
+            "fc85b6dac2e8be7343454b82c1bd5dad62cf42331f3fa060ff7407d79e15be6b"
+        );
+    }
+
+    fn check_group<G: Group>() {
+        let mut rng = SliceRandomSource(&[0x07; 64]);
+        let a = G::random_scalar(&mut rng).unwrap();
+        let mut rng = SliceRandomSource(&[0x05; 64]);
+        let b = G::random_scalar(&mut rng).unwrap();
+
+        // (a + b)G = aG + bG
+        let lhs = G::mul_gen(&G::scalar_add(&a, &b));
+        let rhs = G::add(&G::mul_gen(&a), &G::mul_gen(&b));
+        assert!(G::element_eq(&lhs, &rhs));
+
+        // a(bG) = (ab)G, and doubling via add
+        let ab = G::scalar_mul(&a, &b);
+        assert!(G::element_eq(
+            &G::mul(&G::mul_gen(&b), &a),
+            &G::mul_gen(&ab)
+        ));
+        let a2 = G::scalar_add(&a, &a);
+        let a_g = G::mul_gen(&a);
+        assert!(G::element_eq(&G::add(&a_g, &a_g), &G::mul_gen(&a2)));
+
+        // a / a = 1; a - a = 0
+        let one = G::scalar_mul(&a, &G::scalar_inv(&a));
+        assert!(G::element_eq(&G::mul(&a_g, &one), &a_g));
+        assert!(G::scalar_is_zero(&G::scalar_sub(&a, &a)));
+        assert!(G::scalar_eq(
+            &G::scalar_sub(&ab, &a),
+            &G::scalar_sub(&ab, &a)
+        ));
+
+        // identity behaviour
+        let id = G::identity();
+        assert!(G::is_identity(&id));
+        assert!(G::element_eq(&G::add(&id, &a_g), &a_g));
+        assert!(G::element_eq(&G::add(&a_g, &id), &a_g));
+        assert!(G::is_identity(&G::mul(&id, &a)));
+        assert!(!G::element_eq(&a_g, &id));
+
+        // round trips
+        let enc = G::serialize_element(&a_g);
+        assert_eq!(enc.as_ref().len(), G::ELEMENT_LEN);
+        let dec = G::deserialize_element(enc.as_ref()).unwrap();
+        assert!(G::element_eq(&dec, &a_g));
+        assert!(G::deserialize_element(G::serialize_element(&id).as_ref()).is_err());
+        assert!(G::deserialize_element(&enc.as_ref()[1..]).is_err());
+
+        let enc = G::serialize_scalar(&ab);
+        assert_eq!(enc.as_ref().len(), G::SCALAR_LEN);
+        let dec = G::deserialize_scalar(enc.as_ref()).unwrap();
+        assert!(G::scalar_eq(&dec, &ab));
+        assert!(G::deserialize_scalar(&[0; 32]).is_err());
+        assert!(G::deserialize_scalar(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn ristretto255_group() {
+        check_group::<Ristretto255>();
+    }
+
+    #[test]
+    fn p256_group() {
+        check_group::<P256>();
+    }
+}
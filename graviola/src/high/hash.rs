@@ -2,21 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
 use core::ops::{Deref, DerefMut};
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
 
+use super::pkcs1;
+use crate::Error;
 use crate::low::ct_equal;
-use crate::mid::sha2::{Sha256Context, Sha384Context, Sha512Context};
+use crate::mid::blake2::{Blake2bContext, Blake2sContext};
+use crate::mid::sha1::Sha1Context;
+use crate::mid::sha2::{Sha256Context, Sha384Context, Sha512Context, sha256_many};
 
 /// Output from a hash function.
 ///
 /// This has one variant per supported hash function.
 #[derive(Clone, Debug)]
 pub enum HashOutput {
+    /// Output from SHA-1
+    Sha1([u8; Sha1Context::OUTPUT_SZ]),
     /// Output from SHA256
     Sha256([u8; Sha256Context::OUTPUT_SZ]),
     /// Output from SHA384
     Sha384([u8; Sha384Context::OUTPUT_SZ]),
     /// Output from SHA512
     Sha512([u8; Sha512Context::OUTPUT_SZ]),
+    /// Output from BLAKE2s-256
+    Blake2s([u8; Blake2sContext::OUTPUT_SZ]),
+    /// Output from BLAKE2b-512
+    Blake2b([u8; Blake2bContext::OUTPUT_SZ]),
 }
 
 impl HashOutput {
@@ -43,9 +56,12 @@ impl HashOutput {
 impl PartialEq for HashOutput {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
+            (Self::Sha1(s), Self::Sha1(o)) => ct_equal(s, o),
             (Self::Sha256(s), Self::Sha256(o)) => ct_equal(s, o),
             (Self::Sha384(s), Self::Sha384(o)) => ct_equal(s, o),
             (Self::Sha512(s), Self::Sha512(o)) => ct_equal(s, o),
+            (Self::Blake2s(s), Self::Blake2s(o)) => ct_equal(s, o),
+            (Self::Blake2b(s), Self::Blake2b(o)) => ct_equal(s, o),
             _ => false,
         }
     }
@@ -54,9 +70,12 @@ impl PartialEq for HashOutput {
 impl AsRef<[u8]> for HashOutput {
     fn as_ref(&self) -> &[u8] {
         match self {
+            Self::Sha1(v) => v,
             Self::Sha256(v) => v,
             Self::Sha384(v) => v,
             Self::Sha512(v) => v,
+            Self::Blake2s(v) => v,
+            Self::Blake2b(v) => v,
         }
     }
 }
@@ -64,9 +83,12 @@ impl AsRef<[u8]> for HashOutput {
 impl AsMut<[u8]> for HashOutput {
     fn as_mut(&mut self) -> &mut [u8] {
         match self {
+            Self::Sha1(v) => v,
             Self::Sha256(v) => v,
             Self::Sha384(v) => v,
             Self::Sha512(v) => v,
+            Self::Blake2s(v) => v,
+            Self::Blake2b(v) => v,
         }
     }
 }
@@ -114,6 +136,32 @@ pub trait Hash {
     /// Hash the given bytes (one-shot style) and return the output.
     fn hash(bytes: &[u8]) -> HashOutput;
 
+    /// Hash everything read from `reader` until EOF, and return the output.
+    ///
+    /// Reads are done through an internal buffer, so `reader`
+    /// need not be buffered.  Errors from `reader` are returned
+    /// (except [`io::ErrorKind::Interrupted`], which is retried).
+    fn hash_reader(mut reader: impl Read) -> io::Result<HashOutput> {
+        // a multiple of all supported block sizes, so whole reads
+        // avoid the context's internal buffering.
+        let mut buf = [0u8; 16384];
+        let mut ctx = Self::new();
+
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => return Ok(ctx.finish()),
+                Ok(n) => ctx.update(&buf[..n]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Hash the contents of the file at `path`, and return the output.
+    fn hash_file(path: impl AsRef<Path>) -> io::Result<HashOutput> {
+        Self::hash_reader(File::open(path)?)
+    }
+
     /// Return a zeroed `HashBlock` of the correct size.
     fn zeroed_block() -> HashBlock;
 
@@ -121,6 +169,33 @@ pub trait Hash {
     fn zeroed_output() -> HashOutput;
 }
 
+/// Metadata describing a hash function.
+///
+/// This allows code that needs (for example) a hash function's OID to
+/// be generic over the hash function.
+pub trait Digest: Hash {
+    /// A conventional name for the hash function, eg. `"SHA-256"`.
+    const NAME: &'static str;
+
+    /// The length of the hash function's output, in bytes.
+    const OUTPUT_LEN: usize;
+
+    /// The hash function's internal block size, in bytes.
+    const BLOCK_LEN: usize;
+
+    /// The hash function's object identifier.
+    ///
+    /// This is the DER encoding of the OID's value (ie, without
+    /// the tag and length).
+    const OID: &'static [u8];
+
+    /// The DER encoding of a PKCS#1 `DigestInfo` structure for this
+    /// hash function, up to (but not including) the hash value itself.
+    ///
+    /// See [RFC8017 section 9.2](https://datatracker.ietf.org/doc/html/rfc8017#section-9.2).
+    const PKCS1_DIGEST_INFO: &'static [u8];
+}
+
 /// A generic trait over supported hash function contexts.
 ///
 /// These may be cloned: the semantics of that forks the
@@ -136,6 +211,48 @@ pub trait HashContext: Clone {
     fn finish(self) -> HashOutput;
 }
 
+/// This is SHA-1.
+///
+/// SHA-1 is standardized in [FIPS180](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf).
+///
+/// SHA-1 is not collision resistant, so this does not implement [`Digest`]
+/// and cannot be used for signatures.  It exists for HMAC-SHA1, which is
+/// the default (and most widely supported) choice for HOTP and TOTP.
+#[derive(Clone)]
+pub struct Sha1;
+
+impl Hash for Sha1 {
+    type Context = Sha1Context;
+
+    fn new() -> Self::Context {
+        Sha1Context::new()
+    }
+
+    fn hash(bytes: &[u8]) -> HashOutput {
+        let mut ctx = Self::new();
+        ctx.update(bytes);
+        HashOutput::Sha1(ctx.finish())
+    }
+
+    fn zeroed_block() -> HashBlock {
+        HashBlock::new(Sha1Context::BLOCK_SZ)
+    }
+
+    fn zeroed_output() -> HashOutput {
+        HashOutput::Sha1([0u8; Sha1Context::OUTPUT_SZ])
+    }
+}
+
+impl HashContext for Sha1Context {
+    fn update(&mut self, bytes: &[u8]) {
+        self.update(bytes)
+    }
+
+    fn finish(self) -> HashOutput {
+        HashOutput::Sha1(self.finish())
+    }
+}
+
 /// This is SHA256.
 ///
 /// SHA256 is standardized in [FIPS180](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf).
@@ -164,6 +281,38 @@ impl Hash for Sha256 {
     }
 }
 
+impl Digest for Sha256 {
+    const NAME: &'static str = "SHA-256";
+    const OUTPUT_LEN: usize = Sha256Context::OUTPUT_SZ;
+    const BLOCK_LEN: usize = Sha256Context::BLOCK_SZ;
+    const OID: &'static [u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+    const PKCS1_DIGEST_INFO: &'static [u8] = pkcs1::DIGESTINFO_SHA256;
+}
+
+impl Sha256 {
+    /// Hash many independent messages at once.
+    ///
+    /// `outputs[i]` is set to the SHA256 hash of `messages[i]`.
+    ///
+    /// This is faster than hashing each message separately on x86_64,
+    /// where up to eight messages are processed in lockstep.
+    /// It works best when the messages have similar lengths.
+    ///
+    /// Returns [`Error::WrongLength`] if `messages` and `outputs` have
+    /// different lengths.
+    pub fn hash_many(
+        messages: &[&[u8]],
+        outputs: &mut [[u8; Sha256Context::OUTPUT_SZ]],
+    ) -> Result<(), Error> {
+        if messages.len() != outputs.len() {
+            return Err(Error::WrongLength);
+        }
+
+        sha256_many(messages, outputs);
+        Ok(())
+    }
+}
+
 impl HashContext for Sha256Context {
     fn update(&mut self, bytes: &[u8]) {
         self.update(bytes)
@@ -202,6 +351,14 @@ impl Hash for Sha384 {
     }
 }
 
+impl Digest for Sha384 {
+    const NAME: &'static str = "SHA-384";
+    const OUTPUT_LEN: usize = Sha384Context::OUTPUT_SZ;
+    const BLOCK_LEN: usize = Sha384Context::BLOCK_SZ;
+    const OID: &'static [u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02];
+    const PKCS1_DIGEST_INFO: &'static [u8] = pkcs1::DIGESTINFO_SHA384;
+}
+
 impl HashContext for Sha384Context {
     fn update(&mut self, bytes: &[u8]) {
         self.update(bytes)
@@ -240,6 +397,14 @@ impl Hash for Sha512 {
     }
 }
 
+impl Digest for Sha512 {
+    const NAME: &'static str = "SHA-512";
+    const OUTPUT_LEN: usize = Sha512Context::OUTPUT_SZ;
+    const BLOCK_LEN: usize = Sha512Context::BLOCK_SZ;
+    const OID: &'static [u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03];
+    const PKCS1_DIGEST_INFO: &'static [u8] = pkcs1::DIGESTINFO_SHA512;
+}
+
 impl HashContext for Sha512Context {
     fn update(&mut self, bytes: &[u8]) {
         self.update(bytes)
@@ -250,6 +415,82 @@ impl HashContext for Sha512Context {
     }
 }
 
+/// This is BLAKE2s-256.
+///
+/// BLAKE2s is specified in [RFC7693](https://datatracker.ietf.org/doc/html/rfc7693).
+#[derive(Clone)]
+pub struct Blake2s;
+
+impl Hash for Blake2s {
+    type Context = Blake2sContext;
+
+    fn new() -> Self::Context {
+        Blake2sContext::new()
+    }
+
+    fn hash(bytes: &[u8]) -> HashOutput {
+        let mut ctx = Self::new();
+        ctx.update(bytes);
+        HashOutput::Blake2s(ctx.finish())
+    }
+
+    fn zeroed_block() -> HashBlock {
+        HashBlock::new(Blake2sContext::BLOCK_SZ)
+    }
+
+    fn zeroed_output() -> HashOutput {
+        HashOutput::Blake2s([0u8; Blake2sContext::OUTPUT_SZ])
+    }
+}
+
+impl HashContext for Blake2sContext {
+    fn update(&mut self, bytes: &[u8]) {
+        self.update(bytes)
+    }
+
+    fn finish(self) -> HashOutput {
+        HashOutput::Blake2s(self.finish())
+    }
+}
+
+/// This is BLAKE2b-512.
+///
+/// BLAKE2b is specified in [RFC7693](https://datatracker.ietf.org/doc/html/rfc7693).
+#[derive(Clone)]
+pub struct Blake2b;
+
+impl Hash for Blake2b {
+    type Context = Blake2bContext;
+
+    fn new() -> Self::Context {
+        Blake2bContext::new()
+    }
+
+    fn hash(bytes: &[u8]) -> HashOutput {
+        let mut ctx = Self::new();
+        ctx.update(bytes);
+        HashOutput::Blake2b(ctx.finish())
+    }
+
+    fn zeroed_block() -> HashBlock {
+        HashBlock::new(Blake2bContext::BLOCK_SZ)
+    }
+
+    fn zeroed_output() -> HashOutput {
+        HashOutput::Blake2b([0u8; Blake2bContext::OUTPUT_SZ])
+    }
+}
+
+impl HashContext for Blake2bContext {
+    fn update(&mut self, bytes: &[u8]) {
+        self.update(bytes)
+    }
+
+    fn finish(self) -> HashOutput {
+        HashOutput::Blake2b(self.finish())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -440,4 +681,123 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn hash_reader() {
+        let message = (0..100_000u32).map(|i| i as u8).collect::<Vec<_>>();
+        assert_eq!(
+            Sha256::hash_reader(&message[..]).unwrap(),
+            Sha256::hash(&message)
+        );
+        assert_eq!(
+            Sha384::hash_reader(&message[..]).unwrap(),
+            Sha384::hash(&message)
+        );
+        assert_eq!(
+            Sha512::hash_reader(&message[..]).unwrap(),
+            Sha512::hash(&message)
+        );
+        assert_eq!(Sha256::hash_reader(&b""[..]).unwrap(), Sha256::hash(b""));
+    }
+
+    #[test]
+    fn hash_reader_errors() {
+        /// Yields one byte per read, interleaved with `Interrupted` errors,
+        /// then fails permanently after `fail_after` bytes.
+        struct Awkward {
+            data: Vec<u8>,
+            interrupt: bool,
+            fail_after: usize,
+        }
+
+        impl Read for Awkward {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.interrupt = !self.interrupt;
+                if self.interrupt {
+                    return Err(io::ErrorKind::Interrupted.into());
+                }
+                if self.fail_after == 0 {
+                    return Err(io::ErrorKind::BrokenPipe.into());
+                }
+                match self.data.split_first() {
+                    Some((first, rest)) => {
+                        buf[0] = *first;
+                        self.data = rest.to_vec();
+                        self.fail_after -= 1;
+                        Ok(1)
+                    }
+                    None => Ok(0),
+                }
+            }
+        }
+
+        let ok = Awkward {
+            data: b"hello world".to_vec(),
+            interrupt: false,
+            fail_after: usize::MAX,
+        };
+        assert_eq!(
+            Sha256::hash_reader(ok).unwrap(),
+            Sha256::hash(b"hello world")
+        );
+
+        let broken = Awkward {
+            data: b"hello world".to_vec(),
+            interrupt: false,
+            fail_after: 5,
+        };
+        assert_eq!(
+            Sha256::hash_reader(broken).unwrap_err().kind(),
+            io::ErrorKind::BrokenPipe
+        );
+    }
+
+    #[test]
+    fn hash_many() {
+        let messages = [&b"hello"[..], b"", &[0xff; 300]];
+        let mut outputs = [[0u8; 32]; 3];
+        Sha256::hash_many(&messages, &mut outputs).unwrap();
+        for (message, output) in messages.iter().zip(outputs.iter()) {
+            assert!(Sha256::hash(message).ct_equal(output));
+        }
+
+        assert_eq!(
+            Sha256::hash_many(&messages, &mut outputs[..2]),
+            Err(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn hash_file() {
+        assert_eq!(
+            Sha256::hash_file("src/testdata/aes-gcm-ciphertext.bin").unwrap(),
+            Sha256::hash(include_bytes!("../testdata/aes-gcm-ciphertext.bin"))
+        );
+        assert_eq!(
+            Sha512::hash_file("src/testdata/does-not-exist")
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn digest_metadata() {
+        fn check<D: Digest>(name: &str, oid: &crate::high::asn1::ObjectId) {
+            assert_eq!(D::NAME, name);
+            assert_eq!(D::OUTPUT_LEN, D::zeroed_output().as_ref().len());
+            assert_eq!(D::BLOCK_LEN, D::zeroed_block().len());
+            assert_eq!(D::OID, oid.as_ref());
+            // DigestInfo ends with the OCTET STRING header for the hash
+            assert_eq!(
+                &D::PKCS1_DIGEST_INFO[D::PKCS1_DIGEST_INFO.len() - 2..],
+                &[0x04, D::OUTPUT_LEN as u8]
+            );
+        }
+
+        use crate::high::asn1::oid;
+        check::<Sha256>("SHA-256", &oid::id_sha256);
+        check::<Sha384>("SHA-384", &oid::id_sha384);
+        check::<Sha512>("SHA-512", &oid::id_sha512);
+    }
 }
@@ -0,0 +1,108 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Hex.
+//!
+//! These are constant-time, as the encoded data is often secret.
+
+use super::base64::ct_range;
+use crate::low::zeroise;
+
+/// Lowercase hex encoding.
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len() * 2);
+    for b in data {
+        out.push(encode_nibble(b >> 4) as char);
+        out.push(encode_nibble(b & 0xf) as char);
+    }
+    out
+}
+
+/// Hex decoding.
+///
+/// Upper and lowercase digits are accepted.
+pub(crate) fn decode(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(text.len() / 2);
+    let mut invalid = 0u8;
+    for pair in text.as_bytes().chunks_exact(2) {
+        let (hi, hi_valid) = decode_nibble(pair[0]);
+        let (lo, lo_valid) = decode_nibble(pair[1]);
+        invalid |= !(hi_valid & lo_valid);
+        out.push((hi << 4) | lo);
+    }
+
+    match invalid {
+        0 => Some(out),
+        _ => {
+            zeroise(&mut out);
+            None
+        }
+    }
+}
+
+/// Maps `v` (which must be less than 16) to its lowercase digit,
+/// without table lookups or branches.
+fn encode_nibble(v: u8) -> u8 {
+    let v = i16::from(v);
+    (v + i16::from(b'0') + (((9 - v) >> 8) & i16::from(b'a' - b'9' - 1))) as u8
+}
+
+/// Maps `c` to its value, and 0xff if it is a hex digit (0 if not),
+/// without table lookups or branches.
+fn decode_nibble(c: u8) -> (u8, u8) {
+    let digit = ct_range(c, b'0', b'9');
+    let lower = ct_range(c, b'a', b'f');
+    let upper = ct_range(c, b'A', b'F');
+
+    let value = (digit & c.wrapping_sub(b'0'))
+        | (lower & c.wrapping_sub(b'a' - 10))
+        | (upper & c.wrapping_sub(b'A' - 10));
+    (value, digit | lower | upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for (data, text) in [
+            (&b""[..], ""),
+            (b"\x00", "00"),
+            (b"\x01\x23\x45\x67\x89\xab\xcd\xef", "0123456789abcdef"),
+            (b"\xff\x10", "ff10"),
+        ] {
+            assert_eq!(encode(data), text);
+            assert_eq!(decode(text).unwrap(), data);
+            assert_eq!(decode(&text.to_uppercase()).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn characters() {
+        for v in 0..16u8 {
+            assert_eq!(encode_nibble(v), b"0123456789abcdef"[v as usize]);
+        }
+
+        for c in 0..=255u8 {
+            let expect = (c as char).to_digit(16).map(|v| v as u8);
+            let got = match decode_nibble(c) {
+                (v, 0xff) => Some(v),
+                (_, 0) => None,
+                _ => panic!("invalid mask for {c:#x}"),
+            };
+            assert_eq!(got, expect, "{c:#x}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for text in ["0", "abc", "0g", "g0", " 00", "0x00", "é0"] {
+            assert!(decode(text).is_none(), "{text:?}");
+        }
+    }
+}
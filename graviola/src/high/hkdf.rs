@@ -0,0 +1,174 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! HKDF (HMAC-based Extract-and-Expand Key Derivation Function).
+//!
+//! HKDF is standardized in [RFC5869](https://datatracker.ietf.org/doc/html/rfc5869).
+//!
+//! ```
+//! use graviola::hashing::{hkdf, Sha256};
+//!
+//! let prk = hkdf::extract::<Sha256>(b"salt", &[b"input key material"]);
+//! let mut key = [0u8; 32];
+//! prk.expand(&[b"context"], &mut key).unwrap();
+//! ```
+
+use super::hash::Hash;
+use super::hmac::Hmac;
+use crate::Error;
+
+/// A pseudorandom key, the output of HKDF-Extract.
+///
+/// This is the input to HKDF-Expand.  The key itself is only
+/// held in the form of a keyed HMAC computation.
+#[derive(Clone)]
+pub struct Prk<H: Hash + Clone> {
+    hmac: Hmac<H>,
+}
+
+impl<H: Hash + Clone> Prk<H> {
+    /// Use `prk` directly as a pseudorandom key.
+    ///
+    /// This is for protocols which obtain a PRK some other way than
+    /// [`extract`].  `prk` should be at least as long as the hash
+    /// function output.
+    pub fn new(prk: &[u8]) -> Self {
+        Self {
+            hmac: Hmac::new(prk),
+        }
+    }
+
+    /// HKDF-Expand: fill `out` with output keying material.
+    ///
+    /// `info` is the concatenation of the given slices.
+    ///
+    /// `out` may be at most 255 times the hash function output
+    /// length, otherwise [`Error::WrongLength`] is returned.
+    pub fn expand(&self, info: &[&[u8]], out: &mut [u8]) -> Result<(), Error> {
+        let hash_len = H::zeroed_output().as_ref().len();
+        if out.len() > 255 * hash_len {
+            return Err(Error::WrongLength);
+        }
+
+        let mut t = H::zeroed_output();
+        for (i, chunk) in out.chunks_mut(hash_len).enumerate() {
+            let mut ctx = self.hmac.clone();
+            if i > 0 {
+                ctx.update(&t);
+            }
+            for part in info {
+                ctx.update(part);
+            }
+            ctx.update([i as u8 + 1]);
+            t = ctx.finish();
+            chunk.copy_from_slice(&t.as_ref()[..chunk.len()]);
+        }
+
+        Ok(())
+    }
+}
+
+/// HKDF-Extract: compute a pseudorandom key from `salt` and
+/// input keying material `ikm`.
+///
+/// `ikm` is the concatenation of the given slices.  An empty `salt`
+/// is equivalent to a string of zero bytes the length of the hash
+/// function's output, as the RFC requires.
+pub fn extract<H: Hash + Clone>(salt: &[u8], ikm: &[&[u8]]) -> Prk<H> {
+    // HMAC zero-pads its key, so no special case is needed for an empty salt.
+    let mut hmac = Hmac::<H>::new(salt);
+    for part in ikm {
+        hmac.update(part);
+    }
+    Prk::new(hmac.finish().as_ref())
+}
+
+/// HKDF-Extract then HKDF-Expand, in one shot.
+///
+/// See [`extract`] and [`Prk::expand`].
+pub fn hkdf<H: Hash + Clone>(
+    salt: &[u8],
+    ikm: &[u8],
+    info: &[u8],
+    out: &mut [u8],
+) -> Result<(), Error> {
+    extract::<H>(salt, &[ikm]).expand(&[info], out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::{Sha256, Sha512};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn rfc5869_case_1() {
+        let salt: [u8; 13] = core::array::from_fn(|i| i as u8);
+        let info: [u8; 10] = core::array::from_fn(|i| 0xf0 + i as u8);
+        let mut okm = [0u8; 42];
+        hkdf::<Sha256>(&salt, &[0x0b; 22], &info, &mut okm).unwrap();
+        assert_eq!(
+            hex(&okm),
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+        );
+
+        // the PRK is as given in the RFC
+        let prk = Prk::<Sha256>::new(&[
+            0x07, 0x77, 0x09, 0x36, 0x2c, 0x2e, 0x32, 0xdf, 0x0d, 0xdc, 0x3f, 0x0d, 0xc4, 0x7b,
+            0xba, 0x63, 0x90, 0xb6, 0xc7, 0x3b, 0xb5, 0x0f, 0x9c, 0x31, 0x22, 0xec, 0x84, 0x4a,
+            0xd7, 0xc2, 0xb3, 0xe5,
+        ]);
+        let mut okm2 = [0u8; 42];
+        prk.expand(&[&info[..4], &info[4..]], &mut okm2).unwrap();
+        assert_eq!(okm, okm2);
+    }
+
+    #[test]
+    fn rfc5869_case_2() {
+        let ikm: [u8; 80] = core::array::from_fn(|i| i as u8);
+        let salt: [u8; 80] = core::array::from_fn(|i| 0x60 + i as u8);
+        let info: [u8; 80] = core::array::from_fn(|i| 0xb0 + i as u8);
+        let mut okm = [0u8; 82];
+        hkdf::<Sha256>(&salt, &ikm, &info, &mut okm).unwrap();
+        assert_eq!(
+            hex(&okm),
+            "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c\
+             59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71\
+             cc30c58179ec3e87c14c01d5c1f3434f1d87"
+        );
+    }
+
+    #[test]
+    fn rfc5869_case_3() {
+        let mut okm = [0u8; 42];
+        hkdf::<Sha256>(&[], &[0x0b; 22], &[], &mut okm).unwrap();
+        assert_eq!(
+            hex(&okm),
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8"
+        );
+    }
+
+    #[test]
+    fn sha512() {
+        let salt: [u8; 13] = core::array::from_fn(|i| i as u8);
+        let info: [u8; 10] = core::array::from_fn(|i| 0xf0 + i as u8);
+        let mut okm = [0u8; 42];
+        hkdf::<Sha512>(&salt, &[0x0b; 22], &info, &mut okm).unwrap();
+        assert_eq!(
+            hex(&okm),
+            "832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7fe92c1481579338da362cb8d9f925d7cb"
+        );
+    }
+
+    #[test]
+    fn output_length_limit() {
+        let prk = extract::<Sha256>(&[], &[b"ikm"]);
+        let mut okm = [0u8; 255 * 32 + 1];
+        assert_eq!(prk.expand(&[], &mut okm), Err(Error::WrongLength));
+        assert_eq!(prk.expand(&[], &mut okm[..255 * 32]), Ok(()));
+        assert_eq!(prk.expand(&[], &mut []), Ok(()));
+    }
+}
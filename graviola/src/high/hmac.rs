@@ -5,8 +5,9 @@
 //!
 //! HMAC is standardized in [FIPS 198](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.198-1.pdf).
 
-use super::hash::{Hash, HashContext, HashOutput};
+use super::hash::{Hash, HashContext, HashOutput, Sha256, Sha384, Sha512};
 use crate::Error;
+use crate::mid::sha2::{Sha256Context, Sha384Context, Sha512Context};
 
 /// An in-progress HMAC computation, using hash function `H`.
 #[derive(Clone)]
@@ -72,10 +73,66 @@ impl<H: Hash> Hmac<H> {
     }
 }
 
+/// Compute HMAC-SHA256 of `message` using `key`, in one shot.
+pub fn hmac_sha256(
+    key: impl AsRef<[u8]>,
+    message: impl AsRef<[u8]>,
+) -> [u8; Sha256Context::OUTPUT_SZ] {
+    let mut out = [0u8; Sha256Context::OUTPUT_SZ];
+    out.copy_from_slice(one_shot::<Sha256>(key, message).as_ref());
+    out
+}
+
+/// Compute HMAC-SHA384 of `message` using `key`, in one shot.
+pub fn hmac_sha384(
+    key: impl AsRef<[u8]>,
+    message: impl AsRef<[u8]>,
+) -> [u8; Sha384Context::OUTPUT_SZ] {
+    let mut out = [0u8; Sha384Context::OUTPUT_SZ];
+    out.copy_from_slice(one_shot::<Sha384>(key, message).as_ref());
+    out
+}
+
+/// Compute HMAC-SHA512 of `message` using `key`, in one shot.
+pub fn hmac_sha512(
+    key: impl AsRef<[u8]>,
+    message: impl AsRef<[u8]>,
+) -> [u8; Sha512Context::OUTPUT_SZ] {
+    let mut out = [0u8; Sha512Context::OUTPUT_SZ];
+    out.copy_from_slice(one_shot::<Sha512>(key, message).as_ref());
+    out
+}
+
+/// Check that `tag` is the HMAC of `message` using `key` and hash function `H`.
+///
+/// The comparison is done in constant-time.  `tag` may not be truncated.
+///
+/// ```
+/// use graviola::hashing::{hmac, Sha256};
+///
+/// let tag = hmac::hmac_sha256(b"key", b"message");
+/// assert!(hmac::verify::<Sha256>(b"key", b"message", &tag).is_ok());
+/// assert!(hmac::verify::<Sha256>(b"key", b"massage", &tag).is_err());
+/// ```
+pub fn verify<H: Hash>(
+    key: impl AsRef<[u8]>,
+    message: impl AsRef<[u8]>,
+    tag: &[u8],
+) -> Result<(), Error> {
+    let mut h = Hmac::<H>::new(key);
+    h.update(message);
+    h.verify(tag)
+}
+
+fn one_shot<H: Hash>(key: impl AsRef<[u8]>, message: impl AsRef<[u8]>) -> HashOutput {
+    let mut h = Hmac::<H>::new(key);
+    h.update(message);
+    h.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::high::hash::{Sha256, Sha384, Sha512};
     use crate::test::*;
 
     #[test]
@@ -86,6 +143,44 @@ mod tests {
                    HashOutput::Sha256(*b"\xf1\xac\x97\x02\xeb\x5f\xaf\x23\xca\x29\x1a\x4d\xc4\x6d\xed\xde\xee\x2a\x78\xcc\xda\xf0\xa4\x12\xbe\xd7\x71\x4c\xff\xfb\x1c\xc4"));
     }
 
+    #[test]
+    fn one_shot() {
+        assert_eq!(&hmac_sha256(b"hello", b"world"),
+                   b"\xf1\xac\x97\x02\xeb\x5f\xaf\x23\xca\x29\x1a\x4d\xc4\x6d\xed\xde\xee\x2a\x78\xcc\xda\xf0\xa4\x12\xbe\xd7\x71\x4c\xff\xfb\x1c\xc4");
+
+        for (key, message) in [
+            (&b""[..], &b""[..]),
+            (b"key", b"message"),
+            (&[0xaa; 200], b"long key"),
+        ] {
+            let mut h = Hmac::<Sha384>::new(key);
+            h.update(message);
+            assert_eq!(&hmac_sha384(key, message)[..], h.finish().as_ref());
+
+            let mut h = Hmac::<Sha512>::new(key);
+            h.update(message);
+            assert_eq!(&hmac_sha512(key, message)[..], h.finish().as_ref());
+        }
+    }
+
+    #[test]
+    fn one_shot_verify() {
+        let tag = hmac_sha384(b"key", b"message");
+        assert_eq!(verify::<Sha384>(b"key", b"message", &tag), Ok(()));
+        assert_eq!(
+            verify::<Sha384>(b"key", b"message", &tag[..32]),
+            Err(Error::BadSignature)
+        );
+        assert_eq!(
+            verify::<Sha384>(b"yek", b"message", &tag),
+            Err(Error::BadSignature)
+        );
+        assert_eq!(
+            verify::<Sha512>(b"key", b"message", &tag),
+            Err(Error::BadSignature)
+        );
+    }
+
     #[test]
     fn cavp() {
         #[derive(Debug)]
@@ -0,0 +1,1314 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::marker::PhantomData;
+
+use super::curve::P256;
+use super::hash::{Hash, HashOutput, Sha256, Sha384, Sha512};
+use super::hkdf::Prk;
+use super::hmac::Hmac;
+use super::kem::{Kem, MlKem512, MlKem768, MlKem1024, X25519};
+use crate::Error;
+use crate::low::{Entry, zeroise};
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::chacha20poly1305;
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::{p256, x25519};
+
+/// A KEM usable with HPKE.
+///
+/// The shared secret (`Nsecret`) is 32 bytes for all the KEMs offered here.
+pub trait HpkeKem: Kem + private::KemOps {
+    /// The KEM identifier, from the IANA HPKE registry.
+    const KEM_ID: u16;
+}
+
+/// A Diffie-Hellman based KEM ("DHKEM").
+///
+/// These additionally support the authenticated HPKE modes,
+/// and deterministic key derivation.
+pub trait DhKem: HpkeKem + private::DhOps {
+    /// `DeriveKeyPair()`: deterministically derive a decapsulation key from `ikm`.
+    ///
+    /// `ikm` should have at least as much entropy as the private key is long.
+    fn derive_key_pair(ikm: &[u8]) -> Result<Self::DecapsulationKey, Error>;
+}
+
+/// A key derivation function usable with HPKE.
+pub trait HpkeKdf: private::Sealed {
+    /// The KDF identifier, from the IANA HPKE registry.
+    const KDF_ID: u16;
+
+    /// The hash function used with HKDF.
+    type Hash: Hash + Clone;
+}
+
+/// An AEAD usable with HPKE.
+pub trait HpkeAead: private::AeadOps {
+    /// The AEAD identifier, from the IANA HPKE registry.
+    const AEAD_ID: u16;
+}
+
+/// HKDF-SHA256.
+pub struct HkdfSha256;
+
+/// HKDF-SHA384.
+pub struct HkdfSha384;
+
+/// HKDF-SHA512.
+pub struct HkdfSha512;
+
+/// AES-128-GCM.
+pub struct Aes128Gcm;
+
+/// AES-256-GCM.
+pub struct Aes256Gcm;
+
+/// ChaCha20-Poly1305.
+pub struct ChaCha20Poly1305;
+
+/// The "export-only" AEAD.
+///
+/// Contexts using this may only export secrets: sealing and
+/// opening fail with [`Error::OutOfRange`].
+pub struct ExportOnly;
+
+/// An HPKE cipher suite: KEM `K`, KDF `F` and AEAD `A`.
+///
+/// The `setup_*` functions start a multi-message context in each of
+/// the four HPKE modes.  The authenticated modes are only available with
+/// a [`DhKem`].  [`Suite::seal`], [`Suite::open`], [`Suite::send_export`]
+/// and [`Suite::receive_export`] are single-shot helpers for the base mode.
+pub struct Suite<K, F, A>(PhantomData<(K, F, A)>);
+
+impl<K: HpkeKem, F: HpkeKdf, A: HpkeAead> Suite<K, F, A> {
+    /// `SetupBaseS()`: start a base mode sender context, encrypting to `pk_r`.
+    ///
+    /// Returns the encapsulated key `enc` which must be given
+    /// to the recipient, and the context.
+    pub fn setup_base_sender(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+    ) -> Result<(K::Ciphertext, SenderContext<F, A>), Error> {
+        Self::setup_base_sender_with_rng(pk_r, info, &mut SystemRandom)
+    }
+
+    /// As [`Suite::setup_base_sender`], but using `rng` for the encapsulation.
+    pub fn setup_base_sender_with_rng(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(K::Ciphertext, SenderContext<F, A>), Error> {
+        let (enc, mut shared_secret) = K::encap(pk_r, rng)?;
+        let ctx = Self::key_schedule(MODE_BASE, &shared_secret, info, &[], &[]);
+        zeroise(&mut shared_secret);
+        Ok((enc, SenderContext(ctx?)))
+    }
+
+    /// `SetupBaseR()`: start a base mode recipient context, for `enc`
+    /// encapsulated to `sk_r`.
+    pub fn setup_base_recipient(
+        enc: &[u8],
+        sk_r: &K::DecapsulationKey,
+        info: &[u8],
+    ) -> Result<RecipientContext<F, A>, Error> {
+        let mut shared_secret = K::decap(enc, sk_r)?;
+        let ctx = Self::key_schedule(MODE_BASE, &shared_secret, info, &[], &[]);
+        zeroise(&mut shared_secret);
+        Ok(RecipientContext(ctx?))
+    }
+
+    /// `SetupPSKS()`: start a PSK mode sender context, encrypting to `pk_r`.
+    ///
+    /// `psk` and `psk_id` must both be non-empty, otherwise
+    /// [`Error::OutOfRange`] is returned.  `psk` should have at least
+    /// 32 bytes of entropy.
+    pub fn setup_psk_sender(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+    ) -> Result<(K::Ciphertext, SenderContext<F, A>), Error> {
+        Self::setup_psk_sender_with_rng(pk_r, info, psk, psk_id, &mut SystemRandom)
+    }
+
+    /// As [`Suite::setup_psk_sender`], but using `rng` for the encapsulation.
+    pub fn setup_psk_sender_with_rng(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(K::Ciphertext, SenderContext<F, A>), Error> {
+        let (enc, mut shared_secret) = K::encap(pk_r, rng)?;
+        let ctx = Self::key_schedule(MODE_PSK, &shared_secret, info, psk, psk_id);
+        zeroise(&mut shared_secret);
+        Ok((enc, SenderContext(ctx?)))
+    }
+
+    /// `SetupPSKR()`: start a PSK mode recipient context, for `enc`
+    /// encapsulated to `sk_r`.
+    pub fn setup_psk_recipient(
+        enc: &[u8],
+        sk_r: &K::DecapsulationKey,
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+    ) -> Result<RecipientContext<F, A>, Error> {
+        let mut shared_secret = K::decap(enc, sk_r)?;
+        let ctx = Self::key_schedule(MODE_PSK, &shared_secret, info, psk, psk_id);
+        zeroise(&mut shared_secret);
+        Ok(RecipientContext(ctx?))
+    }
+
+    /// Single-shot base mode encryption to `pk_r`.
+    ///
+    /// On entry, `cipher_inout` contains the plaintext.  On exit it
+    /// contains the ciphertext, and `tag_out` the authentication tag.
+    /// The encapsulated key `enc` is returned.
+    pub fn seal(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag_out: &mut [u8; 16],
+    ) -> Result<K::Ciphertext, Error> {
+        let (enc, mut ctx) = Self::setup_base_sender(pk_r, info)?;
+        ctx.seal(aad, cipher_inout, tag_out)?;
+        Ok(enc)
+    }
+
+    /// Single-shot base mode decryption, of `enc` encapsulated to `sk_r`.
+    ///
+    /// On entry, `cipher_inout` contains the ciphertext.  On success,
+    /// it contains the plaintext.  Otherwise [`Error::DecryptFailed`]
+    /// is returned and `cipher_inout` is cleared.
+    pub fn open(
+        enc: &[u8],
+        sk_r: &K::DecapsulationKey,
+        info: &[u8],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        Self::setup_base_recipient(enc, sk_r, info)?.open(aad, cipher_inout, tag)
+    }
+
+    /// Single-shot base mode secret export, to `pk_r`.
+    ///
+    /// `out` is filled with the exported secret, and the
+    /// encapsulated key `enc` is returned.
+    pub fn send_export(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        exporter_context: &[u8],
+        out: &mut [u8],
+    ) -> Result<K::Ciphertext, Error> {
+        let (enc, ctx) = Self::setup_base_sender(pk_r, info)?;
+        ctx.export(exporter_context, out)?;
+        Ok(enc)
+    }
+
+    /// Single-shot base mode secret export, of `enc` encapsulated to `sk_r`.
+    pub fn receive_export(
+        enc: &[u8],
+        sk_r: &K::DecapsulationKey,
+        info: &[u8],
+        exporter_context: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), Error> {
+        Self::setup_base_recipient(enc, sk_r, info)?.export(exporter_context, out)
+    }
+
+    fn key_schedule(
+        mode: u8,
+        shared_secret: &[u8],
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+    ) -> Result<Context<F, A>, Error> {
+        let _entry = Entry::new_secret();
+        let uses_psk = mode == MODE_PSK || mode == MODE_AUTH_PSK;
+        if psk.is_empty() != psk_id.is_empty() || psk.is_empty() == uses_psk {
+            return Err(Error::OutOfRange);
+        }
+
+        let suite_id = Self::suite_id();
+        let psk_id_hash = labeled_extract::<F::Hash>(&suite_id, &[], b"psk_id_hash", &[psk_id]);
+        let info_hash = labeled_extract::<F::Hash>(&suite_id, &[], b"info_hash", &[info]);
+
+        let mut context_buf = [0u8; 1 + 2 * MAX_HASH_LEN];
+        let hash_len = psk_id_hash.as_ref().len();
+        context_buf[0] = mode;
+        context_buf[1..1 + hash_len].copy_from_slice(psk_id_hash.as_ref());
+        context_buf[1 + hash_len..1 + 2 * hash_len].copy_from_slice(info_hash.as_ref());
+        let key_schedule_context = &context_buf[..1 + 2 * hash_len];
+
+        let mut secret = labeled_extract::<F::Hash>(&suite_id, shared_secret, b"secret", &[psk]);
+        let prk = Prk::<F::Hash>::new(secret.as_ref());
+        zeroise(secret.as_mut());
+
+        let mut key = [0u8; MAX_KEY_LEN];
+        let key = &mut key[..A::KEY_LEN];
+        labeled_expand(&prk, &suite_id, b"key", key_schedule_context, key)?;
+        let cipher = A::new_cipher(key);
+        zeroise(key);
+
+        let mut base_nonce = [0u8; NONCE_LEN];
+        if A::KEY_LEN > 0 {
+            labeled_expand(
+                &prk,
+                &suite_id,
+                b"base_nonce",
+                key_schedule_context,
+                &mut base_nonce,
+            )?;
+        }
+
+        let mut exporter_secret = F::Hash::zeroed_output();
+        labeled_expand(
+            &prk,
+            &suite_id,
+            b"exp",
+            key_schedule_context,
+            exporter_secret.as_mut(),
+        )?;
+        let exporter_secret_prk = Prk::new(exporter_secret.as_ref());
+        zeroise(exporter_secret.as_mut());
+
+        Ok(Context {
+            cipher,
+            base_nonce,
+            seq: 0,
+            exporter_secret: exporter_secret_prk,
+            suite_id,
+        })
+    }
+
+    fn suite_id() -> [u8; 10] {
+        let mut suite_id = *b"HPKE\0\0\0\0\0\0";
+        suite_id[4..6].copy_from_slice(&K::KEM_ID.to_be_bytes());
+        suite_id[6..8].copy_from_slice(&F::KDF_ID.to_be_bytes());
+        suite_id[8..10].copy_from_slice(&A::AEAD_ID.to_be_bytes());
+        suite_id
+    }
+}
+
+impl<K: DhKem, F: HpkeKdf, A: HpkeAead> Suite<K, F, A> {
+    /// `SetupAuthS()`: start an auth mode sender context, encrypting to
+    /// `pk_r` and authenticated with the sender's key `sk_s`.
+    pub fn setup_auth_sender(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        sk_s: &K::DecapsulationKey,
+    ) -> Result<(K::Ciphertext, SenderContext<F, A>), Error> {
+        Self::setup_auth_sender_with_rng(pk_r, info, sk_s, &mut SystemRandom)
+    }
+
+    /// As [`Suite::setup_auth_sender`], but using `rng` for the encapsulation.
+    pub fn setup_auth_sender_with_rng(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        sk_s: &K::DecapsulationKey,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(K::Ciphertext, SenderContext<F, A>), Error> {
+        let (enc, mut shared_secret) = dh_encap::<K>(pk_r, Some(sk_s), rng)?;
+        let ctx = Self::key_schedule(MODE_AUTH, &shared_secret, info, &[], &[]);
+        zeroise(&mut shared_secret);
+        Ok((enc, SenderContext(ctx?)))
+    }
+
+    /// `SetupAuthR()`: start an auth mode recipient context, for `enc`
+    /// encapsulated to `sk_r` by the holder of `pk_s`.
+    pub fn setup_auth_recipient(
+        enc: &[u8],
+        sk_r: &K::DecapsulationKey,
+        info: &[u8],
+        pk_s: &K::EncapsulationKey,
+    ) -> Result<RecipientContext<F, A>, Error> {
+        let mut shared_secret = dh_decap::<K>(enc, sk_r, Some(pk_s))?;
+        let ctx = Self::key_schedule(MODE_AUTH, &shared_secret, info, &[], &[]);
+        zeroise(&mut shared_secret);
+        Ok(RecipientContext(ctx?))
+    }
+
+    /// `SetupAuthPSKS()`: start an auth-PSK mode sender context.
+    ///
+    /// This combines [`Suite::setup_psk_sender`] and [`Suite::setup_auth_sender`].
+    pub fn setup_auth_psk_sender(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+        sk_s: &K::DecapsulationKey,
+    ) -> Result<(K::Ciphertext, SenderContext<F, A>), Error> {
+        Self::setup_auth_psk_sender_with_rng(pk_r, info, psk, psk_id, sk_s, &mut SystemRandom)
+    }
+
+    /// As [`Suite::setup_auth_psk_sender`], but using `rng` for the encapsulation.
+    pub fn setup_auth_psk_sender_with_rng(
+        pk_r: &K::EncapsulationKey,
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+        sk_s: &K::DecapsulationKey,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(K::Ciphertext, SenderContext<F, A>), Error> {
+        let (enc, mut shared_secret) = dh_encap::<K>(pk_r, Some(sk_s), rng)?;
+        let ctx = Self::key_schedule(MODE_AUTH_PSK, &shared_secret, info, psk, psk_id);
+        zeroise(&mut shared_secret);
+        Ok((enc, SenderContext(ctx?)))
+    }
+
+    /// `SetupAuthPSKR()`: start an auth-PSK mode recipient context.
+    ///
+    /// This combines [`Suite::setup_psk_recipient`] and [`Suite::setup_auth_recipient`].
+    pub fn setup_auth_psk_recipient(
+        enc: &[u8],
+        sk_r: &K::DecapsulationKey,
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+        pk_s: &K::EncapsulationKey,
+    ) -> Result<RecipientContext<F, A>, Error> {
+        let mut shared_secret = dh_decap::<K>(enc, sk_r, Some(pk_s))?;
+        let ctx = Self::key_schedule(MODE_AUTH_PSK, &shared_secret, info, psk, psk_id);
+        zeroise(&mut shared_secret);
+        Ok(RecipientContext(ctx?))
+    }
+}
+
+/// A sender's HPKE context, which encrypts a sequence of messages.
+pub struct SenderContext<F: HpkeKdf, A: HpkeAead>(Context<F, A>);
+
+impl<F: HpkeKdf, A: HpkeAead> SenderContext<F, A> {
+    /// Encrypt the next message.
+    ///
+    /// On entry, `cipher_inout` contains the plaintext.  On exit it
+    /// contains the ciphertext, and `tag_out` the authentication tag.
+    ///
+    /// Messages must be opened by the recipient in the order they
+    /// are sealed.
+    pub fn seal(
+        &mut self,
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag_out: &mut [u8; 16],
+    ) -> Result<(), Error> {
+        let nonce = self.0.next_nonce()?;
+        A::seal(&self.0.cipher, &nonce, aad, cipher_inout, tag_out)?;
+        self.0.seq += 1;
+        Ok(())
+    }
+
+    /// Fill `out` with a secret derived from this context and `exporter_context`.
+    ///
+    /// `out` may be at most 255 times the KDF's hash output length,
+    /// otherwise [`Error::WrongLength`] is returned.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        self.0.export(exporter_context, out)
+    }
+}
+
+/// A recipient's HPKE context, which decrypts a sequence of messages.
+pub struct RecipientContext<F: HpkeKdf, A: HpkeAead>(Context<F, A>);
+
+impl<F: HpkeKdf, A: HpkeAead> RecipientContext<F, A> {
+    /// Decrypt and verify the next message.
+    ///
+    /// On entry, `cipher_inout` contains the ciphertext.  On success,
+    /// it contains the plaintext.  Otherwise [`Error::DecryptFailed`] is
+    /// returned, `cipher_inout` is cleared, and the context still expects
+    /// the same message.
+    pub fn open(&mut self, aad: &[u8], cipher_inout: &mut [u8], tag: &[u8]) -> Result<(), Error> {
+        let nonce = self.0.next_nonce()?;
+        A::open(&self.0.cipher, &nonce, aad, cipher_inout, tag)?;
+        self.0.seq += 1;
+        Ok(())
+    }
+
+    /// Fill `out` with a secret derived from this context and `exporter_context`.
+    ///
+    /// `out` may be at most 255 times the KDF's hash output length,
+    /// otherwise [`Error::WrongLength`] is returned.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        self.0.export(exporter_context, out)
+    }
+}
+
+struct Context<F: HpkeKdf, A: HpkeAead> {
+    cipher: A::Cipher,
+    base_nonce: [u8; NONCE_LEN],
+    seq: u64,
+    exporter_secret: Prk<F::Hash>,
+    suite_id: [u8; 10],
+}
+
+impl<F: HpkeKdf, A: HpkeAead> Context<F, A> {
+    fn next_nonce(&self) -> Result<[u8; NONCE_LEN], Error> {
+        // `seq` is narrower than the nonce, so this is the earlier limit
+        if self.seq == u64::MAX {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut nonce = self.base_nonce;
+        for (n, s) in nonce[NONCE_LEN - 8..]
+            .iter_mut()
+            .zip(self.seq.to_be_bytes())
+        {
+            *n ^= s;
+        }
+        Ok(nonce)
+    }
+
+    fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        labeled_expand(
+            &self.exporter_secret,
+            &self.suite_id,
+            b"sec",
+            exporter_context,
+            out,
+        )
+    }
+}
+
+const MODE_BASE: u8 = 0x00;
+const MODE_PSK: u8 = 0x01;
+const MODE_AUTH: u8 = 0x02;
+const MODE_AUTH_PSK: u8 = 0x03;
+
+const NONCE_LEN: usize = 12;
+const MAX_KEY_LEN: usize = 32;
+const MAX_HASH_LEN: usize = 64;
+
+/// The shared secret length of all our KEMs.
+const SHARED_SECRET_LEN: usize = 32;
+
+/// The longest DHKEM public key (P-256 uncompressed).
+const MAX_DH_PUBLIC_KEY_LEN: usize = 65;
+
+fn labeled_extract<H: Hash>(
+    suite_id: &[u8],
+    salt: &[u8],
+    label: &[u8],
+    ikm: &[&[u8]],
+) -> HashOutput {
+    // nb. this is HKDF-Extract, but we need the PRK's value
+    let mut hmac = Hmac::<H>::new(salt);
+    hmac.update(b"HPKE-v1");
+    hmac.update(suite_id);
+    hmac.update(label);
+    for part in ikm {
+        hmac.update(part);
+    }
+    hmac.finish()
+}
+
+fn labeled_expand<H: Hash + Clone>(
+    prk: &Prk<H>,
+    suite_id: &[u8],
+    label: &[u8],
+    info: &[u8],
+    out: &mut [u8],
+) -> Result<(), Error> {
+    let len = u16::try_from(out.len()).map_err(|_| Error::WrongLength)?;
+    prk.expand(
+        &[&len.to_be_bytes(), b"HPKE-v1", suite_id, label, info],
+        out,
+    )
+}
+
+fn kem_suite_id<K: HpkeKem>() -> [u8; 5] {
+    let id = K::KEM_ID.to_be_bytes();
+    [b'K', b'E', b'M', id[0], id[1]]
+}
+
+/// `Encap()` and `AuthEncap()` for a DHKEM.
+fn dh_encap<K: DhKem>(
+    pk_r: &K::EncapsulationKey,
+    sk_s: Option<&K::DecapsulationKey>,
+    rng: &mut dyn RandomSource,
+) -> Result<(K::Ciphertext, [u8; SHARED_SECRET_LEN]), Error> {
+    let _entry = Entry::new_secret();
+    let mut ikm = [0u8; 32];
+    rng.fill(&mut ikm)?;
+    let sk_e = K::derive_key_pair(&ikm);
+    zeroise(&mut ikm);
+    let sk_e = sk_e?;
+
+    let mut dh = [0u8; 2 * SHARED_SECRET_LEN];
+    dh[..SHARED_SECRET_LEN].copy_from_slice(&K::dh(&sk_e, pk_r)?);
+    if let Some(sk_s) = sk_s {
+        dh[SHARED_SECRET_LEN..].copy_from_slice(&K::dh(sk_s, pk_r)?);
+    }
+
+    let enc = K::public_key(&sk_e);
+    let shared_secret = extract_and_expand::<K>(
+        &dh[..dh_len(sk_s.is_some())],
+        enc.as_ref(),
+        K::encode_encapsulation_key(pk_r).as_ref(),
+        sk_s.map(|sk_s| K::public_key(sk_s)).as_ref(),
+    );
+    zeroise(&mut dh);
+    Ok((enc, shared_secret?))
+}
+
+/// `Decap()` and `AuthDecap()` for a DHKEM.
+fn dh_decap<K: DhKem>(
+    enc: &[u8],
+    sk_r: &K::DecapsulationKey,
+    pk_s: Option<&K::EncapsulationKey>,
+) -> Result<[u8; SHARED_SECRET_LEN], Error> {
+    let _entry = Entry::new_secret();
+    let pk_e = K::decode_encapsulation_key(enc)?;
+
+    let mut dh = [0u8; 2 * SHARED_SECRET_LEN];
+    dh[..SHARED_SECRET_LEN].copy_from_slice(&K::dh(sk_r, &pk_e)?);
+    if let Some(pk_s) = pk_s {
+        dh[SHARED_SECRET_LEN..].copy_from_slice(&K::dh(sk_r, pk_s)?);
+    }
+
+    let shared_secret = extract_and_expand::<K>(
+        &dh[..dh_len(pk_s.is_some())],
+        enc,
+        K::public_key(sk_r).as_ref(),
+        pk_s.map(|pk_s| K::encode_encapsulation_key(pk_s)).as_ref(),
+    );
+    zeroise(&mut dh);
+    shared_secret
+}
+
+fn dh_len(auth: bool) -> usize {
+    match auth {
+        true => 2 * SHARED_SECRET_LEN,
+        false => SHARED_SECRET_LEN,
+    }
+}
+
+fn extract_and_expand<K: DhKem>(
+    dh: &[u8],
+    enc: &[u8],
+    pk_rm: &[u8],
+    pk_sm: Option<&impl AsRef<[u8]>>,
+) -> Result<[u8; SHARED_SECRET_LEN], Error> {
+    let mut kem_context = [0u8; 3 * MAX_DH_PUBLIC_KEY_LEN];
+    let mut len = 0;
+    for part in [enc, pk_rm, pk_sm.map(|pk| pk.as_ref()).unwrap_or_default()] {
+        kem_context[len..len + part.len()].copy_from_slice(part);
+        len += part.len();
+    }
+
+    let suite_id = kem_suite_id::<K>();
+    let mut eae_prk = labeled_extract::<Sha256>(&suite_id, &[], b"eae_prk", &[dh]);
+    let prk = Prk::<Sha256>::new(eae_prk.as_ref());
+    zeroise(eae_prk.as_mut());
+
+    let mut shared_secret = [0u8; SHARED_SECRET_LEN];
+    labeled_expand(
+        &prk,
+        &suite_id,
+        b"shared_secret",
+        &kem_context[..len],
+        &mut shared_secret,
+    )?;
+    Ok(shared_secret)
+}
+
+impl HpkeKem for X25519 {
+    const KEM_ID: u16 = 0x0020;
+}
+
+impl private::KemOps for X25519 {
+    fn encap(
+        pk_r: &Self::EncapsulationKey,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self::Ciphertext, [u8; SHARED_SECRET_LEN]), Error> {
+        dh_encap::<Self>(pk_r, None, rng)
+    }
+
+    fn decap(enc: &[u8], sk_r: &Self::DecapsulationKey) -> Result<[u8; SHARED_SECRET_LEN], Error> {
+        dh_decap::<Self>(enc, sk_r, None)
+    }
+}
+
+impl DhKem for X25519 {
+    fn derive_key_pair(ikm: &[u8]) -> Result<Self::DecapsulationKey, Error> {
+        let _entry = Entry::new_secret();
+        let suite_id = kem_suite_id::<Self>();
+        let mut dkp_prk = labeled_extract::<Sha256>(&suite_id, &[], b"dkp_prk", &[ikm]);
+        let prk = Prk::<Sha256>::new(dkp_prk.as_ref());
+        zeroise(dkp_prk.as_mut());
+
+        let mut sk = [0u8; 32];
+        labeled_expand(&prk, &suite_id, b"sk", &[], &mut sk)?;
+        let key = x25519::StaticPrivateKey::from_array(&sk);
+        zeroise(&mut sk);
+        Ok(key)
+    }
+}
+
+impl private::DhOps for X25519 {
+    fn dh(
+        sk: &Self::DecapsulationKey,
+        pk: &Self::EncapsulationKey,
+    ) -> Result<[u8; SHARED_SECRET_LEN], Error> {
+        Ok(sk.diffie_hellman(pk)?.0)
+    }
+
+    fn public_key(sk: &Self::DecapsulationKey) -> Self::Ciphertext {
+        sk.public_key().as_bytes()
+    }
+}
+
+impl HpkeKem for P256 {
+    const KEM_ID: u16 = 0x0010;
+}
+
+impl private::KemOps for P256 {
+    fn encap(
+        pk_r: &Self::EncapsulationKey,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self::Ciphertext, [u8; SHARED_SECRET_LEN]), Error> {
+        dh_encap::<Self>(pk_r, None, rng)
+    }
+
+    fn decap(enc: &[u8], sk_r: &Self::DecapsulationKey) -> Result<[u8; SHARED_SECRET_LEN], Error> {
+        dh_decap::<Self>(enc, sk_r, None)
+    }
+}
+
+impl DhKem for P256 {
+    fn derive_key_pair(ikm: &[u8]) -> Result<Self::DecapsulationKey, Error> {
+        let _entry = Entry::new_secret();
+        let suite_id = kem_suite_id::<Self>();
+        let mut dkp_prk = labeled_extract::<Sha256>(&suite_id, &[], b"dkp_prk", &[ikm]);
+        let prk = Prk::<Sha256>::new(dkp_prk.as_ref());
+        zeroise(dkp_prk.as_mut());
+
+        // rejection sampling: the bitmask for P-256 is 0xff, so is omitted
+        for counter in 0..=255u8 {
+            let mut candidate = [0u8; 32];
+            labeled_expand(&prk, &suite_id, b"candidate", &[counter], &mut candidate)?;
+            let key = p256::StaticPrivateKey::from_bytes(&candidate);
+            zeroise(&mut candidate);
+            if let Ok(key) = key {
+                return Ok(key);
+            }
+        }
+
+        Err(Error::OutOfRange)
+    }
+}
+
+impl private::DhOps for P256 {
+    fn dh(
+        sk: &Self::DecapsulationKey,
+        pk: &Self::EncapsulationKey,
+    ) -> Result<[u8; SHARED_SECRET_LEN], Error> {
+        Ok(sk.diffie_hellman(pk)?.0)
+    }
+
+    fn public_key(sk: &Self::DecapsulationKey) -> Self::Ciphertext {
+        sk.public_key_uncompressed()
+    }
+}
+
+macro_rules! ml_kem {
+    ($name:ident, $id:literal) => {
+        /// ML-KEM, as specified for HPKE by
+        /// [draft-ietf-hpke-pq](https://datatracker.ietf.org/doc/draft-ietf-hpke-pq/).
+        ///
+        /// The shared secret is used directly.  This does not support the
+        /// authenticated modes.
+        impl HpkeKem for $name {
+            const KEM_ID: u16 = $id;
+        }
+
+        impl private::KemOps for $name {
+            fn encap(
+                pk_r: &Self::EncapsulationKey,
+                rng: &mut dyn RandomSource,
+            ) -> Result<(Self::Ciphertext, [u8; SHARED_SECRET_LEN]), Error> {
+                let (enc, shared_secret) = pk_r.encapsulate_with_rng(rng)?;
+                Ok((enc, shared_secret.0))
+            }
+
+            fn decap(
+                enc: &[u8],
+                sk_r: &Self::DecapsulationKey,
+            ) -> Result<[u8; SHARED_SECRET_LEN], Error> {
+                Ok(sk_r.decapsulate(enc)?.0)
+            }
+        }
+    };
+}
+
+ml_kem!(MlKem512, 0x0040);
+ml_kem!(MlKem768, 0x0041);
+ml_kem!(MlKem1024, 0x0042);
+
+impl HpkeKdf for HkdfSha256 {
+    const KDF_ID: u16 = 0x0001;
+    type Hash = Sha256;
+}
+
+impl HpkeKdf for HkdfSha384 {
+    const KDF_ID: u16 = 0x0002;
+    type Hash = Sha384;
+}
+
+impl HpkeKdf for HkdfSha512 {
+    const KDF_ID: u16 = 0x0003;
+    type Hash = Sha512;
+}
+
+impl HpkeAead for Aes128Gcm {
+    const AEAD_ID: u16 = 0x0001;
+}
+
+impl HpkeAead for Aes256Gcm {
+    const AEAD_ID: u16 = 0x0002;
+}
+
+impl HpkeAead for ChaCha20Poly1305 {
+    const AEAD_ID: u16 = 0x0003;
+}
+
+impl HpkeAead for ExportOnly {
+    const AEAD_ID: u16 = 0xffff;
+}
+
+macro_rules! aes_gcm {
+    ($name:ident, $key_len:literal) => {
+        impl private::AeadOps for $name {
+            type Cipher = AesGcm;
+            const KEY_LEN: usize = $key_len;
+
+            fn new_cipher(key: &[u8]) -> Self::Cipher {
+                AesGcm::new(key)
+            }
+
+            fn seal(
+                cipher: &Self::Cipher,
+                nonce: &[u8; NONCE_LEN],
+                aad: &[u8],
+                cipher_inout: &mut [u8],
+                tag_out: &mut [u8; 16],
+            ) -> Result<(), Error> {
+                cipher.encrypt(nonce, aad, cipher_inout, tag_out);
+                Ok(())
+            }
+
+            fn open(
+                cipher: &Self::Cipher,
+                nonce: &[u8; NONCE_LEN],
+                aad: &[u8],
+                cipher_inout: &mut [u8],
+                tag: &[u8],
+            ) -> Result<(), Error> {
+                cipher.decrypt(nonce, aad, cipher_inout, tag)
+            }
+        }
+    };
+}
+
+aes_gcm!(Aes128Gcm, 16);
+aes_gcm!(Aes256Gcm, 32);
+
+impl private::AeadOps for ChaCha20Poly1305 {
+    type Cipher = chacha20poly1305::ChaCha20Poly1305;
+    const KEY_LEN: usize = 32;
+
+    fn new_cipher(key: &[u8]) -> Self::Cipher {
+        chacha20poly1305::ChaCha20Poly1305::new(key.try_into().unwrap())
+    }
+
+    fn seal(
+        cipher: &Self::Cipher,
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag_out: &mut [u8; 16],
+    ) -> Result<(), Error> {
+        cipher.encrypt(nonce, aad, cipher_inout, tag_out);
+        Ok(())
+    }
+
+    fn open(
+        cipher: &Self::Cipher,
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        cipher.decrypt(nonce, aad, cipher_inout, tag)
+    }
+}
+
+impl private::AeadOps for ExportOnly {
+    type Cipher = ();
+    const KEY_LEN: usize = 0;
+
+    fn new_cipher(_key: &[u8]) -> Self::Cipher {}
+
+    fn seal(
+        _cipher: &Self::Cipher,
+        _nonce: &[u8; NONCE_LEN],
+        _aad: &[u8],
+        _cipher_inout: &mut [u8],
+        _tag_out: &mut [u8; 16],
+    ) -> Result<(), Error> {
+        Err(Error::OutOfRange)
+    }
+
+    fn open(
+        _cipher: &Self::Cipher,
+        _nonce: &[u8; NONCE_LEN],
+        _aad: &[u8],
+        _cipher_inout: &mut [u8],
+        _tag: &[u8],
+    ) -> Result<(), Error> {
+        Err(Error::OutOfRange)
+    }
+}
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {}
+
+    impl Sealed for HkdfSha256 {}
+    impl Sealed for HkdfSha384 {}
+    impl Sealed for HkdfSha512 {}
+
+    pub trait KemOps: Kem {
+        fn encap(
+            pk_r: &Self::EncapsulationKey,
+            rng: &mut dyn RandomSource,
+        ) -> Result<(Self::Ciphertext, [u8; SHARED_SECRET_LEN]), Error>;
+
+        fn decap(
+            enc: &[u8],
+            sk_r: &Self::DecapsulationKey,
+        ) -> Result<[u8; SHARED_SECRET_LEN], Error>;
+    }
+
+    pub trait DhOps: Kem {
+        fn dh(
+            sk: &Self::DecapsulationKey,
+            pk: &Self::EncapsulationKey,
+        ) -> Result<[u8; SHARED_SECRET_LEN], Error>;
+
+        /// The encoding of `sk`'s public key, which is a ciphertext
+        /// when `sk` is ephemeral.
+        fn public_key(sk: &Self::DecapsulationKey) -> Self::Ciphertext;
+    }
+
+    pub trait AeadOps {
+        type Cipher;
+        const KEY_LEN: usize;
+
+        fn new_cipher(key: &[u8]) -> Self::Cipher;
+
+        fn seal(
+            cipher: &Self::Cipher,
+            nonce: &[u8; NONCE_LEN],
+            aad: &[u8],
+            cipher_inout: &mut [u8],
+            tag_out: &mut [u8; 16],
+        ) -> Result<(), Error>;
+
+        fn open(
+            cipher: &Self::Cipher,
+            nonce: &[u8; NONCE_LEN],
+            aad: &[u8],
+            cipher_inout: &mut [u8],
+            tag: &[u8],
+        ) -> Result<(), Error>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const INFO: &str = "4f6465206f6e2061204772656369616e2055726e";
+    const PSK: &str = "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82";
+    const PSK_ID: &[u8] = b"Ennyn Durin aran Moria";
+    const PLAINTEXT: &[u8] = b"Beauty is truth, truth beauty";
+    const IKM_R: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const IKM_S: &str = "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f";
+    const IKM_E: &str = "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f";
+
+    struct Vector {
+        mode: u8,
+        ikm_r: &'static str,
+        ikm_e: &'static str,
+        pk_r: &'static str,
+        pk_s: &'static str,
+        enc: &'static str,
+        /// ciphertexts (including tags) with aad "Count-0", "Count-1", ...
+        ciphertexts: &'static [&'static str],
+        /// exports of 32 bytes with context "" and "TestContext"
+        exports: [&'static str; 2],
+    }
+
+    fn check<K: DhKem, F: HpkeKdf, A: HpkeAead>(v: &Vector) {
+        let info = unhex(INFO);
+        let (psk, psk_id) = match v.mode {
+            MODE_PSK | MODE_AUTH_PSK => (unhex(PSK), PSK_ID),
+            _ => (vec![], &b""[..]),
+        };
+        let auth = v.mode == MODE_AUTH || v.mode == MODE_AUTH_PSK;
+
+        let sk_r = K::derive_key_pair(&unhex(v.ikm_r)).unwrap();
+        let pk_r = K::encapsulation_key(&sk_r);
+        assert_eq!(hex(K::encode_encapsulation_key(&pk_r).as_ref()), v.pk_r);
+        let sk_s = K::derive_key_pair(&unhex(IKM_S)).unwrap();
+        let pk_s = K::encapsulation_key(&sk_s);
+        if auth {
+            assert_eq!(hex(K::encode_encapsulation_key(&pk_s).as_ref()), v.pk_s);
+        }
+
+        let ikm_e = unhex(v.ikm_e);
+        let (enc, mut shared_secret) = dh_encap::<K>(
+            &pk_r,
+            if auth { Some(&sk_s) } else { None },
+            &mut SliceRandomSource(&ikm_e),
+        )
+        .unwrap();
+        assert_eq!(hex(enc.as_ref()), v.enc);
+        let mut sender = SenderContext(
+            Suite::<K, F, A>::key_schedule(v.mode, &shared_secret, &info, &psk, psk_id).unwrap(),
+        );
+        zeroise(&mut shared_secret);
+
+        let enc = unhex(v.enc);
+        let mut recipient = match v.mode {
+            MODE_BASE => Suite::<K, F, A>::setup_base_recipient(&enc, &sk_r, &info),
+            MODE_PSK => Suite::<K, F, A>::setup_psk_recipient(&enc, &sk_r, &info, &psk, psk_id),
+            MODE_AUTH => Suite::<K, F, A>::setup_auth_recipient(&enc, &sk_r, &info, &pk_s),
+            _ => {
+                Suite::<K, F, A>::setup_auth_psk_recipient(&enc, &sk_r, &info, &psk, psk_id, &pk_s)
+            }
+        }
+        .unwrap();
+
+        for (i, expected) in v.ciphertexts.iter().enumerate() {
+            let aad = format!("Count-{i}");
+            let mut message = PLAINTEXT.to_vec();
+            let mut tag = [0u8; 16];
+            sender.seal(aad.as_bytes(), &mut message, &mut tag).unwrap();
+            assert_eq!(format!("{}{}", hex(&message), hex(&tag)), *expected);
+
+            recipient.open(aad.as_bytes(), &mut message, &tag).unwrap();
+            assert_eq!(message, PLAINTEXT);
+        }
+
+        for (context, expected) in [&b""[..], b"TestContext"].iter().zip(v.exports) {
+            let mut out = [0u8; 32];
+            sender.export(context, &mut out).unwrap();
+            assert_eq!(hex(&out), expected);
+            recipient.export(context, &mut out).unwrap();
+            assert_eq!(hex(&out), expected);
+        }
+    }
+
+    #[test]
+    fn rfc9180_a_1_1() {
+        // DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, AES-128-GCM, base mode
+        check::<X25519, HkdfSha256, Aes128Gcm>(&Vector {
+            mode: MODE_BASE,
+            ikm_r: "6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037",
+            ikm_e: "7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234",
+            pk_r: "3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d",
+            pk_s: "",
+            enc: "37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431",
+            ciphertexts: &[
+                "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a96d8770ac83d07bea87e13c512a",
+                "af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab251c03d0c22a56b8ca42c2063b84",
+                "498dfcabd92e8acedc281e85af1cb4e3e31c7dc394a1ca20e173cb72516491588d96a19ad4a683518973dcc180",
+            ],
+            exports: [
+                "3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee",
+                "e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931",
+            ],
+        });
+    }
+
+    // The remaining vectors were produced by OpenSSL 3.5.
+
+    #[test]
+    fn x25519_sha256_chacha20poly1305_psk() {
+        check::<X25519, HkdfSha256, ChaCha20Poly1305>(&Vector {
+            mode: MODE_PSK,
+            ikm_r: IKM_R,
+            ikm_e: IKM_E,
+            pk_r: "b1f1b840de7a3241b02748cf9b05b74dc8c5e8451298738817bd76aa8ebe8c2b",
+            pk_s: "",
+            enc: "b259f6ee92dcba0111850b13b3f6dccc827726f9b08235ab62922b6b3f3f2a19",
+            ciphertexts: &[
+                "9d7caca7f39a821db9d732759d51a71dadb3da46a1b9a1a9037183acf9b32f9b3dbd1d365c6c819ebbc44b1d9b",
+                "50338499622c1b255a9d93dd2f0a9ea11fb4766e7f06521b311dd1399bf56d313a56989462bc3ad5be428fb41f",
+                "ae3e3a7781de0e90d675dc4322e74ec9453022a912e6f2ef5d2f3d62636a4442c081fcea92a085f6e015b17b98",
+            ],
+            exports: [
+                "c8adb85e9601585bc4b7ecd1d4ec6531903a446131314f2b9e63b39d77846a3e",
+                "7da019db8c3dc6776c7febb54961a15c0fd514e5dfefd277141b377ab9392206",
+            ],
+        });
+    }
+
+    #[test]
+    fn x25519_sha384_aes256gcm_auth() {
+        check::<X25519, HkdfSha384, Aes256Gcm>(&Vector {
+            mode: MODE_AUTH,
+            ikm_r: IKM_R,
+            ikm_e: IKM_E,
+            pk_r: "b1f1b840de7a3241b02748cf9b05b74dc8c5e8451298738817bd76aa8ebe8c2b",
+            pk_s: "693658254630f73ad8da78fb331bf976cd42f90e0e9c9e83f40c51072a6f7417",
+            enc: "b259f6ee92dcba0111850b13b3f6dccc827726f9b08235ab62922b6b3f3f2a19",
+            ciphertexts: &[
+                "861f90c307342586eb63121b16c9bf5f09c7386f6aa7a30501238d5c8a7cf353f31d259ee5034bee303d3c6290",
+                "bb233a32ccb3aff301fe4575ef7fbf3d66b9ec61d47f477bcc95c6d75f738d826998c5f51ecc66e2ab06b1e439",
+                "e5e5213edb0103d9476f45b6f4d0acaacf3b384cce276f17722865b8d12a7306c9f11519940d235c9af263f0b5",
+            ],
+            exports: [
+                "e1227d3e1d12b1dc15b1a378ad31a1759ed2cd854cd18b33d4f9ed580809b1a8",
+                "277fdecadcb4f9c22997aaeb0be305d4ce515024604b3630e9f4e53049c1ae8a",
+            ],
+        });
+    }
+
+    #[test]
+    fn x25519_sha512_export_only() {
+        check::<X25519, HkdfSha512, ExportOnly>(&Vector {
+            mode: MODE_BASE,
+            ikm_r: IKM_R,
+            ikm_e: IKM_E,
+            pk_r: "b1f1b840de7a3241b02748cf9b05b74dc8c5e8451298738817bd76aa8ebe8c2b",
+            pk_s: "",
+            enc: "b259f6ee92dcba0111850b13b3f6dccc827726f9b08235ab62922b6b3f3f2a19",
+            ciphertexts: &[],
+            exports: [
+                "61eda429309972f539a0aa1eabd0a793f4c291e0dba8ab1872a83cb10963b4b3",
+                "a720726fc7395889b0c8959509af45719a2eccb15feeaa9b7c9ea8402ac4b94b",
+            ],
+        });
+    }
+
+    #[test]
+    fn p256_sha256_aes128gcm_auth() {
+        check::<P256, HkdfSha256, Aes128Gcm>(&Vector {
+            mode: MODE_AUTH,
+            ikm_r: IKM_R,
+            ikm_e: IKM_E,
+            pk_r: "04cfb264d85c7eb276cf60773c461d722e25b64bf345d077c1a8b10c05b8decb45\
+                   b7ad849cce5e9660102f3f368afd22fe797caf6c3a9cbcbe7657b27bbcddfdb1",
+            pk_s: "04c1c2f5142333a5af857db225369758c149e7c63a803a9d85abac5b39f49b178e\
+                   733304398af98a6da5ba2b1813ea73f9d2bd165fba4c4374ed0caa934f97e544",
+            enc: "045864aa8afdd60e38f39ec9a28d9719c273654690310c5c268bc70adecad75975\
+                  d2a3cfff4abb3f373a0c725ba9bc936a5b1936074c13c9089d130fa79672a557",
+            ciphertexts: &[
+                "c5df59c95d97b5084671dd9acdbbb03b91c45fcdc65907132ce18024dfed9d220caf99d98da45915bc8d88945d",
+                "660e0dcaf7f9f7c98a4648fbd12dd2c2c1e45e9ffe9f30b5a9dd0f84387de8686a3f10505e6c8a54f323736390",
+                "901a50c2118e5d0f99e9a6d7ca90a71b942939af62c1311ef737413312aa624b6c3e35df55d86e6b9a0d5018fa",
+            ],
+            exports: [
+                "8522262bf1f60aa946f097e22ff968f90a36d13bd7970c124a51ebb0a36b9ba0",
+                "2e3727d15c7a7e2a7024c328aef7bc69a11f6240e4613b14144ed77a49426005",
+            ],
+        });
+    }
+
+    #[test]
+    fn p256_sha512_aes256gcm_auth_psk() {
+        check::<P256, HkdfSha512, Aes256Gcm>(&Vector {
+            mode: MODE_AUTH_PSK,
+            ikm_r: IKM_R,
+            ikm_e: IKM_E,
+            pk_r: "04cfb264d85c7eb276cf60773c461d722e25b64bf345d077c1a8b10c05b8decb45\
+                   b7ad849cce5e9660102f3f368afd22fe797caf6c3a9cbcbe7657b27bbcddfdb1",
+            pk_s: "04c1c2f5142333a5af857db225369758c149e7c63a803a9d85abac5b39f49b178e\
+                   733304398af98a6da5ba2b1813ea73f9d2bd165fba4c4374ed0caa934f97e544",
+            enc: "045864aa8afdd60e38f39ec9a28d9719c273654690310c5c268bc70adecad75975\
+                  d2a3cfff4abb3f373a0c725ba9bc936a5b1936074c13c9089d130fa79672a557",
+            ciphertexts: &[
+                "11f9bba0e6554bead95054bf84ab2cf63cbd15bf3f88b86d07bd8024d9584289a37506d89fca1851b0ec9ab869",
+                "018ed442118e1c23ad004a91d136d0d86da2f3ced8ddfe8316255168a3e2f9f1416eac09c59747bae15d619dbb",
+                "413db4b077b12e441be8e8aa3c5d454aa64f29b0339c3876679333bde84e737f8871f558976a3c6d5a30d19ad3",
+            ],
+            exports: [
+                "5d9995476271a37718437a5859dab202eba3497ddddafe1e58a7e49e3fe2cb94",
+                "fc25b7fcb8cfb8182cbe7fa8a6e091c942270d9daab2f35d2885624df281a077",
+            ],
+        });
+    }
+
+    fn round_trip<K: HpkeKem, F: HpkeKdf, A: HpkeAead>() {
+        let sk_r = K::generate().unwrap();
+        let pk_r = K::encapsulation_key(&sk_r);
+
+        let (enc, mut sender) = Suite::<K, F, A>::setup_base_sender(&pk_r, b"info").unwrap();
+        let mut recipient =
+            Suite::<K, F, A>::setup_base_recipient(enc.as_ref(), &sk_r, b"info").unwrap();
+
+        for i in 0..3u8 {
+            let mut message = [i; 20];
+            let mut tag = [0u8; 16];
+            sender.seal(&[i], &mut message, &mut tag).unwrap();
+
+            // a failed open does not advance the sequence number
+            let mut tampered = message;
+            tampered[0] ^= 1;
+            assert_eq!(
+                recipient.open(&[i], &mut tampered, &tag),
+                Err(Error::DecryptFailed)
+            );
+
+            recipient.open(&[i], &mut message, &tag).unwrap();
+            assert_eq!(message, [i; 20]);
+        }
+
+        let mut message = *b"hello";
+        let mut tag = [0u8; 16];
+        let enc = Suite::<K, F, A>::seal(&pk_r, b"info", b"aad", &mut message, &mut tag).unwrap();
+        assert_eq!(
+            Suite::<K, F, A>::open(enc.as_ref(), &sk_r, b"other", b"aad", &mut message, &tag),
+            Err(Error::DecryptFailed)
+        );
+        assert_eq!(message, [0u8; 5]);
+
+        let mut sent = [0u8; 48];
+        let enc = Suite::<K, F, A>::send_export(&pk_r, b"info", b"context", &mut sent).unwrap();
+        let mut received = [0u8; 48];
+        Suite::<K, F, A>::receive_export(enc.as_ref(), &sk_r, b"info", b"context", &mut received)
+            .unwrap();
+        assert_eq!(sent, received);
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip::<X25519, HkdfSha256, ChaCha20Poly1305>();
+        round_trip::<P256, HkdfSha512, Aes128Gcm>();
+        round_trip::<MlKem512, HkdfSha256, Aes128Gcm>();
+        round_trip::<MlKem768, HkdfSha384, Aes256Gcm>();
+        round_trip::<MlKem1024, HkdfSha512, ChaCha20Poly1305>();
+    }
+
+    #[test]
+    fn authenticated_modes() {
+        type Hpke = Suite<P256, HkdfSha256, Aes128Gcm>;
+        let sk_r = P256::generate().unwrap();
+        let pk_r = P256::encapsulation_key(&sk_r);
+        let sk_s = P256::generate().unwrap();
+        let pk_s = P256::encapsulation_key(&sk_s);
+
+        let (enc, sender) = Hpke::setup_auth_sender(&pk_r, b"info", &sk_s).unwrap();
+        let recipient = Hpke::setup_auth_recipient(&enc, &sk_r, b"info", &pk_s).unwrap();
+        let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+        sender.export(b"", &mut a).unwrap();
+        recipient.export(b"", &mut b).unwrap();
+        assert_eq!(a, b);
+
+        // the wrong sender key gives a different context
+        let recipient = Hpke::setup_auth_recipient(&enc, &sk_r, b"info", &pk_r).unwrap();
+        recipient.export(b"", &mut b).unwrap();
+        assert_ne!(a, b);
+
+        let (enc, sender) =
+            Hpke::setup_auth_psk_sender(&pk_r, b"info", b"psk", b"id", &sk_s).unwrap();
+        let recipient =
+            Hpke::setup_auth_psk_recipient(&enc, &sk_r, b"info", b"psk", b"id", &pk_s).unwrap();
+        sender.export(b"", &mut a).unwrap();
+        recipient.export(b"", &mut b).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn psk_inputs() {
+        type Hpke = Suite<X25519, HkdfSha256, Aes128Gcm>;
+        let sk_r = X25519::generate().unwrap();
+        let pk_r = X25519::encapsulation_key(&sk_r);
+
+        assert_eq!(
+            Hpke::setup_psk_sender(&pk_r, b"", b"", b"").err(),
+            Some(Error::OutOfRange)
+        );
+        assert_eq!(
+            Hpke::setup_psk_sender(&pk_r, b"", b"psk", b"").err(),
+            Some(Error::OutOfRange)
+        );
+        assert_eq!(
+            Hpke::setup_psk_sender(&pk_r, b"", b"", b"id").err(),
+            Some(Error::OutOfRange)
+        );
+
+        let (enc, sender) = Hpke::setup_psk_sender(&pk_r, b"", b"psk", b"id").unwrap();
+        let recipient = Hpke::setup_psk_recipient(&enc, &sk_r, b"", b"psk", b"id").unwrap();
+        let (mut a, mut b) = ([0u8; 32], [0u8; 32]);
+        sender.export(b"", &mut a).unwrap();
+        recipient.export(b"", &mut b).unwrap();
+        assert_eq!(a, b);
+
+        // wrong psk
+        let recipient = Hpke::setup_psk_recipient(&enc, &sk_r, b"", b"psk2", b"id").unwrap();
+        recipient.export(b"", &mut b).unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn export_only_cannot_seal() {
+        type Hpke = Suite<X25519, HkdfSha256, ExportOnly>;
+        let sk_r = X25519::generate().unwrap();
+        let (_, mut sender) =
+            Hpke::setup_base_sender(&X25519::encapsulation_key(&sk_r), b"").unwrap();
+        let mut tag = [0u8; 16];
+        assert_eq!(sender.seal(b"", &mut [], &mut tag), Err(Error::OutOfRange));
+
+        let mut out = [0u8; 255 * 32 + 1];
+        assert_eq!(sender.export(b"", &mut out), Err(Error::WrongLength));
+        sender.export(b"", &mut out[..255 * 32]).unwrap();
+    }
+
+    #[test]
+    fn sequence_limit() {
+        type Hpke = Suite<X25519, HkdfSha256, Aes128Gcm>;
+        let sk_r = X25519::generate().unwrap();
+        let (_, mut sender) =
+            Hpke::setup_base_sender(&X25519::encapsulation_key(&sk_r), b"").unwrap();
+        sender.0.seq = u64::MAX - 1;
+        let mut tag = [0u8; 16];
+        sender.seal(b"", &mut [], &mut tag).unwrap();
+        assert_eq!(sender.seal(b"", &mut [], &mut tag), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn rejects_bad_enc() {
+        type Hpke = Suite<P256, HkdfSha256, Aes128Gcm>;
+        let sk_r = P256::generate().unwrap();
+        let (enc, _) = Hpke::setup_base_sender(&P256::encapsulation_key(&sk_r), b"").unwrap();
+        assert!(Hpke::setup_base_recipient(&enc[1..], &sk_r, b"").is_err());
+
+        // X25519 rejects an all-zero shared secret
+        let sk_r = X25519::generate().unwrap();
+        assert_eq!(
+            Suite::<X25519, HkdfSha256, Aes128Gcm>::setup_base_recipient(&[0; 32], &sk_r, b"")
+                .err(),
+            Some(Error::NotOnCurve)
+        );
+    }
+}
@@ -0,0 +1,288 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! JSON Object Signing and Encryption (JOSE).
+//!
+//! Only the compact serializations are supported.  These are a
+//! sequence of base64url-encoded parts, separated by `.` characters,
+//! the first of which is the JSON protected header.
+//!
+//! Protected headers are strict: members must be unique, and any
+//! `crit` member is rejected (since no extensions are supported).
+//! Likewise, compressed (`zip`) JWEs are not supported.
+
+use super::base64;
+use super::json::{self, Json};
+use crate::Error;
+use crate::error::KeyFormatError;
+
+mod jwe;
+mod jws;
+
+pub use jwe::{Jwe, JweAlgorithm, JweDecryptionKey, JweEncryption, JweEncryptionKey, JweHeader};
+pub use jws::{Jws, JwsAlgorithm, JwsHeader, JwsSigningKey, JwsVerifyingKey, UnsignedJws};
+
+/// A decoded protected header.
+///
+/// The members used by JWS and JWE are decoded here.
+pub(crate) struct Header {
+    /// The decoded JSON text.
+    text: String,
+    alg: String,
+    kid: Option<String>,
+    typ: Option<String>,
+    cty: Option<String>,
+    enc: Option<String>,
+    /// The JSON text of the `epk` object.
+    epk: Option<String>,
+    apu: Option<String>,
+    apv: Option<String>,
+}
+
+impl Header {
+    /// Decodes `encoded`, which is the base64url encoding of a JSON object.
+    pub(crate) fn decode(encoded: &str) -> Result<Self, Error> {
+        let text = base64::decode_url(encoded)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+
+        let members = match Json::parse(&text) {
+            Some(Json::Object(members)) => members,
+            _ => return Err(invalid()),
+        };
+
+        // member names must be unique (RFC7515 section 4)
+        for (i, (name, _)) in members.iter().enumerate() {
+            if members[..i].iter().any(|(other, _)| other == name) {
+                return Err(invalid());
+            }
+        }
+
+        let string = |name: &str| -> Result<Option<String>, Error> {
+            match members.iter().find(|(n, _)| n == name) {
+                Some((_, value)) => value
+                    .string()
+                    .map(|s| Some(s.to_string()))
+                    .ok_or_else(invalid),
+                None => Ok(None),
+            }
+        };
+
+        if members.iter().any(|(name, _)| name == "crit") {
+            return Err(invalid());
+        }
+        if members.iter().any(|(name, _)| name == "zip") {
+            return Err(KeyFormatError::UnsupportedJoseAlgorithm.into());
+        }
+
+        let epk = match members.iter().find(|(n, _)| n == "epk") {
+            Some((_, value @ Json::Object(_))) => {
+                let mut epk = String::new();
+                value.encode(&mut epk);
+                Some(epk)
+            }
+            Some(_) => return Err(invalid()),
+            None => None,
+        };
+
+        let alg = string("alg")?.ok_or_else(invalid)?;
+        let kid = string("kid")?;
+        let typ = string("typ")?;
+        let cty = string("cty")?;
+        let enc = string("enc")?;
+        let apu = string("apu")?;
+        let apv = string("apv")?;
+
+        Ok(Self {
+            text,
+            alg,
+            kid,
+            typ,
+            cty,
+            enc,
+            epk,
+            apu,
+            apv,
+        })
+    }
+
+    /// The `alg` member.
+    pub(crate) fn algorithm(&self) -> &str {
+        &self.alg
+    }
+
+    /// The `kid` member, if present.
+    pub(crate) fn key_id(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// The `typ` member, if present.
+    pub(crate) fn media_type(&self) -> Option<&str> {
+        self.typ.as_deref()
+    }
+
+    /// The `cty` member, if present.
+    pub(crate) fn content_type(&self) -> Option<&str> {
+        self.cty.as_deref()
+    }
+
+    /// The `enc` member, if present.
+    pub(crate) fn encryption(&self) -> Option<&str> {
+        self.enc.as_deref()
+    }
+
+    /// The `epk` member, if present, as JSON text.
+    pub(crate) fn ephemeral_key(&self) -> Option<&str> {
+        self.epk.as_deref()
+    }
+
+    /// The `apu` member, if present.
+    pub(crate) fn party_u_info(&self) -> Option<&str> {
+        self.apu.as_deref()
+    }
+
+    /// The `apv` member, if present.
+    pub(crate) fn party_v_info(&self) -> Option<&str> {
+        self.apv.as_deref()
+    }
+
+    /// The decoded JSON text.
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// A protected header member's value, for [`encode_header()`].
+pub(crate) enum Value<'a> {
+    /// A string.
+    Text(&'a str),
+    /// A JSON object, given as its text.
+    Object(&'a str),
+}
+
+/// Encodes a protected header with `members`, in the order given.
+///
+/// Absent members are skipped.  The result is base64url encoded.
+pub(crate) fn encode_header(members: &[(&str, Option<Value<'_>>)]) -> String {
+    let mut out = String::from("{");
+    for (name, value) in members {
+        let Some(value) = value else {
+            continue;
+        };
+        if out.len() > 1 {
+            out.push(',');
+        }
+        json::put_string(&mut out, name);
+        out.push(':');
+        match value {
+            Value::Text(text) => json::put_string(&mut out, text),
+            Value::Object(object) => out.push_str(object),
+        }
+    }
+    out.push('}');
+    base64::encode_url(out.as_bytes())
+}
+
+/// Splits a compact serialization into exactly `N` parts.
+pub(crate) fn split<const N: usize>(compact: &str) -> Result<[&str; N], Error> {
+    let mut parts = [""; N];
+    let mut iter = compact.split('.');
+    for part in parts.iter_mut() {
+        *part = iter.next().ok_or_else(invalid)?;
+    }
+    match iter.next() {
+        Some(_) => Err(invalid()),
+        None => Ok(parts),
+    }
+}
+
+/// Decodes one base64url part of a compact serialization.
+pub(crate) fn decode_part(part: &str) -> Result<Vec<u8>, Error> {
+    base64::decode_url(part).ok_or_else(invalid)
+}
+
+fn invalid() -> Error {
+    KeyFormatError::InvalidJose.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header() {
+        let header = Header::decode(&encode_header(&[
+            ("alg", Some(Value::Text("ES256"))),
+            ("kid", None),
+            ("typ", Some(Value::Text("JWT"))),
+            ("cty", Some(Value::Text("\"quoted\""))),
+        ]))
+        .unwrap();
+        assert_eq!(header.algorithm(), "ES256");
+        assert_eq!(header.key_id(), None);
+        assert_eq!(header.media_type(), Some("JWT"));
+        assert_eq!(header.content_type(), Some("\"quoted\""));
+        assert_eq!(header.encryption(), None);
+        assert_eq!(header.ephemeral_key(), None);
+        assert_eq!(
+            header.text(),
+            r#"{"alg":"ES256","typ":"JWT","cty":"\"quoted\""}"#
+        );
+
+        let header = Header::decode(&encode_header(&[
+            ("alg", Some(Value::Text("ECDH-ES"))),
+            ("enc", Some(Value::Text("A128GCM"))),
+            ("epk", Some(Value::Object(r#"{ "kty": "OKP", "x": "AA" }"#))),
+            ("apu", Some(Value::Text("QWxpY2U"))),
+            ("apv", Some(Value::Text("Qm9i"))),
+        ]))
+        .unwrap();
+        assert_eq!(header.encryption(), Some("A128GCM"));
+        assert_eq!(header.ephemeral_key(), Some(r#"{"kty":"OKP","x":"AA"}"#));
+        assert_eq!(header.party_u_info(), Some("QWxpY2U"));
+        assert_eq!(header.party_v_info(), Some("Qm9i"));
+    }
+
+    #[test]
+    fn invalid_header() {
+        for text in [
+            r#""#,
+            r#"[]"#,
+            r#""alg""#,
+            r#"{}"#,
+            r#"{"alg":1}"#,
+            r#"{"alg":"ES256","kid":null}"#,
+            r#"{"alg":"ES256","alg":"ES256"}"#,
+            r#"{"alg":"ES256","crit":["exp"],"exp":1}"#,
+            r#"{"alg":"dir","enc":1}"#,
+            r#"{"alg":"ECDH-ES","epk":"x"}"#,
+        ] {
+            assert_eq!(
+                Header::decode(&base64::encode_url(text.as_bytes())).err(),
+                Some(invalid()),
+                "{text}"
+            );
+        }
+
+        assert_eq!(
+            Header::decode(&base64::encode_url(br#"{"alg":"dir","zip":"DEF"}"#)).err(),
+            Some(KeyFormatError::UnsupportedJoseAlgorithm.into())
+        );
+
+        // not base64url, not UTF-8
+        assert_eq!(Header::decode("e30=").err(), Some(invalid()));
+        assert_eq!(
+            Header::decode(&base64::encode_url(b"{\"alg\":\"\xff\"}")).err(),
+            Some(invalid())
+        );
+    }
+
+    #[test]
+    fn split_parts() {
+        assert_eq!(split::<3>("a.b.c").unwrap(), ["a", "b", "c"]);
+        assert_eq!(split::<3>("..").unwrap(), ["", "", ""]);
+        assert_eq!(split::<3>("a.b").err(), Some(invalid()));
+        assert_eq!(split::<3>("a.b.c.d").err(), Some(invalid()));
+        assert_eq!(split::<5>("a.b.c.d.e").unwrap(), ["a", "b", "c", "d", "e"]);
+    }
+}
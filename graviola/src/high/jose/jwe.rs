@@ -0,0 +1,1060 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! JSON Web Encryption (JWE), in the compact serialization.
+//!
+//! This is [RFC7516](https://datatracker.ietf.org/doc/html/rfc7516), with
+//! algorithms from [RFC7518](https://datatracker.ietf.org/doc/html/rfc7518#section-4)
+//! and [RFC8037](https://datatracker.ietf.org/doc/html/rfc8037#section-3.2).
+
+use super::{Header, Value, decode_part, encode_header, invalid, split};
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::high::base64;
+use crate::high::external::{DecryptionAlgorithm, ExternalDecrypter};
+use crate::high::rsa;
+use crate::low::{Entry, zeroise};
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::aes_kw::{self, AesKw};
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::rsa_pub::MAX_PUBLIC_MODULUS_BYTES;
+use crate::mid::sha2::Sha256Context;
+use crate::mid::{p256, p384, x25519};
+
+/// A JWE key management algorithm.
+///
+/// This decides how the content encryption key is agreed with
+/// the recipient.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JweAlgorithm {
+    /// `dir`: the content encryption key is shared in advance.
+    Direct,
+    /// `ECDH-ES`: the content encryption key is agreed using ephemeral-static
+    /// ECDH.
+    EcdhEs,
+    /// `ECDH-ES+A128KW`: a random content encryption key is wrapped with
+    /// AES-128 key wrap, using a key agreed using ephemeral-static ECDH.
+    EcdhEsA128Kw,
+    /// `RSA-OAEP-256`: a random content encryption key is encrypted with
+    /// RSAES-OAEP, using SHA-256 and MGF1 with SHA-256.
+    RsaOaep256,
+}
+
+impl JweAlgorithm {
+    /// The `alg` header parameter value for this algorithm.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Direct => "dir",
+            Self::EcdhEs => "ECDH-ES",
+            Self::EcdhEsA128Kw => "ECDH-ES+A128KW",
+            Self::RsaOaep256 => "RSA-OAEP-256",
+        }
+    }
+
+    /// Finds the algorithm with `name`.
+    ///
+    /// Names are case-sensitive.  Notably, `RSA1_5` and `RSA-OAEP` are
+    /// not supported.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|alg| alg.name() == name)
+    }
+
+    /// Whether there is an `encrypted_key` part.
+    fn has_encrypted_key(self) -> bool {
+        matches!(self, Self::EcdhEsA128Kw | Self::RsaOaep256)
+    }
+
+    const ALL: [Self; 4] = [
+        Self::Direct,
+        Self::EcdhEs,
+        Self::EcdhEsA128Kw,
+        Self::RsaOaep256,
+    ];
+}
+
+/// A JWE content encryption algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JweEncryption {
+    /// `A128GCM`: AES-128-GCM.
+    A128Gcm,
+    /// `A256GCM`: AES-256-GCM.
+    A256Gcm,
+}
+
+impl JweEncryption {
+    /// The `enc` header parameter value for this algorithm.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::A128Gcm => "A128GCM",
+            Self::A256Gcm => "A256GCM",
+        }
+    }
+
+    /// Finds the algorithm with `name`.
+    ///
+    /// Names are case-sensitive.
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::A128Gcm, Self::A256Gcm]
+            .into_iter()
+            .find(|enc| enc.name() == name)
+    }
+
+    /// The length of the content encryption key, in bytes.
+    ///
+    /// This is the length of key needed for [`JweAlgorithm::Direct`].
+    pub fn key_len(self) -> usize {
+        match self {
+            Self::A128Gcm => 16,
+            Self::A256Gcm => 32,
+        }
+    }
+}
+
+/// The protected header for a new JWE.
+#[derive(Clone, Copy, Debug)]
+pub struct JweHeader<'a> {
+    /// The key management algorithm, written as the `alg` parameter.
+    pub algorithm: JweAlgorithm,
+
+    /// The content encryption algorithm, written as the `enc` parameter.
+    pub encryption: JweEncryption,
+
+    /// The `kid` parameter, if any.
+    pub key_id: Option<&'a str>,
+
+    /// The `typ` parameter, if any.
+    pub media_type: Option<&'a str>,
+
+    /// The `cty` parameter, if any.  Nested JWTs use `JWT`.
+    pub content_type: Option<&'a str>,
+}
+
+impl JweHeader<'_> {
+    /// Makes a header with just the `alg` and `enc` parameters.
+    pub fn new(algorithm: JweAlgorithm, encryption: JweEncryption) -> Self {
+        Self {
+            algorithm,
+            encryption,
+            key_id: None,
+            media_type: None,
+            content_type: None,
+        }
+    }
+}
+
+/// A recipient's key, for producing JWEs.
+#[derive(Clone, Copy)]
+pub enum JweEncryptionKey<'a> {
+    /// A content encryption key shared with the recipient, for `dir`.
+    ///
+    /// This must be [`JweEncryption::key_len()`] bytes.
+    Direct(&'a [u8]),
+    /// A P-256 public key, for `ECDH-ES` and `ECDH-ES+A128KW`.
+    EcdhP256(&'a p256::PublicKey),
+    /// A P-384 public key, for `ECDH-ES` and `ECDH-ES+A128KW`.
+    EcdhP384(&'a p384::PublicKey),
+    /// An X25519 public key, for `ECDH-ES` and `ECDH-ES+A128KW`.
+    X25519(&'a x25519::PublicKey),
+    /// An RSA public key, for `RSA-OAEP-256`.
+    Rsa(&'a rsa::VerifyingKey),
+}
+
+impl JweEncryptionKey<'_> {
+    /// Produces a compact JWE with `header`, encrypting `plaintext`.
+    ///
+    /// The key management algorithm given in `header` must suit this key,
+    /// otherwise `KeyFormatError::MismatchedJoseAlgorithm` is returned.
+    /// The content encryption key (if not [`JweEncryptionKey::Direct`]),
+    /// any ephemeral key and the IV are random.
+    pub fn encrypt(&self, header: &JweHeader<'_>, plaintext: &[u8]) -> Result<String, Error> {
+        self.encrypt_with_rng(header, plaintext, &mut SystemRandom)
+    }
+
+    /// Produces a compact JWE with `header`, encrypting `plaintext`,
+    /// using `rng` for the content encryption key, any ephemeral key
+    /// and the IV.
+    ///
+    /// Otherwise, as [`Self::encrypt()`].
+    pub fn encrypt_with_rng(
+        &self,
+        header: &JweHeader<'_>,
+        plaintext: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<String, Error> {
+        let _entry = Entry::new_secret();
+        let mut cek = [0u8; MAX_KEY_LEN];
+        let cek = &mut cek[..header.encryption.key_len()];
+        let mut encrypted_key = [0u8; MAX_PUBLIC_MODULUS_BYTES];
+
+        let result = self.encrypt_key(header, cek, &mut encrypted_key, rng);
+        let result = result.and_then(|(epk, encrypted_key_len)| {
+            let mut jwe = encode_header(&[
+                ("alg", Some(Value::Text(header.algorithm.name()))),
+                ("enc", Some(Value::Text(header.encryption.name()))),
+                ("epk", epk.as_deref().map(Value::Object)),
+                ("kid", header.key_id.map(Value::Text)),
+                ("typ", header.media_type.map(Value::Text)),
+                ("cty", header.content_type.map(Value::Text)),
+            ]);
+
+            let mut iv = [0u8; IV_LEN];
+            rng.fill(&mut iv)?;
+            let mut ciphertext = plaintext.to_vec();
+            let mut tag = [0u8; TAG_LEN];
+            AesGcm::new(cek).encrypt(&iv, jwe.as_bytes(), &mut ciphertext, &mut tag);
+
+            for part in [&encrypted_key[..encrypted_key_len], &iv, &ciphertext, &tag] {
+                jwe.push('.');
+                jwe.push_str(&base64::encode_url(part));
+            }
+            Ok(jwe)
+        });
+
+        zeroise(cek);
+        result
+    }
+
+    /// Chooses the content encryption key `cek`, and encrypts it for
+    /// the recipient.
+    ///
+    /// Returns the JWK text of any ephemeral public key, and the
+    /// length of the encrypted key written to `encrypted_key`.
+    fn encrypt_key(
+        &self,
+        header: &JweHeader<'_>,
+        cek: &mut [u8],
+        encrypted_key: &mut [u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Option<String>, usize), Error> {
+        match (self, header.algorithm) {
+            (Self::Direct(key), JweAlgorithm::Direct) => {
+                if key.len() != cek.len() {
+                    return Err(Error::WrongLength);
+                }
+                cek.copy_from_slice(key);
+                Ok((None, 0))
+            }
+            (
+                Self::EcdhP256(_) | Self::EcdhP384(_) | Self::X25519(_),
+                JweAlgorithm::EcdhEs | JweAlgorithm::EcdhEsA128Kw,
+            ) => {
+                let mut z = [0u8; MAX_SHARED_SECRET_LEN];
+                let mut derived = [0u8; MAX_KEY_LEN];
+                let result = self.agree(&mut z, rng).and_then(|(epk, z_len)| {
+                    let derived = ecdh_derive(
+                        header.algorithm,
+                        header.encryption,
+                        &z[..z_len],
+                        &[],
+                        &[],
+                        &mut derived,
+                    );
+                    let len = match header.algorithm {
+                        JweAlgorithm::EcdhEs => {
+                            cek.copy_from_slice(derived);
+                            0
+                        }
+                        _ => {
+                            rng.fill(cek)?;
+                            AesKw::new(derived)?.wrap(cek, encrypted_key)?.len()
+                        }
+                    };
+                    Ok((Some(epk), len))
+                });
+                zeroise(&mut z);
+                zeroise(&mut derived);
+                result
+            }
+            (Self::Rsa(key), JweAlgorithm::RsaOaep256) => {
+                rng.fill(cek)?;
+                let len = key.encrypt_oaep_sha256(encrypted_key, cek, rng)?.len();
+                Ok((None, len))
+            }
+            _ => Err(KeyFormatError::MismatchedJoseAlgorithm.into()),
+        }
+    }
+
+    /// Does ECDH with a new ephemeral key, writing the shared secret to `z`.
+    ///
+    /// Returns the ephemeral public key's JWK text, and the length of
+    /// the shared secret.
+    fn agree(
+        &self,
+        z: &mut [u8; MAX_SHARED_SECRET_LEN],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(String, usize), Error> {
+        match self {
+            Self::EcdhP256(peer) => {
+                let private_key = p256::PrivateKey::generate(rng)?;
+                let public_key = p256::PublicKey::from_x962_uncompressed(
+                    &private_key.public_key_uncompressed(),
+                )?;
+                let shared = private_key.diffie_hellman(peer)?;
+                Ok((public_key.to_jwk()?, copy_secret(z, shared.as_ref())))
+            }
+            Self::EcdhP384(peer) => {
+                let private_key = p384::PrivateKey::generate(rng)?;
+                let public_key = p384::PublicKey::from_x962_uncompressed(
+                    &private_key.public_key_uncompressed(),
+                )?;
+                let shared = private_key.diffie_hellman(peer)?;
+                Ok((public_key.to_jwk()?, copy_secret(z, shared.as_ref())))
+            }
+            Self::X25519(peer) => {
+                let private_key = x25519::PrivateKey::generate(rng)?;
+                let public_key = private_key.public_key();
+                let shared = private_key.diffie_hellman(peer)?;
+                Ok((public_key.to_jwk()?, copy_secret(z, shared.as_ref())))
+            }
+            Self::Direct(_) | Self::Rsa(_) => Err(KeyFormatError::MismatchedJoseAlgorithm.into()),
+        }
+    }
+}
+
+/// A recipient's key, for decrypting JWEs.
+#[derive(Clone, Copy)]
+pub enum JweDecryptionKey<'a> {
+    /// A content encryption key shared with the sender, for `dir`.
+    ///
+    /// This must be [`JweEncryption::key_len()`] bytes.
+    Direct(&'a [u8]),
+    /// A P-256 private key, for `ECDH-ES` and `ECDH-ES+A128KW`.
+    EcdhP256(&'a p256::StaticPrivateKey),
+    /// A P-384 private key, for `ECDH-ES` and `ECDH-ES+A128KW`.
+    EcdhP384(&'a p384::StaticPrivateKey),
+    /// An X25519 private key, for `ECDH-ES` and `ECDH-ES+A128KW`.
+    X25519(&'a x25519::StaticPrivateKey),
+    /// An RSA private key, for `RSA-OAEP-256`.
+    Rsa(&'a rsa::SigningKey),
+    /// An external RSA key, for `RSA-OAEP-256`.
+    External(&'a dyn ExternalDecrypter),
+}
+
+impl JweDecryptionKey<'_> {
+    /// Does ECDH with the ephemeral public key `epk` (JWK text), writing
+    /// the shared secret to `z`.
+    ///
+    /// Returns the length of the shared secret.
+    fn agree(&self, epk: &str, z: &mut [u8; MAX_SHARED_SECRET_LEN]) -> Result<usize, Error> {
+        match self {
+            Self::EcdhP256(private_key) => {
+                let shared = private_key.diffie_hellman(&p256::PublicKey::from_jwk(epk)?)?;
+                Ok(copy_secret(z, shared.as_ref()))
+            }
+            Self::EcdhP384(private_key) => {
+                let shared = private_key.diffie_hellman(&p384::PublicKey::from_jwk(epk)?)?;
+                Ok(copy_secret(z, shared.as_ref()))
+            }
+            Self::X25519(private_key) => {
+                let shared = private_key.diffie_hellman(&x25519::PublicKey::from_jwk(epk)?)?;
+                Ok(copy_secret(z, shared.as_ref()))
+            }
+            Self::Direct(_) | Self::Rsa(_) | Self::External(_) => {
+                Err(KeyFormatError::MismatchedJoseAlgorithm.into())
+            }
+        }
+    }
+}
+
+/// A decoded, but not yet decrypted, compact JWE.
+///
+/// The header parameters are available before decryption, so the
+/// right key can be chosen -- but they are not authentic until
+/// [`Jwe::decrypt()`] succeeds.
+pub struct Jwe<'a> {
+    header: Header,
+    algorithm: JweAlgorithm,
+    encryption: JweEncryption,
+    /// The encoded protected header, which is the additional data.
+    aad: &'a str,
+    party_u_info: Vec<u8>,
+    party_v_info: Vec<u8>,
+    encrypted_key: Vec<u8>,
+    iv: [u8; IV_LEN],
+    ciphertext: Vec<u8>,
+    tag: [u8; TAG_LEN],
+}
+
+impl<'a> Jwe<'a> {
+    /// Decodes a compact JWE.
+    ///
+    /// `KeyFormatError::InvalidJose` is returned if `compact` is malformed,
+    /// and `KeyFormatError::UnsupportedJoseAlgorithm` if its key management
+    /// or content encryption algorithm is not supported.
+    pub fn decode(compact: &'a str) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        let [aad, encrypted_key, iv, ciphertext, tag] = split::<5>(compact)?;
+
+        let header = Header::decode(aad)?;
+        let algorithm = JweAlgorithm::from_name(header.algorithm())
+            .ok_or(KeyFormatError::UnsupportedJoseAlgorithm)?;
+        let encryption = JweEncryption::from_name(header.encryption().ok_or_else(invalid)?)
+            .ok_or(KeyFormatError::UnsupportedJoseAlgorithm)?;
+
+        let encrypted_key = decode_part(encrypted_key)?;
+        if encrypted_key.is_empty() == algorithm.has_encrypted_key() {
+            return Err(invalid());
+        }
+
+        let uses_ecdh = matches!(algorithm, JweAlgorithm::EcdhEs | JweAlgorithm::EcdhEsA128Kw);
+        if uses_ecdh != header.ephemeral_key().is_some() {
+            return Err(invalid());
+        }
+
+        let optional_part = |part: Option<&str>| match part {
+            Some(part) => decode_part(part),
+            None => Ok(Vec::new()),
+        };
+
+        Ok(Self {
+            algorithm,
+            encryption,
+            aad,
+            party_u_info: optional_part(header.party_u_info())?,
+            party_v_info: optional_part(header.party_v_info())?,
+            encrypted_key,
+            iv: decode_part(iv)?.try_into().map_err(|_| invalid())?,
+            ciphertext: decode_part(ciphertext)?,
+            tag: decode_part(tag)?.try_into().map_err(|_| invalid())?,
+            header,
+        })
+    }
+
+    /// The key management algorithm.
+    pub fn algorithm(&self) -> JweAlgorithm {
+        self.algorithm
+    }
+
+    /// The content encryption algorithm.
+    pub fn encryption(&self) -> JweEncryption {
+        self.encryption
+    }
+
+    /// The `kid` parameter, if present.
+    pub fn key_id(&self) -> Option<&str> {
+        self.header.key_id()
+    }
+
+    /// The `typ` parameter, if present.
+    pub fn media_type(&self) -> Option<&str> {
+        self.header.media_type()
+    }
+
+    /// The `cty` parameter, if present.
+    pub fn content_type(&self) -> Option<&str> {
+        self.header.content_type()
+    }
+
+    /// The whole protected header, as JSON text.
+    ///
+    /// This allows access to parameters not otherwise supported.
+    pub fn protected_header(&self) -> &str {
+        self.header.text()
+    }
+
+    /// Decrypts the JWE using `key`, and returns the plaintext.
+    ///
+    /// The key management algorithm must suit `key`, otherwise
+    /// `KeyFormatError::MismatchedJoseAlgorithm` is returned.  A
+    /// [`JweDecryptionKey::Direct`] key of the wrong length gives
+    /// [`Error::WrongLength`], and an invalid ephemeral public key gives
+    /// the error from decoding it.  Otherwise, any failure to decrypt the
+    /// content encryption key or the content gives
+    /// [`Error::DecryptFailed`] -- except for a
+    /// [`JweDecryptionKey::External`] key reporting
+    /// [`Error::ExternalKeyFailed`].
+    pub fn decrypt(&self, key: JweDecryptionKey<'_>) -> Result<Vec<u8>, Error> {
+        let _entry = Entry::new_secret();
+        let mut cek = [0u8; MAX_KEY_LEN];
+        let cek = &mut cek[..self.encryption.key_len()];
+
+        let result = self
+            .decrypt_key(key, cek)
+            .and_then(|()| self.decrypt_content(cek));
+
+        zeroise(cek);
+        result
+    }
+
+    /// The `encrypted_key` part.
+    ///
+    /// This is empty for `dir` and `ECDH-ES`.  For `RSA-OAEP-256`, it
+    /// may be decrypted by an [`ExternalDecrypter`] (perhaps
+    /// asynchronously), and the result given to [`Jwe::decrypt_with_cek()`].
+    pub fn encrypted_key(&self) -> &[u8] {
+        &self.encrypted_key
+    }
+
+    /// Decrypts the JWE using the already-recovered content encryption
+    /// key `cek`, and returns the plaintext.
+    ///
+    /// Any failure, including `cek` being the wrong length, gives
+    /// [`Error::DecryptFailed`].
+    pub fn decrypt_with_cek(&self, cek: &[u8]) -> Result<Vec<u8>, Error> {
+        let _entry = Entry::new_secret();
+        if cek.len() != self.encryption.key_len() {
+            return Err(Error::DecryptFailed);
+        }
+        self.decrypt_content(cek)
+    }
+
+    fn decrypt_content(&self, cek: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut plaintext = self.ciphertext.clone();
+        AesGcm::new(cek).decrypt(&self.iv, self.aad.as_bytes(), &mut plaintext, &self.tag)?;
+        Ok(plaintext)
+    }
+
+    /// Recovers the content encryption key into `cek`.
+    fn decrypt_key(&self, key: JweDecryptionKey<'_>, cek: &mut [u8]) -> Result<(), Error> {
+        match (key, self.algorithm) {
+            (JweDecryptionKey::Direct(key), JweAlgorithm::Direct) => {
+                if key.len() != cek.len() {
+                    return Err(Error::WrongLength);
+                }
+                cek.copy_from_slice(key);
+                Ok(())
+            }
+            (
+                JweDecryptionKey::EcdhP256(_)
+                | JweDecryptionKey::EcdhP384(_)
+                | JweDecryptionKey::X25519(_),
+                JweAlgorithm::EcdhEs | JweAlgorithm::EcdhEsA128Kw,
+            ) => {
+                // nb. presence was checked in `decode()`
+                let epk = self.header.ephemeral_key().ok_or_else(invalid)?;
+                let mut z = [0u8; MAX_SHARED_SECRET_LEN];
+                let mut derived = [0u8; MAX_KEY_LEN];
+                let result = key.agree(epk, &mut z).and_then(|z_len| {
+                    let derived = ecdh_derive(
+                        self.algorithm,
+                        self.encryption,
+                        &z[..z_len],
+                        &self.party_u_info,
+                        &self.party_v_info,
+                        &mut derived,
+                    );
+                    match self.algorithm {
+                        JweAlgorithm::EcdhEs => {
+                            cek.copy_from_slice(derived);
+                            Ok(())
+                        }
+                        _ => unwrap_cek(cek, |out| {
+                            AesKw::new(derived)?.unwrap(&self.encrypted_key, out)
+                        }),
+                    }
+                });
+                zeroise(&mut z);
+                zeroise(&mut derived);
+                result
+            }
+            (JweDecryptionKey::Rsa(key), JweAlgorithm::RsaOaep256) => {
+                unwrap_cek(cek, |out| key.decrypt_oaep_sha256(out, &self.encrypted_key))
+            }
+            (JweDecryptionKey::External(key), JweAlgorithm::RsaOaep256)
+                if key.algorithm() == DecryptionAlgorithm::RsaOaepSha256 =>
+            {
+                // `unwrap_cek` makes all errors look the same, but failures
+                // of the external key itself are not secret
+                let mut key_failed = false;
+                let result = unwrap_cek(cek, |out| {
+                    key.decrypt(&self.encrypted_key, out).map_err(|err| {
+                        key_failed = err == Error::ExternalKeyFailed;
+                        err
+                    })
+                });
+                match key_failed {
+                    true => Err(Error::ExternalKeyFailed),
+                    false => result,
+                }
+            }
+            _ => Err(KeyFormatError::MismatchedJoseAlgorithm.into()),
+        }
+    }
+}
+
+/// Derives a key from the ECDH shared secret `z`, into the front of `out`.
+///
+/// For `ECDH-ES`, this is the content encryption key.  For
+/// `ECDH-ES+A128KW` it is the AES-128 key-encryption key.
+fn ecdh_derive<'a>(
+    algorithm: JweAlgorithm,
+    encryption: JweEncryption,
+    z: &[u8],
+    party_u_info: &[u8],
+    party_v_info: &[u8],
+    out: &'a mut [u8; MAX_KEY_LEN],
+) -> &'a [u8] {
+    let (algorithm_id, len) = match algorithm {
+        JweAlgorithm::EcdhEs => (encryption.name(), encryption.key_len()),
+        _ => (algorithm.name(), 16),
+    };
+    let out = &mut out[..len];
+    concat_kdf(z, algorithm_id, party_u_info, party_v_info, out);
+    out
+}
+
+/// Recovers a content encryption key into `cek`, using `decrypt`.
+///
+/// `decrypt` writes the key into the buffer it is given, and returns
+/// the written-to slice.  A key of the wrong length is a decryption
+/// failure.
+fn unwrap_cek(
+    cek: &mut [u8],
+    decrypt: impl FnOnce(&mut [u8]) -> Result<&[u8], Error>,
+) -> Result<(), Error> {
+    let mut buffer = [0u8; MAX_PUBLIC_MODULUS_BYTES];
+    let result = decrypt(&mut buffer).and_then(|key| match key.len() == cek.len() {
+        true => {
+            cek.copy_from_slice(key);
+            Ok(())
+        }
+        false => Err(Error::DecryptFailed),
+    });
+    zeroise(&mut buffer);
+    result.map_err(|_| Error::DecryptFailed)
+}
+
+/// The Concat KDF from NIST SP800-56A, with SHA-256, as profiled by
+/// [RFC7518 section 4.6.2](https://datatracker.ietf.org/doc/html/rfc7518#section-4.6.2).
+///
+/// `out` is no longer than 32 bytes, so only one round is needed.
+fn concat_kdf(
+    z: &[u8],
+    algorithm_id: &str,
+    party_u_info: &[u8],
+    party_v_info: &[u8],
+    out: &mut [u8],
+) {
+    let mut ctx = Sha256Context::new();
+    ctx.update(&1u32.to_be_bytes());
+    ctx.update(z);
+    for field in [algorithm_id.as_bytes(), party_u_info, party_v_info] {
+        ctx.update(&(field.len() as u32).to_be_bytes());
+        ctx.update(field);
+    }
+    ctx.update(&((out.len() * 8) as u32).to_be_bytes());
+
+    let mut hash = ctx.finish();
+    out.copy_from_slice(&hash[..out.len()]);
+    zeroise(&mut hash);
+}
+
+fn copy_secret(z: &mut [u8; MAX_SHARED_SECRET_LEN], shared: &[u8]) -> usize {
+    z[..shared.len()].copy_from_slice(shared);
+    shared.len()
+}
+
+const IV_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const MAX_KEY_LEN: usize = 32;
+const MAX_SHARED_SECRET_LEN: usize = 48;
+
+// AES key wrap adds `aes_kw::OVERHEAD` to the content encryption key,
+// which must still fit in the buffer used for RSA.
+const _: () = assert!(MAX_KEY_LEN + aes_kw::OVERHEAD <= MAX_PUBLIC_MODULUS_BYTES);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::curve::{P256, P384};
+    use crate::high::ecdsa;
+    use crate::mid::rng::TestRng;
+
+    fn p256() -> p256::StaticPrivateKey {
+        ecdsa::SigningKey::<P256>::from_pkcs8_der(include_bytes!("../ecdsa/secp256r1.pkcs8.der"))
+            .unwrap()
+            .private_key
+    }
+
+    fn p384() -> p384::StaticPrivateKey {
+        ecdsa::SigningKey::<P384>::from_pkcs8_der(include_bytes!("../ecdsa/secp384r1.pkcs8.der"))
+            .unwrap()
+            .private_key
+    }
+
+    fn rsa() -> rsa::SigningKey {
+        rsa::SigningKey::from_pkcs8_der(include_bytes!("../rsa/rsa2048.pkcs8.der")).unwrap()
+    }
+
+    fn x25519() -> x25519::StaticPrivateKey {
+        x25519::StaticPrivateKey::from_array(&[1; 32])
+    }
+
+    #[test]
+    fn round_trip() {
+        let p256 = p256();
+        let p384 = p384();
+        let rsa = rsa();
+        let x25519 = x25519();
+
+        let p256_public =
+            p256::PublicKey::from_x962_uncompressed(&p256.public_key_uncompressed()).unwrap();
+        let p384_public =
+            p384::PublicKey::from_x962_uncompressed(&p384.public_key_uncompressed()).unwrap();
+        let rsa_public = rsa.public_key();
+        let x25519_public = x25519.public_key();
+
+        for encryption in [JweEncryption::A128Gcm, JweEncryption::A256Gcm] {
+            let direct = vec![0x42; encryption.key_len()];
+
+            for (algorithm, public_key, private_key) in [
+                (
+                    JweAlgorithm::Direct,
+                    JweEncryptionKey::Direct(&direct),
+                    JweDecryptionKey::Direct(&direct),
+                ),
+                (
+                    JweAlgorithm::EcdhEs,
+                    JweEncryptionKey::EcdhP256(&p256_public),
+                    JweDecryptionKey::EcdhP256(&p256),
+                ),
+                (
+                    JweAlgorithm::EcdhEsA128Kw,
+                    JweEncryptionKey::EcdhP256(&p256_public),
+                    JweDecryptionKey::EcdhP256(&p256),
+                ),
+                (
+                    JweAlgorithm::EcdhEs,
+                    JweEncryptionKey::EcdhP384(&p384_public),
+                    JweDecryptionKey::EcdhP384(&p384),
+                ),
+                (
+                    JweAlgorithm::EcdhEsA128Kw,
+                    JweEncryptionKey::EcdhP384(&p384_public),
+                    JweDecryptionKey::EcdhP384(&p384),
+                ),
+                (
+                    JweAlgorithm::EcdhEs,
+                    JweEncryptionKey::X25519(&x25519_public),
+                    JweDecryptionKey::X25519(&x25519),
+                ),
+                (
+                    JweAlgorithm::EcdhEsA128Kw,
+                    JweEncryptionKey::X25519(&x25519_public),
+                    JweDecryptionKey::X25519(&x25519),
+                ),
+                (
+                    JweAlgorithm::RsaOaep256,
+                    JweEncryptionKey::Rsa(&rsa_public),
+                    JweDecryptionKey::Rsa(&rsa),
+                ),
+            ] {
+                let header = JweHeader {
+                    key_id: Some("key"),
+                    media_type: Some("JWT"),
+                    content_type: Some("text/plain"),
+                    ..JweHeader::new(algorithm, encryption)
+                };
+                let token = public_key.encrypt(&header, b"hello world").unwrap();
+
+                let jwe = Jwe::decode(&token).unwrap();
+                assert_eq!(jwe.algorithm(), algorithm);
+                assert_eq!(jwe.encryption(), encryption);
+                assert_eq!(jwe.key_id(), Some("key"));
+                assert_eq!(jwe.media_type(), Some("JWT"));
+                assert_eq!(jwe.content_type(), Some("text/plain"));
+                assert!(jwe.protected_header().starts_with(&format!(
+                    r#"{{"alg":"{}","enc":"{}""#,
+                    algorithm.name(),
+                    encryption.name()
+                )));
+                assert_eq!(jwe.decrypt(private_key).unwrap(), b"hello world");
+
+                // each encryption is different
+                assert_ne!(public_key.encrypt(&header, b"hello world").unwrap(), token);
+
+                // ... unless the randomness is reproducible
+                let encrypt_with_seed = |seed: &[u8]| {
+                    public_key
+                        .encrypt_with_rng(&header, b"hello world", &mut TestRng::new(seed))
+                        .unwrap()
+                };
+                assert_eq!(encrypt_with_seed(b"seed"), encrypt_with_seed(b"seed"));
+                assert_ne!(encrypt_with_seed(b"seed"), encrypt_with_seed(b"other"));
+
+                // the whole protected header is authenticated
+                let mut parts = token.split('.').collect::<Vec<_>>();
+                let altered_header = base64::encode_url(
+                    jwe.protected_header()
+                        .replace(r#""kid":"key""#, r#""kid":"kex""#)
+                        .as_bytes(),
+                );
+                parts[0] = &altered_header;
+                assert_eq!(
+                    Jwe::decode(&parts.join("."))
+                        .unwrap()
+                        .decrypt(private_key)
+                        .err(),
+                    Some(Error::DecryptFailed)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn interop() {
+        // made by joserfc
+        let p256 = p256();
+        let p384 = p384();
+        let rsa = rsa();
+        let x25519 = x25519();
+        let direct = [0x42; 16];
+
+        for (token, key) in [
+            (
+                "eyJhbGciOiJFQ0RILUVTIiwiZW5jIjoiQTEyOEdDTSIsImtpZCI6InAyNTYiLCJhcHUiOiJRV3hwWTJVIiwiYXB2IjoiUW05aSIsImVwayI6eyJjcnYiOiJQLTI1NiIsIngiOiJsbUtxSXVFcVJzTHRJa1NvcVF3YWt6ekZCZG5tMFRNX0p4MTN0Nk8tQkRrIiwieSI6IkRVR2c3Z3VIVG5teXpNNGpZTDZrczBNbjlVSjZwNGJwOFlVZjdVZ1VUOUUiLCJrdHkiOiJFQyJ9fQ..RR_APdTYPQF21Riz.TCxkPAxvKSRj-5Y.j8rjwu4UaUfCthyeNBCcEA",
+                JweDecryptionKey::EcdhP256(&p256),
+            ),
+            (
+                "eyJhbGciOiJFQ0RILUVTK0ExMjhLVyIsImVuYyI6IkEyNTZHQ00iLCJlcGsiOnsiY3J2IjoiUC0zODQiLCJ4IjoiTkkxaFljc1MwcVVhYVQ2NkhLZGRJUDdSZElFSXZPOGliRmVoX1hFb0cwTUtXREZVRk1fVG5fcW95U2lOWmhVVSIsInkiOiJCUXNVN0NmOWI5MVpHNHFJTE5uSFdJMkt6Z2h4eXpXTGRNXzJqSkZ3ellUXzhKaFJWVDdnTjNsSHFtbmoyUUxZIiwia3R5IjoiRUMifX0.ITz8PF8GBcj93dVn60c2mH7LsyYyfrZ2iCcYyjfHbFlBvcRTriuRMQ.yYjMT25Qv4zi7llb.4pU2gMBJ_oe-YqU.a_0Qdbx_6sME-foEIGz2xQ",
+                JweDecryptionKey::EcdhP384(&p384),
+            ),
+            (
+                "eyJhbGciOiJFQ0RILUVTIiwiZW5jIjoiQTI1NkdDTSIsImVwayI6eyJjcnYiOiJYMjU1MTkiLCJ4IjoiY21lUmpxT1QtaGVIUEEyYXNnTmVMNkcxUUt3R2VFZzRQVHQ3Sjl6RHZWVSIsImt0eSI6Ik9LUCJ9fQ..62diUu5E8B4qeISb.i54fl4yRw-RM1rw.cBZCLig84_TRVyPJ23vDyg",
+                JweDecryptionKey::X25519(&x25519),
+            ),
+            (
+                "eyJhbGciOiJFQ0RILUVTK0ExMjhLVyIsImVuYyI6IkExMjhHQ00iLCJlcGsiOnsiY3J2IjoiWDI1NTE5IiwieCI6ImR4VEhPMHZHTlcwdHlIU2o4UU1LZmh6c1Q4VzFmZFI5ZjVkMzJwckJ6bHMiLCJrdHkiOiJPS1AifX0.brLOHovgYeSJwTJhRcy5lMXpAdVedK0q.XELHxdOLa6oVSXZU.HgaonCCArorY4No.rT1xeRjzIsLKyZXMgJQA5Q",
+                JweDecryptionKey::X25519(&x25519),
+            ),
+            (
+                "eyJhbGciOiJSU0EtT0FFUC0yNTYiLCJlbmMiOiJBMjU2R0NNIiwiY3R5IjoiSldUIn0.ctAn9hqHurbNeJ5FL8HqCGFG24qHAlyHa09fwsvzySLmLQQrdIfQxZfkPCtWiiRpDnzAUM1H1orPfZMQp8IWyvvRFY0OBUg7z90VNTweBLXbdD9ksBkg020Fv2-vQWgLFcQIQ4iMN_f1bizLxI8Gs3a9rf3iDs-zGvX5511FPgAE1_CKfC90BC6pSbdC8pzZDWOvm-VEcDOKnQgoigNhEJTILXecaKDJ5YxQ7D0qDLnpEeQzv4Ik7TchGsyKXYp07012sC5mmEMQp7VnA421dbv6XXL0tagCz7ySjsdTDefEerRRQRJOBGJMVbzMGD8ZMSgnjXNxceODnbfb0NMFOQ.LnK0zW7zgdocSdZN.sHkxvBBkyQ3wyAE.Akzea84LhRREZmn0E7oPOw",
+                JweDecryptionKey::Rsa(&rsa),
+            ),
+            (
+                "eyJhbGciOiJkaXIiLCJlbmMiOiJBMTI4R0NNIn0..iNLRHBr-MnTe_v8s.AhXbz7NmgcO3ZaA.Pxyf-BgFqwOaOFGOMU_s0A",
+                JweDecryptionKey::Direct(&direct),
+            ),
+        ] {
+            let jwe = Jwe::decode(token).unwrap();
+            assert_eq!(jwe.decrypt(key).unwrap(), b"hello world");
+        }
+    }
+
+    #[test]
+    fn rfc7518_appendix_c() {
+        let z = [
+            158, 86, 217, 29, 129, 113, 53, 211, 114, 131, 66, 131, 191, 132, 38, 156, 251, 49,
+            110, 163, 218, 128, 106, 72, 246, 218, 167, 121, 140, 254, 144, 196,
+        ];
+        let mut out = [0u8; MAX_KEY_LEN];
+        let key = ecdh_derive(
+            JweAlgorithm::EcdhEs,
+            JweEncryption::A128Gcm,
+            &z,
+            b"Alice",
+            b"Bob",
+            &mut out,
+        );
+        assert_eq!(base64::encode_url(key), "VqqN6vgjbSBcIijNcacQGg");
+    }
+
+    #[test]
+    fn mismatched_algorithm() {
+        let p256 = p256();
+        let rsa = rsa();
+        let rsa_public = rsa.public_key();
+        let direct = [0x42; 16];
+
+        let header = JweHeader::new(JweAlgorithm::EcdhEs, JweEncryption::A128Gcm);
+        for key in [
+            JweEncryptionKey::Direct(&direct),
+            JweEncryptionKey::Rsa(&rsa_public),
+        ] {
+            assert_eq!(
+                key.encrypt(&header, b"hello").err(),
+                Some(KeyFormatError::MismatchedJoseAlgorithm.into())
+            );
+        }
+
+        let token = JweEncryptionKey::Rsa(&rsa_public)
+            .encrypt(
+                &JweHeader::new(JweAlgorithm::RsaOaep256, JweEncryption::A128Gcm),
+                b"hello",
+            )
+            .unwrap();
+        let jwe = Jwe::decode(&token).unwrap();
+        for key in [
+            JweDecryptionKey::Direct(&direct),
+            JweDecryptionKey::EcdhP256(&p256),
+        ] {
+            assert_eq!(
+                jwe.decrypt(key).err(),
+                Some(KeyFormatError::MismatchedJoseAlgorithm.into())
+            );
+        }
+
+        // direct keys must suit the content encryption algorithm
+        assert_eq!(
+            JweEncryptionKey::Direct(&direct)
+                .encrypt(
+                    &JweHeader::new(JweAlgorithm::Direct, JweEncryption::A256Gcm),
+                    b"hello"
+                )
+                .err(),
+            Some(Error::WrongLength)
+        );
+        let token = JweEncryptionKey::Direct(&direct)
+            .encrypt(
+                &JweHeader::new(JweAlgorithm::Direct, JweEncryption::A128Gcm),
+                b"hello",
+            )
+            .unwrap();
+        assert_eq!(
+            Jwe::decode(&token)
+                .unwrap()
+                .decrypt(JweDecryptionKey::Direct(&[0x42; 32]))
+                .err(),
+            Some(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn external() {
+        use crate::high::external::tests::RsaHsm;
+
+        struct Broken;
+
+        impl ExternalDecrypter for Broken {
+            fn algorithm(&self) -> DecryptionAlgorithm {
+                DecryptionAlgorithm::RsaOaepSha256
+            }
+
+            fn decrypt<'a>(&self, _: &[u8], _: &'a mut [u8]) -> Result<&'a [u8], Error> {
+                Err(Error::ExternalKeyFailed)
+            }
+        }
+
+        let hsm = RsaHsm(rsa());
+        let rsa_public = hsm.0.public_key();
+        for encryption in [JweEncryption::A128Gcm, JweEncryption::A256Gcm] {
+            let token = JweEncryptionKey::Rsa(&rsa_public)
+                .encrypt(
+                    &JweHeader::new(JweAlgorithm::RsaOaep256, encryption),
+                    b"hello",
+                )
+                .unwrap();
+            let jwe = Jwe::decode(&token).unwrap();
+            assert_eq!(
+                jwe.decrypt(JweDecryptionKey::External(&hsm)).unwrap(),
+                b"hello"
+            );
+            assert_eq!(
+                jwe.decrypt(JweDecryptionKey::External(&Broken)).err(),
+                Some(Error::ExternalKeyFailed)
+            );
+
+            // in two steps
+            let mut cek = [0u8; 256];
+            let cek = hsm.decrypt(jwe.encrypted_key(), &mut cek).unwrap();
+            assert_eq!(jwe.decrypt_with_cek(cek).unwrap(), b"hello");
+            assert_eq!(
+                jwe.decrypt_with_cek(&cek[1..]).err(),
+                Some(Error::DecryptFailed)
+            );
+            assert_eq!(
+                jwe.decrypt_with_cek(&[0; 32][..cek.len()]).err(),
+                Some(Error::DecryptFailed)
+            );
+
+            // wrong key
+            let other = RsaHsm(
+                rsa::SigningKey::from_pkcs8_der(include_bytes!("../rsa/rsa3072.pkcs8.der"))
+                    .unwrap(),
+            );
+            assert_eq!(
+                jwe.decrypt(JweDecryptionKey::External(&other)).err(),
+                Some(Error::DecryptFailed)
+            );
+        }
+
+        let token = JweEncryptionKey::X25519(&x25519().public_key())
+            .encrypt(
+                &JweHeader::new(JweAlgorithm::EcdhEs, JweEncryption::A128Gcm),
+                b"hello",
+            )
+            .unwrap();
+        assert_eq!(
+            Jwe::decode(&token)
+                .unwrap()
+                .decrypt(JweDecryptionKey::External(&hsm))
+                .err(),
+            Some(KeyFormatError::MismatchedJoseAlgorithm.into())
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let rsa = rsa();
+        let rsa_public = rsa.public_key();
+        let token = JweEncryptionKey::Rsa(&rsa_public)
+            .encrypt(
+                &JweHeader::new(JweAlgorithm::RsaOaep256, JweEncryption::A128Gcm),
+                b"hello",
+            )
+            .unwrap();
+        let parts = token.split('.').collect::<Vec<_>>();
+        let header = |text: &str| base64::encode_url(text.as_bytes());
+        let with = |index: usize, part: &str| {
+            let mut parts = parts.clone();
+            parts[index] = part;
+            parts.join(".")
+        };
+
+        for compact in [
+            String::new(),
+            parts[..4].join("."),
+            format!("{token}."),
+            with(1, ""),
+            with(2, &base64::encode_url(&[0; 16])),
+            with(4, &base64::encode_url(&[0; 12])),
+            with(3, "a"),
+            with(0, &header(r#"{"alg":"RSA-OAEP-256"}"#)),
+            with(0, &header(r#"{"alg":"dir","enc":"A128GCM"}"#)),
+            with(0, &header(r#"{"alg":"ECDH-ES+A128KW","enc":"A128GCM"}"#)),
+            with(
+                0,
+                &header(r#"{"alg":"RSA-OAEP-256","enc":"A128GCM","apu":"!"}"#),
+            ),
+        ] {
+            assert_eq!(
+                Jwe::decode(&compact).err(),
+                Some(KeyFormatError::InvalidJose.into()),
+                "{compact}"
+            );
+        }
+
+        for alg_enc in [
+            r#""alg":"RSA-OAEP","enc":"A128GCM""#,
+            r#""alg":"RSA-OAEP-256","enc":"A128CBC-HS256""#,
+        ] {
+            assert_eq!(
+                Jwe::decode(&with(0, &header(&format!("{{{alg_enc}}}")))).err(),
+                Some(KeyFormatError::UnsupportedJoseAlgorithm.into())
+            );
+        }
+
+        // tampering with any part fails
+        let mut altered_key = decode_part(parts[1]).unwrap();
+        altered_key[10] ^= 1;
+        let mut altered_ciphertext = decode_part(parts[3]).unwrap();
+        altered_ciphertext[0] ^= 1;
+        let mut altered_tag = decode_part(parts[4]).unwrap();
+        altered_tag[15] ^= 1;
+        for compact in [
+            with(1, &base64::encode_url(&altered_key)),
+            with(1, &base64::encode_url(&altered_key[1..])),
+            with(2, &base64::encode_url(&[0; 12])),
+            with(3, &base64::encode_url(&altered_ciphertext)),
+            with(4, &base64::encode_url(&altered_tag)),
+        ] {
+            assert_eq!(
+                Jwe::decode(&compact)
+                    .unwrap()
+                    .decrypt(JweDecryptionKey::Rsa(&rsa))
+                    .err(),
+                Some(Error::DecryptFailed)
+            );
+        }
+    }
+}
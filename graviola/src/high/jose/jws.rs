@@ -0,0 +1,750 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! JSON Web Signature (JWS), in the compact serialization.
+//!
+//! This is [RFC7515](https://datatracker.ietf.org/doc/html/rfc7515), with
+//! algorithms from [RFC7518](https://datatracker.ietf.org/doc/html/rfc7518#section-3)
+//! and [RFC8037](https://datatracker.ietf.org/doc/html/rfc8037#section-3.1).
+
+use super::{Header, Value, decode_part, encode_header, split};
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::high::base64;
+use crate::high::curve::{MAX_SCALAR_LEN, P256, P384};
+use crate::high::ecdsa;
+use crate::high::external::{ExternalSigner, SignatureAlgorithm, SignatureRequest};
+use crate::high::hash::{Sha256, Sha384};
+use crate::high::rsa;
+use crate::low::Entry;
+use crate::mid::ed25519;
+use crate::mid::rsa_pub::MAX_PUBLIC_MODULUS_BYTES;
+
+/// A JWS signature algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JwsAlgorithm {
+    /// `ES256`: ECDSA on P-256 with SHA-256.
+    Es256,
+    /// `ES384`: ECDSA on P-384 with SHA-384.
+    Es384,
+    /// `RS256`: RSASSA-PKCS1-v1_5 with SHA-256.
+    Rs256,
+    /// `RS384`: RSASSA-PKCS1-v1_5 with SHA-384.
+    Rs384,
+    /// `RS512`: RSASSA-PKCS1-v1_5 with SHA-512.
+    Rs512,
+    /// `PS256`: RSASSA-PSS with SHA-256, and a 32-byte salt.
+    Ps256,
+    /// `PS384`: RSASSA-PSS with SHA-384, and a 48-byte salt.
+    Ps384,
+    /// `PS512`: RSASSA-PSS with SHA-512, and a 64-byte salt.
+    Ps512,
+    /// `EdDSA`: Ed25519.
+    EdDsa,
+}
+
+impl JwsAlgorithm {
+    /// The `alg` header parameter value for this algorithm.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Es256 => "ES256",
+            Self::Es384 => "ES384",
+            Self::Rs256 => "RS256",
+            Self::Rs384 => "RS384",
+            Self::Rs512 => "RS512",
+            Self::Ps256 => "PS256",
+            Self::Ps384 => "PS384",
+            Self::Ps512 => "PS512",
+            Self::EdDsa => "EdDSA",
+        }
+    }
+
+    /// Finds the algorithm with `name`.
+    ///
+    /// Names are case-sensitive.  Notably, `none` is not supported.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|alg| alg.name() == name)
+    }
+
+    /// The equivalent algorithm for an [`ExternalSigner`].
+    pub fn signature_algorithm(self) -> SignatureAlgorithm {
+        match self {
+            Self::Es256 => SignatureAlgorithm::EcdsaP256Sha256,
+            Self::Es384 => SignatureAlgorithm::EcdsaP384Sha384,
+            Self::Rs256 => SignatureAlgorithm::RsaPkcs1Sha256,
+            Self::Rs384 => SignatureAlgorithm::RsaPkcs1Sha384,
+            Self::Rs512 => SignatureAlgorithm::RsaPkcs1Sha512,
+            Self::Ps256 => SignatureAlgorithm::RsaPssSha256,
+            Self::Ps384 => SignatureAlgorithm::RsaPssSha384,
+            Self::Ps512 => SignatureAlgorithm::RsaPssSha512,
+            Self::EdDsa => SignatureAlgorithm::Ed25519,
+        }
+    }
+
+    const ALL: [Self; 9] = [
+        Self::Es256,
+        Self::Es384,
+        Self::Rs256,
+        Self::Rs384,
+        Self::Rs512,
+        Self::Ps256,
+        Self::Ps384,
+        Self::Ps512,
+        Self::EdDsa,
+    ];
+}
+
+/// The protected header for a new JWS.
+#[derive(Clone, Copy, Debug)]
+pub struct JwsHeader<'a> {
+    /// The signature algorithm, written as the `alg` parameter.
+    pub algorithm: JwsAlgorithm,
+
+    /// The `kid` parameter, if any.
+    pub key_id: Option<&'a str>,
+
+    /// The `typ` parameter, if any.  JWTs often use `JWT`.
+    pub media_type: Option<&'a str>,
+
+    /// The `cty` parameter, if any.
+    pub content_type: Option<&'a str>,
+}
+
+impl JwsHeader<'_> {
+    /// Makes a header with just the `alg` parameter.
+    pub fn new(algorithm: JwsAlgorithm) -> Self {
+        Self {
+            algorithm,
+            key_id: None,
+            media_type: None,
+            content_type: None,
+        }
+    }
+}
+
+/// A private key that can produce JWS signatures.
+#[derive(Clone, Copy)]
+pub enum JwsSigningKey<'a> {
+    /// A P-256 key, for `ES256`.
+    EcdsaP256(&'a ecdsa::SigningKey<P256>),
+    /// A P-384 key, for `ES384`.
+    EcdsaP384(&'a ecdsa::SigningKey<P384>),
+    /// An RSA key, for `RS256`, `RS384`, `RS512`, `PS256`, `PS384` and `PS512`.
+    Rsa(&'a rsa::SigningKey),
+    /// An Ed25519 key, for `EdDSA`.
+    Ed25519(&'a ed25519::SigningKey),
+    /// An external key, for the algorithm given by [`ExternalSigner::algorithm()`].
+    External(&'a dyn ExternalSigner),
+}
+
+impl JwsSigningKey<'_> {
+    /// Produces a compact JWS with `header`, over `payload`.
+    ///
+    /// The algorithm given in `header` must suit this key, otherwise
+    /// `KeyFormatError::MismatchedJoseAlgorithm` is returned.
+    pub fn sign(&self, header: &JwsHeader<'_>, payload: &[u8]) -> Result<String, Error> {
+        let _entry = Entry::new_secret();
+        if let Self::External(signer) = self {
+            if signer.algorithm() != header.algorithm.signature_algorithm() {
+                return Err(KeyFormatError::MismatchedJoseAlgorithm.into());
+            }
+            let unsigned = UnsignedJws::new(header, payload);
+            let signature = signer.sign(unsigned.request())?;
+            return unsigned.finish(&signature);
+        }
+
+        let mut jws = signing_input(header, payload);
+        let message = jws.as_bytes();
+        let mut buffer = [0u8; MAX_PUBLIC_MODULUS_BYTES];
+        let signature = match (self, header.algorithm) {
+            (Self::EcdsaP256(key), JwsAlgorithm::Es256) => {
+                key.sign::<Sha256>(&[message], &mut buffer)?
+            }
+            (Self::EcdsaP384(key), JwsAlgorithm::Es384) => {
+                key.sign::<Sha384>(&[message], &mut buffer)?
+            }
+            (Self::Rsa(key), JwsAlgorithm::Rs256) => key.sign_pkcs1_sha256(&mut buffer, message)?,
+            (Self::Rsa(key), JwsAlgorithm::Rs384) => key.sign_pkcs1_sha384(&mut buffer, message)?,
+            (Self::Rsa(key), JwsAlgorithm::Rs512) => key.sign_pkcs1_sha512(&mut buffer, message)?,
+            (Self::Rsa(key), JwsAlgorithm::Ps256) => key.sign_pss_sha256(&mut buffer, message)?,
+            (Self::Rsa(key), JwsAlgorithm::Ps384) => key.sign_pss_sha384(&mut buffer, message)?,
+            (Self::Rsa(key), JwsAlgorithm::Ps512) => key.sign_pss_sha512(&mut buffer, message)?,
+            (Self::Ed25519(key), JwsAlgorithm::EdDsa) => {
+                let signature = key.sign(&[message]);
+                buffer[..signature.len()].copy_from_slice(&signature);
+                &buffer[..signature.len()]
+            }
+            _ => return Err(KeyFormatError::MismatchedJoseAlgorithm.into()),
+        };
+
+        let signature = base64::encode_url(signature);
+        jws.push('.');
+        jws.push_str(&signature);
+        Ok(jws)
+    }
+}
+
+/// A JWS awaiting its signature from an [`ExternalSigner`].
+///
+/// [`JwsSigningKey::External`] does this in one step.  This type
+/// separates the steps, so the signature can be obtained asynchronously.
+///
+/// ```
+/// use graviola::external::*;
+/// use graviola::jose::*;
+/// # use graviola::signing::ed25519;
+/// # let key = ed25519::SigningKey::from_seed(&[1; 32]);
+///
+/// let header = JwsHeader::new(JwsAlgorithm::EdDsa);
+/// let unsigned = UnsignedJws::new(&header, b"hello world");
+/// let request = unsigned.request().clone();
+///
+/// // send `request` to the external key, and get its signature
+/// assert_eq!(request.algorithm(), SignatureAlgorithm::Ed25519);
+/// # let signature = key.sign(&[request.input()]).to_vec();
+///
+/// let compact = unsigned.finish(&signature).unwrap();
+/// # let public_key = key.verifying_key();
+/// # Jws::decode(&compact).unwrap().verify(JwsVerifyingKey::Ed25519(&public_key)).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnsignedJws {
+    algorithm: JwsAlgorithm,
+    signing_input: String,
+    request: SignatureRequest,
+}
+
+impl UnsignedJws {
+    /// Prepares a compact JWS with `header`, over `payload`.
+    pub fn new(header: &JwsHeader<'_>, payload: &[u8]) -> Self {
+        let signing_input = signing_input(header, payload);
+        let request = SignatureRequest::new(
+            header.algorithm.signature_algorithm(),
+            signing_input.as_bytes(),
+        );
+        Self {
+            algorithm: header.algorithm,
+            signing_input,
+            request,
+        }
+    }
+
+    /// The request to give to the external key.
+    pub fn request(&self) -> &SignatureRequest {
+        &self.request
+    }
+
+    /// Produces the compact JWS, using the external key's `signature`.
+    ///
+    /// `signature` is as described in [`ExternalSigner::sign()`].
+    /// [`Error::ExternalKeyFailed`] is returned if it is malformed.
+    pub fn finish(self, signature: &[u8]) -> Result<String, Error> {
+        let mut fixed = [0u8; MAX_SCALAR_LEN * 2];
+        let signature = match self.algorithm {
+            // JWS uses the fixed-length encoding
+            JwsAlgorithm::Es256 => {
+                ecdsa::VerifyingKey::<P256>::asn1_to_fixed(signature, &mut fixed)
+                    .map_err(|_| Error::ExternalKeyFailed)?
+            }
+            JwsAlgorithm::Es384 => {
+                ecdsa::VerifyingKey::<P384>::asn1_to_fixed(signature, &mut fixed)
+                    .map_err(|_| Error::ExternalKeyFailed)?
+            }
+            JwsAlgorithm::EdDsa if signature.len() != ed25519::SIGNATURE_LEN => {
+                return Err(Error::ExternalKeyFailed);
+            }
+            _ if signature.is_empty() => return Err(Error::ExternalKeyFailed),
+            _ => signature,
+        };
+
+        let mut jws = self.signing_input;
+        jws.push('.');
+        jws.push_str(&base64::encode_url(signature));
+        Ok(jws)
+    }
+}
+
+/// The header and payload parts of a compact JWS, and the `.` between them.
+fn signing_input(header: &JwsHeader<'_>, payload: &[u8]) -> String {
+    let mut jws = encode_header(&[
+        ("alg", Some(Value::Text(header.algorithm.name()))),
+        ("kid", header.key_id.map(Value::Text)),
+        ("typ", header.media_type.map(Value::Text)),
+        ("cty", header.content_type.map(Value::Text)),
+    ]);
+    jws.push('.');
+    jws.push_str(&base64::encode_url(payload));
+    jws
+}
+
+/// A public key that can verify JWS signatures.
+#[derive(Clone, Copy)]
+pub enum JwsVerifyingKey<'a> {
+    /// A P-256 key, for `ES256`.
+    EcdsaP256(&'a ecdsa::VerifyingKey<P256>),
+    /// A P-384 key, for `ES384`.
+    EcdsaP384(&'a ecdsa::VerifyingKey<P384>),
+    /// An RSA key, for `RS256`, `RS384`, `RS512`, `PS256`, `PS384` and `PS512`.
+    Rsa(&'a rsa::VerifyingKey),
+    /// An Ed25519 key, for `EdDSA`.
+    Ed25519(&'a ed25519::VerifyingKey),
+}
+
+/// A decoded, but not yet verified, compact JWS.
+///
+/// The header parameters are available before verification, so the
+/// right key can be chosen -- but they are not authentic until
+/// [`Jws::verify()`] succeeds.  The payload is only available from
+/// [`Jws::verify()`].
+pub struct Jws<'a> {
+    header: Header,
+    algorithm: JwsAlgorithm,
+    signing_input: &'a str,
+    payload: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+impl<'a> Jws<'a> {
+    /// Decodes a compact JWS.
+    ///
+    /// `KeyFormatError::InvalidJose` is returned if `compact` is malformed,
+    /// and `KeyFormatError::UnsupportedJoseAlgorithm` if its algorithm
+    /// is not supported.
+    pub fn decode(compact: &'a str) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        let [header, payload, signature] = split::<3>(compact)?;
+        let signing_input = &compact[..header.len() + 1 + payload.len()];
+
+        let header = Header::decode(header)?;
+        let algorithm = JwsAlgorithm::from_name(header.algorithm())
+            .ok_or(KeyFormatError::UnsupportedJoseAlgorithm)?;
+
+        Ok(Self {
+            header,
+            algorithm,
+            signing_input,
+            payload: decode_part(payload)?,
+            signature: decode_part(signature)?,
+        })
+    }
+
+    /// The signature algorithm.
+    pub fn algorithm(&self) -> JwsAlgorithm {
+        self.algorithm
+    }
+
+    /// The `kid` parameter, if present.
+    pub fn key_id(&self) -> Option<&str> {
+        self.header.key_id()
+    }
+
+    /// The `typ` parameter, if present.
+    pub fn media_type(&self) -> Option<&str> {
+        self.header.media_type()
+    }
+
+    /// The `cty` parameter, if present.
+    pub fn content_type(&self) -> Option<&str> {
+        self.header.content_type()
+    }
+
+    /// The whole protected header, as JSON text.
+    ///
+    /// This allows access to parameters not otherwise supported.
+    pub fn protected_header(&self) -> &str {
+        self.header.text()
+    }
+
+    /// Verifies the signature using `key`, and returns the payload.
+    ///
+    /// The algorithm must suit `key`, otherwise
+    /// `KeyFormatError::MismatchedJoseAlgorithm` is returned.  An invalid
+    /// signature gives [`Error::BadSignature`].
+    pub fn verify(&self, key: JwsVerifyingKey<'_>) -> Result<&[u8], Error> {
+        let _entry = Entry::new_public();
+        let message = self.signing_input.as_bytes();
+        let signature = &self.signature;
+
+        match (key, self.algorithm) {
+            (JwsVerifyingKey::EcdsaP256(key), JwsAlgorithm::Es256) => {
+                key.verify::<Sha256>(&[message], signature)
+            }
+            (JwsVerifyingKey::EcdsaP384(key), JwsAlgorithm::Es384) => {
+                key.verify::<Sha384>(&[message], signature)
+            }
+            (JwsVerifyingKey::Rsa(key), JwsAlgorithm::Rs256) => {
+                key.verify_pkcs1_sha256(signature, message)
+            }
+            (JwsVerifyingKey::Rsa(key), JwsAlgorithm::Rs384) => {
+                key.verify_pkcs1_sha384(signature, message)
+            }
+            (JwsVerifyingKey::Rsa(key), JwsAlgorithm::Rs512) => {
+                key.verify_pkcs1_sha512(signature, message)
+            }
+            (JwsVerifyingKey::Rsa(key), JwsAlgorithm::Ps256) => {
+                key.verify_pss_sha256(signature, message)
+            }
+            (JwsVerifyingKey::Rsa(key), JwsAlgorithm::Ps384) => {
+                key.verify_pss_sha384(signature, message)
+            }
+            (JwsVerifyingKey::Rsa(key), JwsAlgorithm::Ps512) => {
+                key.verify_pss_sha512(signature, message)
+            }
+            (JwsVerifyingKey::Ed25519(key), JwsAlgorithm::EdDsa) => {
+                key.verify(&[message], signature)
+            }
+            _ => return Err(KeyFormatError::MismatchedJoseAlgorithm.into()),
+        }
+        // nb. an ECDSA signature of the wrong length is not well-formed, but
+        // is reported the same as any other invalid signature.
+        .map_err(|_| Error::BadSignature)?;
+
+        Ok(&self.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::curve::{Curve, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, PrivateKey};
+
+    fn p256() -> ecdsa::SigningKey<P256> {
+        ecdsa::SigningKey::from_pkcs8_der(include_bytes!("../ecdsa/secp256r1.pkcs8.der")).unwrap()
+    }
+
+    fn p384() -> ecdsa::SigningKey<P384> {
+        ecdsa::SigningKey::from_pkcs8_der(include_bytes!("../ecdsa/secp384r1.pkcs8.der")).unwrap()
+    }
+
+    fn public<C: Curve>(key: &ecdsa::SigningKey<C>) -> ecdsa::VerifyingKey<C> {
+        let mut buffer = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+        let encoded = key
+            .private_key
+            .public_key_encode_uncompressed(&mut buffer)
+            .unwrap();
+        ecdsa::VerifyingKey::from_x962_uncompressed(encoded).unwrap()
+    }
+
+    fn rsa() -> rsa::SigningKey {
+        rsa::SigningKey::from_pkcs8_der(include_bytes!("../rsa/rsa2048.pkcs8.der")).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let p256 = p256();
+        let p384 = p384();
+        let rsa = rsa();
+        let ed25519 = ed25519::SigningKey::from_seed(&[1; 32]);
+
+        let p256_public = public(&p256);
+        let p384_public = public(&p384);
+        let rsa_public = rsa.public_key();
+        let ed25519_public = ed25519.verifying_key();
+
+        for (key, public_key, algorithm) in [
+            (
+                JwsSigningKey::EcdsaP256(&p256),
+                JwsVerifyingKey::EcdsaP256(&p256_public),
+                JwsAlgorithm::Es256,
+            ),
+            (
+                JwsSigningKey::EcdsaP384(&p384),
+                JwsVerifyingKey::EcdsaP384(&p384_public),
+                JwsAlgorithm::Es384,
+            ),
+            (
+                JwsSigningKey::Rsa(&rsa),
+                JwsVerifyingKey::Rsa(&rsa_public),
+                JwsAlgorithm::Rs256,
+            ),
+            (
+                JwsSigningKey::Rsa(&rsa),
+                JwsVerifyingKey::Rsa(&rsa_public),
+                JwsAlgorithm::Rs384,
+            ),
+            (
+                JwsSigningKey::Rsa(&rsa),
+                JwsVerifyingKey::Rsa(&rsa_public),
+                JwsAlgorithm::Rs512,
+            ),
+            (
+                JwsSigningKey::Rsa(&rsa),
+                JwsVerifyingKey::Rsa(&rsa_public),
+                JwsAlgorithm::Ps256,
+            ),
+            (
+                JwsSigningKey::Rsa(&rsa),
+                JwsVerifyingKey::Rsa(&rsa_public),
+                JwsAlgorithm::Ps384,
+            ),
+            (
+                JwsSigningKey::Rsa(&rsa),
+                JwsVerifyingKey::Rsa(&rsa_public),
+                JwsAlgorithm::Ps512,
+            ),
+            (
+                JwsSigningKey::Ed25519(&ed25519),
+                JwsVerifyingKey::Ed25519(&ed25519_public),
+                JwsAlgorithm::EdDsa,
+            ),
+        ] {
+            let header = JwsHeader {
+                key_id: Some("key-1"),
+                media_type: Some("JWT"),
+                ..JwsHeader::new(algorithm)
+            };
+            let compact = key.sign(&header, b"{\"sub\":\"hello\"}").unwrap();
+            let parts = compact.split('.').collect::<Vec<_>>();
+            assert_eq!(parts.len(), 3);
+            assert_eq!(
+                parts[0],
+                base64::encode_url(
+                    format!(
+                        r#"{{"alg":"{}","kid":"key-1","typ":"JWT"}}"#,
+                        algorithm.name()
+                    )
+                    .as_bytes()
+                )
+            );
+            assert_eq!(parts[1], base64::encode_url(b"{\"sub\":\"hello\"}"));
+            assert!(!parts[2].is_empty());
+
+            let jws = Jws::decode(&compact).unwrap();
+            assert_eq!(jws.algorithm(), algorithm);
+            assert_eq!(JwsAlgorithm::from_name(algorithm.name()), Some(algorithm));
+            assert_eq!(jws.key_id(), Some("key-1"));
+            assert_eq!(jws.media_type(), Some("JWT"));
+            assert_eq!(jws.content_type(), None);
+            assert_eq!(
+                jws.protected_header(),
+                format!(
+                    r#"{{"alg":"{}","kid":"key-1","typ":"JWT"}}"#,
+                    algorithm.name()
+                )
+            );
+            assert_eq!(jws.verify(public_key).unwrap(), b"{\"sub\":\"hello\"}");
+
+            // altered payload
+            let (head, tail) = compact.split_once('.').unwrap();
+            let (_, signature) = tail.split_once('.').unwrap();
+            let altered = format!("{head}.{}.{signature}", base64::encode_url(b"{}"));
+            assert_eq!(
+                Jws::decode(&altered).unwrap().verify(public_key).err(),
+                Some(Error::BadSignature)
+            );
+
+            // truncated signature
+            let mut short = base64::decode_url(signature).unwrap();
+            short.pop();
+            let signing_input = &compact[..compact.rfind('.').unwrap()];
+            let truncated = format!("{signing_input}.{}", base64::encode_url(&short));
+            assert_eq!(
+                Jws::decode(&truncated).unwrap().verify(public_key).err(),
+                Some(Error::BadSignature)
+            );
+        }
+    }
+
+    #[test]
+    fn mismatched_algorithm() {
+        let p256 = p256();
+        let rsa = rsa();
+        let rsa_public = rsa.public_key();
+
+        assert_eq!(
+            JwsSigningKey::EcdsaP256(&p256)
+                .sign(&JwsHeader::new(JwsAlgorithm::Es384), b"")
+                .err(),
+            Some(Error::KeyFormatError(
+                KeyFormatError::MismatchedJoseAlgorithm
+            ))
+        );
+        assert_eq!(
+            JwsSigningKey::Rsa(&rsa)
+                .sign(&JwsHeader::new(JwsAlgorithm::EdDsa), b"")
+                .err(),
+            Some(Error::KeyFormatError(
+                KeyFormatError::MismatchedJoseAlgorithm
+            ))
+        );
+
+        let compact = JwsSigningKey::EcdsaP256(&p256)
+            .sign(&JwsHeader::new(JwsAlgorithm::Es256), b"")
+            .unwrap();
+        assert_eq!(
+            Jws::decode(&compact)
+                .unwrap()
+                .verify(JwsVerifyingKey::Rsa(&rsa_public))
+                .err(),
+            Some(Error::KeyFormatError(
+                KeyFormatError::MismatchedJoseAlgorithm
+            ))
+        );
+    }
+
+    #[test]
+    fn external() {
+        use crate::high::external::tests::Hsm;
+
+        let rsa_public = rsa().public_key();
+        let ed25519_public = ed25519::SigningKey::from_seed(&[1; 32]).verifying_key();
+        let p256_public = public(&p256());
+        let p384_public = public(&p384());
+
+        for (hsm, public_key, algorithm) in [
+            (
+                Hsm::p256(),
+                JwsVerifyingKey::EcdsaP256(&p256_public),
+                JwsAlgorithm::Es256,
+            ),
+            (
+                Hsm::p384(),
+                JwsVerifyingKey::EcdsaP384(&p384_public),
+                JwsAlgorithm::Es384,
+            ),
+            (
+                Hsm::rsa(SignatureAlgorithm::RsaPkcs1Sha384),
+                JwsVerifyingKey::Rsa(&rsa_public),
+                JwsAlgorithm::Rs384,
+            ),
+            (
+                Hsm::rsa(SignatureAlgorithm::RsaPssSha256),
+                JwsVerifyingKey::Rsa(&rsa_public),
+                JwsAlgorithm::Ps256,
+            ),
+            (
+                Hsm::ed25519(),
+                JwsVerifyingKey::Ed25519(&ed25519_public),
+                JwsAlgorithm::EdDsa,
+            ),
+        ] {
+            let header = JwsHeader::new(algorithm);
+            let compact = JwsSigningKey::External(&hsm)
+                .sign(&header, b"payload")
+                .unwrap();
+            let jws = Jws::decode(&compact).unwrap();
+            assert_eq!(jws.verify(public_key).unwrap(), b"payload");
+
+            // in two steps
+            let unsigned = UnsignedJws::new(&header, b"payload");
+            assert_eq!(unsigned.request().algorithm(), hsm.algorithm());
+            let signature = hsm.sign(unsigned.request()).unwrap();
+            let compact = unsigned.finish(&signature).unwrap();
+            let jws = Jws::decode(&compact).unwrap();
+            assert_eq!(jws.verify(public_key).unwrap(), b"payload");
+
+            // malformed signature
+            let unsigned = UnsignedJws::new(&header, b"payload");
+            assert_eq!(unsigned.finish(&[]).err(), Some(Error::ExternalKeyFailed));
+        }
+
+        assert_eq!(
+            JwsSigningKey::External(&Hsm::p256())
+                .sign(&JwsHeader::new(JwsAlgorithm::Es384), b"")
+                .err(),
+            Some(Error::KeyFormatError(
+                KeyFormatError::MismatchedJoseAlgorithm
+            ))
+        );
+        assert_eq!(
+            JwsSigningKey::External(&Hsm::Broken(SignatureAlgorithm::Ed25519))
+                .sign(&JwsHeader::new(JwsAlgorithm::EdDsa), b"")
+                .err(),
+            Some(Error::ExternalKeyFailed)
+        );
+    }
+
+    #[test]
+    fn rfc7515_appendix_a3() {
+        // ES256 example from RFC7515 appendix A.3
+        let key = ecdsa::VerifyingKey::<P256>::from_jwk(
+            r#"{"kty":"EC","crv":"P-256",
+                "x":"f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU",
+                "y":"x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"}"#,
+        )
+        .unwrap();
+        let compact = concat!(
+            "eyJhbGciOiJFUzI1NiJ9",
+            ".",
+            "eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFt",
+            "cGxlLmNvbS9pc19yb290Ijp0cnVlfQ",
+            ".",
+            "DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSA",
+            "pmWQxfKTUJqPP3-Kg6NU1Q"
+        );
+        let jws = Jws::decode(compact).unwrap();
+        assert_eq!(jws.algorithm(), JwsAlgorithm::Es256);
+        assert_eq!(
+            jws.verify(JwsVerifyingKey::EcdsaP256(&key)).unwrap(),
+            b"{\"iss\":\"joe\",\r\n \"exp\":1300819380,\r\n \"http://example.com/is_root\":true}"
+        );
+    }
+
+    #[test]
+    fn rfc8037_appendix_a4() {
+        // Ed25519 example from RFC8037 appendix A.4
+        let key = ed25519::VerifyingKey::from_jwk(
+            r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+        )
+        .unwrap();
+        let compact = concat!(
+            "eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDI1NTE5IHNpZ25pbmc.",
+            "hgyY0il_MGCjP0JzlnLWG1PPOt7-09PGcvMg3AIbQR6dWbhijcNR4ki4iylGjg5BhVsPt9g7sVvpAr_MuM0KAg"
+        );
+        let jws = Jws::decode(compact).unwrap();
+        assert_eq!(jws.algorithm(), JwsAlgorithm::EdDsa);
+        assert_eq!(
+            jws.verify(JwsVerifyingKey::Ed25519(&key)).unwrap(),
+            b"Example of Ed25519 signing"
+        );
+
+        let signing_key = ed25519::SigningKey::from_seed(&[
+            0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec,
+            0x2c, 0xc4, 0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03,
+            0x1c, 0xae, 0x7f, 0x60,
+        ]);
+        assert_eq!(
+            JwsSigningKey::Ed25519(&signing_key)
+                .sign(
+                    &JwsHeader::new(JwsAlgorithm::EdDsa),
+                    b"Example of Ed25519 signing"
+                )
+                .unwrap(),
+            compact
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for (compact, error) in [
+            ("", KeyFormatError::InvalidJose),
+            (
+                "eyJhbGciOiJFUzI1NiJ9.e30.AA.AA",
+                KeyFormatError::InvalidJose,
+            ),
+            ("eyJhbGciOiJFUzI1NiJ9.e30=.AA", KeyFormatError::InvalidJose),
+            ("eyJhbGciOiJFUzI1NiJ9.e30.A", KeyFormatError::InvalidJose),
+            // {"alg":"none"}
+            (
+                "eyJhbGciOiJub25lIn0.e30.",
+                KeyFormatError::UnsupportedJoseAlgorithm,
+            ),
+            // {"alg":"HS256"}
+            (
+                "eyJhbGciOiJIUzI1NiJ9.e30.AA",
+                KeyFormatError::UnsupportedJoseAlgorithm,
+            ),
+            // {"alg":"es256"}
+            (
+                "eyJhbGciOiJlczI1NiJ9.e30.AA",
+                KeyFormatError::UnsupportedJoseAlgorithm,
+            ),
+        ] {
+            assert_eq!(
+                Jws::decode(compact).err(),
+                Some(Error::KeyFormatError(error)),
+                "{compact}"
+            );
+        }
+    }
+}
@@ -0,0 +1,411 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Just enough JSON for key formats.
+//!
+//! Errors are reported as `None`, so callers can give them a
+//! format-specific meaning.
+
+/// A parsed JSON value.
+///
+/// Numbers are kept as their source text.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Json<'a> {
+    Null,
+    Bool(bool),
+    Number(&'a str),
+    String(String),
+    Array(Vec<Self>),
+    Object(Vec<(String, Self)>),
+}
+
+impl<'a> Json<'a> {
+    pub(crate) fn parse(input: &'a str) -> Option<Self> {
+        let mut p = Parser {
+            input,
+            pos: 0,
+            depth: 0,
+        };
+        let value = p.value()?;
+        p.whitespace();
+        match p.pos == input.len() {
+            true => Some(value),
+            false => None,
+        }
+    }
+
+    /// The members of an object.
+    pub(crate) fn object(&self) -> Option<&[(String, Self)]> {
+        match self {
+            Self::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+
+    /// The items of an array.
+    pub(crate) fn array(&self) -> Option<&[Self]> {
+        match self {
+            Self::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn string(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Append this value to `out`, as JSON text without whitespace.
+    ///
+    /// Numbers are written as they were parsed.
+    pub(crate) fn encode(&self, out: &mut String) {
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(true) => out.push_str("true"),
+            Self::Bool(false) => out.push_str("false"),
+            Self::Number(n) => out.push_str(n),
+            Self::String(s) => put_string(out, s),
+            Self::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    item.encode(out);
+                }
+                out.push(']');
+            }
+            Self::Object(members) => {
+                out.push('{');
+                for (i, (name, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    put_string(out, name);
+                    out.push(':');
+                    value.encode(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    /// A 32-bit unsigned integer, as a number or a string.
+    ///
+    /// proto3's JSON mapping allows either.
+    pub(crate) fn uint32(&self) -> Option<u32> {
+        let text = match self {
+            Self::Number(n) => n,
+            Self::String(s) => s.as_str(),
+            _ => return None,
+        };
+        // nb. `parse` accepts a leading `+`
+        match text.bytes().all(|b| b.is_ascii_digit()) {
+            true => text.parse().ok(),
+            false => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn value(&mut self) -> Option<Json<'a>> {
+        self.whitespace();
+        match self.peek() {
+            Some(b'{') => self.nested(Self::object),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'"') => Some(Json::String(self.string()?)),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b't') => self.literal("true", Json::Bool(true)),
+            Some(b'f') => self.literal("false", Json::Bool(false)),
+            Some(b'n') => self.literal("null", Json::Null),
+            _ => None,
+        }
+    }
+
+    fn nested(&mut self, f: impl FnOnce(&mut Self) -> Option<Json<'a>>) -> Option<Json<'a>> {
+        if self.depth == MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let value = f(self)?;
+        self.depth -= 1;
+        Some(value)
+    }
+
+    fn object(&mut self) -> Option<Json<'a>> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        self.whitespace();
+        if self.eat(b'}') {
+            return Some(Json::Object(members));
+        }
+
+        loop {
+            self.whitespace();
+            let name = self.string()?;
+            self.whitespace();
+            self.expect(b':')?;
+            members.push((name, self.value()?));
+            self.whitespace();
+            if self.eat(b'}') {
+                return Some(Json::Object(members));
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    fn array(&mut self) -> Option<Json<'a>> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.whitespace();
+        if self.eat(b']') {
+            return Some(Json::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            self.whitespace();
+            if self.eat(b']') {
+                return Some(Json::Array(items));
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+        loop {
+            let rest = &self.input[self.pos..];
+            let c = rest.chars().next()?;
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return Some(out),
+                '\\' => out.push(self.escape()?),
+                '\u{0}'..='\u{1f}' => return None,
+                c => out.push(c),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Option<char> {
+        let c = self.next()?;
+        Some(match c {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{8}',
+            b'f' => '\u{c}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let high = self.hex4()?;
+                let code = match high {
+                    0xd800..=0xdbff => {
+                        self.expect(b'\\')?;
+                        self.expect(b'u')?;
+                        let low = self.hex4()?;
+                        if !(0xdc00..=0xdfff).contains(&low) {
+                            return None;
+                        }
+                        0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+                    }
+                    0xdc00..=0xdfff => return None,
+                    _ => high,
+                };
+                char::from_u32(code)?
+            }
+            _ => return None,
+        })
+    }
+
+    fn hex4(&mut self) -> Option<u32> {
+        let digits = self.input.get(self.pos..self.pos + 4)?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    fn number(&mut self) -> Option<Json<'a>> {
+        let start = self.pos;
+        self.eat(b'-');
+        match self.next() {
+            Some(b'0') => {}
+            Some(b'1'..=b'9') => self.digits(),
+            _ => return None,
+        }
+        if self.eat(b'.') {
+            self.some_digits()?;
+        }
+        if self.eat(b'e') || self.eat(b'E') {
+            if !self.eat(b'+') {
+                self.eat(b'-');
+            }
+            self.some_digits()?;
+        }
+        Some(Json::Number(&self.input[start..self.pos]))
+    }
+
+    fn some_digits(&mut self) -> Option<()> {
+        match self.peek() {
+            Some(b'0'..=b'9') => {
+                self.digits();
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn literal(&mut self, text: &str, value: Json<'a>) -> Option<Json<'a>> {
+        match self.input[self.pos..].starts_with(text) {
+            true => {
+                self.pos += text.len();
+                Some(value)
+            }
+            false => None,
+        }
+    }
+
+    fn whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Option<()> {
+        match self.eat(b) {
+            true => Some(()),
+            false => None,
+        }
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        let found = self.peek() == Some(b);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let b = self.peek()?;
+        self.pos += 1;
+        Some(b)
+    }
+}
+
+/// Append `s` to `out` as a JSON string.
+pub(crate) fn put_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{0}'..='\u{1f}' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+const MAX_DEPTH: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let json = Json::parse(
+            r#" {"a": [1, -2.5e+3, "x\"\u00e9\ud83d\ude00", true, false, null], "b": {}} "#,
+        )
+        .unwrap();
+        assert_eq!(
+            json,
+            Json::Object(vec![
+                (
+                    "a".to_string(),
+                    Json::Array(vec![
+                        Json::Number("1"),
+                        Json::Number("-2.5e+3"),
+                        Json::String("x\"é😀".to_string()),
+                        Json::Bool(true),
+                        Json::Bool(false),
+                        Json::Null,
+                    ])
+                ),
+                ("b".to_string(), Json::Object(vec![])),
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_json() {
+        for input in [
+            "",
+            "{",
+            "[1,]",
+            "{\"a\" 1}",
+            "01",
+            "1.",
+            "\"\\ud800\"",
+            "\"\\x\"",
+            "\"\n\"",
+            "tru",
+            "[] []",
+            &"[".repeat(MAX_DEPTH + 1),
+        ] {
+            assert!(Json::parse(input).is_none(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn encode() {
+        let input = r#" {"a": [1, -2.5e+3, "x\"\u00e9\n", true, false, null], "b": {}} "#;
+        let mut out = String::new();
+        Json::parse(input).unwrap().encode(&mut out);
+        assert_eq!(
+            out,
+            r#"{"a":[1,-2.5e+3,"x\"é\u000a",true,false,null],"b":{}}"#
+        );
+        assert_eq!(Json::parse(&out), Json::parse(input));
+    }
+
+    #[test]
+    fn uint32() {
+        assert_eq!(Json::Number("4294967295").uint32(), Some(u32::MAX));
+        assert_eq!(Json::String("42".to_string()).uint32(), Some(42));
+        assert!(Json::Number("4294967296").uint32().is_none());
+        assert!(Json::Number("-1").uint32().is_none());
+        assert!(Json::Number("1.5").uint32().is_none());
+        assert!(Json::String("+1".to_string()).uint32().is_none());
+    }
+
+    #[test]
+    fn strings() {
+        let mut out = String::new();
+        put_string(&mut out, "a\"b\\c\n");
+        assert_eq!(out, r#""a\"b\\c\u000a""#);
+    }
+}
@@ -0,0 +1,674 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! JSON Web Key (JWK) encodings.
+//!
+//! This is [RFC7517](https://datatracker.ietf.org/doc/html/rfc7517), with
+//! key types from [RFC7518](https://datatracker.ietf.org/doc/html/rfc7518#section-6)
+//! and [RFC8037](https://datatracker.ietf.org/doc/html/rfc8037#section-2).
+//!
+//! Public members are written in lexicographic order, without whitespace,
+//! so a public key's encoding is also its
+//! [RFC7638](https://datatracker.ietf.org/doc/html/rfc7638#section-3)
+//! thumbprint input.  Private members follow them.
+
+use super::base64;
+use super::curve::{
+    Curve, MAX_SCALAR_LEN, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, P256, P384, PrivateKey, PublicKey,
+    Scalar,
+};
+use super::hash::{Hash, HashOutput};
+use super::json::{self, Json};
+use crate::error::{Error, KeyFormatError};
+use crate::low::{Entry, zeroise};
+use crate::mid::{ed25519, p256, p384, x25519};
+
+/// A decoded JWK, whose `kty` (and `crv`, if any) have been checked.
+pub(crate) struct Jwk<'a> {
+    members: Vec<(String, Json<'a>)>,
+}
+
+impl<'a> Jwk<'a> {
+    /// Decode `text` as a JWK with key type `kty` and curve `crv`.
+    ///
+    /// Members other than those later asked for are ignored.
+    pub(crate) fn decode(text: &'a str, kty: &str, crv: Option<&str>) -> Result<Self, Error> {
+        let members = match Json::parse(text) {
+            Some(Json::Object(members)) => members,
+            _ => return Err(invalid()),
+        };
+
+        // member names must be unique (RFC7517 section 4)
+        for (i, (name, _)) in members.iter().enumerate() {
+            if members[..i].iter().any(|(other, _)| other == name) {
+                return Err(invalid());
+            }
+        }
+
+        let jwk = Self { members };
+        jwk.check("kty", kty)?;
+        if let Some(crv) = crv {
+            jwk.check("crv", crv)?;
+        }
+        Ok(jwk)
+    }
+
+    fn check(&self, name: &str, expected: &str) -> Result<(), Error> {
+        match self.text(name)? {
+            Some(value) if value == expected => Ok(()),
+            Some(_) => Err(KeyFormatError::MismatchedJwkKeyType.into()),
+            None => Err(invalid()),
+        }
+    }
+
+    fn text(&self, name: &str) -> Result<Option<&str>, Error> {
+        match self.members.iter().find(|(n, _)| n == name) {
+            Some((_, value)) => value.string().map(Some).ok_or_else(invalid),
+            None => Ok(None),
+        }
+    }
+
+    /// Whether member `name` is present.
+    pub(crate) fn has(&self, name: &str) -> bool {
+        self.members.iter().any(|(n, _)| n == name)
+    }
+
+    /// The value of the base64url-encoded member `name`, which must be present.
+    pub(crate) fn bytes(&self, name: &str) -> Result<Vec<u8>, Error> {
+        self.text(name)?
+            .and_then(base64::decode_url)
+            .ok_or_else(invalid)
+    }
+
+    /// The value of the base64url-encoded member `name`, which must be `len` bytes.
+    pub(crate) fn fixed(&self, name: &str, len: usize) -> Result<Vec<u8>, Error> {
+        let mut value = self.bytes(name)?;
+        if value.len() != len {
+            zeroise(&mut value);
+            return Err(invalid());
+        }
+        Ok(value)
+    }
+
+    /// The value of the `Base64urlUInt` member `name`.
+    ///
+    /// This must be the shortest encoding of a non-zero integer
+    /// (RFC7518 section 2).
+    pub(crate) fn uint(&self, name: &str) -> Result<Vec<u8>, Error> {
+        let mut value = self.bytes(name)?;
+        if value.first().copied().unwrap_or(0) == 0 {
+            zeroise(&mut value);
+            return Err(invalid());
+        }
+        Ok(value)
+    }
+}
+
+/// A JWK member's value, for [`encode()`].
+pub(crate) enum Member<'a> {
+    Text(&'a str),
+    Bytes(&'a [u8]),
+    /// A `Base64urlUInt`: leading zero bytes are removed.
+    Uint(&'a [u8]),
+}
+
+/// Encode a JWK with `members`, in the order given.
+pub(crate) fn encode(members: &[(&str, Member<'_>)]) -> String {
+    let mut out = String::from("{");
+    for (i, (name, value)) in members.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        json::put_string(&mut out, name);
+        out.push(':');
+        match value {
+            Member::Text(text) => json::put_string(&mut out, text),
+            Member::Bytes(bytes) => json::put_string(&mut out, &base64::encode_url(bytes)),
+            Member::Uint(bytes) => {
+                let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+                json::put_string(&mut out, &base64::encode_url(&bytes[start..]))
+            }
+        }
+    }
+    out.push('}');
+    out
+}
+
+/// The RFC7638 thumbprint of a public key, given its encoding from [`encode()`].
+pub(crate) fn thumbprint<H: Hash>(public_jwk: &str) -> HashOutput {
+    H::hash(public_jwk.as_bytes())
+}
+
+/// Decodes an `OKP` public key on curve `crv`, returning `x`.
+fn decode_okp_public(text: &str, crv: &str, len: usize) -> Result<Vec<u8>, Error> {
+    Jwk::decode(text, "OKP", Some(crv))?.fixed("x", len)
+}
+
+/// Decodes an `OKP` private key on curve `crv`, returning `d` and `x`.
+fn decode_okp_private(text: &str, crv: &str, len: usize) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let jwk = Jwk::decode(text, "OKP", Some(crv))?;
+    Ok((jwk.fixed("d", len)?, jwk.fixed("x", len)?))
+}
+
+fn encode_okp(crv: &str, public_key: &[u8], private_key: Option<&[u8]>) -> String {
+    let mut members = vec![
+        ("crv", Member::Text(crv)),
+        ("kty", Member::Text("OKP")),
+        ("x", Member::Bytes(public_key)),
+    ];
+    if let Some(d) = private_key {
+        members.push(("d", Member::Bytes(d)));
+    }
+    encode(&members)
+}
+
+/// Decodes an `EC` public key on curve `C`.
+pub(crate) fn decode_ec_public<C: Curve>(text: &str) -> Result<C::PublicKey, Error> {
+    let jwk = Jwk::decode(text, "EC", Some(C::jwk_crv()))?;
+    let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    let point = ec_point::<C>(&jwk, &mut point)?;
+    C::PublicKey::from_x962_uncompressed(point)
+}
+
+/// Decodes an `EC` private key on curve `C`.
+///
+/// The public key (which is required) must match.
+pub(crate) fn decode_ec_private<C: Curve>(text: &str) -> Result<C::PrivateKey, Error> {
+    let jwk = Jwk::decode(text, "EC", Some(C::jwk_crv()))?;
+    let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    let point = ec_point::<C>(&jwk, &mut point)?;
+
+    let mut d = jwk.fixed("d", C::Scalar::LEN_BYTES)?;
+    let private_key = C::PrivateKey::from_bytes(&d);
+    zeroise(&mut d);
+    let private_key = private_key?;
+
+    let mut public_key = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    if private_key.public_key_encode_uncompressed(&mut public_key)? != point {
+        return Err(KeyFormatError::MismatchedJwkPublicKey.into());
+    }
+    Ok(private_key)
+}
+
+/// Reassembles the uncompressed point from the `x` and `y` members.
+fn ec_point<'a, C: Curve>(jwk: &Jwk<'_>, point: &'a mut [u8]) -> Result<&'a [u8], Error> {
+    let len = (C::PublicKey::LEN_BYTES - 1) / 2;
+    let point = &mut point[..C::PublicKey::LEN_BYTES];
+    point[0] = 0x04;
+    point[1..1 + len].copy_from_slice(&jwk.fixed("x", len)?);
+    point[1 + len..].copy_from_slice(&jwk.fixed("y", len)?);
+    Ok(point)
+}
+
+/// Encodes an `EC` public key on curve `C`.
+pub(crate) fn encode_ec_public<C: Curve>(public_key: &C::PublicKey) -> Result<String, Error> {
+    let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    let point = public_key.encode_uncompressed(&mut point)?;
+    Ok(encode_ec::<C>(point, None))
+}
+
+/// Encodes an `EC` private key on curve `C`, including its public key.
+pub(crate) fn encode_ec_private<C: Curve>(private_key: &C::PrivateKey) -> Result<String, Error> {
+    let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    let point = private_key.public_key_encode_uncompressed(&mut point)?;
+    let mut d = [0u8; MAX_SCALAR_LEN];
+    let jwk = private_key
+        .encode(&mut d)
+        .map(|d| encode_ec::<C>(point, Some(d)));
+    zeroise(&mut d);
+    jwk
+}
+
+/// The RFC7638 thumbprint of the public key of `private_key`, on curve `C`.
+pub(crate) fn ec_private_thumbprint<C: Curve, H: Hash>(
+    private_key: &C::PrivateKey,
+) -> Result<HashOutput, Error> {
+    let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    let point = private_key.public_key_encode_uncompressed(&mut point)?;
+    Ok(thumbprint::<H>(&encode_ec::<C>(point, None)))
+}
+
+fn encode_ec<C: Curve>(point: &[u8], private_key: Option<&[u8]>) -> String {
+    let (x, y) = point[1..].split_at((point.len() - 1) / 2);
+    let mut members = vec![
+        ("crv", Member::Text(C::jwk_crv())),
+        ("kty", Member::Text("EC")),
+        ("x", Member::Bytes(x)),
+        ("y", Member::Bytes(y)),
+    ];
+    if let Some(d) = private_key {
+        members.push(("d", Member::Bytes(d)));
+    }
+    encode(&members)
+}
+
+fn invalid() -> Error {
+    KeyFormatError::InvalidJwk.into()
+}
+
+impl ed25519::VerifyingKey {
+    /// Decodes an Ed25519 public key from JWK format.
+    ///
+    /// This format is defined in
+    /// [RFC8037](https://datatracker.ietf.org/doc/html/rfc8037#section-2):
+    /// `kty` must be `OKP` and `crv` must be `Ed25519`.  Other members
+    /// (including any private key) are ignored.
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        Self::from_bytes(&decode_okp_public(jwk, "Ed25519", 32)?)
+    }
+
+    /// Encodes an Ed25519 public key to JWK format.
+    pub fn to_jwk(&self) -> Result<String, Error> {
+        Ok(encode_okp("Ed25519", self.as_bytes(), None))
+    }
+
+    /// Computes the RFC7638 JWK thumbprint of this public key, using `H`.
+    pub fn jwk_thumbprint<H: Hash>(&self) -> Result<HashOutput, Error> {
+        Ok(thumbprint::<H>(&self.to_jwk()?))
+    }
+}
+
+impl ed25519::SigningKey {
+    /// Decodes an Ed25519 private key from JWK format.
+    ///
+    /// This format is defined in
+    /// [RFC8037](https://datatracker.ietf.org/doc/html/rfc8037#section-2):
+    /// `kty` must be `OKP` and `crv` must be `Ed25519`.  `d` is the
+    /// private key seed, and the public key `x` must match it.
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let (mut seed, public_key) = decode_okp_private(jwk, "Ed25519", 32)?;
+        let mut seed_array = [0u8; 32];
+        seed_array.copy_from_slice(&seed);
+        let key = Self::from_seed(&seed_array);
+        zeroise(&mut seed);
+        zeroise(&mut seed_array);
+
+        match key.verifying_key().as_bytes() == &public_key[..] {
+            true => Ok(key),
+            false => Err(KeyFormatError::MismatchedJwkPublicKey.into()),
+        }
+    }
+
+    /// Encodes an Ed25519 private key to JWK format.
+    ///
+    /// The public key is included.
+    pub fn to_jwk(&self) -> Result<String, Error> {
+        let _entry = Entry::new_secret();
+        let mut seed = self.seed();
+        let jwk = encode_okp("Ed25519", self.verifying_key().as_bytes(), Some(&seed));
+        zeroise(&mut seed);
+        Ok(jwk)
+    }
+
+    /// Computes the RFC7638 JWK thumbprint of this key's public key, using `H`.
+    pub fn jwk_thumbprint<H: Hash>(&self) -> Result<HashOutput, Error> {
+        self.verifying_key().jwk_thumbprint::<H>()
+    }
+}
+
+impl x25519::PublicKey {
+    /// Decodes an X25519 public key from JWK format.
+    ///
+    /// This format is defined in
+    /// [RFC8037](https://datatracker.ietf.org/doc/html/rfc8037#section-2):
+    /// `kty` must be `OKP` and `crv` must be `X25519`.  Other members
+    /// (including any private key) are ignored.
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        Self::try_from_slice(&decode_okp_public(jwk, "X25519", 32)?)
+    }
+
+    /// Encodes an X25519 public key to JWK format.
+    pub fn to_jwk(&self) -> Result<String, Error> {
+        Ok(encode_okp("X25519", &self.as_bytes(), None))
+    }
+
+    /// Computes the RFC7638 JWK thumbprint of this public key, using `H`.
+    pub fn jwk_thumbprint<H: Hash>(&self) -> Result<HashOutput, Error> {
+        Ok(thumbprint::<H>(&self.to_jwk()?))
+    }
+}
+
+impl x25519::StaticPrivateKey {
+    /// Decodes an X25519 private key from JWK format.
+    ///
+    /// This format is defined in
+    /// [RFC8037](https://datatracker.ietf.org/doc/html/rfc8037#section-2):
+    /// `kty` must be `OKP` and `crv` must be `X25519`.  The public key `x`
+    /// must match the private key `d`.
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let (mut private_key, public_key) = decode_okp_private(jwk, "X25519", 32)?;
+        let key = Self::try_from_slice(&private_key);
+        zeroise(&mut private_key);
+        let key = key?;
+
+        match key.public_key().as_bytes()[..] == public_key[..] {
+            true => Ok(key),
+            false => Err(KeyFormatError::MismatchedJwkPublicKey.into()),
+        }
+    }
+
+    /// Encodes an X25519 private key to JWK format.
+    ///
+    /// The public key is included.
+    pub fn to_jwk(&self) -> Result<String, Error> {
+        let _entry = Entry::new_secret();
+        let mut private_key = self.as_bytes();
+        let jwk = encode_okp("X25519", &self.public_key().as_bytes(), Some(&private_key));
+        zeroise(&mut private_key);
+        Ok(jwk)
+    }
+
+    /// Computes the RFC7638 JWK thumbprint of this key's public key, using `H`.
+    pub fn jwk_thumbprint<H: Hash>(&self) -> Result<HashOutput, Error> {
+        self.public_key().jwk_thumbprint::<H>()
+    }
+}
+
+macro_rules! ec_jwk {
+    ($module:ident, $curve:ident, $name:literal) => {
+        impl $module::PublicKey {
+            #[doc = concat!("Decodes a ", $name, " public key from JWK format.")]
+            ///
+            /// This format is defined in
+            /// [RFC7518](https://datatracker.ietf.org/doc/html/rfc7518#section-6.2):
+            #[doc = concat!("`kty` must be `EC` and `crv` must be `", $name, "`.  Other members")]
+            /// (including any private key) are ignored.
+            pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+                let _entry = Entry::new_public();
+                decode_ec_public::<$curve>(jwk)
+            }
+
+            #[doc = concat!("Encodes a ", $name, " public key to JWK format.")]
+            pub fn to_jwk(&self) -> Result<String, Error> {
+                encode_ec_public::<$curve>(self)
+            }
+
+            /// Computes the RFC7638 JWK thumbprint of this public key, using `H`.
+            pub fn jwk_thumbprint<H: Hash>(&self) -> Result<HashOutput, Error> {
+                Ok(thumbprint::<H>(&self.to_jwk()?))
+            }
+        }
+
+        impl $module::StaticPrivateKey {
+            #[doc = concat!("Decodes a ", $name, " private key from JWK format.")]
+            ///
+            /// This format is defined in
+            /// [RFC7518](https://datatracker.ietf.org/doc/html/rfc7518#section-6.2):
+            #[doc = concat!("`kty` must be `EC` and `crv` must be `", $name, "`.  The public")]
+            /// key `x` and `y` must match the private key `d`.
+            pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+                let _entry = Entry::new_secret();
+                decode_ec_private::<$curve>(jwk)
+            }
+
+            #[doc = concat!("Encodes a ", $name, " private key to JWK format.")]
+            ///
+            /// The public key is included.
+            pub fn to_jwk(&self) -> Result<String, Error> {
+                let _entry = Entry::new_secret();
+                encode_ec_private::<$curve>(self)
+            }
+
+            /// Computes the RFC7638 JWK thumbprint of this key's public key, using `H`.
+            pub fn jwk_thumbprint<H: Hash>(&self) -> Result<HashOutput, Error> {
+                ec_private_thumbprint::<$curve, H>(self)
+            }
+        }
+    };
+}
+
+ec_jwk!(p256, P256, "P-256");
+ec_jwk!(p384, P384, "P-384");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::Sha256;
+
+    fn thumbprint_text(thumbprint: Result<HashOutput, Error>) -> String {
+        base64::encode_url(thumbprint.unwrap().as_ref())
+    }
+
+    #[test]
+    fn rfc8037_ed25519() {
+        // from RFC8037 appendix A.1 and A.3
+        let jwk = r#"{"kty":"OKP","crv":"Ed25519",
+                      "d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+                      "x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+        let key = ed25519::SigningKey::from_jwk(jwk).unwrap();
+        assert_eq!(
+            key.to_jwk().unwrap(),
+            r#"{"crv":"Ed25519","kty":"OKP","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo","d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"}"#
+        );
+        assert_eq!(
+            thumbprint_text(key.jwk_thumbprint::<Sha256>()),
+            "kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k"
+        );
+
+        let public_key = ed25519::VerifyingKey::from_jwk(jwk).unwrap();
+        assert_eq!(public_key.as_bytes(), key.verifying_key().as_bytes());
+        assert_eq!(
+            public_key.to_jwk().unwrap(),
+            r#"{"crv":"Ed25519","kty":"OKP","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#
+        );
+        assert_eq!(
+            thumbprint_text(public_key.jwk_thumbprint::<Sha256>()),
+            "kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k"
+        );
+    }
+
+    #[test]
+    fn rfc8037_x25519() {
+        // from RFC8037 appendix A.6
+        let jwk = r#"{"kty":"OKP","crv":"X25519",
+                      "d":"dwdtCnMYpX08FsFyUbJmRd9ML4frwJkqsXf7pR25LCo",
+                      "x":"hSDwCYkwp1R0i33ctD73Wg2_Og0mOBr066SpjqqbTmo"}"#;
+        let key = x25519::StaticPrivateKey::from_jwk(jwk).unwrap();
+        let peer = x25519::PublicKey::from_jwk(
+            r#"{"kty":"OKP","crv":"X25519","x":"3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            base64::encode_url(&key.diffie_hellman(&peer).unwrap().0),
+            "Sl2dW6TOLeFyjjv0gDUPJeB-IclH0Z4zdvCbPB4WF0I"
+        );
+        assert_eq!(
+            x25519::PublicKey::from_jwk(&peer.to_jwk().unwrap())
+                .unwrap()
+                .as_bytes(),
+            peer.as_bytes()
+        );
+    }
+
+    #[test]
+    fn okp_round_trip() {
+        // these were produced by joserfc from the PKCS#8 files
+        let mut buf = [0u8; 128];
+        let jwk = include_str!("jwk/ed25519.jwk");
+        let key = ed25519::SigningKey::from_jwk(jwk).unwrap();
+        assert_eq!(
+            key.to_pkcs8_der(&mut buf).unwrap(),
+            include_bytes!("pkcs8/ed25519.pkcs8.der")
+        );
+        assert_eq!(
+            ed25519::SigningKey::from_jwk(&key.to_jwk().unwrap())
+                .unwrap()
+                .seed(),
+            key.seed()
+        );
+        assert_eq!(
+            thumbprint_text(key.jwk_thumbprint::<Sha256>()),
+            "law2R0-ln5yFqZt8tgGiZ1GAiLWX6biGWnXKK1NhXXg"
+        );
+
+        let jwk = include_str!("jwk/x25519.jwk");
+        let key = x25519::StaticPrivateKey::from_jwk(jwk).unwrap();
+        assert_eq!(
+            key.to_pkcs8_der(&mut buf).unwrap(),
+            include_bytes!("pkcs8/x25519.pkcs8.der")
+        );
+        assert_eq!(
+            x25519::StaticPrivateKey::from_jwk(&key.to_jwk().unwrap())
+                .unwrap()
+                .as_bytes(),
+            key.as_bytes()
+        );
+        assert_eq!(
+            thumbprint_text(key.jwk_thumbprint::<Sha256>()),
+            "ztfhrTiHIxEym6LWkaLgcVAkVGJVIYkxyTlqkJNxAYg"
+        );
+        assert_eq!(
+            thumbprint_text(
+                x25519::PublicKey::from_jwk(jwk)
+                    .unwrap()
+                    .jwk_thumbprint::<Sha256>()
+            ),
+            "ztfhrTiHIxEym6LWkaLgcVAkVGJVIYkxyTlqkJNxAYg"
+        );
+    }
+
+    #[test]
+    fn ec_round_trip() {
+        // these were produced by joserfc from the SEC.1 files
+        let mut buf = [0u8; 256];
+        let jwk = include_str!("jwk/secp256r1.jwk");
+        let key = p256::StaticPrivateKey::from_jwk(jwk).unwrap();
+        assert_eq!(
+            key.to_sec1_der(&mut buf).unwrap(),
+            include_bytes!("ecdsa/secp256r1.der")
+        );
+        assert_eq!(
+            p256::StaticPrivateKey::from_jwk(&key.to_jwk().unwrap())
+                .unwrap()
+                .as_bytes(),
+            key.as_bytes()
+        );
+        assert_eq!(
+            thumbprint_text(key.jwk_thumbprint::<Sha256>()),
+            "06VycLOm-qLEpPn2DoIoC-vki2_Ife4SVHN5HIuloHA"
+        );
+        let public_key = p256::PublicKey::from_jwk(jwk).unwrap();
+        assert_eq!(
+            public_key.as_bytes_uncompressed(),
+            key.public_key_uncompressed()
+        );
+        assert_eq!(
+            thumbprint_text(public_key.jwk_thumbprint::<Sha256>()),
+            "06VycLOm-qLEpPn2DoIoC-vki2_Ife4SVHN5HIuloHA"
+        );
+
+        let jwk = include_str!("jwk/secp384r1.jwk");
+        let key = p384::StaticPrivateKey::from_jwk(jwk).unwrap();
+        assert_eq!(
+            key.to_sec1_der(&mut buf).unwrap(),
+            include_bytes!("ecdsa/secp384r1.der")
+        );
+        assert_eq!(
+            thumbprint_text(key.jwk_thumbprint::<Sha256>()),
+            "q58s0KmojKybTuqmIEI7QdXvKbegMbnObdJhelKblws"
+        );
+        let public_key = p384::PublicKey::from_jwk(&key.to_jwk().unwrap()).unwrap();
+        assert_eq!(
+            public_key.as_bytes_uncompressed(),
+            key.public_key_uncompressed()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let ed25519 = include_str!("jwk/ed25519.jwk");
+        for (jwk, err) in [
+            ("[]".to_string(), KeyFormatError::InvalidJwk),
+            ("{".to_string(), KeyFormatError::InvalidJwk),
+            (
+                ed25519.replace("\"kty\"", "\"ktx\""),
+                KeyFormatError::InvalidJwk,
+            ),
+            (
+                ed25519.replace("\"OKP\"", "\"EC\""),
+                KeyFormatError::MismatchedJwkKeyType,
+            ),
+            (ed25519.replace("\"OKP\"", "1"), KeyFormatError::InvalidJwk),
+            (
+                ed25519.replace("\"Ed25519\"", "\"Ed448\""),
+                KeyFormatError::MismatchedJwkKeyType,
+            ),
+            (
+                ed25519.replace("\"d\"", "\"e\""),
+                KeyFormatError::InvalidJwk,
+            ),
+            (
+                ed25519.replace("\"x\"", "\"d\""),
+                KeyFormatError::InvalidJwk,
+            ),
+            // padding and the standard alphabet are not allowed
+            (
+                ed25519.replace(
+                    "\"d\": \"y9OVF-jOnJhCLjbeI0AhpouSeIVvzdV3_rkeP2rH4Y0\"",
+                    "\"d\": \"y9OVF-jOnJhCLjbeI0AhpouSeIVvzdV3_rkeP2rH4Y0=\"",
+                ),
+                KeyFormatError::InvalidJwk,
+            ),
+            (
+                ed25519.replace(
+                    "y9OVF-jOnJhCLjbeI0AhpouSeIVvzdV3_rkeP2rH4Y0",
+                    "y9OVF+jOnJhCLjbeI0AhpouSeIVvzdV3/rkeP2rH4Y0",
+                ),
+                KeyFormatError::InvalidJwk,
+            ),
+            // 31 bytes
+            (
+                ed25519.replace(
+                    "y9OVF-jOnJhCLjbeI0AhpouSeIVvzdV3_rkeP2rH4Y0",
+                    "y9OVF-jOnJhCLjbeI0AhpouSeIVvzdV3_rkeP2rH4Q",
+                ),
+                KeyFormatError::InvalidJwk,
+            ),
+            // a different private key
+            (
+                ed25519.replace(
+                    "y9OVF-jOnJhCLjbeI0AhpouSeIVvzdV3_rkeP2rH4Y0",
+                    "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+                ),
+                KeyFormatError::MismatchedJwkPublicKey,
+            ),
+            (
+                ed25519.replace("\"kty\": \"OKP\"", "\"kty\": \"OKP\", \"kty\": \"OKP\""),
+                KeyFormatError::InvalidJwk,
+            ),
+        ] {
+            assert_eq!(
+                ed25519::SigningKey::from_jwk(&jwk).err(),
+                Some(Error::KeyFormatError(err)),
+                "{jwk}"
+            );
+        }
+
+        assert_eq!(
+            x25519::PublicKey::from_jwk(ed25519).err(),
+            Some(Error::KeyFormatError(KeyFormatError::MismatchedJwkKeyType))
+        );
+        assert_eq!(
+            p384::PublicKey::from_jwk(include_str!("jwk/secp256r1.jwk")).err(),
+            Some(Error::KeyFormatError(KeyFormatError::MismatchedJwkKeyType))
+        );
+
+        let p256 = include_str!("jwk/secp256r1.jwk");
+        assert_eq!(
+            p256::PublicKey::from_jwk(&p256.replace(
+                "IsvE8xTnPW7Z3Bxl28_gyfsbxfYmNztOcuSppwk6xVg",
+                "IsvE8xTnPW7Z3Bxl28_gyfsbxfYmNztOcuSppwk6xVk"
+            ))
+            .err(),
+            Some(Error::NotOnCurve)
+        );
+        assert_eq!(
+            p256::StaticPrivateKey::from_jwk(&p256.replace("\"y\"", "\"z\"")).err(),
+            Some(Error::KeyFormatError(KeyFormatError::InvalidJwk))
+        );
+    }
+}
@@ -0,0 +1,344 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::curve::{P256, P384};
+use crate::Error;
+use crate::mid::mlkem::{self, ml_kem_512, ml_kem_768, ml_kem_1024};
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::{p256, p384, x25519, x25519mlkem768, xwing};
+
+/// A generic trait over supported key encapsulation mechanisms.
+///
+/// This exists so protocol code (like HPKE) may be generic over the KEM used.
+///
+/// Diffie-Hellman functions are also KEMs: the ciphertext is a fresh
+/// ephemeral public key, and the shared secret is the raw Diffie-Hellman
+/// output.  This trait does not hash that output, so protocols must
+/// do so themselves.
+pub trait Kem {
+    /// Decapsulation (private) key type.
+    type DecapsulationKey;
+
+    /// Encapsulation (public) key type.
+    type EncapsulationKey;
+
+    /// The encoding of an encapsulation key.
+    type EncodedEncapsulationKey: AsRef<[u8]>;
+
+    /// Ciphertext type.
+    type Ciphertext: AsRef<[u8]>;
+
+    /// Shared secret type.
+    type SharedSecret: AsRef<[u8]>;
+
+    /// Length of an encoded encapsulation key.
+    const ENCAPSULATION_KEY_LEN: usize;
+
+    /// Length of a ciphertext.
+    const CIPHERTEXT_LEN: usize;
+
+    /// Length of a shared secret.
+    const SHARED_SECRET_LEN: usize;
+
+    /// Generate a new decapsulation key using the system random number generator.
+    fn generate() -> Result<Self::DecapsulationKey, Error> {
+        Self::generate_with_rng(&mut SystemRandom)
+    }
+
+    /// Generate a new decapsulation key using `rng`.
+    fn generate_with_rng(rng: &mut dyn RandomSource) -> Result<Self::DecapsulationKey, Error>;
+
+    /// Return the encapsulation key matching `key`.
+    fn encapsulation_key(key: &Self::DecapsulationKey) -> Self::EncapsulationKey;
+
+    /// Decode an encapsulation key from `bytes`.
+    ///
+    /// This validates the key.
+    fn decode_encapsulation_key(bytes: &[u8]) -> Result<Self::EncapsulationKey, Error>;
+
+    /// Encode `key`.
+    fn encode_encapsulation_key(key: &Self::EncapsulationKey) -> Self::EncodedEncapsulationKey;
+
+    /// Encapsulate a fresh shared secret to `key`, using the system random
+    /// number generator.
+    ///
+    /// Returns the ciphertext, which should be sent to the
+    /// holder of the decapsulation key, and the shared secret.
+    fn encapsulate(
+        key: &Self::EncapsulationKey,
+    ) -> Result<(Self::Ciphertext, Self::SharedSecret), Error> {
+        Self::encapsulate_with_rng(key, &mut SystemRandom)
+    }
+
+    /// Encapsulate a fresh shared secret to `key`, using `rng`.
+    ///
+    /// Otherwise, as [`Kem::encapsulate()`].
+    fn encapsulate_with_rng(
+        key: &Self::EncapsulationKey,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self::Ciphertext, Self::SharedSecret), Error>;
+
+    /// Decapsulate `ciphertext` using `key`, returning the shared secret.
+    fn decapsulate(
+        key: &Self::DecapsulationKey,
+        ciphertext: &[u8],
+    ) -> Result<Self::SharedSecret, Error>;
+}
+
+/// X25519, as a KEM.
+///
+/// See [RFC7748](https://datatracker.ietf.org/doc/html/rfc7748).
+pub struct X25519;
+
+impl Kem for X25519 {
+    type DecapsulationKey = x25519::StaticPrivateKey;
+    type EncapsulationKey = x25519::PublicKey;
+    type EncodedEncapsulationKey = [u8; 32];
+    type Ciphertext = [u8; 32];
+    type SharedSecret = x25519::SharedSecret;
+
+    const ENCAPSULATION_KEY_LEN: usize = 32;
+    const CIPHERTEXT_LEN: usize = 32;
+    const SHARED_SECRET_LEN: usize = 32;
+
+    fn generate_with_rng(rng: &mut dyn RandomSource) -> Result<Self::DecapsulationKey, Error> {
+        x25519::StaticPrivateKey::generate(rng)
+    }
+
+    fn encapsulation_key(key: &Self::DecapsulationKey) -> Self::EncapsulationKey {
+        key.public_key()
+    }
+
+    fn decode_encapsulation_key(bytes: &[u8]) -> Result<Self::EncapsulationKey, Error> {
+        x25519::PublicKey::try_from_slice(bytes)
+    }
+
+    fn encode_encapsulation_key(key: &Self::EncapsulationKey) -> Self::EncodedEncapsulationKey {
+        key.as_bytes()
+    }
+
+    fn encapsulate_with_rng(
+        key: &Self::EncapsulationKey,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self::Ciphertext, Self::SharedSecret), Error> {
+        let ephemeral = x25519::PrivateKey::generate(rng)?;
+        let ciphertext = ephemeral.public_key().as_bytes();
+        Ok((ciphertext, ephemeral.diffie_hellman(key)?))
+    }
+
+    fn decapsulate(
+        key: &Self::DecapsulationKey,
+        ciphertext: &[u8],
+    ) -> Result<Self::SharedSecret, Error> {
+        key.diffie_hellman(&x25519::PublicKey::try_from_slice(ciphertext)?)
+    }
+}
+
+macro_rules! ecdh_kem {
+    ($curve:ident, $module:ident, $point_len:literal, $secret_len:literal) => {
+        /// Elliptic curve Diffie-Hellman as a KEM.
+        ///
+        /// Encapsulation keys and ciphertexts use the X9.62 uncompressed encoding.
+        impl Kem for $curve {
+            type DecapsulationKey = $module::StaticPrivateKey;
+            type EncapsulationKey = $module::PublicKey;
+            type EncodedEncapsulationKey = [u8; $point_len];
+            type Ciphertext = [u8; $point_len];
+            type SharedSecret = $module::SharedSecret;
+
+            const ENCAPSULATION_KEY_LEN: usize = $point_len;
+            const CIPHERTEXT_LEN: usize = $point_len;
+            const SHARED_SECRET_LEN: usize = $secret_len;
+
+            fn generate_with_rng(
+                rng: &mut dyn RandomSource,
+            ) -> Result<Self::DecapsulationKey, Error> {
+                $module::StaticPrivateKey::generate(rng)
+            }
+
+            fn encapsulation_key(key: &Self::DecapsulationKey) -> Self::EncapsulationKey {
+                // the encoding of our own public key is always valid
+                $module::PublicKey::from_x962_uncompressed(&key.public_key_uncompressed()).unwrap()
+            }
+
+            fn decode_encapsulation_key(bytes: &[u8]) -> Result<Self::EncapsulationKey, Error> {
+                $module::PublicKey::from_x962_uncompressed(bytes)
+            }
+
+            fn encode_encapsulation_key(
+                key: &Self::EncapsulationKey,
+            ) -> Self::EncodedEncapsulationKey {
+                key.as_bytes_uncompressed()
+            }
+
+            fn encapsulate_with_rng(
+                key: &Self::EncapsulationKey,
+                rng: &mut dyn RandomSource,
+            ) -> Result<(Self::Ciphertext, Self::SharedSecret), Error> {
+                let ephemeral = $module::PrivateKey::generate(rng)?;
+                let ciphertext = ephemeral.public_key_uncompressed();
+                Ok((ciphertext, ephemeral.diffie_hellman(key)?))
+            }
+
+            fn decapsulate(
+                key: &Self::DecapsulationKey,
+                ciphertext: &[u8],
+            ) -> Result<Self::SharedSecret, Error> {
+                key.diffie_hellman(&$module::PublicKey::from_x962_uncompressed(ciphertext)?)
+            }
+        }
+    };
+}
+
+ecdh_kem!(P256, p256, 65, 32);
+ecdh_kem!(P384, p384, 97, 48);
+
+macro_rules! kem {
+    ($name:ident, $module:ident, $shared_secret:ty, $secret_len:expr, $doc:literal) => {
+        #[doc = $doc]
+        pub struct $name;
+
+        impl Kem for $name {
+            type DecapsulationKey = $module::DecapsulationKey;
+            type EncapsulationKey = $module::EncapsulationKey;
+            type EncodedEncapsulationKey = [u8; $module::ENCAPSULATION_KEY_LEN];
+            type Ciphertext = [u8; $module::CIPHERTEXT_LEN];
+            type SharedSecret = $shared_secret;
+
+            const ENCAPSULATION_KEY_LEN: usize = $module::ENCAPSULATION_KEY_LEN;
+            const CIPHERTEXT_LEN: usize = $module::CIPHERTEXT_LEN;
+            const SHARED_SECRET_LEN: usize = $secret_len;
+
+            fn generate_with_rng(
+                rng: &mut dyn RandomSource,
+            ) -> Result<Self::DecapsulationKey, Error> {
+                $module::DecapsulationKey::generate(rng)
+            }
+
+            fn encapsulation_key(key: &Self::DecapsulationKey) -> Self::EncapsulationKey {
+                key.encapsulation_key()
+            }
+
+            fn decode_encapsulation_key(bytes: &[u8]) -> Result<Self::EncapsulationKey, Error> {
+                $module::EncapsulationKey::from_bytes(bytes)
+            }
+
+            fn encode_encapsulation_key(
+                key: &Self::EncapsulationKey,
+            ) -> Self::EncodedEncapsulationKey {
+                *key.as_bytes()
+            }
+
+            fn encapsulate_with_rng(
+                key: &Self::EncapsulationKey,
+                rng: &mut dyn RandomSource,
+            ) -> Result<(Self::Ciphertext, Self::SharedSecret), Error> {
+                key.encapsulate_with_rng(rng)
+            }
+
+            fn decapsulate(
+                key: &Self::DecapsulationKey,
+                ciphertext: &[u8],
+            ) -> Result<Self::SharedSecret, Error> {
+                key.decapsulate(ciphertext)
+            }
+        }
+    };
+}
+
+kem!(
+    MlKem512,
+    ml_kem_512,
+    mlkem::SharedSecret,
+    32,
+    "ML-KEM-512, as a KEM."
+);
+kem!(
+    MlKem768,
+    ml_kem_768,
+    mlkem::SharedSecret,
+    32,
+    "ML-KEM-768, as a KEM."
+);
+kem!(
+    MlKem1024,
+    ml_kem_1024,
+    mlkem::SharedSecret,
+    32,
+    "ML-KEM-1024, as a KEM."
+);
+kem!(
+    X25519MlKem768,
+    x25519mlkem768,
+    x25519mlkem768::SharedSecret,
+    x25519mlkem768::SHARED_SECRET_LEN,
+    "The X25519MLKEM768 hybrid, as a KEM."
+);
+kem!(
+    XWing,
+    xwing,
+    xwing::SharedSecret,
+    32,
+    "The X-Wing hybrid KEM."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::TestRng;
+
+    fn check<K: Kem>() {
+        let dk = K::generate().unwrap();
+        let encoded = K::encode_encapsulation_key(&K::encapsulation_key(&dk));
+        assert_eq!(encoded.as_ref().len(), K::ENCAPSULATION_KEY_LEN);
+        let ek = K::decode_encapsulation_key(encoded.as_ref()).unwrap();
+
+        let (ciphertext, sender) = K::encapsulate(&ek).unwrap();
+        assert_eq!(ciphertext.as_ref().len(), K::CIPHERTEXT_LEN);
+        assert_eq!(sender.as_ref().len(), K::SHARED_SECRET_LEN);
+
+        let receiver = K::decapsulate(&dk, ciphertext.as_ref()).unwrap();
+        assert_eq!(sender.as_ref(), receiver.as_ref());
+
+        assert!(K::decapsulate(&dk, &ciphertext.as_ref()[1..]).is_err());
+        assert!(K::decode_encapsulation_key(&encoded.as_ref()[1..]).is_err());
+
+        let encode = |dk: &K::DecapsulationKey| {
+            K::encode_encapsulation_key(&K::encapsulation_key(dk))
+                .as_ref()
+                .to_vec()
+        };
+        let a = K::generate_with_rng(&mut TestRng::new(b"kem")).unwrap();
+        let b = K::generate_with_rng(&mut TestRng::new(b"kem")).unwrap();
+        assert_eq!(encode(&a), encode(&b));
+        assert_ne!(encode(&a), encode(&dk));
+
+        let (ct_a, ss_a) = K::encapsulate_with_rng(&ek, &mut TestRng::new(b"encap")).unwrap();
+        let (ct_b, ss_b) = K::encapsulate_with_rng(&ek, &mut TestRng::new(b"encap")).unwrap();
+        assert_eq!(ct_a.as_ref(), ct_b.as_ref());
+        assert_eq!(ss_a.as_ref(), ss_b.as_ref());
+        assert_ne!(ct_a.as_ref(), ciphertext.as_ref());
+    }
+
+    #[test]
+    fn round_trips() {
+        check::<X25519>();
+        check::<P256>();
+        check::<P384>();
+        check::<MlKem512>();
+        check::<MlKem768>();
+        check::<MlKem1024>();
+        check::<X25519MlKem768>();
+        check::<XWing>();
+    }
+
+    #[test]
+    fn dh_kem_is_diffie_hellman() {
+        let dk = <X25519 as Kem>::generate().unwrap();
+        let (ciphertext, ss) = X25519::encapsulate(&X25519::encapsulation_key(&dk)).unwrap();
+        let expected = dk
+            .diffie_hellman(&x25519::PublicKey::from_array(&ciphertext))
+            .unwrap();
+        assert_eq!(ss.0, expected.0);
+    }
+}
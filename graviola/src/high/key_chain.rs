@@ -0,0 +1,258 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Key hierarchies and forward-secure ratchets, built on HKDF.
+//!
+//! A [`KeyChain`] is a 32-byte secret from which labelled subkeys are
+//! derived.  It can also produce child chains (to build a hierarchy of
+//! keys), and be ratcheted forward: after [`KeyChain::ratchet`], the
+//! previous key -- and everything derived from it -- cannot be
+//! recomputed from the chain.
+//!
+//! Every derivation uses a distinct, unambiguous HKDF `info` encoding,
+//! so keys derived for different purposes, labels or lengths are
+//! independent:
+//!
+//! | Operation | `info` |
+//! |-----------|--------|
+//! | [`KeyChain::new`] | `0x00` |
+//! | [`KeyChain::derive`] | `0x01 ‖ u16(len(label)) ‖ label ‖ u16(len(out))` |
+//! | [`KeyChain::child`] | `0x02 ‖ u16(len(label)) ‖ label` |
+//! | [`KeyChain::ratchet`] | `0x03` |
+//!
+//! where `u16` is a big-endian encoding.
+//!
+//! ```
+//! use graviola::hashing::Sha256;
+//! use graviola::hashing::key_chain::KeyChain;
+//!
+//! let root = KeyChain::<Sha256>::new(b"root secret", b"MyApp v1");
+//!
+//! let mut database_key = [0u8; 32];
+//! root.derive(b"database", &mut database_key).unwrap();
+//!
+//! let mut sessions = root.child(b"sessions").unwrap();
+//! let mut session_key = [0u8; 32];
+//! sessions.derive(b"session key", &mut session_key).unwrap();
+//!
+//! // once the session key is no longer needed:
+//! sessions.ratchet();
+//! assert_eq!(sessions.generation(), 1);
+//! ```
+
+use core::marker::PhantomData;
+
+use super::hash::Hash;
+use super::hkdf::{self, Prk};
+use crate::Error;
+use crate::low;
+
+/// A node in a key hierarchy.
+///
+/// See the [module documentation](self).
+pub struct KeyChain<H: Hash + Clone> {
+    key: [u8; 32],
+    generation: u64,
+    _h: PhantomData<H>,
+}
+
+impl<H: Hash + Clone> KeyChain<H> {
+    /// Start a new key chain from `root_secret`.
+    ///
+    /// `domain` separates this application's use of `root_secret` from
+    /// any other: it is used as the HKDF salt.
+    pub fn new(root_secret: &[u8], domain: &[u8]) -> Self {
+        let mut key = [0u8; 32];
+        // cannot fail: 32 bytes is well within the HKDF output limit
+        hkdf::hkdf::<H>(domain, root_secret, &[INFO_NEW], &mut key).unwrap();
+        let chain = Self::from_bytes(&key, 0);
+        low::zeroise(&mut key);
+        chain
+    }
+
+    /// Use `key` as a key chain, which has been ratcheted `generation`
+    /// times.
+    ///
+    /// This is for restoring previously saved state, from
+    /// [`KeyChain::as_bytes`] and [`KeyChain::generation`].
+    pub fn from_bytes(key: &[u8; 32], generation: u64) -> Self {
+        Self {
+            key: *key,
+            generation,
+            _h: PhantomData,
+        }
+    }
+
+    /// Extract the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// The number of times this chain has been ratcheted.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Fill `out` with a subkey for `label`.
+    ///
+    /// The length of `out` is included in the derivation, so subkeys of
+    /// different lengths are unrelated.
+    ///
+    /// Fails with [`Error::WrongLength`] if `label` is longer than 65535
+    /// bytes, or `out` is longer than 255 times the hash function output.
+    pub fn derive(&self, label: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        let label_len = encode_len(label.len())?;
+        let out_len = encode_len(out.len())?;
+        self.prk()
+            .expand(&[&[INFO_DERIVE], &label_len, label, &out_len], out)
+    }
+
+    /// Derive a child key chain for `label`.
+    ///
+    /// The child starts at generation zero, and ratchets independently
+    /// of this chain.
+    ///
+    /// Fails with [`Error::WrongLength`] if `label` is longer than 65535
+    /// bytes.
+    pub fn child(&self, label: &[u8]) -> Result<Self, Error> {
+        let label_len = encode_len(label.len())?;
+        let mut key = [0u8; 32];
+        self.prk()
+            .expand(&[&[INFO_CHILD], &label_len, label], &mut key)?;
+        let child = Self::from_bytes(&key, 0);
+        low::zeroise(&mut key);
+        Ok(child)
+    }
+
+    /// Ratchet this chain forward, replacing its key.
+    ///
+    /// The previous key is erased.
+    pub fn ratchet(&mut self) {
+        let mut key = [0u8; 32];
+        // cannot fail: 32 bytes is well within the HKDF output limit
+        self.prk().expand(&[&[INFO_RATCHET]], &mut key).unwrap();
+        self.key = key;
+        low::zeroise(&mut key);
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    fn prk(&self) -> Prk<H> {
+        Prk::new(&self.key)
+    }
+}
+
+impl<H: Hash + Clone> Drop for KeyChain<H> {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.key);
+    }
+}
+
+fn encode_len(len: usize) -> Result<[u8; 2], Error> {
+    u16::try_from(len)
+        .map(u16::to_be_bytes)
+        .map_err(|_| Error::WrongLength)
+}
+
+const INFO_NEW: u8 = 0x00;
+const INFO_DERIVE: u8 = 0x01;
+const INFO_CHILD: u8 = 0x02;
+const INFO_RATCHET: u8 = 0x03;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::{Sha256, Sha512};
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn known_answers() {
+        // from an independent implementation
+        let mut chain = KeyChain::<Sha256>::new(b"root secret", b"domain");
+        assert_eq!(
+            chain.as_bytes().to_vec(),
+            unhex("9f60aa61deaebc409eebf2dac36d26f2a79478b3c5e5747b11f2e91e5e4c7768")
+        );
+
+        let mut out = [0u8; 16];
+        chain.derive(b"label", &mut out).unwrap();
+        assert_eq!(out.to_vec(), unhex("e33e3bb53ae2396138f9f0546d40b716"));
+
+        let child = chain.child(b"child").unwrap();
+        assert_eq!(
+            child.as_bytes().to_vec(),
+            unhex("7c62b1a9745cf6e1c64c1dd4713fb37ffa1fdc929052352be9fe80c0d65ad76d")
+        );
+        assert_eq!(child.generation(), 0);
+
+        chain.ratchet();
+        assert_eq!(
+            chain.as_bytes().to_vec(),
+            unhex("698f149e084bee2b1ec1f0989febcbee5508108f5ecd4ef8ff7a22afefd80ff0")
+        );
+        assert_eq!(chain.generation(), 1);
+
+        let chain = KeyChain::<Sha512>::new(b"root secret", b"domain");
+        assert_eq!(
+            chain.as_bytes().to_vec(),
+            unhex("ea7ee3369e3dea217867eb6a6ecbe71d3f518fe8bffda2a8c03996ab615466da")
+        );
+    }
+
+    #[test]
+    fn domain_separation() {
+        let chain = KeyChain::<Sha256>::new(b"root secret", b"domain");
+        let derive = |label: &[u8], len: usize| {
+            let mut out = vec![0u8; len];
+            chain.derive(label, &mut out).unwrap();
+            out
+        };
+
+        assert_ne!(derive(b"a", 32), derive(b"b", 32));
+        assert_ne!(derive(b"a", 16)[..], derive(b"a", 32)[..16]);
+        assert_ne!(
+            derive(b"a", 32)[..],
+            chain.child(b"a").unwrap().as_bytes()[..]
+        );
+        assert_ne!(
+            chain.as_bytes(),
+            KeyChain::<Sha256>::new(b"root secret", b"other domain").as_bytes()
+        );
+        assert_ne!(
+            chain.as_bytes(),
+            KeyChain::<Sha512>::new(b"root secret", b"domain").as_bytes()
+        );
+    }
+
+    #[test]
+    fn restore() {
+        let mut chain = KeyChain::<Sha256>::new(b"root secret", b"domain");
+        chain.ratchet();
+        chain.ratchet();
+
+        let mut restored = KeyChain::<Sha256>::from_bytes(chain.as_bytes(), chain.generation());
+        assert_eq!(restored.generation(), 2);
+        chain.ratchet();
+        restored.ratchet();
+        assert_eq!(chain.as_bytes(), restored.as_bytes());
+    }
+
+    #[test]
+    fn invalid() {
+        let chain = KeyChain::<Sha256>::new(b"", b"");
+        assert_eq!(
+            chain.derive(&vec![0u8; 65536], &mut [0u8; 32]),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            chain.derive(b"", &mut vec![0u8; 255 * 32 + 1]),
+            Err(Error::WrongLength)
+        );
+        assert!(chain.child(&vec![0u8; 65536]).is_err());
+    }
+}
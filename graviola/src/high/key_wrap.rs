@@ -0,0 +1,175 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::Error;
+use crate::mid::aes_siv::AesSiv;
+
+/// A key-encryption key, for wrapping data-encryption keys.
+///
+/// This uses AES-SIV ([RFC5297](https://datatracker.ietf.org/doc/html/rfc5297)),
+/// which was designed for key wrapping.  A wrapped key is:
+///
+/// - a 2-byte header: a version byte, then an algorithm byte,
+/// - the 16-byte synthetic IV,
+/// - the encrypted key.
+///
+/// The header is authenticated as associated data.
+///
+/// Wrapping is deterministic: wrapping the same key twice gives the
+/// same output.  That only reveals whether two wrapped keys are equal,
+/// which does not matter for randomly-generated keys.
+pub struct KeyWrapKey {
+    siv: AesSiv,
+    header: [u8; HEADER_LEN],
+}
+
+impl KeyWrapKey {
+    /// The number of bytes wrapping adds to a key.
+    pub const OVERHEAD: usize = HEADER_LEN + TAG_LEN;
+
+    /// Create a key-encryption key from `kek`.
+    ///
+    /// `kek` must be 32 bytes (for AES-SIV with AES-128) or 64 bytes
+    /// (for AES-SIV with AES-256), otherwise [`Error::WrongLength`] is
+    /// returned.
+    pub fn new(kek: &[u8]) -> Result<Self, Error> {
+        let algorithm = match kek.len() {
+            32 => ALGORITHM_AES_SIV_256,
+            64 => ALGORITHM_AES_SIV_512,
+            _ => return Err(Error::WrongLength),
+        };
+
+        Ok(Self {
+            siv: AesSiv::new(kek)?,
+            header: [VERSION, algorithm],
+        })
+    }
+
+    /// Wrap `dek`, writing the result to `out`.
+    ///
+    /// `out` must be at least [`Self::OVERHEAD`] bytes longer than `dek`,
+    /// otherwise [`Error::WrongLength`] is returned.  The used prefix of
+    /// `out` is returned.
+    pub fn wrap<'a>(&self, dek: &[u8], out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        let out = out
+            .get_mut(..dek.len() + Self::OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+        let (header, rest) = out.split_at_mut(HEADER_LEN);
+        let (tag, ciphertext) = rest.split_at_mut(TAG_LEN);
+
+        header.copy_from_slice(&self.header);
+        ciphertext.copy_from_slice(dek);
+        self.siv
+            .encrypt(&[&self.header], ciphertext, tag.try_into().unwrap());
+        Ok(out)
+    }
+
+    /// Unwrap `wrapped`, writing the key to `out`.
+    ///
+    /// `out` must be at least as long as the key ([`Self::OVERHEAD`] bytes
+    /// shorter than `wrapped`), otherwise [`Error::WrongLength`] is
+    /// returned.  On success, the used prefix of `out` is returned.
+    ///
+    /// [`Error::DecryptFailed`] is returned if `wrapped` was not produced by
+    /// this key, has been altered, or has an unknown version or algorithm.
+    pub fn unwrap<'a>(&self, wrapped: &[u8], out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        let len = wrapped
+            .len()
+            .checked_sub(Self::OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+        let out = out.get_mut(..len).ok_or(Error::WrongLength)?;
+
+        let (header, rest) = wrapped.split_at(HEADER_LEN);
+        let (tag, ciphertext) = rest.split_at(TAG_LEN);
+        if header != self.header {
+            return Err(Error::DecryptFailed);
+        }
+
+        out.copy_from_slice(ciphertext);
+        self.siv.decrypt(&[header], out, tag)?;
+        Ok(out)
+    }
+}
+
+const VERSION: u8 = 0x01;
+const ALGORITHM_AES_SIV_256: u8 = 0x01;
+const ALGORITHM_AES_SIV_512: u8 = 0x02;
+
+const HEADER_LEN: usize = 2;
+const TAG_LEN: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        for kek_len in [32, 64] {
+            let kek = KeyWrapKey::new(&vec![0x42; kek_len]).unwrap();
+            let dek = [0x24u8; 32];
+
+            let mut wrapped = [0u8; 32 + KeyWrapKey::OVERHEAD];
+            let wrapped = kek.wrap(&dek, &mut wrapped).unwrap();
+            assert_eq!(wrapped[0], VERSION);
+
+            let mut out = [0u8; 64];
+            assert_eq!(kek.unwrap(wrapped, &mut out).unwrap(), &dek);
+
+            for i in 0..wrapped.len() {
+                let mut altered = wrapped.to_vec();
+                altered[i] ^= 1;
+                assert_eq!(kek.unwrap(&altered, &mut out), Err(Error::DecryptFailed));
+            }
+        }
+    }
+
+    #[test]
+    fn known_answer() {
+        // from an independent implementation
+        let kek = KeyWrapKey::new(&(0..32).collect::<Vec<u8>>()).unwrap();
+        let mut wrapped = [0u8; 16 + KeyWrapKey::OVERHEAD];
+        assert_eq!(
+            kek.wrap(&[0xaa; 16], &mut wrapped).unwrap(),
+            unhex("010103a78a77e0fb3eda7a2f6c9fd380360f156b366ea9f052461f272cf1fcd67f3e")
+        );
+    }
+
+    #[test]
+    fn mismatched_keys() {
+        let kek1 = KeyWrapKey::new(&[1; 32]).unwrap();
+        let kek2 = KeyWrapKey::new(&[2; 32]).unwrap();
+        let kek3 = KeyWrapKey::new(&[1; 64]).unwrap();
+
+        let mut wrapped = [0u8; 16 + KeyWrapKey::OVERHEAD];
+        let wrapped = kek1.wrap(&[0xaa; 16], &mut wrapped).unwrap();
+        let mut out = [0u8; 16];
+        assert_eq!(kek2.unwrap(wrapped, &mut out), Err(Error::DecryptFailed));
+        assert_eq!(kek3.unwrap(wrapped, &mut out), Err(Error::DecryptFailed));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(KeyWrapKey::new(&[0; 16]).is_err());
+
+        let kek = KeyWrapKey::new(&[0; 32]).unwrap();
+        assert_eq!(
+            kek.wrap(&[0; 16], &mut [0; 33]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            kek.unwrap(&[0; 17], &mut [0; 16]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            kek.unwrap(&[0; 34], &mut [0; 15]).err(),
+            Some(Error::WrongLength)
+        );
+    }
+}
@@ -0,0 +1,367 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::Error;
+use crate::mid::aead::Aead;
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+/// A set of up to `N` AEAD keys, for key rotation.
+///
+/// Each key has a 32-bit identifier.  Messages are sealed with the
+/// primary key, and the output is prefixed with its identifier:
+///
+/// - the 4-byte big-endian key identifier,
+/// - a random nonce, [`Aead::NONCE_LEN`] bytes long,
+/// - the ciphertext,
+/// - the authentication tag, [`Aead::TAG_LEN`] bytes long.
+///
+/// Opening selects the key by its identifier, so a new primary key
+/// can be introduced while messages sealed with older keys in the set
+/// can still be opened.
+///
+/// [`AeadKeyring::open_by_trial()`] supports messages without key
+/// identifiers, by trying every key.
+///
+/// Random nonces limit the number of messages that may be sealed with
+/// one key: for AES-GCM, this should be no more than 2<sup>32</sup>.
+pub struct AeadKeyring<A: Aead, const N: usize> {
+    keys: [Option<(u32, A)>; N],
+    primary: Option<u32>,
+}
+
+impl<A: Aead, const N: usize> AeadKeyring<A, N> {
+    /// The number of bytes [`Self::seal()`] adds to a message.
+    pub const OVERHEAD: usize = KEY_ID_LEN + A::NONCE_LEN + A::TAG_LEN;
+
+    /// Make an empty keyring.
+    pub fn new() -> Self {
+        Self {
+            keys: core::array::from_fn(|_| None),
+            primary: None,
+        }
+    }
+
+    /// Add `key`, with identifier `id`.
+    ///
+    /// The first key added becomes the primary key.
+    ///
+    /// Returns [`Error::OutOfRange`] if the keyring already has `N` keys,
+    /// or already has a key with the identifier `id`.
+    pub fn add(&mut self, id: u32, key: A) -> Result<(), Error> {
+        if self.find(id).is_some() {
+            return Err(Error::OutOfRange);
+        }
+
+        let slot = self
+            .keys
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .ok_or(Error::OutOfRange)?;
+        *slot = Some((id, key));
+
+        if self.primary.is_none() {
+            self.primary = Some(id);
+        }
+        Ok(())
+    }
+
+    /// Use the key with identifier `id` for sealing.
+    ///
+    /// Returns [`Error::OutOfRange`] if there is no such key.
+    pub fn set_primary(&mut self, id: u32) -> Result<(), Error> {
+        self.find(id).ok_or(Error::OutOfRange)?;
+        self.primary = Some(id);
+        Ok(())
+    }
+
+    /// The identifier of the primary key, if any.
+    pub fn primary(&self) -> Option<u32> {
+        self.primary
+    }
+
+    /// Remove the key with identifier `id`, returning it.
+    ///
+    /// Messages sealed with it can no longer be opened.  If it was the
+    /// primary key, there is no primary key until [`Self::set_primary()`]
+    /// is called.
+    pub fn remove(&mut self, id: u32) -> Option<A> {
+        let slot = self
+            .keys
+            .iter_mut()
+            .find(|slot| matches!(slot, Some((slot_id, _)) if *slot_id == id))?;
+
+        if self.primary == Some(id) {
+            self.primary = None;
+        }
+        slot.take().map(|(_, key)| key)
+    }
+
+    /// Seal `plaintext` with the primary key, writing the result to `out`.
+    ///
+    /// `aad` is the additionally-authenticated data.  It may be empty.
+    ///
+    /// `out` must be at least [`Self::OVERHEAD`] bytes longer than
+    /// `plaintext`, otherwise [`Error::WrongLength`] is returned.  The used
+    /// prefix of `out` is returned.
+    ///
+    /// [`Error::OutOfRange`] is returned if there is no primary key.
+    pub fn seal<'a>(
+        &self,
+        aad: &[u8],
+        plaintext: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], Error> {
+        self.seal_with_rng(aad, plaintext, out, &mut SystemRandom)
+    }
+
+    /// Encrypt `plaintext` with the primary key, using `rng` to generate
+    /// the nonce.
+    ///
+    /// Otherwise, as [`Self::seal()`].
+    pub fn seal_with_rng<'a>(
+        &self,
+        aad: &[u8],
+        plaintext: &[u8],
+        out: &'a mut [u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<&'a [u8], Error> {
+        let id = self.primary.ok_or(Error::OutOfRange)?;
+        let key = self.find(id).ok_or(Error::OutOfRange)?;
+
+        let out = out
+            .get_mut(..plaintext.len() + Self::OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+        let (out_id, rest) = out.split_at_mut(KEY_ID_LEN);
+        let (nonce, rest) = rest.split_at_mut(A::NONCE_LEN);
+        let (ciphertext, tag) = rest.split_at_mut(plaintext.len());
+
+        out_id.copy_from_slice(&id.to_be_bytes());
+        rng.fill(nonce)?;
+        ciphertext.copy_from_slice(plaintext);
+        key.seal(nonce, aad, ciphertext, tag)?;
+        Ok(out)
+    }
+
+    /// Open `sealed`, which was produced by [`Self::seal()`], writing the
+    /// plaintext to `out`.
+    ///
+    /// `out` must be at least as long as the plaintext ([`Self::OVERHEAD`]
+    /// bytes shorter than `sealed`), otherwise [`Error::WrongLength`] is
+    /// returned.  On success, the used prefix of `out` is returned.
+    ///
+    /// [`Error::DecryptFailed`] is returned if the key is not in this
+    /// keyring, or the message was altered.
+    pub fn open<'a>(
+        &self,
+        aad: &[u8],
+        sealed: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], Error> {
+        let len = sealed
+            .len()
+            .checked_sub(Self::OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+        let out = out.get_mut(..len).ok_or(Error::WrongLength)?;
+
+        let (id, rest) = sealed.split_at(KEY_ID_LEN);
+        let (nonce, rest) = rest.split_at(A::NONCE_LEN);
+        let (ciphertext, tag) = rest.split_at(len);
+
+        let id = u32::from_be_bytes(id.try_into().unwrap());
+        let key = self.find(id).ok_or(Error::DecryptFailed)?;
+
+        out.copy_from_slice(ciphertext);
+        key.open(nonce, aad, out, tag)?;
+        Ok(out)
+    }
+
+    /// Open a message without a key identifier, by trying every key in turn.
+    ///
+    /// This is for messages produced directly with [`Aead::seal()`], perhaps
+    /// before this keyring was adopted.  `ciphertext` is the ciphertext,
+    /// and `nonce`, `aad` and `tag` must match those used when sealing.
+    ///
+    /// `out` must be at least as long as `ciphertext`, otherwise
+    /// [`Error::WrongLength`] is returned.  On success, the used prefix of
+    /// `out` and the identifier of the key which opened the message are
+    /// returned.  Otherwise, [`Error::DecryptFailed`] is returned.
+    ///
+    /// The cost of this is proportional to the number of keys.
+    pub fn open_by_trial<'a>(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<(&'a [u8], u32), Error> {
+        let out = out.get_mut(..ciphertext.len()).ok_or(Error::WrongLength)?;
+
+        for (id, key) in self.keys.iter().flatten() {
+            out.copy_from_slice(ciphertext);
+            match key.open(nonce, aad, out, tag) {
+                Ok(()) => return Ok((out, *id)),
+                Err(Error::DecryptFailed) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(Error::DecryptFailed)
+    }
+
+    fn find(&self, id: u32) -> Option<&A> {
+        self.keys
+            .iter()
+            .flatten()
+            .find(|(slot_id, _)| *slot_id == id)
+            .map(|(_, key)| key)
+    }
+}
+
+impl<A: Aead, const N: usize> Default for AeadKeyring<A, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const KEY_ID_LEN: usize = 4;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::aead::Aes256Gcm;
+    use crate::mid::chacha20poly1305::ChaCha20Poly1305;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn key<A: Aead>(byte: u8) -> A {
+        A::new(&vec![byte; A::KEY_LEN]).unwrap()
+    }
+
+    fn rotation<A: Aead>() {
+        let mut keyring = AeadKeyring::<A, 2>::new();
+        assert_eq!(
+            keyring.seal(b"", b"", &mut [0u8; 64]).err(),
+            Some(Error::OutOfRange)
+        );
+
+        keyring.add(1, key(0x11)).unwrap();
+        assert_eq!(keyring.primary(), Some(1));
+
+        let mut old = vec![0u8; 5 + AeadKeyring::<A, 2>::OVERHEAD];
+        let old = keyring.seal(b"aad", b"hello", &mut old).unwrap();
+        assert_eq!(&old[..4], &[0, 0, 0, 1]);
+
+        // introduce a new key
+        keyring.add(2, key(0x22)).unwrap();
+        keyring.set_primary(2).unwrap();
+        let mut new = vec![0u8; 5 + AeadKeyring::<A, 2>::OVERHEAD];
+        let new = keyring.seal(b"aad", b"world", &mut new).unwrap();
+        assert_eq!(&new[..4], &[0, 0, 0, 2]);
+
+        let mut out = [0u8; 5];
+        assert_eq!(keyring.open(b"aad", old, &mut out).unwrap(), b"hello");
+        assert_eq!(keyring.open(b"aad", new, &mut out).unwrap(), b"world");
+        assert_eq!(
+            keyring.open(b"AAD", new, &mut out),
+            Err(Error::DecryptFailed)
+        );
+
+        // retire the old key
+        assert!(keyring.remove(1).is_some());
+        assert!(keyring.remove(1).is_none());
+        assert_eq!(
+            keyring.open(b"aad", old, &mut out),
+            Err(Error::DecryptFailed)
+        );
+        assert_eq!(keyring.open(b"aad", new, &mut out).unwrap(), b"world");
+
+        // a key claiming to be another key's identifier doesn't open
+        let mut forged = new.to_vec();
+        forged[3] = 1;
+        keyring.add(1, key(0x33)).unwrap();
+        assert_eq!(
+            keyring.open(b"aad", &forged, &mut out),
+            Err(Error::DecryptFailed)
+        );
+    }
+
+    #[test]
+    fn rotation_aes_gcm() {
+        rotation::<Aes256Gcm>();
+    }
+
+    #[test]
+    fn rotation_chacha20poly1305() {
+        rotation::<ChaCha20Poly1305>();
+    }
+
+    #[test]
+    fn known_answer() {
+        let mut keyring = AeadKeyring::<Aes256Gcm, 1>::new();
+        keyring.add(0x01020304, key(0x42)).unwrap();
+
+        let nonce = [0x24u8; 12];
+        let mut out = [0u8; 5 + 32];
+        let sealed = keyring
+            .seal_with_rng(b"aad", b"hello", &mut out, &mut SliceRandomSource(&nonce))
+            .unwrap();
+
+        let mut expect = *b"hello";
+        let mut tag = [0u8; 16];
+        key::<Aes256Gcm>(0x42)
+            .seal(&nonce, b"aad", &mut expect, &mut tag)
+            .unwrap();
+        assert_eq!(sealed, [&[1, 2, 3, 4][..], &nonce, &expect, &tag].concat());
+    }
+
+    #[test]
+    fn trial() {
+        let mut keyring = AeadKeyring::<ChaCha20Poly1305, 3>::new();
+        for id in 0..3 {
+            keyring.add(id, key(id as u8)).unwrap();
+        }
+
+        let nonce = [0u8; 12];
+        let mut ciphertext = *b"legacy";
+        let mut tag = [0u8; 16];
+        key::<ChaCha20Poly1305>(2)
+            .seal(&nonce, b"", &mut ciphertext, &mut tag)
+            .unwrap();
+
+        let mut out = [0u8; 6];
+        let (plaintext, id) = keyring
+            .open_by_trial(&nonce, b"", &ciphertext, &tag, &mut out)
+            .unwrap();
+        assert_eq!((plaintext, id), (&b"legacy"[..], 2));
+
+        tag[0] ^= 1;
+        assert_eq!(
+            keyring.open_by_trial(&nonce, b"", &ciphertext, &tag, &mut out),
+            Err(Error::DecryptFailed)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let mut keyring = AeadKeyring::<Aes256Gcm, 1>::new();
+        keyring.add(1, key(1)).unwrap();
+        assert_eq!(keyring.add(1, key(1)), Err(Error::OutOfRange));
+        assert_eq!(keyring.add(2, key(2)), Err(Error::OutOfRange));
+        assert_eq!(keyring.set_primary(2), Err(Error::OutOfRange));
+
+        assert_eq!(
+            keyring.seal(b"", b"hello", &mut [0u8; 32]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            keyring.open(b"", &[0u8; 31], &mut [0u8; 32]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            keyring.open(b"", &[0u8; 37], &mut [0u8; 4]).err(),
+            Some(Error::WrongLength)
+        );
+        keyring.remove(1);
+        assert_eq!(keyring.primary(), None);
+    }
+}
@@ -0,0 +1,200 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::hash::{Hash, HashOutput};
+use super::hmac::Hmac;
+use crate::Error;
+use crate::low::ct_equal;
+use crate::mid::aes_gcm::Gmac;
+use crate::mid::cmac::Cmac;
+use crate::mid::kmac::Kmac;
+use crate::mid::poly1305::Poly1305;
+
+/// A generic trait over supported message authentication codes.
+///
+/// This exists so protocol code may be generic over the MAC used.
+pub trait Mac: Sized {
+    /// The type of the MAC's output.
+    type Tag: AsRef<[u8]>;
+
+    /// Add `bytes` to the message.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Complete the computation, returning the tag.
+    fn finish(self) -> Self::Tag;
+
+    /// Complete the computation, and compare the result against `expected_tag`.
+    ///
+    /// This is done in constant-time.  `expected_tag` may not be truncated.
+    /// [`Error::BadSignature`] is returned if the tags differ.
+    fn verify(self, expected_tag: &[u8]) -> Result<(), Error> {
+        match ct_equal(self.finish().as_ref(), expected_tag) {
+            true => Ok(()),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// A [`Mac`] which can be constructed from just a key.
+///
+/// GMAC does not implement this, because it additionally needs a nonce.
+pub trait NewMac: Mac {
+    /// Start a new computation using `key`.
+    ///
+    /// An error is returned if `key` is not acceptable, for example
+    /// because it is the wrong length.
+    fn new_with_key(key: &[u8]) -> Result<Self, Error>;
+}
+
+impl<H: Hash> Mac for Hmac<H> {
+    type Tag = HashOutput;
+
+    fn update(&mut self, bytes: &[u8]) {
+        Self::update(self, bytes)
+    }
+
+    fn finish(self) -> Self::Tag {
+        Self::finish(self)
+    }
+}
+
+impl<H: Hash> NewMac for Hmac<H> {
+    fn new_with_key(key: &[u8]) -> Result<Self, Error> {
+        Ok(Self::new(key))
+    }
+}
+
+impl Mac for Cmac {
+    type Tag = [u8; 16];
+
+    fn update(&mut self, bytes: &[u8]) {
+        Self::update(self, bytes)
+    }
+
+    fn finish(self) -> Self::Tag {
+        Self::finish(self)
+    }
+}
+
+impl NewMac for Cmac {
+    fn new_with_key(key: &[u8]) -> Result<Self, Error> {
+        Self::new(key)
+    }
+}
+
+impl<const RATE: usize, const OUT: usize> Mac for Kmac<RATE, OUT> {
+    type Tag = [u8; OUT];
+
+    fn update(&mut self, bytes: &[u8]) {
+        Self::update(self, bytes)
+    }
+
+    fn finish(self) -> Self::Tag {
+        Self::finish(self)
+    }
+}
+
+impl<const RATE: usize, const OUT: usize> NewMac for Kmac<RATE, OUT> {
+    /// The customization string is empty.
+    fn new_with_key(key: &[u8]) -> Result<Self, Error> {
+        Ok(Self::new(key, b""))
+    }
+}
+
+impl Mac for Poly1305 {
+    type Tag = [u8; 16];
+
+    fn update(&mut self, bytes: &[u8]) {
+        Self::update(self, bytes)
+    }
+
+    fn finish(self) -> Self::Tag {
+        Self::finish(self)
+    }
+}
+
+impl NewMac for Poly1305 {
+    /// `key` must be 32 bytes, and must only be used once.
+    fn new_with_key(key: &[u8]) -> Result<Self, Error> {
+        let key = key.try_into().map_err(|_| Error::WrongLength)?;
+        Ok(Self::new(key))
+    }
+}
+
+impl Mac for Gmac<'_> {
+    type Tag = [u8; 16];
+
+    fn update(&mut self, bytes: &[u8]) {
+        Self::update(self, bytes)
+    }
+
+    fn finish(self) -> Self::Tag {
+        Self::finish(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::hash::{Sha256, Sha512};
+    use super::*;
+    use crate::mid::aes_gcm::AesGcm;
+    use crate::mid::kmac::{Kmac128, Kmac256};
+
+    fn tag_of<M: NewMac>(key: &[u8], message: &[u8]) -> M::Tag {
+        let mut m = M::new_with_key(key).unwrap();
+        m.update(message);
+        m.finish()
+    }
+
+    fn check_verify<M: NewMac>(key: &[u8]) {
+        let tag = tag_of::<M>(key, b"message");
+        let tag = tag.as_ref();
+
+        let mut m = M::new_with_key(key).unwrap();
+        m.update(b"message");
+        assert_eq!(m.verify(tag), Ok(()));
+
+        let mut m = M::new_with_key(key).unwrap();
+        m.update(b"massage");
+        assert_eq!(m.verify(tag), Err(Error::BadSignature));
+
+        let mut m = M::new_with_key(key).unwrap();
+        m.update(b"message");
+        assert_eq!(m.verify(&tag[..tag.len() - 1]), Err(Error::BadSignature));
+    }
+
+    #[test]
+    fn generic_verify() {
+        check_verify::<Hmac<Sha256>>(b"key");
+        check_verify::<Hmac<Sha512>>(b"key");
+        check_verify::<Cmac>(&[0x42; 16]);
+        check_verify::<Cmac>(&[0x42; 32]);
+        check_verify::<Poly1305>(&[0x42; 32]);
+        check_verify::<Kmac128>(b"key");
+        check_verify::<Kmac256>(b"key");
+    }
+
+    #[test]
+    fn new_with_key_errors() {
+        assert!(Cmac::new_with_key(&[0; 24]).is_err());
+        assert!(Poly1305::new_with_key(&[0; 16]).is_err());
+    }
+
+    #[test]
+    fn matches_inherent() {
+        let mut h = Hmac::<Sha256>::new(b"key");
+        h.update(b"message");
+        assert_eq!(tag_of::<Hmac<Sha256>>(b"key", b"message"), h.finish());
+
+        let mut k = Kmac128::new(b"key", b"");
+        k.update(b"message");
+        assert_eq!(tag_of::<Kmac128>(b"key", b"message"), k.finish());
+
+        let aes = AesGcm::new(&[0x42; 16]);
+        let mut expected = [0u8; 16];
+        aes.encrypt(&[0; 12], b"message", &mut [], &mut expected);
+        let mut g = aes.gmac(&[0; 12]);
+        Mac::update(&mut g, b"message");
+        assert_eq!(Mac::verify(g, &expected), Ok(()));
+    }
+}
@@ -3,12 +3,62 @@
 
 #![deny(unsafe_code)]
 
+pub mod age;
 pub(super) mod asn1;
+pub(super) mod base64;
+pub(super) mod cbor;
+pub(super) mod cose;
 pub(super) mod curve;
+pub mod double_ratchet;
+pub(super) mod dsa;
 pub(super) mod ecdsa;
+pub(super) mod envelope;
+pub(super) mod external;
+pub(super) mod ffdhe;
+pub(super) mod fingerprint;
+pub(super) mod group;
 pub(super) mod hash;
+pub(super) mod hex;
+pub mod hkdf;
 pub mod hmac;
 pub(super) mod hmac_drbg;
+pub(super) mod hpke;
+pub(super) mod jose;
+pub(super) mod json;
+pub(super) mod jwk;
+pub(super) mod kem;
+pub mod key_chain;
+pub(super) mod key_wrap;
+pub(super) mod keyring;
+pub(super) mod mac;
+pub(super) mod noise;
+pub(super) mod opaque;
+pub(super) mod openssh;
+pub(super) mod oprf;
+pub mod otp;
+pub(super) mod paseto;
+pub mod pbkdf2;
+pub(super) mod pem;
 pub(super) mod pkcs1;
 pub(super) mod pkcs8;
+pub(super) mod quic;
+#[cfg(feature = "ring-compat")]
+pub(super) mod ring_compat;
 pub(super) mod rsa;
+pub(super) mod rustcrypto;
+pub(super) mod sec1;
+pub(super) mod seed;
+#[cfg(feature = "serde")]
+pub(super) mod serde;
+pub mod shamir;
+pub(super) mod signing;
+pub mod slip10;
+pub(super) mod spake2plus;
+pub(super) mod spki;
+pub(super) mod srp;
+pub(super) mod srtp;
+pub mod stream;
+pub(super) mod tink;
+pub mod tree_hash;
+pub mod x3dh;
+pub(super) mod x509;
@@ -0,0 +1,348 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::hash::{Blake2b, Blake2s, Hash, HashContext, HashOutput, Sha256, Sha512};
+use super::hkdf;
+use super::kem::X25519;
+use crate::Error;
+use crate::mid::{aes_gcm, chacha20poly1305, x25519};
+
+/// A Noise DH function.
+///
+/// See [section 4.1](https://noiseprotocol.org/noise.html#dh-functions).
+pub trait NoiseDh {
+    /// The name of this DH function in Noise protocol names, eg. `"25519"`.
+    const NAME: &'static str;
+
+    /// `DHLEN`: the length of public keys and DH outputs, in bytes.
+    const DH_LEN: usize;
+
+    /// A key pair.  This may be used for many DH operations.
+    type KeyPair;
+
+    /// The encoding of a public key.
+    type PublicKey: AsRef<[u8]>;
+
+    /// The output of a DH operation.
+    type SharedSecret: AsRef<[u8]>;
+
+    /// `GENERATE_KEYPAIR()`: generate a new key pair using the system
+    /// random number generator.
+    fn generate_keypair() -> Result<Self::KeyPair, Error>;
+
+    /// Return the encoding of `key_pair`'s public key.
+    fn public_key(key_pair: &Self::KeyPair) -> Self::PublicKey;
+
+    /// `DH(key_pair, public_key)`: perform a DH operation.
+    ///
+    /// An invalid `public_key` causes an error.
+    fn dh(key_pair: &Self::KeyPair, public_key: &[u8]) -> Result<Self::SharedSecret, Error>;
+}
+
+/// A Noise cipher function, keyed with `k`.
+///
+/// See [section 4.2](https://noiseprotocol.org/noise.html#cipher-functions).
+pub trait NoiseCipher: Sized {
+    /// The name of this cipher in Noise protocol names, eg. `"ChaChaPoly"`.
+    const NAME: &'static str;
+
+    /// Create a new cipher with the 32-byte key `k`.
+    fn new(k: &[u8; 32]) -> Self;
+
+    /// `ENCRYPT(k, n, ad, plaintext)`.
+    ///
+    /// On entry, `cipher_inout` contains the plaintext.  On exit, it
+    /// contains the ciphertext, and `tag_out` the authentication tag.
+    fn encrypt(&self, n: u64, ad: &[u8], cipher_inout: &mut [u8], tag_out: &mut [u8; 16]);
+
+    /// `DECRYPT(k, n, ad, ciphertext)`.
+    ///
+    /// On entry, `cipher_inout` contains the ciphertext.  On success, it
+    /// contains the plaintext.  Otherwise [`Error::DecryptFailed`] is
+    /// returned and `cipher_inout` is cleared.
+    fn decrypt(&self, n: u64, ad: &[u8], cipher_inout: &mut [u8], tag: &[u8]) -> Result<(), Error>;
+
+    /// `REKEY(k)`: return a new key, derived from this one.
+    ///
+    /// This is the default from the specification: the first 32 bytes
+    /// of encrypting 32 zero bytes with the maximum nonce.
+    fn rekey(&self) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        let mut tag = [0u8; 16];
+        self.encrypt(u64::MAX, &[], &mut key, &mut tag);
+        key
+    }
+}
+
+/// A Noise hash function.
+///
+/// `HMAC-HASH` is [`Hmac<Self>`](super::hmac::Hmac).
+///
+/// See [section 4.3](https://noiseprotocol.org/noise.html#hash-functions).
+pub trait NoiseHash: Hash + Clone {
+    /// The name of this hash function in Noise protocol names, eg. `"SHA256"`.
+    const NAME: &'static str;
+
+    /// `HASHLEN`: the length of the hash output, in bytes.
+    const HASH_LEN: usize;
+
+    /// `BLOCKLEN`: the internal block length of the hash function, in bytes.
+    const BLOCK_LEN: usize;
+
+    /// `HKDF(chaining_key, input_key_material, 2)`.
+    ///
+    /// This is used by `MixKey()`.
+    fn hkdf2(chaining_key: &[u8], input_key_material: &[u8]) -> (HashOutput, HashOutput) {
+        let [a, b, _] = hkdf_outputs::<Self, 2>(chaining_key, input_key_material);
+        (a, b)
+    }
+
+    /// `HKDF(chaining_key, input_key_material, 3)`.
+    ///
+    /// This is used by `MixKeyAndHash()`.
+    fn hkdf3(
+        chaining_key: &[u8],
+        input_key_material: &[u8],
+    ) -> (HashOutput, HashOutput, HashOutput) {
+        let [a, b, c] = hkdf_outputs::<Self, 3>(chaining_key, input_key_material);
+        (a, b, c)
+    }
+
+    /// `MixHash(data)`: replace `h` with `HASH(h || data)`.
+    fn mix_hash(h: &mut HashOutput, data: &[u8]) {
+        let mut ctx = Self::new();
+        ctx.update(h.as_ref());
+        ctx.update(data);
+        *h = ctx.finish();
+    }
+}
+
+/// Noise's HKDF is RFC5869 HKDF with `chaining_key` as the salt,
+/// and an empty `info`.  Outputs past `N` are left zeroed.
+fn hkdf_outputs<H: NoiseHash, const N: usize>(
+    chaining_key: &[u8],
+    input_key_material: &[u8],
+) -> [HashOutput; 3] {
+    let mut okm = [0u8; 3 * 64];
+    let okm = &mut okm[..N * H::HASH_LEN];
+    // cannot fail: at most three outputs are requested
+    hkdf::extract::<H>(chaining_key, &[input_key_material])
+        .expand(&[], okm)
+        .unwrap();
+
+    let mut outputs = [H::zeroed_output(), H::zeroed_output(), H::zeroed_output()];
+    for (out, chunk) in outputs.iter_mut().zip(okm.chunks_exact(H::HASH_LEN)) {
+        out.as_mut().copy_from_slice(chunk);
+    }
+    crate::low::zeroise(okm);
+    outputs
+}
+
+impl NoiseDh for X25519 {
+    const NAME: &'static str = "25519";
+    const DH_LEN: usize = 32;
+
+    type KeyPair = x25519::StaticPrivateKey;
+    type PublicKey = [u8; 32];
+    type SharedSecret = x25519::SharedSecret;
+
+    fn generate_keypair() -> Result<Self::KeyPair, Error> {
+        x25519::StaticPrivateKey::new_random()
+    }
+
+    fn public_key(key_pair: &Self::KeyPair) -> Self::PublicKey {
+        key_pair.public_key().as_bytes()
+    }
+
+    fn dh(key_pair: &Self::KeyPair, public_key: &[u8]) -> Result<Self::SharedSecret, Error> {
+        key_pair.diffie_hellman(&x25519::PublicKey::try_from_slice(public_key)?)
+    }
+}
+
+/// The Noise `"ChaChaPoly"` cipher: ChaCha20-Poly1305.
+///
+/// The nonce is 32 zero bits, then `n` in little-endian.
+pub struct ChaChaPoly(chacha20poly1305::ChaCha20Poly1305);
+
+impl NoiseCipher for ChaChaPoly {
+    const NAME: &'static str = "ChaChaPoly";
+
+    fn new(k: &[u8; 32]) -> Self {
+        Self(chacha20poly1305::ChaCha20Poly1305::new(*k))
+    }
+
+    fn encrypt(&self, n: u64, ad: &[u8], cipher_inout: &mut [u8], tag_out: &mut [u8; 16]) {
+        self.0.encrypt(&nonce_le(n), ad, cipher_inout, tag_out)
+    }
+
+    fn decrypt(&self, n: u64, ad: &[u8], cipher_inout: &mut [u8], tag: &[u8]) -> Result<(), Error> {
+        self.0.decrypt(&nonce_le(n), ad, cipher_inout, tag)
+    }
+}
+
+/// The Noise `"AESGCM"` cipher: AES-256-GCM.
+///
+/// The nonce is 32 zero bits, then `n` in big-endian.
+pub struct AesGcm(aes_gcm::AesGcm);
+
+impl NoiseCipher for AesGcm {
+    const NAME: &'static str = "AESGCM";
+
+    fn new(k: &[u8; 32]) -> Self {
+        Self(aes_gcm::AesGcm::new(k))
+    }
+
+    fn encrypt(&self, n: u64, ad: &[u8], cipher_inout: &mut [u8], tag_out: &mut [u8; 16]) {
+        self.0.encrypt(&nonce_be(n), ad, cipher_inout, tag_out)
+    }
+
+    fn decrypt(&self, n: u64, ad: &[u8], cipher_inout: &mut [u8], tag: &[u8]) -> Result<(), Error> {
+        self.0.decrypt(&nonce_be(n), ad, cipher_inout, tag)
+    }
+}
+
+fn nonce_le(n: u64) -> [u8; 12] {
+    let mut nonce = [0u8; 12];
+    nonce[4..].copy_from_slice(&n.to_le_bytes());
+    nonce
+}
+
+fn nonce_be(n: u64) -> [u8; 12] {
+    let mut nonce = [0u8; 12];
+    nonce[4..].copy_from_slice(&n.to_be_bytes());
+    nonce
+}
+
+macro_rules! noise_hash {
+    ($hash:ident, $name:literal, $hash_len:literal, $block_len:literal) => {
+        impl NoiseHash for $hash {
+            const NAME: &'static str = $name;
+            const HASH_LEN: usize = $hash_len;
+            const BLOCK_LEN: usize = $block_len;
+        }
+    };
+}
+
+noise_hash!(Sha256, "SHA256", 32, 64);
+noise_hash!(Sha512, "SHA512", 64, 128);
+noise_hash!(Blake2s, "BLAKE2s", 32, 64);
+noise_hash!(Blake2b, "BLAKE2b", 64, 128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hmac::Hmac;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn check_hkdf<H: NoiseHash>(expected: [&str; 3]) {
+        assert_eq!(H::zeroed_output().as_ref().len(), H::HASH_LEN);
+        assert_eq!(H::zeroed_block().len(), H::BLOCK_LEN);
+
+        let ck: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let (a, b, c) = H::hkdf3(&ck, b"input key material");
+        assert_eq!(
+            [hex(a.as_ref()), hex(b.as_ref()), hex(c.as_ref())],
+            expected
+        );
+
+        let (a2, b2) = H::hkdf2(&ck, b"input key material");
+        assert_eq!(a, a2);
+        assert_eq!(b, b2);
+    }
+
+    #[test]
+    fn hkdf() {
+        // produced with python's hmac and hashlib, following the
+        // definition in section 4.3 of the specification.
+        check_hkdf::<Sha256>([
+            "785dba36cff1fcf591f812fa0b2bade807af9e50d2a96caf28d3c9a285558549",
+            "8b4e05ec153f03cf906c305b981902e2af26ae2966397bb43988c4ab28f3aae1",
+            "35effd0e30e44866f90854f48ee80ae324d856c3ee9ddde0e87365acf4aeeeed",
+        ]);
+        check_hkdf::<Sha512>([
+            "3981313d03b6f968f3c5ca4e594c59aed52b1c603d66e4cabeb81c6c06ce04b6\
+             adbc344338b140688908d6ffbaf2a08bddbdcdf8b692cd2d2dd2aacac58d9e0b",
+            "5067805d7e08218fcf9555bb23b37b5a79fe2198ae1e93eac77062fb511c8087\
+             05705fbe982ea43d88a88c80c87bbaf5576664d50ce6407463a38890f9891c33",
+            "389c9d35dc2129921f9b8a8c0f2ef9740b914b7229b705b90f9e24d5e63208e2\
+             9e6effb0a7f4a12f09a088f46105051e05ad6099521453bc9e305ab0b264728a",
+        ]);
+        check_hkdf::<Blake2s>([
+            "93e2a65883e34ac95485f5d0aa4b09163a4e944dab8cf9406d3a138e362851c6",
+            "dd6fae42afdb2a41170f44dc5b885ec4cfd17c8dd324d38b390c548630dd0a83",
+            "4125e0adfa9b7e736615e99dde6f3f0b64b949d8b480e82cab2e685dc286e45d",
+        ]);
+        check_hkdf::<Blake2b>([
+            "346944681b15e9b86b9bf3e87504caa8bb62fa1e2f86adc97672172b40409560\
+             f77a0a7a60dd76dadd86bed8651477b9c397f603e828e37d89232b6d50414fff",
+            "04fd035b62ea92cc475b273368317124e76c0484233dc512bacc534b712c30c7\
+             40ec8907cadd74dd5c40789dd24b6b0e8bec89ad4c7fad97103e8007a4255eee",
+            "0ec5e8e5193861dda4ceb62d374e823ca62214952762ef3adecf5dad25403c82\
+             0a499de27a1ce362ac5486cf28075f4a137011a1335d0345589f335303b4270f",
+        ]);
+    }
+
+    #[test]
+    fn mix_hash() {
+        let mut h = Blake2s::hash(b"Noise_NN_25519_ChaChaPoly_BLAKE2s");
+        let mut expected = Blake2s::new();
+        expected.update(h.as_ref());
+        expected.update(b"prologue");
+
+        Blake2s::mix_hash(&mut h, b"prologue");
+        assert_eq!(h.as_ref(), &expected.finish());
+
+        // HMAC-HASH is available for all of them
+        let tag = Hmac::<Blake2b>::new(b"key").finish();
+        assert_eq!(tag.as_ref().len(), Blake2b::HASH_LEN);
+    }
+
+    fn check_cipher<C: NoiseCipher>(expected: &str, expected_rekey: &str) {
+        // produced with python's `cryptography`
+        let k: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let cipher = C::new(&k);
+        let mut message = *b"hello";
+        let mut tag = [0u8; 16];
+        cipher.encrypt(0x0102030405060708, b"ad", &mut message, &mut tag);
+        assert_eq!(format!("{}{}", hex(&message), hex(&tag)), expected);
+
+        assert_eq!(
+            cipher.decrypt(0x0102030405060709, b"ad", &mut message.clone(), &tag),
+            Err(Error::DecryptFailed)
+        );
+        cipher
+            .decrypt(0x0102030405060708, b"ad", &mut message, &tag)
+            .unwrap();
+        assert_eq!(&message, b"hello");
+
+        assert_eq!(hex(&cipher.rekey()), expected_rekey);
+    }
+
+    #[test]
+    fn ciphers() {
+        check_cipher::<ChaChaPoly>(
+            "982cc188c470553bfb5182218e71c7b4be8e2490e3",
+            "50835543a205b22c9323f2022bc4f67d838f90e61d5ccf33c4513e01f85b5042",
+        );
+        check_cipher::<AesGcm>(
+            "6c340962b2cb7f32fbec02e07e823f3a3137d78513",
+            "0201675c87335949b909793da5bb4d92fcf6d44b92a6e0792b6ae48b1881259d",
+        );
+    }
+
+    #[test]
+    fn dh() {
+        let alice = X25519::generate_keypair().unwrap();
+        let bob = X25519::generate_keypair().unwrap();
+        let ab = X25519::dh(&alice, &X25519::public_key(&bob)).unwrap();
+        let ba = X25519::dh(&bob, X25519::public_key(&alice).as_ref()).unwrap();
+        assert_eq!(ab.as_ref(), ba.as_ref());
+        assert_eq!(ab.as_ref().len(), X25519::DH_LEN);
+
+        assert!(X25519::dh(&alice, &[0u8; 32]).is_err());
+        assert!(X25519::dh(&alice, &[0u8; 31]).is_err());
+    }
+}
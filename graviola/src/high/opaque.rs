@@ -0,0 +1,1342 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! OPAQUE, an augmented password-authenticated key exchange, as specified in
+//! [RFC9807](https://datatracker.ietf.org/doc/html/rfc9807).
+//!
+//! The server stores a [`RegistrationRecord`] per client rather than a
+//! password-equivalent verifier.  The password is never sent to the server,
+//! and an attacker who obtains the records must run an offline dictionary
+//! attack against each one; nothing can be precomputed.
+//!
+//! This uses the OPRF from [`Oprf`], the 3DH key exchange, and HKDF and HMAC
+//! with the ciphersuite's hash function.
+
+use core::marker::PhantomData;
+
+use super::hash::{Digest, Hash, HashContext, HashOutput};
+use super::hkdf::{self, Prk};
+use super::hmac::Hmac;
+use super::oprf::{
+    Blind, BlindedElement, Ciphersuite, EvaluatedElement, Oprf, PrivateKey, PublicKey, len_prefix,
+};
+use crate::Error;
+use crate::low::{self, Entry};
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+/// A key stretching function, `Stretch()`.
+///
+/// This hardens the OPRF output against offline dictionary attacks.  Both
+/// client and server must agree on the function and its parameters; they
+/// are part of the OPAQUE configuration.  A memory-hard function such as
+/// Argon2id should be used.
+pub trait KeyStretching {
+    /// Stretch `input` into `out`.
+    ///
+    /// Both are as long as the ciphersuite's hash output.
+    fn stretch(&self, input: &[u8], out: &mut [u8]) -> Result<(), Error>;
+}
+
+/// The identity key stretching function.
+///
+/// This provides no hardening, and is only suitable if the password
+/// has high entropy (or for testing).
+pub struct NoStretching;
+
+impl KeyStretching for NoStretching {
+    fn stretch(&self, input: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        if input.len() != out.len() {
+            return Err(Error::WrongLength);
+        }
+        out.copy_from_slice(input);
+        Ok(())
+    }
+}
+
+/// The client and server identities bound into the exchange.
+///
+/// Where an identity is `None`, the corresponding public key is
+/// used instead.  Both parties must use the same identities in
+/// registration and login.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Identifiers<'a> {
+    /// The client's identity.
+    pub client: Option<&'a [u8]>,
+
+    /// The server's identity.
+    pub server: Option<&'a [u8]>,
+}
+
+/// The server's long-term state: its key pair, and the seed for
+/// per-client OPRF keys.
+///
+/// This is shared between all clients.
+pub struct ServerSetup<C: Ciphersuite> {
+    oprf_seed: HashOutput,
+    key: PrivateKey<C>,
+}
+
+impl<C: Ciphersuite> ServerSetup<C> {
+    /// Generate a new random server setup.
+    pub fn new_random() -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        Self::generate(&mut SystemRandom)
+    }
+
+    /// Generate a new random server setup using `rng`.
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let mut oprf_seed = C::Hash::zeroed_output();
+        rng.fill(oprf_seed.as_mut())?;
+        Ok(Self {
+            oprf_seed,
+            key: PrivateKey::generate(rng)?,
+        })
+    }
+
+    /// Construct a server setup from its parts.
+    ///
+    /// `oprf_seed` must be as long as the ciphersuite's hash output.
+    pub fn new(oprf_seed: &[u8], key: PrivateKey<C>) -> Result<Self, Error> {
+        if oprf_seed.len() != C::Hash::OUTPUT_LEN {
+            return Err(Error::WrongLength);
+        }
+        Ok(Self {
+            oprf_seed: hash_output::<C>(oprf_seed),
+            key,
+        })
+    }
+
+    /// Return the OPRF seed, for storage.
+    pub fn oprf_seed(&self) -> &[u8] {
+        self.oprf_seed.as_ref()
+    }
+
+    /// Return the server's private key, for storage.
+    pub fn private_key(&self) -> &PrivateKey<C> {
+        &self.key
+    }
+
+    /// Return the server's public key.
+    pub fn public_key(&self) -> PublicKey<C> {
+        self.key.public_key()
+    }
+
+    /// `CreateRegistrationResponse()`: respond to a client's registration
+    /// request.
+    ///
+    /// `credential_identifier` uniquely identifies the client's record,
+    /// and must be used again when they log in.
+    pub fn registration_response(
+        &self,
+        request: &RegistrationRequest<C>,
+        credential_identifier: &[u8],
+    ) -> Result<RegistrationResponse<C>, Error> {
+        let _entry = Entry::new_secret();
+        let oprf_key = self.oprf_key(credential_identifier)?;
+        Ok(RegistrationResponse {
+            evaluated: Oprf::blind_evaluate(&oprf_key, &request.0),
+            server_public_key: self.public_key(),
+        })
+    }
+
+    /// The OPRF key for `credential_identifier`.
+    fn oprf_key(&self, credential_identifier: &[u8]) -> Result<PrivateKey<C>, Error> {
+        let mut seed = [0u8; SEED_LEN];
+        Prk::<C::Hash>::new(self.oprf_seed.as_ref())
+            .expand(&[credential_identifier, b"OprfKey"], &mut seed)?;
+        let key = Oprf::derive_key_pair(&seed, b"OPAQUE-DeriveKeyPair");
+        low::zeroise(&mut seed);
+        key
+    }
+}
+
+impl<C: Ciphersuite> Drop for ServerSetup<C> {
+    fn drop(&mut self) {
+        low::zeroise(self.oprf_seed.as_mut());
+    }
+}
+
+/// The client's state during registration.
+pub struct ClientRegistration<C: Ciphersuite> {
+    blind: Blind<C>,
+}
+
+impl<C: Ciphersuite> ClientRegistration<C> {
+    /// `CreateRegistrationRequest()`: start registering `password`.
+    ///
+    /// The request is sent to the server.
+    pub fn start(password: &[u8]) -> Result<(Self, RegistrationRequest<C>), Error> {
+        Self::start_with_rng(password, &mut SystemRandom)
+    }
+
+    /// `CreateRegistrationRequest()`, using `rng` to generate the blind.
+    ///
+    /// Otherwise, as [`Self::start()`].
+    pub fn start_with_rng(
+        password: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, RegistrationRequest<C>), Error> {
+        let _entry = Entry::new_secret();
+        let (blind, blinded) = Oprf::blind_with_rng(password, rng)?;
+        Ok((Self { blind }, RegistrationRequest(blinded)))
+    }
+
+    /// `FinalizeRegistrationRequest()`: complete registration, given
+    /// the server's response.
+    ///
+    /// The resulting record is sent to the server for storage.  The
+    /// export key is known only to the client, and is the same each
+    /// time they log in.
+    pub fn finish(
+        self,
+        password: &[u8],
+        response: &RegistrationResponse<C>,
+        identifiers: &Identifiers<'_>,
+        ksf: &dyn KeyStretching,
+    ) -> Result<(RegistrationRecord<C>, ExportKey), Error> {
+        let _entry = Entry::new_secret();
+        self.finish_with_rng(password, response, identifiers, ksf, &mut SystemRandom)
+    }
+
+    /// `FinalizeRegistrationRequest()`, using `rng` to generate the
+    /// envelope nonce.
+    ///
+    /// Otherwise, as [`Self::finish()`].
+    pub fn finish_with_rng(
+        self,
+        password: &[u8],
+        response: &RegistrationResponse<C>,
+        identifiers: &Identifiers<'_>,
+        ksf: &dyn KeyStretching,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(RegistrationRecord<C>, ExportKey), Error> {
+        let _entry = Entry::new_secret();
+        let randomized_password =
+            randomized_password(password, &self.blind, &response.evaluated, ksf)?;
+
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill(&mut nonce)?;
+
+        let masking_key = expand::<C>(&randomized_password, &[b"MaskingKey"])?;
+        let (keys, export_key) = EnvelopeKeys::new(&randomized_password, &nonce)?;
+        let client_public_key = keys.client_key.public_key();
+        let credentials =
+            Credentials::new(&response.server_public_key, &client_public_key, identifiers)?;
+
+        let record = RegistrationRecord {
+            client_public_key,
+            masking_key,
+            envelope: Envelope {
+                nonce,
+                auth_tag: keys.auth_tag(&credentials),
+            },
+        };
+        Ok((record, export_key))
+    }
+}
+
+/// The client's state during login.
+pub struct ClientLogin<C: Ciphersuite> {
+    blind: Blind<C>,
+    secret: PrivateKey<C>,
+    ke1: Ke1<C>,
+}
+
+impl<C: Ciphersuite> ClientLogin<C> {
+    /// `GenerateKE1()`: start logging in with `password`.
+    ///
+    /// The [`Ke1`] message is sent to the server.
+    pub fn start(password: &[u8]) -> Result<(Self, Ke1<C>), Error> {
+        Self::start_with_rng(password, &mut SystemRandom)
+    }
+
+    /// `GenerateKE1()`, using `rng` to generate the blind, nonce and
+    /// ephemeral key.
+    ///
+    /// Otherwise, as [`Self::start()`].
+    pub fn start_with_rng(
+        password: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, Ke1<C>), Error> {
+        let _entry = Entry::new_secret();
+        let (blind, blinded) = Oprf::blind_with_rng(password, rng)?;
+
+        let mut client_nonce = [0u8; NONCE_LEN];
+        rng.fill(&mut client_nonce)?;
+        let secret = derive_diffie_hellman_key_pair(rng)?;
+
+        let ke1 = Ke1 {
+            blinded,
+            client_nonce,
+            client_keyshare: secret.public_key(),
+        };
+        let state = Self {
+            blind,
+            secret,
+            ke1: ke1.clone(),
+        };
+        Ok((state, ke1))
+    }
+
+    /// `GenerateKE3()`: complete the login, given the server's response.
+    ///
+    /// `context` and `identifiers` must match those used by the server.
+    ///
+    /// Fails with [`Error::DecryptFailed`] if the password is wrong (or
+    /// the server has no record for this client), and with
+    /// [`Error::BadSignature`] if the server could not be authenticated.
+    ///
+    /// On success, the [`Ke3`] message is sent to the server.  The
+    /// session key is shared with the server, once it has checked the
+    /// [`Ke3`] message.
+    pub fn finish(
+        self,
+        password: &[u8],
+        ke2: &Ke2<C>,
+        context: &[u8],
+        identifiers: &Identifiers<'_>,
+        ksf: &dyn KeyStretching,
+    ) -> Result<(Ke3<C>, SessionKey, ExportKey), Error> {
+        let _entry = Entry::new_secret();
+
+        // RecoverCredentials()
+        let randomized_password = randomized_password(password, &self.blind, &ke2.evaluated, ksf)?;
+        let masking_key = expand::<C>(&randomized_password, &[b"MaskingKey"])?;
+        let mut response = [0u8; MAX_MASKED_RESPONSE_LEN];
+        let response = &mut response[..Ke2::<C>::MASKED_RESPONSE_LEN];
+        masking_pad::<C>(&masking_key, &ke2.masking_nonce, response)?;
+        for (r, m) in response.iter_mut().zip(ke2.masked_response.iter()) {
+            *r ^= m;
+        }
+
+        let (server_public_key, envelope) = response.split_at(C::ELEMENT_LEN);
+        // a wrong password gives a random server public key
+        let server_public_key =
+            PublicKey::from_bytes(server_public_key).map_err(|_| Error::DecryptFailed)?;
+        let (nonce, auth_tag) = envelope.split_at(NONCE_LEN);
+
+        // Recover()
+        let (keys, export_key) = EnvelopeKeys::new(&randomized_password, nonce)?;
+        let credentials = Credentials::new(
+            &server_public_key,
+            &keys.client_key.public_key(),
+            identifiers,
+        )?;
+        if !keys.auth_tag(&credentials).ct_equal(auth_tag) {
+            return Err(Error::DecryptFailed);
+        }
+
+        // AuthClientFinalize()
+        let keys_3dh = [
+            self.secret.diffie_hellman(&ke2.server_keyshare),
+            self.secret.diffie_hellman(&server_public_key),
+            keys.client_key.diffie_hellman(&ke2.server_keyshare),
+        ];
+        let mut preamble = preamble(context, &credentials, &self.ke1, ke2)?;
+        let (km2, km3, session_key) = derive_keys::<C>(&keys_3dh, &preamble)?;
+
+        let server_mac = mac::<C>(&km2, preamble.clone().finish().as_ref());
+        if !server_mac.ct_equal(ke2.server_mac.as_ref()) {
+            return Err(Error::BadSignature);
+        }
+        preamble.update(server_mac.as_ref());
+        let ke3 = Ke3 {
+            client_mac: mac::<C>(&km3, preamble.finish().as_ref()),
+            _suite: PhantomData,
+        };
+
+        Ok((ke3, session_key, export_key))
+    }
+}
+
+/// The server's state during login.
+pub struct ServerLogin<C: Ciphersuite> {
+    expected_client_mac: HashOutput,
+    session_key: SessionKey,
+    _suite: PhantomData<C>,
+}
+
+impl<C: Ciphersuite> ServerLogin<C> {
+    /// `GenerateKE2()`: respond to a client's [`Ke1`] message.
+    ///
+    /// `record` is the client's record, stored under `credential_identifier`
+    /// at registration.  Pass `None` if there is no such record: the
+    /// response is then indistinguishable from a real one, and login
+    /// fails on the client side.
+    ///
+    /// `context` is shared by client and server, and identifies the
+    /// application.  `identifiers` must match those used at registration.
+    ///
+    /// The [`Ke2`] message is sent to the client.
+    pub fn start(
+        setup: &ServerSetup<C>,
+        record: Option<&RegistrationRecord<C>>,
+        credential_identifier: &[u8],
+        ke1: &Ke1<C>,
+        context: &[u8],
+        identifiers: &Identifiers<'_>,
+    ) -> Result<(Self, Ke2<C>), Error> {
+        Self::start_with_rng(
+            setup,
+            record,
+            credential_identifier,
+            ke1,
+            context,
+            identifiers,
+            &mut SystemRandom,
+        )
+    }
+
+    /// `GenerateKE2()`, using `rng` to generate the nonce, ephemeral key,
+    /// and (for unregistered clients) a fake record.
+    ///
+    /// Otherwise, as [`Self::start()`].
+    pub fn start_with_rng(
+        setup: &ServerSetup<C>,
+        record: Option<&RegistrationRecord<C>>,
+        credential_identifier: &[u8],
+        ke1: &Ke1<C>,
+        context: &[u8],
+        identifiers: &Identifiers<'_>,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, Ke2<C>), Error> {
+        let _entry = Entry::new_secret();
+        let fake;
+        let record = match record {
+            Some(record) => record,
+            None => {
+                fake = RegistrationRecord::fake(rng)?;
+                &fake
+            }
+        };
+
+        // CreateCredentialResponse()
+        let oprf_key = setup.oprf_key(credential_identifier)?;
+        let evaluated = Oprf::blind_evaluate(&oprf_key, &ke1.blinded);
+
+        let mut masking_nonce = [0u8; NONCE_LEN];
+        rng.fill(&mut masking_nonce)?;
+        let mut masked_response = [0u8; MAX_MASKED_RESPONSE_LEN];
+        let masked = &mut masked_response[..Ke2::<C>::MASKED_RESPONSE_LEN];
+        masking_pad::<C>(&record.masking_key, &masking_nonce, masked)?;
+        let server_public_key = setup.public_key();
+        let server_public_key_bytes = server_public_key.as_bytes();
+        let response = [
+            server_public_key_bytes.as_ref(),
+            &record.envelope.nonce,
+            record.envelope.auth_tag.as_ref(),
+        ];
+        for (m, r) in masked
+            .iter_mut()
+            .zip(response.iter().flat_map(|r| r.iter()))
+        {
+            *m ^= r;
+        }
+
+        // AuthServerRespond()
+        let mut server_nonce = [0u8; NONCE_LEN];
+        rng.fill(&mut server_nonce)?;
+        let secret = derive_diffie_hellman_key_pair(rng)?;
+
+        let mut ke2 = Ke2 {
+            evaluated,
+            masking_nonce,
+            masked_response,
+            server_nonce,
+            server_keyshare: secret.public_key(),
+            server_mac: C::Hash::zeroed_output(),
+        };
+
+        let credentials =
+            Credentials::new(&server_public_key, &record.client_public_key, identifiers)?;
+        let keys_3dh = [
+            secret.diffie_hellman(&ke1.client_keyshare),
+            setup.key.diffie_hellman(&ke1.client_keyshare),
+            secret.diffie_hellman(&record.client_public_key),
+        ];
+        let mut preamble = preamble(context, &credentials, ke1, &ke2)?;
+        let (km2, km3, session_key) = derive_keys::<C>(&keys_3dh, &preamble)?;
+
+        ke2.server_mac = mac::<C>(&km2, preamble.clone().finish().as_ref());
+        preamble.update(ke2.server_mac.as_ref());
+        let state = Self {
+            expected_client_mac: mac::<C>(&km3, preamble.finish().as_ref()),
+            session_key,
+            _suite: PhantomData,
+        };
+        Ok((state, ke2))
+    }
+
+    /// `ServerFinish()`: check the client's [`Ke3`] message.
+    ///
+    /// Fails with [`Error::BadSignature`] if the client could not be
+    /// authenticated.  Otherwise, returns the session key shared with
+    /// the client.
+    pub fn finish(self, ke3: &Ke3<C>) -> Result<SessionKey, Error> {
+        let _entry = Entry::new_secret();
+        match self.expected_client_mac.ct_equal(ke3.client_mac.as_ref()) {
+            true => Ok(self.session_key),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// A shared key, resulting from a successful login.
+pub struct SessionKey(HashOutput);
+
+impl SessionKey {
+    /// Extract the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Drop for SessionKey {
+    fn drop(&mut self) {
+        low::zeroise(self.0.as_mut());
+    }
+}
+
+/// A key known only to the client, which is stable across logins.
+///
+/// This can be used to encrypt additional data stored on the server.
+pub struct ExportKey(HashOutput);
+
+impl ExportKey {
+    /// Extract the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Drop for ExportKey {
+    fn drop(&mut self) {
+        low::zeroise(self.0.as_mut());
+    }
+}
+
+/// The client's registration request, sent to the server.
+pub struct RegistrationRequest<C: Ciphersuite>(BlindedElement<C>);
+
+impl<C: Ciphersuite> RegistrationRequest<C> {
+    /// Length of an encoded request.
+    pub const LEN: usize = C::ELEMENT_LEN;
+
+    /// Decode a request.  `bytes` must be [`RegistrationRequest::LEN`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader::new(bytes, Self::LEN)?;
+        Ok(Self(BlindedElement::from_bytes(r.take(C::ELEMENT_LEN))?))
+    }
+
+    /// Encode this request into `out`.
+    ///
+    /// The written prefix of `out` is returned.
+    pub fn encode<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        encode(out, Self::LEN, |w| w(self.0.as_bytes().as_ref()))
+    }
+}
+
+/// The server's registration response, sent to the client.
+pub struct RegistrationResponse<C: Ciphersuite> {
+    evaluated: EvaluatedElement<C>,
+    server_public_key: PublicKey<C>,
+}
+
+impl<C: Ciphersuite> RegistrationResponse<C> {
+    /// Length of an encoded response.
+    pub const LEN: usize = 2 * C::ELEMENT_LEN;
+
+    /// Decode a response.  `bytes` must be [`RegistrationResponse::LEN`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader::new(bytes, Self::LEN)?;
+        Ok(Self {
+            evaluated: EvaluatedElement::from_bytes(r.take(C::ELEMENT_LEN))?,
+            server_public_key: PublicKey::from_bytes(r.take(C::ELEMENT_LEN))?,
+        })
+    }
+
+    /// Encode this response into `out`.
+    ///
+    /// The written prefix of `out` is returned.
+    pub fn encode<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        encode(out, Self::LEN, |w| {
+            w(self.evaluated.as_bytes().as_ref());
+            w(self.server_public_key.as_bytes().as_ref());
+        })
+    }
+}
+
+/// The client's registration record, which the server stores.
+pub struct RegistrationRecord<C: Ciphersuite> {
+    client_public_key: PublicKey<C>,
+    masking_key: HashOutput,
+    envelope: Envelope,
+}
+
+impl<C: Ciphersuite> RegistrationRecord<C> {
+    /// Length of an encoded record.
+    pub const LEN: usize = C::ELEMENT_LEN + NONCE_LEN + 2 * C::Hash::OUTPUT_LEN;
+
+    /// Decode a record.  `bytes` must be [`RegistrationRecord::LEN`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader::new(bytes, Self::LEN)?;
+        Ok(Self {
+            client_public_key: PublicKey::from_bytes(r.take(C::ELEMENT_LEN))?,
+            masking_key: hash_output::<C>(r.take(C::Hash::OUTPUT_LEN)),
+            envelope: Envelope {
+                nonce: r.nonce(),
+                auth_tag: hash_output::<C>(r.take(C::Hash::OUTPUT_LEN)),
+            },
+        })
+    }
+
+    /// Encode this record into `out`.
+    ///
+    /// The written prefix of `out` is returned.
+    pub fn encode<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        encode(out, Self::LEN, |w| {
+            w(self.client_public_key.as_bytes().as_ref());
+            w(self.masking_key.as_ref());
+            w(&self.envelope.nonce);
+            w(self.envelope.auth_tag.as_ref());
+        })
+    }
+
+    /// A record for a client who has not registered.
+    fn fake(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let client_public_key = PrivateKey::generate(rng)?.public_key();
+        let mut masking_key = C::Hash::zeroed_output();
+        rng.fill(masking_key.as_mut())?;
+        Ok(Self {
+            client_public_key,
+            masking_key,
+            envelope: Envelope {
+                nonce: [0u8; NONCE_LEN],
+                auth_tag: C::Hash::zeroed_output(),
+            },
+        })
+    }
+}
+
+impl<C: Ciphersuite> Drop for RegistrationRecord<C> {
+    fn drop(&mut self) {
+        low::zeroise(self.masking_key.as_mut());
+    }
+}
+
+/// The first login message, sent from client to server.
+pub struct Ke1<C: Ciphersuite> {
+    blinded: BlindedElement<C>,
+    client_nonce: [u8; NONCE_LEN],
+    client_keyshare: PublicKey<C>,
+}
+
+impl<C: Ciphersuite> Ke1<C> {
+    /// Length of an encoded message.
+    pub const LEN: usize = 2 * C::ELEMENT_LEN + NONCE_LEN;
+
+    /// Decode a message.  `bytes` must be [`Ke1::LEN`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader::new(bytes, Self::LEN)?;
+        Ok(Self {
+            blinded: BlindedElement::from_bytes(r.take(C::ELEMENT_LEN))?,
+            client_nonce: r.nonce(),
+            client_keyshare: PublicKey::from_bytes(r.take(C::ELEMENT_LEN))?,
+        })
+    }
+
+    /// Encode this message into `out`.
+    ///
+    /// The written prefix of `out` is returned.
+    pub fn encode<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        encode(out, Self::LEN, |w| self.write(w))
+    }
+
+    fn write(&self, w: &mut dyn FnMut(&[u8])) {
+        w(self.blinded.as_bytes().as_ref());
+        w(&self.client_nonce);
+        w(self.client_keyshare.as_bytes().as_ref());
+    }
+}
+
+impl<C: Ciphersuite> Clone for Ke1<C> {
+    fn clone(&self) -> Self {
+        Self {
+            blinded: self.blinded.clone(),
+            client_nonce: self.client_nonce,
+            client_keyshare: self.client_keyshare.clone(),
+        }
+    }
+}
+
+/// The second login message, sent from server to client.
+pub struct Ke2<C: Ciphersuite> {
+    evaluated: EvaluatedElement<C>,
+    masking_nonce: [u8; NONCE_LEN],
+    masked_response: [u8; MAX_MASKED_RESPONSE_LEN],
+    server_nonce: [u8; NONCE_LEN],
+    server_keyshare: PublicKey<C>,
+    server_mac: HashOutput,
+}
+
+impl<C: Ciphersuite> Ke2<C> {
+    /// Length of an encoded message.
+    pub const LEN: usize =
+        2 * C::ELEMENT_LEN + 2 * NONCE_LEN + Self::MASKED_RESPONSE_LEN + C::Hash::OUTPUT_LEN;
+
+    /// `Npk + Nn + Nm`: the server's public key and the envelope.
+    const MASKED_RESPONSE_LEN: usize = C::ELEMENT_LEN + NONCE_LEN + C::Hash::OUTPUT_LEN;
+
+    /// Decode a message.  `bytes` must be [`Ke2::LEN`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader::new(bytes, Self::LEN)?;
+        let evaluated = EvaluatedElement::from_bytes(r.take(C::ELEMENT_LEN))?;
+        let masking_nonce = r.nonce();
+        let mut masked_response = [0u8; MAX_MASKED_RESPONSE_LEN];
+        masked_response[..Self::MASKED_RESPONSE_LEN]
+            .copy_from_slice(r.take(Self::MASKED_RESPONSE_LEN));
+        Ok(Self {
+            evaluated,
+            masking_nonce,
+            masked_response,
+            server_nonce: r.nonce(),
+            server_keyshare: PublicKey::from_bytes(r.take(C::ELEMENT_LEN))?,
+            server_mac: hash_output::<C>(r.take(C::Hash::OUTPUT_LEN)),
+        })
+    }
+
+    /// Encode this message into `out`.
+    ///
+    /// The written prefix of `out` is returned.
+    pub fn encode<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        encode(out, Self::LEN, |w| {
+            self.write_unauthenticated(w);
+            w(self.server_mac.as_ref());
+        })
+    }
+
+    /// The `CredentialResponse`, `server_nonce` and `server_public_keyshare`:
+    /// everything covered by the server's MAC.
+    fn write_unauthenticated(&self, w: &mut dyn FnMut(&[u8])) {
+        w(self.evaluated.as_bytes().as_ref());
+        w(&self.masking_nonce);
+        w(&self.masked_response[..Self::MASKED_RESPONSE_LEN]);
+        w(&self.server_nonce);
+        w(self.server_keyshare.as_bytes().as_ref());
+    }
+}
+
+/// The third login message, sent from client to server.
+pub struct Ke3<C: Ciphersuite> {
+    client_mac: HashOutput,
+    _suite: PhantomData<C>,
+}
+
+impl<C: Ciphersuite> Ke3<C> {
+    /// Length of an encoded message.
+    pub const LEN: usize = C::Hash::OUTPUT_LEN;
+
+    /// Decode a message.  `bytes` must be [`Ke3::LEN`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = Reader::new(bytes, Self::LEN)?;
+        Ok(Self {
+            client_mac: hash_output::<C>(r.take(C::Hash::OUTPUT_LEN)),
+            _suite: PhantomData,
+        })
+    }
+
+    /// Encode this message into `out`.
+    ///
+    /// The written prefix of `out` is returned.
+    pub fn encode<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        encode(out, Self::LEN, |w| w(self.client_mac.as_ref()))
+    }
+}
+
+struct Envelope {
+    nonce: [u8; NONCE_LEN],
+    auth_tag: HashOutput,
+}
+
+/// The keys derived from the randomized password and an envelope nonce.
+struct EnvelopeKeys<C: Ciphersuite> {
+    nonce: [u8; NONCE_LEN],
+    auth_key: HashOutput,
+    client_key: PrivateKey<C>,
+}
+
+impl<C: Ciphersuite> EnvelopeKeys<C> {
+    /// Also returns the export key.
+    fn new(randomized_password: &Prk<C::Hash>, nonce: &[u8]) -> Result<(Self, ExportKey), Error> {
+        let auth_key = expand::<C>(randomized_password, &[nonce, b"AuthKey"])?;
+        let export_key = ExportKey(expand::<C>(randomized_password, &[nonce, b"ExportKey"])?);
+
+        let mut seed = [0u8; SEED_LEN];
+        randomized_password.expand(&[nonce, b"PrivateKey"], &mut seed)?;
+        let client_key = Oprf::derive_key_pair(&seed, DERIVE_DH_KEY_PAIR_INFO);
+        low::zeroise(&mut seed);
+
+        let keys = Self {
+            nonce: nonce.try_into().map_err(|_| Error::WrongLength)?,
+            auth_key,
+            client_key: client_key?,
+        };
+        Ok((keys, export_key))
+    }
+
+    /// `MAC(auth_key, envelope_nonce || cleartext_credentials)`
+    fn auth_tag(&self, credentials: &Credentials<'_, C>) -> HashOutput {
+        let mut mac = Hmac::<C::Hash>::new(self.auth_key.as_ref());
+        mac.update(self.nonce);
+        credentials.write(&mut |part| mac.update(part));
+        mac.finish()
+    }
+}
+
+impl<C: Ciphersuite> Drop for EnvelopeKeys<C> {
+    fn drop(&mut self) {
+        low::zeroise(self.auth_key.as_mut());
+    }
+}
+
+/// `CleartextCredentials`, with missing identities defaulted to the public keys.
+struct Credentials<'a, C: Ciphersuite> {
+    server_public_key: C::EncodedElement,
+    client_public_key: C::EncodedElement,
+    identifiers: &'a Identifiers<'a>,
+}
+
+impl<'a, C: Ciphersuite> Credentials<'a, C> {
+    fn new(
+        server_public_key: &PublicKey<C>,
+        client_public_key: &PublicKey<C>,
+        identifiers: &'a Identifiers<'a>,
+    ) -> Result<Self, Error> {
+        for id in [identifiers.client, identifiers.server]
+            .into_iter()
+            .flatten()
+        {
+            len_prefix(id)?;
+        }
+        Ok(Self {
+            server_public_key: server_public_key.as_bytes(),
+            client_public_key: client_public_key.as_bytes(),
+            identifiers,
+        })
+    }
+
+    fn server_identity(&self) -> &[u8] {
+        self.identifiers
+            .server
+            .unwrap_or(self.server_public_key.as_ref())
+    }
+
+    fn client_identity(&self) -> &[u8] {
+        self.identifiers
+            .client
+            .unwrap_or(self.client_public_key.as_ref())
+    }
+
+    fn write(&self, w: &mut dyn FnMut(&[u8])) {
+        w(self.server_public_key.as_ref());
+        write_with_len(w, self.server_identity());
+        write_with_len(w, self.client_identity());
+    }
+}
+
+/// A hash context over the `preamble`, ready to be finished or
+/// extended with the server MAC.
+fn preamble<C: Ciphersuite>(
+    context: &[u8],
+    credentials: &Credentials<'_, C>,
+    ke1: &Ke1<C>,
+    ke2: &Ke2<C>,
+) -> Result<<C::Hash as Hash>::Context, Error> {
+    len_prefix(context)?;
+    let mut ctx = C::Hash::new();
+    let w: &mut dyn FnMut(&[u8]) = &mut |part| ctx.update(part);
+    w(b"OPAQUEv1-");
+    write_with_len(w, context);
+    write_with_len(w, credentials.client_identity());
+    ke1.write(w);
+    write_with_len(w, credentials.server_identity());
+    ke2.write_unauthenticated(w);
+    Ok(ctx)
+}
+
+/// `DeriveKeys()`: `(Km2, Km3, session_key)` from the 3DH outputs.
+fn derive_keys<C: Ciphersuite>(
+    keys_3dh: &[C::EncodedElement; 3],
+    preamble: &<C::Hash as Hash>::Context,
+) -> Result<(HashOutput, HashOutput, SessionKey), Error> {
+    let ikm = [
+        keys_3dh[0].as_ref(),
+        keys_3dh[1].as_ref(),
+        keys_3dh[2].as_ref(),
+    ];
+    let prk = hkdf::extract::<C::Hash>(&[], &ikm);
+    let preamble_hash = preamble.clone().finish();
+
+    let mut handshake_secret =
+        derive_secret::<C>(&prk, b"HandshakeSecret", preamble_hash.as_ref())?;
+    let session_key = SessionKey(derive_secret::<C>(
+        &prk,
+        b"SessionKey",
+        preamble_hash.as_ref(),
+    )?);
+
+    let handshake_prk = Prk::<C::Hash>::new(handshake_secret.as_ref());
+    low::zeroise(handshake_secret.as_mut());
+    let km2 = derive_secret::<C>(&handshake_prk, b"ServerMAC", &[])?;
+    let km3 = derive_secret::<C>(&handshake_prk, b"ClientMAC", &[])?;
+    Ok((km2, km3, session_key))
+}
+
+/// `Derive-Secret()`: `Expand-Label()` with a hash-length output.
+fn derive_secret<C: Ciphersuite>(
+    prk: &Prk<C::Hash>,
+    label: &[u8],
+    context: &[u8],
+) -> Result<HashOutput, Error> {
+    let length = (C::Hash::OUTPUT_LEN as u16).to_be_bytes();
+    let label_len =
+        u8::try_from(OPAQUE_LABEL_PREFIX.len() + label.len()).map_err(|_| Error::WrongLength)?;
+    let context_len = u8::try_from(context.len()).map_err(|_| Error::WrongLength)?;
+    expand::<C>(
+        prk,
+        &[
+            &length,
+            &[label_len],
+            OPAQUE_LABEL_PREFIX,
+            label,
+            &[context_len],
+            context,
+        ],
+    )
+}
+
+fn mac<C: Ciphersuite>(key: &HashOutput, message: &[u8]) -> HashOutput {
+    let mut mac = Hmac::<C::Hash>::new(key.as_ref());
+    mac.update(message);
+    mac.finish()
+}
+
+/// `randomized_password = Extract("", oprf_output || Stretch(oprf_output))`
+fn randomized_password<C: Ciphersuite>(
+    password: &[u8],
+    blind: &Blind<C>,
+    evaluated: &EvaluatedElement<C>,
+    ksf: &dyn KeyStretching,
+) -> Result<Prk<C::Hash>, Error> {
+    let mut oprf_output = Oprf::finalize(password, blind, evaluated)?;
+    let mut stretched = C::Hash::zeroed_output();
+    let result = ksf.stretch(oprf_output.as_ref(), stretched.as_mut());
+    let prk = hkdf::extract::<C::Hash>(&[], &[oprf_output.as_ref(), stretched.as_ref()]);
+    low::zeroise(oprf_output.as_mut());
+    low::zeroise(stretched.as_mut());
+    result.map(|_| prk)
+}
+
+/// `Expand(masking_key, masking_nonce || "CredentialResponsePad", Npk + Nn + Nm)`
+fn masking_pad<C: Ciphersuite>(
+    masking_key: &HashOutput,
+    masking_nonce: &[u8; NONCE_LEN],
+    out: &mut [u8],
+) -> Result<(), Error> {
+    Prk::<C::Hash>::new(masking_key.as_ref())
+        .expand(&[masking_nonce, b"CredentialResponsePad"], out)
+}
+
+/// `DeriveDiffieHellmanKeyPair()`, from a random seed.
+fn derive_diffie_hellman_key_pair<C: Ciphersuite>(
+    rng: &mut dyn RandomSource,
+) -> Result<PrivateKey<C>, Error> {
+    let mut seed = [0u8; SEED_LEN];
+    rng.fill(&mut seed)?;
+    let key = Oprf::derive_key_pair(&seed, DERIVE_DH_KEY_PAIR_INFO);
+    low::zeroise(&mut seed);
+    key
+}
+
+/// HKDF-Expand to a hash-length output.
+fn expand<C: Ciphersuite>(prk: &Prk<C::Hash>, info: &[&[u8]]) -> Result<HashOutput, Error> {
+    let mut out = C::Hash::zeroed_output();
+    prk.expand(info, out.as_mut())?;
+    Ok(out)
+}
+
+fn hash_output<C: Ciphersuite>(bytes: &[u8]) -> HashOutput {
+    let mut out = C::Hash::zeroed_output();
+    out.as_mut().copy_from_slice(bytes);
+    out
+}
+
+fn write_with_len(w: &mut dyn FnMut(&[u8]), value: &[u8]) {
+    // lengths are checked in advance
+    w(&(value.len() as u16).to_be_bytes());
+    w(value);
+}
+
+/// Encode the parts passed to `w` by `write` into `out`,
+/// where they total `len` bytes.
+fn encode(
+    out: &mut [u8],
+    len: usize,
+    write: impl FnOnce(&mut dyn FnMut(&[u8])),
+) -> Result<&[u8], Error> {
+    let out = out.get_mut(..len).ok_or(Error::WrongLength)?;
+    let mut pos = 0;
+    write(&mut |part| {
+        out[pos..pos + part.len()].copy_from_slice(part);
+        pos += part.len();
+    });
+    Ok(out)
+}
+
+/// Splits an encoding of a known length into its fields.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8], len: usize) -> Result<Self, Error> {
+        match bytes.len() == len {
+            true => Ok(Self(bytes)),
+            false => Err(Error::WrongLength),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> &'a [u8] {
+        let (field, rest) = self.0.split_at(len);
+        self.0 = rest;
+        field
+    }
+
+    fn nonce(&mut self) -> [u8; NONCE_LEN] {
+        // cannot fail: `take` returns exactly `NONCE_LEN` bytes
+        self.take(NONCE_LEN).try_into().unwrap()
+    }
+}
+
+/// `Nn`
+const NONCE_LEN: usize = 32;
+
+/// `Nseed`, and `Nok` for the supported ciphersuites.
+const SEED_LEN: usize = 32;
+
+/// The largest `Npk + Nn + Nm` over the supported ciphersuites.
+const MAX_MASKED_RESPONSE_LEN: usize = 33 + NONCE_LEN + 64;
+
+const DERIVE_DH_KEY_PAIR_INFO: &[u8] = b"OPAQUE-DeriveDiffieHellmanKeyPair";
+
+const OPAQUE_LABEL_PREFIX: &[u8] = b"OPAQUE-";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::curve::P256;
+    use crate::high::group::Ristretto255;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    struct Vectors {
+        context: &'static [u8],
+        identifiers: Identifiers<'static>,
+        oprf_seed: &'static str,
+        server_private_key: &'static str,
+        server_public_key: &'static str,
+        blind_registration: &'static str,
+        blind_login: &'static str,
+        registration_request: &'static str,
+        registration_response: &'static str,
+        registration_record: &'static str,
+        export_key: &'static str,
+        ke1: &'static str,
+        ke2: &'static str,
+        ke3: &'static str,
+        session_key: &'static str,
+    }
+
+    // these inputs are shared between the vectors
+    const CREDENTIAL_IDENTIFIER: &[u8] = b"1234";
+    const PASSWORD: &[u8] = b"CorrectHorseBatteryStaple";
+    const ENVELOPE_NONCE: &str = "ac13171b2f17bc2c74997f0fce1e1f35bec6b91fe2e12dbd323d23ba7a38dfec";
+    const MASKING_NONCE: &str = "38fe59af0df2c79f57b8780278f5ae47355fe1f817119041951c80f612fdfc6d";
+    const SERVER_NONCE: &str = "71cd9960ecef2fe0d0f7494986fa3d8b2bb01963537e60efb13981e138e3d4a1";
+    const CLIENT_NONCE: &str = "da7e07376d6d6f034cfa9bb537d11b8c6b4238c334333d1f0aebb380cae6a6cc";
+    const CLIENT_KEYSHARE_SEED: &str =
+        "82da87d829b5cdaa5b4d3d41e9bd1a7963fd5aa2c4c5d88c89b8bba52c8e5a57";
+    const SERVER_KEYSHARE_SEED: &str =
+        "05a4f54206eef1ba2f615bc0aa285cb22f26d1153b5b40a1e85ff80da12f982f";
+
+    fn check<C: Ciphersuite>(v: &Vectors) {
+        let key = PrivateKey::<C>::from_bytes(&unhex(v.server_private_key)).unwrap();
+        let setup = ServerSetup::new(&unhex(v.oprf_seed), key).unwrap();
+        assert_eq!(
+            hex(setup.public_key().as_bytes().as_ref()),
+            v.server_public_key
+        );
+        let mut buf = [0u8; 512];
+
+        // registration
+        let rng = unhex(v.blind_registration);
+        let (client, request) =
+            ClientRegistration::<C>::start_with_rng(PASSWORD, &mut SliceRandomSource(&rng))
+                .unwrap();
+        assert_eq!(
+            hex(request.encode(&mut buf).unwrap()),
+            v.registration_request
+        );
+
+        let request = RegistrationRequest::from_bytes(&unhex(v.registration_request)).unwrap();
+        let response = setup
+            .registration_response(&request, CREDENTIAL_IDENTIFIER)
+            .unwrap();
+        assert_eq!(
+            hex(response.encode(&mut buf).unwrap()),
+            v.registration_response
+        );
+
+        let rng = unhex(ENVELOPE_NONCE);
+        let (record, export_key) = client
+            .finish_with_rng(
+                PASSWORD,
+                &response,
+                &v.identifiers,
+                &NoStretching,
+                &mut SliceRandomSource(&rng),
+            )
+            .unwrap();
+        assert_eq!(hex(record.encode(&mut buf).unwrap()), v.registration_record);
+        assert_eq!(hex(export_key.as_bytes()), v.export_key);
+
+        // login
+        let rng = unhex(&[v.blind_login, CLIENT_NONCE, CLIENT_KEYSHARE_SEED].concat());
+        let (client, ke1) =
+            ClientLogin::<C>::start_with_rng(PASSWORD, &mut SliceRandomSource(&rng)).unwrap();
+        assert_eq!(hex(ke1.encode(&mut buf).unwrap()), v.ke1);
+
+        let record = RegistrationRecord::from_bytes(&unhex(v.registration_record)).unwrap();
+        let ke1 = Ke1::from_bytes(&unhex(v.ke1)).unwrap();
+        let rng = unhex(&[MASKING_NONCE, SERVER_NONCE, SERVER_KEYSHARE_SEED].concat());
+        let (server, ke2) = ServerLogin::start_with_rng(
+            &setup,
+            Some(&record),
+            CREDENTIAL_IDENTIFIER,
+            &ke1,
+            v.context,
+            &v.identifiers,
+            &mut SliceRandomSource(&rng),
+        )
+        .unwrap();
+        assert_eq!(hex(ke2.encode(&mut buf).unwrap()), v.ke2);
+
+        let ke2 = Ke2::from_bytes(&unhex(v.ke2)).unwrap();
+        let (ke3, session_key, export_key) = client
+            .finish(PASSWORD, &ke2, v.context, &v.identifiers, &NoStretching)
+            .unwrap();
+        assert_eq!(hex(ke3.encode(&mut buf).unwrap()), v.ke3);
+        assert_eq!(hex(session_key.as_bytes()), v.session_key);
+        assert_eq!(hex(export_key.as_bytes()), v.export_key);
+
+        let ke3 = Ke3::from_bytes(&unhex(v.ke3)).unwrap();
+        let server_key = server.finish(&ke3).unwrap();
+        assert_eq!(hex(server_key.as_bytes()), v.session_key);
+    }
+
+    #[test]
+    fn ristretto255_sha512() {
+        // inputs and registration outputs are from RFC9807 appendix C.1.1.
+        // the login messages were produced with an independent implementation.
+        check::<Ristretto255>(&Vectors {
+            context: b"OPAQUE-POC",
+            identifiers: Identifiers::default(),
+            oprf_seed: "f433d0227b0b9dd54f7c4422b600e764e47fb503f1f9a0f0a47c6606b054a7fd\
+                        c65347f1a08f277e22358bbabe26f823fca82c7848e9a75661f4ec5d5c1989ef",
+            server_private_key: "47451a85372f8b3537e249d7b54188091fb18edde78094b43e2ba42b5eb89f0d",
+            server_public_key: "b2fe7af9f48cc502d016729d2fe25cdd433f2c4bc904660b2a382c9b79df1a78",
+            blind_registration: "76cfbfe758db884bebb33582331ba9f159720ca8784a2a070a265d9c2d6abe01",
+            blind_login: "6ecc102d2e7a7cf49617aad7bbe188556792d4acd60a1a8a8d2b65d4b0790308",
+            registration_request: "5059ff249eb1551b7ce4991f3336205bde44a105a032e747d21bf382e75f7a71",
+            registration_response: "7408a268083e03abc7097fc05b587834539065e86fb0c7b6342fcf5e01e5b019\
+                                    b2fe7af9f48cc502d016729d2fe25cdd433f2c4bc904660b2a382c9b79df1a78",
+            registration_record: "76a845464c68a5d2f7e442436bb1424953b17d3e2e289ccbaccafb57ac5c3675\
+                                  1ac5844383c7708077dea41cbefe2fa15724f449e535dd7dd562e66f5ecfb958\
+                                  64eadddec9db5874959905117dad40a4524111849799281fefe3c51fa82785c5\
+                                  ac13171b2f17bc2c74997f0fce1e1f35bec6b91fe2e12dbd323d23ba7a38dfec\
+                                  634b0f5b96109c198a8027da51854c35bee90d1e1c781806d07d49b76de6a28b\
+                                  8d9e9b6c93b9f8b64d16dddd9c5bfb5fea48ee8fd2f75012a8b308605cdd8ba5",
+            export_key: "1ef15b4fa99e8a852412450ab78713aad30d21fa6966c9b8c9fb3262a970dc62\
+                         950d4dd4ed62598229b1b72794fc0335199d9f7fcc6eaedde92cc04870e63f16",
+            ke1: "c4dedb0ba6ed5d965d6f250fbe554cd45cba5dfcce3ce836e4aee778aa3cd44d\
+                  da7e07376d6d6f034cfa9bb537d11b8c6b4238c334333d1f0aebb380cae6a6cc\
+                  cc32409646a51eb5fc77e7979b292ab7bbb4a8c07307cda4a36524f320374b4c",
+            ke2: "7e308140890bcde30cbcea28b01ea1ecfbd077cff62c4def8efa075aabcbb471\
+                  38fe59af0df2c79f57b8780278f5ae47355fe1f817119041951c80f612fdfc6d\
+                  d6ec60bcdb26dc455ddf3e718f1020490c192d70dfc7e403981179d8073d1146\
+                  a4f9aa1ced4e4cd984c657eb3b54ced3848326f70331953d91b02535af44d9fe\
+                  dc80188ca46743c52786e0382f95ad85c08f6afcd1ccfbff95e2bdeb015b166c\
+                  6b20b92f832cc6df01e0b86a7efd92c1c804ff865781fa93f2f20b446c8371b6\
+                  71cd9960ecef2fe0d0f7494986fa3d8b2bb01963537e60efb13981e138e3d4a1\
+                  c4f62198a9d6fa9170c42c3c71f1971b29eb1d5d0bd733e40816c91f7912cc4a\
+                  84b309dda32664dde6c9b77a40d174c789e26fcd8f2a1caeffe9a240e68c6b22\
+                  afdfcafd2ae5b43694f45cb177f4d8b3f7c5efe2d1e7295c57342ae1a3661b1f",
+            ke3: "028ba6d7cf85d779568fa3d0681adeadfab5cdb7170f348b75fdef2db6f283e3\
+                  cc7b502e3accf8711fb40e147e7df4de6d0a30e5beb807bf1b0043934a5cb896",
+            session_key: "816ba5ffe7a9e0e6722037833ca5a721f731dd0e40bb1e8f90fe555f879669fc\
+                          55237002e1f9a1cb35ab1df187f895ec2bfaa8c41609876b907fdc45e2888a3f",
+        });
+    }
+
+    #[test]
+    fn p256_sha256() {
+        // produced with an independent implementation, with identities
+        // and the same nonces and seeds.
+        check::<P256>(&Vectors {
+            context: b"graviola test",
+            identifiers: Identifiers {
+                client: Some(b"alice"),
+                server: Some(b"bob"),
+            },
+            oprf_seed: "62f60b286d20ce4fd1d64809b0021dad6ed5d52a2c8cf27ae6582543a0a8dce2",
+            server_private_key: "47451a85372f8b3537e249d7b54188091fb18edde78094b43e2ba42b5eb89f0d",
+            server_public_key: "0201f950b19c12f5740fe1a1a5c26e9d30f2f7155376a70ddd50c773b607fd85b4",
+            blind_registration: "76cfbfe758db884bebb33582331ba9f159720ca8784a2a070a265d9c2d6abe01",
+            blind_login: "6ecc102d2e7a7cf49617aad7bbe188556792d4acd60a1a8a8d2b65d4b0790308",
+            registration_request: "02e093084130649b921e4b9b7faa744b416dc6fd7c350afa7f6fce7469a5205629",
+            registration_response: "03a04b3ef0a2d80c0c7dcf4c1fbd4843b252f338fa9c49c7871af97a524bbdd64e\
+                                    0201f950b19c12f5740fe1a1a5c26e9d30f2f7155376a70ddd50c773b607fd85b4",
+            registration_record: "031eebe9979742148fc2133ad1dc7a198740e99444dd6ce196be3311827aa70c07\
+                                  7f0ed53532d3ae8e505ecc70d42d2b814b6b0e48156def71ea029148b2803aaf\
+                                  ac13171b2f17bc2c74997f0fce1e1f35bec6b91fe2e12dbd323d23ba7a38dfec\
+                                  72ed1a33607370f34deb32fd3e59232a2dcef481f6c3187f36966194dc38ddd0",
+            export_key: "e2fddcdca6ce593af761c516b95a5343a8232db0c09e07139aad17d972e55c21",
+            ke1: "03a12a0172a4feed2a298e82023db02f9a794ce98dd106b3db9785750a1da09465\
+                  da7e07376d6d6f034cfa9bb537d11b8c6b4238c334333d1f0aebb380cae6a6cc\
+                  03e1681abdab0417cb9334544ab5061ccc79850415c2c211646a32e3c6fdc7e910",
+            ke2: "02528c2034a4c977de6c835cf777befe225463758f0c28101ee949915e09cd8b5f\
+                  38fe59af0df2c79f57b8780278f5ae47355fe1f817119041951c80f612fdfc6d\
+                  2e52edd05dba369ed51ad68514e56296fe1638c385d528512a485e02f9db979d8a\
+                  62ee5bb55d328b826bbb2bc5e6b12ca407cb3bbc55d4e9b73532e0d7d162d89b\
+                  e86b6f942a7232762e0e2fba3e4f6f05e433efbebab6c861b724c2f83ef44af7\
+                  71cd9960ecef2fe0d0f7494986fa3d8b2bb01963537e60efb13981e138e3d4a1\
+                  03c1701353219b53acf337bf6456a83cefed8f563f1040b65afbf3b65d3bc9a19b\
+                  38da3bc16ca15f49ec79a24255a09c1310da286ef988e0ffc57d4391902c78eb",
+            ke3: "bd1e155b3eacea359dab3cfd8d09ad2a24b9ea19fed408592c8bf930d7c0bde3",
+            session_key: "a04efaafc0001887d499da84ec608d8f1ec4682afd885978fd29129f1f86044d",
+        });
+    }
+
+    fn register<C: Ciphersuite>(
+        setup: &ServerSetup<C>,
+        password: &[u8],
+        identifiers: &Identifiers<'_>,
+    ) -> RegistrationRecord<C> {
+        let (client, request) = ClientRegistration::start(password).unwrap();
+        let response = setup.registration_response(&request, b"id").unwrap();
+        let (record, _) = client
+            .finish(password, &response, identifiers, &NoStretching)
+            .unwrap();
+        record
+    }
+
+    fn login<C: Ciphersuite>(
+        setup: &ServerSetup<C>,
+        record: Option<&RegistrationRecord<C>>,
+        password: &[u8],
+        identifiers: &Identifiers<'_>,
+    ) -> Result<(ServerLogin<C>, Ke3<C>), Error> {
+        let ids = Identifiers::default();
+        let (client, ke1) = ClientLogin::start(password).unwrap();
+        let (server, ke2) = ServerLogin::start(setup, record, b"id", &ke1, b"", &ids).unwrap();
+        let (ke3, _, _) = client.finish(password, &ke2, b"", identifiers, &NoStretching)?;
+        Ok((server, ke3))
+    }
+
+    #[test]
+    fn login_failures() {
+        let setup = ServerSetup::<Ristretto255>::new_random().unwrap();
+        let ids = Identifiers::default();
+        let record = register(&setup, b"password", &ids);
+
+        let (server, ke3) = login(&setup, Some(&record), b"password", &ids).unwrap();
+        server.finish(&ke3).unwrap();
+
+        assert!(matches!(
+            login(&setup, Some(&record), b"passw0rd", &ids),
+            Err(Error::DecryptFailed)
+        ));
+        assert!(matches!(
+            login(&setup, None, b"password", &ids),
+            Err(Error::DecryptFailed)
+        ));
+        let other_ids = Identifiers {
+            client: Some(b"mallory"),
+            server: None,
+        };
+        assert!(matches!(
+            login(&setup, Some(&record), b"password", &other_ids),
+            Err(Error::DecryptFailed)
+        ));
+
+        // ... and the server rejects a bad KE3
+        let (server, _) = login(&setup, Some(&record), b"password", &ids).unwrap();
+        let ke3 = Ke3::<Ristretto255>::from_bytes(&[0u8; 64]).unwrap();
+        assert!(matches!(server.finish(&ke3), Err(Error::BadSignature)));
+    }
+
+    #[test]
+    fn tampered_ke2() {
+        let setup = ServerSetup::<P256>::new_random().unwrap();
+        let ids = Identifiers::default();
+        let record = register(&setup, b"password", &ids);
+
+        let (client, ke1) = ClientLogin::start(b"password").unwrap();
+        let (_, ke2) = ServerLogin::start(&setup, Some(&record), b"id", &ke1, b"", &ids).unwrap();
+        let mut buf = [0u8; Ke2::<P256>::LEN];
+        ke2.encode(&mut buf).unwrap();
+        buf[Ke2::<P256>::LEN - 1] ^= 1;
+        let ke2 = Ke2::from_bytes(&buf).unwrap();
+        assert!(matches!(
+            client.finish(b"password", &ke2, b"", &ids, &NoStretching),
+            Err(Error::BadSignature)
+        ));
+    }
+
+    #[test]
+    fn encodings() {
+        assert_eq!(Ke1::<Ristretto255>::LEN, 96);
+        assert_eq!(Ke2::<Ristretto255>::LEN, 320);
+        assert_eq!(Ke3::<Ristretto255>::LEN, 64);
+        assert_eq!(RegistrationRecord::<Ristretto255>::LEN, 192);
+        assert_eq!(Ke1::<P256>::LEN, 98);
+        assert_eq!(Ke2::<P256>::LEN, 259);
+        assert_eq!(Ke3::<P256>::LEN, 32);
+        assert_eq!(RegistrationRecord::<P256>::LEN, 129);
+
+        assert!(Ke1::<Ristretto255>::from_bytes(&[0u8; 95]).is_err());
+        assert!(Ke3::<P256>::from_bytes(&[0u8; 33]).is_err());
+        assert!(
+            ServerSetup::new(
+                &[0u8; 32],
+                PrivateKey::<Ristretto255>::new_random().unwrap()
+            )
+            .is_err()
+        );
+
+        let (_, request) = ClientRegistration::<P256>::start(b"password").unwrap();
+        assert!(matches!(
+            request.encode(&mut [0u8; 32]),
+            Err(Error::WrongLength)
+        ));
+    }
+}
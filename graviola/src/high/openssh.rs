@@ -0,0 +1,951 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! OpenSSH key formats.
+//!
+//! This is the `openssh-key-v1` private key format, described in
+//! [PROTOCOL.key](https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.key),
+//! and built from the wire encodings of
+//! [RFC4251](https://datatracker.ietf.org/doc/html/rfc4251#section-5).
+//!
+//! Encrypted keys use `bcrypt_pbkdf`, with `aes128-ctr`, `aes256-ctr`,
+//! `aes128-gcm@openssh.com` or `aes256-gcm@openssh.com`.  Only files
+//! containing a single key are supported.
+//!
+//! Public keys are in the `public` module.
+
+use super::curve::{Curve, MAX_SCALAR_LEN, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, PrivateKey};
+use super::{ecdsa, pem};
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::low::{AesKey, Entry, zeroise};
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::bcrypt_pbkdf::bcrypt_pbkdf;
+use crate::mid::ed25519;
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+mod public;
+
+pub use public::{AuthorizedKey, KnownHost, KnownHostMarker, SshPublicKey};
+
+/// A private key type with an OpenSSH encoding.
+pub(crate) trait OpenSshKey: Sized {
+    /// The key type name, eg. `ssh-ed25519`.
+    fn key_type() -> String;
+
+    /// Writes the public key fields, which follow the key type.
+    fn encode_public(&self, out: &mut Vec<u8>) -> Result<(), Error>;
+
+    /// Reads the private key fields, which follow the key type.
+    fn decode_private(reader: &mut Reader<'_>) -> Result<Self, Error>;
+
+    /// Writes the private key fields, which follow the key type.
+    fn encode_private(&self, out: &mut Vec<u8>) -> Result<(), Error>;
+}
+
+/// Decodes an `OPENSSH PRIVATE KEY` document from `text`.
+///
+/// The key is decrypted with `passphrase`, which must be given
+/// if and only if the key is encrypted.
+pub(crate) fn decode_private_key<K: OpenSshKey>(
+    text: &str,
+    passphrase: Option<&[u8]>,
+) -> Result<K, Error> {
+    pem::decode_first(text, &[pem::OPENSSH_PRIVATE_KEY], |_, bytes| {
+        decode_private_key_bytes(bytes, passphrase)
+    })
+}
+
+fn decode_private_key_bytes<K: OpenSshKey>(
+    bytes: &[u8],
+    passphrase: Option<&[u8]>,
+) -> Result<K, Error> {
+    let mut file = Reader::new(bytes.strip_prefix(MAGIC).ok_or_else(invalid)?);
+    let cipher = file.string()?;
+    let kdf = file.string()?;
+    let kdf_options = file.string()?;
+    if file.u32()? != 1 {
+        return Err(KeyFormatError::UnsupportedOpenSshKey.into());
+    }
+    let public_key = file.string()?;
+    let section = file.string()?;
+    let tag = file.rest();
+
+    // the key type is public, so is checked without the passphrase
+    if Reader::new(public_key).string()? != K::key_type().as_bytes() {
+        return Err(KeyFormatError::MismatchedOpenSshKeyType.into());
+    }
+
+    let cipher = match cipher {
+        b"none" => None,
+        _ => Some(Cipher::from_name(cipher).ok_or(KeyFormatError::UnsupportedOpenSshKey)?),
+    };
+    if tag.len() != cipher.map_or(0, Cipher::tag_len) {
+        return Err(invalid());
+    }
+
+    let mut section = section.to_vec();
+    let result = match (cipher, passphrase) {
+        (None, None) => match (kdf, kdf_options) {
+            (b"none", b"") => decode_section(&section, NONE_BLOCK_LEN, public_key),
+            _ => Err(invalid()),
+        },
+        (None, Some(_)) => Err(KeyFormatError::UnencryptedOpenSshKey.into()),
+        (Some(_), None) => Err(KeyFormatError::EncryptedOpenSshKey.into()),
+        (Some(cipher), Some(passphrase)) => {
+            if kdf != b"bcrypt" {
+                return Err(KeyFormatError::UnsupportedOpenSshKey.into());
+            }
+            let mut options = Reader::new(kdf_options);
+            let salt = options.string()?;
+            let rounds = options.u32()?;
+            options.finish()?;
+
+            cipher
+                .decrypt(passphrase, salt, rounds, &mut section, tag)
+                .and_then(|()| decode_section(&section, CIPHER_BLOCK_LEN, public_key))
+                .map_err(|e| match e {
+                    // the check values are random, so differ under the wrong passphrase
+                    Error::KeyFormatError(KeyFormatError::InvalidOpenSshKey)
+                        if !cipher.is_authenticated() =>
+                    {
+                        Error::DecryptFailed
+                    }
+                    e => e,
+                })
+        }
+    };
+
+    zeroise(&mut section);
+    result
+}
+
+/// Decodes the (decrypted) private key section, checking it
+/// matches `public_key`.
+fn decode_section<K: OpenSshKey>(
+    section: &[u8],
+    block_len: usize,
+    public_key: &[u8],
+) -> Result<K, Error> {
+    if section.len() % block_len != 0 {
+        return Err(invalid());
+    }
+
+    let mut reader = Reader::new(section);
+    if reader.u32()? != reader.u32()? {
+        return Err(invalid());
+    }
+    if reader.string()? != K::key_type().as_bytes() {
+        return Err(invalid());
+    }
+    let key = K::decode_private(&mut reader)?;
+    let _comment = reader.string()?;
+
+    // padding is 1, 2, 3, ...
+    let padding = reader.rest();
+    if padding.len() >= block_len
+        || padding
+            .iter()
+            .enumerate()
+            .any(|(i, pad)| usize::from(*pad) != i + 1)
+    {
+        return Err(invalid());
+    }
+
+    match encode_public_key(&key)? == public_key {
+        true => Ok(key),
+        false => Err(invalid()),
+    }
+}
+
+/// Encodes `key` as an `OPENSSH PRIVATE KEY` document, with `comment`.
+///
+/// The key is encrypted with `passphrase`, if given, using `aes256-ctr`
+/// and `bcrypt_pbkdf` with a random salt and [`ROUNDS`] rounds.
+pub(crate) fn encode_private_key<K: OpenSshKey>(
+    key: &K,
+    comment: &str,
+    passphrase: Option<&[u8]>,
+) -> Result<String, Error> {
+    encode_private_key_with(
+        key,
+        comment,
+        passphrase.map(|p| (p, Cipher::Aes256Ctr, ROUNDS)),
+    )
+}
+
+fn encode_private_key_with<K: OpenSshKey>(
+    key: &K,
+    comment: &str,
+    encryption: Option<(&[u8], Cipher, u32)>,
+) -> Result<String, Error> {
+    let block_len = match encryption {
+        Some(_) => CIPHER_BLOCK_LEN,
+        None => NONE_BLOCK_LEN,
+    };
+
+    let mut check = [0u8; 4];
+    SystemRandom.fill(&mut check)?;
+    let mut section = Vec::new();
+    section.extend_from_slice(&check);
+    section.extend_from_slice(&check);
+    put_string(&mut section, K::key_type().as_bytes());
+    key.encode_private(&mut section)?;
+    put_string(&mut section, comment.as_bytes());
+    for pad in 1..=u8::MAX {
+        if section.len() % block_len == 0 {
+            break;
+        }
+        section.push(pad);
+    }
+
+    let mut kdf_options = Vec::new();
+    let mut tag = [0u8; 16];
+    let (cipher_name, kdf, tag) = match encryption {
+        Some((passphrase, cipher, rounds)) => {
+            let mut salt = [0u8; SALT_LEN];
+            SystemRandom.fill(&mut salt)?;
+            put_string(&mut kdf_options, &salt);
+            put_u32(&mut kdf_options, rounds);
+            cipher.encrypt(passphrase, &salt, rounds, &mut section, &mut tag)?;
+            (cipher.name(), &b"bcrypt"[..], &tag[..cipher.tag_len()])
+        }
+        None => ("none", &b"none"[..], &tag[..0]),
+    };
+
+    let mut file = MAGIC.to_vec();
+    put_string(&mut file, cipher_name.as_bytes());
+    put_string(&mut file, kdf);
+    put_string(&mut file, &kdf_options);
+    put_u32(&mut file, 1);
+    put_string(&mut file, &encode_public_key(key)?);
+    put_string(&mut file, &section);
+    file.extend_from_slice(tag);
+
+    let text = pem::encode(pem::OPENSSH_PRIVATE_KEY, &file);
+    zeroise(&mut section);
+    zeroise(&mut file);
+    Ok(text)
+}
+
+/// The public key blob: the key type, followed by its public fields.
+fn encode_public_key<K: OpenSshKey>(key: &K) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::new();
+    put_string(&mut out, K::key_type().as_bytes());
+    key.encode_public(&mut out)?;
+    Ok(out)
+}
+
+/// A cipher for encrypting private keys.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Cipher {
+    Aes128Ctr,
+    Aes256Ctr,
+    Aes128Gcm,
+    Aes256Gcm,
+}
+
+impl Cipher {
+    fn from_name(name: &[u8]) -> Option<Self> {
+        [
+            Self::Aes128Ctr,
+            Self::Aes256Ctr,
+            Self::Aes128Gcm,
+            Self::Aes256Gcm,
+        ]
+        .into_iter()
+        .find(|cipher| cipher.name().as_bytes() == name)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Aes128Ctr => "aes128-ctr",
+            Self::Aes256Ctr => "aes256-ctr",
+            Self::Aes128Gcm => "aes128-gcm@openssh.com",
+            Self::Aes256Gcm => "aes256-gcm@openssh.com",
+        }
+    }
+
+    fn key_len(self) -> usize {
+        match self {
+            Self::Aes128Ctr | Self::Aes128Gcm => 16,
+            Self::Aes256Ctr | Self::Aes256Gcm => 32,
+        }
+    }
+
+    fn iv_len(self) -> usize {
+        match self.is_authenticated() {
+            true => 12,
+            false => 16,
+        }
+    }
+
+    fn tag_len(self) -> usize {
+        match self.is_authenticated() {
+            true => 16,
+            false => 0,
+        }
+    }
+
+    fn is_authenticated(self) -> bool {
+        matches!(self, Self::Aes128Gcm | Self::Aes256Gcm)
+    }
+
+    /// Encrypts `section` in place, using a key derived from `passphrase`.
+    ///
+    /// The tag (if any) is written to the start of `tag`.
+    fn encrypt(
+        self,
+        passphrase: &[u8],
+        salt: &[u8],
+        rounds: u32,
+        section: &mut [u8],
+        tag: &mut [u8; 16],
+    ) -> Result<(), Error> {
+        let mut key_iv = [0u8; 32 + 16];
+        let key_iv = self.derive(passphrase, salt, rounds, &mut key_iv)?;
+        let (key, iv) = key_iv.split_at(self.key_len());
+        match self.is_authenticated() {
+            true => AesGcm::new(key).encrypt(iv.try_into().unwrap(), b"", section, tag),
+            false => aes_ctr(key, iv.try_into().unwrap(), section),
+        }
+        zeroise(key_iv);
+        Ok(())
+    }
+
+    /// Decrypts `section` in place, using a key derived from `passphrase`.
+    fn decrypt(
+        self,
+        passphrase: &[u8],
+        salt: &[u8],
+        rounds: u32,
+        section: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        let mut key_iv = [0u8; 32 + 16];
+        let key_iv = self.derive(passphrase, salt, rounds, &mut key_iv)?;
+        let (key, iv) = key_iv.split_at(self.key_len());
+        let result = match self.is_authenticated() {
+            true => AesGcm::new(key).decrypt(iv.try_into().unwrap(), b"", section, tag),
+            false => {
+                aes_ctr(key, iv.try_into().unwrap(), section);
+                Ok(())
+            }
+        };
+        zeroise(key_iv);
+        result
+    }
+
+    fn derive<'a>(
+        self,
+        passphrase: &[u8],
+        salt: &[u8],
+        rounds: u32,
+        key_iv: &'a mut [u8; 48],
+    ) -> Result<&'a mut [u8], Error> {
+        // OpenSSH limits these, and so do we
+        if !(1..=MAX_ROUNDS).contains(&rounds) || salt.is_empty() || salt.len() > MAX_SALT_LEN {
+            return Err(KeyFormatError::UnsupportedOpenSshKey.into());
+        }
+        let key_iv = &mut key_iv[..self.key_len() + self.iv_len()];
+        bcrypt_pbkdf(passphrase, salt, rounds, key_iv)?;
+        Ok(key_iv)
+    }
+}
+
+/// AES in counter mode, with a 128-bit big-endian counter.
+fn aes_ctr(key: &[u8], iv: &[u8; 16], inout: &mut [u8]) {
+    let key = AesKey::new(key);
+    let mut counter = u128::from_be_bytes(*iv);
+    let mut block = [0u8; 16];
+
+    for chunk in inout.chunks_mut(16) {
+        block = counter.to_be_bytes();
+        key.encrypt_block(&mut block);
+        for (x, k) in chunk.iter_mut().zip(block.iter()) {
+            *x ^= *k;
+        }
+        counter = counter.wrapping_add(1);
+    }
+    zeroise(&mut block);
+}
+
+/// Reads the RFC4251 wire encodings.
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn string(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.u32()?;
+        self.take(len as usize)
+    }
+
+    /// A non-negative `mpint`, without its sign byte (if any).
+    ///
+    /// The encoding must be minimal.
+    pub(crate) fn mpint(&mut self) -> Result<&'a [u8], Error> {
+        let bytes = self.string()?;
+        match bytes {
+            [] => Ok(bytes),
+            [0x00, rest @ ..] if rest.first().is_some_and(|b| b & 0x80 != 0) => Ok(rest),
+            [first, ..] if *first != 0x00 && first & 0x80 == 0 => Ok(bytes),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// The remaining bytes.
+    pub(crate) fn rest(&mut self) -> &'a [u8] {
+        core::mem::take(&mut self.bytes)
+    }
+
+    /// Checks all the input has been read.
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        match self.bytes.is_empty() {
+            true => Ok(()),
+            false => Err(invalid()),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if len > self.bytes.len() {
+            return Err(invalid());
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+}
+
+pub(crate) fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+pub(crate) fn put_string(out: &mut Vec<u8>, bytes: &[u8]) {
+    put_u32(out, bytes.len() as u32);
+    out.extend_from_slice(bytes);
+}
+
+/// Writes the non-negative big-endian integer `magnitude` as an `mpint`.
+pub(crate) fn put_mpint(out: &mut Vec<u8>, magnitude: &[u8]) {
+    let start = magnitude
+        .iter()
+        .position(|b| *b != 0x00)
+        .unwrap_or(magnitude.len());
+    let magnitude = &magnitude[start..];
+    match magnitude.first() {
+        Some(first) if first & 0x80 != 0 => {
+            put_u32(out, magnitude.len() as u32 + 1);
+            out.push(0x00);
+            out.extend_from_slice(magnitude);
+        }
+        _ => put_string(out, magnitude),
+    }
+}
+
+pub(crate) fn invalid() -> Error {
+    KeyFormatError::InvalidOpenSshKey.into()
+}
+
+impl OpenSshKey for ed25519::SigningKey {
+    fn key_type() -> String {
+        "ssh-ed25519".to_string()
+    }
+
+    fn encode_public(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_string(out, self.verifying_key().as_bytes());
+        Ok(())
+    }
+
+    fn decode_private(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        let public_key = reader.string()?;
+        // the private key is the seed, followed by the public key
+        let private_key = reader.string()?;
+        if private_key.len() < ed25519::SEED_LEN {
+            return Err(invalid());
+        }
+        let (seed, copy) = private_key.split_at(ed25519::SEED_LEN);
+
+        let mut seed_array = [0u8; ed25519::SEED_LEN];
+        seed_array.copy_from_slice(seed);
+        let key = Self::from_seed(&seed_array);
+        zeroise(&mut seed_array);
+
+        let actual = key.verifying_key();
+        match actual.as_bytes() == public_key && actual.as_bytes() == copy {
+            true => Ok(key),
+            false => Err(invalid()),
+        }
+    }
+
+    fn encode_private(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        let public_key = self.verifying_key();
+        put_string(out, public_key.as_bytes());
+
+        let mut private_key = [0u8; ed25519::SEED_LEN + ed25519::VERIFYING_KEY_LEN];
+        private_key[..ed25519::SEED_LEN].copy_from_slice(&self.seed());
+        private_key[ed25519::SEED_LEN..].copy_from_slice(public_key.as_bytes());
+        put_string(out, &private_key);
+        zeroise(&mut private_key);
+        Ok(())
+    }
+}
+
+impl ed25519::SigningKey {
+    /// Decodes an Ed25519 private key from OpenSSH format.
+    ///
+    /// This is the `OPENSSH PRIVATE KEY` format written by `ssh-keygen`.
+    /// The key must not be encrypted: see [`Self::from_encrypted_openssh()`].
+    /// Its comment is ignored.
+    pub fn from_openssh(text: &str) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        decode_private_key(text, None)
+    }
+
+    /// Decodes an encrypted Ed25519 private key from OpenSSH format.
+    ///
+    /// The key is decrypted using `passphrase`; a wrong passphrase gives
+    /// [`Error::DecryptFailed`].  Otherwise, this is as
+    /// [`Self::from_openssh()`].
+    pub fn from_encrypted_openssh(text: &str, passphrase: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        decode_private_key(text, Some(passphrase))
+    }
+
+    /// Encodes an Ed25519 private key to OpenSSH format, with `comment`.
+    pub fn to_openssh(&self, comment: &str) -> Result<String, Error> {
+        let _entry = Entry::new_secret();
+        encode_private_key(self, comment, None)
+    }
+
+    /// Encodes an Ed25519 private key to encrypted OpenSSH format, with
+    /// `comment`.
+    ///
+    /// As with `ssh-keygen`, the key is encrypted with `aes256-ctr`, using
+    /// a key derived from `passphrase` by `bcrypt_pbkdf` with a random
+    /// salt and 16 rounds.
+    pub fn to_encrypted_openssh(&self, comment: &str, passphrase: &[u8]) -> Result<String, Error> {
+        let _entry = Entry::new_secret();
+        encode_private_key(self, comment, Some(passphrase))
+    }
+}
+
+impl<C: Curve> OpenSshKey for ecdsa::SigningKey<C> {
+    fn key_type() -> String {
+        format!("ecdsa-sha2-{}", C::ssh_curve())
+    }
+
+    fn encode_public(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+        put_string(out, C::ssh_curve().as_bytes());
+        put_string(
+            out,
+            self.private_key
+                .public_key_encode_uncompressed(&mut point)?,
+        );
+        Ok(())
+    }
+
+    fn decode_private(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        if reader.string()? != C::ssh_curve().as_bytes() {
+            return Err(invalid());
+        }
+        // checked against the private key, via the public key blob
+        let _public_key = reader.string()?;
+        Ok(Self {
+            private_key: C::PrivateKey::from_bytes(reader.mpint()?)?,
+        })
+    }
+
+    fn encode_private(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        self.encode_public(out)?;
+        let mut scalar = [0u8; MAX_SCALAR_LEN];
+        let result = self
+            .private_key
+            .encode(&mut scalar)
+            .map(|encoded| put_mpint(out, encoded));
+        zeroise(&mut scalar);
+        result
+    }
+}
+
+impl<C: Curve> ecdsa::SigningKey<C> {
+    /// Decodes an ECDSA private key from OpenSSH format.
+    ///
+    /// This is the `OPENSSH PRIVATE KEY` format written by `ssh-keygen`.
+    /// The key must not be encrypted: see [`Self::from_encrypted_openssh()`].
+    /// Its comment is ignored.
+    pub fn from_openssh(text: &str) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        decode_private_key(text, None)
+    }
+
+    /// Decodes an encrypted ECDSA private key from OpenSSH format.
+    ///
+    /// The key is decrypted using `passphrase`; a wrong passphrase gives
+    /// [`Error::DecryptFailed`].  Otherwise, this is as
+    /// [`Self::from_openssh()`].
+    pub fn from_encrypted_openssh(text: &str, passphrase: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        decode_private_key(text, Some(passphrase))
+    }
+
+    /// Encodes an ECDSA private key to OpenSSH format, with `comment`.
+    pub fn to_openssh(&self, comment: &str) -> Result<String, Error> {
+        let _entry = Entry::new_secret();
+        encode_private_key(self, comment, None)
+    }
+
+    /// Encodes an ECDSA private key to encrypted OpenSSH format, with
+    /// `comment`.
+    ///
+    /// As with `ssh-keygen`, the key is encrypted with `aes256-ctr`, using
+    /// a key derived from `passphrase` by `bcrypt_pbkdf` with a random
+    /// salt and 16 rounds.
+    pub fn to_encrypted_openssh(&self, comment: &str, passphrase: &[u8]) -> Result<String, Error> {
+        let _entry = Entry::new_secret();
+        encode_private_key(self, comment, Some(passphrase))
+    }
+}
+
+const MAGIC: &[u8] = b"openssh-key-v1\0";
+
+/// The padding block length for unencrypted keys.
+const NONE_BLOCK_LEN: usize = 8;
+
+/// The padding block length for encrypted keys: the AES block size.
+const CIPHER_BLOCK_LEN: usize = 16;
+
+/// The default `bcrypt_pbkdf` rounds, as used by `ssh-keygen`.
+const ROUNDS: u32 = 16;
+
+const SALT_LEN: usize = 16;
+
+const MAX_ROUNDS: u32 = 1 << 16;
+
+const MAX_SALT_LEN: usize = 1 << 20;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::curve::{P256, P384};
+    use crate::high::rsa;
+
+    const PASSPHRASE: &[u8] = b"graviola";
+
+    #[test]
+    fn ssh_keygen() {
+        // produced by `ssh-keygen -t <type> [-N graviola -a 4 -Z <cipher>]`
+        let ed25519 =
+            ed25519::SigningKey::from_openssh(include_str!("openssh/ed25519.key")).unwrap();
+        for text in [
+            include_str!("openssh/ed25519-aes256-ctr.key"),
+            include_str!("openssh/ed25519-aes256-gcm.key"),
+        ] {
+            let key = ed25519::SigningKey::from_encrypted_openssh(text, PASSPHRASE).unwrap();
+            check_ed25519(&key);
+        }
+        check_ed25519(&ed25519);
+
+        let p256 =
+            ecdsa::SigningKey::<P256>::from_openssh(include_str!("openssh/p256.key")).unwrap();
+        check_ecdsa(&p256);
+        let p256 = ecdsa::SigningKey::<P256>::from_encrypted_openssh(
+            include_str!("openssh/p256-aes128-ctr.key"),
+            PASSPHRASE,
+        )
+        .unwrap();
+        check_ecdsa(&p256);
+        let p384 =
+            ecdsa::SigningKey::<P384>::from_openssh(include_str!("openssh/p384.key")).unwrap();
+        check_ecdsa(&p384);
+
+        let rsa = rsa::SigningKey::from_openssh(include_str!("openssh/rsa2048.key")).unwrap();
+        check_rsa(&rsa);
+        let rsa = rsa::SigningKey::from_encrypted_openssh(
+            include_str!("openssh/rsa2048-aes128-gcm.key"),
+            PASSPHRASE,
+        )
+        .unwrap();
+        check_rsa(&rsa);
+    }
+
+    fn check_ed25519(key: &ed25519::SigningKey) {
+        let signature = key.sign(&[b"hello"]);
+        key.verifying_key().verify(&[b"hello"], &signature).unwrap();
+    }
+
+    fn check_ecdsa<C: Curve>(key: &ecdsa::SigningKey<C>) {
+        let mut signature = [0u8; 96];
+        let signature = key
+            .sign::<crate::high::hash::Sha256>(&[b"hello"], &mut signature)
+            .unwrap();
+        let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+        ecdsa::VerifyingKey::<C>::from_x962_uncompressed(
+            key.private_key
+                .public_key_encode_uncompressed(&mut point)
+                .unwrap(),
+        )
+        .unwrap()
+        .verify::<crate::high::hash::Sha256>(&[b"hello"], signature)
+        .unwrap();
+    }
+
+    fn check_rsa(key: &rsa::SigningKey) {
+        let mut signature = [0u8; 256];
+        let signature = key.sign_pkcs1_sha256(&mut signature, b"hello").unwrap();
+        key.public_key()
+            .verify_pkcs1_sha256(signature, b"hello")
+            .unwrap();
+
+        // the CRT exponents were computed, so check them against the
+        // PKCS#1 encoding, which includes them
+        let mut der = [0u8; 2048];
+        let der = key.to_pkcs1_der(&mut der).unwrap();
+        let reloaded = rsa::SigningKey::from_pkcs1_der(der).unwrap();
+        let mut signature2 = [0u8; 256];
+        assert_eq!(
+            reloaded
+                .sign_pkcs1_sha256(&mut signature2, b"hello")
+                .unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = include_str!("openssh/ed25519.key");
+        let key = ed25519::SigningKey::from_openssh(text).unwrap();
+        // the check values are random, so the encoding differs
+        let encoded = key.to_openssh("ed25519 test").unwrap();
+        assert_eq!(
+            ed25519::SigningKey::from_openssh(&encoded).unwrap().seed(),
+            key.seed()
+        );
+
+        let encoded = key.to_encrypted_openssh("", PASSPHRASE).unwrap();
+        let decoded = ed25519::SigningKey::from_encrypted_openssh(&encoded, PASSPHRASE).unwrap();
+        assert_eq!(decoded.seed(), key.seed());
+
+        for cipher in [
+            Cipher::Aes128Ctr,
+            Cipher::Aes256Ctr,
+            Cipher::Aes128Gcm,
+            Cipher::Aes256Gcm,
+        ] {
+            let encoded = encode_private_key_with(&key, "", Some((PASSPHRASE, cipher, 1))).unwrap();
+            let decoded =
+                ed25519::SigningKey::from_encrypted_openssh(&encoded, PASSPHRASE).unwrap();
+            assert_eq!(decoded.seed(), key.seed());
+            assert_eq!(
+                ed25519::SigningKey::from_encrypted_openssh(&encoded, b"wrong").err(),
+                Some(Error::DecryptFailed)
+            );
+        }
+
+        let key =
+            ecdsa::SigningKey::<P256>::from_openssh(include_str!("openssh/p256.key")).unwrap();
+        let decoded =
+            ecdsa::SigningKey::<P256>::from_openssh(&key.to_openssh("comment").unwrap()).unwrap();
+        assert_eq!(decoded.to_jwk().unwrap(), key.to_jwk().unwrap());
+
+        let key =
+            ecdsa::SigningKey::<P384>::from_openssh(include_str!("openssh/p384.key")).unwrap();
+        let encoded =
+            encode_private_key_with(&key, "", Some((PASSPHRASE, Cipher::Aes256Gcm, 1))).unwrap();
+        let decoded =
+            ecdsa::SigningKey::<P384>::from_encrypted_openssh(&encoded, PASSPHRASE).unwrap();
+        assert_eq!(decoded.to_jwk().unwrap(), key.to_jwk().unwrap());
+
+        let key = rsa::SigningKey::from_openssh(include_str!("openssh/rsa2048.key")).unwrap();
+        let decoded = rsa::SigningKey::from_openssh(&key.to_openssh("rsa").unwrap()).unwrap();
+        assert_eq!(decoded.to_jwk().unwrap(), key.to_jwk().unwrap());
+    }
+
+    #[test]
+    fn mismatched() {
+        let ed25519 = include_str!("openssh/ed25519.key");
+        let encrypted = include_str!("openssh/ed25519-aes256-ctr.key");
+        assert_eq!(
+            ed25519::SigningKey::from_openssh(encrypted).err(),
+            Some(KeyFormatError::EncryptedOpenSshKey.into())
+        );
+        assert_eq!(
+            ed25519::SigningKey::from_encrypted_openssh(ed25519, PASSPHRASE).err(),
+            Some(KeyFormatError::UnencryptedOpenSshKey.into())
+        );
+        assert_eq!(
+            ed25519::SigningKey::from_encrypted_openssh(encrypted, b"wrong").err(),
+            Some(Error::DecryptFailed)
+        );
+        assert_eq!(
+            ed25519::SigningKey::from_encrypted_openssh(
+                include_str!("openssh/ed25519-aes256-gcm.key"),
+                b"wrong"
+            )
+            .err(),
+            Some(Error::DecryptFailed)
+        );
+
+        // the key type is checked before decryption
+        assert_eq!(
+            ecdsa::SigningKey::<P256>::from_openssh(encrypted).err(),
+            Some(KeyFormatError::MismatchedOpenSshKeyType.into())
+        );
+        assert_eq!(
+            ecdsa::SigningKey::<P384>::from_openssh(include_str!("openssh/p256.key")).err(),
+            Some(KeyFormatError::MismatchedOpenSshKeyType.into())
+        );
+        assert_eq!(
+            rsa::SigningKey::from_openssh(ed25519).err(),
+            Some(KeyFormatError::MismatchedOpenSshKeyType.into())
+        );
+        assert_eq!(
+            ed25519::SigningKey::from_openssh(include_str!("openssh/rsa2048.key")).err(),
+            Some(KeyFormatError::MismatchedOpenSshKeyType.into())
+        );
+
+        assert_eq!(
+            ed25519::SigningKey::from_openssh(&ed25519.replace("OPENSSH", "RSA")).err(),
+            Some(KeyFormatError::MismatchedPemLabel.into())
+        );
+    }
+
+    #[test]
+    fn invalid_keys() {
+        let key = ed25519::SigningKey::from_seed(&[0x11; 32]);
+        let public_key = encode_public_key(&key).unwrap();
+        let mut other_public_key = Vec::new();
+        put_string(&mut other_public_key, b"ssh-ed25519");
+        put_string(&mut other_public_key, &[0x22; 32]);
+
+        let section = |check: u32, padding: &[u8]| {
+            let mut section = Vec::new();
+            put_u32(&mut section, 0x1234);
+            put_u32(&mut section, check);
+            put_string(&mut section, b"ssh-ed25519");
+            key.encode_private(&mut section).unwrap();
+            put_string(&mut section, b"");
+            section.extend_from_slice(padding);
+            section
+        };
+        let file = |cipher: &[u8], count: u32, public_key: &[u8], section: &[u8]| {
+            let mut file = MAGIC.to_vec();
+            put_string(&mut file, cipher);
+            put_string(&mut file, b"none");
+            put_string(&mut file, b"");
+            put_u32(&mut file, count);
+            put_string(&mut file, public_key);
+            put_string(&mut file, section);
+            file
+        };
+
+        // baseline: the section is 8 + 15 + 36 + 68 + 4 = 131 bytes
+        let good = file(b"none", 1, &public_key, &section(0x1234, &[1, 2, 3, 4, 5]));
+        assert_eq!(
+            decode_private_key_bytes::<ed25519::SigningKey>(&good, None)
+                .unwrap()
+                .seed(),
+            key.seed()
+        );
+
+        for (bytes, error) in [
+            (&good[1..], KeyFormatError::InvalidOpenSshKey),
+            (&good[..good.len() - 1], KeyFormatError::InvalidOpenSshKey),
+            (
+                &[&good[..], &[0]].concat(),
+                KeyFormatError::InvalidOpenSshKey,
+            ),
+            (
+                &file(b"none", 2, &public_key, &section(0x1234, &[1, 2, 3, 4, 5])),
+                KeyFormatError::UnsupportedOpenSshKey,
+            ),
+            (
+                &file(
+                    b"3des-cbc",
+                    1,
+                    &public_key,
+                    &section(0x1234, &[1, 2, 3, 4, 5]),
+                ),
+                KeyFormatError::UnsupportedOpenSshKey,
+            ),
+            // mismatched check values
+            (
+                &file(b"none", 1, &public_key, &section(0x1235, &[1, 2, 3, 4, 5])),
+                KeyFormatError::InvalidOpenSshKey,
+            ),
+            // bad padding
+            (
+                &file(b"none", 1, &public_key, &section(0x1234, &[1, 2, 3, 4, 6])),
+                KeyFormatError::InvalidOpenSshKey,
+            ),
+            (
+                &file(b"none", 1, &public_key, &section(0x1234, &[1, 2, 3, 4])),
+                KeyFormatError::InvalidOpenSshKey,
+            ),
+            (
+                &file(
+                    b"none",
+                    1,
+                    &public_key,
+                    &section(0x1234, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]),
+                ),
+                KeyFormatError::InvalidOpenSshKey,
+            ),
+            // public key does not match
+            (
+                &file(
+                    b"none",
+                    1,
+                    &other_public_key,
+                    &section(0x1234, &[1, 2, 3, 4, 5]),
+                ),
+                KeyFormatError::InvalidOpenSshKey,
+            ),
+        ] {
+            assert_eq!(
+                decode_private_key_bytes::<ed25519::SigningKey>(bytes, None).err(),
+                Some(error.into())
+            );
+        }
+    }
+
+    #[test]
+    fn wire_encodings() {
+        for (magnitude, encoding) in [
+            (&[][..], &[0, 0, 0, 0][..]),
+            (&[0x00], &[0, 0, 0, 0]),
+            (&[0x00, 0x7f], &[0, 0, 0, 1, 0x7f]),
+            (&[0x80], &[0, 0, 0, 2, 0x00, 0x80]),
+            (&[0x01, 0x00], &[0, 0, 0, 2, 0x01, 0x00]),
+        ] {
+            let mut out = Vec::new();
+            put_mpint(&mut out, magnitude);
+            assert_eq!(out, encoding);
+
+            let mut reader = Reader::new(encoding);
+            let decoded = reader.mpint().unwrap();
+            reader.finish().unwrap();
+            assert_eq!(decoded, magnitude.strip_prefix(&[0]).unwrap_or(magnitude));
+        }
+
+        for encoding in [
+            &[0, 0, 0, 1, 0x00][..],
+            &[0, 0, 0, 2, 0x00, 0x7f],
+            &[0, 0, 0, 1, 0x80],
+            &[0, 0, 0, 2, 0xff, 0xff],
+            &[0, 0, 0, 2, 0x01],
+            &[0, 0, 0],
+        ] {
+            assert_eq!(Reader::new(encoding).mpint().err(), Some(invalid()));
+        }
+    }
+}
@@ -0,0 +1,701 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! OpenSSH public keys, signatures, `authorized_keys` and `known_hosts`.
+//!
+//! Public keys and signatures use the wire encodings of
+//! [RFC4253](https://datatracker.ietf.org/doc/html/rfc4253#section-6.6),
+//! [RFC5656](https://datatracker.ietf.org/doc/html/rfc5656#section-3),
+//! [RFC8332](https://datatracker.ietf.org/doc/html/rfc8332) and
+//! [RFC8709](https://datatracker.ietf.org/doc/html/rfc8709).  The line
+//! formats are described in `sshd(8)`.
+
+use super::{Reader, invalid, put_string};
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::high::base64;
+use crate::high::curve::{
+    Curve, MAX_SCALAR_LEN, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, P256, P384, PublicKey, Scalar,
+};
+use crate::high::ecdsa::{self, write_fixed};
+use crate::high::hash::{Hash, Sha256, Sha384};
+use crate::high::rsa;
+use crate::low::Entry;
+use crate::mid::ed25519;
+
+/// An SSH public key.
+// the RSA key is much larger, but boxing it would complicate construction
+#[allow(clippy::large_enum_variant)]
+pub enum SshPublicKey {
+    /// An `ssh-ed25519` key.
+    Ed25519(ed25519::VerifyingKey),
+    /// An `ecdsa-sha2-nistp256` key.
+    EcdsaP256(ecdsa::VerifyingKey<P256>),
+    /// An `ecdsa-sha2-nistp384` key.
+    EcdsaP384(ecdsa::VerifyingKey<P384>),
+    /// An `ssh-rsa` key.
+    Rsa(rsa::VerifyingKey),
+}
+
+impl SshPublicKey {
+    /// Decodes a public key blob.
+    ///
+    /// This is the binary encoding used in the SSH protocol, and
+    /// (in base64) in `authorized_keys` and `known_hosts` files.
+    pub fn from_blob(blob: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        let mut reader = Reader::new(blob);
+        let key = match reader.string()? {
+            ED25519 => Self::Ed25519(
+                ed25519::VerifyingKey::from_bytes(reader.string()?).map_err(|_| invalid())?,
+            ),
+            ECDSA_P256 => Self::EcdsaP256(decode_ecdsa(&mut reader)?),
+            ECDSA_P384 => Self::EcdsaP384(decode_ecdsa(&mut reader)?),
+            RSA => Self::Rsa(rsa::VerifyingKey::decode_openssh(&mut reader)?),
+            _ => return Err(KeyFormatError::UnsupportedOpenSshKey.into()),
+        };
+        reader.finish()?;
+        Ok(key)
+    }
+
+    /// Encodes a public key blob.
+    pub fn to_blob(&self) -> Result<Vec<u8>, Error> {
+        let _entry = Entry::new_public();
+        let mut out = Vec::new();
+        put_string(&mut out, self.key_type().as_bytes());
+        match self {
+            Self::Ed25519(key) => put_string(&mut out, key.as_bytes()),
+            Self::EcdsaP256(key) => encode_ecdsa(&mut out, key)?,
+            Self::EcdsaP384(key) => encode_ecdsa(&mut out, key)?,
+            Self::Rsa(key) => key.encode_openssh(&mut out)?,
+        }
+        Ok(out)
+    }
+
+    /// The key type name, eg. `ssh-ed25519`.
+    pub fn key_type(&self) -> &'static str {
+        match self {
+            Self::Ed25519(_) => "ssh-ed25519",
+            Self::EcdsaP256(_) => "ecdsa-sha2-nistp256",
+            Self::EcdsaP384(_) => "ecdsa-sha2-nistp384",
+            Self::Rsa(_) => "ssh-rsa",
+        }
+    }
+
+    /// Verifies an SSH signature blob over `message`.
+    ///
+    /// `signature` is the signature algorithm name, followed by the
+    /// signature.  The algorithm must match this key: for RSA keys,
+    /// `rsa-sha2-256` and `rsa-sha2-512` are supported, but the SHA-1
+    /// based `ssh-rsa` is not.
+    ///
+    /// All failures give [`Error::BadSignature`].
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        let _entry = Entry::new_public();
+        let mut reader = Reader::new(signature);
+        let (algorithm, signature) = match (reader.string(), reader.string()) {
+            (Ok(algorithm), Ok(signature)) if reader.finish().is_ok() => (algorithm, signature),
+            _ => return Err(Error::BadSignature),
+        };
+
+        match (self, algorithm) {
+            (Self::Ed25519(key), ED25519) => key.verify(&[message], signature),
+            (Self::EcdsaP256(key), ECDSA_P256) => {
+                verify_ecdsa::<_, Sha256>(key, message, signature)
+            }
+            (Self::EcdsaP384(key), ECDSA_P384) => {
+                verify_ecdsa::<_, Sha384>(key, message, signature)
+            }
+            (Self::Rsa(key), b"rsa-sha2-256") => {
+                verify_rsa(key, signature, |s| key.verify_pkcs1_sha256(s, message))
+            }
+            (Self::Rsa(key), b"rsa-sha2-512") => {
+                verify_rsa(key, signature, |s| key.verify_pkcs1_sha512(s, message))
+            }
+            _ => Err(Error::BadSignature),
+        }
+        .map_err(|_| Error::BadSignature)
+    }
+}
+
+fn decode_ecdsa<C: Curve>(reader: &mut Reader<'_>) -> Result<ecdsa::VerifyingKey<C>, Error> {
+    if reader.string()? != C::ssh_curve().as_bytes() {
+        return Err(invalid());
+    }
+    ecdsa::VerifyingKey::from_x962_uncompressed(reader.string()?).map_err(|_| invalid())
+}
+
+fn encode_ecdsa<C: Curve>(out: &mut Vec<u8>, key: &ecdsa::VerifyingKey<C>) -> Result<(), Error> {
+    let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    put_string(out, C::ssh_curve().as_bytes());
+    put_string(out, key.public_key.encode_uncompressed(&mut point)?);
+    Ok(())
+}
+
+/// ECDSA signatures are `mpint r`, `mpint s`.
+fn verify_ecdsa<C: Curve, H: Hash>(
+    key: &ecdsa::VerifyingKey<C>,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(), Error> {
+    let mut reader = Reader::new(signature);
+    let (r, s) = (reader.mpint()?, reader.mpint()?);
+    reader.finish()?;
+
+    let fixed = &mut [0u8; MAX_SCALAR_LEN * 2][..C::Scalar::LEN_BYTES * 2];
+    write_fixed(&mut fixed[..C::Scalar::LEN_BYTES], r)?;
+    write_fixed(&mut fixed[C::Scalar::LEN_BYTES..], s)?;
+    key.verify::<H>(&[message], fixed)
+}
+
+/// RSA signatures may be shorter than the modulus (as if leading zero
+/// bytes were removed), but not longer.  This is what OpenSSH does.
+fn verify_rsa(
+    key: &rsa::VerifyingKey,
+    signature: &[u8],
+    verify: impl FnOnce(&[u8]) -> Result<(), Error>,
+) -> Result<(), Error> {
+    match signature.len() > key.modulus_len_bytes() {
+        true => Err(Error::BadSignature),
+        false => verify(signature),
+    }
+}
+
+/// An entry in an `authorized_keys` file.
+///
+/// This is also the format of an OpenSSH `.pub` file, which has no
+/// options.
+pub struct AuthorizedKey<'a> {
+    /// The options, eg. `no-pty,from="10.0.0.0/8"`, if any.
+    ///
+    /// These are not interpreted.
+    pub options: Option<&'a str>,
+
+    /// The public key.
+    pub key: SshPublicKey,
+
+    /// The comment, which may be empty.
+    pub comment: &'a str,
+}
+
+impl<'a> AuthorizedKey<'a> {
+    /// Parses an `authorized_keys` line.
+    ///
+    /// Blank lines and comments (starting with `#`) are not entries, and
+    /// are rejected.  The key type named in the line must match the key.
+    pub fn parse(line: &'a str) -> Result<Self, Error> {
+        let (first, rest) = split_field(line.trim())?;
+        let (options, rest) = match is_key_type(first) {
+            true => (None, line.trim()),
+            false => (Some(first), rest),
+        };
+        let (key, comment) = parse_key(rest)?;
+        Ok(Self {
+            options,
+            key,
+            comment,
+        })
+    }
+
+    /// Encodes this entry as an `authorized_keys` line, without a line ending.
+    pub fn encode(&self) -> Result<String, Error> {
+        Ok(join_fields(&[
+            self.options.unwrap_or_default(),
+            self.key.key_type(),
+            &base64::encode(&self.key.to_blob()?),
+            self.comment,
+        ]))
+    }
+}
+
+/// An entry in a `known_hosts` file.
+pub struct KnownHost<'a> {
+    /// The marker, if any.
+    pub marker: Option<KnownHostMarker>,
+
+    /// The comma-separated host name patterns.
+    ///
+    /// Hosts on a port other than 22 are written `[host]:port`.
+    pub hosts: &'a str,
+
+    /// The host's public key.
+    pub key: SshPublicKey,
+
+    /// The comment, which may be empty.
+    pub comment: &'a str,
+}
+
+impl<'a> KnownHost<'a> {
+    /// Parses a `known_hosts` line.
+    ///
+    /// Blank lines and comments (starting with `#`) are not entries, and
+    /// are rejected.  The key type named in the line must match the key.
+    pub fn parse(line: &'a str) -> Result<Self, Error> {
+        let (first, rest) = split_field(line.trim())?;
+        let (marker, hosts, rest) = match first.strip_prefix('@') {
+            Some(marker) => {
+                let marker = KnownHostMarker::from_name(marker).ok_or_else(invalid)?;
+                let (hosts, rest) = split_field(rest)?;
+                (Some(marker), hosts, rest)
+            }
+            None => (None, first, rest),
+        };
+        let (key, comment) = parse_key(rest)?;
+        Ok(Self {
+            marker,
+            hosts,
+            key,
+            comment,
+        })
+    }
+
+    /// Encodes this entry as a `known_hosts` line, without a line ending.
+    pub fn encode(&self) -> Result<String, Error> {
+        let marker = self.marker.map(KnownHostMarker::name);
+        Ok(join_fields(&[
+            marker.unwrap_or_default(),
+            self.hosts,
+            self.key.key_type(),
+            &base64::encode(&self.key.to_blob()?),
+            self.comment,
+        ]))
+    }
+
+    /// Returns true if `host` matches this entry's host name patterns.
+    ///
+    /// Patterns may use the `*` and `?` wildcards, and are negated by
+    /// a leading `!`: `host` matches if it matches any pattern, and no
+    /// negated ones.  Matching is case-insensitive.
+    ///
+    /// Hashed host names (`|1|...`) use HMAC-SHA1, which this crate
+    /// does not support, so never match.
+    pub fn matches(&self, host: &str) -> bool {
+        let mut matched = false;
+        for pattern in self.hosts.split(',') {
+            let (negated, pattern) = match pattern.strip_prefix('!') {
+                Some(pattern) => (true, pattern),
+                None => (false, pattern),
+            };
+            if !pattern.starts_with('|') && wildcard_match(pattern.as_bytes(), host.as_bytes()) {
+                if negated {
+                    return false;
+                }
+                matched = true;
+            }
+        }
+        matched
+    }
+}
+
+/// A marker on a `known_hosts` entry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KnownHostMarker {
+    /// `@cert-authority`: the key is a certificate authority for the hosts.
+    CertAuthority,
+    /// `@revoked`: the key is revoked, and must not be accepted.
+    Revoked,
+}
+
+impl KnownHostMarker {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "cert-authority" => Some(Self::CertAuthority),
+            "revoked" => Some(Self::Revoked),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::CertAuthority => "@cert-authority",
+            Self::Revoked => "@revoked",
+        }
+    }
+}
+
+/// Parses `keytype base64 [comment]`.
+fn parse_key(text: &str) -> Result<(SshPublicKey, &str), Error> {
+    let (key_type, rest) = split_field(text)?;
+    let (blob, comment) = split_field(rest)?;
+    if !is_key_type(key_type) {
+        return Err(KeyFormatError::UnsupportedOpenSshKey.into());
+    }
+
+    let key = SshPublicKey::from_blob(&base64::decode(blob).ok_or_else(invalid)?)?;
+    match key.key_type() == key_type {
+        true => Ok((key, comment)),
+        false => Err(invalid()),
+    }
+}
+
+/// Splits off the first whitespace-separated field of `text`.
+///
+/// Whitespace within double quotes (as used in `authorized_keys` options)
+/// does not end the field.
+fn split_field(text: &str) -> Result<(&str, &str), Error> {
+    let mut quoted = false;
+    let mut escaped = false;
+    let end = text
+        .char_indices()
+        .find(|(_, c)| {
+            match (quoted, escaped, c) {
+                (true, false, '\\') => escaped = true,
+                (_, false, '"') => quoted = !quoted,
+                (false, _, ' ' | '\t') => return true,
+                _ => escaped = false,
+            }
+            false
+        })
+        .map_or(text.len(), |(i, _)| i);
+
+    match (&text[..end], quoted) {
+        ("", _) | (_, true) => Err(invalid()),
+        (field, false) => Ok((field, text[end..].trim_start_matches([' ', '\t']))),
+    }
+}
+
+fn join_fields(fields: &[&str]) -> String {
+    fields
+        .iter()
+        .filter(|field| !field.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn is_key_type(name: &str) -> bool {
+    [ED25519, ECDSA_P256, ECDSA_P384, RSA].contains(&name.as_bytes())
+}
+
+/// Matches `text` against `pattern`, which may contain `*` and `?`.
+fn wildcard_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // where to resume after the most recent `*` fails to match
+    let mut star = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p + 1, t));
+                p += 1;
+            }
+            Some(c) if *c == b'?' || c.eq_ignore_ascii_case(&text[t]) => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+                    p = star_p;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+const ED25519: &[u8] = b"ssh-ed25519";
+const ECDSA_P256: &[u8] = b"ecdsa-sha2-nistp256";
+const ECDSA_P384: &[u8] = b"ecdsa-sha2-nistp384";
+const RSA: &[u8] = b"ssh-rsa";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::pem;
+
+    #[test]
+    fn ssh_keygen() {
+        // the `.pub` files written alongside the private keys, and
+        // `ssh-keygen -Y sign -n file` signatures of `MESSAGE`
+        for (public_key, signature, key_type) in [
+            (
+                include_str!("ed25519.pub"),
+                include_str!("ed25519.sig"),
+                "ssh-ed25519",
+            ),
+            (
+                include_str!("p256.pub"),
+                include_str!("p256.sig"),
+                "ecdsa-sha2-nistp256",
+            ),
+            (
+                include_str!("p384.pub"),
+                include_str!("p384.sig"),
+                "ecdsa-sha2-nistp384",
+            ),
+            (
+                include_str!("rsa2048.pub"),
+                include_str!("rsa2048.sig"),
+                "ssh-rsa",
+            ),
+        ] {
+            let entry = AuthorizedKey::parse(public_key).unwrap();
+            assert_eq!(entry.options, None);
+            assert_eq!(entry.key.key_type(), key_type);
+            assert!(entry.comment.ends_with(" test"));
+            assert_eq!(entry.encode().unwrap(), public_key.trim_end());
+
+            let (signed_key, signed_data, signature) = decode_sshsig(signature);
+            assert_eq!(signed_key, entry.key.to_blob().unwrap());
+            entry.key.verify(&signed_data, &signature).unwrap();
+            assert_eq!(
+                entry.key.verify(&signed_data[1..], &signature).err(),
+                Some(Error::BadSignature)
+            );
+        }
+    }
+
+    /// Returns the public key, signed data and signature blob of an `SSHSIG`.
+    ///
+    /// This format is described in PROTOCOL.sshsig.
+    fn decode_sshsig(text: &str) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        pem::decode_first(text, &["SSH SIGNATURE"], |_, bytes| {
+            let mut reader = Reader::new(bytes.strip_prefix(b"SSHSIG").unwrap());
+            assert_eq!(reader.u32().unwrap(), 1);
+            let public_key = reader.string().unwrap();
+            let namespace = reader.string().unwrap();
+            let reserved = reader.string().unwrap();
+            assert_eq!(reader.string().unwrap(), b"sha512");
+            let signature = reader.string().unwrap();
+            reader.finish().unwrap();
+
+            let mut signed_data = b"SSHSIG".to_vec();
+            put_string(&mut signed_data, namespace);
+            put_string(&mut signed_data, reserved);
+            put_string(&mut signed_data, b"sha512");
+            put_string(
+                &mut signed_data,
+                crate::high::hash::Sha512::hash(MESSAGE).as_ref(),
+            );
+            Ok((public_key.to_vec(), signed_data, signature.to_vec()))
+        })
+        .unwrap()
+    }
+
+    const MESSAGE: &[u8] = b"hello world\n";
+
+    #[test]
+    fn signatures() {
+        let key = rsa::SigningKey::from_openssh(include_str!("rsa2048.key")).unwrap();
+        let public_key = SshPublicKey::Rsa(key.public_key());
+
+        let mut signature = [0u8; 256];
+        let signature = key.sign_pkcs1_sha256(&mut signature, b"hello").unwrap();
+        let mut blob = Vec::new();
+        put_string(&mut blob, b"rsa-sha2-256");
+        put_string(&mut blob, signature);
+        public_key.verify(b"hello", &blob).unwrap();
+
+        // a leading zero may be omitted
+        if signature[0] == 0x00 {
+            let mut blob = Vec::new();
+            put_string(&mut blob, b"rsa-sha2-256");
+            put_string(&mut blob, &signature[1..]);
+            public_key.verify(b"hello", &blob).unwrap();
+        }
+
+        for (algorithm, signature) in [
+            (&b"rsa-sha2-512"[..], signature),
+            (b"ssh-rsa", signature),
+            (b"ssh-ed25519", signature),
+            (b"rsa-sha2-256", &[signature, &[0]].concat()),
+            (b"rsa-sha2-256", &[&[0], signature].concat()),
+        ] {
+            let mut blob = Vec::new();
+            put_string(&mut blob, algorithm);
+            put_string(&mut blob, signature);
+            assert_eq!(
+                public_key.verify(b"hello", &blob).err(),
+                Some(Error::BadSignature)
+            );
+        }
+
+        assert_eq!(
+            public_key
+                .verify(b"hello", &[&blob[..], &[0]].concat())
+                .err(),
+            Some(Error::BadSignature)
+        );
+        assert_eq!(
+            public_key.verify(b"hello", &blob[..10]).err(),
+            Some(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn authorized_keys() {
+        let key = include_str!("ed25519.pub").trim_end();
+        let line = format!("no-pty,command=\"echo \\\"hi there\\\"\"\t{key}");
+        let entry = AuthorizedKey::parse(&line).unwrap();
+        assert_eq!(
+            entry.options,
+            Some("no-pty,command=\"echo \\\"hi there\\\"\"")
+        );
+        assert_eq!(entry.comment, "ed25519 test");
+        assert_eq!(entry.encode().unwrap(), line.replace('\t', " "));
+
+        let mut fields = key.split(' ');
+        let (key_type, blob) = (fields.next().unwrap(), fields.next().unwrap());
+        let line = format!("  {key_type}  {blob}  ");
+        let entry = AuthorizedKey::parse(&line).unwrap();
+        assert_eq!(entry.options, None);
+        assert_eq!(entry.comment, "");
+        assert_eq!(entry.encode().unwrap(), format!("{key_type} {blob}"));
+
+        for line in [
+            "",
+            "# comment",
+            "ssh-ed25519",
+            "ssh-ed25519 !!!!",
+            "ssh-ed25519 AAAA",
+            "no-pty ssh-ed25519",
+            "command=\"unterminated ssh-ed25519 AAAA",
+        ] {
+            assert!(AuthorizedKey::parse(line).is_err(), "{line:?}");
+        }
+
+        // the key type must match
+        assert_eq!(
+            AuthorizedKey::parse(&key.replace("ssh-ed25519", "ssh-rsa")).err(),
+            Some(invalid())
+        );
+        assert_eq!(
+            AuthorizedKey::parse(&key.replace("ssh-ed25519", "ssh-dss")).err(),
+            Some(KeyFormatError::UnsupportedOpenSshKey.into())
+        );
+    }
+
+    #[test]
+    fn known_hosts() {
+        // without the comment
+        let key = include_str!("p256.pub")
+            .trim_end()
+            .trim_end_matches(" p256 test");
+
+        let line = format!(
+            "example.com,*.example.org,!bad.example.org,192.0.2.?,[git.example.net]:2222 {key}"
+        );
+        let entry = KnownHost::parse(&line).unwrap();
+        assert_eq!(entry.marker, None);
+        assert_eq!(entry.comment, "");
+        assert_eq!(entry.encode().unwrap(), line);
+        for host in [
+            "example.com",
+            "EXAMPLE.com",
+            "www.example.org",
+            "a.b.example.org",
+            "192.0.2.1",
+            "[git.example.net]:2222",
+        ] {
+            assert!(entry.matches(host), "{host}");
+        }
+        for host in [
+            "example.co",
+            "www.example.com",
+            "example.org",
+            "bad.example.org",
+            "192.0.2.10",
+            "git.example.net",
+            "",
+        ] {
+            assert!(!entry.matches(host), "{host}");
+        }
+
+        let line = format!("@cert-authority *.example.com {key} ca");
+        let entry = KnownHost::parse(&line).unwrap();
+        assert_eq!(entry.marker, Some(KnownHostMarker::CertAuthority));
+        assert_eq!(entry.hosts, "*.example.com");
+        assert_eq!(entry.comment, "ca");
+        assert_eq!(entry.encode().unwrap(), line);
+
+        let line = format!("@revoked * {key}");
+        assert_eq!(
+            KnownHost::parse(&line).unwrap().marker,
+            Some(KnownHostMarker::Revoked)
+        );
+
+        // hashed host names are parsed, but never match
+        let line = format!("|1|JfKTdBh7rNbXkVAQCRp4OQoPfmI=|USECr3SWf1JUPsms5AqfD5QfxkM= {key}");
+        let entry = KnownHost::parse(&line).unwrap();
+        assert!(!entry.matches("localhost"));
+
+        for line in [
+            String::new(),
+            "example.com".to_string(),
+            format!("@unknown example.com {key}"),
+            format!("@revoked {key}"),
+            format!("example.com {key} extra").replace("nistp256 ", "nistp384 "),
+        ] {
+            assert!(KnownHost::parse(&line).is_err(), "{line:?}");
+        }
+    }
+
+    #[test]
+    fn blobs() {
+        for public_key in [
+            include_str!("ed25519.pub"),
+            include_str!("p256.pub"),
+            include_str!("p384.pub"),
+            include_str!("rsa2048.pub"),
+        ] {
+            let blob = AuthorizedKey::parse(public_key)
+                .unwrap()
+                .key
+                .to_blob()
+                .unwrap();
+            assert_eq!(
+                SshPublicKey::from_blob(&blob).unwrap().to_blob().unwrap(),
+                blob
+            );
+
+            assert_eq!(
+                SshPublicKey::from_blob(&[&blob[..], &[0]].concat()).err(),
+                Some(invalid())
+            );
+            assert_eq!(
+                SshPublicKey::from_blob(&blob[..blob.len() - 1]).err(),
+                Some(invalid())
+            );
+        }
+
+        let mut blob = Vec::new();
+        put_string(&mut blob, b"ecdsa-sha2-nistp256");
+        put_string(&mut blob, b"nistp384");
+        put_string(&mut blob, &[0x04; 65]);
+        assert_eq!(SshPublicKey::from_blob(&blob).err(), Some(invalid()));
+
+        let mut blob = Vec::new();
+        put_string(&mut blob, b"ssh-dss");
+        assert_eq!(
+            SshPublicKey::from_blob(&blob).err(),
+            Some(KeyFormatError::UnsupportedOpenSshKey.into())
+        );
+    }
+
+    #[test]
+    fn wildcards() {
+        for (pattern, text, expected) in [
+            ("", "", true),
+            ("*", "", true),
+            ("*", "abc", true),
+            ("a*c", "abbbc", true),
+            ("a*c", "abbbd", false),
+            ("*.*.com", "a.b.com", true),
+            ("*.*.com", "a.com", false),
+            ("a?c", "abc", true),
+            ("a?c", "ac", false),
+            ("**a", "bba", true),
+            ("*a*a*a*a*b", "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", false),
+        ] {
+            assert_eq!(
+                wildcard_match(pattern.as_bytes(), text.as_bytes()),
+                expected,
+                "{pattern} {text}"
+            );
+        }
+    }
+}
@@ -0,0 +1,1103 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Oblivious pseudorandom functions, as specified in
+//! [RFC9497](https://datatracker.ietf.org/doc/html/rfc9497).
+//!
+//! [`Oprf`] is the base mode.  [`Voprf`] additionally proves to the client
+//! that the server used the key matching a known public key.  [`Poprf`] is
+//! verifiable, and also binds public `info` into the output.
+
+use core::marker::PhantomData;
+
+use super::curve::P256;
+use super::group::{Group, Ristretto255};
+use super::hash::{Hash, HashContext, HashOutput};
+use crate::Error;
+use crate::low::Entry;
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+/// An OPRF ciphersuite: a prime-order group and its hash function.
+///
+/// This is implemented for [`Ristretto255`] (`ristretto255-SHA512`) and
+/// [`P256`] (`P256-SHA256`).
+pub trait Ciphersuite: Group {
+    /// The ciphersuite identifier, used for domain separation.
+    const IDENTIFIER: &'static [u8];
+}
+
+impl Ciphersuite for Ristretto255 {
+    const IDENTIFIER: &'static [u8] = b"ristretto255-SHA512";
+}
+
+impl Ciphersuite for P256 {
+    const IDENTIFIER: &'static [u8] = b"P256-SHA256";
+}
+
+/// A server's private key, with its public key.
+pub struct PrivateKey<C: Ciphersuite> {
+    scalar: C::Scalar,
+    public: C::Element,
+}
+
+impl<C: Ciphersuite> PrivateKey<C> {
+    /// Generate a new random private key.
+    ///
+    /// Use the `derive_key_pair` function of a mode to derive one
+    /// deterministically instead.
+    pub fn new_random() -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        Self::generate(&mut SystemRandom)
+    }
+
+    /// Decode a private key.
+    ///
+    /// Fails unless `bytes` is the canonical encoding of a non-zero scalar.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        C::deserialize_scalar(bytes).map(Self::from_scalar)
+    }
+
+    /// Encode this private key.
+    pub fn as_bytes(&self) -> C::EncodedScalar {
+        C::serialize_scalar(&self.scalar)
+    }
+
+    /// Return the public key.
+    pub fn public_key(&self) -> PublicKey<C> {
+        PublicKey(self.public)
+    }
+
+    /// Generate a new random private key using `rng`.
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        C::random_scalar(rng).map(Self::from_scalar)
+    }
+
+    /// `SerializeElement(k * peer)`, for protocols that also use
+    /// these keys for Diffie-Hellman.
+    pub(super) fn diffie_hellman(&self, peer: &PublicKey<C>) -> C::EncodedElement {
+        C::serialize_element(&C::mul(&peer.0, &self.scalar))
+    }
+
+    fn from_scalar(scalar: C::Scalar) -> Self {
+        let public = C::mul_gen(&scalar);
+        Self { scalar, public }
+    }
+}
+
+/// A server's public key, for the verifiable modes.
+pub struct PublicKey<C: Ciphersuite>(C::Element);
+
+impl<C: Ciphersuite> Clone for PublicKey<C> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<C: Ciphersuite> PublicKey<C> {
+    /// Decode a public key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        C::deserialize_element(bytes).map(Self)
+    }
+
+    /// Encode this public key.
+    pub fn as_bytes(&self) -> C::EncodedElement {
+        C::serialize_element(&self.0)
+    }
+}
+
+/// The client's secret blinding factor.
+///
+/// This is needed again to finalize the output.
+pub struct Blind<C: Ciphersuite>(C::Scalar);
+
+impl<C: Ciphersuite> Blind<C> {
+    /// Decode a blind previously encoded with [`Blind::as_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        C::deserialize_scalar(bytes).map(Self)
+    }
+
+    /// Encode this blind.
+    pub fn as_bytes(&self) -> C::EncodedScalar {
+        C::serialize_scalar(&self.0)
+    }
+}
+
+/// The client's blinded input, which is sent to the server.
+pub struct BlindedElement<C: Ciphersuite>(C::Element);
+
+impl<C: Ciphersuite> Clone for BlindedElement<C> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<C: Ciphersuite> BlindedElement<C> {
+    /// Decode a blinded element.  The identity is rejected.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        C::deserialize_element(bytes).map(Self)
+    }
+
+    /// Encode this blinded element.
+    pub fn as_bytes(&self) -> C::EncodedElement {
+        C::serialize_element(&self.0)
+    }
+}
+
+/// The server's evaluation of a [`BlindedElement`], which is sent to the client.
+pub struct EvaluatedElement<C: Ciphersuite>(C::Element);
+
+impl<C: Ciphersuite> EvaluatedElement<C> {
+    /// Decode an evaluated element.  The identity is rejected.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        C::deserialize_element(bytes).map(Self)
+    }
+
+    /// Encode this evaluated element.
+    pub fn as_bytes(&self) -> C::EncodedElement {
+        C::serialize_element(&self.0)
+    }
+}
+
+/// A proof that an [`EvaluatedElement`] was computed with a given key.
+pub struct Proof<C: Ciphersuite> {
+    c: C::Scalar,
+    s: C::Scalar,
+}
+
+impl<C: Ciphersuite> Proof<C> {
+    /// Length of an encoded proof.
+    pub const LEN: usize = 2 * C::SCALAR_LEN;
+
+    /// Decode a proof.  `bytes` must be [`Proof::LEN`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::LEN {
+            return Err(Error::WrongLength);
+        }
+        let (c, s) = bytes.split_at(C::SCALAR_LEN);
+        Ok(Self {
+            c: C::deserialize_scalar(c)?,
+            s: C::deserialize_scalar(s)?,
+        })
+    }
+
+    /// Encode this proof into `out`.
+    ///
+    /// The written prefix of `out` is returned.
+    pub fn encode<'a>(&self, out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        let out = out.get_mut(..Self::LEN).ok_or(Error::WrongLength)?;
+        let (c, s) = out.split_at_mut(C::SCALAR_LEN);
+        c.copy_from_slice(C::serialize_scalar(&self.c).as_ref());
+        s.copy_from_slice(C::serialize_scalar(&self.s).as_ref());
+        Ok(out)
+    }
+}
+
+/// The server's public key, tweaked by the `info` for a [`Poprf`] evaluation.
+///
+/// The client computes this in [`Poprf::blind`] and needs it again
+/// in [`Poprf::finalize`].
+pub struct TweakedKey<C: Ciphersuite>(C::Element);
+
+/// The base OPRF mode (`modeOPRF`).
+///
+/// The client learns `F(key, input)` and the server learns nothing.
+pub struct Oprf<C>(PhantomData<C>);
+
+impl<C: Ciphersuite> Oprf<C> {
+    const CONTEXT: Context<C> = Context::new(MODE_OPRF);
+
+    /// `DeriveKeyPair()`: deterministically derive a private key from
+    /// `seed` and `info`.
+    pub fn derive_key_pair(seed: &[u8; 32], info: &[u8]) -> Result<PrivateKey<C>, Error> {
+        let _entry = Entry::new_secret();
+        Self::CONTEXT.derive_key_pair(seed, info)
+    }
+
+    /// `Blind()`: blind `input`, ready to send to the server.
+    ///
+    /// Fails if `input` is longer than 65535 bytes.
+    pub fn blind(input: &[u8]) -> Result<(Blind<C>, BlindedElement<C>), Error> {
+        Self::blind_with_rng(input, &mut SystemRandom)
+    }
+
+    /// `Blind()`, using `rng` to generate the blind.
+    ///
+    /// Otherwise, as [`Self::blind()`].
+    pub fn blind_with_rng(
+        input: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Blind<C>, BlindedElement<C>), Error> {
+        let _entry = Entry::new_secret();
+        Self::CONTEXT.blind(input, rng)
+    }
+
+    /// `BlindEvaluate()`: the server's evaluation of a blinded element.
+    pub fn blind_evaluate(key: &PrivateKey<C>, blinded: &BlindedElement<C>) -> EvaluatedElement<C> {
+        let _entry = Entry::new_secret();
+        EvaluatedElement(C::mul(&blinded.0, &key.scalar))
+    }
+
+    /// `Finalize()`: the client's computation of the output.
+    pub fn finalize(
+        input: &[u8],
+        blind: &Blind<C>,
+        evaluated: &EvaluatedElement<C>,
+    ) -> Result<HashOutput, Error> {
+        let _entry = Entry::new_secret();
+        Self::CONTEXT.finalize(input, None, blind, evaluated)
+    }
+
+    /// `Evaluate()`: compute the output directly, given the private key.
+    pub fn evaluate(key: &PrivateKey<C>, input: &[u8]) -> Result<HashOutput, Error> {
+        let _entry = Entry::new_secret();
+        let element = Self::CONTEXT.hash_to_group(input)?;
+        Self::CONTEXT.output(input, None, &C::mul(&element, &key.scalar))
+    }
+}
+
+/// The verifiable OPRF mode (`modeVOPRF`).
+///
+/// This is [`Oprf`], plus a proof that the server used the
+/// private key for a known [`PublicKey`].
+pub struct Voprf<C>(PhantomData<C>);
+
+impl<C: Ciphersuite> Voprf<C> {
+    const CONTEXT: Context<C> = Context::new(MODE_VOPRF);
+
+    /// `DeriveKeyPair()`: deterministically derive a private key from
+    /// `seed` and `info`.
+    pub fn derive_key_pair(seed: &[u8; 32], info: &[u8]) -> Result<PrivateKey<C>, Error> {
+        let _entry = Entry::new_secret();
+        Self::CONTEXT.derive_key_pair(seed, info)
+    }
+
+    /// `Blind()`: blind `input`, ready to send to the server.
+    ///
+    /// Fails if `input` is longer than 65535 bytes.
+    pub fn blind(input: &[u8]) -> Result<(Blind<C>, BlindedElement<C>), Error> {
+        Self::blind_with_rng(input, &mut SystemRandom)
+    }
+
+    /// `Blind()`, using `rng` to generate the blind.
+    ///
+    /// Otherwise, as [`Self::blind()`].
+    pub fn blind_with_rng(
+        input: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Blind<C>, BlindedElement<C>), Error> {
+        let _entry = Entry::new_secret();
+        Self::CONTEXT.blind(input, rng)
+    }
+
+    /// `BlindEvaluate()`: the server's evaluation of a blinded element,
+    /// with a proof.
+    pub fn blind_evaluate(
+        key: &PrivateKey<C>,
+        blinded: &BlindedElement<C>,
+    ) -> Result<(EvaluatedElement<C>, Proof<C>), Error> {
+        Self::blind_evaluate_with_rng(key, blinded, &mut SystemRandom)
+    }
+
+    /// `BlindEvaluate()`, using `rng` to generate the proof's nonce.
+    ///
+    /// Otherwise, as [`Self::blind_evaluate()`].
+    pub fn blind_evaluate_with_rng(
+        key: &PrivateKey<C>,
+        blinded: &BlindedElement<C>,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(EvaluatedElement<C>, Proof<C>), Error> {
+        let _entry = Entry::new_secret();
+        let evaluated = C::mul(&blinded.0, &key.scalar);
+        let proof = Self::CONTEXT.generate_proof(
+            &key.scalar,
+            &key.public,
+            &[blinded.0],
+            &[evaluated],
+            rng,
+        )?;
+        Ok((EvaluatedElement(evaluated), proof))
+    }
+
+    /// `Finalize()`: verify the server's proof, and compute the output.
+    ///
+    /// `blinded` is the element sent to the server.  Fails with
+    /// [`Error::BadSignature`] if the proof is invalid.
+    pub fn finalize(
+        input: &[u8],
+        blind: &Blind<C>,
+        evaluated: &EvaluatedElement<C>,
+        blinded: &BlindedElement<C>,
+        public_key: &PublicKey<C>,
+        proof: &Proof<C>,
+    ) -> Result<HashOutput, Error> {
+        let _entry = Entry::new_secret();
+        Self::CONTEXT.verify_proof(&public_key.0, &[blinded.0], &[evaluated.0], proof)?;
+        Self::CONTEXT.finalize(input, None, blind, evaluated)
+    }
+
+    /// `Evaluate()`: compute the output directly, given the private key.
+    pub fn evaluate(key: &PrivateKey<C>, input: &[u8]) -> Result<HashOutput, Error> {
+        let _entry = Entry::new_secret();
+        let element = Self::CONTEXT.hash_to_group(input)?;
+        Self::CONTEXT.output(input, None, &C::mul(&element, &key.scalar))
+    }
+}
+
+/// The partially-oblivious PRF mode (`modePOPRF`).
+///
+/// This is verifiable, and the output also depends on `info`
+/// which is known to both the client and server.
+pub struct Poprf<C>(PhantomData<C>);
+
+impl<C: Ciphersuite> Poprf<C> {
+    const CONTEXT: Context<C> = Context::new(MODE_POPRF);
+
+    /// `DeriveKeyPair()`: deterministically derive a private key from
+    /// `seed` and `info`.
+    pub fn derive_key_pair(seed: &[u8; 32], info: &[u8]) -> Result<PrivateKey<C>, Error> {
+        let _entry = Entry::new_secret();
+        Self::CONTEXT.derive_key_pair(seed, info)
+    }
+
+    /// `Blind()`: blind `input`, ready to send to the server.
+    ///
+    /// Fails if `input` or `info` are longer than 65535 bytes.
+    #[allow(clippy::type_complexity)]
+    pub fn blind(
+        input: &[u8],
+        info: &[u8],
+        public_key: &PublicKey<C>,
+    ) -> Result<(Blind<C>, BlindedElement<C>, TweakedKey<C>), Error> {
+        Self::blind_with_rng(input, info, public_key, &mut SystemRandom)
+    }
+
+    /// `Blind()`, using `rng` to generate the blind.
+    ///
+    /// Otherwise, as [`Self::blind()`].
+    #[allow(clippy::type_complexity)]
+    pub fn blind_with_rng(
+        input: &[u8],
+        info: &[u8],
+        public_key: &PublicKey<C>,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Blind<C>, BlindedElement<C>, TweakedKey<C>), Error> {
+        let _entry = Entry::new_secret();
+        let m = Self::info_scalar(info)?;
+        let tweaked = C::add(&C::mul_gen(&m), &public_key.0);
+        if C::is_identity(&tweaked) {
+            return Err(Error::NotOnCurve);
+        }
+        let (blind, blinded) = Self::CONTEXT.blind(input, rng)?;
+        Ok((blind, blinded, TweakedKey(tweaked)))
+    }
+
+    /// `BlindEvaluate()`: the server's evaluation of a blinded element
+    /// for `info`, with a proof.
+    pub fn blind_evaluate(
+        key: &PrivateKey<C>,
+        blinded: &BlindedElement<C>,
+        info: &[u8],
+    ) -> Result<(EvaluatedElement<C>, Proof<C>), Error> {
+        Self::blind_evaluate_with_rng(key, blinded, info, &mut SystemRandom)
+    }
+
+    /// `BlindEvaluate()`, using `rng` to generate the proof's nonce.
+    ///
+    /// Otherwise, as [`Self::blind_evaluate()`].
+    pub fn blind_evaluate_with_rng(
+        key: &PrivateKey<C>,
+        blinded: &BlindedElement<C>,
+        info: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(EvaluatedElement<C>, Proof<C>), Error> {
+        let _entry = Entry::new_secret();
+        let t = Self::tweak(key, info)?;
+        let evaluated = C::mul(&blinded.0, &C::scalar_inv(&t));
+        let tweaked = C::mul_gen(&t);
+        let proof = Self::CONTEXT.generate_proof(&t, &tweaked, &[evaluated], &[blinded.0], rng)?;
+        Ok((EvaluatedElement(evaluated), proof))
+    }
+
+    /// `Finalize()`: verify the server's proof, and compute the output.
+    ///
+    /// `blinded` is the element sent to the server, and `tweaked_key`
+    /// is from [`Poprf::blind`].  Fails with [`Error::BadSignature`]
+    /// if the proof is invalid.
+    pub fn finalize(
+        input: &[u8],
+        blind: &Blind<C>,
+        evaluated: &EvaluatedElement<C>,
+        blinded: &BlindedElement<C>,
+        proof: &Proof<C>,
+        info: &[u8],
+        tweaked_key: &TweakedKey<C>,
+    ) -> Result<HashOutput, Error> {
+        let _entry = Entry::new_secret();
+        Self::CONTEXT.verify_proof(&tweaked_key.0, &[evaluated.0], &[blinded.0], proof)?;
+        Self::CONTEXT.finalize(input, Some(info), blind, evaluated)
+    }
+
+    /// `Evaluate()`: compute the output directly, given the private key.
+    pub fn evaluate(key: &PrivateKey<C>, input: &[u8], info: &[u8]) -> Result<HashOutput, Error> {
+        let _entry = Entry::new_secret();
+        let element = Self::CONTEXT.hash_to_group(input)?;
+        let t = Self::tweak(key, info)?;
+        let evaluated = C::mul(&element, &C::scalar_inv(&t));
+        Self::CONTEXT.output(input, Some(info), &evaluated)
+    }
+
+    /// `m = HashToScalar("Info" || len(info) || info)`
+    fn info_scalar(info: &[u8]) -> Result<C::Scalar, Error> {
+        Self::CONTEXT.hash_to_scalar(&[b"Info", &len_prefix(info)?, info])
+    }
+
+    /// `t = key + m`, which must be invertible.
+    fn tweak(key: &PrivateKey<C>, info: &[u8]) -> Result<C::Scalar, Error> {
+        let t = C::scalar_add(&key.scalar, &Self::info_scalar(info)?);
+        match C::scalar_is_zero(&t) {
+            true => Err(Error::OutOfRange),
+            false => Ok(t),
+        }
+    }
+}
+
+/// The mode's `contextString`, and the operations that depend on it.
+struct Context<C> {
+    mode: [u8; 1],
+    _suite: PhantomData<C>,
+}
+
+impl<C: Ciphersuite> Context<C> {
+    const fn new(mode: u8) -> Self {
+        Self {
+            mode: [mode],
+            _suite: PhantomData,
+        }
+    }
+
+    /// `prefix || contextString`
+    fn dst<'a>(&'a self, prefix: &'static [u8]) -> [&'a [u8]; 5] {
+        [prefix, b"OPRFV1-", &self.mode, b"-", C::IDENTIFIER]
+    }
+
+    fn hash_to_group(&self, input: &[u8]) -> Result<C::Element, Error> {
+        len_prefix(input)?;
+        let element = C::hash_to_group(&[input], &self.dst(b"HashToGroup-"))?;
+        match C::is_identity(&element) {
+            true => Err(Error::NotOnCurve),
+            false => Ok(element),
+        }
+    }
+
+    fn hash_to_scalar(&self, input: &[&[u8]]) -> Result<C::Scalar, Error> {
+        C::hash_to_scalar(input, &self.dst(b"HashToScalar-"))
+    }
+
+    fn derive_key_pair(&self, seed: &[u8; 32], info: &[u8]) -> Result<PrivateKey<C>, Error> {
+        let info_len = len_prefix(info)?;
+        for counter in 0..=255u8 {
+            let scalar = C::hash_to_scalar(
+                &[seed, &info_len, info, &[counter]],
+                &self.dst(b"DeriveKeyPair"),
+            )?;
+            if !C::scalar_is_zero(&scalar) {
+                return Ok(PrivateKey::from_scalar(scalar));
+            }
+        }
+        Err(Error::OutOfRange)
+    }
+
+    fn blind(
+        &self,
+        input: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Blind<C>, BlindedElement<C>), Error> {
+        let blind = C::random_scalar(rng)?;
+        let element = self.hash_to_group(input)?;
+        let blinded = C::mul(&element, &blind);
+        Ok((Blind(blind), BlindedElement(blinded)))
+    }
+
+    fn finalize(
+        &self,
+        input: &[u8],
+        info: Option<&[u8]>,
+        blind: &Blind<C>,
+        evaluated: &EvaluatedElement<C>,
+    ) -> Result<HashOutput, Error> {
+        let unblinded = C::mul(&evaluated.0, &C::scalar_inv(&blind.0));
+        self.output(input, info, &unblinded)
+    }
+
+    /// Hash the unblinded element into the PRF output.
+    fn output(
+        &self,
+        input: &[u8],
+        info: Option<&[u8]>,
+        unblinded: &C::Element,
+    ) -> Result<HashOutput, Error> {
+        let unblinded = C::serialize_element(unblinded);
+
+        let mut ctx = C::Hash::new();
+        ctx.update(&len_prefix(input)?);
+        ctx.update(input);
+        if let Some(info) = info {
+            ctx.update(&len_prefix(info)?);
+            ctx.update(info);
+        }
+        ctx.update(&len_prefix(unblinded.as_ref())?);
+        ctx.update(unblinded.as_ref());
+        ctx.update(b"Finalize");
+        Ok(ctx.finish())
+    }
+
+    /// `GenerateProof()`: a DLEQ proof that `k` relates the generator
+    /// to `b`, and each `c[i]` to `d[i]`.
+    fn generate_proof(
+        &self,
+        k: &C::Scalar,
+        b: &C::Element,
+        c: &[C::Element],
+        d: &[C::Element],
+        rng: &mut dyn RandomSource,
+    ) -> Result<Proof<C>, Error> {
+        let (m, _) = self.compute_composites(b, c, d, false)?;
+        let z = C::mul(&m, k);
+
+        let r = C::random_scalar(rng)?;
+        let t2 = C::mul_gen(&r);
+        let t3 = C::mul(&m, &r);
+
+        let c = self.challenge(b, &m, &z, &t2, &t3)?;
+        let s = C::scalar_sub(&r, &C::scalar_mul(&c, k));
+        Ok(Proof { c, s })
+    }
+
+    /// `VerifyProof()`.  Fails with [`Error::BadSignature`].
+    fn verify_proof(
+        &self,
+        b: &C::Element,
+        c: &[C::Element],
+        d: &[C::Element],
+        proof: &Proof<C>,
+    ) -> Result<(), Error> {
+        let (m, z) = self.compute_composites(b, c, d, true)?;
+
+        let t2 = C::add(&C::mul_gen(&proof.s), &C::mul(b, &proof.c));
+        let t3 = C::add(&C::mul(&m, &proof.s), &C::mul(&z, &proof.c));
+
+        let expected_c = self.challenge(b, &m, &z, &t2, &t3)?;
+        match C::scalar_eq(&expected_c, &proof.c) {
+            true => Ok(()),
+            false => Err(Error::BadSignature),
+        }
+    }
+
+    /// `ComputeComposites()`, returning `(M, Z)`.
+    ///
+    /// The prover knows `k` and computes `Z = k * M` itself (as in
+    /// `ComputeCompositesFast()`), so passes `with_z = false` and ignores
+    /// the returned `Z`.
+    fn compute_composites(
+        &self,
+        b: &C::Element,
+        c: &[C::Element],
+        d: &[C::Element],
+        with_z: bool,
+    ) -> Result<(C::Element, C::Element), Error> {
+        if c.len() != d.len() || c.len() > 0xffff {
+            return Err(Error::WrongLength);
+        }
+
+        let bm = C::serialize_element(b);
+        let seed_dst = self.dst(b"Seed-");
+        let seed_dst_len: usize = seed_dst.iter().map(|p| p.len()).sum();
+
+        let mut ctx = C::Hash::new();
+        ctx.update(&len_prefix(bm.as_ref())?);
+        ctx.update(bm.as_ref());
+        ctx.update(&(seed_dst_len as u16).to_be_bytes());
+        for part in seed_dst {
+            ctx.update(part);
+        }
+        let seed = ctx.finish();
+        let seed_len = len_prefix(seed.as_ref())?;
+
+        let mut m = C::identity();
+        let mut z = C::identity();
+        for (i, (ci, di)) in c.iter().zip(d).enumerate() {
+            let ci_bytes = C::serialize_element(ci);
+            let di_bytes = C::serialize_element(di);
+            let weight = self.hash_to_scalar(&[
+                &seed_len,
+                seed.as_ref(),
+                &(i as u16).to_be_bytes(),
+                &len_prefix(ci_bytes.as_ref())?,
+                ci_bytes.as_ref(),
+                &len_prefix(di_bytes.as_ref())?,
+                di_bytes.as_ref(),
+                b"Composite",
+            ])?;
+            m = C::add(&C::mul(ci, &weight), &m);
+            if with_z {
+                z = C::add(&C::mul(di, &weight), &z);
+            }
+        }
+
+        Ok((m, z))
+    }
+
+    fn challenge(
+        &self,
+        b: &C::Element,
+        m: &C::Element,
+        z: &C::Element,
+        t2: &C::Element,
+        t3: &C::Element,
+    ) -> Result<C::Scalar, Error> {
+        let b = C::serialize_element(b);
+        let m = C::serialize_element(m);
+        let z = C::serialize_element(z);
+        let t2 = C::serialize_element(t2);
+        let t3 = C::serialize_element(t3);
+        self.hash_to_scalar(&[
+            &len_prefix(b.as_ref())?,
+            b.as_ref(),
+            &len_prefix(m.as_ref())?,
+            m.as_ref(),
+            &len_prefix(z.as_ref())?,
+            z.as_ref(),
+            &len_prefix(t2.as_ref())?,
+            t2.as_ref(),
+            &len_prefix(t3.as_ref())?,
+            t3.as_ref(),
+            b"Challenge",
+        ])
+    }
+}
+
+/// `I2OSP(len(value), 2)`, failing if `value` is too long.
+pub(super) fn len_prefix(value: &[u8]) -> Result<[u8; 2], Error> {
+    u16::try_from(value.len())
+        .map(u16::to_be_bytes)
+        .map_err(|_| Error::WrongLength)
+}
+
+const MODE_OPRF: u8 = 0x00;
+const MODE_VOPRF: u8 = 0x01;
+const MODE_POPRF: u8 = 0x02;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const SEED: [u8; 32] = [0xa3; 32];
+    const KEY_INFO: &[u8] = b"test key";
+    const INFO: &[u8] = b"test info";
+    const INPUTS: [&[u8]; 2] = [&[0x00], &[0x5a; 17]];
+
+    /// Test vectors from RFC9497 appendix A, for one mode and ciphersuite.
+    struct Vectors {
+        blind: &'static str,
+        proof_random: &'static str,
+        sk: &'static str,
+        pk: &'static str,
+        blinded: [&'static str; 2],
+        evaluated: [&'static str; 2],
+        output: [&'static str; 2],
+        batch_proof: &'static str,
+    }
+
+    fn test_blind<C: Ciphersuite>(
+        ctx: &Context<C>,
+        v: &Vectors,
+        input: &[u8],
+    ) -> (Blind<C>, BlindedElement<C>) {
+        let blind = unhex(v.blind);
+        ctx.blind(input, &mut SliceRandomSource(&blind)).unwrap()
+    }
+
+    fn check_oprf<C: Ciphersuite>(v: &Vectors) {
+        let key = Oprf::<C>::derive_key_pair(&SEED, KEY_INFO).unwrap();
+        assert_eq!(hex(key.as_bytes().as_ref()), v.sk);
+
+        for (i, input) in INPUTS.into_iter().enumerate() {
+            let (blind, blinded) = test_blind(&Oprf::<C>::CONTEXT, v, input);
+            assert_eq!(hex(blinded.as_bytes().as_ref()), v.blinded[i]);
+
+            let evaluated = Oprf::blind_evaluate(&key, &blinded);
+            assert_eq!(hex(evaluated.as_bytes().as_ref()), v.evaluated[i]);
+
+            let output = Oprf::finalize(input, &blind, &evaluated).unwrap();
+            assert_eq!(hex(output.as_ref()), v.output[i]);
+            assert_eq!(Oprf::evaluate(&key, input).unwrap(), output);
+        }
+    }
+
+    fn check_voprf<C: Ciphersuite>(v: &Vectors) {
+        let key = Voprf::<C>::derive_key_pair(&SEED, KEY_INFO).unwrap();
+        assert_eq!(hex(key.as_bytes().as_ref()), v.sk);
+        let pk = key.public_key();
+        assert_eq!(hex(pk.as_bytes().as_ref()), v.pk);
+
+        let ctx = &Voprf::<C>::CONTEXT;
+        let mut proof_bytes = [0u8; 64];
+        let mut blindeds = [C::identity(); 2];
+        let mut evaluateds = [C::identity(); 2];
+
+        for (i, input) in INPUTS.into_iter().enumerate() {
+            let (blind, blinded) = test_blind(ctx, v, input);
+            assert_eq!(hex(blinded.as_bytes().as_ref()), v.blinded[i]);
+
+            let random = unhex(v.proof_random);
+            let (evaluated, proof) =
+                Voprf::blind_evaluate_with_rng(&key, &blinded, &mut SliceRandomSource(&random))
+                    .unwrap();
+            assert_eq!(hex(evaluated.as_bytes().as_ref()), v.evaluated[i]);
+
+            let output = Voprf::finalize(input, &blind, &evaluated, &blinded, &pk, &proof).unwrap();
+            assert_eq!(hex(output.as_ref()), v.output[i]);
+            assert_eq!(Voprf::evaluate(&key, input).unwrap(), output);
+
+            // a proof for another element does not verify
+            let proof = Proof::<C>::from_bytes(proof.encode(&mut proof_bytes).unwrap()).unwrap();
+            if i == 1 {
+                let (other_blind, other_blinded) = test_blind(ctx, v, INPUTS[0]);
+                assert_eq!(
+                    Voprf::finalize(
+                        INPUTS[0],
+                        &other_blind,
+                        &evaluated,
+                        &other_blinded,
+                        &pk,
+                        &proof
+                    ),
+                    Err(Error::BadSignature)
+                );
+            }
+
+            blindeds[i] = blinded.0;
+            evaluateds[i] = evaluated.0;
+        }
+
+        let random = unhex(v.proof_random);
+        let proof = ctx
+            .generate_proof(
+                &key.scalar,
+                &key.public,
+                &blindeds,
+                &evaluateds,
+                &mut SliceRandomSource(&random),
+            )
+            .unwrap();
+        assert_eq!(hex(proof.encode(&mut proof_bytes).unwrap()), v.batch_proof);
+        ctx.verify_proof(&pk.0, &blindeds, &evaluateds, &proof)
+            .unwrap();
+        evaluateds.swap(0, 1);
+        assert_eq!(
+            ctx.verify_proof(&pk.0, &blindeds, &evaluateds, &proof),
+            Err(Error::BadSignature)
+        );
+    }
+
+    fn check_poprf<C: Ciphersuite>(v: &Vectors) {
+        let key = Poprf::<C>::derive_key_pair(&SEED, KEY_INFO).unwrap();
+        assert_eq!(hex(key.as_bytes().as_ref()), v.sk);
+        let pk = key.public_key();
+        assert_eq!(hex(pk.as_bytes().as_ref()), v.pk);
+
+        let ctx = &Poprf::<C>::CONTEXT;
+        let mut blindeds = [C::identity(); 2];
+        let mut evaluateds = [C::identity(); 2];
+        let mut tweaked = C::identity();
+
+        for (i, input) in INPUTS.into_iter().enumerate() {
+            let b = unhex(v.blind);
+            let (blind, blinded, tweaked_key) =
+                Poprf::blind_with_rng(input, INFO, &pk, &mut SliceRandomSource(&b)).unwrap();
+            assert_eq!(hex(blinded.as_bytes().as_ref()), v.blinded[i]);
+
+            let random = unhex(v.proof_random);
+            let (evaluated, proof) = Poprf::blind_evaluate_with_rng(
+                &key,
+                &blinded,
+                INFO,
+                &mut SliceRandomSource(&random),
+            )
+            .unwrap();
+            assert_eq!(hex(evaluated.as_bytes().as_ref()), v.evaluated[i]);
+
+            let output = Poprf::finalize(
+                input,
+                &blind,
+                &evaluated,
+                &blinded,
+                &proof,
+                INFO,
+                &tweaked_key,
+            )
+            .unwrap();
+            assert_eq!(hex(output.as_ref()), v.output[i]);
+            assert_eq!(Poprf::evaluate(&key, input, INFO).unwrap(), output);
+
+            // info is bound into the output and proof
+            assert_ne!(Poprf::evaluate(&key, input, b"other").unwrap(), output);
+            let (other, _) = Poprf::blind_evaluate_with_rng(
+                &key,
+                &blinded,
+                b"other",
+                &mut SliceRandomSource(&random),
+            )
+            .unwrap();
+            assert_eq!(
+                Poprf::finalize(input, &blind, &other, &blinded, &proof, INFO, &tweaked_key),
+                Err(Error::BadSignature)
+            );
+
+            blindeds[i] = blinded.0;
+            evaluateds[i] = evaluated.0;
+            tweaked = tweaked_key.0;
+        }
+
+        let m = Poprf::<C>::info_scalar(INFO).unwrap();
+        let t = C::scalar_add(&key.scalar, &m);
+        let random = unhex(v.proof_random);
+        let proof = ctx
+            .generate_proof(
+                &t,
+                &tweaked,
+                &evaluateds,
+                &blindeds,
+                &mut SliceRandomSource(&random),
+            )
+            .unwrap();
+        let mut proof_bytes = [0u8; 64];
+        assert_eq!(hex(proof.encode(&mut proof_bytes).unwrap()), v.batch_proof);
+        ctx.verify_proof(&tweaked, &evaluateds, &blindeds, &proof)
+            .unwrap();
+    }
+
+    const RISTRETTO255_OPRF: Vectors = Vectors {
+        blind: "64d37aed22a27f5191de1c1d69fadb899d8862b58eb4220029e036ec4c1f6706",
+        proof_random: "",
+        sk: "5ebcea5ee37023ccb9fc2d2019f9d7737be85591ae8652ffa9ef0f4d37063b0e",
+        pk: "",
+        blinded: [
+            "609a0ae68c15a3cf6903766461307e5c8bb2f95e7e6550e1ffa2dc99e412803c",
+            "da27ef466870f5f15296299850aa088629945a17d1f5b7f5ff043f76b3c06418",
+        ],
+        evaluated: [
+            "7ec6578ae5120958eb2db1745758ff379e77cb64fe77b0b2d8cc917ea0869c7e",
+            "b4cbf5a4f1eeda5a63ce7b77c7d23f461db3fcab0dd28e4e17cecb5c90d02c25",
+        ],
+        output: [
+            "527759c3d9366f277d8c6020418d96bb393ba2afb20ff90df23fb7708264e2f3\
+             ab9135e3bd69955851de4b1f9fe8a0973396719b7912ba9ee8aa7d0b5e24bcf6",
+            "f4a74c9c592497375e796aa837e907b1a045d34306a749db9f34221f7e750cb4\
+             f2a6413a6bf6fa5e19ba6348eb673934a722a7ede2e7621306d18951e7cf2c73",
+        ],
+        batch_proof: "",
+    };
+
+    const RISTRETTO255_VOPRF: Vectors = Vectors {
+        blind: "64d37aed22a27f5191de1c1d69fadb899d8862b58eb4220029e036ec4c1f6706",
+        proof_random: "222a5e897cf59db8145db8d16e597e8facb80ae7d4e26d9881aa6f61d645fc0e",
+        sk: "e6f73f344b79b379f1a0dd37e07ff62e38d9f71345ce62ae3a9bc60b04ccd909",
+        pk: "c803e2cc6b05fc15064549b5920659ca4a77b2cca6f04f6b357009335476ad4e",
+        blinded: [
+            "863f330cc1a1259ed5a5998a23acfd37fb4351a793a5b3c090b642ddc439b945",
+            "cc0b2a350101881d8a4cba4c80241d74fb7dcbfde4a61fde2f91443c2bf9ef0c",
+        ],
+        evaluated: [
+            "aa8fa048764d5623868679402ff6108d2521884fa138cd7f9c7669a9a014267e",
+            "60a59a57208d48aca71e9e850d22674b611f752bed48b36f7a91b372bd7ad468",
+        ],
+        output: [
+            "b58cfbe118e0cb94d79b5fd6a6dafb98764dff49c14e1770b566e42402da1a7d\
+             a4d8527693914139caee5bd03903af43a491351d23b430948dd50cde10d32b3c",
+            "8a9a2f3c7f085b65933594309041fc1898d42d0858e59f90814ae90571a6df60\
+             356f4610bf816f27afdd84f47719e480906d27ecd994985890e5f539e7ea74b6",
+        ],
+        batch_proof: "c0f43199e01ff4a8f9597e224a70b7577e408772e01f5d73a8d314f94f663a06\
+                      bbaddc3c0bc9a119a4bbda8e228afac5c38a2ba3830aeffc1b658e0f28cba90c",
+    };
+
+    const RISTRETTO255_POPRF: Vectors = Vectors {
+        blind: "64d37aed22a27f5191de1c1d69fadb899d8862b58eb4220029e036ec4c1f6706",
+        proof_random: "222a5e897cf59db8145db8d16e597e8facb80ae7d4e26d9881aa6f61d645fc0e",
+        sk: "145c79c108538421ac164ecbe131942136d5570b16d8bf41a24d4337da981e07",
+        pk: "c647bef38497bc6ec077c22af65b696efa43bff3b4a1975a3e8e0a1c5a79d631",
+        blinded: [
+            "c8713aa89241d6989ac142f22dba30596db635c772cbf25021fdd8f3d461f715",
+            "f0f0b209dd4d5f1844dac679acc7761b91a2e704879656cb7c201e82a99ab07d",
+        ],
+        evaluated: [
+            "1a4b860d808ff19624731e67b5eff20ceb2df3c3c03b906f5693e2078450d874",
+            "8c3c9d064c334c6991e99f286ea2301d1bde170b54003fb9c44c6d7bd6fc1540",
+        ],
+        output: [
+            "ca688351e88afb1d841fde4401c79efebb2eb75e7998fa9737bd5a82a152406d\
+             38bd29f680504e54fd4587eddcf2f37a2617ac2fbd2993f7bdf45442ace7d221",
+            "7c6557b276a137922a0bcfc2aa2b35dd78322bd500235eb6d6b6f91bc5b56a52\
+             de2d65612d503236b321f5d0bebcbc52b64b92e426f29c9b8b69f52de98ae507",
+        ],
+        batch_proof: "fc86b8af184c143bd824f16d3ba4567b5de6784790e621d4334180b99999bf01\
+                      d540181132112e5ac1563175c353f42bf6338daf83f0fb65cab947ebc1ca160f",
+    };
+
+    const P256_OPRF: Vectors = Vectors {
+        blind: "3338fa65ec36e0290022b48eb562889d89dbfa691d1cde91517fa222ed7ad364",
+        proof_random: "",
+        sk: "159749d750713afe245d2d39ccfaae8381c53ce92d098a9375ee70739c7ac0bf",
+        pk: "",
+        blinded: [
+            "03723a1e5c09b8b9c18d1dcbca29e8007e95f14f4732d9346d490ffc195110368d",
+            "03cc1df781f1c2240a64d1c297b3f3d16262ef5d4cf102734882675c26231b0838",
+        ],
+        evaluated: [
+            "030de02ffec47a1fd53efcdd1c6faf5bdc270912b8749e783c7ca75bb412958832",
+            "03a0395fe3828f2476ffcd1f4fe540e5a8489322d398be3c4e5a869db7fcb7c52c",
+        ],
+        output: [
+            "a0b34de5fa4c5b6da07e72af73cc507cceeb48981b97b7285fc375345fe495dd",
+            "c748ca6dd327f0ce85f4ae3a8cd6d4d5390bbb804c9e12dcf94f853fece3dcce",
+        ],
+        batch_proof: "",
+    };
+
+    const P256_VOPRF: Vectors = Vectors {
+        blind: "3338fa65ec36e0290022b48eb562889d89dbfa691d1cde91517fa222ed7ad364",
+        proof_random: "f9db001266677f62c095021db018cd8cbb55941d4073698ce45c405d1348b7b1",
+        sk: "ca5d94c8807817669a51b196c34c1b7f8442fde4334a7121ae4736364312fca6",
+        pk: "03e17e70604bcabe198882c0a1f27a92441e774224ed9c702e51dd17038b102462",
+        blinded: [
+            "02dd05901038bb31a6fae01828fd8d0e49e35a486b5c5d4b4994013648c01277da",
+            "03cd0f033e791c4d79dfa9c6ed750f2ac009ec46cd4195ca6fd3800d1e9b887dbd",
+        ],
+        evaluated: [
+            "0209f33cab60cf8fe69239b0afbcfcd261af4c1c5632624f2e9ba29b90ae83e4a2",
+            "030d2985865c693bf7af47ba4d3a3813176576383d19aff003ef7b0784a0d83cf1",
+        ],
+        output: [
+            "0412e8f78b02c415ab3a288e228978376f99927767ff37c5718d420010a645a1",
+            "771e10dcd6bcd3664e23b8f2a710cfaaa8357747c4a8cbba03133967b5c24f18",
+        ],
+        batch_proof: "22da6fd983ea5f23bbfb941ff05fb41194886f03de2c3b73e6afe0f72fc9a155\
+                      620960ee5755e366fc559ead281d6314c9fd7c77471d5f3f3e993aa0a1e6977a",
+    };
+
+    const P256_POPRF: Vectors = Vectors {
+        blind: "3338fa65ec36e0290022b48eb562889d89dbfa691d1cde91517fa222ed7ad364",
+        proof_random: "f9db001266677f62c095021db018cd8cbb55941d4073698ce45c405d1348b7b1",
+        sk: "6ad2173efa689ef2c27772566ad7ff6e2d59b3b196f00219451fb2c89ee4dae2",
+        pk: "030d7ff077fddeec965db14b794f0cc1ba9019b04a2f4fcc1fa525dedf72e2a3e3",
+        blinded: [
+            "031563e127099a8f61ed51eeede05d747a8da2be329b40ba1f0db0b2bd9dd4e2c0",
+            "021a440ace8ca667f261c10ac7686adc66a12be31e3520fca317643a1eee9dcd4d",
+        ],
+        evaluated: [
+            "02c5e5300c2d9e6ba7f3f4ad60500ad93a0157e6288eb04b67e125db024a2c74d2",
+            "0208ca109cbae44f4774fc0bdd2783efdcb868cb4523d52196f700210e777c5de3",
+        ],
+        output: [
+            "193a92520bd8fd1f37accb918040a57108daa110dc4f659abe212636d245c592",
+            "1e6d164cfd835d88a31401623549bf6b9b306628ef03a7962921d62bc5ffce8c",
+        ],
+        batch_proof: "6f2fd0c3cb10268155aae1e0572ee113e8a43b828106f528169efda0baf9ac8c\
+                      6a949176fe846e819bac341c6eef4272c403335e53229900523c065f43e64497",
+    };
+
+    #[test]
+    fn ristretto255_oprf() {
+        check_oprf::<Ristretto255>(&RISTRETTO255_OPRF);
+    }
+
+    #[test]
+    fn ristretto255_voprf() {
+        check_voprf::<Ristretto255>(&RISTRETTO255_VOPRF);
+
+        // single-element proof
+        let key = Voprf::<Ristretto255>::derive_key_pair(&SEED, KEY_INFO).unwrap();
+        let (_, blinded) = test_blind(&Voprf::CONTEXT, &RISTRETTO255_VOPRF, INPUTS[0]);
+        let random = unhex(RISTRETTO255_VOPRF.proof_random);
+        let (_, proof) =
+            Voprf::blind_evaluate_with_rng(&key, &blinded, &mut SliceRandomSource(&random))
+                .unwrap();
+        assert_eq!(
+            hex(proof.encode(&mut [0u8; 64]).unwrap()),
+            "ddef93772692e535d1a53903db24367355cc2cc78de93b3be5a8ffcc6985dd06\
+             6d4346421d17bf5117a2a1ff0fcb2a759f58a539dfbe857a40bce4cf49ec600d"
+        );
+    }
+
+    #[test]
+    fn ristretto255_poprf() {
+        check_poprf::<Ristretto255>(&RISTRETTO255_POPRF);
+    }
+
+    #[test]
+    fn p256_oprf() {
+        check_oprf::<P256>(&P256_OPRF);
+    }
+
+    #[test]
+    fn p256_voprf() {
+        check_voprf::<P256>(&P256_VOPRF);
+    }
+
+    #[test]
+    fn p256_poprf() {
+        check_poprf::<P256>(&P256_POPRF);
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = PrivateKey::<P256>::new_random().unwrap();
+        let (blind, blinded) = Voprf::<P256>::blind(b"password").unwrap();
+        let blinded = BlindedElement::from_bytes(blinded.as_bytes().as_ref()).unwrap();
+        let (evaluated, proof) = Voprf::blind_evaluate(&key, &blinded).unwrap();
+        let blind = Blind::from_bytes(blind.as_bytes().as_ref()).unwrap();
+        let evaluated = EvaluatedElement::from_bytes(evaluated.as_bytes().as_ref()).unwrap();
+        let pk = PublicKey::from_bytes(key.public_key().as_bytes().as_ref()).unwrap();
+        let output =
+            Voprf::finalize(b"password", &blind, &evaluated, &blinded, &pk, &proof).unwrap();
+
+        let key = PrivateKey::<P256>::from_bytes(key.as_bytes().as_ref()).unwrap();
+        assert_eq!(Voprf::evaluate(&key, b"password").unwrap(), output);
+        // modes are domain separated
+        assert_ne!(Oprf::evaluate(&key, b"password").unwrap(), output);
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert!(Oprf::<Ristretto255>::blind(&[0u8; 0x10000]).is_err());
+        let key = PrivateKey::<Ristretto255>::new_random().unwrap();
+        assert_eq!(
+            Poprf::evaluate(&key, b"input", &[0u8; 0x10000]),
+            Err(Error::WrongLength)
+        );
+
+        assert!(BlindedElement::<Ristretto255>::from_bytes(&[0; 32]).is_err());
+        assert!(EvaluatedElement::<P256>::from_bytes(&[0; 33]).is_err());
+        assert!(Proof::<P256>::from_bytes(&[0; 63]).is_err());
+        assert_eq!(
+            Proof::<P256>::from_bytes(&[0xff; 64])
+                .map(|_| ())
+                .unwrap_err(),
+            Error::OutOfRange
+        );
+    }
+}
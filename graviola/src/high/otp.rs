@@ -0,0 +1,255 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! One-time passwords: HOTP and TOTP.
+//!
+//! HOTP is standardized in [RFC4226](https://datatracker.ietf.org/doc/html/rfc4226),
+//! and TOTP in [RFC6238](https://datatracker.ietf.org/doc/html/rfc6238).
+//!
+//! These are generic over the hash function used for the underlying HMAC.
+//! SHA-1 (the default in those RFCs, and for many authenticator apps) is
+//! available here as [`Sha1`]; it is not exported for general use.
+//!
+//! ```
+//! use graviola::hashing::{otp::Totp, Sha256};
+//!
+//! let totp = Totp::<Sha256>::new(b"12345678901234567890123456789012", 8, 30).unwrap();
+//! assert_eq!(totp.generate(59), 46119246);
+//! // accept codes from one period either side
+//! assert!(totp.verify(89, 46119246, 1).is_ok());
+//! assert!(totp.verify(90, 46119246, 1).is_err());
+//!
+//! use graviola::hashing::otp::{Hotp, Sha1};
+//!
+//! let hotp = Hotp::<Sha1>::new(b"12345678901234567890", 6).unwrap();
+//! assert_eq!(hotp.generate(0), 755224);
+//! ```
+
+use super::hash::Hash;
+pub use super::hash::Sha1;
+use super::hmac::Hmac;
+use crate::Error;
+use crate::low::ct_equal;
+
+/// An HOTP generator and verifier, using HMAC with hash function `H`.
+#[derive(Clone)]
+pub struct Hotp<H: Hash + Clone> {
+    hmac: Hmac<H>,
+    digits: u32,
+}
+
+impl<H: Hash + Clone> Hotp<H> {
+    /// Create a new HOTP instance, with the given key and number of digits.
+    ///
+    /// `digits` must be between 6 and 9 inclusive, otherwise
+    /// [`Error::OutOfRange`] is returned.
+    pub fn new(key: impl AsRef<[u8]>, digits: u32) -> Result<Self, Error> {
+        if !(6..=9).contains(&digits) {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(Self {
+            hmac: Hmac::new(key),
+            digits,
+        })
+    }
+
+    /// Return the code for `counter`.
+    ///
+    /// The code has (at most) the configured number of digits;
+    /// when displayed it should be padded with leading zeroes.
+    pub fn generate(&self, counter: u64) -> u32 {
+        let mut hmac = self.hmac.clone();
+        hmac.update(counter.to_be_bytes());
+        let tag = hmac.finish();
+        let tag = tag.as_ref();
+
+        // "dynamic truncation", see RFC4226 section 5.3
+        let offset = (tag[tag.len() - 1] & 0xf) as usize;
+        let binary = u32::from_be_bytes([
+            tag[offset] & 0x7f,
+            tag[offset + 1],
+            tag[offset + 2],
+            tag[offset + 3],
+        ]);
+        binary % 10u32.pow(self.digits)
+    }
+
+    /// Check whether `code` is correct for any counter value in
+    /// `counter..=counter + look_ahead`.
+    ///
+    /// On success, the matching counter value is returned.  The caller
+    /// should then store a counter value one greater than this,
+    /// to prevent replay.  Otherwise [`Error::BadSignature`] is returned.
+    ///
+    /// Every counter value in the window is checked, and comparisons are
+    /// done in constant time.
+    pub fn verify(&self, counter: u64, code: u32, look_ahead: u64) -> Result<u64, Error> {
+        let mut found = None;
+        for candidate in counter..=counter.saturating_add(look_ahead) {
+            let matches = ct_equal(&self.generate(candidate).to_be_bytes(), &code.to_be_bytes());
+            if matches && found.is_none() {
+                found = Some(candidate);
+            }
+        }
+        found.ok_or(Error::BadSignature)
+    }
+}
+
+/// A TOTP generator and verifier, using HMAC with hash function `H`.
+///
+/// Times are expressed as seconds since the UNIX epoch, which is
+/// also the TOTP starting time ("T0" in RFC6238).
+#[derive(Clone)]
+pub struct Totp<H: Hash + Clone> {
+    hotp: Hotp<H>,
+    period: u64,
+}
+
+impl<H: Hash + Clone> Totp<H> {
+    /// Create a new TOTP instance.
+    ///
+    /// `digits` is the number of digits in each code, which must be between
+    /// 6 and 9 inclusive.  `period` is the time step in seconds, which
+    /// must be non-zero; 30 is a common choice.  Otherwise
+    /// [`Error::OutOfRange`] is returned.
+    pub fn new(key: impl AsRef<[u8]>, digits: u32, period: u64) -> Result<Self, Error> {
+        if period == 0 {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(Self {
+            hotp: Hotp::new(key, digits)?,
+            period,
+        })
+    }
+
+    /// Return the code valid at `unix_time`.
+    pub fn generate(&self, unix_time: u64) -> u32 {
+        self.hotp.generate(self.time_step(unix_time))
+    }
+
+    /// Check whether `code` is valid at `unix_time`, allowing for `skew`
+    /// time steps of clock difference in either direction.
+    ///
+    /// On success, the matching time step is returned.  The caller
+    /// should reject later attempts to use a code from the same (or an
+    /// earlier) time step, to prevent replay.  Otherwise
+    /// [`Error::BadSignature`] is returned.
+    pub fn verify(&self, unix_time: u64, code: u32, skew: u64) -> Result<u64, Error> {
+        let step = self.time_step(unix_time);
+        let first = step.saturating_sub(skew);
+        self.hotp
+            .verify(first, code, step.saturating_add(skew) - first)
+    }
+
+    fn time_step(&self, unix_time: u64) -> u64 {
+        unix_time / self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::hash::{Sha256, Sha512};
+    use super::*;
+
+    #[test]
+    fn rfc4226_appendix_d() {
+        let hotp = Hotp::<Sha1>::new(b"12345678901234567890", 6).unwrap();
+        for (counter, code) in [
+            755224, 287082, 359152, 969429, 338314, 254676, 287922, 162583, 399871, 520489,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(hotp.generate(counter as u64), code);
+        }
+    }
+
+    #[test]
+    fn rfc6238_sha1_test_vectors() {
+        let sha1 = Totp::<Sha1>::new(b"12345678901234567890", 8, 30).unwrap();
+
+        for (time, code) in [
+            (59, 94287082),
+            (1111111109, 7081804),
+            (1111111111, 14050471),
+            (1234567890, 89005924),
+            (2000000000, 69279037),
+            (20000000000, 65353130),
+        ] {
+            assert_eq!(sha1.generate(time), code);
+            assert_eq!(sha1.verify(time, code, 0), Ok(time / 30));
+        }
+    }
+
+    #[test]
+    fn rfc6238_test_vectors() {
+        let sha256 = Totp::<Sha256>::new(b"12345678901234567890123456789012", 8, 30).unwrap();
+        let sha512 = Totp::<Sha512>::new(
+            b"1234567890123456789012345678901234567890123456789012345678901234",
+            8,
+            30,
+        )
+        .unwrap();
+
+        for (time, code256, code512) in [
+            (59, 46119246, 90693936),
+            (1111111109, 68084774, 25091201),
+            (1111111111, 67062674, 99943326),
+            (1234567890, 91819424, 93441116),
+            (2000000000, 90698825, 38618901),
+            (20000000000, 77737706, 47863826),
+        ] {
+            assert_eq!(sha256.generate(time), code256);
+            assert_eq!(sha512.generate(time), code512);
+            assert_eq!(sha256.verify(time, code256, 0), Ok(time / 30));
+            assert_eq!(sha512.verify(time, code512, 0), Ok(time / 30));
+        }
+    }
+
+    #[test]
+    fn digits() {
+        let key = b"12345678901234567890123456789012";
+        assert!(Hotp::<Sha256>::new(key, 5).is_err());
+        assert!(Hotp::<Sha256>::new(key, 10).is_err());
+        assert!(Totp::<Sha256>::new(key, 6, 0).is_err());
+
+        // truncating to fewer digits yields a suffix of the longer code
+        let eight = Hotp::<Sha256>::new(key, 8).unwrap();
+        let six = Hotp::<Sha256>::new(key, 6).unwrap();
+        let nine = Hotp::<Sha256>::new(key, 9).unwrap();
+        for counter in 0..20 {
+            assert_eq!(eight.generate(counter) % 1_000_000, six.generate(counter));
+            assert_eq!(
+                nine.generate(counter) % 100_000_000,
+                eight.generate(counter)
+            );
+        }
+    }
+
+    #[test]
+    fn hotp_look_ahead() {
+        let hotp = Hotp::<Sha256>::new(b"key", 6).unwrap();
+        let code = hotp.generate(105);
+        assert_eq!(hotp.verify(105, code, 0), Ok(105));
+        assert_eq!(hotp.verify(100, code, 5), Ok(105));
+        assert_eq!(hotp.verify(100, code, 4), Err(Error::BadSignature));
+        assert_eq!(hotp.verify(106, code, 10), Err(Error::BadSignature));
+        assert!(hotp.verify(u64::MAX, hotp.generate(u64::MAX), 10).is_ok());
+    }
+
+    #[test]
+    fn totp_skew() {
+        let totp = Totp::<Sha256>::new(b"key", 6, 30).unwrap();
+        let code = totp.generate(3000);
+        assert_eq!(totp.verify(3000, code, 0), Ok(100));
+        assert_eq!(totp.verify(2970, code, 1), Ok(100));
+        assert_eq!(totp.verify(3030, code, 1), Ok(100));
+        assert_eq!(totp.verify(3060, code, 1), Err(Error::BadSignature));
+        assert_eq!(totp.verify(3060, code, 2), Ok(100));
+
+        let early = totp.generate(0);
+        assert_eq!(totp.verify(0, early, 5), Ok(0));
+    }
+}
@@ -0,0 +1,127 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! PBKDF2 (Password-Based Key Derivation Function 2).
+//!
+//! PBKDF2 is standardized in [RFC8018](https://datatracker.ietf.org/doc/html/rfc8018#section-5.2),
+//! and here always uses HMAC as its pseudorandom function.
+//!
+//! PBKDF2 is only as strong as its iteration count makes it, and is
+//! principally provided for compatibility with existing formats.
+//!
+//! ```
+//! use graviola::hashing::{pbkdf2, Sha256};
+//!
+//! let mut key = [0u8; 32];
+//! pbkdf2::pbkdf2::<Sha256>(b"password", b"salt", 600_000, &mut key).unwrap();
+//! ```
+
+use super::hash::Hash;
+use super::hmac::Hmac;
+use crate::Error;
+use crate::low::zeroise;
+
+/// Fill `out` with key material derived from `password` and `salt`.
+///
+/// `iterations` must be at least one, otherwise [`Error::OutOfRange`]
+/// is returned.  `out` may be at most 2<sup>32</sup>-1 times the hash
+/// function output length, otherwise [`Error::WrongLength`] is returned.
+pub fn pbkdf2<H: Hash + Clone>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    out: &mut [u8],
+) -> Result<(), Error> {
+    if iterations == 0 {
+        return Err(Error::OutOfRange);
+    }
+
+    let hash_len = H::zeroed_output().as_ref().len();
+    if out.len() as u64 > u32::MAX as u64 * hash_len as u64 {
+        return Err(Error::WrongLength);
+    }
+
+    let prf = Hmac::<H>::new(password);
+    let mut t = H::zeroed_output();
+
+    for (i, chunk) in out.chunks_mut(hash_len).enumerate() {
+        let mut u = prf.clone();
+        u.update(salt);
+        u.update((i as u32 + 1).to_be_bytes());
+        let mut u = u.finish();
+        t.as_mut().copy_from_slice(u.as_ref());
+
+        for _ in 1..iterations {
+            let mut ctx = prf.clone();
+            ctx.update(&u);
+            u = ctx.finish();
+            for (t, u) in t.as_mut().iter_mut().zip(u.as_ref()) {
+                *t ^= *u;
+            }
+        }
+
+        chunk.copy_from_slice(&t.as_ref()[..chunk.len()]);
+        zeroise(u.as_mut());
+    }
+
+    zeroise(t.as_mut());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::{Sha256, Sha384, Sha512};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn rfc7914_vectors() {
+        let mut out = [0u8; 64];
+        pbkdf2::<Sha256>(b"passwd", b"salt", 1, &mut out).unwrap();
+        assert_eq!(
+            hex(&out),
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc\
+             49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        );
+
+        pbkdf2::<Sha256>(b"Password", b"NaCl", 80000, &mut out).unwrap();
+        assert_eq!(
+            hex(&out),
+            "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56\
+             a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+        );
+    }
+
+    #[test]
+    fn other_hashes() {
+        // computed with python's `hashlib.pbkdf2_hmac`
+        let mut out = [0u8; 64];
+        pbkdf2::<Sha512>(b"password", b"salt", 4096, &mut out).unwrap();
+        assert_eq!(
+            hex(&out),
+            "d197b1b33db0143e018b12f3d1d1479e6cdebdcc97c5c0f87f6902e072f457b5\
+             143f30602641b3d55cd335988cb36b84376060ecd532e039b742a239434af2d5"
+        );
+
+        // a partial final block
+        let mut out = [0u8; 70];
+        pbkdf2::<Sha384>(b"password", b"salt", 2, &mut out).unwrap();
+        assert_eq!(
+            hex(&out),
+            "54f775c6d790f21930459162fc535dbf04a939185127016a04176a0730c6f1f4\
+             fb48832ad1261baadd2cedd50814b1c806ad1bbf43ebdc9d047904bf7ceafe1e\
+             beeba01a91d1"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            pbkdf2::<Sha256>(b"password", b"salt", 0, &mut [0u8; 32]),
+            Err(Error::OutOfRange)
+        );
+    }
+}
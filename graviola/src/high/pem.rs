@@ -0,0 +1,365 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! PEM encapsulation of DER-encoded keys, as in
+//! [RFC7468](https://datatracker.ietf.org/doc/html/rfc7468).
+
+use core::str::Lines;
+
+use super::base64;
+use crate::error::{Error, KeyFormatError};
+use crate::low::zeroise;
+
+/// A single PEM document: a type label, and its (usually DER) contents.
+///
+/// The contents are zeroised on drop, as they are frequently private keys.
+pub struct Document {
+    label: String,
+    contents: Vec<u8>,
+}
+
+impl Document {
+    /// Make a new document, from `label` (eg. `PRIVATE KEY`) and `contents`.
+    ///
+    /// `label` must obey the syntax in
+    /// [RFC7468 section 3](https://datatracker.ietf.org/doc/html/rfc7468#section-3):
+    /// printable ASCII, with single spaces or hyphens between other characters.
+    /// Otherwise [`Error::KeyFormatError`] (`InvalidPem`) is returned.
+    pub fn new(label: &str, contents: &[u8]) -> Result<Self, Error> {
+        match valid_label(label) {
+            true => Ok(Self {
+                label: label.to_string(),
+                contents: contents.to_vec(),
+            }),
+            false => Err(KeyFormatError::InvalidPem.into()),
+        }
+    }
+
+    /// Decode the first PEM document in `text`.
+    ///
+    /// See [`Self::iter()`] for the accepted syntax.
+    pub fn from_pem(text: &str) -> Result<Self, Error> {
+        Self::iter(text)
+            .next()
+            .unwrap_or(Err(KeyFormatError::InvalidPem.into()))
+    }
+
+    /// Iterate over all the PEM documents in `text`.
+    ///
+    /// Text outside the documents (explanatory text, in the words of RFC7468)
+    /// is skipped.  Whitespace at the end of each line is ignored, and lines may
+    /// end in `\n` or `\r\n`.  Otherwise, parsing is strict:
+    ///
+    /// - labels must be valid, and the `END` label must match the `BEGIN` label,
+    /// - the contents must be padded, standard-alphabet base64 without blank lines,
+    /// - RFC1421-style headers (such as `Proc-Type`) are not supported.
+    ///
+    /// Errors are yielded as [`Error::KeyFormatError`] (`InvalidPem` or
+    /// `MismatchedPemLabel`), after which iteration stops.
+    pub fn iter(text: &str) -> Documents<'_> {
+        Documents {
+            lines: text.lines(),
+            failed: false,
+        }
+    }
+
+    /// The type label, eg. `PUBLIC KEY`.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The decoded contents.
+    pub fn contents(&self) -> &[u8] {
+        &self.contents
+    }
+
+    /// Encode this document.
+    ///
+    /// Lines are wrapped at 64 characters, and end with `\n`.
+    pub fn to_pem(&self) -> String {
+        encode(&self.label, &self.contents)
+    }
+}
+
+impl Drop for Document {
+    fn drop(&mut self) {
+        zeroise(&mut self.contents);
+    }
+}
+
+/// An iterator over the PEM documents in some text.
+///
+/// Made by [`Document::iter()`].
+pub struct Documents<'a> {
+    lines: Lines<'a>,
+    failed: bool,
+}
+
+impl Documents<'_> {
+    fn document(&mut self, label: &str) -> Result<Document, Error> {
+        let mut body = Vec::new();
+        let result = self.body(label, &mut body);
+        let result = result.and_then(|()| {
+            core::str::from_utf8(&body)
+                .ok()
+                .and_then(base64::decode)
+                .ok_or(KeyFormatError::InvalidPem.into())
+        });
+        zeroise(&mut body);
+
+        result.map(|contents| Document {
+            label: label.to_string(),
+            contents,
+        })
+    }
+
+    /// Collect the base64 lines up to the END line for `label`.
+    fn body(&mut self, label: &str, body: &mut Vec<u8>) -> Result<(), Error> {
+        for line in self.lines.by_ref().map(str::trim_end) {
+            if let Some(rest) = line.strip_prefix("-----END ") {
+                return match boundary_label(rest) {
+                    Some(end) if end == label => Ok(()),
+                    Some(_) => Err(KeyFormatError::MismatchedPemLabel.into()),
+                    None => Err(KeyFormatError::InvalidPem.into()),
+                };
+            }
+            if line.is_empty() {
+                return Err(KeyFormatError::InvalidPem.into());
+            }
+            body.extend_from_slice(line.as_bytes());
+        }
+
+        Err(KeyFormatError::InvalidPem.into())
+    }
+}
+
+impl Iterator for Documents<'_> {
+    type Item = Result<Document, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let begin = self
+            .lines
+            .by_ref()
+            .map(str::trim_end)
+            .find_map(|line| line.strip_prefix("-----BEGIN "))?;
+
+        let result = match boundary_label(begin) {
+            Some(label) => self.document(label),
+            None => Err(KeyFormatError::InvalidPem.into()),
+        };
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+/// Encode `der` with the given `label`, eg. `PRIVATE KEY`.
+///
+/// Lines are wrapped at 64 characters, and end with `\n`.
+///
+/// The output is allocated once, so no partial copies of `der` (which is
+/// often a private key) are left behind.
+pub(crate) fn encode(label: &str, der: &[u8]) -> String {
+    let lines = (der.len() + 47) / 48;
+    let mut out =
+        String::with_capacity(2 * (label.len() + 17) + base64::encoded_len(der.len()) + lines);
+    out.push_str("-----BEGIN ");
+    out.push_str(label);
+    out.push_str("-----\n");
+    for chunk in der.chunks(48) {
+        base64::encode_into(&mut out, chunk);
+        out.push('\n');
+    }
+    out.push_str("-----END ");
+    out.push_str(label);
+    out.push_str("-----\n");
+    out
+}
+
+/// Decode the first PEM document in `text` with one of `labels`, and give
+/// its label and contents to `f`.
+///
+/// Other documents are skipped, so (for example) a key can be found in a
+/// file that also contains certificates or curve parameters.
+/// [`Error::KeyFormatError`] (`MismatchedPemLabel`) is returned if there
+/// is no such document.
+pub(crate) fn decode_first<T>(
+    text: &str,
+    labels: &[&str],
+    f: impl FnOnce(&str, &[u8]) -> Result<T, Error>,
+) -> Result<T, Error> {
+    for doc in Document::iter(text) {
+        let doc = doc?;
+        if labels.contains(&doc.label()) {
+            return f(doc.label(), doc.contents());
+        }
+    }
+
+    Err(KeyFormatError::MismatchedPemLabel.into())
+}
+
+/// The label in `LABEL-----` after a `-----BEGIN ` or `-----END ` prefix.
+fn boundary_label(rest: &str) -> Option<&str> {
+    rest.strip_suffix("-----")
+        .filter(|label| valid_label(label))
+}
+
+/// `label` from RFC7468 section 3.
+fn valid_label(label: &str) -> bool {
+    let is_labelchar = |b: &u8| matches!(b, 0x21..=0x2c | 0x2e..=0x7e);
+    label
+        .as_bytes()
+        .split(|b| *b == b' ' || *b == b'-')
+        .all(|part| !part.is_empty() && part.iter().all(is_labelchar))
+        || label.is_empty()
+}
+
+pub(crate) const PRIVATE_KEY: &str = "PRIVATE KEY";
+pub(crate) const PUBLIC_KEY: &str = "PUBLIC KEY";
+pub(crate) const RSA_PRIVATE_KEY: &str = "RSA PRIVATE KEY";
+pub(crate) const RSA_PUBLIC_KEY: &str = "RSA PUBLIC KEY";
+pub(crate) const EC_PRIVATE_KEY: &str = "EC PRIVATE KEY";
+pub(crate) const ENCRYPTED_PRIVATE_KEY: &str = "ENCRYPTED PRIVATE KEY";
+pub(crate) const OPENSSH_PRIVATE_KEY: &str = "OPENSSH PRIVATE KEY";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(label: &str, text: &str) -> Result<Vec<u8>, Error> {
+        decode_first(text, &[label], |_, contents| Ok(contents.to_vec()))
+    }
+
+    #[test]
+    fn round_trip() {
+        for len in [0, 1, 47, 48, 49, 100] {
+            let der = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+            let pem = encode("TEST", &der);
+            assert!(pem.lines().all(|line| line.len() <= 64));
+            // allocated once, with (almost) exactly the right capacity
+            assert!(pem.capacity() - pem.len() <= 2);
+            assert_eq!(decode("TEST", &pem).unwrap(), der);
+
+            let doc = Document::new("TEST", &der).unwrap();
+            assert_eq!(doc.to_pem(), pem);
+            let doc = Document::from_pem(&pem).unwrap();
+            assert_eq!(doc.label(), "TEST");
+            assert_eq!(doc.contents(), der);
+        }
+    }
+
+    #[test]
+    fn lenient_framing() {
+        let pem =
+            "explanatory text\r\n-----BEGIN TEST-----  \r\nAAEC\r\nAw==\r\n-----END TEST-----\r\n";
+        assert_eq!(decode("TEST", pem).unwrap(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn multiple_documents() {
+        let pem = "-----BEGIN EC PARAMETERS-----\n\
+                   BggqhkjOPQMBBw==\n\
+                   -----END EC PARAMETERS-----\n\
+                   between\n\
+                   -----BEGIN TEST-----\n\
+                   AAEC\n\
+                   -----END TEST-----\n\
+                   -----BEGIN TEST-----\n\
+                   AwQF\n\
+                   -----END TEST-----\n";
+        let docs = Document::iter(pem)
+            .map(|doc| doc.map(|doc| (doc.label().to_string(), doc.contents().to_vec())))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            docs,
+            vec![
+                (
+                    "EC PARAMETERS".to_string(),
+                    vec![0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07]
+                ),
+                ("TEST".to_string(), vec![0, 1, 2]),
+                ("TEST".to_string(), vec![3, 4, 5]),
+            ]
+        );
+
+        // the first matching document is used
+        assert_eq!(decode("TEST", pem).unwrap(), &[0, 1, 2]);
+        assert_eq!(
+            decode("OTHER", pem),
+            Err(KeyFormatError::MismatchedPemLabel.into())
+        );
+
+        // iteration stops at the first error
+        let pem = "-----BEGIN TEST-----\nAA!C\n-----END TEST-----\n\
+                   -----BEGIN TEST-----\nAAEC\n-----END TEST-----\n";
+        let mut docs = Document::iter(pem);
+        assert!(docs.next().unwrap().is_err());
+        assert!(docs.next().is_none());
+    }
+
+    #[test]
+    fn labels() {
+        for label in ["", "A", "PRIVATE KEY", "X509 CRL", "A-B C", "!~"] {
+            assert!(valid_label(label), "{label:?}");
+            assert!(Document::new(label, b"").is_ok());
+        }
+        for label in [" ", "-", "A ", " A", "A-", "A  B", "A- B", "\u{e9}", "A\tB"] {
+            assert!(!valid_label(label), "{label:?}");
+            assert_eq!(
+                Document::new(label, b"").err(),
+                Some(KeyFormatError::InvalidPem.into())
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for (pem, err) in [
+            ("", KeyFormatError::MismatchedPemLabel),
+            ("-----BEGIN TEST-----\nAAEC\n", KeyFormatError::InvalidPem),
+            (
+                "-----BEGIN TEST-----\nAAE!\n-----END TEST-----\n",
+                KeyFormatError::InvalidPem,
+            ),
+            (
+                "-----BEGIN TEST-----\nAA==AA==\n-----END TEST-----\n",
+                KeyFormatError::InvalidPem,
+            ),
+            (
+                "-----BEGIN TEST-----\nAAEC\n\nAw==\n-----END TEST-----\n",
+                KeyFormatError::InvalidPem,
+            ),
+            (
+                "-----BEGIN TEST-----\nProc-Type: 4,ENCRYPTED\n\nAAEC\n-----END TEST-----\n",
+                KeyFormatError::InvalidPem,
+            ),
+            (
+                "-----BEGIN TEST ----\nAAEC\n-----END TEST-----\n",
+                KeyFormatError::InvalidPem,
+            ),
+            (
+                "-----BEGIN TEST-----\nAAEC\n-----END  TEST-----\n",
+                KeyFormatError::InvalidPem,
+            ),
+            (
+                "-----BEGIN OTHER-----\nAAEC\n-----END OTHER-----\n",
+                KeyFormatError::MismatchedPemLabel,
+            ),
+            (
+                "-----BEGIN TEST-----\nAAEC\n-----END OTHER-----\n",
+                KeyFormatError::MismatchedPemLabel,
+            ),
+        ] {
+            assert_eq!(decode("TEST", pem), Err(err.into()), "{pem:?}");
+        }
+
+        assert_eq!(
+            Document::from_pem("no documents here").err(),
+            Some(KeyFormatError::InvalidPem.into())
+        );
+    }
+}
@@ -1,14 +1,17 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
+#[cfg(doc)]
+use super::hash::Digest;
 use super::hash::{Hash, HashContext};
 use crate::Error;
+use crate::low::ct_equal;
 use crate::mid::rng::RandomSource;
 
 /// This is EMSA-PKCS1-v1_5-ENCODE
 ///
 /// `out` is the modulus-size output buffer.
-/// `digest_info` is a constant `DIGESTINFO_*` from this module.
+/// `digest_info` is [`Digest::PKCS1_DIGEST_INFO`] for the hash function used.
 /// `hash` is the hash of the message.
 ///
 /// panics if the encoding cannot fit.  this is not reachable
@@ -56,17 +59,17 @@ pub(crate) fn encode_pkcs1_sig(out: &mut [u8], digest_info: &[u8], hash: &[u8])
     hash_out.copy_from_slice(hash);
 }
 
-pub(crate) static DIGESTINFO_SHA256: &[u8] = &[
+pub(crate) const DIGESTINFO_SHA256: &[u8] = &[
     0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
     0x00, 0x04, 0x20,
 ];
 
-pub(crate) static DIGESTINFO_SHA384: &[u8] = &[
+pub(crate) const DIGESTINFO_SHA384: &[u8] = &[
     0x30, 0x41, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02, 0x05,
     0x00, 0x04, 0x30,
 ];
 
-pub(crate) static DIGESTINFO_SHA512: &[u8] = &[
+pub(crate) const DIGESTINFO_SHA512: &[u8] = &[
     0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03, 0x05,
     0x00, 0x04, 0x40,
 ];
@@ -229,6 +232,145 @@ pub(crate) fn verify_pss_sig<H: Hash>(em: &mut [u8], m_hash: &[u8]) -> Result<()
     }
 }
 
+/// This is EME-OAEP encoding.
+///
+/// `MGF` is `MGF1` with hash `H`.  The label `L` is empty.
+/// `em` is the modulus-length output buffer.
+/// `message` is the message to encode.
+/// `rng` is used to generate the seed.
+///
+/// [`Error::OutOfRange`] is returned if `message` is too long.
+pub(crate) fn encode_oaep<H: Hash>(
+    em: &mut [u8],
+    rng: &mut dyn RandomSource,
+    message: &[u8],
+) -> Result<(), Error> {
+    // 1.  Length checking:
+    //     a. If the length of L is greater than the input limitation for
+    //        the hash function (2^61 - 1 octets for SHA-1), output
+    //        "label too long" and stop.
+    //     b. If mLen > k - 2hLen - 2, output "message too long" and stop.
+    let l_hash = H::hash(&[]);
+    let h_len = l_hash.as_ref().len();
+    let k = em.len();
+    if k < 2 * h_len + 2 || message.len() > k - 2 * h_len - 2 {
+        return Err(Error::OutOfRange);
+    }
+
+    // 2.  EME-OAEP encoding:
+    //     a. If the label L is not provided, let L be the empty string.
+    //        Let lHash = Hash(L), an octet string of length hLen.
+    //     b. Generate a padding string PS consisting of k - mLen - 2hLen - 2
+    //        zero octets.  The length of PS may be zero.
+    //     c. Concatenate lHash, PS, a single octet with hexadecimal value
+    //        0x01, and the message M to form a data block DB of length
+    //        k - hLen - 1 octets as
+    //
+    //           DB = lHash || PS || 0x01 || M.
+    let (y, rest) = em.split_first_mut().unwrap();
+    let (seed, db) = rest.split_at_mut(h_len);
+    let (db_l_hash, ps_one_m) = db.split_at_mut(h_len);
+    let (ps_one, m) = ps_one_m.split_at_mut(ps_one_m.len() - message.len());
+    let (one, ps) = ps_one.split_last_mut().unwrap();
+    db_l_hash.copy_from_slice(l_hash.as_ref());
+    ps.fill(0x00);
+    *one = 0x01;
+    m.copy_from_slice(message);
+
+    //     d. Generate a random octet string seed of length hLen.
+    rng.fill(seed)?;
+
+    //     e. Let dbMask = MGF(seed, k - hLen - 1).
+    //     f. Let maskedDB = DB \xor dbMask.
+    mgf1_xor::<H>(seed, h_len, db);
+
+    //     g. Let seedMask = MGF(maskedDB, hLen).
+    //     h. Let maskedSeed = seed \xor seedMask.
+    mgf1_xor::<H>(db, h_len, seed);
+
+    //     i. Concatenate a single octet with hexadecimal value 0x00,
+    //        maskedSeed, and maskedDB to form an encoded message EM of
+    //        length k octets as
+    //
+    //           EM = 0x00 || maskedSeed || maskedDB.
+    *y = 0x00;
+    Ok(())
+}
+
+/// This is EME-OAEP decoding.
+///
+/// `MGF` is `MGF1` with hash `H`.  The label `L` is empty.
+/// `em` is the modulus-length input and temporary buffer.
+///
+/// Returns the message, as a suffix of `em`.  All failures give the same
+/// [`Error::DecryptFailed`], and the checks are done in constant time;
+/// only the length of the message (on success) is revealed.
+pub(crate) fn decode_oaep<H: Hash>(em: &mut [u8]) -> Result<&[u8], Error> {
+    // 1.  Length checking:
+    //     c. If k < 2hLen + 2, output "decryption error" and stop.
+    // (a. and b. are done by caller)
+    let l_hash = H::hash(&[]);
+    let h_len = l_hash.as_ref().len();
+    if em.len() < 2 * h_len + 2 {
+        return Err(Error::DecryptFailed);
+    }
+
+    // 3.  EME-OAEP decoding:
+    //     a. If the label L is not provided, let L be the empty string.
+    //        Let lHash = Hash(L), an octet string of length hLen.
+    //     b. Separate the encoded message EM into a single octet Y, an
+    //        octet string maskedSeed of length hLen, and an octet string
+    //        maskedDB of length k - hLen - 1 as
+    //
+    //           EM = Y || maskedSeed || maskedDB.
+    let (y, rest) = em.split_first_mut().unwrap();
+    let (masked_seed, masked_db) = rest.split_at_mut(h_len);
+
+    //     c. Let seedMask = MGF(maskedDB, hLen).
+    //     d. Let seed = maskedSeed \xor seedMask.
+    mgf1_xor::<H>(masked_db, h_len, masked_seed);
+    let seed = masked_seed;
+
+    //     e. Let dbMask = MGF(seed, k - hLen - 1).
+    //     f. Let DB = maskedDB \xor dbMask.
+    mgf1_xor::<H>(seed, h_len, masked_db);
+    let db = masked_db;
+
+    //     g. Separate DB into an octet string lHash' of length hLen, a
+    //        (possibly empty) padding string PS consisting of octets
+    //        with hexadecimal value 0x00, and a message M as
+    //
+    //           DB = lHash' || PS || 0x01 || M.
+    //
+    //        If there is no octet with hexadecimal value 0x01 to
+    //        separate PS from M, if lHash does not equal lHash', or if
+    //        Y is nonzero, output "decryption error" and stop.
+    let (l_hash_prime, ps_one_m) = db.split_at(h_len);
+
+    // (all these are 0 or 1, and computed without branching on secret data)
+    let mut good = is_zero(*y) & (ct_equal(l_hash.as_ref(), l_hash_prime) as u8);
+    let mut looking = 1u8;
+    let mut one_index = 0usize;
+    for (i, b) in ps_one_m.iter().enumerate() {
+        let is_one = is_zero(*b ^ 0x01);
+        let found = looking & is_one;
+        one_index |= i & 0usize.wrapping_sub(found as usize);
+        good &= (looking ^ 1) | is_one | is_zero(*b);
+        looking &= is_one ^ 1;
+    }
+    good &= looking ^ 1;
+
+    match good {
+        1 => Ok(&ps_one_m[one_index + 1..]),
+        _ => Err(Error::DecryptFailed),
+    }
+}
+
+/// Returns 1 if `b` is zero, or 0 otherwise.
+fn is_zero(b: u8) -> u8 {
+    ((b as u32).wrapping_sub(1) >> 31) as u8
+}
+
 /// Compute MGF1-H, and XOR the result into `out`.
 fn mgf1_xor<H: Hash>(seed: &[u8], h_len: usize, out: &mut [u8]) {
     for (chunk, counter) in out.chunks_mut(h_len).zip(0u32..) {
@@ -367,4 +509,68 @@ mod tests {
 
         verify_pss_sig::<hash::Sha256>(&mut buf, hash).unwrap();
     }
+
+    #[test]
+    fn oaep_round_trip() {
+        let mut em = [0u8; 256];
+        let mut seed = SliceRandomSource(&[0x55; 32]);
+        encode_oaep::<hash::Sha256>(&mut em, &mut seed, b"hello").unwrap();
+        assert_eq!(em[0], 0x00);
+        assert_eq!(
+            decode_oaep::<hash::Sha256>(&mut em.clone()).unwrap(),
+            b"hello"
+        );
+
+        // the error is the same, whatever is wrong
+        let mut bad_y = em;
+        bad_y[0] = 0x01;
+        let mut bad_l_hash = em;
+        bad_l_hash[40] ^= 0x01;
+        let mut bad_m = em;
+        bad_m[255] ^= 0x01;
+        for mut em in [bad_y, bad_l_hash, bad_m, [0u8; 256]] {
+            assert_eq!(
+                decode_oaep::<hash::Sha256>(&mut em).err(),
+                Some(Error::DecryptFailed)
+            );
+        }
+        assert_eq!(
+            decode_oaep::<hash::Sha256>(&mut [0u8; 65]).err(),
+            Some(Error::DecryptFailed)
+        );
+    }
+
+    #[test]
+    fn oaep_padding() {
+        // encode with no masking, by decoding DB directly
+        for (db_tail, expected) in [
+            (&b"\x01hello"[..], Some(&b"hello"[..])),
+            (b"\x00\x00\x01", Some(b"")),
+            (b"\x00\x02\x01hello", None),
+            (b"\x00\x00\x00", None),
+        ] {
+            let l_hash = hash::Sha256::hash(&[]);
+            let mut db = vec![0u8; 256 - 33];
+            db[..32].copy_from_slice(l_hash.as_ref());
+            let tail_start = db.len() - db_tail.len();
+            db[tail_start..].copy_from_slice(db_tail);
+
+            // mask as encode_oaep would, with a zero seed
+            let mut em = vec![0u8; 256];
+            let seed = [0u8; 32];
+            mgf1_xor::<hash::Sha256>(&seed, 32, &mut db);
+            em[33..].copy_from_slice(&db);
+            let mut masked_seed = seed;
+            mgf1_xor::<hash::Sha256>(&db, 32, &mut masked_seed);
+            em[1..33].copy_from_slice(&masked_seed);
+
+            match expected {
+                Some(message) => assert_eq!(decode_oaep::<hash::Sha256>(&mut em).unwrap(), message),
+                None => assert_eq!(
+                    decode_oaep::<hash::Sha256>(&mut em).err(),
+                    Some(Error::DecryptFailed)
+                ),
+            }
+        }
+    }
 }
@@ -0,0 +1,427 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Password-based encryption of PKCS#8 keys.
+//!
+//! This is PBES2 from [RFC8018](https://datatracker.ietf.org/doc/html/rfc8018#section-6.2),
+//! with PBKDF2 and AES.  AES-CBC is defined in RFC8018 itself; AES-GCM is
+//! not, and follows [RFC5084](https://datatracker.ietf.org/doc/html/rfc5084#section-3.2)
+//! for its parameters, with the tag appended to the ciphertext.
+
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::high::asn1::{self, Any, Integer, Type, oid, pkix};
+use crate::high::hash::{Sha256, Sha384, Sha512};
+use crate::high::pbkdf2::pbkdf2;
+use crate::low::{Entry, zeroise};
+use crate::mid::aes_cbc::{AesCbc, BLOCK_LEN};
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::rng::RandomSource;
+
+/// The encryption scheme.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Scheme {
+    Aes128Cbc,
+    Aes256Cbc,
+    Aes128Gcm,
+    Aes256Gcm,
+}
+
+impl Scheme {
+    fn from_oid(oid: &asn1::ObjectId) -> Option<Self> {
+        [
+            Self::Aes128Cbc,
+            Self::Aes256Cbc,
+            Self::Aes128Gcm,
+            Self::Aes256Gcm,
+        ]
+        .into_iter()
+        .find(|scheme| scheme.oid() == oid)
+    }
+
+    fn oid(self) -> &'static asn1::ObjectId {
+        match self {
+            Self::Aes128Cbc => &oid::id_aes128_CBC,
+            Self::Aes256Cbc => &oid::id_aes256_CBC,
+            Self::Aes128Gcm => &oid::id_aes128_GCM,
+            Self::Aes256Gcm => &oid::id_aes256_GCM,
+        }
+    }
+
+    fn key_len(self) -> usize {
+        match self {
+            Self::Aes128Cbc | Self::Aes128Gcm => 16,
+            Self::Aes256Cbc | Self::Aes256Gcm => 32,
+        }
+    }
+
+    fn iv_len(self) -> usize {
+        match self {
+            Self::Aes128Cbc | Self::Aes256Cbc => BLOCK_LEN,
+            Self::Aes128Gcm | Self::Aes256Gcm => GCM_NONCE_LEN,
+        }
+    }
+}
+
+/// The PBKDF2 pseudorandom function.
+///
+/// The default, HMAC-SHA1, is not supported.
+#[derive(Clone, Copy, Debug)]
+enum Prf {
+    HmacSha256,
+    HmacSha384,
+    HmacSha512,
+}
+
+impl Prf {
+    fn from_algorithm(prf: Option<&pkix::AlgorithmIdentifier<'_>>) -> Result<Self, Error> {
+        let prf = prf.ok_or_else(unsupported)?;
+        if !matches!(prf.parameters, None | Some(Any::Null(_))) {
+            return Err(unsupported());
+        }
+
+        [Self::HmacSha256, Self::HmacSha384, Self::HmacSha512]
+            .into_iter()
+            .find(|p| *p.oid() == prf.algorithm)
+            .ok_or_else(unsupported)
+    }
+
+    fn oid(self) -> &'static asn1::ObjectId {
+        match self {
+            Self::HmacSha256 => &oid::id_hmacWithSHA256,
+            Self::HmacSha384 => &oid::id_hmacWithSHA384,
+            Self::HmacSha512 => &oid::id_hmacWithSHA512,
+        }
+    }
+
+    fn derive(self, password: &[u8], salt: &[u8], iterations: u32, key: &mut [u8]) {
+        let rc = match self {
+            Self::HmacSha256 => pbkdf2::<Sha256>(password, salt, iterations, key),
+            Self::HmacSha384 => pbkdf2::<Sha384>(password, salt, iterations, key),
+            Self::HmacSha512 => pbkdf2::<Sha512>(password, salt, iterations, key),
+        };
+        // iterations is non-zero, and key is at most 32 bytes
+        rc.unwrap();
+    }
+}
+
+/// Decrypts an `EncryptedPrivateKeyInfo`, returning the `PrivateKeyInfo` encoding.
+///
+/// The caller should zeroise the result.
+pub(super) fn decrypt(bytes: &[u8], password: &[u8]) -> Result<Vec<u8>, Error> {
+    let _entry = Entry::new_secret();
+    let info = pkix::EncryptedPrivateKeyInfo::from_bytes(bytes).map_err(Error::Asn1Error)?;
+    if info.encryptionAlgorithm.algorithm != oid::id_PBES2 {
+        return Err(unsupported());
+    }
+
+    let pbes2 = parameters::<pkix::PBES2Params<'_>>(&info.encryptionAlgorithm)?;
+    if pbes2.keyDerivationFunc.algorithm != oid::id_PBKDF2 {
+        return Err(unsupported());
+    }
+
+    let kdf = parameters::<pkix::PBKDF2Params<'_>>(&pbes2.keyDerivationFunc)?;
+    let iterations = kdf.iterationCount.as_usize().map_err(Error::Asn1Error)?;
+    if !(1..=MAX_ITERATIONS).contains(&iterations) {
+        return Err(Error::OutOfRange);
+    }
+
+    let scheme = Scheme::from_oid(&pbes2.encryptionScheme.algorithm).ok_or_else(unsupported)?;
+    if let Some(key_len) = kdf.keyLength {
+        if key_len.as_usize() != Ok(scheme.key_len()) {
+            return Err(unsupported());
+        }
+    }
+    let prf = Prf::from_algorithm(kdf.prf.as_ref())?;
+
+    let mut plaintext = info.encryptedData.into_octets().to_vec();
+    let mut key = [0u8; 32];
+    let key = &mut key[..scheme.key_len()];
+
+    let rc = match scheme {
+        Scheme::Aes128Cbc | Scheme::Aes256Cbc => {
+            let iv = match &pbes2.encryptionScheme.parameters {
+                Some(Any::OctetString(iv)) => iv.clone().into_octets().try_into(),
+                _ => return Err(unsupported()),
+            }
+            .map_err(|_| unsupported())?;
+
+            prf.derive(password, kdf.salt.into_octets(), iterations as u32, key);
+            AesCbc::new(key)
+                .and_then(|cbc| cbc.decrypt(&iv, &mut plaintext))
+                .map_err(|_| Error::DecryptFailed)
+                .and_then(|()| unpad(&mut plaintext))
+        }
+        Scheme::Aes128Gcm | Scheme::Aes256Gcm => {
+            let gcm = parameters::<pkix::GCMParameters<'_>>(&pbes2.encryptionScheme)?;
+            let nonce = gcm
+                .aesNonce
+                .into_octets()
+                .try_into()
+                .map_err(|_| unsupported())?;
+            if gcm.aesICVlen.map(|len| len.as_usize()) != Some(Ok(GCM_TAG_LEN)) {
+                return Err(unsupported());
+            }
+
+            prf.derive(password, kdf.salt.into_octets(), iterations as u32, key);
+            match plaintext.len().checked_sub(GCM_TAG_LEN) {
+                Some(len) => {
+                    let (ciphertext, tag) = plaintext.split_at_mut(len);
+                    let rc = AesGcm::new(key).decrypt(&nonce, &[], ciphertext, tag);
+                    plaintext.truncate(len);
+                    rc
+                }
+                None => Err(Error::DecryptFailed),
+            }
+        }
+    };
+
+    zeroise(key);
+    match rc {
+        Ok(()) => Ok(plaintext),
+        Err(e) => {
+            zeroise(&mut plaintext);
+            Err(e)
+        }
+    }
+}
+
+/// Encrypts the `PrivateKeyInfo` encoding `plaintext`, writing the
+/// `EncryptedPrivateKeyInfo` encoding to the start of `output`.
+///
+/// The key is derived with PBKDF2-HMAC-SHA256, with a random salt.
+/// `output` should be [`MAX_OVERHEAD`] bytes longer than `plaintext`.
+pub(super) fn encrypt<'a>(
+    plaintext: &[u8],
+    password: &[u8],
+    scheme: Scheme,
+    iterations: u32,
+    rng: &mut dyn RandomSource,
+    output: &'a mut [u8],
+) -> Result<&'a [u8], Error> {
+    let _entry = Entry::new_secret();
+    if iterations == 0 {
+        return Err(Error::OutOfRange);
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    let mut iv = [0u8; BLOCK_LEN];
+    rng.fill(&mut salt)?;
+    rng.fill(&mut iv[..scheme.iv_len()])?;
+
+    let mut key = [0u8; 32];
+    let key = &mut key[..scheme.key_len()];
+    let prf = Prf::HmacSha256;
+    prf.derive(password, &salt, iterations, key);
+
+    let mut ciphertext = plaintext.to_vec();
+    let rc = match scheme {
+        Scheme::Aes128Cbc | Scheme::Aes256Cbc => {
+            let pad = BLOCK_LEN - plaintext.len() % BLOCK_LEN;
+            ciphertext.resize(plaintext.len() + pad, pad as u8);
+            AesCbc::new(key).and_then(|cbc| cbc.encrypt(&iv, &mut ciphertext))
+        }
+        Scheme::Aes128Gcm | Scheme::Aes256Gcm => {
+            let mut tag = [0u8; GCM_TAG_LEN];
+            let nonce = iv[..GCM_NONCE_LEN].try_into().unwrap();
+            AesGcm::new(key).encrypt(nonce, &[], &mut ciphertext, &mut tag);
+            ciphertext.extend_from_slice(&tag);
+            Ok(())
+        }
+    };
+    zeroise(key);
+    if let Err(e) = rc {
+        zeroise(&mut ciphertext);
+        return Err(e);
+    }
+
+    let mut gcm_params = [0u8; 32];
+    let scheme_params = match scheme {
+        Scheme::Aes128Cbc | Scheme::Aes256Cbc => Any::OctetString(asn1::OctetString::new(&iv)),
+        Scheme::Aes128Gcm | Scheme::Aes256Gcm => {
+            let len = encode(
+                &pkix::GCMParameters {
+                    aesNonce: asn1::OctetString::new(&iv[..GCM_NONCE_LEN]),
+                    aesICVlen: Some(Integer::new(&[GCM_TAG_LEN as u8])),
+                },
+                &mut gcm_params,
+            )?;
+            Any::Sequence(asn1::Sequence::new(&gcm_params[..len]))
+        }
+    };
+
+    let mut iterations_buf = [0u8; 5];
+    let mut kdf_params = [0u8; 64];
+    let kdf_len = encode(
+        &pkix::PBKDF2Params {
+            salt: asn1::OctetString::new(&salt),
+            iterationCount: Integer::new_positive(&mut iterations_buf, &iterations.to_be_bytes()),
+            keyLength: None,
+            prf: Some(pkix::AlgorithmIdentifier {
+                algorithm: prf.oid().clone(),
+                parameters: Some(Any::Null(asn1::Null)),
+            }),
+        },
+        &mut kdf_params,
+    )?;
+
+    let mut pbes2_params = [0u8; 128];
+    let pbes2_len = encode(
+        &pkix::PBES2Params {
+            keyDerivationFunc: pkix::AlgorithmIdentifier {
+                algorithm: oid::id_PBKDF2.clone(),
+                parameters: Some(Any::Sequence(asn1::Sequence::new(&kdf_params[..kdf_len]))),
+            },
+            encryptionScheme: pkix::AlgorithmIdentifier {
+                algorithm: scheme.oid().clone(),
+                parameters: Some(scheme_params),
+            },
+        },
+        &mut pbes2_params,
+    )?;
+
+    let len = encode(
+        &pkix::EncryptedPrivateKeyInfo {
+            encryptionAlgorithm: pkix::AlgorithmIdentifier {
+                algorithm: oid::id_PBES2.clone(),
+                parameters: Some(Any::Sequence(asn1::Sequence::new(
+                    &pbes2_params[..pbes2_len],
+                ))),
+            },
+            encryptedData: asn1::OctetString::new(&ciphertext),
+        },
+        output,
+    )?;
+
+    output.get(..len).ok_or(Error::WrongLength)
+}
+
+/// Decodes the `SEQUENCE` parameters of `algorithm` as `T`.
+fn parameters<'a, T: Type<'a>>(algorithm: &pkix::AlgorithmIdentifier<'a>) -> Result<T, Error> {
+    match &algorithm.parameters {
+        Some(Any::Sequence(seq)) => seq.decode().map_err(Error::Asn1Error),
+        _ => Err(unsupported()),
+    }
+}
+
+/// Encodes `value` into `output`, returning the length.
+fn encode<'a>(value: &impl Type<'a>, output: &mut [u8]) -> Result<usize, Error> {
+    value
+        .encode(&mut asn1::Encoder::new(output))
+        .map_err(Error::Asn1Error)
+}
+
+/// Removes PKCS#7 padding (RFC8018 section 6.1.1 step 4).
+fn unpad(plaintext: &mut Vec<u8>) -> Result<(), Error> {
+    let pad = plaintext.last().copied().unwrap_or_default() as usize;
+    if !(1..=BLOCK_LEN).contains(&pad)
+        || pad > plaintext.len()
+        || plaintext[plaintext.len() - pad..]
+            .iter()
+            .any(|b| *b as usize != pad)
+    {
+        return Err(Error::DecryptFailed);
+    }
+
+    let len = plaintext.len() - pad;
+    zeroise(&mut plaintext[len..]);
+    plaintext.truncate(len);
+    Ok(())
+}
+
+fn unsupported() -> Error {
+    KeyFormatError::UnsupportedPkcs8Encryption.into()
+}
+
+/// The PBKDF2 iteration count used when encrypting.
+///
+/// This is the current OWASP recommendation for PBKDF2-HMAC-SHA256.
+pub(super) const DEFAULT_ITERATIONS: u32 = 600_000;
+
+/// The largest accepted iteration count, which bounds the work done
+/// when decrypting an untrusted key.
+const MAX_ITERATIONS: usize = 10_000_000;
+
+/// The largest number of bytes encryption adds to a `PrivateKeyInfo`.
+pub(super) const MAX_OVERHEAD: usize = 128;
+
+const SALT_LEN: usize = 16;
+const GCM_NONCE_LEN: usize = 12;
+const GCM_TAG_LEN: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::{SliceRandomSource, SystemRandom};
+
+    const PASSWORD: &[u8] = b"graviola";
+
+    #[test]
+    fn openssl() {
+        let expected = include_bytes!("ed25519.pkcs8.der");
+        for encrypted in [
+            &include_bytes!("ed25519.aes-128-cbc.der")[..],
+            &include_bytes!("ed25519.aes-256-cbc.der")[..],
+            &include_bytes!("ed25519.aes-256-cbc-key-length.der")[..],
+            &include_bytes!("ed25519.aes-256-gcm.der")[..],
+        ] {
+            assert_eq!(decrypt(encrypted, PASSWORD).unwrap(), expected);
+            assert_eq!(decrypt(encrypted, b"wrong"), Err(Error::DecryptFailed));
+        }
+
+        assert_eq!(
+            decrypt(include_bytes!("ed25519.sha1.der"), PASSWORD),
+            Err(unsupported())
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let plaintext = include_bytes!("ed25519.pkcs8.der");
+        for scheme in [
+            Scheme::Aes128Cbc,
+            Scheme::Aes256Cbc,
+            Scheme::Aes128Gcm,
+            Scheme::Aes256Gcm,
+        ] {
+            for len in [0, 1, 15, 16, 17, plaintext.len()] {
+                let mut output = vec![0u8; len + MAX_OVERHEAD];
+                let encrypted = encrypt(
+                    &plaintext[..len],
+                    PASSWORD,
+                    scheme,
+                    1000,
+                    &mut SystemRandom,
+                    &mut output,
+                )
+                .unwrap();
+                assert_eq!(decrypt(encrypted, PASSWORD).unwrap(), &plaintext[..len]);
+            }
+        }
+
+        // the overhead is sufficient for large keys
+        let plaintext = [0u8; 4096];
+        let mut output = vec![0u8; plaintext.len() + MAX_OVERHEAD];
+        let encrypted = encrypt(
+            &plaintext,
+            PASSWORD,
+            Scheme::Aes256Gcm,
+            u16::MAX as u32 + 1,
+            &mut SliceRandomSource(&[0; SALT_LEN + GCM_NONCE_LEN]),
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(decrypt(encrypted, PASSWORD).unwrap(), plaintext);
+        assert_eq!(
+            encrypt(
+                &plaintext,
+                PASSWORD,
+                Scheme::Aes256Cbc,
+                1,
+                &mut SliceRandomSource(&[0; SALT_LEN]),
+                &mut output,
+            ),
+            Err(Error::RngFailed)
+        );
+    }
+}
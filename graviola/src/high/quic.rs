@@ -0,0 +1,640 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! QUIC packet protection, as specified in
+//! [RFC9001](https://datatracker.ietf.org/doc/html/rfc9001) section 5.
+
+use super::hash::{Digest, Hash, Sha256, Sha384};
+use super::hkdf::{self, Prk};
+use crate::Error;
+use crate::low::chacha20::ChaCha20;
+use crate::low::{AesKey, Entry, zeroise};
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::chacha20poly1305::ChaCha20Poly1305;
+
+/// The AEAD algorithms usable with QUIC.
+///
+/// These correspond to the TLS1.3 cipher suites.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// `TLS_AES_128_GCM_SHA256`.
+    Aes128Gcm,
+    /// `TLS_AES_256_GCM_SHA384`.
+    Aes256Gcm,
+    /// `TLS_CHACHA20_POLY1305_SHA256`.
+    ChaCha20Poly1305,
+}
+
+impl Algorithm {
+    /// Length of packet protection and header protection keys.
+    pub fn key_len(&self) -> usize {
+        match self {
+            Self::Aes128Gcm => 16,
+            Self::Aes256Gcm | Self::ChaCha20Poly1305 => 32,
+        }
+    }
+
+    /// The number of packets that may be encrypted with one packet
+    /// protection key, before a key update is required.
+    ///
+    /// See [RFC9001 section 6.6](https://datatracker.ietf.org/doc/html/rfc9001#section-6.6).
+    pub fn confidentiality_limit(&self) -> u64 {
+        match self {
+            Self::Aes128Gcm | Self::Aes256Gcm => 1 << 23,
+            // "greater than the number of possible packets (2^62)"
+            Self::ChaCha20Poly1305 => u64::MAX,
+        }
+    }
+
+    /// The number of packets that may fail authentication with one packet
+    /// protection key, before the connection must be closed.
+    ///
+    /// See [RFC9001 section 6.6](https://datatracker.ietf.org/doc/html/rfc9001#section-6.6).
+    pub fn integrity_limit(&self) -> u64 {
+        match self {
+            Self::Aes128Gcm | Self::Aes256Gcm => 1 << 52,
+            Self::ChaCha20Poly1305 => 1 << 36,
+        }
+    }
+
+    fn secret_len(&self) -> usize {
+        match self {
+            Self::Aes128Gcm | Self::ChaCha20Poly1305 => Sha256::OUTPUT_LEN,
+            Self::Aes256Gcm => Sha384::OUTPUT_LEN,
+        }
+    }
+}
+
+/// A QUIC traffic secret, for one direction of a connection.
+///
+/// Packet protection and header protection keys are derived from this,
+/// and it is updated for each key phase.
+///
+/// See [RFC9001 section 5.1](https://datatracker.ietf.org/doc/html/rfc9001#section-5.1).
+pub struct Secret {
+    algorithm: Algorithm,
+    secret: [u8; MAX_SECRET_LEN],
+}
+
+impl Secret {
+    /// Use `secret` as a traffic secret for `algorithm`.
+    ///
+    /// `secret` is typically a TLS1.3 handshake or application traffic
+    /// secret, and must be the length of the output of the cipher suite's
+    /// hash function.
+    pub fn new(algorithm: Algorithm, secret: &[u8]) -> Result<Self, Error> {
+        if secret.len() != algorithm.secret_len() {
+            return Err(Error::WrongLength);
+        }
+
+        let mut r = Self {
+            algorithm,
+            secret: [0u8; MAX_SECRET_LEN],
+        };
+        r.secret[..secret.len()].copy_from_slice(secret);
+        Ok(r)
+    }
+
+    /// Derive the client and server Initial secrets for a QUIC version 1
+    /// connection, from the client's Destination Connection ID.
+    ///
+    /// Returns `(client, server)`.  These use [`Algorithm::Aes128Gcm`].
+    ///
+    /// See [RFC9001 section 5.2](https://datatracker.ietf.org/doc/html/rfc9001#section-5.2).
+    pub fn initial(connection_id: &[u8]) -> Result<(Self, Self), Error> {
+        let _entry = Entry::new_secret();
+        let prk = hkdf::extract::<Sha256>(&INITIAL_SALT_V1, &[connection_id]);
+
+        let mut client = Self::new(Algorithm::Aes128Gcm, &[0u8; Sha256::OUTPUT_LEN])?;
+        expand_label(&prk, b"client in", client.as_mut())?;
+        let mut server = Self::new(Algorithm::Aes128Gcm, &[0u8; Sha256::OUTPUT_LEN])?;
+        expand_label(&prk, b"server in", server.as_mut())?;
+        Ok((client, server))
+    }
+
+    /// The algorithm this secret is used with.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Derive the packet protection key for this key phase.
+    pub fn packet_key(&self) -> Result<PacketKey, Error> {
+        let _entry = Entry::new_secret();
+        let mut key = [0u8; 32];
+        let key = &mut key[..self.algorithm.key_len()];
+        let mut iv = [0u8; PacketKey::IV_LEN];
+
+        self.expand(b"quic key", key)?;
+        self.expand(b"quic iv", &mut iv)?;
+        let r = PacketKey::new(self.algorithm, key, &iv);
+
+        zeroise(key);
+        zeroise(&mut iv);
+        r
+    }
+
+    /// Derive the header protection key.
+    ///
+    /// The header protection key is not changed by a key update, so
+    /// this should only be used with the secret for the first key phase.
+    pub fn header_protection_key(&self) -> Result<HeaderProtectionKey, Error> {
+        let _entry = Entry::new_secret();
+        let mut key = [0u8; 32];
+        let key = &mut key[..self.algorithm.key_len()];
+
+        self.expand(b"quic hp", key)?;
+        let r = HeaderProtectionKey::new(self.algorithm, key);
+
+        zeroise(key);
+        r
+    }
+
+    /// Derive the secret for the next key phase.
+    ///
+    /// See [RFC9001 section 6](https://datatracker.ietf.org/doc/html/rfc9001#section-6).
+    pub fn next(&self) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let mut next = Self {
+            algorithm: self.algorithm,
+            secret: [0u8; MAX_SECRET_LEN],
+        };
+        self.expand(b"quic ku", next.as_mut())?;
+        Ok(next)
+    }
+
+    fn expand(&self, label: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        let secret = &self.secret[..self.algorithm.secret_len()];
+        match self.algorithm {
+            Algorithm::Aes128Gcm | Algorithm::ChaCha20Poly1305 => {
+                expand_label(&Prk::<Sha256>::new(secret), label, out)
+            }
+            Algorithm::Aes256Gcm => expand_label(&Prk::<Sha384>::new(secret), label, out),
+        }
+    }
+
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.secret[..self.algorithm.secret_len()]
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        zeroise(&mut self.secret);
+    }
+}
+
+/// A QUIC packet protection key, for one direction and key phase.
+///
+/// See [RFC9001 section 5.3](https://datatracker.ietf.org/doc/html/rfc9001#section-5.3).
+pub struct PacketKey {
+    aead: PacketAead,
+    iv: [u8; Self::IV_LEN],
+}
+
+// boxing the AES key would need an allocation.
+#[allow(clippy::large_enum_variant)]
+enum PacketAead {
+    AesGcm(AesGcm),
+    ChaCha20Poly1305(ChaCha20Poly1305),
+}
+
+impl PacketKey {
+    /// Length of the `iv` derived alongside the key.
+    pub const IV_LEN: usize = 12;
+
+    /// Length of the AEAD authentication tag.
+    pub const TAG_LEN: usize = 16;
+
+    /// Create a packet protection key for `algorithm`.
+    ///
+    /// `key` must be [`Algorithm::key_len()`] bytes long, and `iv` must
+    /// be [`Self::IV_LEN`] bytes long.
+    pub fn new(algorithm: Algorithm, key: &[u8], iv: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        if key.len() != algorithm.key_len() {
+            return Err(Error::WrongLength);
+        }
+        let iv = iv.try_into().map_err(|_| Error::WrongLength)?;
+
+        let aead = match algorithm {
+            Algorithm::Aes128Gcm | Algorithm::Aes256Gcm => PacketAead::AesGcm(AesGcm::new(key)),
+            Algorithm::ChaCha20Poly1305 => {
+                PacketAead::ChaCha20Poly1305(ChaCha20Poly1305::new(key.try_into().unwrap()))
+            }
+        };
+        Ok(Self { aead, iv })
+    }
+
+    /// Encrypt a packet payload in place.
+    ///
+    /// `packet_number` is the full packet number (not its truncated
+    /// encoding).  `header` is the unprotected packet header, up to and
+    /// including the encoded packet number.
+    ///
+    /// On entry, `payload` contains the plaintext payload.  On exit, it
+    /// contains the ciphertext, and `tag_out` contains the authentication
+    /// tag which follows it in the packet.
+    pub fn encrypt_in_place(
+        &self,
+        packet_number: u64,
+        header: &[u8],
+        payload: &mut [u8],
+        tag_out: &mut [u8; Self::TAG_LEN],
+    ) {
+        let nonce = self.nonce(packet_number);
+        match &self.aead {
+            PacketAead::AesGcm(aead) => aead.encrypt(&nonce, header, payload, tag_out),
+            PacketAead::ChaCha20Poly1305(aead) => aead.encrypt(&nonce, header, payload, tag_out),
+        }
+    }
+
+    /// Decrypt and verify a packet payload in place.
+    ///
+    /// `packet_number` is the full packet number, recovered from its
+    /// truncated encoding.  `header` is the unprotected packet header
+    /// (that is, after removing header protection).  `tag` is the
+    /// purported authentication tag.
+    ///
+    /// On success, `payload` contains the plaintext payload.  Otherwise,
+    /// [`Error::DecryptFailed`] is returned and `payload` is cleared.
+    pub fn decrypt_in_place(
+        &self,
+        packet_number: u64,
+        header: &[u8],
+        payload: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        let nonce = self.nonce(packet_number);
+        match &self.aead {
+            PacketAead::AesGcm(aead) => aead.decrypt(&nonce, header, payload, tag),
+            PacketAead::ChaCha20Poly1305(aead) => aead.decrypt(&nonce, header, payload, tag),
+        }
+    }
+
+    /// The nonce is the `iv` XORed with the left-padded packet number.
+    fn nonce(&self, packet_number: u64) -> [u8; Self::IV_LEN] {
+        let mut nonce = self.iv;
+        for (n, p) in nonce[4..].iter_mut().zip(packet_number.to_be_bytes()) {
+            *n ^= p;
+        }
+        nonce
+    }
+}
+
+/// A QUIC header protection key.
+///
+/// See [RFC9001 section 5.4](https://datatracker.ietf.org/doc/html/rfc9001#section-5.4).
+pub struct HeaderProtectionKey(HeaderProtection);
+
+// boxing the AES key would need an allocation.
+#[allow(clippy::large_enum_variant)]
+enum HeaderProtection {
+    Aes(AesKey),
+    ChaCha20([u8; 32]),
+}
+
+impl HeaderProtectionKey {
+    /// Length of the ciphertext sample taken from each packet.
+    pub const SAMPLE_LEN: usize = 16;
+
+    /// Length of the mask produced from a sample.
+    pub const MASK_LEN: usize = 5;
+
+    /// Create a header protection key for `algorithm`.
+    ///
+    /// `key` is the `hp` key derived from the packet protection secret, and
+    /// must be [`Algorithm::key_len()`] bytes long.
+    pub fn new(algorithm: Algorithm, key: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        if key.len() != algorithm.key_len() {
+            return Err(Error::WrongLength);
+        }
+
+        Ok(Self(match algorithm {
+            Algorithm::Aes128Gcm | Algorithm::Aes256Gcm => HeaderProtection::Aes(AesKey::new(key)),
+            Algorithm::ChaCha20Poly1305 => HeaderProtection::ChaCha20(key.try_into().unwrap()),
+        }))
+    }
+
+    /// Compute the mask for `sample`.
+    ///
+    /// `sample` must be [`Self::SAMPLE_LEN`] bytes long.
+    pub fn mask(&self, sample: &[u8]) -> Result<[u8; Self::MASK_LEN], Error> {
+        let _entry = Entry::new_secret();
+        let sample: &[u8; Self::SAMPLE_LEN] = sample.try_into().map_err(|_| Error::WrongLength)?;
+
+        let mut block = [0u8; Self::SAMPLE_LEN];
+        match &self.0 {
+            HeaderProtection::Aes(key) => {
+                block.copy_from_slice(sample);
+                key.encrypt_block(&mut block);
+            }
+            HeaderProtection::ChaCha20(key) => {
+                // the sample is the block counter followed by the nonce,
+                // which is exactly the layout `ChaCha20` takes.
+                ChaCha20::new(key, sample).cipher(&mut block);
+            }
+        }
+
+        let mut mask = [0u8; Self::MASK_LEN];
+        mask.copy_from_slice(&block[..Self::MASK_LEN]);
+        Ok(mask)
+    }
+
+    /// Apply header protection to a packet.
+    ///
+    /// `sample` is taken from the packet's ciphertext.  `first` is the first
+    /// byte of the packet, and `packet_number` is the encoded packet number,
+    /// whose length must match that given in `first`.
+    ///
+    /// Both are protected in place.
+    pub fn encrypt_in_place(
+        &self,
+        sample: &[u8],
+        first: &mut u8,
+        packet_number: &mut [u8],
+    ) -> Result<(), Error> {
+        let mask = self.mask(sample)?;
+        let len = packet_number_len(*first, packet_number)?;
+        *first ^= mask[0] & first_byte_mask(*first);
+        mask_packet_number(&mask, &mut packet_number[..len]);
+        Ok(())
+    }
+
+    /// Remove header protection from a packet.
+    ///
+    /// This is the inverse of [`Self::encrypt_in_place()`].  The length of the
+    /// packet number is not known until `first` is unprotected, so
+    /// `packet_number` should be the 4 bytes that follow the header: the
+    /// packet number is the prefix whose length is given in the unprotected
+    /// `first`, and the bytes beyond it are not altered.
+    ///
+    /// The packet number length in bytes is returned.
+    pub fn decrypt_in_place(
+        &self,
+        sample: &[u8],
+        first: &mut u8,
+        packet_number: &mut [u8],
+    ) -> Result<usize, Error> {
+        let mask = self.mask(sample)?;
+        let unprotected = *first ^ (mask[0] & first_byte_mask(*first));
+        let len = packet_number_len(unprotected, packet_number)?;
+        *first = unprotected;
+        mask_packet_number(&mask, &mut packet_number[..len]);
+        Ok(len)
+    }
+}
+
+impl Drop for HeaderProtectionKey {
+    fn drop(&mut self) {
+        if let HeaderProtection::ChaCha20(key) = &mut self.0 {
+            zeroise(key);
+        }
+    }
+}
+
+/// `HKDF-Expand-Label()` from TLS1.3, with an empty context.
+fn expand_label<H: Hash + Clone>(prk: &Prk<H>, label: &[u8], out: &mut [u8]) -> Result<(), Error> {
+    let length = u16::try_from(out.len())
+        .map_err(|_| Error::WrongLength)?
+        .to_be_bytes();
+    let label_len = (TLS13_LABEL_PREFIX.len() + label.len()) as u8;
+    prk.expand(
+        &[&length, &[label_len], TLS13_LABEL_PREFIX, label, &[0]],
+        out,
+    )
+}
+
+/// Long headers protect four bits of the first byte, and short headers five.
+fn first_byte_mask(first: u8) -> u8 {
+    match first & 0x80 {
+        0 => 0x1f,
+        _ => 0x0f,
+    }
+}
+
+/// Returns the packet number length from `first`, checking `packet_number`
+/// is at least that long.
+fn packet_number_len(first: u8, packet_number: &[u8]) -> Result<usize, Error> {
+    let len = (first & 0x03) as usize + 1;
+    match packet_number.len() >= len {
+        true => Ok(len),
+        false => Err(Error::WrongLength),
+    }
+}
+
+fn mask_packet_number(mask: &[u8; HeaderProtectionKey::MASK_LEN], packet_number: &mut [u8]) {
+    for (pn, m) in packet_number.iter_mut().zip(&mask[1..]) {
+        *pn ^= *m;
+    }
+}
+
+const MAX_SECRET_LEN: usize = Sha384::OUTPUT_LEN;
+
+const TLS13_LABEL_PREFIX: &[u8] = b"tls13 ";
+
+const INITIAL_SALT_V1: [u8; 20] = [
+    0x38, 0x76, 0x2c, 0xf7, 0xf5, 0x59, 0x34, 0xb3, 0x4d, 0x17, 0x9a, 0xe6, 0xa4, 0xc8, 0x0c, 0xad,
+    0xcc, 0xbb, 0x7f, 0x0a,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rfc9001_client_initial() {
+        // RFC9001 appendix A.2
+        let key = HeaderProtectionKey::new(
+            Algorithm::Aes128Gcm,
+            &unhex("9f50449e04a0e810283a1e9933adedd2"),
+        )
+        .unwrap();
+        let sample = unhex("d1b1c98dd7689fb8ec11d242b123dc9b");
+        assert_eq!(key.mask(&sample).unwrap().to_vec(), unhex("437b9aec36"));
+
+        let mut first = 0xc3;
+        let mut pn = unhex("00000002");
+        key.encrypt_in_place(&sample, &mut first, &mut pn).unwrap();
+        assert_eq!(first, 0xc0);
+        assert_eq!(pn, unhex("7b9aec34"));
+
+        assert_eq!(
+            key.decrypt_in_place(&sample, &mut first, &mut pn).unwrap(),
+            4
+        );
+        assert_eq!(first, 0xc3);
+        assert_eq!(pn, unhex("00000002"));
+    }
+
+    #[test]
+    fn rfc9001_chacha20_short_header() {
+        // RFC9001 appendix A.5
+        let key = HeaderProtectionKey::new(
+            Algorithm::ChaCha20Poly1305,
+            &unhex("25a282b9e82f06f21f488917a4fc8f1b73573685608597d0efcb076b0ab7a7a4"),
+        )
+        .unwrap();
+        let sample = unhex("5e5cd55c41f69080575d7999c25a5bfb");
+        assert_eq!(key.mask(&sample).unwrap().to_vec(), unhex("aefefe7d03"));
+
+        let mut first = 0x42;
+        let mut pn = unhex("00bff4");
+        key.encrypt_in_place(&sample, &mut first, &mut pn).unwrap();
+        assert_eq!(first, 0x4c);
+        assert_eq!(pn, unhex("fe4189"));
+
+        // the receiver does not know the packet number length, so
+        // provides following bytes too.
+        let mut pn = unhex("fe4189aaaa");
+        let mut first = 0x4c;
+        assert_eq!(
+            key.decrypt_in_place(&sample, &mut first, &mut pn).unwrap(),
+            3
+        );
+        assert_eq!(first, 0x42);
+        assert_eq!(pn, unhex("00bff4aaaa"));
+    }
+
+    #[test]
+    fn rfc9001_initial_secrets() {
+        // RFC9001 appendix A.1
+        let (client, server) = Secret::initial(&unhex("8394c8f03e515708")).unwrap();
+        assert_eq!(
+            client.secret[..client.algorithm.secret_len()].to_vec(),
+            unhex("c00cf151ca5be075ed0ebfb5c80323c42d6b7db67881289af4008f1f6c357aea")
+        );
+        assert_eq!(
+            server.secret[..server.algorithm.secret_len()].to_vec(),
+            unhex("3c199828fd139efd216c155ad844cc81fb82fa8d7446fa7d78be803acdda951b")
+        );
+
+        // RFC9001 appendix A.2
+        let hp = client.header_protection_key().unwrap();
+        assert_eq!(
+            hp.mask(&unhex("d1b1c98dd7689fb8ec11d242b123dc9b"))
+                .unwrap()
+                .to_vec(),
+            unhex("437b9aec36")
+        );
+
+        // RFC9001 appendix A.3
+        let header = unhex("c1000000010008f067a5502a4262b50040750001");
+        let plaintext = unhex(
+            "02000000000600405a020000560303eefce7f7b37ba1d1632e96677825ddf739\
+             88cfc79825df566dc5430b9a045a1200130100002e00330024001d00209d3c94\
+             0d89690b84d08a60993c144eca684d1081287c834d5311bcf32bb9da1a002b00\
+             020304",
+        );
+        let mut payload = plaintext.clone();
+        let mut tag = [0u8; PacketKey::TAG_LEN];
+        let key = server.packet_key().unwrap();
+        key.encrypt_in_place(1, &header, &mut payload, &mut tag);
+        assert_eq!(
+            payload,
+            unhex(
+                "5a482cd0991cd25b0aac406a5816b6394100f37a1c69797554780bb38cc5a99f\
+                 5ede4cf73c3ec2493a1839b3dbcba3f6ea46c5b7684df3548e7ddeb9c3bf9c73\
+                 cc3f3bded74b562bfb19fb84022f8ef4cdd93795d77d06edbb7aaf2f58891850\
+                 abbdca"
+            )
+        );
+        assert_eq!(tag.to_vec(), unhex("3d20398c276456cbc42158407dd074ee"));
+
+        let hp = server.header_protection_key().unwrap();
+        let mut first = header[0];
+        let mut pn = header[18..].to_vec();
+        hp.encrypt_in_place(&payload[2..18], &mut first, &mut pn)
+            .unwrap();
+        assert_eq!((first, pn), (0xcf, unhex("c0d9")));
+
+        key.decrypt_in_place(1, &header, &mut payload, &tag)
+            .unwrap();
+        assert_eq!(payload, plaintext);
+    }
+
+    #[test]
+    fn rfc9001_chacha20_packet() {
+        // RFC9001 appendix A.5
+        let secret = Secret::new(
+            Algorithm::ChaCha20Poly1305,
+            &unhex("9ac312a7f877468ebe69422748ad00a15443f18203a07d6060f688f30f21632b"),
+        )
+        .unwrap();
+        let key = secret.packet_key().unwrap();
+        assert_eq!(
+            key.nonce(654360564).to_vec(),
+            unhex("e0459b3474bdd0e46d417eb0")
+        );
+
+        let mut payload = [0x01];
+        let mut tag = [0u8; PacketKey::TAG_LEN];
+        key.encrypt_in_place(654360564, &unhex("4200bff4"), &mut payload, &mut tag);
+        assert_eq!(payload, [0x65]);
+        assert_eq!(tag.to_vec(), unhex("5e5cd55c41f69080575d7999c25a5bfb"));
+
+        let hp = secret.header_protection_key().unwrap();
+        assert_eq!(hp.mask(&tag).unwrap().to_vec(), unhex("aefefe7d03"));
+
+        let next = secret.next().unwrap();
+        assert_eq!(
+            next.secret[..next.algorithm.secret_len()].to_vec(),
+            unhex("1223504755036d556342ee9361d253421a826c9ecdf3c7148684b36b714881f9")
+        );
+    }
+
+    #[test]
+    fn aes256_key_update() {
+        // from an independent implementation
+        let secret = Secret::new(Algorithm::Aes256Gcm, &(0..48).collect::<Vec<u8>>()).unwrap();
+        let mut payload = *b"hello";
+        let mut tag = [0u8; PacketKey::TAG_LEN];
+        let key = secret.packet_key().unwrap();
+        key.encrypt_in_place(7, &[0x43, 0x07], &mut payload, &mut tag);
+        assert_eq!(payload.to_vec(), unhex("a793ce2585"));
+        assert_eq!(tag.to_vec(), unhex("01ba8f456dabe82bbf40553bde1f544d"));
+
+        let next = secret.next().unwrap();
+        assert_eq!(next.algorithm(), Algorithm::Aes256Gcm);
+        assert_eq!(
+            next.secret[..next.algorithm.secret_len()].to_vec(),
+            unhex(
+                "d21f524277390ba96b86484d9c687f850f1e4d1f997033bba06051129179a762\
+                 a94067d065f3f715e83d65a7bf8c79b9"
+            )
+        );
+
+        // a packet from the old key phase is not accepted by the new one
+        let next_key = next.packet_key().unwrap();
+        assert_eq!(
+            next_key.decrypt_in_place(7, &[0x43, 0x07], &mut payload, &tag),
+            Err(Error::DecryptFailed)
+        );
+        assert_eq!(payload, [0u8; 5]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(HeaderProtectionKey::new(Algorithm::Aes256Gcm, &[0; 16]).is_err());
+        assert!(HeaderProtectionKey::new(Algorithm::ChaCha20Poly1305, &[0; 16]).is_err());
+
+        let key = HeaderProtectionKey::new(Algorithm::Aes256Gcm, &[0; 32]).unwrap();
+        assert_eq!(key.mask(&[0; 15]).err(), Some(Error::WrongLength));
+        assert_eq!(
+            key.encrypt_in_place(&[0; 16], &mut 0x43, &mut [0; 3]),
+            Err(Error::WrongLength)
+        );
+
+        assert!(Secret::new(Algorithm::Aes256Gcm, &[0; 32]).is_err());
+        assert!(Secret::new(Algorithm::Aes128Gcm, &[0; 48]).is_err());
+        assert!(PacketKey::new(Algorithm::Aes128Gcm, &[0; 32], &[0; 12]).is_err());
+        assert!(PacketKey::new(Algorithm::Aes128Gcm, &[0; 16], &[0; 8]).is_err());
+    }
+}
@@ -0,0 +1,19 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! An API in the shape of [*ring*](https://docs.rs/ring)'s.
+//!
+//! This covers the commonly-used parts of *ring*'s `aead`, `agreement`,
+//! `digest`, `hmac` and `signature` modules, so that code written against
+//! *ring* can move to graviola with few changes.  Type, function and
+//! constant names match *ring*'s; where *ring* takes `untrusted::Input`,
+//! byte slices are taken instead.
+
+pub(crate) mod aead;
+pub(crate) mod agreement;
+pub(crate) mod digest;
+pub(crate) mod error;
+pub(crate) mod hmac;
+pub(crate) mod pkcs8;
+pub(crate) mod rand;
+pub(crate) mod signature;
@@ -0,0 +1,536 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::fmt;
+
+use super::error::Unspecified;
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::chacha20poly1305::ChaCha20Poly1305;
+
+/// The length of a nonce for every algorithm in this module.
+pub const NONCE_LEN: usize = 12;
+
+/// The length of the longest tag for any algorithm in this module.
+pub const MAX_TAG_LEN: usize = TAG_LEN;
+
+const TAG_LEN: usize = 16;
+
+/// An AEAD algorithm.
+#[derive(PartialEq, Eq)]
+pub struct Algorithm {
+    id: AlgorithmId,
+    name: &'static str,
+    key_len: usize,
+}
+
+impl Algorithm {
+    /// The length of this algorithm's key, in bytes.
+    pub fn key_len(&self) -> usize {
+        self.key_len
+    }
+
+    /// The length of this algorithm's tag, in bytes.
+    pub fn tag_len(&self) -> usize {
+        TAG_LEN
+    }
+
+    /// The length of this algorithm's nonce, in bytes.
+    pub fn nonce_len(&self) -> usize {
+        NONCE_LEN
+    }
+}
+
+impl fmt::Debug for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AlgorithmId {
+    AesGcm,
+    ChaCha20Poly1305,
+}
+
+/// AES-128 in GCM mode, with 128-bit tags and 96-bit nonces.
+pub static AES_128_GCM: Algorithm = Algorithm {
+    id: AlgorithmId::AesGcm,
+    name: "AES_128_GCM",
+    key_len: 16,
+};
+
+/// AES-256 in GCM mode, with 128-bit tags and 96-bit nonces.
+pub static AES_256_GCM: Algorithm = Algorithm {
+    id: AlgorithmId::AesGcm,
+    name: "AES_256_GCM",
+    key_len: 32,
+};
+
+/// ChaCha20-Poly1305, as described in [RFC8439](https://datatracker.ietf.org/doc/html/rfc8439).
+pub static CHACHA20_POLY1305: Algorithm = Algorithm {
+    id: AlgorithmId::ChaCha20Poly1305,
+    name: "CHACHA20_POLY1305",
+    key_len: 32,
+};
+
+/// A nonce for a single AEAD operation.
+///
+/// The caller is responsible for never using the same nonce twice
+/// with a given key.
+pub struct Nonce([u8; NONCE_LEN]);
+
+impl Nonce {
+    /// Makes a nonce from `value`, which must be unique for the key.
+    pub fn assume_unique_for_key(value: [u8; NONCE_LEN]) -> Self {
+        Self(value)
+    }
+
+    /// Makes a nonce from `value`, which must be unique for the key.
+    ///
+    /// `value` must be [`NONCE_LEN`] bytes long.
+    pub fn try_assume_unique_for_key(value: &[u8]) -> Result<Self, Unspecified> {
+        Ok(Self(value.try_into().map_err(|_| Unspecified)?))
+    }
+}
+
+impl AsRef<[u8; NONCE_LEN]> for Nonce {
+    fn as_ref(&self) -> &[u8; NONCE_LEN] {
+        &self.0
+    }
+}
+
+/// Produces the nonces to be used with a [`SealingKey`] or [`OpeningKey`].
+pub trait NonceSequence {
+    /// Returns the next nonce, or an error if the sequence is exhausted.
+    fn advance(&mut self) -> Result<Nonce, Unspecified>;
+}
+
+/// Additionally-authenticated data.
+#[derive(Clone, Copy)]
+pub struct Aad<A>(A);
+
+impl<A: AsRef<[u8]>> Aad<A> {
+    /// Wraps `aad`.
+    pub fn from(aad: A) -> Self {
+        Self(aad)
+    }
+}
+
+impl Aad<[u8; 0]> {
+    /// No additionally-authenticated data.
+    pub fn empty() -> Self {
+        Self([])
+    }
+}
+
+impl<A: AsRef<[u8]>> AsRef<[u8]> for Aad<A> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+/// An authentication tag.
+#[derive(Clone, Copy, Debug)]
+pub struct Tag([u8; TAG_LEN]);
+
+impl AsRef<[u8]> for Tag {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Tag {
+    type Error = Unspecified;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(value.try_into().map_err(|_| Unspecified)?))
+    }
+}
+
+/// A key, not yet bound to a [`NonceSequence`].
+pub struct UnboundKey {
+    algorithm: &'static Algorithm,
+    inner: KeyInner,
+}
+
+// `AesGcm` carries its expanded key schedule and GHASH table, and so is
+// much larger than `ChaCha20Poly1305`; keys are long-lived so the size
+// is not a concern.
+#[allow(clippy::large_enum_variant)]
+enum KeyInner {
+    AesGcm(AesGcm),
+    ChaCha20Poly1305(ChaCha20Poly1305),
+}
+
+impl UnboundKey {
+    /// Makes a key for `algorithm` from `key_bytes`.
+    ///
+    /// `key_bytes` must be [`Algorithm::key_len()`] bytes long.
+    pub fn new(algorithm: &'static Algorithm, key_bytes: &[u8]) -> Result<Self, Unspecified> {
+        if key_bytes.len() != algorithm.key_len {
+            return Err(Unspecified);
+        }
+
+        let inner = match algorithm.id {
+            AlgorithmId::AesGcm => KeyInner::AesGcm(AesGcm::new(key_bytes)),
+            AlgorithmId::ChaCha20Poly1305 => KeyInner::ChaCha20Poly1305(ChaCha20Poly1305::new(
+                key_bytes.try_into().map_err(|_| Unspecified)?,
+            )),
+        };
+        Ok(Self { algorithm, inner })
+    }
+
+    /// The algorithm this key is for.
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    fn seal(&self, nonce: Nonce, aad: &[u8], in_out: &mut [u8]) -> Tag {
+        let mut tag = [0u8; TAG_LEN];
+        match &self.inner {
+            KeyInner::AesGcm(k) => k.encrypt(&nonce.0, aad, in_out, &mut tag),
+            KeyInner::ChaCha20Poly1305(k) => k.encrypt(&nonce.0, aad, in_out, &mut tag),
+        }
+        Tag(tag)
+    }
+
+    fn open<'io>(
+        &self,
+        nonce: Nonce,
+        aad: &[u8],
+        in_out: &'io mut [u8],
+    ) -> Result<&'io mut [u8], Unspecified> {
+        let plaintext_len = in_out.len().checked_sub(TAG_LEN).ok_or(Unspecified)?;
+        let (ciphertext, tag) = in_out.split_at_mut(plaintext_len);
+        match &self.inner {
+            KeyInner::AesGcm(k) => k.decrypt(&nonce.0, aad, ciphertext, tag)?,
+            KeyInner::ChaCha20Poly1305(k) => k.decrypt(&nonce.0, aad, ciphertext, tag)?,
+        }
+        Ok(ciphertext)
+    }
+}
+
+impl fmt::Debug for UnboundKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnboundKey")
+            .field("algorithm", self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A key which takes a nonce for each operation.
+///
+/// Unlike [`SealingKey`] and [`OpeningKey`], nothing here helps with
+/// making nonces unique: that is entirely up to the caller.
+pub struct LessSafeKey(UnboundKey);
+
+impl LessSafeKey {
+    /// Wraps `key`.
+    pub fn new(key: UnboundKey) -> Self {
+        Self(key)
+    }
+
+    /// The algorithm this key is for.
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.0.algorithm
+    }
+
+    /// Encrypts `in_out` in place, then appends the tag.
+    pub fn seal_in_place_append_tag<A, InOut>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let tag = self.seal_in_place_separate_tag(nonce, aad, in_out.as_mut())?;
+        in_out.extend(tag.as_ref());
+        Ok(())
+    }
+
+    /// Encrypts `in_out` in place, returning the tag.
+    pub fn seal_in_place_separate_tag<A: AsRef<[u8]>>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+    ) -> Result<Tag, Unspecified> {
+        Ok(self.0.seal(nonce, aad.as_ref(), in_out))
+    }
+
+    /// Decrypts and authenticates `in_out` in place.
+    ///
+    /// On entry `in_out` is the ciphertext followed by the tag.  On success,
+    /// the plaintext prefix of `in_out` is returned.  On failure `in_out`
+    /// is cleared.
+    pub fn open_in_place<'io, A: AsRef<[u8]>>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'io mut [u8],
+    ) -> Result<&'io mut [u8], Unspecified> {
+        self.0.open(nonce, aad.as_ref(), in_out)
+    }
+}
+
+impl fmt::Debug for LessSafeKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LessSafeKey")
+            .field("algorithm", self.0.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A key bound to a [`NonceSequence`].
+pub trait BoundKey<N: NonceSequence>: fmt::Debug {
+    /// Binds `key` to `nonce_sequence`.
+    fn new(key: UnboundKey, nonce_sequence: N) -> Self;
+
+    /// The algorithm this key is for.
+    fn algorithm(&self) -> &'static Algorithm;
+}
+
+/// A key for encrypting, which takes its nonces from a [`NonceSequence`].
+pub struct SealingKey<N: NonceSequence> {
+    key: UnboundKey,
+    nonce_sequence: N,
+}
+
+impl<N: NonceSequence> BoundKey<N> for SealingKey<N> {
+    fn new(key: UnboundKey, nonce_sequence: N) -> Self {
+        Self {
+            key,
+            nonce_sequence,
+        }
+    }
+
+    fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm
+    }
+}
+
+impl<N: NonceSequence> SealingKey<N> {
+    /// Encrypts `in_out` in place, then appends the tag.
+    pub fn seal_in_place_append_tag<A, InOut>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let tag = self.seal_in_place_separate_tag(aad, in_out.as_mut())?;
+        in_out.extend(tag.as_ref());
+        Ok(())
+    }
+
+    /// Encrypts `in_out` in place, returning the tag.
+    pub fn seal_in_place_separate_tag<A: AsRef<[u8]>>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+    ) -> Result<Tag, Unspecified> {
+        let nonce = self.nonce_sequence.advance()?;
+        Ok(self.key.seal(nonce, aad.as_ref(), in_out))
+    }
+}
+
+impl<N: NonceSequence> fmt::Debug for SealingKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SealingKey")
+            .field("algorithm", self.key.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A key for decrypting, which takes its nonces from a [`NonceSequence`].
+pub struct OpeningKey<N: NonceSequence> {
+    key: UnboundKey,
+    nonce_sequence: N,
+}
+
+impl<N: NonceSequence> BoundKey<N> for OpeningKey<N> {
+    fn new(key: UnboundKey, nonce_sequence: N) -> Self {
+        Self {
+            key,
+            nonce_sequence,
+        }
+    }
+
+    fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm
+    }
+}
+
+impl<N: NonceSequence> OpeningKey<N> {
+    /// Decrypts and authenticates `in_out` in place.
+    ///
+    /// See [`LessSafeKey::open_in_place()`].
+    pub fn open_in_place<'io, A: AsRef<[u8]>>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &'io mut [u8],
+    ) -> Result<&'io mut [u8], Unspecified> {
+        let nonce = self.nonce_sequence.advance()?;
+        self.key.open(nonce, aad.as_ref(), in_out)
+    }
+}
+
+impl<N: NonceSequence> fmt::Debug for OpeningKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpeningKey")
+            .field("algorithm", self.key.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc8439_vector() {
+        let key = unhex("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+        let nonce = unhex("070000004041424344454647");
+        let aad = unhex("50515253c0c1c2c3c4c5c6c7");
+        let plaintext = b"Ladies and Gentlemen of the class of '99: \
+              If I could offer you only one tip for the future, sunscreen would be it.";
+
+        let key = LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, &key).unwrap());
+        let mut in_out = plaintext.to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+            Aad::from(&aad),
+            &mut in_out,
+        )
+        .unwrap();
+        assert_eq!(
+            &in_out[plaintext.len()..],
+            &unhex("1ae10b594f09e26a7e902ecbd0600691")[..]
+        );
+        assert_eq!(
+            &in_out[..16],
+            &unhex("d31a8d34648e60db7b86afbc53ef7ec2")[..]
+        );
+
+        let plain = key
+            .open_in_place(
+                Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+                Aad::from(&aad),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(plain, &plaintext[..]);
+    }
+
+    #[test]
+    fn aes_gcm_vector() {
+        // Test Case 2 from the original GCM specification
+        let key = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &[0u8; 16]).unwrap());
+        let mut in_out = [0u8; 16];
+        let tag = key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key([0u8; NONCE_LEN]),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(in_out, &unhex("0388dace60b6a392f328c2b971b2fe78")[..]);
+        assert_eq!(tag.as_ref(), &unhex("ab6e47d42cec13bdf53a67b21257bddf")[..]);
+    }
+
+    #[test]
+    fn bound_keys() {
+        struct Counter(u32);
+
+        impl NonceSequence for Counter {
+            fn advance(&mut self) -> Result<Nonce, Unspecified> {
+                let mut nonce = [0u8; NONCE_LEN];
+                nonce[8..].copy_from_slice(&self.0.to_be_bytes());
+                self.0 = self.0.checked_add(1).ok_or(Unspecified)?;
+                Ok(Nonce::assume_unique_for_key(nonce))
+            }
+        }
+
+        for algorithm in [&AES_128_GCM, &AES_256_GCM, &CHACHA20_POLY1305] {
+            let key = vec![0x42; algorithm.key_len()];
+            let mut sealing =
+                SealingKey::new(UnboundKey::new(algorithm, &key).unwrap(), Counter(0));
+            let mut opening =
+                OpeningKey::new(UnboundKey::new(algorithm, &key).unwrap(), Counter(0));
+            assert_eq!(sealing.algorithm(), algorithm);
+            assert_eq!(opening.algorithm(), algorithm);
+
+            let mut first = b"hello".to_vec();
+            sealing
+                .seal_in_place_append_tag(Aad::from(b"aad"), &mut first)
+                .unwrap();
+            let mut second = b"hello".to_vec();
+            sealing
+                .seal_in_place_append_tag(Aad::from(b"aad"), &mut second)
+                .unwrap();
+            assert_eq!(second.len(), 5 + algorithm.tag_len());
+            assert_ne!(first, second);
+
+            assert_eq!(
+                opening
+                    .open_in_place(Aad::from(b"aad"), &mut first)
+                    .unwrap(),
+                b"hello"
+            );
+            let mut wrong = second.clone();
+            wrong[0] ^= 1;
+            assert_eq!(
+                opening.open_in_place(Aad::from(b"aad"), &mut wrong),
+                Err(Unspecified)
+            );
+
+            // (the sequences are now out of step)
+            assert_eq!(
+                opening.open_in_place(Aad::from(b"aad"), &mut second),
+                Err(Unspecified)
+            );
+
+            let mut exhausted =
+                SealingKey::new(UnboundKey::new(algorithm, &key).unwrap(), Counter(u32::MAX));
+            assert!(
+                exhausted
+                    .seal_in_place_separate_tag(Aad::empty(), &mut [])
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(UnboundKey::new(&AES_256_GCM, &[0u8; 16]).is_err());
+        assert!(UnboundKey::new(&CHACHA20_POLY1305, &[0u8; 31]).is_err());
+        assert!(Nonce::try_assume_unique_for_key(&[0u8; 11]).is_err());
+        assert!(Tag::try_from(&[0u8; 15][..]).is_err());
+
+        let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, &[0u8; 32]).unwrap());
+        assert_eq!(
+            key.open_in_place(
+                Nonce::assume_unique_for_key([0u8; NONCE_LEN]),
+                Aad::empty(),
+                &mut [0u8; 15]
+            ),
+            Err(Unspecified)
+        );
+        assert_eq!(
+            format!("{key:?}"),
+            "LessSafeKey { algorithm: AES_256_GCM, .. }"
+        );
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,335 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::fmt;
+
+use super::error::Unspecified;
+use super::rand::{Adapter, SecureRandom};
+use crate::high::curve::{Curve, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, P256, P384};
+use crate::low::zeroise;
+use crate::mid::{p256, p384, x25519};
+
+/// A key agreement algorithm.
+#[derive(PartialEq, Eq)]
+pub struct Algorithm {
+    id: AlgorithmId,
+    name: &'static str,
+}
+
+impl fmt::Debug for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AlgorithmId {
+    X25519,
+    EcdhP256,
+    EcdhP384,
+}
+
+/// X25519, as described in [RFC7748](https://datatracker.ietf.org/doc/html/rfc7748).
+///
+/// Public keys are 32 bytes.
+pub static X25519: Algorithm = Algorithm {
+    id: AlgorithmId::X25519,
+    name: "X25519",
+};
+
+/// ECDH using the P-256 curve.
+///
+/// Public keys are in uncompressed X9.62 format.
+pub static ECDH_P256: Algorithm = Algorithm {
+    id: AlgorithmId::EcdhP256,
+    name: "ECDH_P256",
+};
+
+/// ECDH using the P-384 curve.
+///
+/// Public keys are in uncompressed X9.62 format.
+pub static ECDH_P384: Algorithm = Algorithm {
+    id: AlgorithmId::EcdhP384,
+    name: "ECDH_P384",
+};
+
+/// A private key for use in exactly one key agreement.
+pub struct EphemeralPrivateKey {
+    algorithm: &'static Algorithm,
+    inner: PrivateKeyInner,
+}
+
+enum PrivateKeyInner {
+    X25519(x25519::StaticPrivateKey),
+    P256(p256::StaticPrivateKey),
+    P384(p384::StaticPrivateKey),
+}
+
+impl EphemeralPrivateKey {
+    /// Generates a new private key for `algorithm`, using `rng`.
+    pub fn generate(
+        algorithm: &'static Algorithm,
+        rng: &dyn SecureRandom,
+    ) -> Result<Self, Unspecified> {
+        let inner = match algorithm.id {
+            AlgorithmId::X25519 => {
+                let mut bytes = [0u8; 32];
+                let rc = rng.fill(&mut bytes);
+                let key = x25519::StaticPrivateKey::from_array(&bytes);
+                zeroise(&mut bytes);
+                rc?;
+                PrivateKeyInner::X25519(key)
+            }
+            AlgorithmId::EcdhP256 => {
+                PrivateKeyInner::P256(P256::generate_random_key(&mut Adapter(rng))?)
+            }
+            AlgorithmId::EcdhP384 => {
+                PrivateKeyInner::P384(P384::generate_random_key(&mut Adapter(rng))?)
+            }
+        };
+        Ok(Self { algorithm, inner })
+    }
+
+    /// Computes the public key for this private key.
+    pub fn compute_public_key(&self) -> Result<PublicKey, Unspecified> {
+        let mut bytes = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+        let len = match &self.inner {
+            PrivateKeyInner::X25519(k) => copy_into(&mut bytes, &k.public_key().as_bytes()),
+            PrivateKeyInner::P256(k) => copy_into(&mut bytes, &k.public_key_uncompressed()),
+            PrivateKeyInner::P384(k) => copy_into(&mut bytes, &k.public_key_uncompressed()),
+        };
+        Ok(PublicKey {
+            algorithm: self.algorithm,
+            bytes,
+            len,
+        })
+    }
+
+    /// The algorithm this key is for.
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+impl fmt::Debug for EphemeralPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EphemeralPrivateKey")
+            .field("algorithm", self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+fn copy_into(out: &mut [u8], value: &[u8]) -> usize {
+    out[..value.len()].copy_from_slice(value);
+    value.len()
+}
+
+/// A public key, computed from an [`EphemeralPrivateKey`].
+#[derive(Clone)]
+pub struct PublicKey {
+    algorithm: &'static Algorithm,
+    bytes: [u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN],
+    len: usize,
+}
+
+impl PublicKey {
+    /// The algorithm this key is for.
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PublicKey")
+            .field("algorithm", self.algorithm)
+            .field("bytes", &self.as_ref())
+            .finish()
+    }
+}
+
+/// A peer's public key, which has not yet been validated.
+#[derive(Clone, Copy, Debug)]
+pub struct UnparsedPublicKey<B> {
+    algorithm: &'static Algorithm,
+    bytes: B,
+}
+
+impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
+    /// Wraps `bytes`, which is a public key for `algorithm`.
+    pub fn new(algorithm: &'static Algorithm, bytes: B) -> Self {
+        Self { algorithm, bytes }
+    }
+
+    /// The algorithm this key is for.
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    /// The encoded public key.
+    pub fn bytes(&self) -> &B {
+        &self.bytes
+    }
+}
+
+/// Does key agreement between `my_private_key` and `peer_public_key`.
+///
+/// The shared secret is passed to `kdf`, and its result returned.  The
+/// shared secret is erased afterwards.
+///
+/// This fails if the keys are for different algorithms, if
+/// `peer_public_key` is not valid, or if the shared secret is zero.
+pub fn agree_ephemeral<B: AsRef<[u8]>, R>(
+    my_private_key: EphemeralPrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+    kdf: impl FnOnce(&[u8]) -> R,
+) -> Result<R, Unspecified> {
+    if my_private_key.algorithm != peer_public_key.algorithm {
+        return Err(Unspecified);
+    }
+
+    let peer = peer_public_key.bytes.as_ref();
+    Ok(match &my_private_key.inner {
+        PrivateKeyInner::X25519(k) => {
+            let peer = x25519::PublicKey::try_from_slice(peer)?;
+            kdf(&k.diffie_hellman(&peer)?.0)
+        }
+        PrivateKeyInner::P256(k) => {
+            let peer = p256::PublicKey::from_x962_uncompressed(peer)?;
+            kdf(&k.diffie_hellman(&peer)?.0)
+        }
+        PrivateKeyInner::P384(k) => {
+            let peer = p384::PublicKey::from_x962_uncompressed(peer)?;
+            kdf(&k.diffie_hellman(&peer)?.0)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::ring_compat::rand::SystemRandom;
+
+    /// Returns the same bytes, repeatedly.
+    struct FixedRandom(&'static str);
+
+    impl SecureRandom for FixedRandom {
+        fn fill(&self, dest: &mut [u8]) -> Result<(), Unspecified> {
+            dest.copy_from_slice(&unhex(self.0));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn rfc7748_x25519() {
+        let alice_private =
+            FixedRandom("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob_public = "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f";
+
+        let alice = EphemeralPrivateKey::generate(&X25519, &alice_private).unwrap();
+        assert_eq!(
+            hex(alice.compute_public_key().unwrap().as_ref()),
+            "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+        );
+
+        let shared = agree_ephemeral(
+            alice,
+            &UnparsedPublicKey::new(&X25519, unhex(bob_public)),
+            hex,
+        )
+        .unwrap();
+        assert_eq!(
+            shared,
+            "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let rng = SystemRandom::new();
+        for algorithm in [&X25519, &ECDH_P256, &ECDH_P384] {
+            let alice = EphemeralPrivateKey::generate(algorithm, &rng).unwrap();
+            let bob = EphemeralPrivateKey::generate(algorithm, &rng).unwrap();
+            assert_eq!(alice.algorithm(), algorithm);
+
+            let alice_public = alice.compute_public_key().unwrap();
+            let bob_public = bob.compute_public_key().unwrap();
+            assert_eq!(alice_public.algorithm(), algorithm);
+
+            let a = agree_ephemeral(
+                alice,
+                &UnparsedPublicKey::new(algorithm, bob_public.as_ref()),
+                |s| s.to_vec(),
+            )
+            .unwrap();
+            let b = agree_ephemeral(
+                bob,
+                &UnparsedPublicKey::new(algorithm, alice_public.as_ref()),
+                |s| s.to_vec(),
+            )
+            .unwrap();
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let rng = SystemRandom::new();
+        let x25519 = EphemeralPrivateKey::generate(&X25519, &rng).unwrap();
+        let p256 = EphemeralPrivateKey::generate(&ECDH_P256, &rng).unwrap();
+        let p256_public = p256.compute_public_key().unwrap();
+
+        // wrong algorithm
+        assert_eq!(
+            agree_ephemeral(
+                x25519,
+                &UnparsedPublicKey::new(&ECDH_P256, p256_public.as_ref()),
+                |_| ()
+            ),
+            Err(Unspecified)
+        );
+
+        // not on the curve
+        let mut bad = p256_public.as_ref().to_vec();
+        bad[64] ^= 1;
+        assert_eq!(
+            agree_ephemeral(p256, &UnparsedPublicKey::new(&ECDH_P256, bad), |_| ()),
+            Err(Unspecified)
+        );
+
+        // all-zero shared secret
+        let x25519 = EphemeralPrivateKey::generate(&X25519, &rng).unwrap();
+        assert_eq!(
+            agree_ephemeral(x25519, &UnparsedPublicKey::new(&X25519, [0u8; 32]), |_| ()),
+            Err(Unspecified)
+        );
+
+        // failing random source
+        struct NoRandom;
+        impl SecureRandom for NoRandom {
+            fn fill(&self, _: &mut [u8]) -> Result<(), Unspecified> {
+                Err(Unspecified)
+            }
+        }
+        for algorithm in [&X25519, &ECDH_P256, &ECDH_P384] {
+            assert!(EphemeralPrivateKey::generate(algorithm, &NoRandom).is_err());
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,228 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::fmt;
+
+use crate::high::hash::{Hash, HashContext, HashOutput, Sha256, Sha384, Sha512};
+
+/// A hash function.
+#[derive(PartialEq, Eq)]
+pub struct Algorithm {
+    pub(super) id: AlgorithmId,
+    name: &'static str,
+    output_len: usize,
+    block_len: usize,
+}
+
+impl Algorithm {
+    /// The length of this algorithm's output, in bytes.
+    pub fn output_len(&self) -> usize {
+        self.output_len
+    }
+
+    /// The internal block length of this algorithm, in bytes.
+    pub fn block_len(&self) -> usize {
+        self.block_len
+    }
+}
+
+impl fmt::Debug for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum AlgorithmId {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+/// SHA-256, as specified in [FIPS 180-4](https://csrc.nist.gov/pubs/fips/180-4/upd1/final).
+pub static SHA256: Algorithm = Algorithm {
+    id: AlgorithmId::Sha256,
+    name: "SHA256",
+    output_len: SHA256_OUTPUT_LEN,
+    block_len: 64,
+};
+
+/// SHA-384, as specified in [FIPS 180-4](https://csrc.nist.gov/pubs/fips/180-4/upd1/final).
+pub static SHA384: Algorithm = Algorithm {
+    id: AlgorithmId::Sha384,
+    name: "SHA384",
+    output_len: SHA384_OUTPUT_LEN,
+    block_len: 128,
+};
+
+/// SHA-512, as specified in [FIPS 180-4](https://csrc.nist.gov/pubs/fips/180-4/upd1/final).
+pub static SHA512: Algorithm = Algorithm {
+    id: AlgorithmId::Sha512,
+    name: "SHA512",
+    output_len: SHA512_OUTPUT_LEN,
+    block_len: 128,
+};
+
+/// The length of a SHA-256 output.
+pub const SHA256_OUTPUT_LEN: usize = 32;
+
+/// The length of a SHA-384 output.
+pub const SHA384_OUTPUT_LEN: usize = 48;
+
+/// The length of a SHA-512 output.
+pub const SHA512_OUTPUT_LEN: usize = 64;
+
+/// The longest output of any supported algorithm.
+pub const MAX_OUTPUT_LEN: usize = SHA512_OUTPUT_LEN;
+
+/// The longest block length of any supported algorithm.
+pub const MAX_BLOCK_LEN: usize = 128;
+
+/// An in-progress hash computation.
+#[derive(Clone)]
+pub struct Context {
+    algorithm: &'static Algorithm,
+    inner: Inner,
+}
+
+#[derive(Clone)]
+enum Inner {
+    Sha256(<Sha256 as Hash>::Context),
+    Sha384(<Sha384 as Hash>::Context),
+    Sha512(<Sha512 as Hash>::Context),
+}
+
+impl Context {
+    /// Starts a new hash computation using `algorithm`.
+    pub fn new(algorithm: &'static Algorithm) -> Self {
+        let inner = match algorithm.id {
+            AlgorithmId::Sha256 => Inner::Sha256(Sha256::new()),
+            AlgorithmId::Sha384 => Inner::Sha384(Sha384::new()),
+            AlgorithmId::Sha512 => Inner::Sha512(Sha512::new()),
+        };
+        Self { algorithm, inner }
+    }
+
+    /// Adds `data` to the computation.
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.inner {
+            Inner::Sha256(ctx) => HashContext::update(ctx, data),
+            Inner::Sha384(ctx) => HashContext::update(ctx, data),
+            Inner::Sha512(ctx) => HashContext::update(ctx, data),
+        }
+    }
+
+    /// Completes the computation, returning the hash.
+    pub fn finish(self) -> Digest {
+        let value = match self.inner {
+            Inner::Sha256(ctx) => HashContext::finish(ctx),
+            Inner::Sha384(ctx) => HashContext::finish(ctx),
+            Inner::Sha512(ctx) => HashContext::finish(ctx),
+        };
+        Digest {
+            algorithm: self.algorithm,
+            value,
+        }
+    }
+
+    /// The algorithm this context is using.
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+impl fmt::Debug for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("algorithm", self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Hashes `data` using `algorithm`.
+pub fn digest(algorithm: &'static Algorithm, data: &[u8]) -> Digest {
+    let mut ctx = Context::new(algorithm);
+    ctx.update(data);
+    ctx.finish()
+}
+
+/// The output of a hash function.
+#[derive(Clone)]
+pub struct Digest {
+    algorithm: &'static Algorithm,
+    value: HashOutput,
+}
+
+impl Digest {
+    /// The algorithm that produced this value.
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
+impl AsRef<[u8]> for Digest {
+    fn as_ref(&self) -> &[u8] {
+        self.value.as_ref()
+    }
+}
+
+impl fmt::Debug for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}:", self.algorithm)?;
+        for b in self.as_ref() {
+            write!(f, "{b:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_answers() {
+        for (algorithm, expected) in [
+            (
+                &SHA256,
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            (
+                &SHA384,
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+                 8086072ba1e7cc2358baeca134c825a7",
+            ),
+            (
+                &SHA512,
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                 2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            ),
+        ] {
+            let d = digest(algorithm, b"abc");
+            assert_eq!(hex(d.as_ref()), expected);
+            assert_eq!(d.algorithm(), algorithm);
+            assert_eq!(d.as_ref().len(), algorithm.output_len());
+
+            let mut ctx = Context::new(algorithm);
+            ctx.update(b"a");
+            let fork = ctx.clone();
+            ctx.update(b"bc");
+            assert_eq!(hex(ctx.finish().as_ref()), expected);
+            assert_ne!(hex(fork.finish().as_ref()), expected);
+        }
+
+        assert_eq!(
+            format!("{:?}", digest(&SHA256, b"")),
+            "SHA256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(SHA384.block_len(), MAX_BLOCK_LEN);
+        assert_eq!(
+            format!("{:?}", Context::new(&SHA512)),
+            "Context { algorithm: SHA512, .. }"
+        );
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
@@ -0,0 +1,129 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::fmt;
+
+use crate::error::KeyFormatError;
+
+/// An error with no further detail.
+///
+/// Like *ring*, most operations in this module deliberately do not say
+/// why they failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unspecified;
+
+impl fmt::Display for Unspecified {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Unspecified")
+    }
+}
+
+impl std::error::Error for Unspecified {}
+
+impl From<crate::Error> for Unspecified {
+    fn from(_: crate::Error) -> Self {
+        Self
+    }
+}
+
+impl From<KeyRejected> for Unspecified {
+    fn from(_: KeyRejected) -> Self {
+        Self
+    }
+}
+
+/// A key could not be loaded.
+///
+/// The reason is a short, stable, description -- the same ones
+/// *ring* uses, such as `"InvalidEncoding"` or `"WrongAlgorithm"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyRejected(&'static str);
+
+impl KeyRejected {
+    /// Classifies an error from loading a key.
+    pub(super) fn from_error(error: crate::Error) -> Self {
+        match error {
+            crate::Error::KeyFormatError(
+                KeyFormatError::MismatchedPkcs8Algorithm
+                | KeyFormatError::MismatchedPkcs8Parameters,
+            ) => Self::wrong_algorithm(),
+            crate::Error::KeyFormatError(KeyFormatError::MismatchedPkcs8PublicKey) => {
+                Self::inconsistent_components()
+            }
+            _ => Self::invalid_encoding(),
+        }
+    }
+
+    pub(super) fn invalid_encoding() -> Self {
+        Self("InvalidEncoding")
+    }
+
+    pub(super) fn inconsistent_components() -> Self {
+        Self("InconsistentComponents")
+    }
+
+    pub(super) fn wrong_algorithm() -> Self {
+        Self("WrongAlgorithm")
+    }
+
+    pub(super) fn unexpected_error() -> Self {
+        Self("UnexpectedError")
+    }
+
+    /// The reason the key was rejected.
+    pub fn description_(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for KeyRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for KeyRejected {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Unspecified.to_string(), "Unspecified");
+        assert_eq!(
+            KeyRejected::invalid_encoding().to_string(),
+            "InvalidEncoding"
+        );
+        assert_eq!(
+            KeyRejected::wrong_algorithm().description_(),
+            "WrongAlgorithm"
+        );
+        assert_eq!(
+            Unspecified::from(KeyRejected::unexpected_error()),
+            Unspecified
+        );
+        assert_eq!(Unspecified::from(crate::Error::BadSignature), Unspecified);
+    }
+
+    #[test]
+    fn from_error() {
+        for (error, expected) in [
+            (
+                crate::Error::KeyFormatError(KeyFormatError::MismatchedPkcs8Algorithm),
+                "WrongAlgorithm",
+            ),
+            (
+                crate::Error::KeyFormatError(KeyFormatError::MismatchedPkcs8Parameters),
+                "WrongAlgorithm",
+            ),
+            (
+                crate::Error::KeyFormatError(KeyFormatError::MismatchedPkcs8PublicKey),
+                "InconsistentComponents",
+            ),
+            (crate::Error::WrongLength, "InvalidEncoding"),
+        ] {
+            assert_eq!(KeyRejected::from_error(error).description_(), expected);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::fmt;
+
+use super::digest::{self, AlgorithmId};
+use super::error::Unspecified;
+use super::rand::SecureRandom;
+use crate::high::hash::{HashOutput, Sha256, Sha384, Sha512};
+use crate::high::hmac::Hmac;
+use crate::low::zeroise;
+
+/// An HMAC algorithm.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Algorithm(&'static digest::Algorithm);
+
+impl Algorithm {
+    /// The underlying hash function.
+    pub fn digest_algorithm(&self) -> &'static digest::Algorithm {
+        self.0
+    }
+}
+
+impl fmt::Debug for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HMAC_{:?}", self.0)
+    }
+}
+
+/// HMAC using SHA-256.
+pub static HMAC_SHA256: Algorithm = Algorithm(&digest::SHA256);
+
+/// HMAC using SHA-384.
+pub static HMAC_SHA384: Algorithm = Algorithm(&digest::SHA384);
+
+/// HMAC using SHA-512.
+pub static HMAC_SHA512: Algorithm = Algorithm(&digest::SHA512);
+
+/// An HMAC key.
+///
+/// The key is held in the form of its precomputed inner and outer
+/// hash states, so it can be cheaply used many times.
+#[derive(Clone)]
+pub struct Key {
+    algorithm: Algorithm,
+    inner: Inner,
+}
+
+#[derive(Clone)]
+enum Inner {
+    Sha256(Hmac<Sha256>),
+    Sha384(Hmac<Sha384>),
+    Sha512(Hmac<Sha512>),
+}
+
+impl Key {
+    /// Makes a key from `key_value`.
+    ///
+    /// Any length of key is accepted; keys longer than the hash function's
+    /// block length are hashed first, as HMAC specifies.
+    pub fn new(algorithm: Algorithm, key_value: &[u8]) -> Self {
+        let inner = match algorithm.0.id {
+            AlgorithmId::Sha256 => Inner::Sha256(Hmac::new(key_value)),
+            AlgorithmId::Sha384 => Inner::Sha384(Hmac::new(key_value)),
+            AlgorithmId::Sha512 => Inner::Sha512(Hmac::new(key_value)),
+        };
+        Self { algorithm, inner }
+    }
+
+    /// Generates a random key, as long as the hash function's output.
+    pub fn generate(algorithm: Algorithm, rng: &dyn SecureRandom) -> Result<Self, Unspecified> {
+        let mut key_value = [0u8; digest::MAX_OUTPUT_LEN];
+        let key_value = &mut key_value[..algorithm.0.output_len()];
+        let rc = rng
+            .fill(key_value)
+            .map(|()| Self::new(algorithm, key_value));
+        zeroise(key_value);
+        rc
+    }
+
+    /// The algorithm this key is for.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Key")
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An in-progress HMAC computation.
+#[derive(Clone)]
+pub struct Context {
+    algorithm: Algorithm,
+    inner: Inner,
+}
+
+impl Context {
+    /// Starts a new HMAC computation using `key`.
+    pub fn with_key(key: &Key) -> Self {
+        Self {
+            algorithm: key.algorithm,
+            inner: key.inner.clone(),
+        }
+    }
+
+    /// Adds `data` to the computation.
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.inner {
+            Inner::Sha256(h) => h.update(data),
+            Inner::Sha384(h) => h.update(data),
+            Inner::Sha512(h) => h.update(data),
+        }
+    }
+
+    /// Completes the computation, returning the tag.
+    pub fn sign(self) -> Tag {
+        Tag(match self.inner {
+            Inner::Sha256(h) => h.finish(),
+            Inner::Sha384(h) => h.finish(),
+            Inner::Sha512(h) => h.finish(),
+        })
+    }
+}
+
+impl fmt::Debug for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An HMAC tag.
+#[derive(Clone, Debug)]
+pub struct Tag(HashOutput);
+
+impl AsRef<[u8]> for Tag {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+/// Computes the HMAC of `data` using `key`.
+pub fn sign(key: &Key, data: &[u8]) -> Tag {
+    let mut ctx = Context::with_key(key);
+    ctx.update(data);
+    ctx.sign()
+}
+
+/// Checks that `tag` is the HMAC of `data` using `key`.
+///
+/// The comparison is done in constant time, and `tag` may not be truncated.
+pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), Unspecified> {
+    match sign(key, data).0.ct_equal(tag) {
+        true => Ok(()),
+        false => Err(Unspecified),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::ring_compat::rand::SystemRandom;
+
+    #[test]
+    fn rfc4231_test_case_2() {
+        for (algorithm, expected) in [
+            (
+                HMAC_SHA256,
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            ),
+            (
+                HMAC_SHA384,
+                "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e\
+                 8e2240ca5e69e2c78b3239ecfab21649",
+            ),
+            (
+                HMAC_SHA512,
+                "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554\
+                 9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+            ),
+        ] {
+            let key = Key::new(algorithm, b"Jefe");
+            let data = b"what do ya want for nothing?";
+            let tag = sign(&key, data);
+            assert_eq!(hex(tag.as_ref()), expected);
+            verify(&key, data, tag.as_ref()).unwrap();
+
+            let mut ctx = Context::with_key(&key);
+            ctx.update(&data[..4]);
+            ctx.update(&data[4..]);
+            assert_eq!(hex(ctx.sign().as_ref()), expected);
+
+            assert_eq!(verify(&key, b"what", tag.as_ref()), Err(Unspecified));
+            assert_eq!(verify(&key, data, &tag.as_ref()[..16]), Err(Unspecified));
+        }
+    }
+
+    #[test]
+    fn generate() {
+        let rng = SystemRandom::new();
+        let a = Key::generate(HMAC_SHA384, &rng).unwrap();
+        let b = Key::generate(HMAC_SHA384, &rng).unwrap();
+        assert_eq!(a.algorithm(), HMAC_SHA384);
+        assert_eq!(a.algorithm().digest_algorithm(), &digest::SHA384);
+        assert_ne!(sign(&a, b"hello").as_ref(), sign(&b, b"hello").as_ref());
+        assert_eq!(format!("{a:?}"), "Key { algorithm: HMAC_SHA384, .. }");
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
@@ -0,0 +1,27 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::low::zeroise;
+
+/// A PKCS#8 document, containing a private key.
+///
+/// The contents are erased when this is dropped.
+pub struct Document(Vec<u8>);
+
+impl Document {
+    pub(super) fn new(der: &[u8]) -> Self {
+        Self(der.to_vec())
+    }
+}
+
+impl AsRef<[u8]> for Document {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Drop for Document {
+    fn drop(&mut self) {
+        zeroise(&mut self.0);
+    }
+}
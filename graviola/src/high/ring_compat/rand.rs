@@ -0,0 +1,53 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::error::Unspecified;
+use crate::mid::rng::{RandomSource, SystemRandom as Inner};
+
+/// A source of cryptographic-quality random bytes.
+pub trait SecureRandom {
+    /// Fills the entirety of `dest` with random bytes.
+    fn fill(&self, dest: &mut [u8]) -> Result<(), Unspecified>;
+}
+
+/// Random bytes from the operating system.
+#[derive(Clone, Debug)]
+pub struct SystemRandom(());
+
+impl SystemRandom {
+    /// Makes a new `SystemRandom`.
+    pub fn new() -> Self {
+        Self(())
+    }
+}
+
+impl SecureRandom for SystemRandom {
+    fn fill(&self, dest: &mut [u8]) -> Result<(), Unspecified> {
+        Ok(Inner.fill(dest)?)
+    }
+}
+
+/// Adapts a [`SecureRandom`] for use inside this crate.
+pub(super) struct Adapter<'a>(pub(super) &'a dyn SecureRandom);
+
+impl RandomSource for Adapter<'_> {
+    fn fill(&mut self, out: &mut [u8]) -> Result<(), crate::Error> {
+        self.0.fill(out).map_err(|_| crate::Error::RngFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_random() {
+        let rng = SystemRandom::new();
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        rng.fill(&mut a).unwrap();
+        rng.fill(&mut b).unwrap();
+        assert_ne!(a, b);
+        rng.fill(&mut []).unwrap();
+    }
+}
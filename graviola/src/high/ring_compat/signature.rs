@@ -0,0 +1,865 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::fmt;
+
+use super::digest::{self, AlgorithmId};
+use super::error::{KeyRejected, Unspecified};
+use super::pkcs8::Document;
+use super::rand::SecureRandom;
+use crate::high::curve::{
+    Curve, MAX_SCALAR_LEN, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, P256, P384, PrivateKey,
+};
+use crate::high::hash::{Hash, Sha256, Sha384};
+use crate::high::{ecdsa, rsa};
+use crate::low::zeroise;
+use crate::mid::ed25519;
+
+/// A signature verification algorithm.
+///
+/// Each algorithm defines the encoding of the public key and signature
+/// it accepts.
+pub trait VerificationAlgorithm: fmt::Debug + Send + Sync {
+    /// Verifies `signature` over `msg`, using `public_key`.
+    fn verify(&self, public_key: &[u8], msg: &[u8], signature: &[u8]) -> Result<(), Unspecified>;
+}
+
+/// A public key with its verification algorithm, which has not yet
+/// been validated.
+#[derive(Clone, Copy, Debug)]
+pub struct UnparsedPublicKey<B> {
+    algorithm: &'static dyn VerificationAlgorithm,
+    bytes: B,
+}
+
+impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
+    /// Wraps `bytes`, which is a public key for `algorithm`.
+    pub fn new(algorithm: &'static dyn VerificationAlgorithm, bytes: B) -> Self {
+        Self { algorithm, bytes }
+    }
+
+    /// Verifies `signature` over `message`.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Unspecified> {
+        self.algorithm
+            .verify(self.bytes.as_ref(), message, signature)
+    }
+}
+
+/// Ed25519 signature verification parameters.
+#[derive(Debug)]
+pub struct EdDSAParameters;
+
+/// Ed25519 signature verification.
+///
+/// Public keys are 32 bytes, and signatures 64 bytes.
+pub static ED25519: EdDSAParameters = EdDSAParameters;
+
+impl VerificationAlgorithm for EdDSAParameters {
+    fn verify(&self, public_key: &[u8], msg: &[u8], signature: &[u8]) -> Result<(), Unspecified> {
+        Ok(ed25519::VerifyingKey::from_bytes(public_key)?.verify(&[msg], signature)?)
+    }
+}
+
+/// ECDSA signature verification parameters.
+pub struct EcdsaVerificationAlgorithm {
+    curve: EcdsaCurve,
+    fixed: bool,
+    name: &'static str,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EcdsaCurve {
+    P256,
+    P384,
+}
+
+impl fmt::Debug for EcdsaVerificationAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl VerificationAlgorithm for EcdsaVerificationAlgorithm {
+    fn verify(&self, public_key: &[u8], msg: &[u8], signature: &[u8]) -> Result<(), Unspecified> {
+        match self.curve {
+            EcdsaCurve::P256 => {
+                ecdsa_verify::<P256, Sha256>(self.fixed, public_key, msg, signature)
+            }
+            EcdsaCurve::P384 => {
+                ecdsa_verify::<P384, Sha384>(self.fixed, public_key, msg, signature)
+            }
+        }
+    }
+}
+
+fn ecdsa_verify<C: Curve, H: Hash>(
+    fixed: bool,
+    public_key: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), Unspecified> {
+    let key = ecdsa::VerifyingKey::<C>::from_x962_uncompressed(public_key)?;
+    let rc = match fixed {
+        true => key.verify::<H>(&[msg], signature),
+        false => key.verify_asn1::<H>(&[msg], signature),
+    };
+    Ok(rc?)
+}
+
+/// ECDSA verification using P-256 and SHA-256, with ASN.1 DER signatures.
+///
+/// Public keys are in uncompressed X9.62 format.
+pub static ECDSA_P256_SHA256_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    curve: EcdsaCurve::P256,
+    fixed: false,
+    name: "ECDSA_P256_SHA256_ASN1",
+};
+
+/// ECDSA verification using P-256 and SHA-256, with fixed-length signatures.
+///
+/// Public keys are in uncompressed X9.62 format.
+pub static ECDSA_P256_SHA256_FIXED: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    curve: EcdsaCurve::P256,
+    fixed: true,
+    name: "ECDSA_P256_SHA256_FIXED",
+};
+
+/// ECDSA verification using P-384 and SHA-384, with ASN.1 DER signatures.
+///
+/// Public keys are in uncompressed X9.62 format.
+pub static ECDSA_P384_SHA384_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    curve: EcdsaCurve::P384,
+    fixed: false,
+    name: "ECDSA_P384_SHA384_ASN1",
+};
+
+/// ECDSA verification using P-384 and SHA-384, with fixed-length signatures.
+///
+/// Public keys are in uncompressed X9.62 format.
+pub static ECDSA_P384_SHA384_FIXED: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
+    curve: EcdsaCurve::P384,
+    fixed: true,
+    name: "ECDSA_P384_SHA384_FIXED",
+};
+
+/// RSA signature verification parameters.
+pub struct RsaParameters {
+    encoding: &'static RsaEncoding,
+    name: &'static str,
+}
+
+impl fmt::Debug for RsaParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl VerificationAlgorithm for RsaParameters {
+    fn verify(&self, public_key: &[u8], msg: &[u8], signature: &[u8]) -> Result<(), Unspecified> {
+        let key = rsa::VerifyingKey::from_pkcs1_der(public_key)?;
+        let rc = match (self.encoding.pss, self.encoding.digest.id) {
+            (false, AlgorithmId::Sha256) => key.verify_pkcs1_sha256(signature, msg),
+            (false, AlgorithmId::Sha384) => key.verify_pkcs1_sha384(signature, msg),
+            (false, AlgorithmId::Sha512) => key.verify_pkcs1_sha512(signature, msg),
+            (true, AlgorithmId::Sha256) => key.verify_pss_sha256(signature, msg),
+            (true, AlgorithmId::Sha384) => key.verify_pss_sha384(signature, msg),
+            (true, AlgorithmId::Sha512) => key.verify_pss_sha512(signature, msg),
+        };
+        Ok(rc?)
+    }
+}
+
+macro_rules! rsa_parameters {
+    ($name:ident, $encoding:ident, $doc:literal) => {
+        #[doc = $doc]
+        ///
+        /// Public keys are in PKCS#1 `RSAPublicKey` DER format, with a
+        /// modulus between 2048 and 8192 bits.
+        pub static $name: RsaParameters = RsaParameters {
+            encoding: &$encoding,
+            name: stringify!($name),
+        };
+    };
+}
+
+rsa_parameters!(
+    RSA_PKCS1_2048_8192_SHA256,
+    RSA_PKCS1_SHA256,
+    "RSASSA-PKCS1-v1_5 verification using SHA-256."
+);
+rsa_parameters!(
+    RSA_PKCS1_2048_8192_SHA384,
+    RSA_PKCS1_SHA384,
+    "RSASSA-PKCS1-v1_5 verification using SHA-384."
+);
+rsa_parameters!(
+    RSA_PKCS1_2048_8192_SHA512,
+    RSA_PKCS1_SHA512,
+    "RSASSA-PKCS1-v1_5 verification using SHA-512."
+);
+rsa_parameters!(
+    RSA_PSS_2048_8192_SHA256,
+    RSA_PSS_SHA256,
+    "RSASSA-PSS verification using SHA-256, with MGF1 and a salt as long as the hash."
+);
+rsa_parameters!(
+    RSA_PSS_2048_8192_SHA384,
+    RSA_PSS_SHA384,
+    "RSASSA-PSS verification using SHA-384, with MGF1 and a salt as long as the hash."
+);
+rsa_parameters!(
+    RSA_PSS_2048_8192_SHA512,
+    RSA_PSS_SHA512,
+    "RSASSA-PSS verification using SHA-512, with MGF1 and a salt as long as the hash."
+);
+
+/// A signature, from [`Ed25519KeyPair`] or [`EcdsaKeyPair`].
+#[derive(Clone, Copy)]
+pub struct Signature {
+    value: [u8; MAX_SIGNATURE_LEN],
+    len: usize,
+}
+
+impl Signature {
+    fn new(value: &[u8]) -> Self {
+        let mut sig = Self {
+            value: [0u8; MAX_SIGNATURE_LEN],
+            len: value.len(),
+        };
+        sig.value[..value.len()].copy_from_slice(value);
+        sig
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.value[..self.len]
+    }
+}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Signature").field(&self.as_ref()).finish()
+    }
+}
+
+// an ASN.1 ECDSA P-384 signature: `SEQUENCE { INTEGER, INTEGER }`, each
+// with a possible leading zero.
+const MAX_SIGNATURE_LEN: usize = 2 + 2 * (2 + MAX_SCALAR_LEN + 1);
+
+/// A key pair for signing.
+pub trait KeyPair: fmt::Debug + Send + Sized + Sync {
+    /// The type of the public key.
+    type PublicKey: AsRef<[u8]> + fmt::Debug + Clone + Send + Sized + Sync;
+
+    /// The public key, encoded as the matching verification algorithm expects.
+    fn public_key(&self) -> &Self::PublicKey;
+}
+
+/// The public key of a key pair.
+///
+/// This is encoded as expected by the matching [`VerificationAlgorithm`].
+#[derive(Clone, Debug)]
+pub struct PublicKey(Vec<u8>);
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// An Ed25519 key pair.
+pub struct Ed25519KeyPair {
+    key: ed25519::SigningKey,
+    public_key: PublicKey,
+}
+
+impl Ed25519KeyPair {
+    /// Generates a new key pair, and returns it in PKCS#8 format.
+    ///
+    /// Load the result with [`Self::from_pkcs8()`].
+    pub fn generate_pkcs8(rng: &dyn SecureRandom) -> Result<Document, Unspecified> {
+        let mut seed = [0u8; ed25519::SEED_LEN];
+        let rc = rng.fill(&mut seed);
+        let key = ed25519::SigningKey::from_seed(&seed);
+        zeroise(&mut seed);
+        rc?;
+
+        let mut buf = [0u8; 128];
+        let rc = key.to_pkcs8_der(&mut buf).map(Document::new);
+        zeroise(&mut buf);
+        Ok(rc?)
+    }
+
+    /// Loads a key pair from PKCS#8 format.
+    ///
+    /// Both v1 and v2 (which include the public key) encodings are accepted.
+    /// In the latter case, the public key must match the private key.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<Self, KeyRejected> {
+        ed25519::SigningKey::from_pkcs8_der(pkcs8)
+            .map(Self::new)
+            .map_err(KeyRejected::from_error)
+    }
+
+    /// Loads a key pair from PKCS#8 format.
+    ///
+    /// This is the same as [`Self::from_pkcs8()`], which already accepts
+    /// both v1 and v2 encodings.
+    pub fn from_pkcs8_maybe_unchecked(pkcs8: &[u8]) -> Result<Self, KeyRejected> {
+        Self::from_pkcs8(pkcs8)
+    }
+
+    /// Makes a key pair from a 32-byte `seed`.
+    pub fn from_seed_unchecked(seed: &[u8]) -> Result<Self, KeyRejected> {
+        let seed = seed
+            .try_into()
+            .map_err(|_| KeyRejected::invalid_encoding())?;
+        Ok(Self::new(ed25519::SigningKey::from_seed(seed)))
+    }
+
+    /// Makes a key pair from a 32-byte `seed`, checking it against
+    /// `public_key`.
+    pub fn from_seed_and_public_key(seed: &[u8], public_key: &[u8]) -> Result<Self, KeyRejected> {
+        let key_pair = Self::from_seed_unchecked(seed)?;
+        match key_pair.public_key.as_ref() == public_key {
+            true => Ok(key_pair),
+            false => Err(KeyRejected::inconsistent_components()),
+        }
+    }
+
+    fn new(key: ed25519::SigningKey) -> Self {
+        let public_key = PublicKey(key.verifying_key().as_bytes().to_vec());
+        Self { key, public_key }
+    }
+
+    /// Signs `msg`.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        Signature::new(&self.key.sign(&[msg]))
+    }
+}
+
+impl KeyPair for Ed25519KeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+impl fmt::Debug for Ed25519KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ed25519KeyPair")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// ECDSA signing parameters.
+pub struct EcdsaSigningAlgorithm {
+    curve: EcdsaCurve,
+    fixed: bool,
+    name: &'static str,
+}
+
+impl fmt::Debug for EcdsaSigningAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// ECDSA signing using P-256 and SHA-256, producing ASN.1 DER signatures.
+pub static ECDSA_P256_SHA256_ASN1_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: EcdsaCurve::P256,
+    fixed: false,
+    name: "ECDSA_P256_SHA256_ASN1_SIGNING",
+};
+
+/// ECDSA signing using P-256 and SHA-256, producing fixed-length signatures.
+pub static ECDSA_P256_SHA256_FIXED_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: EcdsaCurve::P256,
+    fixed: true,
+    name: "ECDSA_P256_SHA256_FIXED_SIGNING",
+};
+
+/// ECDSA signing using P-384 and SHA-384, producing ASN.1 DER signatures.
+pub static ECDSA_P384_SHA384_ASN1_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: EcdsaCurve::P384,
+    fixed: false,
+    name: "ECDSA_P384_SHA384_ASN1_SIGNING",
+};
+
+/// ECDSA signing using P-384 and SHA-384, producing fixed-length signatures.
+pub static ECDSA_P384_SHA384_FIXED_SIGNING: EcdsaSigningAlgorithm = EcdsaSigningAlgorithm {
+    curve: EcdsaCurve::P384,
+    fixed: true,
+    name: "ECDSA_P384_SHA384_FIXED_SIGNING",
+};
+
+/// An ECDSA key pair.
+pub struct EcdsaKeyPair {
+    algorithm: &'static EcdsaSigningAlgorithm,
+    key: EcdsaKey,
+    public_key: PublicKey,
+}
+
+enum EcdsaKey {
+    P256(ecdsa::SigningKey<P256>),
+    P384(ecdsa::SigningKey<P384>),
+}
+
+impl EcdsaKeyPair {
+    /// Generates a new key pair for `algorithm`, and returns it in PKCS#8 format.
+    ///
+    /// Load the result with [`Self::from_pkcs8()`].
+    pub fn generate_pkcs8(
+        algorithm: &'static EcdsaSigningAlgorithm,
+        rng: &dyn SecureRandom,
+    ) -> Result<Document, Unspecified> {
+        let mut rng = super::rand::Adapter(rng);
+        let mut buf = [0u8; MAX_PKCS8_LEN];
+        let rc = match algorithm.curve {
+            EcdsaCurve::P256 => ecdsa::SigningKey::<P256> {
+                private_key: P256::generate_random_key(&mut rng)?,
+            }
+            .to_pkcs8_der(&mut buf)
+            .map(Document::new),
+            EcdsaCurve::P384 => ecdsa::SigningKey::<P384> {
+                private_key: P384::generate_random_key(&mut rng)?,
+            }
+            .to_pkcs8_der(&mut buf)
+            .map(Document::new),
+        };
+        zeroise(&mut buf);
+        Ok(rc?)
+    }
+
+    /// Loads a key pair for `algorithm` from PKCS#8 format.
+    ///
+    /// Both v1 and v2 (which include the public key) encodings are accepted.
+    /// In the latter case, the public key must match the private key.
+    ///
+    /// `rng` is not used, and is accepted only for compatibility.
+    pub fn from_pkcs8(
+        algorithm: &'static EcdsaSigningAlgorithm,
+        pkcs8: &[u8],
+        _rng: &dyn SecureRandom,
+    ) -> Result<Self, KeyRejected> {
+        let key = match algorithm.curve {
+            EcdsaCurve::P256 => ecdsa::SigningKey::from_pkcs8_der(pkcs8).map(EcdsaKey::P256),
+            EcdsaCurve::P384 => ecdsa::SigningKey::from_pkcs8_der(pkcs8).map(EcdsaKey::P384),
+        }
+        .map_err(KeyRejected::from_error)?;
+        Self::new(algorithm, key)
+    }
+
+    /// Makes a key pair for `algorithm` from its private scalar (big-endian,
+    /// fixed-length) and uncompressed X9.62 public key.
+    ///
+    /// `rng` is not used, and is accepted only for compatibility.
+    pub fn from_private_key_and_public_key(
+        algorithm: &'static EcdsaSigningAlgorithm,
+        private_key: &[u8],
+        public_key: &[u8],
+        _rng: &dyn SecureRandom,
+    ) -> Result<Self, KeyRejected> {
+        let key = match algorithm.curve {
+            EcdsaCurve::P256 => PrivateKey::from_bytes(private_key)
+                .map(|private_key| EcdsaKey::P256(ecdsa::SigningKey { private_key })),
+            EcdsaCurve::P384 => PrivateKey::from_bytes(private_key)
+                .map(|private_key| EcdsaKey::P384(ecdsa::SigningKey { private_key })),
+        }
+        .map_err(KeyRejected::from_error)?;
+
+        let key_pair = Self::new(algorithm, key)?;
+        match key_pair.public_key.as_ref() == public_key {
+            true => Ok(key_pair),
+            false => Err(KeyRejected::inconsistent_components()),
+        }
+    }
+
+    fn new(algorithm: &'static EcdsaSigningAlgorithm, key: EcdsaKey) -> Result<Self, KeyRejected> {
+        let mut public_key = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+        let public_key = match &key {
+            EcdsaKey::P256(k) => k
+                .private_key
+                .public_key_encode_uncompressed(&mut public_key),
+            EcdsaKey::P384(k) => k
+                .private_key
+                .public_key_encode_uncompressed(&mut public_key),
+        }
+        .map_err(|_| KeyRejected::unexpected_error())?;
+
+        Ok(Self {
+            algorithm,
+            public_key: PublicKey(public_key.to_vec()),
+            key,
+        })
+    }
+
+    /// Signs `message`.
+    ///
+    /// The nonce is derived deterministically as in
+    /// [RFC6979](https://datatracker.ietf.org/doc/html/rfc6979), with
+    /// additional randomness from `rng`.
+    pub fn sign(&self, rng: &dyn SecureRandom, message: &[u8]) -> Result<Signature, Unspecified> {
+        let mut random = [0u8; 16];
+        rng.fill(&mut random)?;
+
+        let mut fixed = [0u8; MAX_SCALAR_LEN * 2];
+        let mut asn1 = [0u8; MAX_SIGNATURE_LEN];
+        let signature = match &self.key {
+            EcdsaKey::P256(k) => {
+                let fixed =
+                    k.rfc6979_sign_with_random::<Sha256>(&[message], &random, &mut fixed)?;
+                match self.algorithm.fixed {
+                    true => fixed,
+                    false => ecdsa::SigningKey::<P256>::fixed_to_asn1(fixed, &mut asn1)?,
+                }
+            }
+            EcdsaKey::P384(k) => {
+                let fixed =
+                    k.rfc6979_sign_with_random::<Sha384>(&[message], &random, &mut fixed)?;
+                match self.algorithm.fixed {
+                    true => fixed,
+                    false => ecdsa::SigningKey::<P384>::fixed_to_asn1(fixed, &mut asn1)?,
+                }
+            }
+        };
+        Ok(Signature::new(signature))
+    }
+}
+
+// `PrivateKeyInfo` containing a SEC1 `ECPrivateKey` with its public key.
+const MAX_PKCS8_LEN: usize = MAX_SCALAR_LEN + MAX_UNCOMPRESSED_PUBLIC_KEY_LEN + 128;
+
+impl KeyPair for EcdsaKeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+impl fmt::Debug for EcdsaKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EcdsaKeyPair")
+            .field("algorithm", self.algorithm)
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An RSA signature encoding.
+pub struct RsaEncoding {
+    pss: bool,
+    digest: &'static digest::Algorithm,
+}
+
+impl fmt::Debug for RsaEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.pss {
+            true => write!(f, "RSA_PSS_{:?}", self.digest),
+            false => write!(f, "RSA_PKCS1_{:?}", self.digest),
+        }
+    }
+}
+
+/// RSASSA-PKCS1-v1_5 signing using SHA-256.
+pub static RSA_PKCS1_SHA256: RsaEncoding = RsaEncoding {
+    pss: false,
+    digest: &digest::SHA256,
+};
+
+/// RSASSA-PKCS1-v1_5 signing using SHA-384.
+pub static RSA_PKCS1_SHA384: RsaEncoding = RsaEncoding {
+    pss: false,
+    digest: &digest::SHA384,
+};
+
+/// RSASSA-PKCS1-v1_5 signing using SHA-512.
+pub static RSA_PKCS1_SHA512: RsaEncoding = RsaEncoding {
+    pss: false,
+    digest: &digest::SHA512,
+};
+
+/// RSASSA-PSS signing using SHA-256, with MGF1 and a salt as long as the hash.
+pub static RSA_PSS_SHA256: RsaEncoding = RsaEncoding {
+    pss: true,
+    digest: &digest::SHA256,
+};
+
+/// RSASSA-PSS signing using SHA-384, with MGF1 and a salt as long as the hash.
+pub static RSA_PSS_SHA384: RsaEncoding = RsaEncoding {
+    pss: true,
+    digest: &digest::SHA384,
+};
+
+/// RSASSA-PSS signing using SHA-512, with MGF1 and a salt as long as the hash.
+pub static RSA_PSS_SHA512: RsaEncoding = RsaEncoding {
+    pss: true,
+    digest: &digest::SHA512,
+};
+
+/// An RSA key pair.
+pub struct RsaKeyPair {
+    key: rsa::SigningKey,
+    public_key: PublicKey,
+}
+
+impl RsaKeyPair {
+    /// Loads a key pair from PKCS#8 format.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> Result<Self, KeyRejected> {
+        rsa::SigningKey::from_pkcs8_der(pkcs8)
+            .map_err(KeyRejected::from_error)
+            .and_then(Self::new)
+    }
+
+    /// Loads a key pair from PKCS#1 `RSAPrivateKey` DER format.
+    pub fn from_der(input: &[u8]) -> Result<Self, KeyRejected> {
+        rsa::SigningKey::from_pkcs1_der(input)
+            .map_err(KeyRejected::from_error)
+            .and_then(Self::new)
+    }
+
+    fn new(key: rsa::SigningKey) -> Result<Self, KeyRejected> {
+        let mut public_key = [0u8; rsa::VerifyingKey::MAX_PUBLIC_PKCS1_LEN];
+        let public_key = key
+            .public_key()
+            .to_pkcs1_der(&mut public_key)
+            .map_err(|_| KeyRejected::unexpected_error())?;
+        Ok(Self {
+            public_key: PublicKey(public_key.to_vec()),
+            key,
+        })
+    }
+
+    /// The length of the public modulus, in bytes.
+    ///
+    /// This is also the length of every signature.
+    pub fn public_modulus_len(&self) -> usize {
+        self.key.modulus_len_bytes()
+    }
+
+    /// Signs `msg` using `padding_alg`, writing the signature to `signature`.
+    ///
+    /// `signature` must be exactly [`Self::public_modulus_len()`] bytes long.
+    ///
+    /// `rng` is not used: PSS salts are taken from the operating system.
+    pub fn sign(
+        &self,
+        padding_alg: &'static RsaEncoding,
+        _rng: &dyn SecureRandom,
+        msg: &[u8],
+        signature: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        if signature.len() != self.public_modulus_len() {
+            return Err(Unspecified);
+        }
+
+        match (padding_alg.pss, padding_alg.digest.id) {
+            (false, AlgorithmId::Sha256) => self.key.sign_pkcs1_sha256(signature, msg)?,
+            (false, AlgorithmId::Sha384) => self.key.sign_pkcs1_sha384(signature, msg)?,
+            (false, AlgorithmId::Sha512) => self.key.sign_pkcs1_sha512(signature, msg)?,
+            (true, AlgorithmId::Sha256) => self.key.sign_pss_sha256(signature, msg)?,
+            (true, AlgorithmId::Sha384) => self.key.sign_pss_sha384(signature, msg)?,
+            (true, AlgorithmId::Sha512) => self.key.sign_pss_sha512(signature, msg)?,
+        };
+        Ok(())
+    }
+}
+
+impl KeyPair for RsaKeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+impl fmt::Debug for RsaKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaKeyPair")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::ring_compat::rand::SystemRandom;
+
+    #[test]
+    fn rfc8032_ed25519() {
+        let seed = unhex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+        let public_key = unhex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+
+        let key_pair = Ed25519KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+        let signature = key_pair.sign(b"");
+        assert_eq!(
+            hex(signature.as_ref()),
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+             5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        );
+
+        let public = UnparsedPublicKey::new(&ED25519, key_pair.public_key().as_ref());
+        public.verify(b"", signature.as_ref()).unwrap();
+        assert_eq!(public.verify(b"x", signature.as_ref()), Err(Unspecified));
+
+        assert_eq!(
+            Ed25519KeyPair::from_seed_and_public_key(&seed, &seed).unwrap_err(),
+            KeyRejected::inconsistent_components()
+        );
+        assert!(Ed25519KeyPair::from_seed_unchecked(&seed[1..]).is_err());
+    }
+
+    #[test]
+    fn ed25519_pkcs8() {
+        let rng = SystemRandom::new();
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let signature = key_pair.sign(b"hello");
+        UnparsedPublicKey::new(&ED25519, key_pair.public_key())
+            .verify(b"hello", signature.as_ref())
+            .unwrap();
+
+        Ed25519KeyPair::from_pkcs8_maybe_unchecked(include_bytes!("../pkcs8/ed25519-v2.pkcs8.der"))
+            .unwrap();
+        assert_eq!(
+            Ed25519KeyPair::from_pkcs8(include_bytes!(
+                "../pkcs8/ed25519-v2-wrong-public-key.pkcs8.der"
+            ))
+            .unwrap_err(),
+            KeyRejected::inconsistent_components()
+        );
+        assert_eq!(
+            Ed25519KeyPair::from_pkcs8(include_bytes!("../pkcs8/x25519.pkcs8.der")).unwrap_err(),
+            KeyRejected::wrong_algorithm()
+        );
+    }
+
+    #[test]
+    fn ecdsa() {
+        let rng = SystemRandom::new();
+        for (signing, verification) in [
+            (&ECDSA_P256_SHA256_ASN1_SIGNING, &ECDSA_P256_SHA256_ASN1),
+            (&ECDSA_P256_SHA256_FIXED_SIGNING, &ECDSA_P256_SHA256_FIXED),
+            (&ECDSA_P384_SHA384_ASN1_SIGNING, &ECDSA_P384_SHA384_ASN1),
+            (&ECDSA_P384_SHA384_FIXED_SIGNING, &ECDSA_P384_SHA384_FIXED),
+        ] {
+            let pkcs8 = EcdsaKeyPair::generate_pkcs8(signing, &rng).unwrap();
+            let key_pair = EcdsaKeyPair::from_pkcs8(signing, pkcs8.as_ref(), &rng).unwrap();
+            let signature = key_pair.sign(&rng, b"hello").unwrap();
+
+            let public = UnparsedPublicKey::new(verification, key_pair.public_key().as_ref());
+            public.verify(b"hello", signature.as_ref()).unwrap();
+            assert_eq!(
+                public.verify(b"world", signature.as_ref()),
+                Err(Unspecified)
+            );
+            assert_ne!(
+                key_pair.sign(&rng, b"hello").unwrap().as_ref(),
+                signature.as_ref()
+            );
+        }
+
+        let pkcs8 = include_bytes!("../ecdsa/secp256r1.pkcs8.der");
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8, &rng).unwrap();
+        assert_eq!(
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P384_SHA384_ASN1_SIGNING, pkcs8, &rng).unwrap_err(),
+            KeyRejected::wrong_algorithm()
+        );
+
+        let ecdsa::SigningKey { private_key } =
+            ecdsa::SigningKey::<P256>::from_pkcs8_der(pkcs8).unwrap();
+        let mut scalar = [0u8; 32];
+        private_key.encode(&mut scalar).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        EcdsaKeyPair::from_private_key_and_public_key(
+            &ECDSA_P256_SHA256_FIXED_SIGNING,
+            &scalar,
+            public_key,
+            &rng,
+        )
+        .unwrap();
+        assert_eq!(
+            EcdsaKeyPair::from_private_key_and_public_key(
+                &ECDSA_P256_SHA256_FIXED_SIGNING,
+                &scalar,
+                &public_key[..64],
+                &rng,
+            )
+            .unwrap_err(),
+            KeyRejected::inconsistent_components()
+        );
+    }
+
+    #[test]
+    fn rsa() {
+        let rng = SystemRandom::new();
+        let key_pair = RsaKeyPair::from_pkcs8(include_bytes!("../rsa/rsa2048.pkcs8.der")).unwrap();
+        let from_der = RsaKeyPair::from_der(include_bytes!("../rsa/rsa2048.der")).unwrap();
+        assert_eq!(
+            key_pair.public_key().as_ref(),
+            from_der.public_key().as_ref()
+        );
+        assert_eq!(key_pair.public_modulus_len(), 256);
+
+        for (encoding, verification) in [
+            (&RSA_PKCS1_SHA256, &RSA_PKCS1_2048_8192_SHA256),
+            (&RSA_PKCS1_SHA384, &RSA_PKCS1_2048_8192_SHA384),
+            (&RSA_PKCS1_SHA512, &RSA_PKCS1_2048_8192_SHA512),
+            (&RSA_PSS_SHA256, &RSA_PSS_2048_8192_SHA256),
+            (&RSA_PSS_SHA384, &RSA_PSS_2048_8192_SHA384),
+            (&RSA_PSS_SHA512, &RSA_PSS_2048_8192_SHA512),
+        ] {
+            let mut signature = vec![0u8; key_pair.public_modulus_len()];
+            key_pair
+                .sign(encoding, &rng, b"hello", &mut signature)
+                .unwrap();
+
+            let public = UnparsedPublicKey::new(verification, key_pair.public_key());
+            public.verify(b"hello", &signature).unwrap();
+            assert_eq!(public.verify(b"world", &signature), Err(Unspecified));
+        }
+
+        let mut short = [0u8; 255];
+        assert_eq!(
+            key_pair.sign(&RSA_PKCS1_SHA256, &rng, b"hello", &mut short),
+            Err(Unspecified)
+        );
+
+        // the schemes are not interchangeable
+        let mut signature = [0u8; 256];
+        key_pair
+            .sign(&RSA_PKCS1_SHA256, &rng, b"hello", &mut signature)
+            .unwrap();
+        assert_eq!(
+            UnparsedPublicKey::new(&RSA_PSS_2048_8192_SHA256, key_pair.public_key())
+                .verify(b"hello", &signature),
+            Err(Unspecified)
+        );
+        assert_eq!(
+            UnparsedPublicKey::new(&RSA_PKCS1_2048_8192_SHA384, key_pair.public_key())
+                .verify(b"hello", &signature),
+            Err(Unspecified)
+        );
+        assert_eq!(format!("{RSA_PSS_SHA384:?}"), "RSA_PSS_SHA384");
+        assert_eq!(
+            format!("{RSA_PKCS1_2048_8192_SHA512:?}"),
+            "RSA_PKCS1_2048_8192_SHA512"
+        );
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
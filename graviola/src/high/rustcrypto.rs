@@ -0,0 +1,17 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Implementations of the [RustCrypto](https://github.com/RustCrypto/traits)
+//! traits for graviola's types.
+//!
+//! Each set of traits is behind its own feature, so only the trait crates
+//! actually used need be depended on.
+
+#[cfg(feature = "rustcrypto-aead")]
+mod aead;
+
+#[cfg(feature = "rustcrypto-digest")]
+mod digest;
+
+#[cfg(feature = "rustcrypto-signature")]
+mod signature;
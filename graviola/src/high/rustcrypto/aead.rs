@@ -0,0 +1,155 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! `aead::AeadInPlace` and `aead::KeyInit`.
+//!
+//! `aead::Aead` (allocating encryption and decryption) then comes from the
+//! `aead` crate's blanket implementation.
+
+use ::aead::consts::{U0, U12, U16, U24, U32};
+use ::aead::{AeadCore, AeadInPlace, Key, KeyInit, KeySizeUser, Nonce, Tag};
+
+use crate::mid::aead::{Aead, Aes128Gcm, Aes256Gcm};
+use crate::mid::chacha20poly1305::ChaCha20Poly1305;
+use crate::mid::xchacha20poly1305::XChaCha20Poly1305;
+
+macro_rules! rustcrypto_aead {
+    ($name:ident, $key_size:ty, $nonce_size:ty) => {
+        impl KeySizeUser for $name {
+            type KeySize = $key_size;
+        }
+
+        impl KeyInit for $name {
+            fn new(key: &Key<Self>) -> Self {
+                <Self as Aead>::new(key).expect("key length is fixed by its type")
+            }
+        }
+
+        impl AeadCore for $name {
+            type NonceSize = $nonce_size;
+            type TagSize = U16;
+            type CiphertextOverhead = U0;
+        }
+
+        impl AeadInPlace for $name {
+            fn encrypt_in_place_detached(
+                &self,
+                nonce: &Nonce<Self>,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+            ) -> Result<Tag<Self>, ::aead::Error> {
+                let mut tag = Tag::<Self>::default();
+                self.seal(nonce, associated_data, buffer, &mut tag)
+                    .map_err(|_| ::aead::Error)?;
+                Ok(tag)
+            }
+
+            fn decrypt_in_place_detached(
+                &self,
+                nonce: &Nonce<Self>,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+                tag: &Tag<Self>,
+            ) -> Result<(), ::aead::Error> {
+                self.open(nonce, associated_data, buffer, tag)
+                    .map_err(|_| ::aead::Error)
+            }
+        }
+    };
+}
+
+rustcrypto_aead!(Aes128Gcm, U16, U12);
+rustcrypto_aead!(Aes256Gcm, U32, U12);
+rustcrypto_aead!(ChaCha20Poly1305, U32, U12);
+rustcrypto_aead!(XChaCha20Poly1305, U32, U24);
+
+#[cfg(test)]
+mod tests {
+    use ::aead::Payload;
+
+    use super::*;
+
+    #[test]
+    fn rfc8439_vector() {
+        let key = unhex("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+        let plaintext = b"Ladies and Gentlemen of the class of '99: \
+              If I could offer you only one tip for the future, sunscreen would be it.";
+        let aad = unhex("50515253c0c1c2c3c4c5c6c7");
+
+        let cipher = <ChaCha20Poly1305 as KeyInit>::new_from_slice(&key).unwrap();
+        let nonce =
+            Nonce::<ChaCha20Poly1305>::from_slice(&unhex("070000004041424344454647")).to_owned();
+        let ciphertext = ::aead::Aead::encrypt(
+            &cipher,
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad: &aad,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            &ciphertext[plaintext.len()..],
+            &unhex("1ae10b594f09e26a7e902ecbd0600691")[..]
+        );
+        let decrypted = ::aead::Aead::decrypt(
+            &cipher,
+            &nonce,
+            Payload {
+                msg: &ciphertext,
+                aad: &aad,
+            },
+        )
+        .unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn aes_gcm_vector() {
+        // Test Case 2 from the original GCM specification
+        let cipher = <Aes128Gcm as KeyInit>::new(&Key::<Aes128Gcm>::default());
+        let nonce = Nonce::<Aes128Gcm>::default();
+        let mut buffer = [0u8; 16];
+        let tag = cipher
+            .encrypt_in_place_detached(&nonce, b"", &mut buffer)
+            .unwrap();
+        assert_eq!(buffer, &unhex("0388dace60b6a392f328c2b971b2fe78")[..]);
+        assert_eq!(&tag[..], &unhex("ab6e47d42cec13bdf53a67b21257bddf")[..]);
+    }
+
+    #[test]
+    fn round_trip() {
+        fn check<A: KeyInit + AeadInPlace>() {
+            let cipher = A::new(&Key::<A>::from_exact_iter((0..).take(A::key_size())).unwrap());
+            let nonce = Nonce::<A>::default();
+
+            let mut buffer = b"hello".to_vec();
+            cipher
+                .encrypt_in_place(&nonce, b"aad", &mut buffer)
+                .unwrap();
+            assert_eq!(buffer.len(), 5 + 16);
+
+            let mut wrong = buffer.clone();
+            assert!(cipher.decrypt_in_place(&nonce, b"add", &mut wrong).is_err());
+
+            cipher
+                .decrypt_in_place(&nonce, b"aad", &mut buffer)
+                .unwrap();
+            assert_eq!(buffer, b"hello");
+
+            assert!(A::new_from_slice(&[0u8; 15]).is_err());
+        }
+
+        check::<Aes128Gcm>();
+        check::<Aes256Gcm>();
+        check::<ChaCha20Poly1305>();
+        check::<XChaCha20Poly1305>();
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,105 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! `digest::Digest`, for the SHA-2 hash contexts.
+//!
+//! `Digest` is provided by the `digest` crate's blanket implementation
+//! over `Default`, `Update`, `FixedOutput` and `HashMarker`.  The block
+//! size is also given, so these may be used with the `hmac` crate.
+
+use ::digest::consts::{U32, U48, U64, U128};
+use ::digest::crypto_common::BlockSizeUser;
+use ::digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+use crate::mid::sha2::{Sha256Context, Sha384Context, Sha512Context};
+
+macro_rules! rustcrypto_digest {
+    ($name:ident, $output_size:ty, $block_size:ty) => {
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $output_size;
+        }
+
+        impl BlockSizeUser for $name {
+            type BlockSize = $block_size;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                $name::update(self, data);
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.finish());
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                *self = Self::new();
+            }
+        }
+
+        impl FixedOutputReset for $name {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                let ctx = core::mem::take(self);
+                ctx.finalize_into(out);
+            }
+        }
+    };
+}
+
+rustcrypto_digest!(Sha256Context, U32, U64);
+rustcrypto_digest!(Sha384Context, U48, U128);
+rustcrypto_digest!(Sha512Context, U64, U128);
+
+#[cfg(test)]
+mod tests {
+    use ::digest::Digest;
+
+    use super::*;
+
+    #[test]
+    fn known_answers() {
+        fn check<D: Digest + FixedOutputReset>(expected: &str) {
+            assert_eq!(hex(&D::digest(b"abc")), expected);
+
+            let mut d = D::new();
+            Digest::update(&mut d, b"a");
+            Digest::update(&mut d, b"bc");
+            assert_eq!(hex(&d.finalize_reset()), expected);
+            Digest::update(&mut d, b"abc");
+            assert_eq!(hex(&d.finalize()), expected);
+
+            let mut d = D::new_with_prefix(b"xyz");
+            Digest::reset(&mut d);
+            Digest::update(&mut d, b"abc");
+            assert_eq!(hex(&d.finalize()), expected);
+        }
+
+        check::<Sha256Context>("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        check::<Sha384Context>(
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+             8086072ba1e7cc2358baeca134c825a7",
+        );
+        check::<Sha512Context>(
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        );
+        assert_eq!(<Sha384Context as Digest>::output_size(), 48);
+        assert_eq!(Sha512Context::block_size(), 128);
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
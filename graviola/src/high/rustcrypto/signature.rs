@@ -0,0 +1,160 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! `signature::Signer` and `signature::Verifier`.
+//!
+//! These are implemented for the same types, and with the same algorithms,
+//! as this crate's own [`Signer`] and [`Verifier`].  Signatures are
+//! [`SignatureBuf`]s, which implement `signature::SignatureEncoding`.
+
+use ::signature::SignatureEncoding;
+
+use crate::high::curve::{P256, P384};
+use crate::high::signing::{
+    ECDSA_P256_MAX_SIG_LEN, ECDSA_P384_MAX_SIG_LEN, RsaPkcs1Sha256, RsaPkcs1Sha384, RsaPkcs1Sha512,
+    RsaPssSha256, RsaPssSha384, RsaPssSha512, SignatureBuf, Signer, Verifier,
+};
+use crate::high::{ecdsa, rsa};
+use crate::mid::ed25519;
+use crate::mid::rsa_pub::MAX_PUBLIC_MODULUS_BYTES;
+
+impl<const N: usize> SignatureEncoding for SignatureBuf<N> {
+    type Repr = Self;
+}
+
+macro_rules! rustcrypto_signature {
+    ($signer:ty, $verifier:ty, $len:expr) => {
+        impl ::signature::Signer<SignatureBuf<{ $len }>> for $signer {
+            fn try_sign(&self, msg: &[u8]) -> Result<SignatureBuf<{ $len }>, ::signature::Error> {
+                Signer::sign(self, msg)
+                    .and_then(|sig| SignatureBuf::try_from(sig.as_ref()))
+                    .map_err(|_| ::signature::Error::new())
+            }
+        }
+
+        impl ::signature::Verifier<SignatureBuf<{ $len }>> for $verifier {
+            fn verify(
+                &self,
+                msg: &[u8],
+                signature: &SignatureBuf<{ $len }>,
+            ) -> Result<(), ::signature::Error> {
+                Verifier::verify(self, msg, signature.as_ref())
+                    .map_err(|_| ::signature::Error::new())
+            }
+        }
+    };
+}
+
+rustcrypto_signature!(
+    ed25519::SigningKey,
+    ed25519::VerifyingKey,
+    ed25519::SIGNATURE_LEN
+);
+rustcrypto_signature!(
+    ecdsa::SigningKey<P256>,
+    ecdsa::VerifyingKey<P256>,
+    ECDSA_P256_MAX_SIG_LEN
+);
+rustcrypto_signature!(
+    ecdsa::SigningKey<P384>,
+    ecdsa::VerifyingKey<P384>,
+    ECDSA_P384_MAX_SIG_LEN
+);
+rustcrypto_signature!(
+    RsaPkcs1Sha256<rsa::SigningKey>,
+    RsaPkcs1Sha256<rsa::VerifyingKey>,
+    MAX_PUBLIC_MODULUS_BYTES
+);
+rustcrypto_signature!(
+    RsaPkcs1Sha384<rsa::SigningKey>,
+    RsaPkcs1Sha384<rsa::VerifyingKey>,
+    MAX_PUBLIC_MODULUS_BYTES
+);
+rustcrypto_signature!(
+    RsaPkcs1Sha512<rsa::SigningKey>,
+    RsaPkcs1Sha512<rsa::VerifyingKey>,
+    MAX_PUBLIC_MODULUS_BYTES
+);
+rustcrypto_signature!(
+    RsaPssSha256<rsa::SigningKey>,
+    RsaPssSha256<rsa::VerifyingKey>,
+    MAX_PUBLIC_MODULUS_BYTES
+);
+rustcrypto_signature!(
+    RsaPssSha384<rsa::SigningKey>,
+    RsaPssSha384<rsa::VerifyingKey>,
+    MAX_PUBLIC_MODULUS_BYTES
+);
+rustcrypto_signature!(
+    RsaPssSha512<rsa::SigningKey>,
+    RsaPssSha512<rsa::VerifyingKey>,
+    MAX_PUBLIC_MODULUS_BYTES
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::curve::{Curve, PrivateKey};
+    use crate::mid::rng::SystemRandom;
+
+    fn check<const N: usize, S, V>(signer: &S, verifier: &V)
+    where
+        S: ::signature::Signer<SignatureBuf<N>>,
+        V: ::signature::Verifier<SignatureBuf<N>>,
+    {
+        let signature = signer.try_sign(b"hello world").unwrap();
+        verifier.verify(b"hello world", &signature).unwrap();
+        assert!(verifier.verify(b"goodbye world", &signature).is_err());
+
+        let mut corrupt = signature.to_bytes().as_ref().to_vec();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 1;
+        let corrupt = SignatureBuf::try_from(&corrupt[..]).unwrap();
+        assert!(verifier.verify(b"hello world", &corrupt).is_err());
+        assert_eq!(corrupt.encoded_len(), signature.encoded_len());
+    }
+
+    #[test]
+    fn ed25519_keys() {
+        let signer = ed25519::SigningKey::new_random().unwrap();
+        check::<{ ed25519::SIGNATURE_LEN }, _, _>(&signer, &signer.verifying_key());
+    }
+
+    #[test]
+    fn ecdsa_keys() {
+        fn keys<C: Curve>() -> (ecdsa::SigningKey<C>, ecdsa::VerifyingKey<C>) {
+            let private_key = C::generate_random_key(&mut SystemRandom).unwrap();
+            let mut public_key = [0u8; 128];
+            let public_key = private_key
+                .public_key_encode_uncompressed(&mut public_key)
+                .unwrap();
+            let verifying_key =
+                ecdsa::VerifyingKey::<C>::from_x962_uncompressed(public_key).unwrap();
+            (ecdsa::SigningKey { private_key }, verifying_key)
+        }
+
+        let (signer, verifier) = keys::<P256>();
+        check::<ECDSA_P256_MAX_SIG_LEN, _, _>(&signer, &verifier);
+        let (signer, verifier) = keys::<P384>();
+        check::<ECDSA_P384_MAX_SIG_LEN, _, _>(&signer, &verifier);
+    }
+
+    #[test]
+    fn rsa_keys() {
+        let key =
+            || rsa::SigningKey::from_pkcs8_der(include_bytes!("../rsa/rsa2048.pkcs8.der")).unwrap();
+        let public = || key().public_key();
+
+        macro_rules! check_rsa {
+            ($scheme:ident) => {
+                check::<MAX_PUBLIC_MODULUS_BYTES, _, _>(&$scheme(key()), &$scheme(public()));
+            };
+        }
+        check_rsa!(RsaPkcs1Sha256);
+        check_rsa!(RsaPkcs1Sha384);
+        check_rsa!(RsaPkcs1Sha512);
+        check_rsa!(RsaPssSha256);
+        check_rsa!(RsaPssSha384);
+        check_rsa!(RsaPssSha512);
+    }
+}
@@ -0,0 +1,224 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::asn1::{self, Type, pkix};
+use super::curve::{
+    Curve, MAX_SCALAR_LEN, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, P256, P384, PrivateKey,
+};
+use super::pem;
+use crate::error::{Error, KeyFormatError};
+use crate::low::{Entry, zeroise};
+use crate::mid::{p256, p384};
+
+/// Decodes a SEC.1 `ECPrivateKey` on curve `C`.
+///
+/// This is defined in [RFC5915](https://datatracker.ietf.org/doc/html/rfc5915#section-3).
+/// The parameters, if present, must name curve `C`.  The public key,
+/// if present, must match the private key.
+pub(crate) fn decode_sec1<C: Curve>(bytes: &[u8]) -> Result<C::PrivateKey, Error> {
+    let ecpk = pkix::EcPrivateKey::from_bytes(bytes).map_err(Error::Asn1Error)?;
+
+    // nb. ecpk.version has one variant, so if it decoded property it is guaranteed
+    // to be EcPrivateKeyVer::ecPrivkeyVer1
+
+    match ecpk.parameters.inner() {
+        Some(x) if x == &C::oid() => {}
+        None => {}
+        _ => return Err(KeyFormatError::MismatchedSec1Curve.into()),
+    }
+
+    let private_key = C::PrivateKey::from_bytes(ecpk.privateKey.into_octets())?;
+
+    if let Some(expected_public_key) = ecpk.publicKey.inner() {
+        let mut encoded_public_key_buf = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+        let encoded_public_key =
+            private_key.public_key_encode_uncompressed(&mut encoded_public_key_buf)?;
+
+        if encoded_public_key != expected_public_key.as_octets() {
+            return Err(KeyFormatError::MismatchedSec1PublicKey.into());
+        }
+    }
+
+    Ok(private_key)
+}
+
+/// Encodes a private key on curve `C` as a SEC.1 `ECPrivateKey`.
+///
+/// The public key is always included.  `parameters` names the curve,
+/// and is omitted inside PKCS#8 (where the `privateKeyAlgorithm`
+/// does the same job).
+pub(crate) fn encode_sec1<'a, C: Curve>(
+    private_key: &C::PrivateKey,
+    parameters: Option<asn1::ObjectId>,
+    output: &'a mut [u8],
+) -> Result<&'a [u8], Error> {
+    let mut encoded_private_key_buf = [0u8; MAX_SCALAR_LEN];
+    let encoded_private_key = private_key.encode(&mut encoded_private_key_buf)?;
+
+    let mut encoded_public_key_buf = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    let encoded_public_key =
+        private_key.public_key_encode_uncompressed(&mut encoded_public_key_buf)?;
+
+    let ecpk = pkix::EcPrivateKey {
+        version: pkix::EcPrivateKeyVer::ecPrivkeyVer1,
+        privateKey: asn1::OctetString::new(encoded_private_key),
+        parameters: match parameters {
+            Some(x) => x.into(),
+            None => asn1::ContextConstructed::absent(),
+        },
+        publicKey: asn1::BitString::new(encoded_public_key).into(),
+    };
+
+    let rc = ecpk.encode(&mut asn1::Encoder::new(output));
+    zeroise(&mut encoded_private_key_buf);
+    let used = rc.map_err(Error::Asn1Error)?;
+
+    output.get(..used).ok_or(Error::WrongLength)
+}
+
+/// Helper for decoding PEM-armoured SEC.1 key encodings.
+///
+/// The first `EC PRIVATE KEY` document is given to `decode_der`; other
+/// documents (such as the `EC PARAMETERS` that OpenSSL may emit) are
+/// skipped.
+pub(crate) fn decode_sec1_pem<T>(
+    pem: &str,
+    decode_der: impl FnOnce(&[u8]) -> Result<T, Error>,
+) -> Result<T, Error> {
+    pem::decode_first(pem, &[pem::EC_PRIVATE_KEY], |_, der| decode_der(der))
+}
+
+/// Helper for producing PEM-armoured SEC.1 key encodings.
+///
+/// `encode_der` writes the DER encoding into the provided buffer,
+/// which is of `max_len` bytes.  The buffer is zeroed afterwards.
+pub(crate) fn encode_sec1_pem(
+    max_len: usize,
+    encode_der: impl FnOnce(&mut [u8]) -> Result<&[u8], Error>,
+) -> Result<String, Error> {
+    let mut buffer = vec![0u8; max_len];
+    let result = encode_der(&mut buffer).map(|der| pem::encode(pem::EC_PRIVATE_KEY, der));
+    zeroise(&mut buffer);
+    result
+}
+
+/// An over-estimate of the SEC.1 encoding length for any supported curve.
+pub(crate) const MAX_SEC1_LEN: usize = MAX_SCALAR_LEN + MAX_UNCOMPRESSED_PUBLIC_KEY_LEN + 32;
+
+macro_rules! ec_private_key {
+    ($module:ident, $curve:ident, $len:literal, $name:literal) => {
+        impl $module::StaticPrivateKey {
+            #[doc = concat!("Decodes a ", $name, " private key from SEC.1 `ECPrivateKey` DER format.")]
+            ///
+            /// This format is defined in
+            /// [RFC5915](https://datatracker.ietf.org/doc/html/rfc5915#section-3).
+            /// The parameters, if present, must name this curve, and the public
+            /// key, if present, must match.
+            pub fn from_sec1_der(bytes: &[u8]) -> Result<Self, Error> {
+                let _entry = Entry::new_secret();
+                decode_sec1::<$curve>(bytes)
+            }
+
+            #[doc = concat!("Encodes a ", $name, " private key to SEC.1 `ECPrivateKey` DER format.")]
+            ///
+            /// The parameters and public key are included.  The encoding is
+            /// written to the start of `output`, and the used span is returned.
+            #[doc = concat!("The encoding is ", $len, " bytes long.")]
+            pub fn to_sec1_der<'a>(&self, output: &'a mut [u8]) -> Result<&'a [u8], Error> {
+                let _entry = Entry::new_secret();
+                encode_sec1::<$curve>(self, Some($curve::oid()), output)
+            }
+
+            #[doc = concat!("Decodes a ", $name, " private key from SEC.1 PEM format.")]
+            ///
+            /// The first `EC PRIVATE KEY` document is used; see [`Self::from_sec1_der()`].
+            pub fn from_sec1_pem(pem: &str) -> Result<Self, Error> {
+                decode_sec1_pem(pem, Self::from_sec1_der)
+            }
+
+            #[doc = concat!("Encodes a ", $name, " private key to SEC.1 PEM format, as an `EC PRIVATE KEY` document.")]
+            pub fn to_sec1_pem(&self) -> Result<String, Error> {
+                encode_sec1_pem($len, |buf| self.to_sec1_der(buf))
+            }
+        }
+    };
+}
+
+ec_private_key!(p256, P256, 121, "P-256");
+ec_private_key!(p384, P384, 167, "P-384");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p256_round_trip() {
+        let der = include_bytes!("ecdsa/secp256r1.der");
+        let key = p256::StaticPrivateKey::from_sec1_der(der).unwrap();
+        let mut buf = [0u8; 121];
+        assert_eq!(key.to_sec1_der(&mut buf).unwrap(), der);
+
+        let pem = key.to_sec1_pem().unwrap();
+        assert_eq!(pem, pem::encode(pem::EC_PRIVATE_KEY, der));
+        let key = p256::StaticPrivateKey::from_sec1_pem(&pem).unwrap();
+        assert_eq!(key.to_sec1_der(&mut buf).unwrap(), der);
+
+        assert_eq!(
+            key.to_sec1_der(&mut [0u8; 120]).err(),
+            Some(Error::Asn1Error(asn1::Error::UnexpectedEof))
+        );
+    }
+
+    #[test]
+    fn p384_round_trip() {
+        let der = include_bytes!("ecdsa/secp384r1.der");
+        let key = p384::StaticPrivateKey::from_sec1_der(der).unwrap();
+        let mut buf = [0u8; 167];
+        assert_eq!(key.to_sec1_der(&mut buf).unwrap(), der);
+
+        let key = p384::StaticPrivateKey::from_sec1_pem(&key.to_sec1_pem().unwrap()).unwrap();
+        assert_eq!(key.to_sec1_der(&mut buf).unwrap(), der);
+    }
+
+    #[test]
+    fn openssl_pem() {
+        // `openssl ecparam -genkey` emits `EC PARAMETERS` first
+        let pem = include_str!("ecdsa/secp256r1.ecparam.pem");
+        let key = p256::StaticPrivateKey::from_sec1_pem(pem).unwrap();
+        let mut buf = [0u8; 121];
+        assert_eq!(
+            key.to_sec1_pem().unwrap(),
+            pem::encode(pem::EC_PRIVATE_KEY, key.to_sec1_der(&mut buf).unwrap())
+        );
+
+        assert_eq!(
+            p256::StaticPrivateKey::from_sec1_pem(&pem::encode(
+                pem::PRIVATE_KEY,
+                include_bytes!("ecdsa/secp256r1.pkcs8.der")
+            ))
+            .err(),
+            Some(Error::KeyFormatError(KeyFormatError::MismatchedPemLabel))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            p256::StaticPrivateKey::from_sec1_der(include_bytes!("ecdsa/secp384r1.der")).err(),
+            Some(Error::KeyFormatError(KeyFormatError::MismatchedSec1Curve))
+        );
+        assert_eq!(
+            p384::StaticPrivateKey::from_sec1_der(include_bytes!("ecdsa/secp256r1.der")).err(),
+            Some(Error::KeyFormatError(KeyFormatError::MismatchedSec1Curve))
+        );
+        assert_eq!(
+            p256::StaticPrivateKey::from_sec1_der(include_bytes!(
+                "ecdsa/secp256r1.wrong-public-key.der"
+            ))
+            .err(),
+            Some(Error::KeyFormatError(
+                KeyFormatError::MismatchedSec1PublicKey
+            ))
+        );
+    }
+}
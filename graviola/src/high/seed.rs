@@ -0,0 +1,218 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Deterministic derivation of private keys from a seed.
+//!
+//! See the public module documentation for the definition.
+
+use super::curve::P256;
+use super::ecdsa;
+use super::hash::Sha512;
+use super::hkdf;
+use crate::Error;
+use crate::low::{self, Entry};
+use crate::mid::mlkem::{ml_kem_512, ml_kem_768, ml_kem_1024};
+use crate::mid::{ed25519, p256, x25519};
+use private::Sealed;
+
+/// The shortest seed accepted, in bytes.
+pub const MIN_SEED_LEN: usize = 16;
+
+/// A private key type which can be derived from a seed.
+///
+/// See the [module documentation](crate::seed) for the derivation.
+pub trait DeriveFromSeed: Sized + Sealed {
+    /// Derive a key from `seed`, for use in `context`.
+    ///
+    /// `seed` must be at least [`MIN_SEED_LEN`] bytes, and should be
+    /// uniformly random: this is not a password-based derivation.
+    /// Fails with [`Error::WrongLength`] if `seed` is too short.
+    fn derive_from_seed(seed: &[u8], context: &[u8]) -> Result<Self, Error>;
+}
+
+impl DeriveFromSeed for x25519::StaticPrivateKey {
+    fn derive_from_seed(seed: &[u8], context: &[u8]) -> Result<Self, Error> {
+        let mut okm = [0u8; 32];
+        derive(seed, Self::LABEL, context, &mut okm)?;
+        let key = Self::from_array(&okm);
+        low::zeroise(&mut okm);
+        Ok(key)
+    }
+}
+
+impl DeriveFromSeed for ed25519::SigningKey {
+    fn derive_from_seed(seed: &[u8], context: &[u8]) -> Result<Self, Error> {
+        let mut okm = [0u8; 32];
+        derive(seed, Self::LABEL, context, &mut okm)?;
+        let key = Self::from_seed(&okm);
+        low::zeroise(&mut okm);
+        Ok(key)
+    }
+}
+
+impl DeriveFromSeed for p256::StaticPrivateKey {
+    fn derive_from_seed(seed: &[u8], context: &[u8]) -> Result<Self, Error> {
+        p256_scalar(seed, Self::LABEL, context)
+    }
+}
+
+impl DeriveFromSeed for ecdsa::SigningKey<P256> {
+    fn derive_from_seed(seed: &[u8], context: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            private_key: p256_scalar(seed, Self::LABEL, context)?,
+        })
+    }
+}
+
+/// Reducing 48 bytes mod `n` has a bias of around 2^-128.
+///
+/// The result is zero with negligible probability, in which case
+/// `from_bytes` fails.
+fn p256_scalar(seed: &[u8], label: &[u8], context: &[u8]) -> Result<p256::StaticPrivateKey, Error> {
+    let mut okm = [0u8; 48];
+    derive(seed, label, context, &mut okm)?;
+    let mut scalar = p256::Scalar::from_wide_bytes(&okm).as_bytes();
+    let key = p256::StaticPrivateKey::from_bytes(&scalar);
+    low::zeroise(&mut okm);
+    low::zeroise(&mut scalar);
+    key
+}
+
+macro_rules! ml_kem_derive {
+    ($module:ident) => {
+        impl DeriveFromSeed for $module::DecapsulationKey {
+            fn derive_from_seed(seed: &[u8], context: &[u8]) -> Result<Self, Error> {
+                let mut okm = [0u8; 64];
+                derive(seed, Self::LABEL, context, &mut okm)?;
+                let key = Self::from_seed(&okm);
+                low::zeroise(&mut okm);
+                Ok(key)
+            }
+        }
+    };
+}
+
+ml_kem_derive!(ml_kem_512);
+ml_kem_derive!(ml_kem_768);
+ml_kem_derive!(ml_kem_1024);
+
+fn derive(seed: &[u8], label: &[u8], context: &[u8], okm: &mut [u8]) -> Result<(), Error> {
+    let _entry = Entry::new_secret();
+    if seed.len() < MIN_SEED_LEN {
+        return Err(Error::WrongLength);
+    }
+
+    hkdf::extract::<Sha512>(SALT, &[seed]).expand(&[label, &[0x00], context], okm)
+}
+
+const SALT: &[u8] = b"graviola seed derivation v1";
+
+mod private {
+    pub trait Sealed {
+        const LABEL: &'static [u8];
+    }
+
+    impl Sealed for super::x25519::StaticPrivateKey {
+        const LABEL: &'static [u8] = b"x25519";
+    }
+
+    impl Sealed for super::ed25519::SigningKey {
+        const LABEL: &'static [u8] = b"ed25519";
+    }
+
+    impl Sealed for super::p256::StaticPrivateKey {
+        const LABEL: &'static [u8] = b"ecdh-p256";
+    }
+
+    impl Sealed for super::ecdsa::SigningKey<super::P256> {
+        const LABEL: &'static [u8] = b"ecdsa-p256";
+    }
+
+    impl Sealed for super::ml_kem_512::DecapsulationKey {
+        const LABEL: &'static [u8] = b"ml-kem-512";
+    }
+
+    impl Sealed for super::ml_kem_768::DecapsulationKey {
+        const LABEL: &'static [u8] = b"ml-kem-768";
+    }
+
+    impl Sealed for super::ml_kem_1024::DecapsulationKey {
+        const LABEL: &'static [u8] = b"ml-kem-1024";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn known_answers() {
+        // the expected values are independently computed with
+        // the Python `cryptography` HKDF implementation
+        let x = x25519::StaticPrivateKey::derive_from_seed(SEED, b"device 1").unwrap();
+        assert_eq!(hex(&x.as_bytes()), X25519_EXPECTED);
+
+        let ed = ed25519::SigningKey::derive_from_seed(SEED, b"device 1").unwrap();
+        assert_eq!(hex(ed.verifying_key().as_bytes()), ED25519_EXPECTED);
+
+        let p = p256::StaticPrivateKey::derive_from_seed(SEED, b"device 1").unwrap();
+        assert_eq!(hex(&p.as_bytes()), ECDH_P256_EXPECTED);
+
+        let e = ecdsa::SigningKey::<P256>::derive_from_seed(SEED, b"device 1").unwrap();
+        assert_eq!(hex(&e.private_key.as_bytes()), ECDSA_P256_EXPECTED);
+    }
+
+    #[test]
+    fn reproducible() {
+        let a = ml_kem_768::DecapsulationKey::derive_from_seed(SEED, b"ctx").unwrap();
+        let b = ml_kem_768::DecapsulationKey::derive_from_seed(SEED, b"ctx").unwrap();
+        assert_eq!(a.to_bytes(), b.to_bytes());
+
+        let c = ml_kem_768::DecapsulationKey::derive_from_seed(SEED, b"other ctx").unwrap();
+        assert_ne!(a.to_bytes(), c.to_bytes());
+
+        let a = ml_kem_512::DecapsulationKey::derive_from_seed(SEED, b"ctx").unwrap();
+        let b = ml_kem_512::DecapsulationKey::derive_from_seed(SEED, b"ctx").unwrap();
+        assert_eq!(a.to_bytes(), b.to_bytes());
+
+        let a = ml_kem_1024::DecapsulationKey::derive_from_seed(SEED, b"ctx").unwrap();
+        let b = ml_kem_1024::DecapsulationKey::derive_from_seed(SEED, b"ctx").unwrap();
+        assert_eq!(a.to_bytes(), b.to_bytes());
+    }
+
+    #[test]
+    fn labels_separate_algorithms() {
+        let ecdh = p256::StaticPrivateKey::derive_from_seed(SEED, b"").unwrap();
+        let ecdsa = ecdsa::SigningKey::<P256>::derive_from_seed(SEED, b"").unwrap();
+        assert_ne!(ecdh.as_bytes(), ecdsa.private_key.as_bytes());
+
+        // the separator means the label cannot run into the context
+        let a = x25519::StaticPrivateKey::derive_from_seed(SEED, b"a").unwrap();
+        let b = x25519::StaticPrivateKey::derive_from_seed(SEED, b"").unwrap();
+        assert_ne!(a.as_bytes(), b.as_bytes());
+    }
+
+    #[test]
+    fn short_seed() {
+        assert_eq!(
+            x25519::StaticPrivateKey::derive_from_seed(&SEED[..MIN_SEED_LEN - 1], b"").err(),
+            Some(Error::WrongLength)
+        );
+        assert!(x25519::StaticPrivateKey::derive_from_seed(&SEED[..MIN_SEED_LEN], b"").is_ok());
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    const X25519_EXPECTED: &str =
+        "3767b8c40a7640fe946b037a311627092bad4538fd3dc8d9198740e58dea7a3b";
+    const ED25519_EXPECTED: &str =
+        "441d74ab27564996495c66c0f34fa3ce9bf8af77d73e04ab2f9ce430b126a8a4";
+    const ECDH_P256_EXPECTED: &str =
+        "502a76ffd881d58f15ee1bbec2fc98b967c681eaadc58af9750f7136c73ea6e3";
+    const ECDSA_P256_EXPECTED: &str =
+        "af99f7b3dad25b878cdda2dfd78f1ba13cba15ac7a971e3e7e5721317e746992";
+}
@@ -0,0 +1,298 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! `serde` support for public keys, signatures and fingerprints.
+//!
+//! Each type is serialized as its usual byte encoding: raw bytes for
+//! Ed25519, X25519, ML-DSA and ML-KEM keys, an uncompressed X9.62 point for
+//! elliptic curve keys, and PKCS#1 `RSAPublicKey` DER for RSA keys.
+//! Human-readable formats get those bytes in standard, padded, base64;
+//! other formats get them as a byte string.
+//!
+//! Deserialization fully validates the key, as the type's own
+//! constructor would.  Private keys are deliberately not supported.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserializer, SeqAccess, Visitor};
+use ::serde::ser::{self, Serializer};
+use ::serde::{Deserialize, Serialize};
+
+use super::base64;
+use super::curve::{Curve, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, PublicKey};
+use super::ecdsa;
+use super::fingerprint::SpkiFingerprint;
+use super::rsa;
+use super::signing::SignatureBuf;
+use crate::Error;
+use crate::mid::mldsa::{ml_dsa_44, ml_dsa_65, ml_dsa_87};
+use crate::mid::mlkem::{ml_kem_512, ml_kem_768, ml_kem_1024};
+use crate::mid::{ed25519, p256, p384, x25519};
+
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    match serializer.is_human_readable() {
+        true => serializer.serialize_str(&base64::encode(bytes)),
+        false => serializer.serialize_bytes(bytes),
+    }
+}
+
+fn deserialize_bytes<'de, D, T>(
+    deserializer: D,
+    decode: fn(&[u8]) -> Result<T, Error>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = BytesVisitor {
+        decode,
+        _output: PhantomData,
+    };
+    match deserializer.is_human_readable() {
+        true => deserializer.deserialize_str(visitor),
+        false => deserializer.deserialize_bytes(visitor),
+    }
+}
+
+struct BytesVisitor<T> {
+    decode: fn(&[u8]) -> Result<T, Error>,
+    _output: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a base64 string or byte string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        let bytes = base64::decode(v).ok_or_else(|| E::custom("invalid base64"))?;
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        (self.decode)(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+macro_rules! serde_bytes {
+    ($ty:ty, $encode:expr, $decode:expr) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_bytes(AsRef::<[u8]>::as_ref(&$encode(self)), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_bytes(deserializer, $decode)
+            }
+        }
+    };
+}
+
+serde_bytes!(
+    ed25519::VerifyingKey,
+    ed25519::VerifyingKey::as_bytes,
+    ed25519::VerifyingKey::from_bytes
+);
+serde_bytes!(
+    x25519::PublicKey,
+    x25519::PublicKey::as_bytes,
+    x25519::PublicKey::try_from_slice
+);
+serde_bytes!(
+    p256::PublicKey,
+    p256::PublicKey::as_bytes_uncompressed,
+    p256::PublicKey::from_x962_uncompressed
+);
+serde_bytes!(
+    p384::PublicKey,
+    p384::PublicKey::as_bytes_uncompressed,
+    p384::PublicKey::from_x962_uncompressed
+);
+serde_bytes!(
+    SpkiFingerprint,
+    SpkiFingerprint::as_bytes,
+    SpkiFingerprint::from_bytes
+);
+
+macro_rules! serde_public_key {
+    ($($module:ident::$ty:ident),+) => {
+        $(
+            serde_bytes!($module::$ty, $module::$ty::as_bytes, $module::$ty::from_bytes);
+        )+
+    };
+}
+
+serde_public_key!(
+    ml_dsa_44::VerifyingKey,
+    ml_dsa_65::VerifyingKey,
+    ml_dsa_87::VerifyingKey,
+    ml_kem_512::EncapsulationKey,
+    ml_kem_768::EncapsulationKey,
+    ml_kem_1024::EncapsulationKey
+);
+
+impl<C: Curve> Serialize for ecdsa::VerifyingKey<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+        let encoded = self
+            .public_key
+            .encode_uncompressed(&mut buf)
+            .map_err(ser::Error::custom)?;
+        serialize_bytes(encoded, serializer)
+    }
+}
+
+impl<'de, C: Curve> Deserialize<'de> for ecdsa::VerifyingKey<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer, Self::from_x962_uncompressed)
+    }
+}
+
+impl Serialize for rsa::VerifyingKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = [0u8; Self::MAX_PUBLIC_PKCS1_LEN];
+        let encoded = self.to_pkcs1_der(&mut buf).map_err(ser::Error::custom)?;
+        serialize_bytes(encoded, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for rsa::VerifyingKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer, Self::from_pkcs1_der)
+    }
+}
+
+impl<const N: usize> Serialize for SignatureBuf<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_ref(), serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for SignatureBuf<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer, |bytes| Self::try_from(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::de::value::{BytesDeserializer, Error as ValueError, SeqDeserializer};
+
+    use super::*;
+    use crate::high::curve::{P256, P384};
+
+    fn round_trip<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> String {
+        let json = serde_json::to_string(value).unwrap();
+        let decoded: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+        json
+    }
+
+    /// Decodes `bytes` as given by a binary format.
+    fn from_bytes<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Result<T, ValueError> {
+        T::deserialize(BytesDeserializer::<ValueError>::new(bytes))
+    }
+
+    #[test]
+    fn ed25519() {
+        let key = ed25519::SigningKey::from_seed(&[0x42; 32]).verifying_key();
+        let json = round_trip(&key);
+        assert_eq!(json, format!("\"{}\"", base64::encode(key.as_bytes())));
+
+        let decoded: ed25519::VerifyingKey = from_bytes(key.as_bytes()).unwrap();
+        assert_eq!(decoded.as_bytes(), key.as_bytes());
+        assert!(from_bytes::<ed25519::VerifyingKey>(&key.as_bytes()[1..]).is_err());
+
+        // some formats give a sequence of bytes instead
+        let seq = SeqDeserializer::<_, ValueError>::new(key.as_bytes().iter().copied());
+        let decoded = ed25519::VerifyingKey::deserialize(seq).unwrap();
+        assert_eq!(decoded.as_bytes(), key.as_bytes());
+    }
+
+    #[test]
+    fn key_agreement() {
+        let x25519 = x25519::StaticPrivateKey::new_random().unwrap().public_key();
+        round_trip(&x25519);
+
+        let p256 = p256::StaticPrivateKey::new_random().unwrap();
+        let p256 =
+            p256::PublicKey::from_x962_uncompressed(&p256.public_key_uncompressed()).unwrap();
+        round_trip(&p256);
+
+        let p384 = p384::StaticPrivateKey::new_random().unwrap();
+        let p384 =
+            p384::PublicKey::from_x962_uncompressed(&p384.public_key_uncompressed()).unwrap();
+        round_trip(&p384);
+    }
+
+    #[test]
+    fn ecdsa() {
+        let spki = include_bytes!("spki/secp256r1.spki.der");
+        let key = ecdsa::VerifyingKey::<P256>::from_spki_der(spki).unwrap();
+        round_trip(&key);
+
+        // the wrong curve
+        let json = serde_json::to_string(&key).unwrap();
+        assert!(serde_json::from_str::<ecdsa::VerifyingKey<P384>>(&json).is_err());
+
+        // not on the curve
+        let mut point = [0u8; 65];
+        key.public_key.encode_uncompressed(&mut point).unwrap();
+        point[64] ^= 1;
+        assert!(from_bytes::<ecdsa::VerifyingKey<P256>>(&point).is_err());
+    }
+
+    #[test]
+    fn rsa() {
+        let der = include_bytes!("rsa/rsa2048.der");
+        let key = rsa::SigningKey::from_pkcs1_der(der).unwrap().public_key();
+        let json = round_trip(&key);
+
+        let mut buf = [0u8; rsa::VerifyingKey::MAX_PUBLIC_PKCS1_LEN];
+        let pkcs1 = key.to_pkcs1_der(&mut buf).unwrap();
+        assert_eq!(json, format!("\"{}\"", base64::encode(pkcs1)));
+        from_bytes::<rsa::VerifyingKey>(pkcs1).unwrap();
+        assert!(from_bytes::<rsa::VerifyingKey>(&pkcs1[1..]).is_err());
+    }
+
+    #[test]
+    fn post_quantum() {
+        let key = ml_dsa_44::SigningKey::new_random().unwrap().verifying_key();
+        round_trip(&key);
+        assert!(from_bytes::<ml_dsa_65::VerifyingKey>(key.as_bytes()).is_err());
+
+        let key = ml_kem_768::DecapsulationKey::new_random()
+            .unwrap()
+            .encapsulation_key();
+        round_trip(&key);
+        assert!(from_bytes::<ml_kem_512::EncapsulationKey>(key.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn signatures_and_fingerprints() {
+        let signature = SignatureBuf::<8>::try_from(&b"abc"[..]).unwrap();
+        assert_eq!(round_trip(&signature), "\"YWJj\"");
+        assert!(serde_json::from_str::<SignatureBuf<2>>("\"YWJj\"").is_err());
+        assert!(serde_json::from_str::<SignatureBuf<8>>("\"YWJ\"").is_err());
+
+        let fingerprint =
+            SpkiFingerprint::of_spki_der(include_bytes!("spki/ed25519.spki.der")).unwrap();
+        assert_eq!(
+            round_trip(&fingerprint),
+            format!("\"{}\"", fingerprint.to_base64())
+        );
+        assert!(serde_json::from_str::<SpkiFingerprint>("1").is_err());
+    }
+}
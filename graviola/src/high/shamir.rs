@@ -0,0 +1,432 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Shamir secret sharing over GF(2^8).
+//!
+//! A secret is split into up to 255 shares, any `threshold` of which
+//! can be combined to recover it.  Fewer than `threshold` shares reveal
+//! nothing about the secret (other than its length).
+//!
+//! Each share carries an integrity tag.  This is keyed by a random key
+//! which is split along with the secret, so it too is only recovered from
+//! `threshold` shares.  After recombination every share's tag is checked,
+//! so corrupted or mismatched shares are detected rather than yielding a
+//! wrong secret.
+//!
+//! A share is encoded as `threshold || index || y || tag`, where `y` is
+//! the share of `secret || tag_key`.  It is [`SHARE_OVERHEAD`] bytes
+//! longer than the secret.
+//!
+//! ```
+//! use graviola::secret_sharing::shamir;
+//!
+//! let secret = b"a 32-byte key, to be backed up!!";
+//! let mut shares = [[0u8; 32 + shamir::SHARE_OVERHEAD]; 5];
+//! let mut outputs = shares.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+//! shamir::split(secret, 3, &mut outputs).unwrap();
+//!
+//! let mut recovered = [0u8; 32];
+//! shamir::combine(&[&shares[4], &shares[0], &shares[2]], &mut recovered).unwrap();
+//! assert_eq!(&recovered, secret);
+//! ```
+
+use super::hash::Sha256;
+use super::hmac::Hmac;
+use crate::Error;
+use crate::low::{self, Entry};
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+/// How much longer a share is than its secret.
+pub const SHARE_OVERHEAD: usize = 2 + TAG_KEY_LEN + TAG_LEN;
+
+/// Split `secret` into `shares.len()` shares, any `threshold` of which
+/// can be combined to recover it.
+///
+/// Each of `shares` must be `secret.len() + SHARE_OVERHEAD` bytes long.
+/// `threshold` must be at least 2, and at most the number of shares;
+/// there can be at most 255 shares.
+pub fn split(secret: &[u8], threshold: u8, shares: &mut [&mut [u8]]) -> Result<(), Error> {
+    split_with_rng(secret, threshold, shares, &mut SystemRandom)
+}
+
+/// Split `secret` into shares, using `rng` to generate the tag key and
+/// the polynomial coefficients.
+///
+/// Otherwise, as [`split()`].
+pub fn split_with_rng(
+    secret: &[u8],
+    threshold: u8,
+    shares: &mut [&mut [u8]],
+    rng: &mut dyn RandomSource,
+) -> Result<(), Error> {
+    let _entry = Entry::new_secret();
+    if threshold < 2 || usize::from(threshold) > shares.len() || shares.len() > MAX_SHARES {
+        return Err(Error::OutOfRange);
+    }
+    if secret.is_empty()
+        || shares
+            .iter()
+            .any(|s| s.len() != secret.len() + SHARE_OVERHEAD)
+    {
+        return Err(Error::WrongLength);
+    }
+
+    for (i, share) in shares.iter_mut().enumerate() {
+        share[0] = threshold;
+        share[1] = i as u8 + 1;
+    }
+
+    let key = TagKey::generate(rng)?;
+
+    // coefficients a_1 .. a_{threshold-1}, for each byte of `secret || key`
+    let mut coeffs = [0u8; MAX_SHARES - 1];
+    let coeffs = &mut coeffs[..usize::from(threshold) - 1];
+    for (k, byte) in secret.iter().chain(key.0.iter()).enumerate() {
+        if let Err(e) = rng.fill(coeffs) {
+            low::zeroise(coeffs);
+            return Err(e);
+        }
+        for share in shares.iter_mut() {
+            share[2 + k] = evaluate(*byte, coeffs, share[1]);
+        }
+    }
+    low::zeroise(coeffs);
+
+    for share in shares.iter_mut() {
+        let (body, tag) = share.split_at_mut(2 + secret.len() + TAG_KEY_LEN);
+        tag.copy_from_slice(&key.tag(body));
+    }
+    Ok(())
+}
+
+/// Recover a secret from `shares`, writing it into `secret`.
+///
+/// At least as many shares as the threshold must be supplied, and they
+/// must have distinct indices.  `secret` must be `SHARE_OVERHEAD` bytes
+/// shorter than each share.
+///
+/// Fails with [`Error::BadSignature`] if any share is corrupt, or if the
+/// shares do not belong together.
+pub fn combine(shares: &[&[u8]], secret: &mut [u8]) -> Result<(), Error> {
+    let _entry = Entry::new_secret();
+    let first = shares.first().ok_or(Error::OutOfRange)?;
+    if first.len() <= SHARE_OVERHEAD
+        || secret.len() != first.len() - SHARE_OVERHEAD
+        || shares.iter().any(|s| s.len() != first.len())
+    {
+        return Err(Error::WrongLength);
+    }
+
+    let threshold = first[0];
+    if shares.len() < usize::from(threshold) || shares.len() > MAX_SHARES {
+        return Err(Error::OutOfRange);
+    }
+
+    let mut indices = [0u8; MAX_SHARES];
+    let indices = &mut indices[..shares.len()];
+    for (i, share) in shares.iter().enumerate() {
+        // nb. a share with a differing threshold would fail its tag check
+        // anyway, but this gives a clearer error for the common mistake
+        if share[0] != threshold || share[1] == 0 || indices[..i].contains(&share[1]) {
+            return Err(Error::OutOfRange);
+        }
+        indices[i] = share[1];
+    }
+
+    // Lagrange basis polynomials, evaluated at zero
+    let mut basis = [0u8; MAX_SHARES];
+    let basis = &mut basis[..shares.len()];
+    for (i, x_i) in indices.iter().enumerate() {
+        basis[i] = 1;
+        for (j, x_j) in indices.iter().enumerate() {
+            if i != j {
+                basis[i] = mul(basis[i], mul(*x_j, inverse(x_j ^ x_i)));
+            }
+        }
+    }
+
+    let mut key = TagKey([0u8; TAG_KEY_LEN]);
+    for (k, out) in secret.iter_mut().chain(key.0.iter_mut()).enumerate() {
+        *out = shares
+            .iter()
+            .zip(basis.iter())
+            .fold(0, |acc, (share, b)| acc ^ mul(share[2 + k], *b));
+    }
+
+    let mut valid = true;
+    for share in shares {
+        let (body, tag) = share.split_at(2 + secret.len() + TAG_KEY_LEN);
+        valid &= low::ct_equal(&key.tag(body), tag);
+    }
+
+    match valid {
+        true => Ok(()),
+        false => {
+            low::zeroise(secret);
+            Err(Error::BadSignature)
+        }
+    }
+}
+
+/// The key for share integrity tags.
+///
+/// This is random, and split along with the secret.  It must not be
+/// derived from the secret: then a single share would be enough to
+/// check guesses of the secret against its tag.
+struct TagKey([u8; TAG_KEY_LEN]);
+
+impl TagKey {
+    fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let mut key = Self([0u8; TAG_KEY_LEN]);
+        rng.fill(&mut key.0)?;
+        Ok(key)
+    }
+
+    fn tag(&self, body: &[u8]) -> [u8; TAG_LEN] {
+        let mut hmac = Hmac::<Sha256>::new(self.0);
+        hmac.update(body);
+        let mut tag = [0u8; TAG_LEN];
+        tag.copy_from_slice(&hmac.finish().as_ref()[..TAG_LEN]);
+        tag
+    }
+}
+
+impl Drop for TagKey {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.0);
+    }
+}
+
+/// Evaluate the polynomial `constant + coeffs[0] * x + coeffs[1] * x^2 ...` at `x`.
+fn evaluate(constant: u8, coeffs: &[u8], x: u8) -> u8 {
+    let acc = coeffs
+        .iter()
+        .rev()
+        .fold(0, |acc, coeff| mul(acc, x) ^ coeff);
+    mul(acc, x) ^ constant
+}
+
+/// Multiplication in GF(2^8), with the AES polynomial.
+///
+/// This is constant-time.
+fn mul(mut a: u8, mut b: u8) -> u8 {
+    let mut r = 0;
+    for _ in 0..8 {
+        r ^= a & (b & 1).wrapping_neg();
+        let carry = (a >> 7).wrapping_neg();
+        a = (a << 1) ^ (0x1b & carry);
+        b >>= 1;
+    }
+    r
+}
+
+/// Inversion in GF(2^8), as `a^254`.
+fn inverse(a: u8) -> u8 {
+    let a2 = mul(a, a);
+    let a3 = mul(a2, a);
+    let a6 = mul(a3, a3);
+    let a12 = mul(a6, a6);
+    let a15 = mul(a12, a3);
+    let a30 = mul(a15, a15);
+    let a60 = mul(a30, a30);
+    let a63 = mul(a60, a3);
+    let a126 = mul(a63, a63);
+    let a127 = mul(a126, a);
+    mul(a127, a127)
+}
+
+const TAG_KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+const MAX_SHARES: usize = 255;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn field() {
+        // FIPS-197 section 4.2
+        assert_eq!(mul(0x57, 0x83), 0xc1);
+        assert_eq!(mul(0x57, 0x13), 0xfe);
+        for a in 1..=255u8 {
+            assert_eq!(mul(a, inverse(a)), 1);
+        }
+        assert_eq!(inverse(0), 0);
+    }
+
+    #[test]
+    fn known_answer() {
+        // from an independent implementation
+        let rng = (0x11..0x79).collect::<Vec<u8>>();
+        let mut shares = [[0u8; 4 + SHARE_OVERHEAD]; 3];
+        let mut outputs = shares.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        split_with_rng(
+            b"\x01\x02\x03\x04",
+            3,
+            &mut outputs,
+            &mut SliceRandomSource(&rng),
+        )
+        .unwrap();
+        assert_eq!(
+            hex(&shares[0]),
+            "03010205000b1215106b161114171a1d18031e191c2f2225201b262124272a2d28332e292c3f\
+             53d2f635a9ff4e7fead63eff1c2869ec"
+        );
+        assert_eq!(
+            hex(&shares[1]),
+            "0302abb4b18a8b949171849b9eadb8a7a2d9ecf3f6f5e0fffa01746b6e5d485752291c030625\
+             cc24afc429f3f2f94aa813f60fbf2aa7"
+        );
+        assert_eq!(
+            hex(&shares[2]),
+            "0303a8b3b2858893920e879c9da2bba0a1c6eff4f5fae3f8f93e776c6d524b5051361f04052a\
+             1917487ae78c726f6cff07e72e6644f5"
+        );
+    }
+
+    #[test]
+    fn one_share_reveals_nothing() {
+        // for any guessed secret there are coefficients which produce
+        // exactly the same first share (tag included), so a single share
+        // cannot be used to confirm a guess.
+        let secret = b"correct secret";
+        let guess = b"another secret";
+        let rng = (0..TAG_KEY_LEN + secret.len() + TAG_KEY_LEN)
+            .map(|i| (i * 37) as u8)
+            .collect::<Vec<_>>();
+
+        // share index 1 with threshold 2 is `value ^ coeff`
+        let mut guess_rng = rng.clone();
+        for (k, (s, g)) in secret.iter().zip(guess.iter()).enumerate() {
+            guess_rng[TAG_KEY_LEN + k] ^= s ^ g;
+        }
+
+        let mut shares = [[0u8; 14 + SHARE_OVERHEAD]; 2];
+        let mut outputs = shares.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        split_with_rng(secret, 2, &mut outputs, &mut SliceRandomSource(&rng)).unwrap();
+        let mut guessed = [[0u8; 14 + SHARE_OVERHEAD]; 2];
+        let mut outputs = guessed.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        split_with_rng(guess, 2, &mut outputs, &mut SliceRandomSource(&guess_rng)).unwrap();
+
+        assert_eq!(shares[0], guessed[0]);
+        assert_ne!(shares[1], guessed[1]);
+
+        let mut recovered = [0u8; 14];
+        combine(&[&shares[0], &shares[1]], &mut recovered).unwrap();
+        assert_eq!(&recovered, secret);
+        combine(&[&guessed[0], &guessed[1]], &mut recovered).unwrap();
+        assert_eq!(&recovered, guess);
+    }
+
+    #[test]
+    fn round_trip() {
+        let secret = (0..=255u8).collect::<Vec<_>>();
+        let mut shares = vec![vec![0u8; secret.len() + SHARE_OVERHEAD]; 7];
+        let mut outputs = shares.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        split(&secret, 4, &mut outputs).unwrap();
+
+        let mut recovered = vec![0u8; secret.len()];
+        for subset in [[0, 1, 2, 3], [6, 5, 4, 3], [1, 3, 5, 0], [2, 6, 0, 4]] {
+            let chosen = subset.map(|i| &shares[i][..]);
+            combine(&chosen, &mut recovered).unwrap();
+            assert_eq!(recovered, secret);
+        }
+
+        // more shares than needed is fine
+        let all = shares.iter().map(|s| &s[..]).collect::<Vec<_>>();
+        combine(&all, &mut recovered).unwrap();
+        assert_eq!(recovered, secret);
+
+        // too few
+        assert_eq!(combine(&all[..3], &mut recovered), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn maximum_shares() {
+        let mut shares = vec![[0u8; 1 + SHARE_OVERHEAD]; 255];
+        let mut outputs = shares.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        split(b"x", 255, &mut outputs).unwrap();
+        let all = shares.iter().map(|s| &s[..]).collect::<Vec<_>>();
+        let mut recovered = [0u8; 1];
+        combine(&all, &mut recovered).unwrap();
+        assert_eq!(&recovered, b"x");
+
+        let mut shares = vec![[0u8; 1 + SHARE_OVERHEAD]; 256];
+        let mut outputs = shares.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        assert_eq!(split(b"x", 2, &mut outputs), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn corrupt_shares() {
+        let mut shares = [[0u8; 16 + SHARE_OVERHEAD]; 3];
+        let mut outputs = shares.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        split(b"sixteen byte key", 2, &mut outputs).unwrap();
+
+        let mut recovered = [0xffu8; 16];
+        for position in [2, 10, 2 + 16, 2 + 16 + TAG_KEY_LEN] {
+            let mut bad = shares[1];
+            bad[position] ^= 1;
+            assert_eq!(
+                combine(&[&shares[0], &bad], &mut recovered),
+                Err(Error::BadSignature)
+            );
+            assert_eq!(recovered, [0u8; 16]);
+        }
+
+        // shares from a different split
+        let mut others = [[0u8; 16 + SHARE_OVERHEAD]; 2];
+        let mut outputs = others.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        split(b"sixteen byte key", 2, &mut outputs).unwrap();
+        assert_eq!(
+            combine(&[&shares[0], &others[1]], &mut recovered),
+            Err(Error::BadSignature)
+        );
+
+        // duplicate, zero or mismatched indices and thresholds
+        assert_eq!(
+            combine(&[&shares[0], &shares[0]], &mut recovered),
+            Err(Error::OutOfRange)
+        );
+        let mut bad = shares[1];
+        bad[1] = 0;
+        assert_eq!(
+            combine(&[&shares[0], &bad], &mut recovered),
+            Err(Error::OutOfRange)
+        );
+        let mut bad = shares[1];
+        bad[0] = 3;
+        assert_eq!(
+            combine(&[&shares[0], &bad, &shares[2]], &mut recovered),
+            Err(Error::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn bad_parameters() {
+        let mut shares = [[0u8; 4 + SHARE_OVERHEAD]; 3];
+        let mut outputs = shares.iter_mut().map(|s| &mut s[..]).collect::<Vec<_>>();
+        assert_eq!(split(b"abcd", 1, &mut outputs), Err(Error::OutOfRange));
+        assert_eq!(split(b"abcd", 4, &mut outputs), Err(Error::OutOfRange));
+        assert_eq!(split(b"abc", 2, &mut outputs), Err(Error::WrongLength));
+        assert_eq!(split(b"", 2, &mut outputs), Err(Error::WrongLength));
+
+        split(b"abcd", 2, &mut outputs).unwrap();
+        assert_eq!(combine(&[], &mut [0u8; 4]), Err(Error::OutOfRange));
+        assert_eq!(
+            combine(&[&shares[0], &shares[1]], &mut [0u8; 5]),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            combine(
+                &[&shares[0], &shares[1][..3 + SHARE_OVERHEAD]],
+                &mut [0u8; 4]
+            ),
+            Err(Error::WrongLength)
+        );
+    }
+}
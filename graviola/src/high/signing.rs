@@ -0,0 +1,290 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::curve::{P256, P384};
+use super::hash::{Sha256, Sha384};
+use super::{ecdsa, rsa};
+use crate::Error;
+use crate::mid::ed25519;
+use crate::mid::mldsa::{ml_dsa_44, ml_dsa_65, ml_dsa_87};
+use crate::mid::rsa_pub::MAX_PUBLIC_MODULUS_BYTES;
+
+/// A generic trait over signing (private) keys.
+///
+/// This exists so protocol code may be generic over the signature
+/// algorithm used.  The algorithm, including any hash function, is
+/// determined by the implementing type.
+pub trait Signer {
+    /// The signature type.
+    type Signature: AsRef<[u8]>;
+
+    /// Sign `message`.
+    fn sign(&self, message: &[u8]) -> Result<Self::Signature, Error>;
+}
+
+/// A generic trait over verifying (public) keys.
+///
+/// See [`Signer`].
+pub trait Verifier {
+    /// Verify `signature` over `message`.
+    ///
+    /// Returns `Ok(())` when the signature is valid, or an error if not (typically --
+    /// but not limited to -- `Error::BadSignature`).
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error>;
+}
+
+/// A signature of variable length, up to `N` bytes.
+#[derive(Clone)]
+pub struct SignatureBuf<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> SignatureBuf<N> {
+    fn new(fill: impl FnOnce(&mut [u8]) -> Result<&[u8], Error>) -> Result<Self, Error> {
+        let mut bytes = [0u8; N];
+        let len = fill(&mut bytes)?.len();
+        Ok(Self { bytes, len })
+    }
+}
+
+/// Copies an encoded signature, which must be at most `N` bytes.
+impl<const N: usize> TryFrom<&[u8]> for SignatureBuf<N> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::new(|buf| {
+            let buf = buf.get_mut(..value.len()).ok_or(Error::WrongLength)?;
+            buf.copy_from_slice(value);
+            Ok(buf)
+        })
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for SignatureBuf<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+/// ECDSA on P-256 with SHA-256, with DER-encoded ASN.1 signatures.
+impl Signer for ecdsa::SigningKey<P256> {
+    type Signature = SignatureBuf<ECDSA_P256_MAX_SIG_LEN>;
+
+    fn sign(&self, message: &[u8]) -> Result<Self::Signature, Error> {
+        SignatureBuf::new(|buf| self.sign_asn1::<Sha256>(&[message], buf))
+    }
+}
+
+/// ECDSA on P-256 with SHA-256, with DER-encoded ASN.1 signatures.
+impl Verifier for ecdsa::VerifyingKey<P256> {
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        self.verify_asn1::<Sha256>(&[message], signature)
+    }
+}
+
+/// ECDSA on P-384 with SHA-384, with DER-encoded ASN.1 signatures.
+impl Signer for ecdsa::SigningKey<P384> {
+    type Signature = SignatureBuf<ECDSA_P384_MAX_SIG_LEN>;
+
+    fn sign(&self, message: &[u8]) -> Result<Self::Signature, Error> {
+        SignatureBuf::new(|buf| self.sign_asn1::<Sha384>(&[message], buf))
+    }
+}
+
+/// ECDSA on P-384 with SHA-384, with DER-encoded ASN.1 signatures.
+impl Verifier for ecdsa::VerifyingKey<P384> {
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        self.verify_asn1::<Sha384>(&[message], signature)
+    }
+}
+
+macro_rules! rsa_scheme {
+    ($name:ident, $sign:ident, $verify:ident, $doc:literal) => {
+        #[doc = $doc]
+        ///
+        /// This wraps an [`rsa::SigningKey`] (to implement [`Signer`]),
+        /// or an [`rsa::VerifyingKey`] (to implement [`Verifier`]).
+        pub struct $name<K>(pub K);
+
+        impl Signer for $name<rsa::SigningKey> {
+            type Signature = SignatureBuf<MAX_PUBLIC_MODULUS_BYTES>;
+
+            fn sign(&self, message: &[u8]) -> Result<Self::Signature, Error> {
+                SignatureBuf::new(|buf| self.0.$sign(buf, message))
+            }
+        }
+
+        impl Verifier for $name<rsa::VerifyingKey> {
+            fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+                self.0.$verify(signature, message)
+            }
+        }
+    };
+}
+
+rsa_scheme!(
+    RsaPkcs1Sha256,
+    sign_pkcs1_sha256,
+    verify_pkcs1_sha256,
+    "RSASSA-PKCS1-v1_5 with SHA-256."
+);
+rsa_scheme!(
+    RsaPkcs1Sha384,
+    sign_pkcs1_sha384,
+    verify_pkcs1_sha384,
+    "RSASSA-PKCS1-v1_5 with SHA-384."
+);
+rsa_scheme!(
+    RsaPkcs1Sha512,
+    sign_pkcs1_sha512,
+    verify_pkcs1_sha512,
+    "RSASSA-PKCS1-v1_5 with SHA-512."
+);
+rsa_scheme!(
+    RsaPssSha256,
+    sign_pss_sha256,
+    verify_pss_sha256,
+    "RSASSA-PSS with SHA-256, and a 32-byte salt."
+);
+rsa_scheme!(
+    RsaPssSha384,
+    sign_pss_sha384,
+    verify_pss_sha384,
+    "RSASSA-PSS with SHA-384, and a 48-byte salt."
+);
+rsa_scheme!(
+    RsaPssSha512,
+    sign_pss_sha512,
+    verify_pss_sha512,
+    "RSASSA-PSS with SHA-512, and a 64-byte salt."
+);
+
+macro_rules! ml_dsa {
+    ($module:ident) => {
+        /// Hedged ML-DSA signing, with an empty context string.
+        impl Signer for $module::SigningKey {
+            type Signature = [u8; $module::SIGNATURE_LEN];
+
+            fn sign(&self, message: &[u8]) -> Result<Self::Signature, Error> {
+                self.sign(b"", &[message])
+            }
+        }
+
+        /// ML-DSA verification, with an empty context string.
+        impl Verifier for $module::VerifyingKey {
+            fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+                self.verify(b"", &[message], signature)
+            }
+        }
+    };
+}
+
+ml_dsa!(ml_dsa_44);
+ml_dsa!(ml_dsa_65);
+ml_dsa!(ml_dsa_87);
+
+/// Ed25519 signing.
+impl Signer for ed25519::SigningKey {
+    type Signature = [u8; ed25519::SIGNATURE_LEN];
+
+    fn sign(&self, message: &[u8]) -> Result<Self::Signature, Error> {
+        Ok(self.sign(&[message]))
+    }
+}
+
+/// Ed25519 verification.
+impl Verifier for ed25519::VerifyingKey {
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        self.verify(&[message], signature)
+    }
+}
+
+/// `SEQUENCE { INTEGER, INTEGER }`, where each integer may need a leading zero.
+pub(crate) const ECDSA_P256_MAX_SIG_LEN: usize = 2 + (2 + 33) * 2;
+pub(crate) const ECDSA_P384_MAX_SIG_LEN: usize = 2 + (2 + 49) * 2;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::curve::Curve;
+    use crate::mid::rng::SystemRandom;
+
+    fn check<S: Signer, V: Verifier>(signer: &S, verifier: &V) {
+        let signature = signer.sign(b"hello world").unwrap();
+        verifier.verify(b"hello world", signature.as_ref()).unwrap();
+        assert_eq!(
+            verifier.verify(b"goodbye world", signature.as_ref()),
+            Err(Error::BadSignature)
+        );
+
+        let mut corrupt = signature.as_ref().to_vec();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 1;
+        assert!(verifier.verify(b"hello world", &corrupt).is_err());
+    }
+
+    fn ecdsa<C: Curve>() -> (ecdsa::SigningKey<C>, ecdsa::VerifyingKey<C>) {
+        use crate::high::curve::PrivateKey;
+        let private_key = C::generate_random_key(&mut SystemRandom).unwrap();
+        let mut public_key = [0u8; 128];
+        let public_key = private_key
+            .public_key_encode_uncompressed(&mut public_key)
+            .unwrap();
+        let verifying_key = ecdsa::VerifyingKey::<C>::from_x962_uncompressed(public_key).unwrap();
+        (ecdsa::SigningKey { private_key }, verifying_key)
+    }
+
+    #[test]
+    fn ecdsa_keys() {
+        let (signer, verifier) = ecdsa::<P256>();
+        check(&signer, &verifier);
+        let (signer, verifier) = ecdsa::<P384>();
+        check(&signer, &verifier);
+    }
+
+    #[test]
+    fn rsa_keys() {
+        let key =
+            || rsa::SigningKey::from_pkcs8_der(include_bytes!("rsa/rsa2048.pkcs8.der")).unwrap();
+        let public = || key().public_key();
+
+        check(&RsaPkcs1Sha256(key()), &RsaPkcs1Sha256(public()));
+        check(&RsaPkcs1Sha384(key()), &RsaPkcs1Sha384(public()));
+        check(&RsaPkcs1Sha512(key()), &RsaPkcs1Sha512(public()));
+        check(&RsaPssSha256(key()), &RsaPssSha256(public()));
+        check(&RsaPssSha384(key()), &RsaPssSha384(public()));
+        check(&RsaPssSha512(key()), &RsaPssSha512(public()));
+
+        let signature = RsaPssSha256(key()).sign(b"hello world").unwrap();
+        assert_eq!(signature.as_ref().len(), 256);
+        assert_eq!(
+            RsaPssSha384(public()).verify(b"hello world", signature.as_ref()),
+            Err(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn ed25519_keys() {
+        let signer = ed25519::SigningKey::new_random().unwrap();
+        check(&signer, &signer.verifying_key());
+    }
+
+    #[test]
+    fn ml_dsa_keys() {
+        let signer = ml_dsa_44::SigningKey::new_random().unwrap();
+        check(&signer, &signer.verifying_key());
+        let signer = ml_dsa_65::SigningKey::new_random().unwrap();
+        check(&signer, &signer.verifying_key());
+        let signer = ml_dsa_87::SigningKey::new_random().unwrap();
+        check(&signer, &signer.verifying_key());
+    }
+
+    #[test]
+    fn signature_buf() {
+        let sig = SignatureBuf::<4>::try_from(&b"abc"[..]).unwrap();
+        assert_eq!(sig.as_ref(), b"abc");
+        assert_eq!(SignatureBuf::<4>::try_from(&b""[..]).unwrap().as_ref(), b"");
+        assert!(SignatureBuf::<4>::try_from(&b"abcde"[..]).is_err());
+    }
+}
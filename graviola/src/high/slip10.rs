@@ -0,0 +1,513 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md)
+//! hierarchical deterministic key derivation.
+//!
+//! SLIP-0010 generalises BIP32 to other curves.  This module supports
+//! its Ed25519 and NIST P-256 (which SLIP-0010 calls "nist256p1")
+//! variants, with hardened derivation only.  Ed25519 does not support
+//! non-hardened derivation at all, and without it there is no
+//! extended public key.
+//!
+//! ```
+//! use graviola::signing::slip10::*;
+//!
+//! let master = ExtendedPrivateKey::<Ed25519>::from_seed(b"a seed of at least 16 bytes").unwrap();
+//!
+//! // these are the same key
+//! let first = master.derive_path("m/44'/0'/1'").unwrap();
+//! let second = master
+//!     .derive_hardened(44)
+//!     .and_then(|k| k.derive_hardened(0))
+//!     .and_then(|k| k.derive_hardened(1))
+//!     .unwrap();
+//! assert_eq!(first.chain_code(), second.chain_code());
+//!
+//! let signing_key = first.signing_key();
+//! let signature = signing_key.sign(&[b"hello world"]);
+//! signing_key
+//!     .verifying_key()
+//!     .verify(&[b"hello world"], &signature)
+//!     .unwrap();
+//! ```
+
+use core::marker::PhantomData;
+
+use super::ecdsa;
+use super::hmac::hmac_sha512;
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::low;
+use crate::mid::{ed25519, p256};
+
+pub use super::curve::P256;
+
+/// A curve supported by SLIP-0010 derivation.
+pub trait Curve: private::Sealed {
+    /// The signing key type produced for this curve.
+    type SigningKey;
+}
+
+/// The Ed25519 variant of SLIP-0010.
+pub struct Ed25519;
+
+impl Curve for Ed25519 {
+    type SigningKey = ed25519::SigningKey;
+}
+
+impl private::Sealed for Ed25519 {
+    const SEED_KEY: &'static [u8] = b"ed25519 seed";
+
+    fn master_key(il: &[u8; 32]) -> Option<[u8; 32]> {
+        Some(*il)
+    }
+
+    fn child_key(_parent: &[u8; 32], il: &[u8; 32]) -> Option<[u8; 32]> {
+        Some(*il)
+    }
+
+    fn signing_key(key: &[u8; 32]) -> ed25519::SigningKey {
+        ed25519::SigningKey::from_seed(key)
+    }
+}
+
+impl Curve for P256 {
+    type SigningKey = ecdsa::SigningKey<Self>;
+}
+
+impl private::Sealed for P256 {
+    const SEED_KEY: &'static [u8] = b"Nist256p1 seed";
+
+    fn master_key(il: &[u8; 32]) -> Option<[u8; 32]> {
+        p256::Scalar::from_bytes_checked(il)
+            .ok()
+            .map(|k| k.as_bytes())
+    }
+
+    fn child_key(parent: &[u8; 32], il: &[u8; 32]) -> Option<[u8; 32]> {
+        // `il` may be zero, but not `n` or larger
+        let il_reduced = p256::Scalar::from_bytes_reduced(il).ok()?;
+        if !low::ct_equal(&il_reduced.as_bytes(), il) {
+            return None;
+        }
+
+        let parent = p256::Scalar::from_bytes_checked(parent).ok()?;
+        let child = il_reduced.add(&parent);
+        match child.is_zero() {
+            true => None,
+            false => Some(child.as_bytes()),
+        }
+    }
+
+    fn signing_key(key: &[u8; 32]) -> ecdsa::SigningKey<Self> {
+        ecdsa::SigningKey {
+            // cannot fail: `key` was checked to be in range when derived
+            private_key: p256::StaticPrivateKey::from_bytes(key).unwrap(),
+        }
+    }
+}
+
+/// The index of the first hardened child.
+///
+/// [`ExtendedPrivateKey::derive_hardened()`] takes indices below this,
+/// and adds it itself.
+pub const HARDENED: u32 = 0x8000_0000;
+
+/// A private key and chain code, at some point in a key hierarchy.
+pub struct ExtendedPrivateKey<C: Curve> {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+    depth: u8,
+    _c: PhantomData<C>,
+}
+
+impl<C: Curve> ExtendedPrivateKey<C> {
+    /// Generate the master key from `seed`.
+    ///
+    /// `seed` must be between 16 and 64 bytes long; otherwise
+    /// [`Error::WrongLength`] is returned.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        let _entry = low::Entry::new_secret();
+        if !(16..=64).contains(&seed.len()) {
+            return Err(Error::WrongLength);
+        }
+
+        let mut i = hmac_sha512(C::SEED_KEY, seed);
+        loop {
+            let (il, ir) = split(&i);
+            if let Some(key) = C::master_key(il) {
+                let r = Self::new(key, ir, 0);
+                low::zeroise(&mut i);
+                return Ok(r);
+            }
+            i = hmac_sha512(C::SEED_KEY, i);
+        }
+    }
+
+    /// Derive the hardened child number `index`.
+    ///
+    /// This is the child SLIP-0010 writes as `index'` or `indexH`.
+    /// `index` must be less than [`HARDENED`], otherwise
+    /// [`Error::OutOfRange`] is returned.
+    pub fn derive_hardened(&self, index: u32) -> Result<Self, Error> {
+        let _entry = low::Entry::new_secret();
+        if index >= HARDENED {
+            return Err(Error::OutOfRange);
+        }
+        let depth = self.depth.checked_add(1).ok_or(Error::OutOfRange)?;
+        let index = (index | HARDENED).to_be_bytes();
+
+        let mut data = [0u8; 37];
+        data[1..33].copy_from_slice(&self.key);
+        data[33..].copy_from_slice(&index);
+
+        loop {
+            let mut i = hmac_sha512(self.chain_code, data);
+            let (il, ir) = split(&i);
+            let child = C::child_key(&self.key, il).map(|key| Self::new(key, ir, depth));
+
+            // on failure, the derivation continues with `0x01 || IR || index`
+            data[0] = 0x01;
+            data[1..33].copy_from_slice(ir);
+            low::zeroise(&mut i);
+
+            if let Some(child) = child {
+                low::zeroise(&mut data);
+                return Ok(child);
+            }
+        }
+    }
+
+    /// Derive the key at `path`, relative to this one.
+    ///
+    /// `path` is written like `m/44'/0'/1'`: an `m` followed by zero
+    /// or more `/`-separated decimal indices.  Each index must be marked
+    /// as hardened with a trailing `'`, `h` or `H`.  A non-hardened
+    /// (or too large) index gives [`Error::OutOfRange`]; anything else
+    /// not of this form gives [`Error::KeyFormatError`].
+    pub fn derive_path(&self, path: &str) -> Result<Self, Error> {
+        let invalid = || Error::from(KeyFormatError::InvalidEncoding);
+
+        let mut components = path.split('/');
+        if components.next() != Some("m") {
+            return Err(invalid());
+        }
+
+        let mut key = self.clone();
+        for component in components {
+            let (index, hardened) = match component.strip_suffix(['\'', 'h', 'H']) {
+                Some(index) => (index, true),
+                None => (component, false),
+            };
+
+            if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            let index = index.parse::<u32>().map_err(|_| Error::OutOfRange)?;
+            if !hardened {
+                return Err(Error::OutOfRange);
+            }
+
+            key = key.derive_hardened(index)?;
+        }
+        Ok(key)
+    }
+
+    /// Return the signing key for this point of the hierarchy.
+    pub fn signing_key(&self) -> C::SigningKey {
+        let _entry = low::Entry::new_secret();
+        C::signing_key(&self.key)
+    }
+
+    /// Return the private key bytes.
+    ///
+    /// For [`Ed25519`] this is the RFC8032 seed; for [`P256`] it is
+    /// the big-endian private scalar.
+    pub fn private_key_bytes(&self) -> [u8; 32] {
+        self.key
+    }
+
+    /// Return the chain code.
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Return the depth of this key: zero for the master key.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    fn new(key: [u8; 32], chain_code: &[u8; 32], depth: u8) -> Self {
+        Self {
+            key,
+            chain_code: *chain_code,
+            depth,
+            _c: PhantomData,
+        }
+    }
+}
+
+impl<C: Curve> Clone for ExtendedPrivateKey<C> {
+    fn clone(&self) -> Self {
+        Self::new(self.key, &self.chain_code, self.depth)
+    }
+}
+
+impl<C: Curve> Drop for ExtendedPrivateKey<C> {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.key);
+        low::zeroise(&mut self.chain_code);
+    }
+}
+
+fn split(i: &[u8; 64]) -> (&[u8; 32], &[u8; 32]) {
+    let (il, ir) = i.split_at(32);
+    (il.try_into().unwrap(), ir.try_into().unwrap())
+}
+
+mod private {
+    pub trait Sealed {
+        /// The HMAC key for master key generation.
+        const SEED_KEY: &'static [u8];
+
+        /// Check the master key `il`, returning `None` if invalid.
+        fn master_key(il: &[u8; 32]) -> Option<[u8; 32]>;
+
+        /// Compute a child key from `parent`, returning `None` if invalid.
+        fn child_key(parent: &[u8; 32], il: &[u8; 32]) -> Option<[u8; 32]>;
+
+        fn signing_key(key: &[u8; 32]) -> <Self as super::Curve>::SigningKey
+        where
+            Self: super::Curve;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::private::Sealed;
+    use super::*;
+    use crate::high::hex;
+
+    fn check<C: Curve>(seed: &str, vectors: &[(&str, &str, &str)]) {
+        let master = ExtendedPrivateKey::<C>::from_seed(&hex::decode(seed).unwrap()).unwrap();
+        let mut key = master.clone();
+
+        for (depth, (path, chain_code, private)) in vectors.iter().enumerate() {
+            if depth > 0 {
+                let index = path.rsplit('/').next().unwrap();
+                let index = index.strip_suffix('H').unwrap().parse().unwrap();
+                key = key.derive_hardened(index).unwrap();
+            }
+            let from_path = master.derive_path(path).unwrap();
+
+            for k in [&key, &from_path] {
+                assert_eq!(hex::encode(k.chain_code()), *chain_code, "{path}");
+                assert_eq!(hex::encode(&k.private_key_bytes()), *private, "{path}");
+                assert_eq!(k.depth() as usize, depth);
+            }
+        }
+    }
+
+    #[test]
+    fn ed25519_vector_1() {
+        check::<Ed25519>(
+            "000102030405060708090a0b0c0d0e0f",
+            &[
+                (
+                    "m",
+                    "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+                    "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+                ),
+                (
+                    "m/0H",
+                    "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+                    "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+                ),
+                (
+                    "m/0H/1H",
+                    "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14",
+                    "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+                ),
+                (
+                    "m/0H/1H/2H",
+                    "2e69929e00b5ab250f49c3fb1c12f252de4fed2c1db88387094a0f8c4c9ccd6c",
+                    "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9",
+                ),
+                (
+                    "m/0H/1H/2H/2H",
+                    "8f6d87f93d750e0efccda017d662a1b31a266e4a6f5993b15f5c1f07f74dd5cc",
+                    "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662",
+                ),
+                (
+                    "m/0H/1H/2H/2H/1000000000H",
+                    "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+                    "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn ed25519_vector_2() {
+        check::<Ed25519>(
+            "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a2\
+             9f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+            &[
+                (
+                    "m",
+                    "ef70a74db9c3a5af931b5fe73ed8e1a53464133654fd55e7a66f8570b8e33c3b",
+                    "171cb88b1b3c1db25add599712e36245d75bc65a1a5c9e18d76f9f2b1eab4012",
+                ),
+                (
+                    "m/0H",
+                    "0b78a3226f915c082bf118f83618a618ab6dec793752624cbeb622acb562862d",
+                    "1559eb2bbec5790b0c65d8693e4d0875b1747f4970ae8b650486ed7470845635",
+                ),
+                (
+                    "m/0H/2147483647H",
+                    "138f0b2551bcafeca6ff2aa88ba8ed0ed8de070841f0c4ef0165df8181eaad7f",
+                    "ea4f5bfe8694d8bb74b7b59404632fd5968b774ed545e810de9c32a4fb4192f4",
+                ),
+                (
+                    "m/0H/2147483647H/1H",
+                    "73bd9fff1cfbde33a1b846c27085f711c0fe2d66fd32e139d3ebc28e5a4a6b90",
+                    "3757c7577170179c7868353ada796c839135b3d30554bbb74a4b1e4a5a58505c",
+                ),
+                (
+                    "m/0H/2147483647H/1H/2147483646H",
+                    "0902fe8a29f9140480a00ef244bd183e8a13288e4412d8389d140aac1794825a",
+                    "5837736c89570de861ebc173b1086da4f505d4adb387c6a1b1342d5e4ac9ec72",
+                ),
+                (
+                    "m/0H/2147483647H/1H/2147483646H/2H",
+                    "5d70af781f3a37b829f0d060924d5e960bdc02e85423494afc0b1a41bbe196d4",
+                    "551d333177df541ad876a60ea71f00447931c0a9da16f227c11ea080d7391b8d",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn nist256p1_vector_1() {
+        check::<P256>(
+            "000102030405060708090a0b0c0d0e0f",
+            &[
+                (
+                    "m",
+                    "beeb672fe4621673f722f38529c07392fecaa61015c80c34f29ce8b41b3cb6ea",
+                    "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2",
+                ),
+                (
+                    "m/0H",
+                    "3460cea53e6a6bb5fb391eeef3237ffd8724bf0a40e94943c98b83825342ee11",
+                    "6939694369114c67917a182c59ddb8cafc3004e63ca5d3b84403ba8613debc0c",
+                ),
+                (
+                    "m/0H/1H",
+                    "07cfab4bd7782bc8f924cae514ec098ad8ff57d3b4250fc23ffffcb80cc4c022",
+                    "a71c76520cd0ce74bfd902564b15c5c4b988c2fcc1f4b3850922c898fbc07fad",
+                ),
+                (
+                    "m/0H/1H/2H",
+                    "2817e365cd69b9daaf0b908a150ff1507b474400f4e42fe927d5542f8cc9eefd",
+                    "37e9c5707ef5e03a29b7a38cd7ccc8594ffcd20e4bfa4ec6679885bda8c60a02",
+                ),
+                (
+                    "m/0H/1H/2H/2H",
+                    "46c9d56234938e6215e83e85f78370f973095853f3679bfb1eea8dbe2c6d78dc",
+                    "3d3825df7415f2eb4a1aa38312001bb35c1d04b928631f2c2b5089877c2f071f",
+                ),
+                (
+                    "m/0H/1H/2H/2H/1000000000H",
+                    "0605af1badee378252f642d9d665075206d714dfa79de6000bcf0833bb1c5603",
+                    "44b1f0793bc31bd80ffa94de5d212888e4d3af128a0f2fd51400fc6196455464",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn nist256p1_master_retry() {
+        // the first HMAC output for this seed is larger than n
+        check::<P256>(
+            "a7305bc8df8d0951f0cb224c0e95d7707cbdf2c6ce7e8d481fec69c7ff5e9446",
+            &[(
+                "m",
+                "7762f9729fed06121fd13f326884c82f59aa95c57ac492ce8c9654e60efd130c",
+                "3b8c18469a4634517d6d0b65448f8e6c62091b45540a1743c5846be55d47d88f",
+            )],
+        );
+    }
+
+    #[test]
+    fn nist256p1_child_key() {
+        let n = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+        let n: [u8; 32] = hex::decode(n).unwrap().try_into().unwrap();
+        let mut n_minus_1 = n;
+        n_minus_1[31] -= 1;
+        let one = {
+            let mut one = [0u8; 32];
+            one[31] = 1;
+            one
+        };
+
+        assert_eq!(P256::child_key(&one, &[0u8; 32]), Some(one));
+        assert_eq!(P256::child_key(&one, &n), None);
+        assert_eq!(P256::child_key(&one, &[0xff; 32]), None);
+        assert_eq!(P256::child_key(&one, &n_minus_1), None);
+        assert_eq!(P256::child_key(&n_minus_1, &one), None);
+        assert_eq!(P256::child_key(&one, &one).unwrap()[31], 2);
+    }
+
+    #[test]
+    fn signing_keys() {
+        let master = ExtendedPrivateKey::<P256>::from_seed(&[0x55; 32]).unwrap();
+        let key = master.derive_path("m/1'/2'").unwrap().signing_key();
+        assert_eq!(
+            key.private_key.as_bytes(),
+            master.derive_path("m/1h/2h").unwrap().private_key_bytes()
+        );
+
+        let master = ExtendedPrivateKey::<Ed25519>::from_seed(&[0x55; 32]).unwrap();
+        let key = master.derive_path("m/1'/2'").unwrap();
+        assert_eq!(key.signing_key().seed(), key.private_key_bytes());
+    }
+
+    #[test]
+    fn seed_length() {
+        for len in [0, 15, 65, 100] {
+            assert_eq!(
+                ExtendedPrivateKey::<Ed25519>::from_seed(&vec![0; len]).err(),
+                Some(Error::WrongLength)
+            );
+        }
+        for len in [16, 32, 64] {
+            assert!(ExtendedPrivateKey::<P256>::from_seed(&vec![0; len]).is_ok());
+        }
+    }
+
+    #[test]
+    fn invalid_paths() {
+        let master = ExtendedPrivateKey::<Ed25519>::from_seed(&[0; 16]).unwrap();
+        assert_eq!(master.derive_path("m").unwrap().depth(), 0);
+        assert_eq!(
+            master.derive_hardened(HARDENED).err(),
+            Some(Error::OutOfRange)
+        );
+
+        for path in ["m/0", "m/2147483648'", "m/4294967296'", "m/0'/1"] {
+            assert_eq!(master.derive_path(path).err(), Some(Error::OutOfRange));
+        }
+        for path in [
+            "", "M", "m/", "/0'", "m/'", "m/0''", "m//0'", "m/+1'", "m/-1'", "m/0x1'", "n/0'",
+            "m/0'/", " m/0'",
+        ] {
+            assert_eq!(
+                master.derive_path(path).err(),
+                Some(Error::KeyFormatError(KeyFormatError::InvalidEncoding)),
+                "{path:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,876 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! SPAKE2+, an augmented password-authenticated key exchange, as specified
+//! in [RFC9383](https://datatracker.ietf.org/doc/html/rfc9383).
+//!
+//! The prover knows the password, and the verifier stores a
+//! [`RegistrationRecord`] computed from it.  This is the protocol used for
+//! Matter (formerly CHIP) device commissioning, where the device is the
+//! verifier: use the [`Matter`] ciphersuite there.
+//!
+//! Only the P-256 ciphersuites are supported.  Computing `w0s || w1s` from
+//! the password and identities is left to the caller: the specification
+//! recommends a memory-hard function, and Matter uses PBKDF2.
+
+use core::marker::PhantomData;
+
+use super::hash::{Digest, Hash, HashContext, HashOutput, Sha256, Sha512};
+use super::hkdf;
+use super::hmac::Hmac;
+use crate::Error;
+use crate::low::{self, Entry};
+use crate::mid::cmac::Cmac;
+use crate::mid::p256::{Element, Scalar};
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+/// A SPAKE2+ ciphersuite over P-256.
+///
+/// This determines the hash function, and the MAC used for key
+/// confirmation.
+pub trait Ciphersuite: private::Sealed {
+    /// The hash function, used for the transcript and HKDF.
+    type Hash: Digest + Clone;
+
+    /// Length of a confirmation MAC.
+    const MAC_LEN: usize;
+
+    /// Length of the shared key.
+    const SHARED_KEY_LEN: usize;
+}
+
+/// `P256-SHA256-HKDF-SHA256-HMAC-SHA256`
+pub struct P256Sha256HmacSha256;
+
+impl Ciphersuite for P256Sha256HmacSha256 {
+    type Hash = Sha256;
+    const MAC_LEN: usize = 32;
+    const SHARED_KEY_LEN: usize = 32;
+}
+
+impl private::Sealed for P256Sha256HmacSha256 {
+    const CONFIRMATION_KEY_LEN: usize = 32;
+    const SPLIT_K_MAIN: bool = false;
+
+    fn mac(key: &[u8], message: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        hmac::<Sha256>(key, message, out)
+    }
+}
+
+/// `P256-SHA512-HKDF-SHA512-HMAC-SHA512`
+pub struct P256Sha512HmacSha512;
+
+impl Ciphersuite for P256Sha512HmacSha512 {
+    type Hash = Sha512;
+    const MAC_LEN: usize = 64;
+    const SHARED_KEY_LEN: usize = 64;
+}
+
+impl private::Sealed for P256Sha512HmacSha512 {
+    const CONFIRMATION_KEY_LEN: usize = 64;
+    const SPLIT_K_MAIN: bool = false;
+
+    fn mac(key: &[u8], message: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        hmac::<Sha512>(key, message, out)
+    }
+}
+
+/// `P256-SHA256-HKDF-SHA256-CMAC-AES-128`
+pub struct P256Sha256CmacAes128;
+
+impl Ciphersuite for P256Sha256CmacAes128 {
+    type Hash = Sha256;
+    const MAC_LEN: usize = 16;
+    const SHARED_KEY_LEN: usize = 32;
+}
+
+impl private::Sealed for P256Sha256CmacAes128 {
+    const CONFIRMATION_KEY_LEN: usize = 16;
+    const SPLIT_K_MAIN: bool = false;
+
+    fn mac(key: &[u8], message: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        cmac(key, message, out)
+    }
+}
+
+/// `P256-SHA512-HKDF-SHA512-CMAC-AES-128`
+pub struct P256Sha512CmacAes128;
+
+impl Ciphersuite for P256Sha512CmacAes128 {
+    type Hash = Sha512;
+    const MAC_LEN: usize = 16;
+    const SHARED_KEY_LEN: usize = 64;
+}
+
+impl private::Sealed for P256Sha512CmacAes128 {
+    const CONFIRMATION_KEY_LEN: usize = 16;
+    const SPLIT_K_MAIN: bool = false;
+
+    fn mac(key: &[u8], message: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        cmac(key, message, out)
+    }
+}
+
+/// The ciphersuite used by Matter.
+///
+/// This is `P256-SHA256-HKDF-SHA256-HMAC-SHA256` with the key schedule of
+/// draft-bar-cfrg-spake2plus-02, which Matter adopted: `Hash(TT)` is split
+/// into `Ka || Ke`, the confirmation keys are derived from `Ka`, and `Ke`
+/// is the 16-byte shared key.
+///
+/// In Matter, `context` is the SHA-256 hash of
+/// `"CHIP PAKE V1 Commissioning"` and the PBKDF parameter messages, and
+/// both identities are empty.
+pub struct Matter;
+
+impl Ciphersuite for Matter {
+    type Hash = Sha256;
+    const MAC_LEN: usize = 32;
+    const SHARED_KEY_LEN: usize = 16;
+}
+
+impl private::Sealed for Matter {
+    const CONFIRMATION_KEY_LEN: usize = 16;
+    const SPLIT_K_MAIN: bool = true;
+
+    fn mac(key: &[u8], message: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        hmac::<Sha256>(key, message, out)
+    }
+}
+
+/// The prover's secrets `w0` and `w1`, which are derived from the password.
+pub struct ProverKey {
+    w0: Scalar,
+    w1: Scalar,
+}
+
+impl ProverKey {
+    /// Length of an encoded key, `w0 || w1`.
+    pub const LEN: usize = 2 * Scalar::BYTES;
+
+    /// Compute `w0` and `w1` from the output of the password-based
+    /// key derivation function, `w0s || w1s`.
+    ///
+    /// This must be between 80 and 96 bytes long, and of even length.
+    pub fn from_pbkdf_output(output: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        if output.len() % 2 != 0 {
+            return Err(Error::WrongLength);
+        }
+        let (w0s, w1s) = output.split_at(output.len() / 2);
+        Ok(Self {
+            w0: reduce(w0s)?,
+            w1: reduce(w1s)?,
+        })
+    }
+
+    /// Decode a key previously encoded with [`ProverKey::as_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        if bytes.len() != Self::LEN {
+            return Err(Error::WrongLength);
+        }
+        let (w0, w1) = bytes.split_at(Scalar::BYTES);
+        Ok(Self {
+            w0: Scalar::from_bytes_checked(w0)?,
+            w1: Scalar::from_bytes_checked(w1)?,
+        })
+    }
+
+    /// Encode this key, as `w0 || w1`.
+    pub fn as_bytes(&self) -> [u8; Self::LEN] {
+        let _entry = Entry::new_secret();
+        let mut r = [0u8; Self::LEN];
+        let (w0, w1) = r.split_at_mut(Scalar::BYTES);
+        w0.copy_from_slice(&self.w0.as_bytes());
+        w1.copy_from_slice(&self.w1.as_bytes());
+        r
+    }
+
+    /// Compute the registration record for the verifier: `w0`, and
+    /// `L = w1 * P`.
+    pub fn registration_record(&self) -> RegistrationRecord {
+        let _entry = Entry::new_secret();
+        RegistrationRecord {
+            w0: self.w0.clone(),
+            l: Element::base_multiply(&self.w1),
+        }
+    }
+}
+
+/// The verifier's registration record, `w0` and `L`.
+pub struct RegistrationRecord {
+    w0: Scalar,
+    l: Element,
+}
+
+impl RegistrationRecord {
+    /// Length of an encoded record, `w0 || L`.
+    pub const LEN: usize = Scalar::BYTES + Share::LEN;
+
+    /// Decode a record previously encoded with [`RegistrationRecord::as_bytes`].
+    ///
+    /// This is the same as Matter's "verifier" encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        if bytes.len() != Self::LEN {
+            return Err(Error::WrongLength);
+        }
+        let (w0, l) = bytes.split_at(Scalar::BYTES);
+        Ok(Self {
+            w0: Scalar::from_bytes_checked(w0)?,
+            l: Element::decode_uncompressed(l)?,
+        })
+    }
+
+    /// Encode this record, as `w0 || L` where `L` is uncompressed.
+    pub fn as_bytes(&self) -> [u8; Self::LEN] {
+        let _entry = Entry::new_secret();
+        let mut r = [0u8; Self::LEN];
+        let (w0, l) = r.split_at_mut(Scalar::BYTES);
+        w0.copy_from_slice(&self.w0.as_bytes());
+        l.copy_from_slice(&self.l.encode_uncompressed());
+        r
+    }
+}
+
+/// A public share, `shareP` or `shareV`.
+pub struct Share(Element);
+
+impl Share {
+    /// Length of an encoded share.
+    pub const LEN: usize = 65;
+
+    /// Decode a share, which must be an uncompressed point on the curve.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        Element::decode_uncompressed(bytes).map(Self)
+    }
+
+    /// Encode this share, as an uncompressed point.
+    pub fn as_bytes(&self) -> [u8; Self::LEN] {
+        let _entry = Entry::new_public();
+        self.0.encode_uncompressed()
+    }
+}
+
+/// A key confirmation MAC, `confirmP` or `confirmV`.
+pub struct Confirmation<S: Ciphersuite> {
+    mac: [u8; MAX_MAC_LEN],
+    _suite: PhantomData<S>,
+}
+
+impl<S: Ciphersuite> Confirmation<S> {
+    /// Length of an encoded confirmation.
+    pub const LEN: usize = S::MAC_LEN;
+
+    /// Decode a confirmation.  `bytes` must be [`Confirmation::LEN`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::LEN {
+            return Err(Error::WrongLength);
+        }
+        let mut mac = [0u8; MAX_MAC_LEN];
+        mac[..Self::LEN].copy_from_slice(bytes);
+        Ok(Self {
+            mac,
+            _suite: PhantomData,
+        })
+    }
+
+    /// Encode this confirmation.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.mac[..Self::LEN]
+    }
+
+    /// Constant-time equality.
+    fn ct_equal(&self, other: &Self) -> bool {
+        low::ct_equal(self.as_bytes(), other.as_bytes())
+    }
+}
+
+/// The shared key `K_shared`, resulting from a successful exchange.
+pub struct SharedKey {
+    key: [u8; MAX_SHARED_KEY_LEN],
+    len: usize,
+}
+
+impl SharedKey {
+    /// Extract the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.key[..self.len]
+    }
+}
+
+impl Drop for SharedKey {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.key);
+    }
+}
+
+/// The prover's state during an exchange.
+pub struct Prover<S: Ciphersuite> {
+    x: Scalar,
+    w0: Scalar,
+    w1: Scalar,
+    share_p: Element,
+    _suite: PhantomData<S>,
+}
+
+impl<S: Ciphersuite> Prover<S> {
+    /// Start an exchange.
+    ///
+    /// The returned `shareP` is sent to the verifier.
+    pub fn start(key: &ProverKey) -> Result<(Self, Share), Error> {
+        Self::start_with_rng(key, &mut SystemRandom)
+    }
+
+    /// Start an exchange, using `rng` to generate the ephemeral scalar.
+    ///
+    /// Otherwise, as [`Self::start()`].
+    pub fn start_with_rng(
+        key: &ProverKey,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, Share), Error> {
+        let _entry = Entry::new_secret();
+        let x = Scalar::generate(rng)?;
+        // X = x*P + w0*M
+        let share_p = Element::base_multiply(&x).add(&point_m().mul(&key.w0));
+        let prover = Self {
+            x,
+            w0: key.w0.clone(),
+            w1: key.w1.clone(),
+            share_p,
+            _suite: PhantomData,
+        };
+        Ok((prover, Share(share_p)))
+    }
+
+    /// Complete the exchange, given the verifier's `shareV` and `confirmV`.
+    ///
+    /// `context`, `prover_id` and `verifier_id` must match those used by
+    /// the verifier.  Fails with [`Error::BadSignature`] if the verifier
+    /// could not be authenticated.
+    ///
+    /// On success, the returned `confirmP` is sent to the verifier.
+    pub fn finish(
+        self,
+        share_v: &Share,
+        confirm_v: &Confirmation<S>,
+        context: &[u8],
+        prover_id: &[u8],
+        verifier_id: &[u8],
+    ) -> Result<(Confirmation<S>, SharedKey), Error> {
+        let _entry = Entry::new_secret();
+
+        // Y - w0*N
+        let unblinded = share_v.0.add(&point_n().mul(&self.w0.negate()));
+        if unblinded.is_identity() {
+            return Err(Error::NotOnCurve);
+        }
+        let z = unblinded.mul(&self.x);
+        let v = unblinded.mul(&self.w1);
+
+        let (keys, shared_key) = Transcript::<S>::new(context, prover_id, verifier_id).finish(
+            &self.share_p,
+            &share_v.0,
+            &z,
+            &v,
+            &self.w0,
+        )?;
+
+        if !keys.confirm_v(&self.share_p)?.ct_equal(confirm_v) {
+            return Err(Error::BadSignature);
+        }
+        Ok((keys.confirm_p(&share_v.0)?, shared_key))
+    }
+}
+
+/// The verifier's state during an exchange.
+pub struct Verifier<S: Ciphersuite> {
+    expected_confirm_p: Confirmation<S>,
+    shared_key: SharedKey,
+}
+
+impl<S: Ciphersuite> Verifier<S> {
+    /// Respond to the prover's `shareP`.
+    ///
+    /// `record` is the prover's registration record.  `context` is shared
+    /// by prover and verifier, and identifies the application.
+    /// `prover_id` and `verifier_id` may be empty.
+    ///
+    /// The returned `shareV` and `confirmV` are sent to the prover.
+    pub fn start(
+        record: &RegistrationRecord,
+        share_p: &Share,
+        context: &[u8],
+        prover_id: &[u8],
+        verifier_id: &[u8],
+    ) -> Result<(Self, Share, Confirmation<S>), Error> {
+        Self::start_with_rng(
+            record,
+            share_p,
+            context,
+            prover_id,
+            verifier_id,
+            &mut SystemRandom,
+        )
+    }
+
+    /// Start an exchange, using `rng` to generate the ephemeral scalar.
+    ///
+    /// Otherwise, as [`Self::start()`].
+    pub fn start_with_rng(
+        record: &RegistrationRecord,
+        share_p: &Share,
+        context: &[u8],
+        prover_id: &[u8],
+        verifier_id: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, Share, Confirmation<S>), Error> {
+        let _entry = Entry::new_secret();
+        let y = Scalar::generate(rng)?;
+        // Y = y*P + w0*N
+        let share_v = Element::base_multiply(&y).add(&point_n().mul(&record.w0));
+
+        // X - w0*M
+        let unblinded = share_p.0.add(&point_m().mul(&record.w0.negate()));
+        if unblinded.is_identity() {
+            return Err(Error::NotOnCurve);
+        }
+        let z = unblinded.mul(&y);
+        let v = record.l.mul(&y);
+
+        let (keys, shared_key) = Transcript::<S>::new(context, prover_id, verifier_id)
+            .finish(&share_p.0, &share_v, &z, &v, &record.w0)?;
+
+        let verifier = Self {
+            expected_confirm_p: keys.confirm_p(&share_v)?,
+            shared_key,
+        };
+        let confirm_v = keys.confirm_v(&share_p.0)?;
+        Ok((verifier, Share(share_v), confirm_v))
+    }
+
+    /// Check the prover's `confirmP`.
+    ///
+    /// Fails with [`Error::BadSignature`] if the prover could not be
+    /// authenticated.  Otherwise, returns the shared key.
+    pub fn finish(self, confirm_p: &Confirmation<S>) -> Result<SharedKey, Error> {
+        let _entry = Entry::new_secret();
+        match self.expected_confirm_p.ct_equal(confirm_p) {
+            true => Ok(self.shared_key),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// The transcript `TT`, being hashed into `K_main`.
+struct Transcript<S: Ciphersuite> {
+    ctx: <S::Hash as Hash>::Context,
+}
+
+impl<S: Ciphersuite> Transcript<S> {
+    fn new(context: &[u8], prover_id: &[u8], verifier_id: &[u8]) -> Self {
+        let mut tt = Self {
+            ctx: S::Hash::new(),
+        };
+        tt.append(context);
+        tt.append(prover_id);
+        tt.append(verifier_id);
+        tt.append(&point_m().encode_uncompressed());
+        tt.append(&point_n().encode_uncompressed());
+        tt
+    }
+
+    /// Complete the transcript, and run the key schedule.
+    fn finish(
+        mut self,
+        share_p: &Element,
+        share_v: &Element,
+        z: &Element,
+        v: &Element,
+        w0: &Scalar,
+    ) -> Result<(ConfirmationKeys<S>, SharedKey), Error> {
+        self.append(&share_p.encode_uncompressed());
+        self.append(&share_v.encode_uncompressed());
+        self.append(&z.encode_uncompressed());
+        self.append(&v.encode_uncompressed());
+        self.append(&w0.as_bytes());
+        let mut k_main = self.ctx.finish();
+        let keys = ConfirmationKeys::new(&k_main);
+        low::zeroise(k_main.as_mut());
+        keys
+    }
+
+    /// `len(value) || value`, where the length is 8 bytes little-endian.
+    fn append(&mut self, value: &[u8]) {
+        self.ctx.update(&(value.len() as u64).to_le_bytes());
+        self.ctx.update(value);
+    }
+}
+
+/// `K_confirmP` and `K_confirmV`.
+struct ConfirmationKeys<S: Ciphersuite> {
+    keys: [u8; 2 * MAX_CONFIRMATION_KEY_LEN],
+    _suite: PhantomData<S>,
+}
+
+impl<S: Ciphersuite> ConfirmationKeys<S> {
+    /// The key schedule.  Also returns `K_shared`.
+    fn new(k_main: &HashOutput) -> Result<(Self, SharedKey), Error> {
+        let mut shared_key = SharedKey {
+            key: [0u8; MAX_SHARED_KEY_LEN],
+            len: S::SHARED_KEY_LEN,
+        };
+        let shared = &mut shared_key.key[..S::SHARED_KEY_LEN];
+
+        let ikm = match S::SPLIT_K_MAIN {
+            true => {
+                let (ka, ke) = k_main.as_ref().split_at(S::Hash::OUTPUT_LEN / 2);
+                shared.copy_from_slice(ke);
+                ka
+            }
+            false => {
+                hkdf::extract::<S::Hash>(&[], &[k_main.as_ref()])
+                    .expand(&[b"SharedKey"], shared)?;
+                k_main.as_ref()
+            }
+        };
+
+        let mut keys = Self {
+            keys: [0u8; 2 * MAX_CONFIRMATION_KEY_LEN],
+            _suite: PhantomData,
+        };
+        hkdf::extract::<S::Hash>(&[], &[ikm]).expand(
+            &[b"ConfirmationKeys"],
+            &mut keys.keys[..2 * S::CONFIRMATION_KEY_LEN],
+        )?;
+        Ok((keys, shared_key))
+    }
+
+    /// `confirmP = MAC(K_confirmP, shareV)`
+    fn confirm_p(&self, share_v: &Element) -> Result<Confirmation<S>, Error> {
+        self.confirm(&self.keys[..S::CONFIRMATION_KEY_LEN], share_v)
+    }
+
+    /// `confirmV = MAC(K_confirmV, shareP)`
+    fn confirm_v(&self, share_p: &Element) -> Result<Confirmation<S>, Error> {
+        self.confirm(
+            &self.keys[S::CONFIRMATION_KEY_LEN..2 * S::CONFIRMATION_KEY_LEN],
+            share_p,
+        )
+    }
+
+    fn confirm(&self, key: &[u8], share: &Element) -> Result<Confirmation<S>, Error> {
+        let mut mac = [0u8; MAX_MAC_LEN];
+        S::mac(key, &share.encode_uncompressed(), &mut mac[..S::MAC_LEN])?;
+        Ok(Confirmation {
+            mac,
+            _suite: PhantomData,
+        })
+    }
+}
+
+impl<S: Ciphersuite> Drop for ConfirmationKeys<S> {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.keys);
+    }
+}
+
+/// `w = ws mod p`, where `ws` is half of the PBKDF output.
+fn reduce(ws: &[u8]) -> Result<Scalar, Error> {
+    if !(MIN_WS_LEN..=MAX_WS_LEN).contains(&ws.len()) {
+        return Err(Error::WrongLength);
+    }
+    let mut wide = [0u8; MAX_WS_LEN];
+    wide[MAX_WS_LEN - ws.len()..].copy_from_slice(ws);
+    let w = Scalar::from_wide_bytes(&wide);
+    low::zeroise(&mut wide);
+    match w.is_zero() {
+        true => Err(Error::OutOfRange),
+        false => Ok(w),
+    }
+}
+
+fn hmac<H: Hash>(key: &[u8], message: &[u8], out: &mut [u8]) -> Result<(), Error> {
+    let mut hmac = Hmac::<H>::new(key);
+    hmac.update(message);
+    out.copy_from_slice(hmac.finish().as_ref());
+    Ok(())
+}
+
+fn cmac(key: &[u8], message: &[u8], out: &mut [u8]) -> Result<(), Error> {
+    let mut cmac = Cmac::new(key)?;
+    cmac.update(message);
+    out.copy_from_slice(&cmac.finish());
+    Ok(())
+}
+
+fn point_m() -> Element {
+    // cannot fail: this is a valid encoding
+    Element::decode(&POINT_M).unwrap()
+}
+
+fn point_n() -> Element {
+    // cannot fail: this is a valid encoding
+    Element::decode(&POINT_N).unwrap()
+}
+
+/// `M`, in SEC1 compressed form.
+const POINT_M: [u8; 33] = [
+    0x02, 0x88, 0x6e, 0x2f, 0x97, 0xac, 0xe4, 0x6e, 0x55, 0xba, 0x9d, 0xd7, 0x24, 0x25, 0x79, 0xf2,
+    0x99, 0x3b, 0x64, 0xe1, 0x6e, 0xf3, 0xdc, 0xab, 0x95, 0xaf, 0xd4, 0x97, 0x33, 0x3d, 0x8f, 0xa1,
+    0x2f,
+];
+
+/// `N`, in SEC1 compressed form.
+const POINT_N: [u8; 33] = [
+    0x03, 0xd8, 0xbb, 0xd6, 0xc6, 0x39, 0xc6, 0x29, 0x37, 0xb0, 0x4d, 0x99, 0x7f, 0x38, 0xc3, 0x77,
+    0x07, 0x19, 0xc6, 0x29, 0xd7, 0x01, 0x4d, 0x49, 0xa2, 0x4b, 0x4f, 0x98, 0xba, 0xa1, 0x29, 0x2b,
+    0x49,
+];
+
+/// `w0s` and `w1s` must have at least 64 bits more than the group order.
+const MIN_WS_LEN: usize = 40;
+const MAX_WS_LEN: usize = 48;
+
+const MAX_MAC_LEN: usize = 64;
+const MAX_CONFIRMATION_KEY_LEN: usize = 64;
+const MAX_SHARED_KEY_LEN: usize = 64;
+
+mod private {
+    use crate::Error;
+
+    pub trait Sealed {
+        /// Length of each of `K_confirmP` and `K_confirmV`.
+        const CONFIRMATION_KEY_LEN: usize;
+
+        /// Whether `K_main` is split into `Ka || Ke`, as in earlier drafts.
+        const SPLIT_K_MAIN: bool;
+
+        /// `MAC(key, message)`, into `out`.
+        fn mac(key: &[u8], message: &[u8], out: &mut [u8]) -> Result<(), Error>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const W0: &str = "bb8e1bbcf3c48f62c08db243652ae55d3e5586053fca77102994f23ad95491b3";
+    const W1: &str = "7e945f34d78785b8a3ef44d0df5a1a97d6b3b460409a345ca7830387a74b1dba";
+    const X: &str = "d1232c8e8693d02368976c174e2088851b8365d0d79a9eee709c6a05a2fad539";
+    const Y: &str = "717a72348a182085109c8d3917d6c43d59b224dc6a7fc4f0483232fa6516d8b3";
+
+    fn prover_key() -> ProverKey {
+        ProverKey::from_bytes(&unhex(&format!("{W0}{W1}"))).unwrap()
+    }
+
+    /// Run an exchange with the fixed inputs, returning `(confirmP, confirmV, K_shared)`.
+    fn exchange<S: Ciphersuite>(context: &[u8]) -> (String, String, String) {
+        let key = prover_key();
+        let record = key.registration_record();
+
+        let x = unhex(X);
+        let (prover, share_p) =
+            Prover::<S>::start_with_rng(&key, &mut SliceRandomSource(&x)).unwrap();
+        assert_eq!(
+            hex(&share_p.as_bytes()),
+            "04ef3bd051bf78a2234ec0df197f7828060fe9856503579bb1733009042c15c0c1de127727f418b5966afadfdd95a6e4591d171056b333dab97a79c7193e341727"
+        );
+
+        let y = unhex(Y);
+        let (verifier, share_v, confirm_v) = Verifier::<S>::start_with_rng(
+            &record,
+            &share_p,
+            context,
+            b"client",
+            b"server",
+            &mut SliceRandomSource(&y),
+        )
+        .unwrap();
+        assert_eq!(
+            hex(&share_v.as_bytes()),
+            "04c0f65da0d11927bdf5d560c69e1d7d939a05b0e88291887d679fcadea75810fb5cc1ca7494db39e82ff2f50665255d76173e09986ab46742c798a9a68437b048"
+        );
+
+        let (confirm_p, prover_shared) = prover
+            .finish(&share_v, &confirm_v, context, b"client", b"server")
+            .unwrap();
+        let verifier_shared = verifier.finish(&confirm_p).unwrap();
+        assert_eq!(prover_shared.as_bytes(), verifier_shared.as_bytes());
+
+        (
+            hex(confirm_p.as_bytes()),
+            hex(confirm_v.as_bytes()),
+            hex(verifier_shared.as_bytes()),
+        )
+    }
+
+    #[test]
+    fn rfc9383_p256_sha256_hmac_sha256() {
+        // RFC9383 appendix C
+        let record = prover_key().registration_record();
+        assert_eq!(
+            hex(&record.as_bytes()),
+            format!(
+                "{W0}04eb7c9db3d9a9eb1f8adab81b5794c1f13ae3e225efbe91ea487425854c7fc00f00bfedcbd09b2400142d40a14f2064ef31dfaa903b91d1faea7093d835966efd"
+            )
+        );
+
+        let (confirm_p, confirm_v, shared) = exchange::<P256Sha256HmacSha256>(
+            b"SPAKE2+-P256-SHA256-HKDF-SHA256-HMAC-SHA256 Test Vectors",
+        );
+        assert_eq!(
+            confirm_p,
+            "926cc713504b9b4d76c9162ded04b5493e89109f6d89462cd33adc46fda27527"
+        );
+        assert_eq!(
+            confirm_v,
+            "9747bcc4f8fe9f63defee53ac9b07876d907d55047e6ff2def2e7529089d3e68"
+        );
+        assert_eq!(
+            shared,
+            "0c5f8ccd1413423a54f6c1fb26ff01534a87f893779c6e68666d772bfd91f3e7"
+        );
+    }
+
+    #[test]
+    fn p256_sha512_hmac_sha512() {
+        // from an independent implementation
+        let (confirm_p, confirm_v, shared) = exchange::<P256Sha512HmacSha512>(
+            b"SPAKE2+-P256-SHA512-HKDF-SHA512-HMAC-SHA512 Test Vectors",
+        );
+        assert_eq!(
+            confirm_p,
+            "e1c24d59734541506b89cb0e95815a7ba8c4c16066cfa7be7042fd9cd0c9765fd9fa284f3d88717e332a99d03828243ce13544d8d8a9a15800292f668183acaf"
+        );
+        assert_eq!(
+            confirm_v,
+            "5e702ba766456f758fff5aa6f1e34328b022a74b5b1f1542a167b021093d0034647bf4d8da484916d9ae1691618ef8bc4b270988547d1ebd8a5f69fd5a3afcde"
+        );
+        assert_eq!(
+            shared,
+            "74e2fe8656bf9a2f4862b61d2a18085f2c0e2b029f7e685a1eda3568663c1418726b2e93d47de36fb2fbf67dd9877bd1e8d07c12f7df38487605ca48fe3dbea7"
+        );
+    }
+
+    #[test]
+    fn matter() {
+        // from an independent implementation of the draft-02 key schedule
+        let (confirm_p, confirm_v, shared) =
+            exchange::<Matter>(b"SPAKE2+-P256-SHA256-HKDF-SHA256-HMAC-SHA256 Test Vectors");
+        assert_eq!(
+            confirm_p,
+            "b6b09119a2f04b889532bec49c599330f5aa8c1f8a8553cc96ef9e2c3f2aa735"
+        );
+        assert_eq!(
+            confirm_v,
+            "5d98987a90a83f91aead1a4e207e0adb5626102ff035a70c221078cbe3f1dcab"
+        );
+        assert_eq!(shared, "89b56cd11542f53d3576fb6c2a438a29");
+    }
+
+    #[test]
+    fn cmac_suites() {
+        let (confirm_p, confirm_v, shared) = exchange::<P256Sha256CmacAes128>(b"test");
+        assert_eq!(confirm_p.len(), 32);
+        assert_eq!(confirm_v.len(), 32);
+        assert_eq!(shared.len(), 64);
+
+        let (confirm_p, confirm_v, shared) = exchange::<P256Sha512CmacAes128>(b"test");
+        assert_eq!(confirm_p.len(), 32);
+        assert_eq!(confirm_v.len(), 32);
+        assert_eq!(shared.len(), 128);
+    }
+
+    #[test]
+    fn wrong_password() {
+        let record = prover_key().registration_record();
+        let wrong = ProverKey::from_pbkdf_output(&[0x11; 80]).unwrap();
+
+        let (prover, share_p) = Prover::<P256Sha256HmacSha256>::start(&wrong).unwrap();
+        let (verifier, share_v, confirm_v) =
+            Verifier::start(&record, &share_p, b"ctx", b"", b"").unwrap();
+        assert_eq!(
+            prover.finish(&share_v, &confirm_v, b"ctx", b"", b"").err(),
+            Some(Error::BadSignature)
+        );
+        let forged = Confirmation::from_bytes(&[0u8; 32]).unwrap();
+        assert_eq!(verifier.finish(&forged).err(), Some(Error::BadSignature));
+    }
+
+    #[test]
+    fn mismatched_context() {
+        let key = prover_key();
+        let record = key.registration_record();
+        let (prover, share_p) = Prover::<Matter>::start(&key).unwrap();
+        let (_, share_v, confirm_v) = Verifier::start(&record, &share_p, b"ctx", b"", b"").unwrap();
+        assert_eq!(
+            prover
+                .finish(&share_v, &confirm_v, b"other ctx", b"", b"")
+                .err(),
+            Some(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn invalid_shares() {
+        let key = prover_key();
+        let record = key.registration_record();
+
+        // shareP = w0*M unblinds to the identity
+        let share_p = Share(point_m().mul(&key.w0));
+        assert_eq!(
+            Verifier::<Matter>::start(&record, &share_p, b"", b"", b"").err(),
+            Some(Error::NotOnCurve)
+        );
+
+        assert!(Share::from_bytes(&[0u8; 65]).is_err());
+        assert!(Share::from_bytes(&[0x04; 64]).is_err());
+        assert!(Confirmation::<Matter>::from_bytes(&[0u8; 16]).is_err());
+    }
+
+    #[test]
+    fn encodings() {
+        let key = prover_key();
+        assert_eq!(
+            ProverKey::from_bytes(&key.as_bytes()).unwrap().as_bytes(),
+            key.as_bytes()
+        );
+        let record = key.registration_record();
+        assert_eq!(
+            RegistrationRecord::from_bytes(&record.as_bytes())
+                .unwrap()
+                .as_bytes(),
+            record.as_bytes()
+        );
+        assert!(RegistrationRecord::from_bytes(&[0u8; 96]).is_err());
+        assert!(ProverKey::from_bytes(&[0xff; 64]).is_err());
+
+        assert_eq!(
+            ProverKey::from_pbkdf_output(&[0u8; 79]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            ProverKey::from_pbkdf_output(&[0u8; 98]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            ProverKey::from_pbkdf_output(&[0u8; 80]).err(),
+            Some(Error::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,513 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::asn1::{self, Type, oid, pkix};
+use super::curve::{Curve, MAX_UNCOMPRESSED_PUBLIC_KEY_LEN, P256, P384, PublicKey};
+use super::pem;
+use crate::error::{Error, KeyFormatError};
+use crate::low::Entry;
+use crate::mid::mldsa::{ml_dsa_44, ml_dsa_65, ml_dsa_87};
+use crate::mid::mlkem::{ml_kem_512, ml_kem_768, ml_kem_1024};
+use crate::mid::slhdsa::{
+    slh_dsa_sha2_128f, slh_dsa_sha2_128s, slh_dsa_sha2_192f, slh_dsa_sha2_192s, slh_dsa_sha2_256f,
+    slh_dsa_sha2_256s, slh_dsa_shake_128f, slh_dsa_shake_128s, slh_dsa_shake_192f,
+    slh_dsa_shake_192s, slh_dsa_shake_256f, slh_dsa_shake_256s,
+};
+use crate::mid::{ed25519, p256, p384, x25519};
+
+/// Helper for decoding `SubjectPublicKeyInfo` encodings.
+///
+/// This decodes the given slice as a whole `SubjectPublicKeyInfo` (from
+/// [RFC5280](https://datatracker.ietf.org/doc/html/rfc5280#section-4.1)),
+/// and then:
+///
+/// - ensures the key algorithm is `algorithm`,
+/// - ensures the parameters are exactly `parameters` -- so absent parameters
+///   are not interchangeable with `NULL`.
+///
+/// Then returns the slice that covers the `subjectPublicKey` `BIT STRING`
+/// contents, that can be decoded by the caller.
+pub(crate) fn decode_spki<'a>(
+    slice: &'a [u8],
+    algorithm: &asn1::ObjectId,
+    parameters: Option<asn1::Any<'_>>,
+) -> Result<&'a [u8], Error> {
+    let spki = pkix::SubjectPublicKeyInfo::from_bytes(slice).map_err(Error::Asn1Error)?;
+
+    if spki.algorithm.algorithm != *algorithm {
+        return Err(KeyFormatError::MismatchedSpkiAlgorithm.into());
+    }
+
+    if spki.algorithm.parameters != parameters {
+        return Err(KeyFormatError::MismatchedSpkiParameters.into());
+    }
+
+    Ok(spki.subjectPublicKey.as_octets())
+}
+
+/// Helper for producing `SubjectPublicKeyInfo` encodings.
+pub(crate) fn encode_spki<'a>(
+    public_key: &[u8],
+    algorithm: asn1::ObjectId,
+    parameters: Option<asn1::Any<'_>>,
+    output: &'a mut [u8],
+) -> Result<&'a [u8], Error> {
+    let len = pkix::SubjectPublicKeyInfo {
+        algorithm: pkix::AlgorithmIdentifier {
+            algorithm,
+            parameters,
+        },
+        subjectPublicKey: asn1::BitString::new(public_key),
+    }
+    .encode(&mut asn1::Encoder::new(output))
+    .map_err(Error::Asn1Error)?;
+
+    output.get(..len).ok_or(Error::WrongLength)
+}
+
+/// Helper for decoding PEM-armoured `SubjectPublicKeyInfo` encodings.
+///
+/// The first `PUBLIC KEY` document is decoded, and then given to `decode_der`.
+pub(crate) fn decode_spki_pem<T>(
+    pem: &str,
+    decode_der: impl FnOnce(&[u8]) -> Result<T, Error>,
+) -> Result<T, Error> {
+    pem::decode_first(pem, &[pem::PUBLIC_KEY], |_, der| decode_der(der))
+}
+
+/// Helper for producing PEM-armoured `SubjectPublicKeyInfo` encodings.
+///
+/// `encode_der` writes the DER encoding into the provided buffer,
+/// which is of `max_len` bytes.
+pub(crate) fn encode_spki_pem(
+    max_len: usize,
+    encode_der: impl FnOnce(&mut [u8]) -> Result<&[u8], Error>,
+) -> Result<String, Error> {
+    let mut buffer = vec![0u8; max_len];
+    encode_der(&mut buffer).map(|der| pem::encode(pem::PUBLIC_KEY, der))
+}
+
+/// Decodes an `id-ecPublicKey` `SubjectPublicKeyInfo` on curve `C`.
+///
+/// This is defined in [RFC5480](https://datatracker.ietf.org/doc/html/rfc5480#section-2).
+/// The parameters must be the `namedCurve` for `C`, and the point must be
+/// uncompressed.
+pub(crate) fn decode_ec_spki<C: Curve>(bytes: &[u8]) -> Result<C::PublicKey, Error> {
+    let point = decode_spki(
+        bytes,
+        &oid::id_ecPublicKey,
+        Some(asn1::Any::ObjectId(C::oid())),
+    )?;
+    C::PublicKey::from_x962_uncompressed(point)
+}
+
+/// Encodes a public key on curve `C` as an `id-ecPublicKey` `SubjectPublicKeyInfo`.
+pub(crate) fn encode_ec_spki<'a, C: Curve>(
+    public_key: &C::PublicKey,
+    output: &'a mut [u8],
+) -> Result<&'a [u8], Error> {
+    let mut point = [0u8; MAX_UNCOMPRESSED_PUBLIC_KEY_LEN];
+    let point = public_key.encode_uncompressed(&mut point)?;
+    encode_spki(
+        point,
+        oid::id_ecPublicKey.clone(),
+        Some(asn1::Any::ObjectId(C::oid())),
+        output,
+    )
+}
+
+impl x25519::PublicKey {
+    /// Decodes an X25519 public key from X.509 `SubjectPublicKeyInfo` DER format.
+    ///
+    /// This format is defined in
+    /// [RFC8410](https://datatracker.ietf.org/doc/html/rfc8410#section-4).
+    /// The algorithm parameters must be absent.
+    pub fn from_spki_der(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        Self::try_from_slice(decode_spki(bytes, &oid::id_X25519, None)?)
+    }
+
+    /// Encodes an X25519 public key to X.509 `SubjectPublicKeyInfo` DER format.
+    ///
+    /// The encoding is written to the start of `output`, and the used span is
+    /// returned.  The encoding is 44 bytes long.
+    pub fn to_spki_der<'a>(&self, output: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        let _entry = Entry::new_public();
+        encode_spki(&self.as_bytes(), oid::id_X25519.clone(), None, output)
+    }
+
+    /// Decodes an X25519 public key from PEM format.
+    ///
+    /// The first `PUBLIC KEY` document is used; see [`Self::from_spki_der()`].
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        decode_spki_pem(pem, Self::from_spki_der)
+    }
+
+    /// Encodes an X25519 public key to PEM format, as a `PUBLIC KEY` document.
+    pub fn to_pem(&self) -> Result<String, Error> {
+        encode_spki_pem(SPKI_CURVE_LEN, |buf| self.to_spki_der(buf))
+    }
+}
+
+impl ed25519::VerifyingKey {
+    /// Decodes an Ed25519 public key from X.509 `SubjectPublicKeyInfo` DER format.
+    ///
+    /// This format is defined in
+    /// [RFC8410](https://datatracker.ietf.org/doc/html/rfc8410#section-4).
+    /// The algorithm parameters must be absent.
+    pub fn from_spki_der(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        Self::from_bytes(decode_spki(bytes, &oid::id_Ed25519, None)?)
+    }
+
+    /// Encodes an Ed25519 public key to X.509 `SubjectPublicKeyInfo` DER format.
+    ///
+    /// The encoding is written to the start of `output`, and the used span is
+    /// returned.  The encoding is 44 bytes long.
+    pub fn to_spki_der<'a>(&self, output: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        let _entry = Entry::new_public();
+        encode_spki(self.as_bytes(), oid::id_Ed25519.clone(), None, output)
+    }
+
+    /// Decodes an Ed25519 public key from PEM format.
+    ///
+    /// The first `PUBLIC KEY` document is used; see [`Self::from_spki_der()`].
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        decode_spki_pem(pem, Self::from_spki_der)
+    }
+
+    /// Encodes an Ed25519 public key to PEM format, as a `PUBLIC KEY` document.
+    pub fn to_pem(&self) -> Result<String, Error> {
+        encode_spki_pem(SPKI_CURVE_LEN, |buf| self.to_spki_der(buf))
+    }
+}
+
+/// The length of an RFC8410 `SubjectPublicKeyInfo`.
+const SPKI_CURVE_LEN: usize = 44;
+
+/// The most any `SubjectPublicKeyInfo` with absent parameters adds to
+/// a public key of less than 64KB.
+const MAX_SPKI_OVERHEAD: usize = 22;
+
+macro_rules! ec_public_key {
+    ($module:ident, $curve:ident, $len:literal, $name:literal) => {
+        impl $module::PublicKey {
+            #[doc = concat!("Decodes a ", $name, " public key from X.509 `SubjectPublicKeyInfo` DER format.")]
+            ///
+            /// This format is defined in
+            /// [RFC5480](https://datatracker.ietf.org/doc/html/rfc5480#section-2).
+            /// The algorithm must be `id-ecPublicKey`, the parameters must name
+            /// this curve, and the point must be uncompressed.
+            pub fn from_spki_der(bytes: &[u8]) -> Result<Self, Error> {
+                let _entry = Entry::new_public();
+                decode_ec_spki::<$curve>(bytes)
+            }
+
+            #[doc = concat!("Encodes a ", $name, " public key to X.509 `SubjectPublicKeyInfo` DER format.")]
+            ///
+            /// The encoding is written to the start of `output`, and the used span is
+            #[doc = concat!("returned.  The encoding is ", $len, " bytes long.")]
+            pub fn to_spki_der<'a>(&self, output: &'a mut [u8]) -> Result<&'a [u8], Error> {
+                let _entry = Entry::new_public();
+                encode_ec_spki::<$curve>(self, output)
+            }
+
+            #[doc = concat!("Decodes a ", $name, " public key from PEM format.")]
+            ///
+            /// The first `PUBLIC KEY` document is used; see [`Self::from_spki_der()`].
+            pub fn from_pem(pem: &str) -> Result<Self, Error> {
+                decode_spki_pem(pem, Self::from_spki_der)
+            }
+
+            #[doc = concat!("Encodes a ", $name, " public key to PEM format, as a `PUBLIC KEY` document.")]
+            pub fn to_pem(&self) -> Result<String, Error> {
+                encode_spki_pem($len, |buf| self.to_spki_der(buf))
+            }
+        }
+    };
+}
+
+ec_public_key!(p256, P256, 91, "P-256");
+ec_public_key!(p384, P384, 120, "P-384");
+
+macro_rules! raw_public_key {
+    ($module:ident, $key:ident, $oid:ident, $name:literal, $rfc:literal) => {
+        impl $module::$key {
+            #[doc = concat!("Decodes an ", $name, " public key from X.509 `SubjectPublicKeyInfo` DER format.")]
+            ///
+            /// This format is defined in
+            #[doc = concat!("[RFC", $rfc, "](https://datatracker.ietf.org/doc/html/rfc", $rfc, ").")]
+            /// The algorithm parameters must be absent.
+            pub fn from_spki_der(bytes: &[u8]) -> Result<Self, Error> {
+                let _entry = Entry::new_public();
+                Self::from_bytes(decode_spki(bytes, &oid::$oid, None)?)
+            }
+
+            #[doc = concat!("Encodes an ", $name, " public key to X.509 `SubjectPublicKeyInfo` DER format.")]
+            ///
+            /// The encoding is written to the start of `output`, and the used span is
+            /// returned.  The encoding is at most 22 bytes longer than the public key.
+            pub fn to_spki_der<'a>(&self, output: &'a mut [u8]) -> Result<&'a [u8], Error> {
+                let _entry = Entry::new_public();
+                encode_spki(self.as_bytes(), oid::$oid.clone(), None, output)
+            }
+
+            #[doc = concat!("Decodes an ", $name, " public key from PEM format.")]
+            ///
+            /// The first `PUBLIC KEY` document is used; see [`Self::from_spki_der()`].
+            pub fn from_pem(pem: &str) -> Result<Self, Error> {
+                decode_spki_pem(pem, Self::from_spki_der)
+            }
+
+            #[doc = concat!("Encodes an ", $name, " public key to PEM format, as a `PUBLIC KEY` document.")]
+            pub fn to_pem(&self) -> Result<String, Error> {
+                encode_spki_pem(self.as_bytes().len() + MAX_SPKI_OVERHEAD, |buf| {
+                    self.to_spki_der(buf)
+                })
+            }
+        }
+    };
+}
+
+raw_public_key!(ml_dsa_44, VerifyingKey, id_ml_dsa_44, "ML-DSA-44", "9881");
+raw_public_key!(ml_dsa_65, VerifyingKey, id_ml_dsa_65, "ML-DSA-65", "9881");
+raw_public_key!(ml_dsa_87, VerifyingKey, id_ml_dsa_87, "ML-DSA-87", "9881");
+raw_public_key!(
+    ml_kem_512,
+    EncapsulationKey,
+    id_ml_kem_512,
+    "ML-KEM-512",
+    "9935"
+);
+raw_public_key!(
+    ml_kem_768,
+    EncapsulationKey,
+    id_ml_kem_768,
+    "ML-KEM-768",
+    "9935"
+);
+raw_public_key!(
+    ml_kem_1024,
+    EncapsulationKey,
+    id_ml_kem_1024,
+    "ML-KEM-1024",
+    "9935"
+);
+raw_public_key!(
+    slh_dsa_sha2_128s,
+    VerifyingKey,
+    id_slh_dsa_sha2_128s,
+    "SLH-DSA-SHA2-128s",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_sha2_128f,
+    VerifyingKey,
+    id_slh_dsa_sha2_128f,
+    "SLH-DSA-SHA2-128f",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_sha2_192s,
+    VerifyingKey,
+    id_slh_dsa_sha2_192s,
+    "SLH-DSA-SHA2-192s",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_sha2_192f,
+    VerifyingKey,
+    id_slh_dsa_sha2_192f,
+    "SLH-DSA-SHA2-192f",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_sha2_256s,
+    VerifyingKey,
+    id_slh_dsa_sha2_256s,
+    "SLH-DSA-SHA2-256s",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_sha2_256f,
+    VerifyingKey,
+    id_slh_dsa_sha2_256f,
+    "SLH-DSA-SHA2-256f",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_shake_128s,
+    VerifyingKey,
+    id_slh_dsa_shake_128s,
+    "SLH-DSA-SHAKE-128s",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_shake_128f,
+    VerifyingKey,
+    id_slh_dsa_shake_128f,
+    "SLH-DSA-SHAKE-128f",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_shake_192s,
+    VerifyingKey,
+    id_slh_dsa_shake_192s,
+    "SLH-DSA-SHAKE-192s",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_shake_192f,
+    VerifyingKey,
+    id_slh_dsa_shake_192f,
+    "SLH-DSA-SHAKE-192f",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_shake_256s,
+    VerifyingKey,
+    id_slh_dsa_shake_256s,
+    "SLH-DSA-SHAKE-256s",
+    "9909"
+);
+raw_public_key!(
+    slh_dsa_shake_256f,
+    VerifyingKey,
+    id_slh_dsa_shake_256f,
+    "SLH-DSA-SHAKE-256f",
+    "9909"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These keys were generated by OpenSSL
+    macro_rules! round_trip {
+        ($name:ident, $module:ident, $key:ident, $file:literal) => {
+            #[test]
+            fn $name() {
+                let spki = include_bytes!(concat!("spki/", $file));
+                let key = $module::$key::from_spki_der(spki).unwrap();
+                let mut buf = [0u8; 4096];
+                assert_eq!(key.to_spki_der(&mut buf).unwrap(), spki);
+                assert_eq!(
+                    key.to_spki_der(&mut buf[..spki.len() - 1]).err(),
+                    Some(Error::Asn1Error(asn1::Error::UnexpectedEof))
+                );
+
+                let pem = key.to_pem().unwrap();
+                assert_eq!(pem, pem::encode(pem::PUBLIC_KEY, spki));
+                let key = $module::$key::from_pem(&pem).unwrap();
+                assert_eq!(key.to_spki_der(&mut buf).unwrap(), spki);
+            }
+        };
+    }
+
+    round_trip!(x25519_key, x25519, PublicKey, "x25519.spki.der");
+    round_trip!(ed25519_key, ed25519, VerifyingKey, "ed25519.spki.der");
+    round_trip!(p256_key, p256, PublicKey, "secp256r1.spki.der");
+    round_trip!(p384_key, p384, PublicKey, "secp384r1.spki.der");
+    round_trip!(ml_dsa_44_key, ml_dsa_44, VerifyingKey, "ml-dsa-44.spki.der");
+    round_trip!(ml_dsa_65_key, ml_dsa_65, VerifyingKey, "ml-dsa-65.spki.der");
+    round_trip!(ml_dsa_87_key, ml_dsa_87, VerifyingKey, "ml-dsa-87.spki.der");
+    round_trip!(
+        ml_kem_512_key,
+        ml_kem_512,
+        EncapsulationKey,
+        "ml-kem-512.spki.der"
+    );
+    round_trip!(
+        ml_kem_768_key,
+        ml_kem_768,
+        EncapsulationKey,
+        "ml-kem-768.spki.der"
+    );
+    round_trip!(
+        ml_kem_1024_key,
+        ml_kem_1024,
+        EncapsulationKey,
+        "ml-kem-1024.spki.der"
+    );
+    round_trip!(
+        slh_dsa_sha2_128s_key,
+        slh_dsa_sha2_128s,
+        VerifyingKey,
+        "slh-dsa-sha2-128s.spki.der"
+    );
+    round_trip!(
+        slh_dsa_shake_256f_key,
+        slh_dsa_shake_256f,
+        VerifyingKey,
+        "slh-dsa-shake-256f.spki.der"
+    );
+
+    #[test]
+    fn mismatched_algorithm() {
+        assert_eq!(
+            x25519::PublicKey::from_spki_der(include_bytes!("spki/ed25519.spki.der")).err(),
+            Some(KeyFormatError::MismatchedSpkiAlgorithm.into())
+        );
+        assert_eq!(
+            ml_dsa_65::VerifyingKey::from_spki_der(include_bytes!("spki/ml-dsa-44.spki.der")).err(),
+            Some(KeyFormatError::MismatchedSpkiAlgorithm.into())
+        );
+        assert_eq!(
+            p256::PublicKey::from_spki_der(include_bytes!("spki/rsa2048.spki.der")).err(),
+            Some(KeyFormatError::MismatchedSpkiAlgorithm.into())
+        );
+    }
+
+    #[test]
+    fn strict_parameters() {
+        let key = [0x09; 32];
+        let mut buf = [0u8; 64];
+
+        // NULL parameters are not the same as absent parameters
+        let spki = encode_spki(
+            &key,
+            oid::id_X25519.clone(),
+            Some(asn1::Any::Null(asn1::Null)),
+            &mut buf,
+        )
+        .unwrap();
+        assert_eq!(
+            x25519::PublicKey::from_spki_der(spki).err(),
+            Some(KeyFormatError::MismatchedSpkiParameters.into())
+        );
+
+        // the curve must match
+        assert_eq!(
+            p384::PublicKey::from_spki_der(include_bytes!("spki/secp256r1.spki.der")).err(),
+            Some(KeyFormatError::MismatchedSpkiParameters.into())
+        );
+
+        // and must be present
+        let p256 = p256::PublicKey::from_spki_der(include_bytes!("spki/secp256r1.spki.der"))
+            .unwrap()
+            .as_bytes_uncompressed();
+        let mut buf = [0u8; 128];
+        let spki = encode_spki(&p256, oid::id_ecPublicKey.clone(), None, &mut buf).unwrap();
+        assert_eq!(
+            p256::PublicKey::from_spki_der(spki).err(),
+            Some(KeyFormatError::MismatchedSpkiParameters.into())
+        );
+    }
+
+    #[test]
+    fn invalid_keys() {
+        let mut buf = [0u8; 64];
+        let spki = encode_spki(&[0x09; 31], oid::id_X25519.clone(), None, &mut buf).unwrap();
+        assert_eq!(
+            x25519::PublicKey::from_spki_der(spki).err(),
+            Some(Error::WrongLength)
+        );
+
+        let mut spki = include_bytes!("spki/ed25519.spki.der").to_vec();
+        spki.push(0x00);
+        assert_eq!(
+            ed25519::VerifyingKey::from_spki_der(&spki).err(),
+            Some(Error::Asn1Error(asn1::Error::UnexpectedTrailingData))
+        );
+    }
+}
@@ -0,0 +1,763 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! SRP-6a, as specified in [RFC5054](https://datatracker.ietf.org/doc/html/rfc5054)
+//! and [RFC2945](https://datatracker.ietf.org/doc/html/rfc2945).
+//!
+//! This exists for compatibility with existing deployments.  New designs
+//! should prefer OPAQUE.
+//!
+//! RFC5054 only specifies SHA-1, which this crate does not support; instead
+//! the protocol is generic over the hash function.  The computations are:
+//!
+//! - `k = H(N | PAD(g))`
+//! - `x = H(s | H(I | ":" | P))`
+//! - `u = H(PAD(A) | PAD(B))`
+//! - `K = H(PAD(S))`
+//! - `M1 = H(H(N) XOR H(g) | H(I) | s | PAD(A) | PAD(B) | K)`
+//! - `M2 = H(PAD(A) | M1 | K)`
+//!
+//! where `PAD` left-pads a value to the length of `N`.
+
+use core::marker::PhantomData;
+
+use super::hash::{Digest, HashContext, HashOutput};
+use crate::Error;
+use crate::low::{self, Entry};
+use crate::mid::modp::{Element, Exponent, PrimeModulus};
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+mod groups;
+
+/// The groups from RFC5054 appendix A.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Group {
+    /// The 1024-bit group.
+    Srp1024,
+    /// The 1536-bit group.
+    Srp1536,
+    /// The 2048-bit group.
+    Srp2048,
+    /// The 3072-bit group.
+    Srp3072,
+    /// The 4096-bit group.
+    Srp4096,
+    /// The 6144-bit group.
+    Srp6144,
+    /// The 8192-bit group.
+    Srp8192,
+}
+
+impl Group {
+    /// Length of `N`, and therefore public values and verifiers, in bytes.
+    pub fn len_bytes(&self) -> usize {
+        self.prime().len()
+    }
+
+    fn prime(&self) -> &'static [u8] {
+        match self {
+            Self::Srp1024 => &groups::PRIME_1024,
+            Self::Srp1536 => &groups::PRIME_1536,
+            Self::Srp2048 => &groups::PRIME_2048,
+            Self::Srp3072 => &groups::PRIME_3072,
+            Self::Srp4096 => &groups::PRIME_4096,
+            Self::Srp6144 => &groups::PRIME_6144,
+            Self::Srp8192 => &groups::PRIME_8192,
+        }
+    }
+
+    fn generator(&self) -> u8 {
+        match self {
+            Self::Srp1024 | Self::Srp1536 | Self::Srp2048 => 2,
+            Self::Srp3072 | Self::Srp4096 | Self::Srp6144 => 5,
+            Self::Srp8192 => 19,
+        }
+    }
+}
+
+/// The password verifier `v`, stored by the server.
+pub struct PasswordVerifier(Value);
+
+impl PasswordVerifier {
+    /// Compute the verifier for `username` and `password`.
+    ///
+    /// `salt` should be randomly generated, and stored alongside the
+    /// verifier.
+    pub fn new<H: Digest>(
+        group: Group,
+        username: &[u8],
+        password: &[u8],
+        salt: &[u8],
+    ) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let mut x = private_key::<H>(username, password, salt);
+        let r = Self::from_private_key(group, x.as_ref());
+        low::zeroise(x.as_mut());
+        r
+    }
+
+    /// Compute the verifier for the private key `x`.
+    ///
+    /// This supports deployments which compute `x` from the password
+    /// other than as specified in RFC5054.
+    pub fn from_private_key(group: Group, x: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let params = Params::new(group)?;
+        let v = params.modulus.exp(&params.g, &Exponent::from_bytes(x)?);
+        Ok(Self(params.encode(&v)))
+    }
+
+    /// Decode a verifier previously encoded with [`PasswordVerifier::as_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Value::new(bytes).map(Self)
+    }
+
+    /// Encode this verifier.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl Drop for PasswordVerifier {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.0.bytes);
+    }
+}
+
+/// A public value, `A` or `B`.
+pub struct PublicValue(Value);
+
+impl PublicValue {
+    /// Decode a public value.
+    ///
+    /// This is checked against the group when used.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Value::new(bytes).map(Self)
+    }
+
+    /// Encode this public value.
+    ///
+    /// This is the length of `N`.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+/// A key confirmation proof, `M1` or `M2`.
+pub struct Proof<H: Digest> {
+    output: HashOutput,
+    _hash: PhantomData<H>,
+}
+
+impl<H: Digest> Proof<H> {
+    /// Decode a proof.  This must be the output length of `H`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != H::OUTPUT_LEN {
+            return Err(Error::WrongLength);
+        }
+        let mut output = H::zeroed_output();
+        output.as_mut().copy_from_slice(bytes);
+        Ok(Self {
+            output,
+            _hash: PhantomData,
+        })
+    }
+
+    /// Encode this proof.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.output.as_ref()
+    }
+
+    fn verify(&self, expected: &Self) -> Result<(), Error> {
+        match low::ct_equal(self.as_bytes(), expected.as_bytes()) {
+            true => Ok(()),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// The session key `K`, resulting from a successful exchange.
+pub struct SessionKey(HashOutput);
+
+impl SessionKey {
+    /// Extract the bytes of this key.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Drop for SessionKey {
+    fn drop(&mut self) {
+        low::zeroise(self.0.as_mut());
+    }
+}
+
+/// The client's state, before it has received `B`.
+pub struct Client<H: Digest> {
+    group: Group,
+    a: [u8; SECRET_LEN],
+    public: PublicValue,
+    _hash: PhantomData<H>,
+}
+
+impl<H: Digest> Client<H> {
+    /// Start an exchange.
+    ///
+    /// The returned `A` is sent to the server.
+    pub fn start(group: Group) -> Result<(Self, PublicValue), Error> {
+        Self::start_with_rng(group, &mut SystemRandom)
+    }
+
+    /// Start an exchange, using `rng` to generate the secret exponent.
+    ///
+    /// Otherwise, as [`Self::start()`].
+    pub fn start_with_rng(
+        group: Group,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, PublicValue), Error> {
+        let _entry = Entry::new_secret();
+        let params = Params::new(group)?;
+        let mut a = [0u8; SECRET_LEN];
+        rng.fill(&mut a)?;
+        let public = params.encode(&params.modulus.exp(&params.g, &Exponent::from_bytes(&a)?));
+        let client = Self {
+            group,
+            a,
+            public: PublicValue(public.clone()),
+            _hash: PhantomData,
+        };
+        Ok((client, PublicValue(public)))
+    }
+
+    /// Process the server's `B`, computing `x` from the password.
+    ///
+    /// The returned `M1` is sent to the server.
+    pub fn finish(
+        self,
+        username: &[u8],
+        password: &[u8],
+        salt: &[u8],
+        server_public: &PublicValue,
+    ) -> Result<(ClientSession<H>, Proof<H>), Error> {
+        let _entry = Entry::new_secret();
+        let mut x = private_key::<H>(username, password, salt);
+        let r = self.finish_with_private_key(username, salt, x.as_ref(), server_public);
+        low::zeroise(x.as_mut());
+        r
+    }
+
+    /// Process the server's `B`, given a separately-computed `x`.
+    ///
+    /// See [`PasswordVerifier::from_private_key`].
+    pub fn finish_with_private_key(
+        self,
+        username: &[u8],
+        salt: &[u8],
+        x: &[u8],
+        server_public: &PublicValue,
+    ) -> Result<(ClientSession<H>, Proof<H>), Error> {
+        let _entry = Entry::new_secret();
+        let params = Params::new(self.group)?;
+        let m = &params.modulus;
+
+        // abort if B % N == 0
+        let b_pub = m.decode(server_public.as_bytes())?;
+        if b_pub.is_zero() {
+            return Err(Error::OutOfRange);
+        }
+        let b_pub = params.encode(&b_pub);
+        let u = scrambler::<H>(&self.public.0, &b_pub)?;
+
+        // S = (B - k * g^x) ^ (a + u * x)
+        let g_x = m.exp(&params.g, &Exponent::from_bytes(x)?);
+        let base = m.sub(
+            &m.decode(b_pub.as_bytes())?,
+            &m.mul(&params.k::<H>()?, &g_x),
+        );
+        let s = m.exp(&base, &Exponent::mul_add(&self.a, u.as_ref(), x)?);
+
+        let key = params.session_key::<H>(&s);
+        let client_proof = params.client_proof::<H>(username, salt, &self.public.0, &b_pub, &key);
+        let server_proof = server_proof::<H>(&self.public.0, &client_proof, &key);
+        let session = ClientSession {
+            server_proof,
+            key: SessionKey(key),
+        };
+        Ok((session, client_proof))
+    }
+}
+
+impl<H: Digest> Drop for Client<H> {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.a);
+    }
+}
+
+/// The client's state, before it has received `M2`.
+pub struct ClientSession<H: Digest> {
+    server_proof: Proof<H>,
+    key: SessionKey,
+}
+
+impl<H: Digest> ClientSession<H> {
+    /// Check the server's `M2`.
+    ///
+    /// Fails with [`Error::BadSignature`] if the server could not be
+    /// authenticated.  Otherwise, returns the session key.
+    pub fn verify(self, server_proof: &Proof<H>) -> Result<SessionKey, Error> {
+        let _entry = Entry::new_secret();
+        server_proof.verify(&self.server_proof)?;
+        Ok(self.key)
+    }
+}
+
+/// The server's state, before it has received `A` and `M1`.
+pub struct Server<H: Digest> {
+    group: Group,
+    b: [u8; SECRET_LEN],
+    public: PublicValue,
+    verifier: PasswordVerifier,
+    _hash: PhantomData<H>,
+}
+
+impl<H: Digest> Server<H> {
+    /// Start an exchange, with the stored verifier for the user.
+    ///
+    /// The returned `B` is sent to the client, along with the user's salt.
+    pub fn start(group: Group, verifier: &PasswordVerifier) -> Result<(Self, PublicValue), Error> {
+        Self::start_with_rng(group, verifier, &mut SystemRandom)
+    }
+
+    /// Start an exchange, using `rng` to generate the secret exponent.
+    ///
+    /// Otherwise, as [`Self::start()`].
+    pub fn start_with_rng(
+        group: Group,
+        verifier: &PasswordVerifier,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, PublicValue), Error> {
+        let _entry = Entry::new_secret();
+        let params = Params::new(group)?;
+        let m = &params.modulus;
+        let mut b = [0u8; SECRET_LEN];
+        rng.fill(&mut b)?;
+
+        // B = k * v + g^b
+        let v = m.decode(verifier.as_bytes())?;
+        let public = params.encode(&m.add(
+            &m.mul(&params.k::<H>()?, &v),
+            &m.exp(&params.g, &Exponent::from_bytes(&b)?),
+        ));
+
+        let server = Self {
+            group,
+            b,
+            public: PublicValue(public.clone()),
+            verifier: PasswordVerifier(params.encode(&v)),
+            _hash: PhantomData,
+        };
+        Ok((server, PublicValue(public)))
+    }
+
+    /// Process the client's `A` and `M1`.
+    ///
+    /// Fails with [`Error::BadSignature`] if the client could not be
+    /// authenticated.  Otherwise, returns `M2` (which is sent to the
+    /// client) and the session key.
+    pub fn finish(
+        self,
+        username: &[u8],
+        salt: &[u8],
+        client_public: &PublicValue,
+        client_proof: &Proof<H>,
+    ) -> Result<(Proof<H>, SessionKey), Error> {
+        let _entry = Entry::new_secret();
+        let params = Params::new(self.group)?;
+        let m = &params.modulus;
+
+        // abort if A % N == 0
+        let a_pub = m.decode(client_public.as_bytes())?;
+        if a_pub.is_zero() {
+            return Err(Error::OutOfRange);
+        }
+        let u = scrambler::<H>(&params.encode(&a_pub), &self.public.0)?;
+
+        // S = (A * v^u) ^ b
+        let v = m.decode(self.verifier.as_bytes())?;
+        let base = m.mul(&a_pub, &m.exp(&v, &Exponent::from_bytes(u.as_ref())?));
+        let s = m.exp(&base, &Exponent::from_bytes(&self.b)?);
+
+        let a_pub = params.encode(&a_pub);
+        let key = params.session_key::<H>(&s);
+        let expected = params.client_proof::<H>(username, salt, &a_pub, &self.public.0, &key);
+        client_proof.verify(&expected)?;
+
+        Ok((
+            server_proof::<H>(&a_pub, client_proof, &key),
+            SessionKey(key),
+        ))
+    }
+}
+
+impl<H: Digest> Drop for Server<H> {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.b);
+    }
+}
+
+/// A group, instantiated for arithmetic.
+struct Params {
+    group: Group,
+    modulus: PrimeModulus,
+    g: Element,
+}
+
+impl Params {
+    fn new(group: Group) -> Result<Self, Error> {
+        let modulus = PrimeModulus::new(group.prime())?;
+        let g = modulus.decode(&[group.generator()])?;
+        Ok(Self { group, modulus, g })
+    }
+
+    /// `k = H(N | PAD(g))`
+    fn k<H: Digest>(&self) -> Result<Element, Error> {
+        let mut ctx = H::new();
+        ctx.update(self.group.prime());
+        ctx.update(self.encode(&self.g).as_bytes());
+        self.modulus.decode(ctx.finish().as_ref())
+    }
+
+    /// `K = H(PAD(S))`
+    fn session_key<H: Digest>(&self, s: &Element) -> HashOutput {
+        let mut s = self.encode(s);
+        let key = H::hash(s.as_bytes());
+        low::zeroise(&mut s.bytes);
+        key
+    }
+
+    /// `M1 = H(H(N) XOR H(g) | H(I) | s | PAD(A) | PAD(B) | K)`
+    fn client_proof<H: Digest>(
+        &self,
+        username: &[u8],
+        salt: &[u8],
+        a_pub: &Value,
+        b_pub: &Value,
+        key: &HashOutput,
+    ) -> Proof<H> {
+        let mut h_n_g = H::hash(self.group.prime());
+        for (n, g) in h_n_g
+            .as_mut()
+            .iter_mut()
+            .zip(H::hash(&[self.group.generator()]).as_ref())
+        {
+            *n ^= g;
+        }
+
+        let mut ctx = H::new();
+        ctx.update(h_n_g.as_ref());
+        ctx.update(H::hash(username).as_ref());
+        ctx.update(salt);
+        ctx.update(a_pub.as_bytes());
+        ctx.update(b_pub.as_bytes());
+        ctx.update(key.as_ref());
+        Proof {
+            output: ctx.finish(),
+            _hash: PhantomData,
+        }
+    }
+
+    /// `PAD(e)`
+    fn encode(&self, e: &Element) -> Value {
+        let len = self.modulus.len_bytes();
+        let mut bytes = [0u8; MAX_LEN];
+        // cannot fail: `bytes` is sliced to the correct length
+        self.modulus.encode(e, &mut bytes[..len]).unwrap();
+        Value { bytes, len }
+    }
+}
+
+/// `M2 = H(PAD(A) | M1 | K)`
+fn server_proof<H: Digest>(a_pub: &Value, client_proof: &Proof<H>, key: &HashOutput) -> Proof<H> {
+    let mut ctx = H::new();
+    ctx.update(a_pub.as_bytes());
+    ctx.update(client_proof.as_bytes());
+    ctx.update(key.as_ref());
+    Proof {
+        output: ctx.finish(),
+        _hash: PhantomData,
+    }
+}
+
+/// `x = H(s | H(I | ":" | P))`
+fn private_key<H: Digest>(username: &[u8], password: &[u8], salt: &[u8]) -> HashOutput {
+    let mut inner = H::new();
+    inner.update(username);
+    inner.update(b":");
+    inner.update(password);
+    let mut inner = inner.finish();
+
+    let mut outer = H::new();
+    outer.update(salt);
+    outer.update(inner.as_ref());
+    low::zeroise(inner.as_mut());
+    outer.finish()
+}
+
+/// `u = H(PAD(A) | PAD(B))`, which must not be zero.
+fn scrambler<H: Digest>(a_pub: &Value, b_pub: &Value) -> Result<HashOutput, Error> {
+    let mut ctx = H::new();
+    ctx.update(a_pub.as_bytes());
+    ctx.update(b_pub.as_bytes());
+    let u = ctx.finish();
+    match u.as_ref().iter().all(|b| *b == 0) {
+        true => Err(Error::OutOfRange),
+        false => Ok(u),
+    }
+}
+
+/// A big-endian integer, of at most `MAX_LEN` bytes.
+#[derive(Clone)]
+struct Value {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl Value {
+    fn new(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.is_empty() || bytes.len() > MAX_LEN {
+            return Err(Error::WrongLength);
+        }
+        let mut r = Self {
+            bytes: [0u8; MAX_LEN],
+            len: bytes.len(),
+        };
+        r.bytes[..bytes.len()].copy_from_slice(bytes);
+        Ok(r)
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+/// Length of the secrets `a` and `b`.
+const SECRET_LEN: usize = 32;
+
+/// Length of the largest group.
+const MAX_LEN: usize = 1024;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::{Sha256, Sha512};
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const USERNAME: &[u8] = b"alice";
+    const PASSWORD: &[u8] = b"password123";
+    const SALT: &str = "beb25379d1a8581eb5a727673a2441ee";
+    const A: &str = "60975527035cf2ad1989806f0407210bc81edc04e2762a56afd529ddda2d4393";
+    const B: &str = "e487cb59d31ac550471e81f00f6928e01dda08e974a004f49e61f5d105284d20";
+
+    struct Transcript {
+        verifier: String,
+        client_public: String,
+        server_public: String,
+        client_proof: String,
+        server_proof: String,
+        key: String,
+    }
+
+    /// Run an exchange with the inputs from RFC5054 appendix B.
+    fn exchange<H: Digest>(group: Group) -> Transcript {
+        let salt = unhex(SALT);
+        let verifier = PasswordVerifier::new::<H>(group, USERNAME, PASSWORD, &salt).unwrap();
+
+        let a = unhex(A);
+        let (client, client_public) =
+            Client::<H>::start_with_rng(group, &mut SliceRandomSource(&a)).unwrap();
+        let b = unhex(B);
+        let (server, server_public) =
+            Server::<H>::start_with_rng(group, &verifier, &mut SliceRandomSource(&b)).unwrap();
+
+        let (client, client_proof) = client
+            .finish(USERNAME, PASSWORD, &salt, &server_public)
+            .unwrap();
+        let (server_proof, server_key) = server
+            .finish(USERNAME, &salt, &client_public, &client_proof)
+            .unwrap();
+        let client_key = client.verify(&server_proof).unwrap();
+        assert_eq!(client_key.as_bytes(), server_key.as_bytes());
+
+        Transcript {
+            verifier: hex(verifier.as_bytes()),
+            client_public: hex(client_public.as_bytes()),
+            server_public: hex(server_public.as_bytes()),
+            client_proof: hex(client_proof.as_bytes()),
+            server_proof: hex(server_proof.as_bytes()),
+            key: hex(client_key.as_bytes()),
+        }
+    }
+
+    #[test]
+    fn srp1024_sha256() {
+        // RFC5054 appendix B inputs, with SHA-256 rather than SHA-1.
+        // outputs are from an independent implementation; `A` matches
+        // the RFC since it does not depend on the hash function.
+        let t = exchange::<Sha256>(Group::Srp1024);
+        assert_eq!(
+            t.verifier,
+            "27e2855ac715f625981dba238667955db341a3bdd919868943bc049736c7804cd8e0507dfefbf5b8573f5aae7bac19b257034254119ab520e1f7cf3f45d01b159016847201d14c8dc95ec34e8b26ee255bc4cb28d4f97e0db97b65bdd196c4d2951cd84f493afd7b34b90984357988601a3643358b81689dfd0cb0d21e21cf6e"
+        );
+        assert_eq!(
+            t.client_public,
+            "61d5e490f6f1b79547b0704c436f523dd0e560f0c64115bb72557ec44352e8903211c04692272d8b2d1a5358a2cf1b6e0bfcf99f921530ec8e39356179eae45e42ba92aeaced825171e1e8b9af6d9c03e1327f44be087ef06530e69f66615261eef54073ca11cf5858f0edfdfe15efeab349ef5d76988a3672fac47b0769447b"
+        );
+        assert_eq!(
+            t.server_public,
+            "439b7630ec82c94d3bbd466a068d663a40b8d5b1d9b006ba43f5d715498088cca8547bbe3de6406c79f15ffa7356bc93580e478322daf8b2d014347859234f01555c457ab8b7f214875224fc9bfd07a68f37bad4d74bc8467ce10ea39301d3604e91fff5f881d52c558187e68fac3268df2897307da5c58a8c667e0fa8dc837e"
+        );
+        assert_eq!(
+            t.key,
+            "febac740e997507c1c7df7690bac49a97f84ecda99ceb047c575b58e160c477b"
+        );
+        assert_eq!(
+            t.client_proof,
+            "51d0af1793f2921cfc4a41bc5134605a7bf89a3497aed7c29ed6c56ae709037f"
+        );
+        assert_eq!(
+            t.server_proof,
+            "2f6b44340bf8dc05148b6b3ae1d70b6a896588ba6b2c16d8aec619d2cc57653f"
+        );
+    }
+
+    #[test]
+    fn srp3072_sha512() {
+        // from an independent implementation
+        let t = exchange::<Sha512>(Group::Srp3072);
+        assert_eq!(
+            t.key,
+            "5cbc219db052138ee1148c71cd4498963d682549ce91ca24f098468f06015beb6af245c2093f98c3651bca83ab8cab2b580bbf02184fefdf26142f73df95ac50"
+        );
+        assert_eq!(
+            t.client_proof,
+            "5f7c14ab57ed0e94fd1d78c6b4dd09ed7e340b7e05d419a9fd760f6b35e523d1310777a1ae1d2826f596f3a85116cc457c7c964d4f44ded5559da818c88b617f"
+        );
+        assert_eq!(
+            t.server_proof,
+            "2fa0e81f5cb73b88fa0964270f321dd641f2227a5d805c40f1bfe96aaf6a19ffce8e23287965a39eab9d5a02215f89e128177ed2c4f103e655a045531bcbf7ad"
+        );
+    }
+
+    #[test]
+    fn all_groups() {
+        for group in [
+            Group::Srp1024,
+            Group::Srp1536,
+            Group::Srp2048,
+            Group::Srp3072,
+            Group::Srp4096,
+            Group::Srp6144,
+            Group::Srp8192,
+        ] {
+            let t = exchange::<Sha256>(group);
+            assert_eq!(t.verifier.len(), group.len_bytes() * 2);
+            assert_eq!(t.client_public.len(), group.len_bytes() * 2);
+            assert_eq!(t.server_public.len(), group.len_bytes() * 2);
+        }
+    }
+
+    #[test]
+    fn wrong_password() {
+        let group = Group::Srp2048;
+        let verifier = PasswordVerifier::new::<Sha256>(group, b"alice", b"right", b"salt").unwrap();
+        let (client, client_public) = Client::<Sha256>::start(group).unwrap();
+        let (server, server_public) = Server::<Sha256>::start(group, &verifier).unwrap();
+        let (_, client_proof) = client
+            .finish(b"alice", b"wrong", b"salt", &server_public)
+            .unwrap();
+        assert_eq!(
+            server
+                .finish(b"alice", b"salt", &client_public, &client_proof)
+                .err(),
+            Some(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn bad_server_proof() {
+        let group = Group::Srp1024;
+        let verifier = PasswordVerifier::new::<Sha256>(group, b"alice", b"pw", b"salt").unwrap();
+        let (client, _) = Client::<Sha256>::start(group).unwrap();
+        let (_, server_public) = Server::<Sha256>::start(group, &verifier).unwrap();
+        let (client, _) = client
+            .finish(b"alice", b"pw", b"salt", &server_public)
+            .unwrap();
+        let forged = Proof::from_bytes(&[0u8; 32]).unwrap();
+        assert_eq!(client.verify(&forged).err(), Some(Error::BadSignature));
+    }
+
+    #[test]
+    fn invalid_public_values() {
+        let group = Group::Srp1024;
+        let verifier = PasswordVerifier::new::<Sha256>(group, b"alice", b"pw", b"salt").unwrap();
+        let proof = Proof::<Sha256>::from_bytes(&[0u8; 32]).unwrap();
+
+        let zero = PublicValue::from_bytes(&[0u8; 128]).unwrap();
+        let n = PublicValue::from_bytes(group.prime()).unwrap();
+        let too_big = PublicValue::from_bytes(&[0xff; 128]).unwrap();
+
+        for bad in [&zero, &n, &too_big] {
+            let (client, _) = Client::<Sha256>::start(group).unwrap();
+            assert_eq!(
+                client.finish(b"alice", b"pw", b"salt", bad).err(),
+                Some(Error::OutOfRange)
+            );
+
+            let (server, _) = Server::<Sha256>::start(group, &verifier).unwrap();
+            assert_eq!(
+                server.finish(b"alice", b"salt", bad, &proof).err(),
+                Some(Error::OutOfRange)
+            );
+        }
+
+        assert_eq!(PublicValue::from_bytes(&[]).err(), Some(Error::WrongLength));
+        assert_eq!(
+            PublicValue::from_bytes(&[1; 1025]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            Proof::<Sha256>::from_bytes(&[0u8; 64]).err(),
+            Some(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn custom_private_key() {
+        let group = Group::Srp1536;
+        let x = [0x42; 32];
+        let verifier = PasswordVerifier::from_private_key(group, &x).unwrap();
+        let verifier = PasswordVerifier::from_bytes(verifier.as_bytes()).unwrap();
+
+        let (client, client_public) = Client::<Sha256>::start(group).unwrap();
+        let (server, server_public) = Server::<Sha256>::start(group, &verifier).unwrap();
+        let (client, client_proof) = client
+            .finish_with_private_key(b"bob", b"salt", &x, &server_public)
+            .unwrap();
+        let (server_proof, server_key) = server
+            .finish(b"bob", b"salt", &client_public, &client_proof)
+            .unwrap();
+        let client_key = client.verify(&server_proof).unwrap();
+        assert_eq!(client_key.as_bytes(), server_key.as_bytes());
+    }
+}
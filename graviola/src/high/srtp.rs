@@ -0,0 +1,443 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! SRTP and SRTCP with AES-GCM, as specified in
+//! [RFC7714](https://datatracker.ietf.org/doc/html/rfc7714).
+//!
+//! Session keys are derived with the AES-CM PRF from
+//! [RFC3711](https://datatracker.ietf.org/doc/html/rfc3711#section-4.3),
+//! with a key derivation rate of zero.
+
+use crate::Error;
+use crate::low::{AesKey, Entry, zeroise};
+use crate::mid::aes_gcm::AesGcm;
+
+/// The SRTP AEAD protection profiles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Profile {
+    /// `AEAD_AES_128_GCM`.
+    AeadAes128Gcm,
+    /// `AEAD_AES_256_GCM`.
+    AeadAes256Gcm,
+}
+
+impl Profile {
+    /// Length of the master key.
+    pub fn master_key_len(&self) -> usize {
+        match self {
+            Self::AeadAes128Gcm => 16,
+            Self::AeadAes256Gcm => 32,
+        }
+    }
+}
+
+/// SRTP and SRTCP session keys for one direction of one SRTP
+/// cryptographic context.
+///
+/// The caller is responsible for the rest of the context: tracking the
+/// rollover counter and SRTCP index, and replay protection.
+pub struct SessionKeys {
+    rtp: SessionKey,
+    rtcp: SessionKey,
+}
+
+impl SessionKeys {
+    /// Length of the master salt.
+    pub const MASTER_SALT_LEN: usize = 12;
+
+    /// Length of the authentication tag appended to each packet.
+    pub const TAG_LEN: usize = 16;
+
+    /// Length of the SRTCP trailer (the E flag and SRTCP index), which
+    /// follows the tag in SRTCP packets.
+    pub const RTCP_TRAILER_LEN: usize = 4;
+
+    /// Derive session keys from a master key and master salt.
+    ///
+    /// `master_key` must be [`Profile::master_key_len()`] bytes long,
+    /// and `master_salt` must be [`Self::MASTER_SALT_LEN`] bytes long.
+    pub fn new(profile: Profile, master_key: &[u8], master_salt: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        if master_key.len() != profile.master_key_len() {
+            return Err(Error::WrongLength);
+        }
+        if master_salt.len() != Self::MASTER_SALT_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        // the 96-bit master salt is used as the most significant bits
+        // of the 112-bit value in RFC3711.
+        let mut salt = [0u8; KDF_SALT_LEN];
+        salt[..Self::MASTER_SALT_LEN].copy_from_slice(master_salt);
+
+        let master_key = AesKey::new(master_key);
+        let key_len = profile.master_key_len();
+        let r = Self {
+            rtp: SessionKey::derive(&master_key, &salt, LABEL_SRTP_ENCRYPTION, key_len),
+            rtcp: SessionKey::derive(&master_key, &salt, LABEL_SRTCP_ENCRYPTION, key_len),
+        };
+        zeroise(&mut salt);
+        Ok(r)
+    }
+
+    /// Encrypt an RTP packet payload in place.
+    ///
+    /// `header` is the RTP header, including any CSRCs and header
+    /// extension.  `roc` is the rollover counter for the packet's SSRC.
+    ///
+    /// On entry, `payload` contains the plaintext payload.  On exit, it
+    /// contains the ciphertext, and `tag_out` contains the authentication
+    /// tag which follows it in the SRTP packet.
+    pub fn encrypt_rtp(
+        &self,
+        roc: u32,
+        header: &[u8],
+        payload: &mut [u8],
+        tag_out: &mut [u8; Self::TAG_LEN],
+    ) -> Result<(), Error> {
+        let nonce = self.rtp.rtp_nonce(roc, header)?;
+        self.rtp.aead.encrypt(&nonce, header, payload, tag_out);
+        Ok(())
+    }
+
+    /// Decrypt and verify an SRTP packet payload in place.
+    ///
+    /// `header` is the RTP header, and `roc` is the rollover counter estimated
+    /// for this packet.  `tag` is the purported authentication tag.
+    ///
+    /// On success, `payload` contains the plaintext payload.  Otherwise,
+    /// [`Error::DecryptFailed`] is returned and `payload` is cleared.
+    pub fn decrypt_rtp(
+        &self,
+        roc: u32,
+        header: &[u8],
+        payload: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        let nonce = self.rtp.rtp_nonce(roc, header)?;
+        self.rtp.aead.decrypt(&nonce, header, payload, tag)
+    }
+
+    /// Encrypt an RTCP packet in place.
+    ///
+    /// `header` is the first 8 bytes of the RTCP packet, which are not
+    /// encrypted.  `payload` is the remainder of the (compound) packet.
+    /// `index` is the 31-bit SRTCP index.
+    ///
+    /// On exit, `payload` contains the ciphertext and `tag_out` contains the
+    /// authentication tag.  The returned SRTCP trailer should be appended
+    /// after the tag.
+    pub fn encrypt_rtcp(
+        &self,
+        index: u32,
+        header: &[u8],
+        payload: &mut [u8],
+        tag_out: &mut [u8; Self::TAG_LEN],
+    ) -> Result<[u8; Self::RTCP_TRAILER_LEN], Error> {
+        if index > MAX_SRTCP_INDEX {
+            return Err(Error::OutOfRange);
+        }
+        let trailer = (index | SRTCP_E_FLAG).to_be_bytes();
+        let (nonce, aad) = self.rtcp.rtcp_nonce_and_aad(header, &trailer)?;
+        self.rtcp.aead.encrypt(&nonce, &aad, payload, tag_out);
+        Ok(trailer)
+    }
+
+    /// Decrypt and verify an SRTCP packet in place.
+    ///
+    /// `header` is the first 8 bytes of the packet, `tag` is the purported
+    /// authentication tag, and `trailer` is the SRTCP trailer which
+    /// follows it.
+    ///
+    /// Only encrypted SRTCP packets (those with the E flag set) are
+    /// supported.
+    ///
+    /// On success, `payload` contains the plaintext and the SRTCP index is
+    /// returned.  Otherwise, [`Error::DecryptFailed`] is returned and `payload`
+    /// is cleared.
+    pub fn decrypt_rtcp(
+        &self,
+        header: &[u8],
+        payload: &mut [u8],
+        tag: &[u8],
+        trailer: &[u8],
+    ) -> Result<u32, Error> {
+        let trailer: &[u8; Self::RTCP_TRAILER_LEN] =
+            trailer.try_into().map_err(|_| Error::WrongLength)?;
+        let word = u32::from_be_bytes(*trailer);
+        if word & SRTCP_E_FLAG == 0 {
+            payload.fill(0);
+            return Err(Error::DecryptFailed);
+        }
+
+        let (nonce, aad) = self.rtcp.rtcp_nonce_and_aad(header, trailer)?;
+        self.rtcp.aead.decrypt(&nonce, &aad, payload, tag)?;
+        Ok(word & MAX_SRTCP_INDEX)
+    }
+}
+
+struct SessionKey {
+    aead: AesGcm,
+    salt: [u8; SESSION_SALT_LEN],
+}
+
+impl SessionKey {
+    /// Derive the session encryption key with `label`, and the session salt
+    /// with `label + 2`.
+    fn derive(
+        master_key: &AesKey,
+        master_salt: &[u8; KDF_SALT_LEN],
+        label: u8,
+        len: usize,
+    ) -> Self {
+        let mut key = [0u8; 32];
+        let key = &mut key[..len];
+        kdf(master_key, master_salt, label, key);
+        let aead = AesGcm::new(key);
+        zeroise(key);
+
+        let mut salt = [0u8; SESSION_SALT_LEN];
+        kdf(master_key, master_salt, label + 2, &mut salt);
+        Self { aead, salt }
+    }
+
+    /// See [RFC7714 section 8.1](https://datatracker.ietf.org/doc/html/rfc7714#section-8.1).
+    fn rtp_nonce(&self, roc: u32, header: &[u8]) -> Result<[u8; SESSION_SALT_LEN], Error> {
+        if header.len() < RTP_FIXED_HEADER_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let mut nonce = [0u8; SESSION_SALT_LEN];
+        nonce[2..6].copy_from_slice(&header[8..12]);
+        nonce[6..10].copy_from_slice(&roc.to_be_bytes());
+        nonce[10..12].copy_from_slice(&header[2..4]);
+        xor(&mut nonce, &self.salt);
+        Ok(nonce)
+    }
+
+    /// See [RFC7714 section 9.1](https://datatracker.ietf.org/doc/html/rfc7714#section-9.1).
+    fn rtcp_nonce_and_aad(
+        &self,
+        header: &[u8],
+        trailer: &[u8; SessionKeys::RTCP_TRAILER_LEN],
+    ) -> Result<([u8; SESSION_SALT_LEN], [u8; RTCP_AAD_LEN]), Error> {
+        let header: &[u8; RTCP_HEADER_LEN] = header.try_into().map_err(|_| Error::WrongLength)?;
+
+        let mut nonce = [0u8; SESSION_SALT_LEN];
+        nonce[2..6].copy_from_slice(&header[4..8]);
+        nonce[8..12].copy_from_slice(trailer);
+        nonce[8] &= 0x7f;
+        xor(&mut nonce, &self.salt);
+
+        let mut aad = [0u8; RTCP_AAD_LEN];
+        aad[..RTCP_HEADER_LEN].copy_from_slice(header);
+        aad[RTCP_HEADER_LEN..].copy_from_slice(trailer);
+        Ok((nonce, aad))
+    }
+}
+
+impl Drop for SessionKey {
+    fn drop(&mut self) {
+        zeroise(&mut self.salt);
+    }
+}
+
+/// The AES-CM PRF from RFC3711 section 4.3.3, with a key derivation rate of
+/// zero (so `r` is always zero).
+fn kdf(master_key: &AesKey, master_salt: &[u8; KDF_SALT_LEN], label: u8, out: &mut [u8]) {
+    let mut block = [0u8; 16];
+    for (i, chunk) in out.chunks_mut(16).enumerate() {
+        block[..KDF_SALT_LEN].copy_from_slice(master_salt);
+        block[7] ^= label;
+        block[KDF_SALT_LEN..].copy_from_slice(&(i as u16).to_be_bytes());
+        master_key.encrypt_block(&mut block);
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+    zeroise(&mut block);
+}
+
+fn xor(out: &mut [u8], other: &[u8]) {
+    for (o, x) in out.iter_mut().zip(other) {
+        *o ^= *x;
+    }
+}
+
+const LABEL_SRTP_ENCRYPTION: u8 = 0x00;
+const LABEL_SRTCP_ENCRYPTION: u8 = 0x03;
+
+const KDF_SALT_LEN: usize = 14;
+const SESSION_SALT_LEN: usize = 12;
+
+const RTP_FIXED_HEADER_LEN: usize = 12;
+const RTCP_HEADER_LEN: usize = 8;
+const RTCP_AAD_LEN: usize = RTCP_HEADER_LEN + SessionKeys::RTCP_TRAILER_LEN;
+
+const SRTCP_E_FLAG: u32 = 0x8000_0000;
+const MAX_SRTCP_INDEX: u32 = 0x7fff_ffff;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rfc3711_kdf() {
+        // RFC3711 appendix B.3
+        let master_key = AesKey::new(&unhex("e1f97a0d3e018be0d64fa32c06de4139"));
+        let master_salt = unhex("0ec675ad498afeebb6960b3aabe6").try_into().unwrap();
+
+        let mut cipher_key = [0u8; 16];
+        kdf(&master_key, &master_salt, 0x00, &mut cipher_key);
+        assert_eq!(
+            cipher_key.to_vec(),
+            unhex("c61e7a93744f39ee10734afe3ff7a087")
+        );
+
+        let mut cipher_salt = [0u8; 14];
+        kdf(&master_key, &master_salt, 0x02, &mut cipher_salt);
+        assert_eq!(cipher_salt.to_vec(), unhex("30cbbc08863d8c85d49db34a9ae1"));
+
+        let mut auth_key = [0u8; 94];
+        kdf(&master_key, &master_salt, 0x01, &mut auth_key);
+        assert_eq!(
+            auth_key.to_vec(),
+            unhex(
+                "cebe321f6ff7716b6fd4ab49af256a156d38baa48f0a0acf3c34e2359e6cdbce\
+                 e049646c43d9327ad175578ef72270986371c10c9a369ac2f94a8c5fbcdddc25\
+                 6d6e919a48b610ef17c2041e474035766b68642c59bbfc2f34db60dbdfb2"
+            )
+        );
+    }
+
+    #[test]
+    fn rfc7714_rtp() {
+        // RFC7714 section 16.1.1
+        let key = SessionKey {
+            aead: AesGcm::new(&unhex("000102030405060708090a0b0c0d0e0f")),
+            salt: unhex("517569642070726f2071756f").try_into().unwrap(),
+        };
+        let keys = SessionKeys {
+            rtp: key,
+            rtcp: SessionKey::derive(&AesKey::new(&[0; 16]), &[0; 14], 3, 16),
+        };
+        let packet = unhex(
+            "8040f17b8041f8d35501a0b247616c6c696120657374206f6d6e697320646976\
+             69736120696e207061727465732074726573",
+        );
+        let (header, plaintext) = packet.split_at(12);
+        assert_eq!(
+            keys.rtp.rtp_nonce(0, header).unwrap().to_vec(),
+            unhex("51753c6580c2726f20718414")
+        );
+
+        let mut payload = plaintext.to_vec();
+        let mut tag = [0u8; SessionKeys::TAG_LEN];
+        keys.encrypt_rtp(0, header, &mut payload, &mut tag).unwrap();
+        assert_eq!(
+            payload,
+            unhex(
+                "f24de3a3fb34de6cacba861c9d7e4bcabe633bd50d294e6f42a5f47a51c7d19b\
+                 36de3adf8833"
+            )
+        );
+        assert_eq!(tag.to_vec(), unhex("899d7f27beb16a9152cf765ee4390cce"));
+
+        keys.decrypt_rtp(0, header, &mut payload, &tag).unwrap();
+        assert_eq!(payload, plaintext);
+
+        // wrong ROC
+        keys.encrypt_rtp(0, header, &mut payload, &mut tag).unwrap();
+        assert_eq!(
+            keys.decrypt_rtp(1, header, &mut payload, &tag),
+            Err(Error::DecryptFailed)
+        );
+    }
+
+    fn check_master_key(profile: Profile, master_key: &[u8], rtp: &str, rtcp: &str) {
+        let master_salt = (0x40..0x4c).collect::<Vec<u8>>();
+        let keys = SessionKeys::new(profile, master_key, &master_salt).unwrap();
+
+        let header = unhex("8040f17b8041f8d35501a0b2");
+        let mut payload = *b"hello rtp";
+        let mut tag = [0u8; SessionKeys::TAG_LEN];
+        keys.encrypt_rtp(1, &header, &mut payload, &mut tag)
+            .unwrap();
+        assert_eq!([&payload[..], &tag[..]].concat(), unhex(rtp));
+        keys.decrypt_rtp(1, &header, &mut payload, &tag).unwrap();
+        assert_eq!(&payload, b"hello rtp");
+
+        let header = unhex("81c8000d4d617273");
+        let mut payload = *b"hello rtcp";
+        let trailer = keys
+            .encrypt_rtcp(5, &header, &mut payload, &mut tag)
+            .unwrap();
+        assert_eq!(trailer, [0x80, 0x00, 0x00, 0x05]);
+        assert_eq!([&payload[..], &tag[..]].concat(), unhex(rtcp));
+        assert_eq!(
+            keys.decrypt_rtcp(&header, &mut payload, &tag, &trailer),
+            Ok(5)
+        );
+        assert_eq!(&payload, b"hello rtcp");
+
+        // the index is authenticated
+        keys.encrypt_rtcp(5, &header, &mut payload, &mut tag)
+            .unwrap();
+        assert_eq!(
+            keys.decrypt_rtcp(&header, &mut payload, &tag, &[0x80, 0, 0, 6]),
+            Err(Error::DecryptFailed)
+        );
+        assert_eq!(payload, [0u8; 10]);
+    }
+
+    #[test]
+    fn master_keys() {
+        // from an independent implementation
+        check_master_key(
+            Profile::AeadAes128Gcm,
+            &(0..16).collect::<Vec<u8>>(),
+            "484ec56ba78a6ca42105cb12c9b564406ed9e75472fc2fece0",
+            "0363d254a4164ea6dbd4dd592e17ae31f16306159f9299237d21",
+        );
+        check_master_key(
+            Profile::AeadAes256Gcm,
+            &(0..32).collect::<Vec<u8>>(),
+            "d2083572a84b997e0dd785bda09467f521db193b77d89057e9",
+            "2f4d7401cf70f672ca707bdcdcc239c63781dbd395ffee49d056",
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(SessionKeys::new(Profile::AeadAes128Gcm, &[0; 32], &[0; 12]).is_err());
+        assert!(SessionKeys::new(Profile::AeadAes256Gcm, &[0; 32], &[0; 14]).is_err());
+
+        let keys = SessionKeys::new(Profile::AeadAes128Gcm, &[0; 16], &[0; 12]).unwrap();
+        let mut tag = [0u8; 16];
+        assert_eq!(
+            keys.encrypt_rtp(0, &[0x80; 11], &mut [], &mut tag),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            keys.encrypt_rtcp(0, &[0x80; 12], &mut [], &mut tag),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            keys.encrypt_rtcp(0x8000_0000, &[0x80; 8], &mut [], &mut tag),
+            Err(Error::OutOfRange)
+        );
+
+        // unencrypted SRTCP
+        let mut payload = [1u8; 4];
+        assert_eq!(
+            keys.decrypt_rtcp(&[0x80; 8], &mut payload, &tag, &[0, 0, 0, 1]),
+            Err(Error::DecryptFailed)
+        );
+        assert_eq!(payload, [0u8; 4]);
+    }
+}
@@ -0,0 +1,754 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Chunked encryption of long messages, such as files.
+//!
+//! This is the STREAM construction from ["Online Authenticated-Encryption
+//! and its Nonce-Reuse Misuse-Resistance"](https://eprint.iacr.org/2015/189),
+//! by Hoang, Reyhanitabar, Rogaway and Vizár.  A message is split into
+//! chunks of a fixed length (the last may be shorter), and each chunk is
+//! encrypted with an AEAD.  The nonce of each chunk includes its position
+//! and whether it is the last one, so that removing, reordering or
+//! truncating chunks is detected.
+//!
+//! A stream is:
+//!
+//! - a 29-byte header: a version byte, an algorithm byte, the chunk
+//!   length (a 32-bit big-endian integer), a 16-byte random salt, and a
+//!   7-byte random nonce prefix,
+//! - a sequence of chunks, each with a 16-byte authentication tag.
+//!
+//! The key for each stream is derived from the caller's key with
+//! HKDF-SHA256, using the salt.  The header and any associated data are
+//! bound into the derivation, so they are authenticated.
+//!
+//! [`StreamEncryptor`] and [`StreamDecryptor`] process one chunk at a time,
+//! and do not allocate.  [`EncryptingWriter`] and [`DecryptingReader`] adapt
+//! these to [`std::io::Write`] and [`std::io::Read`].
+//!
+//! ```
+//! use std::io::{Read, Write};
+//! use graviola::aead::stream::*;
+//!
+//! let key = [0x42; 32];
+//! let mut writer = EncryptingWriter::new(
+//!     Vec::new(),
+//!     Algorithm::ChaCha20Poly1305,
+//!     &key,
+//!     b"file.txt",
+//!     StreamEncryptor::DEFAULT_CHUNK_LEN,
+//! )
+//! .unwrap();
+//! writer.write_all(b"hello world").unwrap();
+//! let encrypted = writer.finish().unwrap();
+//!
+//! let mut reader = DecryptingReader::new(&encrypted[..], &key, b"file.txt").unwrap();
+//! let mut decrypted = Vec::new();
+//! reader.read_to_end(&mut decrypted).unwrap();
+//! assert_eq!(decrypted, b"hello world");
+//! ```
+
+use std::io;
+
+use super::hash::Sha256;
+use super::hkdf;
+use crate::Error;
+use crate::low::{Entry, zeroise};
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::chacha20poly1305::ChaCha20Poly1305;
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+/// The AEAD algorithms usable for chunks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// AES-128-GCM.
+    Aes128Gcm,
+    /// AES-256-GCM.
+    Aes256Gcm,
+    /// ChaCha20-Poly1305.
+    ChaCha20Poly1305,
+}
+
+impl Algorithm {
+    fn key_len(&self) -> usize {
+        match self {
+            Self::Aes128Gcm => 16,
+            Self::Aes256Gcm | Self::ChaCha20Poly1305 => 32,
+        }
+    }
+
+    fn id(&self) -> u8 {
+        match self {
+            Self::Aes128Gcm => 0x01,
+            Self::Aes256Gcm => 0x02,
+            Self::ChaCha20Poly1305 => 0x03,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0x01 => Some(Self::Aes128Gcm),
+            0x02 => Some(Self::Aes256Gcm),
+            0x03 => Some(Self::ChaCha20Poly1305),
+            _ => None,
+        }
+    }
+}
+
+/// The encrypting side of a stream.
+///
+/// See the [module documentation](self).
+pub struct StreamEncryptor {
+    state: State,
+}
+
+impl StreamEncryptor {
+    /// The length of a stream header.
+    pub const HEADER_LEN: usize = HEADER_LEN;
+
+    /// The length of each chunk's authentication tag.
+    pub const TAG_LEN: usize = TAG_LEN;
+
+    /// A reasonable chunk length: 64KiB.
+    pub const DEFAULT_CHUNK_LEN: usize = 64 * 1024;
+
+    /// The largest supported chunk length: 16MiB.
+    pub const MAX_CHUNK_LEN: usize = MAX_CHUNK_LEN;
+
+    /// Start a new stream, encrypted with `algorithm` under `key`.
+    ///
+    /// `aad` is additionally-authenticated data for the whole stream.  It
+    /// is not included in the stream, but must be supplied again to
+    /// decrypt it.  It may be empty.
+    ///
+    /// `chunk_len` is the length of each chunk's plaintext, and must be
+    /// between 1 and [`Self::MAX_CHUNK_LEN`], otherwise
+    /// [`Error::OutOfRange`] is returned.
+    ///
+    /// The stream header is returned, and must be sent before the chunks.
+    pub fn new(
+        algorithm: Algorithm,
+        key: &[u8; 32],
+        aad: &[u8],
+        chunk_len: usize,
+    ) -> Result<(Self, [u8; HEADER_LEN]), Error> {
+        Self::new_with_rng(algorithm, key, aad, chunk_len, &mut SystemRandom)
+    }
+
+    /// Start a new stream, using `rng` to generate the salt and nonce
+    /// prefix.
+    ///
+    /// Otherwise, as [`Self::new()`].
+    pub fn new_with_rng(
+        algorithm: Algorithm,
+        key: &[u8; 32],
+        aad: &[u8],
+        chunk_len: usize,
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, [u8; HEADER_LEN]), Error> {
+        if !(1..=MAX_CHUNK_LEN).contains(&chunk_len) {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut header = [0u8; HEADER_LEN];
+        header[0] = VERSION;
+        header[1] = algorithm.id();
+        header[2..6].copy_from_slice(&(chunk_len as u32).to_be_bytes());
+        rng.fill(&mut header[6..])?;
+
+        Ok((
+            Self {
+                state: State::new(algorithm, key, aad, &header),
+            },
+            header,
+        ))
+    }
+
+    /// The length of each chunk's plaintext.
+    pub fn chunk_len(&self) -> usize {
+        self.state.chunk_len
+    }
+
+    /// Encrypt the next chunk in place.
+    ///
+    /// On entry, `chunk` contains the plaintext of the chunk.  It must be
+    /// exactly [`Self::chunk_len()`] bytes long, unless `last` is true,
+    /// in which case it may be shorter (or empty).  Otherwise,
+    /// [`Error::WrongLength`] is returned.
+    ///
+    /// On exit, `chunk` contains the ciphertext of the chunk, and `tag_out`
+    /// contains its authentication tag.
+    ///
+    /// [`Error::OutOfRange`] is returned once the last chunk has been
+    /// encrypted, or if the stream has reached its limit of 2^32 chunks.
+    pub fn encrypt_chunk(
+        &mut self,
+        chunk: &mut [u8],
+        last: bool,
+        tag_out: &mut [u8; TAG_LEN],
+    ) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        let nonce = self.state.next_nonce(chunk.len(), last)?;
+        match &self.state.aead {
+            ChunkAead::AesGcm(aead) => aead.encrypt(&nonce, &[], chunk, tag_out),
+            ChunkAead::ChaCha20Poly1305(aead) => aead.encrypt(&nonce, &[], chunk, tag_out),
+        }
+        self.state.advance(last);
+        Ok(())
+    }
+}
+
+/// The decrypting side of a stream.
+///
+/// See the [module documentation](self).
+pub struct StreamDecryptor {
+    state: State,
+}
+
+impl StreamDecryptor {
+    /// Start decrypting a stream with `key`, given its `header`.
+    ///
+    /// `aad` must match that given to [`StreamEncryptor::new()`].
+    ///
+    /// [`Error::DecryptFailed`] is returned if the header has an unknown
+    /// version or algorithm, or an unsupported chunk length.  A wrong key,
+    /// `aad` or altered header is only detected when the first chunk is
+    /// decrypted.
+    pub fn new(key: &[u8; 32], aad: &[u8], header: &[u8; HEADER_LEN]) -> Result<Self, Error> {
+        if header[0] != VERSION {
+            return Err(Error::DecryptFailed);
+        }
+        let algorithm = Algorithm::from_id(header[1]).ok_or(Error::DecryptFailed)?;
+        let chunk_len = u32::from_be_bytes(header[2..6].try_into().unwrap()) as usize;
+        if !(1..=MAX_CHUNK_LEN).contains(&chunk_len) {
+            return Err(Error::DecryptFailed);
+        }
+
+        Ok(Self {
+            state: State::new(algorithm, key, aad, header),
+        })
+    }
+
+    /// The algorithm used by this stream.
+    pub fn algorithm(&self) -> Algorithm {
+        self.state.algorithm
+    }
+
+    /// The length of each chunk's plaintext.
+    pub fn chunk_len(&self) -> usize {
+        self.state.chunk_len
+    }
+
+    /// Decrypt and verify the next chunk in place.
+    ///
+    /// On entry, `chunk` contains the ciphertext of the chunk, and `tag`
+    /// its purported authentication tag.  `last` says whether this is the
+    /// last chunk of the stream: it is up to the caller to know this, for
+    /// example by reaching the end of its input.  Chunks other than the
+    /// last must be [`Self::chunk_len()`] bytes long, otherwise
+    /// [`Error::WrongLength`] is returned.
+    ///
+    /// On success, `chunk` contains the plaintext of the chunk.  Otherwise,
+    /// [`Error::DecryptFailed`] is returned, `chunk` is cleared and the
+    /// stream is unchanged.
+    ///
+    /// The caller must decrypt a last chunk: if the stream ends without
+    /// one, it has been truncated.
+    pub fn decrypt_chunk(&mut self, chunk: &mut [u8], last: bool, tag: &[u8]) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        let nonce = self.state.next_nonce(chunk.len(), last)?;
+        match &self.state.aead {
+            ChunkAead::AesGcm(aead) => aead.decrypt(&nonce, &[], chunk, tag),
+            ChunkAead::ChaCha20Poly1305(aead) => aead.decrypt(&nonce, &[], chunk, tag),
+        }?;
+        self.state.advance(last);
+        Ok(())
+    }
+}
+
+/// Encrypts everything written to it, and writes the stream to an
+/// underlying writer.
+///
+/// [`EncryptingWriter::finish()`] must be called once all the plaintext
+/// has been written: otherwise, the stream is incomplete and will not
+/// decrypt.
+pub struct EncryptingWriter<W: io::Write> {
+    inner: W,
+    encryptor: StreamEncryptor,
+    buffer: Vec<u8>,
+}
+
+impl<W: io::Write> EncryptingWriter<W> {
+    /// Start a new stream, and write its header to `inner`.
+    ///
+    /// See [`StreamEncryptor::new()`] for the meaning of the other
+    /// arguments.
+    pub fn new(
+        mut inner: W,
+        algorithm: Algorithm,
+        key: &[u8; 32],
+        aad: &[u8],
+        chunk_len: usize,
+    ) -> io::Result<Self> {
+        let (encryptor, header) =
+            StreamEncryptor::new(algorithm, key, aad, chunk_len).map_err(invalid_input)?;
+        inner.write_all(&header)?;
+        Ok(Self {
+            inner,
+            encryptor,
+            buffer: Vec::with_capacity(chunk_len + TAG_LEN),
+        })
+    }
+
+    /// Encrypt and write the last chunk, flush the underlying writer, and
+    /// then return it.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_chunk(true)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_chunk(&mut self, last: bool) -> io::Result<()> {
+        let mut tag = [0u8; TAG_LEN];
+        self.encryptor
+            .encrypt_chunk(&mut self.buffer, last, &mut tag)
+            .map_err(invalid_input)?;
+        self.buffer.extend_from_slice(&tag);
+        let result = self.inner.write_all(&self.buffer);
+        self.buffer.clear();
+        result
+    }
+}
+
+impl<W: io::Write> io::Write for EncryptingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let chunk_len = self.encryptor.chunk_len();
+
+        // a full chunk is only written once more data arrives: until then,
+        // it could be the last.
+        if self.buffer.len() == chunk_len && !buf.is_empty() {
+            self.write_chunk(false)?;
+        }
+
+        let take = buf.len().min(chunk_len - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..take]);
+        Ok(take)
+    }
+
+    /// Flush the underlying writer.
+    ///
+    /// Buffered plaintext is not written until its chunk is complete.
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reads a stream from an underlying reader, and decrypts it.
+///
+/// Plaintext is returned only once its chunk has been authenticated.
+/// Reads fail with [`io::ErrorKind::InvalidData`] if the stream has been
+/// altered or truncated, and continue to fail thereafter.
+pub struct DecryptingReader<R: io::Read> {
+    inner: R,
+    decryptor: StreamDecryptor,
+    /// One chunk, its tag, and the first byte of the next chunk.
+    buffer: Vec<u8>,
+    lookahead: Option<u8>,
+    plaintext: core::ops::Range<usize>,
+    finished: bool,
+    failed: bool,
+}
+
+impl<R: io::Read> DecryptingReader<R> {
+    /// Read the stream header from `inner`, and start decrypting.
+    ///
+    /// See [`StreamDecryptor::new()`] for the meaning of the other
+    /// arguments.
+    pub fn new(mut inner: R, key: &[u8; 32], aad: &[u8]) -> io::Result<Self> {
+        let mut header = [0u8; HEADER_LEN];
+        inner.read_exact(&mut header)?;
+        let decryptor = StreamDecryptor::new(key, aad, &header).map_err(invalid_data)?;
+        let buffer = vec![0u8; decryptor.chunk_len() + TAG_LEN + 1];
+        Ok(Self {
+            inner,
+            decryptor,
+            buffer,
+            lookahead: None,
+            plaintext: 0..0,
+            finished: false,
+            failed: false,
+        })
+    }
+
+    fn read_chunk(&mut self) -> io::Result<()> {
+        let mut used = 0;
+        if let Some(b) = self.lookahead.take() {
+            self.buffer[0] = b;
+            used = 1;
+        }
+
+        while used < self.buffer.len() {
+            match self.inner.read(&mut self.buffer[used..]) {
+                Ok(0) => break,
+                Ok(n) => used += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        // if the buffer is full, there is at least one more chunk
+        let last = used < self.buffer.len();
+        if !last {
+            used -= 1;
+            self.lookahead = Some(self.buffer[used]);
+        }
+
+        let len = used.checked_sub(TAG_LEN).ok_or_else(|| {
+            self.failed = true;
+            invalid_data(Error::DecryptFailed)
+        })?;
+        let (chunk, rest) = self.buffer.split_at_mut(len);
+        if let Err(e) = self.decryptor.decrypt_chunk(chunk, last, &rest[..TAG_LEN]) {
+            self.failed = true;
+            return Err(invalid_data(e));
+        }
+
+        self.plaintext = 0..len;
+        self.finished = last;
+        Ok(())
+    }
+}
+
+impl<R: io::Read> io::Read for DecryptingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.failed {
+            return Err(invalid_data(Error::DecryptFailed));
+        }
+
+        while self.plaintext.is_empty() && !self.finished && !buf.is_empty() {
+            self.read_chunk()?;
+        }
+
+        let available = &self.buffer[self.plaintext.clone()];
+        let take = available.len().min(buf.len());
+        buf[..take].copy_from_slice(&available[..take]);
+        self.plaintext.start += take;
+        Ok(take)
+    }
+}
+
+struct State {
+    algorithm: Algorithm,
+    aead: ChunkAead,
+    nonce_prefix: [u8; NONCE_PREFIX_LEN],
+    chunk_len: usize,
+    counter: u64,
+    finished: bool,
+}
+
+impl State {
+    fn new(algorithm: Algorithm, key: &[u8; 32], aad: &[u8], header: &[u8; HEADER_LEN]) -> Self {
+        let _entry = Entry::new_secret();
+        let (salt, nonce_prefix) = header[6..].split_at(SALT_LEN);
+
+        let mut stream_key = [0u8; 32];
+        // cannot fail: 32 bytes is well within the HKDF output limit
+        hkdf::extract::<Sha256>(salt, &[key])
+            .expand(
+                &[INFO_PREFIX, header, aad],
+                &mut stream_key[..algorithm.key_len()],
+            )
+            .unwrap();
+
+        let aead = match algorithm {
+            Algorithm::Aes128Gcm | Algorithm::Aes256Gcm => {
+                ChunkAead::AesGcm(AesGcm::new(&stream_key[..algorithm.key_len()]))
+            }
+            Algorithm::ChaCha20Poly1305 => {
+                ChunkAead::ChaCha20Poly1305(ChaCha20Poly1305::new(stream_key))
+            }
+        };
+        zeroise(&mut stream_key);
+
+        Self {
+            algorithm,
+            aead,
+            nonce_prefix: nonce_prefix.try_into().unwrap(),
+            chunk_len: u32::from_be_bytes(header[2..6].try_into().unwrap()) as usize,
+            counter: 0,
+            finished: false,
+        }
+    }
+
+    /// The nonce for the next chunk, which is `len` bytes long.
+    fn next_nonce(&self, len: usize, last: bool) -> Result<[u8; 12], Error> {
+        if self.finished || self.counter > u64::from(u32::MAX) {
+            return Err(Error::OutOfRange);
+        }
+        if len > self.chunk_len || (!last && len != self.chunk_len) {
+            return Err(Error::WrongLength);
+        }
+
+        let mut nonce = [0u8; 12];
+        nonce[..NONCE_PREFIX_LEN].copy_from_slice(&self.nonce_prefix);
+        nonce[NONCE_PREFIX_LEN..11].copy_from_slice(&(self.counter as u32).to_be_bytes());
+        nonce[11] = last as u8;
+        Ok(nonce)
+    }
+
+    fn advance(&mut self, last: bool) {
+        self.counter += 1;
+        self.finished = last;
+    }
+}
+
+// boxing the AES key would need an allocation.
+#[allow(clippy::large_enum_variant)]
+enum ChunkAead {
+    AesGcm(AesGcm),
+    ChaCha20Poly1305(ChaCha20Poly1305),
+}
+
+fn invalid_input(e: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, e)
+}
+
+fn invalid_data(e: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+const VERSION: u8 = 0x01;
+const HEADER_LEN: usize = 6 + SALT_LEN + NONCE_PREFIX_LEN;
+const SALT_LEN: usize = 16;
+const NONCE_PREFIX_LEN: usize = 7;
+const TAG_LEN: usize = 16;
+const MAX_CHUNK_LEN: usize = 1 << 24;
+const INFO_PREFIX: &[u8] = b"graviola stream";
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const ALGORITHMS: [Algorithm; 3] = [
+        Algorithm::Aes128Gcm,
+        Algorithm::Aes256Gcm,
+        Algorithm::ChaCha20Poly1305,
+    ];
+
+    fn encrypt(algorithm: Algorithm, chunk_len: usize, message: &[u8]) -> Vec<u8> {
+        let mut w =
+            EncryptingWriter::new(Vec::new(), algorithm, &[0x42; 32], b"aad", chunk_len).unwrap();
+        w.write_all(message).unwrap();
+        w.finish().unwrap()
+    }
+
+    fn decrypt(stream: &[u8]) -> io::Result<Vec<u8>> {
+        let mut r = DecryptingReader::new(stream, &[0x42; 32], b"aad")?;
+        let mut out = Vec::new();
+        r.read_to_end(&mut out)?;
+        Ok(out)
+    }
+
+    #[test]
+    fn known_answers() {
+        // from an independent implementation
+        let random = unhex("000102030405060708090a0b0c0d0e0f10111213141516");
+        for (algorithm, expected) in [
+            (
+                Algorithm::Aes128Gcm,
+                "010100000004000102030405060708090a0b0c0d0e0f10111213141516\
+                 6ec53b83d138475e6fd9e3415ffea0a20289a2c8271ae766f9efd37738b16ee5\
+                 681efeaae697af41bf5e103710cc7cc504361fb9ccff8c8690a69a",
+            ),
+            (
+                Algorithm::ChaCha20Poly1305,
+                "010300000004000102030405060708090a0b0c0d0e0f10111213141516\
+                 1c098beb8980dfd5b8bd552b4530470600c1e284d9de0dc228b3cb1401f43c42\
+                 d937968185bfb84305095c4b71962471f1d88df2fa86ed5ce30cc3",
+            ),
+        ] {
+            let (mut enc, header) = StreamEncryptor::new_with_rng(
+                algorithm,
+                &[0x42; 32],
+                b"aad",
+                4,
+                &mut SliceRandomSource(&random),
+            )
+            .unwrap();
+
+            let mut stream = header.to_vec();
+            for (chunk, last) in [(&b"hell"[..], false), (b"o wo", false), (b"rld", true)] {
+                let mut chunk = chunk.to_vec();
+                let mut tag = [0u8; TAG_LEN];
+                enc.encrypt_chunk(&mut chunk, last, &mut tag).unwrap();
+                stream.extend_from_slice(&chunk);
+                stream.extend_from_slice(&tag);
+            }
+            assert_eq!(stream, unhex(expected));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for algorithm in ALGORITHMS {
+            for chunk_len in [1, 7, 16] {
+                for len in [0, 1, 6, 7, 8, 16, 33, 100] {
+                    let message = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+                    let stream = encrypt(algorithm, chunk_len, &message);
+                    let chunks = ((len + chunk_len - 1) / chunk_len).max(1);
+                    assert_eq!(stream.len(), HEADER_LEN + len + chunks * TAG_LEN);
+                    assert_eq!(decrypt(&stream).unwrap(), message);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tampering() {
+        let stream = encrypt(Algorithm::Aes256Gcm, 4, b"hello world");
+
+        // any alteration is detected
+        for i in 0..stream.len() {
+            let mut altered = stream.clone();
+            altered[i] ^= 1;
+            assert!(decrypt(&altered).is_err());
+        }
+
+        // truncation is detected, including at a chunk boundary
+        for len in 0..stream.len() {
+            assert!(decrypt(&stream[..len]).is_err());
+        }
+
+        // as are trailing bytes
+        let mut extended = stream.clone();
+        extended.push(0);
+        assert!(decrypt(&extended).is_err());
+
+        // as is removal or reordering of chunks
+        let chunk = 4 + TAG_LEN;
+        let mut removed = stream[..HEADER_LEN].to_vec();
+        removed.extend_from_slice(&stream[HEADER_LEN + chunk..]);
+        assert!(decrypt(&removed).is_err());
+
+        let mut reordered = stream[..HEADER_LEN].to_vec();
+        reordered.extend_from_slice(&stream[HEADER_LEN + chunk..HEADER_LEN + 2 * chunk]);
+        reordered.extend_from_slice(&stream[HEADER_LEN..HEADER_LEN + chunk]);
+        reordered.extend_from_slice(&stream[HEADER_LEN + 2 * chunk..]);
+        assert!(decrypt(&reordered).is_err());
+
+        // wrong key or aad
+        assert!(
+            DecryptingReader::new(&stream[..], &[0x24; 32], b"aad")
+                .unwrap()
+                .read_to_end(&mut Vec::new())
+                .is_err()
+        );
+        assert!(
+            DecryptingReader::new(&stream[..], &[0x42; 32], b"AAD")
+                .unwrap()
+                .read_to_end(&mut Vec::new())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn errors_persist() {
+        let mut stream = encrypt(Algorithm::ChaCha20Poly1305, 4, b"hello world");
+        stream[HEADER_LEN + 4 + TAG_LEN] ^= 1;
+
+        let mut r = DecryptingReader::new(&stream[..], &[0x42; 32], b"aad").unwrap();
+        let mut buf = [0u8; 4];
+        assert_eq!(r.read(&mut buf).unwrap(), 4);
+        assert_eq!(&buf, b"hell");
+        for _ in 0..3 {
+            assert_eq!(
+                r.read(&mut buf).unwrap_err().kind(),
+                io::ErrorKind::InvalidData
+            );
+        }
+    }
+
+    #[test]
+    fn chunk_api() {
+        let (mut enc, header) =
+            StreamEncryptor::new(Algorithm::Aes128Gcm, &[1; 32], b"", 4).unwrap();
+        let mut dec = StreamDecryptor::new(&[1; 32], b"", &header).unwrap();
+        assert_eq!(dec.algorithm(), Algorithm::Aes128Gcm);
+        assert_eq!(dec.chunk_len(), 4);
+
+        let mut tag = [0u8; TAG_LEN];
+        assert_eq!(
+            enc.encrypt_chunk(&mut [0; 3], false, &mut tag),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            enc.encrypt_chunk(&mut [0; 5], true, &mut tag),
+            Err(Error::WrongLength)
+        );
+
+        let mut chunk = *b"abcd";
+        enc.encrypt_chunk(&mut chunk, false, &mut tag).unwrap();
+
+        // decrypting as the last chunk fails, and leaves the stream unchanged
+        let mut attempt = chunk;
+        assert_eq!(
+            dec.decrypt_chunk(&mut attempt, true, &tag),
+            Err(Error::DecryptFailed)
+        );
+        dec.decrypt_chunk(&mut chunk, false, &tag).unwrap();
+        assert_eq!(&chunk, b"abcd");
+
+        let mut chunk = *b"ef";
+        enc.encrypt_chunk(&mut chunk, true, &mut tag).unwrap();
+        dec.decrypt_chunk(&mut chunk, true, &tag).unwrap();
+        assert_eq!(&chunk, b"ef");
+
+        // nothing follows the last chunk
+        assert_eq!(
+            enc.encrypt_chunk(&mut [], true, &mut tag),
+            Err(Error::OutOfRange)
+        );
+        assert_eq!(
+            dec.decrypt_chunk(&mut [], true, &tag),
+            Err(Error::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for chunk_len in [0, MAX_CHUNK_LEN + 1] {
+            assert_eq!(
+                StreamEncryptor::new(Algorithm::Aes128Gcm, &[0; 32], b"", chunk_len).err(),
+                Some(Error::OutOfRange)
+            );
+        }
+
+        let (_, header) = StreamEncryptor::new(Algorithm::Aes128Gcm, &[0; 32], b"", 4).unwrap();
+        for (i, value) in [(0, 0x02), (1, 0x00), (1, 0x04), (2, 0x01), (5, 0x00)] {
+            let mut altered = header;
+            altered[i] = value;
+            assert_eq!(
+                StreamDecryptor::new(&[0; 32], b"", &altered).err(),
+                Some(Error::DecryptFailed)
+            );
+        }
+
+        assert_eq!(
+            DecryptingReader::new(&header[..HEADER_LEN - 1], &[0; 32], b"")
+                .err()
+                .unwrap()
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+}
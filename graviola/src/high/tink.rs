@@ -0,0 +1,1176 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Interoperability with [Tink](https://developers.google.com/tink) keysets.
+//!
+//! Cleartext keysets may be read and written in Tink's binary (protobuf)
+//! and JSON formats.  The supported key types are:
+//!
+//! - `AesGcmKey`, with 128- or 256-bit keys,
+//! - `ChaCha20Poly1305Key`,
+//! - `HmacKey`, with SHA-256, SHA-384 or SHA-512.
+//!
+//! Keysets containing other key types are rejected with
+//! [`Error::KeyFormatError`] (`UnsupportedTinkKeyType`).
+//!
+//! A [`Keyset`] can then encrypt, decrypt, compute and verify MACs
+//! compatibly with Tink, including its output prefixes: ciphertexts and
+//! tags produced by one Tink key can be recognised by its key id and
+//! processed by that key.
+
+use super::base64;
+use super::hash::{Hash, Sha256, Sha384, Sha512};
+use super::hmac::Hmac;
+use super::json::{self, Json};
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::low::{ct_equal, zeroise};
+use crate::mid::aes_gcm::AesGcm;
+use crate::mid::chacha20poly1305::ChaCha20Poly1305;
+use crate::mid::rng::{RandomSource, SystemRandom};
+
+mod proto;
+
+use proto::Reader;
+
+/// A Tink keyset.
+///
+/// See the [module documentation](crate::tink).
+pub struct Keyset {
+    primary_key_id: u32,
+    keys: Vec<Key>,
+}
+
+impl Keyset {
+    /// Read a cleartext keyset in Tink's binary format.
+    ///
+    /// This is a serialized `google.crypto.tink.Keyset` protobuf message.
+    pub fn from_binary(encoded: &[u8]) -> Result<Self, Error> {
+        let mut primary_key_id = 0;
+        let mut keys = Vec::new();
+
+        let mut r = Reader::new(encoded);
+        while let Some((field, value)) = r.next_field()? {
+            match field {
+                1 => primary_key_id = value.uint32()?,
+                2 => keys.push(Key::from_binary(value.bytes()?)?),
+                _ => {}
+            }
+        }
+
+        Self::new(primary_key_id, keys)
+    }
+
+    /// Read a cleartext keyset in Tink's JSON format.
+    pub fn from_json(encoded: &str) -> Result<Self, Error> {
+        let mut primary_key_id = 0;
+        let mut keys = Vec::new();
+
+        for (name, value) in Json::parse(encoded)
+            .ok_or_else(invalid)?
+            .object()
+            .ok_or_else(invalid)?
+        {
+            match name.as_str() {
+                "primaryKeyId" => primary_key_id = value.uint32().ok_or_else(invalid)?,
+                "key" => {
+                    for key in value.array().ok_or_else(invalid)? {
+                        keys.push(Key::from_json(key)?);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self::new(primary_key_id, keys)
+    }
+
+    fn new(primary_key_id: u32, keys: Vec<Key>) -> Result<Self, Error> {
+        let keyset = Self {
+            primary_key_id,
+            keys,
+        };
+        // the primary key must exist, and be usable
+        match keyset.primary() {
+            Some(_) => Ok(keyset),
+            None => Err(KeyFormatError::InvalidTinkKeyset.into()),
+        }
+    }
+
+    /// Write this keyset in Tink's binary format.
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        proto::put_varint(&mut out, 1, u64::from(self.primary_key_id));
+        for key in &self.keys {
+            proto::put_bytes(&mut out, 2, &key.to_binary());
+        }
+        out
+    }
+
+    /// Write this keyset in Tink's JSON format.
+    pub fn to_json(&self) -> String {
+        let mut out = format!("{{\"primaryKeyId\":{},\"key\":[", self.primary_key_id);
+        for (i, key) in self.keys.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            key.to_json(&mut out);
+        }
+        out.push_str("]}");
+        out
+    }
+
+    /// The id of the primary key.
+    ///
+    /// This key is used to encrypt and compute MACs.
+    pub fn primary_key_id(&self) -> u32 {
+        self.primary_key_id
+    }
+
+    /// The keys in this keyset.
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    /// Encrypt `plaintext` with the primary key.
+    ///
+    /// `associated_data` is authenticated, but not included in the output.
+    /// It may be empty.
+    ///
+    /// The output includes the primary key's output prefix, then a random
+    /// nonce, the ciphertext and the authentication tag.
+    ///
+    /// [`Error::KeyFormatError`] (`UnsupportedTinkKeyType`) is returned if the primary
+    /// key is not an AEAD key.
+    pub fn encrypt(&self, plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encrypt_with_rng(plaintext, associated_data, &mut SystemRandom)
+    }
+
+    /// Encrypt `plaintext` with the primary key, using `rng` to generate
+    /// the nonce.
+    ///
+    /// Otherwise, as [`Self::encrypt()`].
+    pub fn encrypt_with_rng(
+        &self,
+        plaintext: &[u8],
+        associated_data: &[u8],
+        rng: &mut dyn RandomSource,
+    ) -> Result<Vec<u8>, Error> {
+        // cannot fail: `Self::new` checked the primary key
+        let primary = self.primary().unwrap();
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill(&mut nonce)?;
+
+        let mut out = primary.prefix().to_vec();
+        out.extend_from_slice(&nonce);
+        let start = out.len();
+        out.extend_from_slice(plaintext);
+        let mut tag = [0u8; TAG_LEN];
+        match &primary.material {
+            Some(KeyMaterial::AesGcm(key)) => AesGcm::new(key.as_ref()).encrypt(
+                &nonce,
+                associated_data,
+                &mut out[start..],
+                &mut tag,
+            ),
+            Some(KeyMaterial::ChaCha20Poly1305(key)) => ChaCha20Poly1305::new(*key).encrypt(
+                &nonce,
+                associated_data,
+                &mut out[start..],
+                &mut tag,
+            ),
+            _ => return Err(KeyFormatError::UnsupportedTinkKeyType.into()),
+        }
+        out.extend_from_slice(&tag);
+        Ok(out)
+    }
+
+    /// Decrypt and verify `ciphertext`.
+    ///
+    /// As Tink does, this tries the enabled keys whose output prefix
+    /// matches the start of `ciphertext`, then the enabled keys with no
+    /// output prefix.
+    ///
+    /// [`Error::DecryptFailed`] is returned if no key succeeds.
+    pub fn decrypt(&self, ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, Error> {
+        for (key, body) in self.candidates(ciphertext) {
+            let Some(body) = body
+                .len()
+                .checked_sub(TAG_LEN)
+                .map(|len| body.split_at(len))
+            else {
+                continue;
+            };
+            let (nonce_and_ciphertext, tag) = body;
+            if nonce_and_ciphertext.len() < NONCE_LEN {
+                continue;
+            }
+            let (nonce, ciphertext) = nonce_and_ciphertext.split_at(NONCE_LEN);
+            let nonce = nonce.try_into().unwrap();
+
+            let mut out = ciphertext.to_vec();
+            let result = match &key.material {
+                Some(KeyMaterial::AesGcm(k)) => {
+                    AesGcm::new(k.as_ref()).decrypt(nonce, associated_data, &mut out, tag)
+                }
+                Some(KeyMaterial::ChaCha20Poly1305(k)) => {
+                    ChaCha20Poly1305::new(*k).decrypt(nonce, associated_data, &mut out, tag)
+                }
+                _ => continue,
+            };
+            if result.is_ok() {
+                return Ok(out);
+            }
+        }
+
+        Err(Error::DecryptFailed)
+    }
+
+    /// Compute a MAC of `message` with the primary key.
+    ///
+    /// The output includes the primary key's output prefix.
+    ///
+    /// [`Error::KeyFormatError`] (`UnsupportedTinkKeyType`) is returned if the primary
+    /// key is not a MAC key.
+    pub fn compute_mac(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        // cannot fail: `Self::new` checked the primary key
+        let primary = self.primary().unwrap();
+        let mut out = primary.prefix().to_vec();
+        let tag = primary
+            .hmac(message)
+            .ok_or(KeyFormatError::UnsupportedTinkKeyType)?;
+        out.extend_from_slice(&tag);
+        Ok(out)
+    }
+
+    /// Verify that `tag` is a MAC of `message`.
+    ///
+    /// The keys are tried in the same order as [`Keyset::decrypt()`].
+    ///
+    /// [`Error::BadSignature`] is returned if no key succeeds.
+    pub fn verify_mac(&self, tag: &[u8], message: &[u8]) -> Result<(), Error> {
+        for (key, tag) in self.candidates(tag) {
+            if let Some(expected) = key.hmac(message) {
+                if ct_equal(&expected, tag) {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(Error::BadSignature)
+    }
+
+    fn primary(&self) -> Option<&Key> {
+        self.keys
+            .iter()
+            .find(|k| k.id == self.primary_key_id && k.status == KeyStatus::Enabled)
+    }
+
+    /// The keys which might have produced `output`, in the order Tink
+    /// tries them, along with `output` stripped of their prefix.
+    fn candidates<'a>(&'a self, output: &'a [u8]) -> impl Iterator<Item = (&'a Key, &'a [u8])> {
+        let enabled = self.keys.iter().filter(|k| k.status == KeyStatus::Enabled);
+        let prefixed = enabled.clone().filter_map(move |k| {
+            let prefix = k.prefix();
+            match !prefix.is_empty() && output.starts_with(&prefix) {
+                true => Some((k, &output[prefix.len()..])),
+                false => None,
+            }
+        });
+        let raw = enabled
+            .filter(|k| k.output_prefix == OutputPrefix::Raw)
+            .map(move |k| (k, output));
+        prefixed.chain(raw)
+    }
+}
+
+/// One key in a [`Keyset`].
+pub struct Key {
+    id: u32,
+    status: KeyStatus,
+    output_prefix: OutputPrefix,
+    /// `None` for destroyed keys.
+    material: Option<KeyMaterial>,
+}
+
+impl Key {
+    /// The key's id.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The key's status.
+    pub fn status(&self) -> KeyStatus {
+        self.status
+    }
+
+    /// The key's output prefix type.
+    pub fn output_prefix(&self) -> OutputPrefix {
+        self.output_prefix
+    }
+
+    /// The key's type, or `None` if it has been destroyed.
+    pub fn key_type(&self) -> Option<KeyType> {
+        self.material.as_ref().map(|m| match m {
+            KeyMaterial::AesGcm(_) => KeyType::AesGcm,
+            KeyMaterial::ChaCha20Poly1305(_) => KeyType::ChaCha20Poly1305,
+            KeyMaterial::Hmac { .. } => KeyType::Hmac,
+        })
+    }
+
+    fn from_binary(encoded: &[u8]) -> Result<Self, Error> {
+        let mut key_data = None;
+        let mut status = None;
+        let mut id = 0;
+        let mut output_prefix = None;
+
+        let mut r = Reader::new(encoded);
+        while let Some((field, value)) = r.next_field()? {
+            match field {
+                1 => key_data = Some(KeyData::from_binary(value.bytes()?)?),
+                2 => status = Some(KeyStatus::from_id(value.varint()?)?),
+                3 => id = value.uint32()?,
+                4 => output_prefix = Some(OutputPrefix::from_id(value.varint()?)?),
+                _ => {}
+            }
+        }
+
+        Self::new(id, status, output_prefix, key_data)
+    }
+
+    fn from_json(value: &Json<'_>) -> Result<Self, Error> {
+        let mut key_data = None;
+        let mut status = None;
+        let mut id = 0;
+        let mut output_prefix = None;
+
+        for (name, value) in value.object().ok_or_else(invalid)? {
+            match name.as_str() {
+                "keyData" => key_data = Some(KeyData::from_json(value)?),
+                "status" => {
+                    status = Some(KeyStatus::from_name(value.string().ok_or_else(invalid)?)?)
+                }
+                "keyId" => id = value.uint32().ok_or_else(invalid)?,
+                "outputPrefixType" => {
+                    output_prefix = Some(OutputPrefix::from_name(
+                        value.string().ok_or_else(invalid)?,
+                    )?)
+                }
+                _ => {}
+            }
+        }
+
+        Self::new(id, status, output_prefix, key_data)
+    }
+
+    fn new(
+        id: u32,
+        status: Option<KeyStatus>,
+        output_prefix: Option<OutputPrefix>,
+        key_data: Option<KeyData>,
+    ) -> Result<Self, Error> {
+        let (Some(status), Some(output_prefix)) = (status, output_prefix) else {
+            return Err(KeyFormatError::InvalidTinkKeyset.into());
+        };
+
+        let material = match (status, key_data) {
+            // destroyed keys need not have any key material, and we discard it
+            (KeyStatus::Destroyed, _) => None,
+            (_, Some(key_data)) => Some(key_data.material()?),
+            (_, None) => return Err(KeyFormatError::InvalidTinkKeyset.into()),
+        };
+
+        Ok(Self {
+            id,
+            status,
+            output_prefix,
+            material,
+        })
+    }
+
+    fn to_binary(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        if let Some(material) = &self.material {
+            let mut key_data = Vec::new();
+            proto::put_bytes(&mut key_data, 1, material.type_url().as_bytes());
+            let mut value = material.to_binary();
+            proto::put_bytes(&mut key_data, 2, &value);
+            zeroise(&mut value);
+            proto::put_varint(&mut key_data, 3, KEY_MATERIAL_SYMMETRIC);
+            proto::put_bytes(&mut out, 1, &key_data);
+            zeroise(&mut key_data);
+        }
+        proto::put_varint(&mut out, 2, self.status.id());
+        proto::put_varint(&mut out, 3, u64::from(self.id));
+        proto::put_varint(&mut out, 4, self.output_prefix.id());
+        out
+    }
+
+    fn to_json(&self, out: &mut String) {
+        out.push('{');
+        if let Some(material) = &self.material {
+            out.push_str("\"keyData\":{\"typeUrl\":");
+            json::put_string(out, material.type_url());
+            out.push_str(",\"value\":");
+            let mut value = material.to_binary();
+            json::put_string(out, &base64::encode(&value));
+            zeroise(&mut value);
+            out.push_str(",\"keyMaterialType\":\"SYMMETRIC\"},");
+        }
+        out.push_str("\"status\":");
+        json::put_string(out, self.status.name());
+        out.push_str(&format!(",\"keyId\":{},\"outputPrefixType\":", self.id));
+        json::put_string(out, self.output_prefix.name());
+        out.push('}');
+    }
+
+    /// The prefix of this key's output.
+    fn prefix(&self) -> Vec<u8> {
+        let mut prefix = match self.output_prefix {
+            OutputPrefix::Tink => vec![0x01],
+            OutputPrefix::Legacy | OutputPrefix::Crunchy => vec![0x00],
+            OutputPrefix::Raw => return vec![],
+        };
+        prefix.extend_from_slice(&self.id.to_be_bytes());
+        prefix
+    }
+
+    /// The HMAC of `message`, or `None` if this is not an HMAC key.
+    fn hmac(&self, message: &[u8]) -> Option<Vec<u8>> {
+        let Some(KeyMaterial::Hmac { hash, tag_len, key }) = &self.material else {
+            return None;
+        };
+
+        fn compute<H: Hash>(key: &[u8], message: &[u8], legacy: bool) -> Vec<u8> {
+            let mut ctx = Hmac::<H>::new(key);
+            ctx.update(message);
+            // legacy keys MAC a trailing zero byte
+            if legacy {
+                ctx.update([0x00]);
+            }
+            ctx.finish().as_ref().to_vec()
+        }
+
+        let legacy = self.output_prefix == OutputPrefix::Legacy;
+        let mut tag = match hash {
+            HmacHash::Sha256 => compute::<Sha256>(key.as_ref(), message, legacy),
+            HmacHash::Sha384 => compute::<Sha384>(key.as_ref(), message, legacy),
+            HmacHash::Sha512 => compute::<Sha512>(key.as_ref(), message, legacy),
+        };
+        tag.truncate(*tag_len);
+        Some(tag)
+    }
+}
+
+/// The status of a [`Key`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyStatus {
+    /// Usable for all operations.
+    Enabled,
+    /// Not used, but retained.
+    Disabled,
+    /// The key material has been erased.
+    Destroyed,
+}
+
+impl KeyStatus {
+    fn id(self) -> u64 {
+        match self {
+            Self::Enabled => 1,
+            Self::Disabled => 2,
+            Self::Destroyed => 3,
+        }
+    }
+
+    fn from_id(id: u64) -> Result<Self, Error> {
+        [Self::Enabled, Self::Disabled, Self::Destroyed]
+            .into_iter()
+            .find(|s| s.id() == id)
+            .ok_or_else(|| KeyFormatError::InvalidTinkKeyset.into())
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Enabled => "ENABLED",
+            Self::Disabled => "DISABLED",
+            Self::Destroyed => "DESTROYED",
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, Error> {
+        [Self::Enabled, Self::Disabled, Self::Destroyed]
+            .into_iter()
+            .find(|s| s.name() == name)
+            .ok_or_else(|| KeyFormatError::InvalidTinkKeyset.into())
+    }
+}
+
+/// How a [`Key`] marks its output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputPrefix {
+    /// A 5-byte prefix: `0x01` then the key id.
+    Tink,
+    /// A 5-byte prefix: `0x00` then the key id.
+    ///
+    /// MACs are computed over the message followed by a zero byte.
+    Legacy,
+    /// No prefix.
+    Raw,
+    /// A 5-byte prefix: `0x00` then the key id.
+    Crunchy,
+}
+
+impl OutputPrefix {
+    fn id(self) -> u64 {
+        match self {
+            Self::Tink => 1,
+            Self::Legacy => 2,
+            Self::Raw => 3,
+            Self::Crunchy => 4,
+        }
+    }
+
+    fn from_id(id: u64) -> Result<Self, Error> {
+        [Self::Tink, Self::Legacy, Self::Raw, Self::Crunchy]
+            .into_iter()
+            .find(|p| p.id() == id)
+            .ok_or_else(|| KeyFormatError::InvalidTinkKeyset.into())
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Tink => "TINK",
+            Self::Legacy => "LEGACY",
+            Self::Raw => "RAW",
+            Self::Crunchy => "CRUNCHY",
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, Error> {
+        [Self::Tink, Self::Legacy, Self::Raw, Self::Crunchy]
+            .into_iter()
+            .find(|p| p.name() == name)
+            .ok_or_else(|| KeyFormatError::InvalidTinkKeyset.into())
+    }
+}
+
+/// The supported types of [`Key`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyType {
+    /// `type.googleapis.com/google.crypto.tink.AesGcmKey`
+    AesGcm,
+    /// `type.googleapis.com/google.crypto.tink.ChaCha20Poly1305Key`
+    ChaCha20Poly1305,
+    /// `type.googleapis.com/google.crypto.tink.HmacKey`
+    Hmac,
+}
+
+/// A `google.crypto.tink.KeyData` message.
+struct KeyData {
+    type_url: String,
+    value: Vec<u8>,
+    key_material_type: u64,
+}
+
+impl KeyData {
+    fn from_binary(encoded: &[u8]) -> Result<Self, Error> {
+        let mut key_data = Self {
+            type_url: String::new(),
+            value: Vec::new(),
+            key_material_type: 0,
+        };
+
+        let mut r = Reader::new(encoded);
+        while let Some((field, value)) = r.next_field()? {
+            match field {
+                1 => {
+                    key_data.type_url = core::str::from_utf8(value.bytes()?)
+                        .map_err(|_| KeyFormatError::InvalidTinkKeyset)?
+                        .to_string()
+                }
+                2 => key_data.value = value.bytes()?.to_vec(),
+                3 => key_data.key_material_type = value.varint()?,
+                _ => {}
+            }
+        }
+        Ok(key_data)
+    }
+
+    fn from_json(value: &Json<'_>) -> Result<Self, Error> {
+        let mut key_data = Self {
+            type_url: String::new(),
+            value: Vec::new(),
+            key_material_type: 0,
+        };
+
+        for (name, value) in value.object().ok_or_else(invalid)? {
+            match name.as_str() {
+                "typeUrl" => key_data.type_url = value.string().ok_or_else(invalid)?.to_string(),
+                "value" => {
+                    key_data.value = base64::decode_lenient(value.string().ok_or_else(invalid)?)
+                        .ok_or(KeyFormatError::InvalidTinkKeyset)?
+                }
+                "keyMaterialType" => {
+                    key_data.key_material_type = match value.string().ok_or_else(invalid)? {
+                        "SYMMETRIC" => KEY_MATERIAL_SYMMETRIC,
+                        _ => 0,
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(key_data)
+    }
+
+    fn material(&self) -> Result<KeyMaterial, Error> {
+        let material = match self.type_url.as_str() {
+            AES_GCM_TYPE_URL => KeyMaterial::aes_gcm(&self.value)?,
+            CHACHA20_POLY1305_TYPE_URL => KeyMaterial::chacha20_poly1305(&self.value)?,
+            HMAC_TYPE_URL => KeyMaterial::hmac(&self.value)?,
+            _ => return Err(KeyFormatError::UnsupportedTinkKeyType.into()),
+        };
+
+        match self.key_material_type {
+            KEY_MATERIAL_SYMMETRIC => Ok(material),
+            _ => Err(KeyFormatError::InvalidTinkKeyset.into()),
+        }
+    }
+}
+
+impl Drop for KeyData {
+    fn drop(&mut self) {
+        zeroise(&mut self.value);
+    }
+}
+
+enum KeyMaterial {
+    AesGcm(SecretBytes),
+    ChaCha20Poly1305([u8; 32]),
+    Hmac {
+        hash: HmacHash,
+        tag_len: usize,
+        key: SecretBytes,
+    },
+}
+
+impl KeyMaterial {
+    fn aes_gcm(encoded: &[u8]) -> Result<Self, Error> {
+        let mut key = None;
+        let mut r = Reader::new(encoded);
+        while let Some((field, value)) = r.next_field()? {
+            match field {
+                1 => check_version(value.varint()?)?,
+                3 => key = Some(value.bytes()?),
+                _ => {}
+            }
+        }
+
+        match key {
+            Some(key) if key.len() == 16 || key.len() == 32 => {
+                Ok(Self::AesGcm(SecretBytes(key.to_vec())))
+            }
+            // AES-192 is not supported
+            Some(key) if key.len() == 24 => Err(KeyFormatError::UnsupportedTinkKeyType.into()),
+            _ => Err(KeyFormatError::InvalidTinkKeyset.into()),
+        }
+    }
+
+    fn chacha20_poly1305(encoded: &[u8]) -> Result<Self, Error> {
+        let mut key = None;
+        let mut r = Reader::new(encoded);
+        while let Some((field, value)) = r.next_field()? {
+            match field {
+                1 => check_version(value.varint()?)?,
+                2 => key = Some(value.bytes()?),
+                _ => {}
+            }
+        }
+
+        key.and_then(|key| key.try_into().ok())
+            .map(Self::ChaCha20Poly1305)
+            .ok_or_else(|| KeyFormatError::InvalidTinkKeyset.into())
+    }
+
+    fn hmac(encoded: &[u8]) -> Result<Self, Error> {
+        let mut params = None;
+        let mut key = None;
+        let mut r = Reader::new(encoded);
+        while let Some((field, value)) = r.next_field()? {
+            match field {
+                1 => check_version(value.varint()?)?,
+                2 => params = Some(value.bytes()?),
+                3 => key = Some(value.bytes()?),
+                _ => {}
+            }
+        }
+
+        let mut hash = 0;
+        let mut tag_len = 0;
+        let mut r = Reader::new(params.unwrap_or_default());
+        while let Some((field, value)) = r.next_field()? {
+            match field {
+                1 => hash = value.varint()?,
+                2 => tag_len = value.uint32()? as usize,
+                _ => {}
+            }
+        }
+
+        let hash = match hash {
+            HASH_TYPE_SHA256 => HmacHash::Sha256,
+            HASH_TYPE_SHA384 => HmacHash::Sha384,
+            HASH_TYPE_SHA512 => HmacHash::Sha512,
+            // SHA-1 and SHA-224 are not supported
+            HASH_TYPE_SHA1 | HASH_TYPE_SHA224 => {
+                return Err(KeyFormatError::UnsupportedTinkKeyType.into());
+            }
+            _ => return Err(KeyFormatError::InvalidTinkKeyset.into()),
+        };
+
+        // these are the limits Tink enforces
+        let key = key.unwrap_or_default();
+        if key.len() < 16 || !(10..=hash.output_len()).contains(&tag_len) {
+            return Err(KeyFormatError::InvalidTinkKeyset.into());
+        }
+
+        Ok(Self::Hmac {
+            hash,
+            tag_len,
+            key: SecretBytes(key.to_vec()),
+        })
+    }
+
+    fn type_url(&self) -> &'static str {
+        match self {
+            Self::AesGcm(_) => AES_GCM_TYPE_URL,
+            Self::ChaCha20Poly1305(_) => CHACHA20_POLY1305_TYPE_URL,
+            Self::Hmac { .. } => HMAC_TYPE_URL,
+        }
+    }
+
+    /// The serialized key protobuf message.
+    fn to_binary(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        match self {
+            Self::AesGcm(key) => proto::put_bytes(&mut out, 3, key.as_ref()),
+            Self::ChaCha20Poly1305(key) => proto::put_bytes(&mut out, 2, key),
+            Self::Hmac { hash, tag_len, key } => {
+                let mut params = Vec::new();
+                proto::put_varint(&mut params, 1, hash.id());
+                proto::put_varint(&mut params, 2, *tag_len as u64);
+                proto::put_bytes(&mut out, 2, &params);
+                proto::put_bytes(&mut out, 3, key.as_ref());
+            }
+        }
+        out
+    }
+}
+
+impl Drop for KeyMaterial {
+    fn drop(&mut self) {
+        if let Self::ChaCha20Poly1305(key) = self {
+            zeroise(key);
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum HmacHash {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HmacHash {
+    fn id(self) -> u64 {
+        match self {
+            Self::Sha256 => HASH_TYPE_SHA256,
+            Self::Sha384 => HASH_TYPE_SHA384,
+            Self::Sha512 => HASH_TYPE_SHA512,
+        }
+    }
+
+    fn output_len(self) -> usize {
+        match self {
+            Self::Sha256 => 32,
+            Self::Sha384 => 48,
+            Self::Sha512 => 64,
+        }
+    }
+}
+
+/// Key material, erased on drop.
+struct SecretBytes(Vec<u8>);
+
+impl AsRef<[u8]> for SecretBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        zeroise(&mut self.0);
+    }
+}
+
+fn invalid() -> Error {
+    KeyFormatError::InvalidTinkKeyset.into()
+}
+
+fn check_version(version: u64) -> Result<(), Error> {
+    match version {
+        0 => Ok(()),
+        _ => Err(KeyFormatError::UnsupportedTinkKeyType.into()),
+    }
+}
+
+const AES_GCM_TYPE_URL: &str = "type.googleapis.com/google.crypto.tink.AesGcmKey";
+const CHACHA20_POLY1305_TYPE_URL: &str =
+    "type.googleapis.com/google.crypto.tink.ChaCha20Poly1305Key";
+const HMAC_TYPE_URL: &str = "type.googleapis.com/google.crypto.tink.HmacKey";
+
+const KEY_MATERIAL_SYMMETRIC: u64 = 1;
+
+const HASH_TYPE_SHA1: u64 = 1;
+const HASH_TYPE_SHA384: u64 = 2;
+const HASH_TYPE_SHA256: u64 = 3;
+const HASH_TYPE_SHA512: u64 = 4;
+const HASH_TYPE_SHA224: u64 = 5;
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn key(type_url: &str, value: &str, status: &str, id: u32, prefix: &str) -> String {
+        format!(
+            r#"{{
+              "keyData": {{
+                "typeUrl": "type.googleapis.com/google.crypto.tink.{type_url}",
+                "value": "{value}",
+                "keyMaterialType": "SYMMETRIC"
+              }},
+              "status": "{status}",
+              "keyId": {id},
+              "outputPrefixType": "{prefix}"
+            }}"#
+        )
+    }
+
+    fn keyset(primary: u32, keys: &[String]) -> String {
+        format!(
+            r#"{{"primaryKeyId": {primary}, "key": [{}]}}"#,
+            keys.join(",")
+        )
+    }
+
+    const AES_128_GCM: &str = "GhAAAQIDBAUGBwgJCgsMDQ4P";
+    const CHACHA20_POLY1305: &str = "EiBCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQg==";
+    const HMAC_SHA256_16: &str = "EgQIAxAQGiALCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCw==";
+    const HMAC_SHA512_32: &str = "EgQIBBAgGiALCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCw==";
+
+    #[test]
+    fn aead_known_answers() {
+        // from an independent implementation
+        let aes = Keyset::from_json(&keyset(
+            0x11223344,
+            &[key("AesGcmKey", AES_128_GCM, "ENABLED", 0x11223344, "TINK")],
+        ))
+        .unwrap();
+        let ciphertext =
+            unhex("0111223344aaaaaaaaaaaaaaaaaaaaaaaa43505facbda027e9cfcd3494ab8a29c8636a96a594");
+        assert_eq!(
+            aes.encrypt_with_rng(b"hello", b"aad", &mut SliceRandomSource(&[0xaa; 12]))
+                .unwrap(),
+            ciphertext
+        );
+        assert_eq!(aes.decrypt(&ciphertext, b"aad").unwrap(), b"hello");
+        assert_eq!(aes.decrypt(&ciphertext, b"AAD"), Err(Error::DecryptFailed));
+
+        let chacha = Keyset::from_json(&keyset(
+            7,
+            &[key(
+                "ChaCha20Poly1305Key",
+                CHACHA20_POLY1305,
+                "ENABLED",
+                7,
+                "RAW",
+            )],
+        ))
+        .unwrap();
+        let ciphertext =
+            unhex("aaaaaaaaaaaaaaaaaaaaaaaa8ce1687c07015de2f14be89984a0b6be31671430f4");
+        assert_eq!(
+            chacha
+                .encrypt_with_rng(b"hello", b"aad", &mut SliceRandomSource(&[0xaa; 12]))
+                .unwrap(),
+            ciphertext
+        );
+        assert_eq!(chacha.decrypt(&ciphertext, b"aad").unwrap(), b"hello");
+    }
+
+    #[test]
+    fn mac_known_answers() {
+        // from an independent implementation
+        let legacy = Keyset::from_json(&keyset(
+            0x01020304,
+            &[key(
+                "HmacKey",
+                HMAC_SHA256_16,
+                "ENABLED",
+                0x01020304,
+                "LEGACY",
+            )],
+        ))
+        .unwrap();
+        let tag = unhex("0001020304dbe9d06158f406ec50cefe88ec321426");
+        assert_eq!(legacy.compute_mac(b"hello").unwrap(), tag);
+        legacy.verify_mac(&tag, b"hello").unwrap();
+        assert_eq!(legacy.verify_mac(&tag, b"hellO"), Err(Error::BadSignature));
+
+        let tink = Keyset::from_json(&keyset(
+            5,
+            &[key("HmacKey", HMAC_SHA512_32, "ENABLED", 5, "TINK")],
+        ))
+        .unwrap();
+        let tag =
+            unhex("0100000005a7069753237bce474aba68cde25339a953e5580f71928825b3d7dd35d76b8625");
+        assert_eq!(tink.compute_mac(b"hello").unwrap(), tag);
+        tink.verify_mac(&tag, b"hello").unwrap();
+        assert_eq!(
+            tink.verify_mac(&tag[..tag.len() - 1], b"hello"),
+            Err(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn key_rotation() {
+        let keys = [
+            key("AesGcmKey", AES_128_GCM, "ENABLED", 1, "TINK"),
+            key(
+                "ChaCha20Poly1305Key",
+                CHACHA20_POLY1305,
+                "ENABLED",
+                2,
+                "RAW",
+            ),
+            key("AesGcmKey", AES_128_GCM, "ENABLED", 3, "CRUNCHY"),
+            key("AesGcmKey", AES_128_GCM, "DISABLED", 4, "TINK"),
+        ];
+
+        let ciphertexts = [1, 2, 3, 4]
+            .iter()
+            .map(|primary| {
+                let mut keys = keys.clone();
+                // make the key under test usable as the primary
+                keys[3] = key("AesGcmKey", AES_128_GCM, "ENABLED", 4, "TINK");
+                Keyset::from_json(&keyset(*primary, &keys))
+                    .unwrap()
+                    .encrypt(b"hello", b"")
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(&ciphertexts[0][..5], &[0x01, 0, 0, 0, 1]);
+        assert_eq!(ciphertexts[1].len(), 12 + 5 + 16);
+        assert_eq!(&ciphertexts[2][..5], &[0x00, 0, 0, 0, 3]);
+
+        // a keyset can decrypt for any enabled key, but not disabled ones
+        let rotated = Keyset::from_json(&keyset(2, &keys)).unwrap();
+        for ciphertext in &ciphertexts[..3] {
+            assert_eq!(rotated.decrypt(ciphertext, b"").unwrap(), b"hello");
+        }
+        assert_eq!(
+            rotated.decrypt(&ciphertexts[3], b""),
+            Err(Error::DecryptFailed)
+        );
+
+        // AEAD and MAC keys are not interchangeable
+        assert_eq!(
+            rotated.compute_mac(b"hello"),
+            Err(KeyFormatError::UnsupportedTinkKeyType.into())
+        );
+        assert_eq!(
+            rotated.verify_mac(&ciphertexts[0], b"hello"),
+            Err(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn formats() {
+        let json = keyset(
+            2,
+            &[
+                key("AesGcmKey", AES_128_GCM, "ENABLED", 1, "TINK"),
+                key("HmacKey", HMAC_SHA256_16, "ENABLED", 2, "LEGACY"),
+                key(
+                    "ChaCha20Poly1305Key",
+                    CHACHA20_POLY1305,
+                    "DISABLED",
+                    3,
+                    "RAW",
+                ),
+                key("AesGcmKey", AES_128_GCM, "DESTROYED", 4, "CRUNCHY"),
+            ],
+        );
+        let parsed = Keyset::from_json(&json).unwrap();
+        assert_eq!(parsed.primary_key_id(), 2);
+        assert_eq!(
+            parsed
+                .keys()
+                .iter()
+                .map(|k| (k.id(), k.status(), k.output_prefix(), k.key_type()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    1,
+                    KeyStatus::Enabled,
+                    OutputPrefix::Tink,
+                    Some(KeyType::AesGcm)
+                ),
+                (
+                    2,
+                    KeyStatus::Enabled,
+                    OutputPrefix::Legacy,
+                    Some(KeyType::Hmac)
+                ),
+                (
+                    3,
+                    KeyStatus::Disabled,
+                    OutputPrefix::Raw,
+                    Some(KeyType::ChaCha20Poly1305)
+                ),
+                (4, KeyStatus::Destroyed, OutputPrefix::Crunchy, None),
+            ]
+        );
+
+        let binary = parsed.to_binary();
+        let from_binary = Keyset::from_binary(&binary).unwrap();
+        assert_eq!(from_binary.to_binary(), binary);
+        let from_json = Keyset::from_json(&parsed.to_json()).unwrap();
+        assert_eq!(from_json.to_binary(), binary);
+        assert_eq!(from_json.to_json(), parsed.to_json());
+
+        // as Tink's protobuf encoder writes it
+        let single = keyset(1, &[key("AesGcmKey", AES_128_GCM, "ENABLED", 1, "TINK")]);
+        assert_eq!(
+            Keyset::from_json(&single).unwrap().to_binary(),
+            unhex(
+                "080112500a480a30747970652e676f6f676c65617069732e636f6d2f676f6f67\
+                 6c652e63727970746f2e74696e6b2e41657347636d4b657912121a1000010203\
+                 0405060708090a0b0c0d0e0f1801100118012001"
+            )
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let aes = key("AesGcmKey", AES_128_GCM, "ENABLED", 1, "TINK");
+        for (json, err) in [
+            // no such primary
+            (
+                keyset(2, core::slice::from_ref(&aes)),
+                KeyFormatError::InvalidTinkKeyset,
+            ),
+            // primary is not enabled
+            (
+                keyset(1, &[key("AesGcmKey", AES_128_GCM, "DISABLED", 1, "TINK")]),
+                KeyFormatError::InvalidTinkKeyset,
+            ),
+            (keyset(1, &[]), KeyFormatError::InvalidTinkKeyset),
+            (
+                keyset(1, &[key("Ed25519PrivateKey", "", "ENABLED", 1, "TINK")]),
+                KeyFormatError::UnsupportedTinkKeyType,
+            ),
+            (
+                keyset(1, &[key("AesGcmKey", AES_128_GCM, "ENABLED", 1, "OTHER")]),
+                KeyFormatError::InvalidTinkKeyset,
+            ),
+            (
+                keyset(1, &[key("AesGcmKey", AES_128_GCM, "UNKNOWN", 1, "TINK")]),
+                KeyFormatError::InvalidTinkKeyset,
+            ),
+            // AES-GCM with a 15-byte key
+            (
+                keyset(
+                    1,
+                    &[key(
+                        "AesGcmKey",
+                        "Gg8AAQIDBAUGBwgJCgsMDQ4=",
+                        "ENABLED",
+                        1,
+                        "TINK",
+                    )],
+                ),
+                KeyFormatError::InvalidTinkKeyset,
+            ),
+            // AES-GCM with key version 1
+            (
+                keyset(
+                    1,
+                    &[key(
+                        "AesGcmKey",
+                        "CAEaEAABAgMEBQYHCAkKCwwNDg8=",
+                        "ENABLED",
+                        1,
+                        "TINK",
+                    )],
+                ),
+                KeyFormatError::UnsupportedTinkKeyType,
+            ),
+            // HMAC-SHA1
+            (
+                keyset(
+                    1,
+                    &[key(
+                        "HmacKey",
+                        "EgQIARAQGhALCwsLCwsLCwsLCwsLCwsL",
+                        "ENABLED",
+                        1,
+                        "TINK",
+                    )],
+                ),
+                KeyFormatError::UnsupportedTinkKeyType,
+            ),
+            // HMAC-SHA256 with a 9-byte tag
+            (
+                keyset(
+                    1,
+                    &[key(
+                        "HmacKey",
+                        "EgQIAxAJGhALCwsLCwsLCwsLCwsLCwsL",
+                        "ENABLED",
+                        1,
+                        "TINK",
+                    )],
+                ),
+                KeyFormatError::InvalidTinkKeyset,
+            ),
+            (
+                keyset(1, &[aes.replace("SYMMETRIC", "ASYMMETRIC_PRIVATE")]),
+                KeyFormatError::InvalidTinkKeyset,
+            ),
+            ("[]".to_string(), KeyFormatError::InvalidTinkKeyset),
+        ] {
+            assert_eq!(
+                Keyset::from_json(&json).err(),
+                Some(Error::KeyFormatError(err)),
+                "{json}"
+            );
+        }
+
+        let binary = Keyset::from_json(&keyset(1, &[aes])).unwrap().to_binary();
+        for len in 0..binary.len() {
+            assert!(Keyset::from_binary(&binary[..len]).is_err());
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Just enough of the protobuf wire format for Tink keysets.
+
+use crate::Error;
+use crate::error::KeyFormatError;
+
+/// A field value.
+#[derive(Debug, PartialEq)]
+pub(super) enum Value<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+impl<'a> Value<'a> {
+    pub(super) fn varint(self) -> Result<u64, Error> {
+        match self {
+            Self::Varint(v) => Ok(v),
+            Self::Bytes(_) => Err(invalid()),
+        }
+    }
+
+    pub(super) fn uint32(self) -> Result<u32, Error> {
+        u32::try_from(self.varint()?).map_err(|_| invalid())
+    }
+
+    pub(super) fn bytes(self) -> Result<&'a [u8], Error> {
+        match self {
+            Self::Bytes(b) => Ok(b),
+            Self::Varint(_) => Err(invalid()),
+        }
+    }
+}
+
+/// Iterates over the fields of an encoded message.
+///
+/// Fields with fixed-width types are skipped, as Tink keysets do
+/// not use them.
+pub(super) struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    /// Returns the next field number and its value, or `None`
+    /// at the end of the message.
+    pub(super) fn next_field(&mut self) -> Result<Option<(u32, Value<'a>)>, Error> {
+        loop {
+            if self.input.is_empty() {
+                return Ok(None);
+            }
+
+            let key = self.varint()?;
+            let field = u32::try_from(key >> 3).map_err(|_| invalid())?;
+            if field == 0 {
+                return Err(invalid());
+            }
+
+            match key & 7 {
+                WIRE_VARINT => return Ok(Some((field, Value::Varint(self.varint()?)))),
+                WIRE_LENGTH_DELIMITED => {
+                    let len = usize::try_from(self.varint()?).map_err(|_| invalid())?;
+                    return Ok(Some((field, Value::Bytes(self.take(len)?))));
+                }
+                WIRE_FIXED64 => {
+                    self.take(8)?;
+                }
+                WIRE_FIXED32 => {
+                    self.take(4)?;
+                }
+                _ => return Err(invalid()),
+            }
+        }
+    }
+
+    fn varint(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        for i in 0..10 {
+            let (&byte, rest) = self.input.split_first().ok_or_else(invalid)?;
+            self.input = rest;
+            // the tenth byte may only contribute the top bit
+            if i == 9 && byte > 1 {
+                return Err(invalid());
+            }
+            value |= u64::from(byte & 0x7f) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(invalid())
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if len > self.input.len() {
+            return Err(invalid());
+        }
+        let (taken, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(taken)
+    }
+}
+
+/// Append a varint field to `out`.
+///
+/// As in proto3, a zero value is not written.
+pub(super) fn put_varint(out: &mut Vec<u8>, field: u32, value: u64) {
+    if value != 0 {
+        varint(out, u64::from(field) << 3 | WIRE_VARINT);
+        varint(out, value);
+    }
+}
+
+/// Append a length-delimited field to `out`.
+pub(super) fn put_bytes(out: &mut Vec<u8>, field: u32, value: &[u8]) {
+    varint(out, u64::from(field) << 3 | WIRE_LENGTH_DELIMITED);
+    varint(out, value.len() as u64);
+    out.extend_from_slice(value);
+}
+
+fn varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn invalid() -> Error {
+    KeyFormatError::InvalidTinkKeyset.into()
+}
+
+const WIRE_VARINT: u64 = 0;
+const WIRE_FIXED64: u64 = 1;
+const WIRE_LENGTH_DELIMITED: u64 = 2;
+const WIRE_FIXED32: u64 = 5;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut out = vec![];
+        put_varint(&mut out, 1, 300);
+        put_varint(&mut out, 2, 0);
+        put_bytes(&mut out, 3, b"hello");
+        put_varint(&mut out, 4, u64::MAX);
+        assert_eq!(&out[..3], &[0x08, 0xac, 0x02]);
+
+        let mut r = Reader::new(&out);
+        assert_eq!(r.next_field().unwrap(), Some((1, Value::Varint(300))));
+        assert_eq!(r.next_field().unwrap(), Some((3, Value::Bytes(b"hello"))));
+        assert_eq!(r.next_field().unwrap(), Some((4, Value::Varint(u64::MAX))));
+        assert_eq!(r.next_field().unwrap(), None);
+    }
+
+    #[test]
+    fn skips_fixed_width() {
+        let input = [0x09, 1, 2, 3, 4, 5, 6, 7, 8, 0x15, 1, 2, 3, 4, 0x18, 0x01];
+        let mut r = Reader::new(&input);
+        assert_eq!(r.next_field().unwrap(), Some((3, Value::Varint(1))));
+        assert_eq!(r.next_field().unwrap(), None);
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        for input in [
+            &[0x08][..],
+            &[0x08, 0x80],
+            &[0x12, 0x02, 0x00],
+            &[0x0b],
+            &[0x00, 0x00],
+            &[
+                0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02,
+            ],
+            &[0x09, 0x00],
+        ] {
+            assert!(Reader::new(input).next_field().is_err(), "{input:?}");
+        }
+    }
+}
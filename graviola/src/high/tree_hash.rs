@@ -0,0 +1,132 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Parallel hashing of large inputs.
+//!
+//! A single hash computation is inherently sequential.  For very large
+//! inputs it can be worthwhile to instead use a tree construction, where
+//! independent parts of the input are hashed concurrently on multiple
+//! threads.
+//!
+//! **The output of this module is not the same as hashing the input in
+//! one go.**  It is only comparable with other outputs of this module,
+//! using the same hash function.
+//!
+//! # Construction
+//!
+//! With `H` the chosen hash function, and `u64be(x)` the 8-byte big-endian
+//! encoding of `x`:
+//!
+//! 1. The input is split into `n` chunks of [`CHUNK_LEN`] bytes.  The final
+//!    chunk may be shorter.  An empty input is one empty chunk.
+//! 2. Each chunk `i` is hashed as `leaf_i = H(0x00 || u64be(i) || chunk_i)`.
+//! 3. The output is `H(0x01 || u64be(input_len) || leaf_0 || ... || leaf_(n-1))`.
+//!
+//! The result does not depend on the number of threads used.
+//!
+//! ```
+//! use graviola::hashing::{Sha256, tree_hash};
+//!
+//! let input = vec![0u8; 4 * tree_hash::CHUNK_LEN + 1];
+//! let a = tree_hash::hash::<Sha256>(&input);
+//! let b = tree_hash::hash_with_threads::<Sha256>(&input, 1.try_into().unwrap());
+//! assert_eq!(a, b);
+//! ```
+
+use core::num::NonZeroUsize;
+use std::thread;
+
+use super::hash::{Hash, HashContext, HashOutput};
+
+/// The length of each chunk of input, in bytes.
+pub const CHUNK_LEN: usize = 1024 * 1024;
+
+const LEAF_PREFIX: u8 = 0x00;
+const ROOT_PREFIX: u8 = 0x01;
+
+/// Hash `input` using all available parallelism.
+///
+/// See the [module documentation](self) for the construction.
+pub fn hash<H: Hash>(input: &[u8]) -> HashOutput {
+    let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+    hash_with_threads::<H>(input, threads)
+}
+
+/// Hash `input` using at most `threads` threads.
+///
+/// The output is the same whatever the value of `threads`.
+/// See the [module documentation](self) for the construction.
+pub fn hash_with_threads<H: Hash>(input: &[u8], threads: NonZeroUsize) -> HashOutput {
+    let chunks = input.len().saturating_sub(1) / CHUNK_LEN + 1;
+    let mut leaves = vec![H::zeroed_output(); chunks];
+
+    let per_thread = (chunks + threads.get() - 1) / threads.get();
+    if per_thread == chunks {
+        hash_leaves::<H>(input, 0, &mut leaves);
+    } else {
+        thread::scope(|s| {
+            for (i, leaves) in leaves.chunks_mut(per_thread).enumerate() {
+                s.spawn(move || hash_leaves::<H>(input, i * per_thread, leaves));
+            }
+        });
+    }
+
+    let mut root = H::new();
+    root.update(&[ROOT_PREFIX]);
+    root.update(&(input.len() as u64).to_be_bytes());
+    for leaf in &leaves {
+        root.update(leaf.as_ref());
+    }
+    root.finish()
+}
+
+/// Fill in `leaves`, which are the hashes of chunks `first..first + leaves.len()`.
+fn hash_leaves<H: Hash>(input: &[u8], first: usize, leaves: &mut [HashOutput]) {
+    for (index, leaf) in (first..).zip(leaves.iter_mut()) {
+        let start = index * CHUNK_LEN;
+        let end = input.len().min(start + CHUNK_LEN);
+
+        let mut ctx = H::new();
+        ctx.update(&[LEAF_PREFIX]);
+        ctx.update(&(index as u64).to_be_bytes());
+        ctx.update(&input[start..end]);
+        *leaf = ctx.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::hash::{Sha256, Sha384};
+    use super::*;
+
+    #[test]
+    fn empty() {
+        // H(0x01 || u64be(0) || H(0x00 || u64be(0)))
+        let leaf = Sha256::hash(&[0u8; 9]);
+        let mut root = vec![0x01];
+        root.extend_from_slice(&[0u8; 8]);
+        root.extend_from_slice(leaf.as_ref());
+        assert_eq!(hash::<Sha256>(&[]), Sha256::hash(&root));
+    }
+
+    #[test]
+    fn independent_of_thread_count() {
+        let input = (0..3 * CHUNK_LEN + 123)
+            .map(|i| (i * 7) as u8)
+            .collect::<Vec<_>>();
+
+        for len in [0, 1, CHUNK_LEN - 1, CHUNK_LEN, CHUNK_LEN + 1, input.len()] {
+            let expect = hash_with_threads::<Sha384>(&input[..len], NonZeroUsize::MIN);
+            for threads in 2..6 {
+                let threads = NonZeroUsize::new(threads).unwrap();
+                assert_eq!(hash_with_threads::<Sha384>(&input[..len], threads), expect);
+            }
+            assert_eq!(hash::<Sha384>(&input[..len]), expect);
+        }
+    }
+
+    #[test]
+    fn differs_from_plain_hash() {
+        assert_ne!(hash::<Sha256>(b"hello"), Sha256::hash(b"hello"));
+    }
+}
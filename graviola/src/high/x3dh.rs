@@ -0,0 +1,309 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! X3DH and PQXDH key agreement.
+//!
+//! These are the DH combinations and KDF from the
+//! [X3DH](https://signal.org/docs/specifications/x3dh/) and
+//! [PQXDH](https://signal.org/docs/specifications/pqxdh/) specifications,
+//! using X25519.
+//!
+//! Prekey bundle publication, signing and verification of signed prekeys
+//! (which those specifications do with XEdDSA), and the encoding of the initial
+//! message are left to the caller.  Identity keys here are X25519 keys.
+//!
+//! ```
+//! use graviola::hashing::Sha256;
+//! use graviola::key_agreement::x25519::StaticPrivateKey;
+//! use graviola::key_agreement::x3dh::*;
+//!
+//! let bob_identity = StaticPrivateKey::new_random().unwrap();
+//! let bob_signed_prekey = StaticPrivateKey::new_random().unwrap();
+//! let bundle = PrekeyBundle {
+//!     identity_key: bob_identity.public_key(),
+//!     signed_prekey: bob_signed_prekey.public_key(),
+//!     one_time_prekey: None,
+//! };
+//!
+//! let alice_identity = StaticPrivateKey::new_random().unwrap();
+//! let alice_ephemeral = StaticPrivateKey::new_random().unwrap();
+//! let alice_key =
+//!     initiate::<Sha256>(b"MyProtocol", &alice_identity, &alice_ephemeral, &bundle, None)
+//!         .unwrap();
+//!
+//! let bob_key = respond::<Sha256>(
+//!     b"MyProtocol",
+//!     &bob_identity,
+//!     &bob_signed_prekey,
+//!     None,
+//!     &alice_identity.public_key(),
+//!     &alice_ephemeral.public_key(),
+//!     None,
+//! )
+//! .unwrap();
+//! assert_eq!(alice_key.as_bytes(), bob_key.as_bytes());
+//! ```
+
+use super::hash::Hash;
+use super::hkdf::Prk;
+use super::hmac::Hmac;
+use crate::Error;
+use crate::low;
+use crate::mid::x25519::{PublicKey, StaticPrivateKey};
+
+/// The public keys of a responder, as retrieved from a server by an initiator.
+///
+/// The caller is responsible for verifying the signature over
+/// `signed_prekey` before use.
+pub struct PrekeyBundle {
+    /// The responder's identity key, `IKB`.
+    pub identity_key: PublicKey,
+
+    /// The responder's signed prekey, `SPKB`.
+    pub signed_prekey: PublicKey,
+
+    /// The responder's one-time prekey, `OPKB`, if one was available.
+    pub one_time_prekey: Option<PublicKey>,
+}
+
+/// The shared secret key `SK` resulting from a key agreement.
+pub struct SharedKey([u8; 32]);
+
+impl SharedKey {
+    /// Extract the bytes of this key.
+    ///
+    /// This is typically used as the initial root key for the
+    /// [double ratchet](crate::key_agreement::double_ratchet).
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl Drop for SharedKey {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.0);
+    }
+}
+
+/// Accumulates the inputs to the X3DH/PQXDH KDF.
+///
+/// This is `KDF(KM)`: HKDF with a zero salt, over `F || KM`,
+/// where `F` is 32 0xff bytes and `KM` is the concatenation of
+/// the DH outputs and KEM shared secrets added with [`KeyAgreement::mix_dh`]
+/// and [`KeyAgreement::mix_shared_secret`].
+///
+/// [`initiate`] and [`respond`] use this with the DH combinations
+/// from the specifications; use it directly for other combinations.
+pub struct KeyAgreement<H: Hash + Clone> {
+    extract: Hmac<H>,
+}
+
+impl<H: Hash + Clone> KeyAgreement<H> {
+    /// Start a new key agreement.
+    pub fn new() -> Self {
+        // HMAC zero-pads its key, so this is the zero-filled salt
+        let mut extract = Hmac::new([]);
+        extract.update([0xff; 32]);
+        Self { extract }
+    }
+
+    /// Add the output of `DH(ours, theirs)` to the key material.
+    ///
+    /// Fails if `theirs` is a low order point.
+    pub fn mix_dh(&mut self, ours: &StaticPrivateKey, theirs: &PublicKey) -> Result<(), Error> {
+        let ss = ours.diffie_hellman(theirs)?;
+        self.extract.update(&ss.0);
+        Ok(())
+    }
+
+    /// Add another shared secret to the key material.
+    ///
+    /// In PQXDH this is the shared secret from a KEM encapsulation
+    /// to the responder's post-quantum prekey.
+    pub fn mix_shared_secret(&mut self, shared_secret: &[u8]) {
+        self.extract.update(shared_secret);
+    }
+
+    /// Complete the key agreement, producing `SK`.
+    ///
+    /// `info` identifies the application.
+    pub fn finish(self, info: &[u8]) -> SharedKey {
+        let mut prk = self.extract.finish();
+        let mut sk = SharedKey([0u8; 32]);
+        // cannot fail: 32 bytes is well within the HKDF output limit
+        Prk::<H>::new(prk.as_ref())
+            .expand(&[info], &mut sk.0)
+            .unwrap();
+        low::zeroise(prk.as_mut());
+        sk
+    }
+}
+
+impl<H: Hash + Clone> Default for KeyAgreement<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compute `SK` as the initiator ("Alice").
+///
+/// `identity` is our identity key `IKA`, `ephemeral` is a freshly generated
+/// key `EKA`.  `bundle` is the responder's (verified) prekey bundle.
+///
+/// For PQXDH, encapsulate to the responder's post-quantum prekey and
+/// pass the shared secret as `kem_shared_secret`.
+pub fn initiate<H: Hash + Clone>(
+    info: &[u8],
+    identity: &StaticPrivateKey,
+    ephemeral: &StaticPrivateKey,
+    bundle: &PrekeyBundle,
+    kem_shared_secret: Option<&[u8]>,
+) -> Result<SharedKey, Error> {
+    let mut ka = KeyAgreement::<H>::new();
+    ka.mix_dh(identity, &bundle.signed_prekey)?;
+    ka.mix_dh(ephemeral, &bundle.identity_key)?;
+    ka.mix_dh(ephemeral, &bundle.signed_prekey)?;
+    if let Some(one_time_prekey) = &bundle.one_time_prekey {
+        ka.mix_dh(ephemeral, one_time_prekey)?;
+    }
+    if let Some(ss) = kem_shared_secret {
+        ka.mix_shared_secret(ss);
+    }
+    Ok(ka.finish(info))
+}
+
+/// Compute `SK` as the responder ("Bob").
+///
+/// `identity`, `signed_prekey` and `one_time_prekey` are our private keys
+/// corresponding to the bundle the initiator used.  `peer_identity` and
+/// `peer_ephemeral` are from the initiator's initial message.
+///
+/// For PQXDH, decapsulate the initiator's KEM ciphertext and pass the
+/// shared secret as `kem_shared_secret`.
+pub fn respond<H: Hash + Clone>(
+    info: &[u8],
+    identity: &StaticPrivateKey,
+    signed_prekey: &StaticPrivateKey,
+    one_time_prekey: Option<&StaticPrivateKey>,
+    peer_identity: &PublicKey,
+    peer_ephemeral: &PublicKey,
+    kem_shared_secret: Option<&[u8]>,
+) -> Result<SharedKey, Error> {
+    let mut ka = KeyAgreement::<H>::new();
+    ka.mix_dh(signed_prekey, peer_identity)?;
+    ka.mix_dh(identity, peer_ephemeral)?;
+    ka.mix_dh(signed_prekey, peer_ephemeral)?;
+    if let Some(one_time_prekey) = one_time_prekey {
+        ka.mix_dh(one_time_prekey, peer_ephemeral)?;
+    }
+    if let Some(ss) = kem_shared_secret {
+        ka.mix_shared_secret(ss);
+    }
+    Ok(ka.finish(info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high::hash::{Sha256, Sha512};
+    use crate::high::kem::{Kem, MlKem1024};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn key(byte: u8) -> StaticPrivateKey {
+        StaticPrivateKey::from_array(&[byte; 32])
+    }
+
+    fn bundle(one_time: bool) -> PrekeyBundle {
+        PrekeyBundle {
+            identity_key: key(3).public_key(),
+            signed_prekey: key(4).public_key(),
+            one_time_prekey: one_time.then(|| key(5).public_key()),
+        }
+    }
+
+    #[test]
+    fn x3dh() {
+        // produced with python's `cryptography`, following section 2.2 of
+        // the X3DH specification.
+        let sk = initiate::<Sha256>(b"x3dh test", &key(1), &key(2), &bundle(true), None).unwrap();
+        assert_eq!(
+            hex(sk.as_bytes()),
+            "b4139aaf8c8d6ef6d42edf2e13c2d03dca449e10fded9f428757c872f4da1150"
+        );
+        let sk2 = respond::<Sha256>(
+            b"x3dh test",
+            &key(3),
+            &key(4),
+            Some(&key(5)),
+            &key(1).public_key(),
+            &key(2).public_key(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(sk.as_bytes(), sk2.as_bytes());
+
+        // without one-time prekey
+        let sk = initiate::<Sha512>(b"x3dh test", &key(1), &key(2), &bundle(false), None).unwrap();
+        assert_eq!(
+            hex(sk.as_bytes()),
+            "b468b1ecb72093298cfea62722753a40ff16c535b505b176b00f8276bde4d5fe"
+        );
+        let sk2 = respond::<Sha512>(
+            b"x3dh test",
+            &key(3),
+            &key(4),
+            None,
+            &key(1).public_key(),
+            &key(2).public_key(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(sk.as_bytes(), sk2.as_bytes());
+    }
+
+    #[test]
+    fn pqxdh() {
+        let ss = [0x42u8; 32];
+        let sk =
+            initiate::<Sha512>(b"pqxdh test", &key(1), &key(2), &bundle(true), Some(&ss)).unwrap();
+        assert_eq!(
+            hex(sk.as_bytes()),
+            "a70c4d7527b8ba6f19048f536ff97bfb17b5c7cd3aaa83ae434b25f49867db58"
+        );
+
+        // and with a real encapsulation
+        let pq_prekey = MlKem1024::generate().unwrap();
+        let (ciphertext, alice_ss) =
+            MlKem1024::encapsulate(&MlKem1024::encapsulation_key(&pq_prekey)).unwrap();
+        let alice = initiate::<Sha512>(
+            b"pqxdh test",
+            &key(1),
+            &key(2),
+            &bundle(false),
+            Some(alice_ss.as_ref()),
+        )
+        .unwrap();
+        let bob_ss = MlKem1024::decapsulate(&pq_prekey, ciphertext.as_ref()).unwrap();
+        let bob = respond::<Sha512>(
+            b"pqxdh test",
+            &key(3),
+            &key(4),
+            None,
+            &key(1).public_key(),
+            &key(2).public_key(),
+            Some(bob_ss.as_ref()),
+        )
+        .unwrap();
+        assert_eq!(alice.as_bytes(), bob.as_bytes());
+    }
+
+    #[test]
+    fn rejects_low_order_points() {
+        let mut b = bundle(true);
+        b.one_time_prekey = Some(PublicKey::from_array(&[0u8; 32]));
+        assert!(initiate::<Sha256>(b"", &key(1), &key(2), &b, None).is_err());
+    }
+}
@@ -0,0 +1,439 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::asn1::{self, Type, oid, pkix};
+use super::curve::{P256, P384};
+use super::hash::{self, Hash};
+use super::{ecdsa, rsa};
+use crate::error::{Error, KeyFormatError};
+use crate::low::Entry;
+use crate::mid::ed25519;
+
+/// Verifies the signature on an X.509 certificate.
+///
+/// - `tbs_certificate` is the whole DER encoding of the certificate's
+///   `tbsCertificate`: this is the signed message.
+/// - `signature_algorithm` is the whole DER encoding of the certificate's
+///   `signatureAlgorithm` `AlgorithmIdentifier`.
+/// - `signature` is the contents of the certificate's `signatureValue`
+///   `BIT STRING`.
+/// - `issuer_spki` is the whole DER encoding of the issuer's
+///   `SubjectPublicKeyInfo`.
+///
+/// The supported signature algorithms are:
+///
+/// - `sha256WithRSAEncryption`, `sha384WithRSAEncryption` and
+///   `sha512WithRSAEncryption`, with `NULL` parameters
+///   ([RFC4055](https://datatracker.ietf.org/doc/html/rfc4055#section-5)).
+/// - `id-RSASSA-PSS` with SHA-256, SHA-384 or SHA-512, MGF1 with the same
+///   hash, and a salt as long as the hash output
+///   ([RFC4055](https://datatracker.ietf.org/doc/html/rfc4055#section-3.1)).
+/// - `ecdsa-with-SHA256`, `ecdsa-with-SHA384` and `ecdsa-with-SHA512`
+///   on P-256 and P-384, with absent parameters
+///   ([RFC5758](https://datatracker.ietf.org/doc/html/rfc5758#section-3.2)).
+/// - `id-Ed25519`, with absent parameters
+///   ([RFC8410](https://datatracker.ietf.org/doc/html/rfc8410#section-6)).
+///
+/// Other algorithms give `KeyFormatError::UnsupportedSignatureAlgorithm`.
+/// An issuer key of the wrong type gives `KeyFormatError::MismatchedSpkiAlgorithm`,
+/// and [`Error::BadSignature`] is returned if the signature is invalid.
+///
+/// Only the signature is checked: everything else needed to validate a
+/// certificate is the caller's job.  Other X.509 `SIGNED{}` structures
+/// (such as CRLs) can be verified in the same way.
+pub fn verify_signature(
+    tbs_certificate: &[u8],
+    signature_algorithm: &[u8],
+    signature: &[u8],
+    issuer_spki: &[u8],
+) -> Result<(), Error> {
+    let _entry = Entry::new_public();
+    let message = tbs_certificate;
+
+    match SignatureAlgorithm::from_der(signature_algorithm)? {
+        SignatureAlgorithm::RsaPkcs1(hash) => {
+            let key = rsa::VerifyingKey::from_spki_der(issuer_spki)?;
+            match hash {
+                HashAlgorithm::Sha256 => key.verify_pkcs1_sha256(signature, message),
+                HashAlgorithm::Sha384 => key.verify_pkcs1_sha384(signature, message),
+                HashAlgorithm::Sha512 => key.verify_pkcs1_sha512(signature, message),
+            }
+        }
+        SignatureAlgorithm::RsaPss(hash) => {
+            let key = rsa::VerifyingKey::from_spki_der(issuer_spki)?;
+            match hash {
+                HashAlgorithm::Sha256 => key.verify_pss_sha256(signature, message),
+                HashAlgorithm::Sha384 => key.verify_pss_sha384(signature, message),
+                HashAlgorithm::Sha512 => key.verify_pss_sha512(signature, message),
+            }
+        }
+        SignatureAlgorithm::Ecdsa(hash) => match hash {
+            HashAlgorithm::Sha256 => verify_ecdsa::<hash::Sha256>(message, signature, issuer_spki),
+            HashAlgorithm::Sha384 => verify_ecdsa::<hash::Sha384>(message, signature, issuer_spki),
+            HashAlgorithm::Sha512 => verify_ecdsa::<hash::Sha512>(message, signature, issuer_spki),
+        },
+        SignatureAlgorithm::Ed25519 => {
+            ed25519::VerifyingKey::from_spki_der(issuer_spki)?.verify(&[message], signature)
+        }
+    }
+}
+
+/// ECDSA keys may be on either curve; the SPKI parameters decide which.
+fn verify_ecdsa<H: Hash>(
+    message: &[u8],
+    signature: &[u8],
+    issuer_spki: &[u8],
+) -> Result<(), Error> {
+    match ecdsa::VerifyingKey::<P256>::from_spki_der(issuer_spki) {
+        Ok(key) => key.verify_asn1::<H>(&[message], signature),
+        Err(Error::KeyFormatError(KeyFormatError::MismatchedSpkiParameters)) => {
+            ecdsa::VerifyingKey::<P384>::from_spki_der(issuer_spki)?
+                .verify_asn1::<H>(&[message], signature)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SignatureAlgorithm {
+    RsaPkcs1(HashAlgorithm),
+    RsaPss(HashAlgorithm),
+    Ecdsa(HashAlgorithm),
+    Ed25519,
+}
+
+impl SignatureAlgorithm {
+    fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let alg = pkix::AlgorithmIdentifier::from_bytes(bytes).map_err(Error::Asn1Error)?;
+        let null = Some(asn1::Any::Null(asn1::Null));
+
+        let rsa_pkcs1 = [
+            (&oid::sha256WithRSAEncryption, HashAlgorithm::Sha256),
+            (&oid::sha384WithRSAEncryption, HashAlgorithm::Sha384),
+            (&oid::sha512WithRSAEncryption, HashAlgorithm::Sha512),
+        ];
+        let ecdsa = [
+            (&oid::ecdsa_with_SHA256, HashAlgorithm::Sha256),
+            (&oid::ecdsa_with_SHA384, HashAlgorithm::Sha384),
+            (&oid::ecdsa_with_SHA512, HashAlgorithm::Sha512),
+        ];
+
+        for (id, hash) in rsa_pkcs1 {
+            if alg.algorithm == *id && alg.parameters == null {
+                return Ok(Self::RsaPkcs1(hash));
+            }
+        }
+
+        for (id, hash) in ecdsa {
+            if alg.algorithm == *id && alg.parameters.is_none() {
+                return Ok(Self::Ecdsa(hash));
+            }
+        }
+
+        if alg.algorithm == oid::id_Ed25519 && alg.parameters.is_none() {
+            return Ok(Self::Ed25519);
+        }
+
+        if alg.algorithm == oid::id_RSASSA_PSS {
+            if let Some(asn1::Any::Sequence(params)) = &alg.parameters {
+                return Self::from_pss_params(params).map(Self::RsaPss);
+            }
+        }
+
+        Err(unsupported())
+    }
+
+    /// Only the combinations that are equivalent to TLS's `rsa_pss_rsae_*`
+    /// schemes are accepted.
+    fn from_pss_params(params: &asn1::Sequence<'_>) -> Result<HashAlgorithm, Error> {
+        let params = params
+            .decode::<pkix::RSASSAPSSParams<'_>>()
+            .map_err(Error::Asn1Error)?;
+
+        let hash = params
+            .hashAlgorithm
+            .inner()
+            .as_ref()
+            .ok_or_else(unsupported)
+            .and_then(HashAlgorithm::from_digest_algorithm)?;
+
+        let mgf = params
+            .maskGenAlgorithm
+            .inner()
+            .as_ref()
+            .ok_or_else(unsupported)?;
+        let mgf_hash = match &mgf.parameters {
+            Some(asn1::Any::Sequence(mgf_hash)) if mgf.algorithm == oid::id_mgf1 => mgf_hash
+                .decode::<pkix::AlgorithmIdentifier<'_>>()
+                .map_err(Error::Asn1Error)?,
+            _ => return Err(unsupported()),
+        };
+
+        if HashAlgorithm::from_digest_algorithm(&mgf_hash)? != hash {
+            return Err(unsupported());
+        }
+
+        match params.saltLength.inner() {
+            Some(len) if len.as_usize() == Ok(hash.output_len()) => {}
+            _ => return Err(unsupported()),
+        }
+
+        // the only defined value is the default, which DER omits
+        if params.trailerField.inner().is_some() {
+            return Err(unsupported());
+        }
+
+        Ok(hash)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HashAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// The parameters may be absent or `NULL`; RFC4055 requires both be accepted.
+    fn from_digest_algorithm(alg: &pkix::AlgorithmIdentifier<'_>) -> Result<Self, Error> {
+        match alg.parameters {
+            None | Some(asn1::Any::Null(_)) => {}
+            _ => return Err(unsupported()),
+        }
+
+        if alg.algorithm == oid::id_sha256 {
+            Ok(Self::Sha256)
+        } else if alg.algorithm == oid::id_sha384 {
+            Ok(Self::Sha384)
+        } else if alg.algorithm == oid::id_sha512 {
+            Ok(Self::Sha512)
+        } else {
+            Err(unsupported())
+        }
+    }
+
+    fn output_len(self) -> usize {
+        match self {
+            Self::Sha256 => 32,
+            Self::Sha384 => 48,
+            Self::Sha512 => 64,
+        }
+    }
+}
+
+fn unsupported() -> Error {
+    KeyFormatError::UnsupportedSignatureAlgorithm.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Splits `certificate` into the arguments for `verify_signature`.
+    fn split(certificate: &[u8]) -> (&[u8], &[u8], &[u8]) {
+        let mut items = asn1::Sequence::from_bytes(certificate)
+            .unwrap()
+            .items()
+            .unwrap();
+        let tbs_certificate = asn1::Sequence::parse(&mut items).unwrap();
+        let signature_algorithm = asn1::Sequence::parse(&mut items).unwrap();
+        let signature = asn1::BitString::parse(&mut items).unwrap();
+        items.check_end().unwrap();
+        (
+            tbs_certificate.as_bytes(),
+            signature_algorithm.as_bytes(),
+            signature.as_octets(),
+        )
+    }
+
+    fn check(certificate: &[u8], issuer_spki: &[u8], expected: SignatureAlgorithm) {
+        let (tbs, alg, signature) = split(certificate);
+        assert_eq!(SignatureAlgorithm::from_der(alg).unwrap(), expected);
+        verify_signature(tbs, alg, signature, issuer_spki).unwrap();
+
+        let mut tbs = tbs.to_vec();
+        let last = tbs.len() - 1;
+        tbs[last] ^= 1;
+        assert_eq!(
+            verify_signature(&tbs, alg, signature, issuer_spki),
+            Err(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn rsa() {
+        // These were produced by `openssl req -x509`, so are self-signed
+        let spki = include_bytes!("spki/rsa2048.spki.der");
+        for (certificate, expected) in [
+            (
+                &include_bytes!("x509/rsa2048-pkcs1-sha256.der")[..],
+                SignatureAlgorithm::RsaPkcs1(HashAlgorithm::Sha256),
+            ),
+            (
+                include_bytes!("x509/rsa2048-pkcs1-sha384.der"),
+                SignatureAlgorithm::RsaPkcs1(HashAlgorithm::Sha384),
+            ),
+            (
+                include_bytes!("x509/rsa2048-pkcs1-sha512.der"),
+                SignatureAlgorithm::RsaPkcs1(HashAlgorithm::Sha512),
+            ),
+            (
+                include_bytes!("x509/rsa2048-pss-sha256.der"),
+                SignatureAlgorithm::RsaPss(HashAlgorithm::Sha256),
+            ),
+            (
+                include_bytes!("x509/rsa2048-pss-sha384.der"),
+                SignatureAlgorithm::RsaPss(HashAlgorithm::Sha384),
+            ),
+            (
+                include_bytes!("x509/rsa2048-pss-sha512.der"),
+                SignatureAlgorithm::RsaPss(HashAlgorithm::Sha512),
+            ),
+        ] {
+            check(certificate, spki, expected);
+        }
+    }
+
+    #[test]
+    fn ecdsa() {
+        for (certificate, spki, expected) in [
+            (
+                &include_bytes!("x509/secp256r1-sha256.der")[..],
+                &include_bytes!("spki/secp256r1.spki.der")[..],
+                SignatureAlgorithm::Ecdsa(HashAlgorithm::Sha256),
+            ),
+            (
+                include_bytes!("x509/secp256r1-sha384.der"),
+                include_bytes!("spki/secp256r1.spki.der"),
+                SignatureAlgorithm::Ecdsa(HashAlgorithm::Sha384),
+            ),
+            (
+                include_bytes!("x509/secp384r1-sha384.der"),
+                include_bytes!("spki/secp384r1.spki.der"),
+                SignatureAlgorithm::Ecdsa(HashAlgorithm::Sha384),
+            ),
+            (
+                include_bytes!("x509/secp384r1-sha512.der"),
+                include_bytes!("spki/secp384r1.spki.der"),
+                SignatureAlgorithm::Ecdsa(HashAlgorithm::Sha512),
+            ),
+        ] {
+            check(certificate, spki, expected);
+        }
+    }
+
+    #[test]
+    fn ed25519() {
+        check(
+            include_bytes!("x509/ed25519.der"),
+            include_bytes!("spki/ed25519.spki.der"),
+            SignatureAlgorithm::Ed25519,
+        );
+    }
+
+    #[test]
+    fn pss_parameters() {
+        let (tbs, _, signature) = split(include_bytes!("x509/rsa2048-pss-sha256.der"));
+        let spki = include_bytes!("spki/rsa2048.spki.der");
+
+        // as produced by OpenSSL, but with the hash parameters absent rather than `NULL`
+        let alg = [
+            0x30, 0x3d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30,
+            0x30, 0xa0, 0x0d, 0x30, 0x0b, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+            0x02, 0x01, 0xa1, 0x1a, 0x30, 0x18, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d,
+            0x01, 0x01, 0x08, 0x30, 0x0b, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+            0x02, 0x01, 0xa2, 0x03, 0x02, 0x01, 0x20,
+        ];
+        verify_signature(tbs, &alg, signature, spki).unwrap();
+
+        // salt length of 20
+        let mut salt = alg;
+        salt[alg.len() - 1] = 0x14;
+        // MGF1 with SHA-384
+        let mut mgf = alg;
+        mgf[57] = 0x02;
+        // no MGF
+        let no_mgf = [
+            0x30, 0x21, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30,
+            0x14, 0xa0, 0x0d, 0x30, 0x0b, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04,
+            0x02, 0x01, 0xa2, 0x03, 0x02, 0x01, 0x20,
+        ];
+        // all defaults: SHA-1
+        let defaults = [
+            0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30,
+            0x00,
+        ];
+
+        for alg in [&salt[..], &mgf, &no_mgf, &defaults] {
+            assert_eq!(
+                verify_signature(tbs, alg, signature, spki),
+                Err(unsupported())
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let (tbs, alg, signature) = split(include_bytes!("x509/secp256r1-sha256.der"));
+        let p256 = include_bytes!("spki/secp256r1.spki.der");
+
+        // wrong key type
+        assert_eq!(
+            verify_signature(tbs, alg, signature, include_bytes!("spki/rsa2048.spki.der")),
+            Err(KeyFormatError::MismatchedSpkiAlgorithm.into())
+        );
+        assert_eq!(
+            verify_signature(tbs, alg, signature, include_bytes!("spki/x25519.spki.der")),
+            Err(KeyFormatError::MismatchedSpkiAlgorithm.into())
+        );
+
+        // wrong key
+        assert_eq!(
+            verify_signature(
+                tbs,
+                alg,
+                signature,
+                include_bytes!("spki/secp384r1.spki.der")
+            ),
+            Err(Error::BadSignature)
+        );
+
+        // `ecdsa-with-SHA256` with `NULL` parameters
+        let mut with_null = alg.to_vec();
+        with_null[1] += 2;
+        with_null.extend_from_slice(&[0x05, 0x00]);
+        assert_eq!(
+            verify_signature(tbs, &with_null, signature, p256),
+            Err(unsupported())
+        );
+
+        // `sha256WithRSAEncryption` with absent parameters
+        let (tbs, alg, signature) = split(include_bytes!("x509/rsa2048-pkcs1-sha256.der"));
+        let mut absent = alg[..alg.len() - 2].to_vec();
+        absent[1] -= 2;
+        assert_eq!(
+            verify_signature(
+                tbs,
+                &absent,
+                signature,
+                include_bytes!("spki/rsa2048.spki.der")
+            ),
+            Err(unsupported())
+        );
+
+        // `id-dsa-with-sha256`
+        let dsa = [
+            0x30, 0x0b, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x02,
+        ];
+        assert_eq!(
+            verify_signature(tbs, &dsa, signature, p256),
+            Err(unsupported())
+        );
+
+        assert_eq!(
+            verify_signature(tbs, &[0x30], signature, p256),
+            Err(Error::Asn1Error(asn1::Error::UnexpectedEof))
+        );
+    }
+}
@@ -50,6 +50,7 @@ mod error;
 mod test;
 
 // vvv Public API
+pub use dispatch::{Backend, set_backend};
 pub use error::Error;
 
 /// Non-API documentation
@@ -60,6 +61,8 @@ pub mod doc {
 
 /// Key agreement algorithms.
 pub mod key_agreement {
+    pub use crate::high::{age, double_ratchet, x3dh};
+
     /// X25519 key agreement.
     ///
     /// ```
@@ -76,6 +79,17 @@ pub mod key_agreement {
     /// assert_eq!(alice_shared_secret.0, bob_shared_secret.0);
     /// ```
     ///
+    /// Long-lived keys can be stored in PKCS#8 format:
+    ///
+    /// ```
+    /// use graviola::key_agreement::x25519::*;
+    ///
+    /// let key = StaticPrivateKey::new_random().unwrap();
+    /// let pem = key.to_pkcs8_pem().unwrap();
+    /// let loaded = StaticPrivateKey::from_pkcs8_pem(&pem).unwrap();
+    /// assert_eq!(loaded.public_key().as_bytes(), key.public_key().as_bytes());
+    /// ```
+    ///
     /// See [RFC7748](https://datatracker.ietf.org/doc/html/rfc7748).
     pub mod x25519 {
         pub use crate::mid::x25519::{PrivateKey, PublicKey, SharedSecret, StaticPrivateKey};
@@ -138,13 +152,77 @@ pub mod key_agreement {
     pub mod p384 {
         pub use crate::mid::p384::{PrivateKey, PublicKey, SharedSecret, StaticPrivateKey};
     }
+
+    /// Finite-field Diffie-Hellman, with the groups from RFC7919.
+    ///
+    /// ```
+    /// use graviola::key_agreement::ffdhe::*;
+    ///
+    /// let alice = PrivateKey::new_random(Group::Ffdhe2048).unwrap();
+    /// let bob = PrivateKey::new_random(Group::Ffdhe2048).unwrap();
+    ///
+    /// let alice_pub = alice.public_key();
+    /// let bob_pub = bob.public_key();
+    ///
+    /// let alice_shared_secret = PublicKey::from_bytes(Group::Ffdhe2048, bob_pub.as_bytes())
+    ///     .and_then(move |bob_pub| alice.diffie_hellman(&bob_pub))
+    ///     .expect("bob gave an invalid public key");
+    ///
+    /// let bob_shared_secret = PublicKey::from_bytes(Group::Ffdhe2048, alice_pub.as_bytes())
+    ///     .and_then(move |alice_pub| bob.diffie_hellman(&alice_pub))
+    ///     .expect("alice gave an invalid public key");
+    ///
+    /// assert_eq!(alice_shared_secret.as_bytes(), bob_shared_secret.as_bytes());
+    /// ```
+    ///
+    /// See [RFC7919](https://datatracker.ietf.org/doc/html/rfc7919).
+    pub mod ffdhe {
+        pub use crate::high::ffdhe::{Group, PrivateKey, PublicKey, SharedSecret};
+    }
 }
 
 /// Public key signatures.
+///
+/// [`Signer`](crate::signing::Signer) and [`Verifier`](crate::signing::Verifier)
+/// are implemented by the keys here, so code can be generic over the algorithm:
+///
+/// ```
+/// use graviola::signing::{ml_dsa::ml_dsa_65, Signer, Verifier};
+///
+/// fn sign_and_verify(signer: &impl Signer, verifier: &impl Verifier) {
+///     let signature = signer.sign(b"hello world").unwrap();
+///     verifier.verify(b"hello world", signature.as_ref()).unwrap();
+/// }
+///
+/// let signing_key = ml_dsa_65::SigningKey::new_random().unwrap();
+/// sign_and_verify(&signing_key, &signing_key.verifying_key());
+/// ```
+///
+/// With the `rustcrypto-signature` feature, the Ed25519, ECDSA and RSA keys
+/// also implement the [`signature`](https://docs.rs/signature/2) crate's
+/// `Signer` and `Verifier` traits, with the same algorithms.  Their signature
+/// type is [`SignatureBuf`](crate::signing::SignatureBuf), which implements
+/// `SignatureEncoding`.
+///
+/// With the `serde` feature, verifying keys and [`SignatureBuf`](crate::signing::SignatureBuf)
+/// implement `Serialize` and `Deserialize`, as do the key agreement public keys
+/// and [`SpkiFingerprint`](crate::x509::SpkiFingerprint).  They are written as
+/// base64 in human-readable formats, and as a byte string otherwise.
+/// Private keys are not supported.
 pub mod signing {
+    pub use crate::high::signing::{SignatureBuf, Signer, Verifier};
+
     /// RSA signatures.
+    ///
+    /// The RSA keys do not determine the signature scheme, so [`Signer`] and
+    /// [`Verifier`] are implemented by wrappers naming the scheme, such as
+    /// [`RsaPssSha256`](crate::signing::rsa::RsaPssSha256).
     pub mod rsa {
-        pub use crate::high::rsa::{SigningKey, VerifyingKey};
+        pub use crate::high::rsa::{KeySize, SigningKey, VerifyingKey};
+        pub use crate::high::signing::{
+            RsaPkcs1Sha256, RsaPkcs1Sha384, RsaPkcs1Sha512, RsaPssSha256, RsaPssSha384,
+            RsaPssSha512,
+        };
     }
 
     /// ECDSA signatures.
@@ -152,27 +230,1328 @@ pub mod signing {
         pub use crate::high::curve::{Curve, P256, P384};
         pub use crate::high::ecdsa::{SigningKey, VerifyingKey};
     }
+
+    /// Ed25519 signatures, as specified in [RFC8032](https://datatracker.ietf.org/doc/html/rfc8032).
+    ///
+    /// ```
+    /// use graviola::signing::ed25519::*;
+    ///
+    /// let signing_key = SigningKey::new_random().unwrap();
+    /// let signature = signing_key.sign(&[b"hello world"]);
+    ///
+    /// let verifying_key = VerifyingKey::from_bytes(signing_key.verifying_key().as_bytes()).unwrap();
+    /// verifying_key.verify(&[b"hello world"], &signature).unwrap();
+    ///
+    /// // public keys are commonly exchanged in X.509 `SubjectPublicKeyInfo` format
+    /// let mut buffer = [0u8; 64];
+    /// let spki = verifying_key.to_spki_der(&mut buffer).unwrap();
+    /// assert_eq!(VerifyingKey::from_spki_der(spki).unwrap().as_bytes(), verifying_key.as_bytes());
+    /// ```
+    pub mod ed25519 {
+        pub use crate::mid::ed25519::{
+            SEED_LEN, SIGNATURE_LEN, SigningKey, VERIFYING_KEY_LEN, VerifyingKey,
+        };
+    }
+
+    pub use crate::high::slip10;
+
+    /// DSA signature verification, for legacy signatures only.
+    ///
+    /// See [FIPS186-4](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.186-4.pdf).
+    pub mod dsa {
+        pub use crate::high::dsa::VerifyingKey;
+    }
+
+    /// ML-DSA signatures, as specified in [FIPS204](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.204.pdf).
+    ///
+    /// ```
+    /// use graviola::signing::ml_dsa::ml_dsa_65::*;
+    ///
+    /// let signing_key = SigningKey::new_random().unwrap();
+    /// let signature = signing_key.sign(b"context", &[b"hello world"]).unwrap();
+    ///
+    /// let verifying_key = VerifyingKey::from_bytes(signing_key.verifying_key().as_bytes()).unwrap();
+    /// verifying_key
+    ///     .verify(b"context", &[b"hello world"], &signature)
+    ///     .unwrap();
+    /// ```
+    pub mod ml_dsa {
+        pub use crate::mid::mldsa::{ml_dsa_44, ml_dsa_65, ml_dsa_87};
+    }
+
+    /// FN-DSA (Falcon) signature verification.
+    ///
+    /// ```
+    /// use graviola::signing::fn_dsa::fn_dsa_512::*;
+    ///
+    /// fn check(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    ///     VerifyingKey::from_bytes(public_key)
+    ///         .and_then(|key| key.verify(&[message], signature))
+    ///         .is_ok()
+    /// }
+    /// ```
+    pub mod fn_dsa {
+        pub use crate::mid::fndsa::{fn_dsa_512, fn_dsa_1024};
+    }
+
+    /// SLH-DSA signatures, as specified in [FIPS205](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.205.pdf).
+    ///
+    /// ```
+    /// use graviola::signing::slh_dsa::slh_dsa_sha2_128f::*;
+    ///
+    /// let signing_key = SigningKey::new_random().unwrap();
+    /// let signature = signing_key.sign(b"context", &[b"hello world"]).unwrap();
+    ///
+    /// let verifying_key = VerifyingKey::from_bytes(signing_key.verifying_key().as_bytes()).unwrap();
+    /// verifying_key
+    ///     .verify(b"context", &[b"hello world"], &signature)
+    ///     .unwrap();
+    /// ```
+    pub mod slh_dsa {
+        pub use crate::mid::slhdsa::{
+            slh_dsa_sha2_128f, slh_dsa_sha2_128s, slh_dsa_sha2_192f, slh_dsa_sha2_192s,
+            slh_dsa_sha2_256f, slh_dsa_sha2_256s, slh_dsa_shake_128f, slh_dsa_shake_128s,
+            slh_dsa_shake_192f, slh_dsa_shake_192s, slh_dsa_shake_256f, slh_dsa_shake_256s,
+        };
+    }
+}
+
+/// Key encapsulation mechanisms.
+///
+/// [`Kem`](crate::kem::Kem) is implemented by all the KEMs here, and by the
+/// Diffie-Hellman functions.
+pub mod kem {
+    pub use super::high::curve::{P256, P384};
+    pub use super::high::kem::{Kem, MlKem512, MlKem768, MlKem1024, X25519, X25519MlKem768, XWing};
+
+    /// ML-KEM, as specified in [FIPS203](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.203.pdf).
+    ///
+    /// ```
+    /// use graviola::kem::ml_kem::ml_kem_768::*;
+    ///
+    /// let alice = DecapsulationKey::new_random().unwrap();
+    /// let alice_pub = EncapsulationKey::from_bytes(alice.encapsulation_key().as_bytes()).unwrap();
+    ///
+    /// let (ciphertext, bob_shared_secret) = alice_pub.encapsulate().unwrap();
+    /// let alice_shared_secret = alice.decapsulate(&ciphertext).unwrap();
+    /// assert_eq!(alice_shared_secret.0, bob_shared_secret.0);
+    /// ```
+    pub mod ml_kem {
+        pub use crate::mid::mlkem::{SharedSecret, ml_kem_512, ml_kem_768, ml_kem_1024};
+    }
+
+    /// The X25519MLKEM768 hybrid, as used in TLS.
+    ///
+    /// ```
+    /// use graviola::kem::x25519_mlkem768::*;
+    ///
+    /// // the client sends its encapsulation key as its key share
+    /// let client = DecapsulationKey::new_random().unwrap();
+    /// let client_share = client.encapsulation_key();
+    ///
+    /// // the server replies with a ciphertext
+    /// let (server_share, server_shared_secret) =
+    ///     EncapsulationKey::from_bytes(client_share.as_bytes())
+    ///         .and_then(|ek| ek.encapsulate())
+    ///         .expect("client gave an invalid key share");
+    ///
+    /// let client_shared_secret = client.decapsulate(&server_share).unwrap();
+    /// assert_eq!(client_shared_secret.0, server_shared_secret.0);
+    /// ```
+    pub mod x25519_mlkem768 {
+        pub use crate::mid::x25519mlkem768::{
+            CIPHERTEXT_LEN, DecapsulationKey, ENCAPSULATION_KEY_LEN, EncapsulationKey,
+            SHARED_SECRET_LEN, SharedSecret,
+        };
+    }
+
+    /// The X-Wing hybrid KEM, combining ML-KEM-768 and X25519.
+    ///
+    /// ```
+    /// use graviola::kem::xwing::*;
+    ///
+    /// let alice = DecapsulationKey::new_random().unwrap();
+    /// let alice_pub = EncapsulationKey::from_bytes(alice.encapsulation_key().as_bytes()).unwrap();
+    ///
+    /// let (ciphertext, bob_shared_secret) = alice_pub.encapsulate().unwrap();
+    /// let alice_shared_secret = alice.decapsulate(&ciphertext).unwrap();
+    /// assert_eq!(alice_shared_secret.0, bob_shared_secret.0);
+    /// ```
+    ///
+    /// See [draft-connolly-cfrg-xwing-kem](https://datatracker.ietf.org/doc/draft-connolly-cfrg-xwing-kem/).
+    pub mod xwing {
+        pub use crate::mid::xwing::{
+            CIPHERTEXT_LEN, DECAPSULATION_KEY_LEN, DecapsulationKey, ENCAPSULATION_KEY_LEN,
+            EncapsulationKey, SharedSecret,
+        };
+    }
 }
 
 /// Cryptographic hash functions.
+///
+/// With the `rustcrypto-digest` feature, the SHA-2 contexts in [`sha2`](crate::hashing::sha2)
+/// also implement the [`digest`](https://docs.rs/digest/0.10) crate's `Digest`
+/// trait (and `BlockSizeUser`, for use with the `hmac` crate).
 pub mod hashing {
-    pub use super::high::hash::{Hash, HashContext, HashOutput, Sha256, Sha384, Sha512};
-    pub use super::high::hmac;
-    pub use super::mid::sha2;
+    pub use super::high::hash::{
+        Blake2b, Blake2s, Digest, Hash, HashContext, HashOutput, Sha256, Sha384, Sha512,
+    };
+    pub use super::high::{hkdf, hmac, key_chain, otp, pbkdf2, tree_hash};
+    pub use super::mid::{blake2, sha2};
 }
 
 /// Authenticated encryption.
+///
+/// [`Aead`](crate::aead::Aead) is implemented by all the AEADs here, so
+/// protocol code can be generic over the AEAD used:
+///
+/// ```
+/// use graviola::aead::{Aead, Aes256Gcm, ChaCha20Poly1305};
+///
+/// fn round_trip<A: Aead>() {
+///     let key = A::new(&vec![0x42; A::KEY_LEN]).unwrap();
+///     let nonce = vec![0u8; A::NONCE_LEN];
+///     let mut message = *b"hello world";
+///     let mut tag = vec![0u8; A::TAG_LEN];
+///
+///     key.seal(&nonce, b"aad", &mut message, &mut tag).unwrap();
+///     key.open(&nonce, b"aad", &mut message, &tag).unwrap();
+///     assert_eq!(&message, b"hello world");
+/// }
+///
+/// round_trip::<Aes256Gcm>();
+/// round_trip::<ChaCha20Poly1305>();
+/// ```
+///
+/// With the `rustcrypto-aead` feature, [`Aes128Gcm`](crate::aead::Aes128Gcm),
+/// [`Aes256Gcm`](crate::aead::Aes256Gcm),
+/// [`ChaCha20Poly1305`](crate::aead::ChaCha20Poly1305) and
+/// [`XChaCha20Poly1305`](crate::aead::XChaCha20Poly1305) also implement the
+/// [`aead`](https://docs.rs/aead/0.5) crate's `KeyInit` and `AeadInPlace`
+/// traits (and so `Aead`), for use with crates generic over those.
+///
+/// [`AeadKeyring`](crate::aead::AeadKeyring) holds several keys, for key rotation:
+///
+/// ```
+/// use graviola::aead::{Aead, AeadKeyring, Aes256Gcm};
+///
+/// let mut keyring = AeadKeyring::<Aes256Gcm, 2>::new();
+/// keyring.add(1, Aes256Gcm::new(&[0x11; 32]).unwrap()).unwrap();
+///
+/// let mut sealed = [0u8; 5 + AeadKeyring::<Aes256Gcm, 2>::OVERHEAD];
+/// let sealed = keyring.seal(b"aad", b"hello", &mut sealed).unwrap();
+///
+/// keyring.add(2, Aes256Gcm::new(&[0x22; 32]).unwrap()).unwrap();
+/// keyring.set_primary(2).unwrap();
+///
+/// let mut message = [0u8; 5];
+/// assert_eq!(keyring.open(b"aad", sealed, &mut message).unwrap(), b"hello");
+/// ```
+///
+/// [`SecretStreamPush`](crate::aead::SecretStreamPush) encrypts a stream of
+/// messages, compatibly with libsodium's `crypto_secretstream_xchacha20poly1305`:
+///
+/// ```
+/// use graviola::aead::{SecretStreamPull, SecretStreamPush, SecretStreamTag};
+///
+/// let key = [0x42; 32];
+/// let (mut push, header) = SecretStreamPush::new(&key).unwrap();
+///
+/// let mut first = [0u8; 5 + SecretStreamPush::OVERHEAD];
+/// let first = push.push(b"hello", b"", SecretStreamTag::Message, &mut first).unwrap();
+/// let mut last = [0u8; 5 + SecretStreamPush::OVERHEAD];
+/// let last = push.push(b"world", b"", SecretStreamTag::Final, &mut last).unwrap();
+///
+/// let mut pull = SecretStreamPull::new(&key, &header);
+/// let mut message = [0u8; 5];
+/// assert_eq!(
+///     pull.pull(first, b"", &mut message).unwrap(),
+///     (&b"hello"[..], SecretStreamTag::Message)
+/// );
+/// assert_eq!(
+///     pull.pull(last, b"", &mut message).unwrap(),
+///     (&b"world"[..], SecretStreamTag::Final)
+/// );
+/// ```
 pub mod aead {
+    pub use super::high::keyring::AeadKeyring;
+    pub use super::high::stream;
+    pub use super::mid::aead::{Aead, Aes128Gcm, Aes256Gcm};
     pub use super::mid::aes_gcm::AesGcm;
+    pub use super::mid::aes_siv::AesSiv;
     pub use super::mid::chacha20poly1305::ChaCha20Poly1305;
+    pub use super::mid::secretstream::{SecretStreamPull, SecretStreamPush, SecretStreamTag};
     pub use super::mid::xchacha20poly1305::XChaCha20Poly1305;
 }
 
+/// Wrapping of data-encryption keys with a key-encryption key.
+///
+/// ```
+/// use graviola::key_wrap::KeyWrapKey;
+///
+/// let kek = KeyWrapKey::new(&[0x42; 32]).unwrap();
+/// let dek = [0x24; 32];
+///
+/// let mut wrapped = [0u8; 32 + KeyWrapKey::OVERHEAD];
+/// let wrapped = kek.wrap(&dek, &mut wrapped).unwrap();
+///
+/// let mut unwrapped = [0u8; 32];
+/// assert_eq!(kek.unwrap(wrapped, &mut unwrapped).unwrap(), &dek);
+/// ```
+pub mod key_wrap {
+    pub use super::high::key_wrap::KeyWrapKey;
+}
+
+/// Hybrid public key encryption, as specified in [RFC9180](https://datatracker.ietf.org/doc/html/rfc9180).
+///
+/// ```
+/// use graviola::hpke::*;
+/// use graviola::kem::Kem;
+///
+/// type Hpke = Suite<X25519, HkdfSha256, ChaCha20Poly1305>;
+///
+/// let recipient = X25519::generate().unwrap();
+/// let recipient_pub = X25519::encapsulation_key(&recipient);
+///
+/// let mut message = *b"hello world";
+/// let mut tag = [0u8; 16];
+/// let enc = Hpke::seal(&recipient_pub, b"info", b"aad", &mut message, &mut tag).unwrap();
+///
+/// Hpke::open(&enc, &recipient, b"info", b"aad", &mut message, &tag).unwrap();
+/// assert_eq!(&message, b"hello world");
+/// ```
+pub mod hpke {
+    pub use super::high::curve::P256;
+    pub use super::high::hpke::{
+        Aes128Gcm, Aes256Gcm, ChaCha20Poly1305, DhKem, ExportOnly, HkdfSha256, HkdfSha384,
+        HkdfSha512, HpkeAead, HpkeKdf, HpkeKem, RecipientContext, SenderContext, Suite,
+    };
+    pub use super::high::kem::{MlKem512, MlKem768, MlKem1024, X25519};
+}
+
+/// Single-shot public key encryption ("envelope encryption").
+///
+/// See [`envelope::Envelope`] for the format.  Any HPKE KEM may be used.
+///
+/// ```
+/// use graviola::envelope::*;
+/// use graviola::kem::Kem;
+///
+/// let recipient = X25519::generate().unwrap();
+/// let recipient_pub = X25519::encapsulation_key(&recipient);
+///
+/// let mut envelope = [0u8; 128];
+/// let envelope =
+///     Envelope::<X25519>::seal_to_recipient(&recipient_pub, b"aad", b"hello world", &mut envelope)
+///         .unwrap();
+///
+/// let mut message = [0u8; 11];
+/// let message = Envelope::<X25519>::open(&recipient, b"aad", envelope, &mut message).unwrap();
+/// assert_eq!(message, b"hello world");
+/// ```
+pub mod envelope {
+    pub use super::high::curve::P256;
+    pub use super::high::envelope::Envelope;
+    pub use super::high::kem::{MlKem512, MlKem768, MlKem1024, X25519};
+}
+
+/// Primitives for the [Noise protocol framework](https://noiseprotocol.org/noise.html).
+///
+/// These are the DH, cipher and hash functions from section 12 of the
+/// specification: `25519`, `ChaChaPoly`, `AESGCM`, `SHA256`, `SHA512`,
+/// `BLAKE2s` and `BLAKE2b`.  The handshake and state machines are not
+/// provided.
+///
+/// ```
+/// use graviola::noise::*;
+///
+/// let (_ck, k) = Blake2s::hkdf2(b"chaining key", b"dh output");
+/// let k: &[u8; 32] = k.as_ref().try_into().unwrap();
+///
+/// let mut message = *b"hello world";
+/// let mut tag = [0u8; 16];
+/// ChaChaPoly::new(k).encrypt(0, b"h", &mut message, &mut tag);
+/// ChaChaPoly::new(k).decrypt(0, b"h", &mut message, &tag).unwrap();
+/// assert_eq!(&message, b"hello world");
+/// ```
+pub mod noise {
+    pub use super::high::hash::{Blake2b, Blake2s, Sha256, Sha512};
+    pub use super::high::kem::X25519;
+    pub use super::high::noise::{AesGcm, ChaChaPoly, NoiseCipher, NoiseDh, NoiseHash};
+}
+
+/// Primitives for [QUIC](https://datatracker.ietf.org/doc/html/rfc9001) packet protection.
+///
+/// A [`quic::Secret`] for each direction yields a [`quic::PacketKey`] for
+/// protecting payloads, and a [`quic::HeaderProtectionKey`] for protecting
+/// headers.  [`quic::Secret::next()`] gives the secret for the next
+/// key phase.
+///
+/// ```
+/// use graviola::quic::*;
+///
+/// let (client, _server) = Secret::initial(b"connection id").unwrap();
+/// let packet_key = client.packet_key().unwrap();
+/// let header_key = client.header_protection_key().unwrap();
+///
+/// // a short header, with a one-byte packet number
+/// let mut header = [0x40, 0x2a];
+/// let mut payload = *b"payload long enough to sample";
+/// let mut tag = [0u8; PacketKey::TAG_LEN];
+/// packet_key.encrypt_in_place(42, &header, &mut payload, &mut tag);
+///
+/// // the sample starts 4 bytes after the start of the packet number
+/// let sample = &payload[3..3 + HeaderProtectionKey::SAMPLE_LEN];
+/// let (first, packet_number) = header.split_at_mut(1);
+/// header_key.encrypt_in_place(sample, &mut first[0], packet_number).unwrap();
+///
+/// // receiver
+/// let (first, packet_number) = header.split_at_mut(1);
+/// header_key.decrypt_in_place(sample, &mut first[0], packet_number).unwrap();
+/// packet_key.decrypt_in_place(42, &header, &mut payload, &tag).unwrap();
+/// assert_eq!(&payload, b"payload long enough to sample");
+/// ```
+pub mod quic {
+    pub use super::high::quic::{Algorithm, HeaderProtectionKey, PacketKey, Secret};
+}
+
+/// SRTP and SRTCP with AES-GCM, as specified in
+/// [RFC7714](https://datatracker.ietf.org/doc/html/rfc7714).
+///
+/// ```
+/// use graviola::srtp::*;
+///
+/// let keys = SessionKeys::new(Profile::AeadAes128Gcm, &[0x42; 16], &[0x24; 12]).unwrap();
+///
+/// let header = [0x80, 0x60, 0x00, 0x01, 0, 0, 0, 0, 0x12, 0x34, 0x56, 0x78];
+/// let mut payload = *b"media";
+/// let mut tag = [0u8; SessionKeys::TAG_LEN];
+/// keys.encrypt_rtp(0, &header, &mut payload, &mut tag).unwrap();
+///
+/// keys.decrypt_rtp(0, &header, &mut payload, &tag).unwrap();
+/// assert_eq!(&payload, b"media");
+/// ```
+pub mod srtp {
+    pub use super::high::srtp::{Profile, SessionKeys};
+}
+
+/// Oblivious pseudorandom functions, as specified in
+/// [RFC9497](https://datatracker.ietf.org/doc/html/rfc9497).
+///
+/// The base ([`oprf::Oprf`]), verifiable ([`oprf::Voprf`]) and partially-oblivious
+/// ([`oprf::Poprf`]) modes are provided, over [`oprf::Ristretto255`] and [`oprf::P256`].
+///
+/// ```
+/// use graviola::oprf::*;
+///
+/// // server
+/// let key = PrivateKey::<Ristretto255>::new_random().unwrap();
+/// let public_key = key.public_key();
+///
+/// // client
+/// let (blind, blinded) = Voprf::blind(b"input").unwrap();
+///
+/// // server
+/// let (evaluated, proof) = Voprf::blind_evaluate(&key, &blinded).unwrap();
+///
+/// // client
+/// let output =
+///     Voprf::finalize(b"input", &blind, &evaluated, &blinded, &public_key, &proof).unwrap();
+/// assert_eq!(output, Voprf::evaluate(&key, b"input").unwrap());
+/// ```
+pub mod oprf {
+    pub use super::high::curve::P256;
+    pub use super::high::group::Ristretto255;
+    pub use super::high::oprf::{
+        Blind, BlindedElement, Ciphersuite, EvaluatedElement, Oprf, Poprf, PrivateKey, Proof,
+        PublicKey, TweakedKey, Voprf,
+    };
+}
+
+/// Password-authenticated key exchange.
+pub mod pake {
+    /// OPAQUE, as specified in [RFC9807](https://datatracker.ietf.org/doc/html/rfc9807).
+    ///
+    /// ```
+    /// use graviola::pake::opaque::*;
+    ///
+    /// let server = ServerSetup::<Ristretto255>::new_random().unwrap();
+    /// let ids = Identifiers::default();
+    /// // use a memory-hard function here
+    /// let ksf = NoStretching;
+    ///
+    /// // registration
+    /// let (client, request) = ClientRegistration::start(b"password").unwrap();
+    /// let response = server.registration_response(&request, b"alice").unwrap();
+    /// let (record, export_key) = client
+    ///     .finish(b"password", &response, &ids, &ksf)
+    ///     .unwrap();
+    ///
+    /// // login
+    /// let (client, ke1) = ClientLogin::start(b"password").unwrap();
+    /// let (server_login, ke2) =
+    ///     ServerLogin::start(&server, Some(&record), b"alice", &ke1, b"my app", &ids).unwrap();
+    /// let (ke3, client_key, client_export_key) = client
+    ///     .finish(b"password", &ke2, b"my app", &ids, &ksf)
+    ///     .unwrap();
+    /// let server_key = server_login.finish(&ke3).unwrap();
+    ///
+    /// assert_eq!(client_key.as_bytes(), server_key.as_bytes());
+    /// assert_eq!(export_key.as_bytes(), client_export_key.as_bytes());
+    /// ```
+    pub mod opaque {
+        pub use crate::high::curve::P256;
+        pub use crate::high::group::Ristretto255;
+        pub use crate::high::opaque::{
+            ClientLogin, ClientRegistration, ExportKey, Identifiers, Ke1, Ke2, Ke3, KeyStretching,
+            NoStretching, RegistrationRecord, RegistrationRequest, RegistrationResponse,
+            ServerLogin, ServerSetup, SessionKey,
+        };
+        pub use crate::high::oprf::{Ciphersuite, PrivateKey, PublicKey};
+    }
+
+    /// SPAKE2+, as specified in [RFC9383](https://datatracker.ietf.org/doc/html/rfc9383).
+    ///
+    /// ```
+    /// use graviola::pake::spake2plus::*;
+    ///
+    /// // `w0s || w1s`: use a memory-hard function of the password here
+    /// let key = ProverKey::from_pbkdf_output(&[0x55; 80]).unwrap();
+    /// let record = key.registration_record();
+    ///
+    /// let (prover, share_p) = Prover::<P256Sha256HmacSha256>::start(&key).unwrap();
+    /// let (verifier, share_v, confirm_v) =
+    ///     Verifier::start(&record, &share_p, b"my app", b"alice", b"bob").unwrap();
+    /// let (confirm_p, prover_key) = prover
+    ///     .finish(&share_v, &confirm_v, b"my app", b"alice", b"bob")
+    ///     .unwrap();
+    /// let verifier_key = verifier.finish(&confirm_p).unwrap();
+    ///
+    /// assert_eq!(prover_key.as_bytes(), verifier_key.as_bytes());
+    /// ```
+    pub mod spake2plus {
+        pub use crate::high::spake2plus::{
+            Ciphersuite, Confirmation, Matter, P256Sha256CmacAes128, P256Sha256HmacSha256,
+            P256Sha512CmacAes128, P256Sha512HmacSha512, Prover, ProverKey, RegistrationRecord,
+            Share, SharedKey, Verifier,
+        };
+    }
+
+    /// SRP-6a, as specified in [RFC5054](https://datatracker.ietf.org/doc/html/rfc5054).
+    ///
+    /// ```
+    /// use graviola::hashing::Sha256;
+    /// use graviola::pake::srp::*;
+    ///
+    /// // registration
+    /// let salt = b"a random salt";
+    /// let verifier =
+    ///     PasswordVerifier::new::<Sha256>(Group::Srp2048, b"alice", b"password", salt).unwrap();
+    ///
+    /// // login
+    /// let (client, client_public) = Client::<Sha256>::start(Group::Srp2048).unwrap();
+    /// let (server, server_public) = Server::<Sha256>::start(Group::Srp2048, &verifier).unwrap();
+    /// let (client, client_proof) = client
+    ///     .finish(b"alice", b"password", salt, &server_public)
+    ///     .unwrap();
+    /// let (server_proof, server_key) = server
+    ///     .finish(b"alice", salt, &client_public, &client_proof)
+    ///     .unwrap();
+    /// let client_key = client.verify(&server_proof).unwrap();
+    ///
+    /// assert_eq!(client_key.as_bytes(), server_key.as_bytes());
+    /// ```
+    pub mod srp {
+        pub use crate::high::srp::{
+            Client, ClientSession, Group, PasswordVerifier, Proof, PublicValue, Server, SessionKey,
+        };
+    }
+}
+
+/// Secret sharing.
+pub mod secret_sharing {
+    pub use crate::high::shamir;
+}
+
+/// Strict DER encoding and decoding.
+///
+/// This is the DER support used internally for PKCS#8, SPKI, SEC.1 and ECDSA
+/// signatures.  It is minimal rather than general: only the types and lengths
+/// needed here are supported, and anything that is not distinguished encoding
+/// is rejected.  Parsing borrows from the input, and encoding writes to a
+/// caller-supplied buffer.
+///
+/// ```
+/// use graviola::der::*;
+///
+/// // an ECDSA signature is `SEQUENCE { r INTEGER, s INTEGER }`
+/// let signature = [0x30, 0x07, 0x02, 0x01, 0x05, 0x02, 0x02, 0x00, 0x80];
+/// let mut items = Sequence::from_bytes(&signature).unwrap().items().unwrap();
+/// let r = Integer::parse(&mut items).unwrap();
+/// let s = Integer::parse(&mut items).unwrap();
+/// items.check_end().unwrap();
+/// assert_eq!(r.as_usize(), Ok(5));
+/// assert_eq!(s.as_ref(), &[0x00, 0x80]);
+///
+/// let mut buffer = [0u8; 4];
+/// let used = s.encode(&mut Encoder::new(&mut buffer)).unwrap();
+/// assert_eq!(&buffer[..used], &signature[5..]);
+///
+/// // non-minimal encodings are rejected
+/// assert_eq!(
+///     Integer::from_bytes(&[0x02, 0x02, 0x00, 0x05]).err(),
+///     Some(Error::IntegerOutOfRange)
+/// );
+/// ```
+pub mod der {
+    pub use crate::high::asn1::{
+        Any, BitString, Encoder, Error, Integer, Null, ObjectId, OctetString, Parser, Sequence,
+        Type,
+    };
+}
+
+/// JSON Object Signing and Encryption (JOSE), in the compact serialization.
+///
+/// JWS ([RFC7515](https://datatracker.ietf.org/doc/html/rfc7515)) is supported
+/// with the `ES256`, `ES384`, `RS256`, `RS384`, `RS512`, `PS256`, `PS384`,
+/// `PS512` and `EdDSA` algorithms.  This is enough to issue and validate
+/// signed JWTs; checking the claims within is left to the caller.
+///
+/// JWE ([RFC7516](https://datatracker.ietf.org/doc/html/rfc7516)) is supported
+/// with the `dir`, `ECDH-ES`, `ECDH-ES+A128KW` (on P-256, P-384 and X25519)
+/// and `RSA-OAEP-256` key management algorithms, and `A128GCM` and `A256GCM`
+/// content encryption.
+///
+/// ```
+/// use graviola::jose::*;
+/// use graviola::signing::ed25519::SigningKey;
+///
+/// let key = SigningKey::new_random().unwrap();
+/// let header = JwsHeader {
+///     key_id: Some("2024-01"),
+///     media_type: Some("JWT"),
+///     ..JwsHeader::new(JwsAlgorithm::EdDsa)
+/// };
+/// let token = JwsSigningKey::Ed25519(&key)
+///     .sign(&header, br#"{"sub":"1234567890"}"#)
+///     .unwrap();
+///
+/// // choose a key using the (unauthenticated) `kid`
+/// let jws = Jws::decode(&token).unwrap();
+/// assert_eq!(jws.key_id(), Some("2024-01"));
+/// let verifying_key = key.verifying_key();
+/// let payload = jws.verify(JwsVerifyingKey::Ed25519(&verifying_key)).unwrap();
+/// assert_eq!(payload, br#"{"sub":"1234567890"}"#);
+/// ```
+///
+/// ```
+/// use graviola::jose::*;
+/// use graviola::key_agreement::x25519::StaticPrivateKey;
+///
+/// let key = StaticPrivateKey::new_random().unwrap();
+/// let header = JweHeader::new(JweAlgorithm::EcdhEs, JweEncryption::A256Gcm);
+/// let public_key = key.public_key();
+/// let token = JweEncryptionKey::X25519(&public_key)
+///     .encrypt(&header, b"hello world")
+///     .unwrap();
+///
+/// let jwe = Jwe::decode(&token).unwrap();
+/// assert_eq!(jwe.encryption(), JweEncryption::A256Gcm);
+/// let plaintext = jwe.decrypt(JweDecryptionKey::X25519(&key)).unwrap();
+/// assert_eq!(plaintext, b"hello world");
+/// ```
+pub mod jose {
+    pub use crate::high::jose::{
+        Jwe, JweAlgorithm, JweDecryptionKey, JweEncryption, JweEncryptionKey, JweHeader, Jws,
+        JwsAlgorithm, JwsHeader, JwsSigningKey, JwsVerifyingKey, UnsignedJws,
+    };
+}
+
+/// Private keys held outside this crate, such as in an HSM, a PKCS#11 token,
+/// or a cloud KMS.
+///
+/// Implement [`ExternalSigner`](crate::external::ExternalSigner) or
+/// [`ExternalDecrypter`](crate::external::ExternalDecrypter) for such a key,
+/// and it can be used by the protocols here that need a private key: see
+/// [`JwsSigningKey::External`](crate::jose::JwsSigningKey::External) and
+/// [`JweDecryptionKey::External`](crate::jose::JweDecryptionKey::External).
+/// The `rustls-graviola` crate uses these for TLS authentication.  (X.509
+/// support here only verifies certificates, so needs no private keys.)
+///
+/// These traits are synchronous.  Where the key is only reachable
+/// asynchronously, the protocol operation can instead be done in steps:
+/// [`UnsignedJws`](crate::jose::UnsignedJws) produces a
+/// [`SignatureRequest`](crate::external::SignatureRequest) to send to the
+/// key, and later accepts its signature;
+/// [`Jwe::encrypted_key()`](crate::jose::Jwe::encrypted_key) and
+/// [`Jwe::decrypt_with_cek()`](crate::jose::Jwe::decrypt_with_cek) serve
+/// the same purpose for decryption.
+///
+/// ```
+/// use graviola::Error;
+/// use graviola::external::*;
+/// use graviola::jose::*;
+/// use graviola::signing::ed25519;
+///
+/// // a stand-in for the external key
+/// struct Hsm(ed25519::SigningKey);
+///
+/// impl ExternalSigner for Hsm {
+///     fn algorithm(&self) -> SignatureAlgorithm {
+///         SignatureAlgorithm::Ed25519
+///     }
+///
+///     fn sign(&self, request: &SignatureRequest) -> Result<Vec<u8>, Error> {
+///         Ok(self.0.sign(&[request.input()]).to_vec())
+///     }
+/// }
+///
+/// let hsm = Hsm(ed25519::SigningKey::new_random().unwrap());
+/// let token = JwsSigningKey::External(&hsm)
+///     .sign(&JwsHeader::new(JwsAlgorithm::EdDsa), b"hello world")
+///     .unwrap();
+///
+/// let verifying_key = hsm.0.verifying_key();
+/// let jws = Jws::decode(&token).unwrap();
+/// let payload = jws.verify(JwsVerifyingKey::Ed25519(&verifying_key)).unwrap();
+/// assert_eq!(payload, b"hello world");
+/// ```
+pub mod external {
+    pub use crate::high::external::{
+        DecryptionAlgorithm, ExternalDecrypter, ExternalSigner, SignatureAlgorithm,
+        SignatureRequest,
+    };
+}
+
+/// CBOR Object Signing and Encryption (COSE), as specified in
+/// [RFC9052](https://datatracker.ietf.org/doc/html/rfc9052).
+///
+/// `COSE_Sign1` is supported with the `ES256` and `EdDSA` algorithms, and
+/// `COSE_Encrypt0` with `A128GCM` and `ChaCha20/Poly1305`.  These are the
+/// building blocks of CWTs and many IoT protocols.
+///
+/// ```
+/// use graviola::cose::*;
+/// use graviola::signing::ed25519::SigningKey;
+///
+/// let key = SigningKey::new_random().unwrap();
+/// let message = CoseSigningKey::Ed25519(&key)
+///     .sign1(Some(b"2024-01"), b"", b"hello world")
+///     .unwrap();
+///
+/// // choose a key using the (unauthenticated) `kid`
+/// let sign1 = CoseSign1::decode(&message).unwrap();
+/// assert_eq!(sign1.key_id(), Some(&b"2024-01"[..]));
+/// let verifying_key = key.verifying_key();
+/// let payload = sign1
+///     .verify(CoseVerifyingKey::Ed25519(&verifying_key), b"")
+///     .unwrap();
+/// assert_eq!(payload, b"hello world");
+///
+/// let key = [0x42; 32];
+/// let message = CoseEncryptionKey::ChaCha20Poly1305(&key)
+///     .encrypt0(None, b"context", b"secret")
+///     .unwrap();
+/// let plaintext = CoseEncrypt0::decode(&message)
+///     .unwrap()
+///     .decrypt(CoseEncryptionKey::ChaCha20Poly1305(&key), b"context")
+///     .unwrap();
+/// assert_eq!(plaintext, b"secret");
+/// ```
+pub mod cose {
+    pub use crate::high::cose::{
+        CoseAlgorithm, CoseEncrypt0, CoseEncryptionKey, CoseSign1, CoseSigningKey, CoseVerifyingKey,
+    };
+}
+
+/// PASETO version 4 tokens, as specified in
+/// [the PASETO specification](https://github.com/paseto-standard/paseto-spec).
+///
+/// `v4.local` tokens are encrypted with a [`paseto::LocalKey`], and
+/// `v4.public` tokens are signed with Ed25519.  Unlike JWTs, the algorithm
+/// is fixed by the version, so there is nothing to negotiate.
+///
+/// ```
+/// use graviola::paseto::*;
+/// use graviola::signing::ed25519::SigningKey;
+///
+/// let key = LocalKey::new_random().unwrap();
+/// let token = key.encrypt(b"hello world", br#"{"kid":"1"}"#, b"").unwrap();
+/// assert!(token.starts_with("v4.local."));
+///
+/// // choose a key using the (unauthenticated) footer
+/// let decoded = Paseto::decode(&token).unwrap();
+/// assert_eq!(decoded.footer(), br#"{"kid":"1"}"#);
+/// assert_eq!(decoded.decrypt(&key, b"").unwrap(), b"hello world");
+///
+/// // the implicit assertion is signed, but not part of the token
+/// let key = SigningKey::new_random().unwrap();
+/// let token = sign(&key, b"hello world", b"", b"user 1234");
+/// let message = Paseto::decode(&token)
+///     .unwrap()
+///     .verify(&key.verifying_key(), b"user 1234")
+///     .unwrap()
+///     .to_vec();
+/// assert_eq!(message, b"hello world");
+/// ```
+pub mod paseto {
+    pub use crate::high::paseto::{LocalKey, Paseto, PasetoPurpose, sign};
+}
+
+/// SSH public keys, signatures, and `authorized_keys` and `known_hosts` entries.
+///
+/// Public keys are `ssh-ed25519`, `ecdsa-sha2-nistp256`, `ecdsa-sha2-nistp384`
+/// and `ssh-rsa`; RSA signatures must be `rsa-sha2-256` or `rsa-sha2-512`.
+/// OpenSSH private keys are supported by the key types themselves: see, for
+/// example, [`signing::ed25519::SigningKey::from_openssh()`].
+///
+/// ```
+/// use graviola::ssh::*;
+///
+/// let key = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIJQwEtm9Y8D6ii3qLW9TnSkJxP7wtCfkzpQRuexiZ9/t";
+/// let line = format!("*.example.com {key}");
+/// let entry = KnownHost::parse(&line).unwrap();
+/// assert!(entry.matches("git.example.com"));
+/// assert_eq!(entry.key.key_type(), "ssh-ed25519");
+///
+/// let line = format!("no-pty {key} alice@laptop");
+/// let entry = AuthorizedKey::parse(&line).unwrap();
+/// assert_eq!(entry.options, Some("no-pty"));
+/// assert_eq!(entry.comment, "alice@laptop");
+/// let blob = entry.key.to_blob().unwrap();
+/// assert!(SshPublicKey::from_blob(&blob).is_ok());
+/// ```
+pub mod ssh {
+    pub use crate::high::openssh::{AuthorizedKey, KnownHost, KnownHostMarker, SshPublicKey};
+}
+
+/// Hex and base64 encodings.
+///
+/// These are constant-time: they use neither lookup tables nor branches
+/// which depend on the data, so are suitable for secret keys and tokens.
+/// The PEM and JOSE formats use the same implementations.
+///
+/// ```
+/// use graviola::encoding::{base64, hex};
+///
+/// let key = hex::decode("000102030405060708090A0B0C0D0E0F").unwrap();
+/// assert_eq!(hex::encode(&key), "000102030405060708090a0b0c0d0e0f");
+/// assert_eq!(base64::encode(&key), "AAECAwQFBgcICQoLDA0ODw==");
+/// assert_eq!(base64::encode_url(&key), "AAECAwQFBgcICQoLDA0ODw");
+/// assert_eq!(base64::decode_url("AAECAwQFBgcICQoLDA0ODw").unwrap(), key);
+/// assert!(base64::decode("AAECAwQFBgcICQoLDA0ODw").is_err());
+/// ```
+pub mod encoding {
+    /// Hex, as used for test vectors and configuration.
+    pub mod hex {
+        use crate::error::{Error, KeyFormatError};
+
+        /// Encodes `data` as lowercase hex.
+        pub fn encode(data: &[u8]) -> String {
+            crate::high::hex::encode(data)
+        }
+
+        /// Decodes hex `text`, which may use upper or lowercase digits.
+        pub fn decode(text: &str) -> Result<Vec<u8>, Error> {
+            crate::high::hex::decode(text).ok_or(KeyFormatError::InvalidEncoding.into())
+        }
+    }
+
+    /// Base64, as specified in [RFC4648](https://datatracker.ietf.org/doc/html/rfc4648).
+    pub mod base64 {
+        use crate::error::{Error, KeyFormatError};
+
+        /// Encodes `data` with the standard alphabet, with padding.
+        pub fn encode(data: &[u8]) -> String {
+            crate::high::base64::encode(data)
+        }
+
+        /// Decodes `text` with the standard alphabet.  Padding is required.
+        pub fn decode(text: &str) -> Result<Vec<u8>, Error> {
+            crate::high::base64::decode(text).ok_or(KeyFormatError::InvalidEncoding.into())
+        }
+
+        /// Encodes `data` with the URL-safe alphabet, without padding.
+        ///
+        /// This is `BASE64URL` from [RFC7515](https://datatracker.ietf.org/doc/html/rfc7515#section-2).
+        pub fn encode_url(data: &[u8]) -> String {
+            crate::high::base64::encode_url(data)
+        }
+
+        /// Decodes `text` with the URL-safe alphabet.  Padding is not accepted.
+        pub fn decode_url(text: &str) -> Result<Vec<u8>, Error> {
+            crate::high::base64::decode_url(text).ok_or(KeyFormatError::InvalidEncoding.into())
+        }
+    }
+}
+
+/// PEM encoding, as specified in [RFC7468](https://datatracker.ietf.org/doc/html/rfc7468).
+///
+/// Keys can be loaded directly from PEM text with their `from_pem()` functions,
+/// which use the first document with a suitable label:
+///
+/// ```
+/// use graviola::pem::Document;
+/// use graviola::signing::ed25519::{SigningKey, VerifyingKey};
+///
+/// let signing_key = SigningKey::new_random().unwrap();
+/// let text = signing_key.verifying_key().to_pem().unwrap() + &signing_key.to_pem().unwrap();
+///
+/// let labels = Document::iter(&text)
+///     .map(|doc| doc.unwrap().label().to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(labels, ["PUBLIC KEY", "PRIVATE KEY"]);
+///
+/// let loaded = SigningKey::from_pem(&text).unwrap();
+/// let verifying_key = VerifyingKey::from_pem(&text).unwrap();
+/// assert_eq!(loaded.verifying_key().as_bytes(), verifying_key.as_bytes());
+/// ```
+pub mod pem {
+    pub use crate::high::pem::{Document, Documents};
+}
+
+/// X.509 certificates.
+///
+/// Only signature verification and key fingerprints are provided here: these
+/// are the cryptographic core of certificate path validation and pinning.
+///
+/// ```
+/// use graviola::Error;
+/// use graviola::der::{BitString, Sequence, Type};
+/// use graviola::x509::verify_signature;
+///
+/// fn verify_certificate(certificate: &[u8], issuer_spki: &[u8]) -> Result<(), Error> {
+///     let mut items = Sequence::from_bytes(certificate)
+///         .and_then(|certificate| certificate.items())
+///         .map_err(Error::Asn1Error)?;
+///     let tbs_certificate = Sequence::parse(&mut items).map_err(Error::Asn1Error)?;
+///     let signature_algorithm = Sequence::parse(&mut items).map_err(Error::Asn1Error)?;
+///     let signature = BitString::parse(&mut items).map_err(Error::Asn1Error)?;
+///     items.check_end().map_err(Error::Asn1Error)?;
+///
+///     verify_signature(
+///         tbs_certificate.as_bytes(),
+///         signature_algorithm.as_bytes(),
+///         signature.as_octets(),
+///         issuer_spki,
+///     )
+/// }
+/// ```
+///
+/// Keys can be pinned using [`SpkiFingerprint`](x509::SpkiFingerprint):
+///
+/// ```
+/// use graviola::signing::ed25519::SigningKey;
+/// use graviola::x509::SpkiFingerprint;
+///
+/// // configured pins are usually base64
+/// let pins = [SpkiFingerprint::from_base64("DQ/zVlNxN8R4qSpLEowav68HKduXpN90XmJud990GjE=").unwrap()];
+///
+/// let key = SigningKey::new_random().unwrap();
+/// let mut buffer = [0u8; 44];
+/// let spki = key.verifying_key().to_spki_der(&mut buffer).unwrap();
+/// let fingerprint = SpkiFingerprint::of_spki_der(spki).unwrap();
+/// assert!(!fingerprint.is_pinned(&pins));
+/// ```
+pub mod x509 {
+    pub use crate::high::fingerprint::SpkiFingerprint;
+    pub use crate::high::x509::verify_signature;
+}
+
+/// Interoperability with [Tink](https://developers.google.com/tink) keysets.
+///
+/// ```
+/// use graviola::tink::Keyset;
+///
+/// let keyset = Keyset::from_json(
+///     r#"{
+///       "primaryKeyId": 294406504,
+///       "key": [{
+///         "keyData": {
+///           "typeUrl": "type.googleapis.com/google.crypto.tink.AesGcmKey",
+///           "value": "GhD+9l0RANZjzZEZ8PDp7LRW",
+///           "keyMaterialType": "SYMMETRIC"
+///         },
+///         "status": "ENABLED",
+///         "keyId": 294406504,
+///         "outputPrefixType": "TINK"
+///       }]
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// let ciphertext = keyset.encrypt(b"hello", b"aad").unwrap();
+/// assert_eq!(&ciphertext[..5], &[0x01, 0x11, 0x8c, 0x49, 0x68]);
+/// assert_eq!(keyset.decrypt(&ciphertext, b"aad").unwrap(), b"hello");
+/// ```
+pub mod tink {
+    pub use super::high::tink::{Key, KeyStatus, KeyType, Keyset, OutputPrefix};
+}
+
+/// Format-preserving encryption.
+///
+/// ```
+/// use graviola::fpe::Ff1;
+///
+/// let ff1 = Ff1::new(&[0x42; 16], 10).unwrap();
+/// let card = "4111111111111111";
+/// let mut digits = card.bytes().map(|b| (b - b'0') as u16).collect::<Vec<_>>();
+///
+/// ff1.encrypt(b"merchant 1234", &mut digits).unwrap();
+/// let token = digits.iter().map(|d| (*d as u8 + b'0') as char).collect::<String>();
+/// assert_eq!(token.len(), card.len());
+///
+/// ff1.decrypt(b"merchant 1234", &mut digits).unwrap();
+/// assert!(digits.iter().map(|d| (*d as u8 + b'0') as char).eq(card.chars()));
+/// ```
+pub mod fpe {
+    pub use super::mid::ff1::Ff1;
+}
+
+/// Message authentication codes.
+pub mod mac {
+    pub use super::high::hmac::Hmac;
+    pub use super::high::mac::{Mac, NewMac};
+    pub use super::mid::aes_gcm::Gmac;
+    pub use super::mid::cmac::Cmac;
+    pub use super::mid::kmac::{Kmac, Kmac128, Kmac256};
+    pub use super::mid::poly1305::Poly1305;
+}
+
+/// Deterministic derivation of private keys from a seed.
+///
+/// This is for backup and recovery schemes, where many keys are
+/// regenerated from one stored secret.  The derivation of a key is
+/// fixed by its algorithm, the seed, and a caller-chosen `context`
+/// string (for example, a device or account name).
+///
+/// The derivation is:
+///
+/// ```text
+/// PRK = HKDF-Extract-SHA512(salt = "graviola seed derivation v1", IKM = seed)
+/// OKM = HKDF-Expand-SHA512(PRK, info = label || 0x00 || context, L)
+/// ```
+///
+/// where `label` and `L` depend on the key type:
+///
+/// | Key type | `label` | `L` | Use of `OKM` |
+/// |----------|---------|-----|--------------|
+/// | X25519 | `x25519` | 32 | the private key |
+/// | Ed25519 | `ed25519` | 32 | the RFC8032 seed |
+/// | P-256 ECDH | `ecdh-p256` | 48 | reduced mod `n` |
+/// | P-256 ECDSA | `ecdsa-p256` | 48 | reduced mod `n` |
+/// | ML-KEM-512 | `ml-kem-512` | 64 | the FIPS203 seed `d \|\| z` |
+/// | ML-KEM-768 | `ml-kem-768` | 64 | the FIPS203 seed `d \|\| z` |
+/// | ML-KEM-1024 | `ml-kem-1024` | 64 | the FIPS203 seed `d \|\| z` |
+///
+/// Each key type has a distinct label, so the same seed and context
+/// never produce related keys for two algorithms.
+///
+/// For hierarchical derivation compatible with other implementations,
+/// see [SLIP-0010](crate::signing::slip10) instead.
+///
+/// ```
+/// use graviola::seed::DeriveFromSeed;
+/// use graviola::signing::ed25519::SigningKey;
+///
+/// // this is the secret to back up
+/// let mut seed = [0u8; 32];
+/// graviola::random::fill(&mut seed).unwrap();
+///
+/// let laptop = SigningKey::derive_from_seed(&seed, b"laptop").unwrap();
+/// let phone = SigningKey::derive_from_seed(&seed, b"phone").unwrap();
+/// assert_ne!(laptop.verifying_key().as_bytes(), phone.verifying_key().as_bytes());
+///
+/// // later, the same key is recovered from the seed
+/// let recovered = SigningKey::derive_from_seed(&seed, b"laptop").unwrap();
+/// assert_eq!(recovered.verifying_key().as_bytes(), laptop.verifying_key().as_bytes());
+/// ```
+pub mod seed {
+    pub use crate::high::seed::{DeriveFromSeed, MIN_SEED_LEN};
+}
+
 /// Cryptographic-quality random source
+///
+/// Keys can be generated with a caller-supplied [`RandomSource`](crate::random::RandomSource),
+/// as well as from the system random number generator.
+///
+/// ```
+/// use graviola::key_agreement::x25519::StaticPrivateKey;
+/// use graviola::random::{RandomSource, SystemRandom};
+///
+/// let key = StaticPrivateKey::generate(&mut SystemRandom).unwrap();
+///
+/// // a (very bad) deterministic random source, for tests
+/// struct Counter(u8);
+///
+/// impl RandomSource for Counter {
+///     fn fill(&mut self, out: &mut [u8]) -> Result<(), graviola::Error> {
+///         for byte in out {
+///             self.0 = self.0.wrapping_add(1);
+///             *byte = self.0;
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let a = StaticPrivateKey::generate(&mut Counter(0)).unwrap();
+/// let b = StaticPrivateKey::generate(&mut Counter(0)).unwrap();
+/// assert_eq!(a.as_bytes(), b.as_bytes());
+/// ```
+///
+/// [`TestRng`](crate::random::TestRng) is a ready-made deterministic source
+/// for this purpose.  Most APIs which use randomness have a variant taking
+/// a `&mut dyn RandomSource`, typically suffixed `_with_rng`.
+///
+/// Where a certifiable deterministic random bit generator is required,
+/// [`CtrDrbg`](crate::random::CtrDrbg) is an AES-256 CTR_DRBG from SP800-90A:
+///
+/// ```
+/// use graviola::random::{CtrDrbg, RandomSource};
+///
+/// let mut drbg = CtrDrbg::new_random(b"my application").unwrap();
+/// let mut nonce = [0u8; 12];
+/// drbg.fill(&mut nonce).unwrap();
+/// ```
+///
+/// Where very many small random values are needed, [`FastRandom`](crate::random::FastRandom)
+/// avoids a system call per request.
+///
+/// On targets without an operating system entropy source, disable the
+/// default `getrandom` feature and register one at startup with
+/// [`set_entropy_source()`](crate::random::set_entropy_source).  Until then,
+/// everything that needs randomness fails with [`Error::RngFailed`].
 pub mod random {
+    pub use crate::mid::ctr_drbg::CtrDrbg;
+    pub use crate::mid::fast_rng::FastRandom;
+    pub use crate::mid::rng::{
+        EntropySource, HealthState, RandomSource, SystemRandom, TestRng, health_failures,
+        health_state, set_entropy_source,
+    };
+
     /// Fills the entirety of `out` with cryptographic-quality random bytes.
     pub fn fill(out: &mut [u8]) -> Result<(), super::Error> {
-        use crate::mid::rng::{RandomSource, SystemRandom};
         SystemRandom.fill(out)
     }
 }
+
+/// Runtime selection of implementations.
+///
+/// Where a primitive has an implementation using optional CPU features,
+/// and a portable fallback, the choice between them is made once per process
+/// according to the features the CPU has.  This module reports those choices,
+/// which may be useful for performance debugging:
+///
+/// ```
+/// use graviola::dispatch::{implementation, Primitive};
+///
+/// println!("AES is implemented by {}", implementation(Primitive::Aes));
+/// ```
+///
+/// [`set_backend()`](crate::set_backend) can force the use of the
+/// portable implementations, to reproduce bugs or compare performance.
+/// It and [`Backend`] are also available at the crate root:
+///
+/// ```
+/// use graviola::aead::AesGcm;
+///
+/// graviola::set_backend(graviola::Backend::Generic);
+/// let key = AesGcm::new(&[0u8; 16]);
+/// graviola::set_backend(graviola::Backend::Auto);
+/// ```
+pub mod dispatch {
+    pub use crate::low::dispatch::{Backend, Primitive, backend, implementation, set_backend};
+}
+
+/// Low-level primitives, for building other constructions.
+///
+/// This requires the `hazmat` feature.  Unlike the rest of this crate,
+/// these are not safe to use on their own: each item documents what its
+/// caller must ensure.  Prefer the complete constructions elsewhere
+/// in this crate where one exists.
+///
+/// [`GhashKey`](crate::hazmat::GhashKey) and [`PolyvalKey`](crate::hazmat::PolyvalKey)
+/// are the universal hash functions from AES-GCM and AES-GCM-SIV.  Their
+/// keys must be secret, and their outputs must be masked before release:
+///
+/// ```
+/// use graviola::hazmat::PolyvalKey;
+///
+/// let key = PolyvalKey::new(&[0x42; 16]);
+/// let mut polyval = key.start();
+/// polyval.update(b"additional data");
+/// polyval.pad();
+/// polyval.update(b"plaintext");
+/// polyval.pad();
+/// polyval.update(&[0x78, 0, 0, 0, 0, 0, 0, 0, 0x48, 0, 0, 0, 0, 0, 0, 0]);
+/// let s = polyval.finish();
+/// ```
+///
+/// [`Aes`](crate::hazmat::Aes) is the raw AES block cipher,
+/// [`gf128_mul`](crate::hazmat::gf128_mul) is multiplication in GHASH's
+/// field, and [`keccak_f1600`](crate::hazmat::keccak_f1600) is the
+/// permutation underlying SHA3:
+///
+/// ```
+/// use graviola::hazmat::Aes;
+///
+/// let aes = Aes::new(&[0x42; 16]).unwrap();
+/// let mut block = *b"sixteen byte msg";
+/// aes.encrypt_block(&mut block);
+/// aes.decrypt_block(&mut block);
+/// assert_eq!(&block, b"sixteen byte msg");
+/// ```
+///
+/// The `bignum_*` functions are arithmetic on integers of any size,
+/// as slices of 64-bit words with the least significant first:
+///
+/// ```
+/// use graviola::hazmat::{bignum_eq, bignum_mul};
+///
+/// let x = [u64::MAX, 1];
+/// let mut z = [0u64; 4];
+/// bignum_mul(&mut z, &x, &[2]).unwrap();
+/// assert!(bignum_eq(&z, &[u64::MAX - 1, 3]));
+/// ```
+///
+/// [`MontgomeryCtx`](crate::hazmat::MontgomeryCtx) does Montgomery
+/// multiplication modulo any odd number of up to 8192 bits:
+///
+/// ```
+/// use graviola::hazmat::MontgomeryCtx;
+///
+/// let ctx = MontgomeryCtx::new(&[0xffff_ffff_0000_0001]).unwrap();
+/// let (mut x, mut z) = ([0u64], [0u64]);
+/// ctx.to_montgomery(&mut x, &[3]).unwrap();
+/// ctx.mul(&mut z, &x, &x).unwrap();
+/// ctx.from_montgomery(&mut x, &z).unwrap();
+/// assert_eq!(x, [9]);
+/// ```
+#[cfg(feature = "hazmat")]
+pub mod hazmat {
+    pub use super::mid::aes::Aes;
+    pub use super::mid::bignum::{
+        MontgomeryCtx, bignum_add, bignum_cmp_lt, bignum_eq, bignum_modadd, bignum_modsub,
+        bignum_mul,
+    };
+    pub use super::mid::ghash::{Ghash, GhashKey, Polyval, PolyvalKey, gf128_mul};
+    pub use super::mid::sha3::keccak_f1600_permute as keccak_f1600;
+}
+
+/// APIs compatible with other cryptography libraries.
+#[cfg(feature = "ring-compat")]
+pub mod compat {
+    /// An API in the shape of [*ring*](https://docs.rs/ring)'s.
+    ///
+    /// This requires the `ring-compat` feature.  It covers the commonly-used
+    /// parts of *ring*'s `aead`, `agreement`, `digest`, `hmac` and `signature`
+    /// modules, so code written against *ring* can often switch by changing
+    /// only its `use` lines.  Where *ring* takes `untrusted::Input`, these take
+    /// byte slices.
+    ///
+    /// ```
+    /// use graviola::compat::ring::{aead, agreement, digest, rand, signature};
+    /// use graviola::compat::ring::signature::KeyPair;
+    ///
+    /// let rng = rand::SystemRandom::new();
+    ///
+    /// let pkcs8 = signature::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+    /// let key_pair = signature::Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+    /// let sig = key_pair.sign(b"hello");
+    /// signature::UnparsedPublicKey::new(&signature::ED25519, key_pair.public_key())
+    ///     .verify(b"hello", sig.as_ref())
+    ///     .unwrap();
+    ///
+    /// let ours = agreement::EphemeralPrivateKey::generate(&agreement::X25519, &rng).unwrap();
+    /// let theirs = agreement::EphemeralPrivateKey::generate(&agreement::X25519, &rng).unwrap();
+    /// let theirs_public = theirs.compute_public_key().unwrap();
+    /// let key_bytes = agreement::agree_ephemeral(
+    ///     ours,
+    ///     &agreement::UnparsedPublicKey::new(&agreement::X25519, theirs_public.as_ref()),
+    ///     |secret| digest::digest(&digest::SHA256, secret),
+    /// )
+    /// .unwrap();
+    ///
+    /// let key = aead::UnboundKey::new(&aead::AES_256_GCM, key_bytes.as_ref()).unwrap();
+    /// let key = aead::LessSafeKey::new(key);
+    /// let mut in_out = b"hello".to_vec();
+    /// key.seal_in_place_append_tag(
+    ///     aead::Nonce::assume_unique_for_key([0u8; aead::NONCE_LEN]),
+    ///     aead::Aad::empty(),
+    ///     &mut in_out,
+    /// )
+    /// .unwrap();
+    /// let plaintext = key
+    ///     .open_in_place(
+    ///         aead::Nonce::assume_unique_for_key([0u8; aead::NONCE_LEN]),
+    ///         aead::Aad::empty(),
+    ///         &mut in_out,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(plaintext, b"hello");
+    /// ```
+    pub mod ring {
+        /// Authenticated encryption with associated data.
+        pub mod aead {
+            pub use crate::high::ring_compat::aead::{
+                AES_128_GCM, AES_256_GCM, Aad, Algorithm, BoundKey, CHACHA20_POLY1305, LessSafeKey,
+                MAX_TAG_LEN, NONCE_LEN, Nonce, NonceSequence, OpeningKey, SealingKey, Tag,
+                UnboundKey,
+            };
+        }
+
+        /// Key agreement.
+        pub mod agreement {
+            pub use crate::high::ring_compat::agreement::{
+                Algorithm, ECDH_P256, ECDH_P384, EphemeralPrivateKey, PublicKey, UnparsedPublicKey,
+                X25519, agree_ephemeral,
+            };
+        }
+
+        /// Hash functions.
+        pub mod digest {
+            pub use crate::high::ring_compat::digest::{
+                Algorithm, Context, Digest, MAX_BLOCK_LEN, MAX_OUTPUT_LEN, SHA256,
+                SHA256_OUTPUT_LEN, SHA384, SHA384_OUTPUT_LEN, SHA512, SHA512_OUTPUT_LEN, digest,
+            };
+        }
+
+        /// Error types.
+        pub mod error {
+            pub use crate::high::ring_compat::error::{KeyRejected, Unspecified};
+        }
+
+        /// HMAC.
+        pub mod hmac {
+            pub use crate::high::ring_compat::hmac::{
+                Algorithm, Context, HMAC_SHA256, HMAC_SHA384, HMAC_SHA512, Key, Tag, sign, verify,
+            };
+        }
+
+        /// PKCS#8 private key documents.
+        pub mod pkcs8 {
+            pub use crate::high::ring_compat::pkcs8::Document;
+        }
+
+        /// Random number generation.
+        pub mod rand {
+            pub use crate::high::ring_compat::rand::{SecureRandom, SystemRandom};
+        }
+
+        /// Signing and verification.
+        pub mod signature {
+            pub use crate::high::ring_compat::signature::{
+                ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P256_SHA256_FIXED,
+                ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P384_SHA384_ASN1,
+                ECDSA_P384_SHA384_ASN1_SIGNING, ECDSA_P384_SHA384_FIXED,
+                ECDSA_P384_SHA384_FIXED_SIGNING, ED25519, EcdsaKeyPair, EcdsaSigningAlgorithm,
+                EcdsaVerificationAlgorithm, Ed25519KeyPair, EdDSAParameters, KeyPair, PublicKey,
+                RSA_PKCS1_2048_8192_SHA256, RSA_PKCS1_2048_8192_SHA384, RSA_PKCS1_2048_8192_SHA512,
+                RSA_PKCS1_SHA256, RSA_PKCS1_SHA384, RSA_PKCS1_SHA512, RSA_PSS_2048_8192_SHA256,
+                RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA512, RSA_PSS_SHA256, RSA_PSS_SHA384,
+                RSA_PSS_SHA512, RsaEncoding, RsaKeyPair, RsaParameters, Signature,
+                UnparsedPublicKey, VerificationAlgorithm,
+            };
+        }
+    }
+}
@@ -31,6 +31,17 @@ impl AesKey {
         }
     }
 
+    /// Creates an AesKey, which also holds the round keys for decryption.
+    ///
+    /// This makes `decrypt_block` as fast as `encrypt_block`, at the
+    /// cost of a slower key setup.
+    pub(crate) fn new_with_decryption(key: &[u8]) -> Self {
+        match Self::new(key) {
+            Self::Aes128(a128) => Self::Aes128(a128.with_decryption()),
+            Self::Aes256(a256) => Self::Aes256(a256.with_decryption()),
+        }
+    }
+
     pub(crate) fn encrypt_block(&self, inout: &mut [u8]) {
         debug_assert_eq!(inout.len(), 16);
 
@@ -40,8 +51,17 @@ impl AesKey {
         }
     }
 
+    pub(crate) fn decrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+
+        match self {
+            Self::Aes128(a128) => a128.decrypt_block(inout),
+            Self::Aes256(a256) => a256.decrypt_block(inout),
+        }
+    }
+
     pub(crate) fn ctr(&self, initial_counter: &[u8; 16], cipher_inout: &mut [u8]) {
-        // SAFETY: this crate requires the `aes` & `neon` cpu features
+        // SAFETY: `aes` is checked by `aes_mux`; this crate requires the `neon` cpu feature
         unsafe { self._ctr(initial_counter, cipher_inout) }
     }
 
@@ -145,6 +165,9 @@ impl AesKey {
 
 pub(crate) struct AesKey128 {
     round_keys: [uint8x16_t; 10 + 1],
+
+    /// Round keys for the equivalent inverse cipher, if made.
+    dec_round_keys: Option<[uint8x16_t; 10 + 1]>,
 }
 
 impl AesKey128 {
@@ -171,23 +194,48 @@ impl AesKey128 {
             round_keys[i] = unsafe { vrev32q_u8(vld1q_u8(rk.as_ptr() as *const _)) };
         }
 
-        Self { round_keys }
+        Self {
+            round_keys,
+            dec_round_keys: None,
+        }
+    }
+
+    fn with_decryption(mut self) -> Self {
+        // SAFETY: `aes` is checked by `aes_mux`; this crate requires the `neon` cpu feature
+        self.dec_round_keys = Some(unsafe { inverse_round_keys(&self.round_keys) });
+        self
     }
 
     pub(crate) fn encrypt_block(&self, inout: &mut [u8]) {
-        // SAFETY: this crate requires the `aes` cpu feature
+        // SAFETY: `aes` is checked by `aes_mux`
         unsafe { aes128_block(&self.round_keys, inout) }
     }
+
+    fn decrypt_block(&self, inout: &mut [u8]) {
+        // SAFETY: `aes` is checked by `aes_mux`; this crate requires the `neon` cpu feature
+        unsafe {
+            match &self.dec_round_keys {
+                Some(dec_round_keys) => aes_inverse_cipher_block(dec_round_keys, inout),
+                None => aes_decrypt_block(&self.round_keys, inout),
+            }
+        }
+    }
 }
 
 impl Drop for AesKey128 {
     fn drop(&mut self) {
         low::zeroise(&mut self.round_keys);
+        if let Some(dec_round_keys) = &mut self.dec_round_keys {
+            low::zeroise(dec_round_keys);
+        }
     }
 }
 
 pub(crate) struct AesKey256 {
     round_keys: [uint8x16_t; 14 + 1],
+
+    /// Round keys for the equivalent inverse cipher, if made.
+    dec_round_keys: Option<[uint8x16_t; 14 + 1]>,
 }
 
 impl AesKey256 {
@@ -222,18 +270,40 @@ impl AesKey256 {
             round_keys[i] = unsafe { vrev32q_u8(vld1q_u8(rk.as_ptr() as *const _)) };
         }
 
-        Self { round_keys }
+        Self {
+            round_keys,
+            dec_round_keys: None,
+        }
+    }
+
+    fn with_decryption(mut self) -> Self {
+        // SAFETY: `aes` is checked by `aes_mux`; this crate requires the `neon` cpu feature
+        self.dec_round_keys = Some(unsafe { inverse_round_keys(&self.round_keys) });
+        self
     }
 
     pub(crate) fn encrypt_block(&self, inout: &mut [u8]) {
-        // SAFETY: this crate requires the `aes` cpu feature
+        // SAFETY: `aes` is checked by `aes_mux`
         unsafe { aes256_block(&self.round_keys, inout) }
     }
+
+    fn decrypt_block(&self, inout: &mut [u8]) {
+        // SAFETY: `aes` is checked by `aes_mux`; this crate requires the `neon` cpu feature
+        unsafe {
+            match &self.dec_round_keys {
+                Some(dec_round_keys) => aes_inverse_cipher_block(dec_round_keys, inout),
+                None => aes_decrypt_block(&self.round_keys, inout),
+            }
+        }
+    }
 }
 
 impl Drop for AesKey256 {
     fn drop(&mut self) {
         low::zeroise(&mut self.round_keys);
+        if let Some(dec_round_keys) = &mut self.dec_round_keys {
+            low::zeroise(dec_round_keys);
+        }
     }
 }
 
@@ -243,7 +313,7 @@ fn zero() -> uint8x16_t {
 }
 
 fn sub_word(w: u32) -> u32 {
-    // SAFETY: this crate requires the `aes` cpu feature
+    // SAFETY: `aes` is checked by `aes_mux`
     unsafe { _sub_word(w) }
 }
 
@@ -488,6 +558,63 @@ unsafe fn _aes256_8_blocks(
     }
 }
 
+/// Decrypt one block, with the equivalent inverse cipher (FIPS-197 section 5.3.5).
+///
+/// The decryption round keys are derived from the encryption round keys
+/// as needed.  This is for keys made without `AesKey::new_with_decryption`,
+/// where decryption is rare enough that they are not worth storing.
+#[target_feature(enable = "aes,neon")]
+unsafe fn aes_decrypt_block(round_keys: &[uint8x16_t], block_inout: &mut [u8]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let (first, rest) = round_keys.split_first().unwrap();
+        let (last, middle) = rest.split_last().unwrap();
+
+        // nb. `aesd` is `InvSubBytes(InvShiftRows(block ^ key))`, so the
+        // xor of each round key happens at the start of the next instruction
+        let mut block = vld1q_u8(block_inout.as_ptr() as *const _);
+        block = vaesdq_u8(block, *last);
+        for rk in middle.iter().rev() {
+            block = vaesdq_u8(vaesimcq_u8(block), vaesimcq_u8(*rk));
+        }
+        block = veorq_u8(block, *first);
+        vst1q_u8(block_inout.as_mut_ptr() as *mut _, block);
+    }
+}
+
+/// Derive the round keys for the equivalent inverse cipher (FIPS-197 section 5.3.5).
+///
+/// These are the encryption round keys in reverse order, with
+/// InvMixColumns applied to all but the first and last.
+#[target_feature(enable = "aes,neon")]
+unsafe fn inverse_round_keys<const N: usize>(round_keys: &[uint8x16_t; N]) -> [uint8x16_t; N] {
+    let mut out = *round_keys;
+    out.reverse();
+    for rk in &mut out[1..N - 1] {
+        *rk = vaesimcq_u8(*rk);
+    }
+    out
+}
+
+/// Decrypt one block, with the equivalent inverse cipher and its
+/// round keys from `inverse_round_keys`.
+#[target_feature(enable = "aes,neon")]
+unsafe fn aes_inverse_cipher_block(dec_round_keys: &[uint8x16_t], block_inout: &mut [u8]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let (first, rest) = dec_round_keys.split_first().unwrap();
+        let (last, middle) = rest.split_last().unwrap();
+
+        let mut block = vld1q_u8(block_inout.as_ptr() as *const _);
+        block = vaesdq_u8(block, *first);
+        for rk in middle {
+            block = vaesdq_u8(vaesimcq_u8(block), *rk);
+        }
+        block = veorq_u8(block, *last);
+        vst1q_u8(block_inout.as_mut_ptr() as *mut _, block);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -577,6 +704,15 @@ mod tests {
                 0xc5, 0x5a
             ]
         );
+
+        AesKey::Aes128(context).decrypt_block(&mut block);
+        assert_eq!(
+            block,
+            [
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+                0xee, 0xff,
+            ]
+        );
     }
 
     #[test]
@@ -598,5 +734,34 @@ mod tests {
                 0x60, 0x89
             ]
         );
+
+        AesKey::Aes256(context).decrypt_block(&mut block);
+        assert_eq!(
+            block,
+            [
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+                0xee, 0xff,
+            ]
+        );
+    }
+    #[test]
+    fn test_inverse_cipher() {
+        for key in [&[0x2bu8; 16][..], &[0x3cu8; 32][..]] {
+            let on_the_fly = AesKey::new(key);
+            let stored = AesKey::new_with_decryption(key);
+
+            let mut block = [0u8; 16];
+            for i in 0..64 {
+                block[i % 16] ^= i as u8;
+                on_the_fly.encrypt_block(&mut block);
+                stored.encrypt_block(&mut block);
+
+                let mut expect = block;
+                on_the_fly.decrypt_block(&mut expect);
+                let mut got = block;
+                stored.decrypt_block(&mut got);
+                assert_eq!(got, expect);
+            }
+        }
     }
 }
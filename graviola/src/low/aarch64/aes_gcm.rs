@@ -1,8 +1,8 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
-use crate::low::AesKey;
-use crate::low::ghash::Ghash;
+use super::aes::AesKey;
+use super::ghash::Ghash;
 
 pub(crate) fn encrypt(
     key: &AesKey,
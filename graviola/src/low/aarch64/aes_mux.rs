@@ -0,0 +1,117 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::mem::ManuallyDrop;
+
+use crate::low::aarch64;
+use crate::low::{dispatch, generic};
+
+/// Multiplex between the AES instructions and the portable bitsliced AES.
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum AesKey {
+    Accelerated(ManuallyDrop<aarch64::aes::AesKey>),
+    Generic(ManuallyDrop<generic::aes::AesKey>),
+}
+
+impl AesKey {
+    /// Creates an AesKey.
+    ///
+    /// `key` must be 16 or 32 bytes in length (AES-192 not supported).
+    pub(crate) fn new(key: &[u8]) -> Self {
+        if use_aes_instructions() {
+            Self::Accelerated(ManuallyDrop::new(aarch64::aes::AesKey::new(key)))
+        } else {
+            Self::Generic(ManuallyDrop::new(generic::aes::AesKey::new(key)))
+        }
+    }
+
+    /// Creates an AesKey, which also holds the round keys for decryption.
+    ///
+    /// Use this where `decrypt_block` is used on many blocks.
+    pub(crate) fn new_with_decryption(key: &[u8]) -> Self {
+        if use_aes_instructions() {
+            Self::Accelerated(ManuallyDrop::new(
+                aarch64::aes::AesKey::new_with_decryption(key),
+            ))
+        } else {
+            Self::Generic(ManuallyDrop::new(
+                generic::aes::AesKey::new_with_decryption(key),
+            ))
+        }
+    }
+
+    pub(crate) fn encrypt_block(&self, inout: &mut [u8]) {
+        match self {
+            Self::Accelerated(k) => k.encrypt_block(inout),
+            Self::Generic(k) => k.encrypt_block(inout),
+        }
+    }
+
+    pub(crate) fn decrypt_block(&self, inout: &mut [u8]) {
+        match self {
+            Self::Accelerated(k) => k.decrypt_block(inout),
+            Self::Generic(k) => k.decrypt_block(inout),
+        }
+    }
+
+    pub(crate) fn ctr(&self, initial_counter: &[u8; 16], cipher_inout: &mut [u8]) {
+        match self {
+            Self::Accelerated(k) => k.ctr(initial_counter, cipher_inout),
+            Self::Generic(k) => k.ctr(initial_counter, cipher_inout),
+        }
+    }
+}
+
+impl Drop for AesKey {
+    fn drop(&mut self) {
+        match self {
+            // SAFETY: `self` is not used again
+            Self::Accelerated(k) => unsafe { ManuallyDrop::drop(k) },
+            // SAFETY: `self` is not used again
+            Self::Generic(k) => unsafe { ManuallyDrop::drop(k) },
+        }
+
+        // the variants zeroise themselves, but not the enum tag, nor the
+        // padding past the smaller variant.
+        // SAFETY: `self` is not used again
+        unsafe { generic::zeroise::zeroise_dropped(self) };
+    }
+}
+
+/// Whether to use the AES instructions (for AES) and PMULL (for GHASH).
+///
+/// These are decided together, so that AES-GCM always has a matching
+/// key and GHASH implementation.  In practice, all processors with one
+/// have the other.
+pub(in crate::low) fn use_aes_instructions() -> bool {
+    let features = aarch64::cpu::features();
+    dispatch::use_native() && features.aes && features.pmull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aes_instructions_match_generic() {
+        if !use_aes_instructions() {
+            return;
+        }
+
+        for key in [&[0x2bu8; 16][..], &[0x61u8; 32][..]] {
+            let accel = AesKey::Accelerated(ManuallyDrop::new(aarch64::aes::AesKey::new(key)));
+            let generic = AesKey::Generic(ManuallyDrop::new(generic::aes::AesKey::new(key)));
+
+            let mut a = *b"0123456789abcdef";
+            let mut b = a;
+            accel.encrypt_block(&mut a);
+            generic.encrypt_block(&mut b);
+            assert_eq!(a, b);
+
+            accel.decrypt_block(&mut a);
+            generic.decrypt_block(&mut b);
+            assert_eq!(a, b);
+            assert_eq!(&a, b"0123456789abcdef");
+        }
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
 use std::arch::is_aarch64_feature_detected;
+use std::sync::OnceLock;
 
 pub(crate) fn enter_cpu_state() -> u32 {
     dit::maybe_enable()
@@ -157,28 +158,72 @@ pub(in crate::low) unsafe fn ct_compare_bytes(a: *const u8, b: *const u8, len: u
     }
 }
 
+/// The CPU features we use.
+pub(crate) struct Features {
+    pub(crate) neon: bool,
+    pub(crate) aes: bool,
+    pub(crate) pmull: bool,
+    pub(crate) sha2: bool,
+    pub(crate) sha3: bool,
+    pub(crate) sve2_sha3: bool,
+    pub(crate) dit: bool,
+
+    /// The SVE vector length in bits, or zero without SVE.
+    pub(crate) sve_vector_bits: usize,
+}
+
+impl Features {
+    fn detect() -> Self {
+        Self {
+            neon: is_aarch64_feature_detected!("neon"),
+            aes: is_aarch64_feature_detected!("aes"),
+            pmull: is_aarch64_feature_detected!("pmull"),
+            sha2: is_aarch64_feature_detected!("sha2"),
+            sha3: is_aarch64_feature_detected!("sha3"),
+            sve2_sha3: is_aarch64_feature_detected!("sve2-sha3"),
+            dit: is_aarch64_feature_detected!("dit"),
+            sve_vector_bits: match is_aarch64_feature_detected!("sve") {
+                // SAFETY: we just checked the `sve` cpu feature is supported
+                true => unsafe { sve_vector_bits() },
+                false => 0,
+            },
+        }
+    }
+}
+
+#[target_feature(enable = "sve")]
+unsafe fn sve_vector_bits() -> usize {
+    let bytes: usize;
+    // SAFETY: inline assembly.
+    unsafe {
+        core::arch::asm!(
+            "rdvl {bytes}, #1",
+            bytes = out(reg) bytes,
+            options(nostack, nomem, pure),
+        )
+    };
+    bytes * 8
+}
+
+/// Returns the CPU features, detecting them on first call.
+///
+/// Every dispatch decision is a function of these, so is made once
+/// per process.
+pub(crate) fn features() -> &'static Features {
+    static FEATURES: OnceLock<Features> = OnceLock::new();
+    FEATURES.get_or_init(Features::detect)
+}
+
 pub(crate) fn verify_cpu_features() {
-    assert!(
-        is_aarch64_feature_detected!("neon"),
-        "graviola requires neon CPU support"
-    );
-    assert!(
-        is_aarch64_feature_detected!("aes"),
-        "graviola requires aes CPU support"
-    );
-    assert!(
-        is_aarch64_feature_detected!("pmull"),
-        "graviola requires pmull CPU support"
-    );
-    assert!(
-        is_aarch64_feature_detected!("sha2"),
-        "graviola requires sha2 CPU support"
-    );
+    assert!(features().neon, "graviola requires neon CPU support");
+
+    // nb. `aes`, `pmull` and `sha2` are optional: see `aes_mux`
+    // and `sha256_mux`.
 }
 
 mod dit {
     pub(super) fn maybe_enable() -> u32 {
-        if super::is_aarch64_feature_detected!("dit") {
+        if super::features().dit {
             // SAFETY: in this branch, we verified `dit` cpu feature is supported
             match unsafe { read() } {
                 0 => {
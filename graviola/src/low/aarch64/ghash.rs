@@ -17,30 +17,33 @@ pub(crate) struct GhashTable {
 
 impl GhashTable {
     pub(crate) fn new(h: u128) -> Self {
+        Self::with_powers(h, 8)
+    }
+
+    /// Makes a table containing only H.
+    ///
+    /// This is cheaper to make, but is only valid for inputs to
+    /// `Ghash::add` shorter than 128 bytes, which never reach the
+    /// eight-block path.
+    pub(crate) fn new_single_block(h: u128) -> Self {
+        Self::with_powers(h, 1)
+    }
+
+    fn with_powers(h: u128, count: usize) -> Self {
         // SAFETY: this crate requires the `neon` cpu feature
         let h = unsafe { gf128_big_endian(from_u128(h)) };
-        let h2 = mul(h, h);
-        let h3 = mul(h2, h);
-        let h4 = mul(h3, h);
-        let h5 = mul(h4, h);
-        let h6 = mul(h5, h);
-        let h7 = mul(h6, h);
-        let h8 = mul(h7, h);
-
-        let powers = [h, h2, h3, h4, h5, h6, h7, h8];
-        // SAFETY: this crate requires the `neon` cpu feature
-        let powers_xor = unsafe {
-            [
-                xor_halves(h),
-                xor_halves(h2),
-                xor_halves(h3),
-                xor_halves(h4),
-                xor_halves(h5),
-                xor_halves(h6),
-                xor_halves(h7),
-                xor_halves(h8),
-            ]
-        };
+        let mut powers = [zero(); 8];
+        powers[0] = h;
+
+        for i in 1..count {
+            powers[i] = mul(powers[i - 1], h);
+        }
+
+        let mut powers_xor = [zero(); 8];
+        for i in 0..count {
+            // SAFETY: this crate requires the `neon` cpu feature
+            powers_xor[i] = unsafe { xor_halves(powers[i]) };
+        }
 
         Self { powers, powers_xor }
     }
@@ -141,7 +144,7 @@ impl<'a> Ghash<'a> {
 
 #[inline]
 fn mul(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
-    // SAFETY: this crate requires the `neon` and `aes` cpu features
+    // SAFETY: `aes` is checked by `aes_mux`; this crate requires the `neon` cpu feature
     unsafe { _mul(a, b) }
 }
 
@@ -157,7 +160,7 @@ fn mul8(
     g: uint64x2_t,
     h: uint64x2_t,
 ) -> uint64x2_t {
-    // SAFETY: this crate requires the `neon` and `aes` cpu features
+    // SAFETY: `aes` is checked by `aes_mux`; this crate requires the `neon` cpu feature
     unsafe { _mul8(table, a, b, c, d, e, f, g, h) }
 }
 
@@ -0,0 +1,171 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! Keccak-f\[1600\] using the ARMv8.2 SHA3 instructions, processing two
+//! states at a time.
+//!
+//! Each 64-bit lane of a vector carries the same word of an independent
+//! state.  The structure otherwise follows the generic implementation,
+//! except that `rax1` computes theta's D values, `xar` combines the
+//! remainder of theta with rho, and `bcax` computes chi.
+//!
+//! The intrinsics for these instructions were stabilised after our MSRV,
+//! so we reach them via inline assembly instead.
+
+use core::arch::aarch64::*;
+
+use crate::low::generic::keccak::RC;
+
+pub(crate) fn keccak_f1600_x4(states: &mut [[u64; 25]; 4]) {
+    let [s0, s1, s2, s3] = states;
+    // SAFETY: this crate requires the `neon` cpu feature, and the caller
+    // checked the `sha3` cpu feature is supported.
+    unsafe {
+        keccak_x2(s0, s1);
+        keccak_x2(s2, s3);
+    }
+}
+
+/// Equivalent to `veor3q_u64`.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+unsafe fn eor3(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t {
+    let ret: uint64x2_t;
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            "eor3 {r:v}.16b, {a:v}.16b, {b:v}.16b, {c:v}.16b",
+            r = lateout(vreg) ret,
+            a = in(vreg) a,
+            b = in(vreg) b,
+            c = in(vreg) c,
+            options(nostack, nomem, pure),
+        );
+    }
+    ret
+}
+
+/// Equivalent to `vrax1q_u64`: `a ^ b.rotate_left(1)` in each lane.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+unsafe fn rax1(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    let ret: uint64x2_t;
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            "rax1 {r:v}.2d, {a:v}.2d, {b:v}.2d",
+            r = lateout(vreg) ret,
+            a = in(vreg) a,
+            b = in(vreg) b,
+            options(nostack, nomem, pure),
+        );
+    }
+    ret
+}
+
+/// Equivalent to `vbcaxq_u64`: `a ^ (b & !c)`.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+unsafe fn bcax(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t {
+    let ret: uint64x2_t;
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            "bcax {r:v}.16b, {a:v}.16b, {b:v}.16b, {c:v}.16b",
+            r = lateout(vreg) ret,
+            a = in(vreg) a,
+            b = in(vreg) b,
+            c = in(vreg) c,
+            options(nostack, nomem, pure),
+        );
+    }
+    ret
+}
+
+/// Equivalent to `vxarq_u64`: `(a ^ b).rotate_right(ror)` in each lane.
+///
+/// `ror` must be a literal.  This must be used in an `unsafe` block:
+/// see [crate::low::inline_assembly_safety] for safety info.
+macro_rules! xar {
+    ($a:expr, $b:expr, $ror:literal) => {{
+        let ret: uint64x2_t;
+        core::arch::asm!(
+            concat!("xar {r:v}.2d, {a:v}.2d, {b:v}.2d, #", stringify!($ror)),
+            r = lateout(vreg) ret,
+            a = in(vreg) $a,
+            b = in(vreg) $b,
+            options(nostack, nomem, pure),
+        );
+        ret
+    }};
+}
+
+#[target_feature(enable = "neon,sha3")]
+unsafe fn keccak_x2(s0: &mut [u64; 25], s1: &mut [u64; 25]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let mut a = [vdupq_n_u64(0); 25];
+        for (i, a) in a.iter_mut().enumerate() {
+            *a = vld1q_u64([s0[i], s1[i]].as_ptr());
+        }
+
+        for rc in RC {
+            // theta
+            let mut c = [vdupq_n_u64(0); 5];
+            for (x, c) in c.iter_mut().enumerate() {
+                *c = eor3(eor3(a[x], a[x + 5], a[x + 10]), a[x + 15], a[x + 20]);
+            }
+            let mut d = [vdupq_n_u64(0); 5];
+            for (x, d) in d.iter_mut().enumerate() {
+                *d = rax1(c[(x + 4) % 5], c[(x + 1) % 5]);
+            }
+
+            // rho and pi, with the remainder of theta.
+            //
+            // b[y + 5 * ((2 * x + 3 * y) % 5)] = (a[x + 5 * y] ^ d[x]).rotate_left(RHO[x + 5 * y])
+            let b = [
+                veorq_u64(a[0], d[0]),
+                xar!(a[6], d[1], 20),
+                xar!(a[12], d[2], 21),
+                xar!(a[18], d[3], 43),
+                xar!(a[24], d[4], 50),
+                xar!(a[3], d[3], 36),
+                xar!(a[9], d[4], 44),
+                xar!(a[10], d[0], 61),
+                xar!(a[16], d[1], 19),
+                xar!(a[22], d[2], 3),
+                xar!(a[1], d[1], 63),
+                xar!(a[7], d[2], 58),
+                xar!(a[13], d[3], 39),
+                xar!(a[19], d[4], 56),
+                xar!(a[20], d[0], 46),
+                xar!(a[4], d[4], 37),
+                xar!(a[5], d[0], 28),
+                xar!(a[11], d[1], 54),
+                xar!(a[17], d[2], 49),
+                xar!(a[23], d[3], 8),
+                xar!(a[2], d[2], 2),
+                xar!(a[8], d[3], 9),
+                xar!(a[14], d[4], 25),
+                xar!(a[15], d[0], 23),
+                xar!(a[21], d[1], 62),
+            ];
+
+            // chi
+            for y in 0..5 {
+                for x in 0..5 {
+                    a[x + 5 * y] =
+                        bcax(b[x + 5 * y], b[(x + 2) % 5 + 5 * y], b[(x + 1) % 5 + 5 * y]);
+                }
+            }
+
+            // iota
+            a[0] = veorq_u64(a[0], vdupq_n_u64(rc));
+        }
+
+        for (i, a) in a.iter().enumerate() {
+            s0[i] = vgetq_lane_u64::<0>(*a);
+            s1[i] = vgetq_lane_u64::<1>(*a);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::low::aarch64;
+use crate::low::{dispatch, generic};
+
+pub(crate) fn keccak_f1600_x4(states: &mut [[u64; 25]; 4]) {
+    if use_sve2() {
+        aarch64::keccak_x4_sve2::keccak_f1600_x4(states)
+    } else if use_sha3_instructions() {
+        aarch64::keccak_x4::keccak_f1600_x4(states)
+    } else {
+        generic::keccak_x4::keccak_f1600_x4(states)
+    }
+}
+
+pub(in crate::low) fn use_sve2() -> bool {
+    dispatch::use_native() && aarch64::keccak_x4_sve2::available()
+}
+
+pub(in crate::low) fn use_sha3_instructions() -> bool {
+    dispatch::use_native() && aarch64::cpu::features().sha3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implementations_match_generic() {
+        let mut input = [[0u64; 25]; 4];
+        for (i, word) in input.iter_mut().flatten().enumerate() {
+            *word = (i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        }
+
+        let mut expect = input;
+        generic::keccak_x4::keccak_f1600_x4(&mut expect);
+
+        if aarch64::cpu::features().sha3 {
+            let mut got = input;
+            aarch64::keccak_x4::keccak_f1600_x4(&mut got);
+            assert_eq!(got, expect);
+        }
+
+        if aarch64::keccak_x4_sve2::available() {
+            let mut got = input;
+            aarch64::keccak_x4_sve2::keccak_f1600_x4(&mut got);
+            assert_eq!(got, expect);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! Keccak-f\[1600\] using the SVE2 SHA3 instructions, processing four
+//! states at once.
+//!
+//! This needs a vector length of at least 256 bits: each of z0-z24
+//! holds one lane of the four states, in its first four elements.  The
+//! rounds are the same as the Neon implementation in `keccak_x4.rs`.
+//!
+//! Rust has no operand class for SVE registers, so this is a single
+//! block of inline assembly with registers allocated by hand.
+
+use crate::low::aarch64::cpu;
+use crate::low::generic::keccak::RC;
+
+/// Whether the CPU supports this implementation.
+pub(crate) fn available() -> bool {
+    let features = cpu::features();
+    features.sve2_sha3 && features.sve_vector_bits >= 256
+}
+
+pub(crate) fn keccak_f1600_x4(states: &mut [[u64; 25]; 4]) {
+    // lane `i` of each state, in elements `4 * i` to `4 * i + 3`
+    let mut lanes = [0u64; 100];
+    for (i, lane) in lanes.chunks_exact_mut(4).enumerate() {
+        for (l, state) in lane.iter_mut().zip(states.iter()) {
+            *l = state[i];
+        }
+    }
+
+    // SAFETY: the caller checked the `sve2-sha3` cpu feature is supported,
+    // and that the vector length is at least 256 bits.
+    unsafe { keccak_x4(&mut lanes) };
+
+    for (i, lane) in lanes.chunks_exact(4).enumerate() {
+        for (l, state) in lane.iter().zip(states.iter_mut()) {
+            state[i] = *l;
+        }
+    }
+}
+
+// Register allocation:
+//
+// z0-z24:  the 25 lanes of four states
+// z25-z31: theta column parities, then theta's D values (z25, z30,
+//          z26, z28, z31 for x = 0 to 4), then temporaries
+// p0:      the first four 64-bit elements
+
+macro_rules! load {
+    ($z:literal) => {
+        concat!(
+            "ld1d {{",
+            $z,
+            ".d}}, p0/z, [{lanes}]\n",
+            "add {lanes}, {lanes}, #32\n",
+        )
+    };
+}
+
+macro_rules! store {
+    ($z:literal) => {
+        concat!(
+            "st1d {{",
+            $z,
+            ".d}}, p0, [{lanes}]\n",
+            "add {lanes}, {lanes}, #32\n",
+        )
+    };
+}
+
+/// c = a0 ^ a1 ^ a2 ^ a3 ^ a4
+macro_rules! theta_column {
+    ($c:literal, $a0:literal, $a1:literal, $a2:literal, $a3:literal, $a4:literal) => {
+        concat!(
+            "movprfx ", $c, ", ", $a0, "\n", "eor3 ", $c, ".d, ", $c, ".d, ", $a1, ".d, ", $a2,
+            ".d\n", "eor3 ", $c, ".d, ", $c, ".d, ", $a3, ".d, ", $a4, ".d\n",
+        )
+    };
+}
+
+/// b = rotate_right(a ^ d, ror)
+macro_rules! rho_pi {
+    ($b:literal, $a:literal, $d:literal, $ror:literal) => {
+        concat!(
+            "movprfx ", $b, ", ", $a, "\n", "xar ", $b, ".d, ", $b, ".d, ", $d, ".d, #", $ror,
+            "\n",
+        )
+    };
+}
+
+/// a[x] = a[x] ^ (!a[x + 1] & a[x + 2]), for one row
+macro_rules! chi_row {
+    ($a0:literal, $a1:literal, $a2:literal, $a3:literal, $a4:literal) => {
+        concat!(
+            "mov z27.d, ",
+            $a0,
+            ".d\n",
+            "mov z28.d, ",
+            $a1,
+            ".d\n",
+            "bcax ",
+            $a0,
+            ".d, ",
+            $a0,
+            ".d, ",
+            $a2,
+            ".d, ",
+            $a1,
+            ".d\n",
+            "bcax ",
+            $a1,
+            ".d, ",
+            $a1,
+            ".d, ",
+            $a3,
+            ".d, ",
+            $a2,
+            ".d\n",
+            "bcax ",
+            $a2,
+            ".d, ",
+            $a2,
+            ".d, ",
+            $a4,
+            ".d, ",
+            $a3,
+            ".d\n",
+            "bcax ",
+            $a3,
+            ".d, ",
+            $a3,
+            ".d, z27.d, ",
+            $a4,
+            ".d\n",
+            "bcax ",
+            $a4,
+            ".d, ",
+            $a4,
+            ".d, z28.d, z27.d\n",
+        )
+    };
+}
+
+#[target_feature(enable = "sve2,sve2-sha3")]
+unsafe fn keccak_x4(lanes: &mut [u64; 100]) {
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            "ptrue p0.d, vl4",
+            load!("z0"),
+            load!("z1"),
+            load!("z2"),
+            load!("z3"),
+            load!("z4"),
+            load!("z5"),
+            load!("z6"),
+            load!("z7"),
+            load!("z8"),
+            load!("z9"),
+            load!("z10"),
+            load!("z11"),
+            load!("z12"),
+            load!("z13"),
+            load!("z14"),
+            load!("z15"),
+            load!("z16"),
+            load!("z17"),
+            load!("z18"),
+            load!("z19"),
+            load!("z20"),
+            load!("z21"),
+            load!("z22"),
+            load!("z23"),
+            load!("z24"),
+            "2:",
+            // theta
+            theta_column!("z25", "z0", "z5", "z10", "z15", "z20"),
+            theta_column!("z26", "z1", "z6", "z11", "z16", "z21"),
+            theta_column!("z27", "z2", "z7", "z12", "z17", "z22"),
+            theta_column!("z28", "z3", "z8", "z13", "z18", "z23"),
+            theta_column!("z29", "z4", "z9", "z14", "z19", "z24"),
+            "rax1 z30.d, z25.d, z27.d",
+            "rax1 z31.d, z28.d, z25.d",
+            "rax1 z25.d, z29.d, z26.d",
+            "rax1 z26.d, z26.d, z28.d",
+            "rax1 z28.d, z27.d, z29.d",
+            // rho and pi, with the remainder of theta.  lane 0 does
+            // not move; the others form one cycle, starting from lane 1
+            "eor z0.d, z0.d, z25.d",
+            "mov z27.d, z1.d",
+            rho_pi!("z1", "z6", "z30", "20"),
+            rho_pi!("z6", "z9", "z31", "44"),
+            rho_pi!("z9", "z22", "z26", "3"),
+            rho_pi!("z22", "z14", "z31", "25"),
+            rho_pi!("z14", "z20", "z25", "46"),
+            rho_pi!("z20", "z2", "z26", "2"),
+            rho_pi!("z2", "z12", "z26", "21"),
+            rho_pi!("z12", "z13", "z28", "39"),
+            rho_pi!("z13", "z19", "z31", "56"),
+            rho_pi!("z19", "z23", "z28", "8"),
+            rho_pi!("z23", "z15", "z25", "23"),
+            rho_pi!("z15", "z4", "z31", "37"),
+            rho_pi!("z4", "z24", "z31", "50"),
+            rho_pi!("z24", "z21", "z30", "62"),
+            rho_pi!("z21", "z8", "z28", "9"),
+            rho_pi!("z8", "z16", "z30", "19"),
+            rho_pi!("z16", "z5", "z25", "28"),
+            rho_pi!("z5", "z3", "z28", "36"),
+            rho_pi!("z3", "z18", "z28", "43"),
+            rho_pi!("z18", "z17", "z26", "49"),
+            rho_pi!("z17", "z11", "z30", "54"),
+            rho_pi!("z11", "z7", "z26", "58"),
+            rho_pi!("z7", "z10", "z25", "61"),
+            rho_pi!("z10", "z27", "z30", "63"),
+            // chi
+            chi_row!("z0", "z1", "z2", "z3", "z4"),
+            chi_row!("z5", "z6", "z7", "z8", "z9"),
+            chi_row!("z10", "z11", "z12", "z13", "z14"),
+            chi_row!("z15", "z16", "z17", "z18", "z19"),
+            chi_row!("z20", "z21", "z22", "z23", "z24"),
+            // iota
+            "ld1rd {{z27.d}}, p0/z, [{rc}]",
+            "add {rc}, {rc}, #8",
+            "eor z0.d, z0.d, z27.d",
+            "subs {rounds}, {rounds}, #1",
+            "b.ne 2b",
+            "sub {lanes}, {lanes}, #800",
+            store!("z0"),
+            store!("z1"),
+            store!("z2"),
+            store!("z3"),
+            store!("z4"),
+            store!("z5"),
+            store!("z6"),
+            store!("z7"),
+            store!("z8"),
+            store!("z9"),
+            store!("z10"),
+            store!("z11"),
+            store!("z12"),
+            store!("z13"),
+            store!("z14"),
+            store!("z15"),
+            store!("z16"),
+            store!("z17"),
+            store!("z18"),
+            store!("z19"),
+            store!("z20"),
+            store!("z21"),
+            store!("z22"),
+            store!("z23"),
+            store!("z24"),
+            lanes = inout(reg) lanes.as_mut_ptr() => _,
+            rc = inout(reg) RC.as_ptr() => _,
+            rounds = inout(reg) RC.len() => _,
+            // clobbers
+            out("v0") _,
+            out("v1") _,
+            out("v2") _,
+            out("v3") _,
+            out("v4") _,
+            out("v5") _,
+            out("v6") _,
+            out("v7") _,
+            out("v8") _,
+            out("v9") _,
+            out("v10") _,
+            out("v11") _,
+            out("v12") _,
+            out("v13") _,
+            out("v14") _,
+            out("v15") _,
+            out("v16") _,
+            out("v17") _,
+            out("v18") _,
+            out("v19") _,
+            out("v20") _,
+            out("v21") _,
+            out("v22") _,
+            out("v23") _,
+            out("v24") _,
+            out("v25") _,
+            out("v26") _,
+            out("v27") _,
+            out("v28") _,
+            out("v29") _,
+            out("v30") _,
+            out("v31") _,
+            out("p0") _,
+            options(nostack),
+        )
+    }
+}
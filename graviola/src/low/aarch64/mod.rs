@@ -3,6 +3,8 @@
 
 pub(crate) mod aes;
 pub(crate) mod aes_gcm;
+pub(crate) mod aes_gcm_mux;
+pub(crate) mod aes_mux;
 pub(crate) mod bignum_add;
 pub(crate) mod bignum_add_p256;
 pub(crate) mod bignum_add_p384;
@@ -52,9 +54,17 @@ pub(crate) mod cpu;
 pub(crate) mod curve25519_x25519;
 pub(crate) mod curve25519_x25519base;
 pub(crate) mod ghash;
+pub(crate) mod ghash_mux;
+pub(crate) mod keccak_x4;
+pub(crate) mod keccak_x4_mux;
+pub(crate) mod keccak_x4_sve2;
 pub(crate) mod p256_montjadd;
 pub(crate) mod p256_montjdouble;
 pub(crate) mod p256_montjmixadd;
 pub(crate) mod p384_montjadd;
 pub(crate) mod p384_montjdouble;
+pub(crate) mod poly1305;
 pub(crate) mod sha256;
+pub(crate) mod sha256_mux;
+pub(crate) mod sha512;
+pub(crate) mod sha512_mux;
@@ -0,0 +1,226 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! Neon Poly1305, processing two blocks at a time.
+//!
+//! This is the same as the AVX2 implementation in
+//! low/x86_64/poly1305.rs, but with two 64-bit lanes: each step
+//! multiplies both accumulators by r^2 and adds the next two blocks.
+//! Finally, the accumulators are multiplied by r^2 and r respectively,
+//! and summed.
+
+use core::arch::aarch64::*;
+
+use crate::low::dispatch;
+use crate::low::generic::poly1305 as generic;
+
+/// Process whole pairs of blocks from the start of `blocks`, updating
+/// the accumulator `h` of the generic implementation (with key `r`).
+///
+/// Returns the unprocessed remainder.  Short inputs are returned
+/// without processing, as the setup cost outweighs any benefit.
+pub(crate) fn poly1305_blocks<'a>(h: &mut [u32; 5], r: &[u32; 5], blocks: &'a [u8]) -> &'a [u8] {
+    if blocks.len() < MIN_LEN || !use_neon() {
+        return blocks;
+    }
+
+    let (whole, rest) = blocks.split_at(blocks.len() & !31);
+    // SAFETY: this crate requires the `neon` cpu feature
+    unsafe { _blocks(h, r, whole) };
+    rest
+}
+
+pub(in crate::low) fn use_neon() -> bool {
+    // nb. neon is in our required set.
+    dispatch::use_native()
+}
+
+#[target_feature(enable = "neon")]
+unsafe fn _blocks(h: &mut [u32; 5], r: &[u32; 5], blocks: &[u8]) {
+    let r2 = mul(r, r);
+
+    let rr = [
+        vdup_n_u32(r2[0]),
+        vdup_n_u32(r2[1]),
+        vdup_n_u32(r2[2]),
+        vdup_n_u32(r2[3]),
+        vdup_n_u32(r2[4]),
+    ];
+    let ss = [
+        vdup_n_u32(r2[1] * 5),
+        vdup_n_u32(r2[2] * 5),
+        vdup_n_u32(r2[3] * 5),
+        vdup_n_u32(r2[4] * 5),
+    ];
+
+    let mut acc = [
+        vsetq_lane_u64::<0>(h[0] as u64, vdupq_n_u64(0)),
+        vsetq_lane_u64::<0>(h[1] as u64, vdupq_n_u64(0)),
+        vsetq_lane_u64::<0>(h[2] as u64, vdupq_n_u64(0)),
+        vsetq_lane_u64::<0>(h[3] as u64, vdupq_n_u64(0)),
+        vsetq_lane_u64::<0>(h[4] as u64, vdupq_n_u64(0)),
+    ];
+
+    for (i, two) in blocks.chunks_exact(32).enumerate() {
+        // SAFETY: `two` is 32 bytes long
+        let m = unsafe { load_blocks(two) };
+
+        if i > 0 {
+            acc = mul_x2(&acc, &rr, &ss);
+        }
+
+        for (a, m) in acc.iter_mut().zip(m.iter()) {
+            *a = vaddq_u64(*a, *m);
+        }
+    }
+
+    // lane 0 holds even blocks, lane 1 odd ones
+    let lanes = |i: usize| vset_lane_u32::<1>(r[i], vdup_n_u32(r2[i]));
+    let rf = [lanes(0), lanes(1), lanes(2), lanes(3), lanes(4)];
+    let sf = [
+        vmul_n_u32(rf[1], 5),
+        vmul_n_u32(rf[2], 5),
+        vmul_n_u32(rf[3], 5),
+        vmul_n_u32(rf[4], 5),
+    ];
+    let sum = mul_x2(&acc, &rf, &sf);
+
+    let mut d = [0u64; 5];
+    for (d, v) in d.iter_mut().zip(sum.iter()) {
+        *d = vaddvq_u64(*v);
+    }
+
+    let carry = d[0] >> 26;
+    h[0] = (d[0] & 0x3ff_ffff) as u32;
+    d[1] += carry;
+    let carry = d[1] >> 26;
+    h[1] = (d[1] & 0x3ff_ffff) as u32;
+    d[2] += carry;
+    let carry = d[2] >> 26;
+    h[2] = (d[2] & 0x3ff_ffff) as u32;
+    d[3] += carry;
+    let carry = d[3] >> 26;
+    h[3] = (d[3] & 0x3ff_ffff) as u32;
+    d[4] += carry;
+    let carry = (d[4] >> 26) as u32;
+    h[4] = (d[4] & 0x3ff_ffff) as u32;
+    h[0] += carry * 5;
+    let carry = h[0] >> 26;
+    h[0] &= 0x3ff_ffff;
+    h[1] += carry;
+}
+
+/// Load two blocks, into lanes in order.
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn load_blocks(two: &[u8]) -> [uint64x2_t; 5] {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    let (a, b) = unsafe {
+        (
+            vreinterpretq_u64_u8(vld1q_u8(two.as_ptr())),
+            vreinterpretq_u64_u8(vld1q_u8(two[16..].as_ptr())),
+        )
+    };
+    let lo = vzip1q_u64(a, b);
+    let hi = vzip2q_u64(a, b);
+
+    let mask = vdupq_n_u64(0x3ff_ffff);
+    [
+        vandq_u64(lo, mask),
+        vandq_u64(vshrq_n_u64::<26>(lo), mask),
+        vandq_u64(
+            vorrq_u64(vshrq_n_u64::<52>(lo), vshlq_n_u64::<12>(hi)),
+            mask,
+        ),
+        vandq_u64(vshrq_n_u64::<14>(hi), mask),
+        vorrq_u64(vshrq_n_u64::<40>(hi), vdupq_n_u64(1 << 24)),
+    ]
+}
+
+/// Multiply each lane of `h` by `r`, and partially reduce.
+///
+/// This is the same as `mul()` in the generic implementation.
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn mul_x2(h: &[uint64x2_t; 5], r: &[uint32x2_t; 5], s: &[uint32x2_t; 4]) -> [uint64x2_t; 5] {
+    let h = [
+        vmovn_u64(h[0]),
+        vmovn_u64(h[1]),
+        vmovn_u64(h[2]),
+        vmovn_u64(h[3]),
+        vmovn_u64(h[4]),
+    ];
+
+    macro_rules! sum {
+        ($a0:expr, $b0:expr; $($a:expr, $b:expr);+) => {{
+            let t = vmull_u32($a0, $b0);
+            $( let t = vmlal_u32(t, $a, $b); )+
+            t
+        }};
+    }
+
+    let d0 = sum!(h[0], r[0]; h[1], s[3]; h[2], s[2]; h[3], s[1]; h[4], s[0]);
+    let d1 = sum!(h[0], r[1]; h[1], r[0]; h[2], s[3]; h[3], s[2]; h[4], s[1]);
+    let d2 = sum!(h[0], r[2]; h[1], r[1]; h[2], r[0]; h[3], s[3]; h[4], s[2]);
+    let d3 = sum!(h[0], r[3]; h[1], r[2]; h[2], r[1]; h[3], r[0]; h[4], s[3]);
+    let d4 = sum!(h[0], r[4]; h[1], r[3]; h[2], r[2]; h[3], r[1]; h[4], r[0]);
+
+    let mask = vdupq_n_u64(0x3ff_ffff);
+
+    let carry = vshrq_n_u64::<26>(d0);
+    let h0 = vandq_u64(d0, mask);
+    let d1 = vaddq_u64(d1, carry);
+    let carry = vshrq_n_u64::<26>(d1);
+    let h1 = vandq_u64(d1, mask);
+    let d2 = vaddq_u64(d2, carry);
+    let carry = vshrq_n_u64::<26>(d2);
+    let h2 = vandq_u64(d2, mask);
+    let d3 = vaddq_u64(d3, carry);
+    let carry = vshrq_n_u64::<26>(d3);
+    let h3 = vandq_u64(d3, mask);
+    let d4 = vaddq_u64(d4, carry);
+    let carry = vshrq_n_u64::<26>(d4);
+    let h4 = vandq_u64(d4, mask);
+    let h0 = vaddq_u64(h0, vaddq_u64(carry, vshlq_n_u64::<2>(carry)));
+    let carry = vshrq_n_u64::<26>(h0);
+    let h0 = vandq_u64(h0, mask);
+    let h1 = vaddq_u64(h1, carry);
+
+    [h0, h1, h2, h3, h4]
+}
+
+fn mul(a: &[u32; 5], b: &[u32; 5]) -> [u32; 5] {
+    let mut h = *a;
+    generic::mul(&mut h, b, &[b[1] * 5, b[2] * 5, b[3] * 5, b[4] * 5]);
+    h
+}
+
+const MIN_LEN: usize = 128;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_generic() {
+        let key = [0xffu8; 32];
+        let pattern = (0..1024 + 15).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        let ones = vec![0xffu8; 1024 + 15];
+
+        for data in [&pattern, &ones] {
+            for len in [0, 16, 128, 128 + 16, 512 + 17, 1024 + 15] {
+                let data = &data[..len];
+
+                // one block at a time never uses `poly1305_blocks`
+                let mut expect = generic::Poly1305::new(&key);
+                for block in data.chunks(16) {
+                    expect.add_bytes(block);
+                }
+
+                let mut got = generic::Poly1305::new(&key);
+                got.add_bytes(data);
+                assert_eq!(got.finish(), expect.finish(), "len={len}");
+            }
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::low::aarch64::cpu;
 
 pub(crate) fn sha256_compress_blocks(state: &mut [u32; 8], blocks: &[u8]) {
     debug_assert!(blocks.len() % 64 == 0);
-    // SAFETY: crate requires the `neon` and `sha2` cpu features
+    // SAFETY: `sha2` is checked by `sha256_mux`; this crate requires the `neon` cpu feature
     unsafe { sha256(state, blocks) }
 }
 
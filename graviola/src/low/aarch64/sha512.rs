@@ -0,0 +1,270 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+// SHA512 using the ARMv8.2 SHA512 instructions.
+//
+// The intrinsics for these instructions were stabilised after our MSRV,
+// so we reach them via inline assembly instead.
+
+use core::arch::aarch64::*;
+
+use crate::low::aarch64::cpu;
+
+pub(crate) fn sha512_compress_blocks(state: &mut [u64; 8], blocks: &[u8]) {
+    debug_assert!(blocks.len() % 128 == 0);
+    // SAFETY: this crate requires the `neon` cpu feature, and the caller
+    // checked the `sha3` cpu feature is supported.
+    unsafe { sha512(state, blocks) }
+}
+
+/// Equivalent to `vsha512hq_u64`.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+unsafe fn sha512h(hash_ed: uint64x2_t, hash_gf: uint64x2_t, kwh_kwh2: uint64x2_t) -> uint64x2_t {
+    let mut ret = hash_ed;
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            "sha512h {r:q}, {n:q}, {m:v}.2d",
+            r = inout(vreg) ret,
+            n = in(vreg) hash_gf,
+            m = in(vreg) kwh_kwh2,
+            options(nostack, nomem, pure),
+        );
+    }
+    ret
+}
+
+/// Equivalent to `vsha512h2q_u64`.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+unsafe fn sha512h2(sum_ab: uint64x2_t, hash_c_: uint64x2_t, hash_ab: uint64x2_t) -> uint64x2_t {
+    let mut ret = sum_ab;
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            "sha512h2 {r:q}, {n:q}, {m:v}.2d",
+            r = inout(vreg) ret,
+            n = in(vreg) hash_c_,
+            m = in(vreg) hash_ab,
+            options(nostack, nomem, pure),
+        );
+    }
+    ret
+}
+
+/// Equivalent to `vsha512su0q_u64`.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+unsafe fn sha512su0(w0_1: uint64x2_t, w2_: uint64x2_t) -> uint64x2_t {
+    let mut ret = w0_1;
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            "sha512su0 {r:v}.2d, {n:v}.2d",
+            r = inout(vreg) ret,
+            n = in(vreg) w2_,
+            options(nostack, nomem, pure),
+        );
+    }
+    ret
+}
+
+/// Equivalent to `vsha512su1q_u64`.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+unsafe fn sha512su1(s01_s02: uint64x2_t, w14_15: uint64x2_t, w9_10: uint64x2_t) -> uint64x2_t {
+    let mut ret = s01_s02;
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            "sha512su1 {r:v}.2d, {n:v}.2d, {m:v}.2d",
+            r = inout(vreg) ret,
+            n = in(vreg) w14_15,
+            m = in(vreg) w9_10,
+            options(nostack, nomem, pure),
+        );
+    }
+    ret
+}
+
+macro_rules! k {
+    ($t:expr) => {
+        vld1q_u64(K.0.as_ptr().add($t))
+    };
+}
+
+/// Two rounds, using message words `$w` (which already contain
+/// the next two words of the schedule).
+///
+/// The working variables rotate through the four state vectors,
+/// so the caller permutes `ab`/`cd`/`ef`/`gh` between invocations.
+macro_rules! round2 {
+    ($ab:ident, $cd:ident, $ef:ident, $gh:ident, $w:expr, $k:expr) => {
+        let wk = vaddq_u64($w, $k);
+        let sum = vaddq_u64(vextq_u64(wk, wk, 1), $gh);
+        let intermed = sha512h(sum, vextq_u64($ef, $gh, 1), vextq_u64($cd, $ef, 1));
+        $gh = sha512h2(intermed, $cd, $ab);
+        $cd = vaddq_u64($cd, intermed);
+    };
+}
+
+/// Eight rounds, processing message words `$s0`..`$s3`.
+macro_rules! round8 {
+    ($ab:ident, $cd:ident, $ef:ident, $gh:ident, $s0:expr, $s1:expr, $s2:expr, $s3:expr, $t:expr) => {
+        round2!($ab, $cd, $ef, $gh, $s0, k!($t));
+        round2!($gh, $ab, $cd, $ef, $s1, k!($t + 2));
+        round2!($ef, $gh, $ab, $cd, $s2, k!($t + 4));
+        round2!($cd, $ef, $gh, $ab, $s3, k!($t + 6));
+    };
+}
+
+/// Compute the next two message schedule words into `$s0`.
+macro_rules! schedule {
+    ($s0:ident, $s1:ident, $s4:ident, $s5:ident, $s7:ident) => {
+        $s0 = sha512su1(sha512su0($s0, $s1), $s7, vextq_u64($s4, $s5, 1));
+    };
+}
+
+#[target_feature(enable = "neon,sha3")]
+unsafe fn sha512(state: &mut [u64; 8], blocks: &[u8]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let mut ab = vld1q_u64(state[0..2].as_ptr());
+        let mut cd = vld1q_u64(state[2..4].as_ptr());
+        let mut ef = vld1q_u64(state[4..6].as_ptr());
+        let mut gh = vld1q_u64(state[6..8].as_ptr());
+
+        for block in blocks.chunks_exact(128) {
+            let ab_prev = ab;
+            let cd_prev = cd;
+            let ef_prev = ef;
+            let gh_prev = gh;
+
+            // prefetch next block
+            cpu::prefetch_ro(block.as_ptr().add(128));
+
+            let mut s0 = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(block[0..].as_ptr())));
+            let mut s1 = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(block[16..].as_ptr())));
+            let mut s2 = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(block[32..].as_ptr())));
+            let mut s3 = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(block[48..].as_ptr())));
+            let mut s4 = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(block[64..].as_ptr())));
+            let mut s5 = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(block[80..].as_ptr())));
+            let mut s6 = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(block[96..].as_ptr())));
+            let mut s7 = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(block[112..].as_ptr())));
+
+            round8!(ab, cd, ef, gh, s0, s1, s2, s3, 0);
+            round8!(ab, cd, ef, gh, s4, s5, s6, s7, 8);
+
+            for t in (16..80).step_by(16) {
+                schedule!(s0, s1, s4, s5, s7);
+                schedule!(s1, s2, s5, s6, s0);
+                schedule!(s2, s3, s6, s7, s1);
+                schedule!(s3, s4, s7, s0, s2);
+                round8!(ab, cd, ef, gh, s0, s1, s2, s3, t);
+
+                schedule!(s4, s5, s0, s1, s3);
+                schedule!(s5, s6, s1, s2, s4);
+                schedule!(s6, s7, s2, s3, s5);
+                schedule!(s7, s0, s3, s4, s6);
+                round8!(ab, cd, ef, gh, s4, s5, s6, s7, t + 8);
+            }
+
+            ab = vaddq_u64(ab, ab_prev);
+            cd = vaddq_u64(cd, cd_prev);
+            ef = vaddq_u64(ef, ef_prev);
+            gh = vaddq_u64(gh, gh_prev);
+        }
+
+        vst1q_u64(state[0..2].as_mut_ptr(), ab);
+        vst1q_u64(state[2..4].as_mut_ptr(), cd);
+        vst1q_u64(state[4..6].as_mut_ptr(), ef);
+        vst1q_u64(state[6..8].as_mut_ptr(), gh);
+    }
+}
+
+#[repr(align(16))]
+struct Aligned([u64; 80]);
+
+static K: Aligned = Aligned([
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+]);
@@ -0,0 +1,39 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::low::aarch64;
+use crate::low::{dispatch, generic};
+
+pub(crate) fn sha512_compress_blocks(state: &mut [u64; 8], blocks: &[u8]) {
+    if use_sha512_instructions() {
+        aarch64::sha512::sha512_compress_blocks(state, blocks)
+    } else {
+        generic::sha512::sha512_compress_blocks(state, blocks)
+    }
+}
+
+pub(in crate::low) fn use_sha512_instructions() -> bool {
+    // nb. the `sha3` feature includes the SHA512 instructions.
+    dispatch::use_native() && aarch64::cpu::features().sha3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha512_instructions_match_generic() {
+        if !aarch64::cpu::features().sha3 {
+            return;
+        }
+
+        let data = (0..128 * 5).map(|i| (i * 13) as u8).collect::<Vec<_>>();
+        for nblocks in 0..=5 {
+            let mut accel = [0x6a09e667f3bcc908u64; 8];
+            let mut generic = accel;
+            aarch64::sha512::sha512_compress_blocks(&mut accel, &data[..nblocks * 128]);
+            generic::sha512::sha512_compress_blocks(&mut generic, &data[..nblocks * 128]);
+            assert_eq!(accel, generic);
+        }
+    }
+}
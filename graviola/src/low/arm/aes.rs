@@ -0,0 +1,232 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! AES using the ARMv8 cryptography instructions, in AArch32 state.
+//!
+//! This follows the aarch64 implementation.  Rust's intrinsics and
+//! target features for 32-bit arm are not stable, so each operation is
+//! a block of inline assembly using q0 and q1 by name, and enabling the
+//! instructions itself.
+//!
+//! ref: <https://developer.arm.com/documentation/ddi0487/latest>
+//! (sections F6.1.1 to F6.1.4)
+
+use crate::low;
+
+pub(crate) struct AesKey {
+    /// Round keys, 16 bytes each.
+    round_keys: [u8; (14 + 1) * 16],
+    rounds: usize,
+}
+
+impl AesKey {
+    /// Creates an AesKey.
+    ///
+    /// `key` must be 16 or 32 bytes in length (AES-192 not supported).
+    pub(crate) fn new(key: &[u8]) -> Self {
+        // we do the key expansion in 32 bit words, then convert
+        // to bytes as the final step.
+        let mut rk32 = [0u32; (14 + 1) * 4];
+        for (w, k) in rk32.iter_mut().zip(key.chunks_exact(4)) {
+            *w = u32::from_be_bytes(k.try_into().unwrap());
+        }
+
+        let rounds = match key.len() {
+            16 => {
+                for r in 1..11 {
+                    let (prev, current) = rk32[((r - 1) * 4)..((r + 1) * 4)].split_at_mut(4);
+                    current[0] = sub_word(prev[3].rotate_left(8)) ^ (RCON[r - 1] << 24) ^ prev[0];
+                    current[1] = current[0] ^ prev[1];
+                    current[2] = current[1] ^ prev[2];
+                    current[3] = current[2] ^ prev[3];
+                }
+                10
+            }
+            32 => {
+                for r in 2..15 {
+                    let (prev, current) = rk32[((r - 2) * 4)..((r + 1) * 4)].split_at_mut(8);
+                    if r & 1 == 1 {
+                        // odd round
+                        current[0] = sub_word(prev[7]) ^ prev[0];
+                    } else {
+                        // even round
+                        current[0] =
+                            sub_word(prev[7].rotate_left(8)) ^ (RCON[(r - 1) / 2] << 24) ^ prev[0];
+                    }
+                    current[1] = current[0] ^ prev[1];
+                    current[2] = current[1] ^ prev[2];
+                    current[3] = current[2] ^ prev[3];
+                }
+                14
+            }
+            24 => panic!("aes-192 not supported"),
+            _ => panic!("invalid aes key size"),
+        };
+
+        let mut round_keys = [0u8; (14 + 1) * 16];
+        for (b, w) in round_keys.chunks_exact_mut(4).zip(rk32.iter()) {
+            b.copy_from_slice(&w.to_be_bytes());
+        }
+        low::zeroise(&mut rk32);
+
+        Self { round_keys, rounds }
+    }
+
+    /// Creates an AesKey for use with `decrypt_block`.
+    ///
+    /// This implementation derives the decryption round keys as each
+    /// block is decrypted, so this is `new`.
+    pub(crate) fn new_with_decryption(key: &[u8]) -> Self {
+        Self::new(key)
+    }
+
+    pub(crate) fn encrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+        // SAFETY: the caller checked the `aes` cpu feature is supported.
+        unsafe { encrypt_block(&self.round_keys[..(self.rounds + 1) * 16], inout) }
+    }
+
+    pub(crate) fn decrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+        // SAFETY: the caller checked the `aes` cpu feature is supported.
+        unsafe { decrypt_block(&self.round_keys[..(self.rounds + 1) * 16], inout) }
+    }
+
+    pub(crate) fn ctr(&self, initial_counter: &[u8; 16], cipher_inout: &mut [u8]) {
+        let mut counter = u32::from_be_bytes(initial_counter[12..].try_into().unwrap());
+        let mut block = *initial_counter;
+
+        for chunk in cipher_inout.chunks_mut(16) {
+            counter = counter.wrapping_add(1);
+            block[12..].copy_from_slice(&counter.to_be_bytes());
+
+            let mut keystream = block;
+            self.encrypt_block(&mut keystream);
+
+            for (c, k) in chunk.iter_mut().zip(keystream.iter()) {
+                *c ^= *k;
+            }
+            low::zeroise(&mut keystream);
+        }
+    }
+}
+
+impl Drop for AesKey {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.round_keys);
+        low::zeroise_value(&mut self.rounds);
+    }
+}
+
+/// `SubWord()` of FIPS-197, via `aese` with a zero round key.
+///
+/// `aese` is `SubBytes(ShiftRows(block ^ key))`: `ShiftRows` leaves the
+/// first column (ie, the first 32-bit word) of a block where it is, so
+/// the result can be read from there.
+fn sub_word(w: u32) -> u32 {
+    let r: u32;
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    // the caller checked the `aes` cpu feature is supported.
+    unsafe {
+        core::arch::asm!(
+            ".arch armv8-a",
+            ".fpu crypto-neon-fp-armv8",
+            "vdup.32 q0, {w}",
+            "vmov.i8 q1, #0",
+            "aese.8 q0, q1",
+            "vmov.32 {r}, d0[0]",
+            "vmov.i8 q0, #0",
+            w = in(reg) w,
+            r = lateout(reg) r,
+            // clobbers
+            out("d0") _,
+            out("d1") _,
+            out("d2") _,
+            out("d3") _,
+            options(pure, nomem, nostack),
+        );
+    }
+    r
+}
+
+const RCON: [u32; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// Encrypt one block with `round_keys` (11 or 15 of them, 16 bytes each).
+unsafe fn encrypt_block(round_keys: &[u8], inout: &mut [u8]) {
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            ".arch armv8-a",
+            ".fpu crypto-neon-fp-armv8",
+            "vld1.8 {{q0}}, [{inout}]",
+            // all but the last round
+            "2:",
+            "vld1.8 {{q1}}, [{rk}]!",
+            "aese.8 q0, q1",
+            "aesmc.8 q0, q0",
+            "subs {n}, {n}, #1",
+            "bne 2b",
+            // the last round has no MixColumns, and then the final key
+            "vld1.8 {{q1}}, [{rk}]!",
+            "aese.8 q0, q1",
+            "vld1.8 {{q1}}, [{rk}]",
+            "veor q0, q0, q1",
+            "vst1.8 {{q0}}, [{inout}]",
+            // don't leave the state or keys in vector registers
+            "vmov.i8 q0, #0",
+            "vmov.i8 q1, #0",
+            inout = in(reg) inout.as_mut_ptr(),
+            rk = inout(reg) round_keys.as_ptr() => _,
+            n = inout(reg) round_keys.len() / 16 - 2 => _,
+            // clobbers
+            out("d0") _,
+            out("d1") _,
+            out("d2") _,
+            out("d3") _,
+            options(nostack),
+        )
+    }
+}
+
+/// Decrypt one block, with the equivalent inverse cipher (FIPS-197 section 5.3.5).
+///
+/// As on aarch64, the decryption round keys are derived from the
+/// encryption round keys as needed.
+unsafe fn decrypt_block(round_keys: &[u8], inout: &mut [u8]) {
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            ".arch armv8-a",
+            ".fpu crypto-neon-fp-armv8",
+            "vld1.8 {{q0}}, [{inout}]",
+            // nb. `aesd` is `InvSubBytes(InvShiftRows(block ^ key))`, so the
+            // xor of each round key happens at the start of the next instruction
+            "vld1.8 {{q1}}, [{rk}]",
+            "aesd.8 q0, q1",
+            "2:",
+            "sub {rk}, {rk}, #16",
+            "vld1.8 {{q1}}, [{rk}]",
+            "aesimc.8 q0, q0",
+            "aesimc.8 q1, q1",
+            "aesd.8 q0, q1",
+            "subs {n}, {n}, #1",
+            "bne 2b",
+            "sub {rk}, {rk}, #16",
+            "vld1.8 {{q1}}, [{rk}]",
+            "veor q0, q0, q1",
+            "vst1.8 {{q0}}, [{inout}]",
+            // don't leave the state or keys in vector registers
+            "vmov.i8 q0, #0",
+            "vmov.i8 q1, #0",
+            inout = in(reg) inout.as_mut_ptr(),
+            rk = inout(reg) round_keys[round_keys.len() - 16..].as_ptr() => _,
+            n = inout(reg) round_keys.len() / 16 - 2 => _,
+            // clobbers
+            out("d0") _,
+            out("d1") _,
+            out("d2") _,
+            out("d3") _,
+            options(nostack),
+        )
+    }
+}
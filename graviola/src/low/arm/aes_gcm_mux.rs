@@ -0,0 +1,48 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::aes_mux::AesKey;
+use super::ghash_mux::Ghash;
+use crate::low::generic;
+
+pub(crate) fn encrypt(
+    key: &AesKey,
+    ghash: &mut Ghash<'_>,
+    initial_counter: &[u8; 16],
+    aad: &[u8],
+    cipher_inout: &mut [u8],
+) {
+    match (key, ghash) {
+        (AesKey::Generic(key), Ghash::Generic(ghash)) => {
+            generic::aes_gcm::encrypt(key, ghash, initial_counter, aad, cipher_inout)
+        }
+        // AES and GHASH are chosen independently (see `aes_mux`); the
+        // AES instructions are used one block at a time, so there is
+        // nothing to gain from interleaving them
+        (key, ghash) => {
+            ghash.add(aad);
+            key.ctr(initial_counter, cipher_inout);
+            ghash.add(cipher_inout);
+        }
+    }
+}
+
+pub(crate) fn decrypt(
+    key: &AesKey,
+    ghash: &mut Ghash<'_>,
+    initial_counter: &[u8; 16],
+    aad: &[u8],
+    cipher_inout: &mut [u8],
+) {
+    match (key, ghash) {
+        (AesKey::Generic(key), Ghash::Generic(ghash)) => {
+            generic::aes_gcm::decrypt(key, ghash, initial_counter, aad, cipher_inout)
+        }
+        // see above
+        (key, ghash) => {
+            ghash.add(aad);
+            ghash.add(cipher_inout);
+            key.ctr(initial_counter, cipher_inout);
+        }
+    }
+}
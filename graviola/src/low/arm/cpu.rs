@@ -0,0 +1,76 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! CPU feature detection for 32-bit arm.
+//!
+//! There are no secret-dependent CPU modes here, so the CPU state
+//! management is shared with the portable targets (see `generic::cpu`).
+
+use std::sync::OnceLock;
+
+/// The CPU features we use.
+///
+/// All are optional: the baseline is armv7 without NEON.
+pub(crate) struct Features {
+    /// The ARMv8 AES instructions, in AArch32 state (and NEON).
+    pub(crate) aes: bool,
+    /// The ARMv8 64-bit polynomial multiply instruction, in
+    /// AArch32 state (and NEON).
+    pub(crate) pmull: bool,
+}
+
+impl Features {
+    fn detect() -> Self {
+        let (hwcap, hwcap2) = auxv::hwcaps();
+        let neon = hwcap & auxv::HWCAP_NEON != 0;
+
+        Self {
+            aes: neon && hwcap2 & auxv::HWCAP2_AES != 0,
+            pmull: neon && hwcap2 & auxv::HWCAP2_PMULL != 0,
+        }
+    }
+}
+
+/// Returns the CPU features, detecting them on first call.
+///
+/// Every dispatch decision is a function of these, so is made once
+/// per process.
+pub(crate) fn features() -> &'static Features {
+    static FEATURES: OnceLock<Features> = OnceLock::new();
+    FEATURES.get_or_init(Features::detect)
+}
+
+/// The hardware capabilities in the ELF auxiliary vector.
+///
+/// `is_arm_feature_detected!` is not stable, so we ask the C library
+/// (which `std` already links) directly.
+///
+/// Ref. <https://docs.kernel.org/arch/arm/elf_hwcaps.html>
+mod auxv {
+    use core::ffi::c_ulong;
+
+    pub(super) const HWCAP_NEON: c_ulong = 1 << 12;
+    pub(super) const HWCAP2_AES: c_ulong = 1 << 0;
+    pub(super) const HWCAP2_PMULL: c_ulong = 1 << 1;
+
+    /// Returns `AT_HWCAP` and `AT_HWCAP2`, or zeroes if they are not
+    /// available.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(super) fn hwcaps() -> (c_ulong, c_ulong) {
+        const AT_HWCAP: c_ulong = 16;
+        const AT_HWCAP2: c_ulong = 26;
+
+        extern "C" {
+            fn getauxval(kind: c_ulong) -> c_ulong;
+        }
+
+        // SAFETY: `getauxval` has no preconditions, and returns zero
+        // for unknown types.
+        unsafe { (getauxval(AT_HWCAP), getauxval(AT_HWCAP2)) }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub(super) fn hwcaps() -> (c_ulong, c_ulong) {
+        (0, 0)
+    }
+}
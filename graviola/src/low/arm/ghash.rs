@@ -0,0 +1,164 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! GHASH using the ARMv8 `vmull.p64` instruction, in AArch32 state.
+//!
+//! This is the one-block-at-a-time path of the aarch64 implementation
+//! (see low/aarch64/ghash.rs), written as one block of inline assembly
+//! as the intrinsics for 32-bit arm are not stable.  Values are held
+//! as in that implementation: a block is a `u128` read big-endian, and
+//! `d(2n)` and `d(2n+1)` are the low and high halves of `q(n)`.
+
+use crate::low;
+
+pub(crate) struct GhashTable {
+    /// H, arranged for use in big endian ordering.
+    h: [u8; 16],
+    /// The two halves of `h`, xored together.
+    h_xor: [u8; 8],
+}
+
+impl GhashTable {
+    pub(crate) fn new(h: u128) -> Self {
+        // takes a raw hash subkey, and arranges that it can
+        // be used in big endian ordering.
+        let carry = 0u128.wrapping_sub(h >> 127);
+        let h = (h << 1) ^ (carry & GF128_POLY_CARRY_MASK);
+        let h_xor = (h as u64) ^ ((h >> 64) as u64);
+
+        Self {
+            h: h.to_le_bytes(),
+            h_xor: h_xor.to_le_bytes(),
+        }
+    }
+
+    /// Makes a table for inputs shorter than 128 bytes.
+    ///
+    /// This implementation only ever uses H, so this is `new`.
+    pub(crate) fn new_single_block(h: u128) -> Self {
+        Self::new(h)
+    }
+}
+
+impl Drop for GhashTable {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.h);
+        low::zeroise(&mut self.h_xor);
+    }
+}
+
+pub(crate) struct Ghash<'a> {
+    table: &'a GhashTable,
+    /// The accumulator, as `u128::to_le_bytes`.
+    current: [u8; 16],
+}
+
+impl<'a> Ghash<'a> {
+    pub(crate) fn new(table: &'a GhashTable) -> Self {
+        Self {
+            table,
+            current: [0u8; 16],
+        }
+    }
+
+    /// Input `bytes` to the computation.
+    ///
+    /// `bytes` is zero-padded, if required.
+    pub(crate) fn add(&mut self, bytes: &[u8]) {
+        let (whole, rest) = bytes.split_at(bytes.len() & !15);
+
+        if !whole.is_empty() {
+            // SAFETY: the caller checked the `neon` and `pmull` cpu features
+            // are supported.
+            unsafe { ghash(&mut self.current, self.table, whole) };
+        }
+
+        if !rest.is_empty() {
+            let mut block = [0u8; 16];
+            block[..rest.len()].copy_from_slice(rest);
+            // SAFETY: as above.
+            unsafe { ghash(&mut self.current, self.table, &block) };
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> [u8; 16] {
+        u128::from_le_bytes(self.current).to_be_bytes()
+    }
+}
+
+/// Absorb `blocks` (a non-zero multiple of 16 bytes) into `current`.
+unsafe fn ghash(current: &mut [u8; 16], table: &GhashTable, blocks: &[u8]) {
+    debug_assert!(!blocks.is_empty() && blocks.len() % 16 == 0);
+
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            ".arch armv8-a",
+            ".fpu crypto-neon-fp-armv8",
+            // q0 = current, q1 = h, d4 = h_xor, d6 = GF128_POLY_HI
+            "vld1.8 {{q0}}, [{current}]",
+            "vld1.8 {{q1}}, [{h}]",
+            "vld1.8 {{d4}}, [{h_xor}]",
+            "vmov.i8 d6, #0xc2",
+            "vshl.u64 d6, d6, #56",
+            "2:",
+            // q4 = the block, byte-reversed to make a `u128`
+            "vld1.8 {{q4}}, [{blocks}]!",
+            "vrev64.8 q4, q4",
+            "vswp d8, d9",
+            "veor q0, q0, q4",
+            // Karatsuba multiply: q5 = lo, q6 = hi, q7 = mi
+            "vmull.p64 q5, d0, d2",
+            "vmull.p64 q6, d1, d3",
+            "veor d8, d0, d1",
+            "vmull.p64 q7, d8, d4",
+            // reduce
+            "veor q7, q7, q5",
+            "veor q7, q7, q6",
+            "veor d14, d14, d11",
+            "veor d15, d15, d10",
+            "vmull.p64 q5, d6, d10",
+            "veor q7, q7, q5",
+            "veor d12, d12, d15",
+            "veor d13, d13, d14",
+            "vmull.p64 q5, d6, d14",
+            "veor q0, q6, q5",
+            "subs {len}, {len}, #16",
+            "bne 2b",
+            "vst1.8 {{q0}}, [{current}]",
+            // don't leave H or the data in vector registers
+            "vmov.i8 q0, #0",
+            "vmov.i8 q1, #0",
+            "vmov.i8 q2, #0",
+            "vmov.i8 q4, #0",
+            "vmov.i8 q5, #0",
+            "vmov.i8 q6, #0",
+            "vmov.i8 q7, #0",
+            current = in(reg) current.as_mut_ptr(),
+            h = in(reg) table.h.as_ptr(),
+            h_xor = in(reg) table.h_xor.as_ptr(),
+            blocks = inout(reg) blocks.as_ptr() => _,
+            len = inout(reg) blocks.len() => _,
+            // clobbers
+            out("d0") _,
+            out("d1") _,
+            out("d2") _,
+            out("d3") _,
+            out("d4") _,
+            out("d5") _,
+            out("d6") _,
+            out("d7") _,
+            out("d8") _,
+            out("d9") _,
+            out("d10") _,
+            out("d11") _,
+            out("d12") _,
+            out("d13") _,
+            out("d14") _,
+            out("d15") _,
+            options(nostack),
+        )
+    }
+}
+
+const GF128_POLY_CARRY_MASK: u128 = 0xc2000000_00000000_00000000_00000001;
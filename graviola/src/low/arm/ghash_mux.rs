@@ -0,0 +1,111 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::mem::ManuallyDrop;
+
+use crate::low::arm;
+use crate::low::{dispatch, generic};
+
+/// Multiplex between the polynomial multiply instruction and the portable GHASH.
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum GhashTable {
+    Pmull(ManuallyDrop<arm::ghash::GhashTable>),
+    Generic(ManuallyDrop<generic::ghash::GhashTable>),
+}
+
+impl GhashTable {
+    pub(crate) fn new(h: u128) -> Self {
+        if use_ghash_instruction() {
+            Self::Pmull(ManuallyDrop::new(arm::ghash::GhashTable::new(h)))
+        } else {
+            Self::Generic(ManuallyDrop::new(generic::ghash::GhashTable::new(h)))
+        }
+    }
+
+    /// Makes a table that is only valid for inputs shorter than 128 bytes.
+    pub(crate) fn new_single_block(h: u128) -> Self {
+        if use_ghash_instruction() {
+            Self::Pmull(ManuallyDrop::new(arm::ghash::GhashTable::new_single_block(h)))
+        } else {
+            Self::Generic(ManuallyDrop::new(generic::ghash::GhashTable::new_single_block(h)))
+        }
+    }
+}
+
+impl Drop for GhashTable {
+    fn drop(&mut self) {
+        match self {
+            // SAFETY: `self` is not used again
+            Self::Pmull(k) => unsafe { ManuallyDrop::drop(k) },
+            // SAFETY: `self` is not used again
+            Self::Generic(k) => unsafe { ManuallyDrop::drop(k) },
+        }
+
+        // the variants zeroise themselves, but not the enum tag, nor the
+        // padding past the smaller variant.
+        // SAFETY: `self` is not used again
+        unsafe { generic::zeroise::zeroise_dropped(self) };
+    }
+}
+
+pub(crate) enum Ghash<'a> {
+    Pmull(arm::ghash::Ghash<'a>),
+    Generic(generic::ghash::Ghash<'a>),
+}
+
+impl<'a> Ghash<'a> {
+    pub(crate) fn new(table: &'a GhashTable) -> Self {
+        match table {
+            GhashTable::Pmull(t) => Self::Pmull(arm::ghash::Ghash::new(t)),
+            GhashTable::Generic(t) => Self::Generic(generic::ghash::Ghash::new(t)),
+        }
+    }
+
+    /// Input `bytes` to the computation.
+    ///
+    /// `bytes` is zero-padded, if required.
+    pub(crate) fn add(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Pmull(g) => g.add(bytes),
+            Self::Generic(g) => g.add(bytes),
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> [u8; 16] {
+        match self {
+            Self::Pmull(g) => g.into_bytes(),
+            Self::Generic(g) => g.into_bytes(),
+        }
+    }
+}
+
+pub(in crate::low) fn use_ghash_instruction() -> bool {
+    dispatch::use_native() && arm::cpu::features().pmull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ghash_instruction_matches_generic() {
+        if !use_ghash_instruction() {
+            return;
+        }
+
+        let h = 0x66e94bd4ef8a2c3b884cfa59ca342b2e;
+        let a = GhashTable::Pmull(ManuallyDrop::new(arm::ghash::GhashTable::new(h)));
+        let b = GhashTable::Generic(ManuallyDrop::new(generic::ghash::GhashTable::new(h)));
+        let data = (0..100).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+
+        for len in [0, 1, 16, 17, 64, 100] {
+            let mut ga = Ghash::new(&a);
+            let mut gb = Ghash::new(&b);
+            ga.add(&data[..len]);
+            gb.add(&data[..len]);
+            ga.add(b"tail");
+            gb.add(b"tail");
+            assert_eq!(ga.into_bytes(), gb.into_bytes(), "len={len}");
+        }
+    }
+}
@@ -0,0 +1,236 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+/// A primitive which has more than one implementation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Primitive {
+    /// The AES block cipher.
+    Aes,
+    /// The GHASH universal hash function, used by AES-GCM.
+    Ghash,
+    /// Bulk AES-GCM encryption and decryption.
+    AesGcm,
+    /// The SHA-256 compression function.
+    Sha256,
+    /// The SHA-512 compression function.
+    Sha512,
+    /// The ChaCha20 stream cipher.
+    ChaCha20,
+    /// The Poly1305 one-time authenticator.
+    Poly1305,
+    /// Four Keccak-f\[1600\] permutations at once, used for hashing
+    /// many short messages.
+    KeccakX4,
+}
+
+/// Which implementations to use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Use the fastest implementation the CPU supports.
+    ///
+    /// This is the default.
+    Auto,
+
+    /// Use the portable implementations, even if the CPU supports
+    /// faster ones.
+    ///
+    /// This only affects the primitives which have a portable
+    /// implementation on this architecture: see [`implementation()`].
+    Generic,
+}
+
+/// Choose which implementations to use.
+///
+/// This affects objects (such as keys) created after this call: existing
+/// ones keep the implementation they were created with.  It is intended
+/// for reproducing bugs, comparing performance, and testing the portable
+/// implementations on capable CPUs.
+///
+/// In debug builds, and this crate's own tests, the initial setting can be
+/// given by the environment variable `GRAVIOLA_BACKEND`, set to `auto` or
+/// `generic`.  In other builds that variable is not honoured, and setting
+/// it causes a panic rather than being silently ignored.
+pub fn set_backend(backend: Backend) {
+    force_generic().store(backend == Backend::Generic, Ordering::Relaxed);
+}
+
+/// Returns the current setting made by [`set_backend()`].
+pub fn backend() -> Backend {
+    match use_native() {
+        true => Backend::Auto,
+        false => Backend::Generic,
+    }
+}
+
+/// Whether to consider implementations which use optional CPU features.
+pub(in crate::low) fn use_native() -> bool {
+    !force_generic().load(Ordering::Relaxed)
+}
+
+fn force_generic() -> &'static AtomicBool {
+    static FORCE_GENERIC: OnceLock<AtomicBool> = OnceLock::new();
+    FORCE_GENERIC.get_or_init(|| AtomicBool::new(initial_backend() == Backend::Generic))
+}
+
+#[cfg(not(any(debug_assertions, test)))]
+fn initial_backend() -> Backend {
+    if std::env::var_os("GRAVIOLA_BACKEND").is_some() {
+        panic!("GRAVIOLA_BACKEND is only honoured in debug builds: use set_backend() instead");
+    }
+    Backend::Auto
+}
+
+#[cfg(any(debug_assertions, test))]
+fn initial_backend() -> Backend {
+    match std::env::var("GRAVIOLA_BACKEND").as_deref() {
+        Ok("generic") => Backend::Generic,
+        Ok("auto") | Err(_) => Backend::Auto,
+        Ok(other) => panic!("unknown GRAVIOLA_BACKEND {other:?}"),
+    }
+}
+
+/// Returns the name of the implementation used for `primitive`.
+///
+/// This is decided according to the features of the CPU, which are
+/// detected once, and [`set_backend()`].  Portable implementations are
+/// named `"generic"`.
+///
+/// The names are intended for display, such as in debug logs: they
+/// are not stable between versions of this crate.
+pub fn implementation(primitive: Primitive) -> &'static str {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "force-generic")] {
+            let _ = primitive;
+            GENERIC
+        } else if #[cfg(target_arch = "x86_64")] {
+            use super::x86_64;
+
+            match primitive {
+                Primitive::Aes if x86_64::aes_mux::use_aesni() => "aes-ni",
+                Primitive::Ghash if x86_64::aes_mux::use_aesni() => "pclmulqdq",
+                Primitive::AesGcm if x86_64::aes_mux::use_aesni() => {
+                    match x86_64::aes_gcm_vaes::available() {
+                        true => "vaes-avx512",
+                        false => "aes-ni",
+                    }
+                }
+                Primitive::Sha256 if x86_64::sha256_mux::use_shaext() => "sha-ni",
+                Primitive::Sha512 if x86_64::sha512_mux::use_avx2() => "avx2",
+                Primitive::ChaCha20 => "avx2",
+                Primitive::Poly1305 if x86_64::poly1305::use_avx2() => "avx2",
+                Primitive::KeccakX4 => "avx2",
+                _ => GENERIC,
+            }
+        } else if #[cfg(target_arch = "aarch64")] {
+            use super::aarch64;
+
+            match primitive {
+                Primitive::Aes if aarch64::aes_mux::use_aes_instructions() => "armv8-aes",
+                Primitive::Ghash if aarch64::aes_mux::use_aes_instructions() => "pmull",
+                Primitive::AesGcm if aarch64::aes_mux::use_aes_instructions() => "armv8-aes",
+                Primitive::Sha256 if aarch64::sha256_mux::use_sha2_instructions() => "armv8-sha2",
+                Primitive::Sha512 if aarch64::sha512_mux::use_sha512_instructions() => "armv8-sha512",
+                Primitive::Poly1305 if aarch64::poly1305::use_neon() => "neon",
+                Primitive::KeccakX4 if aarch64::keccak_x4_mux::use_sve2() => "sve2",
+                Primitive::KeccakX4 if aarch64::keccak_x4_mux::use_sha3_instructions() => {
+                    "armv8-sha3"
+                }
+                _ => GENERIC,
+            }
+        } else if #[cfg(target_arch = "arm")] {
+            use super::arm;
+
+            match primitive {
+                Primitive::Aes if arm::aes_mux::use_aes_instructions() => "armv8-aes",
+                Primitive::Ghash if arm::ghash_mux::use_ghash_instruction() => "pmull",
+                _ => GENERIC,
+            }
+        } else if #[cfg(target_arch = "x86")] {
+            use super::x86;
+
+            match primitive {
+                Primitive::Aes if x86::aes_mux::use_aesni() => "aes-ni",
+                Primitive::Ghash if x86::ghash_mux::use_pclmulqdq() => "pclmulqdq",
+                _ => GENERIC,
+            }
+        } else if #[cfg(target_arch = "riscv64")] {
+            use super::riscv64;
+
+            match primitive {
+                Primitive::Aes if riscv64::aes_mux::use_aes_instructions() => "zkn",
+                Primitive::Ghash if riscv64::ghash_mux::use_ghash_instruction() => "zvkg",
+                Primitive::Sha256 if riscv64::sha256_mux::use_hash_instructions() => "zknh",
+                Primitive::Sha512 if riscv64::sha512_mux::use_hash_instructions() => "zknh",
+                _ => GENERIC,
+            }
+        } else if #[cfg(all(target_arch = "loongarch64", target_feature = "lsx"))] {
+            match primitive {
+                Primitive::ChaCha20 => "lsx",
+                _ => GENERIC,
+            }
+        } else {
+            let _ = primitive;
+            GENERIC
+        }
+    }
+}
+
+const GENERIC: &str = "generic";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consistent() {
+        for p in [
+            Primitive::Aes,
+            Primitive::Ghash,
+            Primitive::AesGcm,
+            Primitive::Sha256,
+            Primitive::Sha512,
+            Primitive::ChaCha20,
+            Primitive::Poly1305,
+            Primitive::KeccakX4,
+        ] {
+            let name = implementation(p);
+            assert!(!name.is_empty());
+            assert_eq!(name, implementation(p));
+        }
+
+        // these are chosen together (but independently on x86, arm and riscv64)
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        {
+            assert_eq!(
+                implementation(Primitive::Aes) == GENERIC,
+                implementation(Primitive::Ghash) == GENERIC
+            );
+            assert_eq!(
+                implementation(Primitive::Aes) == GENERIC,
+                implementation(Primitive::AesGcm) == GENERIC
+            );
+        }
+    }
+
+    #[test]
+    fn set_backend_generic() {
+        // nb. other tests run concurrently, and must work with either backend
+        let before = backend();
+
+        set_backend(Backend::Generic);
+        assert_eq!(backend(), Backend::Generic);
+        assert_eq!(implementation(Primitive::Aes), GENERIC);
+        assert_eq!(implementation(Primitive::Ghash), GENERIC);
+        assert_eq!(implementation(Primitive::AesGcm), GENERIC);
+        assert_eq!(implementation(Primitive::Sha256), GENERIC);
+        assert_eq!(implementation(Primitive::Sha512), GENERIC);
+        assert_eq!(implementation(Primitive::Poly1305), GENERIC);
+
+        set_backend(before);
+        assert_eq!(backend(), before);
+    }
+}
@@ -0,0 +1,51 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+/// Returns a value which changes in the child after every `fork()`.
+///
+/// On unix, the first call registers a `pthread_atfork` child handler
+/// which increments a counter, so later calls are just an atomic load.
+/// Forks before that first call are not counted.  If the handler cannot
+/// be registered, this falls back to the process ID (at the cost of a
+/// system call per call).
+///
+/// `fork()` is only a concern on unix; elsewhere this never changes.
+#[cfg(unix)]
+pub(crate) fn fork_generation() -> usize {
+    use core::ffi::c_int;
+    use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Once;
+
+    static GENERATION: AtomicUsize = AtomicUsize::new(0);
+    static REGISTERED: AtomicBool = AtomicBool::new(false);
+    static REGISTER: Once = Once::new();
+
+    extern "C" fn child() {
+        GENERATION.fetch_add(1, Ordering::Relaxed);
+    }
+
+    extern "C" {
+        fn pthread_atfork(
+            prepare: Option<extern "C" fn()>,
+            parent: Option<extern "C" fn()>,
+            child: Option<extern "C" fn()>,
+        ) -> c_int;
+    }
+
+    REGISTER.call_once(|| {
+        // SAFETY: `child` only does an atomic increment, which is
+        // async-signal-safe, and it lives for the life of the program.
+        let rc = unsafe { pthread_atfork(None, None, Some(child)) };
+        REGISTERED.store(rc == 0, Ordering::Relaxed);
+    });
+
+    match REGISTERED.load(Ordering::Relaxed) {
+        true => GENERATION.load(Ordering::Relaxed),
+        false => std::process::id() as usize,
+    }
+}
+
+#[cfg(not(unix))]
+pub(crate) fn fork_generation() -> usize {
+    0
+}
@@ -0,0 +1,561 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+// Portable, constant-time AES.
+//
+// This is bitsliced, processing four blocks at once in eight 64-bit
+// words.  The approach and the S-box circuit (from Boyar and Peralta)
+// follow the "ct64" implementation in BearSSL.
+//
+// refs:
+// - https://bearssl.org/constanttime.html#aes
+// - https://eprint.iacr.org/2009/191.pdf
+
+use crate::low;
+
+pub(crate) struct AesKey {
+    /// Bitsliced round keys, eight words per round.
+    round_keys: [u64; (14 + 1) * 8],
+    rounds: usize,
+}
+
+impl AesKey {
+    /// Creates an AesKey.
+    ///
+    /// `key` must be 16 or 32 bytes in length (AES-192 not supported).
+    pub(crate) fn new(key: &[u8]) -> Self {
+        let rounds = match key.len() {
+            16 => 10,
+            32 => 14,
+            24 => panic!("aes-192 not supported"),
+            _ => panic!("invalid aes key size"),
+        };
+
+        let nk = key.len() / 4;
+        let mut rk32 = [0u32; (14 + 1) * 4];
+        for (w, k) in rk32.iter_mut().zip(key.chunks_exact(4)) {
+            *w = u32::from_le_bytes(k.try_into().unwrap());
+        }
+
+        for i in nk..(rounds + 1) * 4 {
+            let mut tmp = rk32[i - 1];
+            if i % nk == 0 {
+                tmp = sub_word(tmp.rotate_right(8)) ^ RCON[i / nk - 1];
+            } else if nk > 6 && i % nk == 4 {
+                tmp = sub_word(tmp);
+            }
+            rk32[i] = rk32[i - nk] ^ tmp;
+        }
+
+        let mut round_keys = [0u64; (14 + 1) * 8];
+        for (rk, w) in round_keys
+            .chunks_exact_mut(8)
+            .zip(rk32.chunks_exact(4))
+            .take(rounds + 1)
+        {
+            // the same key for all four blocks
+            let mut q = [0u64; 8];
+            (q[0], q[4]) = interleave_in(w.try_into().unwrap());
+            for i in 1..4 {
+                q[i] = q[0];
+                q[i + 4] = q[4];
+            }
+            ortho(&mut q);
+            rk.copy_from_slice(&q);
+        }
+
+        low::zeroise(&mut rk32);
+        Self { round_keys, rounds }
+    }
+
+    /// Creates an AesKey for use with `decrypt_block`.
+    ///
+    /// This implementation uses the encryption round keys directly
+    /// for decryption, so this is `new`.
+    pub(crate) fn new_with_decryption(key: &[u8]) -> Self {
+        Self::new(key)
+    }
+
+    pub(crate) fn encrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+        let mut blocks = [0u8; 64];
+        blocks[..16].copy_from_slice(inout);
+        self.encrypt_4_blocks(&mut blocks);
+        inout.copy_from_slice(&blocks[..16]);
+        low::zeroise(&mut blocks);
+    }
+
+    pub(crate) fn decrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+        let mut blocks = [0u8; 64];
+        blocks[..16].copy_from_slice(inout);
+
+        let mut q = load(&blocks);
+        let (first, rest) = self.round_keys[..(self.rounds + 1) * 8].split_at(8);
+        let (middle, last) = rest.split_at(rest.len() - 8);
+
+        add_round_key(&mut q, last);
+        for rk in middle.chunks_exact(8).rev() {
+            inv_shift_rows(&mut q);
+            inv_sub_bytes(&mut q);
+            add_round_key(&mut q, rk);
+            inv_mix_columns(&mut q);
+        }
+        inv_shift_rows(&mut q);
+        inv_sub_bytes(&mut q);
+        add_round_key(&mut q, first);
+
+        store(&mut blocks, q);
+        inout.copy_from_slice(&blocks[..16]);
+        low::zeroise(&mut blocks);
+    }
+
+    pub(crate) fn ctr(&self, initial_counter: &[u8; 16], cipher_inout: &mut [u8]) {
+        let mut counter = u32::from_be_bytes(initial_counter[12..].try_into().unwrap());
+
+        for chunk in cipher_inout.chunks_mut(64) {
+            let mut blocks = [0u8; 64];
+            for block in blocks.chunks_exact_mut(16) {
+                counter = counter.wrapping_add(1);
+                block[..12].copy_from_slice(&initial_counter[..12]);
+                block[12..].copy_from_slice(&counter.to_be_bytes());
+            }
+
+            self.encrypt_4_blocks(&mut blocks);
+
+            for (c, k) in chunk.iter_mut().zip(blocks.iter()) {
+                *c ^= *k;
+            }
+            low::zeroise(&mut blocks);
+        }
+    }
+
+    fn encrypt_4_blocks(&self, blocks: &mut [u8; 64]) {
+        let mut q = load(blocks);
+        let (first, rest) = self.round_keys[..(self.rounds + 1) * 8].split_at(8);
+        let (middle, last) = rest.split_at(rest.len() - 8);
+
+        add_round_key(&mut q, first);
+        for rk in middle.chunks_exact(8) {
+            sub_bytes(&mut q);
+            shift_rows(&mut q);
+            mix_columns(&mut q);
+            add_round_key(&mut q, rk);
+        }
+        sub_bytes(&mut q);
+        shift_rows(&mut q);
+        add_round_key(&mut q, last);
+
+        store(blocks, q);
+    }
+}
+
+impl Drop for AesKey {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.round_keys);
+        low::zeroise_value(&mut self.rounds);
+    }
+}
+
+/// Bitslice four blocks.
+fn load(blocks: &[u8; 64]) -> [u64; 8] {
+    let mut q = [0u64; 8];
+    for (i, block) in blocks.chunks_exact(16).enumerate() {
+        let mut w = [0u32; 4];
+        for (w, b) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *w = u32::from_le_bytes(b.try_into().unwrap());
+        }
+        (q[i], q[i + 4]) = interleave_in(&w);
+    }
+    ortho(&mut q);
+    q
+}
+
+/// Inverse of `load`.
+fn store(blocks: &mut [u8; 64], mut q: [u64; 8]) {
+    ortho(&mut q);
+    for (i, block) in blocks.chunks_exact_mut(16).enumerate() {
+        let w = interleave_out(q[i], q[i + 4]);
+        for (w, b) in w.iter().zip(block.chunks_exact_mut(4)) {
+            b.copy_from_slice(&w.to_le_bytes());
+        }
+    }
+}
+
+fn sub_word(x: u32) -> u32 {
+    let mut q = [0u64; 8];
+    q[0] = x as u64;
+    ortho(&mut q);
+    sub_bytes(&mut q);
+    ortho(&mut q);
+    q[0] as u32
+}
+
+/// Transposes the eight words, such that bit `i` of each byte of the
+/// input ends up in word `i`.  This is its own inverse.
+fn ortho(q: &mut [u64; 8]) {
+    fn swap(q: &mut [u64; 8], x: usize, y: usize, lo: u64, shift: u32) {
+        let hi = lo << shift;
+        let a = q[x];
+        let b = q[y];
+        q[x] = (a & lo) | ((b & lo) << shift);
+        q[y] = ((a & hi) >> shift) | (b & hi);
+    }
+
+    for i in (0..8).step_by(2) {
+        swap(q, i, i + 1, 0x5555_5555_5555_5555, 1);
+    }
+    for i in [0, 1, 4, 5] {
+        swap(q, i, i + 2, 0x3333_3333_3333_3333, 2);
+    }
+    for i in 0..4 {
+        swap(q, i, i + 4, 0x0f0f_0f0f_0f0f_0f0f, 4);
+    }
+}
+
+fn interleave_in(w: &[u32; 4]) -> (u64, u64) {
+    let spread = |x: u32| {
+        let mut x = x as u64;
+        x |= x << 16;
+        x &= 0x0000_ffff_0000_ffff;
+        x |= x << 8;
+        x & 0x00ff_00ff_00ff_00ff
+    };
+    (
+        spread(w[0]) | (spread(w[2]) << 8),
+        spread(w[1]) | (spread(w[3]) << 8),
+    )
+}
+
+fn interleave_out(q0: u64, q1: u64) -> [u32; 4] {
+    let gather = |x: u64| {
+        let mut x = x & 0x00ff_00ff_00ff_00ff;
+        x |= x >> 8;
+        x &= 0x0000_ffff_0000_ffff;
+        (x as u32) | ((x >> 16) as u32)
+    };
+    [gather(q0), gather(q1), gather(q0 >> 8), gather(q1 >> 8)]
+}
+
+fn add_round_key(q: &mut [u64; 8], rk: &[u64]) {
+    for (q, rk) in q.iter_mut().zip(rk) {
+        *q ^= *rk;
+    }
+}
+
+fn shift_rows(q: &mut [u64; 8]) {
+    for x in q.iter_mut() {
+        *x = (*x & 0x0000_0000_0000_ffff)
+            | ((*x & 0x0000_0000_fff0_0000) >> 4)
+            | ((*x & 0x0000_0000_000f_0000) << 12)
+            | ((*x & 0x0000_ff00_0000_0000) >> 8)
+            | ((*x & 0x0000_00ff_0000_0000) << 8)
+            | ((*x & 0xf000_0000_0000_0000) >> 12)
+            | ((*x & 0x0fff_0000_0000_0000) << 4);
+    }
+}
+
+fn inv_shift_rows(q: &mut [u64; 8]) {
+    for x in q.iter_mut() {
+        *x = (*x & 0x0000_0000_0000_ffff)
+            | ((*x & 0x0000_0000_0fff_0000) << 4)
+            | ((*x & 0x0000_0000_f000_0000) >> 12)
+            | ((*x & 0x0000_00ff_0000_0000) << 8)
+            | ((*x & 0x0000_ff00_0000_0000) >> 8)
+            | ((*x & 0x000f_0000_0000_0000) << 12)
+            | ((*x & 0xfff0_0000_0000_0000) >> 4);
+    }
+}
+
+fn mix_columns(q: &mut [u64; 8]) {
+    let [q0, q1, q2, q3, q4, q5, q6, q7] = *q;
+    let [r0, r1, r2, r3, r4, r5, r6, r7] = q.map(|x| x.rotate_right(16));
+
+    q[0] = q7 ^ r7 ^ r0 ^ (q0 ^ r0).rotate_right(32);
+    q[1] = q0 ^ r0 ^ q7 ^ r7 ^ r1 ^ (q1 ^ r1).rotate_right(32);
+    q[2] = q1 ^ r1 ^ r2 ^ (q2 ^ r2).rotate_right(32);
+    q[3] = q2 ^ r2 ^ q7 ^ r7 ^ r3 ^ (q3 ^ r3).rotate_right(32);
+    q[4] = q3 ^ r3 ^ q7 ^ r7 ^ r4 ^ (q4 ^ r4).rotate_right(32);
+    q[5] = q4 ^ r4 ^ r5 ^ (q5 ^ r5).rotate_right(32);
+    q[6] = q5 ^ r5 ^ r6 ^ (q6 ^ r6).rotate_right(32);
+    q[7] = q6 ^ r6 ^ r7 ^ (q7 ^ r7).rotate_right(32);
+}
+
+fn inv_mix_columns(q: &mut [u64; 8]) {
+    // InvMixColumns is MixColumns applied three times
+    for _ in 0..3 {
+        mix_columns(q);
+    }
+}
+
+fn inv_sub_bytes(q: &mut [u64; 8]) {
+    // InvSubBytes is the inverse affine transform, SubBytes, and
+    // the inverse affine transform again.
+    inv_affine(q);
+    sub_bytes(q);
+    inv_affine(q);
+}
+
+fn inv_affine(q: &mut [u64; 8]) {
+    let [q0, q1, q2, q3, q4, q5, q6, q7] = *q;
+    let (q0, q1, q5, q6) = (!q0, !q1, !q5, !q6);
+    q[7] = q1 ^ q4 ^ q6;
+    q[6] = q0 ^ q3 ^ q5;
+    q[5] = q7 ^ q2 ^ q4;
+    q[4] = q6 ^ q1 ^ q3;
+    q[3] = q5 ^ q0 ^ q2;
+    q[2] = q4 ^ q7 ^ q1;
+    q[1] = q3 ^ q6 ^ q0;
+    q[0] = q2 ^ q5 ^ q7;
+}
+
+/// The S-box circuit from Boyar and Peralta.
+fn sub_bytes(q: &mut [u64; 8]) {
+    let [x7, x6, x5, x4, x3, x2, x1, x0] = *q;
+
+    // top linear transformation
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // non-linear section
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // bottom linear transformation
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    *q = [s7, s6, s5, s4, s3, s2, s1, s0];
+}
+
+const RCON: [u32; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sbox() {
+        // compute the sbox by the book: inversion in GF(2^8) then
+        // the affine transform.
+        fn gmul(mut a: u8, mut b: u8) -> u8 {
+            let mut r = 0;
+            while b != 0 {
+                if b & 1 == 1 {
+                    r ^= a;
+                }
+                a = (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 };
+                b >>= 1;
+            }
+            r
+        }
+
+        for x in 0..=255u8 {
+            let inv = (1..=255u8).find(|y| gmul(x, *y) == 1).unwrap_or(0);
+            let expect = inv
+                ^ inv.rotate_left(1)
+                ^ inv.rotate_left(2)
+                ^ inv.rotate_left(3)
+                ^ inv.rotate_left(4)
+                ^ 0x63;
+            let word = u32::from_le_bytes([x, x ^ 1, x ^ 2, x ^ 3]);
+            let expect = u32::from_le_bytes([expect, 0, 0, 0]);
+            assert_eq!(sub_word(word) & 0xff, expect);
+        }
+    }
+
+    #[test]
+    fn inverses() {
+        let mut q: [u64; 8] = core::array::from_fn(|i| (i as u64 + 1) * 0x0123_4567_89ab_cdef);
+        let orig = q;
+
+        shift_rows(&mut q);
+        mix_columns(&mut q);
+        sub_bytes(&mut q);
+        assert_ne!(q, orig);
+        inv_sub_bytes(&mut q);
+        inv_mix_columns(&mut q);
+        inv_shift_rows(&mut q);
+        assert_eq!(q, orig);
+    }
+
+    // these test vectors from FIPS-197 appendix C.
+
+    #[test]
+    fn test_block_128() {
+        let key = AesKey::new(&[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+            0x0e, 0x0f,
+        ]);
+        let plain = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+        let mut block = plain;
+        key.encrypt_block(&mut block);
+        assert_eq!(
+            block,
+            [
+                0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4,
+                0xc5, 0x5a
+            ]
+        );
+
+        key.decrypt_block(&mut block);
+        assert_eq!(block, plain);
+    }
+
+    #[test]
+    fn test_block_256() {
+        let key = AesKey::new(&[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+            0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b,
+            0x1c, 0x1d, 0x1e, 0x1f,
+        ]);
+        let plain = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+        let mut block = plain;
+        key.encrypt_block(&mut block);
+        assert_eq!(
+            block,
+            [
+                0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49,
+                0x60, 0x89
+            ]
+        );
+
+        key.decrypt_block(&mut block);
+        assert_eq!(block, plain);
+    }
+
+    #[test]
+    fn ctr() {
+        let key = AesKey::new(&[0x42; 16]);
+        let counter = *b"\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\xff\xff\xff\xfe";
+
+        let mut data = [0u8; 16 * 9 + 5];
+        key.ctr(&counter, &mut data);
+
+        let mut block_counter = counter;
+        for block in data.chunks(16) {
+            let c = u32::from_be_bytes(block_counter[12..].try_into().unwrap());
+            block_counter[12..].copy_from_slice(&c.wrapping_add(1).to_be_bytes());
+            let mut expect = block_counter;
+            key.encrypt_block(&mut expect);
+            assert_eq!(block, &expect[..block.len()]);
+        }
+    }
+}
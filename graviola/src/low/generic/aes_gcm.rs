@@ -0,0 +1,76 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::aes::AesKey;
+use super::ghash::Ghash;
+
+pub(crate) fn encrypt(
+    key: &AesKey,
+    ghash: &mut Ghash<'_>,
+    initial_counter: &[u8; 16],
+    aad: &[u8],
+    cipher_inout: &mut [u8],
+) {
+    ghash.add(aad);
+    key.ctr(initial_counter, cipher_inout);
+    ghash.add(cipher_inout);
+}
+
+pub(crate) fn decrypt(
+    key: &AesKey,
+    ghash: &mut Ghash<'_>,
+    initial_counter: &[u8; 16],
+    aad: &[u8],
+    cipher_inout: &mut [u8],
+) {
+    ghash.add(aad);
+    ghash.add(cipher_inout);
+    key.ctr(initial_counter, cipher_inout);
+}
+
+#[cfg(all(test, target_arch = "x86_64", not(feature = "force-generic")))]
+mod tests {
+    use super::super::ghash::GhashTable;
+    use super::*;
+    use crate::low::x86_64;
+
+    #[test]
+    fn against_assembly() {
+        for key in [&[0x2bu8; 16][..], &[0x61u8; 32][..]] {
+            let mut h = [0u8; 16];
+            let generic_key = AesKey::new(key);
+            generic_key.encrypt_block(&mut h);
+            let generic_table = GhashTable::new(u128::from_be_bytes(h));
+
+            let asm_key = x86_64::aes::AesKey::new(key);
+            let asm_table = x86_64::ghash::GhashTable::new(u128::from_be_bytes(h));
+
+            // includes a counter which wraps
+            let counter = *b"\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\xff\xff\xff\xfe";
+            let aad = b"additional data";
+
+            for len in [0, 1, 15, 16, 17, 63, 64, 65, 127, 128, 129, 300, 1000] {
+                let plain = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+
+                let mut expect = plain.clone();
+                let mut expect_ghash = x86_64::ghash::Ghash::new(&asm_table);
+                x86_64::aes_gcm::encrypt(&asm_key, &mut expect_ghash, &counter, aad, &mut expect);
+
+                let mut got = plain.clone();
+                let mut got_ghash = Ghash::new(&generic_table);
+                encrypt(&generic_key, &mut got_ghash, &counter, aad, &mut got);
+
+                assert_eq!(expect, got);
+                assert_eq!(expect_ghash.into_bytes(), got_ghash.into_bytes());
+
+                let mut got_ghash = Ghash::new(&generic_table);
+                decrypt(&generic_key, &mut got_ghash, &counter, aad, &mut got);
+                assert_eq!(got, plain);
+
+                let mut expect_ghash = x86_64::ghash::Ghash::new(&asm_table);
+                x86_64::aes_gcm::decrypt(&asm_key, &mut expect_ghash, &counter, aad, &mut expect);
+                assert_eq!(expect_ghash.into_bytes(), got_ghash.into_bytes());
+            }
+        }
+    }
+}
@@ -0,0 +1,743 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Portable bignum arithmetic.
+//!
+//! These have the same contracts as the s2n-bignum functions of the same
+//! names used on other architectures (and are tested against them there),
+//! including running in time which depends only on the sizes of the inputs.
+//! They are written for clarity rather than speed.
+//!
+//! Some s2n-bignum functions take a temporary buffer `t`.  These
+//! implementations do not need one, so ignore it: the parameter is
+//! kept so callers are the same on every architecture.
+
+/// Add, z := x + y
+///
+/// Truncates modulo the length of z; x and y may be shorter (or longer).
+pub(crate) fn bignum_add(z: &mut [u64], x: &[u64], y: &[u64]) {
+    let mut carry = 0;
+    for (i, zi) in z.iter_mut().enumerate() {
+        let xi = x.get(i).copied().unwrap_or_default();
+        let yi = y.get(i).copied().unwrap_or_default();
+        (*zi, carry) = adc(xi, yi, carry);
+    }
+}
+
+/// Return size of bignum in bits
+///
+/// In the case of a zero bignum as input the result is 0
+pub(crate) fn bignum_bitsize(x: &[u64]) -> usize {
+    let (digits, top) = top_digit(x);
+    let bits = (digits as u64 * 64).wrapping_sub(top.leading_zeros() as u64);
+    (bits & nonzero_mask(digits as u64)) as usize
+}
+
+/// Return size of bignum in digits (64-bit word)
+///
+/// In the case of a zero bignum as input the result is 0
+pub(crate) fn bignum_digitsize(x: &[u64]) -> usize {
+    top_digit(x).0
+}
+
+/// Compare bignums, x < y
+///
+/// Returns 1 if so, 0 if not.  x and y may have different lengths.
+pub(crate) fn bignum_cmp_lt(x: &[u64], y: &[u64]) -> u64 {
+    let mut borrow = 0;
+    for i in 0..x.len().max(y.len()) {
+        let xi = x.get(i).copied().unwrap_or_default();
+        let yi = y.get(i).copied().unwrap_or_default();
+        (_, borrow) = sbb(xi, yi, borrow);
+    }
+    borrow
+}
+
+/// Test bignums for equality, x = y
+///
+/// x and y may have different lengths.
+pub(crate) fn bignum_eq(x: &[u64], y: &[u64]) -> bool {
+    let mut acc = 0;
+    for i in 0..x.len().max(y.len()) {
+        let xi = x.get(i).copied().unwrap_or_default();
+        let yi = y.get(i).copied().unwrap_or_default();
+        acc |= xi ^ yi;
+    }
+    acc == 0
+}
+
+/// Given table: uint64_t[height*width], copy table[idx*width...(idx+1)*width-1]
+/// into z[0..width-1].
+///
+/// Every row of the table is read, regardless of idx.
+pub(crate) fn bignum_copy_row_from_table(
+    z: &mut [u64],
+    table: &[u64],
+    height: u64,
+    width: u64,
+    index: u64,
+) {
+    debug_assert!(z.len() as u64 == width);
+    z.fill(0);
+    for (i, row) in table
+        .chunks_exact(width as usize)
+        .take(height as usize)
+        .enumerate()
+    {
+        let mask = !nonzero_mask(i as u64 ^ index);
+        for (zi, ri) in z.iter_mut().zip(row) {
+            *zi |= ri & mask;
+        }
+    }
+}
+
+/// Viewing table as rows of `z.len()` words, copy the row at
+/// `table[index - 1]` into z.  If `index` is zero or larger than the
+/// number of rows, z is set to zero.
+pub(super) fn select_row(z: &mut [u64], table: &[u64], index: u8) {
+    z.fill(0);
+    for (i, row) in table.chunks_exact(z.len()).enumerate() {
+        let mask = !nonzero_mask((i as u64 + 1) ^ index as u64);
+        for (zi, ri) in z.iter_mut().zip(row) {
+            *zi |= ri & mask;
+        }
+    }
+}
+
+/// Multiplex/select z := x (if p nonzero) or z := y (if p zero)
+pub(crate) fn bignum_mux(p: u64, z: &mut [u64], x_if_p: &[u64], y_if_not_p: &[u64]) {
+    debug_assert!(z.len() == x_if_p.len());
+    debug_assert!(z.len() == y_if_not_p.len());
+    let mask = nonzero_mask(p);
+    for ((zi, xi), yi) in z.iter_mut().zip(x_if_p).zip(y_if_not_p) {
+        *zi = (xi & mask) | (yi & !mask);
+    }
+}
+
+/// Optionally subtract, z := x - y (if p nonzero) or z := x (if p zero)
+pub(crate) fn bignum_optsub(z: &mut [u64], x: &[u64], y: &[u64], p: u64) {
+    let mask = nonzero_mask(p);
+    let mut borrow = 0;
+    for ((zi, xi), yi) in z.iter_mut().zip(x).zip(y) {
+        (*zi, borrow) = sbb(*xi, yi & mask, borrow);
+    }
+}
+
+/// Add modulo m, z := (x + y) mod m, assuming x and y reduced
+pub(crate) fn bignum_modadd(z: &mut [u64], x: &[u64], y: &[u64], m: &[u64]) {
+    debug_assert!(z.len() == x.len());
+    debug_assert!(z.len() == y.len());
+    debug_assert!(z.len() == m.len());
+    let mut carry = 0;
+    for ((zi, xi), yi) in z.iter_mut().zip(x).zip(y) {
+        (*zi, carry) = adc(*xi, *yi, carry);
+    }
+    reduce_once(z, carry, m);
+}
+
+/// Subtract modulo m, z := (x - y) mod m, assuming x and y reduced
+pub(crate) fn bignum_modsub(z: &mut [u64], x: &[u64], y: &[u64], m: &[u64]) {
+    debug_assert!(z.len() == x.len());
+    debug_assert!(z.len() == y.len());
+    debug_assert!(z.len() == m.len());
+    let mut borrow = 0;
+    for ((zi, xi), yi) in z.iter_mut().zip(x).zip(y) {
+        (*zi, borrow) = sbb(*xi, *yi, borrow);
+    }
+    masked_add(z, m, borrow.wrapping_neg());
+}
+
+/// Multiply z := x * y
+pub(crate) fn bignum_mul(z: &mut [u64], x: &[u64], y: &[u64]) {
+    debug_assert!(z.len() >= x.len() + y.len());
+    z.fill(0);
+    for (i, xi) in x.iter().enumerate() {
+        let mut carry = 0;
+        for (j, yj) in y.iter().enumerate() {
+            (z[i + j], carry) = mac(z[i + j], *xi, *yj, carry);
+        }
+        z[i + y.len()] = carry;
+    }
+}
+
+/// Multiply z := x * y
+///
+/// Inputs x[16], y[16]; output z[32]; temporary buffer t[>=32]
+pub(crate) fn bignum_kmul_16_32(z: &mut [u64], x: &[u64], y: &[u64], _t: &mut [u64; 32]) {
+    debug_assert!(z.len() == 32);
+    debug_assert!(x.len() == 16);
+    debug_assert!(y.len() == 16);
+    bignum_mul(z, x, y);
+}
+
+/// Multiply z := x * y
+///
+/// Inputs x[32], y[32]; output z[64]; temporary buffer t[>=96]
+pub(crate) fn bignum_kmul_32_64(z: &mut [u64], x: &[u64], y: &[u64], _t: &mut [u64; 96]) {
+    debug_assert!(z.len() == 64);
+    debug_assert!(x.len() == 32);
+    debug_assert!(y.len() == 32);
+    bignum_mul(z, x, y);
+}
+
+/// Square, z := x^2
+///
+/// Input x[16]; output z[32]; temporary buffer t[>=24]
+pub(crate) fn bignum_ksqr_16_32(z: &mut [u64], x: &[u64], _t: &mut [u64; 24]) {
+    debug_assert!(z.len() == 32);
+    debug_assert!(x.len() == 16);
+    bignum_mul(z, x, x);
+}
+
+/// Square, z := x^2
+///
+/// Input x[32]; output z[64]; temporary buffer t[>=72]
+pub(crate) fn bignum_ksqr_32_64(z: &mut [u64], x: &[u64], _t: &mut [u64; 72]) {
+    debug_assert!(z.len() == 64);
+    debug_assert!(x.len() == 32);
+    bignum_mul(z, x, x);
+}
+
+/// Negated modular inverse, z := (-1/x) mod 2^{64k}
+///
+/// Assuming x is odd (otherwise nothing makes sense) the result satisfies
+///
+/// ```text
+///       x * z + 1 == 0 (mod 2^{64 * k})
+/// ```
+///
+/// but is not necessarily reduced mod x.
+pub(crate) fn bignum_negmodinv(z: &mut [u64], x: &[u64]) {
+    debug_assert!(z.len() == x.len());
+    let w = negmodinv_word(x[0]);
+
+    // z holds the running value of x * z + 1, whose low digits
+    // are replaced by those of z as they are zeroed.
+    z.fill(0);
+    z[0] = 1;
+    for i in 0..z.len() {
+        let q = z[i].wrapping_mul(w);
+        let mut carry = 0;
+        for j in i..z.len() {
+            (z[j], carry) = mac(z[j], q, x[j - i], carry);
+        }
+        z[i] = q;
+    }
+}
+
+/// Montgomery multiply, z := (x * y / 2^{64k}) mod m
+///
+/// Assumes x * y <= 2^{64k} * m, which is guaranteed in particular if
+/// x < m, y < m initially (the "intended" case).
+pub(crate) fn bignum_montmul(z: &mut [u64], x: &[u64], y: &[u64], m: &[u64]) {
+    debug_assert!(z.len() == x.len());
+    debug_assert!(z.len() == y.len());
+    debug_assert!(z.len() == m.len());
+    montmul(z, x, y, m, negmodinv_word(m[0]));
+}
+
+/// Montgomery square, z := (x^2 / 2^{64k}) mod m
+///
+/// Assumes x^2 <= 2^{64k} * m, which is guaranteed in particular if
+/// x < m initially (the "intended" case).
+pub(crate) fn bignum_montsqr(z: &mut [u64], x: &[u64], m: &[u64]) {
+    debug_assert!(z.len() == x.len());
+    debug_assert!(z.len() == m.len());
+    montmul(z, x, x, m, negmodinv_word(m[0]));
+}
+
+/// Montgomery reduce, z := (x' / 2^{64p}) MOD m
+///
+/// x' is the lowest (p+k) digits of x, and is assumed to be
+/// <= 2^{64p} * m.  That is, p-fold Montgomery reduction w.r.t. a
+/// k-digit modulus m giving a k-digit answer.
+pub(crate) fn bignum_montredc(z: &mut [u64], x: &[u64], m: &[u64], p: u64) {
+    debug_assert!(z.len() == m.len());
+    let k = m.len();
+    let w = negmodinv_word(m[0]);
+
+    // z is a window onto x, into which the next digit is shifted
+    // after each reduction step.  `top` is its carry out.
+    for (i, zi) in z.iter_mut().enumerate() {
+        *zi = x.get(i).copied().unwrap_or_default();
+    }
+    let mut top = 0;
+
+    for i in 0..p as usize {
+        let q = z[0].wrapping_mul(w);
+        let (_, mut carry) = mac(z[0], q, m[0], 0);
+        for j in 1..k {
+            (z[j - 1], carry) = mac(z[j], q, m[j], carry);
+        }
+        let next = x.get(k + i).copied().unwrap_or_default();
+        (z[k - 1], top) = adc(carry, next, top);
+    }
+
+    reduce_once(z, top, m);
+}
+
+/// Convert from (almost-)Montgomery form z := (x / 2^{64k}) mod m
+///
+/// This can handle almost-Montgomery inputs, i.e. any k-digit bignum.
+pub(crate) fn bignum_demont(z: &mut [u64], x: &[u64], m: &[u64]) {
+    debug_assert!(z.len() == x.len());
+    debug_assert!(z.len() == m.len());
+    bignum_montredc(z, x, m, m.len() as u64);
+}
+
+/// Extended Montgomery reduce in 8-digit blocks, results in input-output buffer
+///
+/// Inputs z[2*k], m[k], w; outputs function return (extra result bit) and z[2*k]
+///
+/// Assumes m * w == -1 (mod 2^64).  On return the lower half of z is
+/// the multiplier q, and the upper half with the returned carry is
+/// r = (z + q * m) / 2^{64k}.
+pub(crate) fn bignum_emontredc_8n(z: &mut [u64], m: &[u64], w: u64) -> u64 {
+    debug_assert!(z.len() == m.len() * 2);
+    debug_assert!(z.len() % 8 == 0);
+    let k = m.len();
+    let mut top = 0;
+
+    for i in 0..k {
+        let q = z[i].wrapping_mul(w);
+        let mut carry = 0;
+        for j in 0..k {
+            (z[i + j], carry) = mac(z[i + j], q, m[j], carry);
+        }
+        (z[i + k], top) = adc(z[i + k], carry, top);
+        z[i] = q;
+    }
+
+    top
+}
+
+/// Compute "montification" constant z := 2^{128k} mod m
+pub(crate) fn bignum_montifier(z: &mut [u64], m: &[u64], _t: &mut [u64]) {
+    debug_assert!(z.len() == m.len());
+    z.fill(0);
+    z[0] = 1;
+    for _ in 0..128 * m.len() {
+        let mut carry = 0;
+        for zi in z.iter_mut() {
+            let next = *zi >> 63;
+            *zi = (*zi << 1) | carry;
+            carry = next;
+        }
+        reduce_once(z, carry, m);
+    }
+}
+
+/// Invert modulo m, z = (1/a) mod b, assuming b is an odd number > 1, coprime a
+///
+/// Inputs a[k], b[k]; output z[k]; temporary buffer t[>=3*k]
+///
+/// If a and b are not coprime, z is set to zero.  a need not be reduced.
+pub(crate) fn bignum_modinv(z: &mut [u64], a: &[u64], b: &[u64], t: &mut [u64]) {
+    debug_assert!(z.len() == a.len());
+    debug_assert!(z.len() == b.len());
+    debug_assert!(z.len() * 3 <= t.len());
+    let k = z.len();
+
+    // Binary extended gcd, maintaining f = u * a and g = v * a (mod b).
+    // Each step halves f, so 2 * 64k steps reduce f to zero and g to
+    // the gcd.
+    let (f, t) = t.split_at_mut(k);
+    let (g, t) = t.split_at_mut(k);
+    let u = &mut t[..k];
+    let v = z;
+
+    f.copy_from_slice(a);
+    g.copy_from_slice(b);
+    u.fill(0);
+    u[0] = 1;
+    v.fill(0);
+
+    for _ in 0..128 * k {
+        let odd = (f[0] & 1).wrapping_neg();
+        let swap = bignum_cmp_lt(f, g).wrapping_neg() & odd;
+        cswap(f, g, swap);
+        cswap(u, v, swap);
+
+        // f -= g, u -= v, if f is odd
+        let mut borrow = 0;
+        for (fi, gi) in f.iter_mut().zip(g.iter()) {
+            (*fi, borrow) = sbb(*fi, gi & odd, borrow);
+        }
+        let mut borrow = 0;
+        for (ui, vi) in u.iter_mut().zip(v.iter()) {
+            (*ui, borrow) = sbb(*ui, vi & odd, borrow);
+        }
+        masked_add(u, b, borrow.wrapping_neg());
+
+        // f /= 2, u /= 2 (mod b)
+        shift_right_1(f, 0);
+        let carry = masked_add(u, b, (u[0] & 1).wrapping_neg());
+        shift_right_1(u, carry);
+    }
+
+    // g is the gcd
+    let mut one = g[0] ^ 1;
+    for gi in &g[1..] {
+        one |= gi;
+    }
+    let mask = !nonzero_mask(one);
+    for vi in v.iter_mut() {
+        *vi &= mask;
+    }
+}
+
+/// Montgomery multiply with precomputed `w = -1/m[0] mod 2^64`.
+///
+/// This is a coarsely-integrated operand scanning implementation.
+pub(super) fn montmul(z: &mut [u64], x: &[u64], y: &[u64], m: &[u64], w: u64) {
+    let k = m.len();
+    z.fill(0);
+    let mut top = 0;
+
+    for xi in x {
+        let mut carry = 0;
+        for (zj, yj) in z.iter_mut().zip(y) {
+            (*zj, carry) = mac(*zj, *xi, *yj, carry);
+        }
+        let (top_lo, top_hi) = adc(top, carry, 0);
+
+        let q = z[0].wrapping_mul(w);
+        let (_, mut carry) = mac(z[0], q, m[0], 0);
+        for j in 1..k {
+            (z[j - 1], carry) = mac(z[j], q, m[j], carry);
+        }
+        let hi;
+        (z[k - 1], hi) = adc(top_lo, carry, 0);
+        top = top_hi + hi;
+    }
+
+    reduce_once(z, top, m);
+}
+
+/// Returns -1/x mod 2^64, for odd x.
+pub(super) const fn negmodinv_word(x: u64) -> u64 {
+    // correct to 3 bits, then each Newton step doubles that
+    let mut inv = x;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(x.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// z := (top * 2^{64k} + z) mod m, assuming that is < 2m
+pub(super) fn reduce_once(z: &mut [u64], top: u64, m: &[u64]) {
+    let mut borrow = 0;
+    for (zi, mi) in z.iter().zip(m) {
+        (_, borrow) = sbb(*zi, *mi, borrow);
+    }
+    let mask = nonzero_mask(top | (borrow ^ 1));
+    let mut borrow = 0;
+    for (zi, mi) in z.iter_mut().zip(m) {
+        (*zi, borrow) = sbb(*zi, mi & mask, borrow);
+    }
+}
+
+/// z += y & mask, returning the carry
+fn masked_add(z: &mut [u64], y: &[u64], mask: u64) -> u64 {
+    let mut carry = 0;
+    for (zi, yi) in z.iter_mut().zip(y) {
+        (*zi, carry) = adc(*zi, yi & mask, carry);
+    }
+    carry
+}
+
+/// Shift z right one bit, shifting `top` in at the top.
+fn shift_right_1(z: &mut [u64], top: u64) {
+    let mut carry = top;
+    for zi in z.iter_mut().rev() {
+        let next = *zi & 1;
+        *zi = (*zi >> 1) | (carry << 63);
+        carry = next;
+    }
+}
+
+/// Swap x and y if `mask` is all-ones.
+fn cswap(x: &mut [u64], y: &mut [u64], mask: u64) {
+    for (xi, yi) in x.iter_mut().zip(y.iter_mut()) {
+        let t = (*xi ^ *yi) & mask;
+        *xi ^= t;
+        *yi ^= t;
+    }
+}
+
+/// Returns (number of digits up to the most-significant non-zero
+/// digit, that digit).
+fn top_digit(x: &[u64]) -> (usize, u64) {
+    let mut digits = 0;
+    let mut top = 0;
+    for (i, xi) in x.iter().enumerate() {
+        let mask = nonzero_mask(*xi);
+        digits = ((i as u64 + 1) & mask) | (digits & !mask);
+        top = (xi & mask) | (top & !mask);
+    }
+    (digits as usize, top)
+}
+
+/// All-ones if `x` is non-zero, otherwise zero.
+#[inline]
+pub(super) fn nonzero_mask(x: u64) -> u64 {
+    ((x | x.wrapping_neg()) >> 63).wrapping_neg()
+}
+
+/// Returns (a + b + carry, carry-out)
+#[inline]
+pub(super) fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Returns (a - b - borrow, borrow-out)
+#[inline]
+pub(super) fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (t as u64, (t >> 127) as u64)
+}
+
+/// Returns (acc + a * b + carry, carry-out)
+#[inline]
+fn mac(acc: u64, a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = acc as u128 + (a as u128 * b as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+#[cfg(all(
+    test,
+    not(feature = "force-generic"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+pub(super) mod tests {
+    //! These test against the assembly implementations.
+    use super::*;
+    use crate::low;
+
+    pub(in crate::low::generic) struct Rng(pub(in crate::low::generic) u64);
+
+    impl Rng {
+        pub(in crate::low::generic) fn next(&mut self) -> u64 {
+            // splitmix64
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let z = self.0;
+            let z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            let z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }
+
+        pub(in crate::low::generic) fn words(&mut self, n: usize) -> Vec<u64> {
+            (0..n)
+                .map(|i| match self.next() % 8 {
+                    // bias towards edge cases
+                    0 => 0,
+                    1 => u64::MAX,
+                    _ => self.next() >> (i % 3),
+                })
+                .collect()
+        }
+
+        /// An odd modulus, with its top word non-zero.
+        fn modulus(&mut self, n: usize) -> Vec<u64> {
+            let mut m = self.words(n);
+            m[0] |= 1;
+            m[n - 1] |= 1 << 40;
+            m
+        }
+
+        /// A number reduced modulo `m`.
+        fn reduced(&mut self, m: &[u64]) -> Vec<u64> {
+            let mut x = self.words(m.len());
+            x[m.len() - 1] %= m[m.len() - 1];
+            x
+        }
+    }
+
+    #[test]
+    fn simple() {
+        let mut rng = Rng(1);
+        for _ in 0..500 {
+            let n = 1 + (rng.next() % 9) as usize;
+            let x = rng.words(n);
+            let ylen = 1 + (rng.next() % 9) as usize;
+            let y = rng.words(ylen);
+
+            assert_eq!(bignum_bitsize(&x), low::bignum_bitsize(&x));
+            assert_eq!(bignum_digitsize(&x), low::bignum_digitsize(&x));
+            assert_eq!(bignum_cmp_lt(&x, &y), low::bignum_cmp_lt(&x, &y));
+            assert_eq!(bignum_cmp_lt(&x, &x), 0);
+            assert_eq!(bignum_eq(&x, &y), low::bignum_eq(&x, &y));
+            assert!(bignum_eq(&x, &x));
+
+            let mut expect = vec![0; n];
+            let mut got = vec![0; n];
+            low::bignum_add(&mut expect, &x, &y);
+            bignum_add(&mut got, &x, &y);
+            assert_eq!(expect, got);
+
+            let mut expect = vec![0; n + y.len()];
+            let mut got = vec![0; n + y.len()];
+            low::bignum_mul(&mut expect, &x, &y);
+            bignum_mul(&mut got, &x, &y);
+            assert_eq!(expect, got);
+
+            let y = rng.words(n);
+            let p = rng.next() & 1;
+            let mut expect = vec![0; n];
+            let mut got = vec![0; n];
+            low::bignum_optsub(&mut expect, &x, &y, p);
+            bignum_optsub(&mut got, &x, &y, p);
+            assert_eq!(expect, got);
+
+            low::bignum_mux(p, &mut expect, &x, &y);
+            bignum_mux(p, &mut got, &x, &y);
+            assert_eq!(expect, got);
+
+            let mut x = x;
+            x[0] |= 1;
+            low::bignum_negmodinv(&mut expect, &x);
+            bignum_negmodinv(&mut got, &x);
+            assert_eq!(expect, got);
+        }
+
+        assert_eq!(bignum_bitsize(&[0, 0]), 0);
+        assert_eq!(bignum_digitsize(&[0, 0]), 0);
+        assert_eq!(bignum_bitsize(&[0, 1]), 65);
+        assert_eq!(bignum_digitsize(&[1, 0]), 1);
+    }
+
+    #[test]
+    fn karatsuba() {
+        let mut rng = Rng(2);
+        for _ in 0..10 {
+            let x = rng.words(32);
+            let y = rng.words(32);
+
+            let mut expect = [0; 64];
+            let mut got = [0; 64];
+            low::bignum_kmul_32_64(&mut expect, &x, &y, &mut [0; 96]);
+            bignum_kmul_32_64(&mut got, &x, &y, &mut [0; 96]);
+            assert_eq!(expect, got);
+            low::bignum_ksqr_32_64(&mut expect, &x, &mut [0; 72]);
+            bignum_ksqr_32_64(&mut got, &x, &mut [0; 72]);
+            assert_eq!(expect, got);
+
+            let mut expect = [0; 32];
+            let mut got = [0; 32];
+            low::bignum_kmul_16_32(&mut expect, &x[..16], &y[..16], &mut [0; 32]);
+            bignum_kmul_16_32(&mut got, &x[..16], &y[..16], &mut [0; 32]);
+            assert_eq!(expect, got);
+            low::bignum_ksqr_16_32(&mut expect, &x[..16], &mut [0; 24]);
+            bignum_ksqr_16_32(&mut got, &x[..16], &mut [0; 24]);
+            assert_eq!(expect, got);
+        }
+    }
+
+    #[test]
+    fn modular() {
+        let mut rng = Rng(3);
+        for _ in 0..300 {
+            let n = 1 + (rng.next() % 9) as usize;
+            let m = rng.modulus(n);
+            let x = rng.reduced(&m);
+            let y = rng.reduced(&m);
+            let mut t = vec![0; 3 * n];
+
+            let mut expect = vec![0; n];
+            let mut got = vec![0; n];
+
+            low::bignum_modadd(&mut expect, &x, &y, &m);
+            bignum_modadd(&mut got, &x, &y, &m);
+            assert_eq!(expect, got);
+
+            low::bignum_modsub(&mut expect, &x, &y, &m);
+            bignum_modsub(&mut got, &x, &y, &m);
+            assert_eq!(expect, got);
+
+            low::bignum_montmul(&mut expect, &x, &y, &m);
+            bignum_montmul(&mut got, &x, &y, &m);
+            assert_eq!(expect, got);
+
+            low::bignum_montsqr(&mut expect, &x, &m);
+            bignum_montsqr(&mut got, &x, &m);
+            assert_eq!(expect, got);
+
+            let any = rng.words(n);
+            low::bignum_demont(&mut expect, &any, &m);
+            bignum_demont(&mut got, &any, &m);
+            assert_eq!(expect, got);
+
+            low::bignum_montifier(&mut expect, &m, &mut t);
+            bignum_montifier(&mut got, &m, &mut t);
+            assert_eq!(expect, got);
+
+            // s2n-bignum requires x and m to be coprime, and ours gives zero
+            // if they are not
+            bignum_modinv(&mut got, &x, &m, &mut t);
+            if got.iter().any(|g| *g != 0) {
+                low::bignum_modinv(&mut expect, &x, &m, &mut t);
+                assert_eq!(expect, got);
+            }
+
+            // x' is the lowest p + k digits of a product, so is
+            // below 2^{64p} * m
+            let p = (rng.next() % (n as u64 + 1)) as usize;
+            let mut wide = vec![0; 2 * n + 1];
+            bignum_mul(&mut wide, &x, &y[..p.max(1).min(n)]);
+            low::bignum_montredc(&mut expect, &wide, &m, p as u64);
+            bignum_montredc(&mut got, &wide, &m, p as u64);
+            assert_eq!(expect, got);
+        }
+    }
+
+    #[test]
+    fn emontredc() {
+        let mut rng = Rng(4);
+        for n in [8, 16, 32] {
+            let m = rng.modulus(n);
+            let z = rng.words(2 * n);
+            let w = negmodinv_word(m[0]);
+
+            let mut expect = z.clone();
+            let mut got = z;
+            assert_eq!(
+                low::bignum_emontredc_8n(&mut expect, &m, w),
+                bignum_emontredc_8n(&mut got, &m, w)
+            );
+            assert_eq!(expect, got);
+        }
+    }
+
+    #[test]
+    fn tables() {
+        let mut rng = Rng(5);
+        for (height, width) in [(16, 1), (16, 8), (32, 16), (7, 4), (3, 32)] {
+            let table = rng.words(height * width);
+            for index in 0..height as u64 {
+                let mut expect = vec![0; width];
+                let mut got = vec![0; width];
+                low::bignum_copy_row_from_table(
+                    &mut expect,
+                    &table,
+                    height as u64,
+                    width as u64,
+                    index,
+                );
+                bignum_copy_row_from_table(&mut got, &table, height as u64, width as u64, index);
+                assert_eq!(expect, got);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_of_non_coprime_is_zero() {
+        let mut z = [1; 2];
+        bignum_modinv(&mut z, &[0, 0], &[0xffff_ffff_ffff_fff1, 1], &mut [0; 6]);
+        assert_eq!(z, [0, 0]);
+        bignum_modinv(&mut z, &[3, 0], &[9, 0], &mut [0; 6]);
+        assert_eq!(z, [0, 0]);
+    }
+}
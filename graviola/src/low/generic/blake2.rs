@@ -0,0 +1,95 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! The BLAKE2b and BLAKE2s compression functions, as specified in
+//! [RFC7693](https://datatracker.ietf.org/doc/html/rfc7693).
+
+macro_rules! compress {
+    ($name:ident, $word:ty, $rounds:literal, $iv:ident, $r1:literal, $r2:literal, $r3:literal, $r4:literal) => {
+        /// Compress one `block` into the state `h`.
+        ///
+        /// `t` is the total number of bytes hashed so far, including
+        /// this block (but not any padding).  `last` marks the final block.
+        pub(crate) fn $name(
+            h: &mut [$word; 8],
+            block: &[u8; 16 * core::mem::size_of::<$word>()],
+            t: u128,
+            last: bool,
+        ) {
+            const W: usize = core::mem::size_of::<$word>();
+            let mut m: [$word; 16] = [0; 16];
+            for (m, bytes) in m.iter_mut().zip(block.chunks_exact(W)) {
+                *m = <$word>::from_le_bytes(bytes.try_into().unwrap());
+            }
+
+            let mut v: [$word; 16] = [0; 16];
+            v[..8].copy_from_slice(h);
+            v[8..].copy_from_slice(&$iv);
+            v[12] ^= t as $word;
+            v[13] ^= (t >> (8 * W)) as $word;
+            if last {
+                v[14] = !v[14];
+            }
+
+            fn g(v: &mut [$word; 16], a: usize, b: usize, c: usize, d: usize, x: $word, y: $word) {
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+                v[d] = (v[d] ^ v[a]).rotate_right($r1);
+                v[c] = v[c].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c]).rotate_right($r2);
+                v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+                v[d] = (v[d] ^ v[a]).rotate_right($r3);
+                v[c] = v[c].wrapping_add(v[d]);
+                v[b] = (v[b] ^ v[c]).rotate_right($r4);
+            }
+
+            for round in 0..$rounds {
+                let s = &SIGMA[round % 10];
+                g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+                g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+                g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+                g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+                g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+                g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+                g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+                g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+            }
+
+            for (i, h) in h.iter_mut().enumerate() {
+                *h ^= v[i] ^ v[i + 8];
+            }
+        }
+    };
+}
+
+compress!(blake2b_compress, u64, 12, BLAKE2B_IV, 32, 24, 16, 63);
+compress!(blake2s_compress, u32, 10, BLAKE2S_IV, 16, 12, 8, 7);
+
+/// The BLAKE2b initialisation vector (the same as SHA512's).
+pub(crate) const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// The BLAKE2s initialisation vector (the same as SHA256's).
+pub(crate) const BLAKE2S_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
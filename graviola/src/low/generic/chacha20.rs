@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 // Originally from cifra
 
+use super::hchacha20::{SIGMA, hchacha};
+
 pub(crate) struct ChaCha20 {
     key0: [u32; 4],
     key1: [u32; 4],
@@ -27,6 +29,36 @@ impl ChaCha20 {
     }
 
     pub(crate) fn cipher(&mut self, buffer: &mut [u8]) {
+        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        let buffer = {
+            let mut blocks = buffer.chunks_exact_mut(256);
+            for block in blocks.by_ref() {
+                let mut stream = [0u8; 256];
+                simd128::core4(&self.key0, &self.key1, &self.nonce, &mut stream);
+                for (out, key) in block.iter_mut().zip(stream.iter()) {
+                    *out ^= *key;
+                }
+
+                self.nonce[0] = self.nonce[0].wrapping_add(4);
+            }
+            blocks.into_remainder()
+        };
+
+        #[cfg(all(target_arch = "loongarch64", target_feature = "lsx"))]
+        let buffer = {
+            let mut blocks = buffer.chunks_exact_mut(256);
+            for block in blocks.by_ref() {
+                let mut stream = [0u8; 256];
+                lanes::core4(&self.key0, &self.key1, &self.nonce, &mut stream);
+                for (out, key) in block.iter_mut().zip(stream.iter()) {
+                    *out ^= *key;
+                }
+
+                self.nonce[0] = self.nonce[0].wrapping_add(4);
+            }
+            blocks.into_remainder()
+        };
+
         for block in buffer.chunks_mut(64) {
             let mut stream = [0u8; 64];
             core(&self.key0, &self.key1, &self.nonce, &mut stream);
@@ -63,6 +95,10 @@ impl XChaCha20 {
     pub(crate) fn cipher(&mut self, buffer: &mut [u8]) {
         self.0.cipher(buffer)
     }
+
+    pub(crate) fn as_chacha20(&mut self) -> &mut ChaCha20 {
+        &mut self.0
+    }
 }
 
 fn core(key0: &[u32; 4], key1: &[u32; 4], nonce: &[u32; 4], out: &mut [u8; 64]) {
@@ -134,47 +170,192 @@ fn core(key0: &[u32; 4], key1: &[u32; 4], nonce: &[u32; 4], out: &mut [u8; 64])
     out[60..64].copy_from_slice(&xf.to_le_bytes());
 }
 
-fn hchacha(key0: &mut [u32; 4], key1: &mut [u32; 4], nonce: &[u32; 4]) {
-    let [mut z0, mut z1, mut z2, mut z3] = SIGMA;
-    let &mut [mut z4, mut z5, mut z6, mut z7] = key0;
-    let &mut [mut z8, mut z9, mut za, mut zb] = key1;
-    let &[mut zc, mut zd, mut ze, mut zf] = nonce;
+/// Four blocks at once, using wasm SIMD128.
+///
+/// Each vector holds one word of the state, for four consecutive counter
+/// values.
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod simd128 {
+    use core::arch::wasm32::*;
+
+    use super::SIGMA;
+
+    pub(super) fn core4(key0: &[u32; 4], key1: &[u32; 4], nonce: &[u32; 4], out: &mut [u8; 256]) {
+        let c = nonce[0];
+        let x = [
+            u32x4_splat(SIGMA[0]),
+            u32x4_splat(SIGMA[1]),
+            u32x4_splat(SIGMA[2]),
+            u32x4_splat(SIGMA[3]),
+            u32x4_splat(key0[0]),
+            u32x4_splat(key0[1]),
+            u32x4_splat(key0[2]),
+            u32x4_splat(key0[3]),
+            u32x4_splat(key1[0]),
+            u32x4_splat(key1[1]),
+            u32x4_splat(key1[2]),
+            u32x4_splat(key1[3]),
+            u32x4(c, c.wrapping_add(1), c.wrapping_add(2), c.wrapping_add(3)),
+            u32x4_splat(nonce[1]),
+            u32x4_splat(nonce[2]),
+            u32x4_splat(nonce[3]),
+        ];
+        let mut z = x;
 
-    macro_rules! quarter {
-        ($a:ident, $b:ident, $c:ident, $d:ident) => {
-            $a = $a.wrapping_add($b);
-            $d = ($d ^ $a).rotate_left(16);
-            $c = $c.wrapping_add($d);
-            $b = ($b ^ $c).rotate_left(12);
-            $a = $a.wrapping_add($b);
-            $d = ($d ^ $a).rotate_left(8);
-            $c = $c.wrapping_add($d);
-            $b = ($b ^ $c).rotate_left(7);
-        };
+        macro_rules! rotl {
+            ($v:expr, $n:literal) => {
+                v128_or(u32x4_shl($v, $n), u32x4_shr($v, 32 - $n))
+            };
+        }
+
+        macro_rules! quarter {
+            ($a:literal, $b:literal, $c:literal, $d:literal) => {
+                z[$a] = u32x4_add(z[$a], z[$b]);
+                z[$d] = rotl!(v128_xor(z[$d], z[$a]), 16);
+                z[$c] = u32x4_add(z[$c], z[$d]);
+                z[$b] = rotl!(v128_xor(z[$b], z[$c]), 12);
+                z[$a] = u32x4_add(z[$a], z[$b]);
+                z[$d] = rotl!(v128_xor(z[$d], z[$a]), 8);
+                z[$c] = u32x4_add(z[$c], z[$d]);
+                z[$b] = rotl!(v128_xor(z[$b], z[$c]), 7);
+            };
+        }
+
+        for _ in 0..10 {
+            quarter!(0, 4, 8, 12);
+            quarter!(1, 5, 9, 13);
+            quarter!(2, 6, 10, 14);
+            quarter!(3, 7, 11, 15);
+            quarter!(0, 5, 10, 15);
+            quarter!(1, 6, 11, 12);
+            quarter!(2, 7, 8, 13);
+            quarter!(3, 4, 9, 14);
+        }
+
+        for (i, (xi, zi)) in x.iter().zip(z.iter()).enumerate() {
+            let word = u32x4_add(*xi, *zi);
+            let lanes = [
+                u32x4_extract_lane::<0>(word),
+                u32x4_extract_lane::<1>(word),
+                u32x4_extract_lane::<2>(word),
+                u32x4_extract_lane::<3>(word),
+            ];
+            for (block, lane) in lanes.iter().enumerate() {
+                let offs = block * 64 + i * 4;
+                out[offs..offs + 4].copy_from_slice(&lane.to_le_bytes());
+            }
+        }
     }
+}
 
-    for _ in 0..10 {
-        quarter!(z0, z4, z8, zc);
-        quarter!(z1, z5, z9, zd);
-        quarter!(z2, z6, za, ze);
-        quarter!(z3, z7, zb, zf);
-        quarter!(z0, z5, za, zf);
-        quarter!(z1, z6, zb, zc);
-        quarter!(z2, z7, z8, zd);
-        quarter!(z3, z4, z9, ze);
+/// Four blocks at once, for LoongArch LSX.
+///
+/// This is the same arrangement as the simd128 version above.  Rust's
+/// LoongArch SIMD intrinsics are not stable, so it is written with
+/// four-lane arrays that the compiler vectorizes into LSX instructions.
+#[cfg(any(test, all(target_arch = "loongarch64", target_feature = "lsx")))]
+mod lanes {
+    use super::SIGMA;
+
+    type Lanes = [u32; 4];
+
+    #[inline(always)]
+    fn add(a: Lanes, b: Lanes) -> Lanes {
+        [
+            a[0].wrapping_add(b[0]),
+            a[1].wrapping_add(b[1]),
+            a[2].wrapping_add(b[2]),
+            a[3].wrapping_add(b[3]),
+        ]
     }
 
-    *key0 = [z0, z1, z2, z3];
-    *key1 = [zc, zd, ze, zf];
-}
+    #[inline(always)]
+    fn xor_rotl(a: Lanes, b: Lanes, n: u32) -> Lanes {
+        [
+            (a[0] ^ b[0]).rotate_left(n),
+            (a[1] ^ b[1]).rotate_left(n),
+            (a[2] ^ b[2]).rotate_left(n),
+            (a[3] ^ b[3]).rotate_left(n),
+        ]
+    }
 
-// b"expand 32-byte k" in little-endian
-const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+    pub(super) fn core4(key0: &[u32; 4], key1: &[u32; 4], nonce: &[u32; 4], out: &mut [u8; 256]) {
+        let c = nonce[0];
+        let x: [Lanes; 16] = [
+            [SIGMA[0]; 4],
+            [SIGMA[1]; 4],
+            [SIGMA[2]; 4],
+            [SIGMA[3]; 4],
+            [key0[0]; 4],
+            [key0[1]; 4],
+            [key0[2]; 4],
+            [key0[3]; 4],
+            [key1[0]; 4],
+            [key1[1]; 4],
+            [key1[2]; 4],
+            [key1[3]; 4],
+            [c, c.wrapping_add(1), c.wrapping_add(2), c.wrapping_add(3)],
+            [nonce[1]; 4],
+            [nonce[2]; 4],
+            [nonce[3]; 4],
+        ];
+        let mut z = x;
+
+        macro_rules! quarter {
+            ($a:literal, $b:literal, $c:literal, $d:literal) => {
+                z[$a] = add(z[$a], z[$b]);
+                z[$d] = xor_rotl(z[$d], z[$a], 16);
+                z[$c] = add(z[$c], z[$d]);
+                z[$b] = xor_rotl(z[$b], z[$c], 12);
+                z[$a] = add(z[$a], z[$b]);
+                z[$d] = xor_rotl(z[$d], z[$a], 8);
+                z[$c] = add(z[$c], z[$d]);
+                z[$b] = xor_rotl(z[$b], z[$c], 7);
+            };
+        }
+
+        for _ in 0..10 {
+            quarter!(0, 4, 8, 12);
+            quarter!(1, 5, 9, 13);
+            quarter!(2, 6, 10, 14);
+            quarter!(3, 7, 11, 15);
+            quarter!(0, 5, 10, 15);
+            quarter!(1, 6, 11, 12);
+            quarter!(2, 7, 8, 13);
+            quarter!(3, 4, 9, 14);
+        }
+
+        for (i, (xi, zi)) in x.iter().zip(z.iter()).enumerate() {
+            for (block, lane) in add(*xi, *zi).iter().enumerate() {
+                let offs = block * 64 + i * 4;
+                out[offs..offs + 4].copy_from_slice(&lane.to_le_bytes());
+            }
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn lanes_matches_core() {
+        let key0 = [0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c];
+        let key1 = [0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c];
+        for counter in [0, 1, 0xffff_fffe] {
+            let nonce = [counter, 0x09000000, 0x4a000000, 0];
+            let mut got = [0u8; 256];
+            lanes::core4(&key0, &key1, &nonce, &mut got);
+
+            for (i, chunk) in got.chunks(64).enumerate() {
+                let nonce = [counter.wrapping_add(i as u32), nonce[1], nonce[2], nonce[3]];
+                let mut expect = [0u8; 64];
+                core(&key0, &key1, &nonce, &mut expect);
+                assert_eq!(chunk, expect);
+            }
+        }
+    }
+
     #[test]
     fn test_vectors() {
         // From draft-agl-tls-chacha20poly1305-04 section 7
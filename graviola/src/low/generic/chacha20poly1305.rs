@@ -0,0 +1,15 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::low::chacha20::ChaCha20;
+use crate::low::poly1305::Poly1305;
+
+pub(crate) fn encrypt(chacha: &mut ChaCha20, poly: &mut Poly1305, cipher_inout: &mut [u8]) {
+    chacha.cipher(cipher_inout);
+    poly.add_bytes(cipher_inout);
+}
+
+pub(crate) fn decrypt(chacha: &mut ChaCha20, poly: &mut Poly1305, cipher_inout: &mut [u8]) {
+    poly.add_bytes(cipher_inout);
+    chacha.cipher(cipher_inout);
+}
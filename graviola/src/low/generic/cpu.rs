@@ -0,0 +1,84 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! CPU state management for targets without any.
+//!
+//! There are no secret-dependent CPU modes to enter, nor vector
+//! registers we have written to (outside of those managed by the
+//! compiler), so most of this is empty.
+
+pub(crate) fn enter_cpu_state() -> u32 {
+    0
+}
+
+pub(crate) fn leave_cpu_state(_old: u32) {}
+
+/// Effectively memset(ptr, 0, len), but not visible to optimiser
+///
+/// # Safety
+/// The caller must ensure that there are `len` bytes writable at `ptr`,
+/// and that the pointed-to object has a safe all-zeroes representation.
+/// (see `low::generic::zeroise` which expresses this within the type system).
+pub(in crate::low) fn zero_bytes(ptr: *mut u8, len: usize) {
+    for i in 0..len {
+        // SAFETY: writes to `len` bytes at `ptr`, which the caller guarantees
+        unsafe { ptr.add(i).write_volatile(0) };
+    }
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
+/// Effectively memcmp(a, b, len), but guaranteed to visit every element
+/// of a and b.
+///
+/// The return value does not follow memcmp semantics: it is zero if
+/// `a == b`, otherwise it is non-zero.
+///
+/// # Safety
+/// The caller must ensure that there are `len` bytes readable at `a` and `b`,
+pub(in crate::low) unsafe fn ct_compare_bytes(a: *const u8, b: *const u8, len: usize) -> u8 {
+    let mut acc = 0u8;
+    for i in 0..len {
+        // SAFETY: reads `len` bytes from `a` and `b`, which the caller guarantees
+        acc |= unsafe { a.add(i).read_volatile() ^ b.add(i).read_volatile() };
+    }
+    acc
+}
+
+pub(crate) fn verify_cpu_features() {
+    // the portable code has no requirements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero() {
+        for len in 0..64 {
+            let mut bytes = [0xffu8; 64];
+            zero_bytes(bytes.as_mut_ptr(), len);
+            assert!(bytes[..len].iter().all(|b| *b == 0));
+            assert!(bytes[len..].iter().all(|b| *b == 0xff));
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let a = [0x11u8; 32];
+        let mut b = a;
+        // SAFETY: both are 32 bytes long
+        unsafe {
+            assert_eq!(ct_compare_bytes(a.as_ptr(), b.as_ptr(), 32), 0);
+            b[31] = 0x10;
+            assert_ne!(ct_compare_bytes(a.as_ptr(), b.as_ptr(), 32), 0);
+            assert_eq!(ct_compare_bytes(a.as_ptr(), b.as_ptr(), 31), 0);
+        }
+    }
+
+    #[test]
+    fn state() {
+        verify_cpu_features();
+        let old = enter_cpu_state();
+        leave_cpu_state(old);
+    }
+}
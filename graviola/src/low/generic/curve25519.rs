@@ -0,0 +1,311 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Portable X25519.
+//!
+//! This is the Montgomery ladder from RFC7748, with field elements
+//! represented as five 51-bit limbs.
+
+/// The x25519 function for curve25519
+///
+/// Given a scalar n and the X coordinate of an input point P, returns the
+/// X coordinate of n * P, or 0 when n * P is the point at infinity.  Both
+/// n and X are first mangled as specified in RFC7748.  Does not implement
+/// the zero-check specified in Section 6.1.
+pub(crate) fn curve25519_x25519(res: &mut [u64; 4], scalar: &[u64; 4], point: &[u64; 4]) {
+    let mut k = *scalar;
+    k[0] &= !7;
+    k[3] &= !(1 << 63);
+    k[3] |= 1 << 62;
+
+    let x1 = Fe::from_words(point);
+    let mut x2 = Fe::ONE;
+    let mut z2 = Fe::ZERO;
+    let mut x3 = x1;
+    let mut z3 = Fe::ONE;
+    let mut swap = 0;
+
+    for t in (0..255).rev() {
+        let bit = (k[t / 64] >> (t % 64)) & 1;
+        swap ^= bit;
+        Fe::cswap(&mut x2, &mut x3, swap);
+        Fe::cswap(&mut z2, &mut z3, swap);
+        swap = bit;
+
+        let a = x2.add(&z2);
+        let aa = a.sqr();
+        let b = x2.sub(&z2);
+        let bb = b.sqr();
+        let e = aa.sub(&bb);
+        let c = x3.add(&z3);
+        let d = x3.sub(&z3);
+        let da = d.mul(&a);
+        let cb = c.mul(&b);
+        x3 = da.add(&cb).sqr();
+        z3 = x1.mul(&da.sub(&cb).sqr());
+        x2 = aa.mul(&bb);
+        z2 = e.mul(&aa.add(&e.mul_small(121665)));
+    }
+
+    Fe::cswap(&mut x2, &mut x3, swap);
+    Fe::cswap(&mut z2, &mut z3, swap);
+
+    *res = x2.mul(&z2.invert()).to_words();
+}
+
+/// The x25519 function for curve25519 on base element 9
+pub(crate) fn curve25519_x25519base(res: &mut [u64; 4], scalar: &[u64; 4]) {
+    curve25519_x25519(res, scalar, &[9, 0, 0, 0]);
+}
+
+/// An element of GF(2^255 - 19).
+///
+/// Limbs are nominally 51 bits, but are allowed to grow slightly
+/// between carries.
+#[derive(Clone, Copy)]
+struct Fe([u64; 5]);
+
+const MASK51: u64 = (1 << 51) - 1;
+
+impl Fe {
+    const ZERO: Self = Self([0; 5]);
+    const ONE: Self = Self([1, 0, 0, 0, 0]);
+
+    /// Decodes, ignoring the top bit.
+    fn from_words(w: &[u64; 4]) -> Self {
+        Self([
+            w[0] & MASK51,
+            ((w[0] >> 51) | (w[1] << 13)) & MASK51,
+            ((w[1] >> 38) | (w[2] << 26)) & MASK51,
+            ((w[2] >> 25) | (w[3] << 39)) & MASK51,
+            (w[3] >> 12) & MASK51,
+        ])
+    }
+
+    /// Encodes, fully reduced.
+    fn to_words(self) -> [u64; 4] {
+        let mut h = self.carry().0;
+
+        // h < 2^255 + small, so h >= p iff h + 19 >= 2^255
+        let mut q = (h[0] + 19) >> 51;
+        q = (h[1] + q) >> 51;
+        q = (h[2] + q) >> 51;
+        q = (h[3] + q) >> 51;
+        q = (h[4] + q) >> 51;
+
+        h[0] += 19 * q;
+        for i in 0..4 {
+            h[i + 1] += h[i] >> 51;
+            h[i] &= MASK51;
+        }
+        h[4] &= MASK51;
+
+        [
+            h[0] | (h[1] << 51),
+            (h[1] >> 13) | (h[2] << 38),
+            (h[2] >> 26) | (h[3] << 25),
+            (h[3] >> 39) | (h[4] << 12),
+        ]
+    }
+
+    fn add(&self, b: &Self) -> Self {
+        let a = &self.0;
+        let b = &b.0;
+        Self([
+            a[0] + b[0],
+            a[1] + b[1],
+            a[2] + b[2],
+            a[3] + b[3],
+            a[4] + b[4],
+        ])
+    }
+
+    /// Subtraction; `b` must have been carried.
+    fn sub(&self, b: &Self) -> Self {
+        // add 4p to avoid underflow
+        const P4_0: u64 = 4 * ((1 << 51) - 19);
+        const P4_N: u64 = 4 * MASK51;
+        let a = &self.0;
+        let b = &b.0;
+        Self([
+            a[0] + P4_0 - b[0],
+            a[1] + P4_N - b[1],
+            a[2] + P4_N - b[2],
+            a[3] + P4_N - b[3],
+            a[4] + P4_N - b[4],
+        ])
+        .carry()
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        let a = &self.0;
+        let b = &b.0;
+        let m = |x: u64, y: u64| x as u128 * y as u128;
+
+        let b1 = b[1] * 19;
+        let b2 = b[2] * 19;
+        let b3 = b[3] * 19;
+        let b4 = b[4] * 19;
+
+        let c0 = m(a[0], b[0]) + m(a[1], b4) + m(a[2], b3) + m(a[3], b2) + m(a[4], b1);
+        let c1 = m(a[0], b[1]) + m(a[1], b[0]) + m(a[2], b4) + m(a[3], b3) + m(a[4], b2);
+        let c2 = m(a[0], b[2]) + m(a[1], b[1]) + m(a[2], b[0]) + m(a[3], b4) + m(a[4], b3);
+        let c3 = m(a[0], b[3]) + m(a[1], b[2]) + m(a[2], b[1]) + m(a[3], b[0]) + m(a[4], b4);
+        let c4 = m(a[0], b[4]) + m(a[1], b[3]) + m(a[2], b[2]) + m(a[3], b[1]) + m(a[4], b[0]);
+
+        Self::carry_wide([c0, c1, c2, c3, c4])
+    }
+
+    fn sqr(&self) -> Self {
+        self.mul(self)
+    }
+
+    fn mul_small(&self, b: u32) -> Self {
+        let a = &self.0;
+        let m = |x: u64| x as u128 * b as u128;
+        Self::carry_wide([m(a[0]), m(a[1]), m(a[2]), m(a[3]), m(a[4])])
+    }
+
+    fn carry_wide(c: [u128; 5]) -> Self {
+        let mut h = [0u64; 5];
+        let mut carry = 0u128;
+        for i in 0..5 {
+            let t = c[i] + carry;
+            h[i] = t as u64 & MASK51;
+            carry = t >> 51;
+        }
+        // carry < 2^77, so this fits easily
+        let t = h[0] as u128 + carry * 19;
+        h[0] = t as u64 & MASK51;
+        h[1] += (t >> 51) as u64;
+        Self(h)
+    }
+
+    fn carry(self) -> Self {
+        let mut h = self.0;
+        for i in 0..4 {
+            h[i + 1] += h[i] >> 51;
+            h[i] &= MASK51;
+        }
+        h[0] += 19 * (h[4] >> 51);
+        h[4] &= MASK51;
+        h[1] += h[0] >> 51;
+        h[0] &= MASK51;
+        Self(h)
+    }
+
+    /// self^(p - 2), which is the inverse of self (or zero).
+    fn invert(&self) -> Self {
+        let sqr_n = |x: Self, n: usize| (0..n).fold(x, |acc, _| acc.sqr());
+
+        let z2 = self.sqr();
+        let z9 = sqr_n(z2, 2).mul(self);
+        let z11 = z9.mul(&z2);
+        let z2_5_0 = z11.sqr().mul(&z9);
+        let z2_10_0 = sqr_n(z2_5_0, 5).mul(&z2_5_0);
+        let z2_20_0 = sqr_n(z2_10_0, 10).mul(&z2_10_0);
+        let z2_40_0 = sqr_n(z2_20_0, 20).mul(&z2_20_0);
+        let z2_50_0 = sqr_n(z2_40_0, 10).mul(&z2_10_0);
+        let z2_100_0 = sqr_n(z2_50_0, 50).mul(&z2_50_0);
+        let z2_200_0 = sqr_n(z2_100_0, 100).mul(&z2_100_0);
+        let z2_250_0 = sqr_n(z2_200_0, 50).mul(&z2_50_0);
+        sqr_n(z2_250_0, 5).mul(&z11)
+    }
+
+    /// Swap a and b if `swap` is 1.
+    fn cswap(a: &mut Self, b: &mut Self, swap: u64) {
+        let mask = swap.wrapping_neg();
+        for (ai, bi) in a.0.iter_mut().zip(b.0.iter_mut()) {
+            let t = (*ai ^ *bi) & mask;
+            *ai ^= t;
+            *bi ^= t;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(bytes: &[u8; 32]) -> [u64; 4] {
+        let mut w = [0; 4];
+        for (wi, chunk) in w.iter_mut().zip(bytes.chunks_exact(8)) {
+            *wi = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        w
+    }
+
+    fn hex(s: &str) -> [u64; 4] {
+        let mut b = [0u8; 32];
+        for (i, bi) in b.iter_mut().enumerate() {
+            *bi = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        words(&b)
+    }
+
+    #[test]
+    fn rfc7748_vectors() {
+        let mut res = [0; 4];
+        curve25519_x25519(
+            &mut res,
+            &hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+            &hex("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"),
+        );
+        assert_eq!(
+            res,
+            hex("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+        );
+
+        curve25519_x25519base(
+            &mut res,
+            &hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"),
+        );
+        assert_eq!(
+            res,
+            hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+    }
+
+    #[test]
+    fn iterated() {
+        let mut k = hex("0900000000000000000000000000000000000000000000000000000000000000");
+        let mut u = k;
+        for _ in 0..1000 {
+            let mut r = [0; 4];
+            curve25519_x25519(&mut r, &k, &u);
+            u = k;
+            k = r;
+        }
+        assert_eq!(
+            k,
+            hex("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    #[cfg(all(
+        not(feature = "force-generic"),
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    #[test]
+    fn against_assembly() {
+        use super::super::bignum::tests::Rng;
+        use crate::low;
+
+        let mut rng = Rng(25519);
+        for _ in 0..200 {
+            let scalar: [u64; 4] = rng.words(4).try_into().unwrap();
+            // includes non-canonical points, and those with the top bit set
+            let point: [u64; 4] = rng.words(4).try_into().unwrap();
+
+            let mut expect = [0; 4];
+            let mut got = [0; 4];
+            low::curve25519_x25519(&mut expect, &scalar, &point);
+            curve25519_x25519(&mut got, &scalar, &point);
+            assert_eq!(expect, got);
+
+            low::curve25519_x25519base(&mut expect, &scalar);
+            curve25519_x25519base(&mut got, &scalar);
+            assert_eq!(expect, got);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Polynomial arithmetic for FN-DSA, in the ring Z_q\[X\]/(X^n + 1)
+//! for n = 512 or 1024.
+//!
+//! Only verification is supported, which handles public values,
+//! so this favours simplicity over constant-time operation.
+//! Coefficients are stored fully reduced, in `0..q`.
+
+/// The FN-DSA modulus.
+pub(crate) const Q: u32 = 12289;
+
+/// Powers of the primitive 2048th root of unity 1945, in bit-reversed order.
+///
+/// For n = 512 the first half of this table serves, because the
+/// squares of these roots are the 1024th roots in 9-bit bit-reversed order.
+const ZETAS: [u32; 1024] = zetas();
+
+const fn zetas() -> [u32; 1024] {
+    let mut powers = [0u32; 1024];
+    let mut z = 1;
+    let mut i = 0;
+    while i < 1024 {
+        powers[i] = z;
+        z = z * 1945 % Q;
+        i += 1;
+    }
+
+    let mut r = [0u32; 1024];
+    let mut i = 0;
+    while i < 1024 {
+        r[i] = powers[(i as u16).reverse_bits() as usize >> 6];
+        i += 1;
+    }
+    r
+}
+
+/// Forward NTT, in place.  `a.len()` must be 512 or 1024.
+pub(crate) fn ntt(a: &mut [u32]) {
+    let n = a.len();
+    let mut k = 0;
+    let mut len = n / 2;
+    while len > 0 {
+        for start in (0..n).step_by(2 * len) {
+            k += 1;
+            let zeta = ZETAS[k];
+            for j in start..start + len {
+                let t = zeta * a[j + len] % Q;
+                a[j + len] = (a[j] + Q - t) % Q;
+                a[j] = (a[j] + t) % Q;
+            }
+        }
+        len >>= 1;
+    }
+}
+
+/// Inverse NTT, in place.  `a.len()` must be 512 or 1024.
+pub(crate) fn inv_ntt(a: &mut [u32]) {
+    let n = a.len();
+    let mut k = n;
+    let mut len = 1;
+    while len < n {
+        for start in (0..n).step_by(2 * len) {
+            k -= 1;
+            let zeta = Q - ZETAS[k];
+            for j in start..start + len {
+                let t = a[j];
+                a[j] = (t + a[j + len]) % Q;
+                a[j + len] = zeta * ((t + Q - a[j + len]) % Q) % Q;
+            }
+        }
+        len <<= 1;
+    }
+
+    // n * (q - 1) / n = -1 (mod q), so this is n^-1.
+    let n_inv = Q - (Q - 1) / n as u32;
+    for c in a.iter_mut() {
+        *c = *c * n_inv % Q;
+    }
+}
+
+/// Sets `r` to the pointwise product of `r` and `a`, which are in the NTT domain.
+pub(crate) fn pointwise_mul(r: &mut [u32], a: &[u32]) {
+    for (r, a) in r.iter_mut().zip(a.iter()) {
+        *r = *r * *a % Q;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zetas_are_roots() {
+        assert_eq!(ZETAS[0], 1);
+        // 1945^1024 = -1
+        assert_eq!(ZETAS[1] * ZETAS[1] % Q, Q - 1);
+    }
+
+    #[test]
+    fn multiplication() {
+        // (1 + X) * (1 + X^(n - 1)) = 1 + X + X^(n - 1) + X^n = X + X^(n - 1)
+        for n in [512, 1024] {
+            let mut a = vec![0; n];
+            a[0] = 1;
+            a[1] = 1;
+            let mut b = vec![0; n];
+            b[0] = 1;
+            b[n - 1] = 1;
+
+            ntt(&mut a);
+            ntt(&mut b);
+            pointwise_mul(&mut a, &b);
+            inv_ntt(&mut a);
+
+            let mut expect = vec![0; n];
+            expect[1] = 1;
+            expect[n - 1] = 1;
+            assert_eq!(a, expect);
+        }
+    }
+}
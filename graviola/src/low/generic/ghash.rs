@@ -1,17 +1,86 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+// Portable, constant-time GHASH.
+//
+// This uses integer multiplication with "holes" to do carryless
+// multiplication, following the "ctmul64" implementation in BearSSL.
+//
+// refs:
+// - https://bearssl.org/constanttime.html#ghash-for-gcm
 
-/// An extremely slow, by-the-book implementation.
-///
-/// Useful as a test model for faster implementations.
+use crate::low;
 
 pub(crate) struct GhashTable {
-    h: u128,
+    /// H as (high, low) halves, their xor, and the bit-reversals of those.
+    h: [u64; 6],
 }
 
 impl GhashTable {
     pub(crate) fn new(h: u128) -> Self {
-        Self { h }
+        let h1 = (h >> 64) as u64;
+        let h0 = h as u64;
+        Self {
+            h: [
+                h0,
+                h1,
+                h0 ^ h1,
+                h0.reverse_bits(),
+                h1.reverse_bits(),
+                (h0 ^ h1).reverse_bits(),
+            ],
+        }
+    }
+
+    /// Makes a table for inputs shorter than 128 bytes.
+    ///
+    /// This implementation only ever uses H, so this is `new`.
+    pub(crate) fn new_single_block(h: u128) -> Self {
+        Self::new(h)
+    }
+
+    fn mul(&self, y: u128) -> u128 {
+        let [h0, h1, h2, h0r, h1r, h2r] = self.h;
+        let y1 = (y >> 64) as u64;
+        let y0 = y as u64;
+        let y2 = y0 ^ y1;
+
+        // karatsuba, for the product and its bit-reversal (which
+        // gives the high halves of each 64x64 product)
+        let z0 = bmul64(y0, h0);
+        let z1 = bmul64(y1, h1);
+        let z2 = bmul64(y2, h2) ^ z0 ^ z1;
+        let z0h = bmul64(y0.reverse_bits(), h0r);
+        let z1h = bmul64(y1.reverse_bits(), h1r);
+        let z2h = bmul64(y2.reverse_bits(), h2r) ^ z0h ^ z1h;
+        let z0h = z0h.reverse_bits() >> 1;
+        let z1h = z1h.reverse_bits() >> 1;
+        let z2h = z2h.reverse_bits() >> 1;
+
+        // the 256-bit product, shifted left one bit (as GHASH is
+        // bit-reflected), then reduced
+        let v0 = z0;
+        let v1 = z0h ^ z2;
+        let v2 = z1 ^ z2h;
+        let v3 = z1h;
+
+        let v3 = (v3 << 1) | (v2 >> 63);
+        let v2 = (v2 << 1) | (v1 >> 63);
+        let v1 = (v1 << 1) | (v0 >> 63);
+        let v0 = v0 << 1;
+
+        let v2 = v2 ^ v0 ^ (v0 >> 1) ^ (v0 >> 2) ^ (v0 >> 7);
+        let v1 = v1 ^ (v0 << 63) ^ (v0 << 62) ^ (v0 << 57);
+        let v3 = v3 ^ v1 ^ (v1 >> 1) ^ (v1 >> 2) ^ (v1 >> 7);
+        let v2 = v2 ^ (v1 << 63) ^ (v1 << 62) ^ (v1 << 57);
+
+        ((v3 as u128) << 64) | v2 as u128
+    }
+}
+
+impl Drop for GhashTable {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.h);
     }
 }
 
@@ -19,6 +88,7 @@ pub(crate) struct Ghash<'a> {
     table: &'a GhashTable,
     current: u128,
 }
+
 impl<'a> Ghash<'a> {
     pub(crate) fn new(table: &'a GhashTable) -> Self {
         Self { table, current: 0 }
@@ -51,10 +121,67 @@ impl<'a> Ghash<'a> {
 
     fn one_block(&mut self, block: u128) {
         self.current ^= block;
-        self.current = mul(self.current, self.table.h);
+        self.current = self.table.mul(self.current);
+    }
+}
+
+/// Returns `a * b` in the GHASH field.
+///
+/// This is for combining GHASH values computed separately, so is
+/// not performance critical.
+pub(crate) fn gf128_mul(a: u128, b: u128) -> u128 {
+    GhashTable::new(a).mul(b)
+}
+
+/// Returns `h` to the power `n` in the GHASH field.
+///
+/// `n` is not secret.
+pub(crate) fn gf128_pow(h: u128, n: u64) -> u128 {
+    let mut result = GF128_ONE;
+    let mut base = h;
+    let mut n = n;
+
+    while n != 0 {
+        if n & 1 == 1 {
+            result = gf128_mul(result, base);
+        }
+        base = gf128_mul(base, base);
+        n >>= 1;
     }
+
+    low::zeroise_value(&mut base);
+    result
 }
 
+/// The multiplicative identity (GHASH is bit-reflected).
+const GF128_ONE: u128 = 1 << 127;
+
+/// Carryless 64x64 multiplication, truncated to 64 bits.
+///
+/// Each input is split into four, with bits spaced four apart: the
+/// gaps absorb the carries of the integer multiplications.
+fn bmul64(x: u64, y: u64) -> u64 {
+    const M0: u64 = 0x1111_1111_1111_1111;
+    const M1: u64 = 0x2222_2222_2222_2222;
+    const M2: u64 = 0x4444_4444_4444_4444;
+    const M3: u64 = 0x8888_8888_8888_8888;
+
+    let (x0, x1, x2, x3) = (x & M0, x & M1, x & M2, x & M3);
+    let (y0, y1, y2, y3) = (y & M0, y & M1, y & M2, y & M3);
+    let m = |a: u64, b: u64| a.wrapping_mul(b);
+
+    let z0 = m(x0, y0) ^ m(x1, y3) ^ m(x2, y2) ^ m(x3, y1);
+    let z1 = m(x0, y1) ^ m(x1, y0) ^ m(x2, y3) ^ m(x3, y2);
+    let z2 = m(x0, y2) ^ m(x1, y1) ^ m(x2, y0) ^ m(x3, y3);
+    let z3 = m(x0, y3) ^ m(x1, y2) ^ m(x2, y1) ^ m(x3, y0);
+
+    (z0 & M0) | (z1 & M1) | (z2 & M2) | (z3 & M3)
+}
+
+/// An extremely slow, by-the-book implementation.
+///
+/// Useful as a test model for faster implementations.
+#[cfg(test)]
 pub(crate) fn mul(x: u128, y: u128) -> u128 {
     let mut z = 0;
     let mut v = x;
@@ -70,12 +197,14 @@ pub(crate) fn mul(x: u128, y: u128) -> u128 {
     z
 }
 
+#[cfg(test)]
 fn double(a: u128) -> u128 {
     let mask = (a & 1).wrapping_neg();
     let b = a >> 1;
     b ^ (mask & R)
 }
 
+#[cfg(test)]
 const R: u128 = 0xe1000000_00000000_00000000_00000000;
 
 #[cfg(test)]
@@ -114,4 +243,33 @@ mod tests {
             &mul(x, y).to_be_bytes()
         );
     }
+
+    #[test]
+    fn test_table_mul() {
+        let mut x = 0x0388dace_60b6a392_f328c2b9_71b2fe78u128;
+        let mut y = 0x66e94bd4_ef8a2c3b_884cfa59_ca342b2eu128;
+        for _ in 0..256 {
+            assert_eq!(GhashTable::new(y).mul(x), mul(x, y));
+            x = x.rotate_left(7) ^ y;
+            y = y.wrapping_mul(0x9e3779b9_7f4a7c15_f39cc060_5cedc835) ^ 1;
+        }
+        assert_eq!(
+            GhashTable::new(u128::MAX).mul(u128::MAX),
+            mul(u128::MAX, u128::MAX)
+        );
+        assert_eq!(GhashTable::new(0).mul(u128::MAX), 0);
+    }
+
+    #[test]
+    fn test_pow() {
+        let h = 0x66e94bd4_ef8a2c3b_884cfa59_ca342b2eu128;
+        assert_eq!(gf128_pow(h, 0), GF128_ONE);
+        assert_eq!(gf128_mul(GF128_ONE, h), h);
+
+        let mut expect = GF128_ONE;
+        for n in 0..100 {
+            assert_eq!(gf128_pow(h, n), expect);
+            expect = mul(expect, h);
+        }
+    }
 }
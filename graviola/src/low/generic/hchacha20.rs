@@ -0,0 +1,81 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+// Originally from cifra
+
+/// The `HChaCha20` function, from draft-irtf-cfrg-xchacha section 2.2.
+///
+/// This derives a subkey from `key` and the 16-byte `nonce`.
+pub(crate) fn hchacha20(key: &[u8; 32], nonce: &[u8; 16]) -> [u8; 32] {
+    let words = |b: &[u8]| -> [u32; 4] {
+        core::array::from_fn(|i| u32::from_le_bytes(b[i * 4..i * 4 + 4].try_into().unwrap()))
+    };
+    let mut key0 = words(&key[..16]);
+    let mut key1 = words(&key[16..]);
+    hchacha(&mut key0, &mut key1, &words(nonce));
+
+    let mut out = [0u8; 32];
+    for (chunk, word) in out.chunks_exact_mut(4).zip(key0.iter().chain(key1.iter())) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    out
+}
+
+pub(super) fn hchacha(key0: &mut [u32; 4], key1: &mut [u32; 4], nonce: &[u32; 4]) {
+    let [mut z0, mut z1, mut z2, mut z3] = SIGMA;
+    let &mut [mut z4, mut z5, mut z6, mut z7] = key0;
+    let &mut [mut z8, mut z9, mut za, mut zb] = key1;
+    let &[mut zc, mut zd, mut ze, mut zf] = nonce;
+
+    macro_rules! quarter {
+        ($a:ident, $b:ident, $c:ident, $d:ident) => {
+            $a = $a.wrapping_add($b);
+            $d = ($d ^ $a).rotate_left(16);
+            $c = $c.wrapping_add($d);
+            $b = ($b ^ $c).rotate_left(12);
+            $a = $a.wrapping_add($b);
+            $d = ($d ^ $a).rotate_left(8);
+            $c = $c.wrapping_add($d);
+            $b = ($b ^ $c).rotate_left(7);
+        };
+    }
+
+    for _ in 0..10 {
+        quarter!(z0, z4, z8, zc);
+        quarter!(z1, z5, z9, zd);
+        quarter!(z2, z6, za, ze);
+        quarter!(z3, z7, zb, zf);
+        quarter!(z0, z5, za, zf);
+        quarter!(z1, z6, zb, zc);
+        quarter!(z2, z7, z8, zd);
+        quarter!(z3, z4, z9, ze);
+    }
+
+    *key0 = [z0, z1, z2, z3];
+    *key1 = [zc, zd, ze, zf];
+}
+
+// b"expand 32-byte k" in little-endian
+pub(super) const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector() {
+        // From draft-irtf-cfrg-xchacha-03 section 2.2.1
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let nonce = [
+            0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00, 0x31, 0x41,
+            0x59, 0x27,
+        ];
+        assert_eq!(
+            hchacha20(&key, &nonce),
+            [
+                0x82, 0x41, 0x3b, 0x42, 0x27, 0xb2, 0x7b, 0xfe, 0xd3, 0x0e, 0x42, 0x50, 0x8a, 0x87,
+                0x7d, 0x73, 0xa0, 0xf9, 0xe4, 0xd5, 0x8a, 0x74, 0xa8, 0x53, 0xc1, 0x2e, 0xc4, 0x13,
+                0x26, 0xd3, 0xec, 0xdc
+            ]
+        );
+    }
+}
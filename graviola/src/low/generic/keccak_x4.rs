@@ -0,0 +1,11 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+/// Apply Keccak-f\[1600\] to four independent states.
+///
+/// This processes each state in turn.
+pub(crate) fn keccak_f1600_x4(states: &mut [[u64; 25]; 4]) {
+    for state in states.iter_mut() {
+        crate::low::keccak_f1600(state);
+    }
+}
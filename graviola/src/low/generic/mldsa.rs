@@ -0,0 +1,161 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Polynomial arithmetic for ML-DSA, in the ring Z_q\[X\]/(X^256 + 1).
+//!
+//! This follows the structure of the Dilithium reference implementation:
+//! coefficients are signed 32-bit, products use Montgomery reduction
+//! with R = 2^32, and the NTT is the complete 8-layer transform
+//! from FIPS204.
+
+/// The ML-DSA modulus.
+pub(crate) const Q: i32 = 8380417;
+
+/// q^-1 mod 2^32
+const QINV: i32 = 58728449;
+
+/// Mont^2 / 256, which undoes the Montgomery factor from `pointwise_acc`
+/// and the scaling of the inverse NTT in one multiplication.
+const INV_NTT_SCALE: i64 = 41978;
+
+/// Powers of the primitive 512th root of unity 1753, in bit-reversed order and
+/// Montgomery form, centred around zero.
+const ZETAS: [i32; 256] = zetas();
+
+const fn zetas() -> [i32; 256] {
+    let mut r = [0i32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let rev = (i as u8).reverse_bits();
+        let mut z = 1i64;
+        let mut e = 0;
+        while e < rev {
+            z = (z * 1753) % Q as i64;
+            e += 1;
+        }
+        z = (z << 32) % Q as i64;
+        if z > Q as i64 / 2 {
+            z -= Q as i64;
+        }
+        r[i] = z as i32;
+        i += 1;
+    }
+    r
+}
+
+/// Returns `a * 2^-32 mod q`, in the range `(-q, q)`.
+///
+/// `a` must be in the range `-q * 2^31..q * 2^31`.
+#[inline]
+fn montgomery_reduce(a: i64) -> i32 {
+    let t = (a as i32).wrapping_mul(QINV);
+    ((a - t as i64 * Q as i64) >> 32) as i32
+}
+
+/// Returns a representative of `a mod q` in the range `-6283008..=6283008`.
+///
+/// `a` must be at most `2^31 - 2^22 - 1`.
+#[inline]
+pub(crate) fn reduce32(a: i32) -> i32 {
+    let t = (a + (1 << 22)) >> 23;
+    a - t * Q
+}
+
+/// Adds q if `a` is negative.
+#[inline]
+pub(crate) fn caddq(a: i32) -> i32 {
+    a + ((a >> 31) & Q)
+}
+
+/// Forward NTT, in place.
+///
+/// Input coefficients should be smaller than q in absolute value.
+/// Output coefficients are smaller than 9q in absolute value.
+pub(crate) fn ntt(a: &mut [i32; 256]) {
+    let mut k = 0;
+    let mut len = 128;
+    while len > 0 {
+        for start in (0..256).step_by(2 * len) {
+            k += 1;
+            let zeta = ZETAS[k] as i64;
+            for j in start..start + len {
+                let t = montgomery_reduce(zeta * a[j + len] as i64);
+                a[j + len] = a[j] - t;
+                a[j] += t;
+            }
+        }
+        len >>= 1;
+    }
+}
+
+/// Inverse NTT, in place, with an extra multiplication by 2^32.
+///
+/// Input coefficients should be smaller than q in absolute value.
+/// Output coefficients are also smaller than q in absolute value.
+pub(crate) fn inv_ntt(a: &mut [i32; 256]) {
+    let mut k = 256;
+    let mut len = 1;
+    while len < 256 {
+        for start in (0..256).step_by(2 * len) {
+            k -= 1;
+            let zeta = -ZETAS[k] as i64;
+            for j in start..start + len {
+                let t = a[j];
+                a[j] = t + a[j + len];
+                a[j + len] = montgomery_reduce(zeta * (t - a[j + len]) as i64);
+            }
+        }
+        len <<= 1;
+    }
+
+    for c in a.iter_mut() {
+        *c = montgomery_reduce(INV_NTT_SCALE * *c as i64);
+    }
+}
+
+/// Accumulates `a * b * 2^-32` into `r`, where `a` and `b` are in the NTT domain.
+pub(crate) fn pointwise_acc(r: &mut [i32; 256], a: &[i32; 256], b: &[i32; 256]) {
+    for ((r, a), b) in r.iter_mut().zip(a.iter()).zip(b.iter()) {
+        *r += montgomery_reduce(*a as i64 * *b as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zetas_match_reference() {
+        assert_eq!(
+            &ZETAS[1..9],
+            &[
+                25847, -2608894, -518909, 237124, -777960, -876248, 466468, 1826347
+            ]
+        );
+        assert_eq!(ZETAS[255], 1976782);
+    }
+
+    #[test]
+    fn multiplication() {
+        // (1 + X) * (1 + X^255) = 1 + X + X^255 + X^256 = X + X^255
+        let mut a = [0i32; 256];
+        a[0] = 1;
+        a[1] = 1;
+        let mut b = [0i32; 256];
+        b[0] = 1;
+        b[255] = 1;
+
+        ntt(&mut a);
+        ntt(&mut b);
+        let mut r = [0i32; 256];
+        pointwise_acc(&mut r, &a, &b);
+        inv_ntt(&mut r);
+
+        let mut expect = [0i32; 256];
+        expect[1] = 1;
+        expect[255] = 1;
+        for (x, y) in r.iter().zip(expect.iter()) {
+            assert_eq!(x.rem_euclid(Q), *y);
+        }
+    }
+}
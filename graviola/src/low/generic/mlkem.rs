@@ -0,0 +1,219 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Polynomial arithmetic for ML-KEM, in the ring Z_q\[X\]/(X^256 + 1).
+//!
+//! This follows the structure of the Kyber reference implementation:
+//! coefficients are signed 16-bit, products use Montgomery reduction
+//! with R = 2^16, and the NTT is the incomplete 7-layer transform
+//! from FIPS203, producing 128 degree-one residues.
+
+/// The ML-KEM modulus.
+pub(crate) const Q: i16 = 3329;
+
+/// q^-1 mod 2^16
+pub(crate) const QINV: i16 = -3327;
+
+/// Mont^2 / 128, which undoes the Montgomery factor from `basemul` and
+/// the scaling of the inverse NTT in one multiplication.
+pub(crate) const INV_NTT_SCALE: i16 = 1441;
+
+/// 2^32 mod q.
+const MONT_SQUARED: i16 = 1353;
+
+/// Floor of (2^26 + q / 2) / q, for Barrett reduction.
+pub(crate) const BARRETT_V: i16 = 20159;
+
+/// Powers of the primitive 256th root of unity 17, in bit-reversed order and
+/// Montgomery form, centred around zero.
+pub(crate) const ZETAS: [i16; 128] = zetas();
+
+const fn zetas() -> [i16; 128] {
+    let mut r = [0i16; 128];
+    let mut i = 0;
+    while i < 128 {
+        let rev = (i as u8).reverse_bits() >> 1;
+        let mut z = 1i32;
+        let mut e = 0;
+        while e < rev {
+            z = (z * 17) % Q as i32;
+            e += 1;
+        }
+        z = (z << 16) % Q as i32;
+        if z > Q as i32 / 2 {
+            z -= Q as i32;
+        }
+        r[i] = z as i16;
+        i += 1;
+    }
+    r
+}
+
+/// Returns `a * 2^-16 mod q`, in the range `(-q, q)`.
+///
+/// `a` must be in the range `-q * 2^15..q * 2^15`.
+#[inline]
+pub(crate) fn montgomery_reduce(a: i32) -> i16 {
+    let t = (a as i16).wrapping_mul(QINV);
+    ((a - t as i32 * Q as i32) >> 16) as i16
+}
+
+/// Returns `a * b * 2^-16 mod q`.
+#[inline]
+pub(crate) fn fqmul(a: i16, b: i16) -> i16 {
+    montgomery_reduce(a as i32 * b as i32)
+}
+
+/// Returns the centred representative of `a mod q`.
+#[inline]
+pub(crate) fn barrett_reduce(a: i16) -> i16 {
+    let t = ((BARRETT_V as i32 * a as i32 + (1 << 25)) >> 26) as i16;
+    a.wrapping_sub(t.wrapping_mul(Q))
+}
+
+/// Forward NTT, in place.
+///
+/// Input coefficients should be smaller than q in absolute value.
+/// Output coefficients are reduced by [`barrett_reduce`].
+#[cfg_attr(target_arch = "x86_64", allow(dead_code))]
+pub(crate) fn ntt(r: &mut [i16; 256]) {
+    let mut k = 1;
+    let mut len = 128;
+    while len >= 2 {
+        for start in (0..256).step_by(2 * len) {
+            let zeta = ZETAS[k];
+            k += 1;
+            for j in start..start + len {
+                let t = fqmul(zeta, r[j + len]);
+                r[j + len] = r[j].wrapping_sub(t);
+                r[j] = r[j].wrapping_add(t);
+            }
+        }
+        len >>= 1;
+    }
+    reduce(r);
+}
+
+/// Inverse NTT, in place, with an extra multiplication by 2^16.
+///
+/// Input coefficients should be smaller than q in absolute value.
+/// Output coefficients are smaller than q in absolute value.
+#[cfg_attr(target_arch = "x86_64", allow(dead_code))]
+pub(crate) fn inv_ntt(r: &mut [i16; 256]) {
+    let mut k = 127;
+    let mut len = 2;
+    while len <= 128 {
+        for start in (0..256).step_by(2 * len) {
+            let zeta = ZETAS[k];
+            k -= 1;
+            for j in start..start + len {
+                let t = r[j];
+                r[j] = barrett_reduce(t.wrapping_add(r[j + len]));
+                r[j + len] = fqmul(zeta, r[j + len].wrapping_sub(t));
+            }
+        }
+        len <<= 1;
+    }
+
+    for c in r.iter_mut() {
+        *c = fqmul(*c, INV_NTT_SCALE);
+    }
+}
+
+/// Accumulates `a * b * 2^-16` into `r`, where `a` and `b` are in the NTT domain.
+///
+/// Each output coefficient grows by less than 2q in absolute value.
+pub(crate) fn basemul_acc(r: &mut [i16; 256], a: &[i16; 256], b: &[i16; 256]) {
+    for i in 0..64 {
+        let zeta = ZETAS[64 + i];
+        let j = 4 * i;
+        basemul_pair(&mut r[j..j + 2], &a[j..j + 2], &b[j..j + 2], zeta);
+        basemul_pair(
+            &mut r[j + 2..j + 4],
+            &a[j + 2..j + 4],
+            &b[j + 2..j + 4],
+            -zeta,
+        );
+    }
+}
+
+/// Multiplication of `a0 + a1 X` by `b0 + b1 X`, modulo `X^2 - zeta`.
+#[inline]
+fn basemul_pair(r: &mut [i16], a: &[i16], b: &[i16], zeta: i16) {
+    let r0 = fqmul(fqmul(a[1], b[1]), zeta).wrapping_add(fqmul(a[0], b[0]));
+    let r1 = fqmul(a[0], b[1]).wrapping_add(fqmul(a[1], b[0]));
+    r[0] = r[0].wrapping_add(r0);
+    r[1] = r[1].wrapping_add(r1);
+}
+
+/// Applies [`barrett_reduce`] to every coefficient.
+pub(crate) fn reduce(r: &mut [i16; 256]) {
+    for c in r.iter_mut() {
+        *c = barrett_reduce(*c);
+    }
+}
+
+/// Multiplies every coefficient by 2^16, reducing the result into `(-q, q)`.
+pub(crate) fn to_mont(r: &mut [i16; 256]) {
+    for c in r.iter_mut() {
+        *c = montgomery_reduce(*c as i32 * MONT_SQUARED as i32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zetas_match_reference() {
+        assert_eq!(
+            &ZETAS[..8],
+            &[-1044, -758, -359, -1517, 1493, 1422, 287, 202]
+        );
+        assert_eq!(ZETAS[127], 1628);
+    }
+
+    #[test]
+    fn ntt_round_trip() {
+        let mut a = [0i16; 256];
+        for (i, c) in a.iter_mut().enumerate() {
+            *c = ((i * 1237) % 3329) as i16 - 1664;
+        }
+        let orig = a;
+
+        ntt(&mut a);
+        inv_ntt(&mut a);
+        // inv_ntt leaves a factor of 2^16
+        for (x, y) in a.iter().zip(orig.iter()) {
+            assert_eq!(
+                (*x as i32).rem_euclid(Q as i32),
+                (fqmul(*y, MONT_SQUARED) as i32).rem_euclid(Q as i32)
+            );
+        }
+    }
+
+    #[test]
+    fn multiplication() {
+        // (1 + X) * (1 + X^255) = 1 + X + X^255 + X^256 = X + X^255
+        let mut a = [0i16; 256];
+        a[0] = 1;
+        a[1] = 1;
+        let mut b = [0i16; 256];
+        b[0] = 1;
+        b[255] = 1;
+
+        ntt(&mut a);
+        ntt(&mut b);
+        let mut r = [0i16; 256];
+        basemul_acc(&mut r, &a, &b);
+        reduce(&mut r);
+        inv_ntt(&mut r);
+
+        let mut expect = [0i16; 256];
+        expect[1] = 1;
+        expect[255] = 1;
+        for (x, y) in r.iter().zip(expect.iter()) {
+            assert_eq!((*x as i32).rem_euclid(Q as i32), *y as i32);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Portable arithmetic for NIST P-256.
+//!
+//! See `weierstrass` for the conventions used for points.
+
+use super::bignum::{bignum_cmp_lt, bignum_optsub, negmodinv_word, select_row};
+use super::weierstrass::Field;
+
+const P256: [u64; 4] = [
+    0xffffffffffffffff,
+    0x00000000ffffffff,
+    0x0000000000000000,
+    0xffffffff00000001,
+];
+
+const N256: [u64; 4] = [
+    0xf3b9cac2fc632551,
+    0xbce6faada7179e84,
+    0xffffffffffffffff,
+    0xffffffff00000000,
+];
+
+const FIELD: Field<4> = Field {
+    p: P256,
+    w: negmodinv_word(P256[0]),
+    rr: [
+        0x0000000000000003,
+        0xfffffffbffffffff,
+        0xfffffffffffffffe,
+        0x00000004fffffffd,
+    ],
+    one: [
+        0x0000000000000001,
+        0xffffffff00000000,
+        0xffffffffffffffff,
+        0x00000000fffffffe,
+    ],
+};
+
+/// Add modulo p_256, z := (x + y) mod p_256, assuming x and y reduced
+pub(crate) fn bignum_add_p256(z: &mut [u64; 4], x: &[u64; 4], y: &[u64; 4]) {
+    *z = FIELD.add(x, y);
+}
+
+/// Convert from Montgomery form z := (x / 2^256) mod p_256, assuming x reduced
+pub(crate) fn bignum_demont_p256(z: &mut [u64; 4], x: &[u64; 4]) {
+    *z = FIELD.demont(x);
+}
+
+/// Modular inverse modulo p_256
+///
+/// If the input is divisible by p_256 (ie. is 0 or p_256), z = 0 is returned.
+pub(crate) fn bignum_inv_p256(z: &mut [u64; 4], x: &[u64; 4]) {
+    *z = FIELD.inv(x);
+}
+
+/// Reduce modulo group order, z := x mod n_256
+pub(crate) fn bignum_mod_n256(z: &mut [u64; 4], x: &[u64; 4]) {
+    // n_256 > 2^255, so at most one subtraction is needed
+    bignum_optsub(z, x, &N256, bignum_cmp_lt(x, &N256) ^ 1);
+}
+
+/// Montgomery multiply, z := (x * y / 2^256) mod p_256
+pub(crate) fn bignum_montmul_p256(z: &mut [u64; 4], x: &[u64; 4], y: &[u64; 4]) {
+    *z = FIELD.mul(x, y);
+}
+
+/// Montgomery square, z := (x^2 / 2^256) mod p_256
+pub(crate) fn bignum_montsqr_p256(z: &mut [u64; 4], x: &[u64; 4]) {
+    *z = FIELD.sqr(x);
+}
+
+/// Negate modulo p_256, z := (-x) mod p_256, assuming x reduced
+pub(crate) fn bignum_neg_p256(z: &mut [u64; 4], x: &[u64; 4]) {
+    *z = FIELD.neg(x);
+}
+
+/// Convert to Montgomery form z := (2^256 * x) mod p_256
+pub(crate) fn bignum_tomont_p256(z: &mut [u64; 4], x: &[u64; 4]) {
+    *z = FIELD.tomont(x);
+}
+
+/// Viewing table as rows of 8 words width, copy the 8 words at
+/// table[idx - 1] into z.  If `idx` is zero or larger than `height`,
+/// `z` is set to zero (ie, a affine point at infinity).
+pub(crate) fn bignum_aff_point_select_p256(z: &mut [u64; 8], table: &[u64], index: u8) {
+    select_row(z, table, index);
+}
+
+/// Viewing table as rows of 12 words width, copy the 12 words at
+/// table[idx - 1] into z.  If `idx` is zero or larger than `height`,
+/// `z` is set to zero (ie, a jacobian point at infinity).
+pub(crate) fn bignum_jac_point_select_p256(z: &mut [u64; 12], table: &[u64], index: u8) {
+    select_row(z, table, index);
+}
+
+/// Point addition on NIST curve P-256 in Montgomery-Jacobian coordinates
+pub(crate) fn p256_montjadd(p3: &mut [u64; 12], p1: &[u64; 12], p2: &[u64; 12]) {
+    FIELD.jadd(p3, p1, p2);
+}
+
+/// Point doubling on NIST curve P-256 in Montgomery-Jacobian coordinates
+pub(crate) fn p256_montjdouble(p3: &mut [u64; 12], p1: &[u64; 12]) {
+    FIELD.jdouble(p3, p1);
+}
+
+/// Point mixed addition on NIST curve P-256 in Montgomery-Jacobian coordinates
+///
+/// p2 only has x and y coordinates, with the implicit z coordinate
+/// assumed to be the identity.
+pub(crate) fn p256_montjmixadd(p3: &mut [u64; 12], p1: &[u64; 12], p2: &[u64; 8]) {
+    FIELD.jmixadd(p3, p1, p2);
+}
+
+#[cfg(all(
+    test,
+    not(feature = "force-generic"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod tests {
+    //! These test against the assembly implementations.
+    use super::super::bignum::bignum_montifier;
+    use super::super::bignum::tests::Rng;
+    use super::*;
+    use crate::low;
+
+    fn element(rng: &mut Rng) -> [u64; 4] {
+        loop {
+            let x: [u64; 4] = rng.words(4).try_into().unwrap();
+            if bignum_cmp_lt(&x, &P256) == 1 {
+                return x;
+            }
+        }
+    }
+
+    fn point(rng: &mut Rng) -> [u64; 12] {
+        let mut p = [0; 12];
+        p[..4].copy_from_slice(&element(rng));
+        p[4..8].copy_from_slice(&element(rng));
+        match rng.next() % 8 {
+            0 => {}
+            1 => p[8..].copy_from_slice(&FIELD.one),
+            _ => p[8..].copy_from_slice(&element(rng)),
+        }
+        p
+    }
+
+    #[test]
+    fn constants() {
+        let mut rr = [0; 4];
+        bignum_montifier(&mut rr, &P256, &mut [0; 12]);
+        assert_eq!(rr, FIELD.rr);
+
+        let mut one = [0; 4];
+        low::bignum_tomont_p256(&mut one, &[1, 0, 0, 0]);
+        assert_eq!(one, FIELD.one);
+    }
+
+    #[test]
+    fn field() {
+        let mut rng = Rng(256);
+        for _ in 0..1000 {
+            let x = element(&mut rng);
+            let y = element(&mut rng);
+            let any: [u64; 4] = rng.words(4).try_into().unwrap();
+
+            let mut expect = [0; 4];
+            let mut got = [0; 4];
+
+            low::bignum_add_p256(&mut expect, &x, &y);
+            bignum_add_p256(&mut got, &x, &y);
+            assert_eq!(expect, got);
+            low::bignum_demont_p256(&mut expect, &x);
+            bignum_demont_p256(&mut got, &x);
+            assert_eq!(expect, got);
+            low::bignum_inv_p256(&mut expect, &any);
+            bignum_inv_p256(&mut got, &any);
+            assert_eq!(expect, got);
+            low::bignum_mod_n256(&mut expect, &any);
+            bignum_mod_n256(&mut got, &any);
+            assert_eq!(expect, got);
+            low::bignum_montmul_p256(&mut expect, &x, &y);
+            bignum_montmul_p256(&mut got, &x, &y);
+            assert_eq!(expect, got);
+            low::bignum_montsqr_p256(&mut expect, &x);
+            bignum_montsqr_p256(&mut got, &x);
+            assert_eq!(expect, got);
+            low::bignum_neg_p256(&mut expect, &x);
+            bignum_neg_p256(&mut got, &x);
+            assert_eq!(expect, got);
+            low::bignum_tomont_p256(&mut expect, &any);
+            bignum_tomont_p256(&mut got, &any);
+            assert_eq!(expect, got);
+        }
+
+        let mut z = [1; 4];
+        bignum_inv_p256(&mut z, &P256);
+        assert_eq!(z, [0; 4]);
+    }
+
+    #[test]
+    fn points() {
+        let mut rng = Rng(257);
+        for _ in 0..1000 {
+            let p1 = point(&mut rng);
+            let p2 = point(&mut rng);
+            let p2_affine: [u64; 8] = p2[..8].try_into().unwrap();
+
+            let mut expect = [0; 12];
+            let mut got = [0; 12];
+
+            low::p256_montjadd(&mut expect, &p1, &p2);
+            p256_montjadd(&mut got, &p1, &p2);
+            assert_eq!(expect, got);
+            low::p256_montjdouble(&mut expect, &p1);
+            p256_montjdouble(&mut got, &p1);
+            assert_eq!(expect, got);
+            low::p256_montjmixadd(&mut expect, &p1, &p2_affine);
+            p256_montjmixadd(&mut got, &p1, &p2_affine);
+            assert_eq!(expect, got);
+        }
+    }
+
+    #[test]
+    fn select() {
+        let mut rng = Rng(258);
+        let table = rng.words(12 * 16);
+        for index in 0..=17 {
+            let mut expect = [0; 12];
+            let mut got = [0; 12];
+            low::bignum_jac_point_select_p256(&mut expect, &table, index);
+            bignum_jac_point_select_p256(&mut got, &table, index);
+            assert_eq!(expect, got);
+
+            let mut expect = [0; 8];
+            let mut got = [0; 8];
+            low::bignum_aff_point_select_p256(&mut expect, &table[..8 * 16], index);
+            bignum_aff_point_select_p256(&mut got, &table[..8 * 16], index);
+            assert_eq!(expect, got);
+        }
+    }
+}
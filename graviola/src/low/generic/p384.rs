@@ -0,0 +1,227 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Portable arithmetic for NIST P-384.
+//!
+//! See `weierstrass` for the conventions used for points.
+
+use super::bignum::{bignum_cmp_lt, bignum_optsub, negmodinv_word, select_row};
+use super::weierstrass::Field;
+
+const P384: [u64; 6] = [
+    0x00000000ffffffff,
+    0xffffffff00000000,
+    0xfffffffffffffffe,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+];
+
+const N384: [u64; 6] = [
+    0xecec196accc52973,
+    0x581a0db248b0a77a,
+    0xc7634d81f4372ddf,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+];
+
+const FIELD: Field<6> = Field {
+    p: P384,
+    w: negmodinv_word(P384[0]),
+    rr: [
+        0xfffffffe00000001,
+        0x0000000200000000,
+        0xfffffffe00000000,
+        0x0000000200000000,
+        0x0000000000000001,
+        0x0000000000000000,
+    ],
+    one: [
+        0xffffffff00000001,
+        0x00000000ffffffff,
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+};
+
+/// Add modulo p_384, z := (x + y) mod p_384, assuming x and y reduced
+pub(crate) fn bignum_add_p384(z: &mut [u64; 6], x: &[u64; 6], y: &[u64; 6]) {
+    *z = FIELD.add(x, y);
+}
+
+/// Convert from Montgomery form z := (x / 2^384) mod p_384, assuming x reduced
+pub(crate) fn bignum_demont_p384(z: &mut [u64; 6], x: &[u64; 6]) {
+    *z = FIELD.demont(x);
+}
+
+/// Modular inverse modulo p_384
+///
+/// If the input is divisible by p_384 (ie. is 0 or p_384), z = 0 is returned.
+pub(crate) fn bignum_inv_p384(z: &mut [u64; 6], x: &[u64; 6]) {
+    *z = FIELD.inv(x);
+}
+
+/// Reduce modulo group order, z := x mod n_384
+pub(crate) fn bignum_mod_n384(z: &mut [u64; 6], x: &[u64; 6]) {
+    // n_384 > 2^383, so at most one subtraction is needed
+    bignum_optsub(z, x, &N384, bignum_cmp_lt(x, &N384) ^ 1);
+}
+
+/// Montgomery multiply, z := (x * y / 2^384) mod p_384
+pub(crate) fn bignum_montmul_p384(z: &mut [u64; 6], x: &[u64; 6], y: &[u64; 6]) {
+    *z = FIELD.mul(x, y);
+}
+
+/// Montgomery square, z := (x^2 / 2^384) mod p_384
+pub(crate) fn bignum_montsqr_p384(z: &mut [u64; 6], x: &[u64; 6]) {
+    *z = FIELD.sqr(x);
+}
+
+/// Negate modulo p_384, z := (-x) mod p_384, assuming x reduced
+pub(crate) fn bignum_neg_p384(z: &mut [u64; 6], x: &[u64; 6]) {
+    *z = FIELD.neg(x);
+}
+
+/// Convert to Montgomery form z := (2^384 * x) mod p_384
+pub(crate) fn bignum_tomont_p384(z: &mut [u64; 6], x: &[u64; 6]) {
+    *z = FIELD.tomont(x);
+}
+
+/// Viewing table as rows of 18 words width, copy the 18 words at
+/// table[idx - 1] into z.  If `idx` is zero or larger than `height`,
+/// `z` is set to zero (ie, a jacobian point at infinity).
+pub(crate) fn bignum_jac_point_select_p384(z: &mut [u64; 18], table: &[u64], index: u8) {
+    select_row(z, table, index);
+}
+
+/// Point addition on NIST curve P-384 in Montgomery-Jacobian coordinates
+pub(crate) fn p384_montjadd(p3: &mut [u64; 18], p1: &[u64; 18], p2: &[u64; 18]) {
+    FIELD.jadd(p3, p1, p2);
+}
+
+/// Point doubling on NIST curve P-384 in Montgomery-Jacobian coordinates
+pub(crate) fn p384_montjdouble(p3: &mut [u64; 18], p1: &[u64; 18]) {
+    FIELD.jdouble(p3, p1);
+}
+
+#[cfg(all(
+    test,
+    not(feature = "force-generic"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod tests {
+    //! These test against the assembly implementations.
+    use super::super::bignum::bignum_montifier;
+    use super::super::bignum::tests::Rng;
+    use super::*;
+    use crate::low;
+
+    fn element(rng: &mut Rng) -> [u64; 6] {
+        loop {
+            let x: [u64; 6] = rng.words(6).try_into().unwrap();
+            if bignum_cmp_lt(&x, &P384) == 1 {
+                return x;
+            }
+        }
+    }
+
+    fn point(rng: &mut Rng) -> [u64; 18] {
+        let mut p = [0; 18];
+        p[..6].copy_from_slice(&element(rng));
+        p[6..12].copy_from_slice(&element(rng));
+        match rng.next() % 8 {
+            0 => {}
+            1 => p[12..].copy_from_slice(&FIELD.one),
+            _ => p[12..].copy_from_slice(&element(rng)),
+        }
+        p
+    }
+
+    #[test]
+    fn constants() {
+        let mut rr = [0; 6];
+        bignum_montifier(&mut rr, &P384, &mut [0; 18]);
+        assert_eq!(rr, FIELD.rr);
+
+        let mut one = [0; 6];
+        low::bignum_tomont_p384(&mut one, &[1, 0, 0, 0, 0, 0]);
+        assert_eq!(one, FIELD.one);
+    }
+
+    #[test]
+    fn field() {
+        let mut rng = Rng(384);
+        for _ in 0..1000 {
+            let x = element(&mut rng);
+            let y = element(&mut rng);
+            let any: [u64; 6] = rng.words(6).try_into().unwrap();
+
+            let mut expect = [0; 6];
+            let mut got = [0; 6];
+
+            low::bignum_add_p384(&mut expect, &x, &y);
+            bignum_add_p384(&mut got, &x, &y);
+            assert_eq!(expect, got);
+            low::bignum_demont_p384(&mut expect, &x);
+            bignum_demont_p384(&mut got, &x);
+            assert_eq!(expect, got);
+            low::bignum_inv_p384(&mut expect, &any);
+            bignum_inv_p384(&mut got, &any);
+            assert_eq!(expect, got);
+            low::bignum_mod_n384(&mut expect, &any);
+            bignum_mod_n384(&mut got, &any);
+            assert_eq!(expect, got);
+            low::bignum_montmul_p384(&mut expect, &x, &y);
+            bignum_montmul_p384(&mut got, &x, &y);
+            assert_eq!(expect, got);
+            low::bignum_montsqr_p384(&mut expect, &x);
+            bignum_montsqr_p384(&mut got, &x);
+            assert_eq!(expect, got);
+            low::bignum_neg_p384(&mut expect, &x);
+            bignum_neg_p384(&mut got, &x);
+            assert_eq!(expect, got);
+            low::bignum_tomont_p384(&mut expect, &any);
+            bignum_tomont_p384(&mut got, &any);
+            assert_eq!(expect, got);
+        }
+
+        let mut z = [1; 6];
+        bignum_inv_p384(&mut z, &P384);
+        assert_eq!(z, [0; 6]);
+    }
+
+    #[test]
+    fn points() {
+        let mut rng = Rng(385);
+        for _ in 0..1000 {
+            let p1 = point(&mut rng);
+            let p2 = point(&mut rng);
+
+            let mut expect = [0; 18];
+            let mut got = [0; 18];
+
+            low::p384_montjadd(&mut expect, &p1, &p2);
+            p384_montjadd(&mut got, &p1, &p2);
+            assert_eq!(expect, got);
+            low::p384_montjdouble(&mut expect, &p1);
+            p384_montjdouble(&mut got, &p1);
+            assert_eq!(expect, got);
+        }
+    }
+
+    #[test]
+    fn select() {
+        let mut rng = Rng(386);
+        let table = rng.words(18 * 16);
+        for index in 0..=17 {
+            let mut expect = [0; 18];
+            let mut got = [0; 18];
+            low::bignum_jac_point_select_p384(&mut expect, &table, index);
+            bignum_jac_point_select_p384(&mut got, &table, index);
+            assert_eq!(expect, got);
+        }
+    }
+}
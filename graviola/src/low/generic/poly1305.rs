@@ -55,6 +55,12 @@ impl Poly1305 {
             self.process_whole_block(&block);
         }
 
+        #[cfg(all(
+            not(feature = "force-generic"),
+            any(target_arch = "x86_64", target_arch = "aarch64")
+        ))]
+        let bytes = crate::low::poly1305_blocks(&mut self.h, &self.r, bytes);
+
         let mut full_blocks = bytes.chunks_exact(16);
         for block in full_blocks.by_ref() {
             self.process_whole_block(block.try_into().unwrap());
@@ -98,6 +104,21 @@ impl Poly1305 {
         r
     }
 
+    /// Returns the accumulator and key, so that whole blocks can be
+    /// processed elsewhere.
+    ///
+    /// Returns `None` if a partial block is buffered.
+    #[cfg_attr(
+        any(feature = "force-generic", not(target_arch = "x86_64")),
+        allow(dead_code)
+    )]
+    pub(crate) fn whole_block_state(&mut self) -> Option<(&mut [u32; 5], &[u32; 5])> {
+        match self.bw.used() {
+            0 => Some((&mut self.h, &self.r)),
+            _ => None,
+        }
+    }
+
     fn process_whole_block(&mut self, inp: &[u8; 16]) {
         let block = [
             read32(&inp[0..4]) & 0x3ff_ffff,
@@ -142,7 +163,7 @@ fn add(h: &mut [u32; 5], x: &[u32; 5]) {
     h[4] = h[4].wrapping_add(x[4]);
 }
 
-fn mul(h: &mut [u32; 5], r: &[u32; 5], s: &[u32; 4]) {
+pub(crate) fn mul(h: &mut [u32; 5], r: &[u32; 5], s: &[u32; 4]) {
     fn mul32(a: u32, b: u32) -> u64 {
         u64::from(a) * u64::from(b)
     }
@@ -0,0 +1,15 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+/// Compress `blocks[i]` into `states[i]`, for eight independent lanes.
+///
+/// This processes each lane in turn.  It is used where the
+/// single-message implementation is already hardware-accelerated
+/// to the point that processing lanes in lockstep brings no benefit.
+pub(crate) fn sha256_compress_blocks_x8(states: &mut [[u32; 8]; 8], blocks: [&[u8]; 8]) {
+    for (state, blocks) in states.iter_mut().zip(blocks) {
+        if !blocks.is_empty() {
+            crate::low::sha256_compress_blocks(state, blocks);
+        }
+    }
+}
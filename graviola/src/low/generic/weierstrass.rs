@@ -0,0 +1,229 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Portable field and point arithmetic for the NIST prime curves.
+//!
+//! Points are Jacobian triples (x, y, z), representing the affine
+//! point (x/z^2, y/z^3), with each coordinate in the Montgomery domain.
+//! z = 0 is the point at infinity.  The curve must have a = -3.
+
+use super::bignum::{adc, montmul, nonzero_mask, reduce_once, sbb};
+
+pub(super) struct Field<const N: usize> {
+    /// The prime.
+    pub(super) p: [u64; N],
+    /// -1/p mod 2^64
+    pub(super) w: u64,
+    /// 2^{128N} mod p
+    pub(super) rr: [u64; N],
+    /// 2^{64N} mod p, ie. one in the Montgomery domain
+    pub(super) one: [u64; N],
+}
+
+impl<const N: usize> Field<N> {
+    pub(super) fn mul(&self, x: &[u64; N], y: &[u64; N]) -> [u64; N] {
+        let mut z = [0; N];
+        montmul(&mut z, x, y, &self.p, self.w);
+        z
+    }
+
+    pub(super) fn sqr(&self, x: &[u64; N]) -> [u64; N] {
+        self.mul(x, x)
+    }
+
+    pub(super) fn add(&self, x: &[u64; N], y: &[u64; N]) -> [u64; N] {
+        let mut z = [0; N];
+        let mut carry = 0;
+        for i in 0..N {
+            (z[i], carry) = adc(x[i], y[i], carry);
+        }
+        reduce_once(&mut z, carry, &self.p);
+        z
+    }
+
+    pub(super) fn sub(&self, x: &[u64; N], y: &[u64; N]) -> [u64; N] {
+        let mut z = [0; N];
+        let mut borrow = 0;
+        for i in 0..N {
+            (z[i], borrow) = sbb(x[i], y[i], borrow);
+        }
+        let mask = borrow.wrapping_neg();
+        let mut carry = 0;
+        for (zi, pi) in z.iter_mut().zip(self.p.iter()) {
+            (*zi, carry) = adc(*zi, pi & mask, carry);
+        }
+        z
+    }
+
+    pub(super) fn neg(&self, x: &[u64; N]) -> [u64; N] {
+        let mut z = self.sub(&[0; N], x);
+        // x < p, so this only underflows for x == 0, giving p
+        let mask = nonzero_mask(or_words(x));
+        for zi in z.iter_mut() {
+            *zi &= mask;
+        }
+        z
+    }
+
+    /// z := (2^{64N} * x) mod p, for any x
+    pub(super) fn tomont(&self, x: &[u64; N]) -> [u64; N] {
+        self.mul(x, &self.rr)
+    }
+
+    /// z := (x / 2^{64N}) mod p, for any x
+    pub(super) fn demont(&self, x: &[u64; N]) -> [u64; N] {
+        let mut one = [0; N];
+        one[0] = 1;
+        self.mul(x, &one)
+    }
+
+    /// Multiplicative inverse; zero if x is a multiple of p.
+    ///
+    /// x need not be reduced.
+    pub(super) fn inv(&self, x: &[u64; N]) -> [u64; N] {
+        // reduce x (p is at least 2^{64N-1}), then x^(p-2) by Fermat
+        let mut x = *x;
+        reduce_once(&mut x, 0, &self.p);
+        let x = self.tomont(&x);
+
+        let mut e = self.p;
+        let (e0, borrow) = sbb(e[0], 2, 0);
+        e[0] = e0;
+        debug_assert_eq!(borrow, 0);
+
+        // the exponent is public, so this need not be constant-time
+        // in it.
+        let mut acc = self.one;
+        for i in (0..64 * N).rev() {
+            acc = self.sqr(&acc);
+            if (e[i / 64] >> (i % 64)) & 1 == 1 {
+                acc = self.mul(&acc, &x);
+            }
+        }
+        self.demont(&acc)
+    }
+
+    fn is_zero_mask(&self, x: &[u64; N]) -> u64 {
+        !nonzero_mask(or_words(x))
+    }
+
+    /// Point doubling.
+    ///
+    /// This is "dbl-2001-b" from the explicit-formulas database.
+    pub(super) fn jdouble(&self, p3: &mut [u64], p1: &[u64]) {
+        let (x1, y1, z1) = split3::<N>(p1);
+
+        let delta = self.sqr(&z1);
+        let gamma = self.sqr(&y1);
+        let beta = self.mul(&x1, &gamma);
+        let t = self.mul(&self.sub(&x1, &delta), &self.add(&x1, &delta));
+        let alpha = self.add(&self.add(&t, &t), &t);
+        let beta4 = self.add(&beta, &beta);
+        let beta4 = self.add(&beta4, &beta4);
+        let beta8 = self.add(&beta4, &beta4);
+
+        let x3 = self.sub(&self.sqr(&alpha), &beta8);
+        let z3 = self.sub(&self.sub(&self.sqr(&self.add(&y1, &z1)), &gamma), &delta);
+        let gamma2 = self.sqr(&gamma);
+        let gamma2_4 = self.add(&gamma2, &gamma2);
+        let gamma2_4 = self.add(&gamma2_4, &gamma2_4);
+        let gamma2_8 = self.add(&gamma2_4, &gamma2_4);
+        let y3 = self.sub(&self.mul(&alpha, &self.sub(&beta4, &x3)), &gamma2_8);
+
+        join3(p3, &x3, &y3, &z3);
+    }
+
+    /// Point addition.
+    ///
+    /// This handles either input being the point at infinity, but
+    /// not p1 == p2.
+    pub(super) fn jadd(&self, p3: &mut [u64], p1: &[u64], p2: &[u64]) {
+        let (x1, y1, z1) = split3::<N>(p1);
+        let (x2, y2, z2) = split3::<N>(p2);
+
+        let z1z1 = self.sqr(&z1);
+        let z2z2 = self.sqr(&z2);
+        let u1 = self.mul(&x1, &z2z2);
+        let u2 = self.mul(&x2, &z1z1);
+        let s1 = self.mul(&self.mul(&y1, &z2), &z2z2);
+        let s2 = self.mul(&self.mul(&y2, &z1), &z1z1);
+        let z3 = self.mul(&z1, &z2);
+
+        let (x3, y3, z3) = self.add_common(&u1, &u2, &s1, &s2, &z3);
+        join3(p3, &x3, &y3, &z3);
+
+        let p1_zero = self.is_zero_mask(&z1);
+        let p2_zero = self.is_zero_mask(&z2);
+        select(p3, p2, p1_zero & !p2_zero);
+        select(p3, p1, p2_zero & !p1_zero);
+    }
+
+    /// Point addition, where p2 is affine (its z coordinate is one).
+    ///
+    /// This handles p1 being the point at infinity, but not p1 == p2.
+    pub(super) fn jmixadd(&self, p3: &mut [u64], p1: &[u64], p2: &[u64]) {
+        let (x1, y1, z1) = split3::<N>(p1);
+        let x2: [u64; N] = p2[..N].try_into().unwrap();
+        let y2: [u64; N] = p2[N..2 * N].try_into().unwrap();
+
+        let z1z1 = self.sqr(&z1);
+        let u2 = self.mul(&x2, &z1z1);
+        let s2 = self.mul(&self.mul(&y2, &z1), &z1z1);
+
+        let (x3, y3, z3) = self.add_common(&x1, &u2, &y1, &s2, &z1);
+        join3(p3, &x3, &y3, &z3);
+
+        let mut p2_jac = [0; 18];
+        let p2_jac = &mut p2_jac[..3 * N];
+        join3(p2_jac, &x2, &y2, &self.one);
+        select(p3, p2_jac, self.is_zero_mask(&z1));
+    }
+
+    /// The common tail of addition, with `z` being z1 * z2.
+    #[allow(clippy::type_complexity)]
+    fn add_common(
+        &self,
+        u1: &[u64; N],
+        u2: &[u64; N],
+        s1: &[u64; N],
+        s2: &[u64; N],
+        z: &[u64; N],
+    ) -> ([u64; N], [u64; N], [u64; N]) {
+        let h = self.sub(u2, u1);
+        let r = self.sub(s2, s1);
+        let hh = self.sqr(&h);
+        let hhh = self.mul(&h, &hh);
+        let v = self.mul(u1, &hh);
+
+        let x3 = self.sub(&self.sub(&self.sqr(&r), &hhh), &self.add(&v, &v));
+        let y3 = self.sub(&self.mul(&r, &self.sub(&v, &x3)), &self.mul(s1, &hhh));
+        let z3 = self.mul(z, &h);
+        (x3, y3, z3)
+    }
+}
+
+fn or_words(x: &[u64]) -> u64 {
+    x.iter().fold(0, |acc, xi| acc | xi)
+}
+
+/// z := x if `mask` is all-ones
+fn select(z: &mut [u64], x: &[u64], mask: u64) {
+    for (zi, xi) in z.iter_mut().zip(x) {
+        *zi = (xi & mask) | (*zi & !mask);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn split3<const N: usize>(p: &[u64]) -> ([u64; N], [u64; N], [u64; N]) {
+    (
+        p[..N].try_into().unwrap(),
+        p[N..2 * N].try_into().unwrap(),
+        p[2 * N..3 * N].try_into().unwrap(),
+    )
+}
+
+fn join3<const N: usize>(p: &mut [u64], x: &[u64; N], y: &[u64; N], z: &[u64; N]) {
+    p[..N].copy_from_slice(x);
+    p[N..2 * N].copy_from_slice(y);
+    p[2 * N..3 * N].copy_from_slice(z);
+}
@@ -1,7 +1,9 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
-use core::mem::{size_of, size_of_val};
+use core::fmt;
+use core::mem::{ManuallyDrop, size_of, size_of_val};
+use core::ops::{Deref, DerefMut};
 
 use crate::low::zero_bytes;
 
@@ -15,11 +17,76 @@ pub(crate) fn zeroise_value<T: Zeroable>(v: &mut T) {
     zero_bytes(v as *mut T as *mut _, size_of::<T>());
 }
 
+/// Writes zeroes over the whole of `v`, once it has been dropped.
+///
+/// This reaches what the `Drop` impls of `v`'s fields cannot: for
+/// an enum, its tag and any padding past a smaller variant.
+///
+/// # Safety
+/// All-bits-zero need not be a valid `T`, so `v` must not be used
+/// (or dropped) again.
+pub(crate) unsafe fn zeroise_dropped<T>(v: &mut T) {
+    zero_bytes(v as *mut T as *mut _, size_of::<T>());
+}
+
+/// Holds a `T`, and writes zeroes over the whole of it when dropped.
+///
+/// This is for types whose storage is not all reachable by their own
+/// `Drop` impl: for example, the uninitialised space in an empty `OnceLock`.
+pub(crate) struct ZeroOnDrop<T>(ManuallyDrop<T>);
+
+impl<T> ZeroOnDrop<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self(ManuallyDrop::new(value))
+    }
+}
+
+impl<T> Deref for ZeroOnDrop<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for ZeroOnDrop<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Clone> Clone for ZeroOnDrop<T> {
+    fn clone(&self) -> Self {
+        Self::new(T::clone(self))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ZeroOnDrop<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+impl<T> Drop for ZeroOnDrop<T> {
+    fn drop(&mut self) {
+        // SAFETY: `self.0` is not used again
+        unsafe {
+            ManuallyDrop::drop(&mut self.0);
+            zeroise_dropped(&mut self.0);
+        }
+    }
+}
+
 /// Marker trait for types who have valid all-bits-zero values.
 pub(crate) trait Zeroable {}
 
 impl Zeroable for u8 {}
+impl Zeroable for i16 {}
+impl Zeroable for u16 {}
+impl Zeroable for i32 {}
+impl Zeroable for u32 {}
 impl Zeroable for u64 {}
+impl Zeroable for u128 {}
 impl Zeroable for usize {}
 
 #[cfg(target_arch = "x86_64")]
@@ -27,6 +94,9 @@ impl Zeroable for core::arch::x86_64::__m256i {}
 #[cfg(target_arch = "x86_64")]
 impl Zeroable for core::arch::x86_64::__m128i {}
 
+#[cfg(target_arch = "x86")]
+impl Zeroable for core::arch::x86::__m128i {}
+
 #[cfg(target_arch = "aarch64")]
 impl Zeroable for core::arch::aarch64::uint8x16_t {}
 #[cfg(target_arch = "aarch64")]
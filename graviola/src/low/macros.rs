@@ -11,6 +11,7 @@ macro_rules! Q {
     };
 }
 
+#[allow(unused_imports)]
 pub(crate) use Q;
 
 /// Label macro, which just resolves to the id as a string,
@@ -36,6 +37,7 @@ macro_rules! Label {
     }
 }
 
+#[allow(unused_imports)]
 pub(crate) use Label;
 
 /// Plasters over the difference between ELF and Mach-O relocation
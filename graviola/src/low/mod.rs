@@ -1,49 +1,117 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
+// these are for the assembly backends
+#[cfg_attr(
+    any(
+        feature = "force-generic",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ),
+    allow(unused_macros)
+)]
 #[macro_use]
 mod macros;
 
 pub(crate) mod ct;
+pub(crate) mod dispatch;
 
 #[cfg(doc)]
 pub mod inline_assembly_safety;
 
 mod generic {
+    pub(super) mod aes;
+    pub(crate) mod aes_gcm;
+    #[cfg(any(
+        test,
+        feature = "force-generic",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ))]
+    pub(super) mod bignum;
+    pub(super) mod blake2;
     pub(super) mod blockwise;
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(feature = "force-generic", not(target_arch = "x86_64")))]
     pub(crate) mod chacha20;
+    #[cfg(any(feature = "force-generic", not(target_arch = "x86_64")))]
+    pub(crate) mod chacha20poly1305;
+    #[cfg(any(
+        test,
+        feature = "force-generic",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ))]
+    pub(super) mod cpu;
     pub(super) mod ct_equal;
-    #[cfg(test)]
+    #[cfg(any(
+        test,
+        feature = "force-generic",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ))]
+    pub(super) mod curve25519;
+    pub(crate) mod fndsa;
     pub(crate) mod ghash;
+    pub(super) mod hchacha20;
+    pub(super) mod keccak;
+    #[cfg(any(feature = "force-generic", not(target_arch = "x86_64")))]
+    pub(super) mod keccak_x4;
+    pub(crate) mod mldsa;
+    pub(crate) mod mlkem;
+    #[cfg(any(
+        test,
+        feature = "force-generic",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ))]
+    pub(super) mod p256;
+    #[cfg(any(
+        test,
+        feature = "force-generic",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ))]
+    pub(super) mod p384;
     pub(crate) mod poly1305;
-    #[cfg(target_arch = "x86_64")]
+    pub(super) mod sha1;
     pub(super) mod sha256;
+    #[cfg(any(feature = "force-generic", not(target_arch = "x86_64")))]
+    pub(super) mod sha256_x8;
     pub(super) mod sha512;
+    #[cfg(any(
+        test,
+        feature = "force-generic",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ))]
+    pub(super) mod weierstrass;
     pub(super) mod zeroise;
 }
 
 mod entry;
+mod fork;
 mod posint;
 
 pub(crate) use entry::Entry;
+pub(crate) use fork::fork_generation;
+pub(crate) use generic::blake2::{BLAKE2B_IV, BLAKE2S_IV, blake2b_compress, blake2s_compress};
 pub(crate) use generic::blockwise::Blockwise;
 pub(crate) use generic::ct_equal::ct_equal;
+pub(crate) use generic::fndsa;
+pub(crate) use generic::ghash::{gf128_mul, gf128_pow};
+pub(crate) use generic::hchacha20::hchacha20;
+pub(crate) use generic::keccak::keccak_f1600;
+pub(crate) use generic::mldsa;
 pub(crate) use generic::poly1305;
-pub(crate) use generic::zeroise::{zeroise, zeroise_value};
+pub(crate) use generic::sha1::sha1_compress_blocks;
+pub(crate) use generic::zeroise::{ZeroOnDrop, zeroise, zeroise_value};
 pub(crate) use posint::{PosInt, SecretPosInt};
 
 #[cfg(test)]
 mod tests;
 
 cfg_if::cfg_if! {
-    if #[cfg(target_arch = "x86_64")] {
+    if #[cfg(all(target_arch = "x86_64", not(feature = "force-generic")))] {
         mod x86_64;
 
         pub(in crate::low) use x86_64::cpu::{enter_cpu_state, zero_bytes, ct_compare_bytes, leave_cpu_state, verify_cpu_features};
         pub(crate) use x86_64::chacha20;
-        pub(crate) use x86_64::aes::AesKey;
-        pub(crate) use x86_64::aes_gcm;
+        pub(crate) use x86_64::chacha20poly1305;
+        pub(crate) use x86_64::aes_mux::AesKey;
+        pub(crate) use x86_64::aes_gcm_mux as aes_gcm;
         pub(crate) use x86_64::bignum_add::bignum_add;
         pub(crate) use x86_64::bignum_add_p256::bignum_add_p256;
         pub(crate) use x86_64::bignum_add_p384::bignum_add_p384;
@@ -87,20 +155,24 @@ cfg_if::cfg_if! {
         pub(crate) use x86_64::bignum_tomont_p384::bignum_tomont_p384;
         pub(crate) use x86_64::curve25519_x25519::curve25519_x25519;
         pub(crate) use x86_64::curve25519_x25519base::curve25519_x25519base;
-        pub(crate) use x86_64::ghash;
+        pub(crate) use x86_64::ghash_mux as ghash;
+        pub(crate) use x86_64::keccak_x4::keccak_f1600_x4;
+        pub(crate) use x86_64::mlkem;
         pub(crate) use x86_64::p256_montjadd::p256_montjadd;
         pub(crate) use x86_64::p256_montjdouble::p256_montjdouble;
         pub(crate) use x86_64::p256_montjmixadd::p256_montjmixadd;
         pub(crate) use x86_64::p384_montjadd::p384_montjadd;
         pub(crate) use x86_64::p384_montjdouble::p384_montjdouble;
+        pub(crate) use x86_64::poly1305::poly1305_blocks;
         pub(crate) use x86_64::sha256_mux::sha256_compress_blocks;
+        pub(crate) use x86_64::sha256_x8::sha256_compress_blocks_x8;
         pub(crate) use x86_64::sha512_mux::sha512_compress_blocks;
-    } else if #[cfg(target_arch = "aarch64")] {
+    } else if #[cfg(all(target_arch = "aarch64", not(feature = "force-generic")))] {
         mod aarch64;
 
         pub(in crate::low) use aarch64::cpu::{enter_cpu_state, zero_bytes, ct_compare_bytes, leave_cpu_state, verify_cpu_features};
-        pub(crate) use aarch64::aes::AesKey;
-        pub(crate) use aarch64::aes_gcm;
+        pub(crate) use aarch64::aes_mux::AesKey;
+        pub(crate) use aarch64::aes_gcm_mux as aes_gcm;
         pub(crate) use aarch64::bignum_add::bignum_add;
         pub(crate) use aarch64::bignum_add_p256::bignum_add_p256;
         pub(crate) use aarch64::bignum_add_p384::bignum_add_p384;
@@ -144,17 +216,99 @@ cfg_if::cfg_if! {
         pub(crate) use aarch64::bignum_tomont_p384::bignum_tomont_p384;
         pub(crate) use aarch64::curve25519_x25519::curve25519_x25519;
         pub(crate) use aarch64::curve25519_x25519base::curve25519_x25519base;
-        pub(crate) use aarch64::ghash;
+        pub(crate) use aarch64::ghash_mux as ghash;
+        pub(crate) use aarch64::keccak_x4_mux::keccak_f1600_x4;
         pub(crate) use aarch64::p256_montjadd::p256_montjadd;
         pub(crate) use aarch64::p256_montjdouble::p256_montjdouble;
         pub(crate) use aarch64::p256_montjmixadd::p256_montjmixadd;
         pub(crate) use aarch64::p384_montjadd::p384_montjadd;
         pub(crate) use aarch64::p384_montjdouble::p384_montjdouble;
-        pub(crate) use aarch64::sha256::sha256_compress_blocks;
+        pub(crate) use aarch64::poly1305::poly1305_blocks;
+        pub(crate) use aarch64::sha256_mux::sha256_compress_blocks;
+        pub(crate) use aarch64::sha512_mux::sha512_compress_blocks;
 
         pub(crate) use generic::chacha20;
-        pub(crate) use generic::sha512::sha512_compress_blocks;
+        pub(crate) use generic::chacha20poly1305;
+        pub(crate) use generic::mlkem;
+        pub(crate) use generic::sha256_x8::sha256_compress_blocks_x8;
+    } else if #[cfg(any(
+        feature = "force-generic",
+        target_arch = "arm",
+        target_arch = "loongarch64",
+        target_arch = "riscv64",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))] {
+        // these targets use the portable code for everything except
+        // the symmetric primitives below (and, with the `force-generic`
+        // feature, for those too).
+        pub(in crate::low) use generic::cpu::{enter_cpu_state, zero_bytes, ct_compare_bytes, leave_cpu_state, verify_cpu_features};
+        pub(crate) use generic::bignum::{
+            bignum_add, bignum_bitsize, bignum_cmp_lt, bignum_copy_row_from_table, bignum_demont,
+            bignum_digitsize, bignum_emontredc_8n, bignum_eq, bignum_kmul_16_32, bignum_kmul_32_64,
+            bignum_ksqr_16_32, bignum_ksqr_32_64, bignum_modadd, bignum_modinv, bignum_modsub,
+            bignum_montifier, bignum_montmul, bignum_montredc, bignum_montsqr, bignum_mul, bignum_mux,
+            bignum_negmodinv, bignum_optsub,
+        };
+        pub(crate) use generic::chacha20;
+        pub(crate) use generic::chacha20poly1305;
+        pub(crate) use generic::curve25519::{curve25519_x25519, curve25519_x25519base};
+        pub(crate) use generic::keccak_x4::keccak_f1600_x4;
+        pub(crate) use generic::mlkem;
+        pub(crate) use generic::p256::{
+            bignum_add_p256, bignum_aff_point_select_p256, bignum_demont_p256, bignum_inv_p256,
+            bignum_jac_point_select_p256, bignum_mod_n256, bignum_montmul_p256, bignum_montsqr_p256,
+            bignum_neg_p256, bignum_tomont_p256, p256_montjadd, p256_montjdouble, p256_montjmixadd,
+        };
+        pub(crate) use generic::p384::{
+            bignum_add_p384, bignum_demont_p384, bignum_inv_p384, bignum_jac_point_select_p384,
+            bignum_mod_n384, bignum_montmul_p384, bignum_montsqr_p384, bignum_neg_p384,
+            bignum_tomont_p384, p384_montjadd, p384_montjdouble,
+        };
+        pub(crate) use generic::sha256_x8::sha256_compress_blocks_x8;
+
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "force-generic")] {
+                pub(crate) use generic::aes::AesKey;
+                pub(crate) use generic::aes_gcm;
+                pub(crate) use generic::ghash;
+                pub(crate) use generic::sha256::sha256_compress_blocks;
+                pub(crate) use generic::sha512::sha512_compress_blocks;
+            } else if #[cfg(target_arch = "arm")] {
+                mod arm;
+
+                pub(crate) use arm::aes_mux::AesKey;
+                pub(crate) use arm::aes_gcm_mux as aes_gcm;
+                pub(crate) use arm::ghash_mux as ghash;
+                pub(crate) use generic::sha256::sha256_compress_blocks;
+                pub(crate) use generic::sha512::sha512_compress_blocks;
+            } else if #[cfg(target_arch = "x86")] {
+                mod x86;
+
+                pub(crate) use x86::aes_mux::AesKey;
+                pub(crate) use x86::aes_gcm_mux as aes_gcm;
+                pub(crate) use x86::ghash_mux as ghash;
+                pub(crate) use generic::sha256::sha256_compress_blocks;
+                pub(crate) use generic::sha512::sha512_compress_blocks;
+            } else if #[cfg(target_arch = "riscv64")] {
+                mod riscv64;
+
+                pub(crate) use riscv64::aes_mux::AesKey;
+                pub(crate) use riscv64::aes_gcm_mux as aes_gcm;
+                pub(crate) use riscv64::ghash_mux as ghash;
+                pub(crate) use riscv64::sha256_mux::sha256_compress_blocks;
+                pub(crate) use riscv64::sha512_mux::sha512_compress_blocks;
+            } else {
+                pub(crate) use generic::aes::AesKey;
+                pub(crate) use generic::aes_gcm;
+                pub(crate) use generic::ghash;
+                pub(crate) use generic::sha256::sha256_compress_blocks;
+                pub(crate) use generic::sha512::sha512_compress_blocks;
+            }
+        }
     } else {
-        compile_error!("This crate only supports x86_64 or aarch64");
+        compile_error!(
+            "This crate only supports x86_64, x86, aarch64, arm, loongarch64, riscv64 or wasm32"
+        );
     }
 }
@@ -8,15 +8,38 @@ use crate::low;
 
 #[derive(Clone, Debug)]
 pub(crate) struct PosInt<const N: usize> {
-    words: [u64; N],
+    words: Words<N>,
     used: usize,
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "low-stack")] {
+        /// The backing store of a `PosInt`.
+        ///
+        /// This is on the heap, so each temporary `PosInt` costs a
+        /// pointer of stack rather than `N` words.  The pointer itself
+        /// is cleared on drop, so keys holding these leave nothing behind.
+        type Words<const N: usize> = low::ZeroOnDrop<Box<[u64; N]>>;
+
+        fn zero_words<const N: usize>() -> Words<N> {
+            // nb. `Box::new([0; N])` can build the array on the stack first
+            low::ZeroOnDrop::new(vec![0; N].into_boxed_slice().try_into().unwrap())
+        }
+    } else {
+        /// The backing store of a `PosInt`.
+        type Words<const N: usize> = [u64; N];
+
+        fn zero_words<const N: usize>() -> Words<N> {
+            [0; N]
+        }
+    }
+}
+
 impl<const N: usize> PosInt<N> {
     /// Makes a minimum-width zero.
     pub(crate) fn zero() -> Self {
         Self {
-            words: [0; N],
+            words: zero_words(),
             used: 0,
         }
     }
@@ -29,6 +52,15 @@ impl<const N: usize> PosInt<N> {
         r
     }
 
+    /// Declares `self` to be public: see `low::ct::into_public`.
+    ///
+    /// (That cannot be used directly, as the words may be behind a pointer.)
+    #[must_use]
+    pub(crate) fn into_public(self) -> Self {
+        low::ct::public_slice(&self.words[..]);
+        self
+    }
+
     /// Makes a one, but at the width of `self`.
     pub(crate) fn fixed_one(&self) -> Self {
         let mut one = Self::one();
@@ -133,6 +165,10 @@ impl<const N: usize> PosInt<N> {
         low::bignum_bitsize(self.as_words()).wrapping_add(7) / 8
     }
 
+    pub(crate) fn bit_len(&self) -> usize {
+        low::bignum_bitsize(self.as_words())
+    }
+
     pub(crate) fn is_even(&self) -> bool {
         self.words[0] & 1 == 0
     }
@@ -380,51 +416,29 @@ impl<const N: usize> PosInt<N> {
         let t1 = self.to_montgomery(n_montifier, n);
         table.extend_from_slice(t1.as_words());
 
-        // 2: self ^ 2
-        let t2 = t1.mont_sqr(n, n_0);
-        table.extend_from_slice(t2.as_words());
-
-        // 3: self ^ 2 * self
-        let t3 = t2.mont_mul(&t1, n, n_0);
-        table.extend_from_slice(t3.as_words());
-
-        // 4: self ^ 2 ^ 2
-        let t4 = t2.mont_sqr(n, n_0);
-        table.extend_from_slice(t4.as_words());
-
-        // (and so on)
-        let t5 = t4.mont_mul(&t1, n, n_0);
-        table.extend_from_slice(t5.as_words());
-
-        let t6 = t3.mont_sqr(n, n_0);
-        table.extend_from_slice(t6.as_words());
-
-        let t7 = t6.mont_mul(&t1, n, n_0);
-        table.extend_from_slice(t7.as_words());
-
-        let t8 = t4.mont_sqr(n, n_0);
-        table.extend_from_slice(t8.as_words());
-
-        let t9 = t8.mont_mul(&t1, n, n_0);
-        table.extend_from_slice(t9.as_words());
-
-        let t10 = t5.mont_sqr(n, n_0);
-        table.extend_from_slice(t10.as_words());
-
-        let t11 = t10.mont_mul(&t1, n, n_0);
-        table.extend_from_slice(t11.as_words());
-
-        let t12 = t6.mont_sqr(n, n_0);
-        table.extend_from_slice(t12.as_words());
-
-        let t13 = t12.mont_mul(&t1, n, n_0);
-        table.extend_from_slice(t13.as_words());
-
-        let t14 = t7.mont_sqr(n, n_0);
-        table.extend_from_slice(t14.as_words());
+        // 2..15: even entries are the square of the entry at half their
+        // index, and odd entries are the previous entry times self.
+        //
+        // each is computed from a row already in `table`, so only one
+        // temporary is live (rather than one for each entry), which keeps
+        // the stack usage down for large `N`.
+        let mut row = Self::zero();
+        row.used = n.used;
+
+        for i in 2..16 {
+            let (src, square) = match i % 2 {
+                0 => (i / 2, true),
+                _ => (i - 1, false),
+            };
+            row.as_mut_words()
+                .copy_from_slice(&table[src * n.used..(src + 1) * n.used]);
 
-        let t15 = t14.mont_mul(&t1, n, n_0);
-        table.extend_from_slice(t15.as_words());
+            let t = match square {
+                true => row.mont_sqr(n, n_0),
+                false => row.mont_mul(&t1, n, n_0),
+            };
+            table.extend_from_slice(t.as_words());
+        }
 
         let mut first = true;
         let mut wcount = 0;
@@ -470,6 +484,7 @@ impl<const N: usize> PosInt<N> {
         }
 
         low::zeroise(&mut table);
+        low::zeroise(row.as_mut_words());
         accum.from_montgomery(n)
     }
 
@@ -477,8 +492,8 @@ impl<const N: usize> PosInt<N> {
     #[must_use]
     pub(crate) fn add(&self, b: &Self) -> Self {
         let mut r = Self::zero();
-        low::bignum_add(&mut r.words, self.as_words(), b.as_words());
-        r.used = low::bignum_digitsize(&r.words);
+        low::bignum_add(&mut r.words[..], self.as_words(), b.as_words());
+        r.used = low::bignum_digitsize(&r.words[..]);
         r
     }
 
@@ -496,6 +511,107 @@ impl<const N: usize> PosInt<N> {
         r
     }
 
+    /// Computes `self` - 1, where `self` is odd.
+    #[must_use]
+    pub(crate) fn odd_minus_one(&self) -> Self {
+        debug_assert!(!self.is_even());
+        let mut r = self.clone();
+        r.words[0] &= !1;
+        r
+    }
+
+    /// Computes `self` * `k`.
+    #[must_use]
+    pub(crate) fn mul_small(&self, k: u32) -> Self {
+        assert!(self.used < N);
+        let mut r = Self::zero();
+        let mut carry = 0u64;
+        for (r, a) in r.words.iter_mut().zip(self.as_words()) {
+            let t = (*a as u128) * (k as u128) + carry as u128;
+            *r = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        r.words[self.used] = carry;
+        r.used = low::bignum_digitsize(&r.words[..]);
+        r
+    }
+
+    /// Returns `self` / `m`, and `self` mod `m`.
+    ///
+    /// The instruction trace depends only on `self.used`.
+    #[must_use]
+    pub(crate) fn div_small(&self, m: u32) -> (Self, u32) {
+        let (m, mu) = small_divisor(m);
+        let mut q = Self::zero();
+        let mut r = 0;
+        for (q, a) in q.words.iter_mut().zip(self.as_words()).rev() {
+            let (q_hi, r_hi) = div_step((r << 32) | (a >> 32), m, mu);
+            let (q_lo, r_lo) = div_step((r_hi << 32) | (a & 0xffff_ffff), m, mu);
+            *q = (q_hi << 32) | q_lo;
+            r = r_lo;
+        }
+        q.used = low::bignum_digitsize(&q.words[..]);
+        (q, r as u32)
+    }
+
+    /// Returns `self` mod `m`.
+    ///
+    /// As [`Self::div_small()`], without computing the quotient.
+    #[must_use]
+    pub(crate) fn rem_small(&self, m: u32) -> u32 {
+        let (m, mu) = small_divisor(m);
+        let mut r = 0;
+        for a in self.as_words().iter().rev() {
+            let (_, r_hi) = div_step((r << 32) | (a >> 32), m, mu);
+            let (_, r_lo) = div_step((r_hi << 32) | (a & 0xffff_ffff), m, mu);
+            r = r_lo;
+        }
+        r as u32
+    }
+
+    /// Reduce `self` mod `m`, where `m` need not be odd.
+    ///
+    /// This is binary long division, so is slow: use `reduce()` where possible.
+    /// The instruction trace depends only on `self.used` and `m.used`.
+    #[must_use]
+    pub(crate) fn reduce_slow(&self, m: &Self) -> Self {
+        assert!(m.used < N);
+        // one extra word, for the doubling
+        let width = m.used + 1;
+        let mut r = Self::zero();
+        let mut doubled = zero_words::<N>();
+
+        for i in (0..self.used * 64).rev() {
+            let bit = (self.words[i / 64] >> (i % 64)) & 1;
+            low::bignum_add(&mut doubled[..width], &r.words[..width], &r.words[..width]);
+            doubled[0] |= bit;
+            let not_less = low::bignum_cmp_lt(&doubled[..width], m.as_words()) ^ 1;
+            low::bignum_optsub(
+                &mut r.words[..width],
+                &doubled[..width],
+                &m.words[..width],
+                not_less,
+            );
+        }
+
+        low::zeroise(&mut doubled[..]);
+        r.used = m.used;
+        r
+    }
+
+    /// Truncates `self` to a smaller representation.
+    ///
+    /// [`Error::OutOfRange`] is returned if `self` does not fit.
+    pub(crate) fn narrow<const M: usize>(&self) -> Result<PosInt<M>, Error> {
+        if self.used > M {
+            return Err(Error::OutOfRange);
+        }
+        let mut r = PosInt::<M>::zero();
+        r.words[..self.used].copy_from_slice(self.as_words());
+        r.used = self.used;
+        Ok(r)
+    }
+
     /// Zero extends `self` to have a larger representation.
     #[must_use]
     pub(crate) fn widen<const M: usize>(&self) -> PosInt<M> {
@@ -514,10 +630,8 @@ pub(crate) struct SecretPosInt<const N: usize>(PosInt<N>);
 
 impl<const N: usize> From<PosInt<N>> for SecretPosInt<N> {
     fn from(pi: PosInt<N>) -> Self {
-        Self(PosInt {
-            used: pi.used,
-            words: low::ct::into_secret(pi.words),
-        })
+        low::ct::secret_slice(&pi.words[..]);
+        Self(pi)
     }
 }
 
@@ -579,6 +693,29 @@ impl Iterator for BitsMsbFirstIter<'_> {
     }
 }
 
+/// Returns `m` and its reciprocal, for `div_step()`.
+fn small_divisor(m: u32) -> (u64, u64) {
+    assert!(m > 1);
+    let m = m as u64;
+    (m, u64::MAX / m)
+}
+
+/// Returns `x` / `m` and `x` mod `m`, where `x` < `m` * 2^32.
+///
+/// `mu` is `u64::MAX / m`.  This is done without a (variable-time)
+/// division instruction: the quotient estimate `x * mu / 2^64` is
+/// at most two less than the true quotient, and is then corrected.
+fn div_step(x: u64, m: u64, mu: u64) -> (u64, u64) {
+    let mut q = ((x as u128 * mu as u128) >> 64) as u64;
+    let mut r = x - q * m;
+    for _ in 0..2 {
+        let not_less = (r.wrapping_sub(m) >> 63) ^ 1;
+        q += not_less;
+        r -= m & not_less.wrapping_neg();
+    }
+    (q, r)
+}
+
 fn trim_leading_zeroes(mut bytes: &[u8]) -> &[u8] {
     while let Some((first, rest)) = bytes.split_first() {
         if *first == 0x00 {
@@ -645,7 +782,7 @@ mod tests {
     #[test]
     fn to_bytes() {
         let mut buf = [0xff; 8];
-        assert_eq!(PosInt::<2>::zero().to_bytes(&mut buf).unwrap(), &[]);
+        assert_eq!(PosInt::<2>::zero().to_bytes(&mut buf).unwrap(), &[0u8; 0]);
 
         let all_bits_set = PosInt::<2>::from_bytes(&[0xff; 16]).unwrap();
         assert_eq!(
@@ -686,4 +823,62 @@ mod tests {
         let expect_8 = PosInt::<8>::from_bytes(b"\x34\x64\x15\xf5\x75\xf1\xb7\x01\x8b\x1d\xc4\x68\xde\x4b\xf7\x6e\x6f\x62\x87\xa1\x44\x08\x6f\xb1\x85\x9c\xf3\x84\x41\x64\x48\x9d\x16\xe7\xb0\xd0\xd3\x56\x13\xba\xa2\xb9\xa6\x12\x1a\x6c\x2f\x93\xcd\xe4\x20\xfa\x41\xa4\xef\xa2\xab\xcd\x8b\x48\x19\x62\x4a\xcc").unwrap();
         assert!(xy_8.pub_equals(&expect_8));
     }
+
+    #[test]
+    fn small_operands() {
+        let x = PosInt::<5>::from_bytes(b"\x03\x62\x72\x64\x53\xb1\x3c\xa2\x03\x1e\xeb\x1a\x6d\x92\xc3\x6e\x68\x34\xfe\x5b\x9a\x6e\x5f\xd3\xa1\xe5\xc1\xf5\x2d\xc0\x39\x42").unwrap();
+        assert_eq!(x.bit_len(), 250);
+
+        for m in [2, 3, 65537, 0xffff_fffb, u32::MAX] {
+            let (q, r) = x.div_small(m);
+            assert_eq!(x.rem_small(m), r);
+            assert!(r < m);
+            assert!(
+                q.mul_small(m)
+                    .add(&PosInt::from_bytes(&r.to_be_bytes()).unwrap())
+                    .pub_equals(&x)
+            );
+        }
+
+        // x = 2^64 - 1
+        let x = PosInt::<2>::from_bytes(&[0xff; 8]).unwrap();
+        let (q, r) = x.div_small(3);
+        assert_eq!((q.as_words(), r), (&[0x5555_5555_5555_5555][..], 0));
+        assert_eq!(x.mul_small(3).as_words(), &[0xffff_ffff_ffff_fffd, 2]);
+        assert_eq!(PosInt::<2>::zero().div_small(7).0.as_words(), &[] as &[u64]);
+        assert_eq!(PosInt::<2>::zero().rem_small(7), 0);
+    }
+
+    #[test]
+    fn reduce_slow() {
+        let x = PosInt::<4>::from_bytes(b"\x03\x62\x72\x64\x53\xb1\x3c\xa2\x03\x1e\xeb\x1a\x6d\x92\xc3\x6e\x68\x34\xfe\x5b\x9a\x6e\x5f\xd3\xa1\xe5\xc1\xf5\x2d\xc0\x39\x42").unwrap();
+        let m = PosInt::<4>::from_bytes(
+            b"\x03\x91\x61\x7b\xa4\xbe\x5c\x98\xf8\x7c\xc1\xc2\x50\x08\xf9\x54\x2c",
+        )
+        .unwrap();
+        let expect = PosInt::<4>::from_bytes(
+            b"\x02\x3a\x14\xb5\xfa\x87\xe6\xb3\x21\x02\x8f\x6e\xe6\x06\xc7\x6f\x92",
+        )
+        .unwrap();
+        assert!(x.reduce_slow(&m).pub_equals(&expect));
+
+        // already reduced, and exact multiples
+        assert!(expect.reduce_slow(&m).pub_equals(&expect));
+        assert!(m.reduce_slow(&m).pub_equals(&PosInt::zero()));
+        assert!(
+            PosInt::<4>::zero()
+                .reduce_slow(&m)
+                .pub_equals(&PosInt::zero())
+        );
+
+        let odd = PosInt::<4>::from_bytes(&[0x01, 0x01]).unwrap();
+        assert!(
+            odd.odd_minus_one()
+                .pub_equals(&PosInt::from_bytes(&[0x01, 0x00]).unwrap())
+        );
+
+        let narrowed: PosInt<3> = expect.narrow().unwrap();
+        assert_eq!(narrowed.as_words(), expect.as_words());
+        assert_eq!(x.narrow::<3>().err(), Some(Error::OutOfRange));
+    }
 }
@@ -0,0 +1,285 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! AES using the scalar cryptography instructions (Zkne and Zknd).
+//!
+//! The 128-bit state is held in two 64-bit registers.  Each round
+//! instruction produces one half of the next state from both halves of
+//! the current one.  Decryption uses the equivalent inverse cipher, with
+//! `aes64im` applied to the middle round keys.
+//!
+//! ref: <https://github.com/riscv/riscv-crypto/releases/tag/v1.0.1-scalar>
+//! (sections 2.3 to 2.9)
+
+use crate::low;
+
+pub(crate) struct AesKey {
+    /// Encryption round keys, two words per round.
+    enc: [u64; (14 + 1) * 2],
+    /// Decryption round keys, in the same order.
+    dec: [u64; (14 + 1) * 2],
+    rounds: usize,
+}
+
+impl AesKey {
+    /// Creates an AesKey.
+    ///
+    /// `key` must be 16 or 32 bytes in length (AES-192 not supported).
+    pub(crate) fn new(key: &[u8]) -> Self {
+        let mut enc = [0u64; (14 + 1) * 2];
+        for (w, k) in enc.iter_mut().zip(key.chunks_exact(8)) {
+            *w = u64::from_le_bytes(k.try_into().unwrap());
+        }
+
+        // SAFETY: the caller checked the `zkne` and `zknd` cpu features
+        // are supported.
+        let rounds = unsafe {
+            match key.len() {
+                16 => {
+                    expand_128(&mut enc);
+                    10
+                }
+                32 => {
+                    expand_256(&mut enc);
+                    14
+                }
+                24 => panic!("aes-192 not supported"),
+                _ => panic!("invalid aes key size"),
+            }
+        };
+
+        let mut dec = enc;
+        for w in dec[2..rounds * 2].iter_mut() {
+            // SAFETY: as above.
+            *w = unsafe { aes64im(*w) };
+        }
+
+        Self { enc, dec, rounds }
+    }
+
+    /// Creates an AesKey for use with `decrypt_block`.
+    ///
+    /// This implementation always makes the decryption round keys,
+    /// so this is `new`.
+    pub(crate) fn new_with_decryption(key: &[u8]) -> Self {
+        Self::new(key)
+    }
+
+    pub(crate) fn encrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+        let (s0, s1) = load(inout);
+        let (s0, s1) = self.encrypt(s0, s1);
+        store(inout, s0, s1);
+    }
+
+    pub(crate) fn decrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+        let (s0, s1) = load(inout);
+        let rk = &self.dec[..(self.rounds + 1) * 2];
+
+        let mut s0 = s0 ^ rk[rk.len() - 2];
+        let mut s1 = s1 ^ rk[rk.len() - 1];
+
+        // SAFETY: the caller checked the `zknd` cpu feature is supported.
+        unsafe {
+            for k in rk[2..rk.len() - 2].chunks_exact(2).rev() {
+                (s0, s1) = (aes64dsm(s0, s1) ^ k[0], aes64dsm(s1, s0) ^ k[1]);
+            }
+            (s0, s1) = (aes64ds(s0, s1) ^ rk[0], aes64ds(s1, s0) ^ rk[1]);
+        }
+
+        store(inout, s0, s1);
+    }
+
+    pub(crate) fn ctr(&self, initial_counter: &[u8; 16], cipher_inout: &mut [u8]) {
+        let mut counter = u32::from_be_bytes(initial_counter[12..].try_into().unwrap());
+        let mut block = *initial_counter;
+
+        for chunk in cipher_inout.chunks_mut(16) {
+            counter = counter.wrapping_add(1);
+            block[12..].copy_from_slice(&counter.to_be_bytes());
+
+            let (s0, s1) = load(&block);
+            let (s0, s1) = self.encrypt(s0, s1);
+            let mut keystream = [0u8; 16];
+            store(&mut keystream, s0, s1);
+
+            for (c, k) in chunk.iter_mut().zip(keystream.iter()) {
+                *c ^= *k;
+            }
+            low::zeroise(&mut keystream);
+        }
+    }
+
+    #[inline]
+    fn encrypt(&self, s0: u64, s1: u64) -> (u64, u64) {
+        let rk = &self.enc[..(self.rounds + 1) * 2];
+
+        let mut s0 = s0 ^ rk[0];
+        let mut s1 = s1 ^ rk[1];
+
+        // SAFETY: the caller checked the `zkne` cpu feature is supported.
+        unsafe {
+            for k in rk[2..rk.len() - 2].chunks_exact(2) {
+                (s0, s1) = (aes64esm(s0, s1) ^ k[0], aes64esm(s1, s0) ^ k[1]);
+            }
+            (
+                aes64es(s0, s1) ^ rk[rk.len() - 2],
+                aes64es(s1, s0) ^ rk[rk.len() - 1],
+            )
+        }
+    }
+}
+
+impl Drop for AesKey {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.enc);
+        low::zeroise(&mut self.dec);
+        low::zeroise_value(&mut self.rounds);
+    }
+}
+
+fn load(block: &[u8]) -> (u64, u64) {
+    (
+        u64::from_le_bytes(block[..8].try_into().unwrap()),
+        u64::from_le_bytes(block[8..16].try_into().unwrap()),
+    )
+}
+
+fn store(block: &mut [u8], s0: u64, s1: u64) {
+    block[..8].copy_from_slice(&s0.to_le_bytes());
+    block[8..16].copy_from_slice(&s1.to_le_bytes());
+}
+
+/// Defines a function for an instruction with only register operands.
+macro_rules! instruction {
+    ($(#[$attr:meta])* $name:ident($($arg:ident),+)) => {
+        $(#[$attr])*
+        #[inline]
+        unsafe fn $name($($arg: u64),+) -> u64 {
+            let rd: u64;
+            // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+            unsafe {
+                core::arch::asm!(
+                    ".option push",
+                    ".option arch, +zkne, +zknd",
+                    concat!(stringify!($name), " {rd}", $(", {", stringify!($arg), "}"),+),
+                    ".option pop",
+                    rd = lateout(reg) rd,
+                    $($arg = in(reg) $arg,)+
+                    options(pure, nomem, nostack),
+                );
+            }
+            rd
+        }
+    };
+}
+
+instruction!(
+    /// The low half of ShiftRows, SubBytes and MixColumns applied to `rs2:rs1`.
+    aes64esm(rs1, rs2)
+);
+instruction!(
+    /// The low half of ShiftRows and SubBytes applied to `rs2:rs1`.
+    aes64es(rs1, rs2)
+);
+instruction!(
+    /// The low half of InvShiftRows, InvSubBytes and InvMixColumns applied to `rs2:rs1`.
+    aes64dsm(rs1, rs2)
+);
+instruction!(
+    /// The low half of InvShiftRows and InvSubBytes applied to `rs2:rs1`.
+    aes64ds(rs1, rs2)
+);
+instruction!(
+    /// InvMixColumns applied to both columns of `rs1`.
+    aes64im(rs1)
+);
+instruction!(
+    /// The second half of a key schedule step: see `expand_128`.
+    aes64ks2(rs1, rs2)
+);
+
+/// The first half of a key schedule step: SubWord (and, unless `rnum`
+/// is 0xa, RotWord and the round constant `rnum`) applied to the high
+/// word of `rs1`, in both halves of the result.
+///
+/// `rnum` must be a literal.  This must be used in an `unsafe` block:
+/// see [crate::low::inline_assembly_safety] for safety info.
+macro_rules! aes64ks1i {
+    ($rs1:expr, $rnum:literal) => {{
+        let rd: u64;
+        core::arch::asm!(
+            ".option push",
+            ".option arch, +zkne, +zknd",
+            concat!("aes64ks1i {rd}, {rs1}, ", stringify!($rnum)),
+            ".option pop",
+            rd = lateout(reg) rd,
+            rs1 = in(reg) $rs1,
+            options(pure, nomem, nostack),
+        );
+        rd
+    }};
+}
+
+/// Expands `rk[0..2]` into all eleven AES-128 round keys.
+unsafe fn expand_128(rk: &mut [u64; 30]) {
+    macro_rules! round {
+        ($i:literal) => {
+            let t = aes64ks1i!(rk[2 * $i + 1], $i);
+            rk[2 * $i + 2] = aes64ks2(t, rk[2 * $i]);
+            rk[2 * $i + 3] = aes64ks2(rk[2 * $i + 2], rk[2 * $i + 1]);
+        };
+    }
+
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        round!(0);
+        round!(1);
+        round!(2);
+        round!(3);
+        round!(4);
+        round!(5);
+        round!(6);
+        round!(7);
+        round!(8);
+        round!(9);
+    }
+}
+
+/// Expands `rk[0..4]` into all fifteen AES-256 round keys.
+unsafe fn expand_256(rk: &mut [u64; 30]) {
+    macro_rules! round {
+        ($i:literal) => {
+            let t = aes64ks1i!(rk[4 * $i + 3], $i);
+            rk[4 * $i + 4] = aes64ks2(t, rk[4 * $i]);
+            rk[4 * $i + 5] = aes64ks2(rk[4 * $i + 4], rk[4 * $i + 1]);
+        };
+    }
+
+    // the second half of each pair: SubWord without rotation or round constant
+    macro_rules! half_round {
+        ($i:literal) => {
+            let t = aes64ks1i!(rk[4 * $i + 5], 0xa);
+            rk[4 * $i + 6] = aes64ks2(t, rk[4 * $i + 2]);
+            rk[4 * $i + 7] = aes64ks2(rk[4 * $i + 6], rk[4 * $i + 3]);
+        };
+    }
+
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        round!(0);
+        half_round!(0);
+        round!(1);
+        half_round!(1);
+        round!(2);
+        half_round!(2);
+        round!(3);
+        half_round!(3);
+        round!(4);
+        half_round!(4);
+        round!(5);
+        half_round!(5);
+        round!(6);
+    }
+}
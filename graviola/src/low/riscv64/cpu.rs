@@ -0,0 +1,95 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! CPU feature detection for riscv64.
+//!
+//! There are no secret-dependent CPU modes here, so the CPU state
+//! management is shared with the portable targets (see `generic::cpu`).
+
+use std::sync::OnceLock;
+
+/// The CPU features we use.
+///
+/// All are optional.
+pub(crate) struct Features {
+    /// Scalar AES encryption and decryption (Zkne and Zknd).
+    pub(crate) zkn_aes: bool,
+    /// Scalar SHA-256 and SHA-512 sigma functions (Zknh).
+    pub(crate) zknh: bool,
+    /// The vector extension, with GHASH (V and Zvkg).
+    pub(crate) zvkg: bool,
+}
+
+impl Features {
+    fn detect() -> Self {
+        let ext = hwprobe::ima_ext_0();
+        let has = |bits: u64| ext & bits == bits;
+
+        Self {
+            zkn_aes: has(hwprobe::EXT_ZKND | hwprobe::EXT_ZKNE),
+            zknh: has(hwprobe::EXT_ZKNH),
+            zvkg: has(hwprobe::IMA_V | hwprobe::EXT_ZVKG),
+        }
+    }
+}
+
+/// Returns the CPU features, detecting them on first call.
+///
+/// Every dispatch decision is a function of these, so is made once
+/// per process.
+pub(crate) fn features() -> &'static Features {
+    static FEATURES: OnceLock<Features> = OnceLock::new();
+    FEATURES.get_or_init(Features::detect)
+}
+
+/// The `riscv_hwprobe` system call.
+///
+/// `is_riscv_feature_detected!` was stabilised after our MSRV, and we
+/// don't otherwise depend on `libc`, so we make the system call ourselves.
+///
+/// Ref. <https://docs.kernel.org/arch/riscv/hwprobe.html>
+mod hwprobe {
+    pub(super) const IMA_V: u64 = 1 << 2;
+    pub(super) const EXT_ZKND: u64 = 1 << 11;
+    pub(super) const EXT_ZKNE: u64 = 1 << 12;
+    pub(super) const EXT_ZKNH: u64 = 1 << 13;
+    pub(super) const EXT_ZVKG: u64 = 1 << 20;
+
+    /// Returns the `RISCV_HWPROBE_KEY_IMA_EXT_0` bitmap, or zero if it
+    /// is not available.
+    #[cfg(target_os = "linux")]
+    pub(super) fn ima_ext_0() -> u64 {
+        const NR_RISCV_HWPROBE: usize = 258;
+        const KEY_IMA_EXT_0: i64 = 4;
+
+        // struct riscv_hwprobe { __s64 key; __u64 value; }
+        let mut pair: [u64; 2] = [KEY_IMA_EXT_0 as u64, 0];
+        let ret: isize;
+
+        // SAFETY: the system call writes to the one pair at `pair`.  A
+        // kernel without this system call returns `-ENOSYS`.
+        unsafe {
+            core::arch::asm!(
+                "ecall",
+                inlateout("a0") pair.as_mut_ptr() => ret,
+                in("a1") 1usize,
+                in("a2") 0usize,
+                in("a3") 0usize,
+                in("a4") 0usize,
+                in("a7") NR_RISCV_HWPROBE,
+                options(nostack),
+            );
+        }
+
+        // the kernel sets `key` to -1 if it is not known
+        match (ret, pair[0] as i64) {
+            (0, KEY_IMA_EXT_0) => pair[1],
+            _ => 0,
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(super) fn ima_ext_0() -> u64 {
+        0
+    }
+}
@@ -0,0 +1,118 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! GHASH using the vector GHASH instruction (Zvkg).
+//!
+//! `vghsh.vv` does a whole GHASH step -- `(Y ^ X) * H` -- on values in
+//! GCM's byte order, so unlike the other implementations no table is
+//! precomputed: `GhashTable` holds just H.
+//!
+//! Rust has no operand class for vector registers, so each use is a
+//! block of inline assembly using v1 to v3 by name.
+//!
+//! ref: <https://github.com/riscv/riscv-isa-manual> ("Zvkg")
+
+use crate::low;
+
+pub(crate) struct GhashTable {
+    h: [u8; 16],
+}
+
+impl GhashTable {
+    pub(crate) fn new(h: u128) -> Self {
+        Self { h: h.to_be_bytes() }
+    }
+
+    /// Makes a table for inputs shorter than 128 bytes.
+    ///
+    /// This implementation only ever uses H, so this is `new`.
+    pub(crate) fn new_single_block(h: u128) -> Self {
+        Self::new(h)
+    }
+}
+
+impl Drop for GhashTable {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.h);
+    }
+}
+
+pub(crate) struct Ghash<'a> {
+    table: &'a GhashTable,
+    current: [u8; 16],
+}
+
+impl<'a> Ghash<'a> {
+    pub(crate) fn new(table: &'a GhashTable) -> Self {
+        Self {
+            table,
+            current: [0u8; 16],
+        }
+    }
+
+    /// Input `bytes` to the computation.
+    ///
+    /// `bytes` is zero-padded, if required.
+    pub(crate) fn add(&mut self, bytes: &[u8]) {
+        let (whole, rest) = bytes.split_at(bytes.len() & !15);
+
+        if !whole.is_empty() {
+            // SAFETY: the caller checked the `v` and `zvkg` cpu features
+            // are supported.
+            unsafe { ghash(&mut self.current, &self.table.h, whole) };
+        }
+
+        if !rest.is_empty() {
+            let mut block = [0u8; 16];
+            block[..rest.len()].copy_from_slice(rest);
+            // SAFETY: as above.
+            unsafe { ghash(&mut self.current, &self.table.h, &block) };
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> [u8; 16] {
+        self.current
+    }
+}
+
+/// Absorb `blocks` (a non-zero multiple of 16 bytes) into `current`.
+unsafe fn ghash(current: &mut [u8; 16], h: &[u8; 16], blocks: &[u8]) {
+    debug_assert!(!blocks.is_empty() && blocks.len() % 16 == 0);
+
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        core::arch::asm!(
+            ".option push",
+            ".option arch, +v, +zvkg",
+            // bytewise loads and stores, as our buffers are unaligned;
+            // the register contents are the same either way
+            "vsetivli zero, 16, e8, m1, ta, ma",
+            "vle8.v v1, ({current})",
+            "vle8.v v2, ({h})",
+            "2:",
+            "vsetivli zero, 16, e8, m1, ta, ma",
+            "vle8.v v3, ({blocks})",
+            "vsetivli zero, 4, e32, m1, ta, ma",
+            "vghsh.vv v1, v2, v3",
+            "addi {blocks}, {blocks}, 16",
+            "addi {len}, {len}, -16",
+            "bnez {len}, 2b",
+            "vsetivli zero, 16, e8, m1, ta, ma",
+            "vse8.v v1, ({current})",
+            // don't leave H or the data in vector registers
+            "vmv.v.i v1, 0",
+            "vmv.v.i v2, 0",
+            "vmv.v.i v3, 0",
+            ".option pop",
+            current = in(reg) current.as_mut_ptr(),
+            h = in(reg) h.as_ptr(),
+            blocks = inout(reg) blocks.as_ptr() => _,
+            len = inout(reg) blocks.len() => _,
+            // clobbers
+            out("v1") _,
+            out("v2") _,
+            out("v3") _,
+            options(nostack),
+        )
+    }
+}
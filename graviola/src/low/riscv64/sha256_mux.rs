@@ -0,0 +1,38 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::low::riscv64;
+use crate::low::{dispatch, generic};
+
+pub(crate) fn sha256_compress_blocks(state: &mut [u32; 8], blocks: &[u8]) {
+    if use_hash_instructions() {
+        riscv64::sha256::sha256_compress_blocks(state, blocks)
+    } else {
+        generic::sha256::sha256_compress_blocks(state, blocks)
+    }
+}
+
+pub(in crate::low) fn use_hash_instructions() -> bool {
+    dispatch::use_native() && riscv64::cpu::features().zknh
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_instructions_match_generic() {
+        if !riscv64::cpu::features().zknh {
+            return;
+        }
+
+        let data = (0..64 * 5).map(|i| (i * 13) as u8).collect::<Vec<_>>();
+        for nblocks in 0..=5 {
+            let mut accel = [0x6a09e667u32; 8];
+            let mut generic = accel;
+            riscv64::sha256::sha256_compress_blocks(&mut accel, &data[..nblocks * 64]);
+            generic::sha256::sha256_compress_blocks(&mut generic, &data[..nblocks * 64]);
+            assert_eq!(accel, generic);
+        }
+    }
+}
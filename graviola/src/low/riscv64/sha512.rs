@@ -0,0 +1,145 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! SHA-512 using the scalar hash instructions (Zknh).
+//!
+//! This is the generic implementation, with each sigma function
+//! computed by one instruction.
+
+use crate::low::generic::sha512::K;
+
+pub(crate) fn sha512_compress_blocks(state: &mut [u64; 8], blocks: &[u8]) {
+    debug_assert!(blocks.len() % 128 == 0);
+
+    for block in blocks.chunks_exact(128) {
+        // SAFETY: the caller checked the `zknh` cpu feature is supported.
+        unsafe { sha512_compress_block(state, block) };
+    }
+}
+
+macro_rules! CH {
+    ($x:expr, $y:expr, $z:expr) => {
+        ($x & $y) ^ (!$x & $z)
+    };
+}
+
+macro_rules! MAJ {
+    ($x:expr, $y:expr, $z:expr) => {
+        ($x & $y) ^ ($x & $z) ^ ($y & $z)
+    };
+}
+
+macro_rules! BSIG0 {
+    ($x:expr) => {
+        sha512sum0($x)
+    };
+}
+
+macro_rules! BSIG1 {
+    ($x:expr) => {
+        sha512sum1($x)
+    };
+}
+
+macro_rules! SSIG0 {
+    ($x:expr) => {
+        sha512sig0($x)
+    };
+}
+
+macro_rules! SSIG1 {
+    ($x:expr) => {
+        sha512sig1($x)
+    };
+}
+
+/// Defines a function for a sigma instruction.
+macro_rules! sigma {
+    ($name:ident) => {
+        #[inline]
+        unsafe fn $name(x: u64) -> u64 {
+            let rd: u64;
+            // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+            unsafe {
+                core::arch::asm!(
+                    ".option push",
+                    ".option arch, +zknh",
+                    concat!(stringify!($name), " {rd}, {rs1}"),
+                    ".option pop",
+                    rd = lateout(reg) rd,
+                    rs1 = in(reg) x,
+                    options(pure, nomem, nostack),
+                );
+            }
+            rd
+        }
+    };
+}
+
+sigma!(sha512sum0);
+sigma!(sha512sum1);
+sigma!(sha512sig0);
+sigma!(sha512sig1);
+
+#[inline]
+unsafe fn sha512_compress_block(state: &mut [u64; 8], block: &[u8]) {
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    unsafe {
+        let mut a = state[0];
+        let mut b = state[1];
+        let mut c = state[2];
+        let mut d = state[3];
+        let mut e = state[4];
+        let mut f = state[5];
+        let mut g = state[6];
+        let mut h = state[7];
+
+        // This is a 16-word window into the whole W array.
+        let mut w: [u64; 16] = [0; 16];
+
+        for t in 0..80 {
+            // For W[0..16] we process the input into W.
+            // For W[16..80] we compute the next W value:
+            //
+            // W[t] = SSIG1(W[t - 2]) + W[t - 7] + SSIG0(W[t - 15]) + W[t - 16];
+            //
+            // But all W indices are reduced mod 16 into our window.
+            let w_t = if t < 16 {
+                let w_t = u64::from_be_bytes(block[t * 8..(t + 1) * 8].try_into().unwrap());
+                w[t] = w_t;
+                w_t
+            } else {
+                let w_t = SSIG1!(w[(t - 2) % 16])
+                    .wrapping_add(w[(t - 7) % 16])
+                    .wrapping_add(SSIG0!(w[(t - 15) % 16]))
+                    .wrapping_add(w[(t - 16) % 16]);
+                w[t % 16] = w_t;
+                w_t
+            };
+
+            let t1 = h
+                .wrapping_add(BSIG1!(e))
+                .wrapping_add(CH!(e, f, g))
+                .wrapping_add(K[t])
+                .wrapping_add(w_t);
+            let t2 = BSIG0!(a).wrapping_add(MAJ!(a, b, c));
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+        state[5] = state[5].wrapping_add(f);
+        state[6] = state[6].wrapping_add(g);
+        state[7] = state[7].wrapping_add(h);
+    }
+}
@@ -49,6 +49,38 @@ fn ct_equal_equiv(a: &[u8], b: &[u8]) {
     assert_eq!(a == b, super::ct_equal(a, b));
 }
 
+#[test]
+fn sha256_compress_blocks_x8() {
+    let data = (0..8 * 192).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+    let mut blocks: [&[u8]; 8] = core::array::from_fn(|i| &data[i * 192..(i + 1) * 192]);
+    blocks[3] = &[];
+
+    let mut states: [[u32; 8]; 8] = core::array::from_fn(|i| [i as u32 * 0x01010101; 8]);
+    let mut expect = states;
+    for (state, blocks) in expect.iter_mut().zip(blocks) {
+        if !blocks.is_empty() {
+            super::sha256_compress_blocks(state, blocks);
+        }
+    }
+
+    super::sha256_compress_blocks_x8(&mut states, blocks);
+    assert_eq!(expect, states);
+}
+
+#[test]
+fn keccak_f1600_x4() {
+    let mut states: [[u64; 25]; 4] = core::array::from_fn(|i| {
+        core::array::from_fn(|j| ((i * 25 + j + 1) as u64).wrapping_mul(0x0123_4567_89ab_cdef))
+    });
+    let mut expect = states;
+    for state in expect.iter_mut() {
+        super::keccak_f1600(state);
+    }
+
+    super::keccak_f1600_x4(&mut states);
+    assert_eq!(expect, states);
+}
+
 mod model {
     pub(super) fn bignum_mux(p: u64, z: &mut [u64], x_if_p: &[u64], y_if_not_p: &[u64]) {
         if p > 0 {
@@ -0,0 +1,295 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! AES-NI, one block at a time.
+//!
+//! This is the x86_64 implementation (see low/x86_64/aes.rs) restricted
+//! to SSE2 encodings, and without the multi-block paths.
+
+use core::arch::x86::*;
+
+use crate::low;
+
+pub(crate) struct AesKey {
+    round_keys: [__m128i; 14 + 1],
+
+    /// Round keys for the equivalent inverse cipher, if made.
+    dec_round_keys: Option<[__m128i; 14 + 1]>,
+    rounds: usize,
+}
+
+impl AesKey {
+    /// Creates an AesKey.
+    ///
+    /// `key` must be 16 or 32 bytes in length (AES-192 not supported).
+    pub(crate) fn new(key: &[u8]) -> Self {
+        let mut round_keys = [zero(); 14 + 1];
+
+        // SAFETY: `aes` and `sse2` are checked by `aes_mux`
+        let rounds = unsafe {
+            match key.len() {
+                16 => {
+                    aes128_expand(key.try_into().unwrap(), &mut round_keys);
+                    10
+                }
+                32 => {
+                    aes256_expand(key.try_into().unwrap(), &mut round_keys);
+                    14
+                }
+                24 => panic!("aes-192 not supported"),
+                _ => panic!("invalid aes key size"),
+            }
+        };
+
+        Self {
+            round_keys,
+            dec_round_keys: None,
+            rounds,
+        }
+    }
+
+    /// Creates an AesKey, which also holds the round keys for decryption.
+    ///
+    /// This makes `decrypt_block` as fast as `encrypt_block`, at the
+    /// cost of a slower key setup.
+    pub(crate) fn new_with_decryption(key: &[u8]) -> Self {
+        let mut k = Self::new(key);
+        // SAFETY: `aes` and `sse2` are checked by `aes_mux`
+        k.dec_round_keys = Some(unsafe { inverse_round_keys(&k.round_keys[..k.rounds + 1]) });
+        k
+    }
+
+    pub(crate) fn encrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+        // SAFETY: `aes` and `sse2` are checked by `aes_mux`
+        unsafe { aes_encrypt_block(&self.round_keys[..self.rounds + 1], inout) }
+    }
+
+    pub(crate) fn decrypt_block(&self, inout: &mut [u8]) {
+        debug_assert_eq!(inout.len(), 16);
+        // SAFETY: `aes` and `sse2` are checked by `aes_mux`
+        unsafe {
+            match &self.dec_round_keys {
+                Some(dec_round_keys) => {
+                    aes_inverse_cipher_block(&dec_round_keys[..self.rounds + 1], inout)
+                }
+                None => aes_decrypt_block(&self.round_keys[..self.rounds + 1], inout),
+            }
+        }
+    }
+
+    pub(crate) fn ctr(&self, initial_counter: &[u8; 16], cipher_inout: &mut [u8]) {
+        let mut counter = u32::from_be_bytes(initial_counter[12..].try_into().unwrap());
+        let mut block = [0u8; 16];
+
+        for chunk in cipher_inout.chunks_mut(16) {
+            counter = counter.wrapping_add(1);
+            block[..12].copy_from_slice(&initial_counter[..12]);
+            block[12..].copy_from_slice(&counter.to_be_bytes());
+            self.encrypt_block(&mut block);
+
+            for (c, k) in chunk.iter_mut().zip(block.iter()) {
+                *c ^= *k;
+            }
+        }
+
+        low::zeroise(&mut block);
+    }
+}
+
+impl Drop for AesKey {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.round_keys);
+        if let Some(dec_round_keys) = &mut self.dec_round_keys {
+            low::zeroise(dec_round_keys);
+        }
+        low::zeroise_value(&mut self.rounds);
+    }
+}
+
+fn zero() -> __m128i {
+    // SAFETY: `sse2` is checked by `aes_mux`
+    unsafe { _mm_setzero_si128() }
+}
+
+macro_rules! expand_128 {
+    ($rcon:literal, $t1:ident, $out:expr) => {
+        // with [X3, _, X1,  _] = t1
+        // t2 := [RotWord (SubWord (X3)) XOR RCON, SubWord (X3),
+        //        RotWord (SubWord (X1)) XOR RCON, SubWord (X1)]
+        let t2 = _mm_aeskeygenassist_si128($t1, $rcon);
+
+        // select just high dqword, distribute to other dqwords
+        let t2 = _mm_shuffle_epi32(t2, 0b11_11_11_11);
+
+        let t3 = _mm_slli_si128($t1, 0x4); // nb. 4 bytes shift
+        $t1 = _mm_xor_si128($t1, t3);
+
+        let t3 = _mm_slli_si128(t3, 0x4);
+        $t1 = _mm_xor_si128($t1, t3);
+
+        let t3 = _mm_slli_si128(t3, 0x4);
+        $t1 = _mm_xor_si128($t1, t3);
+
+        $t1 = _mm_xor_si128($t1, t2);
+
+        $out = $t1;
+    };
+}
+
+#[target_feature(enable = "aes,sse2")]
+unsafe fn aes128_expand(key: &[u8; 16], out: &mut [__m128i; 15]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let mut t1 = _mm_loadu_si128(key.as_ptr() as *const _);
+        out[0] = t1;
+
+        expand_128!(0x01, t1, out[1]);
+        expand_128!(0x02, t1, out[2]);
+        expand_128!(0x04, t1, out[3]);
+        expand_128!(0x08, t1, out[4]);
+        expand_128!(0x10, t1, out[5]);
+        expand_128!(0x20, t1, out[6]);
+        expand_128!(0x40, t1, out[7]);
+        expand_128!(0x80, t1, out[8]);
+        expand_128!(0x1b, t1, out[9]);
+        expand_128!(0x36, t1, out[10]);
+    }
+}
+
+macro_rules! expand_256 {
+    (Odd, $rcon:literal, $t1:ident, $t3:ident, $out:expr) => {
+        let t2 = _mm_aeskeygenassist_si128($t3, $rcon);
+        let t2 = _mm_shuffle_epi32(t2, 0b11_11_11_11);
+
+        let t3 = _mm_slli_si128($t1, 0x4); // nb. 4 bytes shift
+        $t1 = _mm_xor_si128($t1, t3);
+
+        let t3 = _mm_slli_si128(t3, 0x4);
+        $t1 = _mm_xor_si128($t1, t3);
+
+        let t3 = _mm_slli_si128(t3, 0x4);
+        $t1 = _mm_xor_si128($t1, t3);
+
+        $t1 = _mm_xor_si128($t1, t2);
+
+        $out = $t1;
+    };
+    (Even, $t1:ident, $t3:ident, $out:expr) => {
+        let t4 = _mm_aeskeygenassist_si128($t1, 0);
+        let t2 = _mm_shuffle_epi32(t4, 0b10_10_10_10); // choose SubWord(X3) term
+
+        let t4 = _mm_slli_si128($t3, 0x4);
+        $t3 = _mm_xor_si128($t3, t4);
+
+        let t4 = _mm_slli_si128(t4, 0x4);
+        $t3 = _mm_xor_si128($t3, t4);
+
+        let t4 = _mm_slli_si128(t4, 0x4);
+        $t3 = _mm_xor_si128($t3, t4);
+        $t3 = _mm_xor_si128($t3, t2);
+
+        $out = $t3;
+    };
+}
+
+#[target_feature(enable = "aes,sse2")]
+unsafe fn aes256_expand(key: &[u8; 32], out: &mut [__m128i; 15]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let mut t1 = _mm_loadu_si128(key.as_ptr() as *const _);
+        let mut t3 = _mm_loadu_si128(key[16..].as_ptr() as *const _);
+        out[0] = t1;
+        out[1] = t3;
+
+        // nb. 'odd' rounds in units of Nk have an rcon term (equivalent
+        // to all rounds of 128-bit key expansion), 'even' rounds do not
+        expand_256!(Odd, 0x01, t1, t3, out[2]);
+        expand_256!(Even, t1, t3, out[3]);
+        expand_256!(Odd, 0x02, t1, t3, out[4]);
+        expand_256!(Even, t1, t3, out[5]);
+        expand_256!(Odd, 0x04, t1, t3, out[6]);
+        expand_256!(Even, t1, t3, out[7]);
+        expand_256!(Odd, 0x08, t1, t3, out[8]);
+        expand_256!(Even, t1, t3, out[9]);
+        expand_256!(Odd, 0x10, t1, t3, out[10]);
+        expand_256!(Even, t1, t3, out[11]);
+        expand_256!(Odd, 0x20, t1, t3, out[12]);
+        expand_256!(Even, t1, t3, out[13]);
+        expand_256!(Odd, 0x40, t1, t3, out[14]);
+    }
+}
+
+#[target_feature(enable = "aes,sse2")]
+unsafe fn aes_encrypt_block(round_keys: &[__m128i], block_inout: &mut [u8]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let (first, rest) = round_keys.split_first().unwrap();
+        let (last, middle) = rest.split_last().unwrap();
+
+        let mut block = _mm_loadu_si128(block_inout.as_ptr() as *const _);
+        block = _mm_xor_si128(block, *first);
+        for rk in middle {
+            block = _mm_aesenc_si128(block, *rk);
+        }
+        block = _mm_aesenclast_si128(block, *last);
+        _mm_storeu_si128(block_inout.as_mut_ptr() as *mut _, block);
+    }
+}
+
+/// Decrypt one block, with the equivalent inverse cipher (FIPS-197 section 5.3.5).
+///
+/// The decryption round keys are derived from the encryption round keys
+/// as needed.  This is for keys made without `AesKey::new_with_decryption`,
+/// where decryption is rare enough that they are not worth storing.
+#[target_feature(enable = "aes,sse2")]
+unsafe fn aes_decrypt_block(round_keys: &[__m128i], block_inout: &mut [u8]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let (first, rest) = round_keys.split_first().unwrap();
+        let (last, middle) = rest.split_last().unwrap();
+
+        let mut block = _mm_loadu_si128(block_inout.as_ptr() as *const _);
+        block = _mm_xor_si128(block, *last);
+        for rk in middle.iter().rev() {
+            block = _mm_aesdec_si128(block, _mm_aesimc_si128(*rk));
+        }
+        block = _mm_aesdeclast_si128(block, *first);
+        _mm_storeu_si128(block_inout.as_mut_ptr() as *mut _, block);
+    }
+}
+
+/// Derive the round keys for the equivalent inverse cipher (FIPS-197 section 5.3.5).
+///
+/// These are `round_keys` in reverse order, with InvMixColumns applied
+/// to all but the first and last.
+#[target_feature(enable = "aes,sse2")]
+unsafe fn inverse_round_keys(round_keys: &[__m128i]) -> [__m128i; 14 + 1] {
+    let mut out = [_mm_setzero_si128(); 14 + 1];
+    for (o, rk) in out.iter_mut().zip(round_keys.iter().rev()) {
+        *o = *rk;
+    }
+    for rk in &mut out[1..round_keys.len() - 1] {
+        *rk = _mm_aesimc_si128(*rk);
+    }
+    out
+}
+
+/// Decrypt one block, with the equivalent inverse cipher and its
+/// round keys from `inverse_round_keys`.
+#[target_feature(enable = "aes,sse2")]
+unsafe fn aes_inverse_cipher_block(dec_round_keys: &[__m128i], block_inout: &mut [u8]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let (first, rest) = dec_round_keys.split_first().unwrap();
+        let (last, middle) = rest.split_last().unwrap();
+
+        let mut block = _mm_loadu_si128(block_inout.as_ptr() as *const _);
+        block = _mm_xor_si128(block, *first);
+        for rk in middle {
+            block = _mm_aesdec_si128(block, *rk);
+        }
+        block = _mm_aesdeclast_si128(block, *last);
+        _mm_storeu_si128(block_inout.as_mut_ptr() as *mut _, block);
+    }
+}
@@ -0,0 +1,40 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! CPU feature detection for 32-bit x86.
+//!
+//! There are no secret-dependent CPU modes here, so the CPU state
+//! management is shared with the portable targets (see `generic::cpu`).
+
+use std::sync::OnceLock;
+
+/// The CPU features we use.
+///
+/// All are optional, and are used alongside SSE2 (which is part of
+/// the `i686` targets' baseline).
+pub(crate) struct Features {
+    /// AES-NI.
+    pub(crate) aes: bool,
+    /// Carryless multiplication.
+    pub(crate) pclmulqdq: bool,
+}
+
+impl Features {
+    fn detect() -> Self {
+        let sse2 = is_x86_feature_detected!("sse2");
+
+        Self {
+            aes: sse2 && is_x86_feature_detected!("aes"),
+            pclmulqdq: sse2 && is_x86_feature_detected!("pclmulqdq"),
+        }
+    }
+}
+
+/// Returns the CPU features, detecting them on first call.
+///
+/// Every dispatch decision is a function of these, so is made once
+/// per process.
+pub(crate) fn features() -> &'static Features {
+    static FEATURES: OnceLock<Features> = OnceLock::new();
+    FEATURES.get_or_init(Features::detect)
+}
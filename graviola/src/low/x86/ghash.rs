@@ -0,0 +1,153 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! GHASH using PCLMULQDQ, one block at a time.
+//!
+//! This is the x86_64 implementation (see low/x86_64/ghash.rs) restricted
+//! to SSE2 encodings, and without the eight-block path.
+
+use core::arch::x86::*;
+use core::mem;
+
+use crate::low;
+
+pub(crate) struct GhashTable {
+    h: __m128i,
+
+    /// `h.lo64 ^ h.hi64`
+    h_xor: __m128i,
+}
+
+impl GhashTable {
+    pub(crate) fn new(h: u128) -> Self {
+        // SAFETY: `sse2` is checked by `ghash_mux`
+        unsafe {
+            let h = gf128_big_endian(u128_to_m128i(h));
+            Self {
+                h,
+                h_xor: xor_halves(h),
+            }
+        }
+    }
+
+    /// Makes a table for inputs shorter than 128 bytes.
+    ///
+    /// This implementation only ever uses H, so this is `new`.
+    pub(crate) fn new_single_block(h: u128) -> Self {
+        Self::new(h)
+    }
+}
+
+impl Drop for GhashTable {
+    fn drop(&mut self) {
+        low::zeroise_value(&mut self.h);
+        low::zeroise_value(&mut self.h_xor);
+    }
+}
+
+pub(crate) struct Ghash<'a> {
+    table: &'a GhashTable,
+    current: __m128i,
+}
+
+impl<'a> Ghash<'a> {
+    pub(crate) fn new(table: &'a GhashTable) -> Self {
+        Self {
+            table,
+            current: u128_to_m128i(0),
+        }
+    }
+
+    /// Input `bytes` to the computation.
+    ///
+    /// `bytes` is zero-padded, if required.
+    pub(crate) fn add(&mut self, bytes: &[u8]) {
+        let mut whole_blocks = bytes.chunks_exact(16);
+
+        for chunk in whole_blocks.by_ref() {
+            let u = u128::from_be_bytes(chunk.try_into().unwrap());
+            self.one_block(u128_to_m128i(u));
+        }
+
+        let bytes = whole_blocks.remainder();
+        if !bytes.is_empty() {
+            let mut block = [0u8; 16];
+            block[..bytes.len()].copy_from_slice(bytes);
+
+            let u = u128::from_be_bytes(block);
+            self.one_block(u128_to_m128i(u));
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> [u8; 16] {
+        // SAFETY: sizeof(u128) == sizeof(__m128i), all bits have same meaning
+        let current: u128 = unsafe { mem::transmute(self.current) };
+        current.to_be_bytes()
+    }
+
+    fn one_block(&mut self, block: __m128i) {
+        // SAFETY: `pclmulqdq` and `sse2` are checked by `ghash_mux`
+        self.current = unsafe { mul(self.current, block, self.table) };
+    }
+}
+
+/// Computes `(current ^ block) * H`.
+#[target_feature(enable = "pclmulqdq,sse2")]
+unsafe fn mul(current: __m128i, block: __m128i, table: &GhashTable) -> __m128i {
+    let x = _mm_xor_si128(current, block);
+
+    let lo = _mm_clmulepi64_si128(x, table.h, 0x00);
+    let hi = _mm_clmulepi64_si128(x, table.h, 0x11);
+    let xx = _mm_xor_si128(_mm_shuffle_epi32(x, 0b01_00_11_10), x);
+    let mi = _mm_clmulepi64_si128(xx, table.h_xor, 0x00);
+
+    // reduce
+    let mi = _mm_xor_si128(mi, lo);
+    let mi = _mm_xor_si128(mi, hi);
+
+    let ls = _mm_shuffle_epi32(lo, 0b01_00_11_10);
+    let lo = _mm_clmulepi64_si128(GF128_POLY_HI, lo, 0x00);
+    let mi = _mm_xor_si128(mi, ls);
+    let mi = _mm_xor_si128(mi, lo);
+
+    let ms = _mm_shuffle_epi32(mi, 0b01_00_11_10);
+    let mi = _mm_clmulepi64_si128(GF128_POLY_HI, mi, 0x00);
+    let hi = _mm_xor_si128(hi, ms);
+    _mm_xor_si128(hi, mi)
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn gf128_big_endian(h: __m128i) -> __m128i {
+    // takes a raw hash subkey, and arranges that it can
+    // be used in big endian ordering.
+    let t = _mm_shuffle_epi32(h, 0b11_01_00_11);
+    let t = _mm_srai_epi32(t, 31);
+    let h = _mm_add_epi64(h, h);
+    let t = _mm_and_si128(GF128_POLY_CARRY_MASK, t);
+    _mm_xor_si128(h, t)
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn xor_halves(h: __m128i) -> __m128i {
+    let hx = _mm_shuffle_epi32(h, 0b01_00_11_10);
+    _mm_xor_si128(hx, h)
+}
+
+#[inline]
+fn u128_to_m128i(v: u128) -> __m128i {
+    // SAFETY: sizeof(u128) == sizeof(__m128i), all bits have same meaning
+    unsafe { mem::transmute(v) }
+}
+
+/// The high half of the ghash polynomial R, rotated left by one
+///
+/// R is 0xe100..00u128
+///
+/// We need this in a __m128i, but only the bottom 64-bits are used.
+// SAFETY: sizeof(u128) == sizeof(__m128i), all bits have same meaning
+const GF128_POLY_HI: __m128i = unsafe { mem::transmute(0xc2000000_00000000u128) };
+
+/// This is, again, R rotated left by one, but with a 2^64 term
+const GF128_POLY_CARRY_MASK: __m128i =
+    // SAFETY: as above
+    unsafe { mem::transmute(0xc2000000_00000001_00000000_00000001u128) };
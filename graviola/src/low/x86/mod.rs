@@ -0,0 +1,9 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+pub(crate) mod aes;
+pub(crate) mod aes_gcm_mux;
+pub(crate) mod aes_mux;
+pub(crate) mod cpu;
+pub(crate) mod ghash;
+pub(crate) mod ghash_mux;
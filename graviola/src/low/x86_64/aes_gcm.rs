@@ -7,6 +7,7 @@ use core::arch::x86_64::*;
 use core::mem;
 
 use super::aes::AesKey;
+use super::aes_gcm_vaes;
 use super::ghash::{self, Ghash};
 
 pub(crate) fn encrypt(
@@ -16,7 +17,7 @@ pub(crate) fn encrypt(
     aad: &[u8],
     cipher_inout: &mut [u8],
 ) {
-    // SAFETY: this crate requires the `aes`, `ssse3`, `pclmulqdq` and `avx` cpu features
+    // SAFETY: `aes` & `pclmulqdq` are checked by `aes_mux`; this crate requires the `ssse3` & `avx` cpu features
     unsafe { _cipher::<true>(key, ghash, initial_counter, aad, cipher_inout) }
 }
 
@@ -27,7 +28,7 @@ pub(crate) fn decrypt(
     aad: &[u8],
     cipher_inout: &mut [u8],
 ) {
-    // SAFETY: this crate requires the `aes`, `ssse3`, `pclmulqdq` and `avx` cpu features
+    // SAFETY: `aes` & `pclmulqdq` are checked by `aes_mux`; this crate requires the `ssse3` & `avx` cpu features
     unsafe { _cipher::<false>(key, ghash, initial_counter, aad, cipher_inout) }
 }
 
@@ -44,6 +45,25 @@ unsafe fn _cipher<const ENC: bool>(
     let (rk_first, rks, rk_last) = key.round_keys();
 
     let mut counter = Counter::new(initial_counter);
+
+    let cipher_inout = if aes_gcm_vaes::available() {
+        let (by16, rest) = cipher_inout.split_at_mut(cipher_inout.len() & !255);
+        // SAFETY: required cpu features checked by `aes_gcm_vaes::available()`
+        unsafe {
+            aes_gcm_vaes::cipher::<ENC>(
+                key.all_round_keys(),
+                &ghash.table.powers,
+                &mut ghash.current,
+                &counter.0,
+                by16,
+            );
+        }
+        counter.skip(by16.len() / 16);
+        rest
+    } else {
+        cipher_inout
+    };
+
     let mut by8_iter = cipher_inout.chunks_exact_mut(128);
 
     for blocks in by8_iter.by_ref() {
@@ -221,10 +241,21 @@ impl Counter {
             _mm_shuffle_epi8(self.0, BYTESWAP_EPI64)
         }
     }
+
+    /// Advance the counter past `blocks` blocks.
+    #[inline]
+    fn skip(&mut self, blocks: usize) {
+        // SAFETY: this crate requires the `avx` feature
+        unsafe {
+            self.0 = _mm_add_epi32(self.0, _mm_set_epi32(0, blocks as i32, 0, 0));
+        }
+    }
 }
 
 // SAFETY: both u128 and __m128i have the same size and all bits mean the same thing
 const COUNTER_1: __m128i = unsafe { mem::transmute(1u128 << 64) };
+// SAFETY: as above
 const BYTESWAP: __m128i = unsafe { mem::transmute(0x00010203_04050607_08090a0b_0c0d0e0fu128) };
 const BYTESWAP_EPI64: __m128i =
+    // SAFETY: as above
     unsafe { mem::transmute(0x08090a0b_0c0d0e0f_00010203_04050607u128) };
@@ -0,0 +1,101 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::aes_mux::AesKey;
+use super::ghash_mux::Ghash;
+use crate::low::generic;
+use crate::low::x86_64;
+
+pub(crate) fn encrypt(
+    key: &AesKey,
+    ghash: &mut Ghash<'_>,
+    initial_counter: &[u8; 16],
+    aad: &[u8],
+    cipher_inout: &mut [u8],
+) {
+    match (key, ghash) {
+        (AesKey::Aesni(key), Ghash::Clmul(ghash)) => {
+            x86_64::aes_gcm::encrypt(key, ghash, initial_counter, aad, cipher_inout)
+        }
+        (AesKey::Generic(key), Ghash::Generic(ghash)) => {
+            generic::aes_gcm::encrypt(key, ghash, initial_counter, aad, cipher_inout)
+        }
+        // the backend changed between making the key and the ghash table
+        (key, ghash) => {
+            ghash.add(aad);
+            key.ctr(initial_counter, cipher_inout);
+            ghash.add(cipher_inout);
+        }
+    }
+}
+
+pub(crate) fn decrypt(
+    key: &AesKey,
+    ghash: &mut Ghash<'_>,
+    initial_counter: &[u8; 16],
+    aad: &[u8],
+    cipher_inout: &mut [u8],
+) {
+    match (key, ghash) {
+        (AesKey::Aesni(key), Ghash::Clmul(ghash)) => {
+            x86_64::aes_gcm::decrypt(key, ghash, initial_counter, aad, cipher_inout)
+        }
+        (AesKey::Generic(key), Ghash::Generic(ghash)) => {
+            generic::aes_gcm::decrypt(key, ghash, initial_counter, aad, cipher_inout)
+        }
+        // the backend changed between making the key and the ghash table
+        (key, ghash) => {
+            ghash.add(aad);
+            ghash.add(cipher_inout);
+            key.ctr(initial_counter, cipher_inout);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ghash_mux::GhashTable;
+    use core::mem::ManuallyDrop;
+
+    use super::*;
+
+    #[test]
+    fn mismatched_implementations() {
+        if !x86_64::aes_mux::use_aesni() {
+            return;
+        }
+
+        let key = [0x2bu8; 16];
+        let aesni = AesKey::Aesni(ManuallyDrop::new(x86_64::aes::AesKey::new(&key)));
+        let generic = AesKey::Generic(ManuallyDrop::new(generic::aes::AesKey::new(&key)));
+
+        let mut h = [0u8; 16];
+        aesni.encrypt_block(&mut h);
+        let h = u128::from_be_bytes(h);
+        let clmul = GhashTable::Clmul(ManuallyDrop::new(x86_64::ghash::GhashTable::new(h)));
+        let portable = GhashTable::Generic(ManuallyDrop::new(generic::ghash::GhashTable::new(h)));
+
+        // includes a counter which wraps
+        let counter = *b"\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\xff\xff\xff\xfe";
+        let aad = b"additional data";
+        let plain = (0..300).map(|i| i as u8).collect::<Vec<_>>();
+
+        let mut expect = plain.clone();
+        let mut expect_ghash = Ghash::new(&clmul);
+        encrypt(&aesni, &mut expect_ghash, &counter, aad, &mut expect);
+        let expect_ghash = expect_ghash.into_bytes();
+
+        for (key, table) in [(&aesni, &portable), (&generic, &clmul)] {
+            let mut got = plain.clone();
+            let mut ghash = Ghash::new(table);
+            encrypt(key, &mut ghash, &counter, aad, &mut got);
+            assert_eq!(got, expect);
+            assert_eq!(ghash.into_bytes(), expect_ghash);
+
+            let mut ghash = Ghash::new(table);
+            decrypt(key, &mut ghash, &counter, aad, &mut got);
+            assert_eq!(got, plain);
+            assert_eq!(ghash.into_bytes(), expect_ghash);
+        }
+    }
+}
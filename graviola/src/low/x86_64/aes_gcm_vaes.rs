@@ -0,0 +1,455 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! AES-GCM bulk encryption and decryption using VAES and VPCLMULQDQ
+//! on 512-bit vectors.
+//!
+//! Each iteration processes 16 blocks: the AES rounds are done on four
+//! blocks per instruction, and GHASH multiplies each block by the
+//! appropriate power of H (up to H^16) before a single reduction.
+//!
+//! This is written in inline assembly (rather than intrinsics) so that
+//! the AVX-512 target features need not be enabled at compile time.
+//!
+//! Ref. <https://www.intel.com/content/www/us/en/content-details/671104/fast-crc-computation-for-generic-polynomials-using-pclmulqdq-instruction.html>
+//! for the data layout, and `ghash.rs` for the arithmetic.
+
+use core::arch::x86_64::*;
+
+use crate::low::x86_64::cpu::have_cpu_feature;
+
+/// Whether the CPU supports this implementation.
+pub(crate) fn available() -> bool {
+    have_cpu_feature!("avx512f")
+        && have_cpu_feature!("avx512bw")
+        && have_cpu_feature!("avx512vl")
+        && have_cpu_feature!("vaes")
+        && have_cpu_feature!("vpclmulqdq")
+}
+
+// Register allocation:
+//
+// zmm0-14:  round keys, each broadcast to all four lanes
+// zmm15:    BYTESWAP, in each lane
+// zmm16:    BYTESWAP_EPI64, in each lane
+// zmm17-20: powers of H: [H^16, H^15, H^14, H^13] to [H^4, H^3, H^2, H]
+// zmm21:    next four counters (pre-shuffle)
+// zmm22:    GF128_POLY_HI (low lane only)
+// zmm23-26: sixteen blocks of AES state, then ciphertext for GHASH
+// zmm27-29: GHASH product low, middle, high terms.  zmm29 also holds
+//           the GHASH state between iterations
+// zmm30-31: temporaries
+// rdi:      input/output pointer
+// rsi:      remaining 256-byte chunks
+
+macro_rules! setup {
+    () => {
+        concat!(
+            "vbroadcasti32x4 zmm0, [{round_keys}]\n",
+            "vbroadcasti32x4 zmm1, [{round_keys} + 16]\n",
+            "vbroadcasti32x4 zmm2, [{round_keys} + 32]\n",
+            "vbroadcasti32x4 zmm3, [{round_keys} + 48]\n",
+            "vbroadcasti32x4 zmm4, [{round_keys} + 64]\n",
+            "vbroadcasti32x4 zmm5, [{round_keys} + 80]\n",
+            "vbroadcasti32x4 zmm6, [{round_keys} + 96]\n",
+            "vbroadcasti32x4 zmm7, [{round_keys} + 112]\n",
+            "vbroadcasti32x4 zmm8, [{round_keys} + 128]\n",
+            "vbroadcasti32x4 zmm9, [{round_keys} + 144]\n",
+            "vbroadcasti32x4 zmm10, [{round_keys} + 160]\n",
+            "cmp {rounds}, 10\n",
+            "je 5f\n",
+            "vbroadcasti32x4 zmm11, [{round_keys} + 176]\n",
+            "vbroadcasti32x4 zmm12, [{round_keys} + 192]\n",
+            "vbroadcasti32x4 zmm13, [{round_keys} + 208]\n",
+            "vbroadcasti32x4 zmm14, [{round_keys} + 224]\n",
+            "5:\n",
+            "vmovdqu64 zmm15, [{constants}]\n",
+            "vmovdqu64 zmm16, [{constants} + 64]\n",
+            // powers are stored ascending; reverse the lanes of each group
+            "vmovdqu64 zmm17, [{powers} + 192]\n",
+            "vshufi64x2 zmm17, zmm17, zmm17, 0x1b\n",
+            "vmovdqu64 zmm18, [{powers} + 128]\n",
+            "vshufi64x2 zmm18, zmm18, zmm18, 0x1b\n",
+            "vmovdqu64 zmm19, [{powers} + 64]\n",
+            "vshufi64x2 zmm19, zmm19, zmm19, 0x1b\n",
+            "vmovdqu64 zmm20, [{powers}]\n",
+            "vshufi64x2 zmm20, zmm20, zmm20, 0x1b\n",
+            "vbroadcasti32x4 zmm21, [{counter}]\n",
+            "vpaddd zmm21, zmm21, [{constants} + 128]\n",
+            "vmovdqu64 xmm22, [{constants} + 256]\n",
+            "vmovdqu64 xmm29, [{current}]\n",
+        )
+    };
+}
+
+macro_rules! aes_ctr {
+    () => {
+        concat!(
+            "vpshufb zmm23, zmm21, zmm16\n",
+            "vpaddd zmm21, zmm21, [{constants} + 192]\n",
+            "vpshufb zmm24, zmm21, zmm16\n",
+            "vpaddd zmm21, zmm21, [{constants} + 192]\n",
+            "vpshufb zmm25, zmm21, zmm16\n",
+            "vpaddd zmm21, zmm21, [{constants} + 192]\n",
+            "vpshufb zmm26, zmm21, zmm16\n",
+            "vpaddd zmm21, zmm21, [{constants} + 192]\n",
+            "vpxord zmm23, zmm23, zmm0\n",
+            "vpxord zmm24, zmm24, zmm0\n",
+            "vpxord zmm25, zmm25, zmm0\n",
+            "vpxord zmm26, zmm26, zmm0\n",
+            aes_round!("zmm1"),
+            aes_round!("zmm2"),
+            aes_round!("zmm3"),
+            aes_round!("zmm4"),
+            aes_round!("zmm5"),
+            aes_round!("zmm6"),
+            aes_round!("zmm7"),
+            aes_round!("zmm8"),
+            aes_round!("zmm9"),
+            "cmp {rounds}, 10\n",
+            "je 3f\n",
+            aes_round!("zmm10"),
+            aes_round!("zmm11"),
+            aes_round!("zmm12"),
+            aes_round!("zmm13"),
+            aes_last_round!("zmm14"),
+            "jmp 4f\n",
+            "3:\n",
+            aes_last_round!("zmm10"),
+            "4:\n",
+        )
+    };
+}
+
+macro_rules! aes_round {
+    ($rk:literal) => {
+        concat!(
+            "vaesenc zmm23, zmm23, ",
+            $rk,
+            "\n",
+            "vaesenc zmm24, zmm24, ",
+            $rk,
+            "\n",
+            "vaesenc zmm25, zmm25, ",
+            $rk,
+            "\n",
+            "vaesenc zmm26, zmm26, ",
+            $rk,
+            "\n",
+        )
+    };
+}
+
+macro_rules! aes_last_round {
+    ($rk:literal) => {
+        concat!(
+            "vaesenclast zmm23, zmm23, ",
+            $rk,
+            "\n",
+            "vaesenclast zmm24, zmm24, ",
+            $rk,
+            "\n",
+            "vaesenclast zmm25, zmm25, ",
+            $rk,
+            "\n",
+            "vaesenclast zmm26, zmm26, ",
+            $rk,
+            "\n",
+        )
+    };
+}
+
+macro_rules! ghash {
+    () => {
+        concat!(
+            // the first block is combined with the current state
+            "vpxord zmm23, zmm23, zmm29\n",
+            "vpclmulqdq zmm27, zmm23, zmm17, 0x00\n",
+            "vpclmulqdq zmm29, zmm23, zmm17, 0x11\n",
+            "vpclmulqdq zmm28, zmm23, zmm17, 0x01\n",
+            "vpclmulqdq zmm30, zmm23, zmm17, 0x10\n",
+            "vpxord zmm28, zmm28, zmm30\n",
+            ghash_mul!("zmm24", "zmm18"),
+            ghash_mul!("zmm25", "zmm19"),
+            ghash_mul!("zmm26", "zmm20"),
+            // sum the lanes of each term
+            fold_lanes!("27"),
+            fold_lanes!("28"),
+            fold_lanes!("29"),
+            // and reduce, as `reduce!` in ghash.rs
+            "vpshufd xmm30, xmm27, 0x4e\n",
+            "vpclmulqdq xmm27, xmm27, xmm22, 0x00\n",
+            "vpternlogd xmm28, xmm30, xmm27, 0x96\n",
+            "vpshufd xmm30, xmm28, 0x4e\n",
+            "vpclmulqdq xmm28, xmm28, xmm22, 0x00\n",
+            "vpternlogd xmm29, xmm30, xmm28, 0x96\n",
+        )
+    };
+}
+
+macro_rules! ghash_mul {
+    ($x:literal, $h:literal) => {
+        concat!(
+            "vpclmulqdq zmm30, ",
+            $x,
+            ", ",
+            $h,
+            ", 0x00\n",
+            "vpclmulqdq zmm31, ",
+            $x,
+            ", ",
+            $h,
+            ", 0x11\n",
+            "vpxord zmm27, zmm27, zmm30\n",
+            "vpxord zmm29, zmm29, zmm31\n",
+            "vpclmulqdq zmm30, ",
+            $x,
+            ", ",
+            $h,
+            ", 0x01\n",
+            "vpclmulqdq zmm31, ",
+            $x,
+            ", ",
+            $h,
+            ", 0x10\n",
+            "vpternlogd zmm28, zmm30, zmm31, 0x96\n",
+        )
+    };
+}
+
+macro_rules! fold_lanes {
+    ($n:literal) => {
+        concat!(
+            "vextracti64x4 ymm30, zmm",
+            $n,
+            ", 1\n",
+            "vpxord ymm",
+            $n,
+            ", ymm",
+            $n,
+            ", ymm30\n",
+            "vextracti32x4 xmm30, ymm",
+            $n,
+            ", 1\n",
+            "vpxord xmm",
+            $n,
+            ", xmm",
+            $n,
+            ", xmm30\n",
+        )
+    };
+}
+
+macro_rules! finish {
+    () => {
+        concat!(
+            "vmovdqu64 [{current}], xmm29\n",
+            // clear zmm16-31 (evex-encoded xor zeroes the whole register) ...
+            "vpxord xmm16, xmm16, xmm16\n",
+            "vpxord xmm17, xmm17, xmm17\n",
+            "vpxord xmm18, xmm18, xmm18\n",
+            "vpxord xmm19, xmm19, xmm19\n",
+            "vpxord xmm20, xmm20, xmm20\n",
+            "vpxord xmm21, xmm21, xmm21\n",
+            "vpxord xmm22, xmm22, xmm22\n",
+            "vpxord xmm23, xmm23, xmm23\n",
+            "vpxord xmm24, xmm24, xmm24\n",
+            "vpxord xmm25, xmm25, xmm25\n",
+            "vpxord xmm26, xmm26, xmm26\n",
+            "vpxord xmm27, xmm27, xmm27\n",
+            "vpxord xmm28, xmm28, xmm28\n",
+            "vpxord xmm29, xmm29, xmm29\n",
+            "vpxord xmm30, xmm30, xmm30\n",
+            "vpxord xmm31, xmm31, xmm31\n",
+            // ... and zmm0-15
+            "vzeroall\n",
+        )
+    };
+}
+
+/// Encrypt (if `ENC`) or decrypt `inout` in place, in AES-CTR mode,
+/// and input the ciphertext to GHASH.
+///
+/// `inout.len()` must be a multiple of 256.
+///
+/// `round_keys` is the expanded AES-128 or AES-256 key.  `powers`
+/// are H to H^16 and `current` is the GHASH state, both in the
+/// representation used by `ghash.rs`.  `counter` is the counter
+/// in the representation used by `aes_gcm.rs`: the first block uses
+/// `counter + 1`.  The caller must advance the counter by
+/// `inout.len() / 16`.
+///
+/// # Safety
+/// The caller must ensure [`available()`] is true.
+pub(super) unsafe fn cipher<const ENC: bool>(
+    round_keys: &[__m128i],
+    powers: &[__m128i; 16],
+    current: &mut __m128i,
+    counter: &__m128i,
+    inout: &mut [u8],
+) {
+    assert_eq!(inout.len() % 256, 0);
+    assert!(round_keys.len() == 11 || round_keys.len() == 15);
+
+    let chunks = inout.len() / 256;
+    if chunks == 0 {
+        return;
+    }
+
+    // SAFETY: inline assembly. see [crate::low::inline_assembly_safety] for safety info.
+    // The caller guarantees the required CPU features.  Reads `round_keys`,
+    // `powers`, `counter` and `CONSTANTS`, reads and writes `current` and
+    // `chunks * 256` bytes of `inout`.
+    unsafe {
+        if ENC {
+            core::arch::asm!(
+                setup!(),
+                "2:",
+                aes_ctr!(),
+                // ciphertext is the AES output, xor the input
+                "vpxord zmm23, zmm23, [rdi]",
+                "vpxord zmm24, zmm24, [rdi + 64]",
+                "vpxord zmm25, zmm25, [rdi + 128]",
+                "vpxord zmm26, zmm26, [rdi + 192]",
+                "vmovdqu64 [rdi], zmm23",
+                "vmovdqu64 [rdi + 64], zmm24",
+                "vmovdqu64 [rdi + 128], zmm25",
+                "vmovdqu64 [rdi + 192], zmm26",
+                "vpshufb zmm23, zmm23, zmm15",
+                "vpshufb zmm24, zmm24, zmm15",
+                "vpshufb zmm25, zmm25, zmm15",
+                "vpshufb zmm26, zmm26, zmm15",
+                ghash!(),
+                "add rdi, 256",
+                "dec rsi",
+                "jnz 2b",
+                finish!(),
+                round_keys = in(reg) round_keys.as_ptr(),
+                rounds = in(reg) round_keys.len() - 1,
+                powers = in(reg) powers.as_ptr(),
+                constants = in(reg) &CONSTANTS as *const Constants,
+                current = in(reg) current as *mut __m128i,
+                counter = in(reg) counter as *const __m128i,
+                inout("rdi") inout.as_mut_ptr() => _,
+                inout("rsi") chunks => _,
+                clobber_abi("C"),
+                options(nostack),
+            );
+        } else {
+            core::arch::asm!(
+                setup!(),
+                "2:",
+                aes_ctr!(),
+                // ciphertext is the input; keep it in zmm30 for ghash
+                "vmovdqu64 zmm30, [rdi]",
+                "vpxord zmm23, zmm23, zmm30",
+                "vmovdqu64 [rdi], zmm23",
+                "vpshufb zmm23, zmm30, zmm15",
+                "vmovdqu64 zmm30, [rdi + 64]",
+                "vpxord zmm24, zmm24, zmm30",
+                "vmovdqu64 [rdi + 64], zmm24",
+                "vpshufb zmm24, zmm30, zmm15",
+                "vmovdqu64 zmm30, [rdi + 128]",
+                "vpxord zmm25, zmm25, zmm30",
+                "vmovdqu64 [rdi + 128], zmm25",
+                "vpshufb zmm25, zmm30, zmm15",
+                "vmovdqu64 zmm30, [rdi + 192]",
+                "vpxord zmm26, zmm26, zmm30",
+                "vmovdqu64 [rdi + 192], zmm26",
+                "vpshufb zmm26, zmm30, zmm15",
+                ghash!(),
+                "add rdi, 256",
+                "dec rsi",
+                "jnz 2b",
+                finish!(),
+                round_keys = in(reg) round_keys.as_ptr(),
+                rounds = in(reg) round_keys.len() - 1,
+                powers = in(reg) powers.as_ptr(),
+                constants = in(reg) &CONSTANTS as *const Constants,
+                current = in(reg) current as *mut __m128i,
+                counter = in(reg) counter as *const __m128i,
+                inout("rdi") inout.as_mut_ptr() => _,
+                inout("rsi") chunks => _,
+                clobber_abi("C"),
+                options(nostack),
+            );
+        }
+    }
+}
+
+#[repr(C, align(64))]
+struct Constants {
+    byteswap: [u128; 4],
+    byteswap_epi64: [u128; 4],
+    counter_offsets: [u128; 4],
+    counter_step: [u128; 4],
+    poly: [u128; 4],
+}
+
+/// Constants, as in `aes_gcm.rs` and `ghash.rs`, for each lane.
+static CONSTANTS: Constants = Constants {
+    byteswap: [0x00010203_04050607_08090a0b_0c0d0e0f; 4],
+    byteswap_epi64: [0x08090a0b_0c0d0e0f_00010203_04050607; 4],
+    counter_offsets: [1 << 64, 2 << 64, 3 << 64, 4 << 64],
+    counter_step: [4 << 64; 4],
+    poly: [0xc2000000_00000000, 0, 0, 0],
+};
+
+#[cfg(test)]
+mod tests {
+    use crate::low::{generic, x86_64};
+
+    #[test]
+    fn matches_generic() {
+        if !super::available() {
+            return;
+        }
+
+        for key in [&[0x2bu8; 16][..], &[0x7eu8; 32][..]] {
+            let fast = x86_64::aes::AesKey::new(key);
+            let slow = generic::aes::AesKey::new(key);
+
+            let mut h = [0u8; 16];
+            fast.encrypt_block(&mut h);
+            let h = u128::from_be_bytes(h);
+            let fast_table = x86_64::ghash::GhashTable::new(h);
+            let slow_table = generic::ghash::GhashTable::new(h);
+
+            for counter in [
+                [0u8; 16],
+                // includes a counter which wraps
+                *b"\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\xff\xff\xff\xf0",
+            ] {
+                for len in [
+                    0,
+                    255,
+                    256,
+                    257,
+                    256 + 128,
+                    512 + 128 + 16 + 5,
+                    1024,
+                    4096 + 7,
+                ] {
+                    let plain = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+                    let aad = b"additional data";
+
+                    let mut expect = plain.clone();
+                    let mut expect_ghash = generic::ghash::Ghash::new(&slow_table);
+                    generic::aes_gcm::encrypt(&slow, &mut expect_ghash, &counter, aad, &mut expect);
+                    let expect_ghash = expect_ghash.into_bytes();
+
+                    let mut got = plain.clone();
+                    let mut ghash = x86_64::ghash::Ghash::new(&fast_table);
+                    x86_64::aes_gcm::encrypt(&fast, &mut ghash, &counter, aad, &mut got);
+                    assert_eq!(got, expect, "len={len}");
+                    assert_eq!(ghash.into_bytes(), expect_ghash, "len={len}");
+
+                    let mut ghash = x86_64::ghash::Ghash::new(&fast_table);
+                    x86_64::aes_gcm::decrypt(&fast, &mut ghash, &counter, aad, &mut got);
+                    assert_eq!(got, plain, "len={len}");
+                    assert_eq!(ghash.into_bytes(), expect_ghash, "len={len}");
+                }
+            }
+        }
+    }
+}
@@ -5,8 +5,8 @@
 use core::arch::x86_64::*;
 
 pub(crate) struct ChaCha20 {
-    z07: __m256i,
-    z8f: __m256i,
+    pub(super) z07: __m256i,
+    pub(super) z8f: __m256i,
 }
 
 impl ChaCha20 {
@@ -45,6 +45,10 @@ impl XChaCha20 {
     pub(crate) fn cipher(&mut self, buffer: &mut [u8]) {
         self.0.cipher(buffer);
     }
+
+    pub(crate) fn as_chacha20(&mut self) -> &mut ChaCha20 {
+        &mut self.0
+    }
 }
 
 macro_rules! rotate_left {
@@ -115,7 +119,8 @@ unsafe fn format_key(key: &[u8; 32], nonce: &[u8; 16]) -> ChaCha20 {
 
 /// Computes 8 blocks.  Does _NOT_ handle ragged output.
 #[target_feature(enable = "avx2")]
-unsafe fn core_8x(t07: __m256i, z8f: &mut __m256i, xor_out_512: &mut [u8]) {
+#[inline]
+pub(super) unsafe fn core_8x(t07: __m256i, z8f: &mut __m256i, xor_out_512: &mut [u8]) {
     // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
     unsafe {
         let t8f = *z8f;
@@ -0,0 +1,152 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! Stitched ChaCha20 and Poly1305.
+//!
+//! This computes eight ChaCha20 blocks alongside eight Poly1305
+//! blocks (using the four-way implementation in `poly1305.rs`), so
+//! the CPU can overlap the two.  When encrypting, Poly1305 necessarily
+//! lags ChaCha20 by 512 bytes.
+
+use super::chacha20::{ChaCha20, core_8x};
+use super::poly1305::{Poly1305x4, use_avx2};
+use crate::low::poly1305::Poly1305;
+
+pub(crate) fn encrypt(chacha: &mut ChaCha20, poly: &mut Poly1305, cipher_inout: &mut [u8]) {
+    // SAFETY: this crate requires the `avx2` cpu feature
+    unsafe { _cipher::<true>(chacha, poly, cipher_inout) }
+}
+
+pub(crate) fn decrypt(chacha: &mut ChaCha20, poly: &mut Poly1305, cipher_inout: &mut [u8]) {
+    // SAFETY: this crate requires the `avx2` cpu feature
+    unsafe { _cipher::<false>(chacha, poly, cipher_inout) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn _cipher<const ENC: bool>(
+    chacha: &mut ChaCha20,
+    poly: &mut Poly1305,
+    cipher_inout: &mut [u8],
+) {
+    let (by8, rest) = cipher_inout.split_at_mut(cipher_inout.len() & !511);
+
+    if !by8.is_empty() {
+        if let Some((h, r)) = poly.whole_block_state().filter(|_| use_avx2()) {
+            // SAFETY: this crate requires the `avx2` cpu feature
+            unsafe {
+                let mut poly_x4 = Poly1305x4::new(h, r);
+
+                if ENC {
+                    let mut previous: Option<&[u8]> = None;
+                    for chunk in by8.chunks_exact_mut(512) {
+                        encrypt_by8(chacha, &mut poly_x4, chunk, previous);
+                        previous = Some(chunk);
+                    }
+                    if let Some(previous) = previous {
+                        poly_x4.blocks(previous);
+                    }
+                } else {
+                    for chunk in by8.chunks_exact_mut(512) {
+                        decrypt_by8(chacha, &mut poly_x4, chunk);
+                    }
+                }
+
+                poly_x4.finish(h);
+            }
+        } else {
+            // not block-aligned (or not allowed): cannot use `Poly1305x4`
+            match ENC {
+                true => {
+                    chacha.cipher(by8);
+                    poly.add_bytes(by8);
+                }
+                false => {
+                    poly.add_bytes(by8);
+                    chacha.cipher(by8);
+                }
+            }
+        }
+    }
+
+    match ENC {
+        true => {
+            chacha.cipher(rest);
+            poly.add_bytes(rest);
+        }
+        false => {
+            poly.add_bytes(rest);
+            chacha.cipher(rest);
+        }
+    }
+}
+
+/// Encrypt `chunk`, while authenticating the `previous` chunk's ciphertext.
+#[target_feature(enable = "avx2")]
+#[inline]
+unsafe fn encrypt_by8(
+    chacha: &mut ChaCha20,
+    poly_x4: &mut Poly1305x4,
+    chunk: &mut [u8],
+    previous: Option<&[u8]>,
+) {
+    // SAFETY: this crate requires the `avx2` cpu feature
+    unsafe {
+        if let Some(previous) = previous {
+            poly_x4.blocks(previous);
+        }
+        core_8x(chacha.z07, &mut chacha.z8f, chunk);
+    }
+}
+
+/// Authenticate and decrypt `chunk`.
+#[target_feature(enable = "avx2")]
+#[inline]
+unsafe fn decrypt_by8(chacha: &mut ChaCha20, poly_x4: &mut Poly1305x4, chunk: &mut [u8]) {
+    // SAFETY: this crate requires the `avx2` cpu feature
+    unsafe {
+        poly_x4.blocks(chunk);
+        core_8x(chacha.z07, &mut chacha.z8f, chunk);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_unstitched() {
+        let key = [0x42u8; 32];
+        let nonce = [0x24u8; 16];
+        let plain = (0..4096 + 100).map(|i| i as u8).collect::<Vec<_>>();
+
+        for aad_len in [0, 3, 16] {
+            for len in [0, 100, 512, 513, 1024, 1024 + 64 + 7, 4096 + 100] {
+                let plain = &plain[..len];
+                let aad = &[0xaa; 16][..aad_len];
+
+                let mut expect = plain.to_vec();
+                let mut chacha = ChaCha20::new(&key, &nonce);
+                let mut poly = Poly1305::new(&[0x11; 32]);
+                poly.add_bytes(aad);
+                chacha.cipher(&mut expect);
+                poly.add_bytes(&expect);
+                let expect_tag = poly.finish();
+
+                let mut got = plain.to_vec();
+                let mut chacha = ChaCha20::new(&key, &nonce);
+                let mut poly = Poly1305::new(&[0x11; 32]);
+                poly.add_bytes(aad);
+                encrypt(&mut chacha, &mut poly, &mut got);
+                assert_eq!(got, expect, "len={len} aad_len={aad_len}");
+                assert_eq!(poly.finish(), expect_tag, "len={len} aad_len={aad_len}");
+
+                let mut chacha = ChaCha20::new(&key, &nonce);
+                let mut poly = Poly1305::new(&[0x11; 32]);
+                poly.add_bytes(aad);
+                decrypt(&mut chacha, &mut poly, &mut got);
+                assert_eq!(got, plain, "len={len} aad_len={aad_len}");
+                assert_eq!(poly.finish(), expect_tag, "len={len} aad_len={aad_len}");
+            }
+        }
+    }
+}
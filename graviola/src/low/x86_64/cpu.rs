@@ -1,6 +1,8 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
+use std::sync::OnceLock;
+
 pub(crate) fn enter_cpu_state() -> u32 {
     // DOIT: "Data Operand Independent Timing" -- turning this on
     // is under kernel control, because MSRs are privileged.
@@ -18,7 +20,8 @@ pub(crate) fn leave_cpu_state(_old: u32) {
             // clear z/y/xmm0-15
             "   vzeroall",
 
-            // TODO: add zmm16-31 here if/when we use AVX512
+            // nb. zmm16-31 are cleared by `aes_gcm_vaes`, our only
+            // user of them.
             out("ymm0") _,
             out("ymm1") _,
             out("ymm2") _,
@@ -113,44 +116,104 @@ pub(in crate::low) unsafe fn ct_compare_bytes(a: *const u8, b: *const u8, len: u
     acc
 }
 
-/// This macro interdicts is_x86_feature_detected to
-/// allow testability.
+/// Queries a CPU feature.
+///
+/// Features are detected once, on first use: see [`features()`].
 macro_rules! have_cpu_feature {
     ("aes") => {
-        crate::low::x86_64::cpu::test_toggle("aes", is_x86_feature_detected!("aes"))
+        crate::low::x86_64::cpu::features().aes
     };
     ("pclmulqdq") => {
-        crate::low::x86_64::cpu::test_toggle("pclmulqdq", is_x86_feature_detected!("pclmulqdq"))
+        crate::low::x86_64::cpu::features().pclmulqdq
     };
     ("bmi1") => {
-        crate::low::x86_64::cpu::test_toggle("bmi1", is_x86_feature_detected!("bmi1"))
+        crate::low::x86_64::cpu::features().bmi1
     };
     ("bmi2") => {
-        crate::low::x86_64::cpu::test_toggle("bmi2", is_x86_feature_detected!("bmi2"))
-    };
-    ("adx") => {
-        crate::low::x86_64::cpu::test_toggle("adx", is_x86_feature_detected!("adx"))
+        crate::low::x86_64::cpu::features().bmi2
     };
     ("avx") => {
-        crate::low::x86_64::cpu::test_toggle("avx", is_x86_feature_detected!("avx"))
+        crate::low::x86_64::cpu::features().avx
     };
     ("avx2") => {
-        crate::low::x86_64::cpu::test_toggle("avx2", is_x86_feature_detected!("avx2"))
+        crate::low::x86_64::cpu::features().avx2
     };
     ("sha") => {
-        crate::low::x86_64::cpu::test_toggle("sha", is_x86_feature_detected!("sha"))
+        crate::low::x86_64::cpu::features().sha
+    };
+    ("avx512f") => {
+        crate::low::x86_64::cpu::features().avx512f
+    };
+    ("avx512bw") => {
+        crate::low::x86_64::cpu::features().avx512bw
+    };
+    ("avx512vl") => {
+        crate::low::x86_64::cpu::features().avx512vl
+    };
+    ("vaes") => {
+        crate::low::x86_64::cpu::features().vaes
+    };
+    ("vpclmulqdq") => {
+        crate::low::x86_64::cpu::features().vpclmulqdq
     };
 }
 
 pub(crate) use have_cpu_feature;
 
+/// The CPU features we use.
+pub(crate) struct Features {
+    pub(crate) aes: bool,
+    pub(crate) pclmulqdq: bool,
+    pub(crate) bmi1: bool,
+    pub(crate) bmi2: bool,
+    pub(crate) avx: bool,
+    pub(crate) avx2: bool,
+    pub(crate) sha: bool,
+    pub(crate) avx512f: bool,
+    pub(crate) avx512bw: bool,
+    pub(crate) avx512vl: bool,
+    pub(crate) vaes: bool,
+    pub(crate) vpclmulqdq: bool,
+}
+
+impl Features {
+    fn detect() -> Self {
+        Self {
+            aes: test_toggle("aes", is_x86_feature_detected!("aes")),
+            pclmulqdq: test_toggle("pclmulqdq", is_x86_feature_detected!("pclmulqdq")),
+            bmi1: test_toggle("bmi1", is_x86_feature_detected!("bmi1")),
+            bmi2: test_toggle("bmi2", is_x86_feature_detected!("bmi2")),
+            // nb. not subject to `test_toggle`, as it is required by
+            // `leave_cpu_state` and `zero_bytes`.
+            avx: is_x86_feature_detected!("avx"),
+            avx2: test_toggle("avx2", is_x86_feature_detected!("avx2")),
+            sha: test_toggle("sha", is_x86_feature_detected!("sha")),
+            avx512f: test_toggle("avx512f", is_x86_feature_detected!("avx512f")),
+            avx512bw: test_toggle("avx512bw", is_x86_feature_detected!("avx512bw")),
+            avx512vl: test_toggle("avx512vl", is_x86_feature_detected!("avx512vl")),
+            vaes: test_toggle("vaes", is_x86_feature_detected!("vaes")),
+            vpclmulqdq: test_toggle("vpclmulqdq", is_x86_feature_detected!("vpclmulqdq")),
+        }
+    }
+}
+
+/// Returns the CPU features, detecting them on first call.
+///
+/// Every dispatch decision is a function of these, so is made once
+/// per process.
+pub(crate) fn features() -> &'static Features {
+    static FEATURES: OnceLock<Features> = OnceLock::new();
+    FEATURES.get_or_init(Features::detect)
+}
+
+/// This interdicts is_x86_feature_detected to allow testability.
 #[cfg(not(debug_assertions))]
-pub(crate) fn test_toggle(_id: &str, detected: bool) -> bool {
+fn test_toggle(_id: &str, detected: bool) -> bool {
     detected
 }
 
 #[cfg(debug_assertions)]
-pub(crate) fn test_toggle(id: &str, detected: bool) -> bool {
+fn test_toggle(id: &str, detected: bool) -> bool {
     if std::env::var(format!("GRAVIOLA_CPU_DISABLE_{id}")).is_ok() {
         println!("DEBUG: denying cpuid {id:?}");
         false
@@ -164,15 +227,8 @@ pub(crate) fn verify_cpu_features() {
     // this limits the library to x86_64 processors released after approx 2013.
 
     // mandatory feature requirements
-    // our aes-gcm
-    assert!(
-        have_cpu_feature!("aes"),
-        "graviola requires aes CPU support"
-    );
-    assert!(
-        have_cpu_feature!("pclmulqdq"),
-        "graviola requires pclmulqdq CPU support"
-    );
+    //
+    // nb. `aes` and `pclmulqdq` are optional: see `aes_mux`.
 
     // s2n-bignum non _alt versions
     assert!(
@@ -193,7 +249,7 @@ pub(crate) fn verify_cpu_features() {
 
     // assorted intrinsic code
     assert!(
-        is_x86_feature_detected!("avx"),
+        have_cpu_feature!("avx"),
         "graviola requires avx CPU support"
     );
     assert!(
@@ -10,11 +10,15 @@
 use core::arch::x86_64::*;
 use core::mem;
 
+use super::aes_gcm_vaes;
 use crate::low;
 
 pub(crate) struct GhashTable {
     /// H, H^2, H^3, H^4, ... H^8
-    powers: [__m128i; 8],
+    ///
+    /// Then H^9 to H^16, only if used by `aes_gcm_vaes`.  Otherwise these
+    /// are zero.
+    pub(crate) powers: [__m128i; 16],
 
     /// `powers_xor[i]` is `powers[i].lo64 ^ powers[i].hi64`
     ///
@@ -24,20 +28,37 @@ pub(crate) struct GhashTable {
 
 impl GhashTable {
     pub(crate) fn new(h: u128) -> Self {
-        let mut powers = [zero(); 8];
-        let mut powers_xor = powers;
+        let count = match aes_gcm_vaes::available() {
+            true => 16,
+            false => 8,
+        };
+        Self::with_powers(h, count)
+    }
+
+    /// Makes a table containing only H.
+    ///
+    /// This is cheaper to make, but is only valid for inputs (to
+    /// `Ghash::add` and `aes_gcm`) shorter than 128 bytes, which never
+    /// reach the multi-block paths.
+    pub(crate) fn new_single_block(h: u128) -> Self {
+        Self::with_powers(h, 1)
+    }
+
+    fn with_powers(h: u128, count: usize) -> Self {
+        let mut powers = [zero(); 16];
+        let mut powers_xor = [zero(); 8];
         let h = u128_to_m128i(h);
 
         // SAFETY: this crate requires the `avx` cpu feature
         let h = unsafe { gf128_big_endian(h) };
         powers[0] = h;
 
-        for i in 1..8 {
-            // SAFETY: this crate requires the `avx` and `pclmulqdq` cpu features
+        for i in 1..count {
+            // SAFETY: `pclmulqdq` is checked by `aes_mux`; this crate requires the `avx` cpu feature
             powers[i] = unsafe { _mul(powers[i - 1], h) };
         }
 
-        for i in 0..8 {
+        for i in 0..count.min(8) {
             // SAFETY: this crate requires the `avx` cpu feature
             powers_xor[i] = unsafe { xor_halves(powers[i]) };
         }
@@ -122,7 +143,7 @@ impl<'a> Ghash<'a> {
     }
 
     fn one_block(&mut self, block: __m128i) {
-        // SAFETY: this crate requires the `avx` and `pclmulqdq` cpu features
+        // SAFETY: `pclmulqdq` is checked by `aes_mux`; this crate requires the `avx` cpu feature
         unsafe {
             self.current = _mm_xor_si128(self.current, block);
             self.current = _mul(self.current, self.table.powers[0]);
@@ -141,7 +162,7 @@ impl<'a> Ghash<'a> {
         b7: __m128i,
         b8: __m128i,
     ) {
-        // SAFETY: this crate requires the `avx` and `pclmulqdq` cpu features
+        // SAFETY: `pclmulqdq` is checked by `aes_mux`; this crate requires the `avx` cpu feature
         unsafe {
             let b1 = _mm_xor_si128(self.current, b1);
             self.current = _mul8(self.table, b1, b2, b3, b4, b5, b6, b7, b8);
@@ -208,42 +229,33 @@ pub(crate) unsafe fn _mul8(
     x7: __m128i,
     x8: __m128i,
 ) -> __m128i {
-    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
-    unsafe {
-        let (mut lo, mut mi, mut hi) = (zero(), zero(), zero());
-        mul!(lo, mi, hi, x1, table.powers[7], table.powers_xor[7]);
-        mul!(lo, mi, hi, x2, table.powers[6], table.powers_xor[6]);
-        mul!(lo, mi, hi, x3, table.powers[5], table.powers_xor[5]);
-        mul!(lo, mi, hi, x4, table.powers[4], table.powers_xor[4]);
-        mul!(lo, mi, hi, x5, table.powers[3], table.powers_xor[3]);
-        mul!(lo, mi, hi, x6, table.powers[2], table.powers_xor[2]);
-        mul!(lo, mi, hi, x7, table.powers[1], table.powers_xor[1]);
-        mul!(lo, mi, hi, x8, table.powers[0], table.powers_xor[0]);
-        reduce!(lo, mi, hi)
-    }
+    let (mut lo, mut mi, mut hi) = (zero(), zero(), zero());
+    mul!(lo, mi, hi, x1, table.powers[7], table.powers_xor[7]);
+    mul!(lo, mi, hi, x2, table.powers[6], table.powers_xor[6]);
+    mul!(lo, mi, hi, x3, table.powers[5], table.powers_xor[5]);
+    mul!(lo, mi, hi, x4, table.powers[4], table.powers_xor[4]);
+    mul!(lo, mi, hi, x5, table.powers[3], table.powers_xor[3]);
+    mul!(lo, mi, hi, x6, table.powers[2], table.powers_xor[2]);
+    mul!(lo, mi, hi, x7, table.powers[1], table.powers_xor[1]);
+    mul!(lo, mi, hi, x8, table.powers[0], table.powers_xor[0]);
+    reduce!(lo, mi, hi)
 }
 
 #[target_feature(enable = "avx")]
 unsafe fn gf128_big_endian(h: __m128i) -> __m128i {
-    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
-    unsafe {
-        // takes a raw hash subkey, and arranges that it can
-        // be used in big endian ordering.
-        let t = _mm_shuffle_epi32(h, 0b11_01_00_11);
-        let t = _mm_srai_epi32(t, 31);
-        let h = _mm_add_epi64(h, h);
-        let t = _mm_and_si128(GF128_POLY_CARRY_MASK, t);
-        _mm_xor_si128(h, t)
-    }
+    // takes a raw hash subkey, and arranges that it can
+    // be used in big endian ordering.
+    let t = _mm_shuffle_epi32(h, 0b11_01_00_11);
+    let t = _mm_srai_epi32(t, 31);
+    let h = _mm_add_epi64(h, h);
+    let t = _mm_and_si128(GF128_POLY_CARRY_MASK, t);
+    _mm_xor_si128(h, t)
 }
 
 #[target_feature(enable = "avx")]
 unsafe fn xor_halves(h: __m128i) -> __m128i {
-    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
-    unsafe {
-        let hx = _mm_shuffle_epi32(h, 0b01_00_11_10);
-        _mm_xor_si128(hx, h)
-    }
+    let hx = _mm_shuffle_epi32(h, 0b01_00_11_10);
+    _mm_xor_si128(hx, h)
 }
 
 #[inline]
@@ -258,6 +270,7 @@ fn u128_to_m128i(v: u128) -> __m128i {
     unsafe { mem::transmute(v) }
 }
 
+// SAFETY: sizeof(u128) == sizeof(__m128i), all bits have same meaning
 const BYTESWAP: __m128i = unsafe { mem::transmute(0x00010203_04050607_08090a0b_0c0d0e0fu128) };
 
 /// The high half of the ghash polynomial R, rotated left by one
@@ -265,10 +278,12 @@ const BYTESWAP: __m128i = unsafe { mem::transmute(0x00010203_04050607_08090a0b_0
 /// R is 0xe100..00u128
 ///
 /// We need this in a __m128i, but only the bottom 64-bits are used.
+// SAFETY: as above
 const GF128_POLY_HI: __m128i = unsafe { mem::transmute(0xc2000000_00000000u128) };
 
 /// This is, again, R rotated left by one, but with a 2^64 term
 const GF128_POLY_CARRY_MASK: __m128i =
+    // SAFETY: as above
     unsafe { mem::transmute(0xc2000000_00000001_00000000_00000001u128) };
 
 #[cfg(test)]
@@ -0,0 +1,84 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use core::mem::ManuallyDrop;
+
+use crate::low::generic;
+use crate::low::x86_64;
+
+/// Multiplex between PCLMULQDQ and the portable GHASH.
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum GhashTable {
+    Clmul(ManuallyDrop<x86_64::ghash::GhashTable>),
+    Generic(ManuallyDrop<generic::ghash::GhashTable>),
+}
+
+impl GhashTable {
+    pub(crate) fn new(h: u128) -> Self {
+        if x86_64::aes_mux::use_aesni() {
+            Self::Clmul(ManuallyDrop::new(x86_64::ghash::GhashTable::new(h)))
+        } else {
+            Self::Generic(ManuallyDrop::new(generic::ghash::GhashTable::new(h)))
+        }
+    }
+
+    /// Makes a table that is only valid for inputs shorter than 128 bytes.
+    pub(crate) fn new_single_block(h: u128) -> Self {
+        if x86_64::aes_mux::use_aesni() {
+            Self::Clmul(ManuallyDrop::new(
+                x86_64::ghash::GhashTable::new_single_block(h),
+            ))
+        } else {
+            Self::Generic(ManuallyDrop::new(
+                generic::ghash::GhashTable::new_single_block(h),
+            ))
+        }
+    }
+}
+
+impl Drop for GhashTable {
+    fn drop(&mut self) {
+        match self {
+            // SAFETY: `self` is not used again
+            Self::Clmul(k) => unsafe { ManuallyDrop::drop(k) },
+            // SAFETY: `self` is not used again
+            Self::Generic(k) => unsafe { ManuallyDrop::drop(k) },
+        }
+
+        // the variants zeroise themselves, but not the enum tag, nor the
+        // padding past the smaller variant.
+        // SAFETY: `self` is not used again
+        unsafe { generic::zeroise::zeroise_dropped(self) };
+    }
+}
+
+pub(crate) enum Ghash<'a> {
+    Clmul(x86_64::ghash::Ghash<'a>),
+    Generic(generic::ghash::Ghash<'a>),
+}
+
+impl<'a> Ghash<'a> {
+    pub(crate) fn new(table: &'a GhashTable) -> Self {
+        match table {
+            GhashTable::Clmul(t) => Self::Clmul(x86_64::ghash::Ghash::new(t)),
+            GhashTable::Generic(t) => Self::Generic(generic::ghash::Ghash::new(t)),
+        }
+    }
+
+    /// Input `bytes` to the computation.
+    ///
+    /// `bytes` is zero-padded, if required.
+    pub(crate) fn add(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Clmul(g) => g.add(bytes),
+            Self::Generic(g) => g.add(bytes),
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> [u8; 16] {
+        match self {
+            Self::Clmul(g) => g.into_bytes(),
+            Self::Generic(g) => g.into_bytes(),
+        }
+    }
+}
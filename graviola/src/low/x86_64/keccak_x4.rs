@@ -0,0 +1,88 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+// Four-lane Keccak-f[1600].
+//
+// Each 64-bit lane of a ymm register carries the same word of an
+// independent state.  The structure otherwise exactly follows the
+// generic implementation.
+
+use core::arch::x86_64::*;
+
+use crate::low::generic::keccak::{RC, RHO};
+
+/// Apply Keccak-f\[1600\] to four independent states.
+pub(crate) fn keccak_f1600_x4(states: &mut [[u64; 25]; 4]) {
+    // SAFETY: this crate requires the `avx` and `avx2` cpu features.
+    unsafe { keccak_x4(states) }
+}
+
+#[target_feature(enable = "avx,avx2")]
+unsafe fn rotl(x: __m256i, n: u32) -> __m256i {
+    _mm256_or_si256(
+        _mm256_sllv_epi64(x, _mm256_set1_epi64x(n as i64)),
+        _mm256_srlv_epi64(x, _mm256_set1_epi64x(64 - n as i64)),
+    )
+}
+
+#[target_feature(enable = "avx,avx2")]
+unsafe fn keccak_x4(states: &mut [[u64; 25]; 4]) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let mut a = [_mm256_setzero_si256(); 25];
+        for (i, a) in a.iter_mut().enumerate() {
+            *a = _mm256_set_epi64x(
+                states[3][i] as i64,
+                states[2][i] as i64,
+                states[1][i] as i64,
+                states[0][i] as i64,
+            );
+        }
+
+        for rc in RC {
+            // theta
+            let mut c = [_mm256_setzero_si256(); 5];
+            for (x, c) in c.iter_mut().enumerate() {
+                *c = _mm256_xor_si256(
+                    _mm256_xor_si256(_mm256_xor_si256(a[x], a[x + 5]), a[x + 10]),
+                    _mm256_xor_si256(a[x + 15], a[x + 20]),
+                );
+            }
+            for x in 0..5 {
+                let d = _mm256_xor_si256(c[(x + 4) % 5], rotl(c[(x + 1) % 5], 1));
+                for y in 0..5 {
+                    a[x + 5 * y] = _mm256_xor_si256(a[x + 5 * y], d);
+                }
+            }
+
+            // rho and pi
+            let mut b = [_mm256_setzero_si256(); 25];
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = rotl(a[x + 5 * y], RHO[x + 5 * y]);
+                }
+            }
+
+            // chi
+            for y in 0..5 {
+                for x in 0..5 {
+                    a[x + 5 * y] = _mm256_xor_si256(
+                        b[x + 5 * y],
+                        _mm256_andnot_si256(b[(x + 1) % 5 + 5 * y], b[(x + 2) % 5 + 5 * y]),
+                    );
+                }
+            }
+
+            // iota
+            a[0] = _mm256_xor_si256(a[0], _mm256_set1_epi64x(rc as i64));
+        }
+
+        for (i, a) in a.iter().enumerate() {
+            let mut lanes = [0u64; 4];
+            _mm256_storeu_si256(lanes.as_mut_ptr().cast(), *a);
+            for (state, lane) in states.iter_mut().zip(lanes) {
+                state[i] = lane;
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+// AVX2 NTT and inverse NTT for ML-KEM.
+//
+// Coefficients stay in the natural order, so these are drop-in
+// replacements for the generic versions, and produce identical outputs.
+// Each ymm register holds 16 coefficients.  Layers with butterflies
+// 16 or more coefficients apart work on whole registers; the last three
+// layers first interleave pairs of registers so that each butterfly
+// again lines up lanewise.
+
+use core::arch::x86_64::*;
+
+use crate::low::generic::mlkem::{BARRETT_V, INV_NTT_SCALE, QINV, ZETAS};
+pub(crate) use crate::low::generic::mlkem::{Q, basemul_acc, reduce, to_mont};
+
+pub(crate) fn ntt(r: &mut [i16; 256]) {
+    // SAFETY: this crate requires the `avx2` cpu feature
+    unsafe { ntt_avx2(r) }
+}
+
+pub(crate) fn inv_ntt(r: &mut [i16; 256]) {
+    // SAFETY: this crate requires the `avx2` cpu feature
+    unsafe { inv_ntt_avx2(r) }
+}
+
+/// Montgomery multiplication of each lane of `a` by `b`, where
+/// `b_qinv` is `b * q^-1 mod 2^16`.
+#[target_feature(enable = "avx2")]
+unsafe fn fqmul(a: __m256i, b: __m256i, b_qinv: __m256i) -> __m256i {
+    let hi = _mm256_mulhi_epi16(a, b);
+    let t = _mm256_mullo_epi16(a, b_qinv);
+    _mm256_sub_epi16(hi, _mm256_mulhi_epi16(t, _mm256_set1_epi16(Q)))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn barrett_reduce(a: __m256i) -> __m256i {
+    let t = _mm256_mulhi_epi16(a, _mm256_set1_epi16(BARRETT_V));
+    let t = _mm256_srai_epi16(_mm256_add_epi16(t, _mm256_set1_epi16(1 << 9)), 10);
+    _mm256_sub_epi16(a, _mm256_mullo_epi16(t, _mm256_set1_epi16(Q)))
+}
+
+/// Cooley-Tukey butterfly: `(a + zeta * b, a - zeta * b)`.
+#[target_feature(enable = "avx2")]
+unsafe fn ct_butterfly(a: &mut __m256i, b: &mut __m256i, zeta: __m256i, zeta_qinv: __m256i) {
+    let t = fqmul(*b, zeta, zeta_qinv);
+    *b = _mm256_sub_epi16(*a, t);
+    *a = _mm256_add_epi16(*a, t);
+}
+
+/// Gentleman-Sande butterfly: `(a + b, zeta * (b - a))`.
+#[target_feature(enable = "avx2")]
+unsafe fn gs_butterfly(a: &mut __m256i, b: &mut __m256i, zeta: __m256i, zeta_qinv: __m256i) {
+    let t = *a;
+    *a = barrett_reduce(_mm256_add_epi16(t, *b));
+    *b = fqmul(_mm256_sub_epi16(*b, t), zeta, zeta_qinv);
+}
+
+// These three interleavings each exchange the upper half of `a`'s
+// 16, 8 or 4 lane groups with the lower half of `b`'s.  Each is its own inverse.
+
+#[target_feature(enable = "avx2")]
+unsafe fn shuffle8(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    (
+        _mm256_permute2x128_si256(a, b, 0x20),
+        _mm256_permute2x128_si256(a, b, 0x31),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn shuffle4(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    (_mm256_unpacklo_epi64(a, b), _mm256_unpackhi_epi64(a, b))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn shuffle2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    (
+        _mm256_blend_epi32(a, _mm256_slli_epi64(b, 32), 0xaa),
+        _mm256_blend_epi32(_mm256_srli_epi64(a, 32), b, 0xaa),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn load(r: &[i16; 256]) -> [__m256i; 16] {
+    let mut v = [_mm256_setzero_si256(); 16];
+    for (v, chunk) in v.iter_mut().zip(r.chunks_exact(16)) {
+        // SAFETY: `chunk` is 32 readable bytes
+        *v = unsafe { _mm256_loadu_si256(chunk.as_ptr().cast()) };
+    }
+    v
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn store(r: &mut [i16; 256], v: &[__m256i; 16]) {
+    for (v, chunk) in v.iter().zip(r.chunks_exact_mut(16)) {
+        // SAFETY: `chunk` is 32 writable bytes
+        unsafe { _mm256_storeu_si256(chunk.as_mut_ptr().cast(), *v) };
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn zeta_pair(table: &[[i16; 16]; 2]) -> (__m256i, __m256i) {
+    // SAFETY: each row is 32 readable bytes
+    unsafe {
+        (
+            _mm256_loadu_si256(table[0].as_ptr().cast()),
+            _mm256_loadu_si256(table[1].as_ptr().cast()),
+        )
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn ntt_avx2(r: &mut [i16; 256]) {
+    let mut v = load(r);
+
+    // layers with len 128, 64, 32, 16: whole registers
+    let mut k = 1;
+    let mut dist = 8;
+    while dist >= 1 {
+        for start in (0..16).step_by(2 * dist) {
+            let zeta = _mm256_set1_epi16(ZETAS[k]);
+            let zeta_qinv = _mm256_set1_epi16(ZETAS_QINV[k]);
+            k += 1;
+            for j in start..start + dist {
+                let (lo, hi) = v.split_at_mut(j + dist);
+                ct_butterfly(&mut lo[j], &mut hi[0], zeta, zeta_qinv);
+            }
+        }
+        dist >>= 1;
+    }
+
+    // layers with len 8, 4, 2: pairs of registers, interleaved
+    for (pair, i) in v.chunks_exact_mut(2).zip(0..) {
+        let (mut a, mut b) = shuffle8(pair[0], pair[1]);
+        let (z, zq) = zeta_pair(&LANE_ZETAS[0][i]);
+        ct_butterfly(&mut a, &mut b, z, zq);
+
+        let (mut a, mut b) = shuffle8(a, b);
+        (a, b) = shuffle4(a, b);
+        let (z, zq) = zeta_pair(&LANE_ZETAS[1][i]);
+        ct_butterfly(&mut a, &mut b, z, zq);
+
+        let (mut a, mut b) = shuffle4(a, b);
+        (a, b) = shuffle2(a, b);
+        let (z, zq) = zeta_pair(&LANE_ZETAS[2][i]);
+        ct_butterfly(&mut a, &mut b, z, zq);
+
+        let (a, b) = shuffle2(a, b);
+        pair[0] = barrett_reduce(a);
+        pair[1] = barrett_reduce(b);
+    }
+
+    store(r, &v);
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn inv_ntt_avx2(r: &mut [i16; 256]) {
+    let mut v = load(r);
+
+    // layers with len 2, 4, 8
+    for (pair, i) in v.chunks_exact_mut(2).zip(0..) {
+        let (mut a, mut b) = shuffle2(pair[0], pair[1]);
+        let (z, zq) = zeta_pair(&LANE_ZETAS_INV[2][i]);
+        gs_butterfly(&mut a, &mut b, z, zq);
+
+        let (mut a, mut b) = shuffle2(a, b);
+        (a, b) = shuffle4(a, b);
+        let (z, zq) = zeta_pair(&LANE_ZETAS_INV[1][i]);
+        gs_butterfly(&mut a, &mut b, z, zq);
+
+        let (mut a, mut b) = shuffle4(a, b);
+        (a, b) = shuffle8(a, b);
+        let (z, zq) = zeta_pair(&LANE_ZETAS_INV[0][i]);
+        gs_butterfly(&mut a, &mut b, z, zq);
+
+        (pair[0], pair[1]) = shuffle8(a, b);
+    }
+
+    // layers with len 16, 32, 64, 128
+    let mut k = 15;
+    let mut dist = 1;
+    while dist <= 8 {
+        for start in (0..16).step_by(2 * dist) {
+            let zeta = _mm256_set1_epi16(ZETAS[k]);
+            let zeta_qinv = _mm256_set1_epi16(ZETAS_QINV[k]);
+            k -= 1;
+            for j in start..start + dist {
+                let (lo, hi) = v.split_at_mut(j + dist);
+                gs_butterfly(&mut lo[j], &mut hi[0], zeta, zeta_qinv);
+            }
+        }
+        dist <<= 1;
+    }
+
+    let f = _mm256_set1_epi16(INV_NTT_SCALE);
+    let f_qinv = _mm256_set1_epi16(INV_NTT_SCALE.wrapping_mul(QINV));
+    for v in v.iter_mut() {
+        *v = fqmul(*v, f, f_qinv);
+    }
+
+    store(r, &v);
+}
+
+static ZETAS_QINV: [i16; 128] = {
+    let mut r = [0i16; 128];
+    let mut i = 0;
+    while i < 128 {
+        r[i] = ZETAS[i].wrapping_mul(QINV);
+        i += 1;
+    }
+    r
+};
+
+/// Per-lane zetas (and those times q^-1) for the last three NTT layers,
+/// for each pair of registers, after interleaving.
+static LANE_ZETAS: [[[[i16; 16]; 2]; 8]; 3] = lane_zetas(false);
+
+/// As `LANE_ZETAS`, for the inverse NTT.
+static LANE_ZETAS_INV: [[[[i16; 16]; 2]; 8]; 3] = lane_zetas(true);
+
+/// After interleaving registers `2 * pair` and `2 * pair + 1` for the layer
+/// with butterflies `len` apart, returns which block of `2 * len`
+/// coefficients lane `lane` belongs to.
+const fn lane_block(layer: usize, pair: usize, lane: usize) -> usize {
+    let v = 2 * pair;
+    match layer {
+        // len 8: lanes 0..8 from the first register, 8..16 from the second
+        0 => v + lane / 8,
+        // len 4: lanes in groups of 4, alternating between registers,
+        // in each 128-bit half
+        1 => 2 * (v + (lane / 4) % 2) + lane / 8,
+        // len 2: lanes in pairs, alternating between registers
+        _ => 4 * (v + (lane / 2) % 2) + lane / 4,
+    }
+}
+
+const fn lane_zetas(inverse: bool) -> [[[[i16; 16]; 2]; 8]; 3] {
+    let mut r = [[[[0i16; 16]; 2]; 8]; 3];
+    let mut layer = 0;
+    while layer < 3 {
+        // zetas for this layer start at 16, 32, 64
+        let first = 16 << layer;
+        let mut pair = 0;
+        while pair < 8 {
+            let mut lane = 0;
+            while lane < 16 {
+                let block = lane_block(layer, pair, lane);
+                // the inverse NTT uses each layer's zetas in reverse order
+                let k = if inverse {
+                    2 * first - 1 - block
+                } else {
+                    first + block
+                };
+                let z = ZETAS[k];
+                r[layer][pair][0][lane] = z;
+                r[layer][pair][1][lane] = z.wrapping_mul(QINV);
+                lane += 1;
+            }
+            pair += 1;
+        }
+        layer += 1;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low::generic::mlkem as model;
+
+    #[test]
+    fn equivalence() {
+        for seed in 0..64i32 {
+            let mut a = [0i16; 256];
+            for (i, c) in a.iter_mut().enumerate() {
+                *c = ((i as i32 * 2654 + seed * 977).rem_euclid(2 * Q as i32 - 1) - (Q as i32 - 1))
+                    as i16;
+            }
+
+            let mut real = a;
+            let mut expect = a;
+            ntt(&mut real);
+            model::ntt(&mut expect);
+            assert_eq!(real, expect);
+
+            let mut real = a;
+            let mut expect = a;
+            inv_ntt(&mut real);
+            model::inv_ntt(&mut expect);
+            assert_eq!(real, expect);
+        }
+    }
+}
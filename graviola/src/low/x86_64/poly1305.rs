@@ -0,0 +1,291 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+//
+//! AVX2 Poly1305, processing four blocks at a time.
+//!
+//! This keeps four interleaved accumulators, one per 64-bit lane, each
+//! in five 26-bit limbs (as in the generic implementation).  Each step
+//! multiplies all four by r^4 and adds the next four blocks.  Finally,
+//! the accumulators are multiplied by r^4, r^3, r^2 and r respectively,
+//! and summed.
+//!
+//! Ref. <https://eprint.iacr.org/2013/538.pdf> (Goll & Gueron,
+//! "Vectorization on ChaCha Stream Cipher" -- in particular section 4
+//! on Poly1305).
+
+use core::arch::x86_64::*;
+
+use crate::low::dispatch;
+use crate::low::generic::poly1305 as generic;
+
+/// Process whole groups of four blocks from the start of `blocks`,
+/// updating the accumulator `h` of the generic implementation (with
+/// key `r`).
+///
+/// Returns the unprocessed remainder.  Short inputs are returned
+/// without processing, as the setup cost outweighs any benefit.
+pub(crate) fn poly1305_blocks<'a>(h: &mut [u32; 5], r: &[u32; 5], blocks: &'a [u8]) -> &'a [u8] {
+    if blocks.len() < MIN_LEN || !use_avx2() {
+        return blocks;
+    }
+
+    let (whole, rest) = blocks.split_at(blocks.len() & !63);
+    // SAFETY: this crate requires the `avx2` cpu feature
+    unsafe {
+        let mut x4 = Poly1305x4::new(h, r);
+        x4.blocks(whole);
+        x4.finish(h);
+    }
+    rest
+}
+
+pub(in crate::low) fn use_avx2() -> bool {
+    // nb. avx2 is in our required set.
+    dispatch::use_native()
+}
+
+pub(crate) struct Poly1305x4 {
+    /// Four accumulators
+    h: [__m256i; 5],
+
+    /// r^4, in each lane
+    r4: [__m256i; 5],
+
+    /// r4[1..5] times 5
+    s4: [__m256i; 4],
+
+    /// r, r^2, r^3, r^4
+    powers: [[u32; 5]; 4],
+
+    /// Whether any blocks have been processed
+    started: bool,
+}
+
+impl Poly1305x4 {
+    /// Start processing, from the accumulator `h` and key `r` of the
+    /// generic implementation.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn new(h: &[u32; 5], r: &[u32; 5]) -> Self {
+        let r2 = mul(r, r);
+        let r3 = mul(&r2, r);
+        let r4 = mul(&r2, &r2);
+
+        Self {
+            h: [
+                _mm256_set_epi64x(0, 0, 0, h[0] as i64),
+                _mm256_set_epi64x(0, 0, 0, h[1] as i64),
+                _mm256_set_epi64x(0, 0, 0, h[2] as i64),
+                _mm256_set_epi64x(0, 0, 0, h[3] as i64),
+                _mm256_set_epi64x(0, 0, 0, h[4] as i64),
+            ],
+            r4: [
+                _mm256_set1_epi64x(r4[0] as i64),
+                _mm256_set1_epi64x(r4[1] as i64),
+                _mm256_set1_epi64x(r4[2] as i64),
+                _mm256_set1_epi64x(r4[3] as i64),
+                _mm256_set1_epi64x(r4[4] as i64),
+            ],
+            s4: [
+                _mm256_set1_epi64x((r4[1] * 5) as i64),
+                _mm256_set1_epi64x((r4[2] * 5) as i64),
+                _mm256_set1_epi64x((r4[3] * 5) as i64),
+                _mm256_set1_epi64x((r4[4] * 5) as i64),
+            ],
+            powers: [*r, r2, r3, r4],
+            started: false,
+        }
+    }
+
+    /// Process `blocks`, which must be a multiple of 64 bytes long.
+    #[target_feature(enable = "avx2")]
+    #[inline]
+    pub(crate) unsafe fn blocks(&mut self, blocks: &[u8]) {
+        debug_assert_eq!(blocks.len() % 64, 0);
+
+        for four in blocks.chunks_exact(64) {
+            // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+            unsafe {
+                let m = load_blocks(four);
+
+                if self.started {
+                    self.h = mul_x4(&self.h, &self.r4, &self.s4);
+                }
+                self.started = true;
+
+                for (h, m) in self.h.iter_mut().zip(m.iter()) {
+                    *h = _mm256_add_epi64(*h, *m);
+                }
+            }
+        }
+    }
+
+    /// Finish processing, writing the result back to `h`.
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn finish(self, h: &mut [u32; 5]) {
+        if !self.started {
+            return;
+        }
+
+        // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+        unsafe {
+            // lanes hold blocks 0, 2, 1 and 3 (see `load_blocks`)
+            let [r, r2, r3, r4] = &self.powers;
+            let lanes =
+                |i: usize| _mm256_set_epi64x(r[i] as i64, r3[i] as i64, r2[i] as i64, r4[i] as i64);
+            let rs = [lanes(0), lanes(1), lanes(2), lanes(3), lanes(4)];
+            let ss = [
+                _mm256_add_epi64(rs[1], _mm256_slli_epi64(rs[1], 2)),
+                _mm256_add_epi64(rs[2], _mm256_slli_epi64(rs[2], 2)),
+                _mm256_add_epi64(rs[3], _mm256_slli_epi64(rs[3], 2)),
+                _mm256_add_epi64(rs[4], _mm256_slli_epi64(rs[4], 2)),
+            ];
+            let sum = mul_x4(&self.h, &rs, &ss);
+
+            let mut d = [0u64; 5];
+            for (d, v) in d.iter_mut().zip(sum.iter()) {
+                let v = _mm_add_epi64(_mm256_castsi256_si128(*v), _mm256_extracti128_si256(*v, 1));
+                let v = _mm_add_epi64(v, _mm_unpackhi_epi64(v, v));
+                *d = _mm_cvtsi128_si64(v) as u64;
+            }
+
+            let carry = d[0] >> 26;
+            h[0] = (d[0] & 0x3ff_ffff) as u32;
+            d[1] += carry;
+            let carry = d[1] >> 26;
+            h[1] = (d[1] & 0x3ff_ffff) as u32;
+            d[2] += carry;
+            let carry = d[2] >> 26;
+            h[2] = (d[2] & 0x3ff_ffff) as u32;
+            d[3] += carry;
+            let carry = d[3] >> 26;
+            h[3] = (d[3] & 0x3ff_ffff) as u32;
+            d[4] += carry;
+            let carry = (d[4] >> 26) as u32;
+            h[4] = (d[4] & 0x3ff_ffff) as u32;
+            h[0] += carry * 5;
+            let carry = h[0] >> 26;
+            h[0] &= 0x3ff_ffff;
+            h[1] += carry;
+        }
+    }
+}
+
+/// Load four blocks, into lanes in the order 0, 2, 1, 3.
+#[target_feature(enable = "avx2")]
+#[inline]
+unsafe fn load_blocks(four: &[u8]) -> [__m256i; 5] {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let a = _mm256_loadu_si256(four.as_ptr().cast());
+        let b = _mm256_loadu_si256(four[32..].as_ptr().cast());
+        let lo = _mm256_unpacklo_epi64(a, b);
+        let hi = _mm256_unpackhi_epi64(a, b);
+
+        let mask = _mm256_set1_epi64x(0x3ff_ffff);
+        [
+            _mm256_and_si256(lo, mask),
+            _mm256_and_si256(_mm256_srli_epi64(lo, 26), mask),
+            _mm256_and_si256(
+                _mm256_or_si256(_mm256_srli_epi64(lo, 52), _mm256_slli_epi64(hi, 12)),
+                mask,
+            ),
+            _mm256_and_si256(_mm256_srli_epi64(hi, 14), mask),
+            _mm256_or_si256(_mm256_srli_epi64(hi, 40), _mm256_set1_epi64x(1 << 24)),
+        ]
+    }
+}
+
+/// Multiply each lane of `h` by `r`, and partially reduce.
+///
+/// This is the same as `mul()` in the generic implementation.
+#[target_feature(enable = "avx2")]
+#[inline]
+unsafe fn mul_x4(h: &[__m256i; 5], r: &[__m256i; 5], s: &[__m256i; 4]) -> [__m256i; 5] {
+    macro_rules! sum {
+        ($($a:expr, $b:expr);+) => {{
+            let mut t = _mm256_setzero_si256();
+            $( t = _mm256_add_epi64(t, _mm256_mul_epu32($a, $b)); )+
+            t
+        }};
+    }
+
+    let d0 = sum!(h[0], r[0]; h[1], s[3]; h[2], s[2]; h[3], s[1]; h[4], s[0]);
+    let d1 = sum!(h[0], r[1]; h[1], r[0]; h[2], s[3]; h[3], s[2]; h[4], s[1]);
+    let d2 = sum!(h[0], r[2]; h[1], r[1]; h[2], r[0]; h[3], s[3]; h[4], s[2]);
+    let d3 = sum!(h[0], r[3]; h[1], r[2]; h[2], r[1]; h[3], r[0]; h[4], s[3]);
+    let d4 = sum!(h[0], r[4]; h[1], r[3]; h[2], r[2]; h[3], r[1]; h[4], r[0]);
+
+    let mask = _mm256_set1_epi64x(0x3ff_ffff);
+
+    let carry = _mm256_srli_epi64(d0, 26);
+    let h0 = _mm256_and_si256(d0, mask);
+    let d1 = _mm256_add_epi64(d1, carry);
+    let carry = _mm256_srli_epi64(d1, 26);
+    let h1 = _mm256_and_si256(d1, mask);
+    let d2 = _mm256_add_epi64(d2, carry);
+    let carry = _mm256_srli_epi64(d2, 26);
+    let h2 = _mm256_and_si256(d2, mask);
+    let d3 = _mm256_add_epi64(d3, carry);
+    let carry = _mm256_srli_epi64(d3, 26);
+    let h3 = _mm256_and_si256(d3, mask);
+    let d4 = _mm256_add_epi64(d4, carry);
+    let carry = _mm256_srli_epi64(d4, 26);
+    let h4 = _mm256_and_si256(d4, mask);
+    let h0 = _mm256_add_epi64(h0, _mm256_add_epi64(carry, _mm256_slli_epi64(carry, 2)));
+    let carry = _mm256_srli_epi64(h0, 26);
+    let h0 = _mm256_and_si256(h0, mask);
+    let h1 = _mm256_add_epi64(h1, carry);
+
+    [h0, h1, h2, h3, h4]
+}
+
+fn mul(a: &[u32; 5], b: &[u32; 5]) -> [u32; 5] {
+    let mut h = *a;
+    generic::mul(&mut h, b, &[b[1] * 5, b[2] * 5, b[3] * 5, b[4] * 5]);
+    h
+}
+
+const MIN_LEN: usize = 256;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_generic() {
+        let key = [0xffu8; 32];
+        let pattern = (0..1024 + 15).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        let ones = vec![0xffu8; 1024 + 15];
+
+        for (data, len) in [&pattern, &ones]
+            .into_iter()
+            .flat_map(|d| [16, 64, 64 + 16, 256, 512 + 17, 1024 + 15].map(|len| (d, len)))
+        {
+            let data = &data[..len];
+
+            // one block at a time never uses `poly1305_blocks`
+            let mut expect = generic::Poly1305::new(&key);
+            for block in data.chunks(16) {
+                expect.add_bytes(block);
+            }
+            let expect = expect.finish();
+
+            let mut got = generic::Poly1305::new(&key);
+            got.add_bytes(&data[..16]);
+            let (whole, rest) = data[16..].split_at((len - 16) & !63);
+            let (h, r) = got.whole_block_state().unwrap();
+            // SAFETY: this crate requires the `avx2` cpu feature
+            unsafe {
+                let mut x4 = Poly1305x4::new(h, r);
+                x4.blocks(whole);
+                x4.finish(h);
+            }
+            got.add_bytes(rest);
+            assert_eq!(got.finish(), expect, "len={len}");
+
+            let mut got = generic::Poly1305::new(&key);
+            got.add_bytes(data);
+            assert_eq!(got.finish(), expect, "len={len}");
+        }
+    }
+}
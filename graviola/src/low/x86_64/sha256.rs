@@ -10,6 +10,23 @@ pub(in crate::low) fn sha256_compress_blocks_shaext(state: &mut [u32; 8], blocks
     unsafe { sha256(state, blocks) }
 }
 
+/// Compress `blocks_a` into `state_a`, and `blocks_b` into `state_b`.
+///
+/// The two computations are interleaved, which hides the latency of
+/// the SHA instructions. `blocks_a` and `blocks_b` must be the same length.
+pub(in crate::low) fn sha256_compress_blocks_shaext_x2(
+    state_a: &mut [u32; 8],
+    blocks_a: &[u8],
+    state_b: &mut [u32; 8],
+    blocks_b: &[u8],
+) {
+    assert_eq!(blocks_a.len(), blocks_b.len());
+    debug_assert!(blocks_a.len() % 64 == 0);
+    // SAFETY: the parent caller checks for the `sha` cpu feature; this
+    // crate requires the `sse4.1` and `ssse3` features
+    unsafe { sha256_x2(state_a, blocks_a, state_b, blocks_b) }
+}
+
 macro_rules! k {
     ($k:literal) => {
         _mm_load_si128(K.0.as_ptr().add($k * 4) as *const _)
@@ -118,6 +135,109 @@ unsafe fn sha256(state: &mut [u32; 8], blocks: &[u8]) {
     }
 }
 
+macro_rules! load_state {
+    ($state:expr) => {{
+        let state0 = _mm_loadu_si128($state[0..4].as_ptr() as *const _);
+        let state1 = _mm_loadu_si128($state[4..8].as_ptr() as *const _);
+
+        let tmp = _mm_shuffle_epi32(state0, 0b10_11_00_01);
+        let state1 = _mm_shuffle_epi32(state1, 0b00_01_10_11);
+        (
+            _mm_alignr_epi8(tmp, state1, 8),
+            _mm_blend_epi16(state1, tmp, 0xf0),
+        )
+    }};
+}
+
+macro_rules! store_state {
+    ($state:expr, $state0:ident, $state1:ident) => {
+        let tmp = _mm_shuffle_epi32($state0, 0b00_01_10_11);
+        let state1 = _mm_shuffle_epi32($state1, 0b10_11_00_01);
+        let state0 = _mm_blend_epi16(tmp, state1, 0xf0);
+        let state1 = _mm_alignr_epi8(state1, tmp, 8);
+
+        _mm_storeu_si128($state[0..4].as_mut_ptr() as *mut _, state0);
+        _mm_storeu_si128($state[4..8].as_mut_ptr() as *mut _, state1);
+    };
+}
+
+macro_rules! compress_block {
+    ($block:expr, $state0:ident, $state1:ident) => {
+        let little_endian_shuffle = _mm_set_epi64x(0x0c0d0e0f08090a0b, 0x0405060700010203);
+        let state0_prev = $state0;
+        let state1_prev = $state1;
+
+        let msg0 = _mm_loadu_si128($block[0..].as_ptr() as *const _);
+        let msg0 = _mm_shuffle_epi8(msg0, little_endian_shuffle);
+        let msg1 = _mm_loadu_si128($block[16..].as_ptr() as *const _);
+        let msg1 = _mm_shuffle_epi8(msg1, little_endian_shuffle);
+        let msg2 = _mm_loadu_si128($block[32..].as_ptr() as *const _);
+        let msg2 = _mm_shuffle_epi8(msg2, little_endian_shuffle);
+        let msg3 = _mm_loadu_si128($block[48..].as_ptr() as *const _);
+        let msg3 = _mm_shuffle_epi8(msg3, little_endian_shuffle);
+
+        let k0 = k!(0);
+        round!(msg0, $state0, $state1, k0);
+        let k1 = k!(1);
+        round!(msg1, msg0, $state0, $state1, k1);
+        let k2 = k!(2);
+        round!(msg2, msg1, $state0, $state1, k2);
+        let k3 = k!(3);
+        round!(msg3, msg2, msg0, $state0, $state1, k3);
+        let k4 = k!(4);
+        round!(msg0, msg3, msg1, $state0, $state1, k4);
+        let k5 = k!(5);
+        round!(msg1, msg0, msg2, $state0, $state1, k5);
+        let k6 = k!(6);
+        round!(msg2, msg1, msg3, $state0, $state1, k6);
+        let k7 = k!(7);
+        round!(msg3, msg2, msg0, $state0, $state1, k7);
+        let k8 = k!(8);
+        round!(msg0, msg3, msg1, $state0, $state1, k8);
+        let k9 = k!(9);
+        round!(msg1, msg0, msg2, $state0, $state1, k9);
+        let k10 = k!(10);
+        round!(msg2, msg1, msg3, $state0, $state1, k10);
+        let k11 = k!(11);
+        round!(msg3, msg2, msg0, $state0, $state1, k11);
+        let k12 = k!(12);
+        round!(msg0, msg3, msg1, $state0, $state1, k12);
+        let k13 = k!(13);
+        round!(msg1, msg0, msg2, $state0, $state1, k13);
+        let k14 = k!(14);
+        round!(msg2, msg1, msg3, $state0, $state1, k14);
+        let k15 = k!(15);
+        round!(msg3, $state0, $state1, k15);
+        let _ = msg1;
+        let _ = msg0;
+
+        $state0 = _mm_add_epi32($state0, state0_prev);
+        $state1 = _mm_add_epi32($state1, state1_prev);
+    };
+}
+
+#[target_feature(enable = "sha,sse4.1,ssse3")]
+unsafe fn sha256_x2(
+    state_a: &mut [u32; 8],
+    blocks_a: &[u8],
+    state_b: &mut [u32; 8],
+    blocks_b: &[u8],
+) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let (mut a0, mut a1) = load_state!(state_a);
+        let (mut b0, mut b1) = load_state!(state_b);
+
+        for (block_a, block_b) in blocks_a.chunks_exact(64).zip(blocks_b.chunks_exact(64)) {
+            compress_block!(block_a, a0, a1);
+            compress_block!(block_b, b0, b1);
+        }
+
+        store_state!(state_a, a0, a1);
+        store_state!(state_b, b0, b1);
+    }
+}
+
 #[repr(align(16))]
 struct Aligned([u32; 64]);
 
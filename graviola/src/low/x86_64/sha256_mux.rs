@@ -1,13 +1,38 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
-use crate::low::generic;
 use crate::low::x86_64;
+use crate::low::{dispatch, generic};
 
 pub(crate) fn sha256_compress_blocks(state: &mut [u32; 8], blocks: &[u8]) {
-    if x86_64::cpu::have_cpu_feature!("sha") {
+    if use_shaext() {
         x86_64::sha256::sha256_compress_blocks_shaext(state, blocks)
     } else {
         generic::sha256::sha256_compress_blocks(state, blocks)
     }
 }
+
+pub(in crate::low) fn use_shaext() -> bool {
+    dispatch::use_native() && x86_64::cpu::have_cpu_feature!("sha")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shaext_matches_generic() {
+        if !x86_64::cpu::have_cpu_feature!("sha") {
+            return;
+        }
+
+        let data = (0..64 * 9).map(|i| (i * 13) as u8).collect::<Vec<_>>();
+        for nblocks in 0..=9 {
+            let mut shaext = [0x6a09e667u32; 8];
+            let mut generic = shaext;
+            x86_64::sha256::sha256_compress_blocks_shaext(&mut shaext, &data[..nblocks * 64]);
+            generic::sha256::sha256_compress_blocks(&mut generic, &data[..nblocks * 64]);
+            assert_eq!(shaext, generic);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+// Eight-lane SHA256.
+//
+// Without the SHA extensions, each 32-bit lane of a ymm register
+// carries the state of an independent message.
+
+use core::arch::x86_64::*;
+
+use crate::low::x86_64;
+
+/// Compress `blocks[i]` into `states[i]`, for eight independent lanes.
+///
+/// Every non-empty `blocks[i]` must have the same length, which must be
+/// a multiple of 64.  An empty `blocks[i]` means lane `i` is unused, and
+/// `states[i]` is left unchanged.
+pub(crate) fn sha256_compress_blocks_x8(states: &mut [[u32; 8]; 8], blocks: [&[u8]; 8]) {
+    if x86_64::cpu::have_cpu_feature!("sha") {
+        sha256_compress_blocks_shaext_pairs(states, blocks)
+    } else {
+        sha256_compress_blocks_avx2(states, blocks)
+    }
+}
+
+/// With the SHA extensions, it is faster to run lanes pairwise
+/// through the SHA instructions than to use a vectorised implementation.
+fn sha256_compress_blocks_shaext_pairs(states: &mut [[u32; 8]; 8], blocks: [&[u8]; 8]) {
+    let mut lanes = states
+        .iter_mut()
+        .zip(blocks)
+        .filter(|(_, blocks)| !blocks.is_empty());
+
+    while let Some((state_a, blocks_a)) = lanes.next() {
+        match lanes.next() {
+            Some((state_b, blocks_b)) => {
+                x86_64::sha256::sha256_compress_blocks_shaext_x2(
+                    state_a, blocks_a, state_b, blocks_b,
+                );
+            }
+            None => x86_64::sha256::sha256_compress_blocks_shaext(state_a, blocks_a),
+        }
+    }
+}
+
+fn sha256_compress_blocks_avx2(states: &mut [[u32; 8]; 8], blocks: [&[u8]; 8]) {
+    let Some(first) = blocks.iter().find(|b| !b.is_empty()) else {
+        return;
+    };
+    let len = first.len();
+    debug_assert!(len % 64 == 0);
+
+    // unused lanes are fed an arbitrary valid input, and their
+    // resulting state is discarded.
+    let mut ptrs = [first.as_ptr(); 8];
+    for (ptr, block) in ptrs.iter_mut().zip(blocks.iter()) {
+        if !block.is_empty() {
+            assert_eq!(block.len(), len);
+            *ptr = block.as_ptr();
+        }
+    }
+
+    let mut scratch = *states;
+    // SAFETY: this crate requires the `avx` and `avx2` cpu features.
+    // `ptrs` all point to `len` readable bytes.
+    unsafe { sha256_x8(&mut scratch, ptrs, len / 64) };
+
+    for ((state, new), block) in states.iter_mut().zip(scratch).zip(blocks) {
+        if !block.is_empty() {
+            *state = new;
+        }
+    }
+}
+
+macro_rules! rotr {
+    ($x:expr, $n:literal, $inv:literal) => {
+        _mm256_or_si256(_mm256_srli_epi32($x, $n), _mm256_slli_epi32($x, $inv))
+    };
+}
+
+macro_rules! add {
+    ($a:expr, $b:expr) => {
+        _mm256_add_epi32($a, $b)
+    };
+}
+
+#[target_feature(enable = "avx,avx2")]
+unsafe fn transpose8(r: [__m256i; 8]) -> [__m256i; 8] {
+    let t0 = _mm256_unpacklo_epi32(r[0], r[1]);
+    let t1 = _mm256_unpackhi_epi32(r[0], r[1]);
+    let t2 = _mm256_unpacklo_epi32(r[2], r[3]);
+    let t3 = _mm256_unpackhi_epi32(r[2], r[3]);
+    let t4 = _mm256_unpacklo_epi32(r[4], r[5]);
+    let t5 = _mm256_unpackhi_epi32(r[4], r[5]);
+    let t6 = _mm256_unpacklo_epi32(r[6], r[7]);
+    let t7 = _mm256_unpackhi_epi32(r[6], r[7]);
+
+    let u0 = _mm256_unpacklo_epi64(t0, t2);
+    let u1 = _mm256_unpackhi_epi64(t0, t2);
+    let u2 = _mm256_unpacklo_epi64(t1, t3);
+    let u3 = _mm256_unpackhi_epi64(t1, t3);
+    let u4 = _mm256_unpacklo_epi64(t4, t6);
+    let u5 = _mm256_unpackhi_epi64(t4, t6);
+    let u6 = _mm256_unpacklo_epi64(t5, t7);
+    let u7 = _mm256_unpackhi_epi64(t5, t7);
+
+    [
+        _mm256_permute2x128_si256(u0, u4, 0x20),
+        _mm256_permute2x128_si256(u1, u5, 0x20),
+        _mm256_permute2x128_si256(u2, u6, 0x20),
+        _mm256_permute2x128_si256(u3, u7, 0x20),
+        _mm256_permute2x128_si256(u0, u4, 0x31),
+        _mm256_permute2x128_si256(u1, u5, 0x31),
+        _mm256_permute2x128_si256(u2, u6, 0x31),
+        _mm256_permute2x128_si256(u3, u7, 0x31),
+    ]
+}
+
+/// Load eight words from each of eight lanes, so that `w[i]` holds
+/// (big endian) word `i` of each lane.
+#[target_feature(enable = "avx,avx2")]
+unsafe fn load_words(ptrs: &[*const u8; 8], offset: usize) -> [__m256i; 8] {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let mut rows = [_mm256_setzero_si256(); 8];
+        for (row, ptr) in rows.iter_mut().zip(ptrs.iter()) {
+            *row = _mm256_loadu_si256(ptr.add(offset).cast());
+        }
+        let mut words = transpose8(rows);
+        for w in words.iter_mut() {
+            *w = _mm256_shuffle_epi8(*w, BSWAP_SHUFFLE);
+        }
+        words
+    }
+}
+
+#[target_feature(enable = "avx,avx2")]
+unsafe fn sha256_x8(states: &mut [[u32; 8]; 8], ptrs: [*const u8; 8], nblocks: usize) {
+    // SAFETY: intrinsics. see [crate::low::inline_assembly_safety#safety-of-intrinsics] for safety info.
+    unsafe {
+        let mut rows = [_mm256_setzero_si256(); 8];
+        for (row, state) in rows.iter_mut().zip(states.iter()) {
+            *row = _mm256_loadu_si256(state.as_ptr().cast());
+        }
+        let mut s = transpose8(rows);
+
+        for block in 0..nblocks {
+            let lo = load_words(&ptrs, block * 64);
+            let hi = load_words(&ptrs, block * 64 + 32);
+            let mut w = [
+                lo[0], lo[1], lo[2], lo[3], lo[4], lo[5], lo[6], lo[7], hi[0], hi[1], hi[2], hi[3],
+                hi[4], hi[5], hi[6], hi[7],
+            ];
+
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = s;
+
+            for t in 0..64 {
+                if t >= 16 {
+                    let w2 = w[(t - 2) % 16];
+                    let w15 = w[(t - 15) % 16];
+                    let ssig1 = _mm256_xor_si256(
+                        _mm256_xor_si256(rotr!(w2, 17, 15), rotr!(w2, 19, 13)),
+                        _mm256_srli_epi32(w2, 10),
+                    );
+                    let ssig0 = _mm256_xor_si256(
+                        _mm256_xor_si256(rotr!(w15, 7, 25), rotr!(w15, 18, 14)),
+                        _mm256_srli_epi32(w15, 3),
+                    );
+                    w[t % 16] = add!(add!(ssig1, w[(t - 7) % 16]), add!(ssig0, w[t % 16]));
+                }
+
+                let bsig1 = _mm256_xor_si256(
+                    _mm256_xor_si256(rotr!(e, 6, 26), rotr!(e, 11, 21)),
+                    rotr!(e, 25, 7),
+                );
+                let ch = _mm256_xor_si256(_mm256_and_si256(e, f), _mm256_andnot_si256(e, g));
+                let k = _mm256_set1_epi32(K[t] as i32);
+                let t1 = add!(add!(h, bsig1), add!(add!(ch, k), w[t % 16]));
+
+                let bsig0 = _mm256_xor_si256(
+                    _mm256_xor_si256(rotr!(a, 2, 30), rotr!(a, 13, 19)),
+                    rotr!(a, 22, 10),
+                );
+                let maj = _mm256_or_si256(
+                    _mm256_and_si256(a, b),
+                    _mm256_and_si256(c, _mm256_or_si256(a, b)),
+                );
+                let t2 = add!(bsig0, maj);
+
+                h = g;
+                g = f;
+                f = e;
+                e = add!(d, t1);
+                d = c;
+                c = b;
+                b = a;
+                a = add!(t1, t2);
+            }
+
+            s = [
+                add!(s[0], a),
+                add!(s[1], b),
+                add!(s[2], c),
+                add!(s[3], d),
+                add!(s[4], e),
+                add!(s[5], f),
+                add!(s[6], g),
+                add!(s[7], h),
+            ];
+        }
+
+        let rows = transpose8(s);
+        for (row, state) in rows.iter().zip(states.iter_mut()) {
+            _mm256_storeu_si256(state.as_mut_ptr().cast(), *row);
+        }
+    }
+}
+
+// SAFETY: sizeof([u8; 32]) == sizeof(__m256i), all bits have same meaning
+const BSWAP_SHUFFLE: __m256i = unsafe {
+    core::mem::transmute([
+        3u8, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12, 3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9,
+        8, 15, 14, 13, 12,
+    ])
+};
+
+static K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairwise() {
+        if !x86_64::cpu::have_cpu_feature!("sha") {
+            return;
+        }
+
+        let data = (0..8 * 128).map(|i| (i * 3) as u8).collect::<Vec<_>>();
+        for unused in 0..8 {
+            let mut blocks: [&[u8]; 8] = core::array::from_fn(|i| &data[i * 128..(i + 1) * 128]);
+            blocks[unused] = &[];
+
+            let mut shaext = [[0x12345678u32; 8]; 8];
+            let mut avx2 = shaext;
+            sha256_compress_blocks_shaext_pairs(&mut shaext, blocks);
+            sha256_compress_blocks_avx2(&mut avx2, blocks);
+            assert_eq!(shaext, avx2);
+        }
+    }
+}
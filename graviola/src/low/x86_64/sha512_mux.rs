@@ -1,14 +1,18 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
-use crate::low::generic;
 use crate::low::x86_64;
+use crate::low::{dispatch, generic};
 
 pub(crate) fn sha512_compress_blocks(state: &mut [u64; 8], blocks: &[u8]) {
-    // nb. avx2 is in our required set.
-    if x86_64::cpu::have_cpu_feature!("bmi2") {
+    if use_avx2() {
         x86_64::sha512::sha512_compress_blocks(state, blocks)
     } else {
         generic::sha512::sha512_compress_blocks(state, blocks)
     }
 }
+
+pub(in crate::low) fn use_avx2() -> bool {
+    // nb. avx2 is in our required set.
+    dispatch::use_native() && x86_64::cpu::have_cpu_feature!("bmi2")
+}
@@ -0,0 +1,235 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::aes_gcm::AesGcm;
+use super::chacha20poly1305::ChaCha20Poly1305;
+use super::xchacha20poly1305::XChaCha20Poly1305;
+use crate::Error;
+use crate::low::zeroise;
+
+/// A generic trait over supported AEADs.
+///
+/// This exists so protocol code may be generic over the AEAD used.
+///
+/// The lengths of keys, nonces and tags are checked at runtime against
+/// the associated constants, returning [`Error::WrongLength`] on mismatch.
+pub trait Aead: Sized {
+    /// Length of a key.
+    const KEY_LEN: usize;
+
+    /// Length of a nonce.
+    const NONCE_LEN: usize;
+
+    /// Length of an authentication tag.
+    const TAG_LEN: usize;
+
+    /// Create a new AEAD key from `key`, which must be `KEY_LEN` bytes.
+    fn new(key: &[u8]) -> Result<Self, Error>;
+
+    /// Encrypt the given message in place.
+    ///
+    /// On entry, `in_out` contains the plaintext of the message.
+    /// `nonce` contains the nonce, which must be unique for a given key.
+    /// `aad` is the additionally-authenticated data.  It may be empty.
+    ///
+    /// On exit, `in_out` contains the ciphertext of the message,
+    /// and `tag_out` contains the authentication tag.
+    fn seal(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        in_out: &mut [u8],
+        tag_out: &mut [u8],
+    ) -> Result<(), Error>;
+
+    /// Decrypt and verify the given message in place.
+    ///
+    /// On entry, `in_out` contains the ciphertext of the message.
+    /// `nonce`, `aad` and `tag` must match those used when sealing.
+    ///
+    /// On success, `in_out` contains the plaintext of the message.
+    /// Otherwise, [`Error::DecryptFailed`] is returned and `in_out`
+    /// is cleared.
+    fn open(&self, nonce: &[u8], aad: &[u8], in_out: &mut [u8], tag: &[u8]) -> Result<(), Error>;
+}
+
+/// AES-128-GCM.
+///
+/// This is an [`AesGcm`] that only accepts 16-byte keys.
+pub struct Aes128Gcm(AesGcm);
+
+/// AES-256-GCM.
+///
+/// This is an [`AesGcm`] that only accepts 32-byte keys.
+pub struct Aes256Gcm(AesGcm);
+
+macro_rules! aes_gcm {
+    ($name:ident, $key_len:literal) => {
+        impl Aead for $name {
+            const KEY_LEN: usize = $key_len;
+            const NONCE_LEN: usize = 12;
+            const TAG_LEN: usize = 16;
+
+            fn new(key: &[u8]) -> Result<Self, Error> {
+                match key.len() {
+                    Self::KEY_LEN => Ok(Self(AesGcm::new(key))),
+                    _ => Err(Error::WrongLength),
+                }
+            }
+
+            fn seal(
+                &self,
+                nonce: &[u8],
+                aad: &[u8],
+                in_out: &mut [u8],
+                tag_out: &mut [u8],
+            ) -> Result<(), Error> {
+                self.0.encrypt(
+                    fixed(nonce)?,
+                    aad,
+                    in_out,
+                    tag_out.try_into().map_err(|_| Error::WrongLength)?,
+                );
+                Ok(())
+            }
+
+            fn open(
+                &self,
+                nonce: &[u8],
+                aad: &[u8],
+                in_out: &mut [u8],
+                tag: &[u8],
+            ) -> Result<(), Error> {
+                self.0.decrypt(fixed(nonce)?, aad, in_out, tag)
+            }
+        }
+    };
+}
+
+aes_gcm!(Aes128Gcm, 16);
+aes_gcm!(Aes256Gcm, 32);
+
+macro_rules! chacha20poly1305 {
+    ($name:ident, $nonce_len:literal) => {
+        impl Aead for $name {
+            const KEY_LEN: usize = 32;
+            const NONCE_LEN: usize = $nonce_len;
+            const TAG_LEN: usize = 16;
+
+            fn new(key: &[u8]) -> Result<Self, Error> {
+                let mut key = *fixed::<32>(key)?;
+                let r = $name::new(key);
+                zeroise(&mut key);
+                Ok(r)
+            }
+
+            fn seal(
+                &self,
+                nonce: &[u8],
+                aad: &[u8],
+                in_out: &mut [u8],
+                tag_out: &mut [u8],
+            ) -> Result<(), Error> {
+                self.encrypt(
+                    fixed(nonce)?,
+                    aad,
+                    in_out,
+                    tag_out.try_into().map_err(|_| Error::WrongLength)?,
+                );
+                Ok(())
+            }
+
+            fn open(
+                &self,
+                nonce: &[u8],
+                aad: &[u8],
+                in_out: &mut [u8],
+                tag: &[u8],
+            ) -> Result<(), Error> {
+                self.decrypt(fixed(nonce)?, aad, in_out, tag)
+            }
+        }
+    };
+}
+
+chacha20poly1305!(ChaCha20Poly1305, 12);
+chacha20poly1305!(XChaCha20Poly1305, 24);
+
+fn fixed<const N: usize>(bytes: &[u8]) -> Result<&[u8; N], Error> {
+    bytes.try_into().map_err(|_| Error::WrongLength)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check<A: Aead>() {
+        let key = vec![0x42u8; A::KEY_LEN];
+        let nonce = vec![0x24u8; A::NONCE_LEN];
+        let aead = A::new(&key).unwrap();
+
+        let mut message = *b"hello world";
+        let mut tag = vec![0u8; A::TAG_LEN];
+        aead.seal(&nonce, b"aad", &mut message, &mut tag).unwrap();
+        assert_ne!(&message, b"hello world");
+
+        let mut opened = message;
+        aead.open(&nonce, b"aad", &mut opened, &tag).unwrap();
+        assert_eq!(&opened, b"hello world");
+
+        let mut opened = message;
+        assert_eq!(
+            aead.open(&nonce, b"AAD", &mut opened, &tag),
+            Err(Error::DecryptFailed)
+        );
+        assert_eq!(opened, [0u8; 11]);
+
+        assert_eq!(A::new(&key[1..]).err(), Some(Error::WrongLength));
+        assert_eq!(
+            aead.seal(&nonce[1..], b"", &mut [], &mut tag),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            aead.seal(&nonce, b"", &mut [], &mut tag[1..]),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            aead.open(&nonce[1..], b"", &mut [], &tag),
+            Err(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn all_aeads() {
+        check::<Aes128Gcm>();
+        check::<Aes256Gcm>();
+        check::<ChaCha20Poly1305>();
+        check::<XChaCha20Poly1305>();
+    }
+
+    #[test]
+    fn matches_inherent() {
+        let key = [0x11u8; 32];
+        let nonce = [0x22u8; 12];
+
+        let mut expect = *b"message";
+        let mut expect_tag = [0u8; 16];
+        AesGcm::new(&key).encrypt(&nonce, b"aad", &mut expect, &mut expect_tag);
+        let mut actual = *b"message";
+        let mut actual_tag = [0u8; 16];
+        <Aes256Gcm as Aead>::new(&key)
+            .unwrap()
+            .seal(&nonce, b"aad", &mut actual, &mut actual_tag)
+            .unwrap();
+        assert_eq!((expect, expect_tag), (actual, actual_tag));
+
+        let mut expect = *b"message";
+        ChaCha20Poly1305::new(key).encrypt(&nonce, b"aad", &mut expect, &mut expect_tag);
+        let mut actual = *b"message";
+        <ChaCha20Poly1305 as Aead>::new(&key)
+            .unwrap()
+            .seal(&nonce, b"aad", &mut actual, &mut actual_tag)
+            .unwrap();
+        assert_eq!((expect, expect_tag), (actual, actual_tag));
+    }
+}
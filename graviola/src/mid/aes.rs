@@ -0,0 +1,86 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::Error;
+use crate::low::{AesKey, Entry};
+
+/// The AES block cipher, from [FIPS-197](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.197-upd1.pdf).
+///
+/// This encrypts or decrypts single blocks, with no mode of operation.
+/// Using it directly on messages (that is, in ECB mode) is insecure.
+///
+/// As with the rest of this crate, AES-192 is not supported.
+pub struct Aes {
+    key: AesKey,
+}
+
+impl Aes {
+    /// The AES block size, in bytes.
+    pub const BLOCK_LEN: usize = 16;
+
+    /// Expand `key` for encryption and decryption.
+    ///
+    /// `key` must be 16 or 32 bytes, corresponding to AES-128 or AES-256.
+    /// [`Error::WrongLength`] is returned otherwise.
+    pub fn new(key: &[u8]) -> Result<Self, Error> {
+        if !matches!(key.len(), 16 | 32) {
+            return Err(Error::WrongLength);
+        }
+
+        let _entry = Entry::new_secret();
+        Ok(Self {
+            key: AesKey::new_with_decryption(key),
+        })
+    }
+
+    /// Encrypt one block in place.
+    pub fn encrypt_block(&self, block: &mut [u8; Self::BLOCK_LEN]) {
+        let _entry = Entry::new_secret();
+        self.key.encrypt_block(block);
+    }
+
+    /// Decrypt one block in place.
+    pub fn decrypt_block(&self, block: &mut [u8; Self::BLOCK_LEN]) {
+        let _entry = Entry::new_secret();
+        self.key.decrypt_block(block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fips197_appendix_c() {
+        let plaintext = *b"\x00\x11\x22\x33\x44\x55\x66\x77\x88\x99\xaa\xbb\xcc\xdd\xee\xff";
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+
+        for (key_len, expect) in [
+            (
+                16,
+                b"\x69\xc4\xe0\xd8\x6a\x7b\x04\x30\xd8\xcd\xb7\x80\x70\xb4\xc5\x5a",
+            ),
+            (
+                32,
+                b"\x8e\xa2\xb7\xca\x51\x67\x45\xbf\xea\xfc\x49\x90\x4b\x49\x60\x89",
+            ),
+        ] {
+            let aes = Aes::new(&key[..key_len]).unwrap();
+            let mut block = plaintext;
+            aes.encrypt_block(&mut block);
+            assert_eq!(&block, expect);
+            aes.decrypt_block(&mut block);
+            assert_eq!(block, plaintext);
+        }
+    }
+
+    #[test]
+    fn rejects_bad_key_lengths() {
+        for len in [0, 15, 17, 24, 31, 33] {
+            assert!(matches!(
+                Aes::new(&[0u8; 33][..len]),
+                Err(Error::WrongLength)
+            ));
+        }
+    }
+}
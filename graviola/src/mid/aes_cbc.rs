@@ -0,0 +1,129 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::Error;
+use crate::low::{AesKey, Entry, zeroise};
+
+/// AES in CBC mode, from [NIST SP800-38A](https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf).
+///
+/// This is unauthenticated, and only exists for reading and writing
+/// legacy formats.  Padding is left to the caller.
+///
+/// As with the rest of this crate, AES-192 is not supported.
+pub(crate) struct AesCbc {
+    key: AesKey,
+}
+
+impl AesCbc {
+    /// `key` must be 16 or 32 bytes, corresponding to AES-128 or AES-256.
+    /// [`Error::WrongLength`] is returned otherwise.
+    pub(crate) fn new(key: &[u8]) -> Result<Self, Error> {
+        if !matches!(key.len(), 16 | 32) {
+            return Err(Error::WrongLength);
+        }
+
+        let _entry = Entry::new_secret();
+        Ok(Self {
+            key: AesKey::new_with_decryption(key),
+        })
+    }
+
+    /// Encrypt `inout` in place.
+    ///
+    /// `inout` must be a whole number of blocks, otherwise
+    /// [`Error::WrongLength`] is returned.
+    pub(crate) fn encrypt(&self, iv: &[u8; BLOCK_LEN], inout: &mut [u8]) -> Result<(), Error> {
+        if inout.len() % BLOCK_LEN != 0 {
+            return Err(Error::WrongLength);
+        }
+
+        let _entry = Entry::new_secret();
+        let mut chain = *iv;
+        for block in inout.chunks_exact_mut(BLOCK_LEN) {
+            for (b, c) in block.iter_mut().zip(chain.iter()) {
+                *b ^= *c;
+            }
+            self.key.encrypt_block(block);
+            chain.copy_from_slice(block);
+        }
+        Ok(())
+    }
+
+    /// Decrypt `inout` in place.
+    ///
+    /// `inout` must be a whole number of blocks, otherwise
+    /// [`Error::WrongLength`] is returned.
+    pub(crate) fn decrypt(&self, iv: &[u8; BLOCK_LEN], inout: &mut [u8]) -> Result<(), Error> {
+        if inout.len() % BLOCK_LEN != 0 {
+            return Err(Error::WrongLength);
+        }
+
+        let _entry = Entry::new_secret();
+        let mut chain = *iv;
+        let mut ciphertext = [0u8; BLOCK_LEN];
+        for block in inout.chunks_exact_mut(BLOCK_LEN) {
+            ciphertext.copy_from_slice(block);
+            self.key.decrypt_block(block);
+            for (b, c) in block.iter_mut().zip(chain.iter()) {
+                *b ^= *c;
+            }
+            chain = ciphertext;
+        }
+        zeroise(&mut ciphertext);
+        Ok(())
+    }
+}
+
+pub(crate) const BLOCK_LEN: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // from SP800-38A appendix F.2
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+                             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+    const IV: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+
+    #[test]
+    fn sp800_38a() {
+        for (key, ciphertext) in [
+            (
+                "2b7e151628aed2a6abf7158809cf4f3c",
+                "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2\
+                 73bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7",
+            ),
+            (
+                "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+                "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d\
+                 39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b",
+            ),
+        ] {
+            let cbc = AesCbc::new(&unhex(key)).unwrap();
+            let mut buf = unhex(PLAINTEXT);
+            cbc.encrypt(&IV, &mut buf).unwrap();
+            assert_eq!(buf, unhex(ciphertext));
+            cbc.decrypt(&IV, &mut buf).unwrap();
+            assert_eq!(buf, unhex(PLAINTEXT));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(AesCbc::new(&[0; 24]).err(), Some(Error::WrongLength));
+        let cbc = AesCbc::new(&[0; 16]).unwrap();
+        assert_eq!(cbc.encrypt(&IV, &mut [0; 15]), Err(Error::WrongLength));
+        assert_eq!(cbc.decrypt(&IV, &mut [0; 17]), Err(Error::WrongLength));
+        assert_eq!(cbc.encrypt(&IV, &mut []), Ok(()));
+    }
+}
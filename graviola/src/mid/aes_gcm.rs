@@ -1,14 +1,22 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
+use core::num::NonZeroUsize;
+use std::sync::OnceLock;
+use std::thread;
+
 use crate::Error;
 use crate::low::ghash::{Ghash, GhashTable};
-use crate::low::{AesKey, Entry, aes_gcm, ct_equal};
+use crate::low::{
+    AesKey, Blockwise, Entry, ZeroOnDrop, aes_gcm, ct_equal, gf128_mul, gf128_pow, zeroise,
+    zeroise_value,
+};
 
 /// An AES-GCM key.
 ///
 /// Making one of these is relatively expensive due to key
-/// expansion and precomputation.
+/// expansion.  Further precomputation for long messages is
+/// deferred until the first such message.
 ///
 /// This implementation is limited to that which is commonly
 /// used:
@@ -17,7 +25,15 @@ use crate::low::{AesKey, Entry, aes_gcm, ct_equal};
 /// - Only nonces that are 12-bytes/96-bits are supported.
 pub struct AesGcm {
     key: AesKey,
-    gh: GhashTable,
+
+    /// GHASH table for short inputs: see `SINGLE_BLOCK_LIMIT`.
+    gh_single: GhashTable,
+
+    /// Complete GHASH table, made when first needed.
+    ///
+    /// Until then, its storage is uninitialised and so may hold anything:
+    /// `ZeroOnDrop` clears that too.
+    gh_full: ZeroOnDrop<OnceLock<GhashTable>>,
 }
 
 impl AesGcm {
@@ -30,13 +46,13 @@ impl AesGcm {
     pub fn new(key: &[u8]) -> Self {
         let _entry = Entry::new_secret();
         let key = AesKey::new(key);
-        let mut h = [0u8; 16];
-        key.encrypt_block(&mut h);
-
-        let h = u128::from_be_bytes(h);
-        let gh = GhashTable::new(h);
+        let gh_single = GhashTable::new_single_block(hash_key(&key));
 
-        Self { key, gh }
+        Self {
+            key,
+            gh_single,
+            gh_full: ZeroOnDrop::new(OnceLock::new()),
+        }
     }
 
     /// Encrypts the given message.
@@ -55,7 +71,7 @@ impl AesGcm {
         tag_out: &mut [u8; 16],
     ) {
         let _entry = Entry::new_secret();
-        let mut ghash = Ghash::new(&self.gh);
+        let mut ghash = Ghash::new(self.table_for(aad, cipher_inout));
 
         let counter = self.nonce_to_y0(nonce);
 
@@ -98,7 +114,7 @@ impl AesGcm {
         tag: &[u8],
     ) -> Result<(), Error> {
         let _entry = Entry::new_secret();
-        let mut ghash = Ghash::new(&self.gh);
+        let mut ghash = Ghash::new(self.table_for(aad, cipher_inout));
 
         let counter = self.nonce_to_y0(nonce);
 
@@ -126,6 +142,214 @@ impl AesGcm {
         }
     }
 
+    /// Encrypts the given message, using all available parallelism.
+    ///
+    /// The result is identical to [`AesGcm::encrypt`], and the arguments
+    /// have the same meaning.
+    ///
+    /// The message is split into contiguous segments which are encrypted
+    /// and hashed concurrently, and the hashes then combined.  Segments
+    /// are at least [`AesGcm::MIN_SEGMENT_LEN`] bytes, so this is only
+    /// worthwhile for multi-megabyte messages: shorter ones are processed
+    /// on the calling thread.
+    pub fn encrypt_parallel(
+        &self,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag_out: &mut [u8; 16],
+    ) {
+        self.encrypt_with_threads(nonce, aad, cipher_inout, tag_out, available_parallelism())
+    }
+
+    /// Encrypts the given message, using at most `threads` threads.
+    ///
+    /// See [`AesGcm::encrypt_parallel`].
+    pub fn encrypt_with_threads(
+        &self,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag_out: &mut [u8; 16],
+        threads: NonZeroUsize,
+    ) {
+        let _entry = Entry::new_secret();
+        let counter = self.nonce_to_y0(nonce);
+
+        let mut e_y0 = counter;
+        self.key.encrypt_block(&mut e_y0);
+
+        let final_xi = self.segmented(
+            aes_gcm::encrypt,
+            &counter,
+            aad,
+            cipher_inout,
+            threads,
+            Self::MIN_SEGMENT_LEN,
+        );
+
+        for ((out, x), e) in tag_out.iter_mut().zip(final_xi.iter()).zip(e_y0.iter()) {
+            *out = *x ^ *e;
+        }
+    }
+
+    /// Decrypts and verifies the given message, using all available parallelism.
+    ///
+    /// The result is identical to [`AesGcm::decrypt`], and the arguments
+    /// have the same meaning.  See [`AesGcm::encrypt_parallel`] for how
+    /// the work is divided.
+    pub fn decrypt_parallel(
+        &self,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        self.decrypt_with_threads(nonce, aad, cipher_inout, tag, available_parallelism())
+    }
+
+    /// Decrypts and verifies the given message, using at most `threads` threads.
+    ///
+    /// See [`AesGcm::decrypt_parallel`].
+    pub fn decrypt_with_threads(
+        &self,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        tag: &[u8],
+        threads: NonZeroUsize,
+    ) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        let counter = self.nonce_to_y0(nonce);
+
+        let mut e_y0 = counter;
+        self.key.encrypt_block(&mut e_y0);
+
+        let mut actual_tag = self.segmented(
+            aes_gcm::decrypt,
+            &counter,
+            aad,
+            cipher_inout,
+            threads,
+            Self::MIN_SEGMENT_LEN,
+        );
+        for (out, e) in actual_tag.iter_mut().zip(e_y0.iter()) {
+            *out ^= *e;
+        }
+
+        if ct_equal(&actual_tag, tag) {
+            Ok(())
+        } else {
+            // avoid unauthenticated plaintext leak
+            cipher_inout.fill(0x00);
+            Err(Error::DecryptFailed)
+        }
+    }
+
+    /// The smallest segment processed by one thread in
+    /// [`AesGcm::encrypt_parallel`] and [`AesGcm::decrypt_parallel`].
+    pub const MIN_SEGMENT_LEN: usize = 1024 * 1024;
+
+    /// Apply `op` (`aes_gcm::encrypt` or `aes_gcm::decrypt`) to
+    /// `cipher_inout` in segments, and return the GHASH of the
+    /// whole message, including the lengths block.
+    ///
+    /// Each segment but the last is a whole number of blocks, so its
+    /// counter is known in advance and its GHASH (starting from zero)
+    /// can be computed independently.  The segment hashes `S_i` are then
+    /// combined in order as `X = X * H^(blocks in S_i) + S_i`.
+    fn segmented(
+        &self,
+        op: fn(&AesKey, &mut Ghash<'_>, &[u8; 16], &[u8], &mut [u8]),
+        counter: &[u8; 16],
+        aad: &[u8],
+        cipher_inout: &mut [u8],
+        threads: NonZeroUsize,
+        min_segment_len: usize,
+    ) -> [u8; 16] {
+        let len = cipher_inout.len();
+        let per_thread = (len + threads.get() - 1) / threads.get();
+        let segment_len = (per_thread.max(min_segment_len) + 15) & !15;
+
+        let (first, rest) = cipher_inout.split_at_mut(segment_len.min(len));
+        let mut partials = vec![0u128; (rest.len() + segment_len - 1) / segment_len];
+        let table = self.full_table();
+        let mut ghash = Ghash::new(table);
+
+        thread::scope(|s| {
+            for (i, (segment, partial)) in rest
+                .chunks_mut(segment_len)
+                .zip(partials.iter_mut())
+                .enumerate()
+            {
+                let counter = add_blocks(counter, (i + 1) * segment_len / 16);
+                s.spawn(move || {
+                    let _entry = Entry::new_secret();
+                    let mut ghash = Ghash::new(table);
+                    op(&self.key, &mut ghash, &counter, &[], segment);
+                    *partial = u128::from_be_bytes(ghash.into_bytes());
+                });
+            }
+
+            op(&self.key, &mut ghash, counter, aad, first);
+        });
+
+        let mut h = hash_key(&self.key);
+
+        let mut x = u128::from_be_bytes(ghash.into_bytes());
+        for (partial, segment) in partials.iter().zip(rest.chunks(segment_len)) {
+            let blocks = (segment.len() + 15) / 16;
+            x = gf128_mul(x, gf128_pow(h, blocks as u64)) ^ *partial;
+        }
+        zeroise(&mut partials);
+
+        let mut lengths = [0u8; 16];
+        lengths[..8].copy_from_slice(&((aad.len() * 8) as u64).to_be_bytes());
+        lengths[8..].copy_from_slice(&((len * 8) as u64).to_be_bytes());
+        x = gf128_mul(x ^ u128::from_be_bytes(lengths), h);
+
+        zeroise_value(&mut h);
+        x.to_be_bytes()
+    }
+
+    /// Start a GMAC computation.
+    ///
+    /// GMAC is AES-GCM where the message is entirely
+    /// additionally-authenticated data: it is authenticated, but not
+    /// encrypted.  The resulting tag is the same as that from
+    /// [`AesGcm::encrypt`] with the message as `aad` and an empty
+    /// plaintext.
+    ///
+    /// As with encryption, `nonce` must be unique for a given key.
+    pub fn gmac(&self, nonce: &[u8; 12]) -> Gmac<'_> {
+        let _entry = Entry::new_secret();
+        let mut e_y0 = self.nonce_to_y0(nonce);
+        self.key.encrypt_block(&mut e_y0);
+
+        Gmac {
+            ghash: Ghash::new(self.full_table()),
+            e_y0,
+            buffer: Blockwise::new(),
+            len: 0,
+        }
+    }
+
+    /// The GHASH table to use for `aad` and `cipher_inout`.
+    fn table_for(&self, aad: &[u8], cipher_inout: &[u8]) -> &GhashTable {
+        match self.gh_full.get() {
+            Some(full) => full,
+            None if aad.len() < SINGLE_BLOCK_LIMIT && cipher_inout.len() < SINGLE_BLOCK_LIMIT => {
+                &self.gh_single
+            }
+            None => self.full_table(),
+        }
+    }
+
+    fn full_table(&self) -> &GhashTable {
+        self.gh_full
+            .get_or_init(|| GhashTable::new(hash_key(&self.key)))
+    }
+
     fn nonce_to_y0(&self, nonce: &[u8; 12]) -> [u8; 16] {
         let mut y0 = [0u8; 16];
         y0[..12].copy_from_slice(nonce);
@@ -134,6 +358,78 @@ impl AesGcm {
     }
 }
 
+/// Inputs shorter than this never use more than the first power of H,
+/// so can be processed with a `GhashTable::new_single_block` table.
+///
+/// This covers one-shot small messages, such as those under
+/// per-message keys in HPKE.
+const SINGLE_BLOCK_LIMIT: usize = 128;
+
+/// The GHASH key, H.
+fn hash_key(key: &AesKey) -> u128 {
+    let mut h = [0u8; 16];
+    key.encrypt_block(&mut h);
+    u128::from_be_bytes(h)
+}
+
+fn available_parallelism() -> NonZeroUsize {
+    thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+}
+
+/// Returns `counter` advanced by `blocks`, in the manner of `inc32()`.
+fn add_blocks(counter: &[u8; 16], blocks: usize) -> [u8; 16] {
+    let mut r = *counter;
+    let c = u32::from_be_bytes(counter[12..].try_into().unwrap());
+    r[12..].copy_from_slice(&c.wrapping_add(blocks as u32).to_be_bytes());
+    r
+}
+
+/// An in-progress GMAC computation.
+///
+/// Create one of these with [`AesGcm::gmac`].
+pub struct Gmac<'a> {
+    ghash: Ghash<'a>,
+    e_y0: [u8; 16],
+    buffer: Blockwise<16>,
+    len: usize,
+}
+
+impl Gmac<'_> {
+    /// Add `bytes` to the message.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let _entry = Entry::new_secret();
+        self.len += bytes.len();
+
+        // `Ghash::add` pads each call, so we must only give it whole blocks
+        let bytes = self.buffer.add_leading(bytes);
+        if let Some(block) = self.buffer.take() {
+            self.ghash.add(&block);
+        }
+
+        let (whole, trailing) = bytes.split_at(bytes.len() - bytes.len() % 16);
+        self.ghash.add(whole);
+        self.buffer.add_trailing(trailing);
+    }
+
+    /// Complete the GMAC computation, returning the tag.
+    pub fn finish(mut self) -> [u8; 16] {
+        let _entry = Entry::new_secret();
+        if let Some(trailing) = self.buffer.peek_remaining() {
+            self.ghash.add(trailing);
+        }
+
+        let mut lengths = [0u8; 16];
+        lengths[..8].copy_from_slice(&((self.len * 8) as u64).to_be_bytes());
+        self.ghash.add(&lengths);
+
+        let mut tag = self.ghash.into_bytes();
+        for (out, e) in tag.iter_mut().zip(self.e_y0.iter()) {
+            *out ^= *e;
+        }
+        tag
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,12 +467,168 @@ mod tests {
         let (cipher, tag) = expected.split_at(expected.len() - 16);
         let mut plain = cipher.to_vec();
 
-        t.decrypt(b"noncenonceno", b"aad", &mut plain, &tag)
-            .unwrap();
+        t.decrypt(b"noncenonceno", b"aad", &mut plain, tag).unwrap();
 
         assert_eq!(plain, &[b'p'; 4164]);
     }
 
+    #[test]
+    fn gmac_matches_encrypt() {
+        let message = (0..300).map(|i| i as u8).collect::<Vec<_>>();
+        for key in [&[0x11; 16][..], &[0x22; 32][..]] {
+            let t = AesGcm::new(key);
+            for len in [0, 1, 15, 16, 17, 128, 129, 300] {
+                let mut expected = [0u8; 16];
+                t.encrypt(b"noncenonceno", &message[..len], &mut [], &mut expected);
+
+                let mut gmac = t.gmac(b"noncenonceno");
+                gmac.update(&message[..len]);
+                assert_eq!(gmac.finish(), expected);
+
+                for split in [1, 7, 16, 33] {
+                    let mut gmac = t.gmac(b"noncenonceno");
+                    for chunk in message[..len].chunks(split) {
+                        gmac.update(chunk);
+                    }
+                    assert_eq!(gmac.finish(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_block_table_matches_full() {
+        let message = (0..300).map(|i| (i * 3) as u8).collect::<Vec<_>>();
+        let nonce = b"noncenonceno";
+
+        for key in [&[0x11; 16][..], &[0x22; 32][..]] {
+            let lazy = AesGcm::new(key);
+            let full = AesGcm::new(key);
+            full.full_table();
+
+            // all of these should use only `gh_single`, and then the
+            // rest should build `gh_full`
+            let short = [0, 1, 15, 16, 17, 100, 127];
+            let long = [128, 129, 255, 256, 300];
+
+            for (lens, expect_full) in [(&short[..], false), (&long[..], true)] {
+                for &aad_len in lens.iter().chain(&[0]) {
+                    for &len in lens.iter().chain(&[0]) {
+                        let aad = &message[..aad_len];
+                        let mut expect = message[..len].to_vec();
+                        let mut expect_tag = [0u8; 16];
+                        full.encrypt(nonce, aad, &mut expect, &mut expect_tag);
+
+                        let mut got = message[..len].to_vec();
+                        let mut got_tag = [0u8; 16];
+                        lazy.encrypt(nonce, aad, &mut got, &mut got_tag);
+                        assert_eq!(got, expect);
+                        assert_eq!(got_tag, expect_tag);
+
+                        lazy.decrypt(nonce, aad, &mut got, &got_tag).unwrap();
+                        assert_eq!(got, &message[..len]);
+                    }
+                }
+                assert_eq!(lazy.gh_full.get().is_some(), expect_full);
+            }
+        }
+    }
+
+    #[test]
+    fn segmented_matches_sequential() {
+        let message = (0..1000).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        let nonce = b"noncenonceno";
+        let aad = b"additional data";
+
+        for key in [&[0x11; 16][..], &[0x22; 32][..]] {
+            let t = AesGcm::new(key);
+            let mut e_y0 = t.nonce_to_y0(nonce);
+            t.key.encrypt_block(&mut e_y0);
+
+            for len in [0, 1, 15, 16, 17, 100, 257, 1000] {
+                let mut expect = message[..len].to_vec();
+                let mut expect_tag = [0u8; 16];
+                t.encrypt(nonce, aad, &mut expect, &mut expect_tag);
+
+                for threads in 1..5 {
+                    let threads = NonZeroUsize::new(threads).unwrap();
+                    for min_segment_len in [16, 32, 48, 512] {
+                        let mut got = message[..len].to_vec();
+                        let mut got_tag = t.segmented(
+                            aes_gcm::encrypt,
+                            &t.nonce_to_y0(nonce),
+                            aad,
+                            &mut got,
+                            threads,
+                            min_segment_len,
+                        );
+                        for (out, e) in got_tag.iter_mut().zip(e_y0.iter()) {
+                            *out ^= *e;
+                        }
+                        assert_eq!(got, expect);
+                        assert_eq!(got_tag, expect_tag);
+
+                        let mut got_tag = t.segmented(
+                            aes_gcm::decrypt,
+                            &t.nonce_to_y0(nonce),
+                            aad,
+                            &mut got,
+                            threads,
+                            min_segment_len,
+                        );
+                        for (out, e) in got_tag.iter_mut().zip(e_y0.iter()) {
+                            *out ^= *e;
+                        }
+                        assert_eq!(got, &message[..len]);
+                        assert_eq!(got_tag, expect_tag);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let t = AesGcm::new(&[b'k'; 32]);
+        let message = (0..2 * AesGcm::MIN_SEGMENT_LEN + 100)
+            .map(|i| (i * 3) as u8)
+            .collect::<Vec<_>>();
+
+        let mut expect = message.clone();
+        let mut expect_tag = [0u8; 16];
+        t.encrypt(b"noncenonceno", b"aad", &mut expect, &mut expect_tag);
+
+        let mut got = message.clone();
+        let mut got_tag = [0u8; 16];
+        t.encrypt_with_threads(
+            b"noncenonceno",
+            b"aad",
+            &mut got,
+            &mut got_tag,
+            NonZeroUsize::new(3).unwrap(),
+        );
+        assert_eq!(got, expect);
+        assert_eq!(got_tag, expect_tag);
+
+        t.decrypt_parallel(b"noncenonceno", b"aad", &mut got, &got_tag)
+            .unwrap();
+        assert_eq!(got, message);
+
+        got_tag[0] ^= 1;
+        assert_eq!(
+            t.decrypt_with_threads(
+                b"noncenonceno",
+                b"aad",
+                &mut expect,
+                &got_tag,
+                NonZeroUsize::new(3).unwrap(),
+            )
+            .unwrap_err(),
+            Error::DecryptFailed
+        );
+        assert!(expect.iter().all(|b| *b == 0));
+    }
+
     #[test]
     fn cavp() {
         #[derive(Default)]
@@ -271,8 +723,10 @@ mod tests {
             &mut State::default(),
         );
 
-        let mut state = State::default();
-        state.encrypt = true;
+        let mut state = State {
+            encrypt: true,
+            ..Default::default()
+        };
         process_cavp("../thirdparty/cavp/gcm/gcmEncryptExtIV128.rsp", &mut state);
         process_cavp("../thirdparty/cavp/gcm/gcmEncryptExtIV256.rsp", &mut state);
     }
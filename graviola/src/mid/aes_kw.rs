@@ -0,0 +1,216 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::Error;
+use crate::low::{AesKey, Entry, ct_equal, zeroise};
+
+/// The AES key wrap algorithm, from [RFC3394](https://datatracker.ietf.org/doc/html/rfc3394).
+///
+/// This is the "index based" formulation from section 2.2.
+///
+/// As with the rest of this crate, AES-192 is not supported.
+pub(crate) struct AesKw {
+    key: AesKey,
+}
+
+impl AesKw {
+    /// `key` must be 16 or 32 bytes, corresponding to AES-128 or AES-256.
+    /// [`Error::WrongLength`] is returned otherwise.
+    pub(crate) fn new(key: &[u8]) -> Result<Self, Error> {
+        if !matches!(key.len(), 16 | 32) {
+            return Err(Error::WrongLength);
+        }
+
+        let _entry = Entry::new_secret();
+        Ok(Self {
+            key: AesKey::new_with_decryption(key),
+        })
+    }
+
+    /// Wraps `plaintext`, writing the result to the start of `out`.
+    ///
+    /// `plaintext` must be a whole number of 64-bit blocks, and at least two
+    /// of them.  `out` must be [`OVERHEAD`] bytes longer.  [`Error::WrongLength`]
+    /// is returned otherwise.
+    pub(crate) fn wrap<'a>(&self, plaintext: &[u8], out: &'a mut [u8]) -> Result<&'a [u8], Error> {
+        let n = blocks(plaintext.len())?;
+        let out = out
+            .get_mut(..plaintext.len() + OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+
+        let _entry = Entry::new_secret();
+        let (a, r) = out.split_at_mut(OVERHEAD);
+        a.copy_from_slice(&IV);
+        r.copy_from_slice(plaintext);
+
+        let mut b = [0u8; 16];
+        for j in 0..6 {
+            for (i, r) in r.chunks_exact_mut(8).enumerate() {
+                // B = AES(K, A | R[i])
+                b[..8].copy_from_slice(a);
+                b[8..].copy_from_slice(r);
+                self.key.encrypt_block(&mut b);
+
+                // A = MSB(64, B) ^ t where t = (n*j)+i
+                let t = ((n * j) + i + 1) as u64;
+                for (a, (b, t)) in a.iter_mut().zip(b.iter().zip(t.to_be_bytes())) {
+                    *a = b ^ t;
+                }
+
+                // R[i] = LSB(64, B)
+                r.copy_from_slice(&b[8..]);
+            }
+        }
+        zeroise(&mut b);
+
+        Ok(out)
+    }
+
+    /// Unwraps `ciphertext`, writing the result to the start of `out`.
+    ///
+    /// `ciphertext` must be a whole number of 64-bit blocks, and at least
+    /// three of them.  `out` must be at least [`OVERHEAD`] bytes shorter.
+    /// [`Error::WrongLength`] is returned otherwise, and
+    /// [`Error::DecryptFailed`] if the integrity check fails.
+    pub(crate) fn unwrap<'a>(
+        &self,
+        ciphertext: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], Error> {
+        let len = ciphertext
+            .len()
+            .checked_sub(OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+        let n = blocks(len)?;
+        let r = out.get_mut(..len).ok_or(Error::WrongLength)?;
+
+        let _entry = Entry::new_secret();
+        let mut a = [0u8; OVERHEAD];
+        a.copy_from_slice(&ciphertext[..OVERHEAD]);
+        r.copy_from_slice(&ciphertext[OVERHEAD..]);
+
+        let mut b = [0u8; 16];
+        for j in (0..6).rev() {
+            for (i, r) in r.chunks_exact_mut(8).enumerate().rev() {
+                // B = AES-1(K, (A ^ t) | R[i]) where t = n*j+i
+                let t = ((n * j) + i + 1) as u64;
+                for (b, (a, t)) in b.iter_mut().zip(a.iter().zip(t.to_be_bytes())) {
+                    *b = a ^ t;
+                }
+                b[8..].copy_from_slice(r);
+                self.key.decrypt_block(&mut b);
+
+                // A = MSB(64, B)
+                a.copy_from_slice(&b[..8]);
+
+                // R[i] = LSB(64, B)
+                r.copy_from_slice(&b[8..]);
+            }
+        }
+        zeroise(&mut b);
+
+        match ct_equal(&a, &IV) {
+            true => Ok(r),
+            false => {
+                zeroise(r);
+                Err(Error::DecryptFailed)
+            }
+        }
+    }
+}
+
+/// The number of 64-bit blocks in `len` bytes, which must be at least two.
+fn blocks(len: usize) -> Result<usize, Error> {
+    match (len % 8, len / 8) {
+        (0, n) if n >= 2 => Ok(n),
+        _ => Err(Error::WrongLength),
+    }
+}
+
+/// The default initial value, from RFC3394 section 2.2.3.1.
+const IV: [u8; 8] = [0xa6; 8];
+
+/// How much longer the wrapped key is than the plaintext.
+pub(crate) const OVERHEAD: usize = 8;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rfc3394() {
+        for (kek, key_data, ciphertext) in [
+            // section 4.1
+            (
+                "000102030405060708090A0B0C0D0E0F",
+                "00112233445566778899AABBCCDDEEFF",
+                "1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5",
+            ),
+            // section 4.3
+            (
+                "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F",
+                "00112233445566778899AABBCCDDEEFF",
+                "64E8C3F9CE0F5BA263E9777905818A2A93C8191E7D6E8AE7",
+            ),
+            // section 4.6
+            (
+                "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F",
+                "00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F",
+                "28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21",
+            ),
+        ] {
+            let kw = AesKw::new(&unhex(kek)).unwrap();
+            let mut buf = [0u8; 40];
+            let wrapped = kw.wrap(&unhex(key_data), &mut buf).unwrap();
+            assert_eq!(wrapped, unhex(ciphertext));
+
+            let mut buf = [0u8; 32];
+            let unwrapped = kw.unwrap(&unhex(ciphertext), &mut buf).unwrap();
+            assert_eq!(unwrapped, unhex(key_data));
+
+            let mut altered = unhex(ciphertext);
+            altered[12] ^= 1;
+            assert_eq!(
+                kw.unwrap(&altered, &mut [0u8; 32]).err(),
+                Some(Error::DecryptFailed)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(AesKw::new(&[0; 24]).err(), Some(Error::WrongLength));
+        let kw = AesKw::new(&[0; 16]).unwrap();
+        assert_eq!(
+            kw.wrap(&[0; 8], &mut [0; 16]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            kw.wrap(&[0; 17], &mut [0; 32]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            kw.wrap(&[0; 16], &mut [0; 23]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            kw.unwrap(&[0; 16], &mut [0; 16]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            kw.unwrap(&[0; 25], &mut [0; 32]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            kw.unwrap(&[0; 24], &mut [0; 15]).err(),
+            Some(Error::WrongLength)
+        );
+    }
+}
@@ -0,0 +1,249 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::cmac::{Cmac, double};
+use crate::Error;
+use crate::low::{AesKey, Entry, ct_equal, zeroise};
+
+/// An AES-SIV key.
+///
+/// AES-SIV is a deterministic authenticated encryption mode, specified in
+/// [RFC5297](https://datatracker.ietf.org/doc/html/rfc5297).  It is
+/// misuse-resistant: encrypting the same message with the same associated
+/// data always produces the same ciphertext, but reveals nothing else.
+/// A nonce may be included as the last associated data component.
+///
+/// As with the rest of this crate, AES-192 is not supported.
+pub struct AesSiv {
+    mac_key: [u8; 32],
+    mac_key_len: usize,
+    ctr_key: AesKey,
+}
+
+impl AesSiv {
+    /// Create a new [`AesSiv`] from key material.
+    ///
+    /// `key` must be 32 or 64 bytes (for "AES-SIV-256" and "AES-SIV-512",
+    /// which use AES-128 and AES-256 respectively).
+    /// [`Error::WrongLength`] is returned otherwise.
+    pub fn new(key: &[u8]) -> Result<Self, Error> {
+        if !matches!(key.len(), 32 | 64) {
+            return Err(Error::WrongLength);
+        }
+
+        let _entry = Entry::new_secret();
+        let (k1, k2) = key.split_at(key.len() / 2);
+        let mut mac_key = [0u8; 32];
+        mac_key[..k1.len()].copy_from_slice(k1);
+        Ok(Self {
+            mac_key,
+            mac_key_len: k1.len(),
+            ctr_key: AesKey::new(k2),
+        })
+    }
+
+    /// Encrypt the given message.
+    ///
+    /// On entry, `cipher_inout` contains the plaintext of the message.
+    /// `associated_data` is a vector of associated data components, which
+    /// are authenticated separately.  It may be empty.
+    ///
+    /// On exit, `cipher_inout` contains the ciphertext of the message,
+    /// and `tag_out` contains the synthetic IV.
+    pub fn encrypt(
+        &self,
+        associated_data: &[&[u8]],
+        cipher_inout: &mut [u8],
+        tag_out: &mut [u8; 16],
+    ) {
+        let _entry = Entry::new_secret();
+        *tag_out = self.s2v(associated_data, cipher_inout);
+        self.ctr(tag_out, cipher_inout);
+    }
+
+    /// Decrypts and verifies the given message.
+    ///
+    /// On entry, `cipher_inout` contains the ciphertext of the message.
+    /// `associated_data` must match that supplied when encrypting.
+    /// `tag` is the purported synthetic IV.
+    ///
+    /// On success, `cipher_inout` contains the plaintext of the message,
+    /// and `Ok(())` is returned.
+    /// Otherwise, `Err(Error::DecryptFailed)` is returned and `cipher_inout`
+    /// is cleared.
+    pub fn decrypt(
+        &self,
+        associated_data: &[&[u8]],
+        cipher_inout: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        let tag: &[u8; 16] = match tag.try_into() {
+            Ok(tag) => tag,
+            Err(_) => {
+                cipher_inout.fill(0);
+                return Err(Error::DecryptFailed);
+            }
+        };
+
+        self.ctr(tag, cipher_inout);
+        let expected = self.s2v(associated_data, cipher_inout);
+
+        if ct_equal(&expected, tag) {
+            Ok(())
+        } else {
+            cipher_inout.fill(0);
+            Err(Error::DecryptFailed)
+        }
+    }
+
+    /// `S2V()`, from RFC5297 section 2.4.
+    fn s2v(&self, associated_data: &[&[u8]], plaintext: &[u8]) -> [u8; 16] {
+        let mut d = self.cmac(&[&[0u8; 16]]);
+        for ad in associated_data {
+            let mac = self.cmac(&[ad]);
+            d = double(&d);
+            xor(&mut d, &mac);
+        }
+
+        if plaintext.len() >= 16 {
+            // `xorend`
+            let (start, end) = plaintext.split_at(plaintext.len() - 16);
+            xor(&mut d, end);
+            self.cmac(&[start, &d])
+        } else {
+            d = double(&d);
+            xor(&mut d, plaintext);
+            d[plaintext.len()] ^= 0x80;
+            self.cmac(&[&d])
+        }
+    }
+
+    fn cmac(&self, parts: &[&[u8]]) -> [u8; 16] {
+        let mut cmac = Cmac::new(&self.mac_key[..self.mac_key_len]).unwrap();
+        for part in parts {
+            cmac.update(part);
+        }
+        cmac.finish()
+    }
+
+    fn ctr(&self, iv: &[u8; 16], inout: &mut [u8]) {
+        // the counter has two bits cleared, to allow for implementations
+        // using 32- or 64-bit counter arithmetic.
+        let mut q = *iv;
+        q[8] &= 0x7f;
+        q[12] &= 0x7f;
+        let mut counter = u128::from_be_bytes(q);
+
+        for chunk in inout.chunks_mut(16) {
+            let mut block = counter.to_be_bytes();
+            self.ctr_key.encrypt_block(&mut block);
+            xor(chunk, &block);
+            counter = counter.wrapping_add(1);
+        }
+    }
+}
+
+impl Drop for AesSiv {
+    fn drop(&mut self) {
+        zeroise(&mut self.mac_key);
+    }
+}
+
+fn xor(out: &mut [u8], other: &[u8]) {
+    for (o, x) in out.iter_mut().zip(other) {
+        *o ^= *x;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn check(key: &str, ad: &[&str], plaintext: &str, expect: &str) {
+        let siv = AesSiv::new(&unhex(key)).unwrap();
+        let ad = ad.iter().map(|a| unhex(a)).collect::<Vec<_>>();
+        let ad = ad.iter().map(|a| a.as_slice()).collect::<Vec<_>>();
+        let expect = unhex(expect);
+
+        let mut inout = unhex(plaintext);
+        let mut tag = [0u8; 16];
+        siv.encrypt(&ad, &mut inout, &mut tag);
+        assert_eq!(&tag[..], &expect[..16]);
+        assert_eq!(&inout[..], &expect[16..]);
+
+        siv.decrypt(&ad, &mut inout, &tag).unwrap();
+        assert_eq!(inout, unhex(plaintext));
+
+        siv.encrypt(&ad, &mut inout, &mut tag);
+        tag[15] ^= 1;
+        assert_eq!(
+            siv.decrypt(&ad, &mut inout, &tag),
+            Err(Error::DecryptFailed)
+        );
+        assert!(inout.iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn rfc5297_deterministic() {
+        // RFC5297 appendix A.1
+        check(
+            "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+            &["101112131415161718191a1b1c1d1e1f2021222324252627"],
+            "112233445566778899aabbccddee",
+            "85632d07c6e8f37f950acd320a2ecc9340c02b9690c4dc04daef7f6afe5c",
+        );
+    }
+
+    #[test]
+    fn rfc5297_nonce_based() {
+        // RFC5297 appendix A.2
+        check(
+            "7f7e7d7c7b7a79787776757473727170404142434445464748494a4b4c4d4e4f",
+            &[
+                "00112233445566778899aabbccddeeffdeaddadadeaddadaffeeddccbbaa99887766554433221100",
+                "102030405060708090a0",
+                "09f911029d74e35bd84156c5635688c0",
+            ],
+            "7468697320697320736f6d6520706c61696e7465787420746f20656e6372797074207573696e67205349562d414553",
+            "7bdb6e3b432667eb06f4d14bff2fbd0fcb900f2fddbe404326601965c889bf17\
+             dba77ceb094fa663b7a3f748ba8af829ea64ad544a272e9c485b62a3fd5c0d",
+        );
+    }
+
+    #[test]
+    fn aes_siv_512() {
+        // from an independent implementation
+        let key = (0..64).map(|i| format!("{i:02x}")).collect::<String>();
+        let plaintext = (0..32).map(|i| format!("{i:02x}")).collect::<String>();
+        check(
+            &key,
+            &["686561646572"],
+            &plaintext,
+            "786c5c133a4b8b3780b6fce17c07818df0e567c46172a29f6375f3f2c004b7db\
+             314658bcc33182ac6972d8d24cac516f",
+        );
+        check(&key, &["78"], "", "79914a8060c8b2b21464746917f870d4");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(AesSiv::new(&[0; 16]).is_err());
+        assert!(AesSiv::new(&[0; 48]).is_err());
+
+        let siv = AesSiv::new(&[0; 32]).unwrap();
+        let mut inout = [1u8; 4];
+        assert_eq!(
+            siv.decrypt(&[], &mut inout, &[0; 15]),
+            Err(Error::DecryptFailed)
+        );
+        assert_eq!(inout, [0; 4]);
+    }
+}
@@ -0,0 +1,249 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! `bcrypt_pbkdf`, as used by OpenSSH to encrypt private keys.
+//!
+//! This is PBKDF2-like, but with a `bcrypt`-derived hash function
+//! in place of HMAC.  It comes from OpenBSD, and is not otherwise
+//! standardized.
+//!
+//! The Blowfish S-boxes are indexed by passphrase-derived values,
+//! so this is not constant-time.
+
+use crate::Error;
+use crate::low::zeroise;
+use crate::mid::sha2::Sha512Context;
+
+mod tables;
+
+/// Fill `out` with key material derived from `passphrase` and `salt`.
+///
+/// `rounds` must be at least one, otherwise [`Error::OutOfRange`] is
+/// returned.  `out` may be at most 1024 bytes, otherwise
+/// [`Error::WrongLength`] is returned.
+pub(crate) fn bcrypt_pbkdf(
+    passphrase: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    out: &mut [u8],
+) -> Result<(), Error> {
+    if rounds == 0 {
+        return Err(Error::OutOfRange);
+    }
+    if out.is_empty() || out.len() > HASH_LEN * HASH_LEN {
+        return Err(Error::WrongLength);
+    }
+
+    let mut sha2_passphrase = sha512(&[passphrase]);
+    // output bytes are spread across the blocks, rather than
+    // taken from each in turn
+    let stride = (out.len() + HASH_LEN - 1) / HASH_LEN;
+
+    for block in 0..stride {
+        let mut sha2_salt = sha512(&[salt, &(block as u32 + 1).to_be_bytes()]);
+        let mut t = bcrypt_hash(&sha2_passphrase, &sha2_salt);
+        let mut u = t;
+
+        for _ in 1..rounds {
+            sha2_salt = sha512(&[&u]);
+            u = bcrypt_hash(&sha2_passphrase, &sha2_salt);
+            for (t, u) in t.iter_mut().zip(u.iter()) {
+                *t ^= *u;
+            }
+        }
+
+        for (out, t) in out.iter_mut().skip(block).step_by(stride).zip(t.iter()) {
+            *out = *t;
+        }
+
+        zeroise(&mut sha2_salt);
+        zeroise(&mut t);
+        zeroise(&mut u);
+    }
+
+    zeroise(&mut sha2_passphrase);
+    Ok(())
+}
+
+fn sha512(parts: &[&[u8]]) -> [u8; Sha512Context::OUTPUT_SZ] {
+    let mut ctx = Sha512Context::new();
+    for part in parts {
+        ctx.update(part);
+    }
+    ctx.finish()
+}
+
+/// The `bcrypt`-derived hash function: a shortened `bcrypt` with
+/// a 64-byte salt, and a different final block.
+fn bcrypt_hash(sha2_passphrase: &[u8; 64], sha2_salt: &[u8; 64]) -> [u8; HASH_LEN] {
+    let mut state = Blowfish::new();
+    state.expand(sha2_passphrase, Some(sha2_salt));
+    for _ in 0..64 {
+        state.expand(sha2_salt, None);
+        state.expand(sha2_passphrase, None);
+    }
+
+    let mut data = [0u32; HASH_LEN / 4];
+    for (word, bytes) in data.iter_mut().zip(MAGIC.chunks_exact(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    for _ in 0..64 {
+        for pair in data.chunks_exact_mut(2) {
+            (pair[0], pair[1]) = state.encrypt(pair[0], pair[1]);
+        }
+    }
+
+    // nb. little-endian, unlike the rest of the algorithm
+    let mut out = [0u8; HASH_LEN];
+    for (bytes, word) in out.chunks_exact_mut(4).zip(data.iter()) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    zeroise(&mut data);
+    out
+}
+
+/// The expensive-key-schedule Blowfish state.
+struct Blowfish {
+    p: [u32; 18],
+    s: [u32; 1024],
+}
+
+impl Blowfish {
+    fn new() -> Self {
+        Self {
+            p: tables::INITIAL_P,
+            s: tables::INITIAL_S,
+        }
+    }
+
+    /// Mixes `key` into the state, and (if given) `data` into the
+    /// encryptions that replace it.
+    ///
+    /// Both are used cyclically, as a stream of big-endian words.
+    fn expand(&mut self, key: &[u8], data: Option<&[u8]>) {
+        let mut key = Words::new(key);
+        for p in self.p.iter_mut() {
+            *p ^= key.next();
+        }
+
+        let mut data = data.map(Words::new);
+        let (mut left, mut right) = (0, 0);
+        for i in (0..self.p.len() + self.s.len()).step_by(2) {
+            if let Some(data) = &mut data {
+                left ^= data.next();
+                right ^= data.next();
+            }
+            (left, right) = self.encrypt(left, right);
+
+            let pair = match i < self.p.len() {
+                true => &mut self.p[i..i + 2],
+                false => &mut self.s[i - self.p.len()..i - self.p.len() + 2],
+            };
+            pair[0] = left;
+            pair[1] = right;
+        }
+    }
+
+    fn encrypt(&self, mut left: u32, mut right: u32) -> (u32, u32) {
+        left ^= self.p[0];
+        for i in (1..17).step_by(2) {
+            right ^= self.f(left) ^ self.p[i];
+            left ^= self.f(right) ^ self.p[i + 1];
+        }
+        (right ^ self.p[17], left)
+    }
+
+    fn f(&self, x: u32) -> u32 {
+        let [a, b, c, d] = x.to_be_bytes().map(usize::from);
+        (self.s[a].wrapping_add(self.s[0x100 + b]) ^ self.s[0x200 + c])
+            .wrapping_add(self.s[0x300 + d])
+    }
+}
+
+impl Drop for Blowfish {
+    fn drop(&mut self) {
+        zeroise(&mut self.p);
+        zeroise(&mut self.s);
+    }
+}
+
+/// Reads big-endian words from a byte string, wrapping around at its end.
+struct Words<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Words<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn next(&mut self) -> u32 {
+        let mut word = 0;
+        for _ in 0..4 {
+            word = (word << 8) | u32::from(self.bytes[self.pos]);
+            self.pos = (self.pos + 1) % self.bytes.len();
+        }
+        word
+    }
+}
+
+const HASH_LEN: usize = 32;
+
+const MAGIC: &[u8; HASH_LEN] = b"OxychromaticBlowfishSwatDynamite";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn known_answers() {
+        for (passphrase, salt, rounds, expected) in [
+            (
+                &b"password"[..],
+                &b"salt"[..],
+                4,
+                "5bbf0cc293587f1c3635555c27796598d47e579071bf427e9d8fbe842aba34d9",
+            ),
+            (
+                b"password",
+                b"salt",
+                8,
+                "e1367ec5151a33faac4cc1c144cd23fa15d5548493ecc99b9b5d9c0d3b27bec7\
+                 6227ea66088b849b20ab7aa478010246e74bba51723fefa9f9474d6508845e8d",
+            ),
+            (
+                b"passphrase",
+                &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+                2,
+                "31980f086fe0fe48931c9cc46d55e6b81c451190bc9f5edf\
+                 010d5a85edf9ba5cad2ef8421eac0ccff5ceba797f00a1eb",
+            ),
+            (b"", &[0; 16], 1, "67a5b8fe45016504"),
+        ] {
+            let mut out = vec![0u8; expected.len() / 2];
+            bcrypt_pbkdf(passphrase, salt, rounds, &mut out).unwrap();
+            assert_eq!(hex(&out), expected);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            bcrypt_pbkdf(b"", b"salt", 0, &mut [0; 32]).err(),
+            Some(Error::OutOfRange)
+        );
+        assert_eq!(
+            bcrypt_pbkdf(b"", b"salt", 1, &mut []).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            bcrypt_pbkdf(b"", b"salt", 1, &mut [0; 1025]).err(),
+            Some(Error::WrongLength)
+        );
+    }
+}
@@ -0,0 +1,359 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Arbitrary-size integer arithmetic.
+//!
+//! Numbers are slices of 64-bit words, least significant word first.
+//! These run in time which depends only on the lengths of their
+//! arguments, and not their values.
+
+use crate::Error;
+use crate::low::{self, Entry};
+
+/// Add, `z := x + y`.
+///
+/// The result is truncated to the length of `z`.  `x` and `y` may have
+/// any lengths.
+pub fn bignum_add(z: &mut [u64], x: &[u64], y: &[u64]) {
+    let _entry = Entry::new_secret();
+    low::bignum_add(z, x, y);
+}
+
+/// Multiply, `z := x * y`.
+///
+/// `z` must have at least as many words as `x` and `y` together,
+/// otherwise [`Error::WrongLength`] is returned.
+pub fn bignum_mul(z: &mut [u64], x: &[u64], y: &[u64]) -> Result<(), Error> {
+    if z.len() < x.len() + y.len() {
+        return Err(Error::WrongLength);
+    }
+
+    let _entry = Entry::new_secret();
+    low::bignum_mul(z, x, y);
+    Ok(())
+}
+
+/// Compare, returning whether `x < y`.
+///
+/// `x` and `y` may have different lengths.
+pub fn bignum_cmp_lt(x: &[u64], y: &[u64]) -> bool {
+    let _entry = Entry::new_secret();
+    low::bignum_cmp_lt(x, y) != 0
+}
+
+/// Compare, returning whether `x == y`.
+///
+/// `x` and `y` may have different lengths.
+pub fn bignum_eq(x: &[u64], y: &[u64]) -> bool {
+    let _entry = Entry::new_secret();
+    low::bignum_eq(x, y)
+}
+
+/// Modular addition, `z := (x + y) mod m`.
+///
+/// `z`, `x`, `y` and `m` must have the same length, otherwise
+/// [`Error::WrongLength`] is returned.
+///
+/// The caller must ensure `x < m` and `y < m`: the result is
+/// meaningless otherwise.
+pub fn bignum_modadd(z: &mut [u64], x: &[u64], y: &[u64], m: &[u64]) -> Result<(), Error> {
+    if x.len() != z.len() || y.len() != z.len() || m.len() != z.len() {
+        return Err(Error::WrongLength);
+    }
+
+    let _entry = Entry::new_secret();
+    low::bignum_modadd(z, x, y, m);
+    Ok(())
+}
+
+/// Modular subtraction, `z := (x - y) mod m`.
+///
+/// `z`, `x`, `y` and `m` must have the same length, otherwise
+/// [`Error::WrongLength`] is returned.
+///
+/// The caller must ensure `x < m` and `y < m`: the result is
+/// meaningless otherwise.
+pub fn bignum_modsub(z: &mut [u64], x: &[u64], y: &[u64], m: &[u64]) -> Result<(), Error> {
+    if x.len() != z.len() || y.len() != z.len() || m.len() != z.len() {
+        return Err(Error::WrongLength);
+    }
+
+    let _entry = Entry::new_secret();
+    low::bignum_modsub(z, x, y, m);
+    Ok(())
+}
+
+/// Montgomery arithmetic modulo an odd number.
+///
+/// This holds a modulus `m` of `k` words, and the constants needed
+/// for Montgomery multiplication with `R = 2^(64k)`.  Values in the
+/// Montgomery domain are `x * R mod m`.
+///
+/// Every operand and output must be exactly `k` words, otherwise
+/// [`Error::WrongLength`] is returned.  The modulus is considered public;
+/// other values may be secret.
+pub struct MontgomeryCtx {
+    modulus: Vec<u64>,
+
+    /// `R^2 mod m`.
+    montifier: Vec<u64>,
+}
+
+impl MontgomeryCtx {
+    /// The largest supported modulus, in words (8192 bits).
+    pub const MAX_WORDS: usize = 128;
+
+    /// Prepare for arithmetic modulo `modulus`.
+    ///
+    /// `modulus` must be between 1 and [`MontgomeryCtx::MAX_WORDS`]
+    /// words, otherwise [`Error::WrongLength`] is returned.  It must be odd,
+    /// greater than one, and have a non-zero most significant word,
+    /// otherwise [`Error::OutOfRange`] is returned.
+    pub fn new(modulus: &[u64]) -> Result<Self, Error> {
+        if modulus.is_empty() || modulus.len() > Self::MAX_WORDS {
+            return Err(Error::WrongLength);
+        }
+
+        if modulus[0] & 1 == 0 || modulus[modulus.len() - 1] == 0 || modulus == [1] {
+            return Err(Error::OutOfRange);
+        }
+
+        let _entry = Entry::new_secret();
+        let mut montifier = vec![0u64; modulus.len()];
+        let mut tmp = vec![0u64; modulus.len()];
+        low::bignum_montifier(&mut montifier, modulus, &mut tmp);
+
+        Ok(Self {
+            modulus: modulus.to_vec(),
+            montifier,
+        })
+    }
+
+    /// The modulus.
+    ///
+    /// Its length is the length, in words, of every operand.
+    pub fn modulus(&self) -> &[u64] {
+        &self.modulus
+    }
+
+    /// Convert into the Montgomery domain, `z := (x * R) mod m`.
+    ///
+    /// `x` need not be reduced.
+    pub fn to_montgomery(&self, z: &mut [u64], x: &[u64]) -> Result<(), Error> {
+        self.check_lengths(z, &[x])?;
+        let _entry = Entry::new_secret();
+        low::bignum_montmul(z, x, &self.montifier, &self.modulus);
+        Ok(())
+    }
+
+    /// Convert out of the Montgomery domain, `z := (x / R) mod m`.
+    ///
+    /// `x` need not be reduced.
+    pub fn from_montgomery(&self, z: &mut [u64], x: &[u64]) -> Result<(), Error> {
+        self.check_lengths(z, &[x])?;
+        let _entry = Entry::new_secret();
+        low::bignum_montredc(z, x, &self.modulus, self.modulus.len() as u64);
+        Ok(())
+    }
+
+    /// Montgomery multiplication, `z := (x * y / R) mod m`.
+    ///
+    /// The caller must ensure `x < m` and `y < m`: the result is
+    /// meaningless otherwise.
+    pub fn mul(&self, z: &mut [u64], x: &[u64], y: &[u64]) -> Result<(), Error> {
+        self.check_lengths(z, &[x, y])?;
+        let _entry = Entry::new_secret();
+        low::bignum_montmul(z, x, y, &self.modulus);
+        Ok(())
+    }
+
+    /// Montgomery squaring, `z := (x * x / R) mod m`.
+    ///
+    /// The caller must ensure `x < m`: the result is meaningless otherwise.
+    pub fn square(&self, z: &mut [u64], x: &[u64]) -> Result<(), Error> {
+        self.check_lengths(z, &[x])?;
+        let _entry = Entry::new_secret();
+        low::bignum_montsqr(z, x, &self.modulus);
+        Ok(())
+    }
+
+    /// Reduce a double-length number, `z := x mod m`.
+    ///
+    /// `x` is `2k` words, and the caller must ensure `x < m * R`.  This is
+    /// true of the product of any two numbers less than `m`, for example
+    /// from [`bignum_mul`].  This works outside the Montgomery domain.
+    pub fn reduce(&self, z: &mut [u64], x: &[u64]) -> Result<(), Error> {
+        if x.len() != self.modulus.len() * 2 {
+            return Err(Error::WrongLength);
+        }
+        self.check_lengths(z, &[])?;
+
+        let _entry = Entry::new_secret();
+        // (x / R) * R^2 / R = x
+        let mut tmp = vec![0u64; self.modulus.len()];
+        low::bignum_montredc(&mut tmp, x, &self.modulus, self.modulus.len() as u64);
+        low::bignum_montmul(z, &tmp, &self.montifier, &self.modulus);
+        low::zeroise(&mut tmp);
+        Ok(())
+    }
+
+    fn check_lengths(&self, z: &[u64], inputs: &[&[u64]]) -> Result<(), Error> {
+        let k = self.modulus.len();
+        if z.len() != k || inputs.iter().any(|i| i.len() != k) {
+            return Err(Error::WrongLength);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_mul() {
+        let x = [u64::MAX, u64::MAX];
+        let mut z = [0u64; 3];
+        bignum_add(&mut z, &x, &[1]);
+        assert_eq!(z, [0, 0, 1]);
+
+        // truncated to the length of z
+        let mut z = [0u64; 2];
+        bignum_add(&mut z, &x, &[1]);
+        assert_eq!(z, [0, 0]);
+
+        // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+        let mut z = [0u64; 4];
+        bignum_mul(&mut z, &x, &x).unwrap();
+        assert_eq!(z, [1, 0, u64::MAX - 1, u64::MAX]);
+
+        let mut z = [0u64; 3];
+        assert!(matches!(
+            bignum_mul(&mut z, &x, &x),
+            Err(Error::WrongLength)
+        ));
+    }
+
+    #[test]
+    fn compare() {
+        assert!(bignum_cmp_lt(&[1], &[2]));
+        assert!(!bignum_cmp_lt(&[2], &[2]));
+        assert!(bignum_cmp_lt(&[u64::MAX], &[0, 1]));
+        assert!(!bignum_cmp_lt(&[0, 1], &[u64::MAX]));
+
+        assert!(bignum_eq(&[5], &[5, 0, 0]));
+        assert!(!bignum_eq(&[5], &[5, 1]));
+    }
+
+    #[test]
+    fn modular_add_sub() {
+        let m = [0xffff_ffff_0000_0001, 0xffff_ffff];
+        let x = [0xffff_ffff_0000_0000, 0xffff_ffff];
+        let one = [1, 0];
+
+        let mut z = [0u64; 2];
+        bignum_modadd(&mut z, &x, &one, &m).unwrap();
+        assert_eq!(z, [0, 0]);
+
+        bignum_modsub(&mut z, &[0, 0], &one, &m).unwrap();
+        assert_eq!(z, x);
+
+        assert!(matches!(
+            bignum_modadd(&mut z, &x, &[1], &m),
+            Err(Error::WrongLength)
+        ));
+        assert!(matches!(
+            bignum_modsub(&mut z, &x, &one, &m[..1]),
+            Err(Error::WrongLength)
+        ));
+    }
+
+    #[test]
+    fn montgomery_small_modulus() {
+        let ctx = MontgomeryCtx::new(&[1_000_003]).unwrap();
+        assert_eq!(ctx.modulus(), &[1_000_003]);
+
+        let (x, y) = ([123_456], [654_321]);
+        let (mut xm, mut ym, mut zm, mut z) = ([0], [0], [0], [0]);
+        ctx.to_montgomery(&mut xm, &x).unwrap();
+        ctx.to_montgomery(&mut ym, &y).unwrap();
+        ctx.mul(&mut zm, &xm, &ym).unwrap();
+        ctx.from_montgomery(&mut z, &zm).unwrap();
+        assert_eq!(z[0], 123_456 * 654_321 % 1_000_003);
+
+        ctx.square(&mut zm, &xm).unwrap();
+        ctx.from_montgomery(&mut z, &zm).unwrap();
+        assert_eq!(z[0], 123_456 * 123_456 % 1_000_003);
+
+        ctx.reduce(&mut z, &[u64::MAX, 1_000_002]).unwrap();
+        let expect = ((1_000_002u128 << 64) + u64::MAX as u128) % 1_000_003;
+        assert_eq!(z[0] as u128, expect);
+    }
+
+    #[test]
+    fn montgomery_matches_mul_and_reduce() {
+        // 2^255 - 19
+        let m = [
+            0xffff_ffff_ffff_ffed,
+            0xffff_ffff_ffff_ffff,
+            0xffff_ffff_ffff_ffff,
+            0x7fff_ffff_ffff_ffff,
+        ];
+        let ctx = MontgomeryCtx::new(&m).unwrap();
+        let x = [1, 2, 3, 4];
+        let y = [0xdead_beef, 0, 0x1234_5678_9abc_def0, 0x7000_0000_0000_0000];
+
+        let mut product = [0u64; 8];
+        bignum_mul(&mut product, &x, &y).unwrap();
+        let mut expect = [0u64; 4];
+        ctx.reduce(&mut expect, &product).unwrap();
+
+        let (mut xm, mut ym, mut zm, mut z) = ([0; 4], [0; 4], [0; 4], [0; 4]);
+        ctx.to_montgomery(&mut xm, &x).unwrap();
+        ctx.to_montgomery(&mut ym, &y).unwrap();
+        ctx.mul(&mut zm, &xm, &ym).unwrap();
+        ctx.from_montgomery(&mut z, &zm).unwrap();
+        assert_eq!(z, expect);
+        assert!(bignum_cmp_lt(&z, &m));
+
+        // addition works unchanged in the Montgomery domain
+        let mut sum = [0u64; 4];
+        bignum_modadd(&mut sum, &xm, &ym, ctx.modulus()).unwrap();
+        ctx.from_montgomery(&mut z, &sum).unwrap();
+        let mut expect = [0u64; 4];
+        bignum_modadd(&mut expect, &x, &y, &m).unwrap();
+        assert_eq!(z, expect);
+
+        // unreduced inputs are reduced on the way in
+        ctx.to_montgomery(&mut xm, &[u64::MAX; 4]).unwrap();
+        ctx.from_montgomery(&mut z, &xm).unwrap();
+        assert_eq!(z, [37, 0, 0, 0]);
+    }
+
+    #[test]
+    fn montgomery_rejects_bad_moduli() {
+        for m in [&[][..], &[1], &[4], &[3, 0], &[u64::MAX - 1, 1]] {
+            assert!(MontgomeryCtx::new(m).is_err());
+        }
+        assert!(matches!(
+            MontgomeryCtx::new(&[1; MontgomeryCtx::MAX_WORDS + 1]),
+            Err(Error::WrongLength)
+        ));
+        assert!(MontgomeryCtx::new(&[u64::MAX; MontgomeryCtx::MAX_WORDS]).is_ok());
+
+        let ctx = MontgomeryCtx::new(&[7, 1]).unwrap();
+        let mut z = [0u64; 2];
+        assert!(matches!(
+            ctx.mul(&mut z, &[1, 0], &[1]),
+            Err(Error::WrongLength)
+        ));
+        assert!(matches!(
+            ctx.reduce(&mut z, &[1, 0]),
+            Err(Error::WrongLength)
+        ));
+        assert!(matches!(
+            ctx.to_montgomery(&mut z[..1], &[1]),
+            Err(Error::WrongLength)
+        ));
+    }
+}
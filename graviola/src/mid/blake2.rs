@@ -0,0 +1,271 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! BLAKE2-family hash functions.
+//!
+//! This is BLAKE2b-512 and BLAKE2s-256, as described in
+//! [RFC7693](https://datatracker.ietf.org/doc/html/rfc7693).
+//! Keyed and shorter-output variants are available with `new_keyed()`.
+
+use crate::Error;
+use crate::low::{BLAKE2B_IV, BLAKE2S_IV, blake2b_compress, blake2s_compress, zeroise};
+
+macro_rules! blake2_context {
+    ($name:ident, $word:ty, $iv:ident, $compress:ident, $block_sz:literal, $output_sz:literal, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            h: [$word; 8],
+            buffer: [u8; $block_sz],
+            used: usize,
+            t: u128,
+        }
+
+        impl $name {
+            /// Start a new hash computation.
+            pub const fn new() -> Self {
+                let mut h = $iv;
+                // parameter block: no key, fanout and depth of 1
+                h[0] ^= 0x0101_0000 ^ Self::OUTPUT_SZ as $word;
+                Self {
+                    h,
+                    buffer: [0u8; $block_sz],
+                    used: 0,
+                    t: 0,
+                }
+            }
+
+            /// Start a new keyed hash computation, with an `output_len`-byte output.
+            ///
+            /// `key` may be empty, and must be at most [`Self::OUTPUT_SZ`] bytes.
+            /// `output_len` must be between 1 and [`Self::OUTPUT_SZ`].
+            /// [`Error::WrongLength`] is returned otherwise.
+            ///
+            /// Both are bound into the parameter block, so this is not the same as
+            /// truncating an unkeyed hash.  The output is the first `output_len` bytes of
+            /// that returned by [`Self::finish()`].
+            pub fn new_keyed(key: &[u8], output_len: usize) -> Result<Self, Error> {
+                if key.len() > Self::OUTPUT_SZ || !(1..=Self::OUTPUT_SZ).contains(&output_len) {
+                    return Err(Error::WrongLength);
+                }
+
+                let mut h = $iv;
+                // parameter block: key length, fanout and depth of 1
+                h[0] ^= 0x0101_0000 ^ ((key.len() as $word) << 8) ^ output_len as $word;
+                let mut ctx = Self {
+                    h,
+                    buffer: [0u8; $block_sz],
+                    used: 0,
+                    t: 0,
+                };
+
+                // the key is the first block, padded with zeroes
+                if !key.is_empty() {
+                    ctx.buffer[..key.len()].copy_from_slice(key);
+                    ctx.used = Self::BLOCK_SZ;
+                }
+                Ok(ctx)
+            }
+
+            /// Add `bytes` to the ongoing hash computation.
+            pub fn update(&mut self, mut bytes: &[u8]) {
+                while !bytes.is_empty() {
+                    // the final block is compressed differently, so a
+                    // full buffer is only compressed once more input arrives.
+                    if self.used == Self::BLOCK_SZ {
+                        self.t += Self::BLOCK_SZ as u128;
+                        $compress(&mut self.h, &self.buffer, self.t, false);
+                        self.used = 0;
+                    }
+
+                    let take = core::cmp::min(Self::BLOCK_SZ - self.used, bytes.len());
+                    self.buffer[self.used..self.used + take].copy_from_slice(&bytes[..take]);
+                    self.used += take;
+                    bytes = &bytes[take..];
+                }
+            }
+
+            /// Complete the computation, returning the hash output.
+            pub fn finish(mut self) -> [u8; Self::OUTPUT_SZ] {
+                self.t += self.used as u128;
+                self.buffer[self.used..].fill(0);
+                $compress(&mut self.h, &self.buffer, self.t, true);
+
+                let mut r = [0u8; Self::OUTPUT_SZ];
+                for (out, state) in r
+                    .chunks_exact_mut(core::mem::size_of::<$word>())
+                    .zip(self.h.iter())
+                {
+                    out.copy_from_slice(&state.to_le_bytes());
+                }
+                zeroise(&mut self.buffer);
+                r
+            }
+
+            /// The internal block size.
+            pub const BLOCK_SZ: usize = $block_sz;
+
+            /// The output size.
+            pub const OUTPUT_SZ: usize = $output_sz;
+        }
+    };
+}
+
+blake2_context!(
+    Blake2bContext,
+    u64,
+    BLAKE2B_IV,
+    blake2b_compress,
+    128,
+    64,
+    "A context for incremental computation of BLAKE2b-512."
+);
+blake2_context!(
+    Blake2sContext,
+    u32,
+    BLAKE2S_IV,
+    blake2s_compress,
+    64,
+    32,
+    "A context for incremental computation of BLAKE2s-256."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn blake2b(bytes: &[u8]) -> String {
+        let mut ctx = Blake2bContext::new();
+        ctx.update(bytes);
+        hex(&ctx.finish())
+    }
+
+    fn blake2s(bytes: &[u8]) -> String {
+        let mut ctx = Blake2sContext::new();
+        ctx.update(bytes);
+        hex(&ctx.finish())
+    }
+
+    #[test]
+    fn rfc7693_abc() {
+        assert_eq!(
+            blake2b(b"abc"),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+        assert_eq!(
+            blake2s(b"abc"),
+            "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"
+        );
+    }
+
+    #[test]
+    fn keyed() {
+        // produced with python's hashlib
+        let key: Vec<u8> = (0..64).collect();
+        for (key, output_len, input, expected) in [
+            (
+                &key[..],
+                64,
+                &b"abc"[..],
+                "06bbc3dedf13a31139498655251b7588ccd3bb5aaa071b2d44d8e0a04095579e\
+                 d590fbfdcf941f4370ce5ce623624e7a76d33e7a8109dcda9b57d72f8f8efa51",
+            ),
+            (
+                &key[..],
+                64,
+                b"",
+                "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786\
+                 b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568",
+            ),
+            (
+                b"k",
+                56,
+                b"",
+                "650a3fcc15eba3e5af967ccdd5ccdcef10b87558aa5b90ff62c59ace376a9d3f\
+                 363b659541b9a0d4fe93426e20a4fed4f9def0817d33c295",
+            ),
+            (
+                b"",
+                32,
+                &key[..],
+                "10d8e6d534b00939843fe9dcc4dae48cdf008f6b8b2b82b156f5404d874887f5",
+            ),
+        ] {
+            let mut ctx = Blake2bContext::new_keyed(key, output_len).unwrap();
+            ctx.update(input);
+            assert_eq!(hex(&ctx.finish()[..output_len]), expected);
+        }
+
+        let mut ctx = Blake2sContext::new_keyed(&key[..32], 32).unwrap();
+        ctx.update(b"abc");
+        assert_eq!(
+            hex(&ctx.finish()),
+            "a281f725754969a702f6fe36fc591b7def866e4b70173ece402fc01c064d6b65"
+        );
+        let mut ctx = Blake2sContext::new_keyed(b"key", 16).unwrap();
+        ctx.update(&key);
+        assert_eq!(hex(&ctx.finish()[..16]), "5f386f3878c2f2d64baeff6dae3f7c14");
+
+        // an unkeyed full-length context is the same as `new()`
+        let mut ctx = Blake2bContext::new_keyed(b"", 64).unwrap();
+        ctx.update(b"abc");
+        assert_eq!(hex(&ctx.finish()), blake2b(b"abc"));
+
+        assert!(Blake2bContext::new_keyed(&[0; 65], 64).is_err());
+        assert!(Blake2bContext::new_keyed(b"", 0).is_err());
+        assert!(Blake2bContext::new_keyed(b"", 65).is_err());
+        assert!(Blake2sContext::new_keyed(&[0; 33], 32).is_err());
+    }
+
+    #[test]
+    fn block_boundaries() {
+        // produced with python's hashlib
+        let input: Vec<u8> = (0..=255u8).collect();
+        let cases = [
+            (
+                0,
+                "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419\
+                 d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce",
+                "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9",
+            ),
+            (
+                64,
+                "2fc6e69fa26a89a5ed269092cb9b2a449a4409a7a44011eecad13d7c4b045660\
+                 2d402fa5844f1a7a758136ce3d5d8d0e8b86921ffff4f692dd95bdc8e5ff0052",
+                "56f34e8b96557e90c1f24b52d0c89d51086acf1b00f634cf1dde9233b8eaaa3e",
+            ),
+            (
+                128,
+                "2319e3789c47e2daa5fe807f61bec2a1a6537fa03f19ff32e87eecbfd64b7e0e\
+                 8ccff439ac333b040f19b0c4ddd11a61e24ac1fe0f10a039806c5dcc0da3d115",
+                "1fa877de67259d19863a2a34bcc6962a2b25fcbf5cbecd7ede8f1fa36688a796",
+            ),
+            (
+                255,
+                "5b21c5fd8868367612474fa2e70e9cfa2201ffeee8fafab5797ad58fefa17c9b\
+                 5b107da4a3db6320baaf2c8617d5a51df914ae88da3867c2d41f0cc14fa67928",
+                "f03f5789d3336b80d002d59fdf918bdb775b00956ed5528e86aa994acb38fe2d",
+            ),
+        ];
+
+        for (len, expected_b, expected_s) in cases {
+            assert_eq!(blake2b(&input[..len]), expected_b);
+            assert_eq!(blake2s(&input[..len]), expected_s);
+
+            // and in awkward pieces
+            let mut b = Blake2bContext::new();
+            let mut s = Blake2sContext::new();
+            for chunk in input[..len].chunks(7) {
+                b.update(chunk);
+                s.update(chunk);
+            }
+            assert_eq!(hex(&b.finish()), expected_b);
+            assert_eq!(hex(&s.finish()), expected_s);
+        }
+    }
+}
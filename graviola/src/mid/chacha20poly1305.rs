@@ -3,6 +3,7 @@
 
 use crate::Error;
 use crate::low::chacha20::ChaCha20;
+use crate::low::chacha20poly1305;
 use crate::low::poly1305::Poly1305;
 use crate::low::{Entry, ct_equal, zeroise};
 
@@ -105,11 +106,9 @@ impl ChaCha20Poly1305 {
         pad(&mut poly, aad.len());
 
         if encrypt {
-            chacha.cipher(cipher_inout);
-            poly.add_bytes(cipher_inout);
+            chacha20poly1305::encrypt(&mut chacha, &mut poly, cipher_inout);
         } else {
-            poly.add_bytes(cipher_inout);
-            chacha.cipher(cipher_inout);
+            chacha20poly1305::decrypt(&mut chacha, &mut poly, cipher_inout);
         }
         pad(&mut poly, cipher_inout.len());
 
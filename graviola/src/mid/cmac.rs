@@ -0,0 +1,195 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::Error;
+use crate::low::{AesKey, Entry, zeroise};
+
+/// An in-progress AES-CMAC computation.
+///
+/// CMAC is standardized in [NIST SP800-38B](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38b.pdf),
+/// and AES-CMAC is also described in [RFC4493](https://datatracker.ietf.org/doc/html/rfc4493).
+///
+/// As with the rest of this crate, AES-192 is not supported.
+pub struct Cmac {
+    key: AesKey,
+    k1: [u8; 16],
+    k2: [u8; 16],
+    /// Running CBC-MAC state
+    x: [u8; 16],
+    /// The most recent (possibly partial) block, which is not processed
+    /// until we know whether it is the last.
+    buffer: [u8; 16],
+    used: usize,
+}
+
+impl Cmac {
+    /// Start a new CMAC computation.
+    ///
+    /// `key` must be 16 or 32 bytes, corresponding to AES-128 or AES-256.
+    /// [`Error::WrongLength`] is returned otherwise.
+    pub fn new(key: &[u8]) -> Result<Self, Error> {
+        if !matches!(key.len(), 16 | 32) {
+            return Err(Error::WrongLength);
+        }
+
+        let _entry = Entry::new_secret();
+        let key = AesKey::new(key);
+
+        // generate subkeys, RFC4493 section 2.3
+        let mut l = [0u8; 16];
+        key.encrypt_block(&mut l);
+        let k1 = double(&l);
+        let k2 = double(&k1);
+
+        Ok(Self {
+            key,
+            k1,
+            k2,
+            x: [0u8; 16],
+            buffer: [0u8; 16],
+            used: 0,
+        })
+    }
+
+    /// Add `bytes` to the message.
+    pub fn update(&mut self, mut bytes: &[u8]) {
+        let _entry = Entry::new_secret();
+
+        while !bytes.is_empty() {
+            if self.used == 16 {
+                self.process_buffer();
+            }
+
+            let take = core::cmp::min(16 - self.used, bytes.len());
+            let (taken, rest) = bytes.split_at(take);
+            self.buffer[self.used..self.used + take].copy_from_slice(taken);
+            self.used += take;
+            bytes = rest;
+        }
+    }
+
+    /// Complete the CMAC computation, returning the tag.
+    pub fn finish(mut self) -> [u8; 16] {
+        let _entry = Entry::new_secret();
+
+        let subkey = if self.used == 16 {
+            self.k1
+        } else {
+            self.buffer[self.used] = 0x80;
+            self.buffer[self.used + 1..].fill(0x00);
+            self.k2
+        };
+
+        for ((x, b), k) in self.x.iter_mut().zip(self.buffer).zip(subkey) {
+            *x ^= b ^ k;
+        }
+        self.key.encrypt_block(&mut self.x);
+        self.x
+    }
+
+    fn process_buffer(&mut self) {
+        for (x, b) in self.x.iter_mut().zip(self.buffer) {
+            *x ^= b;
+        }
+        self.key.encrypt_block(&mut self.x);
+        self.used = 0;
+    }
+}
+
+impl Drop for Cmac {
+    fn drop(&mut self) {
+        zeroise(&mut self.k1);
+        zeroise(&mut self.k2);
+        zeroise(&mut self.x);
+        zeroise(&mut self.buffer);
+    }
+}
+
+/// Multiplication by `x` in GF(2^128), as used for CMAC subkey generation.
+pub(crate) fn double(block: &[u8; 16]) -> [u8; 16] {
+    let v = u128::from_be_bytes(*block);
+    let carry = 0u128.wrapping_sub(v >> 127);
+    ((v << 1) ^ (carry & 0x87)).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &[u8] = b"\x6b\xc1\xbe\xe2\x2e\x40\x9f\x96\xe9\x3d\x7e\x11\x73\x93\x17\x2a\
+                             \xae\x2d\x8a\x57\x1e\x03\xac\x9c\x9e\xb7\x6f\xac\x45\xaf\x8e\x51\
+                             \x30\xc8\x1c\x46\xa3\x5c\xe4\x11\xe5\xfb\xc1\x19\x1a\x0a\x52\xef\
+                             \xf6\x9f\x24\x45\xdf\x4f\x9b\x17\xad\x2b\x41\x7b\xe6\x6c\x37\x10";
+
+    fn check(key: &[u8], len: usize, expected: &[u8; 16]) {
+        let mut c = Cmac::new(key).unwrap();
+        c.update(&MESSAGE[..len]);
+        assert_eq!(&c.finish(), expected);
+
+        // and byte-at-a-time
+        let mut c = Cmac::new(key).unwrap();
+        for b in &MESSAGE[..len] {
+            c.update(core::slice::from_ref(b));
+        }
+        assert_eq!(&c.finish(), expected);
+    }
+
+    #[test]
+    fn rfc4493_test_vectors() {
+        let key = b"\x2b\x7e\x15\x16\x28\xae\xd2\xa6\xab\xf7\x15\x88\x09\xcf\x4f\x3c";
+        check(
+            key,
+            0,
+            b"\xbb\x1d\x69\x29\xe9\x59\x37\x28\x7f\xa3\x7d\x12\x9b\x75\x67\x46",
+        );
+        check(
+            key,
+            16,
+            b"\x07\x0a\x16\xb4\x6b\x4d\x41\x44\xf7\x9b\xdd\x9d\xd0\x4a\x28\x7c",
+        );
+        check(
+            key,
+            40,
+            b"\xdf\xa6\x67\x47\xde\x9a\xe6\x30\x30\xca\x32\x61\x14\x97\xc8\x27",
+        );
+        check(
+            key,
+            64,
+            b"\x51\xf0\xbe\xbf\x7e\x3b\x9d\x92\xfc\x49\x74\x17\x79\x36\x3c\xfe",
+        );
+    }
+
+    #[test]
+    fn sp800_38b_aes256_test_vectors() {
+        let key = b"\x60\x3d\xeb\x10\x15\xca\x71\xbe\x2b\x73\xae\xf0\x85\x7d\x77\x81\
+                    \x1f\x35\x2c\x07\x3b\x61\x08\xd7\x2d\x98\x10\xa3\x09\x14\xdf\xf4";
+        check(
+            key,
+            0,
+            b"\x02\x89\x62\xf6\x1b\x7b\xf8\x9e\xfc\x6b\x55\x1f\x46\x67\xd9\x83",
+        );
+        check(
+            key,
+            16,
+            b"\x28\xa7\x02\x3f\x45\x2e\x8f\x82\xbd\x4b\xf2\x8d\x8c\x37\xc3\x5c",
+        );
+        check(
+            key,
+            40,
+            b"\xaa\xf3\xd8\xf1\xde\x56\x40\xc2\x32\xf5\xb1\x69\xb9\xc9\x11\xe6",
+        );
+        check(
+            key,
+            64,
+            b"\xe1\x99\x21\x90\x54\x9f\x6e\xd5\x69\x6a\x2c\x05\x6c\x31\x54\x10",
+        );
+    }
+
+    #[test]
+    fn key_lengths() {
+        assert!(Cmac::new(&[0u8; 15]).is_err());
+        assert!(Cmac::new(&[0u8; 24]).is_err());
+        assert!(Cmac::new(&[0u8; 16]).is_ok());
+        assert!(Cmac::new(&[0u8; 32]).is_ok());
+    }
+}
@@ -0,0 +1,317 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::rng::{RandomSource, SystemRandom};
+use crate::Error;
+use crate::low::{AesKey, Entry, zeroise};
+
+/// An AES-256 CTR_DRBG, as specified in
+/// [NIST SP800-90A](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-90Ar1.pdf)
+/// section 10.2.1.
+///
+/// This is the variant without a derivation function, so the
+/// entropy input must be [`CtrDrbg::SEED_LEN`] bytes of full
+/// entropy, and personalization strings and additional inputs
+/// may be at most that long.
+///
+/// [`CtrDrbg::new_random()`] seeds one from the system entropy source.
+/// [`CtrDrbg::new()`] takes the entropy input directly, for
+/// deterministic use (for example, known answer tests).
+///
+/// As a [`RandomSource`], it generates output without additional
+/// input, and without prediction resistance.
+pub struct CtrDrbg {
+    key: AesKey,
+    v: [u8; BLOCK_LEN],
+    reseed_counter: u64,
+}
+
+impl CtrDrbg {
+    /// The length of the entropy input (and seed), in bytes.
+    pub const SEED_LEN: usize = KEY_LEN + BLOCK_LEN;
+
+    /// The maximum number of bytes produced by one call to [`Self::generate()`].
+    pub const MAX_REQUEST_LEN: usize = 1 << 16;
+
+    /// Instantiate a new DRBG using `entropy_input`.
+    ///
+    /// `personalization_string` may be empty.  [`Error::WrongLength`]
+    /// is returned if it is longer than [`Self::SEED_LEN`].
+    pub fn new(
+        entropy_input: &[u8; Self::SEED_LEN],
+        personalization_string: &[u8],
+    ) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let mut seed_material = seed_material(entropy_input, personalization_string)?;
+
+        let mut r = Self {
+            key: AesKey::new(&[0u8; KEY_LEN]),
+            v: [0u8; BLOCK_LEN],
+            reseed_counter: 0,
+        };
+        r.update(&seed_material);
+        r.reseed_counter = 1;
+
+        zeroise(&mut seed_material);
+        Ok(r)
+    }
+
+    /// Instantiate a new DRBG seeded from the system entropy source.
+    ///
+    /// `personalization_string` is as for [`Self::new()`].
+    pub fn new_random(personalization_string: &[u8]) -> Result<Self, Error> {
+        let mut entropy_input = [0u8; Self::SEED_LEN];
+        SystemRandom.fill(&mut entropy_input)?;
+        let r = Self::new(&entropy_input, personalization_string);
+        zeroise(&mut entropy_input);
+        r
+    }
+
+    /// Reseed this DRBG with fresh `entropy_input`.
+    ///
+    /// `additional_input` may be empty.  [`Error::WrongLength`] is
+    /// returned if it is longer than [`Self::SEED_LEN`].
+    pub fn reseed(
+        &mut self,
+        entropy_input: &[u8; Self::SEED_LEN],
+        additional_input: &[u8],
+    ) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        let mut seed_material = seed_material(entropy_input, additional_input)?;
+        self.update(&seed_material);
+        self.reseed_counter = 1;
+        zeroise(&mut seed_material);
+        Ok(())
+    }
+
+    /// Fill `out` with output from this DRBG.
+    ///
+    /// `additional_input` may be empty.  [`Error::WrongLength`] is
+    /// returned if it is longer than [`Self::SEED_LEN`], and
+    /// [`Error::OutOfRange`] is returned if `out` is longer than
+    /// [`Self::MAX_REQUEST_LEN`].
+    ///
+    /// [`Error::RngFailed`] is returned if the DRBG must be reseeded
+    /// first.  This happens only after 2<sup>48</sup> requests.
+    pub fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+
+        if out.len() > Self::MAX_REQUEST_LEN {
+            return Err(Error::OutOfRange);
+        }
+
+        // 1. If reseed_counter > reseed_interval, then return an
+        //    indication that a reseed is required.
+        if self.reseed_counter > RESEED_INTERVAL {
+            return Err(Error::RngFailed);
+        }
+
+        // 2. If (additional_input ≠ Null), then
+        //    additional_input = additional_input || 0^(seedlen - len(additional_input))
+        //    (Key, V) = CTR_DRBG_Update (additional_input, Key, V).
+        //    Else additional_input = 0^seedlen.
+        let mut padded_input = seed_material(&[0u8; Self::SEED_LEN], additional_input)?;
+        if !additional_input.is_empty() {
+            self.update(&padded_input);
+        }
+
+        // 3. - 5. temp is written directly to `out`
+        for chunk in out.chunks_mut(BLOCK_LEN) {
+            let mut block = self.next_block();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            zeroise(&mut block);
+        }
+
+        // 6. (Key, V) = CTR_DRBG_Update (additional_input, Key, V).
+        self.update(&padded_input);
+
+        // 7. reseed_counter = reseed_counter + 1.
+        self.reseed_counter += 1;
+
+        zeroise(&mut padded_input);
+        Ok(())
+    }
+
+    /// Fill `out` with output from this DRBG, with prediction resistance.
+    ///
+    /// This first reseeds from `entropy_source` (using `additional_input`),
+    /// and then generates output as for [`Self::generate()`] without
+    /// additional input.  See SP800-90A section 9.3.1.
+    pub fn generate_with_prediction_resistance(
+        &mut self,
+        out: &mut [u8],
+        additional_input: &[u8],
+        entropy_source: &mut dyn RandomSource,
+    ) -> Result<(), Error> {
+        if out.len() > Self::MAX_REQUEST_LEN {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut entropy_input = [0u8; Self::SEED_LEN];
+        entropy_source.fill(&mut entropy_input)?;
+        let r = self.reseed(&entropy_input, additional_input);
+        zeroise(&mut entropy_input);
+        r?;
+
+        self.generate(out, &[])
+    }
+
+    /// CTR_DRBG_Update, SP800-90A section 10.2.1.2.
+    fn update(&mut self, provided_data: &[u8; Self::SEED_LEN]) {
+        // 1. - 3. temp = the next seedlen bits of keystream
+        let mut temp = [0u8; Self::SEED_LEN];
+        for chunk in temp.chunks_exact_mut(BLOCK_LEN) {
+            let mut block = self.next_block();
+            chunk.copy_from_slice(&block);
+            zeroise(&mut block);
+        }
+
+        // 4. temp = temp ⊕ provided_data.
+        for (t, p) in temp.iter_mut().zip(provided_data.iter()) {
+            *t ^= p;
+        }
+
+        // 5. Key = leftmost (temp, keylen).
+        // 6. V = rightmost (temp, blocklen).
+        self.key = AesKey::new(&temp[..KEY_LEN]);
+        self.v.copy_from_slice(&temp[KEY_LEN..]);
+        zeroise(&mut temp);
+    }
+
+    /// V = (V + 1) mod 2^blocklen, and return Block_Encrypt (Key, V).
+    fn next_block(&mut self) -> [u8; BLOCK_LEN] {
+        let v = u128::from_be_bytes(self.v).wrapping_add(1);
+        self.v = v.to_be_bytes();
+
+        let mut block = self.v;
+        self.key.encrypt_block(&mut block);
+        block
+    }
+}
+
+impl RandomSource for CtrDrbg {
+    fn fill(&mut self, out: &mut [u8]) -> Result<(), Error> {
+        for chunk in out.chunks_mut(Self::MAX_REQUEST_LEN) {
+            self.generate(chunk, &[])?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for CtrDrbg {
+    fn drop(&mut self) {
+        zeroise(&mut self.v);
+    }
+}
+
+/// `entropy_input ⊕ (input || 0^(seedlen - len(input)))`
+fn seed_material(
+    entropy_input: &[u8; CtrDrbg::SEED_LEN],
+    input: &[u8],
+) -> Result<[u8; CtrDrbg::SEED_LEN], Error> {
+    if input.len() > CtrDrbg::SEED_LEN {
+        return Err(Error::WrongLength);
+    }
+
+    let mut r = *entropy_input;
+    for (r, i) in r.iter_mut().zip(input.iter()) {
+        *r ^= i;
+    }
+    Ok(r)
+}
+
+const KEY_LEN: usize = 32;
+const BLOCK_LEN: usize = 16;
+
+/// SP800-90A table 3: maximum number of requests between reseeds.
+const RESEED_INTERVAL: u64 = 1 << 48;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    #[test]
+    fn cavp_aes256_no_df() {
+        // CTR_DRBG.rsp, [AES-256 no df], [PredictionResistance = False],
+        // [EntropyInputLen = 384], COUNT = 0
+        let mut drbg = CtrDrbg::new(
+            b"\xe4\xbc\x23\xc5\x08\x9a\x19\xd8\x6f\x41\x19\xcb\x3f\xa0\x8c\x0a\
+              \x49\x91\xe0\xa1\xde\xf1\x7e\x10\x1e\x4c\x14\xd9\xc3\x23\x46\x0a\
+              \x7c\x2f\xb5\x8e\x0b\x08\x6c\x6c\x57\xb5\x5f\x56\xca\xe2\x5b\xad",
+            &[],
+        )
+        .unwrap();
+        drbg.reseed(
+            b"\xfd\x85\xa8\x36\xbb\xa8\x50\x19\x88\x1e\x8c\x6b\xad\x23\xc9\x06\
+              \x1a\xdc\x75\x47\x76\x59\xac\xae\xa8\xe4\xa0\x1d\xfe\x07\xa1\x83\
+              \x2d\xad\x1c\x13\x6f\x59\xd7\x0f\x86\x53\xa5\xdc\x11\x86\x63\xd6",
+            &[],
+        )
+        .unwrap();
+
+        let mut out = [0u8; 64];
+        drbg.generate(&mut out, &[]).unwrap();
+        drbg.generate(&mut out, &[]).unwrap();
+        assert_eq!(
+            &out,
+            b"\xb2\xcb\x89\x05\xc0\x5e\x59\x50\xca\x31\x89\x50\x96\xbe\x29\xea\
+              \x3d\x5a\x3b\x82\xb2\x69\x49\x55\x54\xeb\x80\xfe\x07\xde\x43\xe1\
+              \x93\xb9\xe7\xc3\xec\xe7\x3b\x80\xe0\x62\xb1\xc1\xf6\x82\x02\xfb\
+              \xb1\xc5\x2a\x04\x0e\xa2\x47\x88\x64\x29\x52\x82\x23\x4a\xaa\xda"
+        );
+    }
+
+    #[test]
+    fn prediction_resistance() {
+        let mut a = CtrDrbg::new(&[1u8; CtrDrbg::SEED_LEN], b"hello").unwrap();
+        let mut b = CtrDrbg::new(&[1u8; CtrDrbg::SEED_LEN], b"hello").unwrap();
+
+        // equivalent to an explicit reseed followed by generation
+        let mut out_a = [0u8; 32];
+        a.generate_with_prediction_resistance(
+            &mut out_a,
+            b"extra",
+            &mut SliceRandomSource(&[2u8; CtrDrbg::SEED_LEN]),
+        )
+        .unwrap();
+
+        let mut out_b = [0u8; 32];
+        b.reseed(&[2u8; CtrDrbg::SEED_LEN], b"extra").unwrap();
+        b.generate(&mut out_b, &[]).unwrap();
+        assert_eq!(out_a, out_b);
+
+        // entropy source failure is propagated
+        assert_eq!(
+            a.generate_with_prediction_resistance(&mut out_a, &[], &mut SliceRandomSource(&[]))
+                .unwrap_err(),
+            Error::RngFailed
+        );
+    }
+
+    #[test]
+    fn limits() {
+        assert_eq!(
+            CtrDrbg::new(&[0u8; CtrDrbg::SEED_LEN], &[0u8; CtrDrbg::SEED_LEN + 1])
+                .err()
+                .unwrap(),
+            Error::WrongLength
+        );
+
+        let mut drbg = CtrDrbg::new_random(&[]).unwrap();
+        let mut big = vec![0u8; CtrDrbg::MAX_REQUEST_LEN + 1];
+        assert_eq!(drbg.generate(&mut big, &[]).unwrap_err(), Error::OutOfRange);
+        assert_eq!(
+            drbg.generate(&mut [0u8; 16], &[0u8; CtrDrbg::SEED_LEN + 1])
+                .unwrap_err(),
+            Error::WrongLength
+        );
+        drbg.fill(&mut big).unwrap();
+
+        drbg.reseed_counter = RESEED_INTERVAL + 1;
+        assert_eq!(
+            drbg.generate(&mut [0u8; 16], &[]).unwrap_err(),
+            Error::RngFailed
+        );
+    }
+}
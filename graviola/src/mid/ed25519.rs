@@ -0,0 +1,374 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Ed25519 signatures, as specified in
+//! [RFC8032](https://datatracker.ietf.org/doc/html/rfc8032).
+//!
+//! This shares the twisted Edwards curve arithmetic with ristretto255.
+
+use super::ristretto255::{D, EdwardsPoint, FieldElement, ONE, Scalar};
+use super::rng::{RandomSource, SystemRandom};
+use super::sha2::Sha512Context;
+use crate::Error;
+use crate::low::{self, Entry, zeroise};
+
+/// The length of a signing key seed, in bytes.
+pub const SEED_LEN: usize = 32;
+
+/// The length of an encoded verifying key, in bytes.
+pub const VERIFYING_KEY_LEN: usize = 32;
+
+/// The length of a signature, in bytes.
+pub const SIGNATURE_LEN: usize = 64;
+
+/// An Ed25519 signing (private) key.
+///
+/// The seed is expanded and the secret scalar clamped once, when the
+/// key is made, so [`SigningKey::sign()`] does only the per-message work.
+pub struct SigningKey {
+    seed: [u8; SEED_LEN],
+    scalar: Scalar,
+    prefix: [u8; 32],
+    verifying_key: VerifyingKey,
+}
+
+impl SigningKey {
+    /// Generate a new key using the system random number generator.
+    ///
+    /// Fails only if the random source fails.
+    pub fn new_random() -> Result<Self, Error> {
+        Self::generate(&mut SystemRandom)
+    }
+
+    /// Generate a new key using `rng`.
+    ///
+    /// Fails only if the random source fails.
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let mut seed = [0u8; SEED_LEN];
+        rng.fill(&mut seed)?;
+        let key = Self::from_seed(&seed);
+        zeroise(&mut seed);
+        Ok(key)
+    }
+
+    /// Make a key from its 32-byte seed.
+    ///
+    /// The seed is what RFC8032 calls the private key.
+    pub fn from_seed(seed: &[u8; SEED_LEN]) -> Self {
+        let _entry = Entry::new_secret();
+        low::ct::secret_slice(seed);
+
+        let mut ctx = Sha512Context::new();
+        ctx.update(seed);
+        let mut h = ctx.finish();
+
+        let mut clamped = [0u8; 64];
+        clamped[..32].copy_from_slice(&h[..32]);
+        clamped[0] &= 0xf8;
+        clamped[31] &= 0x7f;
+        clamped[31] |= 0x40;
+        // the base point has order l, so reducing the clamped value is harmless
+        let scalar = Scalar::from_wide_bytes(&clamped);
+
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&h[32..]);
+
+        let point = EdwardsPoint::base_multiply(&scalar);
+        let verifying_key = VerifyingKey {
+            bytes: low::ct::into_public(encode(&point)),
+            point,
+        };
+
+        zeroise(&mut h);
+        zeroise(&mut clamped);
+
+        Self {
+            seed: *seed,
+            scalar,
+            prefix,
+            verifying_key,
+        }
+    }
+
+    /// Return the seed of this key.
+    pub fn seed(&self) -> [u8; SEED_LEN] {
+        self.seed
+    }
+
+    /// Return the matching verifying key.
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.verifying_key.clone()
+    }
+
+    /// Sign `message`.
+    ///
+    /// The message is a sequence of byte slices, so some workloads can
+    /// avoid joining it into one buffer beforehand.
+    ///
+    /// Ed25519 signatures are deterministic.
+    pub fn sign(&self, message: &[&[u8]]) -> [u8; SIGNATURE_LEN] {
+        let _entry = Entry::new_secret();
+
+        let mut ctx = Sha512Context::new();
+        ctx.update(&self.prefix);
+        for m in message {
+            ctx.update(m);
+        }
+        let r = Scalar::from_wide_bytes(&ctx.finish());
+        let big_r = low::ct::into_public(encode(&EdwardsPoint::base_multiply(&r)));
+
+        let k = challenge(&big_r, &self.verifying_key.bytes, message);
+        let s = r.add(&k.mul(&self.scalar));
+
+        let mut signature = [0u8; SIGNATURE_LEN];
+        signature[..32].copy_from_slice(&big_r);
+        signature[32..].copy_from_slice(&s.as_bytes());
+        low::ct::into_public(signature)
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        zeroise(&mut self.seed);
+        zeroise(&mut self.prefix);
+    }
+}
+
+/// An Ed25519 verifying (public) key.
+#[derive(Clone)]
+pub struct VerifyingKey {
+    point: EdwardsPoint,
+    bytes: [u8; VERIFYING_KEY_LEN],
+}
+
+impl VerifyingKey {
+    /// Decode a verifying key.
+    ///
+    /// This fails with [`Error::WrongLength`] if `bytes` is not
+    /// [`VERIFYING_KEY_LEN`] bytes long, or [`Error::NotOnCurve`] if
+    /// it is not a valid point encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        let bytes: &[u8; VERIFYING_KEY_LEN] = bytes.try_into().map_err(|_| Error::WrongLength)?;
+        Ok(Self {
+            point: decode(bytes)?,
+            bytes: *bytes,
+        })
+    }
+
+    /// Return the encoding of this key.
+    pub fn as_bytes(&self) -> &[u8; VERIFYING_KEY_LEN] {
+        &self.bytes
+    }
+
+    /// Verify `signature` over `message`.
+    ///
+    /// This checks the cofactorless verification equation, and rejects
+    /// non-canonical `S` values.
+    ///
+    /// Returns `Err(Error::BadSignature)` if the signature is invalid.
+    pub fn verify(&self, message: &[&[u8]], signature: &[u8]) -> Result<(), Error> {
+        let _entry = Entry::new_public();
+        let signature: &[u8; SIGNATURE_LEN] =
+            signature.try_into().map_err(|_| Error::BadSignature)?;
+        let (big_r, s) = signature.split_at(32);
+        let big_r: &[u8; 32] = big_r.try_into().unwrap();
+        let s = Scalar::from_canonical_bytes(s.try_into().unwrap()).ok_or(Error::BadSignature)?;
+
+        let k = challenge(big_r, &self.bytes, message);
+
+        // [S]B - [k]A must equal R
+        let check = EdwardsPoint::base_multiply(&s).add(&negate(&self.point).multiply(&k));
+
+        match encode(&check) == *big_r {
+            true => Ok(()),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// `SHA-512(R || A || M)`, reduced modulo the group order.
+fn challenge(big_r: &[u8; 32], public: &[u8; 32], message: &[&[u8]]) -> Scalar {
+    let mut ctx = Sha512Context::new();
+    ctx.update(big_r);
+    ctx.update(public);
+    for m in message {
+        ctx.update(m);
+    }
+    Scalar::from_wide_bytes(&ctx.finish())
+}
+
+fn negate(p: &EdwardsPoint) -> EdwardsPoint {
+    EdwardsPoint {
+        x: p.x.neg(),
+        y: p.y,
+        z: p.z,
+        t: p.t.neg(),
+    }
+}
+
+/// See RFC8032 section 5.1.2.
+fn encode(p: &EdwardsPoint) -> [u8; 32] {
+    let z_inv = p.z.pow(&P_MINUS_2);
+    let x = p.x.mul(&z_inv);
+    let mut bytes = p.y.mul(&z_inv).to_bytes();
+    bytes[31] |= x.is_negative() << 7;
+    bytes
+}
+
+/// See RFC8032 section 5.1.3.
+fn decode(bytes: &[u8; 32]) -> Result<EdwardsPoint, Error> {
+    let mut y_bytes = *bytes;
+    let x_negative = y_bytes[31] >> 7;
+    y_bytes[31] &= 0x7f;
+    let y = FieldElement::from_canonical_bytes(&y_bytes).ok_or(Error::NotOnCurve)?;
+
+    let yy = y.sqr();
+    let u = yy.sub(&ONE);
+    let v = D.mul(&yy).add(&ONE);
+    let (was_square, x) = FieldElement::sqrt_ratio_m1(&u, &v);
+    if was_square == 0 || (x.is_zero() == 1 && x_negative == 1) {
+        return Err(Error::NotOnCurve);
+    }
+
+    // `x` is non-negative here
+    let x = FieldElement::select(&x, &x.neg(), x_negative);
+    Ok(EdwardsPoint {
+        x,
+        y,
+        z: ONE,
+        t: x.mul(&y),
+    })
+}
+
+const P_MINUS_2: [u64; 4] = [
+    0xffff_ffff_ffff_ffeb,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0x7fff_ffff_ffff_ffff,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rfc8032_test_vectors() {
+        // RFC8032 section 7.1, tests 1 to 3 and SHA(abc)
+        for (seed, public, message, signature) in [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+            (
+                "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+                "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+                "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+            ),
+        ] {
+            let key = SigningKey::from_seed(&unhex(seed).try_into().unwrap());
+            assert_eq!(key.verifying_key().as_bytes(), &unhex(public)[..]);
+
+            let message = unhex(message);
+            let sig = key.sign(&[&message]);
+            assert_eq!(&sig[..], &unhex(signature)[..]);
+
+            let public = VerifyingKey::from_bytes(&unhex(public)).unwrap();
+            public.verify(&[&message], &sig).unwrap();
+        }
+    }
+
+    #[test]
+    fn sign_verify() {
+        let key = SigningKey::generate(&mut SliceRandomSource(&[0x11; 32])).unwrap();
+        assert_eq!(key.seed(), [0x11; 32]);
+        let public = key.verifying_key();
+
+        let sig = key.sign(&[b"hello", b" ", b"world"]);
+        public.verify(&[b"hello world"], &sig).unwrap();
+
+        assert_eq!(
+            public.verify(&[b"hello worle"], &sig),
+            Err(Error::BadSignature)
+        );
+        for i in 0..SIGNATURE_LEN {
+            let mut bad = sig;
+            bad[i] ^= 0x01;
+            assert_eq!(
+                public.verify(&[b"hello world"], &bad),
+                Err(Error::BadSignature)
+            );
+        }
+        assert_eq!(
+            public.verify(&[b"hello world"], &sig[..63]),
+            Err(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn rejects_non_canonical_s() {
+        let key = SigningKey::from_seed(&[0x22; 32]);
+        let mut sig = key.sign(&[b"message"]);
+
+        // S + l, which is still less than 2^256
+        let l = unhex("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+        let mut carry = 0u16;
+        for (s, l) in sig[32..].iter_mut().zip(l) {
+            let sum = *s as u16 + l as u16 + carry;
+            *s = sum as u8;
+            carry = sum >> 8;
+        }
+        assert_eq!(carry, 0);
+
+        assert_eq!(
+            key.verifying_key().verify(&[b"message"], &sig),
+            Err(Error::BadSignature)
+        );
+    }
+
+    #[test]
+    fn invalid_public_keys() {
+        assert_eq!(
+            VerifyingKey::from_bytes(&[0; 31]).err(),
+            Some(Error::WrongLength)
+        );
+
+        // y = p is non-canonical
+        let mut p = unhex("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+        assert_eq!(VerifyingKey::from_bytes(&p).err(), Some(Error::NotOnCurve));
+
+        // y = 2 is not on the curve
+        p.fill(0);
+        p[0] = 2;
+        assert_eq!(VerifyingKey::from_bytes(&p).err(), Some(Error::NotOnCurve));
+
+        // y = 1 has x = 0, so the sign bit must be clear
+        p[0] = 1;
+        VerifyingKey::from_bytes(&p).unwrap();
+        p[31] = 0x80;
+        assert_eq!(VerifyingKey::from_bytes(&p).err(), Some(Error::NotOnCurve));
+    }
+}
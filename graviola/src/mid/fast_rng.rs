@@ -0,0 +1,175 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::rng::{RandomSource, SystemRandom};
+use crate::Error;
+use crate::low::chacha20::ChaCha20;
+use crate::low::{Entry, fork_generation, zeroise};
+
+/// A userspace random number generator, periodically seeded from
+/// [`SystemRandom`].
+///
+/// This is a "fast-key-erasure" construction using ChaCha20, as described in
+/// <https://blog.cr.yp.to/20170723-random.html>: each batch of keystream
+/// starts with the key for the next batch, and output bytes are erased from
+/// the internal buffer as they are returned.  Compromise of the state
+/// therefore does not reveal earlier outputs.
+///
+/// It is reseeded from the system entropy source once
+/// [`FastRandom::RESEED_INTERVAL`] bytes have been output, and (on unix,
+/// as a fork-safety measure) in the child after a `fork()`.  Fork
+/// detection uses a `pthread_atfork` handler, so processes created
+/// without running those handlers (such as by a raw `clone` system call)
+/// are not detected.
+///
+/// Prefer this over [`SystemRandom`] only where the cost of a system call
+/// per request is significant, such as when generating very many nonces.
+pub struct FastRandom {
+    key: [u8; 32],
+    buffer: [u8; BUFFER_LEN],
+    used: usize,
+    output_since_reseed: u64,
+    fork_generation: usize,
+}
+
+impl FastRandom {
+    /// The generator reseeds before a fill once this many bytes have
+    /// been output since the last reseed.
+    ///
+    /// A single fill is not split, so may output more than this.
+    pub const RESEED_INTERVAL: u64 = 1 << 20;
+
+    /// Make a new generator, seeded from the system entropy source.
+    pub fn new() -> Result<Self, Error> {
+        let mut r = Self {
+            key: [0u8; 32],
+            buffer: [0u8; BUFFER_LEN],
+            used: BUFFER_LEN,
+            output_since_reseed: 0,
+            fork_generation: 0,
+        };
+        r.reseed()?;
+        Ok(r)
+    }
+
+    /// Mix fresh entropy from the system entropy source into this
+    /// generator, and discard any buffered output.
+    pub fn reseed(&mut self) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        let mut fresh = [0u8; 32];
+        SystemRandom.fill(&mut fresh)?;
+        for (k, f) in self.key.iter_mut().zip(fresh.iter()) {
+            *k ^= f;
+        }
+        zeroise(&mut fresh);
+
+        self.refill();
+        self.output_since_reseed = 0;
+        self.fork_generation = fork_generation();
+        Ok(())
+    }
+
+    /// Replace the buffer with fresh keystream, and take the next key
+    /// from its start.
+    fn refill(&mut self) {
+        self.buffer.fill(0);
+        ChaCha20::new(&self.key, &[0u8; 16]).cipher(&mut self.buffer);
+        self.key.copy_from_slice(&self.buffer[..32]);
+        zeroise(&mut self.buffer[..32]);
+        self.used = 32;
+    }
+}
+
+impl RandomSource for FastRandom {
+    fn fill(&mut self, mut out: &mut [u8]) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+
+        if self.fork_generation != fork_generation()
+            || self.output_since_reseed >= Self::RESEED_INTERVAL
+        {
+            self.reseed()?;
+        }
+
+        self.output_since_reseed = self.output_since_reseed.saturating_add(out.len() as u64);
+
+        while !out.is_empty() {
+            if self.used == BUFFER_LEN {
+                self.refill();
+            }
+
+            let available = &mut self.buffer[self.used..];
+            let take = core::cmp::min(available.len(), out.len());
+            let (chunk, rest) = out.split_at_mut(take);
+            chunk.copy_from_slice(&available[..take]);
+            zeroise(&mut available[..take]);
+            self.used += take;
+            out = rest;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for FastRandom {
+    fn drop(&mut self) {
+        zeroise(&mut self.key);
+        zeroise(&mut self.buffer);
+    }
+}
+
+const BUFFER_LEN: usize = 768;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outputs_differ() {
+        let mut a = FastRandom::new().unwrap();
+        let mut b = FastRandom::new().unwrap();
+
+        let mut out_a = [0u8; 1000];
+        let mut out_b = [0u8; 1000];
+        a.fill(&mut out_a).unwrap();
+        b.fill(&mut out_b).unwrap();
+        assert_ne!(out_a, out_b);
+        assert_ne!(out_a, [0u8; 1000]);
+
+        let mut again = [0u8; 1000];
+        a.fill(&mut again).unwrap();
+        assert_ne!(out_a, again);
+    }
+
+    #[test]
+    fn key_erasure() {
+        let mut rng = FastRandom::new().unwrap();
+        let mut out = [0u8; 100];
+        rng.fill(&mut out).unwrap();
+
+        // nothing already output remains in the buffer
+        assert_eq!(rng.used, 32 + 100);
+        assert!(rng.buffer[..rng.used].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn reseeds() {
+        let mut rng = FastRandom::new().unwrap();
+        let mut out = [0u8; 16];
+
+        // as if in a forked child
+        rng.fork_generation = rng.fork_generation.wrapping_add(1);
+        rng.output_since_reseed = 1234;
+        rng.fill(&mut out).unwrap();
+        assert_eq!(rng.fork_generation, fork_generation());
+        assert_eq!(rng.output_since_reseed, 16);
+
+        rng.output_since_reseed = FastRandom::RESEED_INTERVAL;
+        rng.fill(&mut out).unwrap();
+        assert_eq!(rng.output_since_reseed, 16);
+
+        let mut big = vec![0u8; FastRandom::RESEED_INTERVAL as usize + 1];
+        rng.fill(&mut big).unwrap();
+        rng.fill(&mut out).unwrap();
+        assert_eq!(rng.output_since_reseed, 16);
+    }
+}
@@ -0,0 +1,443 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::Error;
+use crate::low::{AesKey, Entry, zeroise};
+
+/// FF1 format-preserving encryption.
+///
+/// FF1 is standardized in [NIST SP800-38G](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38Gr1-draft.pdf).
+/// It encrypts a string of numerals in a given radix to another string of the
+/// same length and radix.
+///
+/// Mapping between numerals and an alphabet (such as the decimal digits of a
+/// card number) is left to the caller.  The domain must contain at least one
+/// million values, as required by revision 1 of SP800-38G.  At most 256
+/// numerals are supported.
+///
+/// As with the rest of this crate, AES-192 is not supported.
+pub struct Ff1 {
+    key: AesKey,
+    radix: u32,
+}
+
+impl Ff1 {
+    /// Prepare to encrypt numerals in the given radix.
+    ///
+    /// `key` must be 16 or 32 bytes, corresponding to AES-128 or AES-256.
+    /// [`Error::WrongLength`] is returned otherwise.
+    ///
+    /// `radix` must be between 2 and 65536.  [`Error::OutOfRange`] is
+    /// returned otherwise.
+    pub fn new(key: &[u8], radix: u32) -> Result<Self, Error> {
+        if !matches!(key.len(), 16 | 32) {
+            return Err(Error::WrongLength);
+        }
+        if !(MIN_RADIX..=MAX_RADIX).contains(&radix) {
+            return Err(Error::OutOfRange);
+        }
+
+        let _entry = Entry::new_secret();
+        Ok(Self {
+            key: AesKey::new(key),
+            radix,
+        })
+    }
+
+    /// Encrypt `numerals` in place, under `tweak`.
+    ///
+    /// Each numeral must be less than the radix.
+    pub fn encrypt(&self, tweak: &[u8], numerals: &mut [u16]) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        self.cipher(tweak, numerals, true)
+    }
+
+    /// Decrypt `numerals` in place, under `tweak`.
+    ///
+    /// Each numeral must be less than the radix.
+    pub fn decrypt(&self, tweak: &[u8], numerals: &mut [u16]) -> Result<(), Error> {
+        let _entry = Entry::new_secret();
+        self.cipher(tweak, numerals, false)
+    }
+
+    /// Algorithms 7 and 8.
+    fn cipher(&self, tweak: &[u8], x: &mut [u16], encrypt: bool) -> Result<(), Error> {
+        let n = x.len();
+        if !(MIN_LEN..=MAX_LEN).contains(&n) || !self.domain_is_large_enough(n) {
+            return Err(Error::WrongLength);
+        }
+        let t = u32::try_from(tweak.len()).map_err(|_| Error::WrongLength)?;
+        if x.iter().any(|numeral| u32::from(*numeral) >= self.radix) {
+            return Err(Error::OutOfRange);
+        }
+
+        // 1. - 2.
+        let u = n / 2;
+        let v = n - u;
+        let mut a = Numerals::new(&x[..u]);
+        let mut b = Numerals::new(&x[u..]);
+
+        // 3. - 4.
+        let b_len = self.num_bytes(v);
+        let d = 4 * ((b_len + 3) / 4) + 4;
+
+        // 5.
+        let mut p = [0u8; 16];
+        p[..3].copy_from_slice(&[1, 2, 1]);
+        p[3..6].copy_from_slice(&self.radix.to_be_bytes()[1..]);
+        p[6] = 10;
+        p[7] = u as u8;
+        p[8..12].copy_from_slice(&(n as u32).to_be_bytes());
+        p[12..16].copy_from_slice(&t.to_be_bytes());
+
+        let mut num = [0u8; MAX_NUM_BYTES];
+        let mut s = [0u8; MAX_S_BYTES];
+        let mut y = [0u16; MAX_LEN];
+
+        // 6.
+        for round in 0..10u8 {
+            let i = match encrypt {
+                true => round,
+                false => 9 - round,
+            };
+            let m = match i % 2 {
+                0 => u,
+                _ => v,
+            };
+
+            // i. - iv.
+            let source = match encrypt {
+                true => &b,
+                false => &a,
+            };
+            self.num(source.as_slice(), &mut num[..b_len]);
+            self.round_function(&p, tweak, i, &num[..b_len], &mut s[..d]);
+            self.digits(&mut s[..d], &mut y[..m]);
+
+            // vi. - ix.
+            match encrypt {
+                true => self.add(a.as_mut_slice(), &y[..m]),
+                false => self.sub(b.as_mut_slice(), &y[..m]),
+            }
+            core::mem::swap(&mut a, &mut b);
+        }
+
+        // 7.
+        x[..u].copy_from_slice(a.as_slice());
+        x[u..].copy_from_slice(b.as_slice());
+
+        zeroise(&mut num);
+        zeroise(&mut s);
+        zeroise(&mut y);
+        Ok(())
+    }
+
+    /// Steps 6.i. to 6.iii: `S`, from `PRF(P || Q)`.
+    fn round_function(&self, p: &[u8; 16], tweak: &[u8], i: u8, num: &[u8], s: &mut [u8]) {
+        let pad = (16 - (tweak.len() + num.len() + 1) % 16) % 16;
+
+        let mut mac = CbcMac::new(&self.key);
+        mac.update(p);
+        mac.update(tweak);
+        mac.update(&[0u8; 16][..pad]);
+        mac.update(&[i]);
+        mac.update(num);
+        let r = mac.finish();
+
+        for (j, chunk) in s.chunks_mut(16).enumerate() {
+            let mut block = r;
+            if j > 0 {
+                for (b, jb) in block.iter_mut().zip((j as u128).to_be_bytes()) {
+                    *b ^= jb;
+                }
+                self.key.encrypt_block(&mut block);
+            }
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+    }
+
+    /// `NUM_radix(x)`, as a big-endian integer in `out`.
+    fn num(&self, x: &[u16], out: &mut [u8]) {
+        out.fill(0);
+        for numeral in x {
+            mul_add(out, self.radix, u32::from(*numeral));
+        }
+    }
+
+    /// `STR_m_radix(NUM(s) mod radix^m)`, into `out`.
+    ///
+    /// `s` is destroyed.
+    fn digits(&self, s: &mut [u8], out: &mut [u16]) {
+        for digit in out.iter_mut().rev() {
+            *digit = div_rem(s, self.radix) as u16;
+        }
+    }
+
+    /// `x = x + y mod radix^m`, where `x` and `y` are `m` numerals.
+    fn add(&self, x: &mut [u16], y: &[u16]) {
+        let mut carry = 0;
+        for (x, y) in x.iter_mut().zip(y).rev() {
+            let sum = u32::from(*x) + u32::from(*y) + carry;
+            *x = (sum % self.radix) as u16;
+            carry = sum / self.radix;
+        }
+    }
+
+    /// `x = x - y mod radix^m`, where `x` and `y` are `m` numerals.
+    fn sub(&self, x: &mut [u16], y: &[u16]) {
+        let mut borrow = 0;
+        for (x, y) in x.iter_mut().zip(y).rev() {
+            let subtrahend = u32::from(*y) + borrow;
+            let minuend = u32::from(*x);
+            if minuend >= subtrahend {
+                *x = (minuend - subtrahend) as u16;
+                borrow = 0;
+            } else {
+                *x = (minuend + self.radix - subtrahend) as u16;
+                borrow = 1;
+            }
+        }
+    }
+
+    /// `ceil(ceil(v * log2(radix)) / 8)`: the length of `radix^v - 1` in bytes.
+    fn num_bytes(&self, v: usize) -> usize {
+        let mut x = [0u8; MAX_NUM_BYTES + 1];
+        x[MAX_NUM_BYTES] = 1;
+        for _ in 0..v {
+            mul_add(&mut x, self.radix, 0);
+        }
+
+        // subtract one; x is non-zero
+        for byte in x.iter_mut().rev() {
+            let (r, borrow) = byte.overflowing_sub(1);
+            *byte = r;
+            if !borrow {
+                break;
+            }
+        }
+
+        x.len() - x.iter().take_while(|b| **b == 0).count()
+    }
+
+    /// Whether `radix^n` is at least one million.
+    fn domain_is_large_enough(&self, n: usize) -> bool {
+        let mut size = 1u64;
+        for _ in 0..n {
+            size = size.saturating_mul(u64::from(self.radix));
+        }
+        size >= MIN_DOMAIN_SIZE
+    }
+}
+
+/// A numeral string, of at most `MAX_LEN` numerals.
+struct Numerals {
+    numerals: [u16; MAX_LEN],
+    len: usize,
+}
+
+impl Numerals {
+    fn new(numerals: &[u16]) -> Self {
+        let mut r = Self {
+            numerals: [0; MAX_LEN],
+            len: numerals.len(),
+        };
+        r.numerals[..numerals.len()].copy_from_slice(numerals);
+        r
+    }
+
+    fn as_slice(&self) -> &[u16] {
+        &self.numerals[..self.len]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u16] {
+        &mut self.numerals[..self.len]
+    }
+}
+
+impl Drop for Numerals {
+    fn drop(&mut self) {
+        zeroise(&mut self.numerals);
+    }
+}
+
+/// CBC-MAC with a zero IV, of a message which is a whole number of blocks.
+struct CbcMac<'a> {
+    key: &'a AesKey,
+    state: [u8; 16],
+    used: usize,
+}
+
+impl<'a> CbcMac<'a> {
+    fn new(key: &'a AesKey) -> Self {
+        Self {
+            key,
+            state: [0u8; 16],
+            used: 0,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.state[self.used] ^= b;
+            self.used += 1;
+            if self.used == 16 {
+                self.key.encrypt_block(&mut self.state);
+                self.used = 0;
+            }
+        }
+    }
+
+    fn finish(mut self) -> [u8; 16] {
+        debug_assert_eq!(self.used, 0);
+        let r = self.state;
+        zeroise(&mut self.state);
+        r
+    }
+}
+
+/// `x = x * m + a`, where `x` is a big-endian integer.
+///
+/// The result must fit in `x`.
+fn mul_add(x: &mut [u8], m: u32, a: u32) {
+    let mut carry = a;
+    for byte in x.iter_mut().rev() {
+        let t = u32::from(*byte) * m + carry;
+        *byte = t as u8;
+        carry = t >> 8;
+    }
+    debug_assert_eq!(carry, 0);
+}
+
+/// `x = x / d`, returning `x mod d`, where `x` is a big-endian integer.
+fn div_rem(x: &mut [u8], d: u32) -> u32 {
+    let mut rem = 0;
+    for byte in x.iter_mut() {
+        let t = (rem << 8) | u32::from(*byte);
+        *byte = (t / d) as u8;
+        rem = t % d;
+    }
+    rem
+}
+
+const MIN_RADIX: u32 = 2;
+const MAX_RADIX: u32 = 1 << 16;
+const MIN_LEN: usize = 2;
+const MAX_LEN: usize = 256;
+const MIN_DOMAIN_SIZE: u64 = 1_000_000;
+
+/// Length of `NUM_radix(B)` with the largest radix and `v`.
+const MAX_NUM_BYTES: usize = (MAX_LEN - MAX_LEN / 2) * 2;
+
+/// Length of `S`, rounded up to a whole block.
+const MAX_S_BYTES: usize = MAX_NUM_BYTES + 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numerals(s: &str) -> Vec<u16> {
+        s.chars().map(|c| c.to_digit(36).unwrap() as u16).collect()
+    }
+
+    fn string(n: &[u16]) -> String {
+        n.iter()
+            .map(|d| char::from_digit(u32::from(*d), 36).unwrap())
+            .collect()
+    }
+
+    fn check(key: &[u8], radix: u32, tweak: &[u8], plaintext: &str, ciphertext: &str) {
+        let ff1 = Ff1::new(key, radix).unwrap();
+        let mut x = numerals(plaintext);
+        ff1.encrypt(tweak, &mut x).unwrap();
+        assert_eq!(string(&x), ciphertext);
+        ff1.decrypt(tweak, &mut x).unwrap();
+        assert_eq!(string(&x), plaintext);
+    }
+
+    const AES128_KEY: &[u8] = b"\x2b\x7e\x15\x16\x28\xae\xd2\xa6\xab\xf7\x15\x88\x09\xcf\x4f\x3c";
+    const AES256_KEY: &[u8] = b"\x2b\x7e\x15\x16\x28\xae\xd2\xa6\xab\xf7\x15\x88\x09\xcf\x4f\x3c\
+                                \xef\x43\x59\xd8\xd5\x80\xaa\x4f\x7f\x03\x6d\x6f\x04\xfc\x6a\x94";
+    const TWEAK: &[u8] = b"\x39\x38\x37\x36\x35\x34\x33\x32\x31\x30";
+    const TWEAK_36: &[u8] = b"\x37\x37\x37\x37\x70\x71\x72\x73\x37\x37\x37";
+
+    #[test]
+    fn nist_samples_aes128() {
+        check(AES128_KEY, 10, b"", "0123456789", "2433477484");
+        check(AES128_KEY, 10, TWEAK, "0123456789", "6124200773");
+        check(
+            AES128_KEY,
+            36,
+            TWEAK_36,
+            "0123456789abcdefghi",
+            "a9tv40mll9kdu509eum",
+        );
+    }
+
+    #[test]
+    fn nist_samples_aes256() {
+        check(AES256_KEY, 10, b"", "0123456789", "6657667009");
+        check(AES256_KEY, 10, TWEAK, "0123456789", "1001623463");
+        check(
+            AES256_KEY,
+            36,
+            TWEAK_36,
+            "0123456789abcdefghi",
+            "xs8a0azh2avyalyzuwd",
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let ff1 = Ff1::new(AES128_KEY, 65536).unwrap();
+        let original = (0..MAX_LEN).map(|i| (i * 257) as u16).collect::<Vec<_>>();
+        let mut x = original.clone();
+        ff1.encrypt(&[0xaa; 100], &mut x).unwrap();
+        assert_ne!(x, original);
+        ff1.decrypt(&[0xaa; 100], &mut x).unwrap();
+        assert_eq!(x, original);
+
+        for n in 20..24 {
+            let ff1 = Ff1::new(AES256_KEY, 2).unwrap();
+            let original = (0..n).map(|i| (i % 3 % 2) as u16).collect::<Vec<_>>();
+            let mut x = original.clone();
+            ff1.encrypt(b"tweak", &mut x).unwrap();
+            assert!(x.iter().all(|d| *d < 2));
+            ff1.decrypt(b"tweak", &mut x).unwrap();
+            assert_eq!(x, original);
+        }
+    }
+
+    #[test]
+    fn num_bytes() {
+        let ff1 = Ff1::new(AES128_KEY, 10).unwrap();
+        // 10^5 - 1 needs 17 bits
+        assert_eq!(ff1.num_bytes(5), 3);
+        let ff1 = Ff1::new(AES128_KEY, 256).unwrap();
+        assert_eq!(ff1.num_bytes(5), 5);
+        let ff1 = Ff1::new(AES128_KEY, 65536).unwrap();
+        assert_eq!(ff1.num_bytes(MAX_LEN / 2), MAX_NUM_BYTES);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Ff1::new(&[0; 24], 10).err(), Some(Error::WrongLength));
+        assert_eq!(Ff1::new(AES128_KEY, 1).err(), Some(Error::OutOfRange));
+        assert_eq!(Ff1::new(AES128_KEY, 65537).err(), Some(Error::OutOfRange));
+
+        let ff1 = Ff1::new(AES128_KEY, 10).unwrap();
+        // domain is too small
+        assert_eq!(
+            ff1.encrypt(b"", &mut [0; 5]).err(),
+            Some(Error::WrongLength)
+        );
+        ff1.encrypt(b"", &mut [0; 6]).unwrap();
+        assert_eq!(
+            ff1.encrypt(b"", &mut [0; MAX_LEN + 1]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            ff1.decrypt(b"", &mut [0, 1, 2, 3, 4, 10]).err(),
+            Some(Error::OutOfRange)
+        );
+    }
+}
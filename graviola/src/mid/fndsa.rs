@@ -0,0 +1,429 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! FN-DSA signature verification.
+//!
+//! FN-DSA is the name NIST has chosen for Falcon, to be specified in the
+//! forthcoming FIPS206.  Until that is published, this follows the
+//! [Falcon round 3 specification](https://falcon-sign.info/falcon.pdf):
+//! the message is hashed with the 40-byte nonce only, with no context string.
+//!
+//! Signing is not provided.  Verification handles only public values,
+//! so it is not constant-time.
+//!
+//! The two parameter sets are in separate modules, which share the
+//! implementation here.  Internally, the parameter sets are distinguished
+//! by the ring degree `N`.
+
+use crate::Error;
+use crate::low::{Entry, fndsa as poly};
+use crate::mid::sha3::Shake256;
+
+const Q: u32 = poly::Q;
+
+/// Length of the nonce at the start of each signature.
+const NONCE_LEN: usize = 40;
+
+/// Sizes and parameters for the parameter set of degree `N`.
+struct Params<const N: usize>;
+
+impl<const N: usize> Params<N> {
+    const LOGN: u8 = N.trailing_zeros() as u8;
+
+    /// Encoding of `h`, plus a header byte.
+    const PK_LEN: usize = 1 + N * 14 / 8;
+
+    /// The fixed length of signatures in the "padded" format.
+    ///
+    /// Signatures in the (default) "compressed" format have a variable
+    /// length, which is never more than this.
+    const MAX_SIG_LEN: usize = if N == 512 { 666 } else { 1280 };
+
+    /// The largest acceptable squared norm of `(s1, s2)`.
+    const NORM_BOUND: u64 = if N == 512 { 34034726 } else { 70265242 };
+}
+
+/// A public key.
+#[derive(Clone)]
+struct PublicKey<const N: usize> {
+    /// `h`, in the NTT domain.
+    h: [u32; N],
+}
+
+impl<const N: usize> PublicKey<N> {
+    fn decode(pk: &[u8]) -> Result<Self, Error> {
+        if pk.len() != Params::<N>::PK_LEN {
+            return Err(Error::WrongLength);
+        }
+        if pk[0] != Params::<N>::LOGN {
+            return Err(Error::OutOfRange);
+        }
+
+        let mut h = [0u32; N];
+        let mut acc = 0u32;
+        let mut have = 0;
+        let mut bytes = pk[1..].iter();
+        for c in h.iter_mut() {
+            while have < 14 {
+                acc = (acc << 8) | *bytes.next().unwrap() as u32;
+                have += 8;
+            }
+            have -= 14;
+            *c = (acc >> have) & 0x3fff;
+            if *c >= Q {
+                return Err(Error::OutOfRange);
+            }
+        }
+
+        poly::ntt(&mut h);
+        Ok(Self { h })
+    }
+
+    fn verify(&self, message: &[&[u8]], sig: &[u8]) -> Result<(), Error> {
+        if sig.len() < 1 + NONCE_LEN || sig.len() > Params::<N>::MAX_SIG_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let (header, rest) = sig.split_at(1);
+        let (nonce, compressed) = rest.split_at(NONCE_LEN);
+        if header[0] != 0x30 | Params::<N>::LOGN {
+            return Err(Error::BadSignature);
+        }
+
+        let mut s2 = [0i32; N];
+        let used = decompress(compressed, &mut s2).ok_or(Error::BadSignature)?;
+
+        // The compressed format must have no trailing bytes; the padded format
+        // has a fixed length, filled with zeroes.
+        if used != compressed.len()
+            && (sig.len() != Params::<N>::MAX_SIG_LEN || compressed[used..].iter().any(|b| *b != 0))
+        {
+            return Err(Error::BadSignature);
+        }
+
+        let c = hash_to_point::<N>(nonce, message);
+
+        // s1 = c - s2 * h
+        let mut s2h = [0u32; N];
+        for (r, s) in s2h.iter_mut().zip(s2.iter()) {
+            *r = s.rem_euclid(Q as i32) as u32;
+        }
+        poly::ntt(&mut s2h);
+        poly::pointwise_mul(&mut s2h, &self.h);
+        poly::inv_ntt(&mut s2h);
+
+        let mut norm = 0u64;
+        for ((c, s2h), s2) in c.iter().zip(s2h.iter()).zip(s2.iter()) {
+            let s1 = centre((c + Q - s2h) % Q);
+            norm += (s1 * s1) as u64 + (s2 * s2) as u64;
+        }
+
+        match norm <= Params::<N>::NORM_BOUND {
+            true => Ok(()),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// Returns the representative of `x` in the range `-q/2..=q/2`.
+fn centre(x: u32) -> i32 {
+    match x > Q / 2 {
+        true => x as i32 - Q as i32,
+        false => x as i32,
+    }
+}
+
+/// HashToPoint, the variable-time version.
+fn hash_to_point<const N: usize>(nonce: &[u8], message: &[&[u8]]) -> [u32; N] {
+    let mut shake = Shake256::new();
+    shake.update(nonce);
+    for m in message {
+        shake.update(m);
+    }
+
+    let mut c = [0u32; N];
+    let mut i = 0;
+    let mut block = [0u8; Shake256::BLOCK_SZ];
+    while i < N {
+        shake.squeeze(&mut block);
+        for pair in block.chunks_exact(2) {
+            let t = u16::from_be_bytes([pair[0], pair[1]]) as u32;
+            if t < 5 * Q && i < N {
+                c[i] = t % Q;
+                i += 1;
+            }
+        }
+    }
+    c
+}
+
+/// Decodes the compressed encoding of `s2` from `bytes` into `out`.
+///
+/// Each coefficient is a sign bit, the low 7 bits of its absolute value,
+/// then the remaining bits of its absolute value in unary.
+///
+/// Returns the number of bytes used, or `None` if the encoding is
+/// invalid or non-canonical.
+fn decompress<const N: usize>(bytes: &[u8], out: &mut [i32; N]) -> Option<usize> {
+    let mut bytes_iter = bytes.iter();
+    let mut acc = 0u32;
+    let mut have = 0;
+
+    for c in out.iter_mut() {
+        acc = (acc << 8) | *bytes_iter.next()? as u32;
+        let b = acc >> have;
+        let negative = b & 0x80 != 0;
+        let mut m = b & 0x7f;
+
+        loop {
+            if have == 0 {
+                acc = (acc << 8) | *bytes_iter.next()? as u32;
+                have = 8;
+            }
+            have -= 1;
+            if (acc >> have) & 1 == 1 {
+                break;
+            }
+            m += 0x80;
+            if m > 2047 {
+                return None;
+            }
+        }
+
+        // "-0" is not canonical
+        if negative && m == 0 {
+            return None;
+        }
+        *c = match negative {
+            true => -(m as i32),
+            false => m as i32,
+        };
+    }
+
+    // unused bits of the final byte must be zero
+    if acc & ((1 << have) - 1) != 0 {
+        return None;
+    }
+
+    Some(bytes.len() - bytes_iter.len())
+}
+
+macro_rules! fn_dsa {
+    ($name:ident, $n:literal, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $name {
+            use super::*;
+
+            const N: usize = $n;
+
+            /// The length of a verifying key, in bytes.
+            pub const VERIFYING_KEY_LEN: usize = Params::<N>::PK_LEN;
+
+            /// The maximum length of a signature, in bytes.
+            ///
+            /// This is also the length of a signature in the padded format.
+            pub const MAX_SIGNATURE_LEN: usize = Params::<N>::MAX_SIG_LEN;
+
+            /// A verifying (public) key.
+            #[derive(Clone)]
+            pub struct VerifyingKey {
+                public: PublicKey<N>,
+                bytes: [u8; VERIFYING_KEY_LEN],
+            }
+
+            impl VerifyingKey {
+                /// Decode a verifying key.
+                ///
+                /// This fails with [`Error::WrongLength`] if `bytes` is not
+                /// [`VERIFYING_KEY_LEN`] bytes long, or [`Error::OutOfRange`]
+                /// if the header byte or any coefficient is invalid.
+                pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                    let _entry = Entry::new_public();
+                    let public = PublicKey::decode(bytes)?;
+                    Ok(Self {
+                        public,
+                        bytes: bytes.try_into().unwrap(),
+                    })
+                }
+
+                /// Return the encoding of this key.
+                pub fn as_bytes(&self) -> &[u8; VERIFYING_KEY_LEN] {
+                    &self.bytes
+                }
+
+                /// Verify `signature` over `message`.
+                ///
+                /// The message is a sequence of byte slices, so some workloads
+                /// can avoid joining it into one buffer beforehand.
+                /// `signature` may be in the compressed or padded format.
+                ///
+                /// Returns `Err(Error::BadSignature)` if the signature is
+                /// invalid, or `Err(Error::WrongLength)` if `signature` has
+                /// an invalid length.
+                pub fn verify(&self, message: &[&[u8]], signature: &[u8]) -> Result<(), Error> {
+                    let _entry = Entry::new_public();
+                    self.public.verify(message, signature)
+                }
+            }
+        }
+    };
+}
+
+fn_dsa!(
+    fn_dsa_512,
+    512,
+    "FN-DSA-512, which targets NIST security category 1."
+);
+fn_dsa!(
+    fn_dsa_1024,
+    1024,
+    "FN-DSA-1024, which targets NIST security category 5."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These test vectors are signatures of "message", made with an
+    // independent implementation.  The `-too-long` signature has a
+    // correct structure, but exceeds the norm bound.
+
+    #[test]
+    fn fn_dsa_512() {
+        use fn_dsa_512::*;
+
+        let pk = VerifyingKey::from_bytes(include_bytes!("fndsa/fn-dsa-512.pk")).unwrap();
+        let sig = include_bytes!("fndsa/fn-dsa-512.sig");
+        pk.verify(&[b"message"], sig).unwrap();
+        pk.verify(&[b"mess", b"age"], sig).unwrap();
+        assert_eq!(pk.verify(&[b"massage"], sig), Err(Error::BadSignature));
+
+        assert_eq!(
+            pk.verify(
+                &[b"message"],
+                include_bytes!("fndsa/fn-dsa-512-too-long.sig")
+            ),
+            Err(Error::BadSignature)
+        );
+
+        check_encodings(sig, |sig| pk.verify(&[b"message"], sig), MAX_SIGNATURE_LEN);
+    }
+
+    #[test]
+    fn fn_dsa_1024() {
+        use fn_dsa_1024::*;
+
+        let pk = VerifyingKey::from_bytes(include_bytes!("fndsa/fn-dsa-1024.pk")).unwrap();
+        let sig = include_bytes!("fndsa/fn-dsa-1024.sig");
+        pk.verify(&[b"message"], sig).unwrap();
+        assert_eq!(pk.verify(&[b"massage"], sig), Err(Error::BadSignature));
+
+        // keys and signatures are not interchangeable between parameter sets
+        assert_eq!(
+            pk.verify(&[b"message"], include_bytes!("fndsa/fn-dsa-512.sig")),
+            Err(Error::BadSignature)
+        );
+        assert_eq!(
+            VerifyingKey::from_bytes(include_bytes!("fndsa/fn-dsa-512.pk")).err(),
+            Some(Error::WrongLength)
+        );
+
+        check_encodings(sig, |sig| pk.verify(&[b"message"], sig), MAX_SIGNATURE_LEN);
+    }
+
+    fn check_encodings(sig: &[u8], verify: impl Fn(&[u8]) -> Result<(), Error>, max_len: usize) {
+        // every byte of the signature matters
+        for i in (0..sig.len()).step_by(37) {
+            let mut bad = sig.to_vec();
+            bad[i] ^= 0x08;
+            assert!(verify(&bad).is_err());
+        }
+
+        // padded format
+        let mut padded = sig.to_vec();
+        padded.resize(max_len, 0);
+        verify(&padded).unwrap();
+        padded[max_len - 1] = 1;
+        assert_eq!(verify(&padded), Err(Error::BadSignature));
+
+        // but trailing zeroes are not allowed otherwise
+        let mut trailing = sig.to_vec();
+        trailing.push(0);
+        assert_eq!(verify(&trailing), Err(Error::BadSignature));
+
+        assert_eq!(verify(&sig[..40]), Err(Error::WrongLength));
+        assert_eq!(verify(&sig[..sig.len() - 1]), Err(Error::BadSignature));
+        padded.push(0);
+        assert_eq!(verify(&padded), Err(Error::WrongLength));
+    }
+
+    #[test]
+    fn public_key_coefficients_in_range() {
+        let mut pk = include_bytes!("fndsa/fn-dsa-512.pk").to_vec();
+        fn_dsa_512::VerifyingKey::from_bytes(&pk).unwrap();
+
+        // the first coefficient is the top 14 bits after the header
+        pk[1] = 0xff;
+        pk[2] |= 0xfc;
+        assert_eq!(
+            fn_dsa_512::VerifyingKey::from_bytes(&pk).err(),
+            Some(Error::OutOfRange)
+        );
+
+        let mut pk = include_bytes!("fndsa/fn-dsa-512.pk").to_vec();
+        pk[0] = 0x0a;
+        assert_eq!(
+            fn_dsa_512::VerifyingKey::from_bytes(&pk).err(),
+            Some(Error::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn decompress_is_strict() {
+        let mut out = [0i32; 512];
+
+        // sign and low bits 0x00, then the unary terminator
+        let zero = (1, 9);
+        let zeroes = bits([zero; 512]);
+        assert_eq!(decompress(&zeroes, &mut out), Some(576));
+        assert!(out.iter().all(|c| *c == 0));
+        assert_eq!(decompress(&zeroes[..575], &mut out), None);
+
+        // the largest magnitude is 2047: 127 low bits, and 15 in unary
+        let largest = bits([(0b1111_1111, 8), (1, 16)].into_iter().chain([zero; 511]));
+        assert!(decompress(&largest, &mut out).is_some());
+        assert_eq!(out[0], -2047);
+
+        let too_large = bits([(0b1111_1111, 8), (1, 17)].into_iter().chain([zero; 511]));
+        assert_eq!(decompress(&too_large, &mut out), None);
+
+        let negative_zero = bits([(0x80 << 1 | 1, 9)].into_iter().chain([zero; 511]));
+        assert_eq!(decompress(&negative_zero, &mut out), None);
+
+        // 128 takes one more bit, leaving seven unused bits at the end
+        let mut padded = bits([(1, 10)].into_iter().chain([zero; 511]));
+        assert_eq!(decompress(&padded, &mut out), Some(577));
+        assert_eq!(out[0], 128);
+        *padded.last_mut().unwrap() |= 1;
+        assert_eq!(decompress(&padded, &mut out), None);
+    }
+
+    /// Packs `(value, length)` bit fields, most significant bit first.
+    fn bits(fields: impl IntoIterator<Item = (u32, u32)>) -> Vec<u8> {
+        let mut out = vec![];
+        let mut acc = 0u64;
+        let mut have = 0;
+        for (value, len) in fields {
+            acc = (acc << len) | value as u64;
+            have += len;
+            while have >= 8 {
+                have -= 8;
+                out.push((acc >> have) as u8);
+            }
+        }
+        if have > 0 {
+            out.push((acc << (8 - have)) as u8);
+        }
+        out
+    }
+}
@@ -0,0 +1,325 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::low::ghash::{self, GhashTable};
+use crate::low::{Blockwise, Entry, gf128_mul as low_gf128_mul, zeroise};
+
+/// A GHASH key.
+///
+/// GHASH is the universal hash function used by AES-GCM, from
+/// [NIST SP800-38D](https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38d.pdf)
+/// section 6.4.
+///
+/// GHASH is not a MAC.  Its output is a linear function of the message,
+/// so revealing it (or using a key for more than one message, without
+/// masking each output with a fresh one-time pad as AES-GCM does) allows
+/// forgeries and recovery of the key.  The key must be secret and
+/// uniformly random, and is typically derived with a block cipher.
+pub struct GhashKey {
+    table: GhashTable,
+}
+
+impl GhashKey {
+    /// Make a GHASH key from the hash subkey `h`.
+    ///
+    /// In AES-GCM, this is the encryption of the all-zero block.
+    pub fn new(h: &[u8; 16]) -> Self {
+        let _entry = Entry::new_secret();
+        Self {
+            table: GhashTable::new(u128::from_be_bytes(*h)),
+        }
+    }
+
+    /// Start a GHASH computation.
+    pub fn start(&self) -> Ghash<'_> {
+        Ghash(State::new(&self.table, false))
+    }
+}
+
+/// An in-progress GHASH computation.
+///
+/// Create one of these with [`GhashKey::start`].
+pub struct Ghash<'a>(State<'a>);
+
+impl Ghash<'_> {
+    /// Add `bytes` to the message.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let _entry = Entry::new_secret();
+        self.0.update(bytes);
+    }
+
+    /// Zero-pad the message so far to a whole number of blocks.
+    ///
+    /// This does nothing if it is already a whole number of blocks.
+    /// AES-GCM pads this way after the additional data and after the
+    /// ciphertext.
+    pub fn pad(&mut self) {
+        let _entry = Entry::new_secret();
+        self.0.pad();
+    }
+
+    /// Complete the GHASH computation, returning the hash.
+    ///
+    /// The message is zero-padded if required.  No lengths block is
+    /// added: callers that need one must add it with [`Ghash::update`].
+    pub fn finish(self) -> [u8; 16] {
+        let _entry = Entry::new_secret();
+        self.0.finish()
+    }
+}
+
+/// A POLYVAL key.
+///
+/// POLYVAL is the universal hash function used by AES-GCM-SIV, from
+/// [RFC8452](https://datatracker.ietf.org/doc/html/rfc8452#section-3).
+/// It is GHASH with the opposite byte order, and is computed here using
+/// GHASH as described in appendix A of that document.
+///
+/// The same warnings as for [`GhashKey`] apply: POLYVAL is not a MAC,
+/// and its output must never be revealed unmasked.
+pub struct PolyvalKey {
+    table: GhashTable,
+}
+
+impl PolyvalKey {
+    /// Make a POLYVAL key from the hash key `h`.
+    pub fn new(h: &[u8; 16]) -> Self {
+        let _entry = Entry::new_secret();
+        Self {
+            table: GhashTable::new(mul_x_ghash(u128::from_le_bytes(*h))),
+        }
+    }
+
+    /// Start a POLYVAL computation.
+    pub fn start(&self) -> Polyval<'_> {
+        Polyval(State::new(&self.table, true))
+    }
+}
+
+/// An in-progress POLYVAL computation.
+///
+/// Create one of these with [`PolyvalKey::start`].
+pub struct Polyval<'a>(State<'a>);
+
+impl Polyval<'_> {
+    /// Add `bytes` to the message.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let _entry = Entry::new_secret();
+        self.0.update(bytes);
+    }
+
+    /// Zero-pad the message so far to a whole number of blocks.
+    ///
+    /// This does nothing if it is already a whole number of blocks.
+    /// AES-GCM-SIV pads this way after the additional data and after
+    /// the plaintext.
+    pub fn pad(&mut self) {
+        let _entry = Entry::new_secret();
+        self.0.pad();
+    }
+
+    /// Complete the POLYVAL computation, returning the hash.
+    ///
+    /// The message is zero-padded if required.  No lengths block is
+    /// added: callers that need one must add it with [`Polyval::update`].
+    pub fn finish(self) -> [u8; 16] {
+        let _entry = Entry::new_secret();
+        let mut r = self.0.finish();
+        r.reverse();
+        r
+    }
+}
+
+/// GHASH over whole blocks, optionally byte-reversing each one (for POLYVAL).
+struct State<'a> {
+    ghash: ghash::Ghash<'a>,
+    buffer: Blockwise<16>,
+    reverse: bool,
+}
+
+impl<'a> State<'a> {
+    fn new(table: &'a GhashTable, reverse: bool) -> Self {
+        Self {
+            ghash: ghash::Ghash::new(table),
+            buffer: Blockwise::new(),
+            reverse,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        // `ghash::Ghash::add` pads each call, so we must only give it whole blocks
+        let bytes = self.buffer.add_leading(bytes);
+        if let Some(block) = self.buffer.take() {
+            self.add_blocks(&block);
+        }
+
+        let (whole, trailing) = bytes.split_at(bytes.len() - bytes.len() % 16);
+        self.add_blocks(whole);
+        self.buffer.add_trailing(trailing);
+    }
+
+    fn pad(&mut self) {
+        if let Some(trailing) = self.buffer.peek_remaining() {
+            let mut block = [0u8; 16];
+            block[..trailing.len()].copy_from_slice(trailing);
+            self.add_blocks(&block);
+            zeroise(&mut block);
+        }
+        self.buffer = Blockwise::new();
+    }
+
+    fn finish(mut self) -> [u8; 16] {
+        self.pad();
+        self.ghash.into_bytes()
+    }
+
+    fn add_blocks(&mut self, blocks: &[u8]) {
+        if !self.reverse {
+            self.ghash.add(blocks);
+            return;
+        }
+
+        // reverse in batches, so `ghash` can still process several blocks at once
+        let mut reversed = [0u8; 128];
+        for batch in blocks.chunks(reversed.len()) {
+            for (r, b) in reversed.chunks_exact_mut(16).zip(batch.chunks_exact(16)) {
+                r.copy_from_slice(b);
+                r.reverse();
+            }
+            self.ghash.add(&reversed[..batch.len()]);
+        }
+        zeroise(&mut reversed);
+    }
+}
+
+/// Multiply `a` and `b` in GHASH's field, GF(2^128).
+///
+/// The field elements are encoded as in GHASH: the first bit (the most
+/// significant bit of the first byte) is the coefficient of x^0.
+pub fn gf128_mul(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    let _entry = Entry::new_secret();
+    low_gf128_mul(u128::from_be_bytes(*a), u128::from_be_bytes(*b)).to_be_bytes()
+}
+
+/// `mulX_GHASH()` from RFC8452 appendix A.
+///
+/// This multiplies `v` by x, in GHASH's bit order (where the most
+/// significant bit of `v` is the coefficient of x^0).
+fn mul_x_ghash(v: u128) -> u128 {
+    let carry = 0u128.wrapping_sub(v & 1);
+    (v >> 1) ^ (carry & (0xe1 << 120))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low::AesKey;
+    use crate::mid::aes_gcm::AesGcm;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn polyval_rfc8452_appendix_a() {
+        let h: [u8; 16] = unhex("25629347589242761d31f826ba4b757b")
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            mul_x_ghash(u128::from_le_bytes(h)).to_be_bytes()[..],
+            unhex("dcbaa5dd137c188ebb21492c23c9b112")
+        );
+
+        let key = PolyvalKey::new(&h);
+        let mut p = key.start();
+        p.update(&unhex("4f4f95668c83dfb6401762bb2d01a262"));
+        p.update(&unhex("d1a24ddd2721d006bbe45f20d3c9f362"));
+        assert_eq!(p.finish()[..], unhex("f7a3b47b846119fae5b7866cf5e5b77e"));
+    }
+
+    #[test]
+    fn ghash_matches_aes_gcm() {
+        let key = [0x42u8; 16];
+        let nonce = *b"noncenonceno";
+        let message = (0..300).map(|i| i as u8).collect::<Vec<_>>();
+
+        let aes = AesKey::new(&key);
+        let mut h = [0u8; 16];
+        aes.encrypt_block(&mut h);
+        let mut e_y0 = [0u8; 16];
+        e_y0[..12].copy_from_slice(&nonce);
+        e_y0[15] = 1;
+        aes.encrypt_block(&mut e_y0);
+
+        let ghash_key = GhashKey::new(&h);
+        let gcm = AesGcm::new(&key);
+
+        for (aad_len, len) in [(0, 0), (1, 0), (0, 17), (13, 100), (128, 129), (200, 300)] {
+            let aad = &message[..aad_len];
+            let mut cipher = message[..len].to_vec();
+            let mut tag = [0u8; 16];
+            gcm.encrypt(&nonce, aad, &mut cipher, &mut tag);
+
+            let mut lengths = [0u8; 16];
+            lengths[..8].copy_from_slice(&((aad_len * 8) as u64).to_be_bytes());
+            lengths[8..].copy_from_slice(&((len * 8) as u64).to_be_bytes());
+
+            // feed in awkward pieces, to exercise the buffering
+            let mut g = ghash_key.start();
+            for chunk in aad.chunks(7) {
+                g.update(chunk);
+            }
+            g.pad();
+            for chunk in cipher.chunks(33) {
+                g.update(chunk);
+            }
+            g.pad();
+            g.update(&lengths);
+
+            let mut got = g.finish();
+            for (g, e) in got.iter_mut().zip(e_y0.iter()) {
+                *g ^= *e;
+            }
+            assert_eq!(got, tag);
+        }
+    }
+
+    #[test]
+    fn gf128_mul_matches_ghash() {
+        // GHASH of a single block is that block multiplied by H
+        let h = [0x66u8; 16];
+        let block = *b"a single block!!";
+        let key = GhashKey::new(&h);
+        let mut g = key.start();
+        g.update(&block);
+        assert_eq!(gf128_mul(&block, &h), g.finish());
+
+        let mut one = [0u8; 16];
+        one[0] = 0x80;
+        assert_eq!(gf128_mul(&one, &h), h);
+        assert_eq!(gf128_mul(&h, &block), gf128_mul(&block, &h));
+    }
+
+    #[test]
+    fn polyval_pieces() {
+        let key = PolyvalKey::new(&[0x24; 16]);
+        let message = (0..300).map(|i| (i * 5) as u8).collect::<Vec<_>>();
+
+        for len in [0, 1, 15, 16, 17, 128, 129, 300] {
+            let mut expect = key.start();
+            expect.update(&message[..len]);
+            let expect = expect.finish();
+
+            for piece in [1, 5, 16, 100] {
+                let mut got = key.start();
+                for chunk in message[..len].chunks(piece) {
+                    got.update(chunk);
+                }
+                assert_eq!(got.finish(), expect);
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::sha3::{CShake, left_encode, right_encode};
+use crate::low::Entry;
+
+/// An in-progress KMAC computation.
+///
+/// KMAC is standardized in [NIST SP800-185](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-185.pdf).
+///
+/// This is generic over the cSHAKE rate `RATE` and the default
+/// output length `OUT`; use [`Kmac128`] or [`Kmac256`].
+#[derive(Clone)]
+pub struct Kmac<const RATE: usize, const OUT: usize> {
+    cshake: CShake<RATE>,
+}
+
+/// KMAC128, with a 32-byte tag.
+pub type Kmac128 = Kmac<168, 32>;
+
+/// KMAC256, with a 64-byte tag.
+pub type Kmac256 = Kmac<136, 64>;
+
+impl<const RATE: usize, const OUT: usize> Kmac<RATE, OUT> {
+    /// Start a new KMAC computation, with the given key and
+    /// customization string.
+    ///
+    /// `key` may be any length, but should be at least as long as
+    /// the desired security strength.  `customization` may be empty.
+    pub fn new(key: &[u8], customization: &[u8]) -> Self {
+        let _entry = Entry::new_secret();
+        let mut cshake = CShake::new(b"KMAC", customization);
+        cshake.update(left_encode(RATE as u64).as_ref());
+        cshake.absorb_encoded_string(key);
+        cshake.pad_to_block();
+        Self { cshake }
+    }
+
+    /// Add `bytes` to the message.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let _entry = Entry::new_secret();
+        self.cshake.update(bytes);
+    }
+
+    /// Complete the KMAC computation, returning the tag.
+    pub fn finish(self) -> [u8; OUT] {
+        let mut out = [0u8; OUT];
+        self.finish_into(&mut out);
+        out
+    }
+
+    /// Complete the KMAC computation, writing a tag of `out.len()` bytes.
+    ///
+    /// The requested length is bound into the computation, so
+    /// tags of different lengths are unrelated (and not prefixes
+    /// of each other).
+    pub fn finish_into(mut self, out: &mut [u8]) {
+        let _entry = Entry::new_secret();
+        self.cshake
+            .update(right_encode(out.len() as u64 * 8).as_ref());
+        self.cshake.squeeze(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"\x40\x41\x42\x43\x44\x45\x46\x47\x48\x49\x4a\x4b\x4c\x4d\x4e\x4f\
+                         \x50\x51\x52\x53\x54\x55\x56\x57\x58\x59\x5a\x5b\x5c\x5d\x5e\x5f";
+
+    fn long_message() -> Vec<u8> {
+        (0..200).map(|i| i as u8).collect()
+    }
+
+    fn kmac<const RATE: usize, const OUT: usize>(
+        customization: &[u8],
+        message: &[u8],
+    ) -> [u8; OUT] {
+        let mut k = Kmac::<RATE, OUT>::new(KEY, customization);
+        k.update(message);
+        let tag = k.finish();
+
+        // and byte-at-a-time
+        let mut k = Kmac::<RATE, OUT>::new(KEY, customization);
+        for b in message {
+            k.update(core::slice::from_ref(b));
+        }
+        assert_eq!(k.finish(), tag);
+        tag
+    }
+
+    #[test]
+    fn sp800_185_kmac128_samples() {
+        assert_eq!(
+            &kmac::<168, 32>(b"", b"\x00\x01\x02\x03"),
+            b"\xe5\x78\x0b\x0d\x3e\xa6\xf7\xd3\xa4\x29\xc5\x70\x6a\xa4\x3a\x00\
+              \xfa\xdb\xd7\xd4\x96\x28\x83\x9e\x31\x87\x24\x3f\x45\x6e\xe1\x4e"
+        );
+        assert_eq!(
+            &kmac::<168, 32>(b"My Tagged Application", b"\x00\x01\x02\x03"),
+            b"\x3b\x1f\xba\x96\x3c\xd8\xb0\xb5\x9e\x8c\x1a\x6d\x71\x88\x8b\x71\
+              \x43\x65\x1a\xf8\xba\x0a\x70\x70\xc0\x97\x9e\x28\x11\x32\x4a\xa5"
+        );
+        assert_eq!(
+            &kmac::<168, 32>(b"My Tagged Application", &long_message()),
+            b"\x1f\x5b\x4e\x6c\xca\x02\x20\x9e\x0d\xcb\x5c\xa6\x35\xb8\x9a\x15\
+              \xe2\x71\xec\xc7\x60\x07\x1d\xfd\x80\x5f\xaa\x38\xf9\x72\x92\x30"
+        );
+    }
+
+    #[test]
+    fn sp800_185_kmac256_samples() {
+        assert_eq!(
+            &kmac::<136, 64>(b"My Tagged Application", b"\x00\x01\x02\x03"),
+            b"\x20\xc5\x70\xc3\x13\x46\xf7\x03\xc9\xac\x36\xc6\x1c\x03\xcb\x64\
+              \xc3\x97\x0d\x0c\xfc\x78\x7e\x9b\x79\x59\x9d\x27\x3a\x68\xd2\xf7\
+              \xf6\x9d\x4c\xc3\xde\x9d\x10\x4a\x35\x16\x89\xf2\x7c\xf6\xf5\x95\
+              \x1f\x01\x03\xf3\x3f\x4f\x24\x87\x10\x24\xd9\xc2\x77\x73\xa8\xdd"
+        );
+        assert_eq!(
+            &kmac::<136, 64>(b"", &long_message()),
+            b"\x75\x35\x8c\xf3\x9e\x41\x49\x4e\x94\x97\x07\x92\x7c\xee\x0a\xf2\
+              \x0a\x3f\xf5\x53\x90\x4c\x86\xb0\x8f\x21\xcc\x41\x4b\xcf\xd6\x91\
+              \x58\x9d\x27\xcf\x5e\x15\x36\x9c\xbb\xff\x8b\x9a\x4c\x2e\xb1\x78\
+              \x00\x85\x5d\x02\x35\xff\x63\x5d\xa8\x25\x33\xec\x6b\x75\x9b\x69"
+        );
+        assert_eq!(
+            &kmac::<136, 64>(b"My Tagged Application", &long_message()),
+            b"\xb5\x86\x18\xf7\x1f\x92\xe1\xd5\x6c\x1b\x8c\x55\xdd\xd7\xcd\x18\
+              \x8b\x97\xb4\xca\x4d\x99\x83\x1e\xb2\x69\x9a\x83\x7d\xa2\xe4\xd9\
+              \x70\xfb\xac\xfd\xe5\x00\x33\xae\xa5\x85\xf1\xa2\x70\x85\x10\xc3\
+              \x2d\x07\x88\x08\x01\xbd\x18\x28\x98\xfe\x47\x68\x76\xfc\x89\x65"
+        );
+    }
+
+    #[test]
+    fn output_length_is_bound() {
+        let mut k = Kmac128::new(KEY, b"");
+        k.update(b"message");
+        let long = k.clone().finish();
+        let mut short = [0u8; 16];
+        k.finish_into(&mut short);
+        assert_ne!(&long[..16], &short);
+    }
+}
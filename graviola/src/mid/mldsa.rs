@@ -0,0 +1,1148 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! ML-DSA, as specified in
+//! [FIPS204](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.204.pdf).
+//!
+//! The three parameter sets are in separate modules, which share
+//! the implementation here.  Internally, the parameter sets are
+//! distinguished by the matrix dimensions `K` and `L`.
+//!
+//! Only "pure" ML-DSA is provided, not the pre-hash variant HashML-DSA.
+
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::low::{self, Entry, ct_equal, mldsa as poly, zeroise};
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::sha3::{Shake128, Shake256};
+
+type Poly = [i32; 256];
+
+const Q: i32 = poly::Q;
+
+/// Number of dropped bits from `t`.
+const D: usize = 13;
+
+/// Sizes and parameters for the parameter set with matrix dimensions `K` by `L`.
+struct Params<const K: usize, const L: usize>;
+
+impl<const K: usize, const L: usize> Params<K, L> {
+    const ETA: i32 = if K == 6 { 4 } else { 2 };
+    const TAU: usize = match K {
+        4 => 39,
+        6 => 49,
+        _ => 60,
+    };
+    const BETA: i32 = Self::TAU as i32 * Self::ETA;
+    const GAMMA1: i32 = if K == 4 { 1 << 17 } else { 1 << 19 };
+    const GAMMA2: i32 = if K == 4 { (Q - 1) / 88 } else { (Q - 1) / 32 };
+    const OMEGA: usize = match K {
+        4 => 80,
+        6 => 55,
+        _ => 75,
+    };
+    const CTILDE_LEN: usize = match K {
+        4 => 32,
+        6 => 48,
+        _ => 64,
+    };
+
+    const ETA_BITS: usize = if Self::ETA == 2 { 3 } else { 4 };
+    const Z_BITS: usize = if K == 4 { 18 } else { 20 };
+    const W1_BITS: usize = if K == 4 { 6 } else { 4 };
+
+    const PK_LEN: usize = 32 + 32 * 10 * K;
+    const SK_LEN: usize = 128 + 32 * ((K + L) * Self::ETA_BITS + D * K);
+    const SIG_LEN: usize = Self::CTILDE_LEN + 32 * L * Self::Z_BITS + Self::OMEGA + K;
+    const W1_LEN: usize = 32 * K * Self::W1_BITS;
+}
+
+/// Largest `W1_LEN`, for sizing buffers.
+const MAX_W1_LEN: usize = 32 * 8 * 4;
+
+/// Largest `PK_LEN`, for sizing buffers.
+const MAX_PK_LEN: usize = Params::<8, 7>::PK_LEN;
+
+/// A public key.
+#[derive(Clone)]
+struct PublicKey<const K: usize, const L: usize> {
+    rho: [u8; 32],
+    t1: [Poly; K],
+    /// H(pk)
+    tr: [u8; 64],
+}
+
+impl<const K: usize, const L: usize> PublicKey<K, L> {
+    /// pkDecode
+    fn decode(pk: &[u8]) -> Result<Self, Error> {
+        if pk.len() != Params::<K, L>::PK_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let (rho, packed) = pk.split_at(32);
+        let mut t1 = [[0i32; 256]; K];
+        for (t1, bytes) in t1.iter_mut().zip(packed.chunks_exact(320)) {
+            unpack(10, bytes, t1, |x| x as i32);
+        }
+
+        Ok(Self {
+            rho: rho.try_into().unwrap(),
+            t1,
+            tr: h64(&[pk]),
+        })
+    }
+
+    /// pkEncode
+    fn encode(&self, out: &mut [u8]) {
+        let (rho, packed) = out.split_at_mut(32);
+        rho.copy_from_slice(&self.rho);
+        for (t1, bytes) in self.t1.iter().zip(packed.chunks_exact_mut(320)) {
+            pack(10, t1, |x| x as u32, bytes);
+        }
+    }
+
+    /// ML-DSA.Verify_internal, taking the message representative `mu`.
+    fn verify(&self, mu: &[u8; 64], sig: &[u8]) -> Result<(), Error> {
+        let p = Params::<K, L>::default();
+        if sig.len() != Params::<K, L>::SIG_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let (ctilde, rest) = sig.split_at(Params::<K, L>::CTILDE_LEN);
+        let (z_bytes, h_bytes) = rest.split_at(32 * L * Params::<K, L>::Z_BITS);
+
+        let mut z = [[0i32; 256]; L];
+        for (z, bytes) in z
+            .iter_mut()
+            .zip(z_bytes.chunks_exact(32 * Params::<K, L>::Z_BITS))
+        {
+            unpack(Params::<K, L>::Z_BITS, bytes, z, |x| {
+                Params::<K, L>::GAMMA1 - x as i32
+            });
+            if exceeds_norm(z, Params::<K, L>::GAMMA1 - Params::<K, L>::BETA) {
+                return Err(Error::BadSignature);
+            }
+        }
+
+        let hint = p.hint_decode(h_bytes)?;
+
+        let mut c = sample_in_ball(Params::<K, L>::TAU, ctilde);
+        poly::ntt(&mut c);
+        for z in z.iter_mut() {
+            poly::ntt(z);
+        }
+
+        let mut w1_encoded = [0u8; MAX_W1_LEN];
+        let w1_encoded = &mut w1_encoded[..Params::<K, L>::W1_LEN];
+        for (i, ((t1, h), out)) in self
+            .t1
+            .iter()
+            .zip(hint.iter())
+            .zip(w1_encoded.chunks_exact_mut(32 * Params::<K, L>::W1_BITS))
+            .enumerate()
+        {
+            let mut w = [0i32; 256];
+            for (j, z) in z.iter().enumerate() {
+                poly::pointwise_acc(&mut w, &rej_ntt_poly(&self.rho, j as u8, i as u8), z);
+            }
+
+            let mut ct1 = [0i32; 256];
+            let mut t1 = *t1;
+            for c in t1.iter_mut() {
+                *c <<= D;
+            }
+            poly::ntt(&mut t1);
+            poly::pointwise_acc(&mut ct1, &c, &t1);
+
+            for (w, ct1) in w.iter_mut().zip(ct1.iter()) {
+                *w = poly::reduce32(*w - *ct1);
+            }
+            poly::inv_ntt(&mut w);
+
+            for (w, h) in w.iter_mut().zip(h.iter()) {
+                *w = p.use_hint(poly::caddq(*w), *h);
+            }
+            pack(Params::<K, L>::W1_BITS, &w, |x| x as u32, out);
+        }
+
+        let mut ctilde_prime = [0u8; 64];
+        let ctilde_prime = &mut ctilde_prime[..Params::<K, L>::CTILDE_LEN];
+        h(&[mu, w1_encoded], ctilde_prime);
+
+        match ctilde == ctilde_prime {
+            true => Ok(()),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// A private key, plus its public key.
+struct PrivateKey<const K: usize, const L: usize> {
+    key: [u8; 32],
+    s1: [Poly; L],
+    s2: [Poly; K],
+    t0: [Poly; K],
+    public: PublicKey<K, L>,
+}
+
+impl<const K: usize, const L: usize> PrivateKey<K, L> {
+    /// ML-DSA.KeyGen_internal
+    fn generate(xi: &[u8; 32]) -> Self {
+        let mut seeds = [0u8; 128];
+        h(&[xi, &[K as u8, L as u8]], &mut seeds);
+        let (rho, rest) = seeds.split_at(32);
+        let (rho_prime, key) = rest.split_at(64);
+        let rho: [u8; 32] = rho.try_into().unwrap();
+
+        let mut s1 = [[0i32; 256]; L];
+        for (r, s1) in s1.iter_mut().enumerate() {
+            *s1 = rej_bounded_poly(Params::<K, L>::ETA, rho_prime, r as u16);
+        }
+        let mut s2 = [[0i32; 256]; K];
+        for (r, s2) in s2.iter_mut().enumerate() {
+            *s2 = rej_bounded_poly(Params::<K, L>::ETA, rho_prime, (L + r) as u16);
+        }
+
+        let mut t1 = [[0i32; 256]; K];
+        let mut t0 = [[0i32; 256]; K];
+        Self::compute_t(&rho, &s1, &s2, &mut t1, &mut t0);
+
+        let mut public = PublicKey {
+            rho,
+            t1,
+            tr: [0u8; 64],
+        };
+        let mut pk = [0u8; MAX_PK_LEN];
+        let pk = &mut pk[..Params::<K, L>::PK_LEN];
+        public.encode(pk);
+        public.tr = h64(&[pk]);
+
+        let r = Self {
+            key: key.try_into().unwrap(),
+            s1,
+            s2,
+            t0,
+            public,
+        };
+        zeroise(&mut seeds);
+        r
+    }
+
+    /// Computes `t = A * s1 + s2`, split into high and low parts.
+    fn compute_t(
+        rho: &[u8; 32],
+        s1: &[Poly; L],
+        s2: &[Poly; K],
+        t1: &mut [Poly; K],
+        t0: &mut [Poly; K],
+    ) {
+        let mut s1_hat = *s1;
+        for s in s1_hat.iter_mut() {
+            poly::ntt(s);
+        }
+
+        for (i, ((t1, t0), s2)) in t1.iter_mut().zip(t0.iter_mut()).zip(s2.iter()).enumerate() {
+            let mut t = [0i32; 256];
+            for (j, s) in s1_hat.iter().enumerate() {
+                poly::pointwise_acc(&mut t, &rej_ntt_poly(rho, j as u8, i as u8), s);
+            }
+            for c in t.iter_mut() {
+                *c = poly::reduce32(*c);
+            }
+            poly::inv_ntt(&mut t);
+
+            for ((t, s2), (t1, t0)) in t
+                .iter()
+                .zip(s2.iter())
+                .zip(t1.iter_mut().zip(t0.iter_mut()))
+            {
+                // Power2Round
+                let t = poly::caddq(*t + *s2);
+                *t1 = (t + (1 << (D - 1)) - 1) >> D;
+                *t0 = t - (*t1 << D);
+            }
+            zeroise(&mut t);
+        }
+
+        for s in s1_hat.iter_mut() {
+            zeroise(s);
+        }
+    }
+
+    /// skDecode, plus a check that the private key matches its public part.
+    fn decode(sk: &[u8]) -> Result<Self, Error> {
+        if sk.len() != Params::<K, L>::SK_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let eta = Params::<K, L>::ETA;
+        let eta_len = 32 * Params::<K, L>::ETA_BITS;
+        let (rho, rest) = sk.split_at(32);
+        let (key, rest) = rest.split_at(32);
+        let (tr, rest) = rest.split_at(64);
+        let (s1_bytes, rest) = rest.split_at(eta_len * L);
+        let (s2_bytes, t0_bytes) = rest.split_at(eta_len * K);
+
+        let mut s1 = [[0i32; 256]; L];
+        for (s1, bytes) in s1.iter_mut().zip(s1_bytes.chunks_exact(eta_len)) {
+            unpack(Params::<K, L>::ETA_BITS, bytes, s1, |x| eta - x as i32);
+        }
+        let mut s2 = [[0i32; 256]; K];
+        for (s2, bytes) in s2.iter_mut().zip(s2_bytes.chunks_exact(eta_len)) {
+            unpack(Params::<K, L>::ETA_BITS, bytes, s2, |x| eta - x as i32);
+        }
+        let mut t0 = [[0i32; 256]; K];
+        for (t0, bytes) in t0.iter_mut().zip(t0_bytes.chunks_exact(32 * D)) {
+            unpack(D, bytes, t0, |x| (1 << (D - 1)) - x as i32);
+        }
+
+        let rho: [u8; 32] = rho.try_into().unwrap();
+        let mut t1 = [[0i32; 256]; K];
+        let mut expect_t0 = [[0i32; 256]; K];
+        Self::compute_t(&rho, &s1, &s2, &mut t1, &mut expect_t0);
+
+        let mut public = PublicKey {
+            rho,
+            t1,
+            tr: [0u8; 64],
+        };
+        let mut pk = [0u8; MAX_PK_LEN];
+        let pk = &mut pk[..Params::<K, L>::PK_LEN];
+        public.encode(pk);
+        public.tr = h64(&[pk]);
+
+        let r = Self {
+            key: key.try_into().unwrap(),
+            s1,
+            s2,
+            t0,
+            public,
+        };
+
+        let t0_matches =
+            r.t0.iter()
+                .zip(expect_t0.iter())
+                .all(|(a, b)| a.iter().zip(b.iter()).all(|(a, b)| a == b));
+        for t in expect_t0.iter_mut() {
+            zeroise(t);
+        }
+
+        if !t0_matches || !ct_equal(&r.public.tr, tr) {
+            return Err(KeyFormatError::MismatchedMlDsaPrivateKey.into());
+        }
+
+        Ok(r)
+    }
+
+    /// skEncode
+    fn encode(&self, out: &mut [u8]) {
+        let eta = Params::<K, L>::ETA;
+        let eta_len = 32 * Params::<K, L>::ETA_BITS;
+        let (rho, rest) = out.split_at_mut(32);
+        let (key, rest) = rest.split_at_mut(32);
+        let (tr, rest) = rest.split_at_mut(64);
+        let (s1_bytes, rest) = rest.split_at_mut(eta_len * L);
+        let (s2_bytes, t0_bytes) = rest.split_at_mut(eta_len * K);
+
+        rho.copy_from_slice(&self.public.rho);
+        key.copy_from_slice(&self.key);
+        tr.copy_from_slice(&self.public.tr);
+        for (s1, bytes) in self.s1.iter().zip(s1_bytes.chunks_exact_mut(eta_len)) {
+            pack(Params::<K, L>::ETA_BITS, s1, |x| (eta - x) as u32, bytes);
+        }
+        for (s2, bytes) in self.s2.iter().zip(s2_bytes.chunks_exact_mut(eta_len)) {
+            pack(Params::<K, L>::ETA_BITS, s2, |x| (eta - x) as u32, bytes);
+        }
+        for (t0, bytes) in self.t0.iter().zip(t0_bytes.chunks_exact_mut(32 * D)) {
+            pack(D, t0, |x| ((1 << (D - 1)) - x) as u32, bytes);
+        }
+    }
+
+    /// ML-DSA.Sign_internal, taking the message representative `mu`.
+    fn sign(&self, mu: &[u8; 64], rnd: &[u8; 32], sig: &mut [u8]) {
+        let p = Params::<K, L>::default();
+        let (gamma1, gamma2, beta) = (
+            Params::<K, L>::GAMMA1,
+            Params::<K, L>::GAMMA2,
+            Params::<K, L>::BETA,
+        );
+
+        let mut s1_hat = self.s1;
+        let mut s2_hat = self.s2;
+        let mut t0_hat = self.t0;
+        for p in s1_hat
+            .iter_mut()
+            .chain(s2_hat.iter_mut())
+            .chain(t0_hat.iter_mut())
+        {
+            poly::ntt(p);
+        }
+
+        let mut a = [[[0i32; 256]; L]; K];
+        for (i, row) in a.iter_mut().enumerate() {
+            for (j, a) in row.iter_mut().enumerate() {
+                *a = rej_ntt_poly(&self.public.rho, j as u8, i as u8);
+            }
+        }
+
+        let mut rho_pp = [0u8; 64];
+        h(&[&self.key, rnd, mu], &mut rho_pp);
+
+        let (ctilde, rest) = sig.split_at_mut(Params::<K, L>::CTILDE_LEN);
+        let (z_out, h_out) = rest.split_at_mut(32 * L * Params::<K, L>::Z_BITS);
+
+        let mut kappa = 0u16;
+        let mut y = [[0i32; 256]; L];
+        let mut z = [[0i32; 256]; L];
+        let mut w0 = [[0i32; 256]; K];
+        let mut w1 = [[0i32; 256]; K];
+        let mut hint = [[false; 256]; K];
+        let mut w1_encoded = [0u8; MAX_W1_LEN];
+        let w1_encoded = &mut w1_encoded[..Params::<K, L>::W1_LEN];
+
+        loop {
+            // y = ExpandMask(rho'', kappa)
+            for (r, y) in y.iter_mut().enumerate() {
+                let mut buf = [0u8; 32 * 20];
+                let buf = &mut buf[..32 * Params::<K, L>::Z_BITS];
+                h(&[&rho_pp, &kappa.wrapping_add(r as u16).to_le_bytes()], buf);
+                unpack(Params::<K, L>::Z_BITS, buf, y, |x| gamma1 - x as i32);
+                zeroise(buf);
+            }
+            kappa = kappa.wrapping_add(L as u16);
+
+            // w = A * y
+            let mut y_hat = y;
+            for y in y_hat.iter_mut() {
+                poly::ntt(y);
+            }
+            for ((row, w0), (w1, out)) in a.iter().zip(w0.iter_mut()).zip(
+                w1.iter_mut()
+                    .zip(w1_encoded.chunks_exact_mut(32 * Params::<K, L>::W1_BITS)),
+            ) {
+                let mut w = [0i32; 256];
+                for (a, y) in row.iter().zip(y_hat.iter()) {
+                    poly::pointwise_acc(&mut w, a, y);
+                }
+                for c in w.iter_mut() {
+                    *c = poly::reduce32(*c);
+                }
+                poly::inv_ntt(&mut w);
+                for ((w, w0), w1) in w.iter().zip(w0.iter_mut()).zip(w1.iter_mut()) {
+                    (*w1, *w0) = p.decompose(poly::caddq(*w));
+                }
+                pack(Params::<K, L>::W1_BITS, w1, |x| x as u32, out);
+            }
+            for y in y_hat.iter_mut() {
+                zeroise(y);
+            }
+
+            h(&[mu, w1_encoded], ctilde);
+            let mut c = sample_in_ball(Params::<K, L>::TAU, ctilde);
+            poly::ntt(&mut c);
+
+            // z = y + c * s1
+            let mut reject = false;
+            for ((z, s1), y) in z.iter_mut().zip(s1_hat.iter()).zip(y.iter()) {
+                *z = [0i32; 256];
+                poly::pointwise_acc(z, &c, s1);
+                poly::inv_ntt(z);
+                for (z, y) in z.iter_mut().zip(y.iter()) {
+                    *z = poly::reduce32(*z + *y);
+                }
+                reject |= exceeds_norm(z, gamma1 - beta);
+            }
+            if low::ct::into_public(reject) {
+                continue;
+            }
+
+            // r0 = LowBits(w - c * s2)
+            for (w0, s2) in w0.iter_mut().zip(s2_hat.iter()) {
+                let mut cs2 = [0i32; 256];
+                poly::pointwise_acc(&mut cs2, &c, s2);
+                poly::inv_ntt(&mut cs2);
+                for (w0, cs2) in w0.iter_mut().zip(cs2.iter()) {
+                    *w0 = poly::reduce32(*w0 - *cs2);
+                }
+                reject |= exceeds_norm(w0, gamma2 - beta);
+                zeroise(&mut cs2);
+            }
+            if low::ct::into_public(reject) {
+                continue;
+            }
+
+            // hint = MakeHint(-c * t0, w - c * s2 + c * t0)
+            let mut hints = 0;
+            for ((w0, t0), (w1, hint)) in w0
+                .iter_mut()
+                .zip(t0_hat.iter())
+                .zip(w1.iter().zip(hint.iter_mut()))
+            {
+                let mut ct0 = [0i32; 256];
+                poly::pointwise_acc(&mut ct0, &c, t0);
+                poly::inv_ntt(&mut ct0);
+                for c in ct0.iter_mut() {
+                    *c = poly::reduce32(*c);
+                }
+                reject |= exceeds_norm(&ct0, gamma2);
+
+                for (((w0, ct0), w1), hint) in w0
+                    .iter_mut()
+                    .zip(ct0.iter())
+                    .zip(w1.iter())
+                    .zip(hint.iter_mut())
+                {
+                    *w0 += *ct0;
+                    *hint = p.make_hint(*w0, *w1);
+                    hints += *hint as usize;
+                }
+                zeroise(&mut ct0);
+            }
+            if low::ct::into_public(reject || hints > Params::<K, L>::OMEGA) {
+                continue;
+            }
+
+            break;
+        }
+
+        // sigEncode
+        for (z, out) in z
+            .iter()
+            .zip(z_out.chunks_exact_mut(32 * Params::<K, L>::Z_BITS))
+        {
+            pack(Params::<K, L>::Z_BITS, z, |x| (gamma1 - x) as u32, out);
+        }
+        p.hint_encode(&hint, h_out);
+
+        for p in s1_hat
+            .iter_mut()
+            .chain(y.iter_mut())
+            .chain(z.iter_mut())
+            .chain(s2_hat.iter_mut())
+            .chain(t0_hat.iter_mut())
+            .chain(w0.iter_mut())
+        {
+            zeroise(p);
+        }
+        zeroise(&mut rho_pp);
+    }
+}
+
+impl<const K: usize, const L: usize> Drop for PrivateKey<K, L> {
+    fn drop(&mut self) {
+        zeroise(&mut self.key);
+        for p in self
+            .s1
+            .iter_mut()
+            .chain(self.s2.iter_mut())
+            .chain(self.t0.iter_mut())
+        {
+            zeroise(p);
+        }
+    }
+}
+
+impl<const K: usize, const L: usize> Params<K, L> {
+    // (taking `self` makes the call sites much more readable)
+    const fn default() -> Self {
+        Self
+    }
+
+    /// Decompose, for `a` in `[0, q)`.  Returns `(r1, r0)`.
+    fn decompose(&self, a: i32) -> (i32, i32) {
+        let mut a1 = (a + 127) >> 7;
+        if Self::GAMMA2 == (Q - 1) / 32 {
+            a1 = (a1 * 1025 + (1 << 21)) >> 22;
+            a1 &= 15;
+        } else {
+            a1 = (a1 * 11275 + (1 << 23)) >> 24;
+            a1 ^= ((43 - a1) >> 31) & a1;
+        }
+        let mut a0 = a - a1 * 2 * Self::GAMMA2;
+        a0 -= (((Q - 1) / 2 - a0) >> 31) & Q;
+        (a1, a0)
+    }
+
+    /// MakeHint, given the low bits `a0` and high bits `a1` of
+    /// `w - c * s2 + c * t0`.
+    fn make_hint(&self, a0: i32, a1: i32) -> bool {
+        a0 > Self::GAMMA2 || a0 < -Self::GAMMA2 || (a0 == -Self::GAMMA2 && a1 != 0)
+    }
+
+    /// UseHint, for `a` in `[0, q)`.
+    fn use_hint(&self, a: i32, hint: bool) -> i32 {
+        let (a1, a0) = self.decompose(a);
+        match (hint, Self::GAMMA2 == (Q - 1) / 32) {
+            (false, _) => a1,
+            (true, true) if a0 > 0 => (a1 + 1) & 15,
+            (true, true) => (a1 - 1) & 15,
+            (true, false) if a0 > 0 => match a1 {
+                43 => 0,
+                _ => a1 + 1,
+            },
+            (true, false) => match a1 {
+                0 => 43,
+                _ => a1 - 1,
+            },
+        }
+    }
+
+    /// HintBitPack
+    fn hint_encode(&self, hint: &[[bool; 256]; K], out: &mut [u8]) {
+        out.fill(0);
+        let mut index = 0;
+        for (i, h) in hint.iter().enumerate() {
+            for (j, h) in h.iter().enumerate() {
+                if *h {
+                    out[index] = j as u8;
+                    index += 1;
+                }
+            }
+            out[Self::OMEGA + i] = index as u8;
+        }
+    }
+
+    /// HintBitUnpack
+    fn hint_decode(&self, bytes: &[u8]) -> Result<[[bool; 256]; K], Error> {
+        let mut hint = [[false; 256]; K];
+        let mut index = 0;
+        for (i, h) in hint.iter_mut().enumerate() {
+            let end = bytes[Self::OMEGA + i] as usize;
+            if end < index || end > Self::OMEGA {
+                return Err(Error::BadSignature);
+            }
+
+            let first = index;
+            while index < end {
+                if index > first && bytes[index - 1] >= bytes[index] {
+                    return Err(Error::BadSignature);
+                }
+                h[bytes[index] as usize] = true;
+                index += 1;
+            }
+        }
+
+        match bytes[index..Self::OMEGA].iter().all(|b| *b == 0) {
+            true => Ok(hint),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// H: SHAKE256, filling `out`.
+fn h(inputs: &[&[u8]], out: &mut [u8]) {
+    let mut ctx = Shake256::new();
+    for i in inputs {
+        ctx.update(i);
+    }
+    ctx.squeeze(out);
+}
+
+fn h64(inputs: &[&[u8]]) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    h(inputs, &mut out);
+    out
+}
+
+/// Computes the message representative `mu` from `tr`, the context and message.
+fn message_representative(tr: &[u8; 64], context: &[u8], message: &[&[u8]]) -> [u8; 64] {
+    let mut ctx = Shake256::new();
+    ctx.update(tr);
+    // M' = 0 || |ctx| || ctx || M
+    ctx.update(&[0, context.len() as u8]);
+    ctx.update(context);
+    for m in message {
+        ctx.update(m);
+    }
+    let mut mu = [0u8; 64];
+    ctx.squeeze(&mut mu);
+    mu
+}
+
+/// RejNTTPoly, from the XOF input `rho || s || r`.
+///
+/// The matrix entry `A[r][s]` is `rej_ntt_poly(rho, s, r)`.
+fn rej_ntt_poly(rho: &[u8; 32], s: u8, r: u8) -> Poly {
+    let mut xof = Shake128::new();
+    xof.update(rho);
+    xof.update(&[s, r]);
+
+    let mut a = [0i32; 256];
+    let mut n = 0;
+    let mut buf = [0u8; Shake128::BLOCK_SZ];
+    while n < a.len() {
+        xof.squeeze(&mut buf);
+        for c in buf.chunks_exact(3) {
+            let t = c[0] as i32 | (c[1] as i32) << 8 | (c[2] as i32 & 0x7f) << 16;
+            if t < Q && n < a.len() {
+                a[n] = t;
+                n += 1;
+            }
+        }
+    }
+    a
+}
+
+/// RejBoundedPoly, from the XOF input `rho' || nonce`.
+fn rej_bounded_poly(eta: i32, rho_prime: &[u8], nonce: u16) -> Poly {
+    let mut xof = Shake256::new();
+    xof.update(rho_prime);
+    xof.update(&nonce.to_le_bytes());
+
+    let mut a = [0i32; 256];
+    let mut n = 0;
+    let mut buf = [0u8; Shake256::BLOCK_SZ];
+    while n < a.len() {
+        xof.squeeze(&mut buf);
+        for b in buf.iter() {
+            for t in [(*b & 0xf) as i32, (*b >> 4) as i32] {
+                if n == a.len() {
+                    break;
+                }
+                if eta == 2 && t < 15 {
+                    // t mod 5, avoiding division
+                    a[n] = 2 - (t - ((205 * t) >> 10) * 5);
+                    n += 1;
+                } else if eta == 4 && t < 9 {
+                    a[n] = 4 - t;
+                    n += 1;
+                }
+            }
+        }
+    }
+    zeroise(&mut buf);
+    a
+}
+
+/// SampleInBall
+fn sample_in_ball(tau: usize, seed: &[u8]) -> Poly {
+    let mut xof = Shake256::new();
+    xof.update(seed);
+    let mut signs = [0u8; 8];
+    xof.squeeze(&mut signs);
+    let mut signs = u64::from_le_bytes(signs);
+
+    let mut c = [0i32; 256];
+    for i in 256 - tau..256 {
+        let j = loop {
+            let mut j = [0u8];
+            xof.squeeze(&mut j);
+            if j[0] as usize <= i {
+                break j[0] as usize;
+            }
+        };
+        c[i] = c[j];
+        c[j] = 1 - 2 * (signs & 1) as i32;
+        signs >>= 1;
+    }
+    c
+}
+
+/// Returns true if any coefficient of `a` is `bound` or more in absolute value.
+///
+/// Coefficients of `a` must be reduced with `reduce32`.
+fn exceeds_norm(a: &Poly, bound: i32) -> bool {
+    let mut r = 0;
+    for c in a {
+        let abs = *c - ((*c >> 31) & (2 * *c));
+        r |= (bound - 1 - abs) >> 31;
+    }
+    r != 0
+}
+
+/// Packs `f(a[i])` into `bits` bits each, little-endian.
+fn pack(bits: usize, a: &Poly, f: impl Fn(i32) -> u32, out: &mut [u8]) {
+    debug_assert_eq!(out.len(), 32 * bits);
+    let mut acc = 0u64;
+    let mut have = 0;
+    let mut out = out.iter_mut();
+    for c in a {
+        acc |= (f(*c) as u64) << have;
+        have += bits;
+        while have >= 8 {
+            *out.next().unwrap() = acc as u8;
+            acc >>= 8;
+            have -= 8;
+        }
+    }
+}
+
+/// Unpacks `bits`-bit values `x`, setting `a[i]` to `f(x)`.
+fn unpack(bits: usize, bytes: &[u8], a: &mut Poly, f: impl Fn(u32) -> i32) {
+    debug_assert_eq!(bytes.len(), 32 * bits);
+    let mask = (1u64 << bits) - 1;
+    let mut acc = 0u64;
+    let mut have = 0;
+    let mut bytes = bytes.iter();
+    for c in a.iter_mut() {
+        while have < bits {
+            acc |= (*bytes.next().unwrap() as u64) << have;
+            have += 8;
+        }
+        *c = f((acc & mask) as u32);
+        acc >>= bits;
+        have -= bits;
+    }
+}
+
+macro_rules! ml_dsa {
+    ($name:ident, $k:literal, $l:literal, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $name {
+            use super::*;
+
+            const K: usize = $k;
+            const L: usize = $l;
+
+            /// The length of a verifying key, in bytes.
+            pub const VERIFYING_KEY_LEN: usize = Params::<K, L>::PK_LEN;
+
+            /// The length of an expanded signing key, in bytes.
+            pub const SIGNING_KEY_LEN: usize = Params::<K, L>::SK_LEN;
+
+            /// The length of a signature, in bytes.
+            pub const SIGNATURE_LEN: usize = Params::<K, L>::SIG_LEN;
+
+            /// A signing (private) key.
+            pub struct SigningKey(PrivateKey<K, L>);
+
+            impl SigningKey {
+                /// Generate a new key using the system random number generator.
+                ///
+                /// Fails only if the random source fails.
+                pub fn new_random() -> Result<Self, Error> {
+                    Self::generate(&mut SystemRandom)
+                }
+
+                /// Generate a new key using `rng`.
+                ///
+                /// Fails only if the random source fails.
+                pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+                    let mut seed = [0u8; 32];
+                    rng.fill(&mut seed)?;
+                    let key = Self::from_seed(&seed);
+                    zeroise(&mut seed);
+                    Ok(key)
+                }
+
+                /// Deterministically derive a key from a 32-byte seed.
+                ///
+                /// The seed is `ξ` in the notation of FIPS204.  This is
+                /// the most compact way to store a signing key.
+                pub fn from_seed(seed: &[u8; 32]) -> Self {
+                    let _entry = Entry::new_secret();
+                    Self(PrivateKey::generate(seed))
+                }
+
+                /// Decode an expanded signing key.
+                ///
+                /// This fails with [`Error::WrongLength`] if `bytes` is not
+                /// [`SIGNING_KEY_LEN`] bytes long, or with another error if
+                /// the encoded public key information does not match the
+                /// private key.
+                pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                    let _entry = Entry::new_secret();
+                    PrivateKey::decode(bytes).map(Self)
+                }
+
+                /// Encode this key in its expanded form.
+                pub fn to_bytes(&self) -> [u8; SIGNING_KEY_LEN] {
+                    let _entry = Entry::new_secret();
+                    let mut out = [0u8; SIGNING_KEY_LEN];
+                    self.0.encode(&mut out);
+                    out
+                }
+
+                /// Return the matching verifying key.
+                pub fn verifying_key(&self) -> VerifyingKey {
+                    let _entry = Entry::new_public();
+                    VerifyingKey::from_public(self.0.public.clone())
+                }
+
+                /// Sign `message` with the "hedged" variant of ML-DSA.
+                ///
+                /// `context` is the context string, which may be empty,
+                /// and must be no more than 255 bytes.  The message is a
+                /// sequence of byte slices, so some workloads can avoid
+                /// joining it into one buffer beforehand.
+                ///
+                /// The hedged variant mixes fresh randomness into the
+                /// signature, which is recommended by FIPS204.
+                ///
+                /// Fails if `context` is too long or the random source fails.
+                pub fn sign(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                ) -> Result<[u8; SIGNATURE_LEN], Error> {
+                    self.sign_with_rng(context, message, &mut SystemRandom)
+                }
+
+                /// Sign `message` with the hedged variant of ML-DSA,
+                /// taking the fresh randomness from `rng`.
+                ///
+                /// See [`Self::sign()`] for the meaning of the other arguments.
+                ///
+                /// Fails if `context` is too long or `rng` fails.
+                pub fn sign_with_rng(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                    rng: &mut dyn RandomSource,
+                ) -> Result<[u8; SIGNATURE_LEN], Error> {
+                    let _entry = Entry::new_secret();
+                    let mut rnd = [0u8; 32];
+                    rng.fill(&mut rnd)?;
+                    let rnd = low::ct::into_secret(rnd);
+                    self.sign_with_rnd(context, message, &rnd)
+                }
+
+                /// Sign `message` with the deterministic variant of ML-DSA.
+                ///
+                /// See [`Self::sign()`] for the meaning of the arguments.
+                ///
+                /// Fails if `context` is too long.
+                pub fn sign_deterministic(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                ) -> Result<[u8; SIGNATURE_LEN], Error> {
+                    let _entry = Entry::new_secret();
+                    self.sign_with_rnd(context, message, &[0u8; 32])
+                }
+
+                fn sign_with_rnd(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                    rnd: &[u8; 32],
+                ) -> Result<[u8; SIGNATURE_LEN], Error> {
+                    if context.len() > 255 {
+                        return Err(Error::WrongLength);
+                    }
+                    let mu = message_representative(&self.0.public.tr, context, message);
+                    let mut signature = [0u8; SIGNATURE_LEN];
+                    self.0.sign(&mu, rnd, &mut signature);
+                    Ok(signature)
+                }
+            }
+
+            /// A verifying (public) key.
+            #[derive(Clone)]
+            pub struct VerifyingKey {
+                public: PublicKey<K, L>,
+                bytes: [u8; VERIFYING_KEY_LEN],
+            }
+
+            impl VerifyingKey {
+                /// Decode a verifying key.
+                ///
+                /// This fails with [`Error::WrongLength`] if `bytes` is not
+                /// [`VERIFYING_KEY_LEN`] bytes long.
+                pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                    let _entry = Entry::new_public();
+                    PublicKey::decode(bytes).map(Self::from_public)
+                }
+
+                /// Return the encoding of this key.
+                pub fn as_bytes(&self) -> &[u8; VERIFYING_KEY_LEN] {
+                    &self.bytes
+                }
+
+                /// Verify `signature` over `message` and `context`.
+                ///
+                /// See [`SigningKey::sign()`] for the meaning of `context`
+                /// and `message`.
+                ///
+                /// Returns `Err(Error::BadSignature)` if the signature is
+                /// invalid, or `Err(Error::WrongLength)` if `signature` or
+                /// `context` have an invalid length.
+                pub fn verify(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                    signature: &[u8],
+                ) -> Result<(), Error> {
+                    let _entry = Entry::new_public();
+                    if context.len() > 255 {
+                        return Err(Error::WrongLength);
+                    }
+                    let mu = message_representative(&self.public.tr, context, message);
+                    self.public.verify(&mu, signature)
+                }
+
+                fn from_public(public: PublicKey<K, L>) -> Self {
+                    let mut bytes = [0u8; VERIFYING_KEY_LEN];
+                    public.encode(&mut bytes);
+                    Self { public, bytes }
+                }
+            }
+        }
+    };
+}
+
+ml_dsa!(
+    ml_dsa_44,
+    4,
+    4,
+    "ML-DSA-44, which targets NIST security category 2."
+);
+ml_dsa!(
+    ml_dsa_65,
+    6,
+    5,
+    "ML-DSA-65, which targets NIST security category 3."
+);
+ml_dsa!(
+    ml_dsa_87,
+    8,
+    7,
+    "ML-DSA-87, which targets NIST security category 5."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::sha2::Sha256Context;
+
+    fn sha256(bytes: &[u8]) -> String {
+        let mut ctx = Sha256Context::new();
+        ctx.update(bytes);
+        ctx.finish().iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn seed() -> [u8; 32] {
+        core::array::from_fn(|i| i as u8)
+    }
+
+    // These test vectors were produced with OpenSSL 3.5, using the seed
+    // `00 01 02 .. 1f`, and deterministic signing of "message" with
+    // and without the context "ctx".
+    macro_rules! kat_test {
+        ($name:ident, $module:ident, $pk:literal, $sk:literal, $sig:literal, $sig_no_ctx:literal) => {
+            #[test]
+            fn $name() {
+                use $module::*;
+
+                let sk = SigningKey::from_seed(&seed());
+                let pk = sk.verifying_key();
+                assert_eq!(sha256(pk.as_bytes()), $pk);
+                let sk_bytes = sk.to_bytes();
+                assert_eq!(sha256(&sk_bytes), $sk);
+
+                let sig = sk.sign_deterministic(b"ctx", &[b"message"]).unwrap();
+                assert_eq!(sha256(&sig), $sig);
+                let sig_no_ctx = sk.sign_deterministic(b"", &[b"mess", b"age"]).unwrap();
+                assert_eq!(sha256(&sig_no_ctx), $sig_no_ctx);
+
+                let pk = VerifyingKey::from_bytes(pk.as_bytes()).unwrap();
+                pk.verify(b"ctx", &[b"message"], &sig).unwrap();
+                pk.verify(b"", &[b"message"], &sig_no_ctx).unwrap();
+                assert_eq!(
+                    pk.verify(b"", &[b"message"], &sig),
+                    Err(Error::BadSignature)
+                );
+                assert_eq!(
+                    pk.verify(b"ctx", &[b"massage"], &sig),
+                    Err(Error::BadSignature)
+                );
+
+                // every byte of the signature matters
+                for i in (0..SIGNATURE_LEN).step_by(97) {
+                    let mut bad = sig;
+                    bad[i] ^= 0x10;
+                    assert!(pk.verify(b"ctx", &[b"message"], &bad).is_err());
+                }
+                assert_eq!(
+                    pk.verify(b"ctx", &[b"message"], &sig[1..]),
+                    Err(Error::WrongLength)
+                );
+
+                // hedged signatures differ, but verify
+                let sk = SigningKey::from_bytes(&sk_bytes).unwrap();
+                let hedged = sk.sign(b"ctx", &[b"message"]).unwrap();
+                assert_ne!(hedged, sig);
+                pk.verify(b"ctx", &[b"message"], &hedged).unwrap();
+
+                assert_eq!(
+                    sk.sign(&[0u8; 256], &[b"message"]).err(),
+                    Some(Error::WrongLength)
+                );
+
+                // key consistency checks
+                let mut bad_sk = sk_bytes;
+                bad_sk[100] ^= 1;
+                assert_eq!(
+                    SigningKey::from_bytes(&bad_sk).err(),
+                    Some(KeyFormatError::MismatchedMlDsaPrivateKey.into())
+                );
+                let mut bad_sk = sk_bytes;
+                bad_sk[SIGNING_KEY_LEN - 1] ^= 1;
+                assert_eq!(
+                    SigningKey::from_bytes(&bad_sk).err(),
+                    Some(KeyFormatError::MismatchedMlDsaPrivateKey.into())
+                );
+                assert!(SigningKey::from_bytes(&sk_bytes[1..]).is_err());
+                assert!(VerifyingKey::from_bytes(&pk.as_bytes()[1..]).is_err());
+            }
+        };
+    }
+
+    kat_test!(
+        ml_dsa_44_kat,
+        ml_dsa_44,
+        "9f107644c1084526af3bc8098680b05499a2325a644e388fb4f970e058d19d46",
+        "04bf6b9f579166a627961dfc5c3bf9717df868db88863856356c4668c8b56b0b",
+        "3bb052bd420004053a6d200f368a041ad88609807e58cedbccba184e9eb4f16d",
+        "d764b00526ae8e81071a8f0d25ec52588642a0eae1ebe79e23aaebd1c48f82eb"
+    );
+
+    kat_test!(
+        ml_dsa_65_kat,
+        ml_dsa_65,
+        "d666806e11cee19a7c989f7445f90dd419cf4d2d51db8c0fdb4c0f0a542238c9",
+        "9f1e24f47795fe50040384e3d6183988047170fa2d866406b70fe0a3f8216063",
+        "244a863431d81ee343fbdb1b4baf144e965df5b0b130258109720d83586f0071",
+        "528fbdd2627565d4bf5caede411725759b235999ce0d91a92a3eaba020906ef4"
+    );
+
+    kat_test!(
+        ml_dsa_87_kat,
+        ml_dsa_87,
+        "91dc389cfaa01470b7f66eee45a4ae9026d154817c754dfe22298b3fa241ffcd",
+        "764d3e223ed90c07bc91a0ab6ecd170e5c66ffe39f7039298596039a36005435",
+        "9a6ed4f10e6350428f89be3a1161a44513696e3d5696a49747492e73a29923ba",
+        "b20f48bbdf5ddaef42efc83ccf823ca8e74d7692c3c61968d80d8f8385b6594b"
+    );
+
+    #[test]
+    fn hint_decoding_is_strict() {
+        type P = Params<4, 4>;
+        let p = P::default();
+        let mut hint = [[false; 256]; 4];
+        hint[0][3] = true;
+        hint[0][7] = true;
+        hint[2][0] = true;
+        let mut bytes = [0u8; P::OMEGA + 4];
+        p.hint_encode(&hint, &mut bytes);
+        assert_eq!(p.hint_decode(&bytes).unwrap(), hint);
+
+        // indices must be increasing
+        let mut bad = bytes;
+        bad.swap(0, 1);
+        assert!(p.hint_decode(&bad).is_err());
+
+        // unused indices must be zero
+        let mut bad = bytes;
+        bad[P::OMEGA - 1] = 1;
+        assert!(p.hint_decode(&bad).is_err());
+
+        // counts must not decrease, or exceed omega
+        let mut bad = bytes;
+        bad[P::OMEGA + 1] = 1;
+        assert!(p.hint_decode(&bad).is_err());
+        let mut bad = bytes;
+        bad[P::OMEGA + 3] = P::OMEGA as u8 + 1;
+        assert!(p.hint_decode(&bad).is_err());
+    }
+}
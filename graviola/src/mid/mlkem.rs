@@ -0,0 +1,779 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! ML-KEM, as specified in
+//! [FIPS203](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.203.pdf).
+//!
+//! The three parameter sets are in separate modules, which share
+//! the implementation here.  Internally, the parameter sets are
+//! distinguished by the module rank `K`.
+
+use crate::Error;
+use crate::error::KeyFormatError;
+use crate::low::{self, Entry, ct_equal, mlkem as poly, zeroise};
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::sha3::{Sha3_256, Sha3_512, Shake128, Shake256};
+
+type Poly = [i16; 256];
+
+const Q: i16 = poly::Q;
+
+/// Sizes and parameters for the parameter set with module rank `K`.
+struct Params<const K: usize>;
+
+impl<const K: usize> Params<K> {
+    const ETA1: usize = if K == 2 { 3 } else { 2 };
+    const ETA2: usize = 2;
+    const DU: usize = if K == 4 { 11 } else { 10 };
+    const DV: usize = if K == 4 { 5 } else { 4 };
+
+    const ENCODED_VECTOR_LEN: usize = 384 * K;
+    const EK_LEN: usize = Self::ENCODED_VECTOR_LEN + 32;
+    const DK_LEN: usize = Self::ENCODED_VECTOR_LEN + Self::EK_LEN + 64;
+    const CT_LEN: usize = 32 * (Self::DU * K + Self::DV);
+}
+
+/// The largest `CT_LEN`, used to size a buffer for re-encryption.
+const MAX_CT_LEN: usize = Params::<4>::CT_LEN;
+
+/// A shared secret agreed by ML-KEM.
+pub struct SharedSecret(pub [u8; 32]);
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        zeroise(&mut self.0);
+    }
+}
+
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The public part of a key, as a vector in the NTT domain plus the seed for the matrix.
+#[derive(Clone)]
+struct PublicKey<const K: usize> {
+    t_hat: [Poly; K],
+    rho: [u8; 32],
+    /// H(ek)
+    h: [u8; 32],
+}
+
+impl<const K: usize> PublicKey<K> {
+    /// Decode an encapsulation key, performing the modulus check of FIPS203 section 7.2.
+    fn decode(ek: &[u8]) -> Result<Self, Error> {
+        if ek.len() != Params::<K>::EK_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let (encoded, rho) = ek.split_at(Params::<K>::ENCODED_VECTOR_LEN);
+        let mut t_hat = [[0i16; 256]; K];
+        for (t, bytes) in t_hat.iter_mut().zip(encoded.chunks_exact(384)) {
+            byte_decode(12, bytes, t);
+            if t.iter().any(|c| *c >= Q) {
+                return Err(Error::OutOfRange);
+            }
+        }
+
+        Ok(Self {
+            t_hat,
+            rho: rho.try_into().unwrap(),
+            h: Sha3_256::hash(ek),
+        })
+    }
+
+    fn encode(&self, out: &mut [u8]) {
+        let (encoded, rho) = out.split_at_mut(Params::<K>::ENCODED_VECTOR_LEN);
+        for (t, bytes) in self.t_hat.iter().zip(encoded.chunks_exact_mut(384)) {
+            byte_encode(12, t, bytes);
+        }
+        rho.copy_from_slice(&self.rho);
+    }
+
+    /// K-PKE.Encrypt, writing the ciphertext to `ct`.
+    fn encrypt(&self, m: &[u8; 32], r: &[u8; 32], ct: &mut [u8]) {
+        let mut y = [[0i16; 256]; K];
+        let mut e1 = [[0i16; 256]; K];
+        for (i, (y, e1)) in y.iter_mut().zip(e1.iter_mut()).enumerate() {
+            *y = sample_cbd(Params::<K>::ETA1, r, i as u8);
+            *e1 = sample_cbd(Params::<K>::ETA2, r, (K + i) as u8);
+        }
+        let mut e2 = sample_cbd(Params::<K>::ETA2, r, (2 * K) as u8);
+
+        for y in y.iter_mut() {
+            poly::ntt(y);
+        }
+
+        let (ct_u, ct_v) = ct.split_at_mut(32 * Params::<K>::DU * K);
+
+        for (i, (e1, ct_u)) in e1
+            .iter()
+            .zip(ct_u.chunks_exact_mut(32 * Params::<K>::DU))
+            .enumerate()
+        {
+            let mut u = [0i16; 256];
+            for (j, y) in y.iter().enumerate() {
+                // A^T[i][j] = A[j][i]
+                poly::basemul_acc(&mut u, &sample_ntt(&self.rho, i as u8, j as u8), y);
+            }
+            poly::reduce(&mut u);
+            poly::inv_ntt(&mut u);
+            add_assign(&mut u, e1);
+            poly::reduce(&mut u);
+            compress_encode(Params::<K>::DU, &u, ct_u);
+        }
+
+        let mut v = [0i16; 256];
+        for (t, y) in self.t_hat.iter().zip(y.iter()) {
+            poly::basemul_acc(&mut v, t, y);
+        }
+        poly::reduce(&mut v);
+        poly::inv_ntt(&mut v);
+        let mut mu = [0i16; 256];
+        decode_decompress(1, m, &mut mu);
+        add_assign(&mut v, &e2);
+        add_assign(&mut v, &mu);
+        poly::reduce(&mut v);
+        compress_encode(Params::<K>::DV, &v, ct_v);
+
+        for p in y.iter_mut().chain(e1.iter_mut()) {
+            zeroise(p);
+        }
+        zeroise(&mut e2);
+        zeroise(&mut mu);
+        zeroise(&mut v);
+    }
+
+    /// ML-KEM.Encaps_internal, writing the ciphertext to `ct`.
+    fn encapsulate(&self, m: &[u8; 32], ct: &mut [u8]) -> SharedSecret {
+        let (mut k, mut r) = g(&[m, &self.h]);
+        self.encrypt(m, &r, ct);
+        zeroise(&mut r);
+        let ss = SharedSecret(k);
+        zeroise(&mut k);
+        ss
+    }
+}
+
+/// The private part of a key, plus its public key.
+struct PrivateKey<const K: usize> {
+    s_hat: [Poly; K],
+    z: [u8; 32],
+    public: PublicKey<K>,
+}
+
+impl<const K: usize> PrivateKey<K> {
+    /// ML-KEM.KeyGen_internal
+    fn generate(d: &[u8; 32], z: &[u8; 32]) -> Self {
+        let (rho, mut sigma) = g(&[d, &[K as u8]]);
+
+        let mut s_hat = [[0i16; 256]; K];
+        let mut e_hat = [[0i16; 256]; K];
+        for (i, (s, e)) in s_hat.iter_mut().zip(e_hat.iter_mut()).enumerate() {
+            *s = sample_cbd(Params::<K>::ETA1, &sigma, i as u8);
+            *e = sample_cbd(Params::<K>::ETA1, &sigma, (K + i) as u8);
+            poly::ntt(s);
+            poly::ntt(e);
+        }
+        zeroise(&mut sigma);
+
+        let mut t_hat = [[0i16; 256]; K];
+        for (i, (t, e)) in t_hat.iter_mut().zip(e_hat.iter_mut()).enumerate() {
+            for (j, s) in s_hat.iter().enumerate() {
+                poly::basemul_acc(t, &sample_ntt(&rho, j as u8, i as u8), s);
+            }
+            poly::reduce(t);
+            poly::to_mont(t);
+            add_assign(t, e);
+            poly::reduce(t);
+            normalize(t);
+            zeroise(e);
+        }
+
+        for s in s_hat.iter_mut() {
+            normalize(s);
+        }
+
+        let mut public = PublicKey {
+            t_hat,
+            rho,
+            h: [0u8; 32],
+        };
+        let mut ek = [0u8; Params::<4>::EK_LEN];
+        let ek = &mut ek[..Params::<K>::EK_LEN];
+        public.encode(ek);
+        public.h = Sha3_256::hash(ek);
+
+        Self {
+            s_hat,
+            z: *z,
+            public,
+        }
+    }
+
+    /// Decode an (expanded) decapsulation key, performing the hash check of
+    /// FIPS203 section 7.3.
+    fn decode(dk: &[u8]) -> Result<Self, Error> {
+        if dk.len() != Params::<K>::DK_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let (dk_pke, rest) = dk.split_at(Params::<K>::ENCODED_VECTOR_LEN);
+        let (ek, rest) = rest.split_at(Params::<K>::EK_LEN);
+        let (h, z) = rest.split_at(32);
+
+        let public = PublicKey::decode(ek)?;
+        if !ct_equal(&public.h, h) {
+            return Err(KeyFormatError::MismatchedMlKemPublicKeyHash.into());
+        }
+
+        let mut s_hat = [[0i16; 256]; K];
+        for (s, bytes) in s_hat.iter_mut().zip(dk_pke.chunks_exact(384)) {
+            byte_decode(12, bytes, s);
+        }
+
+        Ok(Self {
+            s_hat,
+            z: z.try_into().unwrap(),
+            public,
+        })
+    }
+
+    fn encode(&self, out: &mut [u8]) {
+        let (dk_pke, rest) = out.split_at_mut(Params::<K>::ENCODED_VECTOR_LEN);
+        let (ek, rest) = rest.split_at_mut(Params::<K>::EK_LEN);
+        let (h, z) = rest.split_at_mut(32);
+
+        for (s, bytes) in self.s_hat.iter().zip(dk_pke.chunks_exact_mut(384)) {
+            byte_encode(12, s, bytes);
+        }
+        self.public.encode(ek);
+        h.copy_from_slice(&self.public.h);
+        z.copy_from_slice(&self.z);
+    }
+
+    /// K-PKE.Decrypt
+    fn decrypt(&self, ct: &[u8]) -> [u8; 32] {
+        let (ct_u, ct_v) = ct.split_at(32 * Params::<K>::DU * K);
+
+        let mut w = [0i16; 256];
+        for (s, ct_u) in self
+            .s_hat
+            .iter()
+            .zip(ct_u.chunks_exact(32 * Params::<K>::DU))
+        {
+            let mut u = [0i16; 256];
+            decode_decompress(Params::<K>::DU, ct_u, &mut u);
+            poly::ntt(&mut u);
+            poly::basemul_acc(&mut w, s, &u);
+        }
+        poly::reduce(&mut w);
+        poly::inv_ntt(&mut w);
+
+        let mut v = [0i16; 256];
+        decode_decompress(Params::<K>::DV, ct_v, &mut v);
+        for (v, w) in v.iter_mut().zip(w.iter()) {
+            *v = v.wrapping_sub(*w);
+        }
+        poly::reduce(&mut v);
+
+        let mut m = [0u8; 32];
+        compress_encode(1, &v, &mut m);
+        zeroise(&mut v);
+        zeroise(&mut w);
+        m
+    }
+
+    /// ML-KEM.Decaps_internal
+    fn decapsulate(&self, ct: &[u8]) -> SharedSecret {
+        let mut m = self.decrypt(ct);
+        let (mut k, mut r) = g(&[&m, &self.public.h]);
+        let mut k_bar = j(&self.z, ct);
+
+        let mut ct_prime = [0u8; MAX_CT_LEN];
+        let ct_prime = &mut ct_prime[..ct.len()];
+        self.public.encrypt(&m, &r, ct_prime);
+
+        // implicit rejection
+        let mask = 0u8.wrapping_sub(ct_equal(ct, ct_prime) as u8);
+        let mut ss = SharedSecret([0u8; 32]);
+        for ((ss, k), k_bar) in ss.0.iter_mut().zip(k.iter()).zip(k_bar.iter()) {
+            *ss = (k & mask) | (k_bar & !mask);
+        }
+
+        zeroise(&mut m);
+        zeroise(&mut k);
+        zeroise(&mut r);
+        zeroise(&mut k_bar);
+        ss
+    }
+}
+
+impl<const K: usize> Drop for PrivateKey<K> {
+    fn drop(&mut self) {
+        for s in self.s_hat.iter_mut() {
+            zeroise(s);
+        }
+        zeroise(&mut self.z);
+    }
+}
+
+/// G: SHA3-512, split into two 32-byte outputs.
+fn g(inputs: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut ctx = Sha3_512::new();
+    for i in inputs {
+        ctx.update(i);
+    }
+    let mut out = ctx.finish();
+    let (a, b) = out.split_at(32);
+    let r = (a.try_into().unwrap(), b.try_into().unwrap());
+    zeroise(&mut out);
+    r
+}
+
+/// J: SHAKE256 with a 32-byte output.
+fn j(z: &[u8; 32], ct: &[u8]) -> [u8; 32] {
+    let mut ctx = Shake256::new();
+    ctx.update(z);
+    ctx.update(ct);
+    let mut out = [0u8; 32];
+    ctx.squeeze(&mut out);
+    out
+}
+
+/// SampleNTT, from the XOF input `rho || x || y`.
+///
+/// The matrix entry `A[i][j]` is `sample_ntt(rho, j, i)`.
+fn sample_ntt(rho: &[u8; 32], x: u8, y: u8) -> Poly {
+    let mut xof = Shake128::new();
+    xof.update(rho);
+    xof.update(&[x, y]);
+
+    let mut r = [0i16; 256];
+    let mut n = 0;
+    let mut buf = [0u8; Shake128::BLOCK_SZ];
+    while n < r.len() {
+        xof.squeeze(&mut buf);
+        for c in buf.chunks_exact(3) {
+            let d1 = c[0] as i16 | ((c[1] as i16 & 0xf) << 8);
+            let d2 = (c[1] >> 4) as i16 | ((c[2] as i16) << 4);
+            for d in [d1, d2] {
+                if d < Q && n < r.len() {
+                    r[n] = d;
+                    n += 1;
+                }
+            }
+        }
+    }
+    r
+}
+
+/// SamplePolyCBD, from the PRF output `PRF_eta(seed, nonce)`.
+fn sample_cbd(eta: usize, seed: &[u8; 32], nonce: u8) -> Poly {
+    let mut prf = Shake256::new();
+    prf.update(seed);
+    prf.update(&[nonce]);
+    let mut buf = [0u8; 64 * 3];
+    let buf = &mut buf[..64 * eta];
+    prf.squeeze(buf);
+
+    let bit = |i: usize| ((buf[i / 8] >> (i % 8)) & 1) as i16;
+
+    let mut r = [0i16; 256];
+    for (i, c) in r.iter_mut().enumerate() {
+        let base = 2 * eta * i;
+        let mut x = 0;
+        let mut y = 0;
+        for j in 0..eta {
+            x += bit(base + j);
+            y += bit(base + eta + j);
+        }
+        *c = x - y;
+    }
+    zeroise(buf);
+    r
+}
+
+fn add_assign(a: &mut Poly, b: &Poly) {
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        *a = a.wrapping_add(*b);
+    }
+}
+
+/// Maps coefficients from `(-q, q)` into `[0, q)`.
+fn normalize(a: &mut Poly) {
+    for c in a.iter_mut() {
+        *c += (*c >> 15) & Q;
+    }
+}
+
+/// ByteEncode_d, for coefficients already in `[0, 2^d)` (or `[0, q)` if d is 12).
+fn byte_encode(d: usize, a: &Poly, out: &mut [u8]) {
+    debug_assert_eq!(out.len(), 32 * d);
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut out = out.iter_mut();
+    for c in a {
+        acc |= (*c as u32) << bits;
+        bits += d;
+        while bits >= 8 {
+            *out.next().unwrap() = acc as u8;
+            acc >>= 8;
+            bits -= 8;
+        }
+    }
+}
+
+/// ByteDecode_d.
+///
+/// For d = 12, coefficients may be as large as 4095.
+fn byte_decode(d: usize, bytes: &[u8], a: &mut Poly) {
+    debug_assert_eq!(bytes.len(), 32 * d);
+    let mask = (1u32 << d) - 1;
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut bytes = bytes.iter();
+    for c in a.iter_mut() {
+        while bits < d {
+            acc |= (*bytes.next().unwrap() as u32) << bits;
+            bits += 8;
+        }
+        *c = (acc & mask) as i16;
+        acc >>= d;
+        bits -= d;
+    }
+}
+
+/// Compress_d then ByteEncode_d.  Coefficients of `a` must be in `(-q, q)`.
+fn compress_encode(d: usize, a: &Poly, out: &mut [u8]) {
+    let mut c = [0i16; 256];
+    for (c, a) in c.iter_mut().zip(a.iter()) {
+        let x = (*a + ((*a >> 15) & Q)) as u32;
+        // round(2^d * x / q) mod 2^d
+        *c = (div_q((x << d) + (Q as u32 / 2)) & ((1 << d) - 1)) as i16;
+    }
+    byte_encode(d, &c, out);
+    zeroise(&mut c);
+}
+
+/// ByteDecode_d then Decompress_d.
+fn decode_decompress(d: usize, bytes: &[u8], a: &mut Poly) {
+    byte_decode(d, bytes, a);
+    for c in a.iter_mut() {
+        // round(q * y / 2^d)
+        *c = ((*c as u32 * Q as u32 + (1 << (d - 1))) >> d) as i16;
+    }
+}
+
+/// Returns `n / q`, for `n < 2^24`.
+///
+/// This avoids a division instruction, which may not be constant-time.
+fn div_q(n: u32) -> u32 {
+    const M: u64 = ((1 << 40) + Q as u64 - 1) / Q as u64;
+    ((n as u64 * M) >> 40) as u32
+}
+
+macro_rules! ml_kem {
+    ($name:ident, $k:literal, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $name {
+            use super::*;
+
+            const K: usize = $k;
+
+            /// The length of an encapsulation key, in bytes.
+            pub const ENCAPSULATION_KEY_LEN: usize = Params::<K>::EK_LEN;
+
+            /// The length of an expanded decapsulation key, in bytes.
+            pub const DECAPSULATION_KEY_LEN: usize = Params::<K>::DK_LEN;
+
+            /// The length of a ciphertext, in bytes.
+            pub const CIPHERTEXT_LEN: usize = Params::<K>::CT_LEN;
+
+            /// A decapsulation (private) key.
+            pub struct DecapsulationKey(PrivateKey<K>);
+
+            impl DecapsulationKey {
+                /// Generate a new key using the system random number generator.
+                ///
+                /// Fails only if the random source fails.
+                pub fn new_random() -> Result<Self, Error> {
+                    Self::generate(&mut SystemRandom)
+                }
+
+                /// Generate a new key using `rng`.
+                ///
+                /// Fails only if the random source fails.
+                pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+                    let mut seed = [0u8; 64];
+                    rng.fill(&mut seed)?;
+                    let key = Self::from_seed(&seed);
+                    zeroise(&mut seed);
+                    Ok(key)
+                }
+
+                /// Deterministically derive a key from a 64-byte seed.
+                ///
+                /// The seed is `d || z` in the notation of FIPS203.  This is
+                /// the most compact way to store a decapsulation key.
+                pub fn from_seed(seed: &[u8; 64]) -> Self {
+                    let _entry = Entry::new_secret();
+                    let (d, z) = seed.split_at(32);
+                    Self(PrivateKey::generate(
+                        d.try_into().unwrap(),
+                        z.try_into().unwrap(),
+                    ))
+                }
+
+                /// Decode an expanded decapsulation key.
+                ///
+                /// This fails with [`Error::WrongLength`] if `bytes` is not
+                /// [`DECAPSULATION_KEY_LEN`] bytes long, or with another error
+                /// if it fails the checks in FIPS203 section 7.3.
+                pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                    let _entry = Entry::new_secret();
+                    PrivateKey::decode(bytes).map(Self)
+                }
+
+                /// Encode this key in its expanded form.
+                pub fn to_bytes(&self) -> [u8; DECAPSULATION_KEY_LEN] {
+                    let _entry = Entry::new_secret();
+                    let mut out = [0u8; DECAPSULATION_KEY_LEN];
+                    self.0.encode(&mut out);
+                    out
+                }
+
+                /// Return the matching encapsulation key.
+                pub fn encapsulation_key(&self) -> EncapsulationKey {
+                    let _entry = Entry::new_public();
+                    EncapsulationKey::from_public(self.0.public.clone())
+                }
+
+                /// Decapsulate `ciphertext`, returning the shared secret.
+                ///
+                /// Fails with [`Error::WrongLength`] if `ciphertext` is not
+                /// [`CIPHERTEXT_LEN`] bytes long.
+                ///
+                /// An invalid ciphertext of the correct length does not cause
+                /// an error: instead a pseudorandom shared secret is returned
+                /// which will not match the sender's.
+                pub fn decapsulate(&self, ciphertext: &[u8]) -> Result<SharedSecret, Error> {
+                    let _entry = Entry::new_secret();
+                    if ciphertext.len() != CIPHERTEXT_LEN {
+                        return Err(Error::WrongLength);
+                    }
+                    Ok(self.0.decapsulate(ciphertext))
+                }
+            }
+
+            /// An encapsulation (public) key.
+            #[derive(Clone)]
+            pub struct EncapsulationKey {
+                public: PublicKey<K>,
+                bytes: [u8; ENCAPSULATION_KEY_LEN],
+            }
+
+            impl EncapsulationKey {
+                /// Decode an encapsulation key.
+                ///
+                /// This fails with [`Error::WrongLength`] if `bytes` is not
+                /// [`ENCAPSULATION_KEY_LEN`] bytes long, or [`Error::OutOfRange`]
+                /// if it fails the checks in FIPS203 section 7.2.
+                pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                    let _entry = Entry::new_public();
+                    PublicKey::decode(bytes).map(Self::from_public)
+                }
+
+                /// Return the encoding of this key.
+                pub fn as_bytes(&self) -> &[u8; ENCAPSULATION_KEY_LEN] {
+                    &self.bytes
+                }
+
+                /// Encapsulate a fresh shared secret to this key.
+                ///
+                /// Returns the ciphertext, which should be sent to the
+                /// holder of the decapsulation key, and the shared secret.
+                ///
+                /// Fails only if the random source fails.
+                pub fn encapsulate(&self) -> Result<([u8; CIPHERTEXT_LEN], SharedSecret), Error> {
+                    self.encapsulate_with_rng(&mut SystemRandom)
+                }
+
+                /// Encapsulate a fresh shared secret to this key, using `rng`.
+                ///
+                /// Otherwise, as [`Self::encapsulate()`].
+                pub fn encapsulate_with_rng(
+                    &self,
+                    rng: &mut dyn RandomSource,
+                ) -> Result<([u8; CIPHERTEXT_LEN], SharedSecret), Error> {
+                    let _entry = Entry::new_secret();
+                    let mut m = [0u8; 32];
+                    rng.fill(&mut m)?;
+                    let m = low::ct::into_secret(m);
+                    let mut ct = [0u8; CIPHERTEXT_LEN];
+                    let ss = self.public.encapsulate(&m, &mut ct);
+                    Ok((ct, ss))
+                }
+
+                fn from_public(public: PublicKey<K>) -> Self {
+                    let mut bytes = [0u8; ENCAPSULATION_KEY_LEN];
+                    public.encode(&mut bytes);
+                    Self { public, bytes }
+                }
+            }
+        }
+    };
+}
+
+ml_kem!(
+    ml_kem_512,
+    2,
+    "ML-KEM-512, which targets NIST security category 1."
+);
+ml_kem!(
+    ml_kem_768,
+    3,
+    "ML-KEM-768, which targets NIST security category 3."
+);
+ml_kem!(
+    ml_kem_1024,
+    4,
+    "ML-KEM-1024, which targets NIST security category 5."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+    use crate::mid::sha2::Sha256Context;
+
+    fn sha256(bytes: &[u8]) -> [u8; 32] {
+        let mut ctx = Sha256Context::new();
+        ctx.update(bytes);
+        ctx.finish()
+    }
+
+    fn seed() -> [u8; 64] {
+        let mut seed = [0x01u8; 64];
+        seed[..32].fill(0x00);
+        seed
+    }
+
+    const M: &[u8] = &[0x02; 32];
+
+    // These test vectors were produced with OpenSSL 3.5, using the seed
+    // `00 * 32 || 01 * 32` and encapsulation randomness `02 * 32`.  The
+    // rejection secret is for the ciphertext with its first bit flipped.
+    macro_rules! kat_test {
+        ($name:ident, $module:ident, $ek:literal, $dk:literal, $ct:literal, $ss:literal, $rej:literal) => {
+            #[test]
+            fn $name() {
+                use $module::*;
+
+                let dk = DecapsulationKey::from_seed(&seed());
+                let ek = dk.encapsulation_key();
+                assert_eq!(hex(&sha256(ek.as_bytes())), $ek);
+                let dk_bytes = dk.to_bytes();
+                assert_eq!(hex(&sha256(&dk_bytes)), $dk);
+
+                let (ct, ss) = ek.encapsulate_with_rng(&mut SliceRandomSource(M)).unwrap();
+                assert_eq!(hex(&sha256(&ct)), $ct);
+                assert_eq!(hex(&ss.0), $ss);
+
+                let dk = DecapsulationKey::from_bytes(&dk_bytes).unwrap();
+                assert_eq!(hex(&dk.decapsulate(&ct).unwrap().0), $ss);
+
+                let mut bad = ct;
+                bad[0] ^= 1;
+                assert_eq!(hex(&dk.decapsulate(&bad).unwrap().0), $rej);
+
+                // round trips
+                let ek2 = EncapsulationKey::from_bytes(ek.as_bytes()).unwrap();
+                assert_eq!(ek2.as_bytes(), ek.as_bytes());
+                let (ct, ss) = ek2.encapsulate().unwrap();
+                assert_eq!(dk.decapsulate(&ct).unwrap().0, ss.0);
+
+                // length checks
+                assert_eq!(dk.decapsulate(&ct[1..]).err(), Some(Error::WrongLength));
+                assert!(EncapsulationKey::from_bytes(&ek.as_bytes()[1..]).is_err());
+                assert!(DecapsulationKey::from_bytes(&dk_bytes[1..]).is_err());
+
+                // modulus check: set the first coefficient to q
+                let mut bad_ek = *ek.as_bytes();
+                bad_ek[0] = 0x01;
+                bad_ek[1] = (bad_ek[1] & 0xf0) | 0x0d;
+                assert_eq!(
+                    EncapsulationKey::from_bytes(&bad_ek).err(),
+                    Some(Error::OutOfRange)
+                );
+
+                // hash check
+                let mut bad_dk = dk_bytes;
+                bad_dk[DECAPSULATION_KEY_LEN - 40] ^= 1;
+                assert_eq!(
+                    DecapsulationKey::from_bytes(&bad_dk).err(),
+                    Some(KeyFormatError::MismatchedMlKemPublicKeyHash.into())
+                );
+            }
+        };
+    }
+
+    kat_test!(
+        ml_kem_512_kat,
+        ml_kem_512,
+        "52b46f0597ac5cb10c6281ad5731f18d599feaa92ce24d897d4084195b27e448",
+        "3fd548ed596f9dc562c9bbb28f9183b3972d8cc5c7fdc1c3940d68740be75eb2",
+        "7ba440154df9c1b89f773bb31f9868948fc5920a54f83fd0c6aa4ff957133ac2",
+        "55985b48c30f2e399974d7ba2bf4324d23a73b9b2235e391165bd6d4048409fc",
+        "0572df59465f369a50e2cb568059c0a01f28ed4bed281d00ec6dde584629c464"
+    );
+
+    kat_test!(
+        ml_kem_768_kat,
+        ml_kem_768,
+        "f95c185fe5b2335d2fc938dd889c6425944acd74376b6952bf1130f720f6ba99",
+        "1918e0264daa7c642f18c59d76a5a0202de64ca2debfcc1612ed8b03a9c467dd",
+        "86f66867d89282f82e30c89fb8a1bcde190f77756c3c475a22bfdccb4e91903f",
+        "848df5046be06b37cefa3e4fc8d7ed85cbfba7f265f40d0293039c29c011fe74",
+        "dd87ea4e619508a1d2c76a98cd00b4815618a1123aa167e17a99152ce05f2fac"
+    );
+
+    kat_test!(
+        ml_kem_1024_kat,
+        ml_kem_1024,
+        "29e3692e1c08422f548ca7e683e89015482c09a5442f8d2ead471c3931a5ee76",
+        "dc7149d4b104922cd0b15c66b8758fee52a5814863be5197c02b4c3035f180b1",
+        "c54ca275cec365288576fad0928d58ab7f83b3e3ecf7b3ff3404fb1a87ec63f4",
+        "384254f36d937d86d256fe6af35ce04bcc714f137b040f529e4e8a0209d14029",
+        "14a8ff2b39526540335e12af39d8fea663415d515a13d220fae65d3be2bdd185"
+    );
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn div_q_is_exact() {
+        for n in 0..(Q as u32) << 12 {
+            assert_eq!(div_q(n), n / Q as u32);
+        }
+    }
+
+    #[test]
+    fn compression_round_trip() {
+        for d in [1, 4, 5, 10, 11] {
+            let mut a = [0i16; 256];
+            for (i, c) in a.iter_mut().enumerate() {
+                *c = ((i * 13) % (1 << d)) as i16;
+            }
+            let mut bytes = vec![0u8; 32 * d];
+            byte_encode(d, &a, &mut bytes);
+            let mut b = [0i16; 256];
+            decode_decompress(d, &bytes, &mut b);
+            let mut again = vec![0u8; 32 * d];
+            compress_encode(d, &b, &mut again);
+            assert_eq!(bytes, again);
+        }
+    }
+}
@@ -3,14 +3,44 @@
 
 #![deny(unsafe_code)]
 
+pub(super) mod aead;
+#[cfg(feature = "hazmat")]
+pub(super) mod aes;
+pub(super) mod aes_cbc;
 pub(super) mod aes_gcm;
+pub(super) mod aes_kw;
+pub(super) mod aes_siv;
+pub(super) mod bcrypt_pbkdf;
+#[cfg(feature = "hazmat")]
+pub(super) mod bignum;
+pub mod blake2;
 pub(super) mod chacha20poly1305;
+pub(super) mod cmac;
+pub(super) mod ctr_drbg;
+pub(super) mod ed25519;
+pub(super) mod fast_rng;
+pub(super) mod ff1;
+pub(super) mod fndsa;
+#[cfg(feature = "hazmat")]
+pub(super) mod ghash;
+pub(super) mod kmac;
+pub(super) mod mldsa;
+pub(super) mod mlkem;
+pub(super) mod modp;
 pub(super) mod p256;
 pub(super) mod p384;
+pub(super) mod poly1305;
+pub(super) mod ristretto255;
 pub(super) mod rng;
 pub(super) mod rsa_priv;
 pub(super) mod rsa_pub;
+pub(super) mod secretstream;
+pub(super) mod sha1;
 pub mod sha2;
+pub(super) mod sha3;
+pub(super) mod slhdsa;
 pub(super) mod util;
 pub(super) mod x25519;
+pub(super) mod x25519mlkem768;
 pub(super) mod xchacha20poly1305;
+pub(super) mod xwing;
@@ -0,0 +1,236 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! Arithmetic in the multiplicative group of integers modulo a public prime.
+
+use crate::Error;
+use crate::low::{self, PosInt, SecretPosInt};
+
+pub(crate) struct PrimeModulus {
+    p: Int,
+    montifier: Int,
+    p0: u64,
+    len: usize,
+}
+
+impl PrimeModulus {
+    pub(crate) fn new(p: &[u8]) -> Result<Self, Error> {
+        let p = Int::from_bytes(p)?;
+        let len = p.len_bytes();
+        if p.is_even() || !(MIN_MODULUS_BYTES..=MAX_MODULUS_BYTES).contains(&len) {
+            return Err(Error::OutOfRange);
+        }
+
+        let montifier = p.montifier();
+        let p0 = p.mont_neg_inverse();
+        Ok(Self {
+            p,
+            montifier,
+            p0,
+            len,
+        })
+    }
+
+    /// Length of the modulus, and therefore an encoded element, in bytes.
+    pub(crate) fn len_bytes(&self) -> usize {
+        self.len
+    }
+
+    /// Decode big-endian `bytes` as an element.
+    ///
+    /// The value must be less than the modulus, but may be zero.
+    pub(crate) fn decode(&self, bytes: &[u8]) -> Result<Element, Error> {
+        let mut e = Int::from_bytes(bytes)?;
+        if !e.less_than(&self.p) {
+            return Err(Error::OutOfRange);
+        }
+        e.expand(&self.p);
+        Ok(Element(e.into()))
+    }
+
+    /// Encode `e` into `out`, which must be exactly `len_bytes()` long.
+    pub(crate) fn encode(&self, e: &Element, out: &mut [u8]) -> Result<(), Error> {
+        if out.len() != self.len {
+            return Err(Error::WrongLength);
+        }
+        let mut tmp = [0u8; MAX_MODULUS_BYTES];
+        let bytes = e.0.to_bytes(&mut tmp)?;
+        // nb. `e` is reduced, so the excess leading bytes are zero
+        out.copy_from_slice(&bytes[bytes.len() - self.len..]);
+        low::zeroise(&mut tmp);
+        Ok(())
+    }
+
+    /// Returns `base` ^ `exponent` mod p.
+    ///
+    /// This is side-channel silent, except for the length of `exponent`.
+    pub(crate) fn exp(&self, base: &Element, exponent: &Exponent) -> Element {
+        let e = SecretPosInt::from(exponent.0.widen::<MAX_MODULUS_WORDS>());
+        Element(
+            base.0
+                .mont_exp(&e, &self.p, &self.montifier, self.p0)
+                .into(),
+        )
+    }
+
+    /// Returns `base` ^ `exponent` mod p, for a public `exponent`.
+    ///
+    /// `exponent` is big-endian, and may be as long as the modulus.
+    pub(crate) fn exp_public(&self, base: &Element, exponent: &[u8]) -> Result<Element, Error> {
+        let e = Int::from_bytes(exponent)?;
+        Ok(Element(
+            base.0
+                .mont_exp(&e, &self.p, &self.montifier, self.p0)
+                .into(),
+        ))
+    }
+
+    /// Returns `a` * `b` mod p.
+    pub(crate) fn mul(&self, a: &Element, b: &Element) -> Element {
+        let a_mont = SecretPosInt::from(a.0.to_montgomery(&self.montifier, &self.p));
+        Element(a_mont.mont_mul(&b.0, &self.p, self.p0).into())
+    }
+
+    /// Returns `a` + `b` mod p.
+    pub(crate) fn add(&self, a: &Element, b: &Element) -> Element {
+        // a - (0 - b)
+        let neg_b = self.sub(&self.zero(), b);
+        self.sub(a, &neg_b)
+    }
+
+    /// Returns `a` - `b` mod p.
+    pub(crate) fn sub(&self, a: &Element, b: &Element) -> Element {
+        Element(a.0.sub_mod(&b.0, &self.p).into())
+    }
+
+    fn zero(&self) -> Element {
+        let mut zero = Int::zero();
+        zero.expand(&self.p);
+        Element(zero.into())
+    }
+}
+
+/// An element modulo a [`PrimeModulus`].
+///
+/// This is zeroed on drop.
+pub(crate) struct Element(SecretPosInt<MAX_MODULUS_WORDS>);
+
+impl Element {
+    pub(crate) fn is_zero(&self) -> bool {
+        self.0.equals(&Int::zero())
+    }
+
+    /// Constant-time equality.
+    pub(crate) fn ct_equal(&self, other: &Self) -> bool {
+        self.0.equals(&other.0)
+    }
+}
+
+/// An exponent.
+///
+/// This is zeroed on drop.
+pub(crate) struct Exponent(SecretPosInt<EXPONENT_WORDS>);
+
+impl Exponent {
+    /// Decode a non-zero big-endian exponent of at most `MAX_FACTOR_BYTES`.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let f = SecretPosInt::from(Factor::from_bytes(bytes)?);
+        let e = SecretPosInt::from(f.widen::<EXPONENT_WORDS>());
+        match e.len_bytes() {
+            0 => Err(Error::OutOfRange),
+            _ => Ok(Self(e)),
+        }
+    }
+
+    /// Returns `a` + `b` * `c`.
+    ///
+    /// Each is at most `MAX_FACTOR_BYTES` long.
+    pub(crate) fn mul_add(a: &[u8], b: &[u8], c: &[u8]) -> Result<Self, Error> {
+        let a = SecretPosInt::from(Factor::from_bytes(a)?);
+        let b = SecretPosInt::from(Factor::from_bytes(b)?);
+        let c = SecretPosInt::from(Factor::from_bytes(c)?);
+        let bc = SecretPosInt::from(Factor::mul::<EXPONENT_WORDS>(&b, &c));
+        let e = SecretPosInt::from(a.widen::<EXPONENT_WORDS>().add(&bc));
+        match e.len_bytes() {
+            0 => Err(Error::OutOfRange),
+            _ => Ok(Self(e)),
+        }
+    }
+}
+
+const MAX_MODULUS_BITS: usize = 8192;
+const MAX_MODULUS_WORDS: usize = MAX_MODULUS_BITS / 64;
+const MAX_MODULUS_BYTES: usize = MAX_MODULUS_BITS / 8;
+
+const MIN_MODULUS_BITS: usize = 1024;
+const MIN_MODULUS_BYTES: usize = MIN_MODULUS_BITS / 8;
+
+/// Largest input to [`Exponent::from_bytes`] and [`Exponent::mul_add`].
+const MAX_FACTOR_BYTES: usize = 64;
+const FACTOR_WORDS: usize = MAX_FACTOR_BYTES / 8;
+
+/// Enough for `a + b * c`, where each is `FACTOR_WORDS` long.
+const EXPONENT_WORDS: usize = 4 * FACTOR_WORDS;
+
+type Int = PosInt<MAX_MODULUS_WORDS>;
+type Factor = PosInt<FACTOR_WORDS>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        // 2^1279 - 1 is a mersenne prime
+        let mut p = [0xffu8; 160];
+        p[0] = 0x7f;
+        let m = PrimeModulus::new(&p).unwrap();
+        assert_eq!(m.len_bytes(), 160);
+
+        let two = m.decode(&[2]).unwrap();
+        let three = m.decode(&[3]).unwrap();
+        let mut p_minus_one_bytes = p;
+        p_minus_one_bytes[159] = 0xfe;
+        let p_minus_one = m.decode(&p_minus_one_bytes).unwrap();
+        assert!(m.decode(&p).is_err());
+        assert!(m.decode(&[0]).unwrap().is_zero());
+
+        // 2 + 3 = 5, 2 - 3 = -1, 2 * 3 = 6
+        assert!(m.add(&two, &three).ct_equal(&m.decode(&[5]).unwrap()));
+        assert!(m.sub(&two, &three).ct_equal(&p_minus_one));
+        assert!(m.mul(&two, &three).ct_equal(&m.decode(&[6]).unwrap()));
+        assert!(m.add(&p_minus_one, &three).ct_equal(&two));
+
+        // 2^1279 = 1 mod p, and 3 + 2 * 638 = 1279
+        let e = Exponent::mul_add(&[3], &[2], &[0x02, 0x7e]).unwrap();
+        assert!(m.exp(&two, &e).ct_equal(&m.decode(&[1]).unwrap()));
+        let e = Exponent::from_bytes(&[10]).unwrap();
+        assert!(m.exp(&two, &e).ct_equal(&m.decode(&[4, 0]).unwrap()));
+        assert!(
+            m.exp_public(&two, &[10])
+                .unwrap()
+                .ct_equal(&m.decode(&[4, 0]).unwrap())
+        );
+
+        // fermat: 3^(p - 1) = 1 mod p
+        assert!(
+            m.exp_public(&three, &p_minus_one_bytes)
+                .unwrap()
+                .ct_equal(&m.decode(&[1]).unwrap())
+        );
+
+        let mut out = [0u8; 160];
+        m.encode(&three, &mut out).unwrap();
+        assert_eq!(out[159], 3);
+        assert!(out[..159].iter().all(|b| *b == 0));
+        assert_eq!(m.encode(&three, &mut [0u8; 159]), Err(Error::WrongLength));
+
+        assert_eq!(Exponent::from_bytes(&[0; 4]).err(), Some(Error::OutOfRange));
+        assert_eq!(
+            Exponent::from_bytes(&[1; 65]).err(),
+            Some(Error::OutOfRange)
+        );
+        assert!(PrimeModulus::new(&[0xfe; 128]).is_err());
+        assert!(PrimeModulus::new(&[0xff; 127]).is_err());
+    }
+}
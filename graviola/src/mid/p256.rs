@@ -16,6 +16,7 @@ mod precomp;
 pub struct PublicKey {
     point: AffineMontPoint,
     precomp_w5: JacobianMontPointTableW5,
+    precomp_w7: Option<Box<AffineMontPointTableW7>>,
 }
 
 impl PublicKey {
@@ -38,9 +39,29 @@ impl PublicKey {
         self.point.as_bytes_uncompressed()
     }
 
+    /// Precompute a larger table of multiples of this public key.
+    ///
+    /// This speeds up subsequent ECDSA verifications using this key,
+    /// at the cost of around 150KB of memory and a one-off computation
+    /// comparable to a few dozen verifications.  It is worthwhile for
+    /// keys that are used to verify very many signatures.
+    ///
+    /// Calling this more than once has no further effect.
+    pub fn precompute(&mut self) {
+        let _entry = low::Entry::new_public();
+        if self.precomp_w7.is_none() {
+            // build the table directly on the heap: it is too large for the stack
+            let mut table: Box<AffineMontPointTableW7> =
+                vec![[0u64; 512]; 37].into_boxed_slice().try_into().unwrap();
+            self.point.public_precomp_w7(&mut table);
+            self.precomp_w7 = Some(table);
+        }
+    }
+
     fn from_affine(point: AffineMontPoint) -> Self {
         Self {
             precomp_w5: point.public_precomp_w5(),
+            precomp_w7: None,
             point,
         }
     }
@@ -54,7 +75,10 @@ impl PublicKey {
         // 5. Compute: R = (xR, yR) = u1 G + u2 QU
         //  If R = O, output "invalid" and stop.
         let lhs = JacobianMontPoint::public_base_multiply(&u1);
-        let rhs = JacobianMontPoint::public_multiply_w5(&u2, &self.precomp_w5);
+        let rhs = match &self.precomp_w7 {
+            Some(precomp_w7) => JacobianMontPoint::multiply_w7::<false>(&u2, precomp_w7),
+            None => JacobianMontPoint::public_multiply_w5(&u2, &self.precomp_w5),
+        };
 
         // nb. if lhs == rhs, then we need a doubling rather than addition
         // (because `p256_montjadd` doesn't handle P + P.)
@@ -94,6 +118,24 @@ impl PrivateKey {
         Self::generate(&mut SystemRandom)
     }
 
+    /// Generate a new random private key using `rng`.
+    ///
+    /// Fails if the random source fails, or does not produce a valid
+    /// scalar after many attempts (which a working random source does
+    /// with negligible probability).
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let _entry = low::Entry::new_secret();
+        for _ in 0..64 {
+            let mut r = [0u8; Scalar::BYTES];
+            rng.fill(&mut r)?;
+            if let Ok(p) = Self::from_bytes(&r) {
+                return Ok(p);
+            }
+        }
+
+        Err(Error::RngFailed)
+    }
+
     /// Derive the corresponding public key, and return it in
     /// X9.62 uncompressed encoding.
     pub fn public_key_uncompressed(&self) -> [u8; PublicKey::BYTES] {
@@ -127,19 +169,6 @@ impl PrivateKey {
         }
     }
 
-    pub(crate) fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
-        let _entry = low::Entry::new_secret();
-        for _ in 0..64 {
-            let mut r = [0u8; Scalar::BYTES];
-            rng.fill(&mut r)?;
-            if let Ok(p) = Self::from_bytes(&r) {
-                return Ok(p);
-            }
-        }
-
-        Err(Error::RngFailed)
-    }
-
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         Scalar::from_bytes_checked(bytes).map(|scalar| Self {
             scalar: low::ct::into_secret(scalar),
@@ -165,6 +194,16 @@ impl StaticPrivateKey {
         PrivateKey::generate(&mut SystemRandom).map(Self)
     }
 
+    /// Generate a new random private key using `rng`.
+    ///
+    /// Fails if the random source fails, or does not produce a valid
+    /// scalar after many attempts (which a working random source does
+    /// with negligible probability).
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let _entry = low::Entry::new_secret();
+        PrivateKey::generate(rng).map(Self)
+    }
+
     /// Decode a private key from `bytes`.
     ///
     /// `bytes` may be larger or smaller than the size of `n`: excess bytes
@@ -205,11 +244,6 @@ impl StaticPrivateKey {
         .diffie_hellman(peer)
     }
 
-    pub(crate) fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
-        let _entry = low::Entry::new_secret();
-        PrivateKey::generate(rng).map(Self)
-    }
-
     pub(crate) fn public_key_x_scalar(&self) -> Scalar {
         let _entry = low::Entry::new_secret();
         self.0.public_point().x_scalar()
@@ -238,6 +272,142 @@ impl Drop for SharedSecret {
     }
 }
 
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A P-256 group element, for protocols that need arbitrary
+/// group operations rather than just Diffie-Hellman.
+#[derive(Clone, Copy, Debug)]
+pub struct Element(JacobianMontPoint);
+
+impl Element {
+    /// Length of a SEC1 compressed encoding.
+    pub(crate) const BYTES: usize = 1 + FieldElement::BYTES;
+
+    pub(crate) fn identity() -> Self {
+        Self(JacobianMontPoint::infinity())
+    }
+
+    /// Decode a SEC1 compressed point.
+    pub(crate) fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::BYTES {
+            return Err(Error::WrongLength);
+        }
+
+        let (indicator, x) = bytes.split_at(1);
+        let sign = match indicator[0] {
+            0x02 => 0,
+            0x03 => 1,
+            _ => return Err(Error::NotOnCurve),
+        };
+
+        let x = util::big_endian_slice_to_u64x4(x).unwrap();
+        if low::bignum_cmp_lt(&x, &CURVE_P) == 0 {
+            return Err(Error::NotOnCurve);
+        }
+        let x = FieldElement(x).as_mont();
+
+        let (is_square, mut y) = x.curve_rhs().sqrt();
+        if is_square == 0 {
+            return Err(Error::NotOnCurve);
+        }
+        if y.sgn0() != sign {
+            y = y.negate_mod_p();
+        }
+
+        Ok(Self(JacobianMontPoint::from_affine(
+            &AffineMontPoint::from_xy(x, y),
+        )))
+    }
+
+    /// Produce a SEC1 compressed encoding.
+    ///
+    /// The identity has no such encoding; it is encoded as zeroes
+    /// (which `decode` rejects).
+    pub(crate) fn encode(&self) -> [u8; Self::BYTES] {
+        let mut r = [0u8; Self::BYTES];
+        if self.0.public_is_infinity() {
+            return r;
+        }
+
+        let point = self.0.as_affine();
+        r[0] = 0x02 | point.y().sgn0();
+        r[1..].copy_from_slice(&util::u64x4_to_big_endian(&point.x().demont().0));
+        r
+    }
+
+    /// Decode an X9.62 uncompressed point.
+    pub(crate) fn decode_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        AffineMontPoint::from_x962_uncompressed(bytes)
+            .map(|point| Self(JacobianMontPoint::from_affine(&point)))
+    }
+
+    /// Produce an X9.62 uncompressed encoding.
+    ///
+    /// As with `encode`, the identity is encoded as zeroes.
+    pub(crate) fn encode_uncompressed(&self) -> [u8; PublicKey::BYTES] {
+        if self.0.public_is_infinity() {
+            return [0u8; PublicKey::BYTES];
+        }
+        self.0.as_affine().as_bytes_uncompressed()
+    }
+
+    /// Map 96 uniformly random bytes to an element.
+    ///
+    /// These are interpreted as two big-endian 48-byte field elements,
+    /// which are mapped with simplified SWU and added.  This is
+    /// `hash_to_curve` for the `P256_XMD:SHA-256_SSWU_RO_` suite of
+    /// [RFC9380](https://datatracker.ietf.org/doc/html/rfc9380) when
+    /// the bytes come from `expand_message_xmd`.
+    pub(crate) fn from_uniform_bytes(bytes: &[u8; 96]) -> Self {
+        let (u0, u1) = bytes.split_at(48);
+        let q0 = FieldElement::from_wide_bytes(u0.try_into().unwrap()).map_to_curve();
+        let q1 = FieldElement::from_wide_bytes(u1.try_into().unwrap()).map_to_curve();
+        Self(JacobianMontPoint::from_affine(&q0)).add(&Self(JacobianMontPoint::from_affine(&q1)))
+    }
+
+    #[must_use]
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        // p256_montjadd handles the point at infinity, but not doubling
+        let sum = self.0.add(&other.0);
+        let double = self.0.double();
+        Self(JacobianMontPoint::select(
+            &sum,
+            &double,
+            self.0.private_eq(&other.0) & !self.0.is_infinity() & 1,
+        ))
+    }
+
+    /// Multiply this element by `scalar`, in constant time.
+    ///
+    /// Whether `self` is the identity is deemed a non-secret property.
+    #[must_use]
+    pub(crate) fn mul(&self, scalar: &Scalar) -> Self {
+        if self.0.public_is_infinity() {
+            return *self;
+        }
+        let precomp = self.0.as_affine().public_precomp_w5();
+        Self(JacobianMontPoint::multiply_w5(scalar, &precomp))
+    }
+
+    /// Multiply the generator by `scalar`, in constant time.
+    pub(crate) fn base_multiply(scalar: &Scalar) -> Self {
+        Self(JacobianMontPoint::base_multiply(scalar))
+    }
+
+    /// Equality, in constant time.
+    pub(crate) fn ct_equal(&self, other: &Self) -> bool {
+        self.0.private_eq(&other.0) == 1
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        self.0.is_infinity() == 1
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 struct AffineMontPoint {
     xy: [u64; 8],
@@ -382,6 +552,31 @@ impl AffineMontPoint {
         r
     }
 
+    /// Fill `table` with multiples of this point, in the same layout as
+    /// `CURVE_GENERATOR_PRECOMP_W7` (see `public_precomp_w7_slow`).
+    ///
+    /// Each row is computed in Jacobian coordinates, and then converted
+    /// to affine coordinates with a single inversion.
+    fn public_precomp_w7(&self, table: &mut AffineMontPointTableW7) {
+        let mut first = JacobianMontPoint::from_affine(self);
+        let mut row = [JacobianMontPoint::zero(); 64];
+
+        for (window, out) in table.iter_mut().enumerate() {
+            if window > 0 {
+                first.double_inplace_n(7);
+            }
+
+            // nb. `add` does not handle P + P
+            row[0] = first;
+            row[1] = first.double();
+            for i in 2..64 {
+                row[i] = row[i - 1].add(&first);
+            }
+
+            JacobianMontPoint::public_batch_as_affine(&row, out);
+        }
+    }
+
     fn public_precomp_w5(&self) -> JacobianMontPointTableW5 {
         let mut r = [JacobianMontPoint::zero(); 16];
 
@@ -470,6 +665,30 @@ impl JacobianMontPoint {
         self.z().public_eq(&FieldElement::default())
     }
 
+    fn is_infinity(&self) -> u8 {
+        self.z().private_eq(&FieldElement::default())
+    }
+
+    /// Equality, in constant time.  Returns 1 if equal.
+    fn private_eq(&self, other: &Self) -> u8 {
+        let a_z2 = self.z().mont_sqr();
+        let a_z3 = self.z().mont_mul(&a_z2);
+        let b_z2 = other.z().mont_sqr();
+        let b_z3 = other.z().mont_mul(&b_z2);
+
+        let x_eq = self
+            .x()
+            .mont_mul(&b_z2)
+            .private_eq(&other.x().mont_mul(&a_z2));
+        let y_eq = self
+            .y()
+            .mont_mul(&b_z3)
+            .private_eq(&other.y().mont_mul(&a_z3));
+        let both_infinity = self.is_infinity() & other.is_infinity();
+        let neither_infinity = !(self.is_infinity() | other.is_infinity()) & 1;
+        both_infinity | (neither_infinity & x_eq & y_eq)
+    }
+
     fn zero() -> Self {
         Self { xyz: [0; 12] }
     }
@@ -490,6 +709,11 @@ impl JacobianMontPoint {
         self.xyz[Self::Z].copy_from_slice(&fe.0);
     }
 
+    /// Multiply the curve generator by `scalar`.
+    ///
+    /// `CURVE_GENERATOR_PRECOMP_W7` has a row for each 7-bit window
+    /// of the scalar, so this does no doublings: only 36 mixed additions.
+    /// That is why there is no separate comb table for the generator.
     fn base_multiply(scalar: &Scalar) -> Self {
         Self::multiply_w7::<true>(scalar, &precomp::CURVE_GENERATOR_PRECOMP_W7)
     }
@@ -521,8 +745,7 @@ impl JacobianMontPoint {
             digit,
         ));
 
-        let mut index = 1;
-        for (digit, sign) in terms {
+        for (index, (digit, sign)) in (1..).zip(terms) {
             let tmp = if SECRET {
                 let mut tmp = AffineMontPoint::lookup_w7(&precomp[index], digit);
                 tmp.maybe_negate_y(sign);
@@ -536,7 +759,6 @@ impl JacobianMontPoint {
             };
 
             result.add_inplace_affine(&tmp);
-            index += 1;
         }
 
         result
@@ -595,13 +817,12 @@ impl JacobianMontPoint {
 
     fn as_affine(&self) -> AffineMontPoint {
         // recover (x, y) from (x / z ^ 2, x / z ^ 3, z)
-        let z2 = self.z().mont_sqr();
-        let z3 = self.z().mont_mul(&z2);
-
+        //
         // inversion calculated outside montgomery domain
         // (benchmarked vs addition chain in montgomery)
-        let z2_inv = z2.demont().inv().as_mont();
-        let z3_inv = z3.demont().inv().as_mont();
+        let z_inv = self.z().demont().inv().as_mont();
+        let z2_inv = z_inv.mont_sqr();
+        let z3_inv = z2_inv.mont_mul(&z_inv);
 
         let x = self.x().mont_mul(&z2_inv);
         let y = self.y().mont_mul(&z3_inv);
@@ -609,6 +830,41 @@ impl JacobianMontPoint {
         AffineMontPoint::from_xy(x, y)
     }
 
+    /// Convert `points` to affine coordinates, writing them contiguously
+    /// into `out`.
+    ///
+    /// This uses Montgomery's trick to share one inversion between all
+    /// the points, so none may be the point at infinity.  This is not
+    /// constant time.
+    fn public_batch_as_affine(points: &[Self; 64], out: &mut [u64; 512]) {
+        // products[i] = z[0] * ... * z[i]
+        let mut products = [FieldElement::default(); 64];
+        products[0] = points[0].z();
+        for i in 1..64 {
+            products[i] = products[i - 1].mont_mul(&points[i].z());
+        }
+
+        // inversion calculated outside montgomery domain
+        let mut inv = products[63].demont().inv().as_mont();
+
+        for i in (0..64).rev() {
+            let z_inv = match i {
+                0 => inv,
+                _ => {
+                    let z_inv = inv.mont_mul(&products[i - 1]);
+                    inv = inv.mont_mul(&points[i].z());
+                    z_inv
+                }
+            };
+            let z2_inv = z_inv.mont_sqr();
+            let z3_inv = z2_inv.mont_mul(&z_inv);
+
+            let x = points[i].x().mont_mul(&z2_inv);
+            let y = points[i].y().mont_mul(&z3_inv);
+            out[i * 8..(i + 1) * 8].copy_from_slice(&AffineMontPoint::from_xy(x, y).xy);
+        }
+    }
+
     fn public_eq(&self, other: &Self) -> bool {
         // we can't compare these directly, because they could be
         // the same point but with different z factors.  instead,
@@ -765,6 +1021,78 @@ impl FieldElement {
         self.0 == other.0
     }
 
+    /// Private equality.  Returns 1 if equal.
+    fn private_eq(&self, other: &Self) -> u8 {
+        low::bignum_eq(&self.0, &other.0) as u8
+    }
+
+    /// Reduce a big-endian 48-byte value mod p, into montgomery domain.
+    fn from_wide_bytes(bytes: &[u8; 48]) -> Self {
+        let (hi, lo) = bytes.split_at(16);
+        let hi = Self(util::big_endian_slice_any_size_to_u64x4(hi).unwrap());
+        let lo = Self(util::big_endian_slice_to_u64x4(lo).unwrap());
+        // hi * 2^256 + lo
+        hi.as_mont().as_mont().add(&lo.as_mont())
+    }
+
+    /// Raise to a public exponent, in montgomery domain
+    fn pow(&self, exponent: &[u64; 4]) -> Self {
+        let mut r = CURVE_ONE_MONT;
+        for word in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                r = r.mont_sqr();
+                if (word >> bit) & 1 == 1 {
+                    r = r.mont_mul(self);
+                }
+            }
+        }
+        r
+    }
+
+    /// Returns `(is_square, sqrt)`.  `sqrt` is meaningless if
+    /// `is_square` is 0.
+    fn sqrt(&self) -> (u8, Self) {
+        // p = 3 mod 4
+        let r = self.pow(&SQRT_EXPONENT);
+        (r.mont_sqr().private_eq(self), r)
+    }
+
+    /// The "sign" of this element.  See RFC9380 section 4.1.
+    fn sgn0(&self) -> u8 {
+        (self.demont().0[0] & 1) as u8
+    }
+
+    /// x ^ 3 + ax + b
+    fn curve_rhs(&self) -> Self {
+        self.mont_sqr()
+            .add(&CURVE_A_MONT)
+            .mont_mul(self)
+            .add(&CURVE_B_MONT)
+    }
+
+    /// Simplified Shallue-van de Woestijne-Ulas method.
+    ///
+    /// See RFC9380 section 6.6.2.  This is constant time.
+    fn map_to_curve(&self) -> AffineMontPoint {
+        let zero = Self::default();
+        let u2 = self.mont_sqr();
+        let z_u2 = SSWU_Z_MONT.mont_mul(&u2);
+        let tv1 = z_u2.mont_sqr().add(&z_u2);
+        let tv1_inv = tv1.demont().inv().as_mont();
+
+        let x1 = SSWU_C1_MONT.mont_mul(&tv1_inv.add(&CURVE_ONE_MONT));
+        let x1 = Self::select(&x1, &SSWU_C2_MONT, tv1.private_eq(&zero));
+        let (x1_square, y1) = x1.curve_rhs().sqrt();
+
+        let x2 = z_u2.mont_mul(&x1);
+        let (_, y2) = x2.curve_rhs().sqrt();
+
+        let x = Self::select(&x2, &x1, x1_square);
+        let y = Self::select(&y2, &y1, x1_square);
+        let y = Self::select(&y, &y.negate_mod_p(), self.sgn0() ^ y.sgn0());
+        AffineMontPoint::from_xy(x, y)
+    }
+
     /// Return p0 if select == 0, p1 otherwise
     fn select(p0: &Self, p1: &Self, select: u8) -> Self {
         let mut r = Self::default();
@@ -774,7 +1102,7 @@ impl FieldElement {
     }
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct Scalar([u64; 4]);
 
 impl Scalar {
@@ -816,7 +1144,7 @@ impl Scalar {
 
     #[cfg(test)]
     fn small_u64(v: u64) -> Self {
-        Scalar([v, 0, 0, 0])
+        Self([v, 0, 0, 0])
     }
 
     /// Private test for zero
@@ -874,8 +1202,52 @@ impl Scalar {
         r
     }
 
+    /// Reduce a big-endian 48-byte value mod n.
+    pub(crate) fn from_wide_bytes(bytes: &[u8; 48]) -> Self {
+        let (hi, lo) = bytes.split_at(16);
+        let hi = Self(util::big_endian_slice_any_size_to_u64x4(hi).unwrap());
+        let lo = Self(util::big_endian_slice_to_u64x4(lo).unwrap());
+        // hi * 2^256 + lo
+        hi.as_mont().add(&lo.reduce_mod_n())
+    }
+
+    /// Generate a uniformly random, non-zero scalar.
+    pub(crate) fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        for _ in 0..64 {
+            let mut r = [0u8; Self::BYTES];
+            rng.fill(&mut r)?;
+            if let Ok(s) = Self::from_bytes_checked(&r) {
+                return Ok(s);
+            }
+        }
+
+        Err(Error::RngFailed)
+    }
+
+    /// Constant-time equality
+    pub(crate) fn ct_equal(&self, other: &Self) -> bool {
+        self.private_eq(other)
+    }
+
+    /// Multiply `self` * `other` mod n
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        self.mont_mul(other).as_mont()
+    }
+
+    /// Subtract `self` - `other` mod n
+    pub(crate) fn sub(&self, other: &Self) -> Self {
+        let mut r = Self::default();
+        low::bignum_modsub(&mut r.0, &self.0, &other.0, &CURVE_ORDER);
+        r
+    }
+
+    /// Negate `self` mod n
+    pub(crate) fn negate(&self) -> Self {
+        Self::default().sub(self)
+    }
+
     /// Find the multiplicative inverse of `self` mod n
-    fn inv(&self) -> Self {
+    pub(crate) fn inv(&self) -> Self {
         let mut r = Self::default();
         let mut temp = [0u64; 4 * 3];
         low::bignum_modinv(&mut r.0, &self.0, &CURVE_ORDER, &mut temp);
@@ -883,7 +1255,7 @@ impl Scalar {
     }
 
     /// Add `self` + `other` mod n
-    fn add(&self, other: &Self) -> Self {
+    pub(crate) fn add(&self, other: &Self) -> Self {
         let mut r = Self::default();
         low::bignum_modadd(&mut r.0, &self.0, &other.0, &CURVE_ORDER);
         r
@@ -1089,6 +1461,45 @@ const CURVE_ONE_MONT: FieldElement = FieldElement([
     0x0000_0000_ffff_fffe,
 ]);
 
+const CURVE_P: [u64; 4] = [
+    0xffff_ffff_ffff_ffff,
+    0x0000_0000_ffff_ffff,
+    0x0000_0000_0000_0000,
+    0xffff_ffff_0000_0001,
+];
+
+/// (p + 1) / 4
+const SQRT_EXPONENT: [u64; 4] = [
+    0x0000_0000_0000_0000,
+    0x0000_0000_4000_0000,
+    0x4000_0000_0000_0000,
+    0x3fff_ffff_c000_0000,
+];
+
+/// Z = -10, for simplified SWU
+const SSWU_Z_MONT: FieldElement = FieldElement([
+    0xffff_ffff_ffff_fff5,
+    0x0000_000a_ffff_ffff,
+    0x0000_0000_0000_0000,
+    0xffff_fff5_0000_000b,
+]);
+
+/// -B / A
+const SSWU_C1_MONT: FieldElement = FieldElement([
+    0x9d89_9fcb_6341_949f,
+    0x8efa_ac9a_7d81_6585,
+    0xa1e0_b58e_a7b5_ba47,
+    0xf410_0209_0182_6d67,
+]);
+
+/// B / (Z * A)
+const SSWU_C2_MONT: FieldElement = FieldElement([
+    0x5c8d_c32d_f053_5ba9,
+    0xc17f_77a9_8c8c_f08d,
+    0x7696_788e_43f8_92a0,
+    0x9868_0033_99c0_3e24,
+]);
+
 const CURVE_ORDER: [u64; 4] = [
     0xf3b9_cac2_fc63_2551,
     0xbce6_faad_a717_9e84,
@@ -1108,6 +1519,7 @@ mod tests {
     use core::mem::size_of_val;
 
     use super::*;
+    use crate::mid::rng::SliceRandomSource;
 
     const CURVE_GENERATOR: AffineMontPoint = AffineMontPoint {
         xy: [
@@ -1190,6 +1602,76 @@ mod tests {
                b"\x04\xcb\x8a\x14\x1c\xd7\xe4\x07\xaf\x69\xa5\x01\x88\xe9\x1c\xe5\x5d\xcc\xfd\x33\x48\xda\xba\x4a\x9c\x46\x64\x33\x2e\x95\x59\xb6\x81\x44\xfc\x1a\x61\xd8\x41\xe4\xdb\x80\x1b\x33\x51\x20\x12\x1d\x0b\xa4\x84\xb3\xc9\x53\xb3\x1d\x35\x1d\x7f\xa2\x13\x97\xd1\x25\x47");
     }
 
+    #[test]
+    fn generate_rejects_invalid_scalars() {
+        let mut random = [0xff; 64];
+        random[32..].fill(0x11);
+        let key = PrivateKey::generate(&mut SliceRandomSource(&random)).unwrap();
+        assert_eq!(
+            key.public_key_uncompressed(),
+            PrivateKey::from_bytes(&[0x11; 32])
+                .unwrap()
+                .public_key_uncompressed()
+        );
+
+        assert_eq!(
+            PrivateKey::generate(&mut SliceRandomSource(&[0; 32 * 64])).err(),
+            Some(Error::RngFailed)
+        );
+    }
+
+    #[test]
+    fn element_compressed_encoding() {
+        let g = Element(JacobianMontPoint::from_affine(&CURVE_GENERATOR));
+        let encoded = g.encode();
+        assert_eq!(
+            encoded,
+            &[
+                0x03, 0x6b, 0x17, 0xd1, 0xf2, 0xe1, 0x2c, 0x42, 0x47, 0xf8, 0xbc, 0xe6, 0xe5, 0x63,
+                0xa4, 0x40, 0xf2, 0x77, 0x03, 0x7d, 0x81, 0x2d, 0xeb, 0x33, 0xa0, 0xf4, 0xa1, 0x39,
+                0x45, 0xd8, 0x98, 0xc2, 0x96
+            ][..]
+        );
+        assert!(Element::decode(&encoded).unwrap().ct_equal(&g));
+
+        let mut negated = encoded;
+        negated[0] = 0x02;
+        assert!(Element::decode(&negated).unwrap().add(&g).is_identity());
+
+        let mut bad = encoded;
+        bad[0] = 0x04;
+        assert_eq!(Element::decode(&bad).unwrap_err(), Error::NotOnCurve);
+
+        // x = p is not canonical
+        let mut bad = [0x02; Element::BYTES];
+        bad[1..].copy_from_slice(&util::u64x4_to_big_endian(&CURVE_P));
+        assert_eq!(Element::decode(&bad).unwrap_err(), Error::NotOnCurve);
+
+        // x = 1 gives a non-square rhs
+        let mut bad = [0u8; Element::BYTES];
+        bad[0] = 0x02;
+        bad[32] = 0x01;
+        assert_eq!(Element::decode(&bad).unwrap_err(), Error::NotOnCurve);
+
+        assert_eq!(
+            Element::decode(&encoded[1..]).unwrap_err(),
+            Error::WrongLength
+        );
+        assert_eq!(Element::identity().encode(), [0u8; Element::BYTES]);
+
+        let uncompressed = g.encode_uncompressed();
+        assert_eq!(&uncompressed[1..33], &encoded[1..]);
+        assert!(
+            Element::decode_uncompressed(&uncompressed)
+                .unwrap()
+                .ct_equal(&g)
+        );
+        assert_eq!(
+            Element::identity().encode_uncompressed(),
+            [0u8; PublicKey::BYTES]
+        );
+    }
+
     #[test]
     fn test_raw_ecdsa_sign() {
         let private = StaticPrivateKey::from_bytes(b"\xd1\xf6\xbc\xcc\x3e\x5a\x40\x1b\xcc\x2c\x21\xbe\x34\x90\xed\x38\xde\xf4\x93\x7f\x78\x06\x03\xf5\x2b\x23\xb9\xa6\xfa\x9c\xf6\x0e").unwrap();
@@ -1203,6 +1685,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn public_precomp_w7() {
+        let mut table = [[0u64; 512]; 37];
+        CURVE_GENERATOR.public_precomp_w7(&mut table);
+        assert_eq!(table, precomp::CURVE_GENERATOR_PRECOMP_W7);
+    }
+
+    #[test]
+    fn precomputed_public_key_verify() {
+        let private = StaticPrivateKey::from_bytes(&[0x42; 32]).unwrap();
+        let k = StaticPrivateKey::from_bytes(&[0x24; 32]).unwrap();
+        let e = Scalar::from_bytes_checked(&[0x11; 32]).unwrap();
+        let wrong_e = Scalar::from_bytes_checked(&[0x12; 32]).unwrap();
+        let r = k.public_key_x_scalar();
+        let s = private.raw_ecdsa_sign(&k, &e, &r);
+
+        let mut public =
+            PublicKey::from_x962_uncompressed(&private.public_key_uncompressed()).unwrap();
+        public.raw_ecdsa_verify(&r, &s, &e).unwrap();
+        public.raw_ecdsa_verify(&r, &s, &wrong_e).unwrap_err();
+
+        public.precompute();
+        public.precompute();
+        public.raw_ecdsa_verify(&r, &s, &e).unwrap();
+        public.raw_ecdsa_verify(&r, &s, &wrong_e).unwrap_err();
+    }
+
     #[test]
     fn test_booth_recoded_w7() {
         let s = Scalar::small_u64(0x3bce_3c3e_27d2_604b);
@@ -1259,19 +1768,19 @@ mod tests {
         let precomp = CURVE_GENERATOR.public_precomp_w7_slow();
 
         println!("pub(super) static CURVE_GENERATOR_PRECOMP_W7: super::AffineMontPointTableW7 = [");
-        for w in 0..37 {
+        for (w, window) in precomp.iter().enumerate() {
             println!("    // 1G..64G << {}", w * 7);
             println!("    [");
-            for p in 0..64 {
-                for j in 0..8 {
-                    println!("            0x{:016x}, ", precomp[w][p].xy[j]);
+            for point in window {
+                for word in point.xy {
+                    println!("            0x{:016x}, ", word);
                 }
             }
             println!("    ],");
         }
         println!("];");
 
-        println!("");
+        println!();
         println!("table size is {} bytes", size_of_val(&precomp));
     }
 }
@@ -95,6 +95,23 @@ impl PrivateKey {
         Self::generate(&mut SystemRandom)
     }
 
+    /// Generate a new random private key using `rng`.
+    ///
+    /// Fails if the random source fails, or does not produce a valid
+    /// scalar after many attempts (which a working random source does
+    /// with negligible probability).
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        for _ in 0..64 {
+            let mut r = [0u8; Scalar::BYTES];
+            rng.fill(&mut r)?;
+            if let Ok(p) = Self::from_bytes(&r) {
+                return Ok(p);
+            }
+        }
+
+        Err(Error::RngFailed)
+    }
+
     /// Derive the corresponding public key, and return it in
     /// X9.62 uncompressed encoding.
     pub fn public_key_uncompressed(&self) -> [u8; PublicKey::BYTES] {
@@ -128,18 +145,6 @@ impl PrivateKey {
         }
     }
 
-    fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
-        for _ in 0..64 {
-            let mut r = [0u8; Scalar::BYTES];
-            rng.fill(&mut r)?;
-            if let Ok(p) = Self::from_bytes(&r) {
-                return Ok(p);
-            }
-        }
-
-        Err(Error::RngFailed)
-    }
-
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         Scalar::from_bytes_checked(bytes).map(|scalar| Self {
             scalar: low::ct::into_secret(scalar),
@@ -166,6 +171,15 @@ impl StaticPrivateKey {
         Self::generate(&mut SystemRandom)
     }
 
+    /// Generate a new random private key using `rng`.
+    ///
+    /// Fails if the random source fails, or does not produce a valid
+    /// scalar after many attempts (which a working random source does
+    /// with negligible probability).
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        PrivateKey::generate(rng).map(Self)
+    }
+
     /// Decode a private key from `bytes`.
     ///
     /// `bytes` may be larger or smaller than the size of `n`: excess bytes
@@ -210,10 +224,6 @@ impl StaticPrivateKey {
         self.0.public_point().x_scalar()
     }
 
-    pub(crate) fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
-        PrivateKey::generate(rng).map(Self)
-    }
-
     pub(crate) fn raw_ecdsa_sign(&self, k: &Self, e: &Scalar, r: &Scalar) -> Scalar {
         // this is (e + r * d) / k
         let lhs_mont = self
@@ -237,6 +247,12 @@ impl Drop for SharedSecret {
     }
 }
 
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 struct AffineMontPoint {
     xy: [u64; 12],
@@ -706,7 +722,7 @@ impl Scalar {
 
     #[cfg(test)]
     fn small_u64(v: u64) -> Self {
-        Scalar([v, 0, 0, 0, 0, 0])
+        Self([v, 0, 0, 0, 0, 0])
     }
 
     /// Private test for zero
@@ -1108,17 +1124,15 @@ mod tests {
         println!(
             "pub(super) static CURVE_GENERATOR_PRECOMP_W5: super::JacobianMontPointTableW5 = ["
         );
-        let mut i = 1;
-        for point in precomp.chunks_exact(18) {
+        for (i, point) in (1..).zip(precomp.chunks_exact(18)) {
             println!("// {i}G");
             for p in point {
                 println!("            0x{:016x}, ", p);
             }
-            i += 1;
         }
         println!("];");
 
-        println!("");
+        println!();
         println!("table size is {} bytes", size_of_val(&precomp));
     }
 }
@@ -0,0 +1,55 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use crate::low::{Entry, poly1305};
+
+/// An in-progress Poly1305 computation.
+///
+/// Poly1305 is a one-time authenticator: a key must never be used
+/// for more than one message.  Typically the key is derived per-message
+/// from a stream cipher, as in ChaCha20-Poly1305.
+///
+/// Poly1305 is described in [RFC8439](https://datatracker.ietf.org/doc/html/rfc8439#section-2.5).
+pub struct Poly1305 {
+    inner: poly1305::Poly1305,
+}
+
+impl Poly1305 {
+    /// Start a new Poly1305 computation, with the given one-time key.
+    pub fn new(key: &[u8; 32]) -> Self {
+        let _entry = Entry::new_secret();
+        Self {
+            inner: poly1305::Poly1305::new(key),
+        }
+    }
+
+    /// Add `bytes` to the message.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let _entry = Entry::new_secret();
+        self.inner.add_bytes(bytes);
+    }
+
+    /// Complete the Poly1305 computation, returning the tag.
+    pub fn finish(self) -> [u8; 16] {
+        let _entry = Entry::new_secret();
+        self.inner.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc8439_test_vector() {
+        let key = b"\x85\xd6\xbe\x78\x57\x55\x6d\x33\x7f\x44\x52\xfe\x42\xd5\x06\xa8\
+                    \x01\x03\x80\x8a\xfb\x0d\xb2\xfd\x4a\xbf\xf6\xaf\x41\x49\xf5\x1b";
+        let mut p = Poly1305::new(key);
+        p.update(b"Cryptographic Forum ");
+        p.update(b"Research Group");
+        assert_eq!(
+            &p.finish(),
+            b"\xa8\x06\x1d\xc1\x30\x51\x36\xc6\xc2\x2b\x8b\xaf\x0c\x01\x27\xa9"
+        );
+    }
+}
@@ -0,0 +1,988 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! The ristretto255 prime-order group, as specified in
+//! [RFC9496](https://datatracker.ietf.org/doc/html/rfc9496).
+//!
+//! This is built on the twisted Edwards form of curve25519.  Field and
+//! scalar arithmetic uses the generic Montgomery bignum primitives.
+
+use super::util;
+use crate::Error;
+use crate::low;
+use crate::mid::rng::RandomSource;
+
+mod precomp;
+
+/// A ristretto255 group element.
+#[derive(Clone, Copy, Debug)]
+pub struct Element(EdwardsPoint);
+
+impl Element {
+    pub(crate) const BYTES: usize = 32;
+
+    /// The canonical generator.
+    #[cfg(test)]
+    pub(crate) fn generator() -> Self {
+        Self(EdwardsPoint {
+            x: BASE_X,
+            y: BASE_Y,
+            z: ONE,
+            t: BASE_T,
+        })
+    }
+
+    /// The identity element.
+    pub(crate) fn identity() -> Self {
+        Self(EdwardsPoint::identity())
+    }
+
+    /// Decode an element, rejecting non-canonical encodings.
+    ///
+    /// See RFC9496 section 4.3.1.
+    pub(crate) fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes: &[u8; Self::BYTES] = bytes.try_into().map_err(|_| Error::WrongLength)?;
+        let s = FieldElement::from_canonical_bytes(bytes).ok_or(Error::NotOnCurve)?;
+        if s.is_negative() == 1 {
+            return Err(Error::NotOnCurve);
+        }
+
+        let ss = s.sqr();
+        let u1 = ONE.sub(&ss);
+        let u2 = ONE.add(&ss);
+        let u2_sqr = u2.sqr();
+
+        let v = D.mul(&u1.sqr()).neg().sub(&u2_sqr);
+
+        let (was_square, invsqrt) = FieldElement::sqrt_ratio_m1(&ONE, &v.mul(&u2_sqr));
+
+        let den_x = invsqrt.mul(&u2);
+        let den_y = invsqrt.mul(&den_x).mul(&v);
+
+        let x = s.add(&s).mul(&den_x).abs();
+        let y = u1.mul(&den_y);
+        let t = x.mul(&y);
+
+        if was_square == 0 || t.is_negative() == 1 || y.is_zero() == 1 {
+            return Err(Error::NotOnCurve);
+        }
+
+        Ok(Self(EdwardsPoint { x, y, z: ONE, t }))
+    }
+
+    /// Encode this element.
+    ///
+    /// See RFC9496 section 4.3.2.
+    pub(crate) fn encode(&self) -> [u8; Self::BYTES] {
+        let EdwardsPoint { x, y, z, t } = self.0;
+
+        let u1 = z.add(&y).mul(&z.sub(&y));
+        let u2 = x.mul(&y);
+
+        let (_, invsqrt) = FieldElement::sqrt_ratio_m1(&ONE, &u1.mul(&u2.sqr()));
+        let den1 = invsqrt.mul(&u1);
+        let den2 = invsqrt.mul(&u2);
+        let z_inv = den1.mul(&den2).mul(&t);
+
+        let ix0 = x.mul(&SQRT_M1);
+        let iy0 = y.mul(&SQRT_M1);
+        let enchanted_denominator = den1.mul(&INVSQRT_A_MINUS_D);
+
+        let rotate = t.mul(&z_inv).is_negative();
+        let x = FieldElement::select(&x, &iy0, rotate);
+        let y = FieldElement::select(&y, &ix0, rotate);
+        let den_inv = FieldElement::select(&den2, &enchanted_denominator, rotate);
+
+        let y = FieldElement::select(&y, &y.neg(), x.mul(&z_inv).is_negative());
+        den_inv.mul(&z.sub(&y)).abs().to_bytes()
+    }
+
+    /// Map 64 uniformly random bytes to an element.
+    ///
+    /// This is the element derivation function from RFC9496 section 4.3.4,
+    /// and is used by `hash_to_ristretto255`.
+    pub(crate) fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        let (r0, r1) = bytes.split_at(32);
+        let p1 = Self::map(&FieldElement::from_bytes_masked(r0.try_into().unwrap()));
+        let p2 = Self::map(&FieldElement::from_bytes_masked(r1.try_into().unwrap()));
+        Self(p1.add(&p2))
+    }
+
+    fn map(t: &FieldElement) -> EdwardsPoint {
+        let minus_one = ONE.neg();
+
+        let r = SQRT_M1.mul(&t.sqr());
+        let u = r.add(&ONE).mul(&ONE_MINUS_D_SQ);
+        let v = minus_one.sub(&r.mul(&D)).mul(&r.add(&D));
+
+        let (was_square, s) = FieldElement::sqrt_ratio_m1(&u, &v);
+        let s_prime = s.mul(t).abs().neg();
+        let s = FieldElement::select(&s_prime, &s, was_square);
+        let c = FieldElement::select(&r, &minus_one, was_square);
+
+        let n = c.mul(&r.sub(&ONE)).mul(&D_MINUS_ONE_SQ).sub(&v);
+
+        let w0 = s.add(&s).mul(&v);
+        let w1 = n.mul(&SQRT_AD_MINUS_ONE);
+        let ss = s.sqr();
+        let w2 = ONE.sub(&ss);
+        let w3 = ONE.add(&ss);
+
+        EdwardsPoint {
+            x: w0.mul(&w3),
+            y: w2.mul(&w1),
+            z: w1.mul(&w3),
+            t: w0.mul(&w2),
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        Self(self.0.add(&other.0))
+    }
+
+    /// Multiply this element by `scalar`, in constant time.
+    #[must_use]
+    pub(crate) fn mul(&self, scalar: &Scalar) -> Self {
+        Self(self.0.multiply(scalar))
+    }
+
+    /// Multiply the generator by `scalar`, in constant time.
+    pub(crate) fn base_multiply(scalar: &Scalar) -> Self {
+        Self(EdwardsPoint::base_multiply(scalar))
+    }
+
+    /// Equality, in constant time.
+    ///
+    /// Different representatives of the same element compare equal.
+    pub(crate) fn ct_equal(&self, other: &Self) -> bool {
+        let a = &self.0;
+        let b = &other.0;
+        (a.x.mul(&b.y).ct_eq(&a.y.mul(&b.x)) | a.y.mul(&b.y).ct_eq(&a.x.mul(&b.x))) == 1
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        self.ct_equal(&Self::identity())
+    }
+}
+
+/// A scalar, modulo the group order `l`.
+pub struct Scalar([u64; 4]);
+
+impl Scalar {
+    pub(crate) const BYTES: usize = 32;
+
+    /// Decode a little-endian scalar.
+    ///
+    /// This returns an error if the value is zero or not less
+    /// than the group order.
+    pub(crate) fn from_bytes_checked(bytes: &[u8]) -> Result<Self, Error> {
+        let words = util::little_endian_slice_to_u64x4(bytes).ok_or(Error::WrongLength)?;
+        let r = Self(words);
+        if low::bignum_cmp_lt(&r.0, &ORDER) == 0 || r.is_zero() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(r)
+    }
+
+    /// Decode a little-endian scalar, which may be zero.
+    ///
+    /// Returns `None` if the value is not less than the group order.
+    pub(crate) fn from_canonical_bytes(bytes: &[u8; Self::BYTES]) -> Option<Self> {
+        let r = Self(util::little_endian_to_u64x4(bytes));
+        match low::bignum_cmp_lt(&r.0, &ORDER) {
+            0 => None,
+            _ => Some(r),
+        }
+    }
+
+    /// Reduce a 64-byte little-endian value modulo the group order.
+    pub(crate) fn from_wide_bytes(bytes: &[u8; 64]) -> Self {
+        let (lo, hi) = bytes.split_at(32);
+        let lo = util::little_endian_to_u64x4(lo.try_into().unwrap());
+        let hi = util::little_endian_to_u64x4(hi.try_into().unwrap());
+
+        // (lo * RR) / R = lo * R, and demont removes that R.
+        let mut lo_r = [0u64; 4];
+        low::bignum_montmul(&mut lo_r, &lo, &ORDER_RR, &ORDER);
+        let mut lo_reduced = [0u64; 4];
+        low::bignum_demont(&mut lo_reduced, &lo_r, &ORDER);
+
+        // (hi * RR) / R = hi * 2^256
+        let mut hi_shifted = [0u64; 4];
+        low::bignum_montmul(&mut hi_shifted, &hi, &ORDER_RR, &ORDER);
+
+        let mut r = Self([0; 4]);
+        low::bignum_modadd(&mut r.0, &lo_reduced, &hi_shifted, &ORDER);
+        r
+    }
+
+    /// Generate a uniformly random, non-zero scalar.
+    pub(crate) fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        for _ in 0..64 {
+            let mut r = [0u8; Self::BYTES];
+            rng.fill(&mut r)?;
+            // the order is a little over 2^252
+            r[31] &= 0x1f;
+            if let Ok(s) = Self::from_bytes_checked(&r) {
+                return Ok(s);
+            }
+        }
+
+        Err(Error::RngFailed)
+    }
+
+    pub(crate) fn as_bytes(&self) -> [u8; Self::BYTES] {
+        util::u64x4_to_little_endian(&self.0)
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        low::bignum_eq(&self.0, &[0; 4])
+    }
+
+    pub(crate) fn ct_equal(&self, other: &Self) -> bool {
+        low::bignum_eq(&self.0, &other.0)
+    }
+
+    #[must_use]
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let mut r = Self([0; 4]);
+        low::bignum_modadd(&mut r.0, &self.0, &other.0, &ORDER);
+        r
+    }
+
+    #[must_use]
+    pub(crate) fn sub(&self, other: &Self) -> Self {
+        let mut r = Self([0; 4]);
+        low::bignum_modsub(&mut r.0, &self.0, &other.0, &ORDER);
+        r
+    }
+
+    #[must_use]
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        // (a * b / R) * RR / R = a * b
+        let mut tmp = [0u64; 4];
+        low::bignum_montmul(&mut tmp, &self.0, &other.0, &ORDER);
+        let mut r = Self([0; 4]);
+        low::bignum_montmul(&mut r.0, &tmp, &ORDER_RR, &ORDER);
+        r
+    }
+
+    /// Multiplicative inverse.  `self` must not be zero.
+    #[must_use]
+    pub(crate) fn inv(&self) -> Self {
+        let mut r = Self([0; 4]);
+        let mut temp = [0u64; 4 * 3];
+        low::bignum_modinv(&mut r.0, &self.0, &ORDER, &mut temp);
+        r
+    }
+
+    /// Little-endian nibbles, most significant first.
+    fn nibbles_msb_first(&self) -> impl Iterator<Item = u8> {
+        let bytes = self.as_bytes();
+        (0..64)
+            .rev()
+            .map(move |i| (bytes[i / 2] >> ((i % 2) * 4)) & 0xf)
+    }
+}
+
+impl Clone for Scalar {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl Drop for Scalar {
+    fn drop(&mut self) {
+        low::zeroise(&mut self.0);
+    }
+}
+
+/// A point on the twisted Edwards curve -x^2 + y^2 = 1 + d x^2 y^2,
+/// in extended coordinates (X : Y : Z : T) where x = X/Z, y = Y/Z
+/// and xy = T/Z.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct EdwardsPoint {
+    pub(super) x: FieldElement,
+    pub(super) y: FieldElement,
+    pub(super) z: FieldElement,
+    pub(super) t: FieldElement,
+}
+
+impl EdwardsPoint {
+    const WORDS: usize = 16;
+
+    pub(super) fn identity() -> Self {
+        Self {
+            x: FieldElement::default(),
+            y: ONE,
+            z: ONE,
+            t: FieldElement::default(),
+        }
+    }
+
+    /// Complete addition, "add-2008-hwcd-3".
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let a = self.y.sub(&self.x).mul(&other.y.sub(&other.x));
+        let b = self.y.add(&self.x).mul(&other.y.add(&other.x));
+        let c = self.t.mul(&D2).mul(&other.t);
+        let d = self.z.add(&self.z).mul(&other.z);
+        let e = b.sub(&a);
+        let f = d.sub(&c);
+        let g = d.add(&c);
+        let h = b.add(&a);
+        Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            t: e.mul(&h),
+            z: f.mul(&g),
+        }
+    }
+
+    /// Doubling, "dbl-2008-hwcd" with a = -1.
+    fn double(&self) -> Self {
+        let a = self.x.sqr();
+        let b = self.y.sqr();
+        let c = self.z.sqr();
+        let c = c.add(&c);
+        let h = a.add(&b);
+        let e = h.sub(&self.x.add(&self.y).sqr());
+        let g = a.sub(&b);
+        let f = c.add(&g);
+        Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            t: e.mul(&h),
+            z: f.mul(&g),
+        }
+    }
+
+    /// Mixed addition with a point in Niels coordinates,
+    /// "madd-2008-hwcd-3".
+    ///
+    /// This is `add()`, where `other` has z = 1 and its other
+    /// coordinates are stored already combined.
+    fn add_niels(&self, other: &NielsPoint) -> Self {
+        let a = self.y.sub(&self.x).mul(&other.y_minus_x);
+        let b = self.y.add(&self.x).mul(&other.y_plus_x);
+        let c = self.t.mul(&other.xy2d);
+        let d = self.z.add(&self.z);
+        let e = b.sub(&a);
+        let f = d.sub(&c);
+        let g = d.add(&c);
+        let h = b.add(&a);
+        Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            t: e.mul(&h),
+            z: f.mul(&g),
+        }
+    }
+
+    /// Multiply the base point by `scalar`, in constant time.
+    ///
+    /// This uses the precomputed table `BASE_POINT_PRECOMP`, where
+    /// row `j` contains `[i * 16^j]B` for `i` in 0..16.  The result
+    /// is then the sum of one entry from each row, selected by each
+    /// nibble of `scalar`: 64 mixed additions and no doublings.
+    pub(super) fn base_multiply(scalar: &Scalar) -> Self {
+        let mut bytes = scalar.as_bytes();
+        let mut result = Self::identity();
+
+        for (j, row) in precomp::BASE_POINT_PRECOMP.iter().enumerate() {
+            let nibble = (bytes[j / 2] >> ((j % 2) * 4)) & 0xf;
+            let mut words = [0u64; NielsPoint::WORDS];
+            low::bignum_copy_row_from_table(
+                &mut words,
+                row,
+                16,
+                NielsPoint::WORDS as u64,
+                nibble as u64,
+            );
+            result = result.add_niels(&NielsPoint::from_words(&words));
+        }
+
+        low::zeroise(&mut bytes);
+        result
+    }
+
+    /// Fixed-window (w=4) scalar multiplication, with constant-time
+    /// table lookups.
+    pub(super) fn multiply(&self, scalar: &Scalar) -> Self {
+        let mut table = [0u64; 16 * Self::WORDS];
+        let mut row = Self::identity();
+        for entry in table.chunks_exact_mut(Self::WORDS) {
+            entry.copy_from_slice(&row.to_words());
+            row = row.add(self);
+        }
+
+        let mut result = Self::identity();
+        for nibble in scalar.nibbles_msb_first() {
+            result = result.double().double().double().double();
+            let mut words = [0u64; Self::WORDS];
+            low::bignum_copy_row_from_table(
+                &mut words,
+                &table,
+                16,
+                Self::WORDS as u64,
+                nibble as u64,
+            );
+            result = result.add(&Self::from_words(&words));
+        }
+
+        low::zeroise(&mut table);
+        result
+    }
+
+    fn to_words(self) -> [u64; Self::WORDS] {
+        let mut r = [0u64; Self::WORDS];
+        r[0..4].copy_from_slice(&self.x.0);
+        r[4..8].copy_from_slice(&self.y.0);
+        r[8..12].copy_from_slice(&self.z.0);
+        r[12..16].copy_from_slice(&self.t.0);
+        r
+    }
+
+    fn from_words(w: &[u64; Self::WORDS]) -> Self {
+        Self {
+            x: FieldElement(w[0..4].try_into().unwrap()),
+            y: FieldElement(w[4..8].try_into().unwrap()),
+            z: FieldElement(w[8..12].try_into().unwrap()),
+            t: FieldElement(w[12..16].try_into().unwrap()),
+        }
+    }
+}
+
+/// A point in Niels coordinates (y + x, y - x, 2dxy), with z = 1.
+///
+/// This is the form of the entries in `BASE_POINT_PRECOMP`.
+#[derive(Clone, Copy, Debug)]
+struct NielsPoint {
+    y_plus_x: FieldElement,
+    y_minus_x: FieldElement,
+    xy2d: FieldElement,
+}
+
+impl NielsPoint {
+    const WORDS: usize = 12;
+
+    #[cfg(test)]
+    fn from_point(p: &EdwardsPoint) -> Self {
+        // z ^ (p - 2)
+        let z_inv = p.z.pow(&[
+            0xffff_ffff_ffff_ffeb,
+            0xffff_ffff_ffff_ffff,
+            0xffff_ffff_ffff_ffff,
+            0x7fff_ffff_ffff_ffff,
+        ]);
+        let x = p.x.mul(&z_inv);
+        let y = p.y.mul(&z_inv);
+        Self {
+            y_plus_x: y.add(&x),
+            y_minus_x: y.sub(&x),
+            xy2d: x.mul(&y).mul(&D2),
+        }
+    }
+
+    #[cfg(test)]
+    fn to_words(self) -> [u64; Self::WORDS] {
+        let mut r = [0u64; Self::WORDS];
+        r[0..4].copy_from_slice(&self.y_plus_x.0);
+        r[4..8].copy_from_slice(&self.y_minus_x.0);
+        r[8..12].copy_from_slice(&self.xy2d.0);
+        r
+    }
+
+    fn from_words(w: &[u64; Self::WORDS]) -> Self {
+        Self {
+            y_plus_x: FieldElement(w[0..4].try_into().unwrap()),
+            y_minus_x: FieldElement(w[4..8].try_into().unwrap()),
+            xy2d: FieldElement(w[8..12].try_into().unwrap()),
+        }
+    }
+}
+
+/// See `EdwardsPoint::base_multiply()`.
+type BasePointTable = [[u64; 16 * NielsPoint::WORDS]; 64];
+
+/// An element of GF(2^255 - 19), fully reduced and in Montgomery form.
+#[derive(Clone, Copy, Debug, Default)]
+pub(super) struct FieldElement([u64; 4]);
+
+impl FieldElement {
+    /// Read 32 little-endian bytes, ignoring the top bit, and reduce mod p.
+    fn from_bytes_masked(bytes: &[u8; 32]) -> Self {
+        let mut words = util::little_endian_to_u64x4(bytes);
+        words[3] &= 0x7fff_ffff_ffff_ffff;
+        Self::from_integer(&words)
+    }
+
+    /// Read 32 little-endian bytes, returning `None` if they are not
+    /// the canonical encoding of an element.
+    pub(super) fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let words = util::little_endian_to_u64x4(bytes);
+        match low::bignum_cmp_lt(&words, &P) {
+            0 => None,
+            _ => Some(Self::from_integer(&words)),
+        }
+    }
+
+    /// `words` must be less than 2^256.
+    fn from_integer(words: &[u64; 4]) -> Self {
+        let mut r = Self::default();
+        low::bignum_montmul(&mut r.0, words, &P_RR, &P);
+        r
+    }
+
+    fn to_integer(self) -> [u64; 4] {
+        let mut r = [0u64; 4];
+        low::bignum_demont(&mut r, &self.0, &P);
+        r
+    }
+
+    pub(super) fn to_bytes(self) -> [u8; 32] {
+        util::u64x4_to_little_endian(&self.to_integer())
+    }
+
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let mut r = Self::default();
+        low::bignum_modadd(&mut r.0, &self.0, &other.0, &P);
+        r
+    }
+
+    pub(super) fn sub(&self, other: &Self) -> Self {
+        let mut r = Self::default();
+        low::bignum_modsub(&mut r.0, &self.0, &other.0, &P);
+        r
+    }
+
+    pub(super) fn neg(&self) -> Self {
+        Self::default().sub(self)
+    }
+
+    pub(super) fn mul(&self, other: &Self) -> Self {
+        let mut r = Self::default();
+        low::bignum_montmul(&mut r.0, &self.0, &other.0, &P);
+        r
+    }
+
+    pub(super) fn sqr(&self) -> Self {
+        let mut r = Self::default();
+        low::bignum_montsqr(&mut r.0, &self.0, &P);
+        r
+    }
+
+    /// Raise to a public exponent.
+    pub(super) fn pow(&self, exponent: &[u64; 4]) -> Self {
+        let mut r = ONE;
+        for word in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                r = r.sqr();
+                if (word >> bit) & 1 == 1 {
+                    r = r.mul(self);
+                }
+            }
+        }
+        r
+    }
+
+    /// Returns 1 if this element is "negative", ie. its canonical
+    /// encoding is odd.
+    pub(super) fn is_negative(&self) -> u8 {
+        (self.to_integer()[0] & 1) as u8
+    }
+
+    pub(super) fn is_zero(&self) -> u8 {
+        self.ct_eq(&Self::default())
+    }
+
+    fn ct_eq(&self, other: &Self) -> u8 {
+        low::bignum_eq(&self.0, &other.0) as u8
+    }
+
+    fn abs(&self) -> Self {
+        Self::select(self, &self.neg(), self.is_negative())
+    }
+
+    /// Return `p0` if `select` == 0, `p1` otherwise.
+    pub(super) fn select(p0: &Self, p1: &Self, select: u8) -> Self {
+        let mut r = Self::default();
+        low::bignum_mux(select as u64, &mut r.0, &p1.0, &p0.0);
+        r
+    }
+
+    /// Returns `(was_square, r)` where `r` is the non-negative square
+    /// root of `u / v` if that exists, or of `SQRT_M1 * u / v` otherwise.
+    ///
+    /// See RFC9496 section 4.2.
+    pub(super) fn sqrt_ratio_m1(u: &Self, v: &Self) -> (u8, Self) {
+        let v3 = v.sqr().mul(v);
+        let v7 = v3.sqr().mul(v);
+        let r = u.mul(&v3).mul(&u.mul(&v7).pow(&P_MINUS_5_OVER_8));
+        let check = v.mul(&r.sqr());
+
+        let neg_u = u.neg();
+        let correct_sign_sqrt = check.ct_eq(u);
+        let flipped_sign_sqrt = check.ct_eq(&neg_u);
+        let flipped_sign_sqrt_i = check.ct_eq(&neg_u.mul(&SQRT_M1));
+
+        let r_prime = SQRT_M1.mul(&r);
+        let r = Self::select(&r, &r_prime, flipped_sign_sqrt | flipped_sign_sqrt_i);
+        (correct_sign_sqrt | flipped_sign_sqrt, r.abs())
+    }
+}
+
+const P: [u64; 4] = [
+    0xffff_ffff_ffff_ffed,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0x7fff_ffff_ffff_ffff,
+];
+
+/// 2^512 mod p
+const P_RR: [u64; 4] = [0x0000_0000_0000_05a4, 0, 0, 0];
+
+const P_MINUS_5_OVER_8: [u64; 4] = [
+    0xffff_ffff_ffff_fffd,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0x0fff_ffff_ffff_ffff,
+];
+
+/// The group order, l = 2^252 + 27742317777372353535851937790883648493
+const ORDER: [u64; 4] = [
+    0x5812_631a_5cf5_d3ed,
+    0x14de_f9de_a2f7_9cd6,
+    0x0000_0000_0000_0000,
+    0x1000_0000_0000_0000,
+];
+
+/// 2^512 mod l
+const ORDER_RR: [u64; 4] = [
+    0xa406_11e3_449c_0f01,
+    0xd00e_1ba7_6885_9347,
+    0xceec_73d2_17f5_be65,
+    0x0399_411b_7c30_9a3d,
+];
+
+// The following are in Montgomery form.
+
+pub(super) const ONE: FieldElement = FieldElement([0x0000_0000_0000_0026, 0, 0, 0]);
+
+pub(super) const D: FieldElement = FieldElement([
+    0x80ed_8bfe_df47_e9fa,
+    0x10a1_8777_afc6_2973,
+    0xe593_9207_bc18_8690,
+    0x2c82_2b5a_729f_c526,
+]);
+
+const D2: FieldElement = FieldElement([
+    0x01db_17fd_be8f_d3f4,
+    0x2143_0eef_5f8c_52e7,
+    0xcb27_240f_7831_0d20,
+    0x5904_56b4_e53f_8a4d,
+]);
+
+const SQRT_M1: FieldElement = FieldElement([
+    0x3b58_07d4_fe2b_db04,
+    0x03f5_90fd_b51b_e9ed,
+    0x6d6e_16bf_3362_02d1,
+    0x7577_6b0b_d6c7_1ba8,
+]);
+
+const SQRT_AD_MINUS_ONE: FieldElement = FieldElement([
+    0xca8e_9bca_e848_d932,
+    0x1163_15cc_13dc_9420,
+    0x430d_85dd_6a7d_2ba8,
+    0x399d_6260_757c_c98a,
+]);
+
+const INVSQRT_A_MINUS_D: FieldElement = FieldElement([
+    0xd3d5_a74f_0dd7_a555,
+    0x54fd_4777_65b7_084a,
+    0x60de_57ab_b446_1997,
+    0x601c_56dc_d41f_800f,
+]);
+
+const ONE_MINUS_D_SQ: FieldElement = FieldElement([
+    0x9e69_72bc_0636_b784,
+    0x9b3d_ee6e_7bfb_e05b,
+    0xcc11_82ea_44c1_3bde,
+    0x6171_050a_86b3_6000,
+]);
+
+const D_MINUS_ONE_SQ: FieldElement = FieldElement([
+    0x5fbb_7546_3b39_74ae,
+    0x437f_02a2_2477_ccbd,
+    0x68c7_5906_430d_b701,
+    0x458a_a440_940d_15b1,
+]);
+
+#[cfg(test)]
+const BASE_X: FieldElement = FieldElement([
+    0xe2ca_bc55_3f9d_a287,
+    0x9ca5_9856_2396_e489,
+    0x9879_936b_ade4_b5b7,
+    0x759e_2370_7e60_77d0,
+]);
+
+#[cfg(test)]
+const BASE_Y: FieldElement = FieldElement([
+    0x3333_3333_3333_334a,
+    0x3333_3333_3333_3333,
+    0x3333_3333_3333_3333,
+    0x3333_3333_3333_3333,
+]);
+
+#[cfg(test)]
+const BASE_T: FieldElement = FieldElement([
+    0x4f08_96aa_994a_e86c,
+    0xe3b7_ad11_b612_506e,
+    0x46c7_a922_f183_c492,
+    0x5e18_1c59_feb3_930d,
+]);
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of_val;
+
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn base_point_precomp_slow() -> BasePointTable {
+        let mut table = [[0u64; 16 * NielsPoint::WORDS]; 64];
+        let mut base = Element::generator().0;
+
+        for row in table.iter_mut() {
+            let mut entry = EdwardsPoint::identity();
+            for words in row.chunks_exact_mut(NielsPoint::WORDS) {
+                words.copy_from_slice(&NielsPoint::from_point(&entry).to_words());
+                entry = entry.add(&base);
+            }
+            base = base.double().double().double().double();
+        }
+
+        table
+    }
+
+    #[test]
+    fn base_point_precomp() {
+        let table = base_point_precomp_slow();
+
+        println!("// This file is autogenerated, run");
+        println!("// `cargo test ristretto255::tests::base_point_precomp -- --nocapture`");
+        println!("// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0");
+        println!();
+        println!("pub(super) static BASE_POINT_PRECOMP: super::BasePointTable = [");
+        for (j, row) in table.iter().enumerate() {
+            println!("    // 0B..15B << {}", j * 4);
+            println!("    [");
+            for word in row {
+                println!("        0x{word:016x},");
+            }
+            println!("    ],");
+        }
+        println!("];");
+
+        println!();
+        println!("table size is {} bytes", size_of_val(&table));
+        assert_eq!(table, precomp::BASE_POINT_PRECOMP);
+    }
+
+    #[test]
+    fn base_multiply() {
+        for scalar in [
+            Scalar::from_wide_bytes(&[0; 64]),
+            Scalar::from_wide_bytes(&[0x01; 64]),
+            Scalar::from_wide_bytes(&[0xff; 64]),
+            Scalar::from_wide_bytes(&core::array::from_fn(|i| i as u8)),
+        ] {
+            let expected = Element::generator().mul(&scalar);
+            let got = Element::base_multiply(&scalar);
+            assert!(got.ct_equal(&expected));
+            assert_eq!(got.encode(), expected.encode());
+        }
+    }
+
+    #[test]
+    fn multiples_of_generator() {
+        // RFC9496 appendix A.1
+        let expected = [
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+            "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+            "94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+            "da80862773358b466ffadfe0b3293ab3d9fd53c5ea6c955358f568322daf6a57",
+            "e882b131016b52c1d3337080187cf768423efccbb517bb495ab812c4160ff44e",
+            "f64746d3c92b13050ed8d80236a7f0007c3b3f962f5ba793d19a601ebb1df403",
+            "44f53520926ec81fbd5a387845beb7df85a96a24ece18738bdcfa6a7822a176d",
+            "903293d8f2287ebe10e2374dc1a53e0bc887e592699f02d077d5263cdd55601c",
+            "02622ace8f7303a31cafc63f8fc48fdc16e1c8c8d234b2f0d6685282a9076031",
+            "20706fd788b2720a1ed2a5dad4952b01f413bcf0e7564de8cdc816689e2db95f",
+            "bce83f8ba5dd2fa572864c24ba1810f9522bc6004afe95877ac73241cafdab42",
+            "e4549ee16b9aa03099ca208c67adafcafa4c3f3e4e5303de6026e3ca8ff84460",
+            "aa52e000df2e16f55fb1032fc33bc42742dad6bd5a8fc0be0167436c5948501f",
+            "46376b80f409b29dc2b5f6f0c52591990896e5716f41477cd30085ab7f10301e",
+            "e0c418f7c8d9c4cdd7395b93ea124f3ad99021bb681dfc3302a9d99a2e53e64e",
+        ];
+
+        let mut p = Element::identity();
+        for (i, e) in expected.iter().enumerate() {
+            assert_eq!(&hex(&p.encode()), e);
+
+            let decoded = Element::decode(&unhex(e)).unwrap();
+            assert!(decoded.ct_equal(&p));
+            assert_eq!(&hex(&decoded.encode()), e);
+
+            let mut s = [0u8; 32];
+            s[0] = i as u8;
+            if i > 0 {
+                let s = Scalar::from_bytes_checked(&s).unwrap();
+                assert!(Element::base_multiply(&s).ct_equal(&p));
+            }
+
+            p = p.add(&Element::generator());
+        }
+    }
+
+    #[test]
+    fn invalid_encodings() {
+        // RFC9496 appendix A.2
+        for bad in [
+            // non-canonical field encodings
+            "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "f3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            // negative field elements
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "ed57ffd8c914fb201471d1c3d245ce3c746fcbe63a3679d51b6a516ebebe0e20",
+            "c34c4e1826e5d403b78e246e88aa051c36ccf0aafebffe137d148a2bf9104562",
+            "c940e5a4404157cfb1628b108db051a8d439e1a421394ec4ebccb9ec92a8ac78",
+            "47cfc5497c53dc8e61c91d17fd626ffb1c49e2bca94eed052281b510b1117a24",
+            "f1c6165d33367351b0da8f6e4511010c68174a03b6581212c71c0e1d026c3c72",
+            "87260f7a2f12495118360f02c26a470f450dadf34a413d21042b43b9d93e1309",
+            // non-square x^2
+            "26948d35ca62e643e26a83177332e6b6afeb9d08e4268b650f1f5bbd8d81d371",
+            "4eac077a713c57b4f4397629a4145982c661f48044dd3f96427d40b147d9742f",
+            "de6a7b00deadc788eb6b6c8d20c0ae96c2f2019078fa604fee5b87d6e989ad7b",
+            "bcab477be20861e01e4a0e295284146a510150d9817763caf1a6f4b422d67042",
+            "2a292df7e32cababbd9de088d1d1abec9fc0440f637ed2fba145094dc14bea08",
+            "f4a9e534fc0d216c44b218fa0c42d99635a0127ee2e53c712f70609649fdff22",
+            "8268436f8c4126196cf64b3c7ddbda90746a378625f9813dd9b8457077256731",
+            "2810e5cbc2cc4d4eece54f61c6f69758e289aa7ab440b3cbeaa21995c2f4232b",
+            // negative xy value
+            "3eb858e78f5a7254d8c9731174a94f76755fd3941c0ac93735c07ba14579630e",
+            "a45fdc55c76448c049a1ab33f17023edfb2be3581e9c7aade8a6125215e04220",
+            "d483fe813c6ba647ebbfd3ec41adca1c6130c2beeee9d9bf065c8d151c5f396e",
+            "8a2e1d30050198c65a54483123960ccc38aef6848e1ec8f5f780e8523769ba32",
+            "32888462f8b486c68ad7dd9610be5192bbeaf3b443951ac1a8118419d9fa097b",
+            "227142501b9d4355ccba290404bde41575b037693cef1f438c47f8fbf35d1165",
+            "5c37cc491da847cfeb9281d407efc41e15144c876e0170b499a96a22ed31e01e",
+            "445425117cb8c90edcbc7c1cc0e74f747f2c1efa5630a967c64f287792a48a4b",
+            // s = -1, which causes y = 0
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        ] {
+            assert_eq!(
+                Element::decode(&unhex(bad)).unwrap_err(),
+                Error::NotOnCurve,
+                "{bad}"
+            );
+        }
+        assert_eq!(Element::decode(&[0; 31]).unwrap_err(), Error::WrongLength);
+    }
+
+    #[test]
+    fn from_uniform_bytes() {
+        // RFC9496 appendix A.3
+        for (input, expected) in [
+            (
+                "5d1be09e3d0c82fc538112490e35701979d99e06ca3e2b5b54bffe8b4dc772c1\
+                 4d98b696a1bbfb5ca32c436cc61c16563790306c79eaca7705668b47dffe5bb6",
+                "3066f82a1a747d45120d1740f14358531a8f04bbffe6a819f86dfe50f44a0a46",
+            ),
+            (
+                "f116b34b8f17ceb56e8732a60d913dd10cce47a6d53bee9204be8b44f6678b27\
+                 0102a56902e2488c46120e9276cfe54638286b9e4b3cdb470b542d46c2068d38",
+                "f26e5b6f7d362d2d2a94c5d0e7602cb4773c95a2e5c31a64f133189fa76ed61b",
+            ),
+            (
+                "8422e1bbdaab52938b81fd602effb6f89110e1e57208ad12d9ad767e2e25510c\
+                 27140775f9337088b982d83d7fcf0b2fa1edffe51952cbe7365e95c86eaf325c",
+                "006ccd2a9e6867e6a2c5cea83d3302cc9de128dd2a9a57dd8ee7b9d7ffe02826",
+            ),
+            (
+                "ac22415129b61427bf464e17baee8db65940c233b98afce8d17c57beeb7876c2\
+                 150d15af1cb1fb824bbd14955f2b57d08d388aab431a391cfc33d5bafb5dbbaf",
+                "f8f0c87cf237953c5890aec3998169005dae3eca1fbb04548c635953c817f92a",
+            ),
+            (
+                "165d697a1ef3d5cf3c38565beefcf88c0f282b8e7dbd28544c483432f1cec767\
+                 5debea8ebb4e5fe7d6f6e5db15f15587ac4d4d4a1de7191e0c1ca6664abcc413",
+                "ae81e7dedf20a497e10c304a765c1767a42d6e06029758d2d7e8ef7cc4c41179",
+            ),
+            (
+                "a836e6c9a9ca9f1e8d486273ad56a78c70cf18f0ce10abb1c7172ddd605d7fd2\
+                 979854f47ae1ccf204a33102095b4200e5befc0465accc263175485f0e17ea5c",
+                "e2705652ff9f5e44d3e841bf1c251cf7dddb77d140870d1ab2ed64f1a9ce8628",
+            ),
+            (
+                "2cdc11eaeb95daf01189417cdddbf95952993aa9cb9c640eb5058d09702c7462\
+                 2c9965a697a3b345ec24ee56335b556e677b30e6f90ac77d781064f866a3c982",
+                "80bd07262511cdde4863f8a7434cef696750681cb9510eea557088f76d9e5065",
+            ),
+        ] {
+            let e = Element::from_uniform_bytes(&unhex(input).try_into().unwrap());
+            assert_eq!(hex(&e.encode()), expected);
+        }
+    }
+
+    #[test]
+    fn scalar_arithmetic() {
+        // l - 1, as a wide value, reduces to itself
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&util::u64x4_to_little_endian(&ORDER));
+        wide[0] -= 1;
+        let minus_one = Scalar::from_wide_bytes(&wide);
+        assert_eq!(minus_one.as_bytes(), wide[..32]);
+
+        // 2^512 - 1 mod l
+        let all_ones = Scalar::from_wide_bytes(&[0xff; 64]);
+        assert_eq!(
+            hex(&all_ones.as_bytes()),
+            "000f9c44e31106a447938568a71b0ed065bef517d273ecce3d9a307c1b419903"
+        );
+
+        let one = minus_one.mul(&minus_one);
+        let mut expected = [0u8; 32];
+        expected[0] = 1;
+        assert_eq!(one.as_bytes(), expected);
+        assert!(one.add(&minus_one).is_zero());
+        assert!(all_ones.mul(&all_ones.inv()).ct_equal(&one));
+        assert!(one.sub(&all_ones).add(&all_ones).ct_equal(&one));
+
+        assert_eq!(
+            Scalar::from_bytes_checked(&util::u64x4_to_little_endian(&ORDER)).err(),
+            Some(Error::OutOfRange)
+        );
+        assert_eq!(
+            Scalar::from_bytes_checked(&[0; 32]).err(),
+            Some(Error::OutOfRange)
+        );
+
+        // (l - 1) * G = -G
+        let p = Element::base_multiply(&minus_one);
+        assert!(p.add(&Element::generator()).is_identity());
+    }
+}
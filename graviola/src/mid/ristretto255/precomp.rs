@@ -0,0 +1,12486 @@
+// This file is autogenerated, run
+// `cargo test ristretto255::tests::base_point_precomp -- --nocapture`
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+pub(super) static BASE_POINT_PRECOMP: super::BasePointTable = [
+    // 0B..15B << 0
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x15fdef8872d0d5e4,
+        0xcfd8cb8956ca17bd,
+        0xcbacc69ee117e8ea,
+        0x28d156a3b193ab03,
+        0x506876ddf39590b0,
+        0x968d9add0f9c4ea9,
+        0x9ab99fc7854e7d7b,
+        0x3d950fc2b4d2bb62,
+        0x7fd8acd21c354dd0,
+        0xc458755061592f8d,
+        0x6014ac42d7ff4acd,
+        0x7c9851879bd716fd,
+        0xb17a6f7ddaf8e7d8,
+        0xa47b64561e5e78f3,
+        0x74baeb1f85c50a63,
+        0x37c8ed730294dae1,
+        0x92ce8c49e6d7b93d,
+        0x407527d3a259ac98,
+        0x577941fc565c4351,
+        0x7cf436b3b3db8abf,
+        0x17350aaa95142c8d,
+        0x20b602dbe4facbda,
+        0xe251d399ab1dc34e,
+        0x2400784f4c7fb31d,
+        0xff9838fb6b6a73cc,
+        0x596f9f4283ef3695,
+        0xaa0de0639a35516c,
+        0x1f4f9818ef24f454,
+        0xd26abc887d873be2,
+        0xe15e77b01ac1ae9a,
+        0x2d6a15e0f141e016,
+        0x57793216aba26a9f,
+        0x11e9db5c0d002644,
+        0x25fd37102ffe6a8f,
+        0x16c20852ec0c8d55,
+        0x61f5bdfcc790d583,
+        0x011e218b39696fd4,
+        0x591b884ab3938656,
+        0x03ccf7334391bb0d,
+        0x7229867708120fe8,
+        0x43b4bf7ccd73ad2b,
+        0x7ec866d2d265352c,
+        0x1491d06efbcbf119,
+        0x5fdb655942566ef9,
+        0x5ef764b329a9de15,
+        0x6b7c80504fc4b6f9,
+        0xeea2ccf8666c2e08,
+        0x714fe468bea73a6f,
+        0x0ec7f2194899ca76,
+        0xf9eacd0f98172e64,
+        0xd224e3d5787d122c,
+        0x205c6bd0858d0702,
+        0xef99690a62a9e00e,
+        0x9e03171127d0313c,
+        0xbb0bca435eefd429,
+        0x290ec534e60ec233,
+        0x03e2a5d92c4f613a,
+        0xcc153128fc2d2b84,
+        0xbc114ba61e26d0b3,
+        0x0b5843a5eadd1af6,
+        0x9deb76f3ad2ecf0e,
+        0x07cd32561dca0e38,
+        0x0f191390694ed21b,
+        0x28105d1c2a506c21,
+        0xec90ff1654a2e188,
+        0xf7a39f9de931d280,
+        0xc33dca4eca4a6d82,
+        0x6b0f51bfddb7e3b3,
+        0xd78f7b4a6ec73312,
+        0x44c1407bbe01c239,
+        0x73f0172338deb034,
+        0x7b7299f39bfc87be,
+        0xe5e9c6f603ac4fd6,
+        0x48a7e6b6a96a9f3b,
+        0xe0783f51fc7edee7,
+        0x6824cb9bf8fdbec4,
+        0xac809ef5437100de,
+        0x1fc4f25adda14ae5,
+        0x70cd8fcfafbc90ac,
+        0x5e74dbd4e0558b57,
+        0xd9824bb0416aacb8,
+        0x9280248ac74c6eff,
+        0x4331c1a921c3d22c,
+        0x33b5c637be4f78d6,
+        0x51374524b8d74960,
+        0x229283cba040a695,
+        0x4da937321452f742,
+        0x5940584e820a0514,
+        0xa4d7f15227b3d979,
+        0x4d1f972180eb3517,
+        0x8dd3bf985ced5758,
+        0x31656fed6e1442f8,
+        0x66ca7e53ee218aad,
+        0x6842d7e4c060ea0c,
+        0xc0daafb82f0a4387,
+        0x397275aef253aa2c,
+        0x08e35e94bcfeba17,
+        0xaff7848621f28155,
+        0xae73aff3780878f6,
+        0x539935a69ce0e754,
+        0x2258f8b489611067,
+        0xa7d3412862c9bd26,
+        0x623f6552580e2a2e,
+        0x728d682b3cbf3d50,
+        0xfeda8c6f6a580088,
+        0xb1ed9488cdd532fa,
+        0x60a96e2bf196bd65,
+        0x2eb6ec500214a2fd,
+        0xde63f146a0514e90,
+        0x6265d77b478b4d0f,
+        0xfa9e80c90ea86e6e,
+        0x0b998a095e13a92f,
+        0x821eb3191cb346ce,
+        0xbcd398131739a70b,
+        0xa92874627eb08310,
+        0x7e657892e1fb2a5f,
+        0xe9145dd89104f05c,
+        0x4fcc464175aebb9e,
+        0xdd5b9c495160df5a,
+        0x316a6048bc656a6f,
+        0x165a13ac8f065e5d,
+        0x1e5bd2ba5326d9d1,
+        0x8f47a8c97aa7b906,
+        0x5d775d98884353fc,
+        0x54278256e9edfd35,
+        0x53dea1a7ab43852d,
+        0x49d16aadd110f827,
+        0x296f490635bb9cf2,
+        0x1668a5639c5479ec,
+        0x768ecfed7f6df078,
+        0x1f489cd0e1efe90c,
+        0x2b70a326e73300ba,
+        0x93f109e4ce85669c,
+        0x50fd2e0a17d71f37,
+        0x6128d4e4a3ec8fe6,
+        0x1e961b08f708db1a,
+        0xce8d8378e71a9622,
+        0x4c886f7e6279876b,
+        0x3b935260e3bea2d3,
+        0x02e729a08a452f42,
+        0x6c941822f0024b82,
+        0x9428614a179791c5,
+        0xf82f27004e78e68a,
+        0x477c81223b90fd89,
+        0x6abcd1240c12eaec,
+        0x6db2a8f6fa09149e,
+        0x641e16d143259d26,
+        0x3dda0d70fc87f5b2,
+        0xf07a2ec97ef66fd2,
+        0xf98affed13ba89ed,
+        0x50c6738555bb4589,
+        0x5b5e36b1784570e1,
+        0x6057e9c718ec455f,
+        0x604c3e672f0d88d2,
+        0x2f824ee59eec0597,
+        0x685ac9a73d67deca,
+        0xc8e0c3115eef344c,
+        0x05dcf68d0d932a0f,
+        0xe695489e03422db1,
+        0x6e085a766f714b23,
+        0x3a9686377b6cbff7,
+        0xa3b06e5a94803967,
+        0x8ce0e7d67712a334,
+        0x51c2c2958afb1f2e,
+        0x5c03521c2078160a,
+        0xa5488d462532668e,
+        0x2cbb2aa952261b5f,
+        0x746cbd082bb33de9,
+        0x76b24874f0dcd5e7,
+        0xeecce0f1a72d6833,
+        0xca2a3338ca0b96f3,
+        0x0792198dccd05068,
+        0x99c081ee78e6bd1e,
+        0x65631a5e52b87fc4,
+        0x5f37213124781bcb,
+        0x0cfc77cfaeee106b,
+        0xe4ed8b1120fae30c,
+        0xea6a059c337b1761,
+        0x78e16ea3f1cddcc2,
+        0x0a5c6ae79df819cc,
+    ],
+    // 0B..15B << 4
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x72aeb084c3650af6,
+        0x92bda1a37b80712f,
+        0x2224a5fbeb2da371,
+        0x00c64ce94afdc273,
+        0xd1bc2408a73ad3b4,
+        0xccbfb63aaec1eaa4,
+        0x3effdbbd804f2862,
+        0x6bee831572f04394,
+        0xadf67aa4589a1620,
+        0x267d9319cb5bd02d,
+        0xf8e95d44bf91676c,
+        0x00e87c3a33abc584,
+        0xd78e265d19c2fdf9,
+        0xdf263bda64add9e8,
+        0x0e62bd744f678a9a,
+        0x349b2fcee076a4fe,
+        0x21dedd2820437c2b,
+        0x963384d054f53701,
+        0x6bb12cd4fa61dd3a,
+        0x0b07009bf8d6a2ea,
+        0x3e846e62a1916fed,
+        0xe68dcc2c1ada8053,
+        0xe90b0e21093ca705,
+        0x53a000ff32eba277,
+        0xd51118e6677d1c6b,
+        0xacbae5f81c7c7774,
+        0xa38a4b63e063d449,
+        0x0485e17ffa96fd68,
+        0x53b083cdb28d2115,
+        0xdbaf6644505be917,
+        0x697127ba190e8b97,
+        0x0b6c80787073ab79,
+        0x6aae30c528eba050,
+        0xa8d10b4a32567aed,
+        0x35c38799b69ff48e,
+        0x561ab6bf1f56e78e,
+        0x33f25651b35db6af,
+        0x4c4343a232479bd5,
+        0x5c4d7b10a169566a,
+        0x74f873a2c27a1eea,
+        0x8fc219a9615d0332,
+        0x674e3066799befed,
+        0xc44a70fc84a93853,
+        0x2ea2ba1f34cded17,
+        0xac400e1965bc0350,
+        0x2f2c40f31a0bd613,
+        0xc4af67b0f3ba3bd5,
+        0x50a97d84a65134a4,
+        0xc0e17ff5df25e32e,
+        0x7d7f64dae17c2189,
+        0x1fcd754e5581265b,
+        0x50b39330cb40b939,
+        0xae685fd8c37534ca,
+        0x0bec969e1e5799ab,
+        0xfc63cf3e2864769c,
+        0x7b31887cfaa48b7c,
+        0x9e56b7b5cb92f5da,
+        0x415bdf16462feb5c,
+        0xa1c996beabcf6cb5,
+        0x759305ad0f7a46bc,
+        0x489def9820e60e50,
+        0x1473dd8d856bde72,
+        0x1f938e8a20ac1cc5,
+        0x2b05c73d539c53ff,
+        0x6fc17beab9dc17c8,
+        0xaa207f6e32d3875d,
+        0x99ea0268ffe7357e,
+        0x196657b7a7a98cd6,
+        0x8e785ee98231f4b0,
+        0x1196bc09de1e5981,
+        0x58346b0c5d91747c,
+        0x3cdc32a9569d1f79,
+        0xb305c4d04f6dba5c,
+        0x45fabbca4e100c08,
+        0x2266c808b56ab430,
+        0x0a200140c8273567,
+        0x9b912179d1c3d25e,
+        0x596bc3b1d8ea6764,
+        0xad17f4ba22980000,
+        0x539c4abef470ae39,
+        0x6bcdef8319c6caec,
+        0x89e3a5406f2495ed,
+        0x2aa9b3bb8354e2a3,
+        0x7acc4feca0dbbce2,
+        0x8bf950fafa10ed86,
+        0xc8feb70b62a46899,
+        0x2f2f9abab7f5e4ab,
+        0x5464099f6cfaf024,
+        0xbfcb89af7c5b26ff,
+        0xa9f64e50101c1d7d,
+        0x7e51e9fec1746a2e,
+        0x518f6ce11c5ab9b9,
+        0xb92c0f2fece00e16,
+        0xc82ee6ad11ff399a,
+        0xff65d690b3860464,
+        0x33ae2b1a464adbfd,
+        0xc4e52b48e1cc7772,
+        0xaae17360d68fb175,
+        0x3150eb962bfb2e92,
+        0x2f6a2b89cdffeda8,
+        0x4dbff0ad5721efd7,
+        0x042becff927f8ee8,
+        0x6493753f1cc537c5,
+        0x30f7dc36faa43785,
+        0x9469412033fb8b62,
+        0xbe27d9b0e2877ca5,
+        0xf9a1b2258dea3a3e,
+        0x10d92b6a88e48b1d,
+        0x995127be7faaadfd,
+        0xe493bf1c310c0106,
+        0x9a876c6e0219a920,
+        0x19a4a449087e895e,
+        0x2b53e41980d95d1d,
+        0xefc2c1ba9cf704b4,
+        0x8901ae9f17b6f844,
+        0x0eb5d0d317bbca96,
+        0xf7be26f4a53cff7c,
+        0x95b7a92bfd363c44,
+        0x4d4d6fe0bc22e499,
+        0x4d23bdf66de27838,
+        0x08d090791b4250a9,
+        0xd785b1a1e3830600,
+        0x713ed8254a7f2186,
+        0x2b77e34df8366b16,
+        0xa406d20a994d93d6,
+        0xc6ff712b8ce80767,
+        0x13239d03027e0a5d,
+        0x187c2faaa1cea339,
+        0x2d834d3b4a26b6e2,
+        0x7e8218c1d17a92ff,
+        0x38d8cd5aa3597b3b,
+        0x3012345d8018d14c,
+        0x4641695477bcb1be,
+        0x717580938b161918,
+        0xa511475e9e18bb8f,
+        0x211a72116cd4834a,
+        0x967e22658cadc7c4,
+        0x173730a9e33fc7c6,
+        0x468e27d1e55f610b,
+        0x5cdfadd431161067,
+        0xd26cd90f57ae5efd,
+        0xee62a1d5bf55108a,
+        0xf176be705520b7b8,
+        0x1c88d2266a1cfa19,
+        0x09e5a57518c65eb6,
+        0x5f1c44453c6fd0af,
+        0x9eb8e0a68b413cd0,
+        0x15916aa90d3a40fc,
+        0x718f329ea09e6dd0,
+        0x1a758a90e8899cd0,
+        0xce8ffac7bab836f5,
+        0x71e0082aef6d1893,
+        0x8dbe9f4917395dda,
+        0xc070c8769ac07e26,
+        0xaeee33b1c4e3302f,
+        0x19db29886cf5debe,
+        0xb1a9feb9f468e9a3,
+        0xa3335c65a7aff84b,
+        0x268be00b187f731d,
+        0x04753cacd2b15936,
+        0xd18188e919be94ff,
+        0x86307363933fb123,
+        0xf148cb33c0788015,
+        0x64ac5b74c855514a,
+        0x41d4de05c86919da,
+        0x8597fc3b0cefc8c7,
+        0xdd795c60369a19a4,
+        0x41c6b54cdb1ea6a3,
+        0xcc358915dac5de75,
+        0x9b9e3fe9b346e662,
+        0x1fd54b91271e7c8e,
+        0x1d2e0042c069af34,
+        0x9f2e4aae355dce7b,
+        0x73602baa3073c9e3,
+        0x0f1047b30898bcc4,
+        0x690139ac9ec9b9f8,
+        0x54d3a610c4ec3129,
+        0xb561a2f3b4ce7213,
+        0x09d5c85e0ece4254,
+        0x7a74e419ca8ba317,
+    ],
+    // 0B..15B << 8
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xf268d022b9112eae,
+        0x24fb1a55985d4924,
+        0x4d3539fafec4291c,
+        0x4d6498edc2378282,
+        0x3b90be82ef4814fe,
+        0xff1b2f9bbf7754bf,
+        0xbfccbca38beb2330,
+        0x3ffa3e7a91553989,
+        0x12221b6ee705f5d6,
+        0x4e71d715c100e9a6,
+        0xc9298c67a1775fc9,
+        0x7d7a6c8ce7ef3c3a,
+        0xe0aa53d37f2db0d1,
+        0xc5191dc420c9d68b,
+        0x46f76aacc4a94eb7,
+        0x0e711c069f31078b,
+        0xedef1ddcaa423bc5,
+        0xb1d9d3b1cfc0cc1a,
+        0x142137ba6b2a5365,
+        0x202841853d49b558,
+        0x5cf36c0e551f2673,
+        0x87213522c5474abd,
+        0xdb0d314c4abb946e,
+        0x543fdebc5205929f,
+        0xe6c7dfdb71631952,
+        0x16da8c6015536709,
+        0x719d959115ff6120,
+        0x1d5aa21c9fec724b,
+        0x4c426fb144a738d5,
+        0xfd20e3b7071f2409,
+        0x43010a4cecf94c9d,
+        0x2877a00a124ffe3a,
+        0x07f46753a9e2c9ea,
+        0x3f4207b5f3b00cf3,
+        0x171607165068d681,
+        0x0b634acf97743c0f,
+        0x084ba905a4e92363,
+        0x30e4e97f9869cbb7,
+        0x690ae2b29859cd22,
+        0x6370df245fe054d0,
+        0xe40455ae8ae43bd6,
+        0x19439b3947aef6ef,
+        0x75d5dc6efbe084b2,
+        0x1a538e822b713729,
+        0x736aabe0461b15fe,
+        0xce2cc6eb36c93ebf,
+        0xaa73fb8b32473c25,
+        0x32395497b7029a9f,
+        0xbb963c24fd6715c0,
+        0x7caf018360b2a5ec,
+        0x7c52f9dc587bf663,
+        0x06736cf135727cdf,
+        0x15b2db979f1fcee1,
+        0x930d54906856e5d1,
+        0x00d5ac2f41a8dbee,
+        0x364c8e10c5b50cc0,
+        0x52dbf3e76fb3967c,
+        0xed97bec5b90adcb6,
+        0x864c0566f422e7e0,
+        0x58304c962abf68d4,
+        0x49074ed1b10815d8,
+        0x94e6125c4520faae,
+        0x20d5176e199b571e,
+        0x0376725ac87e2523,
+        0x0f7d55a3935a434e,
+        0x50b257aa5b276b30,
+        0x8679f84c7b6728a9,
+        0x71c18ec4ce56ab4d,
+        0xf79b38c44d2e8a17,
+        0x6f082a76ce725d18,
+        0x5c092b0dd5a9ffc2,
+        0x4703bb11cad3491d,
+        0x20746dbdbe7e8c1f,
+        0x3143573deb3658f3,
+        0xd1976a91623cbefd,
+        0x0cc9eecd2061a564,
+        0xe9c55f2e6989bff4,
+        0x2d895bfbd21fa53e,
+        0x2ca8fa8be00d4c77,
+        0x4f13a906944ad099,
+        0xa78084b1ecbdd50a,
+        0xab3e29e255ef3eba,
+        0x7ef14b0179e9f140,
+        0x58b0a83a4744f214,
+        0x320b436b1d639fa7,
+        0x624e254df230f585,
+        0x25b0f6c74dccf174,
+        0x2e3bf9549cd7778c,
+        0xe37b28aefbb87fa7,
+        0x0b81ec135fbfbcc2,
+        0x561c357042f8ff31,
+        0x563f4cd98f40aa12,
+        0x4a61368ccc00f509,
+        0x964c2e6d66a2d0b9,
+        0xb85a44fa75043e94,
+        0x41d64252fc30aef6,
+        0x8ae238d7124fd3e2,
+        0x65d39fa797618487,
+        0xa3c9e85a58a457a7,
+        0x2a4ed982fe3b491f,
+        0x078a5e2dbb8a5ec2,
+        0x4654d85a3cd7dd7f,
+        0xc83b56c2491fb8d1,
+        0x26fede69c050a9b5,
+        0x731b64b65ea1df79,
+        0x7e95c5a6ba3a1ea7,
+        0xdbff2dd1e38f4d3f,
+        0x3973386bd01361d3,
+        0x08163469a50de996,
+        0xcfebac8ede5fce1f,
+        0xf809ce7b20080db6,
+        0x636967d59cf13b17,
+        0x42f0a820f7187705,
+        0x9b2da95efd703171,
+        0x7ff55053fdab356e,
+        0x5a8d0d94b099e352,
+        0x7e0c2471de26607c,
+        0x443e8b835d247aa2,
+        0xb0fc2a2b3aca790c,
+        0x6b2de7dae901f225,
+        0x7f3c0e6ef2d8fb8a,
+        0xce16fe51329d73fa,
+        0x83749b5776f4ba35,
+        0x3bb15824355ac74d,
+        0x2e7417d96cdeb69c,
+        0xfee022312bbceaca,
+        0xcf64671296130077,
+        0x0b2a9bf775c737fd,
+        0x200bbd79a7b9af5a,
+        0xac192f19a124b7b7,
+        0xe84f468decd64934,
+        0x7968d9ed73bd7b50,
+        0x35e4ffc26f5b81c1,
+        0x62f7047253d04573,
+        0xca07a0fdfae24423,
+        0x647ae9ca3e254a28,
+        0x576e615e8a402377,
+        0x5b8caba77920d8aa,
+        0x5edb50957227110c,
+        0x11093b65d64852e9,
+        0x695b727d5caf6bf3,
+        0xff80646f26ed5b7b,
+        0x901ab109940eff9a,
+        0x5f4ed24abe808558,
+        0x1dd44560071c12d1,
+        0x8e1d1c91c6a7f34c,
+        0x7a71c1ddbaf3444f,
+        0x67c9bcdd3e2cbed9,
+        0x376ad0e42bf06ac8,
+        0x87c4065c4adc9595,
+        0xbe1ba6080648d854,
+        0x356f2fef60389cfa,
+        0xaaca28f3f764b3f5,
+        0x532e74a56e85f4da,
+        0xe96abfd31a94ad67,
+        0x3f086c48c1fdb807,
+        0x1ee203d0d41dd0cb,
+        0x53a5ca21681f7db4,
+        0x5a72a031dd2bef07,
+        0x2215019cc66c323c,
+        0xf14eb0038590380c,
+        0x91de7657912ea535,
+        0x11d9ac57ed255ca8,
+        0x04c5e00557941b9a,
+        0x54c540182e9bd0c5,
+        0xbf221e1a04dd93ab,
+        0xea23f7abd89e7941,
+        0x2eab0191248490ad,
+        0x71a84f9fb9647891,
+        0xc900c35eed09fddd,
+        0x5b926843d6581ef2,
+        0x665b20b2c961f011,
+        0x4ae3041862ee59b2,
+        0x902345a8535320dd,
+        0x8ef6b0ac85c82e51,
+        0x3c6430918ee01418,
+        0xfa3bf610f0e66c05,
+        0x5414769c6812ec1c,
+        0x713188949e5082b1,
+        0x427dbc20272fe931,
+    ],
+    // 0B..15B << 12
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8a492159449acb07,
+        0xfce859d8497ee23e,
+        0x98164dd3287ab743,
+        0x601622d53e99009e,
+        0xbb5d1014d672f811,
+        0xf42eb6660dfa83d1,
+        0xbba6ce5fd0508122,
+        0x3e3917fbd1e4e744,
+        0x5941d2a7f11122c4,
+        0xe04952ce13033166,
+        0x3e0d85653ae2297e,
+        0x10cc485a2123561a,
+        0x39bef357dd020855,
+        0xd86bcbeb391b716f,
+        0x1604a764fca2db50,
+        0x1c085c164eda6d48,
+        0x4b4066891c3a1963,
+        0x5eb924453e0bf36f,
+        0x082946b79acf291a,
+        0x0899e43c8b8c66dc,
+        0x2f20e09dfdb94ee0,
+        0xe43d6a75cc4d2404,
+        0xf7ac913039240746,
+        0x5eaef2db5e08f9e8,
+        0x72a44a265918c290,
+        0xe37a10a9bbef730f,
+        0x68e47b626e89fac0,
+        0x42867d00de510743,
+        0x0072b02c9f640881,
+        0x43cffabf00198adc,
+        0xbfba5d9a19b15cf2,
+        0x418465ce7034f882,
+        0x9439d5bd61ab8600,
+        0x2eebefe28daf9a03,
+        0x8b52bcc9638b8dea,
+        0x3113b0eaf1a620e1,
+        0x6c3a57b184598f4e,
+        0x6453535bee732a50,
+        0xfe99dd7a85b0ba04,
+        0x1a01feb6a9bb3011,
+        0xf26167d09faf21f7,
+        0xf90f15b47038369f,
+        0x56978835c434ec47,
+        0x53ea0f9dd9748935,
+        0x8a62ff9921c51355,
+        0x821aecd5abec35ac,
+        0x70c3ce2d85aed9dd,
+        0x2379661df7cea67f,
+        0xdd22564e5eaf08ef,
+        0xa4ad3fa54599856f,
+        0xa40eafb6db6225a2,
+        0x593148a5f50e27d2,
+        0xfa60b78aca360839,
+        0x6bee2592093fb244,
+        0xf067fc3869dff751,
+        0x4de7f8267cb5f053,
+        0xc582a7d82aca438b,
+        0xe1ed8d2bf2f6c57a,
+        0x13c8a2311f08a462,
+        0x38e2765342d0777c,
+        0xe2c56f96d86f251c,
+        0x56b93e62d732fb5f,
+        0xbe0c3bafe491666b,
+        0x38ac41774cbe1c1c,
+        0x2dd23d0c11dd27f3,
+        0x707bb2a804c5d965,
+        0x0e71ba9bd3b32610,
+        0x0000a776f03be5b9,
+        0x8f66afa015aab023,
+        0x1500aeb188b5d647,
+        0xfd46c903ca58467f,
+        0x78adc0d54e34f98c,
+        0xc31eb59248ca31d6,
+        0x1bbe6ef1b37c5f60,
+        0xe4c8ba67be13ca08,
+        0x0ad28c61f2a88586,
+        0x694736e56d1890df,
+        0x519a8b717f546643,
+        0x83d30572f4c93e99,
+        0x72e6d0ac0366e502,
+        0xb8e26938896a97f7,
+        0x33a3a2587c0d4884,
+        0x81b2d6a7b5c8e8db,
+        0x2c702ae9de39209e,
+        0xf615902e15c6d61a,
+        0x7154b9950ddeefa0,
+        0x376464509d981798,
+        0x30db6583d6766d7f,
+        0x04f9d0d142a31d80,
+        0x58c56c493cd4d6b2,
+        0x967a28cf20c944d6,
+        0x1526b0e178b4603b,
+        0x3ef66f61a8174446,
+        0x9d1d633925df104c,
+        0x3d4295ebeb092fb0,
+        0x79fa4fe4d5e22b6c,
+        0xb81a484480d8ef09,
+        0x21e87c54b2d569ca,
+        0xf2ea214a06a4e7af,
+        0x1656926b27c6c468,
+        0xfa0467a4726893d7,
+        0xf5108b5c94cd09ba,
+        0xe806c4a6970bef0e,
+        0x118af63d034e1682,
+        0x1e9a69204564820c,
+        0x6eaea38f819d28cd,
+        0x7a3bef16b38c55d7,
+        0x64c59df45dcedd2a,
+        0xe7c3603b84f3a4bf,
+        0xc9fe066ebde1cd0a,
+        0xc9b98e7832e5b2a8,
+        0x13bb6ad5cd039d46,
+        0xdaac3ad65ed9a20f,
+        0xd8163b0afeaaf4af,
+        0x5467a268550e0143,
+        0x1c06ecf758645dd4,
+        0xb3173f76b045f29a,
+        0x2f0b96213e494e4b,
+        0xf285919a57a20769,
+        0x514cf391b81a6c86,
+        0x629dd98c0a72b6dd,
+        0x1e5355008301a547,
+        0xdbc3fcf0f67ef3e4,
+        0x4524d14af2c59bac,
+        0x2f9b4cb873956b9e,
+        0x501899b23a790570,
+        0xa27ffad549abb56f,
+        0x4718a02b229ace61,
+        0x7af02dac91ad0481,
+        0xc1486070723b63c4,
+        0x5fba2ea5adb7291c,
+        0x6bd765adf7902192,
+        0x9c2058c5166c5bd0,
+        0x6bb09dc5ef6f1e05,
+        0xe33fdbec06de7873,
+        0x40f6f56dc7232e30,
+        0xf6d6cc0172e89107,
+        0x1550aebef6ab7662,
+        0x4386332a5dba8fbf,
+        0x73b836d891f49651,
+        0x9741a40ff632890a,
+        0x0092c83c7f287395,
+        0x0e70495ec166680f,
+        0x173509741fa206e0,
+        0x9ff798ea883c1129,
+        0x907d7609ae02af15,
+        0x9b8d843f1a2daad0,
+        0x5519188d98732e64,
+        0x432aaadb97f7fd0d,
+        0xde97332a316e052d,
+        0xd20dfabeba14e389,
+        0x6b89f88dee9e8325,
+        0x0d88ae260e0d2c19,
+        0xf31ec0a10d7bae63,
+        0x7da90687ae8d05f3,
+        0x7f3fa6e37b64cef2,
+        0xe1847afde117316a,
+        0x9c4ce3118be9d550,
+        0x6cc7faf1ec4b2acd,
+        0x5bd2879dc35b39f3,
+        0x54eb66240c0b10f4,
+        0xb101138f3b1d249c,
+        0x29f88a725d20f26d,
+        0x72b8f5c299b86c8b,
+        0xdb9d7804c5eb8104,
+        0x181989c802adc5a8,
+        0xae75597d37c83d31,
+        0x47a3f0a79cb9a965,
+        0xd62f9e207b61a6b4,
+        0x07d990c831abe390,
+        0x64173f83755a5ef7,
+        0x09794482f2f985d5,
+        0x9cca575ca4b59903,
+        0x4f016ecc8fa428cb,
+        0x6278e15533e692d6,
+        0x19a1126c1a8c18ca,
+        0xfec82121c3ae5c3f,
+        0x41f7967dec0ee5b4,
+        0xf9af2b5242450f65,
+        0x431626fa95d9f825,
+    ],
+    // 0B..15B << 16
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xb93d55487739a00d,
+        0xec47570f8fd8d5c2,
+        0x7da9d7cfe9213e58,
+        0x100e750bd7d3786e,
+        0xa282982941fc81e9,
+        0x58d07b7b38e46787,
+        0x70b07fa7eed04938,
+        0x2f300cad188a32bc,
+        0x9240f4c13dfc6bac,
+        0x21fbdca9a2d7da47,
+        0xfc7fcf8fd5b57cf9,
+        0x5651223fb7dd86d9,
+        0x172e009d9c85644f,
+        0x1b892944c93283f4,
+        0xe6f1f86fb2c4735a,
+        0x18a77c2ad5656737,
+        0x7d38bfef86addf89,
+        0x034693b667a68dac,
+        0xfecc77e3cd7a280f,
+        0x6532f8a27f8b9ad4,
+        0x7a7ae30d37bfe5b2,
+        0x57454ef1928882f1,
+        0xe9168e65bfd53301,
+        0x0cec72f5d5f91b10,
+        0x98f10ca4fde96eff,
+        0xe0fc59d6541cc2fb,
+        0xb907b23da45904fa,
+        0x5f5cfbb9adec018d,
+        0x7c6dd095fddba720,
+        0x7ac9b45897999520,
+        0xc001ee5064699c82,
+        0x7e1120901be9e185,
+        0x5f4b74aeddda868d,
+        0xccfc0f2cc617deaa,
+        0x7892418afd56f440,
+        0x26b8ce71cb375c4a,
+        0x2af195048365d750,
+        0xff0b21d7e9e3c1f1,
+        0x0374ac45977a6062,
+        0x3518f66d39806b35,
+        0x068867f68b61d333,
+        0x0b1dcfa8a83ab6ef,
+        0x1630664b2847133c,
+        0x1becc21a140671b4,
+        0xdb78694fd3a7cf72,
+        0xcf9f09b6750c84a3,
+        0xa3fa16f12942773c,
+        0x3a116a527fd1cb5f,
+        0xd190a2dd9daaed5d,
+        0xe7202333fad6dc8a,
+        0x67328e20e39ae14f,
+        0x470dcdf4a722c0b0,
+        0x4cfbf23b1acf2872,
+        0x4802140d86351c83,
+        0xfca7ae9797a43c3d,
+        0x2f46f1a8114c6032,
+        0x336e66b6bef7d109,
+        0x74986cc6695cfe85,
+        0x20cd844db7bb4179,
+        0x7a835da6e6245063,
+        0x2bdca0cc8b34f0e7,
+        0x8781fda5523a93a9,
+        0x9e3e509012f8d4f6,
+        0x7bfec0e03f561397,
+        0x360ede7489a0db53,
+        0xb1a0debbf6e934e9,
+        0xc8e214ad3269f248,
+        0x44b1004c472c654d,
+        0x004f258a6b78f3af,
+        0xf87a8fa149baae17,
+        0x9a75ea43a9aca316,
+        0x56a7887bb9d3e97b,
+        0x553ddeb5e0df115a,
+        0xc906a91c3a28831d,
+        0xd9874b5284464568,
+        0x13b8c204aad43d60,
+        0xec7bcf96a2cc5aed,
+        0x7fd06ea089da25b6,
+        0x1e161ef72d2bc9c7,
+        0x1895324fe0b6ba2e,
+        0xe128b5f215b14c2d,
+        0x6041ced095688f0b,
+        0xc82c891f6087fcb1,
+        0x21f447f337292c6c,
+        0xdb2dd6e9405bfe3d,
+        0x6f5c6e282c8eebe9,
+        0x527d2709c651f8a9,
+        0x1d4a84b32f6ed69f,
+        0xf0fb1c240a77652b,
+        0xe9ee870cb9fab938,
+        0x3f6b145fd690f416,
+        0x5aadd0112519c181,
+        0x648a4767820aaaba,
+        0xbb0d05c8e9c24f55,
+        0x954051c121a89aa0,
+        0x3a0c0c3b33c15f2f,
+        0xe4081a24c3b71fd5,
+        0x1de566cb72f3c69f,
+        0x00180ae4381c0361,
+        0x608e12c844bce362,
+        0x5ac44ad0c9e0b646,
+        0x9a83f95361f1ea3a,
+        0xc180f2946eb2d609,
+        0x6773fc4c076d5650,
+        0x477016e50cced1f5,
+        0xe6888f85f481a662,
+        0xb1174e3028257542,
+        0x523aab7abe39c1b8,
+        0x0d9400527c77bfa3,
+        0x811f0fe7b9d3d203,
+        0x8857fa52b201ba20,
+        0x2602949b6e002570,
+        0x802ddb8d400dcf76,
+        0x4a7d75a135543469,
+        0x01eb68f18af4aba6,
+        0x354da0fe711f0456,
+        0x6445725d4f26a95e,
+        0xd2f9bbdc920a3dda,
+        0x6ab4295e01442179,
+        0x05c22b64179da833,
+        0x7b1180ff8d11b223,
+        0x71b660d4e5fb21b3,
+        0x6978dc50d43d3011,
+        0x4f9a2a283d5d3272,
+        0xcb2afbf873818f01,
+        0x3882f7e62f694cdd,
+        0x7ade2337b23d9ff2,
+        0x46ed2b4cd8f6ce32,
+        0x8953d593b811ec39,
+        0x796afe0c6723a7b3,
+        0xfb684676d3e6e3a5,
+        0x1b3521d8083e5e13,
+        0x824cfb1351f74eda,
+        0x0d7ffb395e5691e9,
+        0x5bda5ec84435c26b,
+        0x673a3395d3ce6678,
+        0x7fce87eb285d42a2,
+        0x1bfa8956d0e76b07,
+        0x809b3563cac5a1cc,
+        0x16a7362140416a45,
+        0x0afa5b2401fc9dec,
+        0x3e1a5cefab0fed85,
+        0x131d4c6254f2933b,
+        0x586491f7d9944290,
+        0x29804d6b70c29c18,
+        0x4ab8a7832937fdec,
+        0x61c0352aaa8fc3df,
+        0x0c9de05f5a02049e,
+        0x435c99f642c2a90b,
+        0xd121af4732c14027,
+        0x9a2014c40f7a15e6,
+        0x4688e367a37aaddb,
+        0x65e38337984e864f,
+        0xc1d7ae64fc0228b9,
+        0xa7c765967d04aa3c,
+        0x390e9f390fadb2e3,
+        0x1673461acdfb40c0,
+        0xbbcaeb4ef784718e,
+        0xd938d9b53fc8895d,
+        0x15155a1362b8ae66,
+        0x5f0e028e07568555,
+        0x87b162d30ff0039b,
+        0x4f24dd056bffba08,
+        0x673f049b37bcc718,
+        0x3125328926b6bec0,
+        0x03c9fab9ac2cc059,
+        0x7a1858611260e1e5,
+        0x369edf68be1810ca,
+        0x6e15a22b13c51143,
+        0x947ff1a5e5786cbc,
+        0xbf4d09113bc2987d,
+        0x7c903c7ac826e523,
+        0xd75a52cd91f79ef5,
+        0x26096f7f39331d26,
+        0x734c7dbe38631c97,
+        0x47d498bd576cf0f1,
+        0xc13641b76d75b49f,
+        0xd326b26aa88e39fe,
+        0xdb0605ed26dcdd7b,
+        0x6921f69013a4fbaa,
+    ],
+    // 0B..15B << 20
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x7701905f33a7fe4a,
+        0xf7195a4a516624b2,
+        0x8096d5001a043c80,
+        0x2072b9ec607238ae,
+        0xfb2bc67d75b9fba2,
+        0xdce04c9db8e4eab5,
+        0x627bb36a8b9e15c3,
+        0x2338c70b65ff293c,
+        0x7736326c06da1ced,
+        0xfe2226c2318c2b61,
+        0x6edb44d795d985df,
+        0x58b8c4e967072069,
+        0x295fdd1a2ce1fbc5,
+        0x98dd5de22ef8fef1,
+        0x1133feda51b2d6c2,
+        0x5dee672c4d499f67,
+        0x0abdac12927a5f78,
+        0x204b072511023cbf,
+        0x44b86ff3b684ae70,
+        0x715f94de3897e67c,
+        0x7987e23918e98a8c,
+        0x077f11525bf5882d,
+        0x0406ea060922e9d7,
+        0x09e36f1d6e97c62b,
+        0xb96b713efe183e25,
+        0x559c5b2c83603742,
+        0x1167adda567d08e0,
+        0x7d60d3f15a0dbabd,
+        0x85a53c6839088e7d,
+        0xd29dfa958e7cada4,
+        0x45e77d8eca0a7c06,
+        0x3869aa6363c317bb,
+        0xd034bcc1e8114d5b,
+        0x956cb4e145a541e0,
+        0xd5a278689d059de8,
+        0x1149671846256f5b,
+        0xe859d2ebe2e15d2b,
+        0x04f8cc7c52bc97b6,
+        0xc99519c16fe351f2,
+        0x2b92972ae5f3145a,
+        0xb06c92648f82d4af,
+        0x31129656d2e98d33,
+        0x234649aba68aa2d9,
+        0x740e8cf77a0c4d42,
+        0x53dbb978d27d662a,
+        0x66359eae54247485,
+        0x2fbdc839c63d6932,
+        0x28776a789696a533,
+        0x824e27d041fb3f94,
+        0x17d18deaa6d3e5a0,
+        0x0e553e80b925d28e,
+        0x0a45d9284543e173,
+        0xd8579304c37e611f,
+        0xef5781df973dc0c1,
+        0x6665c0bf12e83a39,
+        0x37aa47a831340691,
+        0x70e638d87204ea9c,
+        0x3fc6afb3f9b9569f,
+        0xd77a91f2311db9d0,
+        0x44319fd44ca00d16,
+        0x5a055a1906da9593,
+        0x618b54bd0e2f5ea6,
+        0xe640b86cb066a070,
+        0x5321716be266d350,
+        0xdac2fcfa4264a603,
+        0x235cd2046e2e9bc9,
+        0xddd44cb9293d2c64,
+        0x4e0d637688db8277,
+        0x8b5541600e746f0b,
+        0xe6ec3a0ed30198ec,
+        0xbd6fdc772e21f354,
+        0x5a6d72e566cade55,
+        0xb8612041601581e5,
+        0x2cfd1745afcc9df4,
+        0x530d446f5c8bdc96,
+        0x6cbca56fa26ca81d,
+        0x8f7bbe5892f49fce,
+        0x6c150d34ee3323de,
+        0xedaabeb9a72a6d4a,
+        0x31c9fbb360199db3,
+        0x1a1224ccc4d04837,
+        0x259546a5bef7a08b,
+        0xfeb302886085df00,
+        0x54389e73b1ae3d6e,
+        0xbfa99fbf2f368fd4,
+        0x255e9a80c5331f9f,
+        0x3d008eb94e7e8176,
+        0x61f4565df4b0abd0,
+        0x52855e06c8420bd9,
+        0x53e2e39aa55f92d5,
+        0xbc1b67d4bc277ca2,
+        0x31c4da8756de478c,
+        0x2310dd87292507aa,
+        0x27d56f0b0fe124eb,
+        0x32c97cc7f75be422,
+        0x3f1b4c57bfdeb9fd,
+        0x47fadaadac717faf,
+        0x1001b267dbc09435,
+        0x804566b036597874,
+        0x78de861ec9fee36e,
+        0x09571bc861d2b875,
+        0x8552a588750d0941,
+        0x1fe884492c0e8fb5,
+        0x7f27f4442a7031ba,
+        0x06c2b2cb366f52c0,
+        0xa95d1166a3f8bdb1,
+        0x7ca01912290c78ed,
+        0x0699ed3bd6fe2ee4,
+        0x9d4e7e3e462a4c71,
+        0xa7769c66f9066ecb,
+        0xfb939d297056ca9c,
+        0x3424fedd6e44a467,
+        0xd036c74eb720f3f0,
+        0xeeccc351cb1a3f4e,
+        0x0dc987f51970222e,
+        0x44497aa35ffdc30c,
+        0xe066a72d145ec8d0,
+        0x3ac5472b11f65af9,
+        0xa0bd307e56dea918,
+        0x3d9f901761489422,
+        0xc56ee7355918fe6f,
+        0xea5a2d78374a0978,
+        0x31a8790bfe25b19f,
+        0x365e6561bf44c8b5,
+        0x49d249a1eb620d38,
+        0x35bed279f8486ac6,
+        0x372f7234ef56425d,
+        0x3018f568d6f32d09,
+        0xe7e08aa144acbde5,
+        0x253c65a0fca0df10,
+        0x8543f1bea9df2bc2,
+        0x6b75d0c8ffb69fc7,
+        0xfff764f4faef5836,
+        0xac5660cae78e504c,
+        0x0ce5f95f3ce6b426,
+        0x67e8b6bf20a638a6,
+        0x7cc3de6f14ed72ec,
+        0x419a6c8c6d323506,
+        0x87235c7f2e4644bf,
+        0x659ea56c6f50bbd1,
+        0x16738532f1bdabbf,
+        0x1aa666b1f4cc824f,
+        0x4e11d601149d6cf8,
+        0x210559420dd889ef,
+        0x454f45c20b32027c,
+        0xcb2a2bc12b0e6ad6,
+        0xcf303029d87961eb,
+        0x07f0a22c400b2091,
+        0xd0c546bc6be02927,
+        0x194332305be0753a,
+        0x29693a0de5b519c0,
+        0x61823b2179c93329,
+        0xf44135a5a7e4ecc5,
+        0x7e74567582ec7311,
+        0x2dc5a0c1e8d1dd11,
+        0x4a36b361571a6edd,
+        0xe4e32e584582bafd,
+        0x1c8d902fa8b71c1e,
+        0xac16378ae894e0ec,
+        0x6018a87df05383d7,
+        0x27346f0e1acc02d6,
+        0x8bc0e8862018911a,
+        0x60ed602c5cba5bb8,
+        0x0c74e11c5ef59446,
+        0x7dd3c60fa20c92cb,
+        0xc21c493d2fab8f18,
+        0x64d0cd59d80a1472,
+        0x07a40c1baafa5302,
+        0x1f2020c789997f93,
+        0x34ddf5af4e8531fa,
+        0x7303c52d1c014d04,
+        0x5d5e7b8bd2d969e4,
+        0x734ce0bf474e74a7,
+        0x1e392b6cdca9977b,
+        0x8a6385bbece73e9c,
+        0x4f37d30aa15f15fa,
+        0x8e84f4cf30e3f268,
+        0x6e654cc333357685,
+        0x6805930cdd89694e,
+        0x066a36b823dc4625,
+    ],
+    // 0B..15B << 24
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x35f0d4eaa9d9fbdf,
+        0x44e3cc30df300953,
+        0x9b52d8ddd23721b5,
+        0x0896638e0e629256,
+        0x24d355164ad8a006,
+        0x17ff401307094b4b,
+        0xcec7a85782855335,
+        0x4853a3324d4c46ee,
+        0xf83da1704e6a6cdb,
+        0x32422d3ebbd52207,
+        0x5b691faf90d8cefd,
+        0x2c078c2a245fdc01,
+        0x30d73033d519c194,
+        0xbbeef4d3169f4a68,
+        0x0cb5672bddb0c304,
+        0x1f3833a41302dcb3,
+        0x12a104885881d482,
+        0x8b7a29106af9e8c6,
+        0x56bfae6706a4a4ec,
+        0x382fce69276cfddd,
+        0x3a56dd37b808cf67,
+        0xa83beafebff3e5c8,
+        0x2a527004682f4896,
+        0x104db22ada77f54c,
+        0xe4bbd187c26dac3f,
+        0xf3c901857fbce494,
+        0xa7ef7d48787027ef,
+        0x7cd1d85655782daa,
+        0x85110d3add2a7d14,
+        0x6da261ddb4d75863,
+        0x002b8cfe6daf8bcc,
+        0x70da4cad09eeb554,
+        0x8643268d369d9594,
+        0x7ef066e39dee590b,
+        0x82bd01b7eede9372,
+        0x02524781a8603f7d,
+        0xc691f6409462bda3,
+        0xcf1eb799f373d817,
+        0x3643b706d63fc4a3,
+        0x44a194e97e4eaf8f,
+        0x2a96634a291d9545,
+        0xfcd2d5d700992819,
+        0x80b7e5c946285e5b,
+        0x6f63a172268dc2cb,
+        0x9a3ee9e330895b49,
+        0xaa50e4d838680109,
+        0xa30e2e26d99670f3,
+        0x0883b03e9664837d,
+        0xd33b175fb2e41ae3,
+        0xb27bfce0ef6c5fd7,
+        0xf332a4e3d715adb6,
+        0x2e0b8528c782d851,
+        0x3cad35f5ad43512a,
+        0x94b3fb10af28078b,
+        0x13198d96f05bf9c5,
+        0x3a9556d1946410a1,
+        0x22ae49f00ea313e7,
+        0x779f12eb1596bb50,
+        0xeffef6068c1f8cb6,
+        0x15dfa5afcc6deda2,
+        0xb00d02b35f6f067f,
+        0xa043a03f71151379,
+        0xbb4ac2a89924c725,
+        0x79e47dbe8eeeb655,
+        0x615484634dcd1e6e,
+        0x3021ab02839b7cde,
+        0xd19a741769c63a3f,
+        0x495677f58e33b907,
+        0xf26a59d837247184,
+        0xef77c0e56b4aaa2d,
+        0x7f5ed9a30a97fdbb,
+        0x6b600bbc9b4a48ab,
+        0xb0bcf7b5e2b3acc2,
+        0x6f352a17217795f2,
+        0xeec5e16849cffa6f,
+        0x23254357f036a250,
+        0x4d57c69615a86c7d,
+        0x7bcca1d6092aace0,
+        0xff149768e2511de9,
+        0x070393378219e391,
+        0x90570661a470dfcc,
+        0x81bd96f730be3066,
+        0x8db7a7a10e87f1ea,
+        0x63c4c45ec35f302a,
+        0xaff6daddc6210df8,
+        0xe1a127b968b3e01a,
+        0x95cc5d507bf59350,
+        0x527daa48f021164e,
+        0x66c176ba45c9b831,
+        0x918ef1a77c1afee0,
+        0x2f4e24c30fb75985,
+        0x60f14949e1c7cb79,
+        0x3bc506183e335626,
+        0xf50fd3039c9c1589,
+        0x0f70fe6e1121faa6,
+        0x61333cbc5c166d85,
+        0x290ffb15863ffbca,
+        0x0035e5620d326528,
+        0xb3cb60f08b944c3a,
+        0x4d5fee9de4276ec9,
+        0x3607fd309bb6a080,
+        0x62feb4e650bbce5c,
+        0xe8b6916c87b91177,
+        0x592dcc13b90d68be,
+        0xfb0f957cc842e5c6,
+        0xea359b85f5a6a669,
+        0x6db2677268032827,
+        0x6298d06b6cd18fe6,
+        0x9e82819e3be76120,
+        0xfd8fdaf743242cb0,
+        0x87484e37f4416888,
+        0x3973baf76ff8532e,
+        0xf181346026f4ebe7,
+        0xd4d11bc6ca5c3a26,
+        0xd69bb9b1c011e213,
+        0x59a752247622b5cd,
+        0xb3d1e9a3cc58a3d8,
+        0x422ab28e674d5650,
+        0xae2a10ff98b1310d,
+        0x6274ab212d2344a3,
+        0x91effb0921994414,
+        0x5c9ebc647b4c34ab,
+        0x5189f68dcade1980,
+        0x42d4540607ca5986,
+        0xffe0ca80fe5dd34f,
+        0x9018ce21048cbed8,
+        0x7e4dbf148b8f13f2,
+        0x6db4331219d60831,
+        0x9901d6f5da79b12f,
+        0x20ef54ea6c117fd3,
+        0xf39ee19113e6fb5a,
+        0x1e93bc46bf40778c,
+        0x1a22a4afa745aeb3,
+        0x8e9cbb095dd4721d,
+        0xf5829ebe95830f8f,
+        0x55af02a67ef3971d,
+        0x029101ba71ce8e68,
+        0xca64a121c48bb207,
+        0x6149109161a2abd9,
+        0x4bafee4a9a5f7251,
+        0xcfc1f9b460b594d3,
+        0x52003dbb1f00d4e2,
+        0xec7094c8d3012e07,
+        0x5b393e29fa1a360a,
+        0xb956b1531aa3b027,
+        0xbfea515d5c5adf86,
+        0x3645c3517c5d68be,
+        0x7fe38bdfdf996e37,
+        0x56b21731e3474210,
+        0x1aa591c87eac9bfe,
+        0x1b487ff0226ebae5,
+        0x0c5bced19679053b,
+        0x8dc744fe8392e136,
+        0x896c5ea2615f5acd,
+        0x25c0c2439bca1aab,
+        0x08d7ba3167b96e9f,
+        0x47c209c737a48005,
+        0x29c8e2e4f9a52ecc,
+        0xfb2578d5de39404f,
+        0x306cb7bb4ea16fdb,
+        0x2e7c36c49314a950,
+        0x5356fc83479b1904,
+        0x8bf2749eb10d4db1,
+        0x7a54926b6d518700,
+        0x2b7cce2fd1b8c29b,
+        0xc4b6d4865fd0042b,
+        0x99518865b0931ba1,
+        0x41680fc57f31ec16,
+        0xfdefa8e97cd2d568,
+        0x1ab13d3808838d4c,
+        0xe3624767ef1a123a,
+        0x6f1dd995fe2d9fd3,
+        0xd3ced2cd2142005a,
+        0x855f9f60177fdf90,
+        0xf8acd3ab61b092e3,
+        0x315eb49467611211,
+        0x3c83cae33f302249,
+        0x2519da085277d5ef,
+        0x6e188c8e98785b95,
+        0x142f4d3e547ff767,
+    ],
+    // 0B..15B << 28
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xa7041ae30e73c8dd,
+        0xb4e420607d1071f6,
+        0x0188148df345c32c,
+        0x236aaa98cb8c2976,
+        0x17b206570e4752c3,
+        0x9d949f739a4ce34e,
+        0x9ea6a351cd2b712c,
+        0x26bd62399af30f48,
+        0x25db8840261778a4,
+        0xacb2e74edc98019c,
+        0x660731443d9f6511,
+        0x5afc6aa23cf04df7,
+        0x9bb9ec6421753113,
+        0xffeacc572a5b160d,
+        0x1210dd996f7fe5e0,
+        0x668d16b4fae59648,
+        0x5ab2f9b37d9d6d29,
+        0xb5e98e72c4d0a75b,
+        0xbff34bed46e08634,
+        0x7926adaca200ca89,
+        0x86db9e556b1f518f,
+        0x31890d15b60ab206,
+        0x1c6e2296d9a6c271,
+        0x426177b8c740a1df,
+        0x1930f6a7653572f3,
+        0x9b703c3d9887bc58,
+        0xec5c0d05e485e5e0,
+        0x723f02899ead5a7f,
+        0xaf798b04dd85683c,
+        0x38a352b10550ec1f,
+        0x29eeb752f5064082,
+        0x1705d587af7c1334,
+        0xf635bcaf0ece35dd,
+        0xf92c29f3a9772d46,
+        0x6d8b7196da37455c,
+        0x2132ba9f9604762e,
+        0x0bbfacddb9566e72,
+        0x1519592735b0381d,
+        0xd3dc5f309fc850a5,
+        0x26a9ebf5db22ba50,
+        0xac0249a93500ec22,
+        0x5e2f7bb94acb0678,
+        0x0b45b1bf68be46bb,
+        0x7e21cee65b9f93e2,
+        0x57dd3640520a7cbd,
+        0xb7b7420415c64d24,
+        0xf833f7651ef4813f,
+        0x2714364e144baa97,
+        0x8eb03828e4d1efd6,
+        0xca4c097f94eb815c,
+        0x23e2c1ad78bd6c42,
+        0x44ae3e686e21a524,
+        0x5825d1741f9c4b1d,
+        0x0ff6264fb428d81c,
+        0x55c74447c5ee63ea,
+        0x3fdad112fad256a5,
+        0x89514b21efe6c01c,
+        0xecf81a23f8b9de2f,
+        0xa724a9c8a633f7e8,
+        0x1f04dd895a121d4e,
+        0xb566ec999f8903d6,
+        0xb29ad0d424d4ea76,
+        0x6ea63d38d874ea95,
+        0x625f94bc1f0da884,
+        0x7ea4fee5bc7bbe98,
+        0x385ae28ad70c8ea2,
+        0xb63ba2246c26c9ae,
+        0x0c4c545ab0ddc3aa,
+        0x3a612d7908453046,
+        0xce3f36d09f63790d,
+        0x9b81c8cb0821cc69,
+        0x5e36aca1e1faa271,
+        0x73b73d830d49a077,
+        0xc3b0e57c1af8ecf5,
+        0x058c47919bc59afa,
+        0x108f08632b6d7aa9,
+        0xe93e9a159b87cec1,
+        0x7925db5c7e0d6c8e,
+        0x5d70c59f69bd4068,
+        0x3a55f973ab97d52a,
+        0x82b3f49addba97a0,
+        0xd66d7241f17e94b7,
+        0xe2a31fa641532f23,
+        0x00191ba49473528f,
+        0x99a6cf7ad76d161c,
+        0xcad5700c7718e25c,
+        0x7b12abe0bd7e3bb4,
+        0x19b48473c44085d8,
+        0xcd787960a9dfa8a4,
+        0xbdae7b9bff02e143,
+        0x8ffce3f492f40e2c,
+        0x13fced4e0721876c,
+        0xe6065dd10b3e800c,
+        0x627ef55b63118ab3,
+        0xab09e0b59aa75490,
+        0x6f700fa92630f278,
+        0x00459726fa435cba,
+        0x4dab75de8e8d4ad4,
+        0x32c57d2137ee9db0,
+        0x61a4158aac1af5b0,
+        0xc534312bdd0f4209,
+        0xdce6cf9fc4d915e0,
+        0xe393769d2563327e,
+        0x7476e4ea049034e7,
+        0x8b1086208c2ed00c,
+        0x35878c6f7f02ac1f,
+        0x37e969933ab0363a,
+        0x32e9380be6494348,
+        0xc28ed271cb223323,
+        0xc1d6bb58088dfc45,
+        0x6ee48552af61e348,
+        0x1569a59014275dd2,
+        0x6ee0670d8e87fb35,
+        0xe5573c273f8f5581,
+        0x300e7ef7e29a46ab,
+        0x632b84c095a31607,
+        0x71e0d796a26363bf,
+        0x7fd7f7fd06e98c82,
+        0xa6b071805c01df95,
+        0x7d0ab5863e6d29df,
+        0xf57fe59edd25635f,
+        0x3503aa7df322d60a,
+        0x902571b694e3f582,
+        0x1e898a1547c7552e,
+        0x47be6886898acddd,
+        0x43e3ad6fea72378b,
+        0x4bb243c8f76360d0,
+        0x5aa65530e8052e5d,
+        0x3c150a148d16ac29,
+        0x5ef4a8b224915f70,
+        0xf3c484b1c7f20dc1,
+        0x6e1359c047887c3c,
+        0xc0b7986dd0c9e88b,
+        0x3f20f8e2607806c5,
+        0xe05e9301281d82ef,
+        0x2e4c25b980e7090c,
+        0xca40722783def3a6,
+        0x5d80602723eb76ea,
+        0x8ddfe51547472be7,
+        0x3e615c998af3d9cf,
+        0xb142e88322fb1230,
+        0xa4c064dc05f39faa,
+        0x0bd9a42d3ba3ee04,
+        0x757160bc443af384,
+        0xbcd4162368304fda,
+        0x5e17dc10d053732e,
+        0x56f5fb0831c915eb,
+        0x4bff35bea057ba0e,
+        0x7992a38ce317cfcd,
+        0x164c6301331d5765,
+        0xb9ddca6c83458c19,
+        0x7b9db949bb2822d1,
+        0x9b13c4d71169fdb7,
+        0x8aba6f9c92d3a5be,
+        0x1cb4dd81ecb95ac0,
+        0x5d864d231b32d467,
+        0x5ca04805d7573094,
+        0x9d727e2a6f1b919f,
+        0x33a25229507b0a2f,
+        0x539dd38e0d805afa,
+        0xa336403e6305f532,
+        0xdd61f369d146b256,
+        0x36a77669291e2344,
+        0x14129a28efbb614a,
+        0x2aaea0a85278f497,
+        0xa91a9098523fd5dd,
+        0xe123f0fba9c41c4b,
+        0x62e1eed4127016fa,
+        0x870b292d0f12e171,
+        0x2c667267a17ead4d,
+        0x23d231a8f3c3df8b,
+        0x39d6eb8890e0c411,
+        0xde1c68b16c0f0104,
+        0x0a5b8e3dfaacc9b0,
+        0xe8895d0eeb597ee0,
+        0x53c29e9f0f8136f2,
+        0xe53668140c072feb,
+        0xf50dc01f8d605891,
+        0x4c7a4d6f21371726,
+        0x1ab6022c6bf9f1e0,
+    ],
+    // 0B..15B << 32
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x19191bac55db61b3,
+        0x9daf5ec1158bb680,
+        0xacc25c6c55945112,
+        0x2a2a3a203a0f2670,
+        0x6e1816dca60796c5,
+        0x8b4709defb82b4f8,
+        0xddbf60b966964c91,
+        0x453d0dbe7928cbb2,
+        0xa32e0914d7ea7aeb,
+        0x0e2fdd15a2931451,
+        0x8a4700dab3da96a1,
+        0x07d753e70dfbc148,
+        0xb31c7c4f89ac5d68,
+        0xec3cb199080bd16a,
+        0xf73e6755a0459e3f,
+        0x57a586c868a835e6,
+        0xc37e65a2242f37d5,
+        0x4fa7c387a588d255,
+        0x4bae62cea6624006,
+        0x786dc3ece2a96012,
+        0x15fc3ec5066fe7de,
+        0x26e5eba93b4fc381,
+        0x6ad3e8a0516bbd73,
+        0x06c368a4fcf5f2bf,
+        0xf0ee253845b1639d,
+        0x4c8052c2a377a46c,
+        0x8a485434163248ed,
+        0x619a0a6a18895ebc,
+        0xb640a278df65b7e9,
+        0x878166737e54502f,
+        0xb98d794acf5b112d,
+        0x3f10f33c10366764,
+        0xb05c51f537bb7be5,
+        0x6e26c6bf97751b9a,
+        0x05ddb2c8a7d8d972,
+        0x25dd893858bc64db,
+        0xe6ecb70c8c2ad248,
+        0x38d71cb769f6ca16,
+        0x19adf691773f43c2,
+        0x1abdf875797f3b09,
+        0x2bd5f89c8cb3bdc7,
+        0x6a7fd7d335980849,
+        0x4b9a04fd3531e5e2,
+        0x3dd7c8abf6510122,
+        0xc8864ce658f904d1,
+        0xe569ba9a0aadfb76,
+        0x512159bd293d4869,
+        0x19fafd97b1e1a696,
+        0x1d0d1587b56865a0,
+        0x685389873b43bc35,
+        0x0fd745fea0c7ebf2,
+        0x1b68ebd09001d1b1,
+        0xe3b5bb8aa46ba39a,
+        0x240039c2792ae940,
+        0xe29a1729df485b32,
+        0x6ccbf1c784469997,
+        0x50767aee9b5bf38c,
+        0x70679edbeb9a9be4,
+        0x9bcf1f14d84dc77d,
+        0x310901965d499cec,
+        0xcc63bae4ec79a8f1,
+        0x78cab03bd088dfaf,
+        0xda48f38a2632245d,
+        0x36de7765d4ae16c6,
+        0x4d6418854b98ee81,
+        0xd4b2852489ea8d2c,
+        0xa718ca2e1f5d6d5b,
+        0x763b627ce989c60a,
+        0x21d72952d3e15e7d,
+        0x32108a94201b512f,
+        0x6141a6e7ce6867b1,
+        0x734f1d28f5411f87,
+        0x67df39ce70e09307,
+        0x6e66044c0c2fe508,
+        0xe74f956e1778a976,
+        0x2db72875c022c28f,
+        0xcc5b992753e9bfe0,
+        0xb16b52820a5fc734,
+        0x42f9ae6a8b121010,
+        0x31046318937ad540,
+        0x8b28aa99ebdff385,
+        0x67d9071e739a90b0,
+        0xb16cdd8414940302,
+        0x65b9142f106e4dfa,
+        0x5f63745f73f631b7,
+        0x1cb165f9f8ea4727,
+        0x74f3adb0eb2b638d,
+        0x5eba2f26acf8b031,
+        0x74f94f623cc2fff7,
+        0xc440ff9a07a4e592,
+        0x377b575c61f59731,
+        0x1901f391cf56536b,
+        0x388a2940d000cf44,
+        0xca97483212830b8e,
+        0xfbd786e6ab243a46,
+        0x400144a970959c91,
+        0x2bbfc54e2b41a580,
+        0xd58330829fe8ccf5,
+        0x9885ff8dc9659ae2,
+        0x3a3054625ccdc76c,
+        0xb39082cf5aa3a487,
+        0x2788254b2c6dc457,
+        0x4b0508ccb7ff4dc7,
+        0x6afc9d3c9cd013e3,
+        0x146814bf0789fa54,
+        0x7cb8f47d31f3112e,
+        0x93df55729c9dbc67,
+        0x252798a1c05de0da,
+        0x861b3a1f44d841fb,
+        0x736e381290fecd52,
+        0xa9c23b7a8c1e7834,
+        0x37d67134b5e94f7c,
+        0xcf6b21da75c304bf,
+        0x50bfc2f90c9e7ae5,
+        0xea5c55d49602dc9d,
+        0x7fa49395eabe31bf,
+        0xffe86f8da4161c42,
+        0xaebe26c5b0a2e02c,
+        0xbaeda8d0ee7bd1d9,
+        0x3fdec4cd1d3f093a,
+        0x81a5c5c8496eb82e,
+        0xc3da780b119943af,
+        0x341e1bc0e75f1252,
+        0x0277bd4090c8d899,
+        0x1a5e28babd4e7601,
+        0x39152816d554daf6,
+        0xa88699ee77c4576b,
+        0x2f9d191739646c90,
+        0x907ee87d51e3a5a2,
+        0x8087a0ea62d849f3,
+        0x5aaa4c0b9954e3ae,
+        0x192bd26dca7451f2,
+        0xdba690c7c1b58b6e,
+        0x4dd39b5ab7240275,
+        0xdb46e374e52acfd4,
+        0x5524a926ce154353,
+        0x5ae335715cc181c2,
+        0x7e2dab18c58055e2,
+        0x2b4f8dfc8328645a,
+        0x4317d5743526ffeb,
+        0xce64d5aed029398a,
+        0x83f1eab4203c5809,
+        0xebd404a7f79dea08,
+        0x1affc5cb9dd933e0,
+        0x6f1c8cd76fe8df8b,
+        0x57049fdb31b1782d,
+        0x24bc0ed77672c1cd,
+        0x5fd6868e9e96a2e2,
+        0x514dca57374749af,
+        0xe741a28820e5403b,
+        0x220329bb6b0fe28d,
+        0x7c8e69492ae83164,
+        0x04d399cb8a464d4d,
+        0x2b56761138e4f8c0,
+        0x2e71e7ba68532b97,
+        0x4e9ba0063766cc91,
+        0x0b744a00be4a4d96,
+        0xc89ac1e9bfd23ff5,
+        0x96af5c205d855c79,
+        0x320e21d2fa614269,
+        0x8903343556a4abc3,
+        0x5e630b7e5491aaa8,
+        0xb0c6d4c21f389d2f,
+        0x1957ccf9f67f4b8d,
+        0x5e3169e0af264df3,
+        0xbd65905942b4e2fb,
+        0xe895ffdbcf3851d2,
+        0x0d58ca5285e82a5f,
+        0x5ae5203c0ebe441e,
+        0x01e3f3bb3f3062b8,
+        0x81a8c964a4a3c875,
+        0x209d7b61acdf4a63,
+        0x8ccaa8c34576e6e3,
+        0xf2b373347a5d9d03,
+        0xc9c2f188e407a0cf,
+        0x5e979470947ce83f,
+        0x669460ce0882c9f9,
+        0xc100bfc98104ec39,
+        0x89a5db9d05f81ff6,
+        0x277f2189746fe175,
+    ],
+    // 0B..15B << 36
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x37af7d3e8a1d5bc2,
+        0xa9bf35b5000b0f89,
+        0x743053e7bf3719c2,
+        0x5042ebf4b8bea060,
+        0xe9df745c1a467814,
+        0xa390d3369088b272,
+        0x56a8c437a948aa84,
+        0x6c56333776660e0f,
+        0x414d9857a82cb9f3,
+        0xde7470241d8a0ec2,
+        0x150b7d2157debdfd,
+        0x3acf7fb5ee189665,
+        0xdcbf25e608e04645,
+        0x75ab97a56eafd678,
+        0xe0089c29ad4e9af8,
+        0x08d00f0f505c3348,
+        0xc3f1b58e6336df92,
+        0xeeb0fcfa5c2b3a67,
+        0x7caef238a7802a5f,
+        0x59a11c7078bee3e9,
+        0x21d550755d751d5f,
+        0x9736d6ba67bb7b5e,
+        0xbc9d44c2dd96b6cd,
+        0x492f57b6f46d02d0,
+        0x70a9c37d840c282b,
+        0x9c4cf5535bc15d5b,
+        0x9c1c0b76f2a30fa8,
+        0x7d00767bd86235f1,
+        0x61fae5b7fc783a54,
+        0xf9e82c63185e0107,
+        0xa52c1852d26e0064,
+        0x7f5ac1d6c7a416db,
+        0x1460b128cd70e86e,
+        0xb4ca67a033ae1869,
+        0xc9a9f5c9ab4f96a8,
+        0x56e9ea22c86f49cb,
+        0xa92396b635d6c3a2,
+        0x1e7216eb172ed828,
+        0x03d216275f33a62d,
+        0x124a56aedadd73df,
+        0xf331cfb47c47f37b,
+        0x957b5db4139f355e,
+        0x12651a7e078f7b34,
+        0x35edbd80e307956d,
+        0xd9785755d4f292d6,
+        0xb55984cb086b0310,
+        0xe9817aeaed080dae,
+        0x76356937afba274d,
+        0x05c2c93a2feeaf44,
+        0xa794adee46d2714a,
+        0x7c8bb9d42ea34a55,
+        0x64179194c8ca319f,
+        0xee731cdda3e75e4e,
+        0x529b8f8a69c6006b,
+        0x15df3a0e60c9afeb,
+        0x2a88f63d5d7e65e8,
+        0xb049048633513a30,
+        0x4a51c559c253dc67,
+        0x7d06faf2cbb9c2fb,
+        0x573718193b978010,
+        0x91d60ec5705ff572,
+        0x70a22fc664b0e591,
+        0x783f8f99c6577f56,
+        0x76716e004d84fcb4,
+        0xf60591d6a73e8493,
+        0x8112f75764a5662f,
+        0x83215d109b7c8b94,
+        0x7f26ae789c74ff88,
+        0x0233c96b8da1ecfb,
+        0x1bc197853f9ecd54,
+        0xae33bf68f4552185,
+        0x6e127c6f17181cf4,
+        0x1574fcbe73394391,
+        0x79ad855686a823e5,
+        0x73b8aff91397c38f,
+        0x12e3ab0fce48740e,
+        0x586d681fa470f247,
+        0x0b3e49fd46295749,
+        0xc026ebe3d6b2781a,
+        0x488098d44f96d021,
+        0x0b4132015064c0a6,
+        0x324e1493e5b6e1a6,
+        0xa0f7fe2b7156778c,
+        0x47c16a24488b5e37,
+        0x72681c3df03fcb68,
+        0x1891e791330bda02,
+        0x432ae6e65e78892f,
+        0x747b9e227dc1f9a9,
+        0xa5616c147459012f,
+        0xe0bf5de64f758e25,
+        0x3918bf9b53de8839,
+        0x584100fe7cba44db,
+        0xb0f2f561727dc606,
+        0x0eb2e9f0b20e3956,
+        0x9b6e0e39ab0a01df,
+        0x69990b1e5da89071,
+        0xb2bf8c863b70f010,
+        0xab270e613dbadc38,
+        0x02ef02148a8855a3,
+        0x48c15c990d798248,
+        0x2cbd4c28e0ce72da,
+        0x65577b2af9830e54,
+        0xb5851b55ca855d2b,
+        0x7b78e3bf0bd191f5,
+        0x7258d44d42bdda27,
+        0x24aba62be1084049,
+        0x0bfcab140ea08576,
+        0x03f3f603af949e83,
+        0xa1955fe92582aebc,
+        0x7d8eec3c9ae37571,
+        0x1601145c46179ad6,
+        0x427bd8ed7f4b8174,
+        0xef35120fc12f89b1,
+        0x927de761aafccdfb,
+        0x508d225be6566915,
+        0x778081a47ac77591,
+        0xb4822de152f37bd3,
+        0x35c6c179b94f6317,
+        0x9716438912852b08,
+        0x6a6de3c1eb2eb9a1,
+        0x0f5b9bac64976b4f,
+        0xfd0ce155afac1ca5,
+        0x55cbb51f4b7b044f,
+        0x17f56c02ca8657d0,
+        0x4688be0e1ef99124,
+        0x1096c6918cafeaad,
+        0x1e1e05e476ad6910,
+        0x6efcc0b455a474c7,
+        0xb5d10f3702df8e52,
+        0xecc4cb06378b1906,
+        0xcbf953bb86b3a4da,
+        0x7b18baa40bdd847b,
+        0xb3d22154ab63fc19,
+        0x79632300517e37f5,
+        0x0fd864f5bc7a7177,
+        0x1b52429afe50afb6,
+        0x9026b8d17b5ece0c,
+        0x0af9a1404c881373,
+        0x9f434ccc7d5a13d4,
+        0x0efb4ed6b75c72a0,
+        0x6c65b76788faf06f,
+        0x16f2e6da5b677bf4,
+        0x4862b051e48addda,
+        0x6a26bfb52be262b2,
+        0x4471d37461847f0c,
+        0xfa27332463820526,
+        0x6484fd4d39dab32d,
+        0x0ffec21e01322e50,
+        0x32fcd1d3bf8a85bf,
+        0x75befa54efb83d86,
+        0x157bd19ad305f327,
+        0x6445b6b5a26ae6fd,
+        0xd35556112d44ab92,
+        0x532a53996514b1b3,
+        0xd2a174256b8b36fa,
+        0x739053f14db98bc8,
+        0xf2ac3db8d8476632,
+        0x9425b42db707775e,
+        0x8314b54709b5fca3,
+        0x7270c3810ca42acc,
+        0x649fbd4097566aa5,
+        0xf40194e7bc26c30d,
+        0x5b7b7fdee87ae3e0,
+        0x38c57194be1bd065,
+        0x14003e70ccb7264d,
+        0xf480543eb46d8cf2,
+        0xcc8d7b636843270c,
+        0x0fcdb0f3e40c2c95,
+        0xd2a651d37344fd66,
+        0x8be6cfb18981cb75,
+        0x5645b104e3c08d3d,
+        0x51617fd4f397a3c1,
+        0x1783dc3e7691b939,
+        0x1d8c874a87515e96,
+        0xbe5e693d5de053bf,
+        0x60020671328570c7,
+        0xc08ed6861a1f3746,
+        0xd105f05fdf726833,
+        0xddd1d2633082550b,
+        0x6de4c3c8dea3fccb,
+    ],
+    // 0B..15B << 40
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xf158264841e542fc,
+        0xe031b4815836d54c,
+        0x862b4ba46e5b343a,
+        0x63bb3e052cb64746,
+        0x665d1bfea8cc3e55,
+        0x47bf05f3aefd2782,
+        0x86e312c08547c285,
+        0x4f99d93aabc5a376,
+        0x1f1b656fdfeebb2d,
+        0x439acda14804a0e3,
+        0x5e48753022aadbd3,
+        0x20f60b9b8c10f3ac,
+        0x8075eb622ee177ad,
+        0x9d3e22e25186d780,
+        0x1d68560dd02f2d2d,
+        0x51471c64c82f97fd,
+        0x3f7e83c1b3e7f214,
+        0xf4178d37af299d69,
+        0x988315ec02838a9b,
+        0x4768000825377112,
+        0xcbc28a1fce4a3c2f,
+        0xfeed155bfb182ab5,
+        0x9c6341bbac29cb88,
+        0x068cf3a0621c7325,
+        0x03db9b9f585e5eef,
+        0x87d4121ff69852cd,
+        0x05111bf28dc25fae,
+        0x73f00fab3b7e0631,
+        0xc0add29833a49cd3,
+        0xd72719f60b559c2e,
+        0x329cc0dd518d75c3,
+        0x60c1033c84fec66b,
+        0xaed5a6ea07aef59b,
+        0x982cf9ed1e353a83,
+        0xd438858e77a9e125,
+        0x5289735062bcd438,
+        0x8de81f498ca5662d,
+        0x52191f43b092230f,
+        0xf59650fc73ea80ac,
+        0x708e2048dc8fb7b3,
+        0xe906f3fa00137ba3,
+        0x1a0420477dee65df,
+        0xc32365ee165c9596,
+        0x3a3483f048cf9eb9,
+        0x9cf7945de2753ef3,
+        0xf93c8f73cdb5f95f,
+        0x129c373949ee5de9,
+        0x709c8eac8da00f18,
+        0x5ec72a2e5668c1bc,
+        0x30a5665cc5935ac3,
+        0x70d7d5fc1371dccd,
+        0x43fb0707db04ce6d,
+        0x5af4a8479bc36c03,
+        0x00d7b2144e902038,
+        0x264f099f577db03b,
+        0x20ef128b836a12a1,
+        0x7d4dde6958a7dcb5,
+        0x6781106087da0036,
+        0x1adc8476249398aa,
+        0x7cbd35d344e93275,
+        0x2ac4c1f6b6cbbfb0,
+        0x03def67be581ee51,
+        0xdb1ebb8ff544748d,
+        0x698bcc8d4b62bdba,
+        0x929b7a7c9d447887,
+        0x9a3d4d6b0bd579a4,
+        0xc64a8f601b0140f9,
+        0x6580f05c1d91b9f5,
+        0xf33ee182d6204f80,
+        0x4a8ef7f6cb15d2c8,
+        0xa1a48290498efee7,
+        0x2d3885447cb83b41,
+        0xe71550ba68e35832,
+        0xf5cdbf27395b05a2,
+        0x088281faf67c7572,
+        0x5caa1bd5768fe8fe,
+        0x0ea14d5cf3efea83,
+        0xc3233233a3cbf9a7,
+        0x49e792cf725248b3,
+        0x6819608c82ccc39f,
+        0xd489d70797b19064,
+        0xac508152e4478a26,
+        0x253933e90ff4d329,
+        0x4f296132fda17f79,
+        0x3b687f120e403f69,
+        0x273e346895e51dd8,
+        0xd37f2c36eda4eef3,
+        0x35e521f72afc05a0,
+        0x5384e1c78480dbf7,
+        0x5d63c53287b2e4db,
+        0x81865ecc83c9c477,
+        0x7c8174bea8574735,
+        0xdd100d9cb2ae1ea5,
+        0x0f2c1c7b31e326cc,
+        0x1c2b1a600f0b597e,
+        0x39e260b3ddcc8f46,
+        0xf64298e469f099f7,
+        0xe437f39597bc6067,
+        0x28b08c9b69cf2f71,
+        0x22ca0018ed04b5b0,
+        0x2896f541438d1235,
+        0x2e5a755be5e6bde4,
+        0x75be29f58463aa43,
+        0x5592c0fd1e88c8df,
+        0xbaa0a06fe2c2a489,
+        0xa81f7f8b41e119a7,
+        0x2fb0b5f242b38238,
+        0x2474decb9e65d7e7,
+        0xfeb7d8a6f3fc7533,
+        0xc05004458dbbc3b6,
+        0x371798ff9844691a,
+        0x78b73b51a4036a8e,
+        0x9389ac6b498aa5a1,
+        0xb3ea23d2f0a64cc6,
+        0x206eebb93093e9ce,
+        0x61455d159e708901,
+        0x51572498cfb7e867,
+        0xf3d7dc12cead780e,
+        0x2a9aa9ccfdb76417,
+        0x4ff5896af42fb329,
+        0xd0e12cbebb7db4ae,
+        0x8c566caa941971a2,
+        0x0104d57b06ffd2b1,
+        0x7803fe7963faa88c,
+        0xa734b1a0acff417a,
+        0xc4849894a8906f17,
+        0x93d66283daf5f51d,
+        0x71117fd370de5abd,
+        0xea1869aee2519c07,
+        0x90368ff3554025ce,
+        0x34671957f0027ff4,
+        0x0d95cf390658293f,
+        0xc598293e1a5bc5a5,
+        0x42258b8f0f026ddd,
+        0x39355ef95a8e65d2,
+        0x3758c3f5dd3bc8e7,
+        0xdb2fe8bc021c9048,
+        0x03a3edd40a27eb5e,
+        0x3cb6a15d4c66a84e,
+        0x3008e0095c4aa55f,
+        0x7990ff8346e5240a,
+        0x095a35553ee78d1e,
+        0x057f0de98c944ef7,
+        0x6a510d2d840a514b,
+        0x5a047f2665f09aba,
+        0x218e132838d954cd,
+        0xa49bf83ae40d5b2d,
+        0x59df2e4f05498f2c,
+        0x925e26ef1ee556cb,
+        0x7e3655ac5646ebb3,
+        0x03ed8c5e80a314e6,
+        0x46e32a4468e29f76,
+        0xa7e686cd59bc10fb,
+        0x1bbe692c7bd55463,
+        0x70bcadaee5ed1db2,
+        0x11ad39840ef46e6c,
+        0xd6c4592f7ca4ae9a,
+        0x8740bbba842bc4a8,
+        0xac70b6c8bf4d690f,
+        0x2eec771765f4999d,
+        0x1c50ec0deeb3cddb,
+        0xae25524ef46232e3,
+        0x80410036360ea42b,
+        0x3984fec88a7c3157,
+        0x58963254b78a47b3,
+        0xb2a6a8e32b8332be,
+        0xbfea820535f7c2ee,
+        0x1710d9329278517d,
+        0x26a20924f7ccd2b3,
+        0xa3d9f0704ac37d62,
+        0x92ef741ab1b159ae,
+        0x30475948f48d3812,
+        0x217f0d1c4cf1b4c0,
+        0x824cbb64d9b0b140,
+        0xb75c894c24956c34,
+        0x4a6e6b09f589f71d,
+        0x915cb40ec1e26cab,
+        0x8a8f8b43bf499750,
+        0xf88950df25f22fdb,
+        0x0652666ba36a5be3,
+    ],
+    // 0B..15B << 44
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x759305a91b56e0c0,
+        0x05bbba1a9c574ab5,
+        0x31aed067eb039e71,
+        0x1d986fc4ff570389,
+        0xa9e1639b7284eaa2,
+        0xbc567b8dd673fdfb,
+        0x631af30c3dd96780,
+        0x7ed794b270eb1ffb,
+        0x9ed20a388b76633d,
+        0x38933059648cb517,
+        0x807f1369cea76a70,
+        0x13a42c6897753e04,
+        0x1f6977980a9ebfd1,
+        0xa60a8bd926ca5b9f,
+        0xcfe42d632acbc657,
+        0x71c09d9106d88378,
+        0x40b7d8db09f80158,
+        0xf50586919c5d75c7,
+        0x1662a6bc71c3e640,
+        0x2849c0c597aecae1,
+        0x9a552f3860cdcd7d,
+        0x07589db683ff83c6,
+        0x45f3aa938edd0ee1,
+        0x4b70a85d2114ac97,
+        0xa0b442a41777a270,
+        0x9cf14b86a44b7cbd,
+        0x43261574a461d3d3,
+        0x63563628111b478d,
+        0x2b2656598311d79b,
+        0x87ed4956b2aab7af,
+        0x79fd0f126eabc7c4,
+        0x4f94bc945766ed1e,
+        0x6a014f99255f39bf,
+        0x349a0488a7b566c6,
+        0x0808a3cf98ad2727,
+        0x69fc94bb5fa1f291,
+        0x75c282269d055d02,
+        0x40561ec702805051,
+        0x9aa8caf15e94cdc6,
+        0x71eeb0ef76492af4,
+        0x9b670179adfbb821,
+        0x1f40f1a9f4d4c567,
+        0x350d541592c30231,
+        0x6f2a75a758c09be6,
+        0x12d5b10ba374558d,
+        0x745e9348d6b93f0b,
+        0x780d48a2efe84d54,
+        0x6828e753d08299c4,
+        0x6e03d46e4fc40f6c,
+        0x6668081ce5d8b816,
+        0xc643cfb154c8aa03,
+        0x20585fcee6131d88,
+        0x91cc49ee78ce2abf,
+        0xcb1f59f82e7424ff,
+        0xf1e8ffb07e0b90b9,
+        0x66d10859f21b13e7,
+        0xfe834d40c65e0a3b,
+        0x4ea760171c8747e5,
+        0x338acb14e4da37a2,
+        0x27ea9c97571ec808,
+        0x61cd087b3e2a209b,
+        0xa25733fc93e7040f,
+        0x774fabf122f05f43,
+        0x3ad583ffcbe2e7ca,
+        0x9123776b69fda320,
+        0x910d2426ba7eb6dd,
+        0xd3b2276e0c2fcead,
+        0x326aed2943c99d7c,
+        0x69606504369701c6,
+        0x6d3c22e3b3e282e7,
+        0x1e84d84da0c6780b,
+        0x386a1dacdcc7e7ac,
+        0x92e9c3f67632dcbb,
+        0x3f400427934bbbfb,
+        0x1f46d23e803aedb6,
+        0x7ac3369594ec8bf0,
+        0x7b054c3de156f177,
+        0x537dc237aa41e02c,
+        0xa59b07cb5f5ba76c,
+        0x1934c97258e7e8b5,
+        0xb2dc0d0ef4d89a44,
+        0x2aac16aac2dc8753,
+        0xb3a5c9a8d525917f,
+        0x0ece65b2fd4f2411,
+        0xf9ca601ea01d6d09,
+        0x61a299718083dde1,
+        0x00cf06ca7821c0c1,
+        0x065139aa35934d35,
+        0x83869f1d902ca891,
+        0x37f760ec61889ed8,
+        0xd3236d4517917061,
+        0x56b01cf38dd81140,
+        0xc156ccdcf14a436f,
+        0x5631131554f8027c,
+        0x9b69613db4bab7d0,
+        0x3586048098b32cfa,
+        0xaa69d191100fb33c,
+        0x87e8023b1a10c92a,
+        0x2427f788c37e0e98,
+        0x1cc23c4f370d05c8,
+        0x30e5993f1a5dc4f4,
+        0x6d19da9c1d6c1f75,
+        0x364cc8c9899d0520,
+        0x2cabfb6e91b5caba,
+        0x283e7755a7558a5a,
+        0x47965420ae2c17aa,
+        0x0edc809e984f43be,
+        0x7bcac86cb7c79272,
+        0x781a1231cf407897,
+        0x1fa21462faf1278c,
+        0xab97a07231b0f7de,
+        0x15ee0433467df63f,
+        0x443d42782c53dc32,
+        0x20a0df7b87aa0ad4,
+        0x50cdc8ee306a283b,
+        0x0bc9ed60e8af3299,
+        0x06a2150e34004b29,
+        0x652f5bd26f9e59bd,
+        0x6b6ea7d775fbf7ec,
+        0x366e9896a410b10b,
+        0xa0d9e39a00be6d92,
+        0x7ead515f68b068f7,
+        0x4a7695a1af2694f1,
+        0x69e540935cdfbe33,
+        0xb0eab149e6cac6fb,
+        0xf00e28b091c8e64e,
+        0x48545a3e88a4d1e7,
+        0x15b22e0416ddb4cc,
+        0x0f60d5227d85d1e9,
+        0x3f8080578bdbbc5d,
+        0xf11f78008a996a9e,
+        0x444e8ea9df05e856,
+        0x6c482fae59b36dbd,
+        0x0fa95a8f3ca7ce0c,
+        0xe2d61bb13a23d44a,
+        0x3ce0d9a19a84b877,
+        0x707ab8845de1317b,
+        0xf413ee89eb9a341e,
+        0xe5a43a927cdb0a26,
+        0x69063961b18fd671,
+        0x244376ffc0cf74d5,
+        0x2c2abbde491121e7,
+        0x9d29e0cf5499c0ad,
+        0x0a6abb354f0fd1bd,
+        0xee3ad2a0cdd75204,
+        0x301074a132bd6bbc,
+        0xc618faff0239d795,
+        0x5a711cb73c68a720,
+        0xfda43043d4f9eaff,
+        0x059a5e53a590a397,
+        0xd0a5fe8a5a875c29,
+        0x1ac0bbf0d5120e37,
+        0x2913a5ba7efeb3ff,
+        0x376a50fb48ae6cd2,
+        0x74b15da6ed63505d,
+        0x535a4b6366b6be76,
+        0x03a48cd893c4dad1,
+        0xbb090ae522cbb3f6,
+        0x46fafa85522ada00,
+        0x54aa41d1dee3f4bc,
+        0x2dcbfa71bc94c4b6,
+        0x1536c6ad3d1ca2d8,
+        0xeb88e99e514e6083,
+        0x53f01c0e4a52d647,
+        0x65ed0f51f962e694,
+        0x01e962f8ce6d22bb,
+        0x1bfca94e23030d86,
+        0x5f54f1ce109ece15,
+        0xabf64f6e8f55cca7,
+        0x332d019cc258d833,
+        0x2b2be348ead130b2,
+        0x4756ffd00a8438eb,
+        0xf699b2c4908a93aa,
+        0x1eb5e1fbd3fae40c,
+        0x036e574275a24d47,
+        0x607d60bfdc9516a9,
+        0xa7dd0f62118784c1,
+        0xcda53235ee5e79d2,
+        0x122e62a829096609,
+        0x12ebd7490fcf1249,
+    ],
+    // 0B..15B << 48
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xbc2a6bfdc4663a00,
+        0x13529190ac0529db,
+        0xac30e82f41e06179,
+        0x44c6aa340c8597f5,
+        0x9768b53f46eb0205,
+        0x786f9c98d10f4f12,
+        0x6b930294f8755650,
+        0x3ad61e3fafc5d74b,
+        0x2def813d4a40c193,
+        0x34761a3b9bc79060,
+        0x656e3d3193eadf11,
+        0x6012bad04892bfc3,
+        0xc5d4ec3292cdffc4,
+        0x288220a2ca737c82,
+        0x15b4683489f24a2a,
+        0x1116b5f9f4e6e03f,
+        0x1ca4910a826d084e,
+        0x711898d611efc833,
+        0x44003fa73ab75bd1,
+        0x4e8feeb2e662e116,
+        0xdf3d8d9ad4d3a4a2,
+        0x7045cd1a12b0a9d7,
+        0x247d5381fd9f3fce,
+        0x537e819cab73114b,
+        0xfbe8f37ea53dc7d0,
+        0xf79491e43b9a6208,
+        0x71a52129bf97a043,
+        0x6c701e190551b300,
+        0xb4cd26d6b892b85b,
+        0x198276b00f88eafb,
+        0xa304cc86cd55e92a,
+        0x3f7ae754ebea212c,
+        0xb949def04c841cc7,
+        0x3088815faf106a8c,
+        0xf32471547b5abd9c,
+        0x4c958d905bf8e5a1,
+        0x6e4b26f925dd4fc3,
+        0xe95ea2840009ef37,
+        0x791c4f766cc7e2dd,
+        0x629a73a3e960e475,
+        0xc680c1cec53cc37b,
+        0x5b5fca74e5f5b75d,
+        0xee508e3f4adfa4ab,
+        0x02b9991035a666db,
+        0x0169345e32da2d06,
+        0x420f3965bed68955,
+        0x502a289b0dcf247e,
+        0x50f97e07c490c3a2,
+        0x5b6cc62ff9322f39,
+        0x2ed30b912aa66631,
+        0x27e7c04849be3b6c,
+        0x662721cb9789dc0d,
+        0x20b92c5e0db4b345,
+        0xd534231e0e75172b,
+        0xf0eac62664ea7d25,
+        0x3d0e497855100873,
+        0xfd6f111a207ea1da,
+        0xf26f6520dc1e1aec,
+        0x004cbacbcc9c7fd0,
+        0x56e07bd5fe94bdae,
+        0xa6094a2a8086ab66,
+        0xc05d17c77ab87bde,
+        0x900ae5de72ea1b28,
+        0x14110ab9c1d614b5,
+        0x1d015b7676cf61ae,
+        0xec5d5b4d1d7f69cf,
+        0xc6c3bc426c161205,
+        0x10b98c130bb37f67,
+        0x3710cbc29f2cbd35,
+        0x533362b19d7c78b3,
+        0x8960d951a83a7439,
+        0x5074946957b71561,
+        0x1c885bebbb5e81c0,
+        0xbfcb17d583b94ce7,
+        0x92796541c69dc39e,
+        0x2db84f1e288e0f34,
+        0x72112331c09547c4,
+        0xfa8f8928dc699fd0,
+        0x60178b4f307788ac,
+        0x5a4de9861c1b20b1,
+        0xed0583d4697d6da8,
+        0x6ec3e6244edbc403,
+        0xc4ee5c98ddfdd3c0,
+        0x33cb36097c90db9c,
+        0xa264b0797a6fce0b,
+        0x3337f0d50ec4c880,
+        0xe66013e7d445ac0f,
+        0x2a54fc94a80957ca,
+        0x2627d6152e31de6e,
+        0x6359694c9572cbb0,
+        0x3d0305791bba5ac3,
+        0x0379b1f2268f51f0,
+        0x17a68ceba0be7ea6,
+        0xefba493d226cc600,
+        0xbb319602aed9f0b4,
+        0x3b749face990b969,
+        0x5d72e0db330cc5e6,
+        0xf2383acda072f83c,
+        0xbcc84b001f23984a,
+        0x5d1a2261133a8fe9,
+        0x4586eec9d6005a9c,
+        0xcba42295c0d1a8e0,
+        0xe2b6a2b0fb39c0a4,
+        0x6e0a517a86adb6b9,
+        0xcd0ece74e551d12a,
+        0xd65d3beb5bffc20f,
+        0x060dd4cc7a690bcf,
+        0x62433d3d5a3b2437,
+        0x955158e21a158dbe,
+        0x7feee86dfb6c3de3,
+        0x125b05aa5d83e616,
+        0x57c56cebbfbe2d34,
+        0x9b9ba38f889224d6,
+        0x167903261c6ff8f4,
+        0x2cdc71812c1d4bc8,
+        0x701bdf322ad3e459,
+        0x943a787239944179,
+        0x7f53fbfd8c584cf6,
+        0x808aad229b36e58b,
+        0x6880d1c1b7639eec,
+        0x070f7acef2760b07,
+        0xb71b55690031bd40,
+        0x1a9affd52359f3f2,
+        0x649f0538a359d733,
+        0xde87c5c7cc8a0327,
+        0xa3cc6c59357203ae,
+        0x2084818fac126513,
+        0x1e51b775f202bac6,
+        0x98ead0622edacb64,
+        0x54f089fc04f9d613,
+        0x8b3b7134a476a553,
+        0x7785c2d695b8959c,
+        0x4f399e9d45ce90b6,
+        0xba2e8342596144ee,
+        0x98f5fc5dc2be1c87,
+        0x5d16c8f696f0c128,
+        0xbcf8c8294567461e,
+        0x8ed06d581808ebd4,
+        0x56bd1600a82cb6ee,
+        0x3369af9b325002f8,
+        0x66f4e9bbcc230c06,
+        0x62a1177663598810,
+        0xe6214ddf2c442b88,
+        0x4492c345a0f39bea,
+        0x32639949e4faa1a0,
+        0xb5a21b421a30808d,
+        0x5cbc30c2e5c5a636,
+        0x409b016d0e704e13,
+        0x08c01f8f03510085,
+        0x635c9c6c8eac94e0,
+        0x778cd746bef778f1,
+        0x5570df8ca4b154a9,
+        0x04bf79d0f65db560,
+        0xe859149464436730,
+        0x3b8bb7fade9fcbac,
+        0x2154a71079b0f00f,
+        0xf496f05d8598a71d,
+        0xd99edab208b1e726,
+        0x772d5e74c1e83f09,
+        0x793338f6d289d478,
+        0xa51651260a3b049b,
+        0xfab11c1c2af64d28,
+        0xd566f3800207033e,
+        0x7118746aa7fa8173,
+        0xd239fc31d3e116b2,
+        0x6abefc343ad65519,
+        0x87335cc390e6abdf,
+        0x73617f057669de40,
+        0x0ba66013aeb617f5,
+        0x5b04d1c65ab11f54,
+        0x079419d5cfb142a3,
+        0x01023969c76d574b,
+        0xe1a16a8d6fb2a681,
+        0x8ca7b821e7c69b77,
+        0x168c451fe11e20e1,
+        0x3e3e6292514a93dc,
+        0x65604b33319abe18,
+        0xd72ad210d32a8ee1,
+        0x93b6ab652b13a461,
+        0x46da8787818b42a5,
+    ],
+    // 0B..15B << 52
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x53ae33a0209268dc,
+        0x0b61ff1deb0b569b,
+        0xd30d88f9d3bd3f8c,
+        0x0d041e9b019607d9,
+        0x9df795df6f1c7419,
+        0xb7e55cc09347ae53,
+        0x6f0b8cbc98115c20,
+        0x6d331b213cef32fc,
+        0xeaf6ea6e69d43473,
+        0xbf87f929103e581b,
+        0xc7d3388bd3e60e76,
+        0x08173d8e5a427ab7,
+        0x6684dd9873e85904,
+        0x36e21ecab55c8268,
+        0x721447f6c29ae1e7,
+        0x164c36f9bbd9db6d,
+        0x7aef1819d0d7537b,
+        0xc2f7b4ef7d89ef49,
+        0x8ccfce15b406a7ff,
+        0x4afb7a1823845406,
+        0x82912ca69c3ee172,
+        0x7eb828f46e4ef718,
+        0x7a0c0f3e3f22111c,
+        0x60d15c4413bfd088,
+        0xde45e8d3273ea705,
+        0x4325567b103085cf,
+        0x8cf68b0e4be4234d,
+        0x22a44eb928ced07e,
+        0xf629bac125480d24,
+        0x469fccc3fd136a29,
+        0x387ae9029ae5105f,
+        0x5d4ade3d0d86b7dc,
+        0xcf9188a415d73e0a,
+        0x438016f24238752b,
+        0xe74d33d882533cb3,
+        0x5c399c83598bc467,
+        0xa9570743bad30358,
+        0x331414257381ed7e,
+        0xcbe1928dd792a5d8,
+        0x283a61d48e1bfbc0,
+        0x1ae4452441040bea,
+        0x225dad086d87d4e8,
+        0x804a1f71470f081b,
+        0x7ca83d107a9166fb,
+        0x419b06dd334e96f0,
+        0x02ac483f5b0cf631,
+        0xd22c31dd1a7d1370,
+        0x7275ab1362d4abfc,
+        0xc9c47f05c4ada9d5,
+        0xa4acea2b55e72fb8,
+        0xc517a8710f78de48,
+        0x42d6ca98034feac6,
+        0x60d2e20145c8e9c4,
+        0x790d2744acaf3077,
+        0x5645cc1ff083b40b,
+        0x3def7f122a5b1650,
+        0xb921f3b71dfadbb6,
+        0xf9310ddab51362fc,
+        0xa3e678dcf3bec5ef,
+        0x279a4a6ca2c44e53,
+        0x7a3706473b51add7,
+        0x3643887242ad9906,
+        0x1da1cc5d51d6279c,
+        0x3bbe58b9e72eb469,
+        0x2a7d1120cc3f6230,
+        0x4b38ae2df309ba50,
+        0xc90397bfb98ccfea,
+        0x76e9bad280b3b614,
+        0xc809762a94fd5fd2,
+        0x84971244cdb4656b,
+        0x92c400888e116fd1,
+        0x261901a723ac4634,
+        0x34736cde776af632,
+        0x717ed3ddb15b1801,
+        0x0daea9a6ecf9d19a,
+        0x37d22a5a89c98925,
+        0xf692fa69b280294e,
+        0x05dae7d3eea972a3,
+        0x8053846eab8b37af,
+        0x723b14344d5b4694,
+        0x0ec8116095460c7d,
+        0x5ffb14e8dde83e81,
+        0xde15be58fa7c6893,
+        0x58211cad5660d7b5,
+        0x0b0287ab029be4c7,
+        0x7e3d2864783be20e,
+        0x2a501ad8236efa49,
+        0x1d73f0d8dc059ba9,
+        0x6218988cf8a37127,
+        0x1bcbbce14a39bf34,
+        0x72e21864e1a16916,
+        0x5ac2d02cabfa225b,
+        0x14dd384652965a8b,
+        0x6fe96b3a890c45b6,
+        0xd7638d3ee5f6c587,
+        0x01c1ecb2d8fd99d5,
+        0xe00d015e60d1fc69,
+        0x8881221198bffa1c,
+        0xa034c6d84b87d13a,
+        0x79ad152f65bc05ec,
+        0x6520ba58ffaf49f3,
+        0x36017272f2588318,
+        0x26ecdfa7bb535262,
+        0x6ce9cb788b0eac6f,
+        0xa37eb379d157199a,
+        0xd7528b960df99a59,
+        0x985e5d433a23994c,
+        0x1600803e2562322b,
+        0x1dfffb19a94c46c9,
+        0x7e767c575cbe5322,
+        0x41bf434e474c5711,
+        0x4a8dff8b754b223b,
+        0x70d45d6c8a81b205,
+        0xc6f768aa0b5bad1a,
+        0x110492c030d02ce6,
+        0x5ac477d4bf9077a0,
+        0x2ca49700b0230c25,
+        0x4177c130cd701558,
+        0x973c94bb62e25aeb,
+        0x0a5bab0e36eed06d,
+        0x7f78c4b231957b38,
+        0x348b9ba361d84759,
+        0x5d2b17a5e32981f4,
+        0x7235a4ad3bea8458,
+        0x50ad84f3567ffeaf,
+        0xb4fa5068d0b287f4,
+        0x62b014ee8ddebb18,
+        0x23ce900a32620b44,
+        0x281fb50797765999,
+        0x28485628a859e899,
+        0x6f8fe9526fbb3b39,
+        0x3fae668d8e03bad2,
+        0xbc6b3a77460be7e6,
+        0xabac60f272fb37d3,
+        0x35fb481e065ecfc9,
+        0x5db707dd7fbec2a5,
+        0x1a82633b3c1b742c,
+        0x6d344933ce4a52d2,
+        0x256ec05a61bb2008,
+        0x37adb05d64d3ecf9,
+        0x95474e1a5b4d8956,
+        0x80b19df852674949,
+        0xdd8fa9f23eb6990e,
+        0x75ce6bb15c709aa1,
+        0xd5dc68bda4502bdc,
+        0x2959d86540f00a39,
+        0x4e9415d415420126,
+        0x31852633bd6ec598,
+        0x8b9e0b392873bf06,
+        0x99f89c0ccdf1a982,
+        0x1fc3b5e0bc006a20,
+        0x4c84aa6137addc80,
+        0x680fb56782a8a34d,
+        0x9a451c292a178c75,
+        0x5e0e5fde80cf280e,
+        0x617b90e06dc91a86,
+        0x4f1da3624205984b,
+        0x37ebe2444b56c1da,
+        0x959b96a4f11edfed,
+        0x296ecb1250a2ae93,
+        0x2af398ebb5390663,
+        0x02bc9a6c945fa931,
+        0xb37f057e7ae4ce05,
+        0x39eae06508485b48,
+        0x1efda3b26a94d4fa,
+        0x6056c5e09d1557e5,
+        0x5fce838d20cd8064,
+        0x3fc8ce7a835a9d74,
+        0x661ea1118bce446d,
+        0xa21223d8ad2fc8b2,
+        0xbc57df3f91aacd50,
+        0x7fa95a380b00d03f,
+        0xa8033be03c17ab85,
+        0x54fbdb900050950d,
+        0xc29619cf71cb83a4,
+        0x47f08ff96c5a7bd1,
+        0x008e942d4945681e,
+        0x2f9f0772439755d7,
+        0xa86dfbc3ebb74d88,
+        0x5ced5aaef05cbb1a,
+    ],
+    // 0B..15B << 56
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0209c4bbe0826e14,
+        0x7114deaf82b20e50,
+        0xa9c7f366f581e5a1,
+        0x6c348a015ca743fe,
+        0xa13af1db7c0e496b,
+        0xf246ab8bfee479c5,
+        0xd21ed934ce03f2b8,
+        0x1e7582897d96f381,
+        0xe03c236315af4128,
+        0x39831b2ad680474d,
+        0x4da3e078bbcf6f6f,
+        0x5d44f7807d8e79e1,
+        0xa67f5ade81e432f9,
+        0x3599ad0e906ce172,
+        0x95f18cdfa6dcee76,
+        0x3ffaf6d051135a51,
+        0xafe271f0e7a2c612,
+        0x2ff7b447fa1ea1dd,
+        0x1f4b5e3fa5261478,
+        0x7bff2f4ca6d81564,
+        0x509e7b2b50e3cfa9,
+        0x9fad985b317d4759,
+        0x0040f3e8b527dd1c,
+        0x436ba16b27a83ae4,
+        0x2b8c63a68ddfa3fb,
+        0xee6e9c10b519e219,
+        0xf521992a51e64e47,
+        0x58c36172474448cf,
+        0xec31c93a5b073c9e,
+        0xee8b7377962bedb0,
+        0xbf19ebaa43b16567,
+        0x02e4c32cbeb68daf,
+        0x61f86b626ebbd189,
+        0xbac3a6c5c4c6587e,
+        0xd2fe933f80f2e2e7,
+        0x3cece28e3b154668,
+        0xa58e56a97340b7ef,
+        0x3d84fb9bfb973831,
+        0xd9696d4201c38ce9,
+        0x075e6e535c870a1f,
+        0x0c7c03f7aa9d0b0b,
+        0x5ae6cd2081701afd,
+        0x71aeca216b570fcb,
+        0x565396c0c80bae34,
+        0x186ec655e2ce7e41,
+        0xd9b8ab4b41e402f2,
+        0xe7b9984de1f888e3,
+        0x37faf3b91f67c3ca,
+        0xf1dbad7c000076c2,
+        0x4d8ccf10b56de4c9,
+        0xdea4fcb4b16718b5,
+        0x6b2fcd2915c2dfe9,
+        0xd8b61a6a0acb36af,
+        0x8f5698fca96938b4,
+        0x6ee347ab17043662,
+        0x207c3033e84d5fa5,
+        0x0d5c11d2dc0930f6,
+        0xaac62a20979de6c8,
+        0x1ec14b6c6a658b4e,
+        0x73c734c52c236c6d,
+        0xcad1ce6082ddb81b,
+        0x80ffb4f2bd2b6001,
+        0x605f30ce4da01bbd,
+        0x7254611bfcefd158,
+        0x5f1331718d03673e,
+        0x02cdfb9a680ce0ca,
+        0x3017fc03a2d60735,
+        0x5d5d480e77a30145,
+        0x367093ed7a90958c,
+        0x3b5523f33b1b100f,
+        0x809bc85ae0f8bbd9,
+        0x5da8006d9c565f8d,
+        0xe353814fe4251e20,
+        0x197d8dbc2f85cfb9,
+        0x6597a2a43bf05eae,
+        0x4fb35d2593476a03,
+        0x0d2b0600c506576d,
+        0xbc6a441c5f71656d,
+        0x52abd1f95ea84c38,
+        0x3536389f7d25335f,
+        0xc2d4414bbd1c3d75,
+        0x7fdaaac4b18920dc,
+        0x1c66a36d8e78f9d8,
+        0x705e5766713634f6,
+        0x23ad23d571d0b20b,
+        0xeabcb721feb86c3c,
+        0x8623d31ab0380722,
+        0x491472955ea70ed0,
+        0xa5d3d412047f74b3,
+        0x92abb5a0c3a3f121,
+        0x5bd015e900e0c2d4,
+        0x339f16e78de572a1,
+        0x37ba78f1bbb683af,
+        0xa801e7374609881b,
+        0x5ee6544b34770b6b,
+        0x5c6678bd74a6d846,
+        0x90d7e937722b7395,
+        0x42488f3dc7757c3d,
+        0x431295f77e975bdf,
+        0x2e3c299bd6a94f1e,
+        0x39269acd89eaece2,
+        0x5740c33678ec5357,
+        0xe9facea07c912f6a,
+        0x02372c46846381f8,
+        0x4ff414f32c4b2517,
+        0xca9f8e67a608675d,
+        0x2fe773e4b315fc07,
+        0x35a39809ce214a88,
+        0xfff1029df0a49784,
+        0xdd9806f2951d9e4b,
+        0x2d5a4320a4a2e732,
+        0x76dae43176346bef,
+        0xf5fbc0a32f54cf6c,
+        0xba8406885d440305,
+        0x62bfd89895b5fe83,
+        0x319758f5e8043761,
+        0x0196e9cb0ede7721,
+        0x8e10549512cd8cff,
+        0x96b40227a97a73aa,
+        0x0daf86234d561369,
+        0x8cc6baa613ce2f92,
+        0x89929ce1aa63d3bd,
+        0xf9bfcfdbc536cf6f,
+        0x5e64babfae0754f9,
+        0x384d708d869bd782,
+        0x99babd17ca482be7,
+        0x367384a42b1f86c2,
+        0x7cf6a2ffd2e05287,
+        0x340cce33ee54ce13,
+        0x402f20fccb66479a,
+        0xfdf960cc27af54e8,
+        0x2b47ebffa41b0b9b,
+        0x7a235053161a60bc,
+        0xeb61fef326ff1c00,
+        0x79b338df61613f0b,
+        0x046f414d92614b6f,
+        0x54a8e90daa4c0720,
+        0x4d96781b2b54f4c4,
+        0x4ad4911e40ee8265,
+        0x4e1545cc69b0ad4f,
+        0x28a0d13937700646,
+        0x1f23074105b947ac,
+        0x8215d33f381e022e,
+        0x52367b848ff6f3e2,
+        0x9f4308b6482a722a,
+        0x6eeede6db0ffec12,
+        0x4c27292865fd143b,
+        0x1f19d9fb83e439fb,
+        0x6a5281effcc24632,
+        0x5d7ce8bcc44c9b0c,
+        0x64b02623929682e1,
+        0x0c10598994c44a30,
+        0xf8b19a4b5e859414,
+        0xd349e601ffaff16b,
+        0x7dd58fdd7a168bcc,
+        0x4e7bbb81e109c15d,
+        0xb872c4a2087c135f,
+        0x9e24cf39d493ed50,
+        0x6c381aeffd6514b3,
+        0x097caad18b97311e,
+        0xbbb7cc039c229107,
+        0x763a039590f68e96,
+        0xd1f43f111e99a5ce,
+        0x1a96506f9e82ac83,
+        0x3f74923853f01919,
+        0xc5bbe31463b690c1,
+        0x871f8cd7d8418ee1,
+        0x3b83bee16d36dbb0,
+        0xf0acbe1ca0be0d28,
+        0x3ec86cc72cc4ade5,
+        0x1987f801b9da0103,
+        0x498772a00744a50e,
+        0x8ae1e987311c1a62,
+        0x468b5da37ec1e2a6,
+        0xda74051b9ed559d9,
+        0x7fcb2deea9c8a91e,
+        0xe1cf43da95a9c33b,
+        0x74f898318e490b4a,
+        0x7cbe4dfa26792be3,
+        0x61f0ed208c907f4f,
+    ],
+    // 0B..15B << 60
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x38ff4160a860d251,
+        0x2f82b6fc2f879ae6,
+        0x237789349ece0b1e,
+        0x603400081dcd40f3,
+        0x206c10de755075b9,
+        0xcff73737296ebc0a,
+        0x7a1c5f0d6fafca4e,
+        0x787c40da7d83d81b,
+        0x67d17af56fc1b747,
+        0xd548e75d628ad3c0,
+        0x7ded11474a49dc28,
+        0x1be3ce1b2d5c1f9f,
+        0xf90c5bfa9fbecd62,
+        0xb27be7822b9501bb,
+        0xaf3f7d1e88b1ca79,
+        0x3b178f005d3033b0,
+        0xd3e6097db865233d,
+        0x1953e6e708145b35,
+        0xe645c086f9c58d63,
+        0x202f1625cfb4b39f,
+        0x8e3ad53701829de0,
+        0x46f5181e122d6681,
+        0x148471ae291755f4,
+        0x13f8b0bd7149316c,
+        0xcc8c390bcae97dc1,
+        0x029c14a6a6d87e1a,
+        0xab6418dbce60e07d,
+        0x04e21ddb42bf5e55,
+        0xeac9addf1e6883f0,
+        0x91260969591a8d81,
+        0x6bdcee9960738906,
+        0x5a05c1dc9ea8f843,
+        0xb690423c37bc4774,
+        0x230476ffd7a17cc0,
+        0x7231eb33cf330d03,
+        0x6360795ad50551dd,
+        0x9880071171f1c6d4,
+        0x5d65c82bcd1468e3,
+        0xcebe0e382bc41122,
+        0x20db026ac452859b,
+        0x8794f96f2a443a91,
+        0x96406f931c41b308,
+        0xa24a76b8e586a849,
+        0x27a195f6a4512c50,
+        0x07088d6567c95059,
+        0xcd502f1682fae36b,
+        0x4004774bf342eb3b,
+        0x3881134f544f33e4,
+        0x4d1c5a60bfb07486,
+        0xd5d75ea41faeb944,
+        0xd8acc6a9f0a7dc41,
+        0x7bfff2545b368f5c,
+        0xff70bc52d0e04f76,
+        0xf539f1a7c57ba005,
+        0x907ded1b28cc9182,
+        0x4e438ba685c70927,
+        0x3c61b1498689af01,
+        0xa2b85cb2a81ab535,
+        0x8658d4aea0f3d1ee,
+        0x6fd71a34b355cef0,
+        0x1b83b7411ffb624a,
+        0xd4187a695337d592,
+        0x59aa06027b750327,
+        0x4d8a813060c041c2,
+        0x7154700bd7b191b0,
+        0x676ad295094b0569,
+        0xd71a54cd0c3bc11c,
+        0x33bbde0f7a2aa245,
+        0xda7ce5e573529436,
+        0x9c7ac1941e832e26,
+        0x46b6c8dc821766b1,
+        0x05fb543c48ce3d54,
+        0x6ea49fd8ea6834e8,
+        0x733a9a0e2f0257e6,
+        0x09355a00738db57d,
+        0x489754e311150130,
+        0x56f4f68b7772a2df,
+        0x42c3eabe8e990241,
+        0x985db15ca0c2f669,
+        0x4d9e85de5d65187f,
+        0xaa1512395340e91a,
+        0xcc1bd94b60acfc95,
+        0x90151b73ba3f1fbc,
+        0x01f466ac798d4539,
+        0x4969bed14a110bfb,
+        0x9be830b6aedec51e,
+        0x0c999d27ff40f3bb,
+        0x129dfdf75f2d865e,
+        0x17cd32a4f0390daa,
+        0x5968abd38165fbbb,
+        0xe0bd7f4618c26310,
+        0x5a4d154b5d012214,
+        0xdd098d77e3b4f588,
+        0xd093673b3667a0f0,
+        0x9ce3c839455442d3,
+        0x2501c24ca1be6902,
+        0x9bfa37da67f13f37,
+        0x135635974470e142,
+        0xf0ab90a2e8f2b7d5,
+        0x3c78de7a3f5de216,
+        0x7b3d568777680c60,
+        0xf3b92a5ac083cd77,
+        0x1a971decedf2a432,
+        0x3f476ab0262587f2,
+        0x741281d681e6ce4a,
+        0x022e83a0d3549825,
+        0xfe4ae056bd464043,
+        0x1a4656c2c0dbd80a,
+        0xec79e0927658ce4d,
+        0xeb31f02f5f40c5b3,
+        0x43c03b43d3b851ca,
+        0x221842338eeebd7a,
+        0xd533c084383e904d,
+        0x8143ae3337ab734e,
+        0xc6251e066ac51031,
+        0x792233ed81e6a90d,
+        0x807cb82e94040bce,
+        0x9e29a79c083dbdca,
+        0x01efba7ceaab4e2a,
+        0x6c98125a54441545,
+        0xe09444dd7a3dcf91,
+        0x5c6d7ddee44cff9e,
+        0xb38e08e5295bee4d,
+        0x285e494178783b6d,
+        0x3d02022e40d5e9d3,
+        0x6155e66a44f6541c,
+        0xee1b97c7667bd390,
+        0x4f352042e77b8c21,
+        0x2c18be552e71b729,
+        0xc68c6f4a448f0335,
+        0xd709f493be6f7160,
+        0x48f9109e79604600,
+        0x5e6da5869c52fd63,
+        0x830d234bb8fe5159,
+        0x3c8ce8875b5fd75b,
+        0x7f76c8ed39e2a2a9,
+        0x973c6822f860fc58,
+        0x0017f73d97eae0e7,
+        0x29dc628827b34710,
+        0x4bce38dc5df6e6ff,
+        0x789539615282772b,
+        0xedac693b25be9627,
+        0xb19df30250cace5a,
+        0x7bc1ce3e4a49b76a,
+        0xe3b9da02ccf2be33,
+        0x799b2172b76b0299,
+        0x54ae2f03c638608d,
+        0x1b1c5307bf33c4e2,
+        0xf4801d2d305d8046,
+        0x22ffc40923b6aac9,
+        0x05c3f6c96cf608cd,
+        0x067d560db1f386d3,
+        0xf906e111c983446d,
+        0xcedfb0e29fa3fcd9,
+        0x42191a5f64354e70,
+        0x423f3b33a57866b8,
+        0x752429bb1c507f99,
+        0x6e62474708aa34cd,
+        0x5e8d5d13be66962c,
+        0x2e5873a76c19e06e,
+        0x8c027249dda8e8ff,
+        0x6a6d5e2dfe894790,
+        0xde7e05f9bf6e292d,
+        0x6ceb68788d49fc81,
+        0x211bfbd1f9d6805e,
+        0x5a03d1db9e63b158,
+        0xf06dff954b788922,
+        0x06893699c4b96a3c,
+        0xa9c962499fb84494,
+        0xdcae00804e7cd50c,
+        0x630be0d085a0480f,
+        0x7c021beb19a8875c,
+        0x2f2567c2e83efdad,
+        0xc5675c181bc6a5d6,
+        0xaca4005029e5130a,
+        0x3005cf0f5f53b0f2,
+        0x8bf6c5917222de56,
+        0xe5d02111c34f077c,
+        0x03cb576962d78903,
+        0x178c7c8bac87ea4f,
+    ],
+    // 0B..15B << 64
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x744b205bc92a6358,
+        0x8c6b9a1f534a63e4,
+        0x6132de0877e9060a,
+        0x08c1ee3b2885fb5c,
+        0x08e7319f129e465f,
+        0x688a70780f82620e,
+        0x5ad2205ed83fe182,
+        0x6671a9a266dfcfe1,
+        0x5b95d8becbca3c1f,
+        0x2000d972b81fb42c,
+        0xa00061834ea65b26,
+        0x1bef9e88392287a4,
+        0xd1a5e9df02ce8f73,
+        0x649a3b926e83fa0f,
+        0xc4177295f4c5f928,
+        0x49582652548dba47,
+        0xb1db64597d99668c,
+        0x156832eebe981f5e,
+        0xffb21dc6c9141405,
+        0x1f98df52ddc80fe3,
+        0xf8b60c049b216ead,
+        0x74857c9a6c9ab8de,
+        0x0b8c6dccf604087e,
+        0x4063d30424ff0c7a,
+        0xa03bff5405b6c47e,
+        0x48885571d687acb7,
+        0xdfaea149f0a76041,
+        0x35f59d98754246d4,
+        0x9e0a4b1cd65f3519,
+        0x066c2bed010aac71,
+        0x35cd4761cf925200,
+        0x057bb96f55df334e,
+        0x32092354c17ecfc3,
+        0x2e98d2bcf0b5b5ef,
+        0xcb305fabe78df969,
+        0x37ed8e752db88a31,
+        0xf6d843f04663753b,
+        0x91a397a4d674ef4c,
+        0x1d1823e5645d57af,
+        0x4dc62ae10bd4f858,
+        0x8e60d5341939453c,
+        0x72b49dd54f6557d2,
+        0xf9ef5b0b3a49e26e,
+        0x59e7a54b6f4b572e,
+        0x2e6bab0235886f10,
+        0xf4e1270f4a061513,
+        0x67dbe261ef4df833,
+        0x45d51f3329ebe752,
+        0x482526ef055da3e2,
+        0x669635e0c85cb0ee,
+        0x6ecd730fc16de136,
+        0x0573a4094c6bb895,
+        0x6639607a7ff3cc93,
+        0xc987a535aea16142,
+        0xa0a38c351a32bea4,
+        0x7d950af845ec7cff,
+        0x92f9e20dbd82c2ea,
+        0x73e242fcae66762d,
+        0xab1b28f67747fcc5,
+        0x31141e6bdf30cabc,
+        0xd34f9b0b20e9ef16,
+        0xd3778f3864954ace,
+        0xa515872dccb12f1c,
+        0x4baba5ee565ba217,
+        0x13723ff5fb414cb2,
+        0xcde7a15c52499ef6,
+        0xc9c570ebedc10b0d,
+        0x1e29707c8be2a903,
+        0xcd3757711597507b,
+        0xb94ca1899de4b297,
+        0xc520257ddeb4e422,
+        0x2cebacce0a8dc09a,
+        0x68155094d8ae61a2,
+        0xf9445240c49634f7,
+        0x6a8587b8a8ce1384,
+        0x13fae3d02a7ee315,
+        0xb80ea1e594660ae9,
+        0xb6644f0b2b073e71,
+        0xbc4528341bb8748b,
+        0x7d5392d0d7b399ce,
+        0xec00348dcaac8f2f,
+        0xf7d72ff22db41e57,
+        0x17a54b9b6d97aa74,
+        0x4213550640a473a7,
+        0x4a8c6d71a1f30939,
+        0x0dfb3975e33f8a77,
+        0xa81d7d6dbd905e20,
+        0x48007a7c34405855,
+        0x302354e1fa31ba4d,
+        0x376b4d78514f4639,
+        0x35f7d64876aeb109,
+        0x65b21088b2f2e51e,
+        0x10d8324e754d5048,
+        0x4c7b81956c9e79d4,
+        0x9cc6a36822f7a2a1,
+        0x2501fa0aaf2045ee,
+        0x614b4d6b46ca3337,
+        0x190c7b9e16ed8247,
+        0x965da0fdc99e4905,
+        0x66c2e48c45497b0e,
+        0xc5b37e312027c83c,
+        0x1983d1d45be41fa9,
+        0x66455793a4b3ad5d,
+        0x391f7f120eae4211,
+        0xa2d5b974c319a8dc,
+        0x663a391cacd5f032,
+        0x3ce22e83511dfb9b,
+        0x366feeb439529e6b,
+        0x24e550ceb605196c,
+        0x1ecc0c436cdb790c,
+        0x9b4e25bfb34eb419,
+        0x2dd27a9f248cc5ab,
+        0xa74a15c3d94a67f0,
+        0x2d87420ffc626d60,
+        0x20f4759a621775a1,
+        0x706ff31ee96cea72,
+        0x64276441084ca2f9,
+        0x1dd47918ecbf1aa6,
+        0xd8798c9f40f5221c,
+        0x5f19e5c2f520a53d,
+        0x247fede50bc399da,
+        0xb147368a32eff087,
+        0x0528cc7e101f0862,
+        0x415a27bfa06fe9ef,
+        0x01f5955affb1ad7c,
+        0xf41be227eed6ace9,
+        0x7f5680df428858ac,
+        0x216bc793cf82639e,
+        0xe73f1681770b41f8,
+        0x9766a22f32d28667,
+        0x8b3e8ecc8e0e03f4,
+        0x66dc479efefc1516,
+        0xf4a85e8507b6a4c8,
+        0xa81f1f36d90452a7,
+        0x606cd4bcb12143c7,
+        0x701d2efa4ea4d55e,
+        0xd3ad4e9644a8f106,
+        0x69104f5360d333a5,
+        0x07068271156cbcfe,
+        0x7b7d8b0e4c58dbd5,
+        0x5989c15da05f043d,
+        0x963c157cee307511,
+        0x704cda28589b426d,
+        0x023993def1662d6f,
+        0x9d859436a0cbd3bb,
+        0xb178d0a512085679,
+        0x88e503194b94cb7a,
+        0x57c69ceda3e52d67,
+        0xd99f3667c2d045fb,
+        0x8892411c7cc4b3a8,
+        0x7042b94aa19ddc59,
+        0x47603797936607ea,
+        0x2698a5dde1f65db3,
+        0x898701cf3ce99f94,
+        0xb3e0dcf09625d2c8,
+        0x195adbd7cc8b8977,
+        0x8e94dbcc35938f43,
+        0xe41eafca918fee17,
+        0x45b565b7123fdbb7,
+        0x009e667cff733033,
+        0xdf8ac482857c6fea,
+        0xb5c809f92fa13a04,
+        0xf4722966c69b9510,
+        0x4642f6e846b9c2bd,
+        0x594102bfe5b38766,
+        0x259402bc2d35cccc,
+        0xc2c742ffda28be92,
+        0x08378d5538188193,
+        0x8b5c0694808bf2ad,
+        0x6f4fd9d9956f5da2,
+        0x8ea9fa312b9a4b55,
+        0x74b177e6b9fb8e67,
+        0xef6b91a07113c596,
+        0x38b75e922de027bb,
+        0x0c930f92e2c5aa42,
+        0x39b6e2505fc894f2,
+        0x742a237a0f1a859d,
+        0x95d1f97235ce6012,
+        0x741bd954a5ed2279,
+        0x1fb640cdb531bce3,
+    ],
+    // 0B..15B << 68
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2d063167074ed4eb,
+        0x6237c383f1804366,
+        0x20ab0ee28f3f76a8,
+        0x11ffad706385bb6d,
+        0xa5584eb6575eac97,
+        0x8e4b2ce489cecff6,
+        0x91316763863c0d43,
+        0x52c2239bc282892b,
+        0xe527e87b24e37e06,
+        0x27c12fee01a11582,
+        0x9cb43fa5488fd27c,
+        0x602c9fc3e118d220,
+        0x4b26f7c0a4317564,
+        0x70de0611b8068230,
+        0xb79431b8407a9737,
+        0x122568c3552070c1,
+        0xac2bd0e67f921311,
+        0x3c0da23ed4c8172d,
+        0x187e777cc1be9665,
+        0x721b9bc3197e5f23,
+        0xdf2cb6376cc5fcb7,
+        0xe72d22576d4a5f75,
+        0xae08cd9d5f1a6c37,
+        0x572c637a0bcfb499,
+        0xfc3a528d37300f50,
+        0xfdc9dae49e783cfa,
+        0x1ff11421c4139501,
+        0x0b12d8b41f949d6b,
+        0x13accc611486b68d,
+        0xab233816af401e75,
+        0xa9f66dcd7905a0b1,
+        0x3bac7a5d11c87cfb,
+        0xe8f75a136a054082,
+        0xb0f48a92cff31a56,
+        0xf1cb8ea5d0aa3675,
+        0x33b7d272594669d6,
+        0xec2a819584770e14,
+        0xfba6e0778f0cbc26,
+        0x226991e899b0d3be,
+        0x321ff437d28af49c,
+        0x8a9f41906da7b0f1,
+        0x310260b99b2b4dff,
+        0xe6e9793a0b3b9405,
+        0x5a17ebbaaf1152fe,
+        0xae46ec5049a9793e,
+        0x18ddbc55dc694763,
+        0x39222e0502b0abf7,
+        0x72ed742407341e89,
+        0xdc1608e81530bcd5,
+        0xe235eeae186408a1,
+        0xd96b8bbea2d4d1ea,
+        0x1a8905ff7cd7b54e,
+        0x9c922c7f9babe718,
+        0x6dcea9e37167df94,
+        0x8cfacf001ba085bc,
+        0x7992786c52fb7620,
+        0x038e158801b2688b,
+        0x1f6e41e925ba9b15,
+        0x605083113f4b7294,
+        0x51fef3fb3e35ff65,
+        0xc9a673ef081ade1d,
+        0x5ba290bb9ace7f90,
+        0xaca29affbb9a0cd5,
+        0x478e35c0620debb9,
+        0x7583fc95d47cd0a7,
+        0x4bcb33b104ef789a,
+        0x303ad186f1fe1713,
+        0x6545cd506bbcc44c,
+        0x7c01316444d2ad59,
+        0xe664a0de65b9bb4e,
+        0xfc8fd21b367d4b01,
+        0x1f38a07b85297c57,
+        0xd661ddf232f09ca6,
+        0xb21dd8724d6d0e37,
+        0x58bd6188c800ae8c,
+        0x56359267b35b579e,
+        0xfa0d64b76ca0091c,
+        0xa0b35589f4ff3079,
+        0xc5175d08cd083044,
+        0x4bb79ad88d63d2a8,
+        0x507cf45b13401e73,
+        0xfbaae7e3fe5de731,
+        0x3b00a1d6ea9bfdfa,
+        0x7f88eeba4a0ae506,
+        0x1d9e532cfc265b26,
+        0xc33b6dbb066d8501,
+        0x1abdfc6a4429cac0,
+        0x4f56321df78353e0,
+        0xa27e4a434f270e95,
+        0x13888bf87c7e2574,
+        0x88a5cf09efd4185d,
+        0x34050ab40858781e,
+        0xc60b135dba0d8e03,
+        0x9ac0d9a642e3b261,
+        0x8a33fa4fcd3a4409,
+        0x1f205f31d23d4671,
+        0x0bf9df73b919ce38,
+        0x26fe3fe8f111a7e5,
+        0xc217c33e18de42e2,
+        0x3b5624a13560dcc3,
+        0x3d7d9140d87768e5,
+        0x211a2d29d7f57c55,
+        0x6779cae7e30e7f07,
+        0x5a948e03e15c9d94,
+        0xc255c74605cf5d5c,
+        0xf892706533a220e3,
+        0x21a27f695067f3b0,
+        0x30ed30cfe61757a8,
+        0xcf6e82b27b56f476,
+        0xdee48d9d7e70fa8e,
+        0x29c2ebd55d3cd8fd,
+        0x158bc21c075b7f74,
+        0x81b2852c42564cc8,
+        0xc1043b9d624c76bd,
+        0x91c49cacba32b8c4,
+        0x7a8e709ebc252d32,
+        0x92cd2391fd134b78,
+        0xf024a96e439ac619,
+        0x22e9c832ed548fc0,
+        0x11ebfa3877225873,
+        0xc27e767ad3f61a83,
+        0xfa2e503be5f8b60a,
+        0x26b492aa742ad407,
+        0x3774d07867105edc,
+        0x828b2eea30e541cd,
+        0x0cc3b2ba165c8813,
+        0x031fb3898a41edc2,
+        0x06a196df934e4afb,
+        0x59070c611ba64398,
+        0xa4de52ec948d8a89,
+        0x289916167d99ba44,
+        0x6cd0dfcc6915d90c,
+        0x7b79267bdc01102c,
+        0xaff7449ef8f62197,
+        0x89e417943e8214e4,
+        0x2f9c2ece7cf6833d,
+        0x4ab30c2a190fcb0f,
+        0xb7068ffc084dbf7b,
+        0x4ce1f41c1bb350cf,
+        0x51314fb9472903b3,
+        0x3231fe72472bf7dc,
+        0xedaa045e39127be8,
+        0x2c9b95e0ec7e7d1b,
+        0x06f0fd1f7cb6a52e,
+        0xa9ad8446a1452ef5,
+        0x1946450f25700b10,
+        0x328bc0d78c9b3f10,
+        0x1f3b1c1ffa6fc9b4,
+        0xaaade25c100b4ace,
+        0x9613753bc46e5d5b,
+        0xd791e1c42a26a4e9,
+        0x52682993b9979573,
+        0x398a2c157f82c5c2,
+        0x33a901c0156343b1,
+        0x8464b5bea1410b33,
+        0x2fe786e07481881d,
+        0xf22004476447c4ea,
+        0x9e28c217bdf226b6,
+        0x4a2ff4db19a9f4da,
+        0x1506914700155d9f,
+        0xa67a19647699af19,
+        0xf236495f4afdb0b3,
+        0x02f1a5baad1d529e,
+        0x3595552123b729c8,
+        0x610166e35965d005,
+        0x511898ea1deb998f,
+        0xd3df86674047dcc0,
+        0x366fe926af236e95,
+        0x4419f0292b4361cf,
+        0x30336bb83d4ce38d,
+        0x09d51cf0ee23e151,
+        0x3f0b514651399235,
+        0x4eff36f3dd633bb2,
+        0xc6f43c4db13970f0,
+        0x8e529875617ca483,
+        0x19eea83022f4f913,
+        0xc2e647caebbaab67,
+        0x1e73aeac22714e74,
+        0x91bd28de87664ab9,
+        0x3220628f67a37b5d,
+    ],
+    // 0B..15B << 72
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x56749a9a34434694,
+        0x4489b9baf56c0358,
+        0xd4a41e49d2afc505,
+        0x6a859bb2e09487ad,
+        0x653347a51458ae0a,
+        0x1b30b9a25fcf0cd2,
+        0x57b05378cf4dfe32,
+        0x170427564c6ad6b3,
+        0x6b586156c7776a11,
+        0xf269ee8862c4bf35,
+        0x7097daada15ee0f0,
+        0x4e40334086210bf0,
+        0x4b2b30bfb21da22c,
+        0xfe4372aa383cf32f,
+        0x6ce12ecbabc55763,
+        0x6bd309383518bb8f,
+        0x378ba8f07e9aa536,
+        0x71bb7d5496268d55,
+        0x13825d115c8b4154,
+        0x74b2c123888ec1c9,
+        0x030b2377684e6d21,
+        0xc7d0a098ff617bbf,
+        0x7fd0adfa43f6325a,
+        0x18b09bfda15c170a,
+        0xa7ff780845b69a49,
+        0xfb88b011bded4ab0,
+        0x9fc17a191bdda564,
+        0x023d61666ef36a90,
+        0xcca3810b94d41340,
+        0x63bafe2f87b21684,
+        0xf522a7f139594d12,
+        0x6c83a7ac1ce44b9e,
+        0x98fa88b1e9e4f5c1,
+        0x39b45a07060dae3e,
+        0x999457d297ae9cd3,
+        0x1100e238752ae367,
+        0x9b02e0de46bfdbec,
+        0x1771faebf307216d,
+        0xea0c041b5ff5fcb4,
+        0x694113c841dbc6c0,
+        0x591926bef28640e6,
+        0x507a1e28012e44e4,
+        0x53d21ca90d174c07,
+        0x0a45b8bf74da284f,
+        0x7b41be70c5e10e9d,
+        0x56c164d89ee92e61,
+        0x4e796403303f3877,
+        0x1455ad64728b0d3d,
+        0x245cc74c1b0d8d78,
+        0x7a86a0d90bafecc4,
+        0xaad027159bd904f7,
+        0x08c49f4da84549de,
+        0xc371539c367ad459,
+        0x6843a55b9adf4b54,
+        0xb988d006471aab56,
+        0x6276b99f4dab15c4,
+        0x92cf0c68009660f3,
+        0xc4728439c9766485,
+        0xe7157dbf0ebadb61,
+        0x5d96af06689a52ec,
+        0x6049330118f76488,
+        0x493294578bb37011,
+        0x5f158980e4796901,
+        0x31a489f75c342f89,
+        0x2e7d22f162359542,
+        0x2f6708a1414b4804,
+        0x62a1d55ca870347a,
+        0x69919ccfc149ec21,
+        0x3a9dfdcdcc28fd85,
+        0xaa03d38d9974f36a,
+        0x1cc00381e5ec77ac,
+        0x2e323b74c5bee219,
+        0x49af56de4a24750c,
+        0x541ed534785632e1,
+        0xea8aecadd2c071d0,
+        0x68383c6e5cdadae2,
+        0x8d83a1b3dcf68bd2,
+        0x6aab1337365723bb,
+        0xbf0a30231b20e85b,
+        0x481818326157b9e5,
+        0xd2f3ea1ccc84c833,
+        0x5201a2b522ac57f3,
+        0x5290517ac86b0137,
+        0x7cb7103d4567b40e,
+        0x1dd2b050198df2d5,
+        0x47c7da5ce63f0e9c,
+        0xb70947c69dcf2bfe,
+        0x2c52b3754215c220,
+        0xc19b89967ed2c590,
+        0x11411e683f9ea281,
+        0xc76f475bf22e22ba,
+        0x652ed6a699ff43e8,
+        0x0d1255e97426720f,
+        0x2ef2c74233b5662b,
+        0x4bb832e2d417ef2c,
+        0x6e8b15dff4be779d,
+        0x2349161a9d0276a0,
+        0x8fa4949bba8da011,
+        0x207d301a8f8d7094,
+        0x338f26b97054a232,
+        0xdb5a970ac67a660b,
+        0x36108e2b79913ffd,
+        0x8029edf75ea9c946,
+        0x105fb6a5de5dd39e,
+        0x252a946a385f3000,
+        0x3673d495e51fac2e,
+        0x9f9f7fa606e6cdcb,
+        0x6e7972d229cb2f4a,
+        0xc3c7ea7b1fb2d38b,
+        0x634d7cbfec15ce8d,
+        0x5230ac41d3a71da1,
+        0x1c3caa8575233199,
+        0x429d478fc4fa2f71,
+        0x65df38aabb633fe4,
+        0x80bb7b70bbcc7327,
+        0x4d545f6424ad53cd,
+        0x4faa2502d6173519,
+        0x52b33abf6ea5db58,
+        0x51e7bb7690e56a5d,
+        0x7939ee38a0e0feb3,
+        0xa094f20d374da6fd,
+        0x4e1a275d20ff192e,
+        0xfdc861ac50bfc7a6,
+        0x1b38a3f48a94f3ac,
+        0x4812020eb8f3e7ca,
+        0x77fb3933d27a502e,
+        0x702713a6d626ad9b,
+        0x75a061f09329207e,
+        0x55887e63463af88c,
+        0x67321020232b971f,
+        0x45821575897be7b9,
+        0x7986fb589e469841,
+        0x896dcdafc7a6c61a,
+        0x2004d287e80f73bd,
+        0xa3353ea80f3ef90d,
+        0x772ee911333b3cbb,
+        0x0df193a026b7cc7e,
+        0xe008c6941ad1aaf4,
+        0x08709d447c8678c8,
+        0x4a12be40bd68eb69,
+        0x9c346b24eee89b90,
+        0x1fc60844f926737f,
+        0x43ce317c97ed70c5,
+        0x74220873ddd88cb7,
+        0x21d0a9bf69d0b785,
+        0xc9b07b05e7a35693,
+        0xf87f45ef67ac4ee0,
+        0x3338cdc2f87a5feb,
+        0xf6cba195d39227b3,
+        0xc7ee4e3faf7f2534,
+        0x9e3ce7d2ba11553e,
+        0x78e44ac686ba4b0a,
+        0xe818ff07729a5e5a,
+        0x07d3587ac316a297,
+        0x1c3a5fd6b03bf66a,
+        0x58c130726406acba,
+        0xa2b5c04883f44760,
+        0x6f61a4e2c2d7e0a9,
+        0xae6e6950cfaeb398,
+        0x50aaaa49da0ed0f7,
+        0x72d21ee43a1259da,
+        0xb37f610f5100aa44,
+        0xfa6beac32ac5222a,
+        0x7316f3c136ac21c1,
+        0x1b5b35e74f09b808,
+        0xa84ed649000bbfb1,
+        0x7a009b6b3fee4c5a,
+        0x4fa5345ba1218ca3,
+        0x29fa33581553f77f,
+        0x6c1288741024ed69,
+        0x117e0e2ea6194b61,
+        0x13d902affd01bb91,
+        0xdfe3ec7f5249b124,
+        0xc438baeaf2affa16,
+        0x1dc71a7ef8693be5,
+        0x2203d57c3a564701,
+        0x10fe02cb5c9e7c3f,
+        0xab5dafd3f30c54d0,
+        0xadfce4398c236469,
+        0x59a179caeb298ed7,
+    ],
+    // 0B..15B << 76
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x01636b57e814caef,
+        0x9c392a99c1e7241a,
+        0x3534fb62089df48e,
+        0x63f5947c536d3ce2,
+        0xf5d68a1106e950e3,
+        0x13dc56e4da3df5ce,
+        0xe2f3760bdad6f966,
+        0x08a4838e677782a5,
+        0xa78f33be67687122,
+        0x64070000f434231c,
+        0x030c57949c2c9466,
+        0x221d7fce880bfbcc,
+        0x673222c204c7d38f,
+        0x1dfbdbddfd76e0a3,
+        0xba9fe70db401136f,
+        0x14e007e8780c638d,
+        0xaa95f88026d08443,
+        0x26a026d335b1989b,
+        0xc07265152d7f1f8e,
+        0x3f830cb3982a58ce,
+        0x0470c9aab000948e,
+        0xfc1c7469b9447c76,
+        0xcabf86b4c949559f,
+        0x6f94d710307ac2b9,
+        0xba018b7c6eb9d4d0,
+        0xe1d0ee87328c50a0,
+        0x13a6b730b8b0ce6b,
+        0x06ce34a5e4b11368,
+        0xadf33e21966037c6,
+        0xf53bdecaddbddf3e,
+        0x14591227cb4c345f,
+        0x4b90b64a6af0b040,
+        0xc121b6493724dae8,
+        0x1c770a9c486dd7ae,
+        0x0d378a05ca989d5e,
+        0x3309bebc2b6321e3,
+        0x23b0a117ee8ee520,
+        0x24922333f1d053e8,
+        0x0a8923a7f9903f4c,
+        0x41ec5712a047bbe3,
+        0x04e406067e94b148,
+        0x01da505744307064,
+        0xffb185bcd65188c0,
+        0x0411dec156643717,
+        0x51015331406bf30d,
+        0xc9945c813c6957d7,
+        0xb0780615427c8197,
+        0x186391bc850b07e4,
+        0x01e660113f5cf761,
+        0xedf796b580b70b10,
+        0xd20d2273b5c6c9f1,
+        0x140410d0edd41abf,
+        0x74ffaa61bee2ccec,
+        0xbf105111568ce7b0,
+        0x404b862357c6ec56,
+        0x025a75220acb4bae,
+        0x75f0810a8e2537bb,
+        0x6971ebc07d1d50b6,
+        0x91bce5cb5b5d8de1,
+        0x4b83c361e1a2133e,
+        0x70c3cb326c3b2833,
+        0x08c54b3fc06bc4d9,
+        0x831e55815e384557,
+        0x7603ccf4529d759d,
+        0xe7adf8a2671fd1dc,
+        0x927fe9c032a799d4,
+        0xb83a37ffc482d746,
+        0x0cd500b73bafff95,
+        0xc32517d7f52d8f21,
+        0x9850cd98409ca3f8,
+        0x432eca652998afc1,
+        0x2ef3db666ff0f3c0,
+        0x217c8e6fd87cec16,
+        0x0da8e5f048fb9778,
+        0xbf6530939d87b826,
+        0x4f303d0791c9c078,
+        0x79d772136daaf02c,
+        0x2e2da995a6ed0891,
+        0x63cf7fab31f3476f,
+        0x6dcd19398de53eb3,
+        0x3e7a704018dc8727,
+        0xdfb633ced54974c1,
+        0x3e16c1ba749cd602,
+        0x7203b685326eccc5,
+        0xcb98fd399540ac95,
+        0x8da6afbe8771f7dd,
+        0xf1aabddb71cb5a9f,
+        0x40ce5c5573a93f70,
+        0xb71a50920687e312,
+        0xf363c540d401c513,
+        0xf6ec65ad9613f1e8,
+        0x11261a21d963cc35,
+        0xecf265bcb44d31ed,
+        0x3ff4c7bc10dafc76,
+        0xa97532f503609515,
+        0x753cdfab480eace0,
+        0xe4e8cc9b16614969,
+        0x7115dd78660a8360,
+        0xfc8592efcb946363,
+        0x187b2b5fd3b02b98,
+        0x873b8b1e6e0e1f53,
+        0x6f7a2dcf921776b0,
+        0xf38b32d121e8565d,
+        0x4249b862ac1bc652,
+        0x5d72e75c211b6e18,
+        0xe3c7f58c90c17c89,
+        0xe07ba3f4dcfc6a15,
+        0x329cd5e0c9aba63e,
+        0x53ff61a920e46e1f,
+        0x0c42033fd8c45827,
+        0xe936be5c840c41ab,
+        0x2b87d9d749b4247e,
+        0x1b4bc218c52a2f76,
+        0xa47279916e36ca7e,
+        0x2a707654c810af0c,
+        0x69d6681bdfe45241,
+        0x42a8c26cde3c8be6,
+        0x70bbec24210ae271,
+        0xd314871eed066bcc,
+        0x10b198add398880f,
+        0x9b0992afdd20f43c,
+        0xf0babf31f618c7bb,
+        0x6f869668c0a267d0,
+        0x122ead8a1b431513,
+        0x271de5f83ac83bcd,
+        0x4dbc95fc0b5956ce,
+        0x557265764479ad4f,
+        0x4c9d0165bd9015d1,
+        0x18a9db59c89385e1,
+        0x04034b21e401c745,
+        0xed793c4a7c62ada9,
+        0x1e81cdbef81f9515,
+        0xdedee25398bb8466,
+        0x0e0bd797567d7205,
+        0x3add0b743d8c5514,
+        0x394acecb3a657394,
+        0xa77176fca26d48ae,
+        0x5559f0d32e1da1af,
+        0x21d476a03c12d9e8,
+        0x2449872a30a2de42,
+        0x339d94037d9044ee,
+        0xdcad77785aeee684,
+        0x7a0ae192779ddabd,
+        0x709065425feacfc7,
+        0x7c47e24efd784724,
+        0x618d1e47a0fb64ea,
+        0xb82ff31752034b53,
+        0x5f1fb009cf5fb1e3,
+        0x07c49624a872d9c7,
+        0x75d59c549664a78f,
+        0x3c64b5cbf9b131f0,
+        0x545ac6d043fe7633,
+        0xc7a12d5c8f22723b,
+        0xa0ef9425649a82a5,
+        0xb52b52f5eed1e8ba,
+        0x1f74ddae3d72fb7e,
+        0xd64693db54bc034b,
+        0x82b8ea8ed7c3e388,
+        0xecd24b4cfdd12619,
+        0x7c32ac6ecccd23cd,
+        0x41d4f7f80c4a0148,
+        0x01888bccb5803108,
+        0xd80107062b0b3c6b,
+        0x13146cce2ce983a8,
+        0xd5648aa081524c7a,
+        0xb18d75458bc1ac7d,
+        0xf4f1c41aea9f7bb9,
+        0x61298044791b9c6e,
+        0x032cfe8baa5fc167,
+        0x9a34e34611aa13f4,
+        0x3a918dce3fbbe495,
+        0x37bb8c6a37f41e32,
+        0x1be42cafc32cd7ec,
+        0x6b6a6a47a64943cb,
+        0x39800048fd960c25,
+        0x2ec81481b4d0e8fa,
+        0x01cd6ab5d75bbf21,
+        0x528aa0fea2927f7f,
+        0x31028b51c7714849,
+        0x15a1a29e61b6519a,
+    ],
+    // 0B..15B << 80
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xe0a96110d336c4be,
+        0x0d98eedfbd07bef1,
+        0x4aad4a748141c540,
+        0x1e747fbaab4c99b3,
+        0x7a5ed2845669b800,
+        0x7da79dbbb8cc9acc,
+        0x4fecde8a8728a261,
+        0x7455456436138e39,
+        0x0d5bccb3aa450df5,
+        0x2864b889099d0813,
+        0xfec582d641f07772,
+        0x7d2b71a208630856,
+        0x59af2e8b29c270b7,
+        0x96707d02e2ada36f,
+        0xcbc0f584e7bfd5d3,
+        0x7a0c3f41b211d329,
+        0xb5daca3892130e0c,
+        0x353dfa82f8719ad1,
+        0xdaa14f09b9b9cfa6,
+        0x5bbb907a554db867,
+        0x5fdaf4294291a483,
+        0x2c95a8eaf467707c,
+        0xd04cd127bbcde43c,
+        0x3afe355fbf00e0f5,
+        0xf775c4fc5a2ebd4e,
+        0xd3be37885a62bd47,
+        0x4addbb8d8baa3442,
+        0x42af711fcea9ae67,
+        0x5fd30cd53560c78f,
+        0x8e2604edcb9eae22,
+        0x1273e58020d792b0,
+        0x653123c79d2bee21,
+        0xc643c423e8222d86,
+        0x7241fd945d4a49f2,
+        0xde40b4f4c95ee21b,
+        0x55f2c63ee22022e9,
+        0x0317704253987f82,
+        0x5cd2e715d2555c88,
+        0x71a39d7d0aaefa25,
+        0x5b591deab0eaa376,
+        0xbe28efd1df50b21c,
+        0x22f89e2ab4527d96,
+        0x0d9a0d76db4446ca,
+        0x0928bd3ab5e32872,
+        0x66372661ec0a31f6,
+        0x74b34643eb04bb5f,
+        0xc4d9076670f46d38,
+        0x0d4acd6ee48611b4,
+        0xfd9ad0c9973bf222,
+        0x9b12f8b9b8020846,
+        0x7e2af85d0b9c9d57,
+        0x36b0811f89109eb3,
+        0x5cfa0eae0158b8a7,
+        0x7a6e9e9f51f5ced0,
+        0xbf097040f2e75936,
+        0x536b13df9e9cc47c,
+        0xaf66ff404e602f8a,
+        0x2b3c2745b8d4f2a7,
+        0x3efea172b1d4c73e,
+        0x2127330341fb5aed,
+        0x2ff941182ba7d6e9,
+        0x93d4b3e1ce8c8250,
+        0xfa4796d1073c895f,
+        0x185d453408aec201,
+        0xc768a8266322124b,
+        0xa084dd3b27237641,
+        0x370bccafa9eceb13,
+        0x06d9ce97ab36dc1b,
+        0xc9a21631855666e3,
+        0x433785e262fe8adf,
+        0x068d21ce9264fcc3,
+        0x78169c46fdc7e6d5,
+        0xd87ff23f947810d1,
+        0x0bfcb1e285ad961d,
+        0x677cc7aa765700e9,
+        0x418086bc5707140a,
+        0x949315747f971a36,
+        0x4176bce2c0c76a2d,
+        0xd9b81af650b1b2fb,
+        0x119a4c8ca7778e00,
+        0x1b09856ce0feb951,
+        0xe1fea702cd30488c,
+        0xba75de42d4dc8e6e,
+        0x4032083b027f9c75,
+        0x83908792623394d0,
+        0x05d1e7b726b6ce80,
+        0x625810e5db20e1b1,
+        0x722297b1ed5fe0f5,
+        0xfbf5fcfe475be0ca,
+        0xba5ea619eaa8d91e,
+        0x884839f941aa4b30,
+        0x243640d035345589,
+        0xc06baa13b1ade4ef,
+        0xd3aaf54656db6624,
+        0xa087d60caf6d6b76,
+        0x3d7f7d2f023a73a4,
+        0x054a72146297e866,
+        0x1dba81ba0a1c7ae7,
+        0x36884278dd2d97f5,
+        0x4af996b91c7b9eb4,
+        0xa9bfb43d5ddcb573,
+        0xd33c5d6736097bb8,
+        0x5d94a372c1ef6352,
+        0x62cafc97720a91ce,
+        0xee5ace54a6e8d01d,
+        0xe38d6afce155a6cd,
+        0x8fd56d554ab4d8ed,
+        0x263fe74c55f40e30,
+        0xd1cb23c27c0f7e37,
+        0x1d9b419bcdaf0457,
+        0x554c146f9d8d0d00,
+        0x6073bf66633458c1,
+        0x1020063735c2ba0e,
+        0x377e8919c48329d1,
+        0x3f7e98627b56f615,
+        0x1a29aa96bf6ff818,
+        0x973de8d84d13e138,
+        0x84667f4746b82695,
+        0x1936d4521402e082,
+        0x00fb51dee5b906d3,
+        0x860cc9fbe3693cdd,
+        0xcdd4a11b0e26ebf8,
+        0xda1944150b0c3d1a,
+        0x0c3bc2543e0265a1,
+        0x6687059dfb596c01,
+        0x2edda931135596bc,
+        0xba6e3b48aa2a603f,
+        0x1fb5071054f028c8,
+        0x82b7eb13ad7a8897,
+        0x31902dd8972c2f91,
+        0xa6bce6dc941c8616,
+        0x7d0d44ea35a08f9c,
+        0x4a6fdd613d6efafd,
+        0x8dc1a5cdf266336f,
+        0x73ea5889c8da31fb,
+        0x62ba1e4e1026175a,
+        0x6e710e482220d2bf,
+        0xab9e2107af59bcbb,
+        0xae5eafc67faf570d,
+        0x0f1242e87351990a,
+        0x6fde41e8c17b62c5,
+        0x31cef540ad151aea,
+        0x912191881e7cc345,
+        0x5fdcba5e21403244,
+        0x2feb0ba9ab706785,
+        0x2a6f676ce07bca26,
+        0xf1d5a21a46289e76,
+        0x6978f680c655fd94,
+        0x05ee69d4423428c2,
+        0xa6c3e61b644f2685,
+        0xe2e3e8152fbd814b,
+        0x6089bb6e792b1431,
+        0x1dd6ec6e6542ab1f,
+        0xe94d87b2db7d086a,
+        0xe7d78d8472c26576,
+        0x5763c6dc4e85303e,
+        0x2518eaf533ef0486,
+        0xec47a52f6a5be48e,
+        0x1006ebde03fe8274,
+        0x2d240f7d5dd6deff,
+        0x4f3f4e74021cc1cd,
+        0x23371bde959d3757,
+        0xaf1c6f884687377a,
+        0x18d0d9dcb70659f3,
+        0x133ee9bfeb47252e,
+        0x08c5c5c339def54b,
+        0x52b242cc37c542f6,
+        0x054624aae92ded66,
+        0xc313544bb43f48fe,
+        0x9e4f6156aca3ad8f,
+        0x4f9796732e0f6d60,
+        0x7aaa0248ca167675,
+        0xc199316abacce7be,
+        0xbe4ca3691290442c,
+        0x7575891f32ba386e,
+        0x7518aeef18ae0b26,
+        0x1cc74896b73378b8,
+        0x03fdf167c31c0a00,
+        0xbd7ddd758889407c,
+        0x7b0a1e871c97cf3b,
+    ],
+    // 0B..15B << 84
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xef9eb3d163529275,
+        0xf18a45e1a5e7417b,
+        0xb3edde22ef812c8b,
+        0x488e5fb5d9229588,
+        0xce46b4abf217270b,
+        0xd605c7d1196f3e6a,
+        0x48e2629146de2a32,
+        0x71f690a5c38bbf0c,
+        0xb0f7fd1f3afd3c89,
+        0xc00d53c79b2a33bc,
+        0xd3c07913674e407e,
+        0x1d82b0a3018e1bae,
+        0x9a721b3bd6527007,
+        0xdbdf35b92cee6bba,
+        0xa6ec03f60511f5fa,
+        0x390c0d19a574c7c7,
+        0x1d753431acb05784,
+        0x2e6192c2c35003ea,
+        0x000fcd2e391e6bdc,
+        0x11b4088dbdbac806,
+        0x7de343aa86c89ef4,
+        0xabc45100242788da,
+        0xac898004a9b911e9,
+        0x3f542c0b3e955159,
+        0x45fba079584d643e,
+        0xc7c22720b6c921b2,
+        0x7a715c84a60c3ec0,
+        0x5d689a9608f2127e,
+        0xb0d1680345ab0f87,
+        0x81e30dae645e67ca,
+        0xfa606fd098e2fdda,
+        0x129245bdd43150b7,
+        0xb8525c6587f85795,
+        0xe7b79f005d3512d6,
+        0x0f1cb6423996c254,
+        0x74a52be2aa449206,
+        0x563c5aecf4221a40,
+        0x396552abb13bdb77,
+        0x25d4f0e9536ab6f5,
+        0x3c9d722483676c7b,
+        0x475c79cf8d35e082,
+        0x60950244d7bc5971,
+        0x67865833ac86d42e,
+        0x19a909dab38bb4ba,
+        0x88f9dd4ac147b3f0,
+        0x72e5b7d7b8f93032,
+        0xd3aa9e038db4a2d5,
+        0x5f4d6766a86ac8f6,
+        0x75763f8cfbb6da90,
+        0x730bb1b108422686,
+        0x33678e66dc09769d,
+        0x4ceba5628ef47a5d,
+        0x50da4fc1d961d62d,
+        0xe686f29f50d7ac1e,
+        0x897c05adaa0a7fdd,
+        0x00872bccb8b1b725,
+        0x2458ff5d8bd82344,
+        0x1944edcdf1c8a106,
+        0xb5140b1cce9d4686,
+        0x452ebcd9a055fc36,
+        0x5c5b1b0e630f6fc0,
+        0x1c70f92be2e51198,
+        0x33fae34668871fa4,
+        0x222c34e673db922a,
+        0x18ecfcbec293de61,
+        0xc2a7c94079ceebea,
+        0x1de02dd4bd18c9c8,
+        0x76d56469044dd68a,
+        0x2283bfddcc904b09,
+        0x560852b223e4b11d,
+        0xa1ca5343839504ed,
+        0x376b9c725b685b91,
+        0x21b27d896054ec69,
+        0x535cc34a92c7c745,
+        0x903c324ff11d948b,
+        0x625644c7e912dcae,
+        0x4abc2d151b11fafe,
+        0xc588e8990a2acd0f,
+        0x9836f9362fded036,
+        0x03cabc2dd7edfc12,
+        0x949a2b9089b05c0f,
+        0x8ebe598d54977d66,
+        0x1a886907763aa24f,
+        0x6733d3d877457a9a,
+        0x3d46e6b19a4ca22c,
+        0x82eda8b6a771bd38,
+        0x35b17b8bbd3d95f2,
+        0x10d9187b4ed8a77b,
+        0x79afff6e69043944,
+        0xfeb4ed01cb6d73b1,
+        0x92a447e464439ded,
+        0x5f66d5b59a85ea83,
+        0xaa9b0f523639a5ba,
+        0xa8537fa9c7b9ea6c,
+        0x2327cbeaaea5071b,
+        0x52eac6be7ddc5ce6,
+        0x50c9d92bd48e9ff6,
+        0x90452bc5933d25d1,
+        0x6147c776b93a479a,
+        0x4f9580982f647f88,
+        0xca8e97b435ce11a2,
+        0x5f6f2089b60228ee,
+        0x126641c70b337875,
+        0x06d43f91d951c854,
+        0x47e7450d2c0ec358,
+        0xd6e799424c3dfb0c,
+        0xea3a82bfa9f07276,
+        0x27c068b262783f21,
+        0x47d6a6d5c8d963f8,
+        0xdfbcd168d2a9c3c4,
+        0xbf29e8eca23149b1,
+        0x077728bdfcfaaae2,
+        0x5002d79533dfc816,
+        0x1dc38b467b377649,
+        0x65a2d7239564ef0a,
+        0x59c1759f7cdd8b87,
+        0xb8d9529fa11f7ae4,
+        0x36a09497fc0fc7a0,
+        0xe43d32f7a8684288,
+        0x42e9fc4f2ed06293,
+        0x625a9436a2757060,
+        0x7ec6542c6b32e332,
+        0x413cdfea23586c2c,
+        0x6c61689af361714c,
+        0x682737db77cad353,
+        0xb2eb85cff557d44c,
+        0x4bcd52b562fed45b,
+        0x1018839e1b50e3ff,
+        0xd78633d29fe9cc11,
+        0xddb06d4af0c3d689,
+        0x06b1706f9cb08826,
+        0x42bf9d25f376d7d2,
+        0x1d478e244baac838,
+        0xba6a6c24307a47cf,
+        0xeca1462ae1268ea4,
+        0x53e4c6cf8bee851a,
+        0x89bfa7dd8867e136,
+        0xe9bf1021ca5c720b,
+        0xef8952fbb1c85b94,
+        0x2d20652ba995df9c,
+        0xa56f18b1ea8c5fa3,
+        0x92b20e4d62a79d8a,
+        0x85e2a8aa00ca7294,
+        0x0e910b602c8a68e5,
+        0xe60d965df3b43b2a,
+        0x4153a5351958268c,
+        0x82fabfb1569f2461,
+        0x7623151d16ad2727,
+        0xdcd896b42d4efde5,
+        0xc442d1b19075e3a9,
+        0xb899fd232efa6dec,
+        0x33edc867f118d3a5,
+        0x77072315e535a409,
+        0x4636864f202f58e6,
+        0xd109a7bf30d47c22,
+        0x4e3f8b67fe615acd,
+        0x3fc4dfe810a27868,
+        0x860385d80b808d40,
+        0xddfc1072889a635a,
+        0x28b06e8f63ecd1a2,
+        0xea12f9357014e2f8,
+        0x30f1f72fe34a39cd,
+        0x5da23dcdade5ee78,
+        0x0134ebefdcaf7779,
+        0xeaeca7a51e4be726,
+        0x22978de441754416,
+        0x26cf534daebbc424,
+        0x170cd50b4b840e9d,
+        0x56689acbdede7c5c,
+        0xb66027f3995896ee,
+        0xa6fc59c7d282f199,
+        0x0d9cc28e0e666440,
+        0x7a29da3014df187f,
+        0x4aa8860713ca9751,
+        0x05d9ceeea445f330,
+        0x205a77f65c9c8181,
+        0x71de58b0aeab8d90,
+        0xb6a01c9ec8d39ecc,
+        0x85d9e248391f9b10,
+        0x4cf5d1a963d613e2,
+    ],
+    // 0B..15B << 88
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2b461e653679f6b5,
+        0x037b5100eb36649d,
+        0xbffa66139019be13,
+        0x700e3c41b7b9cb8a,
+        0x49d07745f4d15e1f,
+        0x500e252979f8db98,
+        0xb2c1a37b4a9f846e,
+        0x0378dc1d17be6f4d,
+        0xdf95b3a1c43ffab6,
+        0xac27effd8072a5f5,
+        0xb79de07d44412a11,
+        0x0de901d441565340,
+        0x2e368bfd584d8a75,
+        0x354781f2290d47a1,
+        0xfbe060700eea23f2,
+        0x3d66da025b4ee058,
+        0xba22d4de7d273da8,
+        0x51e63da73c40356b,
+        0xa567eca4959af466,
+        0x7354e8cdd1aa3d6d,
+        0x9052de5c92a6cbfe,
+        0x787ff6d8ffd68a1f,
+        0x4d1bbc8d37105320,
+        0x24ff8c295498c804,
+        0x0d8fedc3241077e1,
+        0x1952fec329393cea,
+        0xa3acac6a55a6914b,
+        0x254ff939ed78a44a,
+        0xf5ede74f11593a92,
+        0xf03cb91c2a8e02f8,
+        0xa20f56185de7590b,
+        0x2a013ac8c4d64808,
+        0x2f675943e950d144,
+        0x7cf3eabd8970e316,
+        0xfaef5e8286b6c26e,
+        0x2fe2d8400caf3d05,
+        0x87008384f5fe8e66,
+        0xb5c73650c80822d2,
+        0xce2dd377878b6b64,
+        0x615fd0d1430ce0b8,
+        0x1f386ac0063a3d94,
+        0x428309ee5854fd15,
+        0x7d0d043ca36dc84d,
+        0x310ecfb7d036348f,
+        0x9cf7cf95fd2e01ef,
+        0x3e9d92ee6ff294d5,
+        0xe631dc5b43694634,
+        0x6907a9a9592ac26c,
+        0x43d7805e40a5c613,
+        0x9934159778897c5f,
+        0x93b330e8af544cc4,
+        0x6321273fe606eb53,
+        0x109e5e5b594986ee,
+        0x82c6ccc37bb0fcc2,
+        0x03aeb8390b147b35,
+        0x0129c568616a99f7,
+        0x9c5e641f39e52194,
+        0x8b6f00bf2540dd50,
+        0x9247006bce27af94,
+        0x3191fbd8ec446de5,
+        0x607c8ab65f540f19,
+        0x52116198b0a5534f,
+        0x656c703641960fa8,
+        0x6310efa78f4b117f,
+        0x2b6c2f87e61e3e37,
+        0x48709f04ad4a375b,
+        0xeeaa3ebb25595629,
+        0x044a20e749434abd,
+        0xf405fdafef467214,
+        0x8516bdc5327cd7e3,
+        0x0e726dd1ca88d1b3,
+        0x212b6bc21a0ca634,
+        0x8de279fc9fbceefe,
+        0x00b98049c27ef2e0,
+        0x00b4f57e2f1584c7,
+        0x598c9ff158569a51,
+        0xbf0729e6fd2ca23a,
+        0x55bbf6828ce00332,
+        0xefffd1642172c5e8,
+        0x5108dfbd19fb4207,
+        0x1309a021d2bac25a,
+        0xb8662ae0c854579c,
+        0xd2a42eb83de7a409,
+        0x1025f718ec7a5054,
+        0x5d50a3687e4c64fd,
+        0xd46a17cfad1f8c2a,
+        0x2cfb00cc3b44c2de,
+        0x4eab61405fd98119,
+        0xf5e66d2ac502e265,
+        0x236fe9ec233e029e,
+        0x6c1d9bcd52c5de97,
+        0x55a08167c7c23bcb,
+        0xc7de5ea4fb35cb6d,
+        0xb0f1dff6b04ae119,
+        0x1c8e2120a9989971,
+        0x448dd5add74ad01b,
+        0xca36d6f028b2eff3,
+        0x0b6f48858370c9ed,
+        0xa2b2e5ed82d40dbc,
+        0x1979441a662058d9,
+        0x717bf5e7861ed682,
+        0x6837c61f34be26ee,
+        0x25d3407ea6e86079,
+        0x561d26001f905a93,
+        0x6338960d469ef658,
+        0xa1ed9a1919e2011e,
+        0x830e3dabf3d9e826,
+        0x222b694651ca858f,
+        0x0ce8a33e62482bbc,
+        0x149e27a4ae6bc037,
+        0xc622b3e29bc79de1,
+        0x5e08cf55e628d2ad,
+        0xd13a4cff2b235d33,
+        0xcc9e3a45d08ae738,
+        0xd7df330239694d79,
+        0x44fb40a888d6d448,
+        0x1fabff975a4f81c3,
+        0x4c1a0df1172756eb,
+        0x96f1af75d931c320,
+        0x5858bf2c3c3a2648,
+        0xe7b1f87063439e6a,
+        0x5d79913bdef3e563,
+        0x7574078360f2fa0d,
+        0x6bbdd8b9d4ce61a8,
+        0x6e5966b477154d62,
+        0x01cf939027b9fa5f,
+        0xe95fe6f974ec5b82,
+        0x0d8de3c163363251,
+        0x143c95cce05329e4,
+        0xb14cd011139995d2,
+        0xba6f477ed09300f8,
+        0x16a127adfae37475,
+        0x7013c002d68350c0,
+        0x23c2740c749b0363,
+        0xce9a8341685e6749,
+        0x7a50dbef65db9088,
+        0x6b08fecc78ea23c8,
+        0x1fe1dda5e67b0673,
+        0x12b53612f7ba3374,
+        0x6537dfa5c758e128,
+        0xe5dff757f527a006,
+        0x14c0814810979120,
+        0x027cf51fea9bbb73,
+        0x54b39c8010b6b22f,
+        0xe64507428177de65,
+        0x229125f660c7865c,
+        0xd0d6f00d723b5d9d,
+        0x48c915231b290bfe,
+        0xb9659f82f03d343f,
+        0xb486aa5827b1ad14,
+        0xebbea75ea9ea0130,
+        0x3be8f429fa218d6b,
+        0xabc03ff3967faf22,
+        0x303848b64b7b74ec,
+        0xad72812325b77993,
+        0x04e70f3ce7319109,
+        0x3c9d046ebc965104,
+        0x900aab9044276529,
+        0xae624d5a846f9171,
+        0x1581fa9bab103dc2,
+        0x4905fd3da75a99b0,
+        0x4ff90a5039fa2cc8,
+        0xea45ed7243dcf4b5,
+        0x1a3eeca42fb400b4,
+        0x31aa454941c49afe,
+        0xb62c1e253b50cc9d,
+        0x4b5549cebe5953a0,
+        0x4c1d9c7ec4f41fab,
+        0x9534c244d7670dbd,
+        0x590ae9f07d319d62,
+        0xb0633268886d6d60,
+        0x3eb22a362c152c43,
+        0x8ee8bb25d916336e,
+        0x7cfd7fa03130c1e8,
+        0x756e62ae856ccae5,
+        0x12692db28b1614e0,
+        0x5672c4630bd59c2d,
+        0x3c2ca8e3dfb5ae69,
+        0x748cd4a4cbd81293,
+        0x238e07c3d15be60d,
+    ],
+    // 0B..15B << 92
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x25904d5855c74491,
+        0x7afc224c509c14dc,
+        0x8c7a13cc08b21fac,
+        0x1f04d63dde00bbf3,
+        0x8fff72214bcbb123,
+        0x771d901a069e1a3b,
+        0x4fd0edad9dcd446c,
+        0x278097047fd0682a,
+        0x5b7c40c57bc26a35,
+        0x018d4a4830d80922,
+        0x1bc12061b7ef6a58,
+        0x0bdb39c0ce36b0d6,
+        0xbb73d5062212e9ab,
+        0xf7d396863de8eb86,
+        0x81280125c5968163,
+        0x7dfa82d51aa3baac,
+        0xc560d1824d1400cc,
+        0x6bcc48c0819c0bd1,
+        0xd8a4fb2b923ea2c1,
+        0x3b1008831d81fdaa,
+        0xf8914725907ffac4,
+        0x096191c789cd74bc,
+        0x9f1264b7f11670ef,
+        0x0837238c9985ad52,
+        0xeac51bce197bef62,
+        0xe1b9b48021bb0c55,
+        0x8b59f91db1718bd9,
+        0x3b7aa2eeea2415eb,
+        0x00408a740e2361ba,
+        0xd74e1dd07e2d75d6,
+        0xf7c1f9a6c7bc0354,
+        0x4f470a22d23ef25a,
+        0x2495f32a6f2155b6,
+        0x5975bde747bd0392,
+        0xc9ff592566be5046,
+        0x03e6cc6f8f5ebd90,
+        0x02f41777adfedbb0,
+        0xae0de97e8618b322,
+        0xcd86adaf304b37e0,
+        0x79be685530090c9b,
+        0x27172c6645db6f61,
+        0x9ff47758eaeaa728,
+        0xa2fb595df1c832a2,
+        0x0abe79579acbd406,
+        0xe2c0ffacfb9643c2,
+        0x73a7e31743805ce3,
+        0xb26073d8623d8653,
+        0x445425a436708661,
+        0xc69c3b86e5bb5655,
+        0xec420adbd4cabf9c,
+        0xdd92ca032302d73b,
+        0x6a3068678310e551,
+        0x9f18a214d7b2a4a7,
+        0xb494292e36e60d63,
+        0x0067a25278c3537c,
+        0x6a00ab0e3a181b10,
+        0x975493869d93ae4f,
+        0x22c71ef1a3f5b982,
+        0x00c4420f24c1681e,
+        0x0ab8d94ca68e15da,
+        0xd99706622be6d3c5,
+        0xb987237a9350c95f,
+        0x0e01b236a3951941,
+        0x33b106b2f4b33985,
+        0x9697a63b44ed31f0,
+        0xca0c58e7d1c2aac0,
+        0x1713e9662d28b1ae,
+        0x2ffafe6c04f31bcf,
+        0x4cd46f3b13cb5fdd,
+        0x8da8b5f1f4c65c42,
+        0x350fe07819411c43,
+        0x1debb63e2bc5f345,
+        0x9a555431c5fdf836,
+        0xdf9335929b029b1e,
+        0x77d416a0e30d2b40,
+        0x08d119e0ab181e8f,
+        0xb22312478a865953,
+        0x3964001fd45e166b,
+        0x27b762d5f352ed9f,
+        0x671caa9bae9e875e,
+        0x265852c7c96e5b29,
+        0x322d521036c4eea0,
+        0x559693b0d56f4ecf,
+        0x35784e68162d4921,
+        0x6a9044f6d2fc1450,
+        0x4f68440e968f318a,
+        0x83774a54a94ccf94,
+        0x5c55def25f57ae13,
+        0x08f2605332eecece,
+        0xf28bd6275cbee5f1,
+        0x16014d8d914026ef,
+        0x1dfd2ac6a4ed3d3a,
+        0x03e18b702ff44a22,
+        0xde693adc5edfaa6e,
+        0x5f0b6c3339e8be7b,
+        0x6313a4532e5dd9fe,
+        0x7b1007bb3db06c1a,
+        0x14e4fd196d862e8e,
+        0x768f21c6efc48731,
+        0x21b9d3fe391f9d5f,
+        0xa7802a68355a3976,
+        0xe09355633f74cf81,
+        0xd2fe4ec6878108d0,
+        0x2209226325fe37ac,
+        0x293f057e7c63003e,
+        0x87be93b0c88c74f3,
+        0x56c1b2f684e11e84,
+        0x72f8a05e40523446,
+        0x56b4f5b128b04503,
+        0xd9c3039fa96b50e7,
+        0x9246e9f9b33a1a57,
+        0x45e29812b9bb3428,
+        0x47d8e005ffb958ab,
+        0x5b49d05fc321d0d4,
+        0xdad1737d316e5102,
+        0x1388bbfbc985f3ec,
+        0xb1fce0af93058d4c,
+        0xf5201b15e2b8d1b7,
+        0xfcbb5d5f358a2b79,
+        0x552457d20cbe7bce,
+        0xfc6ca0ffa08d9b69,
+        0x5a46321305f76f65,
+        0x41000cdc31f19b75,
+        0x7f3a4d2feb92cfe8,
+        0xfa82981fae275238,
+        0xe5b89102eea4b694,
+        0x6d68a3ccc5377319,
+        0x77f12aa1d562ad02,
+        0xf4e74b04f188ebdf,
+        0x9ff744726b3a2b7e,
+        0x7ea7c203e7637ba6,
+        0x160fd942ff611a44,
+        0x9c1541b13a22d98c,
+        0x7e4e255a4155f245,
+        0xd296f70686654a7f,
+        0x75895632cd556f93,
+        0x450a27c7086eae48,
+        0x717e586b0dda1373,
+        0xb9b1751c4773d3ee,
+        0x5f899d88c60bd82f,
+        0xefd5bf07689fb3fa,
+        0x85f591ea3f19b15e,
+        0xa9b4290fe0e010ed,
+        0x555644970b04cce0,
+        0xf1e542e51e819392,
+        0xe94cbc701b8c4da9,
+        0xb69c797fa12f452d,
+        0x3d5b177c30b7d7e1,
+        0x01bb30a5354903f4,
+        0x26c0eecdf190df78,
+        0x45ea1e9992a6b66d,
+        0x66bff9d07a6cc99c,
+        0xcae335a8a590ae6c,
+        0xa1207334080e7093,
+        0x465ea07ecb7883f9,
+        0x3bfdf8fd75350f51,
+        0xcd8ab2f319d92ed3,
+        0x0a71908cfe89a142,
+        0xe554848622024a86,
+        0x1c58247f8f32137d,
+        0xb02b49a582d7099a,
+        0xe65aa893a90a80b9,
+        0x68e66095236e0cec,
+        0x6ca1044bdab282bc,
+        0x096a5148f0843f01,
+        0xa22aaf300d7cd2ef,
+        0xca666b163d24fa12,
+        0x525aea536128390a,
+        0x6f47d5e6f390a6b9,
+        0x7490abaaeac60d80,
+        0xb33ee4cb349ec0cb,
+        0x1bfb52e8613eb91c,
+        0xf5c5d30e61b9470f,
+        0xf0b75b6521f2f986,
+        0xa2f983d29e4dbee0,
+        0x70eacae2ebaf6c81,
+        0x3424b9b0e91f66bf,
+        0x99fe554b80c07351,
+        0x1f9c5678d13d305b,
+        0x093e4c27c58dbc57,
+    ],
+    // 0B..15B << 96
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xb49ec92acce8387e,
+        0x9d1da13c856bf401,
+        0x186efa7f3589e658,
+        0x21fc2124d564089b,
+        0x269333552ef13b1b,
+        0xfcb08265cdbc3028,
+        0x39ab04398314f74b,
+        0x4d40437a3b578bd2,
+        0xf8193a5467823906,
+        0x69fac26acf0fe0da,
+        0x120b9beb47abae07,
+        0x495c790e78fa2e44,
+        0x40ab5d1093990fa6,
+        0x3741f11bb3483147,
+        0x955be680ba5e8fd3,
+        0x2bc546cea992dbfc,
+        0x0ea53976ceb8247e,
+        0x1bf5dcaf4b3a48dd,
+        0xf8357d7481e7a1ae,
+        0x79a4d408f391812a,
+        0x94969e90be8d1c6a,
+        0x65c890b21aa292ea,
+        0xa63aa60aeb82ba4e,
+        0x56b6f2561bc1820e,
+        0x3f72bd2ed4ff9d9d,
+        0xfa24fbe414afd09a,
+        0x559a83c5d34afd46,
+        0x15995aafc0b04c0b,
+        0xd698b998e9b19ea4,
+        0xc1668b51955d0756,
+        0x96d056cbc2025424,
+        0x138f0175ec6ef510,
+        0xd7c94f359a22379b,
+        0xda0a75a004493980,
+        0x47cedaec67dd55db,
+        0x6027ff4708b9e197,
+        0xd47f6224bd56dc7f,
+        0xea4bfd389145ff78,
+        0x7f15d3a601f1c7a8,
+        0x0b4351f2b880f68a,
+        0x0a5797d665e5b57a,
+        0xa5cd8fae6f7f1788,
+        0x761704305b68aef3,
+        0x53710041833ad5a7,
+        0x5b198ed5f9969a16,
+        0x4a60dfac919ccc4c,
+        0x4f3191504f5df089,
+        0x0c7a86befeecbd4a,
+        0xc20f22b21e20e0d8,
+        0x1dc4cba401c64a45,
+        0x60f080eda9d02d60,
+        0x66d7bf95e1f964d8,
+        0x0b8677ca4bd412cc,
+        0xd792f2aab823f570,
+        0x268ef59a4930c3a9,
+        0x7a4cbcc787d9fee6,
+        0xcff2bc280f1b12bd,
+        0xda526e67a029ff4a,
+        0x2f4c4de21b560678,
+        0x62c1b16fe1d5ba7d,
+        0x748dcb99211da336,
+        0xd90540f9cb42168a,
+        0x1b293b67b5ea8228,
+        0x0f1e804922cd94c8,
+        0xbac700729c085b27,
+        0x4971f2ab5769a484,
+        0xf2f53235426b1d64,
+        0x2314d75de857f8d7,
+        0x597292fe77c3878c,
+        0x1fd0ca2900e9d5d3,
+        0x605a3736321bc451,
+        0x4672533265844017,
+        0x20d90f912ef47502,
+        0xbeabbfb98df97586,
+        0x855504f2b51cab41,
+        0x7fa9565d5e4d4928,
+        0xedd00d5aac84137f,
+        0x781f74ea6ea09461,
+        0x40f75b8e00ec4426,
+        0x0b8b7fb135754baf,
+        0x9c53e1c43f93a3ab,
+        0x33f15b6604e7a80a,
+        0x33bd9f285ea12b70,
+        0x02c5529327fa44c4,
+        0xeef86cedd0e92fcc,
+        0x6c86d8d87c6cbc7c,
+        0x6c4d06d4ca34afae,
+        0x6f175e0002e559c7,
+        0xdb03a145913a23da,
+        0x22145c295967927b,
+        0x065eee0a441c1caa,
+        0x1e6260711af02391,
+        0x2e92439f1ec1fd37,
+        0xb0c8cde0780b5500,
+        0x7d2c6467797b872d,
+        0x2dfcb22fbe4a4d45,
+        0x1d98f996c9b3cf8e,
+        0xb5945718a497db6e,
+        0x016854708b873f9c,
+        0x5c2a36e23f8c1607,
+        0x4d4aa55ef0134dee,
+        0x54af510811655c0c,
+        0x4eb14d1ef1e0e394,
+        0x7fd174ffc59b1125,
+        0x2edd6a3500be9a22,
+        0xc43e6fab4257f34e,
+        0x778a4cf0b530835c,
+        0x4775236edeaf995d,
+        0x36eabd741574801a,
+        0xe5949dd03fe99d2b,
+        0xce0130835b61fb6b,
+        0x27ed9c5c0e35e121,
+        0xab0035540adf195c,
+        0x76e99d75a4ff2d5f,
+        0x2bbcfd27d3bc6107,
+        0x765c9c5ab98e8e20,
+        0xfe48938e0da1bea0,
+        0xc54f69646b0b0b94,
+        0x75ecffb74eaaddcd,
+        0x20790465cee37188,
+        0x7a0a9b243f317436,
+        0x1486a545a0122dff,
+        0x8a7b29a379d6d366,
+        0x27547c1a6ecd7085,
+        0xe02bbbcf9c2617c7,
+        0x53c5aaeac2292af2,
+        0x7d683555dc11ea2b,
+        0x673aea23e8e09e59,
+        0x1fa4b1460c631545,
+        0xa42cdf0a93db265c,
+        0x6828c8d4dc1fb412,
+        0x5bc7c087ec108fa5,
+        0x2ee1fed55a9af4db,
+        0x2e7e3a48bd56e0c1,
+        0x48aa7da21691cbcf,
+        0x46abe3106eb6f2b6,
+        0xc10a9393dc89f9e6,
+        0xe07547bf4c4cb74a,
+        0x89ae95a3a28e06ae,
+        0x3a1d8540c7a83d4a,
+        0xcbe552f8a1946142,
+        0x96658e8781fae03b,
+        0xca3c124186c59985,
+        0x202c88750fab6da3,
+        0xaee0a3a1710306e9,
+        0xa49e4e8eff8786b2,
+        0x86003309b15d8577,
+        0x6143b2e5ef6d7775,
+        0x524549265dc10886,
+        0x2c281c92f249b631,
+        0x624641e256050cf9,
+        0x3fa9d746b2011510,
+        0x79f03c27b5c14751,
+        0x52481754a8a0f507,
+        0x14dc99024750a903,
+        0x54c1bf9feab256c5,
+        0x8b37d2979b1c4ab2,
+        0x98f6508cf84bf43d,
+        0xb345e8ac3c12de62,
+        0x131ad39cd50368f3,
+        0x6038ec824fcb1000,
+        0x7f427a514d031f3d,
+        0x56248fc86704ffa0,
+        0x71181f272e826e35,
+        0xb956b57a668ea0c2,
+        0x806fa5841f01b273,
+        0x857015c00a62b2bc,
+        0x632b8fee50a4ab59,
+        0x1abdacb47d136e9a,
+        0x3fdd1ac452a325e3,
+        0xfcca2e14186e2908,
+        0x2f76189d5ef81fb4,
+        0x7d5c75e398806128,
+        0x9e40105448502bfb,
+        0xafec913c3e43a27e,
+        0x59493417bbcdec97,
+        0x652c3c85e5d5974a,
+        0x1e487d169f7fdeb4,
+        0x1396b1d142aeb786,
+        0x60d913a1b9122b6d,
+    ],
+    // 0B..15B << 100
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x47c60df97ab892fb,
+        0x358cd87543675570,
+        0x87be23a11f4906f7,
+        0x13b1a5664d740bd1,
+        0x4187c6f59335dbad,
+        0xe525745c28f60348,
+        0x8e9b66b4cb3c7245,
+        0x2d2d2aa90dd88116,
+        0x3dc655f2155c17a9,
+        0xaebdc196e461d284,
+        0x68d554a54d34022c,
+        0x6bc2c921ca6cff5a,
+        0xb98ab380daba075d,
+        0xf5a4e08883428def,
+        0xfb7a3b64036d36e2,
+        0x6709a8776d6d1bd1,
+        0xc61b1878ed9666ba,
+        0x2e486be4420ce551,
+        0x97fbaf939fe18e07,
+        0x5ce0be142ae89f17,
+        0x33132f04aca337fa,
+        0x87c1bc3b916b9715,
+        0x8c71508e59660ae3,
+        0x2705e5f681bd2b46,
+        0xfd2d38282b39fd3b,
+        0x057a6770e2ea0cbc,
+        0x5c58c054d01ed647,
+        0x5be607a9f114aa7e,
+        0xa14f6c5f441e0baf,
+        0x0b7f7638a68dffce,
+        0xf0511c1a50ea1de1,
+        0x477875d11aabaf4b,
+        0x5b7f8374a4fd118c,
+        0x9134f18e2f75a1e3,
+        0x4d16a9ec3d7f99ef,
+        0x40f225cc9cadcd6e,
+        0xae58081d8c07a689,
+        0x6ef69c2fa0b6f7f3,
+        0xf92755d1c425ff71,
+        0x2e2173ca4ec4ec18,
+        0x9d2c427b227ecf8e,
+        0xd89b2750cee4ed75,
+        0xba66afea3da75ae1,
+        0x0cb52eb58275938b,
+        0x6a058edf8acc3de4,
+        0x80b12086b24c0329,
+        0x97cec3d86912d9b7,
+        0x12c4499e47a9ba59,
+        0x501446b583c3cf3a,
+        0xe91350dc2df29052,
+        0x498857ffb5767c5c,
+        0x142fa07ba7d510d3,
+        0x38d8edd8a305a99f,
+        0x3bd423a06c6c5d94,
+        0x4941aecdfe94af23,
+        0x29bdce3c2ef5fe3d,
+        0x32bf982fe3fca48d,
+        0x3661f992f8ac6127,
+        0x7ed1c6e890aecb24,
+        0x71becbe63dff85f4,
+        0x2f0e032e84b2684c,
+        0xe26516d9019ed8b0,
+        0x0be6e4747bdae399,
+        0x3306309e91c312da,
+        0x9d77af1bcea14dad,
+        0x5a62207afc44d42f,
+        0xd6efe6e8db47252c,
+        0x60ddd47362d6bcb2,
+        0xd08ca4890e47690e,
+        0xda1a4d40c510604d,
+        0xe271292bce802a31,
+        0x41815bebb8b6385b,
+        0x032fc92b8d9e73e3,
+        0xc06450a083844157,
+        0xd94d506a83dbdfc0,
+        0x0df80ac4186e1b3e,
+        0xd9f13a90736c3df2,
+        0x66b0f36a1c41acea,
+        0xca3f73b090765681,
+        0x73e3349b0b5e682b,
+        0x159a4947ce06d557,
+        0x9a6915dca26094ad,
+        0x2138633df017f303,
+        0x01fe947f7a6d16cf,
+        0x462524527239536b,
+        0x3ff6e0cfd68add7c,
+        0x4c6330d7abf8167b,
+        0x432d7302c81558ea,
+        0x7d64efb7ca83fff9,
+        0x5af88f8ed2ab457c,
+        0x6ac34f9429cf19de,
+        0x21e7add25e239655,
+        0x107838b56743885f,
+        0x3c483be11abf4581,
+        0x6640ce817b021a9e,
+        0x01a8844cde037367,
+        0xb2a5c7addd5ff356,
+        0xee17714b0dfbd88d,
+        0xbc4c42357f7b9a29,
+        0x75849f86cf19e296,
+        0x499cf3e5d1cc3be4,
+        0x336c0e91bbcc85bc,
+        0xf97e0207e42985bb,
+        0x2df7d1c7d3f8c01d,
+        0xda8761447c96d3d2,
+        0x01264dd170369e5d,
+        0xfb23be520b7d772b,
+        0x44af6880f045a282,
+        0x2a6590fdf96964a0,
+        0xf41bf9123433efa7,
+        0x8aa23320ca4a10a1,
+        0x5c8cfc71096f947b,
+        0xa2fd2b804681d0ea,
+        0x1d52ff66b1dee19c,
+        0x8ada4a13f7018407,
+        0x6d46e0f1eb614358,
+        0x366b30fce8e69c0a,
+        0x4505b1073e51a97a,
+        0x7dc884ce630314ac,
+        0x337bea34c6416583,
+        0x12042f986eb6a038,
+        0x74df0997b2375bb1,
+        0xd0fc90adddc83659,
+        0x1c31ca8463558783,
+        0xf0c3ee7e88743658,
+        0x7e2301ea23734c16,
+        0x2377268a29cc24b5,
+        0x3261fe5464fe1c4a,
+        0xad8e937e41052b4c,
+        0xced55c868d1ce9f5,
+        0x65af6a830acdd564,
+        0x2c8af1e669907696,
+        0xa07164dfacd58703,
+        0x3d2019824df50d18,
+        0x31134076fdec7a39,
+        0x58ab14e79c497d52,
+        0xffb344cb16e6f0e1,
+        0xc8264455dc9d58fe,
+        0x516029a46cc08063,
+        0x34d9590aac659e8e,
+        0xe7f16dce3c822b8f,
+        0x4233cc8ffbc335b2,
+        0x189978d0e622f218,
+        0x21d888044acd4209,
+        0xfb609c7dc830f220,
+        0x71655be544bf0525,
+        0x47906d9c053e616d,
+        0x66d28bc64a5dfbb4,
+        0x73dfa3b9ab11e6b6,
+        0x9c3e444bc7a2e66d,
+        0xa56338baed20455b,
+        0x0ff593b10686e725,
+        0x060d66cb1bb82dd4,
+        0x393f4d758cfde397,
+        0xdb40e0bcefbedf90,
+        0x78d96ec15177bb8e,
+        0x739740a584781a48,
+        0x67a912fce0debfca,
+        0x6f4f418823c957c0,
+        0x45e007ee73540537,
+        0x42dc9c96b9333064,
+        0x0020390a9a9319e9,
+        0x6b67eed3215f76bc,
+        0x7bfc7ca49f45d24f,
+        0x1bd4295493adb6dd,
+        0xfb70e3cb7a37e94d,
+        0x34629c4510e70232,
+        0x6446816d46f91837,
+        0x97775f7886eec667,
+        0x40a7387b4ec950c9,
+        0xf3ad996413cdc0a5,
+        0x1e62f7743dcd082b,
+        0x39ab85840aa4808a,
+        0x9be9f914f8b77d65,
+        0x3dd4bd71c76c36ea,
+        0x3e79b3fbc2abbfff,
+        0x925b2af42ef6d7ae,
+        0xdae848c471ef5394,
+        0xf64111d13aa232e0,
+        0x45afe169cfc08093,
+    ],
+    // 0B..15B << 104
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x66301fd8c55c1fff,
+        0xad3be44cfbbd49b3,
+        0xa03c2fa741fbe254,
+        0x187ba8613b6f17e8,
+        0x6ea68d6039edee6e,
+        0xa7ea4745bbf6535f,
+        0x72690b2d8bf0bb5a,
+        0x21b83d1b6dea0271,
+        0x5d537f44e89f7f37,
+        0x00a3623fb3eba567,
+        0x4bb250e31fd2cffd,
+        0x7ccf7928e99f674d,
+        0xdb6fd296c4557bea,
+        0xc003eaac4e7d9ca9,
+        0x6c4066eeb4030061,
+        0x6878cbb484f342c5,
+        0xc9ec12796f6d1e7e,
+        0x8dcfde0e012ca666,
+        0x426b591863b595b6,
+        0x5b0ce5065f48d0d3,
+        0x8c8d8cc374ea6f95,
+        0xf7ed45c01d6d9c26,
+        0xa52971327d9f7964,
+        0x3cbe8f0aba9e7577,
+        0x319effc4b272d4b1,
+        0xf2b796f060d4500b,
+        0x217649bcd8f558df,
+        0x68e95b4aa79c2dae,
+        0x21f8626f1ad51d4a,
+        0xb94f4039f60b3d35,
+        0x95871318c94c32de,
+        0x3aeb0ce4eb45a599,
+        0x0143ededbf6bf967,
+        0x258b05dadc702df2,
+        0xa12be0cdadc9900d,
+        0x6f2b8160d35289be,
+        0x38cd51c9e2c0f4b5,
+        0x16ff3b14ede6b9e9,
+        0x928e838d720a611f,
+        0x7c2bc2abd21955e9,
+        0xd4a9d87176b383b0,
+        0x6aeeba27adcf686f,
+        0xdad812fb22f99129,
+        0x48d1ddeaac45050d,
+        0x50363d82753b691e,
+        0x73e73741a29f8244,
+        0xf228b8f79932d84b,
+        0x6dace228fa39b7e2,
+        0xe28c24014bb9c81f,
+        0x996abea8dd83135c,
+        0x33c6c37dbc607c7a,
+        0x24a64048ca32dc92,
+        0x526afed7a08ebeda,
+        0xffa9d928e4806b33,
+        0x64d047fcdb0ddcbd,
+        0x22e538b0fdab18e4,
+        0x1f41016618806ad4,
+        0x675559930a108061,
+        0x27c2183180c68d08,
+        0x5add03e7eb2f0261,
+        0xdd3b7a2f666d6a45,
+        0x7daf5117bec57ccf,
+        0xb1c273b21f473093,
+        0x76e5c2f7941fa090,
+        0xfd45ce7861ffb874,
+        0xdfa2f2e8df789147,
+        0xfeaf73cdafd4b37b,
+        0x175a7760e05a5513,
+        0x2a9074c0c8fba0a9,
+        0xff583646dc06a1ae,
+        0x6f0abf17617b4867,
+        0x5a7da53807e8e669,
+        0x833de0d00b40cfb3,
+        0xdc4c0bba30a341c3,
+        0xab4d23f74508358b,
+        0x789dc891543e54c0,
+        0x0b6ee2a5501d443b,
+        0x831abb9b1fa6c73e,
+        0x553a6af52fe03c3c,
+        0x51b6e517224630e0,
+        0x982697ed3dc1302e,
+        0xe8fcf610f8def534,
+        0x076480ffda2a3e04,
+        0x3e3ba0fa9ce141f4,
+        0x1e8cac72ed1a2864,
+        0x1fd444cbf7a68188,
+        0x8c9fc69e61d2bda8,
+        0x359896dfd7de31af,
+        0x8b1531cb7e76999a,
+        0xd844c5a2345bf466,
+        0x32a46149000e7fe6,
+        0x6fbc92ad97254059,
+        0x4faf9601999e7282,
+        0x5f3b6697a753781e,
+        0xf4f91299c454cda8,
+        0x16a68806c4a45924,
+        0x8e537b2f4df3f629,
+        0xbabc2aebfab524a5,
+        0x07bec62775b874ff,
+        0x32f84cfbc56ed99e,
+        0xb51ce39120313a29,
+        0xd435c1f02cf84685,
+        0xc1ad6c7412155a49,
+        0x4ed276317900de19,
+        0x0ea50c11217230a4,
+        0x6c9f41f6a1910843,
+        0x1d1f3999956b7522,
+        0x3bddcf1d432fcd9d,
+        0xa8176c4e826b4b32,
+        0x709c7288b259b7ae,
+        0x34278b5781abcf0c,
+        0x7b255e3e119c1ecc,
+        0x482bf86b3beef1dc,
+        0x5f435388cea268f7,
+        0xb42b75ef8f42f34e,
+        0x774e68e94eecc84f,
+        0x3e41beeab74f6b7e,
+        0xcfd082dfbb02cb0f,
+        0x2605a63c925858a5,
+        0x451b66b99b81d701,
+        0x542380b5f589d05a,
+        0xdb9eb3b3bfa015ec,
+        0x8094500ce1366ef2,
+        0x412db7173663b18a,
+        0xf0823baec38082b5,
+        0xafcff97a0e7dee1d,
+        0x6546abd5d283c81c,
+        0x2d75bc2871361e09,
+        0xb958ceaaca2e08cc,
+        0x8f94f7cb62e0c01f,
+        0x13a628b31d764a23,
+        0x51e6a1e2ed18c702,
+        0x1f09857b4bc9520f,
+        0x7a40435d2831be51,
+        0x977f7c100c746d7f,
+        0x065aac850aef5a08,
+        0x421ccecff85a36ed,
+        0x9288d7f7348d1e00,
+        0x622d17cb16ae700b,
+        0x0300e6f976d62f84,
+        0x6f18009a04e1221f,
+        0xe6c91002cf9a38fb,
+        0x727bb4db19844d71,
+        0x2c25e46f5c7caf86,
+        0x825fc28ef99a8661,
+        0xcd899cc50e10ca66,
+        0xf898210e1cdbaece,
+        0x179905e65dc19d4c,
+        0x8f164c48dbc84811,
+        0xf8098cc20bb35723,
+        0x11a7bd064a3e9bb7,
+        0x50a5a516e25f19e7,
+        0x64c4b9d4a5cd18ab,
+        0xa611bebfbfe2ea42,
+        0x19f756b275f2d4f9,
+        0x15fd2183f68245d5,
+        0x65a8c8d5ccbb2669,
+        0x3c5d7eb100ffb5b2,
+        0x4a7950f3c95d1b8e,
+        0x43690609939da631,
+        0x05fed02c69ec8cc4,
+        0xf0e51a322066ae1e,
+        0x1f6d58345ac2ffaa,
+        0x6f0756ec3314718a,
+        0x05dbe1b164963740,
+        0x905a398dd31384ae,
+        0xe1fe70456f8a130e,
+        0x0ca8ee26630c3532,
+        0x4e9d1a9d40185734,
+        0xb05710a5115333bc,
+        0x56e11d0f845a855a,
+        0x571624930cb41654,
+        0x4580796e5ead3021,
+        0xe6bf3e957b156207,
+        0xef63a595603a92b8,
+        0x6cc5a5531d5ceec5,
+        0x4777238bd7098930,
+        0x75021cdba673bc28,
+        0x9b52ba84cb530830,
+        0x495a5fcc7f44fdc4,
+    ],
+    // 0B..15B << 108
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x995bae83176f4f24,
+        0xcde965d33b67bb2a,
+        0x18be717995ca645f,
+        0x5906b4ff0bfc3575,
+        0x3a7a455df774a4e3,
+        0xc821e039702b9d4b,
+        0x18c35512c354579a,
+        0x09f64aedd2028dfb,
+        0xa445d3d748e44abf,
+        0x235edc36ff7367e5,
+        0xdbb855f173e7f030,
+        0x30f1ab620c21e347,
+        0x5a3d46a63f66295c,
+        0x11134725e49adb6e,
+        0xf67d9bb6230bc5cf,
+        0x07d0fdf6c5596418,
+        0x395d2cf189f972a9,
+        0x107092efcc973d4f,
+        0x674a118f13047053,
+        0x757bc968c6f7fb2e,
+        0xf019fbe25f6934b9,
+        0xf7b3cff7d41d93db,
+        0x489140480dcda68e,
+        0x51be51f2d72b1005,
+        0xfea993ed52a852f5,
+        0xc1b32329237094dc,
+        0x8d17ee67ffe55a7f,
+        0x4e43c4609df89f07,
+        0xaa4877d0a05e715c,
+        0x650286f328986d86,
+        0xf5e37fe0a4984360,
+        0x544c06ed81b593d0,
+        0x02d5fc6971269820,
+        0xca030cd60cf592e4,
+        0x04651ca28917f9a1,
+        0x495cce1bcb3e76ab,
+        0xe3eb6c67cb337695,
+        0xa74745e0b311a695,
+        0x83c46644b6a35aa6,
+        0x4b7da2cafda135d2,
+        0x61e7fb961daa55c2,
+        0x201aad92c0bb6853,
+        0xfd5cbc45736f25b5,
+        0x18f3f1961820f13c,
+        0x8bdaed98ddea80cd,
+        0x441896ea1c9f87c2,
+        0x6a9d72a4bd0c57d5,
+        0x4e742f7c057e8127,
+        0x9d8b771091a1300c,
+        0x10673ffee340768d,
+        0x8f2939691fc9e927,
+        0x12a368c2ced91aa1,
+        0x199fc862d8a8c402,
+        0xeb0a2210bcf4e0e7,
+        0x8ac1536deb694d60,
+        0x07de096a93696aba,
+        0xe7c6fe117144b43f,
+        0xf093c1552b8abe6d,
+        0xb51617a09490c928,
+        0x3ba6285ca196a43f,
+        0x1b930464c08d97a7,
+        0x1e66df304732644e,
+        0xb370f1f51210bfed,
+        0x0984a021358d65d5,
+        0x76af5e2a13c22b55,
+        0xe7829d2e817d9c63,
+        0x525711c953d35b29,
+        0x0781a8c7c0532e9a,
+        0x5ec981191d766d8b,
+        0x06133ff57bb4080e,
+        0x165256840178b968,
+        0x26fe55400c41a385,
+        0x6bdc67a697fb2c4b,
+        0x74562baecab9b6f6,
+        0x05fbc08be4b2d6bb,
+        0x346ae276aefdf95d,
+        0x6e1025d04734162f,
+        0x1c5b4fd02df8a98e,
+        0x29a34fe44b622b0b,
+        0x6bfd1153bada195c,
+        0xb63ff7ba39983c3c,
+        0x55708c2409bcf1ef,
+        0x276164d38a9810ba,
+        0x01a04e67181c36dd,
+        0xb412999ab08fe4ab,
+        0xac4398785f009b2f,
+        0x2e71a44c091be2a5,
+        0x1f2a0947f1c8fcc9,
+        0xbbba9decb5027b15,
+        0xb91c87dfc89f5658,
+        0x614f00e6c52f0fac,
+        0x5516a71e2ab9af5d,
+        0x4ae816782c2f1343,
+        0x7ce614221e6b0358,
+        0xa2725fbaa46a3654,
+        0x7da2b3c4f0f7685f,
+        0x32130a0af0b4a2dd,
+        0xac939125b08c6886,
+        0x9b636d75147e74a1,
+        0x3d5b4b81dd16e1dd,
+        0xee3e912a798861af,
+        0x76b45420c28491f9,
+        0x0df43003726cc609,
+        0x7cb21ac94abfc078,
+        0xe5ea8af0bf52ad9c,
+        0x90a4646cb01794bb,
+        0x52fd6ded8d860199,
+        0x4ccfb38f3a04218b,
+        0xe53a517520e489be,
+        0xe6651977354205db,
+        0x0e85383ae265b077,
+        0x5f46725e37876437,
+        0xce201a1cfa6384b2,
+        0x5389ab85e46a8074,
+        0xe0755fd1774c4a91,
+        0x6e60908db3f7ffcc,
+        0x32eebab519df4fb8,
+        0x574359b654a409fd,
+        0xa9d99cd25df90874,
+        0x3bfc9aebbe2d27e1,
+        0x77fa7338f5114bf5,
+        0xa38d8b403af1fd85,
+        0x729f22247fb112bc,
+        0x0a0d0b946e163d06,
+        0x480cf466db503fb8,
+        0x52ed6a8c7ae006b1,
+        0xeb92b09ffacd41fc,
+        0x60a93e1c5ed78112,
+        0x98e0ba561219a66e,
+        0xdc8415c3509641dd,
+        0x939b6ad4a43b6805,
+        0x79e307bf91a75655,
+        0x4c22e476b3b817b1,
+        0x9ad88da8fd947f3b,
+        0xb3ea964d297bf979,
+        0x17523c5d750d2201,
+        0xa454dc903677f61a,
+        0x5be5d61ab504d3f5,
+        0x9f36b700eb3ed63c,
+        0x44bd1eced4880ec4,
+        0x18fb48521db89a17,
+        0xe706e6f212913065,
+        0x176f7c7eadcd09f4,
+        0x1058f48b0c88d0f3,
+        0x215f920bad0f3540,
+        0xad4a91a65a3a17ea,
+        0x46131f8939f4a5d1,
+        0x7a5f2283ff51a4a7,
+        0x1f9bb6c2b8b8b5b4,
+        0x714b98369a2a4970,
+        0xbdc23f0ed0d26b41,
+        0x1dff5d67cc35d7b2,
+        0xff1acc05809888bf,
+        0xc77d3a5e3968d9c4,
+        0xa54c88c085100997,
+        0x677295fc7b03260e,
+        0xefc651354fd90463,
+        0x85a41827a882e24d,
+        0xb4178e36bec0064b,
+        0x71699c0540a9f344,
+        0xab09682d9b6f85c0,
+        0x0dee2743ff262408,
+        0x4f951faa9fb26b9a,
+        0x32462ee03762ccf2,
+        0x675e387e6971063a,
+        0x34dbdef41ca88352,
+        0xcc966fa38afece7f,
+        0x2dfbc615527bf9ee,
+        0xeccd342cba7458e0,
+        0x2b5ace23f6421781,
+        0xe8e64d8f6bf64295,
+        0x2695ef75af86cd80,
+        0x3c85cb0603c9ebd5,
+        0xaea40eb9b587ab0d,
+        0xb18d524fff4be5e7,
+        0x15c14ad78bf355b1,
+        0x8530928a713a97ab,
+        0x49f05648781c1fb5,
+        0x4cd17f14ac61f6bd,
+        0x4d7061db85df4442,
+    ],
+    // 0B..15B << 112
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xe2d929883aea9832,
+        0x766b59b2b6f7ecb3,
+        0x9dadefd2c34bd4ee,
+        0x29c37fc2bb347907,
+        0x6e6e678d598fb4b6,
+        0xacc28cf41d29fef2,
+        0xdef1c1bbfd9a9864,
+        0x348d1fca9448b983,
+        0x5e10111642e570fc,
+        0xd0607b75a1ede8d6,
+        0xe67aabd02a0859c1,
+        0x6b3e1d72f8aeee45,
+        0x23e4ea39d6fdd55d,
+        0x9c843b596596d73f,
+        0x4ed05768f8e3f90c,
+        0x4bc040e54310d279,
+        0x29c5955593e93f4b,
+        0x9b2a6c8e2082ded8,
+        0x38b5b1f2b7500cfa,
+        0x248e54eb5c4cd0e0,
+        0xb300cb1542fc32c7,
+        0x72592b781d04db3d,
+        0x06f0ef5b4a3baf57,
+        0x0ec54586ddf948a9,
+        0xbced229fbd55661a,
+        0xd2495b054f6bff09,
+        0x0a06c2c24875ddfb,
+        0x5c362fa6f439f382,
+        0x592416e0a30730b4,
+        0x83068b8847c45c11,
+        0x8d8cfe4b18e38cb0,
+        0x2c3120c852739a90,
+        0x1013701fa1042162,
+        0x5660aba52c471aa2,
+        0x640f88d33f82451b,
+        0x39ce34dc3891c201,
+        0x7f2cfd94bc3a7ea7,
+        0x06df8f61da54f7be,
+        0xe454bdacfd792301,
+        0x37d09c1706964735,
+        0x93ded97580b12748,
+        0x0b69af998fe54472,
+        0xb580478f70982a6f,
+        0x04e87595eb79bbaf,
+        0x06aaa964ea28ff40,
+        0x22990f19069ed4bc,
+        0x4305337199d021b2,
+        0x0e27bfa7a6b9fbee,
+        0x750c81a0023048c4,
+        0xf26f5cb0d930adf1,
+        0xe1abccebf86d9801,
+        0x124b4fd153635bb2,
+        0x50cd905a3454307d,
+        0x329221c7efac175e,
+        0x938bb3be214288b5,
+        0x20dfd368ed559ace,
+        0xc675d419487dfb79,
+        0x27177ccb2b2fa44e,
+        0xb19ccb0d0e66b5fd,
+        0x45409f8ca4005417,
+        0x419a76712f4fb384,
+        0xe4d4db581f0d3571,
+        0xd2eb9725fbedd2c5,
+        0x29eab60c73442234,
+        0xceb8a17d915d941e,
+        0xc6ccf8d9d072938c,
+        0x2fdd75ee4ac50c3d,
+        0x3788d1132e284843,
+        0xdec4b2002f1b92d6,
+        0xcb7dc24a09934785,
+        0x61e588f42d9bee7b,
+        0x678539def34985cf,
+        0xf21ff490ec2d8ec1,
+        0x0b83a971df9d4983,
+        0x23714d79fd17a950,
+        0x1b1da01dc9ac196f,
+        0xfd82abdbd8c396a8,
+        0x75e07753bccef9bc,
+        0x58b5b29ca96c5418,
+        0x72846400716b5e3a,
+        0x6334fcc7e60b068e,
+        0x590789df14e93339,
+        0xcb1c89afa88c19b3,
+        0x068dd45928ba974a,
+        0xeb38a17b13cd0002,
+        0xd087f0fe99d7cf34,
+        0xc907b9f2220cd599,
+        0x49056e37a3d2236a,
+        0xb530ab551150bb71,
+        0xb1ec81ab9b277a60,
+        0x97a1ca95118d7bae,
+        0x235ff1595c71a016,
+        0x8c03206421af5f06,
+        0xc37c45b54cce1945,
+        0x4f09fc134001e8e7,
+        0x617fa6310f931049,
+        0x9ea4183c24e1dc8e,
+        0xdf5f7f996dbf6de1,
+        0xf1ace931f241543a,
+        0x1a79ecb23d9e094c,
+        0x08dde0fafab7695e,
+        0x83cc8b668f85f50e,
+        0xf987ef2d07ab0bc4,
+        0x630a446217bfa502,
+        0xa7a047c1f8a28bb1,
+        0x32d54abb46039984,
+        0x29004fa78b74af37,
+        0x3bc8c495bf18c068,
+        0xf8fa432c6da04620,
+        0x4c0c5c340d95af78,
+        0x6897b586807ccd74,
+        0x50514347ac3af367,
+        0x98d21a96a1b90fd0,
+        0xff4b194c94ac6e20,
+        0x327339389260f287,
+        0x35250cdbb0760b97,
+        0x58eb1478a946ae69,
+        0x70763f47fecc9e5f,
+        0xde42d2befabd8ee4,
+        0x21a743c90b3b35d5,
+        0xa9adcbac7c7e5d41,
+        0x48a0ff9dd7792395,
+        0xa85d3951517c07c7,
+        0x06b68495a5101ed3,
+        0xd90f43f5fbd717f6,
+        0xfb6f89ea34cb7362,
+        0x516379eb79d09f45,
+        0x35e679dc9f54e367,
+        0x844cbcccf1733659,
+        0xa90f2bcae2b759b7,
+        0x780eb5b87f5e67d6,
+        0x38e1b96c4667c424,
+        0xbb3cad2bda4b4034,
+        0x2f75c53606e3576d,
+        0xed042ee5374374ac,
+        0x77355910d1ce2136,
+        0x4620a3baf22b71b3,
+        0x4260e37e209a5707,
+        0xacf8dda3b8615af6,
+        0x371da42660f7834a,
+        0x038eb4083045f001,
+        0x62cc5fdc21598cc3,
+        0x7b638e101848027b,
+        0x1627aceda5d5038d,
+        0xda1efb59bf34b322,
+        0x0a21a39b9312ec43,
+        0xd9a503dedfbb42d6,
+        0x0f5740da9c1e0099,
+        0x2ba2ea8a16723aa3,
+        0xe0591420124c9d43,
+        0xeb5ed2b6d72f6128,
+        0x5f960c22a6640cfe,
+        0x1ff4fa3c49f56a27,
+        0x5bff821878f209d2,
+        0x96fddc7e91ad5d61,
+        0x522009822df9dce0,
+        0x1030527f5e3c0c44,
+        0xefeb0afa1067fce9,
+        0x1bdc0f4d1f786669,
+        0x3c7cd8ddaae43d24,
+        0x5970fe23e82c12bd,
+        0xd0b2d26d69f802ba,
+        0xaab6a8a7983533ac,
+        0x60fe128393c28c9c,
+        0xc6b0d7790ecfa367,
+        0x9ec2268b2d325c65,
+        0xb7dae8178634543e,
+        0x24532090b85cba6a,
+        0x298062e7eab0612c,
+        0x5ac08482224a3b4a,
+        0xdccf2ffa01dbe861,
+        0x65ba265d332cb9fd,
+        0xaa4a9d84731a0f1b,
+        0x678343e5f090c603,
+        0xd4b40933ddc84cf0,
+        0x2846c78cb1227cb5,
+        0xade97bdf38285e53,
+        0x9fefb47ce791f9fa,
+        0x09389ff3635479a4,
+        0x6781d0b0e7d4a972,
+    ],
+    // 0B..15B << 116
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xc697fd2be88fca9d,
+        0x0035f969c625b5e6,
+        0xc5cffe937ff226f7,
+        0x13d1d82760eb7606,
+        0x6935154e590b3f59,
+        0xa17eebca7c799cef,
+        0x5a3906d6b98c6b20,
+        0x0f1408f148735a7e,
+        0xeb55d403f49ee8c8,
+        0x900e26ed9aab2d02,
+        0xc743ba5defc2a230,
+        0x1faf6e4eeac0c36c,
+        0xcfdb4f88efd5b637,
+        0x59af077a530b2587,
+        0x23085e6518bf3392,
+        0x4930eb37cb709a97,
+        0xc6323d33d6611322,
+        0xa524877bbfcc0d63,
+        0xdb59a1d5dd1fb919,
+        0x29108eceec5668d5,
+        0xea8c3eaef1c41907,
+        0x98fbd5a500891abc,
+        0xe1132ec85aafca50,
+        0x16ce51012ab5f2dc,
+        0xe81aaaafd208ecf2,
+        0x964e00a23c5c67b6,
+        0x3ce9a03c145dcc03,
+        0x70e6f7a4ee07cf1f,
+        0xfc2602c4cde2035c,
+        0x96aa98cf0238413c,
+        0xa3a696dfbdebc6af,
+        0x1b9cf87401e8552f,
+        0xccd11ce0cf42cc42,
+        0x8427a2d38c5a87fd,
+        0x479cc05270034895,
+        0x68d9fb2a1dea2e14,
+        0xbc3329cb67a12041,
+        0x22f4c262f24d74ba,
+        0x3d158ef1f055b161,
+        0x1dc3acf02039716d,
+        0x110f6b3f8355e997,
+        0xd6a7ee937931a3c1,
+        0x0cb0934199c2165c,
+        0x6900c89711f47b82,
+        0xe5fb665659d02cfc,
+        0x5a87d067a4c07cc9,
+        0x5a8cbd86ea6cd58e,
+        0x51d9ed9b9718772e,
+        0x53b51544383b89a6,
+        0x7274124751ba50f2,
+        0xb45a05cd62f2d4ae,
+        0x2a330201121a8668,
+        0x8b72b7a6bd353e0d,
+        0x93a6f872e3e803fe,
+        0x9c06f42cc8e10a3b,
+        0x77d48b36aa1b0d83,
+        0x4233533ef07d6303,
+        0x5215533ac29ce176,
+        0x66130742e83a58a8,
+        0x7096889a8ed6713d,
+        0xb467a2bbc1476813,
+        0x7fcbd5e1be1162c0,
+        0x98466ce690e303cb,
+        0x3101f6c4c5e9b76a,
+        0x9071ce343bbaa4ff,
+        0xddc68dcc4210e078,
+        0x59ae451c616707f9,
+        0x440ff5d4a2b5dd26,
+        0x872e7bdab81f055d,
+        0xa54d49b96ad5aef6,
+        0x7aab48cb95031ea9,
+        0x3236dc0ef006a005,
+        0x67de27977b37105d,
+        0x3055a8e977fd0da3,
+        0xa4efd163565d5508,
+        0x41f982d6bf21238b,
+        0x155a5c157c87defb,
+        0xc6063da020409d9c,
+        0x00161199a7daad33,
+        0x22328cdf73757a47,
+        0xd49f392cc5ec9aaf,
+        0x5965f550415184dc,
+        0xf8ad0e409a9fff13,
+        0x245261d742256417,
+        0xd4e2e18dd74b1730,
+        0x6d00ead3e898605a,
+        0x19b15e66addc44dc,
+        0x0bf9c29187329422,
+        0xa0344e6f90f84a96,
+        0x8fa1b24c34c3ecbe,
+        0xd59face40e9ed6a0,
+        0x028b9e7175117901,
+        0xcdade91199e10947,
+        0xc1027415ff99cd6e,
+        0xd016fec604ea057a,
+        0x235f87241eef6362,
+        0xddfd8de0730ad99b,
+        0xdd490a7768a8eff2,
+        0xa7ec0c0183419f74,
+        0x1ebd01ddd52e4e89,
+        0x7f9430ebed764d3c,
+        0xced3ad90d138cc72,
+        0x88e4af7940cb04a9,
+        0x63cc3cc5c4ebaf09,
+        0x862ce1560bb2a693,
+        0x83a78d086b18cce1,
+        0xcadb2278f025b366,
+        0x1f05d93a547f441b,
+        0x31c6262053d44e2d,
+        0x692a31ca8f54de2d,
+        0x2cee5e1c1ef8e70f,
+        0x3a4b78e29f74354b,
+        0xa2d463c54b761a78,
+        0x60374aa498bfd047,
+        0xce22d921e2854a3f,
+        0x1764e22864aa9a9e,
+        0x9611ab269fdad8d7,
+        0xf6fb4db55d107e96,
+        0xddbad84337087fc1,
+        0x2421105af4258638,
+        0xf9e2c4bce948b4f5,
+        0x74e752951ef7a43e,
+        0xaaf8421308dc2e9f,
+        0x779b733baa746858,
+        0x9bf577794fad91e7,
+        0xa2683f25abceba1c,
+        0x5153de5477432360,
+        0x5cd8ab6f1e74183b,
+        0xa234babd069145a8,
+        0x039ede1ceeeee92e,
+        0xd091c0881d742df7,
+        0x5e5bd27f34b72189,
+        0xb185429f6d317f03,
+        0x8721e5d6c5d81561,
+        0x207995804501ec05,
+        0x71557e22de1161a3,
+        0x39d9199f9a439ae1,
+        0x4dea458713a5bf9e,
+        0x1f64050978b87e76,
+        0x6140f27faa657485,
+        0x7cd0f4a45f5d9e73,
+        0x5bf8d167cccd9cb6,
+        0x92aeed52256c4d1f,
+        0x172070de982fe18e,
+        0x9dfc9442048670c1,
+        0x82142851a63118b7,
+        0x6d450c75f36b2aa9,
+        0x484811855d78a0d9,
+        0x63f918910455f781,
+        0x7913049e70c22b25,
+        0xb087ef3c96b238d8,
+        0x6f63fc153dfea238,
+        0xdb11b419285a7b7b,
+        0x2a654485a792cfd4,
+        0x2a37bf352e8a21ec,
+        0x294ee9585ea2a17a,
+        0x9a80b689d8c85d97,
+        0x5024b311be3f1aff,
+        0xa19946f0973725c6,
+        0x3e4df02f34b8cc5a,
+        0x357bc5a969b0d4f0,
+        0x5ee13f1a7ef37f9f,
+        0x49df3afd7e338430,
+        0x41d99c2e84b7ec76,
+        0x0dd4f1a2bd6ede5d,
+        0x0eb635261378030f,
+        0x0bb5102ca82917b4,
+        0x31c080cb3499d9c6,
+        0xadd2e9598022520d,
+        0xb824ad1d34a7ffa9,
+        0xce69a963b97b2f70,
+        0x6dd4ad596578f3fa,
+        0x3269cd9af8f0d173,
+        0x9576caf934fe474d,
+        0x2ae0e9d58ae41c12,
+        0x3ae33816d84384c9,
+        0x174809a104c425e1,
+        0x5ee0f02cb91c95bd,
+        0xc9a124b45f52b6f6,
+        0x41a58b884f976a30,
+    ],
+    // 0B..15B << 120
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x954be5436bef4315,
+        0xbb38d09fef8b85b6,
+        0xfdbec786c91c5f73,
+        0x55ee93517a26c509,
+        0x498a4959472984fb,
+        0x94cd3488c1be9255,
+        0x8aa5344d8fd1ec59,
+        0x4917f5a7703bd14f,
+        0x619f51c7889e033e,
+        0x68777f8b15714c68,
+        0x6945f0e5e51191bc,
+        0x3524defbd90464c6,
+        0x5967373fa98da8e5,
+        0xd569897ece82d7ed,
+        0x3d58df3a1e40550b,
+        0x3481d4972732419a,
+        0xf25b331d4d0f6cd9,
+        0xc8f5bc5cfbcf8e2d,
+        0xdbdc5f3d2716db2c,
+        0x08d7cdb23d59d4c3,
+        0x8ad30d3aac71cded,
+        0xdde41f5f0b239edb,
+        0xf68c73aeb5a9d0fa,
+        0x6b9c4c567a77a851,
+        0x3246799770d91816,
+        0x43608968b0ad3e9e,
+        0x7b1c8462dbdd60d1,
+        0x61b2e0f14c45c324,
+        0xce82f6594dede684,
+        0x1a21b859204c3d0a,
+        0x8bff3fed1ce05de0,
+        0x51152d6f96f0f4ad,
+        0x6c3d531c14ea10fb,
+        0x40bc5c0c405782a8,
+        0x93371c33f0652638,
+        0x27060e1dc576bb1b,
+        0x6431d9fdbbef3e46,
+        0xaf25323aaa22d045,
+        0x06369ac61bfe914e,
+        0x55cbc6aa8f8cfc3c,
+        0x1024687ae913c4b0,
+        0xd0d858abead5de60,
+        0xb8a8a9e6a70f7406,
+        0x1b6715cf8e7ad6cc,
+        0x578edf939116dc6f,
+        0xbee242cb317dd345,
+        0xe116d87be275d31b,
+        0x12562ed5cb7da644,
+        0x52425ab5b6766c36,
+        0xf4ececb77a7f253f,
+        0x238f277edb6d14c6,
+        0x1738ad7839f76763,
+        0x6b4c6d6876131c3b,
+        0x2ce20a2a7a7b11b3,
+        0xec177f7192ace39e,
+        0x51df7b52b7af7ebf,
+        0xcccebef82de3504e,
+        0xb9bd967f2bd34a72,
+        0x261716dedd374066,
+        0x3a9ea4c9ce85fb73,
+        0x00fe0711aa577963,
+        0x80cd535a27c3e659,
+        0xa4c123a09782026d,
+        0x11ef00f652d08b4d,
+        0x7d1555878d6bc2da,
+        0xa368b96c48bfc929,
+        0x9d58cca8c4edec30,
+        0x6ae10f5d552dddf4,
+        0x723c77781817f845,
+        0x1545ea4dbfc366d3,
+        0x0c685f25dc2f6c7d,
+        0x3ce2489ba87ce64d,
+        0xdae2af853e7d2c8b,
+        0x4cbbf49e3e91591b,
+        0x54a0126010945cb1,
+        0x019e8d5e58fccec4,
+        0x7662fabb0bfeccaa,
+        0x6d3d8a2c940cb601,
+        0xca525b8c4f231a0a,
+        0x6ebc3b865c21854b,
+        0x50f7e3c95a3367f1,
+        0x54d1f223aed0226e,
+        0x65d36539b5d7930a,
+        0x48c747e3e2332a5c,
+        0x2e7f0249cb9fe083,
+        0xa295e95a1d487be1,
+        0xc719149f7cf418d7,
+        0x51ba882112eb1386,
+        0x406bdbfcddd7cf22,
+        0x96a6d0fc80b1306a,
+        0x4a421f57163c2d27,
+        0x3959c24785647135,
+        0x1009435e6612f3ea,
+        0xd43e5fba084470e9,
+        0xf15d58d222a7b552,
+        0x4760af3a8d196da2,
+        0x2b337bef3f07514c,
+        0xcff9c3033e11ec99,
+        0x7dbca1bc4ea88158,
+        0x75724180c736f9a8,
+        0x514ecd3ac78d8e4d,
+        0x4c5c905a2e79811f,
+        0x013f3a81dbc9f5ae,
+        0x6eb33a29bc34c757,
+        0xdafffb8c03007db4,
+        0xfbd73145e32dbe96,
+        0x60a8188c41cf4cbd,
+        0x4ad651a5d46df516,
+        0x68f43f9b09763842,
+        0x2f96e381df19874c,
+        0x966993ff6cb98171,
+        0x6e9215bf9f6c8748,
+        0x2554e7d103ec4ccf,
+        0xfe36cb098c6d0e36,
+        0xe384c0b48efdd08a,
+        0x5818c676ba78e44c,
+        0x916b8bc2ca79a49f,
+        0xf4a4f3556e766b03,
+        0x2efb7e206524a357,
+        0x5de8d88aed6e6104,
+        0xee98146fbde0b1cc,
+        0xb3640080dc49ec66,
+        0x2dac7f526b4f7699,
+        0x1617fee22ff22aa6,
+        0x2ab48e523d310add,
+        0xd3012cfdd61e93cd,
+        0x8608b004c02ec6c6,
+        0x3661a9a81a645813,
+        0x4f8f3ce8e04ec747,
+        0x64d94d4e3e2c666c,
+        0x8e3612439e6c8705,
+        0x63ac988534a9b8f4,
+        0xca26f41311ad18b1,
+        0x26f18f9dd8cf9c12,
+        0x79dff0cfdbee7fe7,
+        0x57f22b94a4280e3c,
+        0x393aa6af2d4660f7,
+        0x999a18016a4f680f,
+        0x52a5b25cfbff18a0,
+        0x6e1a8b1a24e4d6d6,
+        0x3d9af59eb76303d0,
+        0xb4f65afa297522b0,
+        0xfe009587b4bca708,
+        0x4c5ecfa51ff9c27c,
+        0xce672d0d85f218f6,
+        0x9e5683a674f071e6,
+        0xcd21b16e377b0d8c,
+        0x237b46654f89ca66,
+        0x3120c78b28e186c2,
+        0x4bc5aace97f2d370,
+        0x84b6c500c2ce8571,
+        0x5bb86f3deac72d09,
+        0x2ba66c8ca3d08a28,
+        0x288c12095617de5f,
+        0xa8994013db942b4e,
+        0x2c54e93dc52ce13a,
+        0x158c536b93728732,
+        0x9716fca96d0f3e92,
+        0xbfb48edbbe24d68b,
+        0x3d7ced3db2a9d9fa,
+        0x06fece589815b5bf,
+        0xaebf58c5dfb0e652,
+        0xf0de247c55934704,
+        0x3ab4eb2cfea89da2,
+        0x239a62fc0656e57f,
+        0x464d05af35bef87b,
+        0xc38d88427e97cf7a,
+        0x6c00bddb87015ef2,
+        0x2baa7e7ba3103b94,
+        0xb8110f86fe1dec63,
+        0x51bb3f3af7f817ae,
+        0x79354f8b242783b2,
+        0x1980a8400ef598cd,
+        0x81ca882d4b63dc58,
+        0xa13ca55e3a507fdb,
+        0x0394dbe40bf55493,
+        0x9e4b0ed40b50a9de,
+        0x8680249d19d8fbbd,
+        0x7694223b88ff4c33,
+        0x6e9130b0bcceae66,
+    ],
+    // 0B..15B << 124
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x3fefe2d4a70d3268,
+        0x4addf7a2dd5e2b87,
+        0x09e79f5a1d3fd238,
+        0x557cfa6848d34245,
+        0x3a7f08c66e9fce2e,
+        0x473d62810ad921c5,
+        0xfa418f0e62471df8,
+        0x0427825bd5fea874,
+        0xe5fe6aeffb9708a2,
+        0x10c4ef5ef240f51a,
+        0x5b04e38f61ddc6d1,
+        0x15f2897f6a7cd6a8,
+        0x03f64badfb3974d6,
+        0x24c5c54eb0c827c9,
+        0x8e279cc202727813,
+        0x164db0086de23ef1,
+        0x3de9928dad62c46e,
+        0x71160ffa0715152c,
+        0x26fb56cde55a2edd,
+        0x0aab89f624d97f56,
+        0xd84cb7ed7f516dc8,
+        0x62b0de79015c462b,
+        0x80c8eb9ae9fbc5ec,
+        0x573a9c39345694c4,
+        0x8eebab78c263cb35,
+        0xe4ead669d79521b3,
+        0xa2e9238f2ae24397,
+        0x493e2fea64a59392,
+        0x8f063b7b3c240673,
+        0xd18976d6cc404a6e,
+        0x19db3ef51c56a352,
+        0x5b0dbc395f61d026,
+        0xd7ed4fa44597cb75,
+        0x6d9eb5a6f4cf6151,
+        0x6bc8e54eb1a9188e,
+        0x56020c238d766f7a,
+        0x0f5eee93f92ce8ed,
+        0x9904b2ff92065c0b,
+        0x401f5dd812968442,
+        0x4878fc8c2f8d82a0,
+        0x6039c4e6d28364e7,
+        0x8e79d29e455edbc8,
+        0x9cef930754ab79f5,
+        0x0ecb6f4ff11aa70f,
+        0x246d173afd7b6c70,
+        0xb2d9ff0a9fb3287c,
+        0x0f3faccc15b1a132,
+        0x54d9699d10b99e44,
+        0x99483df5e7fe1100,
+        0x5f62f0049ded36c1,
+        0x5c5d268f858e394f,
+        0x04be07c712fe9ef9,
+        0x6ed0de0cc1625a1b,
+        0x4c84c932d3a13617,
+        0x218f11ccf7ccbf66,
+        0x4fe6977e0524e5f6,
+        0x82742b892937b84c,
+        0x9c2990f9457f2b9f,
+        0x57e9193129f2337a,
+        0x0ecb91d25f7cb1f4,
+        0x42e39e21eb535dcc,
+        0xec2f5cd7e2d928f3,
+        0x83f0c6094454faea,
+        0x336c2ff1a59f375c,
+        0x2a67153184a69583,
+        0xed1680d5d7a2d907,
+        0x11b6be8890f197a5,
+        0x10a9bf48a88840ec,
+        0x9849ea919f879ae0,
+        0x62f33ef3d633e3dd,
+        0xf26ee0297c5c3d8d,
+        0x1d9bbe44ed29a6b5,
+        0xc8041e3c50154c7c,
+        0x1fcc3261e3bef40c,
+        0x46b9768b66083fa6,
+        0x0de3d69850bcafe0,
+        0xb52641fab1a9d3ae,
+        0xbc31445541f5aec8,
+        0x22e970dbcbd77d8e,
+        0x1a95a95cec4405e2,
+        0xb38126891bc9b555,
+        0x6a456257ffcf41be,
+        0xfd366242daada386,
+        0x6825231551f6daa0,
+        0xdbf4f1da18d76d8f,
+        0x86e6176fef1dd2f8,
+        0xf0b4cada9ba22ec3,
+        0x2195e5896d526d3e,
+        0x26310ea3bcce5c95,
+        0xb4df7e53028b333a,
+        0x1f8138d9ee64be71,
+        0x6e4c60ab255d0e9c,
+        0xb99017af4bd3f456,
+        0x3d1fb9e77eefae6f,
+        0x71fceaecc0ce096d,
+        0x4fb9342c5a10b93a,
+        0x159112f8159809e2,
+        0x90cc1868c48f1f5d,
+        0xf0fa3df49b1db937,
+        0x4739a154f165c977,
+        0x997fd463c0b1dd29,
+        0x656940fa8bf18385,
+        0x916d6f70b64d60de,
+        0x28473300b6fdc392,
+        0x2b1bf1847db7b663,
+        0x6359eb9a91bf24f4,
+        0xac894e4b1fd1b272,
+        0x06d8ed72e59bded2,
+        0x9dbe3576752af82a,
+        0x897ce2ae77a6d892,
+        0x359e4f1ddc537404,
+        0x30197652fbedf846,
+        0x9087f424b1789333,
+        0x9973033b4782ad45,
+        0x5cc31c433eba19dd,
+        0x1cb3df54d3a29e0f,
+        0x010038ad1934f886,
+        0x093ad22ab29208db,
+        0x4e0d1023d493fa31,
+        0x7e7325fa2694a57f,
+        0xb55ef55d2248689f,
+        0x8000eb58d00358f9,
+        0xf998ee37f1e04dfb,
+        0x3f29a6352dc21d90,
+        0x43d6fa1d7df48bd6,
+        0x31141885e70d685b,
+        0xc76540c03caef024,
+        0x4e2475cd4ddd570e,
+        0x2b5d2e2c4b47e795,
+        0x95caca6b33caed80,
+        0xf9df6642601eeede,
+        0x1c1cf695b435ecd5,
+        0xcd3a35c1b6a7b1dc,
+        0x072fe1ee274960b9,
+        0x7b79a941950f0ed9,
+        0x754f42ff183caff6,
+        0x5af49a5dc895e94c,
+        0xa90dafa260804b52,
+        0xd8dffd955e489670,
+        0x6d9a4669dbb59a69,
+        0x737520e56289f05d,
+        0x6788994b6d1575a6,
+        0xd7c4d5c38570f353,
+        0x06728c9d419f0750,
+        0x4d7d524a449a453a,
+        0x7d7b75ed755b7091,
+        0x4c81fb25b9f40012,
+        0x2c6032dee3141fe5,
+        0x7e9c823c50931c90,
+        0x7547ccef7177cd36,
+        0x93b65520ce541c67,
+        0x7153e27fd8cdada3,
+        0x12b1ae2b9b92c14b,
+        0x599f8f2e1fb2285d,
+        0x1ae507d2d7e9a6f5,
+        0x29562347983adb26,
+        0x765dcc93f5eb195b,
+        0xbf8676ed046711fe,
+        0xcc6ea9bc24b82311,
+        0x278dfdda5a8fb33d,
+        0x1156f8078d1556ba,
+        0x5527462e5b9d95db,
+        0xf8db0eda7a8dfc80,
+        0x639f527c369fd29c,
+        0x803ac498d6da9cbd,
+        0x3ca8f2b9ad8c98dd,
+        0xbb9df82b4fbf42ac,
+        0x762423c6e7bb15a7,
+        0x35e33abb915da78b,
+        0x3b1d09f27cbcc58c,
+        0xdd24b614a8113734,
+        0x69e50598805f5d37,
+        0x7554ddb5bbd1a7f1,
+        0x6817321dc064a3ea,
+        0x9e4cbf04038df9c1,
+        0x6a3747eee33d6794,
+        0x9a3f7d280d315432,
+        0xe0496445ddacea0e,
+        0x76b1a3701ab65cc4,
+        0x69f1780a60d4373b,
+    ],
+    // 0B..15B << 128
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x18c2b473a1c32cf0,
+        0x313414730cc483a5,
+        0x668118c3327d5e88,
+        0x6e82f8132eaddb42,
+        0x22ad6caeeb2a3ee9,
+        0xccc45c8d3025db5e,
+        0x1d1405504f4902da,
+        0x52bab84d49e9aa06,
+        0xe693173bdcbf4f30,
+        0xec1b6b6eacd0cfb3,
+        0x5e5b8bff08136584,
+        0x2790fac06ecdd9f2,
+        0x38fc2d88deb32a95,
+        0xe88de54fffcfcdfb,
+        0x90ff414d102db08c,
+        0x66addec2b7691e4b,
+        0x21c9ec09fbc07dd4,
+        0xb444b34d52eb9684,
+        0xea9923ce2af0c493,
+        0x26a6789f4c341cc7,
+        0xf21578510d8d2bea,
+        0x76f478c5dbd8bed3,
+        0x49c2d064fb431255,
+        0x3402f87c61a0a104,
+        0x2f6af46ac83294d1,
+        0x516a065a6a8e1ef3,
+        0x0e10ece39faab790,
+        0x60fe2f5b9d8cc0a8,
+        0x9832d3771053454b,
+        0x788291a23cc59ca0,
+        0xaae383be34334958,
+        0x7b8a3330ee3c7c27,
+        0x70c0563f737d06a3,
+        0x4d4806dd2eb26719,
+        0x9394093aef9807b9,
+        0x249f99c3e1f0bb4d,
+        0x51b4a4488450ab45,
+        0xa2fd0635547aa3ef,
+        0xe478e32593a25fd7,
+        0x6d899e8bf62c000a,
+        0x0b0a44f1e6762c25,
+        0xaa0f262b799702ab,
+        0x87b2e8be5400954c,
+        0x55ddc8762b19c4a4,
+        0xd15b6009150d642a,
+        0x7ba8aa3895e58769,
+        0x98fb8e1fad21dc86,
+        0x2b1943c503b7c317,
+        0x116b21abe8625884,
+        0xf30b6a2b8cf5758b,
+        0x3113e25e378fffa3,
+        0x14381bba4d9708fe,
+        0x5dbb8d5f4eeeda6c,
+        0x673b9602a6f7f039,
+        0x8b2d523abea80e7d,
+        0x0e86baeb61a4622f,
+        0x7ceb741607fccc61,
+        0xf461c982f1ce10d9,
+        0x6356fc2388ef5870,
+        0x35627a3ff53d7636,
+        0xe839b322eea95e71,
+        0xa56fdd1a831fcaa4,
+        0xcce466bb020fb776,
+        0x5299a2be34d350a7,
+        0xe0d95401a889cc99,
+        0xd2655782259392f0,
+        0x0d2bf2770b68f7a5,
+        0x7e6bd4b918a367c0,
+        0x8b53a967f745e2a7,
+        0x944e774b41d6d670,
+        0x3f4bdab323144390,
+        0x17a9785ad3c31ffb,
+        0x9781a060e7ac2ffb,
+        0xb5604e55ac419852,
+        0xede9dfd513fe2118,
+        0x29c5409bfb96ff08,
+        0xd20a26ed99367d28,
+        0xcf37fa528491ab1a,
+        0x19d27e6f04a18b1e,
+        0x5570a7575c9c21c1,
+        0x5874bcd55311dc71,
+        0x9c93d90b4951c7d2,
+        0x939b3956b7496227,
+        0x4113ae295f761f27,
+        0x4aa83a6932dd0d6d,
+        0x24cfc3616ac32946,
+        0x887d0a9a2d075878,
+        0x441cc420203b6736,
+        0x344e75bc11dfa1f4,
+        0x267c34099d0d8a12,
+        0xa883e8b618eeae9e,
+        0x248bb06e70f8c37a,
+        0x925186f7bccea0b4,
+        0x0f361e1235dfcc99,
+        0x932af5468ffdb18b,
+        0x7b6740a49e05ed2f,
+        0xa6ded8ac1c47cf65,
+        0xe7053ea186eb2e00,
+        0x6a71c99e0bc15a21,
+        0x5f7be794918c3121,
+        0x183f5e8c5eae9b26,
+        0xbd3d6bdf1055224c,
+        0x482ddebfaa506dfa,
+        0x7a204fa5f00f7c06,
+        0x53c12170bb2bf3ea,
+        0x4f3d2728b30d7a1e,
+        0xa097651efeb15de6,
+        0x57c26319f937be95,
+        0x17a488a5ab47e23d,
+        0xb77938673127801f,
+        0x9dddc4f8f45738ec,
+        0x7fb24a054edaf192,
+        0x30c76af46a63a59e,
+        0x7aad15715a7ef274,
+        0x0c4b4d44f892299d,
+        0x55f05204d27850b8,
+        0x68c56786cf38f9ce,
+        0xedd9371f71f81253,
+        0x088d2d9bafbd715b,
+        0x60ff62ea20cecd55,
+        0xd1eddbc3df4c6748,
+        0x9169df4874145e70,
+        0x3f99fb24c3a43512,
+        0x79b08c987f9bba90,
+        0xa63072fccc547c61,
+        0xc5fbefeb6f479273,
+        0x850655f1305ca933,
+        0x223425ed68c1159b,
+        0x37e9741506222cd6,
+        0x7a8af13f2f0548a5,
+        0xfc07f3cd7585ee19,
+        0x3fc87afaec098371,
+        0x38dd3642619e8907,
+        0xb435d9c2d9bf0fed,
+        0x893e8dd092eec266,
+        0x487a3fa73ffa4780,
+        0x5840eadc61484a8d,
+        0x46732194f29b85a0,
+        0x20678884039ac7ea,
+        0x18cec199fc6f6515,
+        0x240a0117c2226f24,
+        0xd33207761f26fe8c,
+        0x99899e997b93a211,
+        0x66bd15a06dd79da0,
+        0x91773face775f6be,
+        0xb2ad3bd5fcd6d833,
+        0x27dc3a277396537b,
+        0x1d62a96e5c62a059,
+        0x4f1734d9940c5c94,
+        0x1f97bd97468a2aaa,
+        0x4d76e50b96ef8b6a,
+        0x302ca3bb32625c66,
+        0x8fa149210ce635eb,
+        0x246c58ebb6039929,
+        0x106aff24c6379163,
+        0x621e60388064cf1a,
+        0x92b2465e16530dc4,
+        0xe028969dc47ac34c,
+        0xb8cfab76a57a23cf,
+        0x778913376830ba97,
+        0xc8436b0a3b4985a0,
+        0xb66cd0139cc9f73f,
+        0x5f9adaee660688ca,
+        0x78927c0bfcf7577d,
+        0x5eff4e252b549d07,
+        0x9f148b3c554746c8,
+        0x45ad08d25a49f5e0,
+        0x471faec4e53bb436,
+        0xa9d781b572a9a627,
+        0x2916e0bb940aed85,
+        0x9b9f2db4e4b09186,
+        0x3f1d64e89ca32ec2,
+        0x8de529f2404f8c9c,
+        0x2bdc9c0bea587611,
+        0x79c55b85555da692,
+        0x5ea3763fa7ae09ab,
+        0xbf3db680db8a3e6a,
+        0xfcba1fae77b735ab,
+        0xb6729c22b2fe035d,
+        0x49a17fc2cb738b01,
+    ],
+    // 0B..15B << 132
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xd68913d6155d14b7,
+        0x49b9909e5388a9b9,
+        0x85f664e5bfd1b858,
+        0x54de58e088aa9d92,
+        0x0845dc5cd0e64591,
+        0x6ebcdb685dc9f6d1,
+        0x19cdf6ecde50c645,
+        0x6d95627c4d110576,
+        0x229f63fbc824f01a,
+        0x289dd20a1ebc3199,
+        0x677c8b67480b75d9,
+        0x1ef9beeb9b52dfa9,
+        0x61a03ddbeabdedb5,
+        0x32bf01a653133bd9,
+        0x7a01d6d70e6838fc,
+        0x7b6ceae767ea4dce,
+        0xf71784a10af97657,
+        0x5bf7853ff9fe3a69,
+        0x2e1eed360fdc30a3,
+        0x2c2e35c8bf33dbf5,
+        0xb7dfc930f886e62e,
+        0x9facec5e075c9ee6,
+        0x8405c5160a97d0de,
+        0x22ca6ce7089a92a5,
+        0x5ac2ea07e6b24b8b,
+        0x10db7af212008c92,
+        0xf5a8a1512493789e,
+        0x495cc2d9fc0f398d,
+        0x372384380991ed13,
+        0xf380d39a8ce05119,
+        0x8b344c18092c2506,
+        0x607027aa16d7f755,
+        0x67da4cfe6cbb1594,
+        0x3059084d4e9c1d8c,
+        0xd3b78d9d5b8bd0e7,
+        0x0e76bc009edad23b,
+        0x2054939da7ae4f1d,
+        0xa65da27e2d65d8b4,
+        0x5245300c424cf193,
+        0x7d065d49d5e13e71,
+        0xe3b1a8fdc01a385c,
+        0xc4d0ddc848142ce5,
+        0x4487c380c65e3eb5,
+        0x546d5e232ea4eea5,
+        0x9efb695a2635893c,
+        0xfc17396e6de660d5,
+        0xeb2f622330e43e21,
+        0x0812db2cbd5c1f8f,
+        0x72afc4278b98b596,
+        0x442a8b0f55413171,
+        0x08a8fb1c0fee4c1c,
+        0x6ccd2f0a8ce9cf2b,
+        0x87adadd7bdc20c13,
+        0x5b10d09ac8b8b442,
+        0xe4f498affd4fd36d,
+        0x35c8d039eb67a49f,
+        0x018a7714b56557ef,
+        0x7e91b3a56eba3abe,
+        0x75f6895abd867d55,
+        0x14df9bdaf808d45a,
+        0x97af9b337b5c4105,
+        0xcf28c9fb6fc0fb01,
+        0xe796f3ba295e7f93,
+        0x42b2180ed6798958,
+        0x7311f0972e56a0d4,
+        0xe2ed8625b5f6f7e2,
+        0xa4ba632e75503509,
+        0x7b3d9cd831dd4d6c,
+        0x46c5093c1c8c8128,
+        0x3bc7f461a5a5f7e0,
+        0x98a8126dfbecd2e0,
+        0x42a04dda5dbb99ee,
+        0xb2de4c57b65f916b,
+        0x217e6cce4aca09dd,
+        0xe7b9f645f0da24e4,
+        0x7b1d1ca4ac383462,
+        0xea8023cf6910ab70,
+        0x281a6c408e742edf,
+        0xe53133589678e46b,
+        0x14b97f63da51bf37,
+        0x3f93050b23b9a79c,
+        0x1cb162a2a1871687,
+        0xf746213e10b80374,
+        0x6709ea628660b209,
+        0x252f06075030ae56,
+        0x25eeff6648c9299f,
+        0xb68a71f4861b4205,
+        0x2e528c0bf3020426,
+        0xc7f6ee349a0e0c7a,
+        0xbb5c47a583055f31,
+        0x53dc74022ec2b775,
+        0x4a11599fde02ab98,
+        0x10e8c10e500430bc,
+        0xbc58071cb0b12867,
+        0x93359a8da67b2c27,
+        0x0cc0bd611097af3a,
+        0x97574b68c95de52c,
+        0x7580ed4d3c99c855,
+        0x949f29bdc6d3ad0d,
+        0x3e0b9bbd23a57dc3,
+        0x261c7ef409ff0d4c,
+        0x851093bdfa4d9ffc,
+        0xc570a41443aeece4,
+        0x6ee7ae96e6fe1b59,
+        0xf6eb6d4d3734cc9b,
+        0x10df9d5730ed9bdf,
+        0x8d3468f9292b89db,
+        0x435980e7b58da90b,
+        0x3b7a78c37c9331b6,
+        0xdb1fa4d475ba898d,
+        0xbea7ff8367587724,
+        0x70a0eade698966bf,
+        0x8f8175a4e3d13e78,
+        0xe12f156944029b7d,
+        0xe6eb16456a42784b,
+        0x79e21febbb957e00,
+        0xfdad4142472816aa,
+        0x44814980f954503c,
+        0xa09939ec98afeafe,
+        0x78148661aa043835,
+        0xfe6e4120c0c82172,
+        0x2b35de89b423cddd,
+        0x413573d0aab81cb2,
+        0x190f0a452895d569,
+        0x43cc811c778281ab,
+        0x4bde24739c21dc6b,
+        0x94ab419d84fb79da,
+        0x331c5ec10482aa45,
+        0xebea52f9399277bf,
+        0x23525b57b0706adb,
+        0x6b937628ca6b28d3,
+        0x03a0a4954ff7f02e,
+        0xbe7cfe39cec01757,
+        0xf01139439d63395a,
+        0x736fffc7793563db,
+        0x37e6317c9b4f1979,
+        0xbae450fea6182a55,
+        0xcda00a15b5048e05,
+        0x53726602d49fbc3b,
+        0x514d93559b56d271,
+        0x1305630a082fbd3c,
+        0xe4ec4232037dca55,
+        0xa2a54724c1f5c3c3,
+        0x0a795a64aa3137b2,
+        0xb6f50eb12bb342a1,
+        0x5c73a478b7a3713c,
+        0x1dc243b6bd7ba997,
+        0x5031e3b42aace6a4,
+        0xba4763642a08eaca,
+        0x39f6302f3e6000b1,
+        0xee3ac63b48244c4b,
+        0x3aaf834a0eb5a501,
+        0xc61e3379969e39c9,
+        0x429e5b4704c1dccd,
+        0x6e4f40de56ddaa86,
+        0x2c63e53765709577,
+        0x70463b9c903e5cc6,
+        0x5df63cb0b80ef020,
+        0x8f569e41db91f190,
+        0x27d97022661cd2a0,
+        0xe0dc262d8454fd27,
+        0x0ed84ad2306499bb,
+        0xaf59dd0569985f3a,
+        0x613ff2da5cd7fcda,
+        0xea319c15b3ee09d4,
+        0x64fd201fdfb1a118,
+        0x58e187fff554a868,
+        0x51730d8a9ce22c67,
+        0xbe0a2aa7b2838022,
+        0x6b1bc349dfc327b8,
+        0x6366eb8a6abc6478,
+        0x30c4b0ad2e3d48b0,
+        0xaeb4ad5b157ac207,
+        0xb3bf26beac745dc5,
+        0x831b39aa0fddad6b,
+        0x115dc90bbd5f8c42,
+        0x7c5da3ae683d5f3e,
+        0xe013573593e41044,
+        0x45c0bcb7686b453c,
+        0x2e7ee7d684e82c43,
+    ],
+    // 0B..15B << 136
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x667fc2416661b0d9,
+        0xf281f73e4536290f,
+        0x97b9f93911e62122,
+        0x094b26597bf98718,
+        0x1b4094ec6d9607d3,
+        0xcf06ed43645b5fad,
+        0x5aa8782515157d20,
+        0x5b61efafc5361b14,
+        0xee05fd911363462f,
+        0x977c01d5f62e6f9b,
+        0x2dcd309100a40531,
+        0x70f1c8d0571b7ccf,
+        0x6569459a40c83481,
+        0xc4d15707c61b4b62,
+        0x089108154d116b37,
+        0x1f719bcb5f285844,
+        0xd9c4e38c5322bbe6,
+        0x7acd17f101a16038,
+        0xe960f8db238676cf,
+        0x3b24cd5136e43ff5,
+        0x77c537812a1ed73c,
+        0x94ccc507cafce359,
+        0xaffbe5a909479616,
+        0x1bad297a5dc84041,
+        0xde1dc880d04bca17,
+        0x04f61649ae72e75b,
+        0xbf9a73cdb3a19fe8,
+        0x7228b069a1af31b1,
+        0x3ceab101bc1f7fc4,
+        0x1a75495c5ae42d27,
+        0x9364d06510cebb2e,
+        0x77f8a54363bff414,
+        0xb55c6ba635e37e89,
+        0x8fdd0d82b0b9330a,
+        0x7337d7ee5205d337,
+        0x5f6c257a69cdc05d,
+        0xca75dfb55e924c79,
+        0x05b1eb26e733464f,
+        0x9fed3cc87bd12fc8,
+        0x6ebf9d65592e9f5b,
+        0xaf6f6e98c532e49f,
+        0xb797d58bcc7d8883,
+        0x26c77be9ecfd275e,
+        0x1281df2ae1ab051c,
+        0x37e3f71b50c75d70,
+        0x4fc241792e85b40d,
+        0xeed7728dabb91cd7,
+        0x0fee2d19eb7ed789,
+        0x7c34b75f9495396d,
+        0xb92dd151b837ee3a,
+        0x92a2009a8650e369,
+        0x2faea2c7f3ab7cb3,
+        0x375edb7d642fd5ca,
+        0x17471da652b48192,
+        0x0b229562ac15ea91,
+        0x7b0bbc20df986b19,
+        0x04bf9c531c8c146f,
+        0xb0323ee7e1de98d7,
+        0x9ff3219f756aef30,
+        0x685179fdea46394e,
+        0xcd0617659c8c12f8,
+        0x17240c9115772113,
+        0x6eac47f76b6519b2,
+        0x7112c9b5da0ce773,
+        0x9b565d7efe288c7e,
+        0xf1e3f46f49683915,
+        0xd8f1de14474d0cba,
+        0x3a345ab50cb73f14,
+        0x780e4e723e6eac49,
+        0xaf2353665766ab4f,
+        0x91f90e62fa258ffa,
+        0x58988e6642555f63,
+        0xcf805e9a3bb13d28,
+        0x6efe005df9b6306c,
+        0x20e34ff5b9b88155,
+        0x57ade6bcd5132b81,
+        0xcc8e1267378716d1,
+        0x04bdf588df3d8d8f,
+        0x9e579dfeb3147c7e,
+        0x4eaadc444c8e8ad6,
+        0x863d4379718d8d01,
+        0x489e127be27a730b,
+        0x51144ab5f66f8035,
+        0x5692153e2d1a26fa,
+        0xafccac19dc0140ac,
+        0xda483ff45e1a3026,
+        0xdd30b57ce13d0378,
+        0x5a96bce7bdd06f3e,
+        0x23de6a3472610d39,
+        0x6e861cfe6d3caed6,
+        0xfff69739e321e457,
+        0x364d68b2210b29d1,
+        0x7f0f75e599292e0e,
+        0xb58a715428f3dfe7,
+        0x1e55ee62ff81321d,
+        0x6de6c2541c0a01f9,
+        0xbcb90df84bdad584,
+        0xe1c4f764e2d79a40,
+        0xe04a3443257ab9b5,
+        0x3fe202770216a330,
+        0x55305fc2b131e07d,
+        0x9fb339fc52939c1e,
+        0xdeff3552033dcea1,
+        0x46a8513120514a40,
+        0x4ebfa464f709502e,
+        0x6e618b7d17e423bb,
+        0xe08e4ac6d9e74c37,
+        0x1d7a46ee2c530fd8,
+        0xf576e85eaae2fbd7,
+        0x1b7b23ce966845d8,
+        0xdb05b11f5ae4dd8d,
+        0x20015a451161884d,
+        0xee710ad60c467f5b,
+        0x1d4c7739f662a3a3,
+        0xbe46bcb2021a7cb4,
+        0x1128a977b5108227,
+        0xf6a45dd0e308aeda,
+        0xef792507d3d06ff1,
+        0xd9e6fe36e30c8e12,
+        0x788d0f53da572a15,
+        0x5cef51161eb29010,
+        0x02de933975fc1f4e,
+        0x93688b2bd94e28da,
+        0x19b6cdd623dd7a8b,
+        0xedea326159fd7b51,
+        0x36be1b5a3d976d9e,
+        0x714e766003c1d787,
+        0x70016cbd00eb6d7c,
+        0x5247ab98521a69ba,
+        0x17d45bd6c0ab2801,
+        0xf4ba8c9bb28e0971,
+        0x4b47929e72613013,
+        0x68e0a91ab68826ac,
+        0x5a8cef7614a5c3aa,
+        0x365a45a10f4ef7d7,
+        0x25a1c9256effe5c4,
+        0xbba20571295ef6fc,
+        0xffb25989e64246b6,
+        0xf703dc913dfae32a,
+        0x3b15b0466f52f9fc,
+        0x50b02fedaed38509,
+        0xd626d0beb1ec2d8c,
+        0xe88a1a82bdc7fff6,
+        0x78aabd638d807130,
+        0xb037b0ed5ad9d34d,
+        0x7927f0913c4fb341,
+        0x39911a6824ac4775,
+        0x196405bdb6a988e0,
+        0xe8e7ddc86a0e6f4b,
+        0x191906fadbddc242,
+        0x3eb2bae2978c6495,
+        0x74453def89abe365,
+        0xe6c4c3855589c3fb,
+        0x9726a0a601202fe7,
+        0x85010370d915db38,
+        0x1276a63b748adf3a,
+        0x5aea9b6beb7b7842,
+        0x541c361c1e2ff33a,
+        0xf75944fbefb795e9,
+        0x4f7fd8849e722522,
+        0x644535b1785ee1d5,
+        0x9a589e5191d81bfb,
+        0x4fe091b76704211f,
+        0x73a1abfa9513d038,
+        0xc6d9030084078973,
+        0x5fea7a07c1481dd4,
+        0x77f8f66b0bcd5949,
+        0x698be71f69153618,
+        0xb3c13c4a3ab889f3,
+        0x8d048fac2884ecb0,
+        0xabfae4e1ee11b6b7,
+        0x2d6bc1e064af03c0,
+        0xc2dd33738564ec69,
+        0x97e7aeac819f8cd8,
+        0x91ae47e6f54ccd7b,
+        0x63512f677aa66462,
+        0x052f2c544d61d8d5,
+        0xfd3ce76e6e2326c5,
+        0x97f7669e4ff9a37d,
+        0x2a4636979ad71ec2,
+    ],
+    // 0B..15B << 140
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x43bb3fc2c6da68be,
+        0x2079824864acec2a,
+        0x45e49dbe1bae56f7,
+        0x5fdd17f19b2d5b4f,
+        0xdf345296c0306411,
+        0x4ef5e0e153dab03f,
+        0x30f05ee4f3be0bf8,
+        0x17a4782d63c60c76,
+        0xb08d80e703fc5af6,
+        0x81a89a8a101a64b3,
+        0x96feb7f61b45edf3,
+        0x5b506a4ad7757162,
+        0x6b1ad01e8ae0ef1c,
+        0xf940a99026baf6a5,
+        0x38e834a6d1483988,
+        0x38a01098b225a9b6,
+        0xca8a10a7a543e25e,
+        0x5e00051b669d4c32,
+        0xe4b7e1a78e734b50,
+        0x3c52b83b68a02493,
+        0x686fd5138778257d,
+        0x43090ae12498e514,
+        0xd9e46d309c23a3fb,
+        0x182429caf7bbb145,
+        0xf95a5ac3d06643ee,
+        0x075dc8449cf38c46,
+        0x306cf501d631a38d,
+        0x7f73998d30cdd233,
+        0x8094a99faa834bbb,
+        0x1c51e40737686d5d,
+        0x6e7955c09016af5e,
+        0x5d97a6a9b7f6e952,
+        0x25d64e373239b8bc,
+        0xafcddfe1785b1a8a,
+        0x223831c7006add66,
+        0x0001cd8594faf4c6,
+        0x63e36a62e22d7fc1,
+        0xaaba3f593b216b3c,
+        0x71d005ebfc2c7f0b,
+        0x7d13b1394bea8547,
+        0xe2027c397721685e,
+        0x670da0c8ec95de63,
+        0xbff2ee7d7eb39835,
+        0x6843c5f0d5c4c0ef,
+        0x2cbb6522ff35f451,
+        0xc0eededbc7f7c79b,
+        0x99b80c45822bf2d6,
+        0x77d85cd1f4eaab58,
+        0x43eb0ef53a82776e,
+        0x2a6795a257f98bb1,
+        0xce07bf3886c09a32,
+        0x2aa8a786ca351ea5,
+        0x9953ebb8a7b53dde,
+        0xf14107fcc792c638,
+        0x99221e31ab8d5399,
+        0x14b369efeaaefcbd,
+        0x98f7120038c72fc0,
+        0xf047ba9680aa0571,
+        0x605617340730064c,
+        0x149db1d4cac7c28f,
+        0xda5981730a49b6c4,
+        0x68afd619e6852fdb,
+        0x4d9d0688f4d0327e,
+        0x07dcfc422c8eaebb,
+        0x3522c20475de772c,
+        0x0a2903f3d4663e2d,
+        0x3a2864da6c566592,
+        0x25900a938cf9e0ed,
+        0x7d2909b4bf212e0c,
+        0x2749820eae8cad03,
+        0x658d737e5aa49d10,
+        0x664885fc3b266813,
+        0xb92c70d9711b6177,
+        0x9c87e383355094db,
+        0xa228ef65650c717b,
+        0x5d027dbeb7592da4,
+        0xfcd46d1a3ede2578,
+        0xfe96969d2ee6720f,
+        0xaf9b380f716cf1cb,
+        0x5c826b3aba5d9537,
+        0xc26f44e10b444b9a,
+        0x1d2f74dedfc0cb38,
+        0x3eeb1f9c3ca72923,
+        0x17c6f7af7864ff76,
+        0x1fae9a45396067c3,
+        0xf73be7c94950c43e,
+        0xe9f765227a7f5112,
+        0x7da1ea3c9f5a37d3,
+        0x24517105f4290afb,
+        0xe3ef3edefaa59910,
+        0x5c4725d612177b06,
+        0x4cabdd1dd160a3e7,
+        0x998f598267625460,
+        0xa66d2ff863d5d0f2,
+        0x5b77b0832b27b7be,
+        0x7287725560cdbc46,
+        0xf8ac10e5c2ee69e1,
+        0x4e1edf00b9e91b1a,
+        0x02fcfa2e2626f284,
+        0x765fad119d1ef1ac,
+        0x6bbec04bb3531869,
+        0x64325cdcd03e6047,
+        0xa651f433c55ff867,
+        0x5aa85bef050531c6,
+        0xd831898dfb1129ec,
+        0x511f404a4eae93ac,
+        0x5e7659f166fc5ec3,
+        0x070bf78e5294fb49,
+        0x81207da1092d6124,
+        0xf59710cf275b2b5a,
+        0x18676c1ec213a0c8,
+        0x15a2f2e75ba88446,
+        0x34a367084276a1e5,
+        0x65dc1de4b4237d7b,
+        0x1334bf07d7f438ce,
+        0x125360a7135e9386,
+        0xb482fe62b0f9cd69,
+        0x9fe31e154ed13551,
+        0x482c4837ecdec5b4,
+        0x08824b8b58d5c1ec,
+        0x9484d8bc371d18c9,
+        0x070605ae9f2de823,
+        0xc1fbd91335dced19,
+        0x0af329adf05f3a30,
+        0x6a37cefa323d877d,
+        0xefa9919f4a98771c,
+        0x0a3e8132e1eb205d,
+        0x54eac3bb1c659869,
+        0x7668736c708d1116,
+        0x1631216ebd329ba2,
+        0x8e8b3b4ebefbd1ce,
+        0x581b495261a45bf7,
+        0x22a50cd213984387,
+        0xc5cf22f9a1ed2ce6,
+        0x8f8a78a601daf0cc,
+        0x6fe4b5f66ae41c88,
+        0x42cd2765a4e6d135,
+        0x4487627756c54fa7,
+        0x9e538f5e7f0c9ce4,
+        0x3f73de0d81ba9303,
+        0x7aa1994672f747be,
+        0x0f17e226a6f3fcef,
+        0x24e412ee02a9d137,
+        0x1ee6698f00a7e92b,
+        0xbbeb3b2460d304ac,
+        0xa81045f1bc6da07a,
+        0xf689422e100e041f,
+        0x7a86b46f88302bdc,
+        0x508fb4fec7a53fb7,
+        0xda74b91c03184c08,
+        0x2a26ded2b09267e3,
+        0x5f43deb93af1f6b8,
+        0xcb61ace8da621b8e,
+        0xe4794057a5f9ca26,
+        0x4d755b2c1f2e4fc6,
+        0x20fe6934422a47b9,
+        0xf14d6f4202e0b7de,
+        0x9abdd0ca0b39374c,
+        0x220fe2c1b677fbf8,
+        0x12faf19b8a0127d9,
+        0x8db29660ed77a23a,
+        0x4fafa1d0b9555fb2,
+        0x41096792741bf4ae,
+        0x0566f0a3d7b5f7cd,
+        0xe3bffd38b0a5b2c4,
+        0x4c96cd8d539bf576,
+        0x27c540455e4aed31,
+        0x4a865f2de2ff2b8d,
+        0xc5d30af94859f17b,
+        0x4085f3f2b9b4a46b,
+        0x24614fc78675c78f,
+        0x48b3b131ed6eef26,
+        0xb1240d0c17b4f58f,
+        0xaaeba3993d8d2bce,
+        0x15dc197779e8ac19,
+        0x6c03bc5d8b242a10,
+        0x3c884ba235bf3939,
+        0x977c680db0bdf8c8,
+        0x07c025b032f0a9d9,
+        0x0ed32f9a7ce59d8e,
+    ],
+    // 0B..15B << 144
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2bb58d2404acf1a3,
+        0x193568a3266d6ee5,
+        0xbfa1dfb9afcaf942,
+        0x4870d7681e7fd598,
+        0xb4dde5e7d63e10a9,
+        0xa26c888eccd985ca,
+        0xe89ff189697f46d4,
+        0x6b14db9607206f20,
+        0xe168a7aad82d95af,
+        0x191f14916a3fd843,
+        0xdab363c3c7616756,
+        0x09bc012569a32fa9,
+        0x26f7b40a1c215d2d,
+        0xa2bebd66ec97489f,
+        0x2659cc1c975ff7bc,
+        0x4c3d4fe2901ee3d4,
+        0x0ea36fa615005ea3,
+        0xff4f3f2dc6d34217,
+        0x5b32415839c86578,
+        0x7cd80647979fba5a,
+        0x1d75c0d55a374252,
+        0xfcf40f9a74b46a42,
+        0x1b95fc99c03e282b,
+        0x0dcfb34fff4dc2b6,
+        0xb0c127386e9f405a,
+        0x82ab3130f424bd3d,
+        0x7565458c27185c89,
+        0x3ddba5ae90e1569d,
+        0x41aa7762591f62c4,
+        0x94b9318cd0a5a5c5,
+        0xe2dde170782124c8,
+        0x490ef04edcd8c7d8,
+        0x3d937fab8ec36f70,
+        0x038b740437e9c345,
+        0x844eff0cb3f4eb14,
+        0x4dd43bcd73422245,
+        0x9d698759a9ea5dd2,
+        0x95273f6316b73d26,
+        0xcbd17a95f1974c70,
+        0x44adb588c4693fe1,
+        0xc097dce794309de8,
+        0xd4056116ba806819,
+        0x223768c053803155,
+        0x655a5267254e61c3,
+        0xfbe4c1cee9e1ccbc,
+        0xdf26ddcc22527fad,
+        0x658cc1a1eb44a3f1,
+        0x6d54a0492b361c5b,
+        0x9ffad2e27e797ead,
+        0x72727ee726edb80f,
+        0xfc0fbd0d25e6bee6,
+        0x065963f1fd76b95a,
+        0x06c11e2367138426,
+        0x957a184d37dcbb11,
+        0x6d0b443355bd5fa6,
+        0x12c6926a771660f3,
+        0x2947a73c4993a0ae,
+        0xa7bc1a7d650c6a19,
+        0xd230eaa9a1aecac6,
+        0x10b4acb91d3ccd08,
+        0xd6c5de23c690cabe,
+        0x8dbd57b934420afa,
+        0xb06e57ff717fef65,
+        0x610eaeb858704a96,
+        0xde10d562e0518207,
+        0x86be512b2916d6c1,
+        0xe25a4cf953224957,
+        0x2bdd96f610fd610c,
+        0x103c6fd72b727640,
+        0xde2d0af3a178489b,
+        0xf018736321d2da99,
+        0x2ab6ea89f7fa3e26,
+        0x842d0ebd80f52141,
+        0x736324b9c0cc1a97,
+        0x2f4c219bb269e4a9,
+        0x7aa9cd908aea0ab4,
+        0x51be02759eabe389,
+        0x3673dc4df92eb18a,
+        0xfb9a502338e8c9c7,
+        0x5368f98b7bc9ebd3,
+        0xdc7722f1819d9c51,
+        0xb6f2f328cd03d9aa,
+        0x21eb52f472718f31,
+        0x65ca82d871f837d4,
+        0x74c8fe766ab818f6,
+        0xfcf03cbd5e2f227c,
+        0x04eebcc564f9b3dd,
+        0x2f9a08ebaa686b11,
+        0x05b04e0f63f12f86,
+        0xa48c03f77e5272c7,
+        0xc0e63f1aaa466980,
+        0x57c2158e15707a79,
+        0x009c0a876a5410c8,
+        0x8ab47964682ad043,
+        0x6c2f45cdcb9694ca,
+        0x1f005248acb64ae8,
+        0x2d9fb62744ddacc3,
+        0xccc3c66011a21c2d,
+        0xf4a83e9dd62f422d,
+        0x4ed3bc7b3af172b3,
+        0xe7011719b0109c77,
+        0x5939121a58024036,
+        0x1b9dce03f00e1722,
+        0x63e6168a5723baf1,
+        0x19459fc084a97126,
+        0x150d15825abc1436,
+        0xb908438f63d0c257,
+        0x220194a9f607c183,
+        0xcc18118b94cc5ae0,
+        0xc7a7fb3b2c49a2e0,
+        0x489490d4fa7a26d0,
+        0x61c4cdd6d9aa95a6,
+        0x392c722d03c41b99,
+        0xf92019854d7ae963,
+        0x000dfafd0d47da09,
+        0x72d2ebee23f35683,
+        0x12b963592d50c3f2,
+        0x45d745b0cd143479,
+        0x4f0b1ab36268f229,
+        0x08ffb6e4ac228dce,
+        0x041d04bb5fda02d6,
+        0xfcc13e4fce4e00b7,
+        0x64fadcccb74045f0,
+        0x478401359d3e95c7,
+        0x89b9b25cefe8967e,
+        0x20a934f68d8428fa,
+        0xb88d232e758422cf,
+        0x23ef1cdb5e0699e4,
+        0x2804728e288130ed,
+        0x8a303c847e7197b3,
+        0xc862e99bd6fe0f75,
+        0x098cc89fb393f22b,
+        0x3929de240660f665,
+        0xb7f67bea758dfea4,
+        0x0e6e1c0445f299c3,
+        0x315da7cfa11afeb3,
+        0x0a28271b004ac8be,
+        0x8d0f95ee9bf8a954,
+        0x8ac29fa8c6d42de2,
+        0x041b14220053ccf5,
+        0x9844758726d536fd,
+        0xf4093103f78a50e6,
+        0x654f82cb4428e9d0,
+        0x1b8782158b521c97,
+        0x6a90fa75c58c3023,
+        0xa5a952f05c4efea6,
+        0xa9d4e84b86896c7b,
+        0x47565576e868dbb1,
+        0x92b8217e965d2f35,
+        0x4e98fb6006f3ad48,
+        0x990b37f99dac1be5,
+        0x5614ae5df1fc8941,
+        0xfcef4fbc73e58086,
+        0x5981824380c50397,
+        0x447d5c64877f00e6,
+        0x54b28e306539be10,
+        0x8dc1cf24e7902bc0,
+        0xe97fc08136539456,
+        0x0be6b4643b4d9154,
+        0x37eb23f3e89b4ce3,
+        0xc769a4c1b26d1805,
+        0x38e17db8a538097c,
+        0x966a52271dd5743a,
+        0x3a87e82f50c91b32,
+        0x9131f64b5f6ec6e2,
+        0xc59083b689fa0854,
+        0xd4eb32abc9c06341,
+        0x5f515c766de677a9,
+        0x3c94275c068f49e7,
+        0x576775def5541312,
+        0x2d58330dc59ebce1,
+        0x615e0e2bc64aa2f5,
+        0xc3ac501ae05c6528,
+        0x9b4506daf063b736,
+        0x15c517aaf5d3764e,
+        0x32acb13d7fb68af3,
+        0x1e773a65a45b3f70,
+        0x5a595259b3dcd911,
+        0x800f66a76a8ef7d1,
+        0x47b6ca87dce22f45,
+    ],
+    // 0B..15B << 148
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x10b06cafcd4e630f,
+        0x73c2d1472583b03c,
+        0x81e51430ac134b0b,
+        0x2bb5e194f0c73423,
+        0x55776cecdd2fedd0,
+        0x3c8a1d2b6d7dd784,
+        0xce59f742dbf0dcc2,
+        0x15c4863b9c85eee1,
+        0xcb4bb7aa3ab8ba74,
+        0xc6c0e0b80a5b6f9c,
+        0xd28a3453fe9589f2,
+        0x12330e47de3b923b,
+        0xd6dd6706927fac7d,
+        0x9eb9a4f0aef5e597,
+        0x08aab22d3d070590,
+        0x06b186af955aeaf6,
+        0x81363607195b4ec4,
+        0x6464287080194512,
+        0x48e51d1cf5c49623,
+        0x56d9cedd29a083d9,
+        0x27a342629a9ff94f,
+        0x4feafb8fdab420d4,
+        0x8d7b5feedf760f07,
+        0x7fefb0af3b802d10,
+        0x65c60423a142df6b,
+        0x5267a2166e9afda4,
+        0xe012930ebca97e4b,
+        0x04eb615ade6bb33d,
+        0x7bcec9d6c38ca57f,
+        0xf23ac705f344a0af,
+        0xedbb416a00fc6b54,
+        0x1b2f74e457a3403b,
+        0x8bef2b885e0ae49d,
+        0x658746bd58c53e45,
+        0x2855caae43b520e2,
+        0x5770b39297670203,
+        0x4df53594b09e908b,
+        0x7b8272d001fc871b,
+        0x1ad18842a1a9b846,
+        0x42b3f24cbdd1d77d,
+        0x3f3be0cad7bae8a2,
+        0x381c0f8829facf1f,
+        0x4c5b50900967c65e,
+        0x5458f1bd9058b21d,
+        0x15bb6aee17076688,
+        0xb2a0f24078711002,
+        0x069e2da1662adb87,
+        0x6fd942d84865ec4b,
+        0x797751ffb586671a,
+        0xafab32239dbea75a,
+        0x03cdfe883330985c,
+        0x481feeeee6868c5d,
+        0xd1bfbc479f6f3b48,
+        0xd1a94eb206377bc7,
+        0x9565694f6137bee9,
+        0x453c92fc728f359e,
+        0x3ea501476ec71732,
+        0x1d4b52d8a7a454f3,
+        0x7ad490d264f25ade,
+        0x723d0dc8e8916bb6,
+        0xfb706f1a3eb2b912,
+        0x6b6f2cf7c0950917,
+        0xe3d64a3261814c29,
+        0x7c70ca92afb002c8,
+        0xaff9feead05c5d9e,
+        0x7659267c051a95bc,
+        0x87392cc9f5484661,
+        0x62ac47e90da760bb,
+        0x95ebcebf2926ced2,
+        0x4d1a3742658904bd,
+        0xd2a52d91317b8659,
+        0x76f26d1f80d478e2,
+        0xde3228b144241ec3,
+        0x185da1044022e5b5,
+        0x3f71b1bf64c860a0,
+        0x2d8f47375d050137,
+        0x02c561d72418a53d,
+        0xb61b49464786b356,
+        0xec94f17165679094,
+        0x04ba803604eafe39,
+        0xefe364cc0a48ddc6,
+        0xd91c9632c653b426,
+        0x0eb1e8b33e2a4336,
+        0x04f9eb6efce0e8fc,
+        0xb10b2380167688b0,
+        0x45eea84f009511f9,
+        0xd5aac8efd26282a1,
+        0x6fa57e7211b7b9bc,
+        0x4ee40dc098605018,
+        0x3957fd62f9248610,
+        0x78745590778dfc5a,
+        0x686e388cc4988c8e,
+        0x6aa8aa41f0f81622,
+        0x6ef86064d8b732bb,
+        0x2152b38ff5b4f672,
+        0x7eb6fbfecc12270f,
+        0x51745d336049cb34,
+        0x56a156892fd66e33,
+        0x7973b2bfa4b8f142,
+        0x16ff2076adee6542,
+        0x2b1ff684815e8180,
+        0x970a9bf3464cef0a,
+        0x557df717779ff3bb,
+        0x0473c0d52e47d250,
+        0x0346cbfb9ba022dc,
+        0x3809699b09119d4f,
+        0x053caca68d4cfc2d,
+        0x221e414ec960b047,
+        0xe7fdde74353953eb,
+        0x321d9a18fc15e4d3,
+        0x980a5600104655e7,
+        0x79e21bde33749acd,
+        0x77956b3f631758fb,
+        0xa0ed25ed4f7c70f3,
+        0xfaf564310cabdca3,
+        0x3247c2b200496078,
+        0x4febd35b1406b5fd,
+        0xfa22676b59bbc662,
+        0xbe413ee14d7880ab,
+        0x2e27b76c58c97e6d,
+        0xd36512e778dd413e,
+        0x463b9852bcc8421c,
+        0xd4aaa08e1d27452b,
+        0x0835c201d68546dd,
+        0x3d6c26286e4625b4,
+        0xc1112d85093f9128,
+        0x4ca7f780c0ff5c75,
+        0x3233dc88769d26fc,
+        0x8dcac4ce62559819,
+        0x1d3354aac397afc1,
+        0x8a729a058a8cd910,
+        0x38e71a6aaf7484a6,
+        0x4d05ff09ef228851,
+        0x184b02f3ebcc57d5,
+        0xb901260162b35454,
+        0x4df4927af936c933,
+        0x0b10ef941f01ec43,
+        0xced5e7b42c6266fe,
+        0xe2fa12f37d14551d,
+        0x1cf15c0bfad895b1,
+        0x8443751ecc69fe1f,
+        0x75301b2993a6bf1f,
+        0xe072945bc75cf96a,
+        0x5ed6e26a07eca477,
+        0x705864032cd9b88d,
+        0xda2757461243e940,
+        0xc47f2ae9b978a18e,
+        0x79a43e1158f85956,
+        0xd3006e2922331ce5,
+        0x9ee5151d2d556539,
+        0x3fc135746fd27e0c,
+        0x04125339f3678302,
+        0x98640660a879aceb,
+        0x934d97fe4ab62dde,
+        0xc51edc2922f41010,
+        0x172e890251efbe86,
+        0xb3b380178070eced,
+        0x44406a1b4bae0281,
+        0x82edad870faa240d,
+        0x39ae737d269f4f6a,
+        0x87f0a66f204e2bbe,
+        0x702d6c4665bfd6f5,
+        0x6a13bae98cd46343,
+        0x06fe2a6a4a06ba96,
+        0xc0465affaff367bb,
+        0xca20bc836438b7ed,
+        0x6833d716c651e009,
+        0x5e1427426a8a8893,
+        0x4889179f003f2390,
+        0x17cdef4e7742b371,
+        0x371f58802672292d,
+        0x788ae5264bec580b,
+        0x9fd1f690dfe098a0,
+        0xd015ffb478b7b5a0,
+        0x9e3792de814e44f4,
+        0x6c05cbaa85a70825,
+        0x849e02f67d401d6c,
+        0x455cfd7e920033bd,
+        0xc81b3035e52d65d6,
+        0x53fc1b5ef230a249,
+    ],
+    // 0B..15B << 152
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xab9505851e9306d2,
+        0x0fe9785f9afd9255,
+        0xa42c9c7d09280d1d,
+        0x72e256ebee0720a8,
+        0xcfa10e07da2e45d7,
+        0x656bb952012cd8a2,
+        0x5332c312e5e95b38,
+        0x6255670365293fb8,
+        0x6de23e99c1210d40,
+        0x2fd116f38079acac,
+        0x4733df31e552bd4d,
+        0x058470ec5d8c35d8,
+        0x907c08a381828111,
+        0xdb3db765deeea294,
+        0xd9de247a4e0e5fd2,
+        0x62abbff1018444d3,
+        0x9ef9ba167d54803c,
+        0x9b1b81d6b371d83d,
+        0x7bbff70fcb6f4516,
+        0x330e47e3c5749b2d,
+        0xcc2e2083d72102ac,
+        0x370faabbd64952a8,
+        0x8e7fd72d3c96ba8c,
+        0x3f05b134e00d32c3,
+        0x42ce6e838b5a975c,
+        0xd62ef22532f3e0d1,
+        0xbd104fb51125ac79,
+        0x671f39fe23527643,
+        0x4d4171ffeeb3accb,
+        0x22a45f37be538549,
+        0xa501a2ef44ecedae,
+        0x11aefcc6896c9f7f,
+        0xfd63d299ef8dc817,
+        0xbb8c87f1447d61ef,
+        0x24525e3514bd8f14,
+        0x5ffdf4063c09f3b6,
+        0x37852f1b9248440f,
+        0xbbfb8bc6ae2c6691,
+        0x02c42c282c33bbaf,
+        0x2d0ab88711ff55c4,
+        0xa3a761f0f5dca633,
+        0xdb7cab52ddb22a49,
+        0x9399260ed454e841,
+        0x4b6714e7e93cf084,
+        0x5f38659fe41b2d0a,
+        0x6b2c52d66ceb6778,
+        0x8af7b448f9c9ea24,
+        0x76955d2c7ea066c7,
+        0x78d1f2c3b213b799,
+        0x50ad591c0c30ec61,
+        0x8d4ef902edd0da37,
+        0x0ebc08d177c3df1a,
+        0xbc8584838f00a84d,
+        0xfb2777ebe23283f0,
+        0x5dd155c468e2034e,
+        0x4599cee07d03f535,
+        0xfc5b84d8d557e463,
+        0xe9a7f3493a3fb0df,
+        0xf0e15ae118e57daf,
+        0x5a114510a0cde9d7,
+        0x81ab1133006af188,
+        0x3312a2096b91fb33,
+        0x53d043faac545a7b,
+        0x6c9f6a3b2a3ab15d,
+        0xb4784f2bacc384f0,
+        0xd1c2ee5ab76498a6,
+        0x8998664a46f040ab,
+        0x541a6efdbe15f1a1,
+        0x058af4fcb9ed2fd6,
+        0x67e5e847216dda39,
+        0xaf159615a9baaecb,
+        0x53f90f6ee31e92d2,
+        0x3da71c4e1ef871c0,
+        0x90f49172d6190310,
+        0x7bac3ac9ced55be5,
+        0x08b46b41e4761b88,
+        0x0b500b418b00b7b8,
+        0xbabfd5b8e16e5f5c,
+        0x94b537505bbdeeb4,
+        0x20cd675c637abb93,
+        0x1d4971558d64c0b2,
+        0x45ca9ae880fc31eb,
+        0x5634a1cedc20c5ef,
+        0x647b0bbb077e0684,
+        0x7bbe74afe4cc054a,
+        0x9adbe7bbc7610e10,
+        0x6eeb6bc228ebefd2,
+        0x230c6ff108f6f8bd,
+        0xca2994e4c7736962,
+        0x5cfc3a525811db5d,
+        0x66444684b9d0a845,
+        0x1100f408bdd17278,
+        0xbc33df97f9284be1,
+        0xbfed47cd79d89d7e,
+        0x202926911d811ba7,
+        0x78617e3d9af5fd3e,
+        0x96a4163fa4393a51,
+        0x6f49b81d67c2bdbf,
+        0xe818442ee5f29415,
+        0x697692eb112f1f2d,
+        0x231b38abb19cd3c9,
+        0x1dc22fec1f97ac22,
+        0x01cee309558c6b7d,
+        0x7f704c615cc9b155,
+        0x5ef2c8f291b4e358,
+        0x83871807e60e3b43,
+        0x85a76180c36397c5,
+        0x41d0e4a800f81c38,
+        0x300f4ced3a00c8d0,
+        0xb360ac7b7f1154b5,
+        0xfe13960220734d59,
+        0x3d356aaf6b4370ce,
+        0xbbcf62651ca8d23c,
+        0x4ad54750e5e896ac,
+        0x5675b26e69cc261c,
+        0x4c8994a7cb4ab80f,
+        0x7e80b3d0f579d7c1,
+        0x1ac692e9ebea730c,
+        0xed99591d7401101a,
+        0x39d404586abe640b,
+        0x33f9630575f72e66,
+        0xa0f2755d0fdc8fa6,
+        0xaf49225db6ed27e6,
+        0x4e0a2e6deb58640f,
+        0x5aeb6f43801a7f31,
+        0x3179af83abcd5e78,
+        0xe3ec9c79f495c169,
+        0x3806e7e5476fe852,
+        0xab859938fd76f1be,
+        0x37c5dfde1e79a727,
+        0xb5acbba1d962ba6e,
+        0x26373753d00d72a7,
+        0xca5c3f900b4379fb,
+        0x4457f1d4f4abeb32,
+        0xb90af3f2ab97aa54,
+        0x7f9e4848d0462119,
+        0xb587beba9d6ec28d,
+        0x6febb8489f11f596,
+        0x3c1bbfeeab75bcbf,
+        0x62316f12185012d2,
+        0x663a6b63827d8b1e,
+        0x7ea261e24a4145d0,
+        0xa2f0a25a92f58edf,
+        0x43d8af411078498f,
+        0x410fd390dc4d9db8,
+        0x5fb9e26e5e232f6d,
+        0xec9ad57684003b02,
+        0x4a0d98ac7ba1d216,
+        0xa7b7dbe111dd4f21,
+        0x0dc0eeab041517bb,
+        0xaf0b489b1caf6100,
+        0x3a4da7f1cef69dab,
+        0x1532729f2604e470,
+        0x4ba10aa5207e5a7d,
+        0x84ca47abf8ece088,
+        0x4a4753700b703e0e,
+        0xc9c196c65b0b8a90,
+        0xb1dc726b775f1d95,
+        0x7ac32ded4d70451b,
+        0x0ef227ba4250e17c,
+        0x09aef6ccd469e678,
+        0x8ee4a7332f0314fe,
+        0x72eb1f6c6e4227a1,
+        0x4273c0e7ffdabd08,
+        0x8a2dbe053d440537,
+        0x09682df16e41f945,
+        0x5436f600dcb54ddd,
+        0x03c95ae3a2a43e2f,
+        0x930e08cd1aa716bb,
+        0x6adcc3d8e415ec1a,
+        0x019b693270418155,
+        0x0d565ea5ad2bdbf4,
+        0xfb9eafeaddbd16bb,
+        0x34f45ecee6ca4b43,
+        0x016d1a2917704d26,
+        0x303beafd86fc8b09,
+        0xe924e20deafccbcf,
+        0xf9df57670e8976e9,
+        0x5864f83bf622ea87,
+        0x22edaab85c222c26,
+    ],
+    // 0B..15B << 156
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8c6017847846097a,
+        0x12ba6ffeb5922a38,
+        0x27204b86a1ed12c3,
+        0x08e7ed8ff5064033,
+        0x4c1806e70a75fa78,
+        0x3e3067f8106c1344,
+        0x56de1c3f9be4604f,
+        0x4b38df879856ede9,
+        0x75a3e8d88f1d8c7e,
+        0x733ba438495d57f5,
+        0x8780cff4a793e447,
+        0x4e1971c40f4c3532,
+        0xa78b8a6fb5004b58,
+        0x4737e8d5c7b24721,
+        0xf4984a8869d1319d,
+        0x3224cbc2bbaf593a,
+        0x07e81964ec2a557c,
+        0x060cac9f86c89c4e,
+        0xc2f96c671a0eda01,
+        0x4c2ef916ae32e253,
+        0x36efcf1a6eebcb1b,
+        0x174620733e03b971,
+        0x4739745ac3218677,
+        0x2e18aa515e5c0648,
+        0x81376052864219ee,
+        0x817d44d9ef7ae5d3,
+        0x8419246540e036b5,
+        0x04578278d372f5c3,
+        0x1a6e17126eb62d9d,
+        0xd4031b9b6c0d1a85,
+        0x19d61fb561245aff,
+        0x052bbcd36121c732,
+        0x8004d8c9d38e8e09,
+        0x13527dbe17526202,
+        0xf81855de997de8dc,
+        0x4867d7f47876a8fc,
+        0x5fdabdb70e0212a8,
+        0x70198449f1c8edec,
+        0x4e5d827e56932136,
+        0x1a7c1e81a7068955,
+        0x32680e8e9d2968a2,
+        0x3048508defe1aa54,
+        0x68931d2e217a4d2b,
+        0x460e533e96af6517,
+        0xde7c878c8e340da3,
+        0x6c8a8233840eb1f3,
+        0x195ecff9444132c5,
+        0x77b145420b4b6a76,
+        0x3a3fd1b22119fa38,
+        0x354a6c5daaa8a647,
+        0x16d5f21882d34977,
+        0x02f75a84dd3f183c,
+        0xd5175b23902584be,
+        0xe58b3a0bd7824b28,
+        0xf5e5110bbefc9a3d,
+        0x7d896c3bd96c6cde,
+        0x9b335183de99406f,
+        0xb9e2b10cd9141de2,
+        0x18731e49309f1579,
+        0x607a8bdf4e874b3d,
+        0xb1062f4b132a8f25,
+        0xb9cb86f4d30dc56d,
+        0xef8a2718d8770d25,
+        0x6a453b7c8af46068,
+        0x02cc806c27161362,
+        0x3e0ae113779f7aa0,
+        0x91bbd7da0e6b3fcd,
+        0x620fd35520c75970,
+        0x66dc5f692ed8cc30,
+        0x85b4341f32e3bad0,
+        0x7f047c0e2e2101f4,
+        0x55b9000d55d40fb4,
+        0xf7dfdaacc272bdc8,
+        0x78289e9a81983709,
+        0xaa68d647ceb370bc,
+        0x73fa577a2c4e0759,
+        0xae31cfcc4429a567,
+        0x90defa6e16a16f48,
+        0xee42fd488cbcbb70,
+        0x1ae110a671e44344,
+        0xce64fb4a28b0c296,
+        0x3ad422024dab0b35,
+        0x6cf63c920420a040,
+        0x1ed7e4491bfa0eef,
+        0xc0edd4053133d3ff,
+        0x66f5923c9a9adc39,
+        0x019db2aeb89ddbc1,
+        0x285caa75ffc71974,
+        0x6b229d7da034bf73,
+        0xc3f31f46f8c81690,
+        0xc03467925880e00d,
+        0x0412a4b282777a08,
+        0x1a3081efa87702f3,
+        0xfc3d5a99c0a83a3f,
+        0x8e693d1d0ad33d94,
+        0x0d54bd7409f5e837,
+        0x2645c711b934ff1e,
+        0x9f6e206ed1c238e3,
+        0x45c365dcefbfcf03,
+        0x61dcc4cd1ba2ae17,
+        0x40bd75dffb9b2a28,
+        0x654cb3b6470b73d3,
+        0x1f65b56ed5aa8427,
+        0x3c3ea163de51720f,
+        0x7328e3da5227b96a,
+        0x15cbe366005a1022,
+        0x8341e5b4e45918c1,
+        0x580c2fb4681e55c7,
+        0x281ce532f4c18e71,
+        0x0bc50a42d0b81d8a,
+        0x4d8040f8d2346d41,
+        0x771b7a206553cdf4,
+        0x8a322bbf853e4935,
+        0x0ca366c9279865bd,
+        0x4acc64ee49e14e8e,
+        0x684340d13f1dba8c,
+        0x324c131341c7af2f,
+        0x6443518817716724,
+        0xa34767b374a7eeff,
+        0x27bd6106a3f864fb,
+        0x8f7c48bd9646d191,
+        0xd82772837bc11747,
+        0x8fcf9599ae27e1a9,
+        0x082fc489fc0833e1,
+        0xfd98f465edefafa0,
+        0x3d658c824f558584,
+        0xb4cc711f54eedc25,
+        0x7bb71b6a8a419ff6,
+        0x821fe5bfb15cde4f,
+        0xa4014446050331a5,
+        0x6e9110f43a1e0132,
+        0x2709334202690330,
+        0x2c1e8361491cf42f,
+        0x8372f3abad386d97,
+        0xb3d8fcd8beecfed8,
+        0x78a8fd983029f282,
+        0x68b91d3dec86cd4b,
+        0xa835d0ae6d520071,
+        0x46f9fa6a91bc74a2,
+        0x74dec91e8782fed2,
+        0x1e69ceb62037c726,
+        0x3cd03b1cde8d01b4,
+        0xb20d046655e53bc7,
+        0x384b5b035c6fdebc,
+        0x7549f8dccceb29a4,
+        0x35d12ad9acac6b0e,
+        0x5d4eec28dd36cf88,
+        0x544d8c9f5cec8210,
+        0xc5ff8f2e70fb81c0,
+        0x2cf9b78baad32168,
+        0x9d102b91d28e1cf2,
+        0x065290e6257b359a,
+        0x98d37c76ea6c9d04,
+        0x2172bfb4858bf9d9,
+        0xd5a06213d9d472c9,
+        0x6ee5695b6ffbe9c1,
+        0x6e884d9f4949ec40,
+        0x1aa447d5139e946c,
+        0xe37aed0077aa44e3,
+        0x02e061d42e0ab426,
+        0xa8658b7e7b2843cc,
+        0xa7817673e556a137,
+        0x9804e5066d88c87c,
+        0x3b5304ee7daf7e18,
+        0xb181eeb54a8df5d3,
+        0xa2f2a07bca67d703,
+        0x4d58d900115e5122,
+        0x2b1d50f2fe28ceb1,
+        0xc6059564f8500dfa,
+        0x82cdfd1582a6104d,
+        0x4eb0563ad1b7bae0,
+        0x6e26c8d359c1d9a6,
+        0xece70b4f4daaa7fb,
+        0x39536e0c4d7f1ad4,
+        0x0bd3a22f2df9072a,
+        0x774ac4edd2f01982,
+        0x58863a8e63ca65ed,
+        0x3cc80b79f5ab40e9,
+        0xac0c9ee870bbed4a,
+        0x24b885560145d956,
+    ],
+    // 0B..15B << 160
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x67707f492d8907f4,
+        0x76fe69c246345f66,
+        0xc464cb9483bed39d,
+        0x0057617bca9c1641,
+        0x6c4075a8d529f59f,
+        0x997f315b85a9be62,
+        0x79de42e6a910da97,
+        0x7e13f536500b08c5,
+        0xadf759f380e168e5,
+        0x27e112f37bc312ae,
+        0xcb5f6f0075af732a,
+        0x2b213bfeae545384,
+        0x6dd649add34a6da3,
+        0xefa17ac3ffb9a405,
+        0x40f410c29f44973b,
+        0x23d554616b9f92b8,
+        0xd453331242ae55b1,
+        0xd85109513c2c5eb1,
+        0x855512f37ed21063,
+        0x58aec35572e4ad17,
+        0xc71dad57172bd4f5,
+        0x049a2e6c9f56bf52,
+        0x0caa45d26c41cce3,
+        0x6c0955712f40d4f2,
+        0xd32cbdaf947227c3,
+        0x35de61940bfdd103,
+        0x0470c2b0b13ba44b,
+        0x713029f62903e7a9,
+        0x79d7348e4ce0f4a9,
+        0xae14bf937ab31023,
+        0xc6e7ae28737c15ae,
+        0x07948331fdb850ba,
+        0xeeb1dbf3949419dc,
+        0x3adc9bdfa6af53e4,
+        0xd2e29e066e65d8a4,
+        0x0dee8f9a9797c818,
+        0x49cfea30e17021f2,
+        0xfd141f0b70520fc4,
+        0x006c9006d75c2205,
+        0x77b20cb877b6bd69,
+        0x5310c759c4259e99,
+        0x2baa16da728caf73,
+        0xc67f2068a38b98a2,
+        0x10e058d086cecb46,
+        0x2dc00ce2ba76268d,
+        0x808595850604248f,
+        0xa21f2e356d80afec,
+        0x0772ed1725a08270,
+        0x50359afa52403434,
+        0x05755f9311ea1196,
+        0x438ad6269dfb2887,
+        0x6578572368f6c762,
+        0xb9e601cf03c382cd,
+        0x2fac12a7edd95916,
+        0xb9269aa905423fb7,
+        0x6198fe2d70a41807,
+        0x926c8fdef0000729,
+        0xfce11796558e16f9,
+        0x1d5a9e6e3a98a43a,
+        0x6fdfa244c67fc9dd,
+        0x64ffa96e85d5837e,
+        0xe83de538819e2373,
+        0x94ab4c2b05859487,
+        0x6955fb2892d54f3d,
+        0x3cf6ae2b09e84c31,
+        0xe90cb887c6e469b0,
+        0xfa35990d43e6c9b8,
+        0x1db9b697c3db1e91,
+        0x0517cc71ad0565e2,
+        0x7d7906d829836d98,
+        0xb2aacd2f6ba4e939,
+        0x6d47c6b74abef168,
+        0x9c94e1ec1d87b591,
+        0x537b4f11f3e2ce59,
+        0xb07148c046f2e0bb,
+        0x1b73ab5d6914b12d,
+        0xef88585ad4502169,
+        0x85c367eb67121534,
+        0xf888d33723d28aa4,
+        0x6c0856a6bc5f6bea,
+        0x7bca5745a2596fad,
+        0x4614f7d2f964da46,
+        0x0be98e017979b839,
+        0x3e6b32c5c5209058,
+        0x0c215731e02188ed,
+        0x64c731634393ca65,
+        0x958f71b6125ac8f4,
+        0x34156a15156b302c,
+        0x87c871d2eaa20f29,
+        0x631ced844a040eae,
+        0x0baf76b12dada63a,
+        0x69b8e28f2d85c987,
+        0xd5cce31a5d5e4868,
+        0xe7e4f98aebc03b75,
+        0x31498f393a9306f3,
+        0x26c3cb2cc67b4242,
+        0x634439779296bc7c,
+        0x74150f5945776345,
+        0x3f75bdd66e6d5916,
+        0x603bb041cad19a77,
+        0xc492eadcf63f50f2,
+        0x3493f89df67491e2,
+        0x3ee66c75c42ce83f,
+        0x58f963316a2a82d5,
+        0x4c3a20a6c6388364,
+        0x6b3ee48beafa524a,
+        0xfb43a4ff10c6ee8c,
+        0x29e95ea6021538a4,
+        0xa66a6eebb735abed,
+        0xfdb46410dae99b7a,
+        0x3e5cc1b16b70f7b0,
+        0x1e9b1ac6a4fd49d6,
+        0x980fb3f8e81afdb7,
+        0x7e71228e03c1af18,
+        0x4a7129007ef68976,
+        0x286059906fa89d4a,
+        0x5c4a99f7e7628f98,
+        0x23ba6fe2cb7e5195,
+        0x746c53a9c48a8ea7,
+        0x75ab918bfa553909,
+        0x145a3ca4f6440821,
+        0x164dfb4e0ad5dcd3,
+        0x46a3516f3f2fc363,
+        0x0bcc5b7bb9aca732,
+        0xdba2518f951eb765,
+        0x9aa974834589c4fc,
+        0x1bb4028dc46d617e,
+        0x51392affc60de46e,
+        0x005aeced143cd601,
+        0xb457d4e72020b8fe,
+        0xcf250c481c125ac0,
+        0x09dc6d32f608e5e7,
+        0x63995a8ef6abd3a3,
+        0x3ecd1704f0531e4c,
+        0x10b3461be0082529,
+        0x7a74a2a6887df7ed,
+        0x5f1aa3b8b92f98fd,
+        0xdee36cc95fbf8e30,
+        0x5f9450194c2ed967,
+        0x6237bd6b41734c19,
+        0xab649d85d0351084,
+        0x325f6da963fa084e,
+        0xaa071e388be018a1,
+        0x27b14c170542ba05,
+        0xc607d3bd5e37185b,
+        0x7f830bd0198c4453,
+        0x28c8fb391488a96c,
+        0x281dac3a3a91f3af,
+        0xcd5b0c6878584314,
+        0x99298450ee7e6fe0,
+        0x4c0bad5d50beb7d5,
+        0x471a972f302e6d00,
+        0x7e235bad9cdc2e82,
+        0xca60e25639490793,
+        0x25c92186733386ad,
+        0x6d1716b6917c06c0,
+        0x7fa8d7f0a32b73ce,
+        0x2237ea4053ba6533,
+        0x42adacb650f3ef3c,
+        0x5061fa4a07e76586,
+        0x6103e8a67b7555d0,
+        0x890d834f11661122,
+        0xa5ab99d3d4bba0b4,
+        0x39ffc50efb8f6ea9,
+        0x64a4bf4fae344631,
+        0x8e4fa4b4d1f5af53,
+        0xfd5b8a772b5ff6a2,
+        0x3a46a1a9aa4911db,
+        0x26b27192e037c4f9,
+        0x9fa06fa9ffe789d0,
+        0x1a16db97bee6c6af,
+        0x54081a76c6b4365b,
+        0x818d1ad632cffdda,
+        0x1f8f7e1c024d99a8,
+        0xd7d7b38ce75762e0,
+        0x3db47ec043f40fe1,
+        0x0e3d69a5cab1cb71,
+        0x460e33838f8d1d7c,
+        0x6efb408b6bd424d6,
+        0x778e8f7451cac4dd,
+    ],
+    // 0B..15B << 164
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x3056b6a41266efde,
+        0x4039ab912263b6a6,
+        0xdfa2b4a7c50064fa,
+        0x246d50f17ded1ff1,
+        0x553655782b5954b4,
+        0x0f1838d36cee5203,
+        0xa5bb81a90fab53a8,
+        0x2085e7ec81a15851,
+        0xf4aaa0bcf7739679,
+        0x8e887e2a45de879d,
+        0x7f0c9226381f52ca,
+        0x335a4adaad038e10,
+        0x4bc1370be136b889,
+        0xacbf7ca414266eaf,
+        0xfba2596eee23f8eb,
+        0x64c38a0ebbf143d1,
+        0xd9bd253897b67bde,
+        0xd47821b198da3fe4,
+        0xd0ed8ce85877f7ba,
+        0x17352d96a4e18782,
+        0xf48b156a941eefbf,
+        0x400967d66e00257b,
+        0x2b9f939cf18d3758,
+        0x5f78549237324317,
+        0x47447d0a5f16c4b2,
+        0x0c7d34bdac0f07a0,
+        0x131163f9b4f80738,
+        0x4e17246d7a025b4c,
+        0xcc4231bd912e7285,
+        0x922a3515467f2542,
+        0x0018a7fd9478105e,
+        0x6f995649b5aa0728,
+        0x76c18b0e0fb8a1f2,
+        0xb87ed55f36152f0d,
+        0x7d866f6acd224e93,
+        0x318d14086467f107,
+        0x8765cf1322bab587,
+        0x9fef318c89acd3cd,
+        0x1c8c4e14c5e48651,
+        0x3038545badd212fc,
+        0x6ca51e0214abe819,
+        0x9c17676126879302,
+        0x956899dfa4aab8ff,
+        0x16ff6c1a82f31241,
+        0x46a5f0aadc9599c9,
+        0x60991c2c80ce2757,
+        0xa9b8aa3028f7261d,
+        0x6f49e3f95b9205a8,
+        0x953685f1a7a8ffca,
+        0xddcde6e4fcd2fa3d,
+        0x6c06e07187080d5b,
+        0x660bfcd1d54fb65a,
+        0x88e8df06121dcf40,
+        0xfcfd261745864d36,
+        0xb4b87ccec641df1e,
+        0x5e082ccc55d1b2d2,
+        0xf4c0b3a7ef89d10a,
+        0xa6aba4ee1898fb7f,
+        0xcb370982ede41b13,
+        0x6a5ece3f6aecbe24,
+        0x74d8316731b73501,
+        0x615a0d18e8f81168,
+        0x1270ca213eea5821,
+        0x630f63575f1fafb9,
+        0xaf6b7a24d41e57a3,
+        0x693a4c8fa92bc548,
+        0xef7d43026dbd6330,
+        0x0a2892a31f76de91,
+        0x27456f9e5d56c55f,
+        0x84bfb5ca07c87e6d,
+        0x3e4f32545089900b,
+        0x5b3b4c5623b7ec25,
+        0x4a89e3b83f9f714f,
+        0xd9de8bb5eb3981e4,
+        0xae9c53fbfd257c97,
+        0x2a2fea867ecfffe6,
+        0xa1e8b5310a7f72c3,
+        0x6d1c04cda988c4ac,
+        0x2e36a73a7ff8423f,
+        0x20a4fa0ca2064e2a,
+        0xab95afe14d83670e,
+        0xb5864ffbf3e10c74,
+        0x77cf8bfb11f29624,
+        0x1582f364402f48ce,
+        0xb3a17b80a1b50d4d,
+        0xfbfe308d90c7d2c2,
+        0xf14fea834bf8d102,
+        0x3cdfc5a9bd1dcf06,
+        0x7a4a547819c770c2,
+        0x0354a000015b59fd,
+        0xc9987069b08cd0b1,
+        0x6406f7a8ad1cf24b,
+        0x3414bd0e1fc32498,
+        0xd5a89385a3518f6d,
+        0x1c29807afd4e1883,
+        0x7ed120d630296828,
+        0x88035cb6bba7462d,
+        0x8df0fedfef1afcf6,
+        0x09674775167e8356,
+        0x5d6ed2fcde1f704a,
+        0x866f20f5c7783564,
+        0x7debf4f75015e29b,
+        0x0d4856d5ec4b7595,
+        0x72f3ff788143e70c,
+        0xfd268900589ddfbc,
+        0xfc426fd25773a204,
+        0x9016410f4e6e779d,
+        0x247035a9e6b8eb3a,
+        0xdff3bca80c5de263,
+        0xe224e4a3f70baab0,
+        0xaea3eefee35bc7fb,
+        0x5d9ee5f1815f1226,
+        0xed13337ffdca0b38,
+        0x4492d43699c9094a,
+        0xf983c5055b60e597,
+        0x5e3ca644ab4c0c4b,
+        0xf788f1094543a2e0,
+        0x1633f9d90d35a53c,
+        0x05a3e22961813945,
+        0x6e11fd6a77736639,
+        0xd402ba4e8ed4aa7e,
+        0xea7ae4f9a063743d,
+        0x3de81c87606eb6cc,
+        0x2a43d66d512aff78,
+        0x9d8ee80e7b19f11d,
+        0xf9abb4a31a117816,
+        0x1f260a1116018af0,
+        0x26d9c9e110d29144,
+        0xfcafee0db151c50a,
+        0x6c2aa62e1f7d51c6,
+        0xd8d40948ceb492f7,
+        0x47d4204ee1b2df65,
+        0x227d8fc885cca7d9,
+        0x91e2d1844e307906,
+        0x9665618cfe0e2714,
+        0x79aa3dfedefbc599,
+        0x9aa22015fda0e5fc,
+        0x0b5b35e92ec1991d,
+        0x4388718b3fb16b44,
+        0x38a9f957d75774ac,
+        0x424c3107218f2443,
+        0x520d22ebb67e25fd,
+        0xed4bcff45b3d9b6a,
+        0x0d4443e0f0940a02,
+        0x1967dbbffa61672e,
+        0x7a38ab5acb13029c,
+        0xcc496340cdd53062,
+        0x0069ce47efc2404f,
+        0x6b1f34875adecb8f,
+        0x03743e8ff1a541d9,
+        0x6388cc30371d27f6,
+        0x469dfa250fdca691,
+        0x07c02e0873965bf9,
+        0x406bc032568361c5,
+        0xadd98cf276e173f1,
+        0x1b86323101dd423f,
+        0xad45a230572eaaf9,
+        0x68f1b6028181f684,
+        0x956b405fc294dd19,
+        0x0ad22bce40457de8,
+        0x33317269fed22b19,
+        0xcf01a51844379099,
+        0xeae3efc57f6408b4,
+        0x2e063be76e1a2e27,
+        0xa7f4a0ab6f4243d8,
+        0x2e0deee06050a78f,
+        0x83c114f989a2b3d6,
+        0x64f408c5791616fd,
+        0xa5d357995a6e5458,
+        0xac8340f4003cb48f,
+        0x27ff9f3fee9c7e5a,
+        0x280be330da8987fd,
+        0xa20afdcfe8a52590,
+        0x2f731d06d3858308,
+        0x2047c43a2f17f698,
+        0x037564c3c40c6665,
+        0x0c542553f1a955c6,
+        0x2b1a4c25249b12d3,
+        0x406869ef8d0dd93f,
+        0x3cc16502c5d5d78e,
+    ],
+    // 0B..15B << 168
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xc9f89525f963d7f6,
+        0xb982602e741dd1d8,
+        0xf7c54389e240a670,
+        0x34b6280ebdc2ee4d,
+        0xd2eebe28005df96d,
+        0x3cafb59df9ed2e47,
+        0xf1a6036cdb953007,
+        0x021d6d3a7e41df61,
+        0x846e79d0727124c6,
+        0xb6ac970d713a8db2,
+        0x6e0facc27efe8154,
+        0x58c8140e0843ad08,
+        0xab598ee28efbd5d9,
+        0x64e0e4d5928e1b3e,
+        0x2f41cc5c942ac329,
+        0x2c299f816eb23725,
+        0x1f86586151ccf291,
+        0x990de6bd28ec43f9,
+        0x69e1a9274b0c47b9,
+        0x77c672424c32cec6,
+        0x2b341e0d2e1698dc,
+        0x4ac32a90da27509a,
+        0x422d93153b776c18,
+        0x1e27be62a296f9c3,
+        0x6898be2e9da757f8,
+        0x894a6a4cdc4b4e12,
+        0x8b8fc2cf7d936b47,
+        0x6d7d78419a611ceb,
+        0x0ea69d485c303959,
+        0xa252d6d2bc0b91e5,
+        0x0e42f874fed3fc52,
+        0x5c203a0586633480,
+        0x6a455db4f08a06cb,
+        0x8110530dfbe4e23c,
+        0x2afd607240930da2,
+        0x5ea431094c423d5d,
+        0x7b8d600992e799a2,
+        0xbe84e5d87169daca,
+        0x1f66ac2cfd8915ee,
+        0x470ebcc13378a6f5,
+        0xb3c0a203cd25a43c,
+        0x81abe44ed6aa9c89,
+        0x118d89b0c9097518,
+        0x10f953eb1c049c3a,
+        0x59918ce0bd4bd43b,
+        0xf2fb42119379d2f2,
+        0x8408bcf29a049fce,
+        0x4ccb3e336e22e29e,
+        0xe3ba2115ea42dc39,
+        0xc076c648a504dbfa,
+        0x15b6ed97de6e0738,
+        0x3fef712ff031cbc3,
+        0x9b553df4fd2d27de,
+        0x1c2e428a6fceaa48,
+        0xce2ccfe077625384,
+        0x5cbebc3ce2c71f5c,
+        0xa79455cfd93cec90,
+        0x8c0b2387eac549be,
+        0xa709edfd86ea8b12,
+        0x3d8b0551b9140d6a,
+        0xc207fa76ec39feaf,
+        0x1190019563f9f0e0,
+        0x627e62af0e86bd69,
+        0x4beb091250bfdee0,
+        0xc93fe4fecefbe86e,
+        0x933c9ae788f8abe4,
+        0x49702a0e92b80ba4,
+        0x6ca58f9eea6ed13b,
+        0x49bb1e84e0fb9d63,
+        0x2b97aff9b593f37c,
+        0xf8f9eeb5f0389c57,
+        0x2fe331ac7941731c,
+        0xd146cf519b4b919e,
+        0x4c7b51c556785e19,
+        0x0779f53476860766,
+        0x3e5d810c2b36b308,
+        0xf88fdc0b9a7d1387,
+        0x7277695d27d3eecf,
+        0x64c8799c2a256c67,
+        0x1b779a1a25a204b8,
+        0xcd24bc52c38cb921,
+        0xb8dcf2785c585032,
+        0xbbe7fefb08f83205,
+        0x7656fb06d8e33ab4,
+        0x937130e2fbaa93bd,
+        0xf7f808ed91d4b2a7,
+        0x3841d05aa66290b4,
+        0x29315348d8c920c0,
+        0x2c53c9b49ca50eac,
+        0xc3707b40fb658da7,
+        0xf3cb369ff10f7ad4,
+        0x4dece68b4d34f337,
+        0x1f65fba47f27750a,
+        0x6d2bceb684c52dca,
+        0x64098b5a243f21c0,
+        0x670b2e7af49fbbdc,
+        0x6bb751652411f51c,
+        0xb3a373c776adc21a,
+        0x4428a1dac5fb85c9,
+        0x5d72437706ff3628,
+        0x2c2b3f1aa73233fe,
+        0xc460a901f6b4cfaf,
+        0x3b669aa70914f265,
+        0x795ab4904cd32917,
+        0xd77fea786eb96211,
+        0xbaf580d7ba230902,
+        0xdbf6ca59903883c7,
+        0x35286fb99dae22d3,
+        0x544aa26d634a913c,
+        0x86789c2565ac62cd,
+        0xf2ce72bc9abaf9db,
+        0x1a387fd1edc4222c,
+        0x4e5a56d46da765d5,
+        0xf24112ca4ef89e7d,
+        0x92a3617b9378f3b3,
+        0x32bbe57553e1dec6,
+        0x8b24d7e449a3e22b,
+        0x5df3c877e0434ec3,
+        0x44810948a8a2e4e3,
+        0x6fd886b6fe6b3ac4,
+        0x66001a62bfba4c2c,
+        0x38738f1a3c53b84d,
+        0x4141616b698cbba4,
+        0x6f9f84c63f7bb21a,
+        0x8f914f7413ccba8e,
+        0x5050fbaab85ad242,
+        0x01af2ff84684aaf2,
+        0x65a4b232dee9a214,
+        0xff092cf375c8b8ad,
+        0x17f7c1cff07334e0,
+        0xb8c3bb08246324e8,
+        0x051a43f4a90ac303,
+        0xd91ee6c0d65fbef5,
+        0xe960cc03af2a66b2,
+        0xd193233ce1ee72c0,
+        0x3f6604a0c3381c6d,
+        0x8d44c7cf03662844,
+        0x376358f27b7293e2,
+        0xe9cd8ae241d58cb7,
+        0x7744343aee61ad19,
+        0x094d4ccdaa5bdbec,
+        0x4c46d8621cc7121e,
+        0x4eabd4ea27b4ea8f,
+        0x54d732aefec3c352,
+        0x8a9e02db01b81256,
+        0x391745f1c959044c,
+        0x4b3956a373cc3e7c,
+        0x4cc07ade48fd9f1a,
+        0x77c679ece4950a29,
+        0xbabb51cc843a30f0,
+        0xc1d1eb50bf97a62e,
+        0x5be0f69b2713a434,
+        0xf38dc9933697a97f,
+        0x104629252c95d45f,
+        0x31deb2603e5b615a,
+        0x621dc702514e11fd,
+        0xf6eb44be0d5e47dd,
+        0xf1b170dc9d440178,
+        0x490c2be3245aeab6,
+        0x0b2738013853f8a9,
+        0x94c64564602270c0,
+        0xcc221ebc1928ed2a,
+        0x4685282c60e2fbea,
+        0x47462a01d47d856e,
+        0xee1810f2ab6ec241,
+        0xe759ec76c0bf0120,
+        0xb2f6feb9671c3922,
+        0x7f75cc245b3de074,
+        0xe59af0faa7c4447b,
+        0x06adefb6697c87e8,
+        0xe7f3992c6e1a4e4d,
+        0x7ad077caa7acea51,
+        0x66397b093db8bc1e,
+        0x5240405bcf0cc816,
+        0x9a1b2f10de237f24,
+        0x02b2bee84f66af1c,
+        0xaae6129e44462365,
+        0x27fe1b313dcdfcec,
+        0x22b551e67d4d2d19,
+        0x5680c14acb21296a,
+    ],
+    // 0B..15B << 172
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xf3323a21ea409865,
+        0x0f509ea67b959d05,
+        0xad0ccac587c43c70,
+        0x7e0814ca7f684361,
+        0xf62b9fdad0cdf8c3,
+        0x6f42de6bdde6facc,
+        0xe6856c48cbeb1d8b,
+        0x5c3388b0691f37a8,
+        0xa3be55f7fef87a2a,
+        0x27ae65fa2ade95b9,
+        0xa4f90e9f362061d2,
+        0x5b384d45f3c29d86,
+        0xaed9cd0259ac2e31,
+        0xa74ed7e7c829c5fb,
+        0x7139d897f585c843,
+        0x67f255f0a2deb460,
+        0x5edc65c400e1868d,
+        0x6f4bb3705d15e1c4,
+        0x799b425785c2186d,
+        0x044702b1793b4345,
+        0x2431d6a8baeccce8,
+        0x32c4b73ddb82fe42,
+        0x7f015297a19d7a5f,
+        0x05c1645ee2114e9a,
+        0x0185e7954a573f05,
+        0x23b51638e1e71a79,
+        0x8c0f1109e625282b,
+        0x72293b8c4cd63f3d,
+        0x71891bf9c15122f6,
+        0x95db91d3fd01e9ef,
+        0x236e8432ec9c50e8,
+        0x71c9bd372bc7e43e,
+        0x7c19f196a58c8df3,
+        0x5fae79e213b61c5a,
+        0xfe4dca4b6aee6139,
+        0x4fd0f46f2920693a,
+        0x4ed9ae980ef580c9,
+        0x68bdd5efd97183bb,
+        0x775311b347c74055,
+        0x7c3cb6a332db0e65,
+        0x8ede307274dc4f3c,
+        0xb889e8e60c70c271,
+        0x67bf903978617a52,
+        0x1a546bcece5435a6,
+        0x2618978caec0618a,
+        0x49a422de7e9d4679,
+        0xca135640c77ce2d3,
+        0x0a2019fc435645ca,
+        0x881769ca05bbf163,
+        0xd3c23b1085e8e675,
+        0x4e029e1d9d55546f,
+        0x4ff26961fb8494c4,
+        0x487fb6069158e056,
+        0xa8949d22846561fa,
+        0x16b20f8eca80dc94,
+        0x324b5627ae4bde27,
+        0xad07bb4916cf44d9,
+        0xe77bd23939c1793a,
+        0xc16af86b9c564175,
+        0x313ce08d14e8bb55,
+        0x0805f688689e5658,
+        0x7de9f7314ad3b6bd,
+        0xd3197fba01477fe8,
+        0x71a7288753a06c9d,
+        0x7ef4564eb4a7d1e3,
+        0x19c9a4a91edf399b,
+        0x93309248dbf1443e,
+        0x637c90ee1b539ceb,
+        0xfb23bcc27b6f087e,
+        0xfa8c42995cce0694,
+        0x99f968e15bf5ee89,
+        0x4071632b03162686,
+        0x8d2935eb9c2e84f3,
+        0xeca9eb8ffc290a0b,
+        0x6d2deacba1ebba1d,
+        0x5e8a5dd420b66200,
+        0x96a18c8fd1393f82,
+        0x1092b8f636ddb64f,
+        0x9b596dbecb5af7da,
+        0x4ede9365ac50a293,
+        0x72c166bcc07758b8,
+        0x702a1d4fbafe019a,
+        0x97525a0a864778ca,
+        0x6353aa561b5760cc,
+        0x07487806532f210c,
+        0x38f85ab13e099fef,
+        0x8c4fe3b10a705755,
+        0x390d0979fdbe8fa3,
+        0xded45570ab66bb9d,
+        0xe6a5ef877b1343d4,
+        0x342c43e21f2b8404,
+        0x29ace897fe81a7d9,
+        0x5b01d023346cd186,
+        0x2506494a22a56368,
+        0x20ef075357d5dafa,
+        0x10e29229a0142528,
+        0xd78b9d4f2e9fcba7,
+        0x6d6d36bf9e09cfab,
+        0x4b906e01a41987fc,
+        0x6bd984b4493c1983,
+        0x279a334434197bdc,
+        0x6344634fe1328646,
+        0x9cdfa6bc8d845329,
+        0x4a5695407d3e0746,
+        0x05798e1c3be7dfe1,
+        0xc1270e6cae6c7cb0,
+        0xb9e112fd9e62704e,
+        0x09cbc078e0f62d5d,
+        0xa5333cf1fb523822,
+        0x87705a32475f3a13,
+        0x6a0d086b171e85e9,
+        0x1db66fc514217919,
+        0x66b628b3e50de435,
+        0x90116b645c6a935d,
+        0x60131cdbd4dfcac4,
+        0x0f8ca288c78e6077,
+        0x59659ee17b337895,
+        0x1443d89359a1c0a6,
+        0xcdeb8e1dbfa04573,
+        0x440b022e625cc48b,
+        0x6d3d61331abb0fe3,
+        0x21b191568cbf2b64,
+        0x0d4e529afd62917b,
+        0x2d9f30e5e30a8c5f,
+        0xf05a06e742502168,
+        0x2e51429fd27b0f59,
+        0x1df02f17e77e0712,
+        0x4947a983391896d7,
+        0xb3b76b3d12b3952e,
+        0xe17812f0db2c5244,
+        0x937a554cfe64731b,
+        0x44586da2c862d938,
+        0x68c0b3d2e00bba89,
+        0x4ac0946e75a7d3fa,
+        0xe3c313539b74fab0,
+        0x6fec3a01b8ec7e78,
+        0x43670988ebea4a23,
+        0x133c5fe73062b2b9,
+        0xfa29b3326e1dc26c,
+        0x2b243b99dbfc406f,
+        0x467ea2ee1fc1c5b5,
+        0xaed8c1ed2ace1402,
+        0xc8e2c74f544c759c,
+        0x1b1c8286658261e3,
+        0xcb51c41582959303,
+        0xd38e64c4d5dcf424,
+        0x437881ffacf0bb33,
+        0x5b314fdba6d3809a,
+        0x0a4a738033a55777,
+        0x7cd4488998ee2be5,
+        0x2f3d3a02bc77884c,
+        0x2a3b68ead88f587b,
+        0x5c00604465cca48a,
+        0xc0814b34c357cf83,
+        0x7c29b15ed6967f04,
+        0x2f6c2b49f88f1dba,
+        0x39d71ec812a0d238,
+        0xda8e1eb82a50cd08,
+        0x8efb023f9258169b,
+        0x514f5a3487a249c6,
+        0xd5c5d366ab2da092,
+        0x9e98b4f07c30da80,
+        0xc4cb76a9afd95cb3,
+        0x09077a7cf1ed770b,
+        0x7a8aecf07115d7a9,
+        0xf82eec5f8d17b3a7,
+        0xb2c355f9df3994e2,
+        0x3f30e41737b44d94,
+        0x33a1f04f90e4fd02,
+        0x17b3ed46ade4154e,
+        0x1713dabe013dad96,
+        0x5285510b060adac1,
+        0xccd02281968fe70e,
+        0x68e9e97b1681c9e7,
+        0xb4e63692adc60c01,
+        0x5e9173f46158fb69,
+        0x172957219b0c6493,
+        0x9de16ae021e55389,
+        0xed14ca75486c31e7,
+        0x52b10d9b65a45660,
+    ],
+    // 0B..15B << 176
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x26d87d5aea0a4032,
+        0x39be461c87ffdafc,
+        0x94a237f269e9d3ca,
+        0x6a01ef9e67abecc4,
+        0xe5ab8f74e6f45c94,
+        0x54b09f60fe277982,
+        0x2fcccc62d2c41316,
+        0x114893a0c9a82f29,
+        0x1d8300446e4d2c34,
+        0x3f8d018497796e2b,
+        0xe748f50bac61d8ce,
+        0x54d24bd66a5fe2ee,
+        0x47df081edc8d3315,
+        0x51ac109a6245eff0,
+        0x71e7f88340c16c95,
+        0x0e54b728267c179d,
+        0xecd432f7a3b79957,
+        0xd384e324ede17065,
+        0x225822231d6c814c,
+        0x53f7bf84374082da,
+        0x992e23ef1e6782aa,
+        0xdc1fd88d9a7a029e,
+        0xde16bd53adc275e2,
+        0x2689a63e91af3bfd,
+        0xccc9d53a4132c70a,
+        0xcd3c8c22cb83a2d3,
+        0xc36b8ac6e1137679,
+        0x7238c6e69f2b6ee8,
+        0x2e06e0b92cdbd86c,
+        0x96087240f66e8fcb,
+        0x76858ad6bea178d5,
+        0x345589e354c754fd,
+        0x57e68f9ec0be26ad,
+        0x7d1a0bb94652b1e8,
+        0xf83fafad80869dec,
+        0x5bbfb27fa11915ee,
+        0x8b9f67bf22eeea58,
+        0xbea8eba2f8b0eaa3,
+        0x4a7af4d81517a301,
+        0x56b172af11577365,
+        0x760ffa5dc83ae2b3,
+        0xfd521b810b4727f9,
+        0x3fd13a41c46cee04,
+        0x72af0a0cf374a67c,
+        0x9f59d94f52f346a8,
+        0x1d83b3c1c209cb1b,
+        0x2bf02ee872770428,
+        0x002a0c303627afb2,
+        0x50c8a9a5bd94d9cf,
+        0xc26af9b51c481dbf,
+        0x9f10e0f0ee053dca,
+        0x524a482b3fc065a6,
+        0x7b31d01177740b0b,
+        0xd7a1c71f8dc60d35,
+        0x3dc6aa55b91f7ed3,
+        0x392d0396677f0b78,
+        0x02ad166c161339f1,
+        0x9c80af75ae349772,
+        0xd353b0a5ba5077a8,
+        0x373ba4674187cf48,
+        0xf4a5cefd1d2addc6,
+        0x106dcc42d5eeba36,
+        0x57cf26472c9c8e7b,
+        0x04febc63778ce7a4,
+        0x78eef3815fdc77b6,
+        0xa0dedf279b6bb1ea,
+        0x85ad875012e05465,
+        0x60a43e2e18e4c0e4,
+        0xb11a020769498f6d,
+        0x686e163428cc48b6,
+        0xc2871e73b7b1b633,
+        0x368e03dbfbe56d8d,
+        0xaa47798e42a211d9,
+        0xff1d6363b0d0ca36,
+        0x61133fd18de5ef76,
+        0x76a09ae9322d1aea,
+        0xb4969a806b49cd75,
+        0xded92e3c0b6616be,
+        0xf124786a99126d85,
+        0x34c4eca68d19d0f9,
+        0xc45abc2bd34c4862,
+        0x1169e51208c8111b,
+        0x560a1be0d57935ed,
+        0x42a8d42f8b86c7b8,
+        0xa3a0df1d3a3fbaf3,
+        0x6c3372de326503bc,
+        0x36f22b1a3fc7002e,
+        0x4ef9ad29088f914c,
+        0x954a1409ac9ddfe0,
+        0xebf07cb49bba36cb,
+        0x898a99b14ce8922b,
+        0x50a4667af0911976,
+        0x063fc5eaa7843538,
+        0xe51dfc789302a445,
+        0xcd4ea6d6ac44881b,
+        0x493bbb8da236e28b,
+        0x19628e364de6955d,
+        0x1187e8f29088801c,
+        0x3e9654f60ce47c20,
+        0x583de3bb3d99bdac,
+        0xdf55cdd57cad525c,
+        0x61678afa9819dd9a,
+        0x72ae1067eedafd36,
+        0x491154611b6f3780,
+        0xadd25d2e1efe4ded,
+        0x56ed7f909a9446a7,
+        0x827eb5fbc0ce3618,
+        0x6c10b30ffe07daaa,
+        0x39c82caa35802e71,
+        0x3fc09e7387b62380,
+        0x7c7f9b1b503ff7fb,
+        0x4664f36a7f37adf9,
+        0x0e2c6ee2103a95fc,
+        0x4258cb0375c2803f,
+        0xc41c9b9c79d3020e,
+        0x00c9abc3f9a2f6fa,
+        0x1ea50d904b0f3e7a,
+        0x68e32fee2ecac1f2,
+        0xf03859d31a265c6a,
+        0x309706567ef345c0,
+        0x4221487bc3991134,
+        0x08c569507491d3ac,
+        0xab90eff29bec8593,
+        0x74f5026964b5cf8b,
+        0x35e24a93e09da926,
+        0x2c1338517d92055f,
+        0xdaff16698b4b9954,
+        0x2988316bb584eadc,
+        0x182478d44de0cd6a,
+        0xf2d030a333127247,
+        0x9302da2f97efe809,
+        0x4d2922d1b40c37aa,
+        0x38d5f9e24d02ff9b,
+        0x69f0b9cc95489be7,
+        0xc301f7ce5a65dd24,
+        0x14efe03ff3146efe,
+        0x3d53bfa62ebc5800,
+        0xad3187a6d4faaf9d,
+        0xa1f6044533782717,
+        0x74014b827c0a34cf,
+        0xcb34140280b3f250,
+        0x2f0b5a17e551067d,
+        0x6acc2aa993728d6b,
+        0x71be22b350f0caa1,
+        0xd920e27a2866f669,
+        0xd41c192b7a4392b8,
+        0x95061005d2628d70,
+        0x3300c537e8855a8b,
+        0x9d21e6b6f280377a,
+        0x502b6f7cd1515d29,
+        0x08235ef72eb4faed,
+        0x6c93ea89f0f5fdda,
+        0xca6061cba32b194c,
+        0x3b9efea1af1a8b77,
+        0xbed1056ba1a365de,
+        0x2a80185846be6abc,
+        0x8612ff2875733dad,
+        0x3b21714548732940,
+        0xf825d6d9aa09869d,
+        0x2888fbdb17e668c7,
+        0x32aa6d975a00ec73,
+        0x48d69102bfe63059,
+        0x22607a9d57e234e6,
+        0x72c1c31576228f31,
+        0x2150939c0222eb74,
+        0x68df0d8c5e76a3be,
+        0x6cc3a07ccac48051,
+        0x0017576c89b36dd7,
+        0x7acd48b635d9d037,
+        0x0e975a6050797c73,
+        0x1bff3ff1ea62a37c,
+        0x3769f54917462375,
+        0xbdfa17fa0d6521e6,
+        0xa260c533d98be3a9,
+        0xdecd7715cd64d56a,
+        0x3395397a1a9746f3,
+        0x990d2634f8403869,
+        0xedfd328917c47b61,
+        0x2f6582559b216742,
+        0x72d58f6e76968cc7,
+    ],
+    // 0B..15B << 180
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xc4cd2b971afebe5a,
+        0x711ca508f7fe6743,
+        0x12c92e5ada991d18,
+        0x5db6d0bb10696b8f,
+        0xdcfed991aaacc0e3,
+        0x9af5a9d821d649b7,
+        0xe70233caa03fe1ac,
+        0x2d2c277e795a446b,
+        0xe371a80af8e4e9aa,
+        0x2aff5d1982f77fc8,
+        0x9eaacf8c4e6808ff,
+        0x2f988af1fe45e1f4,
+        0x29ce92b4fe9266f2,
+        0xff40722c02c36485,
+        0x7eeb8edc3d5801fb,
+        0x31211d6293a6d44c,
+        0xbd18f11392f48106,
+        0x6a62e788085b3377,
+        0x1e57f5cdb5c43d29,
+        0x2c8235a46ab74757,
+        0x295c6a041ca134ec,
+        0x920e4c3747fd2394,
+        0x1e2c9cb9f0353aef,
+        0x19a3a00d19b360de,
+        0x5956470debf728d0,
+        0x6837045d7b0215a5,
+        0x57f1bb42e89f8a84,
+        0x44fc3f21e65b3a54,
+        0x7af770deb33b8872,
+        0x8b3a6800b46724a6,
+        0x1f8ae972f50656c8,
+        0x550a9bf7fd9c3142,
+        0xe3013524af6571db,
+        0xa94d4b46b3f3ac65,
+        0x20c905867e1f5261,
+        0x7f92bacadce9a277,
+        0x01d5f0b3338c646c,
+        0x14d79b3aefe065bf,
+        0x771298ce96b7ec39,
+        0x6e95ad3b06bc5c45,
+        0x4b77b4d0cd5dfa25,
+        0x78b61f11f5cc6280,
+        0xcce43b9c7c6f2e3e,
+        0x06f60bdb29a89c3f,
+        0x4b7b0b49e43c17b0,
+        0x3654c914d4775a1d,
+        0xb49f90e5a48bbe95,
+        0x4a2bc816546e4285,
+        0x6a9a358881caa794,
+        0x2eb80f04ef79bf4d,
+        0xff0472506caaab4d,
+        0x0980ca49c82f2fb4,
+        0xed465ffd53127ff1,
+        0xf16d9c3dfefcb853,
+        0xa56130675d02c628,
+        0x7daec428c42e4267,
+        0x0cf6dadbba94ab1b,
+        0x6f6e1b13f2486398,
+        0x672a90ab2b103975,
+        0x31fb590cbff309d0,
+        0xcb17bbcc2a191ec2,
+        0x340551726dbfe316,
+        0x1e1a7c72bab72b72,
+        0x0ae64b2d734c9acc,
+        0x3e2a8035e931cd4f,
+        0x9b8918e2af65a00c,
+        0xe971c80c0dd8fec2,
+        0x141f837db9db1e29,
+        0x3953650adec2c08c,
+        0x14bc7db8f10bbfcc,
+        0xf063544b360c2921,
+        0x73a15e0c0508c749,
+        0xae8950cfb76789c1,
+        0x26709f48a8112945,
+        0xe04f35c9958edd4d,
+        0x099682797ae0c890,
+        0x62f6a4a6389da52d,
+        0xa139330df8e5c853,
+        0xd091da0a5613ea94,
+        0x12f532603bac612d,
+        0x743f3526ed1c42c5,
+        0x23036e29d1356885,
+        0xa9248215b04e6896,
+        0x460a634ab30094c9,
+        0xc977be8cd55dae0c,
+        0x42bed1fcea7d83ad,
+        0x3dbcc4b30d5530e7,
+        0x5202ce92edb3a3b1,
+        0x36ff1606641843a1,
+        0x3f81a401ca842bf5,
+        0xd017202f3d400cb1,
+        0x7255db6d2aba05f2,
+        0x1f6e6b680e068a75,
+        0x640f647060656436,
+        0x7b2d50b4c783590d,
+        0x5298d29fa1ec31e3,
+        0x4f54583476d2bf2a,
+        0xc780be84b516ee70,
+        0x63ba59902731666d,
+        0x47ad8147286a5fd1,
+        0xe237693ab0a54e5a,
+        0xb48fdc14f4af6c77,
+        0xda4a9165986e0641,
+        0x0189353ac5dbef39,
+        0x94f76a503bfd1c9a,
+        0xa0fb5a2384005120,
+        0xee0d705c267ae073,
+        0x5cafcb8c84ca3196,
+        0x1926a44a8728a511,
+        0x7bea309fd1f4683a,
+        0x858cc96224a3da0c,
+        0x41505250e73c4e5b,
+        0x6656df44fdeb3bb3,
+        0xe1e0646d60f3a478,
+        0x4ef304314e188c17,
+        0x1ffe49d7bf400999,
+        0x657f75fb938abde3,
+        0xb30f806ea9ae4cac,
+        0x8430c6d37e2c65a7,
+        0x0c65f18593a088b7,
+        0xe9a9b9732acd170f,
+        0x46ebfd4b4c119b58,
+        0x3198f10698bc1aaf,
+        0x0662fdcb141a3c06,
+        0x2e7fd579db76f308,
+        0x6e726a325ba6286e,
+        0x1f6e114474575e2e,
+        0x0b137ae3150cba15,
+        0x5d55a197b128aaa7,
+        0x457f0363a49a8696,
+        0x2b232c0d59d9ebac,
+        0x79a5904616590b5c,
+        0xc71186dc80dc3478,
+        0x3cce073d6a170bce,
+        0x688af517555be0ac,
+        0x3cd5df89243e2595,
+        0x97c49b50c7e719d0,
+        0xd04e35179e9bee24,
+        0x07f73bc3a03cb1c6,
+        0x49d13634cb8ddfb8,
+        0xd41087ac5f454bab,
+        0xe0009ff48f057846,
+        0x6b2ae9081ecea449,
+        0x2ce480cfabca4c0b,
+        0xbac850c15abfd709,
+        0x58cefceafa58ae11,
+        0x7d7f535dff024162,
+        0x713d29b4fb3b548c,
+        0x1053473b6c122691,
+        0x15febf923ecf8b32,
+        0xf2119187039e6e0f,
+        0x5db2f75c34bc72d3,
+        0xaa28b08310f540c6,
+        0xa10386ad8acc5ba1,
+        0x17219ff72002052a,
+        0x2c1d842a8ee626b8,
+        0x1d9cfc5969c69104,
+        0x8fb28471903e4818,
+        0x3f9cb8dccf9633b7,
+        0x557aa05fdfd3a358,
+        0x8d7d4649efea3256,
+        0xbe72b8fb7ceb8a83,
+        0x26afeea72bc76f7a,
+        0x000c3134ff6df7a9,
+        0x9a1b34826020e953,
+        0x59aca683a22da53a,
+        0x5ff3565046881c5a,
+        0x37d940b4e278ee43,
+        0x496e1595b13a9874,
+        0x66eec4103f107fec,
+        0xbbfcb5622d060dd9,
+        0x2e2231f46043f332,
+        0xbf79b601e66e05f3,
+        0x133c8301e0310e6c,
+        0x21e420ead304ba9c,
+        0x61a30cebc1b73979,
+        0x05363ac1710c6761,
+        0x3e3ec4fdf9e450fc,
+        0x2ccb0c42b4400674,
+        0x1b1fd92847517d3a,
+    ],
+    // 0B..15B << 184
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xd8ed47c8be25ef22,
+        0x34851ef2ec0995e0,
+        0x27ac10552df32af8,
+        0x018eb220c8d7b352,
+        0x97eed71889b6ff7b,
+        0xbb0a0e533e893a0d,
+        0x49c3277cae7c0657,
+        0x293b3d27bcebe0e9,
+        0x5739388876a73f97,
+        0x29d5d9146d16b7ec,
+        0x80030350bd8c346d,
+        0x6efa66336d1efb71,
+        0xfdd9f59e050067a7,
+        0x3b4c92e503bd3535,
+        0xd4017c897be7ec9d,
+        0x0fddf8173227459c,
+        0xb260a18f91941daf,
+        0x651aa3a07d84ccb9,
+        0x9f720a9d6cee6993,
+        0x7f34df5c8ceaf268,
+        0xc6fda16edc63d264,
+        0xa864e9393b8ce37e,
+        0xc74315b8dc94a4f2,
+        0x50e56f36604da22c,
+        0xceee9d6fdb8b246e,
+        0x40762b23a47aebb6,
+        0xbbec7586748d6818,
+        0x18269417dc9b95f5,
+        0xfddf3e63f8225e6d,
+        0x2aad90f06e79ff45,
+        0xcde0ecf13039158b,
+        0x4dbad960c2581aaa,
+        0xa581c0a7fc9c453a,
+        0xdc4c03ae37bc3bef,
+        0x75abb489b5e21e77,
+        0x12c8ca9f7e854836,
+        0x0d83613a05e5bba6,
+        0x6357d7ab5de75dc9,
+        0xd55b0222e91ca67e,
+        0x1d611dca21da5ed7,
+        0x1fea1e503b0b745e,
+        0x161c7e686e941d61,
+        0x8645c64576d05bef,
+        0x29fc20cbaa696e0d,
+        0xfa8a2d53b18c6725,
+        0x3aad330119d590f1,
+        0xdf249286333dcbe9,
+        0x10c9de62039094b2,
+        0x76b12a54d002d232,
+        0xb0ed5d2b0d2c3ac9,
+        0xbc746f6f5986911c,
+        0x0be64bb306f6874e,
+        0x6d10c755a2b7cdd6,
+        0x254d518ebeff27f4,
+        0xc05c77b36eec2951,
+        0x63b4590cc15a7ca9,
+        0xfa6d07b87ce21b13,
+        0x1cdb072ea4d0a257,
+        0x3ecafb2ef30481ac,
+        0x061429f13a54b7da,
+        0xcca272347f533ab5,
+        0xb07fd21e2d6f1447,
+        0xb80eafdbee43a011,
+        0x1e96e4b6952a1058,
+        0x2dc0211578cbd800,
+        0x60b1ceffcb7684d0,
+        0xfef7521a0d17197b,
+        0x1e00220aa4ca5758,
+        0x3cbc7767b84b13a8,
+        0x1eef81fc0df9b119,
+        0x5697eb98c0c3e69c,
+        0x642225a9e0eb0f13,
+        0x5a77bfaba994d21d,
+        0x90b419f0f024db41,
+        0xe838dd2276a7bf5b,
+        0x30b7aefc296c4efe,
+        0xae20c165ccf29002,
+        0x2123d06fd56174c0,
+        0x068de865e90c133e,
+        0x14e27b352226e0b1,
+        0x39c3adb22494cb40,
+        0x928002fbaace0b52,
+        0xc770db802e0f4c3e,
+        0x52371b725d7e3745,
+        0x299ebc2c00f89e97,
+        0x0319d47e4587c42c,
+        0xbad684ee51c7002d,
+        0x2ccda109d8e1b13d,
+        0x339dc5df70099411,
+        0xa7273089833e9eb3,
+        0xaed19d009df6c148,
+        0x055c197489c8a4e0,
+        0x1d7fcebb06918aba,
+        0xa2f017ac08cb82c6,
+        0xae3a0d0793eb8585,
+        0x528582c517cdba1c,
+        0x3aea68c2780966b4,
+        0xdccaef1de9a88b58,
+        0xb5497cf8ed7f52cb,
+        0x5982e49be9e5db3c,
+        0xee605918b178447c,
+        0x3dc508a61c1d57c2,
+        0x8c9918bb19f63b58,
+        0x2eabaa48c11ba023,
+        0x7d591ae2b669c65e,
+        0x6fc3678b9519e2d1,
+        0x63b448fe9082c2ad,
+        0x3760ba43f56d76d0,
+        0x874221877f6e4202,
+        0xfa8dba68762dac70,
+        0x2a558ddd03f6ad16,
+        0x7cd60d862ba3bc4d,
+        0x6ca3114c1889d7e3,
+        0x6f2062a53086176e,
+        0x5639af5b4fbba79f,
+        0x659753c4a2511200,
+        0x23b1cfa636eaba56,
+        0x41b68e65f35a6f1b,
+        0x7cb2c4aa99aa7202,
+        0x5aad2cba8482b1c9,
+        0x69b8a0e53a8fa58a,
+        0x152783fa6c4d5d92,
+        0xf86951e925cd6e16,
+        0x6b1448b6df6c8fcc,
+        0x461f6a7e09458c35,
+        0x23cb11c0824ab73d,
+        0xf307acd035f44b93,
+        0x37c9520283849519,
+        0x62b2b7b737bc6910,
+        0x0456766a72c04d72,
+        0x62bf22986d410659,
+        0x009a6dc136c2bf87,
+        0x3ddb10d315c3a3ae,
+        0x0bc357a7d46c7df3,
+        0x0c3b985ddb5b156a,
+        0x62566c4f95fb84df,
+        0x22072536ed52ed99,
+        0xb4b168e0afc94ae6,
+        0xc26bd205b9ff6761,
+        0x2d038cb9e9b8b1e8,
+        0x4c1ad1d743fedd3e,
+        0x02e3a6500e798768,
+        0x0b19c198412d43f5,
+        0x4830d5e654ed7f3d,
+        0x05d91ef846963288,
+        0x465940ab53d001f2,
+        0x01937a02c01bd0ea,
+        0x51efb62af32d2a39,
+        0xdf48f6270315d18d,
+        0x53d03bbda9a3760f,
+        0x7f2c622ebf045369,
+        0x5dce718e00ff0e7b,
+        0xaddba6d27f5e24da,
+        0x8f39d1716479b938,
+        0xf3c93f64b30e57f4,
+        0x04e57cb20d7a7a53,
+        0x66b426d6f9b2ca20,
+        0x68e00e91ffcd7c24,
+        0x4380c7adbea5bd87,
+        0x7572ad83eec95113,
+        0x4dbbb16eed22ecfc,
+        0x14cb325af9ef7cc9,
+        0x50e322057a5cb176,
+        0x5588fea9a59d7dd6,
+        0xd3a4aec025da87f7,
+        0x84e0735e054fe60a,
+        0xfe2a3710d7d2d55a,
+        0x0873621a59b4a864,
+        0x43338067381fe377,
+        0x5f3105140d785574,
+        0xdaec603f50a3e25a,
+        0x1e66971b05e9f7b1,
+        0xfc73c661a7db890c,
+        0xa475e1f117ed713a,
+        0x7c0b4fb59d232eb7,
+        0x129e4757737a5b8f,
+        0xc47b09d23aef20ba,
+        0x8089bac8451d78cc,
+        0x5cbff9ecbb6bb848,
+        0x57c99d485373519d,
+    ],
+    // 0B..15B << 188
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xea175afdf4386f45,
+        0x92dd552db9a9022a,
+        0xcaaf68a3381113d2,
+        0x3c8e60f9ce44ef45,
+        0xa91b60c0c13316ba,
+        0x98c62575ae357397,
+        0xa4330e7b9bcad1c1,
+        0x13209c1c9e0c0862,
+        0x03f9ad42e9e5b64f,
+        0x6d4c5ce01c7f8089,
+        0xe0b9920ea0a7af88,
+        0x1d5ba7e67be35ed1,
+        0x327b587ed33496b0,
+        0x08868725c299ffdf,
+        0x7ad958c858bb81d3,
+        0x350c57cdeb858200,
+        0x6d70c81f8637e879,
+        0x17eaff5116ec2bb0,
+        0x906e388644701bbf,
+        0x117afdae6faccfd4,
+        0x9241d477e62520b7,
+        0xa67f6a4b86e4e378,
+        0x87f3f2768525faee,
+        0x7b848d0558a18c47,
+        0xd09c34305cbbd4bb,
+        0x011aa5843521f76d,
+        0x3f118102130a626b,
+        0x44a121a1a4925917,
+        0x7b6ea5c866e6d6ab,
+        0xa7527152edd82e92,
+        0x84478808a8972422,
+        0x7cc6269fe8b7020f,
+        0x4f90df5dd4bc8746,
+        0xde366a77049bf8f1,
+        0x80ac31baa3d71a7e,
+        0x027b6c4b8f1395c2,
+        0x905a6fc49981ecdd,
+        0x9ba54574af349210,
+        0x29c836ea049028e5,
+        0x16d4a206be1abdef,
+        0xcd21ab95813d5cf3,
+        0x6792ce49408449e7,
+        0x6981fe186b9dbd93,
+        0x20626091acb1b705,
+        0xf9eb1fce880b0ad0,
+        0xa7ea2838a7231b43,
+        0x76d93e0dcdf41308,
+        0x34ff5b04930314f1,
+        0xc70b2546208489f1,
+        0x29ea01ee4786b743,
+        0xa2cdbd6b44d8bd86,
+        0x5eb6859cef577d2d,
+        0x14690a581fffefe7,
+        0xe95a5e40e9c49cd0,
+        0x1c215d90f3cad2a2,
+        0x76e6e3dbd177f677,
+        0x335805975d8cbef1,
+        0x0b823138a109c07c,
+        0xe1dd406238a7c522,
+        0x098e4ac53a5b9100,
+        0x43821be03d3c7971,
+        0xe9fba7d1a9d7daf6,
+        0x8caa19f03a1050b2,
+        0x496bfdbbdd56a346,
+        0xea6474dd8d2917ce,
+        0xfb5de74b3c604c30,
+        0xb87af34f450dfcf9,
+        0x0b2e1d6685dd85f8,
+        0x611a70c4aa3c3238,
+        0xa3ad0afda0c50189,
+        0x351484e5748b15ac,
+        0x0538e4c79b1a5400,
+        0x23863c08a842f435,
+        0x208e7b009b96d8d4,
+        0x804429395120a25a,
+        0x420f5f403c4012a8,
+        0x316b3a1f963248eb,
+        0x5108bfe8829466f0,
+        0x065a94bf19ba5e2f,
+        0x6e71da7b95fbd071,
+        0x0bc4cf54b0d3a3ff,
+        0xff4ede2520a7373e,
+        0x1078fffa8ca7d182,
+        0x04745cbe1dcc7b93,
+        0x25feae3a502645d8,
+        0x1b3742449b207743,
+        0xa7d059c7bd8a59fd,
+        0x2b122959e8242abc,
+        0x34a6eadbf667fd46,
+        0x093ad584680bb2f0,
+        0xe0dc4d66bd1f3d3a,
+        0x6fe2131e0215f931,
+        0xc4ea610910fc439a,
+        0x053a226e67d76bfd,
+        0xb8368c1a515e5329,
+        0x1841411fc8ed0de0,
+        0x045f9340e631e3f3,
+        0xef8e54ae873041fd,
+        0x7e0b5143333808b7,
+        0x6cc412dcc06c80b9,
+        0xb7ae8556237b0faa,
+        0xf211d2df07b1f891,
+        0x1d388ba3cda1c806,
+        0x2c5c7e21701450ed,
+        0x079b8a34f4c42833,
+        0xa0c615dd7aa679f8,
+        0x3787ca01e9152c76,
+        0x450b41b2ad00b05b,
+        0x9dde0b8ba73dd09b,
+        0xfb8000ad8270e86e,
+        0x7cd10bcb569b2ea5,
+        0x6c2d8d7fa837df14,
+        0xdf9d5f25a783f1d7,
+        0x9bd51604d6843c3f,
+        0xfa67502846ab9d91,
+        0x0ae299e20792079c,
+        0x6b13cc2fb29bc0f6,
+        0x1adafa07846a8cce,
+        0x777098014d885105,
+        0x47af6874784654a7,
+        0x6454476370499db3,
+        0xcae3b5ab38b05011,
+        0xfc92b9ae691350f1,
+        0x1e66e16e28dfaef5,
+        0x4f2dfb5df0610286,
+        0x9cf3f1453c53ecb9,
+        0x05864810ec5a6afc,
+        0x75718ebaaa70c26d,
+        0x634e38a9c21f27b8,
+        0x1863500bc6ac8b3e,
+        0x0d4c0ea410507013,
+        0x0071957779b87a99,
+        0xfc8e4242bda7499e,
+        0xcf11f23fd25c65a7,
+        0x0cf4d1c90e292559,
+        0x7f25d86e3431fac4,
+        0xd084e341c1007b2d,
+        0xce5988c0b02c6503,
+        0x46a908156b095915,
+        0x452518bcef26d09b,
+        0x70f32a34529d53bd,
+        0xa0c5392505b1d878,
+        0x85495ba2d10e5241,
+        0x2d1f7999603d54d7,
+        0xc631f8461a2d7046,
+        0x1631e5eceb1ba2f7,
+        0xf76189606a4bc179,
+        0x2bed5dd12b95e3d1,
+        0xab4b4e5f5fe63f36,
+        0xd9c2ab40ca60482f,
+        0xeff9c2ef2db311ed,
+        0x396fe55085e2464d,
+        0xafb4ce74bcc654fc,
+        0xbb01bb5944c14256,
+        0x60595525a7f96b9f,
+        0x11f6328199c9be84,
+        0x588782f459630f23,
+        0x064d1c496fe8bd18,
+        0xc5c72a51e8a1c671,
+        0x2e930ead49bce1f7,
+        0xc6e8f32dfa59d935,
+        0x7e5018cf17ee2e0f,
+        0x305fe344ef95dc0a,
+        0x7ce6a534815d686c,
+        0x7e5c385c18c649ac,
+        0x69d464b2b9bd8210,
+        0xfc4ea373669d67b5,
+        0x6eb768f10ff1b7c6,
+        0xf77e58815358f451,
+        0xdf133bc81d03b3cf,
+        0xcad9d5c938a27884,
+        0x33bcb88dfc137728,
+        0x24a8690e9fced2a2,
+        0x804dfa8e60e7c78d,
+        0x862d41273e63eaa1,
+        0x03d19c247ed7e394,
+        0xd32f5ab1d6ee6a3c,
+        0x8a921ff4b6c9a581,
+        0x3746e6664a5db1eb,
+        0x31fc88e6fe7d78f7,
+    ],
+    // 0B..15B << 192
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xb44c5f6bb1cbfb3f,
+        0x81cdcc3c0a68601c,
+        0xed062e4a3ea2077a,
+        0x25db749900c6a77a,
+        0x8bd4c3005cd57820,
+        0x1aefa13961a73893,
+        0x6728ededc9330e3a,
+        0x669377a0598a9a9d,
+        0x7a3dc26f0cfe6f72,
+        0x67988b16e89d8b21,
+        0x9056450bce794444,
+        0x6a2e42c781552d22,
+        0x8a4a0f69d8ae0ef2,
+        0xfb10222240f6ceef,
+        0x0e5260686ed10f86,
+        0x13c43dabbd238bdf,
+        0x48b244cab7ef11b6,
+        0xd8af5325e00cfd2d,
+        0xaa62899074006ad2,
+        0x13b110e5e370ffdc,
+        0x62bd5b0746c56fdf,
+        0x51245a1ccdeeb888,
+        0x1b7c34d809ac5295,
+        0x41084279d9c0b250,
+        0x83ac18bb3ad08f2e,
+        0xe5533a9ab4f0d249,
+        0x787f8ae299663c1e,
+        0x462e030a0488aa0b,
+        0xe3676b832a863408,
+        0x20cc423c1f33e37b,
+        0xb69e16fcdb6736ab,
+        0x200719ddfc43e6f9,
+        0xc48344fc18e1f7ee,
+        0x3df3c0b6675e4c16,
+        0x887cf53a7df66f5c,
+        0x2e14283f8202f80f,
+        0x0f930df64b77d9ca,
+        0x087163b7b2aa4f2c,
+        0xab031e96d1b48651,
+        0x31db9c3547ff9c98,
+        0x44cabaae34b0a004,
+        0xfce5c8374dbb3e9c,
+        0x3b4ab2611cc02fcc,
+        0x36fa57575c69726a,
+        0x4c007191da88177b,
+        0x23862b5b79e6cfe3,
+        0x616e1b6fcfe05960,
+        0x2ba31014f0075f3e,
+        0x71dd7dacc0bc255a,
+        0x33e644abd699037f,
+        0x7ef4b30d5aadbf2f,
+        0x1c02e86587b0c842,
+        0x374e2d2baef5e42f,
+        0xbffcee1c7dcbddd4,
+        0x435ec4f48d0feb63,
+        0x2a39aff3f9684ca4,
+        0x2ad8d4ad988c1072,
+        0x0b06c098d0e529a1,
+        0x71b3d34715395691,
+        0x04f411ba65555f54,
+        0xa3da76eafb4a9da7,
+        0x9851581f31a18846,
+        0xade327b2b190339b,
+        0x3a6ccabc331cf143,
+        0xf7a769144ecafb73,
+        0x3c0557cefa18438e,
+        0xdd8445e63bba7b9a,
+        0x4dc86b52a4dad7fe,
+        0x4008ef4aa08c9394,
+        0x3839d4b2f034b88a,
+        0x2735bdcbffe8cc99,
+        0x1bc08b44fced8b06,
+        0xa5d3a35f3f25be63,
+        0xafd3682536368d83,
+        0x3f6f8a6d1a83de8c,
+        0x0e9d13e204413158,
+        0x16932c3f52b03284,
+        0x70b479abdd52fb25,
+        0x4172a8b42d9242ef,
+        0x0078c618840942fd,
+        0xbb82b533a9448575,
+        0xc2588b7e0ff681a7,
+        0x0c568e71b03a0f7e,
+        0x521050f1cf730a8f,
+        0x4ebce012243ddb84,
+        0x4b0ee84250d1bf4b,
+        0x2df8fa00723181c1,
+        0x71977db586f222aa,
+        0x9d9fc83f46fe6634,
+        0x650f12be3ae56ee5,
+        0x698b2a0786b2a158,
+        0x2e80c694dbc0e9e0,
+        0x079e37ff861c3846,
+        0x0ccabdbf98454148,
+        0x2baacf543e1e62d7,
+        0x7692c426f0d3ff50,
+        0x633546bb0f0f81f8,
+        0xfd4383e885e18f5d,
+        0xb10d85434befbcf7,
+        0x58c6bf743a409649,
+        0x0a42d16c8e440ba0,
+        0xcabc51486e377849,
+        0xfa734737d2efa7e7,
+        0x547dbe79dea45ca4,
+        0x9f16534af8bf600e,
+        0x8d8d642a4917f8b0,
+        0x50de31f6e49a651a,
+        0x16678ffb8b118616,
+        0x5620b5a29f431948,
+        0x99776fdd4dd15580,
+        0x6b7402999a66d359,
+        0x0a3a46e345f514ab,
+        0xd7387ddd29a7092a,
+        0xcada49b781b3a76a,
+        0x6c29a8d3a53e5000,
+        0x7ba4714dea49b43e,
+        0x57666c0b534ef12e,
+        0x314aea708bdc9191,
+        0x22f764104cb1a560,
+        0x62144a9da0fa79d4,
+        0xb1c7c66807807a56,
+        0xba8583bb37c795ac,
+        0x58825f1dcb37f5ab,
+        0x25be1d4a5f3f12f0,
+        0x518950fa6d33bfd5,
+        0x0b02c374998f6063,
+        0x9dfa3679cce5b5b4,
+        0x1cf92e1aeb5573e0,
+        0xa8300ff46ec02ac8,
+        0x2f768f00da97bd40,
+        0x433da11fd7573684,
+        0x33c6e2310aa11427,
+        0x112fb38b241f1fb5,
+        0x2b77e76f3af035a6,
+        0xa3119829ab57761a,
+        0x1fab5c5f30628237,
+        0xfcf8a1f2521342ba,
+        0x3ac48f4c0261d0d0,
+        0x514c52a4330e1f8d,
+        0x0ed930bb5963e80a,
+        0x995c7c8b7ab5d482,
+        0x92a8b3e6e41ec1af,
+        0x08999b2caca27838,
+        0x1ae82214a89a00dd,
+        0xf676db477c656246,
+        0xb6a0e3cdf60eedfd,
+        0x2c6874fd85549a4c,
+        0x7be82335c42cfada,
+        0xddb4d1f028c64f2a,
+        0xab3986ba27b490c8,
+        0x9c5319800b8d50e8,
+        0x348e101147da7656,
+        0xa5c8ab90484b7f23,
+        0x99f764919b83fae5,
+        0x786cde45567ff871,
+        0x71a97631d5957dab,
+        0x2a19339a76dae615,
+        0xcb6a6866bcf0af11,
+        0xd61daae92ada8ca8,
+        0x38b2ab6fd09a1b9d,
+        0xaf3c9bcd2587048e,
+        0xfcc31cbedc51b218,
+        0xd6ef958c6e63b6ea,
+        0x03ad4247096be210,
+        0x53df68c36bc25455,
+        0x8122473cba0241ec,
+        0x92ee7621b408ca9f,
+        0x505748ec923a9f92,
+        0xf2dff9ef35c5076e,
+        0xf719e8da4967b545,
+        0xefab497c18ccede0,
+        0x31a9bf6ad2515392,
+        0x77be9e18b557df1b,
+        0x8d583c2f6f02199f,
+        0x3bfb29ae180a1622,
+        0x23383d4180fb8ce4,
+        0xba908c66a2b652dd,
+        0x88fe163b98cc398d,
+        0x35d46b96a752aa4b,
+        0x13fa764c0bd5ec94,
+    ],
+    // 0B..15B << 196
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x46315aca25fef758,
+        0xbdf11f981324e30f,
+        0x42184f671898b4aa,
+        0x2b6e680f807b029a,
+        0xf7076510c614e55e,
+        0xdd3cbfc676f865a5,
+        0xfbcdbe597fbf0858,
+        0x34cf9f4e3e73e01e,
+        0x175909803fa52d28,
+        0x33a2156c71f0a18a,
+        0x89424c713d72eef0,
+        0x63f9707b53c51971,
+        0xf4761c84d1e23644,
+        0x21ee98b4e5dcf215,
+        0xe708d89b8790d329,
+        0x255b06a509d083e8,
+        0x3dfa389f266f72fd,
+        0x19c29dab6a31859f,
+        0x8a92afbc894f3c2a,
+        0x341b654b751612fb,
+        0x111bd3d88f164e96,
+        0x3449ba6d9cd67017,
+        0x0ce11bad90b117c6,
+        0x473bedba50a7f8cd,
+        0xb9d22ff43ffcab39,
+        0x430c8a4644a50a05,
+        0xda5e8cb829202fe5,
+        0x1f49dd9cb8c0ace6,
+        0x966802cdbc6d4845,
+        0x1379d97c62677abe,
+        0x30cbb6d81e8954e5,
+        0x6dacebe87212dedd,
+        0xf3e8766419eb2ec6,
+        0x914c364e09b6a6eb,
+        0xd642f4318d175774,
+        0x399c580355d3f3e5,
+        0x7a994ff4e24bfba0,
+        0xe4b42e2e80a6261a,
+        0x556cbc3f5f48aca8,
+        0x67e1f1ce514181e3,
+        0x065349feaa7a800a,
+        0xcd7a007bbafe0360,
+        0xe318148da476d6c3,
+        0x72bc75fba347dd96,
+        0x4a25239563e457b7,
+        0xe56768cc9ff5e774,
+        0xab04f58e60fdf5f1,
+        0x16f90194f6715789,
+        0x9c492a1e824725b2,
+        0xb28ff6155dbdb659,
+        0xe4438481c57bd4af,
+        0x0036847b3f055dfb,
+        0x651304d3741354b3,
+        0x9576ab3d36c0c29e,
+        0x7705a972d10893ae,
+        0x06d295b80dd5ab24,
+        0xf24737310a43c82e,
+        0x0cbfd4cf0851d24b,
+        0xe21de3c40bea13b9,
+        0x075dab7fe0964e66,
+        0x706e7185d3a93a2f,
+        0xbb76153cefff3e20,
+        0xcbf1d29e3194e1db,
+        0x261296964d9f615b,
+        0xe7d8198bc265c9ce,
+        0x7f52c1645efa508b,
+        0x9747fa2fc44028fa,
+        0x74ebc2cad5fe4652,
+        0x94bbd5e1c8aa4700,
+        0xd26ef6d0c3fdf257,
+        0xa6f23089797e9994,
+        0x2379d16fdb3c5739,
+        0x273e8e38ba3d1aa0,
+        0xfe3f879d4c0ee417,
+        0x44a7ffb59bb3b41d,
+        0x46a171d85c5fe2e4,
+        0xe81f91e11653b12e,
+        0x0ccaf7072e82f634,
+        0x2425e53cedcbb362,
+        0x76c5edf8b492b915,
+        0x8217691bb287cd31,
+        0xa69c9913e1210095,
+        0x5c2d2953bcd92e53,
+        0x63baa66860aa0b1a,
+        0x2f6e9cc71e8b1c8e,
+        0x0936606ad8028d54,
+        0x8f787f82c553ac5d,
+        0x30326d35e8c5815a,
+        0xd484d92525dffe2e,
+        0xe9783fd36a23012f,
+        0x805c115ee5f50a1a,
+        0x082aa2ecc6a277ae,
+        0xe6bb63d385537470,
+        0x166077e47c58cf2c,
+        0xb383bb2c2747b62d,
+        0x4e48faa9dc523059,
+        0xe2f32a42f7185c24,
+        0x4a995455a45c93a0,
+        0x3271fb61a05b321d,
+        0x786247fc5c28b084,
+        0x68a1cb8673debe62,
+        0x79d84c0737b743f3,
+        0x7ab4d978be96807b,
+        0x1d9671fbfc2d3d6b,
+        0xd9b4af66f33bffdf,
+        0xaf02f939196bafae,
+        0x32ef5fd9088f98c7,
+        0x7ef6552bf03875f6,
+        0xa99d4833dc003f6a,
+        0xa736428c7569e561,
+        0x2048cad55f77f952,
+        0x1719fe2558eda015,
+        0x1add1ed1087cf25d,
+        0xc6ba103244b59027,
+        0x83eb3a5be05f6813,
+        0x2f97e46a5220bec4,
+        0xb81f52ac3deee1c4,
+        0x3af83148671b2e2d,
+        0x1e94421f46e3e90d,
+        0x09ff5ed0f11bfac2,
+        0xc81055669bb473a2,
+        0xb80d4a3c71a18e2c,
+        0x59eb4e5eb72b9862,
+        0x38d090c0d3e48470,
+        0xadacbb8e072d6513,
+        0x32831384a3d9e651,
+        0x93e6b86e3def5729,
+        0x312cc55e01817d22,
+        0x3fa0bbff6564d657,
+        0xd8b263dcb1565be4,
+        0x38507c4cedf205ce,
+        0x3b57265daba2c6f6,
+        0x4e773f65eab3c9f5,
+        0x10899bf8faa0a321,
+        0x0674f4a516566489,
+        0x5c4e08a45aace2f2,
+        0xdc38ca0b6ddbad88,
+        0x12137719bc15acfe,
+        0x3115995892466143,
+        0x511369abeea1d6a5,
+        0xec7bf628edfb7bbc,
+        0xb97b766b31e2fef2,
+        0x45bafafeeafc9b5b,
+        0x0499171ca5861b15,
+        0x4806ab56820685a6,
+        0xfcfcfe14720a8dbc,
+        0x43878929b0568cca,
+        0x1546ad7c9de85944,
+        0x09a6ceef46cd2471,
+        0x38678d1769bbf8f1,
+        0xd9c9182e797e064d,
+        0x437dd5185c6c81e6,
+        0xdd75ceb2548facab,
+        0x8b740de015f0d203,
+        0x119d27efc9f7569b,
+        0x650bdfb521b343d7,
+        0x0b1738e041b2c53d,
+        0xbbdc37a6a9b53cb2,
+        0x7ab8d91f8e627442,
+        0x3612e7294250a9e2,
+        0x8f486c65cebc9a36,
+        0xcbd461eb25394b9c,
+        0x98f065e987754589,
+        0x048fae8af924344c,
+        0x61a2a739b22c0ee9,
+        0xe628bf2eede6166d,
+        0xf6b6a3492cdad681,
+        0x57ec6d4957c9dea0,
+        0x53be328d8a5a89c0,
+        0xb6f97d9f8bdd75f3,
+        0x004050c550faa70d,
+        0x47bd3971f29b1264,
+        0x1a8aca8563cae648,
+        0xdc2937eee18fe6a5,
+        0x62c0b00840c0c700,
+        0x3bbda06422a72eb0,
+        0x3f82ac74673b8c44,
+        0x2668a21d8fb8a13e,
+        0x7d7101eb71aaa49c,
+        0x0db12563d59bbe5a,
+    ],
+    // 0B..15B << 200
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xec98541ad63e640d,
+        0x6897628bce2eac29,
+        0xda6ef7328ca5b1b9,
+        0x79135699a4598047,
+        0x43c99ced5b85d621,
+        0xaf5e652c9c334822,
+        0x4f9445536e6a422e,
+        0x6320f3c79a4fa080,
+        0x299e52a93385585c,
+        0xd95dec09f1ffb637,
+        0x58b8b103e990b837,
+        0x6d72e0477d6201e5,
+        0xe9559aa65c86be31,
+        0x11360e7aef2d2f38,
+        0x9a3cf6301c895b67,
+        0x0d2505ee8c958179,
+        0x272fcc8040696f15,
+        0xb50d834bb099e08a,
+        0xd1781c71dacc2804,
+        0x49bd9632b4f30f29,
+        0x924503070ffb8519,
+        0x0f4bf00f79889a08,
+        0x6db16506fb45438e,
+        0x3aae0abe0bbcbb2a,
+        0xdd1ea707fd2e3957,
+        0x7d5b59b1f73865ea,
+        0x05b35884578725d3,
+        0x680b23d32123997a,
+        0x59055a37863bae7b,
+        0x53976abfb9cdeec4,
+        0x48f4179dc61ab850,
+        0x34c12c52f41ee30b,
+        0xf5207d89085358af,
+        0x401fb096f1013c28,
+        0x5357b23782789e0e,
+        0x6c178e4472b92f73,
+        0xfb93d91945863a98,
+        0x7eb4479955978b7a,
+        0x5cfde6cd1077b56b,
+        0x1bcda8be9dc059bc,
+        0xa2d7d92c7a874a06,
+        0x43b2dad11c5ff677,
+        0xff8b98fb4675dd5f,
+        0x6b8fec6d8e50acfd,
+        0x00aca23987c2763a,
+        0x473e78ef69146d32,
+        0x7097665adb662327,
+        0x28ecb91cc3d3ff94,
+        0xdeeb7a5962959f8c,
+        0x50def6134af06b8c,
+        0x77a9168158ab20f3,
+        0x39c8cc6e3f206437,
+        0x48775a86b7ee5623,
+        0xf1846a8c85574ea2,
+        0x260b0d50ed84fd13,
+        0x1f6ffd24f5f81cd6,
+        0xb97443d7fa0b7750,
+        0x5c4c042ebb0fce96,
+        0xa89acab67d34f47a,
+        0x60e1aaca57ebe49d,
+        0x061e6976d8626a84,
+        0x569ee1c81ba88839,
+        0x5dd7526ea8ab43e3,
+        0x6b30b6c9dae43f87,
+        0xa99839d10a5df250,
+        0x4d31342ac0faf015,
+        0xfbe4130de712cb6f,
+        0x6a16ffb080ac7d09,
+        0x42e6790787a90c35,
+        0x40ea12be35f09a91,
+        0x5caad2dd2b03f938,
+        0x24d2458bb28fb090,
+        0x5b3b1d0575a60818,
+        0xfdd29dd290b261b9,
+        0x09286616bac39b3d,
+        0x11531e7a76220d73,
+        0x5aaab0b78065dc73,
+        0x123f7b0592884845,
+        0x76084d14c011ea23,
+        0x11afcedfa273dafd,
+        0x5cd169b9566e3463,
+        0x27d4bc7bb29ac905,
+        0x98d74ffc70bdb95a,
+        0x2f2287b4dc31f948,
+        0xad0ff81e150a034c,
+        0x387c07d01766b093,
+        0x75ae3fcb6b37f10a,
+        0x7b8f19f16d2d709d,
+        0x2140023a69246c05,
+        0xe493f09034ee0928,
+        0x4b69cefb85ed58d0,
+        0x615af9cf25442c45,
+        0x0b6ab607e37367b2,
+        0x26971781fcc05ca2,
+        0x6c10320f703416b9,
+        0x2e9ee3ed628ddba8,
+        0x49a9bd5122db8025,
+        0xe76a63629e9d97ce,
+        0xab59e784a20f02a9,
+        0x59d3e3835454fc0f,
+        0x47d873e7f22706ef,
+        0x0b7c45b3d0f750e9,
+        0x07a06ce1d0de4eda,
+        0x74d5c803df293c23,
+        0x9234c5a2fd3d3ff0,
+        0x01ea9c441d727c74,
+        0x7b6cd366b9a1c2a0,
+        0x75eff8000f5bda00,
+        0xe469c19edc24a109,
+        0xc14e8d2d95aa70eb,
+        0x9fb3ceede664d31d,
+        0x1c1fb449870aece9,
+        0x0e863fdbea1296e0,
+        0x8a6752e78cdf484e,
+        0x4cf92c61c4e2f298,
+        0x0e8792b4ebaed85e,
+        0x722f7fb40f96bfb1,
+        0x032361bb346fb1f0,
+        0xd778370286e48898,
+        0x09b939d5967a227e,
+        0xaffb409998816118,
+        0x528684ef72d47d0b,
+        0x955183b6e3fdf457,
+        0x1675ad293e336ca7,
+        0xa1d48d725bb4a38d,
+        0x29502a8858e3157e,
+        0xd57d43df111b4e2e,
+        0x3e1c1019692a664f,
+        0xdcdd801f1122052e,
+        0x9ef5e73e355d6272,
+        0x70c15558bae919b9,
+        0x36ea1f89ecbc3e49,
+        0x973a107ed17dcaa6,
+        0x7e7c56e1169c8e54,
+        0x2811627e2e08dd89,
+        0x028bb2eeac7a5847,
+        0x5095e3364f48f1bd,
+        0x2f9fe10dfbf43462,
+        0x9256b02e81dd32ec,
+        0x535323df77c180d5,
+        0x3584e125880baeef,
+        0x0349c3fe3cdf9741,
+        0xb6972586a4cdd77b,
+        0x5b77d00e9e48762e,
+        0xcb02e22f1d4d973d,
+        0x6210553a2728ce9c,
+        0xd3e0ab6a991f368a,
+        0x55194ed97baf0b56,
+        0x2804f24cf653d97a,
+        0x62fae2f0e3667d6d,
+        0x0eed6eb4db042b60,
+        0x5a243a5b81b1333b,
+        0x1dd36b04de549369,
+        0x9a02718b852b80a3,
+        0xbd66d43b83a4adaf,
+        0x1d9dad0c0433ef06,
+        0x3e346eb4d3b6eec3,
+        0xc821be1b8e5f32e7,
+        0x515d589a53c28c80,
+        0x07b6b125136d1299,
+        0xfd800f1cebdef010,
+        0xeeb187fec19521e4,
+        0x0e0e27f0ebecb404,
+        0x316b5e16f8455020,
+        0xd0bc2ad8ac21aedb,
+        0xa2d1c8af0497f53a,
+        0xed6329440d189653,
+        0x1888f112ac7a8e71,
+        0x81425e7211ab7ea6,
+        0x50fdb48f96c2b4ac,
+        0x31652dc8561af204,
+        0x27660fe8e80b0385,
+        0x93a08f20d2c99ee3,
+        0xaf3fe8ce2ec8ffd5,
+        0xced2ada0cc98ebb4,
+        0x25855b4103051191,
+        0xc430fdc411ef3bad,
+        0xcf7ec1775e00bb08,
+        0x07fb2660239990ca,
+        0x4aa6956f00053ef9,
+    ],
+    // 0B..15B << 204
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x531f78cc0b03fee1,
+        0x66f643faab252717,
+        0x55d3a277440fb4bd,
+        0x3a4ae1a279c2bfda,
+        0x65f87f87df554a84,
+        0x6747df5ee63cdc63,
+        0x1b5118457d42a6fa,
+        0x71489e8b21442597,
+        0xee0d7a75b28b2cf3,
+        0x28a0a47b198fff8d,
+        0xc5cbd4188e5304e2,
+        0x2c2dc854ff556078,
+        0xe56d173dcbf0e1e8,
+        0x0d1914e3f3d1e2bf,
+        0x1a359c27e347da19,
+        0x6054d027ffa435a2,
+        0xa42b174dffa876f4,
+        0x8eb271f5f9fe7e3d,
+        0xd31d4e2adcaee3c4,
+        0x6eb56bbfccc4974f,
+        0x126653c08dd24504,
+        0x0d4175cecc632a80,
+        0xb3414bf3b121321c,
+        0x00c159a3b16d4087,
+        0xb96b86f1025f0f24,
+        0x47ed60888a914d8f,
+        0x23eaffb075f3658e,
+        0x7f456e777b9ba6f5,
+        0x98489b7c4654e2da,
+        0x65260b190e871d99,
+        0xf5fa1bf616ce60d0,
+        0x09f9feaa900973d2,
+        0xa92ad409c6d70554,
+        0x63ac04510612804c,
+        0x4e9b58ae16793ea1,
+        0x0bee786a0896a37a,
+        0x806ae3f7bd6c210a,
+        0x1d0fb245bd73c310,
+        0x802b2a710c053662,
+        0x29597bfa728fcaa2,
+        0xd8b9bf4c48a6f7a0,
+        0x42db2eaea79898b8,
+        0x95bd25afe4bd8cbe,
+        0x0e426c87cf4ee869,
+        0x2f2dd5bdfb225eaf,
+        0x6dde28e3d100bffe,
+        0x14122a537b5e4170,
+        0x569b276280f6a6f6,
+        0x5b37041b664493af,
+        0x569726c3de733adb,
+        0x9db098c5bb5f3928,
+        0x6af450c0f42cfbc5,
+        0xbfcf5186c2110a29,
+        0xc854fc5f1c610932,
+        0xafa435da170599aa,
+        0x0df3dd882ef57e37,
+        0x5a5e4241c3501d4e,
+        0x9c7c93c7c7e1bc07,
+        0xe567b385c18074c1,
+        0x4c084cf1cd0d064e,
+        0x96be57dc6889af47,
+        0x85275b928f3295ca,
+        0x42f07a08c25abe95,
+        0x0055dd64f5d7f724,
+        0xecf1e70ea08c680e,
+        0xc4a3b5754d9e349a,
+        0x914ed4a96979e567,
+        0x54ea48c0edf2e348,
+        0xb3c9bc9a88083917,
+        0xbbdc63d9d606b3c0,
+        0x5dda74abda446924,
+        0x3ec769a8feb9f40a,
+        0xfee2e103f5f6f1a9,
+        0x1823d958b21a40d7,
+        0x88432a369eee1eb8,
+        0x495764557da97dd8,
+        0xf07ec0a2958e3e95,
+        0x3c8bb8203a1e738c,
+        0x96e40eb5ecba520f,
+        0x2acfa652253be34a,
+        0x9cacc9b917443cad,
+        0x06be2128052828e4,
+        0x04bfbefc62a7c2d4,
+        0x048055c0a2adf11a,
+        0xbf47520da35da414,
+        0x22223689b64246bc,
+        0x147be76c3e632536,
+        0x5fa46b9324af7166,
+        0x01cbc366aded140f,
+        0xd6853ecd12869507,
+        0xbed0ae4d946ca9da,
+        0x73d973ec8296d18c,
+        0xca76a511a01fdd87,
+        0xe7c16b0c60897049,
+        0x6df91be1c129ad96,
+        0x75b6a850b1d1488a,
+        0x56da1b9c75627c1e,
+        0x5c4fdd4909d7fa41,
+        0x459a607220ad8b7b,
+        0x184ff4d01702b5f9,
+        0xac4beefd8d5ce96d,
+        0xf5044ad2b16c1eac,
+        0x0b5acdca8c7b70d6,
+        0x73913794eec136f6,
+        0x8faf1643d796fbc3,
+        0x5f68d0a4c480d898,
+        0x9621a5b1e11fbc54,
+        0x7c707fcc83716f88,
+        0x830cf4f47931e57f,
+        0x7d849ec6e4e1ce1c,
+        0x653e4eee2943c7cc,
+        0x6d0e5fc72c821165,
+        0xd590585f16650347,
+        0xeec78f579b4845af,
+        0xe5a69a466028b8bd,
+        0x3db9db35ae87cf6f,
+        0xb728c0bad1d82443,
+        0xff5991e0eadba90c,
+        0xd8fd71f8f9c10e2d,
+        0x21a5ebfe11bfa1df,
+        0x8f8a1d9fc6923e38,
+        0x23d31ba9e012ccaa,
+        0x9e14413924156872,
+        0x295a50bb6309640e,
+        0xb9bde8ab507ba86f,
+        0x0faad614357869c2,
+        0x79423ccae25a0d53,
+        0x524637706befd784,
+        0x4df275ba19bd2635,
+        0x67a209db29f57871,
+        0x99290b246d7101fe,
+        0x6cb9bd306207b68c,
+        0x205edc2680b91859,
+        0x133071c0b729d3b3,
+        0xf1c8570cb09154ed,
+        0x01c9ed0e3a614b51,
+        0x98806ff4c4e65a01,
+        0x91c1252ffa317900,
+        0x3463c182441739f7,
+        0x0edb4e29e970fd52,
+        0xfc0dcf961e5ffec4,
+        0x0ca5f6c5f5046131,
+        0x5972f0562b061e81,
+        0x64b2e3a57d78db5d,
+        0xaa5f72cbbcd7c3b0,
+        0xab9b73bcd325cb1b,
+        0xc9352a1dee091d06,
+        0x6a9b2bd76813e5ae,
+        0x570afc6401ee30eb,
+        0x84bc0a70953ab0e0,
+        0x310008ddb49fe5d8,
+        0x1ed42b473a6685e0,
+        0xbcd08634a987da9a,
+        0xe85b9b86026ff07c,
+        0xd4ee3d82c05d8084,
+        0x3ea8c0aa62e8d9c8,
+        0xdcfe9677c68db1ae,
+        0x6121b3b94aa3a919,
+        0xa556751c4868fe7f,
+        0x5eac6bce2e96304f,
+        0x08925d640ce0c4bf,
+        0x6d9e816fc04f4b19,
+        0x8dd00f2ce914ac24,
+        0x76f96373160e5bb8,
+        0xb4be082b053f0686,
+        0x24f26f6fc6e481e1,
+        0x1b35b54c70054a6b,
+        0x658f7b7de12a0480,
+        0xae9a749f813be9e9,
+        0x185bcf049716ec97,
+        0x7f88c89d476355d5,
+        0x1991de9569642460,
+        0x8c9e530c7930563c,
+        0xf182a3747429f4de,
+        0x943ddfaf397ad261,
+        0x77a7fcdf5e7ec5b1,
+        0xd7e72bebea36cf06,
+        0x08737688b7435096,
+        0xb461c5e0d9269074,
+        0x319448483c665753,
+    ],
+    // 0B..15B << 208
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2b490211e05fc70c,
+        0x38b7b741032a610a,
+        0x09438f975dc87a3c,
+        0x166f4a70176ce318,
+        0xfcaa5f14a2114bbb,
+        0x892e4bf6cefcd404,
+        0x5f0127adc4e6211e,
+        0x6657751188b2a1e2,
+        0x6713ac2bb027d33b,
+        0x97ada71ffcde4f02,
+        0x7e0f74f3acfcd9e8,
+        0x04eebaff1ffdef60,
+        0xc6380c0cf579351b,
+        0x67a7a3e6c7a84f81,
+        0xc4f4d88a6ce51779,
+        0x074b8ed2d0f662b6,
+        0xc342de03fa878f84,
+        0x5a1df667d1b18bd0,
+        0x7e097f0ca757088f,
+        0x6a523a1aa3f40e6e,
+        0xfb478275670c40ee,
+        0x853dcf613d044304,
+        0x24c96a9b20276adc,
+        0x1191dd96e43f14aa,
+        0x1cc8a7e92bab23d0,
+        0xc78430eed5c33363,
+        0x7e239ebc38261b93,
+        0x1af290659a6357d6,
+        0xfc9ea78fc34bc281,
+        0x3ac58aff8dcd07f4,
+        0x09d6de3819882f6b,
+        0x6f21d32dfb92f09a,
+        0xb335bb43d1e2c3bb,
+        0x706419a7fa72c95a,
+        0xed578a8cc22e59bd,
+        0x094a2981287d3ff2,
+        0xba5f328e50349bfe,
+        0x72447e06918ab8b9,
+        0xb54d74cfa4dc8b8d,
+        0x444244f4c3ec1120,
+        0xb5568d6b3b339302,
+        0x515949a4624845d1,
+        0xafd72041111f02e2,
+        0x70a9382ad7a70596,
+        0xce33dd694f7475a7,
+        0x1edc4dbb0d44525b,
+        0x3a3d792ea2c7a4eb,
+        0x38c4dfe93f5f67aa,
+        0x5b7b90a92fdbb20d,
+        0xd5b0d92805a99eb8,
+        0xfb74cebb08ce1c04,
+        0x296eb200a08f3a10,
+        0x9fd1e53951283add,
+        0x954fab35f49bc00e,
+        0xc6646a825a254ba5,
+        0x277c4c3aad390905,
+        0xc87b94400a24daa7,
+        0x6465118133c26c0c,
+        0x7d4df19a0e30eb16,
+        0x5d8cb42968b4a8d3,
+        0x99c66773eed35290,
+        0xcb461b4db7b31004,
+        0xd373d88791ba5521,
+        0x29ce329f14a35235,
+        0xbc8b4b59e6796952,
+        0x60d18c2a704076aa,
+        0xbf08435f9704330b,
+        0x16e2ab4f932ad6b6,
+        0x622c274a8ccf7e69,
+        0x3b66a293863cfcb5,
+        0x6d14b2187f64262f,
+        0x609453e94f8d8d36,
+        0x1410338d7c273a5f,
+        0x17c4bbcfbdb3e41b,
+        0xe0f596609acfcfb9,
+        0x06b4cc6b7f368750,
+        0xa237108879005790,
+        0x0e26376db3789123,
+        0xf925a3897f102a50,
+        0x655557f8b874b62e,
+        0xa5b8d4fcbd56a130,
+        0x04f53f6ed4def9d1,
+        0x66de976f7f81573f,
+        0x61fb465945b8f0ff,
+        0x92bd4422b2f29216,
+        0x49def86a23a3a561,
+        0x11bb69beca6e7e57,
+        0x2d29b8111985e8dc,
+        0x5616b0722c13f768,
+        0xecd94f1209d744e0,
+        0xcda2c74316514438,
+        0x5460909e6f109b0a,
+        0x74ea5fb00f92d1b1,
+        0xdb13226812eca667,
+        0x2b9e98279ec1d0dc,
+        0x308fb27b9bd2bed2,
+        0x40be3870790de818,
+        0x2c9108cce5ee818d,
+        0xe8558e89f99f09e6,
+        0x2bb48d07076c826f,
+        0xb814a2c1cdaa6390,
+        0xe712e976b486e2c1,
+        0x32e11d1d27431ea6,
+        0x6f16bd9a9ef6577f,
+        0x7a65e2a8c46c089f,
+        0x2a5d7cfe4182a2fc,
+        0x251f8456298bb18a,
+        0x1aef2dcfb3bc6eea,
+        0xbe90642f3c945e9c,
+        0x867d81fbb18a9f62,
+        0x3828069085cb17b3,
+        0x735f36d53ab7ace2,
+        0x84033fa6eb827ecf,
+        0xec780a8f128b11ee,
+        0x902b1696c82c50a5,
+        0x1056c766c378ea36,
+        0x5524513d4904303c,
+        0xad98ed8628cdcd3c,
+        0xe0a7698605b11ee8,
+        0x148ec85e2b90c42b,
+        0xdab3ae7c24268a64,
+        0xb75d06dcfe534ad9,
+        0xdfa31edaed4a5e99,
+        0x63fb6f21c01c5b73,
+        0x4a67634124cf6f53,
+        0x24cf9b505c332186,
+        0x19f718c8bc23a211,
+        0x61d5f98aca406ecc,
+        0xcc87e6df9f850be1,
+        0x694c1afe56414823,
+        0xc6c6a111743e6a2f,
+        0x266d69255e030205,
+        0xa9f8fc3fcfcdf93c,
+        0xb14143ee96a2d9d7,
+        0x335d6abdd9b1b99b,
+        0x654d23f28ddcdb85,
+        0x019786ad07ed902e,
+        0x472e6927e6f8ac40,
+        0x8b9b528d91b45c61,
+        0x556d690f1e11e0cd,
+        0x7a3422ced08b7dbb,
+        0x6bde88a2ae5b86e0,
+        0x02176679c96516cc,
+        0x30514a826c9b41c6,
+        0x11f626d8637e015c,
+        0x0eeb4cb41ccd71f2,
+        0x018adff829699685,
+        0x1f936ca221aaf9e7,
+        0x942200aa52f20234,
+        0xe4a7cb47779f2ec0,
+        0x410239c4ae765614,
+        0x5046595732d599b4,
+        0x91d9e491d9916617,
+        0x392f4aacfb528b58,
+        0x78a40be664be3790,
+        0x2ddb2e4c9d614980,
+        0x37583176213728ab,
+        0x645dfdc3431ea240,
+        0xe10534e6e279f3c7,
+        0x5ef87a8127da3277,
+        0xe5ca8d74930a86be,
+        0x21983010c6868b70,
+        0xa3ebd0ba7ed2dd3a,
+        0x4f4e15b62411725f,
+        0x5747f258d452a714,
+        0xbb0d8697acbd84f5,
+        0xcfb058a226be19fd,
+        0x18df50d0cfac6328,
+        0xe69018bd0c72a863,
+        0x915e6f6e85684c4b,
+        0x4caef9644056b991,
+        0x061ec5169e5b600b,
+        0x26501446017b79b6,
+        0x662400f518fb9378,
+        0xd8893fded913ab66,
+        0x61694f1b15622863,
+        0xc3c2e532d33d0b71,
+        0x2673be7aa4c12603,
+        0x8b0aade92d61685c,
+        0x35ff816aa3e65df0,
+    ],
+    // 0B..15B << 212
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xb89deb8eb668618a,
+        0xb83c2c0950df5464,
+        0x6d9764da674d7f73,
+        0x4e77828b98d99d49,
+        0x26aa6ffe28a94fe5,
+        0x09b13195b80f04ff,
+        0x3126df4bd20d5649,
+        0x58c0daeafcfd7bdd,
+        0x84ee2c3867a26d1b,
+        0x587fe87609e4f887,
+        0x6bd15a7b82ff510b,
+        0x44982beda03bf3e8,
+        0x65fc50fb38af8bf9,
+        0x607e6bfcb5bbe466,
+        0x644079fe54341cc9,
+        0x2811b917709a85a6,
+        0x627acda12f8435b3,
+        0x10ad8634a721a0d2,
+        0x7c357b6357bb0528,
+        0x604786c053f4e115,
+        0x39474ef980e6341c,
+        0x03e40d2ae21a76ab,
+        0xe5627cb5f33e605e,
+        0x5c7967e30071a60a,
+        0xcd617eea9f1bde34,
+        0x24cc3cd038921168,
+        0x649d9bc6277cf2c5,
+        0x7f70cc008c1a755b,
+        0x38392103be6ab685,
+        0x5dba5db1ac68cf04,
+        0x5a13eaec828fcd2d,
+        0x1ee99cf4a7037673,
+        0x8fbebfb325fbc47d,
+        0x135458aef7c0aaca,
+        0x73258ecb019e3254,
+        0x3ade10c27d0dd72f,
+        0x2aeabc84ce34c4a5,
+        0xc9c6aed159ac1871,
+        0x487b758df554c4e4,
+        0x4dc7eb01e441f814,
+        0x8af9a0d4beabb6e4,
+        0xd3318abda8d902cf,
+        0x0f91f742c1ae304d,
+        0x03ecf6fabdf64a26,
+        0x71b36e7544e6e3a7,
+        0x31728c06ac0b0743,
+        0x47fbf3d6e35d78cb,
+        0x6c0318bd41e3b370,
+        0x2e6c609fdb269b64,
+        0x0925ae30eea81921,
+        0x64230002f4179353,
+        0x4619cbf52cca50a0,
+        0xa28ef3d4e0b0f5c5,
+        0x600e93e60ba79a55,
+        0xe68a89046edf4e31,
+        0x1d31d6d08582e591,
+        0xdfb7e4cefcb5388d,
+        0x3e40efa11a093978,
+        0x3153923671c08b48,
+        0x6d647fac3673f294,
+        0x89a1033231c79d20,
+        0xd4af43126dc60292,
+        0x3701075e164532cc,
+        0x5d284f24416faf8e,
+        0x4b35e06426613be6,
+        0x03aa2947e1e908a7,
+        0xa093c6c1aae983c6,
+        0x5f24889f0accbbe4,
+        0x56f756073202ab3e,
+        0x09a96a323dc949db,
+        0x893484e42495b16d,
+        0x177148133b00b87e,
+        0xb356a47e753e9823,
+        0xa497e65710edec47,
+        0x11014c25c71c4915,
+        0x4193e0dd1e394d11,
+        0xcffff0481104493a,
+        0x0560f250913c8a77,
+        0x3d34391734f1c272,
+        0x3871748978a3bd30,
+        0xe4b2966ab7c8b801,
+        0x8a9c99b5b9037a78,
+        0x3828a8426241de61,
+        0x2556686223e89a69,
+        0x10d41a9776471a08,
+        0xcffff8d0990e3486,
+        0xbf0d57b26e58cb7c,
+        0x2549f48d01fa8649,
+        0xb557516eacfc4329,
+        0xd0667e01f2646579,
+        0xe35feb2fd1b5ec3f,
+        0x75fe6f0b97f0806d,
+        0x22a6459fb52da73a,
+        0x16263c97a763760f,
+        0xe31845da5968d977,
+        0x798f4d22a9e4bafd,
+        0x7e1ab0c722fb29cc,
+        0x9866be71bf17ba7d,
+        0x3c51a8bc09785f77,
+        0x5b4622da48bd3de2,
+        0xf1113da21efcd3e6,
+        0x7f53164a47167fd7,
+        0xf1a7c86d36bd4f98,
+        0x3f66b95787d83db4,
+        0x759cc8e74f509d46,
+        0xf352505a5bb41367,
+        0xaef744ebe7fd0375,
+        0x1f6525df64b67600,
+        0xfec9741e3b92db97,
+        0xa2a274810ebd9bef,
+        0x8ccddf1128138d2e,
+        0x24b2a275faad7328,
+        0xdc582d7b1943bc3f,
+        0xf19318b4d94301b3,
+        0xbe3ebc823e72b64e,
+        0x22bc95f1aef23cb3,
+        0x5085a570fb1f9378,
+        0xed5ab86f178ad8b1,
+        0xe69a8a77d8d1d672,
+        0x17c54063b411f39b,
+        0x9987be730bc27c58,
+        0x4646582da3130e20,
+        0x132e4d8a19b74a67,
+        0x79470b0ae4cc1ea8,
+        0x503e0dfdfacc211c,
+        0xc3753537cd1579f2,
+        0x78732cdc79657df8,
+        0x1ce833879c66a1d1,
+        0xca1ceaff4c8ad32b,
+        0x8be5d00c0702c5b2,
+        0x05329ca8ca7fb45c,
+        0x1f940caf26a793c2,
+        0xb5cececcbabefb92,
+        0x3e9cf38038f4142d,
+        0xeafc8318a1362cc0,
+        0x29f808b8edcfc356,
+        0xe70de3d7a86c8ac7,
+        0x367e8f9e14e22c07,
+        0xb89cabc35515f9ff,
+        0x4cfa2dff7af4ff8b,
+        0x7de6a4d7c12b3149,
+        0xa29a92f2c7b834b0,
+        0xead8538f53a32ea7,
+        0x5600572e2bee472a,
+        0x1c73a7e7452323c2,
+        0xc5c74753cb4469f5,
+        0x5296dfdcedfe4bd0,
+        0x0db4f0fbcb52f2d4,
+        0x2911e5e1c2449a9e,
+        0x0750a7b3b2542fd5,
+        0x83be8ffdbca56709,
+        0x67812eca17b9f895,
+        0x84f317e2cbda8820,
+        0x16a0409a8496df8b,
+        0x5e685be04ddc7925,
+        0x70e486ce9735c0e5,
+        0x16715688a7c5ef24,
+        0xd6419603a0cbd273,
+        0x830874bbf32ac1a2,
+        0x41e8276dc88ac651,
+        0xe41f6ef7ba5843f4,
+        0x28e9dfdad3d60581,
+        0xb57e35fe05cc17a4,
+        0x7f8c3b14443f758e,
+        0x98c5b563096d7015,
+        0x5aa9f2c1ba4db6df,
+        0xab0e3bf7817391c1,
+        0x6d3405e8f8e2a3e5,
+        0x3730d8172f067247,
+        0xa2b0e99300bb0e7c,
+        0x665d516322986073,
+        0x50cdf412ea73705b,
+        0x0e7f95f5e6530e4a,
+        0xcf1383f753228341,
+        0x2a884979a920e577,
+        0x777cf17f2bc1a93a,
+        0x31b54bbb90935bce,
+        0x575da5a6d2c23cc5,
+        0xc75ae06497be36c8,
+        0x267f183ce9e5db34,
+    ],
+    // 0B..15B << 216
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8c77c8f003693597,
+        0xfcf8babbd0fe2c9e,
+        0x31f57ec2739dd2a9,
+        0x1e813b6d8a9cfbc7,
+        0x3c5b666884071649,
+        0x32d8b779bbc29137,
+        0x84af501ae957723b,
+        0x3bc586b6d84765f9,
+        0x52e5a89ebf88ac6b,
+        0x19a1286ca0b6cbaf,
+        0x765731956a08a3cd,
+        0x3204e461e881d121,
+        0x70c1881f273013e8,
+        0xe4cc82ed0732109e,
+        0xdcf49f59adbf9a92,
+        0x378b31b36adc4810,
+        0x5b0cb40a9b4c9a05,
+        0x7e70e4644dd2a2de,
+        0xdaa8bb4394d3d6c4,
+        0x31eccdf7cd7cc1a8,
+        0xfe68973eb4e9433d,
+        0x6df26b6755c9526e,
+        0xffc8b1c9bcc65d84,
+        0x23dbf2a415efc42c,
+        0xd8bbfbc59deb8a65,
+        0x39f5325ef80b39a7,
+        0x2f7b907c0512f54f,
+        0x339fc60b72ebe075,
+        0x97dbaf51bb8a29db,
+        0xde4d455382128da1,
+        0x217e6c06143d02ad,
+        0x180063ce5560356d,
+        0x4b6ccb08efc1ebae,
+        0x435ae44182317255,
+        0x2e0ce6c8d1d50386,
+        0x524dc88f3b0522a3,
+        0xf139870f62454b52,
+        0x6953a4b18300c59c,
+        0xf4244315de3c7f29,
+        0x7733111527e9e1c7,
+        0x43cac87c041b261f,
+        0x0cb527c854e7bcd5,
+        0xc44aca231375a294,
+        0x294b20adfe5505d3,
+        0x0b15d2ac0c3c85e1,
+        0xa0a9dd3317f39a0e,
+        0x941d335007d17fd9,
+        0x0b03f41b3568a5e3,
+        0x061c765f4e6bd839,
+        0xc9d153c73360bc63,
+        0xf939c65b2997ce58,
+        0x2d3f3d6068f2cfaf,
+        0x33411551b396d5da,
+        0x2576c00d3a5b8a1e,
+        0x816eebb7e7a283a0,
+        0x6c370fef1dba04f1,
+        0x12fccf3f4087e610,
+        0xaf6daafc6a1e48b2,
+        0x0e8d369d40daa773,
+        0x242013d8988e3710,
+        0x24410cdfa99495e9,
+        0x67a3f0c54242944b,
+        0x7671ef9d6967e59f,
+        0x45da32ba7af2ace2,
+        0xa5bad3dd95594357,
+        0xb427ceb7f82e662a,
+        0x371ccd2cc1977656,
+        0x1eb1421b4597a744,
+        0x2d909e269bee46ce,
+        0x0d299850a3dcc3ed,
+        0xbc0d6304f733990e,
+        0x3911dca332faa0c8,
+        0xf1f1c2106ff039d3,
+        0x5532fc0e2384d222,
+        0x63b3d58839d0e28c,
+        0x2df370450f9d0c11,
+        0xfeaecf1ffa5768ae,
+        0xfa0da0478efcabec,
+        0x876031cd806dbc64,
+        0x5f7dfc611ee95711,
+        0x47f37dc370d49720,
+        0x6f12f50c9bff5418,
+        0xe14f86b6c5038e27,
+        0x075203fbfec8b85a,
+        0xa9c5ac5b19a2f30a,
+        0xc2c40b73ce0c4e84,
+        0x60ed74b48d9eb1d1,
+        0x548bfd0e27360a49,
+        0x85aa97e2b96ce1f5,
+        0xb2178aa6d68e91fd,
+        0xc5b4d05245c35b1f,
+        0x052abedae020050e,
+        0x3a1bce25c9142419,
+        0xe9499abf41d05083,
+        0xe46d5b71a8fbbee1,
+        0x74960b108d97f2df,
+        0x6297c70f32038908,
+        0x018a32301f852eb8,
+        0x220293c4be6ae610,
+        0x0eff6b16181f97e0,
+        0x4f7ee2c2f6b3af3b,
+        0x0842e577b2edac3c,
+        0xdf6faf1c86196efc,
+        0x5dcb521e4233cac8,
+        0x60cf803901a6c619,
+        0xde2d2cac52f00ff6,
+        0x680736901a4b5f4b,
+        0x1565dc757cfb0ef0,
+        0x0c9fb7eaf31dbb34,
+        0xf13444475eee26a3,
+        0x78e6318d0a9825d4,
+        0x5d8442c292a33071,
+        0x109859532354bd15,
+        0x7b45b9c3dddea3aa,
+        0x10ef601ebb347279,
+        0x3f66830e0027f81e,
+        0xa4b78d8a223e57e1,
+        0x18ff0bece7a9034d,
+        0x15cf3bba28e88c17,
+        0x559ae1b76f42ed79,
+        0xc19e92cd4c5b6249,
+        0xbe0e581a7e485cd4,
+        0x20515c809b8103e6,
+        0x57e5e5e8e708c2d6,
+        0x89d8e493b1bd8f02,
+        0x4ad73fedace3ebd5,
+        0xdc822c319ce09bfd,
+        0x4ddc8898ff9f609b,
+        0x50c5b2bea8f47546,
+        0x4e779661886ca69d,
+        0xa8e351d0eaea981b,
+        0x6953158266754d96,
+        0xd425dcb4ec3a64d5,
+        0x4c892fb15abcd0de,
+        0x81068ae4799d5deb,
+        0x7e0eaaacc82747b0,
+        0x5d58166e9291e496,
+        0xa9c32d5a5579b986,
+        0xbdd78ac8f37ecfc7,
+        0x6191f8d38ed71da1,
+        0xdbcb051208019be0,
+        0x2bfa2c890cf5be5b,
+        0xcfba713c0f839203,
+        0x337d762358a2653b,
+        0x68a260ed06b99443,
+        0xcf170904be7c6eaa,
+        0x32207194bcad5f10,
+        0x55a5d85a9dca006a,
+        0x2957b907d0bbc3fb,
+        0xb12655e5b5691b6a,
+        0x6a17271b635d90ae,
+        0x68715eda89df9cc4,
+        0xf9059af38fc556e4,
+        0x023aa49916c41947,
+        0x41dc198b12308beb,
+        0x476494d42436598e,
+        0x1d8074634899f51e,
+        0x89764d2522202b29,
+        0xd8c364d16c2680e3,
+        0x5537d4a83df2e5ec,
+        0x45f35b5e29f2fad8,
+        0xe508a4f5b10353fb,
+        0xa5a3e04c7627fde9,
+        0x67be77ad3819320d,
+        0xba6b850d0f900355,
+        0xa31df719b104c8f6,
+        0x67d609dd88d5fe2e,
+        0x1a9ee4e078d6deeb,
+        0xc936fb3319d0fb26,
+        0x151044270fb477a7,
+        0xd20d2298db20d594,
+        0x09e499bd671687d4,
+        0x03f27392f7ed5688,
+        0x3c9011d56beb4b5b,
+        0x096a21a0acb88b45,
+        0x1f59aefe0b3c2434,
+        0x9bcb193d7eeeb8d5,
+        0x8aa728fa4829a273,
+        0xf9fdf4de2bc98693,
+        0x3fb26a3e172844a5,
+    ],
+    // 0B..15B << 220
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x829fb5510fec77be,
+        0x629ac86457dc5246,
+        0xb16a432aa3ef3aa9,
+        0x224fd3cf4482b2b4,
+        0x3cf8eaae08788a4d,
+        0x2adba1301c2824ba,
+        0xcbeb17bdd14141e3,
+        0x5fd08bd18c34967a,
+        0xb39631d6cbb3ff73,
+        0x8b181a39b1d9da50,
+        0xaebd00584c9ec41b,
+        0x3f2e8f335dae34a3,
+        0x898fb7104e496318,
+        0x803cb97748fd0369,
+        0x30dd9cb8149fd763,
+        0x3e455bd2ba6744e4,
+        0x4de5feeb8a7a650f,
+        0xae509e4d892055cd,
+        0xcac231bb304f9089,
+        0x09e40e5b48dc387f,
+        0x265e929eb0333a8f,
+        0xa43cf0e2b3b064a7,
+        0x292b4397bf7cc18f,
+        0x1a5b63b7d4e1a00d,
+        0xadf69e21a8fab714,
+        0xcf1a70b7a2f1d66d,
+        0x052a4c65734b9646,
+        0x1896a6361b012d4c,
+        0xf8921b71e029a6a6,
+        0x0df34ca8950fda64,
+        0xee59cf347f65e4f8,
+        0x3deaa749f6213c78,
+        0x4d66e286fb4c395d,
+        0x1e840217d784fa64,
+        0xc63edee94ab67027,
+        0x4bc27ce8319d422b,
+        0x4df29b4f289be588,
+        0x3a40bf959ec94d70,
+        0x84d728d338dcf598,
+        0x18006947a5a3bb86,
+        0xa1e58115fe8e17af,
+        0xf8d13c922dcfc962,
+        0xc686e412e689f141,
+        0x4ef3cc1bac6c12b0,
+        0x87f1bcb7547a783c,
+        0xf64a99c855423804,
+        0xbb9beea428561f84,
+        0x5310ef7c593956c1,
+        0x2936ff8cce1cff35,
+        0xd03a8eb4737f5cc3,
+        0x3987016197c411cb,
+        0x0e81004408d02027,
+        0xe8a9bc1beea02d2c,
+        0x4f9b9825891c8405,
+        0xac356b38b978a87b,
+        0x3444a9d0bb0a4f0e,
+        0x725fdcabab36453d,
+        0x215d4d009ad4eadc,
+        0x7655af2869efbd9e,
+        0x7cfede1ab5e7daff,
+        0xdd45491e09618771,
+        0x4bfd29d2e0820030,
+        0x0c66fb20d0ccadbc,
+        0x5b0872e3d85a4803,
+        0x76c463fbc2f27628,
+        0x6f9ce69975946c95,
+        0xb8129eb4ac7a2e09,
+        0x6a6c56571361f83c,
+        0xc4db171d1d72dc16,
+        0xa26305e522edbc04,
+        0x5ad8a3e41cba0083,
+        0x6ff2153e9ba022d4,
+        0xd58acb181770e81c,
+        0xefb57e2de507e816,
+        0x2092d3d8f2d099ef,
+        0x59653ef29ea283e5,
+        0x3994598774b6a9bf,
+        0x5f552e74a2705bb9,
+        0xc3acd34938534797,
+        0x663dff5e58636cf5,
+        0xf8ddab20602528b2,
+        0x74b07589e3b5e693,
+        0xe04a0231efce820a,
+        0x0b43059bd5a605e4,
+        0x2aaf9720e06af988,
+        0x33245658fc684ec0,
+        0x5358ab3d3d07e117,
+        0x71c9dd31dc0b1c01,
+        0xed0a32f3164c7460,
+        0xe4d993e046d1100b,
+        0x3833812cf9e22a64,
+        0x52d6490b5b6270f3,
+        0x87cbc61ed54cce42,
+        0x20678baa042be5ab,
+        0x9695d496b7ffdf91,
+        0x2994c8ac7f07c8bd,
+        0x15ca1e9fc9f4f323,
+        0xf54f149b4cae69a5,
+        0x424a02dc443e6e2f,
+        0x7a8d9b5e4b777807,
+        0x4b1c077ac4805b43,
+        0x43601728bf370b96,
+        0x84389633618c34cf,
+        0x7cadfd62f14f2ea7,
+        0xd34482fba327b782,
+        0x347e8e279142005d,
+        0x57aa1f5b54f555dd,
+        0x1f0dff4ccb6b83f1,
+        0x01f7ee719842f543,
+        0x3989a5714758b483,
+        0x58262089f2e830a1,
+        0x7211d876687ffedd,
+        0x91b8865769ac9bfb,
+        0x603d8ed69c4699a5,
+        0x6cd47d7df35c2d6a,
+        0x6e0e55ea4f33929e,
+        0xe1becf59971290fc,
+        0x5637aa3c0e3d2fe8,
+        0xa935ff93c0884358,
+        0x2e3a7c1e454aef91,
+        0xe2aec7e4cb202b40,
+        0x9ad6d47d94da1b5e,
+        0x836dbc8f252d829f,
+        0x523da519accd34cd,
+        0xf42683c3dd4a4276,
+        0xa91f45634576b8a2,
+        0x1c9a129cbfa2a929,
+        0x2be7db95d2b5fef3,
+        0xdf585a3671e25b3f,
+        0x1a932f7f0428a4d9,
+        0xf702286df08846e5,
+        0x3b22def341bd4e16,
+        0x47369a43114c5bfa,
+        0x870d76dc5387db90,
+        0x49950f6d0904a544,
+        0x0a62e8dcd71c9632,
+        0x822b6bbf4f4feced,
+        0xe2d40d58a704beba,
+        0x0de24757eea633b1,
+        0x1a7b1c36b33ddf70,
+        0xf332913fda5c3ef7,
+        0xff5a9f6ae1857ae9,
+        0x5338539c89f78161,
+        0x48b36f916306c422,
+        0x271e564462aad16e,
+        0xa494bf2d027a81e5,
+        0x422facbad4156525,
+        0x1a7ef46b775fb83e,
+        0x300da1d83664d35d,
+        0xed2ab52660c40310,
+        0x9109b4471361d69e,
+        0x6af03ad30264048c,
+        0x1a62c35347a42295,
+        0x49e9f0f1e4775a41,
+        0x6b98d3c37aad0c86,
+        0x5cf429c30c09b5e9,
+        0x10b5537792b76fde,
+        0x52c46908fbe6f484,
+        0xded0b78d478ec4d3,
+        0x4eb7f71bc08b5892,
+        0x7565fd8569882101,
+        0xa9396af0b10801b5,
+        0x470cb16b0940f2f7,
+        0x4cb6c17574f607e4,
+        0x6ab636c2846fcfcb,
+        0xf6465236761517ac,
+        0x050c85d6b94ae5fc,
+        0x28cbb949e0430dc5,
+        0x79057781f50957b3,
+        0x89ec316c71be1923,
+        0x1000072e65c5849f,
+        0x61e79600a7c25da8,
+        0xda383d974e4a9b31,
+        0x6cf97d67e07ec28c,
+        0x9dc7bd61d234edb1,
+        0x53f67d59a088265c,
+        0xdbdb99dd274db973,
+        0x202c59bf4a08543a,
+        0xfd657e5528f30dc7,
+        0x7c20a64f2c0ea00c,
+    ],
+    // 0B..15B << 224
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xc8d41dd9bed61eee,
+        0x29cb058160b3effd,
+        0x2c2d46882502efbd,
+        0x24649946b10b77ff,
+        0x29623a517918b708,
+        0x246fe1efe406dec3,
+        0x87ea0c1735dfd8cf,
+        0x27e4c8331409e5fb,
+        0x7682c94a28664de5,
+        0x350ec72b9baf77d3,
+        0x7c6d2af47a602b7d,
+        0x2ce87a85a5c077ff,
+        0xa2e8c2b788effcb8,
+        0x7734543081e3c5e8,
+        0xf9153b39d47e8325,
+        0x2c8ce005a5dc7ca3,
+        0x43f7a20b0c8849f5,
+        0xd9497564706ae8e6,
+        0x8255c764dd21d004,
+        0x4af21e6115843d68,
+        0x8f7ba2fdd2ff86ea,
+        0xc3625c418b2977b4,
+        0x4d00e4941d90799b,
+        0x429f5d374853fc59,
+        0xd312fdb06a46e7ad,
+        0x60f6adfe13e9d54a,
+        0x976fa1a9912744db,
+        0x3497ab3bfdb9a682,
+        0x295fbc4cd042db5b,
+        0x7ac7cc76cc0570d4,
+        0xdc086e28cb4c2994,
+        0x175c4c03b0b1175f,
+        0x2986a40a82a21494,
+        0xd1f5dd078ac975cb,
+        0xbeb4a1cfa79f3e4c,
+        0x2b45eabe0ec649c8,
+        0x7be14956c00b773f,
+        0xc3db32c253945f1b,
+        0x1c74931ed86ae5ff,
+        0x347995c015230e56,
+        0x69d295356f980e45,
+        0xed74b35c89e4ecc4,
+        0xd32b0eea75ef0ca1,
+        0x7314398250258e5e,
+        0xa4ae6c604392e37b,
+        0xbd196f522e9bc0f8,
+        0x38b65e18a8f54eec,
+        0x11814c5e78224209,
+        0x6d226917de286ba0,
+        0x8bb5b753eef3d6d7,
+        0xb88f280e86044f97,
+        0x4888ba20da41c4b5,
+        0x5f4e690b50a501da,
+        0x35c14f4571bc31fe,
+        0x588318f92f9bf03b,
+        0x69ecb4b9d01dc9c1,
+        0x65201c57721e70d4,
+        0xa052f302c00316a3,
+        0x9f45d07c7c1ba04f,
+        0x4cd96a12d3d2a1d9,
+        0x7fbc0810da6a2d66,
+        0x3f7f6da323f17166,
+        0x5e73cc418d50f451,
+        0x4e808e5b54e76490,
+        0xc1e34fc175b22f37,
+        0xe7d27f407a2deafe,
+        0x99dcb22a453c95d2,
+        0x327a43a8da3da04c,
+        0xcc538a8626da0a78,
+        0x930597ce93d1a2f6,
+        0xed7cd446ad8a164d,
+        0x698bc6647193134e,
+        0xaa05886452ff0fb3,
+        0xa4599152833302f7,
+        0xe2cca41b68be3057,
+        0x62fa8bd7cec09c7e,
+        0x202d132fcfa91693,
+        0xb3b4ff6114c36a14,
+        0x4a30a890c4f333b1,
+        0x2b9dbb8597987f61,
+        0x21d3ab1d28a44f18,
+        0x57a0237b1a4665e9,
+        0x3f43831af9bf451f,
+        0x4504e3fc1c0913eb,
+        0xf930e7e3f4adf0b9,
+        0x358542d3b20d0ddb,
+        0xc64ff77fa34d39bc,
+        0x23c88bb0fdc57855,
+        0xf546d169c5917a1a,
+        0xee8334a1fefd1b17,
+        0x45a9828fb2578bd4,
+        0x177172a685075121,
+        0x4baa08cdac6bd9c3,
+        0xb20e7960687c294d,
+        0x451484e33174faae,
+        0x538a19aa78c75cf6,
+        0x8ab48192ef206905,
+        0x1f408521b5a41c58,
+        0xbcfa20adfb112cd1,
+        0x088349b95135a791,
+        0x5a27ca41a8f64946,
+        0x3519c4b9b5ae1a6c,
+        0xc2279800102a9794,
+        0x1b46e20c5f764731,
+        0x469cec7e7291e392,
+        0x348c86ddf0af21c5,
+        0x214887ecc43be9ee,
+        0x38c93540fb78bd40,
+        0x315318e789f35e8c,
+        0x8aa6fb2792f15db8,
+        0xc7d565228027ccd6,
+        0x6cc91362103cc59b,
+        0x8d07ff66c4f6e58c,
+        0x6181d23a7cdfc5e7,
+        0x8559cd0b8240fb58,
+        0x5876f16a86e333ac,
+        0x9e91e109c3c8add4,
+        0xb3c1d9e5506326ed,
+        0xb91876daceb77bbd,
+        0x76b9d657748831ce,
+        0x86e807ef593ca12b,
+        0x833fa58531b6e089,
+        0xc2d72367de1eb7aa,
+        0x42b9d06bb3d1bd1f,
+        0x9c375cc3a2f60071,
+        0x5a7fe10174c846c9,
+        0x4714792d864432c4,
+        0x43c203f71f57c921,
+        0xbf402b0f66a4b36a,
+        0xa9b3d01aee53c6ab,
+        0x0d128168cf47b330,
+        0x01b4b60952d6d339,
+        0xb9d84b064aa27b65,
+        0x1e50051ab3c2a9ea,
+        0x4e18bc08b21ba288,
+        0x2517eb2c7746902a,
+        0x1151fe60d6d0eaf0,
+        0xa0c98f3e6b2932a9,
+        0x782270af7611f392,
+        0x2e272c0193c0190b,
+        0xbe2b4bc82b566af3,
+        0x25f90cf75864a686,
+        0x0bb002db5f5c9da4,
+        0x2b151736ff1b280f,
+        0xc9e9753779de0a4a,
+        0x2bcbd7d19460c0fa,
+        0x25fbaf7193a29807,
+        0x29978ad3e16411eb,
+        0x06fdf78f8373c03e,
+        0xcdcd38ec21b49640,
+        0x4d558d5ad65015f9,
+        0x1ea1b6d2523c1e98,
+        0x280a105b27f81a8d,
+        0x4325dbe5358a7492,
+        0xb2a89ed09c794ed3,
+        0x753b0dfa97648413,
+        0x531339b6ad0786b3,
+        0xe2e26ea5f96df5f3,
+        0x3299ffbb710b688c,
+        0x3dcf0cdf6659c411,
+        0xdf8d75547a8d6498,
+        0xd0739709a421b63d,
+        0xafa0e98d9de7190d,
+        0x38dcfd3905847e46,
+        0x24e07f2aa746fd3b,
+        0x4d625b7e7419489a,
+        0x7a382e79094f2b12,
+        0x5019d274cee7b5d2,
+        0x8f5119f478145d8c,
+        0x195539df8da18227,
+        0x67415dc661cbe99d,
+        0x2af4ac94faacae89,
+        0xdafc22176af9cddb,
+        0xb4efd6547f63ac72,
+        0xbd210e603a656107,
+        0x05a6e99bd71aea09,
+        0x81e17e4a7d9ef374,
+        0xfabd09199bbca47b,
+        0x984bda13440a076d,
+        0x66f4c8ec2584c5d9,
+    ],
+    // 0B..15B << 228
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x42ba691d63cbb258,
+        0x7d0d59a6d699bbb0,
+        0x344a5b111c35d402,
+        0x5a74e17b425a095f,
+        0xb6c7f6df0c4fe1cb,
+        0x134fcd57ea2dbd3a,
+        0xb3b3bfe2375fa54d,
+        0x12c5e89ff388d028,
+        0x5b056794c6fedab6,
+        0x70b1af4fcc7562b0,
+        0x7d2f2342f952e057,
+        0x398c382c964faaa5,
+        0x2ed6ac2d220c74ce,
+        0x124e5f37b44fdce0,
+        0xd25d210fa4ba0af7,
+        0x2171d8d8e31d2c4e,
+        0x34e4d3fe98773dbe,
+        0x21d950f3adfa75f9,
+        0x3a0d5135df873d7f,
+        0x4edd6b4990c9fad7,
+        0xc93b57c6a4c7048d,
+        0x77b323e6234af7a2,
+        0x89cf8fc712b856e2,
+        0x5034c3168055e4ea,
+        0x08a5188205dcf42f,
+        0xbfbda5e69ab47051,
+        0x7611510b6ec80f20,
+        0x145496a61ede9f61,
+        0x8451d1cc694af680,
+        0xaac462dab297766e,
+        0x16c7213d5aa276b4,
+        0x7fcd4caa20ca53b4,
+        0xc7d1704fcb8fa86b,
+        0x53e889e1f0d3224f,
+        0x503937c0359e5c0a,
+        0x3250b58671cf5d1d,
+        0x2de665831bb549f4,
+        0x5eaa173bffd3fa1e,
+        0x8514e11a6482cb0f,
+        0x3ded40789035e6fd,
+        0xb89ff64e3e5b4f54,
+        0xb3f140aea80b6660,
+        0xda531d53a6616280,
+        0x69bb9f9d3eeaefd5,
+        0x898cf13e2149b9e6,
+        0x6bd87f711db5f51c,
+        0xb09d255307e78148,
+        0x23f0f9373b7f1521,
+        0x6717dba92f99c72c,
+        0x6427c9f3d7328e60,
+        0x06c30ff20b4885c7,
+        0x586331dabaf56fde,
+        0x89acb349ff8aca28,
+        0xe7bf462b2389c9a9,
+        0x323f79af51351875,
+        0x62ec3516afd433e8,
+        0x3b1a4d438fff748c,
+        0xf26cb375af9cccd3,
+        0xff0c9d86930bbb10,
+        0x045fe8759ce85a4d,
+        0x65a5d23e595ee167,
+        0x8a6e07bb60fe404a,
+        0x2a1c910118a8e355,
+        0x27c3b17b441dc6ee,
+        0x95ac45bb7b44c28f,
+        0x6338f2ea96f9577d,
+        0x80c313e859b32391,
+        0x665719ab2b380495,
+        0x994d74c5e7f22104,
+        0xe4ab7decb75e38b5,
+        0xead89e5f19abb9f9,
+        0x55c8e897a229ae55,
+        0xfb5f656c633af78f,
+        0x904fb373c78c76c0,
+        0x7a752ca357c5a66a,
+        0x243805f0aeba587c,
+        0xa49f357c7b78c7ff,
+        0xe2cf3e5ec97459f4,
+        0x0c1e8ca47152f844,
+        0x2c65c45aa59a6cff,
+        0x2fc85100b98ef238,
+        0x46e1ca9ea96527c3,
+        0x4edfa51cf3021902,
+        0x7f809a85df071c69,
+        0xaf29eda9fb17f716,
+        0x40c2efaf6e0d27e4,
+        0x6fd8000e78cb2f9a,
+        0x181b0c67e8d4189f,
+        0xccbfcc63cf6482d8,
+        0x63b2952ef9b33d4d,
+        0x122fef8629189636,
+        0x2cd4420e9dd5efee,
+        0x9c4138ee6731d305,
+        0x67419643357cf328,
+        0x2d903cfd8fff384d,
+        0x7d578a177a645196,
+        0xf5399eeb497a4068,
+        0x7a06f19e40d5ec18,
+        0x30b002fce5d7f01e,
+        0x573b16484b8bfe5e,
+        0x9b085b898a6b1cf9,
+        0xcd90bfcd6f0d3b42,
+        0x4c98535e761d3a64,
+        0x19b28ebfb413602a,
+        0x9f584c17d5dbe450,
+        0x0f2db1f17433aa3b,
+        0x6974c8f3f6db40aa,
+        0x65f8e6e43ce94e91,
+        0xeeaf50e413004b00,
+        0xaf5471baaeaf062e,
+        0x62c79eb06f801e51,
+        0x4697131c211d8db4,
+        0xfead1f17243b1cf1,
+        0x36bc3cd5cef9c996,
+        0xc27d3bf8cce5e459,
+        0x1c3f879760cc53dc,
+        0xcf5d365695f224c8,
+        0x764815fc21537037,
+        0x7e197860de952951,
+        0x3b5bcca2fce533c1,
+        0x345d17295b9bf718,
+        0xa5214eed7aa56a2d,
+        0xeff9bb3fb38fd83a,
+        0x119f49aab2453543,
+        0x1909ef2d722cdd24,
+        0x3eaabc87caf9d6b1,
+        0xe04055c1d4e21f85,
+        0x3ad2d94df2d72cee,
+        0xf6ee8c0717c0534b,
+        0x7cf76aad55e063a3,
+        0x9285307296782111,
+        0x4b1767b201c93219,
+        0xc8db75dd9282f775,
+        0x9d0f8c1173a70603,
+        0x13e3e53eac7f6d2e,
+        0x5866e701439f52fd,
+        0x06080715b35da446,
+        0xd674cbb68fd293ea,
+        0xc6635c1fc853978b,
+        0x01ce56d353bb97c2,
+        0xe7ecab44df7ca973,
+        0x0246f7e35b2c3ce5,
+        0xa3ce1cd692185bc6,
+        0x23494516ce6524d1,
+        0xe516859e1d633676,
+        0x9738e1d2f011d85e,
+        0xde9cfd28986fddb9,
+        0x79c5e43bce959c8e,
+        0x782f52cc7f1e9b10,
+        0x51e44f475c7479ca,
+        0x1a20392bad2d51a0,
+        0x02b547b761da679a,
+        0x28d4981e1ba3d95a,
+        0xf6c3d27516dc52c1,
+        0x883c02b2119e361e,
+        0x094be187c5b0aea4,
+        0xfb9df14ff70cd698,
+        0xdcc60acc1f46cfe2,
+        0xdf2e4c2892bbf28e,
+        0x4229f6dc5cfb3878,
+        0x56caf25d70a993b6,
+        0x0db8dbd862f37a71,
+        0x2db5aa67d8b15ac7,
+        0x129bd4058f21bd9a,
+        0x66385acd51f2f393,
+        0xcc02868935e54423,
+        0x36338f5070bd519d,
+        0x3c189bd0dfc6d568,
+        0x55b131c32990d830,
+        0x30b7179756197b04,
+        0x7e2faad22bb9dea7,
+        0x5b39ce2045e2f2e2,
+        0x507713e4a9097f62,
+        0xc7f62c223521df3a,
+        0xc776e5819503b63a,
+        0x541aaf5708e59f6c,
+        0x00e91cbe053bb3c2,
+        0x63071eec64408449,
+        0xbfcfde5e952c8278,
+        0x3f02beafbf8b357f,
+    ],
+    // 0B..15B << 232
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x5cefac1e0cf6e155,
+        0xdcafc55644c42ce2,
+        0x5b91c9232ceb84f2,
+        0x2c1915236f28b16f,
+        0xada8c9967f1c320f,
+        0xa42373460c9361a4,
+        0xabc8d37dbca8b182,
+        0x6b0b2d0f41cbd692,
+        0x1cdabba4d32e116a,
+        0x971f7b484c165bd0,
+        0xf24ff0aab96087fa,
+        0x58258f6da5374f5a,
+        0xed1ea43ee2769862,
+        0x0f9e12515d04d3cb,
+        0x3d33de28f84a0c0b,
+        0x6443fb636a76f1f2,
+        0x1a942c81e57d71c7,
+        0xeb361b3621c18cba,
+        0x00c42e45a4d91342,
+        0x724d72aa34f542df,
+        0x313010f7081a9e94,
+        0xd831b3f5edd2720c,
+        0xa21e2ba15e0549cb,
+        0x29050b80c1479b0b,
+        0xf9a46ba5ea3db1d5,
+        0xc453a5b0b18af1e7,
+        0xff09af123d84a671,
+        0x51668f269587a2bb,
+        0xb84c3bdc3b513b2e,
+        0x2554fe6c3af5552a,
+        0x3ff00f4cb7fa412d,
+        0x6c2680bd0ec90605,
+        0xd9554b001329cb7a,
+        0x132f7a46cae89edf,
+        0xbb745a9ea082f9a7,
+        0x3301b6a1a559f83f,
+        0xe24d155676e9dc59,
+        0xe028a3f67fedf245,
+        0x4728e5c6b1170556,
+        0x728ad46808c4b7f9,
+        0x8206bd8169fa7068,
+        0xb847af6b6ddc461e,
+        0x8b33889bbae88f60,
+        0x09a43240481bdb7f,
+        0x19b09e9b9b4a0579,
+        0x3dd09c62078451e9,
+        0x307a5925c949d19a,
+        0x3e9f839d01899253,
+        0x1da91a4ef88a4e19,
+        0x12db893da9195157,
+        0x62952bb391dbdb12,
+        0x0f1c7c134e9f962a,
+        0xff29e748edb4c380,
+        0x7d9e3021e084f574,
+        0xce681582f201d1d1,
+        0x56caa9aa80bf4628,
+        0x53758cf439d7d7ce,
+        0x28c5cfb990a16375,
+        0xac38ac62f696a8ea,
+        0x7a01bcf31bf3367e,
+        0x80e3558c74c27430,
+        0x43b37a2b999ea502,
+        0xc758036b6e5f7a39,
+        0x63213b3d94690908,
+        0xe8540074c703604f,
+        0x97a8219afd07783b,
+        0x9e7ab5b646cd5b61,
+        0x05266e98973e7b0b,
+        0xd3f31178de203982,
+        0x753ed3b5f416b775,
+        0x1820723d88b29211,
+        0x42d2f6b5f4e290a0,
+        0xf3514aa423c12d41,
+        0x14a75a43b0e3a455,
+        0xeb2be05001c39156,
+        0x4aa594c16f8a6d94,
+        0x12f48fea81217925,
+        0xdc50f96eb06a3461,
+        0xceee754f401c17b9,
+        0x13c00df1053fd017,
+        0x96f9ed9a89c744d7,
+        0x05a40a4a63148f6e,
+        0x5298b1d677e27767,
+        0x6f62380a1b9e4c7b,
+        0xb58bfdf7142bde55,
+        0xef60cab7a8b498ac,
+        0xdc204543d19d0035,
+        0x44b0a768c69ab9d4,
+        0x444bef6f460ca071,
+        0x2aabd969a1a03894,
+        0x077c87ecbd9ff311,
+        0x13b068dbdaab93d4,
+        0xc047d6021c41154d,
+        0xc6b98f7865772e52,
+        0x320702289a071fe5,
+        0x3ec3683aa001bc9f,
+        0x21f920c384dc95ca,
+        0x55f597ce7899186d,
+        0x02ca6b599357c219,
+        0x7184dee042025a11,
+        0x1fcd4562024b5a5f,
+        0xa957f40d027d2c25,
+        0xcd9aeb6c3892e8f7,
+        0x4a31a31063154714,
+        0x2de09da0ea55ab8f,
+        0xd07542540f7e0862,
+        0x1c2d8fd5a30f72b2,
+        0x27fc98ce4189734f,
+        0x0e8f4afe82bc414e,
+        0xba1d663b906b1042,
+        0x1ecdad2194401ab7,
+        0x3fa2e338519aa1d2,
+        0x35ac2071cc08508e,
+        0x86202c156a36f21f,
+        0xbe5b559ddc293e52,
+        0x1094bad4a209e43d,
+        0xb70322fd9dc1bd9c,
+        0xf8944e13bcbd2f63,
+        0x608d58630dd1d50a,
+        0x025823147bc30135,
+        0xa45b8bb632c5c0fe,
+        0xae4fff5c2343d665,
+        0xeef21652364342b3,
+        0x5388983eee80b2fc,
+        0x10717d941258c9a2,
+        0xb1d9edccd5dc04f4,
+        0x2d15d73ff83c39cc,
+        0x4c9ae8542a0ab6d3,
+        0xe88187fe9b281eea,
+        0x7e57d925bc3d2036,
+        0xd26718e78bbba1c5,
+        0x54cebc4cdfd7f98b,
+        0x29dac7df4b06a13d,
+        0xf8b241e34eb66c39,
+        0xa1c35540749a50f3,
+        0x2253ba8d5b33a6dd,
+        0x29bd0abf3cff52a1,
+        0xe76e8ef4150c65aa,
+        0x5ce7ad2daa4eb7ce,
+        0x37263c4e96a00642,
+        0x4510b09d9090d2ba,
+        0xfdec09ffe28a3eec,
+        0xc37b94ad43ead979,
+        0x2ff5e49d4c5c1225,
+        0x36e52d993c073280,
+        0x3c55ae45be36263a,
+        0x89b17e9923487e67,
+        0x25e12b66f899d232,
+        0x12802dba8343d226,
+        0x19faeac6c3251780,
+        0x7f71117513f3f87d,
+        0x40b346267095fa22,
+        0xe2fb16dacd65d779,
+        0x853d8d991f964103,
+        0xa2d7960b1e704fa4,
+        0x6db7ae9c6629b1bd,
+        0xe4f9d6fa805ef6cc,
+        0x340885f18dcb51cc,
+        0x1c9adb3e6be72463,
+        0x1d5ad696b2655e1e,
+        0x81aa023d870331a3,
+        0x70264bc2a94f8204,
+        0x5b80b6b3117da9fb,
+        0x359aa55e3c2e36e4,
+        0x707398a277061922,
+        0x6966934243d2f2aa,
+        0x06269a2fcf5fc9a2,
+        0x3005ba90099a3f7b,
+        0xdd268e47bb61be3b,
+        0x604994f39c4378af,
+        0x46762d0eb62188d4,
+        0x475635611656bd72,
+        0xac43ba65fdff4c4f,
+        0xea4461cb947cbfb9,
+        0x5f48f98835163883,
+        0x56791281335c6803,
+        0x81a72cc7c6124004,
+        0xbef554b6b38ce89f,
+        0xb9f27412dc0b7cea,
+        0x066aa99dba40f1dc,
+    ],
+    // 0B..15B << 236
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x042b9ec825fe2276,
+        0x1655a56aad97d134,
+        0x2f5955aad4d680f0,
+        0x1c3efc4fa472b00c,
+        0x219167e67eb4c579,
+        0x6de28d5dec7c6475,
+        0x46be95cdb5eed112,
+        0x7372caee17567bc0,
+        0x5b3196d9731768c4,
+        0x428f7b3be217bac8,
+        0xd62fb4a7cd317c1f,
+        0x1f5748e7839661bb,
+        0x8c0b2f9d57ab2b4d,
+        0x29b7f10eb94f9941,
+        0xb41618f6cdaa98dc,
+        0x4fab41d1b314191e,
+        0x2b3610fe041f42af,
+        0xdc6c24d289fcfdfa,
+        0xbd87e20b8f1bbe34,
+        0x730af93bb426235b,
+        0x215f590325d7afe1,
+        0xa0305504ac9ff4da,
+        0xb4fa35f0372b6725,
+        0x779ff14d2acdcfd9,
+        0x3366a2fac9ba774f,
+        0x470046b4db77e65b,
+        0xe5fc00a6de8bd648,
+        0x62d78eea0c60beb5,
+        0x10ab8783faefcf61,
+        0x06380bad20a6ed01,
+        0xaf94b8718a30c7e3,
+        0x653d740806660094,
+        0x5b98b98fccbe6615,
+        0x1ba162655c66444b,
+        0xd56061ab1eba53f8,
+        0x4d328ebb9bb5e03b,
+        0x9dd816e789cf33dd,
+        0x2b0096e1be8dd367,
+        0xedb64848c7a965d4,
+        0x62a3f502fbd522d8,
+        0x79996f5ec3988f88,
+        0xf79ffb953d31d482,
+        0x1182bbafefd2bbcb,
+        0x4d53e34870b15e9b,
+        0xb59218d4896c07af,
+        0xef6720e20407c64c,
+        0x7a0e0dabe840cfad,
+        0x4372be6ec0da6e5b,
+        0xebb0c1c515638e0d,
+        0x4be6940b027014fb,
+        0xfc66b24b9e59d397,
+        0x6a687a8e69919c72,
+        0x8ffbea55e482a60e,
+        0x2b0fe1dd5ac0e179,
+        0xce69da24d5701432,
+        0x5535e40f666f2654,
+        0xcd7b69de33ac8550,
+        0x7ae78a4407988e63,
+        0xa9df10bc6b96e38d,
+        0x52a60876c26a6873,
+        0xb8650908f863b945,
+        0x17e8cfb1e239ab3f,
+        0xfc1c55d4acbbf4ac,
+        0x0de9df66c765a684,
+        0x36bc9c83f65e7353,
+        0x8d82773be00a792a,
+        0x3a6cf79f9f9e479f,
+        0x45cdbe225cc82fec,
+        0x375274d45442f211,
+        0x0384dcb5d0384ebd,
+        0x772d2a48353d7c75,
+        0x28e52fff3bf16ba5,
+        0x1d76740c1288a89e,
+        0x31c3fac963cf7abc,
+        0xbb4a365e8b2fc9a1,
+        0x34038cb099c720c9,
+        0xdfcc1e0cb8e5e662,
+        0x8e7cf1574f7340e9,
+        0xc45a7e75b9d36e2f,
+        0x049c5208f4eb246d,
+        0xc5a3fc30c0f3c651,
+        0x2c494033eeb9f82e,
+        0x77b14ecf1bf68784,
+        0x6dd8575aaf374be9,
+        0x4e51939e854d3573,
+        0x02b915f3c9cb40f0,
+        0xfbec75c4fbc792de,
+        0x6641208fc0a6d4c3,
+        0x3b9bb910796fbe07,
+        0x4974fccaa03df636,
+        0x4ee50c09e541af0d,
+        0x4420f58628ef048b,
+        0x8abde4dfe829be4a,
+        0xf8ffcfa68cf8ac13,
+        0xa3e8b61ed0513c1d,
+        0x3526e63f3b52fec8,
+        0x6d02c73d064529a2,
+        0x9667957c51908e82,
+        0x53e672b7db693b57,
+        0x7d36d80621d20c0d,
+        0x5f418597d7629448,
+        0x7a7c7ecd20326879,
+        0x077e9083ef9c69dc,
+        0x3f71d08db6ea6589,
+        0xdb77333e56f09776,
+        0xf241718ba4fed145,
+        0x5c175bd0b64d06c7,
+        0x5fd7593791b65585,
+        0xb7fb579def841b4e,
+        0x0abb31d0616b7a88,
+        0xaab802d5dbbb6db4,
+        0x34b2ec5047932c79,
+        0xf1e5070e967b2516,
+        0xfae514f386707234,
+        0xe445739164ee0067,
+        0x27a7b9ae15338364,
+        0x64e87fb66d3e52ba,
+        0x915178ec1d157a67,
+        0xff463cf6845089bc,
+        0x23a48f3dbf08b113,
+        0x0dfe7e8ad90a0702,
+        0x479a3fa22c25f6ed,
+        0x56b8cef86cc4f86b,
+        0x3dff5447a4c5112e,
+        0x8a4860f4acd4b9a2,
+        0xc116e02c30aee421,
+        0x08b8e7e4fde7b901,
+        0x493179a71ee4352a,
+        0x7185900a2a4cff57,
+        0x127d06de5555f4a2,
+        0x50122f9c294b0836,
+        0x2836de584d4a1534,
+        0x99fa611ba09f310a,
+        0xaa61a8aa103ea010,
+        0x429d382e0cbb6b7b,
+        0x709208dc8a87a41c,
+        0x9231f0324a5e3da5,
+        0x3e89b6e34a1a7c15,
+        0x25bbd858188a1248,
+        0x1923c34d9634eadc,
+        0x2a85cd8a1e982eb6,
+        0x2bcfbef6dd81a56e,
+        0xe3e590cbbcfb919e,
+        0x7daeaf9ed0a90a53,
+        0x39372ff6c1a10b4e,
+        0xbc8a224fcbab0559,
+        0x829426dc33673360,
+        0x626a2ac7a8cfe22b,
+        0xcc0ddb1600d78562,
+        0x73bcb467631cf69d,
+        0x4c21aef14d733cad,
+        0x7e6462de377d070f,
+        0x8d401857401f408e,
+        0xe785574fec5f5b52,
+        0xea7f69f6c2575831,
+        0x3b462644de84aa79,
+        0x8fa010c808b14df7,
+        0xed31a9aa30547d52,
+        0x12c392e0389ab7cb,
+        0x7d4a5fd39dea0e1f,
+        0x4f21313b2c11dd4c,
+        0x66717a53f549c3ce,
+        0x8dd71852891d736f,
+        0x34aa3a244946c494,
+        0x20a13f0eae6f6474,
+        0xaeaeb49da56267ac,
+        0xfd54122fc20b22e7,
+        0x0e5824d2484a4354,
+        0xd4b3b2ca9338f1d8,
+        0x8f19e6fb979e9a16,
+        0x0fb4acfd94114453,
+        0x17ae4e4902ed96b2,
+        0x6aa73a2e69cc1b89,
+        0xe60890760f526bfa,
+        0x5772d968597d6fc1,
+        0x703813e762d2c893,
+        0xfeffc073d165262d,
+        0xf57f0975cc2293ca,
+        0xe384ccdc03ac98d3,
+        0x1336cfd71a1a5fae,
+    ],
+    // 0B..15B << 240
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x814e01e09d128831,
+        0x2e3e1bfd001082a9,
+        0x8179be98797ad503,
+        0x30e4630f38fa685a,
+        0x29aaba9f42614a55,
+        0x79ea0c4f44522af4,
+        0xf564b6384d9eccb8,
+        0x18b7840c2a789dda,
+        0x30a609d019476642,
+        0x424508a4aa707032,
+        0x23002e523bc3f2ed,
+        0x52392bbe8aa9aec9,
+        0x00af759f97741298,
+        0xa37afb0b4b3b5321,
+        0x1141bdb52394238f,
+        0x624ee1f274adda69,
+        0xd1b9e1873ead3aa5,
+        0xf6708cdf0fc18f2f,
+        0x19b1249be1907248,
+        0x7ea36e2d6fa4c36e,
+        0xbdacd98001390f81,
+        0x941e41bc8d116874,
+        0xcdf54f636b620df8,
+        0x4e07be6e99daf68c,
+        0xbcf70a8db4602c94,
+        0xf34a4808b379719a,
+        0xb4525ca4c1a770f3,
+        0x220168b65b3ddbcf,
+        0x92396c5bc11cdc5b,
+        0xb37dd7dd860c0278,
+        0xbe426dac9fd2e92c,
+        0x3287e4a2cdcfcc05,
+        0xca58b9f5d9d2ae21,
+        0x8990913053350f25,
+        0x56282129d9bb8368,
+        0x2b22362762f36fc6,
+        0xe30a3a87a1f4d98a,
+        0xa571ac76edaba888,
+        0x65677c33f616c5f5,
+        0x185bd6f1c37ce10a,
+        0xd3ed165470d3e708,
+        0x67f027b452edb581,
+        0xcb5b6eec939b3d1e,
+        0x197274fce4b4dbc5,
+        0xaf37df8890373cc9,
+        0xed2e47393dadd3a1,
+        0xa45fb944821a6252,
+        0x69d44b1efab961f2,
+        0xab77a50d0eea0b52,
+        0x81f87a4a5425241e,
+        0xbabe622b9dbfe7fb,
+        0x4193a50ae44d53c3,
+        0x332108e0adec9b17,
+        0x514a6e97f2d45e8c,
+        0x1a9235fef2c0f63d,
+        0x42c8bb3b5b2cd4d1,
+        0x31aec00b79c7543c,
+        0x0b775ca455d8d8c6,
+        0x16279b36ee5a0c9d,
+        0x4a0793db9f3bd079,
+        0x9d151740744e9549,
+        0x43f4109e200d7f0f,
+        0xafa40fe923a0f69f,
+        0x5d4ec5b81a495c57,
+        0xf7ced6bc349f86af,
+        0x4ec39e6dbfd74d09,
+        0x683cf35151c1ca4f,
+        0x07415ec94c9f5902,
+        0x5be2f0595382a34b,
+        0x2804e14234862314,
+        0xef6fba75181d7073,
+        0x318a195eaf3acc69,
+        0x127169e1ea991158,
+        0x57cd6c5348c67c0b,
+        0x2d74d078ac443482,
+        0x729658999a3d14f3,
+        0xae284ba23490d255,
+        0xfededccea668173f,
+        0x97fc2e898775806e,
+        0x2341d841e346614c,
+        0x487c974a8342eecd,
+        0x9e7dc6c6779f6c6e,
+        0xba0926ddac1defc6,
+        0x058f1dd118e5d61b,
+        0x8cb9f701cc970908,
+        0xdb4ac42215de8a2c,
+        0xa3e6df9b98d2257c,
+        0x732cb142f5cef0c7,
+        0x665fd80bdf809e86,
+        0xd0391bb947257043,
+        0xe058dd678a1850ed,
+        0x48b112f987a88481,
+        0x82c71275ccd0230e,
+        0xc1e3c5670840c88b,
+        0xcfb8eb4226c11ccb,
+        0x2a51c3e40c2d0029,
+        0xd3039c50531b0d58,
+        0x65c84d84bfe0d397,
+        0x22661ff8bc55d630,
+        0x1cb69c39c53a4199,
+        0xda2691ddd0d8a32a,
+        0xa2fc8fb371c4bea1,
+        0xf27858fcc0b98abd,
+        0x01cf0de1e1d138b7,
+        0xa8f2391df4617f1f,
+        0x7c8c907df1290430,
+        0x60800f1365325fb5,
+        0x16181fb365a20a34,
+        0x16554c6ccb8706f1,
+        0xb5f5c134f3c04867,
+        0xb82bb3f45ca42035,
+        0x231f03f2303b415f,
+        0x6f11ec42e99b74ac,
+        0x486afa076c61407c,
+        0x867623708aeec53b,
+        0x40cb9292ff59872c,
+        0x332454c1fb2a2c73,
+        0xda389e9adb9e53ce,
+        0xbbcbd6d33fd70500,
+        0x3174f2bc8b9ddd55,
+        0x8deed827d23fc4a9,
+        0x77a67a89c8a48587,
+        0x595b5e9d4bd50430,
+        0x2455557fe6b1aef8,
+        0x4cdc9a8ab262327b,
+        0xb52b2a43301628ae,
+        0x9da0c11d48aa9451,
+        0x18220e9a17cdb1fe,
+        0x79cc5e2280d25b4a,
+        0xe3b2bbefc16d777b,
+        0xe41c853c04cf8fc2,
+        0x0d8b28986c948b84,
+        0xaecef0a24995e4dc,
+        0x967bc0323d4b89e7,
+        0xa744af095926b2b1,
+        0x58b706855d24f0f7,
+        0xfe0967baea88a162,
+        0xb25548a260e5fe93,
+        0x85dfdc3b4cc2028d,
+        0x65a284ced10b0027,
+        0x917bd60f779be733,
+        0x27dc8b25ae3168d4,
+        0xc0c77cc9fcee249a,
+        0x7f308e1ccb892a98,
+        0xf4288b9df145770c,
+        0x8c5c18aff87f4f21,
+        0x9e7ee73b4d1f0789,
+        0x5a579b60683e5985,
+        0xe042cae3cd7130de,
+        0xaa3a38ad019ec596,
+        0xd0c7b1b48dc36254,
+        0x10eadd51fdb5ee9f,
+        0x9113e12c0b1ade24,
+        0x27b1fcc5e78c712f,
+        0xd1ae33f3395030c3,
+        0x070f67be4e790d24,
+        0xae9e309178af1999,
+        0x3e71162a481a506e,
+        0x56df3a60921244e5,
+        0x5ce26d22b3e96b58,
+        0x1143b35a270ce0f1,
+        0xf2216980652e16d9,
+        0x055a8483f25640e8,
+        0x62e0d29158f46e53,
+        0x5cc6f97fb45c6038,
+        0x2e53e34f9819b8db,
+        0x3297542b8746dcb6,
+        0x116bbf229c30b872,
+        0xb8d3d36304df9fa7,
+        0x852d54e740f91364,
+        0x454080c44d60d35f,
+        0x425963d280b0eb2c,
+        0xb43f459993fdfd82,
+        0x0206f77fa4c868fe,
+        0xedb9738753f8d465,
+        0x4594e3a4dc1c4a5c,
+        0x84a0da303b1c2777,
+        0xf770f6990fc19d8b,
+        0x0820d0e294633a5a,
+        0x5c768452d03379bd,
+    ],
+    // 0B..15B << 244
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xaa96207c95477446,
+        0xbda300ac6dc37e54,
+        0x9e7a4d215b162cfb,
+        0x41db57106f2bd581,
+        0x568942f1fa87dbd3,
+        0x50f84d5e48b32f3c,
+        0x1fa440ca1384ba18,
+        0x4b660fd75e1eb97d,
+        0x7d5f18cc26144cee,
+        0xe44d138cadbcf221,
+        0xa6e212c8fce38f10,
+        0x40b01f15f225f50d,
+        0x5eba0bae6abab878,
+        0x60d9343c90f8dc00,
+        0x9984e189483b7e13,
+        0x35a5fb2cf36f2e1b,
+        0x2480256b15c13569,
+        0xf0d7c2c1197f678e,
+        0x1ef747fd966df7ff,
+        0x4ac8056fb405218b,
+        0x32f7bebbebfbfb2c,
+        0x871d1d0542eb4daf,
+        0xc317d2ef332d41c3,
+        0x32dd52f2924c7a9b,
+        0x43cb46d49f8839cf,
+        0xc0f111691385f3e7,
+        0x4caaee50bff68fd6,
+        0x5077e5087fbc3fe1,
+        0x8df451b14788c2cf,
+        0x0a5a5f1a5f084141,
+        0xa69628754fc7bc0f,
+        0x3e76d4c93ed3f303,
+        0x7111f3b7fe789fd5,
+        0x210b3e76829f4736,
+        0x80bedc27bbb225a3,
+        0x54226dfcf31a6ec4,
+        0xe216ca7a6dbcb472,
+        0xe057c849b8981b7c,
+        0x5027a6c5bfd01ac6,
+        0x6cdc058cd1ab29ca,
+        0xae738b6ffabf9e09,
+        0x1732d25331b46e65,
+        0x0f14404fc722f1c8,
+        0x278b6ade8974b30b,
+        0x7ec92da78d8d2e65,
+        0x7ed1649a7a763f66,
+        0x2fc8f3398e503a6b,
+        0x4afa3b6aabfcf1d3,
+        0x7ff6e75d26b34bd7,
+        0xdf8dfcf4d24cd8ef,
+        0xe436769a6f462cb8,
+        0x7803482bc21be5c9,
+        0x399507e98d3bea33,
+        0x708c61360fc98ca2,
+        0x82e75c9ddea756b4,
+        0x1fe2abc3c106a796,
+        0xc908a1e7c5582cc3,
+        0xc56b5e5bd8109a36,
+        0x547e7505ea0e8405,
+        0x30e35b0284b90a76,
+        0x415229f3aa7fd85d,
+        0xbe95077757fe8cd6,
+        0xbefe216aa226a4aa,
+        0x63cf02e80873340f,
+        0x3d7da9975bde258d,
+        0x0d905bdec41d9b2b,
+        0x276c6f2efdfd23f9,
+        0x3772bb9bbb92b23b,
+        0xb782c230ef77eab5,
+        0x7428bbf6187f746e,
+        0x203a805540b37480,
+        0x3b88c19ef3374cfb,
+        0x0f490f11c5d9475a,
+        0x3af3db28951c3a70,
+        0x7036df7a8c77d960,
+        0x31c00d1321bfab29,
+        0x6add0259017314e1,
+        0x8586f7b2764fc738,
+        0x6fa8d2f7bb380149,
+        0x59a1290e3db8a0dd,
+        0x196418f15418e95b,
+        0x8825f197793fd608,
+        0x3f3388a6429ccaa6,
+        0x6712607862e64895,
+        0xf726e4d4b4391c98,
+        0xeb900c7870f6b545,
+        0x5734c72b30a1c850,
+        0x380d4a34f65d1d91,
+        0xcf9994757b9759aa,
+        0x40044f9d50223438,
+        0x4cd18cacf9d310c5,
+        0x723c61f79d5284ff,
+        0x383c8effad932c91,
+        0x07f4a2980023ebcb,
+        0x27c2eff4de1cf538,
+        0x3105db017397ea6b,
+        0xd6491d306a640be4,
+        0xe7b97968ed90e4f2,
+        0xa7fdf30db002e11b,
+        0x23a13fc59b80ab62,
+        0x9cbe9aaaad9a7f78,
+        0x7e06136d5a9a00b4,
+        0xd99f63d9f6602ddc,
+        0x060f47a2ba08ab5b,
+        0x23e10bd74ec1c92e,
+        0x02f721bd251b2594,
+        0x868c35c4493616c8,
+        0x3b692a3949f0854f,
+        0xd68c62ede64e0b11,
+        0xfa1443a72662cece,
+        0xf98fa049c0586a53,
+        0x4860e54b16a6e1e9,
+        0xe1f7bfd1cce0fe0b,
+        0x43028c21c3196cab,
+        0x72a1c774557cb4c2,
+        0x5da736e99e16115e,
+        0xd05c38e35144e121,
+        0xb4881cfa626ca00c,
+        0x1fe2186b2a79ffce,
+        0x2e67a34695f34b31,
+        0x0fdbdbc782e522ec,
+        0xd0990c5c2c6387bc,
+        0x6f62534c53129e3d,
+        0x510e6bb29d74e1f3,
+        0xe599c30ce1abc1c4,
+        0xc504eaa1402ec7d1,
+        0xa76829b4e446f261,
+        0x24df5c364fe1f91f,
+        0xfce73480210e22ec,
+        0x43199c7034c6f274,
+        0xbe6392a2c2bbe91c,
+        0x3e5218e1a1e2c351,
+        0xd12ac4349e06c8ef,
+        0xf5a76a623a045358,
+        0x00f2eb27599e592b,
+        0x4171398c481c0615,
+        0x386b0b51d338f094,
+        0x62adf96e9dbc226e,
+        0xbf8bdf45eb7828d0,
+        0x188fb1c315c56c53,
+        0xa9fec822e22656bc,
+        0xabd8ad46667c7e93,
+        0x6f842ce7207c3d7e,
+        0x7e89572681a9368c,
+        0x803e6333d451e11e,
+        0x8dd2588ba5cf5640,
+        0x0cc3cbe4e4de3a68,
+        0x3a0fb7a873245d22,
+        0x362e68b213e4f4bc,
+        0x49b2a13264e65166,
+        0x3dae93ffc6fbfda8,
+        0x629a0dee48ec9d3f,
+        0x985ea0ef49c5cdd2,
+        0x790c3d8fc17e456f,
+        0x86eb09c91be94cfd,
+        0x6d528163cac6bf00,
+        0x3230e541fa04402a,
+        0xf1fc3a6e654c43c6,
+        0x3b6ab2eb605b1455,
+        0x479f17f2ccf60f03,
+        0xe5129f8ec2045ede,
+        0xe768497af6a80660,
+        0x225b107259d69b5c,
+        0x647423adaccd41b5,
+        0xd318531d6caf08e2,
+        0xbe0f3b6cb6185ca0,
+        0xe05e4afe1eeac18f,
+        0x4ced58cb7ba34e66,
+        0x285f851da5437e30,
+        0x4e5916e56f19a479,
+        0xa3f5a4275b53e38b,
+        0x2dc253c64c8ef249,
+        0x2f905fd970e12a38,
+        0x9f33c7a9b3a7deeb,
+        0x64537ea1ba30c11d,
+        0x3b5d38db2bd785ab,
+        0x946de2e1c952997d,
+        0xb5ac56816ee2dcb6,
+        0x36a1db1b5991f9c3,
+        0x4373c02f8465757e,
+    ],
+    // 0B..15B << 248
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x97366428dde1c949,
+        0x13edc7fac6795913,
+        0x9533409539350bdd,
+        0x00c7bd06547eaf90,
+        0x4964b30922013426,
+        0xfce4529e36d4a9df,
+        0xd389de287d13f5eb,
+        0x524071c2c640c067,
+        0xc097d0299247704b,
+        0xd472c002a6319d77,
+        0xdaedaf89c29d3118,
+        0x35a0fa9f83996bf0,
+        0xc340eaa146423e58,
+        0xf457575550760ce5,
+        0xb8937d78d6c917de,
+        0x145fd0f7ebb885bb,
+        0x6e8e02d539d46864,
+        0x4aa686ee5aac33bf,
+        0x5d9e1a9e0e6ff439,
+        0x7cdcba150dc05ddd,
+        0x6415da9f4883f8cb,
+        0xd51d3025242c734f,
+        0xbd5d39a802f63968,
+        0x1f78fbf79f98aec5,
+        0x942cfe6fa7cb0117,
+        0xfd0371d118822bc7,
+        0x63a31aff722403c0,
+        0x7daf614e65aa6d20,
+        0x820721ee74bc9655,
+        0xd518344bcdce501f,
+        0x1a042a27c79ab366,
+        0x0af6101e53b49e12,
+        0xab21f73985bc7801,
+        0x62e8ffa7d6c83bbf,
+        0x6e5d99120da65f83,
+        0x3559ebcce9f463ee,
+        0x32c5a86ea33b49ed,
+        0x142ffd02731bd419,
+        0x38653e5c4e8794c3,
+        0x354cbc39e941a028,
+        0xdb2583eab4ee979e,
+        0x5db5613c8aa6fa80,
+        0x81c2c8b0fbf01ba0,
+        0x3f64cbb112bb6f6b,
+        0x9af1a569c7b8c5e6,
+        0x994fceec17531ab2,
+        0x8d6bdce617765e51,
+        0x048487a9d9a7f27c,
+        0x77e9ce3c173b60c1,
+        0xece1a61bdd8ba344,
+        0x8cf6cd680c778800,
+        0x09ab6dc5217a8aad,
+        0xe6b0e364f23fdab7,
+        0xe2b8d7cad9fbb38c,
+        0x2bb5da3b6ea699db,
+        0x27e009d7466938cb,
+        0x6ad5d8383206d04e,
+        0x3524597343ae99d0,
+        0x5e6059e14c533939,
+        0x42f85fb8df547472,
+        0x365a45078e51e057,
+        0x700192db1a92f854,
+        0x224dcdc495c9364c,
+        0x22895f2684136271,
+        0x6c327b66b14acadd,
+        0x5044014870ef8630,
+        0x6ebe9468a3760525,
+        0x37b26154c2819f1f,
+        0xe389e2d6d7945c6f,
+        0x327eb52ae3a1b9fc,
+        0xcf562278ee3c28bb,
+        0x43a6392bbdb2d69f,
+        0x4bd61349f67a3cf2,
+        0xf3b842c37a9bf51c,
+        0xb35953c344bbc8ab,
+        0x3b209624d5425777,
+        0x66d32498f7373bcb,
+        0xd49f01aa64b14854,
+        0xe022f555c41a813d,
+        0x349be28c0f50bd2d,
+        0x9d82b77e583da8c3,
+        0xbf65721839ba3e53,
+        0xf834563661834472,
+        0x4f11640c9ab0b7f8,
+        0x6c5dd685e52783ed,
+        0xd5b087c05dc63f2c,
+        0x9ef79a98914746c4,
+        0x47203a0e9fecd8c5,
+        0xf7fdc604f345bf33,
+        0xfd7412a08e493ae3,
+        0x9220dd1385bb9a3b,
+        0x20ff1da863654815,
+        0xcb777ee9e9b9db70,
+        0xf5ad164cddc28ad5,
+        0x7e8a04ef166e8598,
+        0x5d8f889ba72e641f,
+        0xc373d52efb638dd1,
+        0xa0c91599a1e35693,
+        0x487a20d6e8d00244,
+        0x0760c2f83d1f72dc,
+        0x03193877e556f58b,
+        0x1474e163cb4c217b,
+        0x6bc673cb1578db56,
+        0x1c330a913491821a,
+        0xb6816d24dcff824c,
+        0xfb57e8b43c29c237,
+        0xdf35668f3f29adc5,
+        0x1a168b4e15170bdb,
+        0x53f8c4f1cdffa8fb,
+        0x79cac719f1ffd77c,
+        0x8c029ffa6ae4ea3a,
+        0x318ef3e3bce78f28,
+        0x8a7fa165d79e12df,
+        0xd3499d1e35358ca3,
+        0xfc8ad0f143cd1874,
+        0x3fa44414d945eb4e,
+        0xa9a63342a75d7dbb,
+        0x16cfa3609d5e4b45,
+        0x706b688ca218e816,
+        0x0b895471f02bbf4e,
+        0x2f4e92610c40a7c5,
+        0x881d83cf63bb6184,
+        0xc42414af989fd374,
+        0x485c600739e196f5,
+        0x900675de190ebcd1,
+        0x704b1a091a00a8af,
+        0x7638d77660687b98,
+        0x1bce3eb07ed0218e,
+        0x8b8f8b92dd01e13a,
+        0xd9bfd680e464220d,
+        0x9de0e800577262ab,
+        0x3bc5c016ad38ca18,
+        0x1ceed9af5f5af078,
+        0x5daab3dd69a4de51,
+        0xe693ada11435d3fe,
+        0x6923eeb00580f007,
+        0x57055232a13601db,
+        0xa4958c6fdda86729,
+        0x9fa6d10cc141a7f0,
+        0x5cf62ec345aa0dd5,
+        0xd4b04a31310e414d,
+        0xc6a8ea57a2f97232,
+        0xc9c91508295cffc6,
+        0x769a5c1cffe88a03,
+        0x28e89cb974efbf2e,
+        0xc0d6ed89caad9f4e,
+        0x3ad43d8a3d70d5f0,
+        0x41aa427efd2eda80,
+        0x6c69af95b138cdc0,
+        0x5949276b710ccaa2,
+        0x4865da8204401d2a,
+        0x022221cbc43f0521,
+        0xe858974d6c61d2cc,
+        0x9f5850b5f3b72cf5,
+        0x211c370869561758,
+        0x3a64885349879295,
+        0xeeee0845ae3a30f9,
+        0x5b5d7ff4d44536c4,
+        0xc7338dad9a0db297,
+        0x278c249e069aa825,
+        0xc96f994eb0ca6b2c,
+        0xcb5116a324236dfd,
+        0x58c41615f16ba450,
+        0x7f57e82c29df2bfc,
+        0x1d285087379f6216,
+        0x002149bbcdf7ff64,
+        0xa4fc8857452e44d8,
+        0x317bffba46879dde,
+        0x9571a31a9ebaee15,
+        0x264167ca3a88cd1b,
+        0x900ebbd3a274c99c,
+        0x54b25707033d512b,
+        0xb808236fdf2884be,
+        0x629abdc28db9bd58,
+        0x6590b51e90d70fdc,
+        0x63d79bf5e09fc312,
+        0x7425bcca24ab607c,
+        0x896232491ebcc88b,
+        0xf929f5b84f9080dd,
+        0x7a5271778d9245b8,
+    ],
+    // 0B..15B << 252
+    [
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000026,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xd6fec35d862b60b9,
+        0x14a58a78fa47e28d,
+        0x724a2bed1ea1864a,
+        0x6bb46efb9e2e1354,
+        0x51f27fff663871fc,
+        0x182d43375f1824ac,
+        0xc4c354e09a319f7e,
+        0x6ffb237b005d10ad,
+        0x2c48603e37b0b111,
+        0x35d4d29ec114ea65,
+        0x3fe37cb22ab09d7d,
+        0x3c632df3a8a33fb0,
+        0xf17044b22e33b9ae,
+        0x85248c27917b1261,
+        0xaa2eb840dd83017e,
+        0x37ece45d43dc79c6,
+        0x1a71041c634063c1,
+        0xf8311c6730b85ad3,
+        0x769e2bedb4be871b,
+        0x1cabc46bbac0ede1,
+        0xb90053c77bb81d83,
+        0x234fc14a61a3b7af,
+        0xc3086e6763f61d2b,
+        0x1636853690f6b203,
+        0x1d2df706564e6584,
+        0x13b118c41b8a57a3,
+        0x33cda7da2729fae2,
+        0x7e55487e7ace623d,
+        0xcfb1896d6a86b007,
+        0x00b42f03f7178d4b,
+        0x3e8bfa57d00237da,
+        0x0a13e62152b0df68,
+        0xa250c98c09c67532,
+        0x6c539e1fffc6f4db,
+        0x85a9bf1c89b1c4a6,
+        0x368215cc1ea088c7,
+        0xf4ae4f673a3b2dbf,
+        0xd65cb9bff7064521,
+        0x2c2f2c7ddf0c442c,
+        0x54ab3bc6be14a0b9,
+        0xf3ddb1d5aeeb5cea,
+        0xcfbdef34407cc2a2,
+        0x6b1fd3ceda0cf0c7,
+        0x3c7523caa78e18b0,
+        0x0a1314d650f8c85a,
+        0x03322a281f5abdca,
+        0x4ff79b8a4fce7b1e,
+        0x7422328278928ebf,
+        0xa527814e5a0140b3,
+        0x18a9ba165229ec6f,
+        0x1c6234c196db2b92,
+        0x24ab84d1d044e80b,
+        0x1812ebc8fcc4b2e0,
+        0xf064db1fb92dd0ed,
+        0x8f0e35b0c3b6760e,
+        0x4bf9417564438947,
+        0xfd98acf21339b918,
+        0xcfb071d8bda399ac,
+        0x3a82a853a00ef429,
+        0x74dbc042184207e2,
+        0x587d7b33f4bc5547,
+        0xc404be0e330373cd,
+        0x5937b2bb12569cd5,
+        0x125eedaf04317fc3,
+        0xff0e911b75a1bb51,
+        0xcc74d05d86128180,
+        0x1736dac33040354b,
+        0x689fe32f76d24172,
+        0x9d975700ab1a3133,
+        0xec8ba433c7915225,
+        0xb8c4bbb2bd26865c,
+        0x5d641c3efd14de56,
+        0x0aa1e29dda1860ba,
+        0xda7ae1a3e30615d1,
+        0x181e09889d6db8c1,
+        0x156d9f3e00bb2a62,
+        0x5a36339cb23174c1,
+        0xbc341b3820d6feb5,
+        0x891873fda9aff1bd,
+        0x4566f15510acdd47,
+        0x19ad3768466f3421,
+        0x5532b62e99d9df14,
+        0x14d3e79a5a4bf79f,
+        0x2b1c4dfb5f984197,
+        0x5b2399c4fb405e24,
+        0xde3d20119455940e,
+        0x7c66e63ae1843728,
+        0x651ce26854576098,
+        0x6d2a4ebbe0c60956,
+        0xe2ac2d314b717643,
+        0xacc84656f8e69135,
+        0x104ddb8e36ab4e4a,
+        0x59bfcaa7386af215,
+        0x0f6a20b7a1e36c33,
+        0xf798209b6a77a647,
+        0x38704c0646373b46,
+        0xc877f675270c15bf,
+        0xe94518acdde01551,
+        0xafa442ca757d09bf,
+        0x7a3ef75fc52eaaee,
+        0xc6a8f9f865bd4782,
+        0xf5d088b9058f4d3f,
+        0x5d76be78b8fed832,
+        0x6c397e3064bf8a29,
+        0x30d3ba756145d8c0,
+        0x6b13faf5d055f59f,
+        0xa9ced452d8ca1f1d,
+        0x13e945b7e8b3fc2c,
+        0xe3c5431f164d265b,
+        0xce63a7cee1ca9dd5,
+        0x499cf022cc7f5f36,
+        0x2c644670ec9ca40f,
+        0x357cc5381047ffd5,
+        0x60dd32e645ba01b9,
+        0x16e42ea5506c51b6,
+        0x74264020946dbaea,
+        0xc5676ac5d6eeef1e,
+        0x911f3b173b1802d5,
+        0x5304a83b3e5a2f19,
+        0x135d1dc32f1af90a,
+        0x3db2b5b1c4702c6b,
+        0x3f176faede4f8c34,
+        0xa0cd09dd5d98d7bf,
+        0x0e9f067e1aeb940c,
+        0xc75a4ad11879b427,
+        0x27f5d7e68f8a51ee,
+        0x9d937d618303348c,
+        0x5968bb9458acbdd1,
+        0xe9bd2093f53da9f7,
+        0xe49af4dea2ce282d,
+        0x03b57b442a41f187,
+        0x69b351a95c9a438e,
+        0x82a0ee25c1209b1a,
+        0xe1a544da39442ee5,
+        0xc16c388966f11765,
+        0x6c1c387a2c58455f,
+        0xce28b7da6adcda85,
+        0xc944845073fb8e33,
+        0x5f36373f87ba0775,
+        0x5e3fe96c969075f6,
+        0x13e59fe4ad5b49d7,
+        0xdc0c5d07ec6fba98,
+        0xa75e90fd7b7fc71b,
+        0x418d7f58312c44fc,
+        0x3bdd5e2c8ceb8c8e,
+        0x9602b78292462267,
+        0x51f7277d2ab1b7eb,
+        0x1aace2ce4d4aa4a2,
+        0xce083cb347fda7aa,
+        0xc2c014c90a0baf8a,
+        0xae8deca1da1abcf6,
+        0x62a7cf7b4046af43,
+        0xb13c57a80c9950cf,
+        0x94cdbcee7c4dac5e,
+        0xa1c3c98f06d1b806,
+        0x70bb24ea0c0c3015,
+        0x3114426be695ea3a,
+        0x452315736fc4eb3b,
+        0x4bdd295dc726c441,
+        0x4eb06796de1cc8ae,
+        0x267c91653fed71f6,
+        0xcaf8d82acd085e58,
+        0xa0d70d4ec38c12ab,
+        0x026801b875ca401e,
+        0x2deb6220976bb916,
+        0x6fb1cd278de2ae5a,
+        0xbb12bd270f007984,
+        0x2b58cdbb54471734,
+        0x47448ad48422298e,
+        0xefb004748ef9609e,
+        0xceaf106900ca1243,
+        0x4acac786e17a6274,
+        0x6244ff927c502953,
+        0x2f042846152acf1f,
+        0x480c4192882b6dd2,
+        0x08cfa20e4e1c4798,
+        0x32029a462b80c671,
+        0x19902d5e29e02a9c,
+        0x0cc06392d300ce43,
+        0x22a1d5fff78552a3,
+    ],
+];
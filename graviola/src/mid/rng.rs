@@ -1,21 +1,257 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use super::ctr_drbg::CtrDrbg;
+use super::sha2::Sha384Context;
 use crate::Error;
 
 /// The library's external and internal trait for all
 /// random consumption.
+///
+/// Key generation functions take a `&mut dyn RandomSource`, so keys
+/// can be generated from [`SystemRandom`] or (for example, in tests) a
+/// deterministic random bit generator.
 pub trait RandomSource {
+    /// Fills the entirety of `out` with random bytes.
+    ///
+    /// This should be cryptographic quality, unless only used in tests.
     fn fill(&mut self, out: &mut [u8]) -> Result<(), Error>;
 }
 
-/// Random generation from the system entropy source via
-/// the `getrandom` crate.
-pub(crate) struct SystemRandom;
+/// Random generation from the system entropy source.
+///
+/// This is the source registered with [`set_entropy_source()`], if any.
+/// Otherwise it is the operating system, via the `getrandom` crate.
+///
+/// If the `getrandom` feature is disabled and no source has been
+/// registered, this fails with [`Error::RngFailed`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemRandom;
 
 impl RandomSource for SystemRandom {
     fn fill(&mut self, out: &mut [u8]) -> Result<(), Error> {
-        getrandom::fill(out).map_err(|_| Error::RngFailed)
+        match ENTROPY_SOURCE.get() {
+            Some(source) => fill_tested(
+                *source,
+                &mut HEALTH.lock().unwrap_or_else(PoisonError::into_inner),
+                out,
+            ),
+            None => os_fill(out),
+        }
+    }
+}
+
+/// Fill `out` from a registered entropy source, applying the health tests.
+///
+/// If the tests fail, the output is discarded and the tests restart on
+/// fresh samples, up to `HEALTH_ATTEMPTS` times in total.
+fn fill_tested(
+    source: EntropySource,
+    health: &mut HealthTests,
+    out: &mut [u8],
+) -> Result<(), Error> {
+    for _ in 0..HEALTH_ATTEMPTS {
+        source(out)?;
+        if health.test(out) == HealthState::Healthy {
+            return Ok(());
+        }
+        out.fill(0);
+    }
+    Err(Error::RngFailed)
+}
+
+/// How many times [`fill_tested()`] draws from the source before giving up.
+const HEALTH_ATTEMPTS: usize = 3;
+
+#[cfg(feature = "getrandom")]
+fn os_fill(out: &mut [u8]) -> Result<(), Error> {
+    getrandom::fill(out).map_err(|_| Error::RngFailed)
+}
+
+#[cfg(not(feature = "getrandom"))]
+fn os_fill(_out: &mut [u8]) -> Result<(), Error> {
+    Err(Error::RngFailed)
+}
+
+/// A raw entropy source, for [`set_entropy_source()`].
+///
+/// This must fill the entirety of its argument with bytes of full
+/// entropy, or return an error.
+pub type EntropySource = fn(&mut [u8]) -> Result<(), Error>;
+
+static ENTROPY_SOURCE: OnceLock<EntropySource> = OnceLock::new();
+
+/// Register `source` as the system entropy source.
+///
+/// From then on, [`SystemRandom`] -- and so all key generation, nonce
+/// generation and DRBG seeding that uses it -- takes entropy from `source`
+/// instead of the operating system.  This is intended for targets where
+/// `getrandom` is not available, such as bare-metal or embedded systems.
+///
+/// Only one source may be registered in the lifetime of a process.
+/// Later calls fail with [`Error::RngFailed`], and do not change the
+/// registered source.
+pub fn set_entropy_source(source: EntropySource) -> Result<(), Error> {
+    ENTROPY_SOURCE.set(source).map_err(|_| Error::RngFailed)
+}
+
+/// The state of the continuous health tests on a registered entropy source.
+///
+/// Every byte taken from a source registered with [`set_entropy_source()`]
+/// passes through the repetition count test and the adaptive proportion
+/// test from SP800-90B section 4.4, treating each byte as a sample with
+/// full entropy and with a false positive probability of 2<sup>-40</sup>.
+///
+/// On a failure, the samples are discarded and the tests restart on fresh
+/// samples from the source.  The request fails with [`Error::RngFailed`]
+/// only if that keeps happening; later requests may succeed.
+///
+/// The operating system source is not tested here: it is a conditioned
+/// source, and the operating system does its own health testing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HealthState {
+    /// No failures have been detected.
+    Healthy,
+
+    /// The repetition count test failed: too many identical
+    /// consecutive samples were seen.
+    RepetitionCountFailure,
+
+    /// The adaptive proportion test failed: one sample value was
+    /// too common within a window of samples.
+    AdaptiveProportionFailure,
+}
+
+/// Return the result of the most recent continuous health test on a
+/// registered entropy source.
+///
+/// This is [`HealthState::Healthy`] if no source is registered.
+/// See [`HealthState`].
+pub fn health_state() -> HealthState {
+    HEALTH.lock().unwrap_or_else(PoisonError::into_inner).state
+}
+
+/// Return how many times the continuous health tests on a registered
+/// entropy source have failed, in the lifetime of the process.
+///
+/// See [`HealthState`].
+pub fn health_failures() -> u64 {
+    HEALTH
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .failures
+}
+
+static HEALTH: Mutex<HealthTests> = Mutex::new(HealthTests::new());
+
+struct HealthTests {
+    state: HealthState,
+    failures: u64,
+
+    rct_sample: u8,
+    rct_count: usize,
+
+    apt_sample: u8,
+    apt_count: usize,
+    apt_position: usize,
+}
+
+impl HealthTests {
+    const fn new() -> Self {
+        Self {
+            state: HealthState::Healthy,
+            failures: 0,
+            rct_sample: 0,
+            rct_count: 0,
+            apt_sample: 0,
+            apt_count: 0,
+            apt_position: 0,
+        }
+    }
+
+    /// Test `samples`, which follow on from those previously tested.
+    ///
+    /// On failure, the tests restart, so the next call starts afresh.
+    fn test(&mut self, samples: &[u8]) -> HealthState {
+        self.state = samples
+            .iter()
+            .map(|s| self.sample(*s))
+            .find(|state| *state != HealthState::Healthy)
+            .unwrap_or(HealthState::Healthy);
+
+        if self.state != HealthState::Healthy {
+            self.failures += 1;
+            self.rct_count = 0;
+            self.apt_position = 0;
+        }
+        self.state
+    }
+
+    fn sample(&mut self, s: u8) -> HealthState {
+        // SP800-90B 4.4.1
+        if self.rct_count > 0 && s == self.rct_sample {
+            self.rct_count += 1;
+            if self.rct_count >= RCT_CUTOFF {
+                return HealthState::RepetitionCountFailure;
+            }
+        } else {
+            self.rct_sample = s;
+            self.rct_count = 1;
+        }
+
+        // SP800-90B 4.4.2
+        if self.apt_position == 0 {
+            self.apt_sample = s;
+            self.apt_count = 1;
+        } else if s == self.apt_sample {
+            self.apt_count += 1;
+            if self.apt_count >= APT_CUTOFF {
+                return HealthState::AdaptiveProportionFailure;
+            }
+        }
+        self.apt_position = (self.apt_position + 1) % APT_WINDOW;
+        HealthState::Healthy
+    }
+}
+
+/// Repetition count test cutoff: 1 + ceil(-log2(alpha) / H), for
+/// alpha = 2^-40 and H = 8.
+const RCT_CUTOFF: usize = 6;
+
+/// Adaptive proportion test window size, for non-binary samples.
+const APT_WINDOW: usize = 512;
+
+/// Adaptive proportion test cutoff: 1 + CRITBINOM(W, 2^-H, 1 - alpha), for
+/// alpha = 2^-40 and H = 8.
+const APT_CUTOFF: usize = 20;
+
+/// A deterministic random source, for reproducible tests.
+///
+/// The same `seed` always produces the same output, on all platforms
+/// and in all versions of this crate.  Giving one to functions which take
+/// a `&mut dyn RandomSource` means their results can be reproduced, for
+/// example in protocol tests or known answer tests.
+///
+/// This is a [`CtrDrbg`] instantiated from the SHA-384 hash of the seed.
+/// Its output is therefore only as unpredictable as `seed`: do not
+/// use this outside of tests.
+pub struct TestRng(CtrDrbg);
+
+impl TestRng {
+    /// Make a new `TestRng` from `seed`.
+    pub fn new(seed: &[u8]) -> Self {
+        let mut ctx = Sha384Context::new();
+        ctx.update(seed);
+        // unwrap: personalization string is within the limit
+        Self(CtrDrbg::new(&ctx.finish(), b"graviola TestRng").unwrap())
+    }
+}
+
+impl RandomSource for TestRng {
+    fn fill(&mut self, out: &mut [u8]) -> Result<(), Error> {
+        self.0.fill(out)
     }
 }
 
@@ -38,3 +274,142 @@ impl RandomSource for SliceRandomSource<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn health_rct() {
+        let mut h = HealthTests::new();
+        assert_eq!(h.test(&[1, 2, 2, 2, 2, 2, 3]), HealthState::Healthy);
+        assert_eq!(h.test(&[3, 3, 3, 3]), HealthState::Healthy);
+        assert_eq!(h.test(&[3]), HealthState::RepetitionCountFailure);
+        assert_eq!(h.failures, 1);
+
+        // the tests restart after a failure
+        assert_eq!(h.test(&[3, 3, 3, 3, 3]), HealthState::Healthy);
+        assert_eq!(h.test(&[1, 2, 3]), HealthState::Healthy);
+        assert_eq!(h.failures, 1);
+    }
+
+    #[test]
+    fn health_apt() {
+        // a window whose first sample appears `count` times
+        fn window(count: usize) -> [u8; APT_WINDOW] {
+            let mut w = [0u8; APT_WINDOW];
+            for (i, s) in w.iter_mut().enumerate().skip(1) {
+                *s = (i % 255) as u8 + 1;
+            }
+            for s in w.iter_mut().step_by(2).take(count) {
+                *s = 0;
+            }
+            w
+        }
+
+        let mut h = HealthTests::new();
+        for _ in 0..4 {
+            assert_eq!(h.test(&window(APT_CUTOFF - 1)), HealthState::Healthy);
+        }
+        assert_eq!(
+            h.test(&window(APT_CUTOFF)),
+            HealthState::AdaptiveProportionFailure
+        );
+    }
+
+    #[test]
+    fn health_system_random() {
+        let mut buf = [0u8; 4096];
+        for _ in 0..16 {
+            SystemRandom.fill(&mut buf).unwrap();
+        }
+        assert_eq!(health_state(), HealthState::Healthy);
+    }
+
+    #[test]
+    fn health_failure_is_recoverable() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        // stuck on its first call, then counting
+        fn flaky_source(out: &mut [u8]) -> Result<(), Error> {
+            let call = CALLS.fetch_add(1, Ordering::SeqCst);
+            for (i, o) in out.iter_mut().enumerate() {
+                *o = if call == 0 { 0x55 } else { i as u8 };
+            }
+            Ok(())
+        }
+
+        fn stuck_source(out: &mut [u8]) -> Result<(), Error> {
+            out.fill(0x55);
+            Ok(())
+        }
+
+        let mut h = HealthTests::new();
+        let mut buf = [0u8; 64];
+        fill_tested(flaky_source, &mut h, &mut buf).unwrap();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+        assert_eq!(buf[63], 63);
+        assert_eq!(h.failures, 1);
+
+        assert_eq!(
+            fill_tested(stuck_source, &mut h, &mut buf),
+            Err(Error::RngFailed)
+        );
+        assert_eq!(buf, [0u8; 64]);
+        assert_eq!(h.failures, 1 + HEALTH_ATTEMPTS as u64);
+
+        // a working source is usable again
+        fill_tested(flaky_source, &mut h, &mut buf).unwrap();
+        assert_eq!(h.state, HealthState::Healthy);
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn entropy_source() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        // nb. this is process-wide, so must remain a good source for other tests
+        fn counting_source(out: &mut [u8]) -> Result<(), Error> {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            os_fill(out)
+        }
+
+        fn other_source(_out: &mut [u8]) -> Result<(), Error> {
+            panic!("must not be registered");
+        }
+
+        set_entropy_source(counting_source).unwrap();
+        assert_eq!(set_entropy_source(other_source), Err(Error::RngFailed));
+
+        let before = CALLS.load(Ordering::SeqCst);
+        SystemRandom.fill(&mut [0u8; 16]).unwrap();
+        assert!(CALLS.load(Ordering::SeqCst) > before);
+    }
+
+    #[test]
+    fn test_rng_is_reproducible() {
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 64];
+        TestRng::new(b"seed").fill(&mut a).unwrap();
+        TestRng::new(b"seed").fill(&mut b).unwrap();
+        assert_eq!(a, b);
+
+        TestRng::new(b"other seed").fill(&mut b).unwrap();
+        assert_ne!(a, b);
+
+        // fixed for all time
+        let mut a = [0u8; 16];
+        TestRng::new(b"").fill(&mut a).unwrap();
+        assert_eq!(
+            a,
+            [
+                0x21, 0xd2, 0xff, 0xc4, 0x17, 0x3a, 0xeb, 0x9b, 0x83, 0x3e, 0xa9, 0x52, 0x41, 0x37,
+                0x05, 0xd4
+            ]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 // Written for Graviola by Joe Birr-Pixton, 2024.
 // SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
 
+use super::rng::RandomSource;
 use super::rsa_pub::{MAX_PUBLIC_MODULUS_BYTES, RsaPublicKey};
 use crate::error::Error;
 use crate::low;
@@ -73,6 +74,70 @@ impl RsaPrivateKey {
         })
     }
 
+    /// Like `new()`, but computes the CRT exponents `dp` and `dq` from `d`.
+    ///
+    /// This is for formats which do not include them.
+    pub(crate) fn new_without_crt_exponents(
+        p: RsaPosIntModP,
+        q: RsaPosIntModP,
+        d: RsaPosIntD,
+        iqmp: RsaPosIntModP,
+        n: RsaPosIntModN,
+        e: u32,
+    ) -> Result<Self, Error> {
+        let dp = crt_exponent(&d, &p)?;
+        let dq = crt_exponent(&d, &q)?;
+        Self::new(p, q, d, dp, dq, iqmp, n, e)
+    }
+
+    /// Generates a new key with a `bits`-bit modulus, and public exponent 65537.
+    ///
+    /// This follows FIPS 186-5 appendix A.1.3, choosing random probable
+    /// primes `p` and `q` with their top two bits set (so `n` is exactly
+    /// `bits` long), and `p` = `q` = 3 mod 4.  That latter choice means
+    /// the Miller-Rabin test has a fixed shape, so it is side-channel
+    /// silent like the rest of the arithmetic here.
+    pub(crate) fn generate(bits: usize, rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        const E: u32 = 65537;
+        let prime_bits = bits / 2;
+
+        loop {
+            let p = generate_prime(prime_bits, E, rng)?;
+            let q = generate_prime(prime_bits, E, rng)?;
+            let (p, q) = match q.less_than(&p) {
+                true => (p, q),
+                false => (q, p),
+            };
+
+            // |p - q| > 2 ^ (prime_bits - 100)
+            if p.sub_mod(&q, &p).bit_len() <= prime_bits - 100 {
+                continue;
+            }
+
+            // d = e ^ -1 mod (p - 1)(q - 1), computed as (1 + phi * (e - t)) / e,
+            // where t = phi ^ -1 mod e.
+            let phi: RsaPosIntD = low::PosInt::mul(&p.odd_minus_one(), &q.odd_minus_one());
+            let t = small_inverse(phi.rem_small(E), E);
+            let (d, _) = phi.mul_small(E - t).add(&phi.fixed_one()).div_small(E);
+
+            if d.bit_len() <= bits / 2 {
+                continue;
+            }
+
+            // iqmp = q ^ (p - 2) mod p
+            let p_minus_two = p.odd_minus_one().sub_mod(&p.fixed_one(), &p);
+            let iqmp = q.mont_exp(&p_minus_two, &p, &p.montifier(), p.mont_neg_inverse());
+
+            let n = low::PosInt::mul(&p, &q);
+            let key = Self::new_without_crt_exponents(p, q, d, iqmp, n, E)?;
+
+            // pairwise consistency test: `private_op` checks its result
+            // using the public key
+            key.private_op(&RsaPosIntModN::from_bytes(&[2])?)?;
+            return Ok(key);
+        }
+    }
+
     pub(crate) fn public_key(&self) -> RsaPublicKey {
         self.public.clone()
     }
@@ -133,8 +198,10 @@ impl RsaPrivateKey {
 
         // A note about blinding:
         //
-        // In this library, we only perform RSA signatures, where `c` is
-        // public information, there is little use for base blinding.
+        // In this library, we mostly perform RSA signatures, where `c` is
+        // public information, so there is little use for base blinding.
+        // For RSAES-OAEP decryption `c` is chosen by the peer, but it is
+        // still not secret.
         //
         // Exponent and modulus blinding are _also_ relatively unnecessary,
         // since our `PosInt::mont_exp` is side-channel silent.
@@ -158,7 +225,7 @@ impl RsaPrivateKey {
 
         // iv.  Let m = m_2 + q * h.
         let m = m_2.widen().add(&low::PosInt::mul(&self.q, &h));
-        let m = low::ct::into_public(m);
+        let m = m.into_public();
 
         // validate the result as a fault attack countermeasure,
         // at the same time it validates our working above, and
@@ -179,6 +246,126 @@ impl Drop for RsaPrivateKey {
     }
 }
 
+/// Returns `d` mod (`p` - 1).
+fn crt_exponent(d: &RsaPosIntD, p: &RsaPosIntModP) -> Result<RsaPosIntModP, Error> {
+    if p.is_even() {
+        return Err(Error::OutOfRange);
+    }
+    let p_minus_one: RsaPosIntD = p.odd_minus_one().widen();
+    d.reduce_slow(&p_minus_one).narrow()
+}
+
+/// Generates a random `bits`-bit probable prime `p`, such that `p` - 1 is
+/// coprime to `e`.
+fn generate_prime(bits: usize, e: u32, rng: &mut dyn RandomSource) -> Result<RsaPosIntModP, Error> {
+    let mut bytes = [0u8; MAX_PRIVATE_MODULUS_BYTES];
+    let bytes = &mut bytes[..bits / 8];
+
+    // nb. a working random source finds a prime within a few thousand
+    // candidates, so this limit is only reached by broken ones.
+    for _ in 0..bits * 64 {
+        rng.fill(bytes)?;
+        bytes[0] |= 0xc0;
+        bytes[bytes.len() - 1] |= 0x03;
+        let candidate = RsaPosIntModP::from_bytes(bytes)?;
+
+        // rejecting composites with small factors is much quicker than
+        // the Miller-Rabin test.
+        if candidate.rem_small(e) == 1
+            || SMALL_PRIMES
+                .iter()
+                .any(|prime| candidate.rem_small(*prime as u32) == 0)
+        {
+            continue;
+        }
+
+        if is_probable_prime(&candidate, rng)? {
+            low::zeroise(bytes);
+            return Ok(candidate);
+        }
+    }
+
+    low::zeroise(bytes);
+    Err(Error::RngFailed)
+}
+
+/// The Miller-Rabin probabilistic primality test for `w`, where
+/// `w` = 3 mod 4.
+///
+/// The number of rounds gives an error probability below 2^-100 for
+/// random candidates of 1024 bits or longer (see FIPS 186-5 table B.1).
+fn is_probable_prime(w: &RsaPosIntModP, rng: &mut dyn RandomSource) -> Result<bool, Error> {
+    const ROUNDS: usize = 5;
+
+    let montifier = w.montifier();
+    let w0 = w.mont_neg_inverse();
+    let one = w.fixed_one();
+    let two = one.add(&one);
+    let w_minus_one = w.odd_minus_one();
+    // (w - 1) / 2 is odd, so this is the whole of the test
+    let (exponent, _) = w.div_small(2);
+
+    let mut bytes = [0u8; MAX_PRIVATE_MODULUS_BYTES];
+    let bytes = &mut bytes[..w.len_bytes()];
+
+    for _ in 0..ROUNDS {
+        // choose a random base 1 < b < w - 1
+        let mut base = None;
+        for _ in 0..64 {
+            rng.fill(bytes)?;
+            let mut b = RsaPosIntModP::from_bytes(bytes)?;
+            if !b.less_than(&two) && b.less_than(&w_minus_one) {
+                b.expand(w);
+                base = Some(b);
+                break;
+            }
+        }
+        let base = base.ok_or(Error::RngFailed)?;
+
+        let z = base.mont_exp(&exponent, w, &montifier, w0);
+        if !(z.equals(&one) || z.equals(&w_minus_one)) {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Returns `a` ^ -1 mod `m`, where `m` is prime.
+fn small_inverse(a: u32, m: u32) -> u32 {
+    // by Fermat's little theorem, a ^ (m - 2)
+    let (a, m) = (a as u64, m as u64);
+    let mut result = 1;
+    for bit in (0..32).rev() {
+        result = result * result % m;
+        if ((m - 2) >> bit) & 1 == 1 {
+            result = result * a % m;
+        }
+    }
+    result as u32
+}
+
+/// The odd primes below 2048.
+static SMALL_PRIMES: [u16; 308] = small_primes();
+
+const fn small_primes() -> [u16; 308] {
+    let mut primes = [0u16; 308];
+    let mut i = 0;
+    let mut candidate = 3;
+    while i < primes.len() {
+        let mut divisor = 3;
+        while divisor * divisor <= candidate && candidate % divisor != 0 {
+            divisor += 2;
+        }
+        if divisor * divisor > candidate {
+            primes[i] = candidate as u16;
+            i += 1;
+        }
+        candidate += 2;
+    }
+    primes
+}
+
 pub(crate) struct RsaComponents<'a> {
     pub(crate) public_modulus: &'a [u8],
     pub(crate) public_exponent: &'a [u8],
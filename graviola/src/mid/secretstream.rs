@@ -0,0 +1,513 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+use super::rng::{RandomSource, SystemRandom};
+use crate::Error;
+use crate::low::chacha20::ChaCha20;
+use crate::low::poly1305::Poly1305;
+use crate::low::{Entry, ct_equal, hchacha20, zeroise};
+
+/// The tag attached to each message of a [`SecretStreamPush`] stream.
+///
+/// Tags are encrypted and authenticated along with their message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecretStreamTag {
+    /// An ordinary message.
+    Message,
+    /// Marks the end of a set of messages, but not the end of the stream.
+    Push,
+    /// Rekeys the stream after this message.
+    Rekey,
+    /// The last message of the stream.
+    ///
+    /// This also rekeys the stream.
+    Final,
+}
+
+impl SecretStreamTag {
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Message => 0x00,
+            Self::Push => 0x01,
+            Self::Rekey => 0x02,
+            Self::Final => 0x03,
+        }
+    }
+
+    fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0x00 => Some(Self::Message),
+            0x01 => Some(Self::Push),
+            0x02 => Some(Self::Rekey),
+            0x03 => Some(Self::Final),
+            _ => None,
+        }
+    }
+}
+
+/// The encrypting side of a stream of messages.
+///
+/// This is libsodium's `crypto_secretstream_xchacha20poly1305`, and is
+/// interoperable with it.  A stream starts with a 24-byte header, and
+/// is followed by a sequence of messages which are each
+/// [`SecretStreamPush::OVERHEAD`] bytes longer than their plaintext.
+///
+/// The messages of a stream must be decrypted in the order they were
+/// encrypted: removal, reordering, duplication or truncation (before a
+/// [`SecretStreamTag::Final`] message) is detected by [`SecretStreamPull`].
+pub struct SecretStreamPush {
+    state: State,
+}
+
+impl SecretStreamPush {
+    /// The length of a stream header.
+    pub const HEADER_LEN: usize = HEADER_LEN;
+
+    /// The number of bytes encryption adds to each message.
+    pub const OVERHEAD: usize = OVERHEAD;
+
+    /// Start a new stream with `key`.
+    ///
+    /// A random header is generated, which must be sent to the recipient
+    /// before the stream's messages.
+    pub fn new(key: &[u8; 32]) -> Result<(Self, [u8; HEADER_LEN]), Error> {
+        Self::new_with_rng(key, &mut SystemRandom)
+    }
+
+    /// Start a new stream with `key`, using `rng` to generate the header.
+    ///
+    /// Otherwise, as [`Self::new()`].
+    pub fn new_with_rng(
+        key: &[u8; 32],
+        rng: &mut dyn RandomSource,
+    ) -> Result<(Self, [u8; HEADER_LEN]), Error> {
+        let mut header = [0u8; HEADER_LEN];
+        rng.fill(&mut header)?;
+        let _entry = Entry::new_secret();
+        Ok((
+            Self {
+                state: State::new(key, &header),
+            },
+            header,
+        ))
+    }
+
+    /// Encrypt `message` with tag `tag`, writing the result to `out`.
+    ///
+    /// `aad` is additionally-authenticated data, which is not included in
+    /// the output but must be supplied again to decrypt it.  It may be
+    /// empty.
+    ///
+    /// `out` must be at least [`Self::OVERHEAD`] bytes longer than
+    /// `message`, otherwise [`Error::WrongLength`] is returned.  The used
+    /// prefix of `out` is returned.
+    pub fn push<'a>(
+        &mut self,
+        message: &[u8],
+        aad: &[u8],
+        tag: SecretStreamTag,
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], Error> {
+        if message.len() as u64 > MESSAGE_MAX {
+            return Err(Error::WrongLength);
+        }
+        let out = out
+            .get_mut(..message.len() + OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+
+        let _entry = Entry::new_secret();
+        let (out_tag, rest) = out.split_at_mut(1);
+        let (ciphertext, mac) = rest.split_at_mut(message.len());
+
+        let mut block = [0u8; 64];
+        block[0] = tag.to_byte();
+        self.state.cipher(1, &mut block);
+        out_tag[0] = block[0];
+
+        ciphertext.copy_from_slice(message);
+        self.state.cipher(2, ciphertext);
+
+        let expected = self.state.mac(aad, &block, ciphertext);
+        mac.copy_from_slice(&expected);
+        self.state.advance(tag.to_byte(), &expected);
+        Ok(out)
+    }
+
+    /// Explicitly rekey the stream.
+    ///
+    /// The recipient must call [`SecretStreamPull::rekey`] at the same
+    /// point in the stream.
+    pub fn rekey(&mut self) {
+        let _entry = Entry::new_secret();
+        self.state.rekey();
+    }
+}
+
+/// The decrypting side of a stream of messages.
+///
+/// See [`SecretStreamPush`].
+pub struct SecretStreamPull {
+    state: State,
+}
+
+impl SecretStreamPull {
+    /// The number of bytes encryption adds to each message.
+    pub const OVERHEAD: usize = OVERHEAD;
+
+    /// Start decrypting a stream with `key` and its `header`.
+    pub fn new(key: &[u8; 32], header: &[u8; HEADER_LEN]) -> Self {
+        let _entry = Entry::new_secret();
+        Self {
+            state: State::new(key, header),
+        }
+    }
+
+    /// Decrypt and verify the next message of the stream, `ciphertext`,
+    /// writing the message to `out`.
+    ///
+    /// `aad` must match that given to [`SecretStreamPush::push()`].
+    ///
+    /// `out` must be at least as long as the message ([`Self::OVERHEAD`]
+    /// bytes shorter than `ciphertext`), otherwise [`Error::WrongLength`]
+    /// is returned.  On success, the used prefix of `out` and the message's
+    /// tag are returned.
+    ///
+    /// Otherwise, [`Error::DecryptFailed`] is returned and the stream is
+    /// unchanged.  This includes messages with a tag value that libsodium
+    /// does not define.
+    ///
+    /// The caller must check for a [`SecretStreamTag::Final`] tag: if the
+    /// stream ends without one, it has been truncated.
+    pub fn pull<'a>(
+        &mut self,
+        ciphertext: &[u8],
+        aad: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<(&'a [u8], SecretStreamTag), Error> {
+        let len = ciphertext
+            .len()
+            .checked_sub(OVERHEAD)
+            .ok_or(Error::WrongLength)?;
+        let out = out.get_mut(..len).ok_or(Error::WrongLength)?;
+
+        let _entry = Entry::new_secret();
+        let (in_tag, rest) = ciphertext.split_at(1);
+        let (ciphertext, mac) = rest.split_at(len);
+
+        let mut block = [0u8; 64];
+        block[0] = in_tag[0];
+        self.state.cipher(1, &mut block);
+        let tag = block[0];
+        block[0] = in_tag[0];
+
+        let expected = self.state.mac(aad, &block, ciphertext);
+        if !ct_equal(&expected, mac) {
+            return Err(Error::DecryptFailed);
+        }
+        let tag = SecretStreamTag::from_byte(tag).ok_or(Error::DecryptFailed)?;
+
+        out.copy_from_slice(ciphertext);
+        self.state.cipher(2, out);
+        self.state.advance(tag.to_byte(), &expected);
+        Ok((out, tag))
+    }
+
+    /// Explicitly rekey the stream.
+    ///
+    /// This must be called at the same point in the stream as
+    /// [`SecretStreamPush::rekey`] was.
+    pub fn rekey(&mut self) {
+        let _entry = Entry::new_secret();
+        self.state.rekey();
+    }
+}
+
+struct State {
+    key: [u8; 32],
+    counter: u32,
+    inonce: [u8; INONCE_LEN],
+}
+
+impl State {
+    fn new(key: &[u8; 32], header: &[u8; HEADER_LEN]) -> Self {
+        let (hchacha_nonce, inonce) = header.split_at(16);
+        Self {
+            key: hchacha20(key, hchacha_nonce.try_into().unwrap()),
+            counter: 1,
+            inonce: inonce.try_into().unwrap(),
+        }
+    }
+
+    /// XOR `buffer` with the ChaCha20 keystream for the current nonce,
+    /// starting at block `block_counter`.
+    fn cipher(&self, block_counter: u32, buffer: &mut [u8]) {
+        let mut nonce = [0u8; 16];
+        nonce[0..4].copy_from_slice(&block_counter.to_le_bytes());
+        nonce[4..8].copy_from_slice(&self.counter.to_le_bytes());
+        nonce[8..16].copy_from_slice(&self.inonce);
+        ChaCha20::new(&self.key, &nonce).cipher(buffer);
+    }
+
+    fn mac(&self, aad: &[u8], block: &[u8; 64], ciphertext: &[u8]) -> [u8; 16] {
+        let mut poly_key = [0u8; 32];
+        self.cipher(0, &mut poly_key);
+        let mut poly = Poly1305::new(&poly_key);
+        zeroise(&mut poly_key);
+
+        let pad = [0u8; 16];
+        poly.add_bytes(aad);
+        poly.add_bytes(&pad[..aad.len().wrapping_neg() & 0xf]);
+        poly.add_bytes(block);
+        poly.add_bytes(ciphertext);
+        // nb. libsodium computes this as `(0x10 - 64 + mlen) & 0xf`,
+        // which is not the usual padding to a multiple of 16 bytes.
+        poly.add_bytes(&pad[..ciphertext.len() & 0xf]);
+        poly.add_bytes(&(aad.len() as u64).to_le_bytes());
+        poly.add_bytes(&((block.len() + ciphertext.len()) as u64).to_le_bytes());
+        poly.finish()
+    }
+
+    /// Move to the next message, after one with `tag` and `mac`.
+    fn advance(&mut self, tag: u8, mac: &[u8; 16]) {
+        for (n, m) in self.inonce.iter_mut().zip(mac) {
+            *n ^= *m;
+        }
+        self.counter = self.counter.wrapping_add(1);
+        if tag & TAG_REKEY_BIT != 0 || self.counter == 0 {
+            self.rekey();
+        }
+    }
+
+    fn rekey(&mut self) {
+        let mut new = [0u8; 32 + INONCE_LEN];
+        new[..32].copy_from_slice(&self.key);
+        new[32..].copy_from_slice(&self.inonce);
+        self.cipher(0, &mut new);
+        self.key.copy_from_slice(&new[..32]);
+        self.inonce.copy_from_slice(&new[32..]);
+        zeroise(&mut new);
+        self.counter = 1;
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        zeroise(&mut self.key);
+    }
+}
+
+const HEADER_LEN: usize = 24;
+const INONCE_LEN: usize = 8;
+/// One byte of encrypted tag, and a 16-byte Poly1305 MAC.
+const OVERHEAD: usize = 17;
+const TAG_REKEY_BIT: u8 = 0x02;
+/// The ChaCha20 block counter starts at 2 for the message.
+const MESSAGE_MAX: u64 = 64 * ((1 << 32) - 2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Message, associated data, tag (or `None` for an explicit rekey)
+    /// and ciphertext.
+    type Vector = (Vec<u8>, Vec<u8>, Option<SecretStreamTag>, Vec<u8>);
+
+    fn messages() -> Vec<Vector> {
+        // from libsodium
+        vec![
+            (
+                b"hello".to_vec(),
+                vec![],
+                Some(SecretStreamTag::Message),
+                unhex("cbddb760b7d62047dba8e4568a030ba01f33006b7d99"),
+            ),
+            (
+                (0..100).collect(),
+                b"ad".to_vec(),
+                Some(SecretStreamTag::Push),
+                unhex(
+                    "5e7e2631e6ccc32162e3f0cb725dc606d0892a757a30f76bc3221184deaa74eb\
+                     20ec83a8928b8e4dfb9ac81f1b4ee5424a57ad6bb06ac1bfb050beb317dc71e0\
+                     d482e8aecbdfc170b1f92ede36fdd74a4c9acf85ca46d9c1d1c61ad7a985fce9\
+                     452d439d81d2c143a0ae8245a1ce9f132972c5f88e",
+                ),
+            ),
+            (
+                vec![],
+                vec![],
+                Some(SecretStreamTag::Rekey),
+                unhex("c38aded19b0786caa5369dff65bd2763a1"),
+            ),
+            (
+                b"after rekey".to_vec(),
+                vec![],
+                Some(SecretStreamTag::Message),
+                unhex("a4d4fd817e7e89828891760af7e9baa6772945586047c8c6d7856a5f"),
+            ),
+            // explicit rekey
+            (vec![], vec![], None, vec![]),
+            (
+                b"the end".to_vec(),
+                (0..17).collect(),
+                Some(SecretStreamTag::Final),
+                unhex("ea60fa817abd290a9a904bfddac60973780a2e9b3d3f1f25"),
+            ),
+        ]
+    }
+
+    const KEY: [u8; 32] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f,
+    ];
+    const HEADER: &str = "1de64af9099f38a481683019fb450d6b31e889757f59d116";
+
+    #[test]
+    fn push_libsodium_vectors() {
+        let header = unhex(HEADER);
+        let (mut push, actual_header) =
+            SecretStreamPush::new_with_rng(&KEY, &mut SliceRandomSource(&header)).unwrap();
+        assert_eq!(&actual_header[..], &header);
+
+        for (message, aad, tag, expected) in messages() {
+            match tag {
+                Some(tag) => {
+                    let mut out = [0u8; 128];
+                    let out = push.push(&message, &aad, tag, &mut out).unwrap();
+                    assert_eq!(out, &expected);
+                }
+                None => push.rekey(),
+            }
+        }
+    }
+
+    #[test]
+    fn pull_libsodium_vectors() {
+        let header: [u8; 24] = unhex(HEADER).try_into().unwrap();
+        let mut pull = SecretStreamPull::new(&KEY, &header);
+
+        for (message, aad, tag, ciphertext) in messages() {
+            match tag {
+                Some(tag) => {
+                    let mut out = [0u8; 128];
+                    let (out, actual_tag) = pull.pull(&ciphertext, &aad, &mut out).unwrap();
+                    assert_eq!(out, &message);
+                    assert_eq!(actual_tag, tag);
+                }
+                None => pull.rekey(),
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = [0x42; 32];
+        let (mut push, header) = SecretStreamPush::new(&key).unwrap();
+        let mut pull = SecretStreamPull::new(&key, &header);
+
+        let mut ciphertext = [0u8; 64 + OVERHEAD];
+        let mut plaintext = [0u8; 64];
+        for len in 0..64 {
+            let message = vec![len as u8; len];
+            let ct = push
+                .push(&message, b"aad", SecretStreamTag::Message, &mut ciphertext)
+                .unwrap();
+            let (pt, tag) = pull.pull(ct, b"aad", &mut plaintext).unwrap();
+            assert_eq!(pt, &message);
+            assert_eq!(tag, SecretStreamTag::Message);
+        }
+    }
+
+    #[test]
+    fn tampering() {
+        let key = [0x42; 32];
+        let (mut push, header) = SecretStreamPush::new(&key).unwrap();
+        let mut first = [0u8; 5 + OVERHEAD];
+        let first = push
+            .push(b"first", b"", SecretStreamTag::Message, &mut first)
+            .unwrap()
+            .to_vec();
+        let mut second = [0u8; 6 + OVERHEAD];
+        let second = push
+            .push(b"second", b"", SecretStreamTag::Final, &mut second)
+            .unwrap()
+            .to_vec();
+
+        let mut pull = SecretStreamPull::new(&key, &header);
+        let mut out = [0u8; 6];
+
+        // any alteration is detected
+        for i in 0..first.len() {
+            let mut altered = first.clone();
+            altered[i] ^= 1;
+            assert_eq!(
+                pull.pull(&altered, b"", &mut out),
+                Err(Error::DecryptFailed)
+            );
+        }
+        assert_eq!(pull.pull(&first, b"x", &mut out), Err(Error::DecryptFailed));
+
+        // reordering is detected
+        assert_eq!(pull.pull(&second, b"", &mut out), Err(Error::DecryptFailed));
+
+        // failures leave the stream unchanged
+        assert_eq!(
+            pull.pull(&first, b"", &mut out).unwrap(),
+            (&b"first"[..], SecretStreamTag::Message)
+        );
+
+        // replay is detected
+        assert_eq!(pull.pull(&first, b"", &mut out), Err(Error::DecryptFailed));
+        assert_eq!(
+            pull.pull(&second, b"", &mut out).unwrap(),
+            (&b"second"[..], SecretStreamTag::Final)
+        );
+
+        // a different header gives a different stream
+        let mut other_header = header;
+        other_header[23] ^= 1;
+        let mut pull = SecretStreamPull::new(&key, &other_header);
+        assert_eq!(pull.pull(&first, b"", &mut out), Err(Error::DecryptFailed));
+    }
+
+    #[test]
+    fn counter_wrap_rekeys() {
+        let mut state = State::new(&KEY, &[0u8; 24]);
+        let key = state.key;
+        state.counter = u32::MAX;
+        state.advance(SecretStreamTag::Message.to_byte(), &[0u8; 16]);
+        assert_eq!(state.counter, 1);
+        assert_ne!(state.key, key);
+    }
+
+    #[test]
+    fn invalid() {
+        let (mut push, header) = SecretStreamPush::new(&KEY).unwrap();
+        assert_eq!(
+            push.push(b"hello", b"", SecretStreamTag::Message, &mut [0u8; 21]),
+            Err(Error::WrongLength)
+        );
+
+        let mut pull = SecretStreamPull::new(&KEY, &header);
+        assert_eq!(
+            pull.pull(&[0u8; 16], b"", &mut [0u8; 16]),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            pull.pull(&[0u8; 22], b"", &mut [0u8; 4]),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            SecretStreamPush::new_with_rng(&KEY, &mut SliceRandomSource(&[0u8; 23])).err(),
+            Some(Error::RngFailed)
+        );
+    }
+}
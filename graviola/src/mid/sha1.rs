@@ -0,0 +1,119 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! SHA-1, as described in [FIPS180](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf).
+//!
+//! SHA-1 is not collision resistant.  This exists only for HMAC-SHA1,
+//! which is the default for HOTP and TOTP.
+
+use crate::low::Blockwise;
+
+/// A context for incremental computation of SHA-1.
+#[derive(Clone)]
+pub struct Sha1Context {
+    h: [u32; 5],
+    blockwise: Blockwise<{ Self::BLOCK_SZ }>,
+    nblocks: usize,
+}
+
+impl Sha1Context {
+    /// Start a new SHA-1 hash computation.
+    pub const fn new() -> Self {
+        Self {
+            h: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
+            blockwise: Blockwise::new(),
+            nblocks: 0,
+        }
+    }
+
+    /// Add `bytes` to the ongoing hash computation.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let bytes = self.blockwise.add_leading(bytes);
+
+        if let Some(block) = self.blockwise.take() {
+            self.update_blocks(&block);
+        }
+
+        let whole_len = bytes.len() - (bytes.len() & (Self::BLOCK_SZ - 1));
+        let (whole_blocks, remainder) = bytes.split_at(whole_len);
+        self.update_blocks(whole_blocks);
+        self.blockwise.add_trailing(remainder);
+    }
+
+    /// Complete the SHA-1 computation, returning the hash output.
+    pub fn finish(mut self) -> [u8; Self::OUTPUT_SZ] {
+        let bytes = self
+            .nblocks
+            .checked_mul(Self::BLOCK_SZ)
+            .and_then(|bytes| bytes.checked_add(self.blockwise.used()))
+            .unwrap();
+
+        let bits = bytes
+            .checked_mul(8)
+            .expect("excess data processed by hash function");
+
+        let last_blocks = self
+            .blockwise
+            .md_pad_with_length(&(bits as u64).to_be_bytes());
+        self.update_blocks(last_blocks.as_ref());
+
+        let mut r = [0u8; Self::OUTPUT_SZ];
+        for (out, state) in r.chunks_exact_mut(4).zip(self.h.iter()) {
+            out.copy_from_slice(&state.to_be_bytes());
+        }
+        r
+    }
+
+    fn update_blocks(&mut self, blocks: &[u8]) {
+        debug_assert!(blocks.len() % Self::BLOCK_SZ == 0);
+        if !blocks.is_empty() {
+            crate::low::sha1_compress_blocks(&mut self.h, blocks);
+            self.nblocks = self.nblocks.saturating_add(blocks.len() / Self::BLOCK_SZ);
+        }
+    }
+
+    /// The internal block size of SHA-1.
+    pub const BLOCK_SZ: usize = 64;
+
+    /// The output size of SHA-1.
+    pub const OUTPUT_SZ: usize = 20;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sha1(bytes: &[u8]) -> [u8; Sha1Context::OUTPUT_SZ] {
+        let mut ctx = Sha1Context::new();
+        ctx.update(bytes);
+        ctx.finish()
+    }
+
+    #[test]
+    fn fips180_examples() {
+        assert_eq!(
+            sha1(b"abc"),
+            *b"\xa9\x99\x3e\x36\x47\x06\x81\x6a\xba\x3e\x25\x71\x78\x50\xc2\x6c\x9c\xd0\xd8\x9d"
+        );
+        assert_eq!(
+            sha1(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            *b"\x84\x98\x3e\x44\x1c\x3b\xd2\x6e\xba\xae\x4a\xa1\xf9\x51\x29\xe5\xe5\x46\x70\xf1"
+        );
+        assert_eq!(
+            sha1(b""),
+            *b"\xda\x39\xa3\xee\x5e\x6b\x4b\x0d\x32\x55\xbf\xef\x95\x60\x18\x90\xaf\xd8\x07\x09"
+        );
+    }
+
+    #[test]
+    fn million_a_in_pieces() {
+        let mut ctx = Sha1Context::new();
+        for _ in 0..1000 {
+            ctx.update(&[b'a'; 1000][..]);
+        }
+        assert_eq!(
+            ctx.finish(),
+            *b"\x34\xaa\x97\x3c\xd4\xc4\xda\xa4\xf6\x1e\xeb\x2b\xdb\xad\x27\x31\x65\x34\x01\x6f"
+        );
+    }
+}
@@ -12,7 +12,7 @@ use crate::low::Blockwise;
 #[derive(Clone)]
 pub struct Sha256Context {
     h: [u32; 8],
-    blockwise: Blockwise<{ Sha256Context::BLOCK_SZ }>,
+    blockwise: Blockwise<{ Self::BLOCK_SZ }>,
     nblocks: usize,
 }
 
@@ -91,6 +91,151 @@ impl Sha256Context {
     pub const OUTPUT_SZ: usize = 32;
 }
 
+/// Compute the SHA256 hashes of many independent messages at once.
+///
+/// `outputs[i]` is set to the hash of `messages[i]`.  The messages
+/// are processed eight at a time, in lockstep.
+pub(crate) fn sha256_many(messages: &[&[u8]], outputs: &mut [[u8; Sha256Context::OUTPUT_SZ]]) {
+    sha256_many_after(&Sha256Context::new(), messages, outputs)
+}
+
+/// As [`sha256_many`], but each message is hashed as a continuation of `prefix`.
+///
+/// `prefix` must have consumed a whole number of blocks.  This suits
+/// many short messages which share a common first block.
+pub(crate) fn sha256_many_after(
+    prefix: &Sha256Context,
+    messages: &[&[u8]],
+    outputs: &mut [[u8; Sha256Context::OUTPUT_SZ]],
+) {
+    debug_assert_eq!(messages.len(), outputs.len());
+    debug_assert_eq!(prefix.blockwise.used(), 0);
+
+    for (messages, outputs) in messages.chunks(8).zip(outputs.chunks_mut(8)) {
+        let mut lanes = Sha256Lanes::new(prefix, messages);
+
+        // once we are down to one message, lockstep processing is no help.
+        while lanes.active() > 1 {
+            lanes.step();
+        }
+
+        lanes.finish(outputs);
+    }
+}
+
+/// Up to eight messages being hashed by [`sha256_many`].
+///
+/// Each message is hashed in two parts: first its whole blocks
+/// (directly from the message), then its final partial block
+/// plus padding.
+struct Sha256Lanes<'a> {
+    h: [[u32; 8]; 8],
+    /// Remaining whole blocks of each message.
+    body: [&'a [u8]; 8],
+    /// Trailing partial block of each message, plus padding.
+    tail: [[u8; 2 * Sha256Context::BLOCK_SZ]; 8],
+    /// Range of `tail` yet to be hashed.
+    tail_start: [usize; 8],
+    tail_end: [usize; 8],
+}
+
+impl<'a> Sha256Lanes<'a> {
+    fn new(prefix: &Sha256Context, messages: &[&'a [u8]]) -> Self {
+        let mut lanes = Self {
+            h: [prefix.h; 8],
+            body: [&[]; 8],
+            tail: [[0u8; 2 * Sha256Context::BLOCK_SZ]; 8],
+            tail_start: [0; 8],
+            tail_end: [0; 8],
+        };
+
+        for (i, message) in messages.iter().enumerate() {
+            let bits = prefix
+                .nblocks
+                .checked_mul(Sha256Context::BLOCK_SZ)
+                .and_then(|bytes| bytes.checked_add(message.len()))
+                .and_then(|bytes| (bytes as u64).checked_mul(8))
+                .expect("excess data processed by hash function");
+
+            let whole_len = message.len() - (message.len() & (Sha256Context::BLOCK_SZ - 1));
+            let (body, remainder) = message.split_at(whole_len);
+            lanes.body[i] = body;
+
+            let tail = &mut lanes.tail[i];
+            tail[..remainder.len()].copy_from_slice(remainder);
+            tail[remainder.len()] = 0x80;
+            let tail_len = match remainder.len() + 1 + 8 > Sha256Context::BLOCK_SZ {
+                true => 2 * Sha256Context::BLOCK_SZ,
+                false => Sha256Context::BLOCK_SZ,
+            };
+            tail[tail_len - 8..tail_len].copy_from_slice(&bits.to_be_bytes());
+            lanes.tail_end[i] = tail_len;
+        }
+
+        lanes
+    }
+
+    /// The next contiguous run of whole blocks to be hashed, for lane `i`.
+    fn pending(&self, i: usize) -> &[u8] {
+        match self.body[i].is_empty() {
+            false => self.body[i],
+            true => &self.tail[i][self.tail_start[i]..self.tail_end[i]],
+        }
+    }
+
+    /// Mark the first `len` bytes of `self.pending(i)` as hashed.
+    fn consume(&mut self, i: usize, len: usize) {
+        match self.body[i].is_empty() {
+            false => self.body[i] = &self.body[i][len..],
+            true => self.tail_start[i] += len,
+        }
+    }
+
+    fn active(&self) -> usize {
+        (0..8).filter(|i| !self.pending(*i).is_empty()).count()
+    }
+
+    /// Hash as many blocks as possible in lockstep across all active lanes.
+    fn step(&mut self) {
+        let len = (0..8)
+            .map(|i| self.pending(i).len())
+            .filter(|len| *len > 0)
+            .min()
+            .unwrap_or_default();
+
+        let blocks: [&[u8]; 8] = core::array::from_fn(|i| {
+            let pending = self.pending(i);
+            &pending[..pending.len().min(len)]
+        });
+        let mut h = self.h;
+        crate::low::sha256_compress_blocks_x8(&mut h, blocks);
+        self.h = h;
+
+        for i in 0..8 {
+            if !self.pending(i).is_empty() {
+                self.consume(i, len);
+            }
+        }
+    }
+
+    fn finish(mut self, outputs: &mut [[u8; Sha256Context::OUTPUT_SZ]]) {
+        for (i, output) in outputs.iter_mut().enumerate() {
+            while !self.pending(i).is_empty() {
+                let mut h = self.h[i];
+                let pending = self.pending(i);
+                let len = pending.len();
+                crate::low::sha256_compress_blocks(&mut h, pending);
+                self.h[i] = h;
+                self.consume(i, len);
+            }
+
+            for (out, state) in output.chunks_exact_mut(4).zip(self.h[i].iter()) {
+                out.copy_from_slice(&state.to_be_bytes());
+            }
+        }
+    }
+}
+
 /// A context for incremental computation of SHA384.
 #[derive(Clone)]
 pub struct Sha384Context {
@@ -130,6 +275,9 @@ impl Sha384Context {
         inner[..Self::OUTPUT_SZ].try_into().unwrap()
     }
 
+    /// The internal block size of SHA384.
+    pub const BLOCK_SZ: usize = Sha512Context::BLOCK_SZ;
+
     /// The output size of SHA384.
     pub const OUTPUT_SZ: usize = 48;
 }
@@ -138,7 +286,7 @@ impl Sha384Context {
 #[derive(Clone)]
 pub struct Sha512Context {
     h: [u64; 8],
-    blockwise: Blockwise<{ Sha512Context::BLOCK_SZ }>,
+    blockwise: Blockwise<{ Self::BLOCK_SZ }>,
     nblocks: usize,
 }
 
@@ -275,6 +423,60 @@ mod tests {
         assert_eq!(&outer.finish(),
                    b"\x55\x7b\xfd\xd5\xef\xda\xfd\x63\x06\x5e\xb7\x98\x87\xde\x86\xdb\x54\xc3\xfe\xdf\x7b\xcc\xcb\x97\x08\xfa\x87\xf0\x11\x87\x61\xdc");
     }
+    #[test]
+    fn sha256_many_matches_single() {
+        let data = (0..5100u32).map(|i| (i * 13) as u8).collect::<Vec<_>>();
+        let lengths = [
+            0, 1, 55, 56, 63, 64, 65, 119, 120, 127, 128, 129, 1000, 4096, 4999,
+        ];
+
+        for count in 0..=lengths.len() + 3 {
+            let messages = (0..count)
+                .map(|i| &data[i..i + lengths[(i * 7) % lengths.len()]])
+                .collect::<Vec<_>>();
+            let mut outputs = vec![[0u8; 32]; count];
+
+            sha256_many(&messages, &mut outputs);
+
+            for (message, output) in messages.iter().zip(outputs.iter()) {
+                let mut ctx = Sha256Context::new();
+                ctx.update(message);
+                assert_eq!(&ctx.finish(), output);
+            }
+        }
+    }
+
+    #[test]
+    fn sha256_many_equal_lengths() {
+        let data = vec![0x5au8; 8 * 256];
+        for len in 0..256 {
+            let messages = data.chunks(256).map(|m| &m[..len]).collect::<Vec<_>>();
+            let mut outputs = [[0u8; 32]; 8];
+            sha256_many(&messages, &mut outputs);
+
+            let mut ctx = Sha256Context::new();
+            ctx.update(&data[..len]);
+            assert_eq!([ctx.finish(); 8], outputs);
+        }
+    }
+
+    #[test]
+    fn sha256_many_after_prefix() {
+        let mut prefix = Sha256Context::new();
+        prefix.update(&[0x11; 128]);
+
+        let data = (0..300u32).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        let messages = (0..11).map(|i| &data[..i * 27]).collect::<Vec<_>>();
+        let mut outputs = [[0u8; 32]; 11];
+        sha256_many_after(&prefix, &messages, &mut outputs);
+
+        for (message, output) in messages.iter().zip(outputs.iter()) {
+            let mut ctx = prefix.clone();
+            ctx.update(message);
+            assert_eq!(&ctx.finish(), output);
+        }
+    }
+
     #[test]
     fn sha512_all_lengths() {
         let mut outer = Sha512Context::new();
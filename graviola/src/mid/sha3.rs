@@ -0,0 +1,467 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! SHA3 and SHAKE, as specified in
+//! [FIPS202](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf),
+//! and cSHAKE from [SP800-185](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-185.pdf).
+
+use crate::low::{keccak_f1600, keccak_f1600_x4, zeroise};
+
+/// The Keccak-f\[1600\] permutation, from FIPS202 section 3.
+///
+/// `state` is the 25 lanes `A[x, y]`, at index `x + 5y`, with each lane
+/// read from bytes in little endian order.
+#[cfg(feature = "hazmat")]
+pub fn keccak_f1600_permute(state: &mut [u64; 25]) {
+    let _entry = crate::low::Entry::new_secret();
+    keccak_f1600(state);
+}
+
+/// A Keccak sponge with a rate of `RATE` bytes.
+#[derive(Clone)]
+struct Sponge<const RATE: usize> {
+    state: [u64; 25],
+    /// Byte offset into the rate portion of `state`.
+    pos: usize,
+}
+
+impl<const RATE: usize> Sponge<RATE> {
+    const fn new() -> Self {
+        Self {
+            state: [0u64; 25],
+            pos: 0,
+        }
+    }
+
+    fn absorb(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            if self.pos == 0 && bytes.len() >= RATE {
+                let (block, rest) = bytes.split_at(RATE);
+                for (lane, chunk) in self.state.iter_mut().zip(block.chunks_exact(8)) {
+                    *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+                }
+                keccak_f1600(&mut self.state);
+                bytes = rest;
+                continue;
+            }
+
+            let take = core::cmp::min(RATE - self.pos, bytes.len());
+            let (taken, rest) = bytes.split_at(take);
+            for b in taken {
+                self.xor_byte(self.pos, *b);
+                self.pos += 1;
+            }
+            if self.pos == RATE {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+            bytes = rest;
+        }
+    }
+
+    /// Apply padding and domain separation; after this, `squeeze` may be called.
+    fn finalize(&mut self, domain: u8) {
+        self.xor_byte(self.pos, domain);
+        self.xor_byte(RATE - 1, 0x80);
+        keccak_f1600(&mut self.state);
+        self.pos = 0;
+    }
+
+    fn squeeze(&mut self, out: &mut [u8]) {
+        for o in out {
+            if self.pos == RATE {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+            *o = (self.state[self.pos / 8] >> (8 * (self.pos % 8))) as u8;
+            self.pos += 1;
+        }
+    }
+
+    /// Absorb zero bytes up to the next block boundary.
+    ///
+    /// This is the tail of SP800-185 `bytepad()`.
+    fn pad_to_block(&mut self) {
+        if self.pos != 0 {
+            keccak_f1600(&mut self.state);
+            self.pos = 0;
+        }
+    }
+
+    fn xor_byte(&mut self, pos: usize, b: u8) {
+        self.state[pos / 8] ^= (b as u64) << (8 * (pos % 8));
+    }
+}
+
+impl<const RATE: usize> Drop for Sponge<RATE> {
+    fn drop(&mut self) {
+        zeroise(&mut self.state);
+    }
+}
+
+const SHA3_DOMAIN: u8 = 0x06;
+const SHAKE_DOMAIN: u8 = 0x1f;
+const CSHAKE_DOMAIN: u8 = 0x04;
+
+/// A context for incremental computation of SHA3-256.
+#[derive(Clone)]
+pub(crate) struct Sha3_256 {
+    sponge: Sponge<136>,
+}
+
+impl Sha3_256 {
+    pub(crate) const fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        self.sponge.absorb(bytes);
+    }
+
+    pub(crate) fn finish(mut self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        self.sponge.finalize(SHA3_DOMAIN);
+        self.sponge.squeeze(&mut out);
+        out
+    }
+
+    pub(crate) fn hash(bytes: &[u8]) -> [u8; 32] {
+        let mut ctx = Self::new();
+        ctx.update(bytes);
+        ctx.finish()
+    }
+}
+
+/// A context for incremental computation of SHA3-512.
+#[derive(Clone)]
+pub(crate) struct Sha3_512 {
+    sponge: Sponge<72>,
+}
+
+impl Sha3_512 {
+    pub(crate) const fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        self.sponge.absorb(bytes);
+    }
+
+    pub(crate) fn finish(mut self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        self.sponge.finalize(SHA3_DOMAIN);
+        self.sponge.squeeze(&mut out);
+        out
+    }
+}
+
+/// SHAKE128 or SHAKE256, depending on `RATE`.
+///
+/// Input is added with `update`.  The first call to `squeeze`
+/// ends the input, and then any amount of output can be read
+/// over subsequent calls.
+#[derive(Clone)]
+pub(crate) struct Shake<const RATE: usize> {
+    sponge: Sponge<RATE>,
+    squeezing: bool,
+}
+
+/// SHAKE128.
+pub(crate) type Shake128 = Shake<168>;
+
+/// SHAKE256.
+pub(crate) type Shake256 = Shake<136>;
+
+impl<const RATE: usize> Shake<RATE> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+            squeezing: false,
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        debug_assert!(!self.squeezing);
+        self.sponge.absorb(bytes);
+    }
+
+    pub(crate) fn squeeze(&mut self, out: &mut [u8]) {
+        if !self.squeezing {
+            self.sponge.finalize(SHAKE_DOMAIN);
+            self.squeezing = true;
+        }
+        self.sponge.squeeze(out);
+    }
+
+    /// The maximum-efficiency amount of output to request from
+    /// `squeeze` at once.
+    pub(crate) const BLOCK_SZ: usize = RATE;
+}
+
+/// cSHAKE128 or cSHAKE256, depending on `RATE`.
+///
+/// This is SP800-185 section 3.  Unlike that definition, this
+/// never degrades to plain SHAKE when `name` and `customization`
+/// are both empty; no user of this type needs that.
+#[derive(Clone)]
+pub(crate) struct CShake<const RATE: usize> {
+    sponge: Sponge<RATE>,
+    squeezing: bool,
+}
+
+impl<const RATE: usize> CShake<RATE> {
+    /// Start a new cSHAKE computation, with function-name string `name`
+    /// and customization string `customization`.
+    pub(crate) fn new(name: &[u8], customization: &[u8]) -> Self {
+        let mut s = Self {
+            sponge: Sponge::new(),
+            squeezing: false,
+        };
+        s.sponge.absorb(left_encode(RATE as u64).as_ref());
+        s.absorb_encoded_string(name);
+        s.absorb_encoded_string(customization);
+        s.sponge.pad_to_block();
+        s
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        debug_assert!(!self.squeezing);
+        self.sponge.absorb(bytes);
+    }
+
+    /// Absorb SP800-185 `encode_string(bytes)`.
+    pub(crate) fn absorb_encoded_string(&mut self, bytes: &[u8]) {
+        self.update(left_encode(bytes.len() as u64 * 8).as_ref());
+        self.update(bytes);
+    }
+
+    /// Absorb zeroes up to the next block boundary.
+    pub(crate) fn pad_to_block(&mut self) {
+        self.sponge.pad_to_block();
+    }
+
+    pub(crate) fn squeeze(&mut self, out: &mut [u8]) {
+        if !self.squeezing {
+            self.sponge.finalize(CSHAKE_DOMAIN);
+            self.squeezing = true;
+        }
+        self.sponge.squeeze(out);
+    }
+}
+
+/// SP800-185 `left_encode()` and `right_encode()` output.
+pub(crate) struct Encoded {
+    buf: [u8; 9],
+    start: usize,
+    end: usize,
+}
+
+impl AsRef<[u8]> for Encoded {
+    fn as_ref(&self) -> &[u8] {
+        &self.buf[self.start..self.end]
+    }
+}
+
+/// SP800-185 `left_encode(x)`: the length of `x`, then `x`, both big endian.
+pub(crate) fn left_encode(x: u64) -> Encoded {
+    let mut buf = [0u8; 9];
+    buf[1..].copy_from_slice(&x.to_be_bytes());
+    let n = core::cmp::max(1, 8 - (x.leading_zeros() as usize / 8));
+    let start = 8 - n;
+    buf[start] = n as u8;
+    Encoded { buf, start, end: 9 }
+}
+
+/// SP800-185 `right_encode(x)`: `x`, then its length, both big endian.
+pub(crate) fn right_encode(x: u64) -> Encoded {
+    let mut buf = [0u8; 9];
+    buf[..8].copy_from_slice(&x.to_be_bytes());
+    let n = core::cmp::max(1, 8 - (x.leading_zeros() as usize / 8));
+    buf[8] = n as u8;
+    Encoded {
+        buf,
+        start: 8 - n,
+        end: 9,
+    }
+}
+
+/// Compute SHAKE256 of many short messages at once.
+///
+/// `outputs[i]` is set to the first `N` bytes of SHAKE256 of `messages[i]`.
+/// Each message must be shorter than one block, and `N` no longer than one
+/// block, so each needs exactly one permutation.  The messages are processed
+/// four at a time, in lockstep.
+pub(crate) fn shake256_many<const N: usize>(messages: &[&[u8]], outputs: &mut [[u8; N]]) {
+    debug_assert_eq!(messages.len(), outputs.len());
+    debug_assert!(N <= Shake256::BLOCK_SZ);
+
+    let xor_byte = |state: &mut [u64; 25], pos: usize, b: u8| {
+        state[pos / 8] ^= (b as u64) << (8 * (pos % 8));
+    };
+
+    for (messages, outputs) in messages.chunks(4).zip(outputs.chunks_mut(4)) {
+        let mut states = [[0u64; 25]; 4];
+        for (state, message) in states.iter_mut().zip(messages) {
+            debug_assert!(message.len() < Shake256::BLOCK_SZ);
+            for (pos, b) in message.iter().enumerate() {
+                xor_byte(state, pos, *b);
+            }
+            xor_byte(state, message.len(), SHAKE_DOMAIN);
+            xor_byte(state, Shake256::BLOCK_SZ - 1, 0x80);
+        }
+
+        keccak_f1600_x4(&mut states);
+
+        for (state, output) in states.iter().zip(outputs.iter_mut()) {
+            for (pos, o) in output.iter_mut().enumerate() {
+                *o = (state[pos / 8] >> (8 * (pos % 8))) as u8;
+            }
+        }
+        for state in states.iter_mut() {
+            zeroise(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shake<const RATE: usize>(input: &[u8], len: usize) -> Vec<u8> {
+        let mut s = Shake::<RATE>::new();
+        s.update(input);
+        let mut out = vec![0u8; len];
+        s.squeeze(&mut out);
+        out
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn keccak_f1600_zero_state() {
+        let mut state = [0u64; 25];
+        keccak_f1600_permute(&mut state);
+        assert_eq!(state[0], 0xf1258f7940e1dde7);
+        assert_eq!(state[24], 0xeaf1ff7b5ceca249);
+    }
+
+    #[test]
+    fn sha3_256() {
+        assert_eq!(
+            Sha3_256::hash(b""),
+            *b"\xa7\xff\xc6\xf8\xbf\x1e\xd7\x66\x51\xc1\x47\x56\xa0\x61\xd6\x62\
+               \xf5\x80\xff\x4d\xe4\x3b\x49\xfa\x82\xd8\x0a\x4b\x80\xf8\x43\x4a"
+        );
+        assert_eq!(
+            Sha3_256::hash(b"abc"),
+            *b"\x3a\x98\x5d\xa7\x4f\xe2\x25\xb2\x04\x5c\x17\x2d\x6b\xd3\x90\xbd\
+               \x85\x5f\x08\x6e\x3e\x9d\x52\x5b\x46\xbf\xe2\x45\x11\x43\x15\x32"
+        );
+    }
+
+    #[test]
+    fn sha3_512() {
+        let mut ctx = Sha3_512::new();
+        ctx.update(b"abc");
+        assert_eq!(
+            ctx.finish(),
+            *b"\xb7\x51\x85\x0b\x1a\x57\x16\x8a\x56\x93\xcd\x92\x4b\x6b\x09\x6e\
+               \x08\xf6\x21\x82\x74\x44\xf7\x0d\x88\x4f\x5d\x02\x40\xd2\x71\x2e\
+               \x10\xe1\x16\xe9\x19\x2a\xf3\xc9\x1a\x7e\xc5\x76\x47\xe3\x93\x40\
+               \x57\x34\x0b\x4c\xf4\x08\xd5\xa5\x65\x92\xf8\x27\x4e\xec\x53\xf0"
+        );
+    }
+
+    #[test]
+    fn shake128() {
+        assert_eq!(
+            shake::<168>(b"", 32),
+            b"\x7f\x9c\x2b\xa4\xe8\x8f\x82\x7d\x61\x60\x45\x50\x76\x05\x85\x3e\
+              \xd7\x3b\x80\x93\xf6\xef\xbc\x88\xeb\x1a\x6e\xac\xfa\x66\xef\x26"
+        );
+    }
+
+    #[test]
+    fn shake256() {
+        assert_eq!(
+            shake::<136>(b"", 32),
+            b"\x46\xb9\xdd\x2b\x0b\xa8\x8d\x13\x23\x3b\x3f\xeb\x74\x3e\xeb\x24\
+              \x3f\xcd\x52\xea\x62\xb8\x1b\x82\xb5\x0c\x27\x64\x6e\xd5\x76\x2f"
+        );
+    }
+
+    #[test]
+    fn incremental() {
+        let input = (0..1000).map(|i| i as u8).collect::<Vec<_>>();
+        let expected = shake::<168>(&input, 500);
+
+        for split in [1, 7, 167, 168, 169, 400] {
+            let mut s = Shake128::new();
+            for chunk in input.chunks(split) {
+                s.update(chunk);
+            }
+            let mut out = vec![0u8; 500];
+            for chunk in out.chunks_mut(split) {
+                s.squeeze(chunk);
+            }
+            assert_eq!(out, expected);
+
+            let mut h = Sha3_256::new();
+            for chunk in input.chunks(split) {
+                h.update(chunk);
+            }
+            assert_eq!(h.finish(), Sha3_256::hash(&input));
+        }
+    }
+
+    #[test]
+    fn sp800_185_encodings() {
+        assert_eq!(left_encode(0).as_ref(), b"\x01\x00");
+        assert_eq!(left_encode(168).as_ref(), b"\x01\xa8");
+        assert_eq!(left_encode(256).as_ref(), b"\x02\x01\x00");
+        assert_eq!(right_encode(0).as_ref(), b"\x00\x01");
+        assert_eq!(right_encode(512).as_ref(), b"\x02\x00\x02");
+        assert_eq!(
+            left_encode(u64::MAX).as_ref(),
+            b"\x08\xff\xff\xff\xff\xff\xff\xff\xff"
+        );
+    }
+
+    #[test]
+    fn cshake128() {
+        // SP800-185 cSHAKE samples #1 and #2
+        let mut c = CShake::<168>::new(b"", b"Email Signature");
+        c.update(b"\x00\x01\x02\x03");
+        let mut out = [0u8; 32];
+        c.squeeze(&mut out);
+        assert_eq!(
+            &out,
+            b"\xc1\xc3\x69\x25\xb6\x40\x9a\x04\xf1\xb5\x04\xfc\xbc\xa9\xd8\x2b\
+              \x40\x17\x27\x7c\xb5\xed\x2b\x20\x65\xfc\x1d\x38\x14\xd5\xaa\xf5"
+        );
+
+        let mut c = CShake::<168>::new(b"", b"Email Signature");
+        c.update(&(0..200).map(|i| i as u8).collect::<Vec<_>>());
+        c.squeeze(&mut out);
+        assert_eq!(
+            &out,
+            b"\xc5\x22\x1d\x50\xe4\xf8\x22\xd9\x6a\x2e\x88\x81\xa9\x61\x42\x0f\
+              \x29\x4b\x7b\x24\xfe\x3d\x20\x94\xba\xed\x2c\x65\x24\xcc\x16\x6b"
+        );
+    }
+
+    #[test]
+    fn shake256_many_matches_single() {
+        let input = (0..1000).map(|i| i as u8).collect::<Vec<_>>();
+        let messages = (0..11).map(|i| &input[i..i * 13]).collect::<Vec<_>>();
+        let mut outputs = [[0u8; 48]; 11];
+        shake256_many(&messages, &mut outputs);
+
+        for (message, output) in messages.iter().zip(outputs.iter()) {
+            assert_eq!(&shake::<136>(message, 48)[..], &output[..]);
+        }
+    }
+}
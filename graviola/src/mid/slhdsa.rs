@@ -0,0 +1,1525 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! SLH-DSA, as specified in
+//! [FIPS205](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.205.pdf).
+//!
+//! The twelve parameter sets are in separate modules, which share
+//! the implementation here.  Internally, a parameter set is the hash
+//! output length `N`, a choice of [`Hashes`], and a [`Params`].
+//!
+//! Nearly all the work is in evaluating the tweakable hash function F
+//! over many independent inputs: WOTS+ chains, and FORS leaves.  These are
+//! done in batches, using the multi-buffer SHA256 and SHAKE256 implementations.
+//!
+//! Only "pure" SLH-DSA is provided, not the pre-hash variant HashSLH-DSA.
+
+use crate::Error;
+use crate::low::{Entry, zeroise};
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::sha2::{self, Sha256Context, Sha512Context};
+use crate::mid::sha3::{self, Shake256};
+
+/// Parameters of a parameter set, other than `n` and the hash functions.
+struct Params {
+    /// Total height of the hypertree.
+    h: usize,
+    /// Number of layers in the hypertree.
+    d: usize,
+    /// Height of each XMSS tree: `h / d`.
+    hp: usize,
+    /// Height of each FORS tree.
+    a: usize,
+    /// Number of FORS trees.
+    k: usize,
+    /// Length of the message digest.
+    m: usize,
+}
+
+impl Params {
+    const fn new(h: usize, d: usize, a: usize, k: usize, m: usize) -> Self {
+        Self {
+            h,
+            d,
+            hp: h / d,
+            a,
+            k,
+            m,
+        }
+    }
+
+    const fn sig_len(&self, n: usize) -> usize {
+        n + self.k * (self.a + 1) * n + (self.h + self.d * wots_len(n)) * n
+    }
+
+    const fn xmss_sig_len(&self, n: usize) -> usize {
+        (wots_len(n) + self.hp) * n
+    }
+}
+
+/// The length of a WOTS+ signature, in `n`-byte units, when `lg_w` is 4.
+const fn wots_len(n: usize) -> usize {
+    2 * n + 3
+}
+
+/// The largest `wots_len`.
+const MAX_WOTS_LEN: usize = wots_len(32);
+
+/// The largest number of FORS trees.
+const MAX_K: usize = 35;
+
+/// The largest FORS tree height, which exceeds any XMSS tree height.
+const MAX_TREE_HEIGHT: usize = 14;
+
+/// Number of leaves computed at once, when computing the root of a tree.
+const LEAF_BATCH: usize = 8;
+
+/// The WOTS+ chain length, less one.
+const W_MAX: u8 = 15;
+
+/// An address, which tweaks each hash function call.
+#[derive(Clone, Copy, Default)]
+struct Adrs([u8; 32]);
+
+impl Adrs {
+    fn set_layer(&mut self, layer: u32) {
+        self.0[0..4].copy_from_slice(&layer.to_be_bytes());
+    }
+
+    fn set_tree(&mut self, tree: u64) {
+        self.0[4..8].fill(0);
+        self.0[8..16].copy_from_slice(&tree.to_be_bytes());
+    }
+
+    fn set_type_and_clear(&mut self, typ: u32) {
+        self.0[16..20].copy_from_slice(&typ.to_be_bytes());
+        self.0[20..32].fill(0);
+    }
+
+    fn set_keypair(&mut self, keypair: u32) {
+        self.0[20..24].copy_from_slice(&keypair.to_be_bytes());
+    }
+
+    fn keypair(&self) -> u32 {
+        u32::from_be_bytes(self.0[20..24].try_into().unwrap())
+    }
+
+    fn set_chain(&mut self, chain: u32) {
+        self.0[24..28].copy_from_slice(&chain.to_be_bytes());
+    }
+
+    fn set_hash(&mut self, hash: u32) {
+        self.0[28..32].copy_from_slice(&hash.to_be_bytes());
+    }
+
+    fn set_tree_height(&mut self, height: u32) {
+        self.set_chain(height);
+    }
+
+    fn set_tree_index(&mut self, index: u32) {
+        self.set_hash(index);
+    }
+
+    /// The compressed form used by the SHA2 parameter sets.
+    fn compressed(&self) -> [u8; 22] {
+        let mut r = [0u8; 22];
+        r[0] = self.0[3];
+        r[1..9].copy_from_slice(&self.0[8..16]);
+        r[9] = self.0[19];
+        r[10..22].copy_from_slice(&self.0[20..32]);
+        r
+    }
+
+    const WOTS_HASH: u32 = 0;
+    const WOTS_PK: u32 = 1;
+    const TREE: u32 = 2;
+    const FORS_TREE: u32 = 3;
+    const FORS_ROOTS: u32 = 4;
+    const WOTS_PRF: u32 = 5;
+    const FORS_PRF: u32 = 6;
+}
+
+/// The hash functions of a parameter set.
+trait Hashes<const N: usize>: Sized {
+    /// Prepare to hash under the public seed `pk_seed`.
+    fn new(pk_seed: &[u8; N]) -> Self;
+
+    /// Sets each `values[i]` to `F(PK.seed, adrs[i], values[i])`.
+    ///
+    /// `PRF(PK.seed, SK.seed, adrs)` is `F(PK.seed, adrs, SK.seed)`
+    /// for every parameter set, so this also evaluates PRF.
+    fn f_many(&self, adrs: &[Adrs], values: &mut [[u8; N]]);
+
+    /// `T_l(PK.seed, adrs, inputs)`, which is `H` when there are two inputs.
+    fn t(&self, adrs: &Adrs, inputs: &[[u8; N]]) -> [u8; N];
+
+    /// `H_msg(r, PK.seed, PK.root, message)`, filling `out`.
+    fn h_msg(
+        r: &[u8; N],
+        pk_seed: &[u8; N],
+        pk_root: &[u8; N],
+        message: &Message<'_>,
+        out: &mut [u8],
+    );
+
+    /// `PRF_msg(SK.prf, opt_rand, message)`.
+    fn prf_msg(sk_prf: &[u8; N], opt_rand: &[u8; N], message: &Message<'_>) -> [u8; N];
+}
+
+/// The SHAKE parameter sets, which use SHAKE256 throughout.
+struct ShakeHashes<const N: usize> {
+    pk_seed: [u8; N],
+}
+
+impl<const N: usize> Hashes<N> for ShakeHashes<N> {
+    fn new(pk_seed: &[u8; N]) -> Self {
+        Self { pk_seed: *pk_seed }
+    }
+
+    fn f_many(&self, adrs: &[Adrs], values: &mut [[u8; N]]) {
+        let mut messages = [[0u8; 32 + 32 + 32]; MAX_WOTS_LEN];
+        for ((m, adrs), value) in messages.iter_mut().zip(adrs).zip(values.iter()) {
+            m[..N].copy_from_slice(&self.pk_seed);
+            m[N..N + 32].copy_from_slice(&adrs.0);
+            m[N + 32..2 * N + 32].copy_from_slice(value);
+        }
+
+        let count = values.len();
+        let messages: [&[u8]; MAX_WOTS_LEN] = core::array::from_fn(|i| &messages[i][..2 * N + 32]);
+        sha3::shake256_many(&messages[..count], values);
+    }
+
+    fn t(&self, adrs: &Adrs, inputs: &[[u8; N]]) -> [u8; N] {
+        let mut ctx = Shake256::new();
+        ctx.update(&self.pk_seed);
+        ctx.update(&adrs.0);
+        for i in inputs {
+            ctx.update(i);
+        }
+        let mut out = [0u8; N];
+        ctx.squeeze(&mut out);
+        out
+    }
+
+    fn h_msg(
+        r: &[u8; N],
+        pk_seed: &[u8; N],
+        pk_root: &[u8; N],
+        message: &Message<'_>,
+        out: &mut [u8],
+    ) {
+        let mut ctx = Shake256::new();
+        ctx.update(r);
+        ctx.update(pk_seed);
+        ctx.update(pk_root);
+        message.feed(|m| ctx.update(m));
+        ctx.squeeze(out);
+    }
+
+    fn prf_msg(sk_prf: &[u8; N], opt_rand: &[u8; N], message: &Message<'_>) -> [u8; N] {
+        let mut ctx = Shake256::new();
+        ctx.update(sk_prf);
+        ctx.update(opt_rand);
+        message.feed(|m| ctx.update(m));
+        let mut out = [0u8; N];
+        ctx.squeeze(&mut out);
+        out
+    }
+}
+
+/// The SHA2 parameter sets.
+///
+/// F and PRF always use SHA256.  The other functions use SHA256
+/// for security category 1, and SHA512 otherwise.
+struct Sha2Hashes<const N: usize> {
+    /// SHA256, having absorbed `PK.seed` padded to one block.
+    sha256: Sha256Context,
+    /// SHA512, having absorbed `PK.seed` padded to one block.
+    sha512: Sha512Context,
+}
+
+impl<const N: usize> Hashes<N> for Sha2Hashes<N> {
+    fn new(pk_seed: &[u8; N]) -> Self {
+        let mut sha256 = Sha256Context::new();
+        sha256.update(pk_seed);
+        sha256.update(&[0u8; Sha256Context::BLOCK_SZ][N..]);
+        let mut sha512 = Sha512Context::new();
+        sha512.update(pk_seed);
+        sha512.update(&[0u8; Sha512Context::BLOCK_SZ][N..]);
+        Self { sha256, sha512 }
+    }
+
+    fn f_many(&self, adrs: &[Adrs], values: &mut [[u8; N]]) {
+        let mut messages = [[0u8; 22 + 32]; MAX_WOTS_LEN];
+        for ((m, adrs), value) in messages.iter_mut().zip(adrs).zip(values.iter()) {
+            m[..22].copy_from_slice(&adrs.compressed());
+            m[22..22 + N].copy_from_slice(value);
+        }
+
+        let count = values.len();
+        let messages: [&[u8]; MAX_WOTS_LEN] = core::array::from_fn(|i| &messages[i][..22 + N]);
+        let mut outputs = [[0u8; Sha256Context::OUTPUT_SZ]; MAX_WOTS_LEN];
+        sha2::sha256_many_after(&self.sha256, &messages[..count], &mut outputs[..count]);
+
+        for (value, output) in values.iter_mut().zip(outputs.iter()) {
+            value.copy_from_slice(&output[..N]);
+        }
+    }
+
+    fn t(&self, adrs: &Adrs, inputs: &[[u8; N]]) -> [u8; N] {
+        match N {
+            16 => t_with(self.sha256.clone(), adrs, inputs),
+            _ => t_with(self.sha512.clone(), adrs, inputs),
+        }
+    }
+
+    fn h_msg(
+        r: &[u8; N],
+        pk_seed: &[u8; N],
+        pk_root: &[u8; N],
+        message: &Message<'_>,
+        out: &mut [u8],
+    ) {
+        match N {
+            16 => h_msg_with::<Sha256Context>(r, pk_seed, pk_root, message, out),
+            _ => h_msg_with::<Sha512Context>(r, pk_seed, pk_root, message, out),
+        }
+    }
+
+    fn prf_msg(sk_prf: &[u8; N], opt_rand: &[u8; N], message: &Message<'_>) -> [u8; N] {
+        match N {
+            16 => prf_msg_with::<Sha256Context, N>(sk_prf, opt_rand, message),
+            _ => prf_msg_with::<Sha512Context, N>(sk_prf, opt_rand, message),
+        }
+    }
+}
+
+/// Abstracts SHA256 and SHA512 for [`Sha2Hashes`].
+trait Sha2: Clone {
+    const BLOCK_SZ: usize;
+    type Output: AsRef<[u8]>;
+    fn new() -> Self;
+    fn update(&mut self, bytes: &[u8]);
+    fn finish(self) -> Self::Output;
+}
+
+impl Sha2 for Sha256Context {
+    const BLOCK_SZ: usize = Self::BLOCK_SZ;
+    type Output = [u8; Self::OUTPUT_SZ];
+
+    fn new() -> Self {
+        Self::new()
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.update(bytes)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.finish()
+    }
+}
+
+impl Sha2 for Sha512Context {
+    const BLOCK_SZ: usize = Self::BLOCK_SZ;
+    type Output = [u8; Self::OUTPUT_SZ];
+
+    fn new() -> Self {
+        Self::new()
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.update(bytes)
+    }
+
+    fn finish(self) -> Self::Output {
+        self.finish()
+    }
+}
+
+fn t_with<S: Sha2, const N: usize>(mut ctx: S, adrs: &Adrs, inputs: &[[u8; N]]) -> [u8; N] {
+    ctx.update(&adrs.compressed());
+    for i in inputs {
+        ctx.update(i);
+    }
+    ctx.finish().as_ref()[..N].try_into().unwrap()
+}
+
+/// `MGF1(R || PK.seed || Hash(R || PK.seed || PK.root || M))`
+fn h_msg_with<S: Sha2>(
+    r: &[u8],
+    pk_seed: &[u8],
+    pk_root: &[u8],
+    message: &Message<'_>,
+    out: &mut [u8],
+) {
+    let mut inner = S::new();
+    inner.update(r);
+    inner.update(pk_seed);
+    inner.update(pk_root);
+    message.feed(|m| inner.update(m));
+    let inner = inner.finish();
+
+    let mut seed = S::new();
+    seed.update(r);
+    seed.update(pk_seed);
+    seed.update(inner.as_ref());
+
+    for (counter, chunk) in out.chunks_mut(inner.as_ref().len()).enumerate() {
+        let mut ctx = seed.clone();
+        ctx.update(&(counter as u32).to_be_bytes());
+        let block = ctx.finish();
+        chunk.copy_from_slice(&block.as_ref()[..chunk.len()]);
+    }
+}
+
+/// `HMAC(SK.prf, opt_rand || M)`, truncated.
+fn prf_msg_with<S: Sha2, const N: usize>(
+    sk_prf: &[u8; N],
+    opt_rand: &[u8; N],
+    message: &Message<'_>,
+) -> [u8; N] {
+    let mut key_block = [0u8; Sha512Context::BLOCK_SZ];
+    let key_block = &mut key_block[..S::BLOCK_SZ];
+    key_block[..N].copy_from_slice(sk_prf);
+
+    for byte in key_block.iter_mut() {
+        *byte ^= 0x36;
+    }
+    let mut inner = S::new();
+    inner.update(key_block);
+    inner.update(opt_rand);
+    message.feed(|m| inner.update(m));
+
+    for byte in key_block.iter_mut() {
+        *byte ^= 0x5c ^ 0x36;
+    }
+    let mut outer = S::new();
+    outer.update(key_block);
+    outer.update(inner.finish().as_ref());
+    zeroise(key_block);
+
+    outer.finish().as_ref()[..N].try_into().unwrap()
+}
+
+/// The key material for signing, plus the hash functions keyed by `PK.seed`.
+struct Engine<const N: usize, H> {
+    p: &'static Params,
+    hash: H,
+    pk_seed: [u8; N],
+}
+
+impl<const N: usize, H: Hashes<N>> Engine<N, H> {
+    fn new(p: &'static Params, pk_seed: &[u8; N]) -> Self {
+        Self {
+            p,
+            hash: H::new(pk_seed),
+            pk_seed: *pk_seed,
+        }
+    }
+
+    /// Advances each chain `i` from position `start[i]`, by `steps[i]` applications of F.
+    ///
+    /// Chains are advanced in lockstep, so that hash computations can be batched.
+    fn chains(&self, adrs: &Adrs, values: &mut [[u8; N]], start: &[u8], steps: &[u8]) {
+        let mut batch_adrs = [Adrs::default(); MAX_WOTS_LEN];
+        let mut batch = [[0u8; N]; MAX_WOTS_LEN];
+        let mut which = [0usize; MAX_WOTS_LEN];
+
+        for s in 0..W_MAX {
+            let mut count = 0;
+            for (i, (start, steps)) in start.iter().zip(steps.iter()).enumerate() {
+                if s < *steps {
+                    batch_adrs[count] = *adrs;
+                    batch_adrs[count].set_chain(i as u32);
+                    batch_adrs[count].set_hash((start + s) as u32);
+                    batch[count] = values[i];
+                    which[count] = i;
+                    count += 1;
+                }
+            }
+
+            if count == 0 {
+                break;
+            }
+
+            self.hash.f_many(&batch_adrs[..count], &mut batch[..count]);
+            for (value, i) in batch[..count].iter().zip(which.iter()) {
+                values[*i] = *value;
+            }
+        }
+
+        zeroise_values(&mut batch);
+    }
+
+    /// Sets `out[i]` to `PRF(PK.seed, SK.seed, adrs[i])`.
+    fn prf_many(&self, sk_seed: &[u8; N], adrs: &[Adrs], out: &mut [[u8; N]]) {
+        for o in out.iter_mut() {
+            *o = *sk_seed;
+        }
+        self.hash.f_many(adrs, out);
+    }
+
+    /// The WOTS+ secret values, for the key pair addressed by `adrs`.
+    fn wots_secrets(&self, sk_seed: &[u8; N], adrs: &Adrs, out: &mut [[u8; N]]) {
+        let mut sk_adrs = *adrs;
+        sk_adrs.set_type_and_clear(Adrs::WOTS_PRF);
+        sk_adrs.set_keypair(adrs.keypair());
+        let all_adrs: [Adrs; MAX_WOTS_LEN] = core::array::from_fn(|i| {
+            let mut a = sk_adrs;
+            a.set_chain(i as u32);
+            a
+        });
+        self.prf_many(sk_seed, &all_adrs[..out.len()], out);
+    }
+
+    /// Compresses the WOTS+ chain ends `values` into a public key.
+    fn wots_compress(&self, adrs: &Adrs, values: &[[u8; N]]) -> [u8; N] {
+        let mut pk_adrs = *adrs;
+        pk_adrs.set_type_and_clear(Adrs::WOTS_PK);
+        pk_adrs.set_keypair(adrs.keypair());
+        self.hash.t(&pk_adrs, values)
+    }
+
+    /// wots_pkGen
+    fn wots_pk_gen(&self, sk_seed: &[u8; N], adrs: &Adrs) -> [u8; N] {
+        let len = wots_len(N);
+        let mut values = [[0u8; N]; MAX_WOTS_LEN];
+        let values = &mut values[..len];
+        self.wots_secrets(sk_seed, adrs, values);
+        self.chains(
+            adrs,
+            values,
+            &[0; MAX_WOTS_LEN][..len],
+            &[W_MAX; MAX_WOTS_LEN][..len],
+        );
+        self.wots_compress(adrs, values)
+    }
+
+    /// wots_sign
+    fn wots_sign(&self, message: &[u8; N], sk_seed: &[u8; N], adrs: &Adrs, out: &mut [u8]) {
+        let len = wots_len(N);
+        let digits = wots_digits(message);
+        let mut values = [[0u8; N]; MAX_WOTS_LEN];
+        let values = &mut values[..len];
+        self.wots_secrets(sk_seed, adrs, values);
+        self.chains(adrs, values, &[0; MAX_WOTS_LEN][..len], &digits[..len]);
+
+        for (out, value) in out.chunks_exact_mut(N).zip(values.iter()) {
+            out.copy_from_slice(value);
+        }
+    }
+
+    /// wots_pkFromSig
+    fn wots_pk_from_sig(&self, sig: &[u8], message: &[u8; N], adrs: &Adrs) -> [u8; N] {
+        let len = wots_len(N);
+        let digits = wots_digits(message);
+        let mut steps = [0u8; MAX_WOTS_LEN];
+        for (s, d) in steps.iter_mut().zip(digits.iter()) {
+            *s = W_MAX - d;
+        }
+
+        let mut values = [[0u8; N]; MAX_WOTS_LEN];
+        for (value, sig) in values.iter_mut().zip(sig.chunks_exact(N)) {
+            value.copy_from_slice(sig);
+        }
+        let values = &mut values[..len];
+        self.chains(adrs, values, &digits[..len], &steps[..len]);
+        self.wots_compress(adrs, values)
+    }
+
+    /// Computes the root of a tree of the given `height`, whose leftmost
+    /// leaf has index `first_leaf`.
+    ///
+    /// `leaves(i, out)` must compute the leaves with indices `i..i + out.len()`.
+    /// `adrs` is used for the internal nodes, and must already have its
+    /// type and key pair set.
+    fn tree_root(
+        &self,
+        adrs: &mut Adrs,
+        first_leaf: u32,
+        height: usize,
+        mut leaves: impl FnMut(u32, &mut [[u8; N]]),
+    ) -> [u8; N] {
+        let mut stack = [[0u8; N]; MAX_TREE_HEIGHT + 1];
+        let mut heights = [0u32; MAX_TREE_HEIGHT + 1];
+        let mut depth = 0;
+        let mut batch = [[0u8; N]; LEAF_BATCH];
+
+        let count = 1u32 << height;
+        let mut i = 0;
+        while i < count {
+            let batch = &mut batch[..(count - i).min(LEAF_BATCH as u32) as usize];
+            leaves(first_leaf + i, batch);
+
+            for leaf in batch.iter() {
+                let mut index = first_leaf + i;
+                stack[depth] = *leaf;
+                heights[depth] = 0;
+                depth += 1;
+
+                while depth >= 2 && heights[depth - 1] == heights[depth - 2] {
+                    index >>= 1;
+                    let height = heights[depth - 1] + 1;
+                    adrs.set_tree_height(height);
+                    adrs.set_tree_index(index);
+                    stack[depth - 2] = self.hash.t(adrs, &stack[depth - 2..depth]);
+                    heights[depth - 2] = height;
+                    depth -= 1;
+                }
+                i += 1;
+            }
+        }
+
+        stack[0]
+    }
+
+    /// Climbs from `node`, the leaf with index `leaf`, to the root of its tree,
+    /// using the authentication path `auth`.
+    fn climb(&self, adrs: &mut Adrs, leaf: u32, mut node: [u8; N], auth: &[u8]) -> [u8; N] {
+        for (k, auth) in auth.chunks_exact(N).enumerate() {
+            adrs.set_tree_height(k as u32 + 1);
+            adrs.set_tree_index(leaf >> (k + 1));
+            let auth: [u8; N] = auth.try_into().unwrap();
+            node = match (leaf >> k) & 1 {
+                0 => self.hash.t(adrs, &[node, auth]),
+                _ => self.hash.t(adrs, &[auth, node]),
+            };
+        }
+        node
+    }
+
+    /// xmss_node: the node with index `index` at height `z` of the XMSS tree
+    /// addressed by `adrs`.
+    fn xmss_node(&self, sk_seed: &[u8; N], index: u32, z: usize, adrs: &Adrs) -> [u8; N] {
+        let mut leaf_adrs = *adrs;
+        leaf_adrs.set_type_and_clear(Adrs::WOTS_HASH);
+        let mut node_adrs = *adrs;
+        node_adrs.set_type_and_clear(Adrs::TREE);
+
+        self.tree_root(&mut node_adrs, index << z, z, |first, out| {
+            for (i, out) in (first..).zip(out.iter_mut()) {
+                leaf_adrs.set_keypair(i);
+                *out = self.wots_pk_gen(sk_seed, &leaf_adrs);
+            }
+        })
+    }
+
+    /// xmss_sign
+    fn xmss_sign(
+        &self,
+        message: &[u8; N],
+        sk_seed: &[u8; N],
+        index: u32,
+        adrs: &Adrs,
+        out: &mut [u8],
+    ) {
+        let (wots_sig, auth) = out.split_at_mut(wots_len(N) * N);
+        for (j, auth) in auth.chunks_exact_mut(N).enumerate() {
+            let sibling = (index >> j) ^ 1;
+            auth.copy_from_slice(&self.xmss_node(sk_seed, sibling, j, adrs));
+        }
+
+        let mut wots_adrs = *adrs;
+        wots_adrs.set_type_and_clear(Adrs::WOTS_HASH);
+        wots_adrs.set_keypair(index);
+        self.wots_sign(message, sk_seed, &wots_adrs, wots_sig);
+    }
+
+    /// xmss_pkFromSig
+    fn xmss_pk_from_sig(&self, index: u32, sig: &[u8], message: &[u8; N], adrs: &Adrs) -> [u8; N] {
+        let (wots_sig, auth) = sig.split_at(wots_len(N) * N);
+        let mut adrs = *adrs;
+        adrs.set_type_and_clear(Adrs::WOTS_HASH);
+        adrs.set_keypair(index);
+        let node = self.wots_pk_from_sig(wots_sig, message, &adrs);
+
+        adrs.set_type_and_clear(Adrs::TREE);
+        self.climb(&mut adrs, index, node, auth)
+    }
+
+    /// ht_sign
+    fn ht_sign(
+        &self,
+        message: &[u8; N],
+        sk_seed: &[u8; N],
+        mut tree: u64,
+        mut leaf: u32,
+        out: &mut [u8],
+    ) {
+        let mut adrs = Adrs::default();
+        let mut root = *message;
+
+        for (layer, out) in out.chunks_exact_mut(self.p.xmss_sig_len(N)).enumerate() {
+            if layer > 0 {
+                leaf = (tree & ((1 << self.p.hp) - 1)) as u32;
+                tree >>= self.p.hp;
+            }
+            adrs.set_layer(layer as u32);
+            adrs.set_tree(tree);
+            self.xmss_sign(&root, sk_seed, leaf, &adrs, out);
+            if layer < self.p.d - 1 {
+                root = self.xmss_pk_from_sig(leaf, out, &root, &adrs);
+            }
+        }
+    }
+
+    /// ht_verify
+    fn ht_verify(
+        &self,
+        message: &[u8; N],
+        sig: &[u8],
+        mut tree: u64,
+        mut leaf: u32,
+        pk_root: &[u8; N],
+    ) -> bool {
+        let mut adrs = Adrs::default();
+        let mut node = *message;
+
+        for (layer, sig) in sig.chunks_exact(self.p.xmss_sig_len(N)).enumerate() {
+            if layer > 0 {
+                leaf = (tree & ((1 << self.p.hp) - 1)) as u32;
+                tree >>= self.p.hp;
+            }
+            adrs.set_layer(layer as u32);
+            adrs.set_tree(tree);
+            node = self.xmss_pk_from_sig(leaf, sig, &node, &adrs);
+        }
+
+        node == *pk_root
+    }
+
+    /// fors_skGen, for the consecutive leaves starting at `first`.
+    fn fors_secrets(&self, sk_seed: &[u8; N], adrs: &Adrs, first: u32, out: &mut [[u8; N]]) {
+        let mut sk_adrs = *adrs;
+        sk_adrs.set_type_and_clear(Adrs::FORS_PRF);
+        sk_adrs.set_keypair(adrs.keypair());
+        let all_adrs: [Adrs; LEAF_BATCH] = core::array::from_fn(|i| {
+            let mut a = sk_adrs;
+            a.set_tree_index(first + i as u32);
+            a
+        });
+        self.prf_many(sk_seed, &all_adrs[..out.len()], out);
+    }
+
+    /// fors_node: the node with index `index` at height `z`, across all FORS trees.
+    fn fors_node(&self, sk_seed: &[u8; N], index: u32, z: usize, adrs: &Adrs) -> [u8; N] {
+        let mut node_adrs = *adrs;
+        self.tree_root(&mut node_adrs, index << z, z, |first, out| {
+            self.fors_secrets(sk_seed, adrs, first, out);
+            let leaf_adrs: [Adrs; LEAF_BATCH] = core::array::from_fn(|i| {
+                let mut a = *adrs;
+                a.set_tree_height(0);
+                a.set_tree_index(first + i as u32);
+                a
+            });
+            self.hash.f_many(&leaf_adrs[..out.len()], out);
+        })
+    }
+
+    /// fors_sign
+    fn fors_sign(&self, md: &[u8], sk_seed: &[u8; N], adrs: &Adrs, out: &mut [u8]) {
+        let a = self.p.a;
+        let indices = base_2b(md, a, self.p.k);
+
+        for (i, (out, index)) in out
+            .chunks_exact_mut((a + 1) * N)
+            .zip(indices.iter())
+            .enumerate()
+        {
+            let (sk, auth) = out.split_at_mut(N);
+            let leaf = ((i as u32) << a) + index;
+            let mut secret = [[0u8; N]];
+            self.fors_secrets(sk_seed, adrs, leaf, &mut secret);
+            sk.copy_from_slice(&secret[0]);
+
+            for (j, auth) in auth.chunks_exact_mut(N).enumerate() {
+                let sibling = (leaf >> j) ^ 1;
+                auth.copy_from_slice(&self.fors_node(sk_seed, sibling, j, adrs));
+            }
+        }
+    }
+
+    /// fors_pkFromSig
+    fn fors_pk_from_sig(&self, sig: &[u8], md: &[u8], adrs: &Adrs) -> [u8; N] {
+        let a = self.p.a;
+        let k = self.p.k;
+        let indices = base_2b(md, a, k);
+
+        let mut roots = [[0u8; N]; MAX_K];
+        let roots = &mut roots[..k];
+        let mut leaf_adrs = [*adrs; MAX_K];
+        for (i, ((root, leaf_adrs), sig)) in roots
+            .iter_mut()
+            .zip(leaf_adrs.iter_mut())
+            .zip(sig.chunks_exact((a + 1) * N))
+            .enumerate()
+        {
+            root.copy_from_slice(&sig[..N]);
+            leaf_adrs.set_tree_height(0);
+            leaf_adrs.set_tree_index(((i as u32) << a) + indices[i]);
+        }
+        self.hash.f_many(&leaf_adrs[..k], roots);
+
+        for (i, (root, sig)) in roots
+            .iter_mut()
+            .zip(sig.chunks_exact((a + 1) * N))
+            .enumerate()
+        {
+            let mut node_adrs = *adrs;
+            let leaf = ((i as u32) << a) + indices[i];
+            *root = self.climb(&mut node_adrs, leaf, *root, &sig[N..]);
+        }
+
+        let mut pk_adrs = *adrs;
+        pk_adrs.set_type_and_clear(Adrs::FORS_ROOTS);
+        pk_adrs.set_keypair(adrs.keypair());
+        self.hash.t(&pk_adrs, roots)
+    }
+
+    /// Splits the message digest into the FORS message, and the hypertree indices.
+    fn split_digest<'a>(&self, digest: &'a [u8]) -> (&'a [u8], u64, u32) {
+        let p = self.p;
+        let (md, rest) = digest.split_at((p.k * p.a + 7) / 8);
+        let (tree, rest) = rest.split_at((p.h - p.hp + 7) / 8);
+        let leaf = &rest[..(p.hp + 7) / 8];
+
+        let tree = tree.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+        let tree = match p.h - p.hp {
+            64 => tree,
+            bits => tree & ((1 << bits) - 1),
+        };
+        let leaf = leaf.iter().fold(0u32, |acc, b| (acc << 8) | *b as u32) & ((1 << p.hp) - 1);
+        (md, tree, leaf)
+    }
+
+    /// FORS then hypertree addresses; returns the address for the FORS key pair.
+    fn fors_adrs(tree: u64, leaf: u32) -> Adrs {
+        let mut adrs = Adrs::default();
+        adrs.set_tree(tree);
+        adrs.set_type_and_clear(Adrs::FORS_TREE);
+        adrs.set_keypair(leaf);
+        adrs
+    }
+
+    /// slh_keygen_internal, returning `PK.root`.
+    fn root(&self, sk_seed: &[u8; N]) -> [u8; N] {
+        let mut adrs = Adrs::default();
+        adrs.set_layer(self.p.d as u32 - 1);
+        self.xmss_node(sk_seed, 0, self.p.hp, &adrs)
+    }
+
+    /// slh_sign_internal
+    fn sign(&self, key: &PrivateKey<N>, message: &Message<'_>, opt_rand: &[u8; N], sig: &mut [u8]) {
+        let p = self.p;
+        let (r, rest) = sig.split_at_mut(N);
+        let (fors_sig, ht_sig) = rest.split_at_mut(p.k * (p.a + 1) * N);
+
+        let randomiser = H::prf_msg(&key.sk_prf, opt_rand, message);
+        r.copy_from_slice(&randomiser);
+
+        let mut digest = [0u8; 64];
+        let digest = &mut digest[..p.m];
+        H::h_msg(&randomiser, &self.pk_seed, &key.pk_root, message, digest);
+        let (md, tree, leaf) = self.split_digest(digest);
+
+        let adrs = Self::fors_adrs(tree, leaf);
+        self.fors_sign(md, &key.sk_seed, &adrs, fors_sig);
+        let pk_fors = self.fors_pk_from_sig(fors_sig, md, &adrs);
+        self.ht_sign(&pk_fors, &key.sk_seed, tree, leaf, ht_sig);
+    }
+
+    /// slh_verify_internal
+    fn verify(&self, pk_root: &[u8; N], message: &Message<'_>, sig: &[u8]) -> Result<(), Error> {
+        let p = self.p;
+        if sig.len() != p.sig_len(N) {
+            return Err(Error::WrongLength);
+        }
+
+        let (r, rest) = sig.split_at(N);
+        let (fors_sig, ht_sig) = rest.split_at(p.k * (p.a + 1) * N);
+
+        let mut digest = [0u8; 64];
+        let digest = &mut digest[..p.m];
+        H::h_msg(
+            r.try_into().unwrap(),
+            &self.pk_seed,
+            pk_root,
+            message,
+            digest,
+        );
+        let (md, tree, leaf) = self.split_digest(digest);
+
+        let adrs = Self::fors_adrs(tree, leaf);
+        let pk_fors = self.fors_pk_from_sig(fors_sig, md, &adrs);
+        match self.ht_verify(&pk_fors, ht_sig, tree, leaf, pk_root) {
+            true => Ok(()),
+            false => Err(Error::BadSignature),
+        }
+    }
+}
+
+/// The WOTS+ message and checksum digits of `message`, for `lg_w` = 4.
+fn wots_digits<const N: usize>(message: &[u8; N]) -> [u8; MAX_WOTS_LEN] {
+    let mut digits = [0u8; MAX_WOTS_LEN];
+    let mut checksum = 0u16;
+    for (d, m) in digits.chunks_exact_mut(2).zip(message.iter()) {
+        d[0] = m >> 4;
+        d[1] = m & 0xf;
+        checksum += (2 * W_MAX - d[0] - d[1]) as u16;
+    }
+
+    // the checksum is 12 bits, left-aligned in two bytes
+    let checksum = (checksum << 4).to_be_bytes();
+    digits[2 * N] = checksum[0] >> 4;
+    digits[2 * N + 1] = checksum[0] & 0xf;
+    digits[2 * N + 2] = checksum[1] >> 4;
+    digits
+}
+
+/// base_2b: the first `count` `b`-bit big-endian integers in `x`.
+fn base_2b(x: &[u8], b: usize, count: usize) -> [u32; MAX_K] {
+    let mut r = [0u32; MAX_K];
+    let mut x = x.iter();
+    let mut total = 0u32;
+    let mut bits = 0;
+    for r in r[..count].iter_mut() {
+        while bits < b {
+            total = (total << 8) | *x.next().unwrap() as u32;
+            bits += 8;
+        }
+        bits -= b;
+        *r = (total >> bits) & ((1 << b) - 1);
+    }
+    r
+}
+
+fn zeroise_values<const N: usize>(values: &mut [[u8; N]]) {
+    for v in values.iter_mut() {
+        zeroise(v);
+    }
+}
+
+/// A private key.
+struct PrivateKey<const N: usize> {
+    sk_seed: [u8; N],
+    sk_prf: [u8; N],
+    pk_seed: [u8; N],
+    pk_root: [u8; N],
+}
+
+impl<const N: usize> PrivateKey<N> {
+    /// slh_keygen_internal
+    fn generate<H: Hashes<N>>(
+        p: &'static Params,
+        sk_seed: &[u8; N],
+        sk_prf: &[u8; N],
+        pk_seed: &[u8; N],
+    ) -> Self {
+        Self {
+            sk_seed: *sk_seed,
+            sk_prf: *sk_prf,
+            pk_seed: *pk_seed,
+            pk_root: Engine::<N, H>::new(p, pk_seed).root(sk_seed),
+        }
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != 4 * N {
+            return Err(Error::WrongLength);
+        }
+
+        let mut parts = bytes.chunks_exact(N).map(|c| c.try_into().unwrap());
+        Ok(Self {
+            sk_seed: parts.next().unwrap(),
+            sk_prf: parts.next().unwrap(),
+            pk_seed: parts.next().unwrap(),
+            pk_root: parts.next().unwrap(),
+        })
+    }
+
+    fn encode(&self, out: &mut [u8]) {
+        for (out, part) in
+            out.chunks_exact_mut(N)
+                .zip([&self.sk_seed, &self.sk_prf, &self.pk_seed, &self.pk_root])
+        {
+            out.copy_from_slice(part);
+        }
+    }
+}
+
+impl<const N: usize> Drop for PrivateKey<N> {
+    fn drop(&mut self) {
+        zeroise(&mut self.sk_seed);
+        zeroise(&mut self.sk_prf);
+    }
+}
+
+/// The message `M' = 0 || |ctx| || ctx || M` for pure SLH-DSA.
+struct Message<'a> {
+    prefix: [u8; 2],
+    context: &'a [u8],
+    message: &'a [&'a [u8]],
+}
+
+impl<'a> Message<'a> {
+    /// Fails if `context` is longer than 255 bytes.
+    fn new(context: &'a [u8], message: &'a [&'a [u8]]) -> Result<Self, Error> {
+        let len = u8::try_from(context.len()).map_err(|_| Error::WrongLength)?;
+        Ok(Self {
+            prefix: [0, len],
+            context,
+            message,
+        })
+    }
+
+    fn feed(&self, mut f: impl FnMut(&[u8])) {
+        f(&self.prefix);
+        f(self.context);
+        for m in self.message {
+            f(m);
+        }
+    }
+}
+
+macro_rules! slh_dsa {
+    ($name:ident, $hashes:ident, $n:literal, $h:literal, $d:literal, $a:literal, $k:literal, $m:literal, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $name {
+            use super::*;
+
+            const N: usize = $n;
+            const PARAMS: Params = Params::new($h, $d, $a, $k, $m);
+            type Hash = $hashes<N>;
+
+            /// The length of a verifying key, in bytes.
+            pub const VERIFYING_KEY_LEN: usize = 2 * N;
+
+            /// The length of a signing key, in bytes.
+            pub const SIGNING_KEY_LEN: usize = 4 * N;
+
+            /// The length of a signature, in bytes.
+            pub const SIGNATURE_LEN: usize = PARAMS.sig_len(N);
+
+            /// The length of the seed accepted by [`SigningKey::from_seed()`].
+            pub const SEED_LEN: usize = 3 * N;
+
+            /// A signing (private) key.
+            pub struct SigningKey(PrivateKey<N>);
+
+            impl SigningKey {
+                /// Generate a new key using the system random number generator.
+                ///
+                /// Fails only if the random source fails.
+                pub fn new_random() -> Result<Self, Error> {
+                    Self::generate(&mut SystemRandom)
+                }
+
+                /// Generate a new key using `rng`.
+                ///
+                /// Fails only if the random source fails.
+                pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+                    let mut seed = [0u8; SEED_LEN];
+                    rng.fill(&mut seed)?;
+                    let key = Self::from_seed(&seed);
+                    zeroise(&mut seed);
+                    Ok(key)
+                }
+
+                /// Deterministically derive a key from a seed.
+                ///
+                /// The seed is `SK.seed || SK.prf || PK.seed` in the
+                /// notation of FIPS205.
+                pub fn from_seed(seed: &[u8; SEED_LEN]) -> Self {
+                    let _entry = Entry::new_secret();
+                    let mut parts = seed.chunks_exact(N).map(|c| c.try_into().unwrap());
+                    let (sk_seed, sk_prf, pk_seed) = (
+                        parts.next().unwrap(),
+                        parts.next().unwrap(),
+                        parts.next().unwrap(),
+                    );
+                    Self(PrivateKey::generate::<Hash>(
+                        &PARAMS, &sk_seed, &sk_prf, &pk_seed,
+                    ))
+                }
+
+                /// Decode a signing key.
+                ///
+                /// This fails with [`Error::WrongLength`] if `bytes` is not
+                /// [`SIGNING_KEY_LEN`] bytes long.
+                ///
+                /// The public part of the key is not checked against the
+                /// private part, because that is as expensive as generating
+                /// a new key.
+                pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                    let _entry = Entry::new_secret();
+                    PrivateKey::decode(bytes).map(Self)
+                }
+
+                /// Encode this key.
+                pub fn to_bytes(&self) -> [u8; SIGNING_KEY_LEN] {
+                    let _entry = Entry::new_secret();
+                    let mut out = [0u8; SIGNING_KEY_LEN];
+                    self.0.encode(&mut out);
+                    out
+                }
+
+                /// Return the matching verifying key.
+                pub fn verifying_key(&self) -> VerifyingKey {
+                    let mut bytes = [0u8; VERIFYING_KEY_LEN];
+                    bytes[..N].copy_from_slice(&self.0.pk_seed);
+                    bytes[N..].copy_from_slice(&self.0.pk_root);
+                    VerifyingKey(bytes)
+                }
+
+                /// Sign `message` with the "hedged" variant of SLH-DSA.
+                ///
+                /// `context` is the context string, which may be empty,
+                /// and must be no more than 255 bytes.  The message is a
+                /// sequence of byte slices, so some workloads can avoid
+                /// joining it into one buffer beforehand.
+                ///
+                /// The hedged variant mixes fresh randomness into the
+                /// signature, which is recommended by FIPS205.
+                ///
+                /// Fails if `context` is too long or the random source fails.
+                pub fn sign(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                ) -> Result<[u8; SIGNATURE_LEN], Error> {
+                    self.sign_with_rng(context, message, &mut SystemRandom)
+                }
+
+                /// Sign `message` with the hedged variant of SLH-DSA,
+                /// taking the fresh randomness from `rng`.
+                ///
+                /// See [`Self::sign()`] for the meaning of the other arguments.
+                ///
+                /// Fails if `context` is too long or `rng` fails.
+                pub fn sign_with_rng(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                    rng: &mut dyn RandomSource,
+                ) -> Result<[u8; SIGNATURE_LEN], Error> {
+                    let _entry = Entry::new_secret();
+                    let mut opt_rand = [0u8; N];
+                    rng.fill(&mut opt_rand)?;
+                    self.sign_with_rand(context, message, &opt_rand)
+                }
+
+                /// Sign `message` with the deterministic variant of SLH-DSA.
+                ///
+                /// See [`Self::sign()`] for the meaning of the arguments.
+                ///
+                /// Fails if `context` is too long.
+                pub fn sign_deterministic(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                ) -> Result<[u8; SIGNATURE_LEN], Error> {
+                    let _entry = Entry::new_secret();
+                    let pk_seed = self.0.pk_seed;
+                    self.sign_with_rand(context, message, &pk_seed)
+                }
+
+                fn sign_with_rand(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                    opt_rand: &[u8; N],
+                ) -> Result<[u8; SIGNATURE_LEN], Error> {
+                    let message = Message::new(context, message)?;
+                    let mut signature = [0u8; SIGNATURE_LEN];
+                    Engine::<N, Hash>::new(&PARAMS, &self.0.pk_seed).sign(
+                        &self.0,
+                        &message,
+                        opt_rand,
+                        &mut signature,
+                    );
+                    Ok(signature)
+                }
+            }
+
+            /// A verifying (public) key.
+            #[derive(Clone)]
+            pub struct VerifyingKey([u8; VERIFYING_KEY_LEN]);
+
+            impl VerifyingKey {
+                /// Decode a verifying key.
+                ///
+                /// This fails with [`Error::WrongLength`] if `bytes` is not
+                /// [`VERIFYING_KEY_LEN`] bytes long.
+                pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                    bytes.try_into().map(Self).map_err(|_| Error::WrongLength)
+                }
+
+                /// Return the encoding of this key.
+                pub fn as_bytes(&self) -> &[u8; VERIFYING_KEY_LEN] {
+                    &self.0
+                }
+
+                /// Verify `signature` over `message` and `context`.
+                ///
+                /// See [`SigningKey::sign()`] for the meaning of `context`
+                /// and `message`.
+                ///
+                /// Returns `Err(Error::BadSignature)` if the signature is
+                /// invalid, or `Err(Error::WrongLength)` if `signature` or
+                /// `context` have an invalid length.
+                pub fn verify(
+                    &self,
+                    context: &[u8],
+                    message: &[&[u8]],
+                    signature: &[u8],
+                ) -> Result<(), Error> {
+                    let _entry = Entry::new_public();
+                    let message = Message::new(context, message)?;
+                    let (pk_seed, pk_root) = self.0.split_at(N);
+                    Engine::<N, Hash>::new(&PARAMS, pk_seed.try_into().unwrap()).verify(
+                        pk_root.try_into().unwrap(),
+                        &message,
+                        signature,
+                    )
+                }
+            }
+        }
+    };
+}
+
+slh_dsa!(
+    slh_dsa_sha2_128s,
+    Sha2Hashes,
+    16,
+    63,
+    7,
+    12,
+    14,
+    30,
+    "SLH-DSA-SHA2-128s, which targets NIST security category 1 with small signatures."
+);
+slh_dsa!(
+    slh_dsa_sha2_128f,
+    Sha2Hashes,
+    16,
+    66,
+    22,
+    6,
+    33,
+    34,
+    "SLH-DSA-SHA2-128f, which targets NIST security category 1 with fast signing."
+);
+slh_dsa!(
+    slh_dsa_sha2_192s,
+    Sha2Hashes,
+    24,
+    63,
+    7,
+    14,
+    17,
+    39,
+    "SLH-DSA-SHA2-192s, which targets NIST security category 3 with small signatures."
+);
+slh_dsa!(
+    slh_dsa_sha2_192f,
+    Sha2Hashes,
+    24,
+    66,
+    22,
+    8,
+    33,
+    42,
+    "SLH-DSA-SHA2-192f, which targets NIST security category 3 with fast signing."
+);
+slh_dsa!(
+    slh_dsa_sha2_256s,
+    Sha2Hashes,
+    32,
+    64,
+    8,
+    14,
+    22,
+    47,
+    "SLH-DSA-SHA2-256s, which targets NIST security category 5 with small signatures."
+);
+slh_dsa!(
+    slh_dsa_sha2_256f,
+    Sha2Hashes,
+    32,
+    68,
+    17,
+    9,
+    35,
+    49,
+    "SLH-DSA-SHA2-256f, which targets NIST security category 5 with fast signing."
+);
+slh_dsa!(
+    slh_dsa_shake_128s,
+    ShakeHashes,
+    16,
+    63,
+    7,
+    12,
+    14,
+    30,
+    "SLH-DSA-SHAKE-128s, which targets NIST security category 1 with small signatures."
+);
+slh_dsa!(
+    slh_dsa_shake_128f,
+    ShakeHashes,
+    16,
+    66,
+    22,
+    6,
+    33,
+    34,
+    "SLH-DSA-SHAKE-128f, which targets NIST security category 1 with fast signing."
+);
+slh_dsa!(
+    slh_dsa_shake_192s,
+    ShakeHashes,
+    24,
+    63,
+    7,
+    14,
+    17,
+    39,
+    "SLH-DSA-SHAKE-192s, which targets NIST security category 3 with small signatures."
+);
+slh_dsa!(
+    slh_dsa_shake_192f,
+    ShakeHashes,
+    24,
+    66,
+    22,
+    8,
+    33,
+    42,
+    "SLH-DSA-SHAKE-192f, which targets NIST security category 3 with fast signing."
+);
+slh_dsa!(
+    slh_dsa_shake_256s,
+    ShakeHashes,
+    32,
+    64,
+    8,
+    14,
+    22,
+    47,
+    "SLH-DSA-SHAKE-256s, which targets NIST security category 5 with small signatures."
+);
+slh_dsa!(
+    slh_dsa_shake_256f,
+    ShakeHashes,
+    32,
+    68,
+    17,
+    9,
+    35,
+    49,
+    "SLH-DSA-SHAKE-256f, which targets NIST security category 5 with fast signing."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn sha256(bytes: &[u8]) -> String {
+        let mut ctx = Sha256Context::new();
+        ctx.update(bytes);
+        hex(&ctx.finish())
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    // These test vectors were produced with OpenSSL 3.5, using the seed
+    // `00 01 02 ..`, and deterministic signing of "message" with the
+    // context "ctx".
+    /// How much of a test runs without `SLOW_TESTS`: signing with
+    /// some parameter sets takes minutes in debug builds.
+    #[derive(PartialEq)]
+    enum Quick {
+        Everything,
+        KeyGen,
+        Nothing,
+    }
+
+    macro_rules! kat_test {
+        ($name:ident, $module:ident, $quick:expr, $root:literal, $sig:literal) => {
+            #[test]
+            fn $name() {
+                use $module::*;
+
+                let quick = match option_env!("SLOW_TESTS") {
+                    Some(_) => Quick::Everything,
+                    None => $quick,
+                };
+                if quick == Quick::Nothing {
+                    return;
+                }
+
+                let seed = core::array::from_fn(|i| i as u8);
+                let sk = SigningKey::from_seed(&seed);
+                let pk = sk.verifying_key();
+                assert_eq!(&pk.as_bytes()[..SEED_LEN / 3], &seed[2 * SEED_LEN / 3..]);
+                assert_eq!(hex(&pk.as_bytes()[SEED_LEN / 3..]), $root);
+
+                let sk_bytes = sk.to_bytes();
+                assert_eq!(&sk_bytes[..SEED_LEN], &seed[..]);
+                assert_eq!(&sk_bytes[SEED_LEN..], &pk.as_bytes()[SEED_LEN / 3..]);
+                if quick == Quick::KeyGen {
+                    return;
+                }
+
+                let sig = sk.sign_deterministic(b"ctx", &[b"mess", b"age"]).unwrap();
+                assert_eq!(sha256(&sig), $sig);
+
+                let pk = VerifyingKey::from_bytes(pk.as_bytes()).unwrap();
+                pk.verify(b"ctx", &[b"message"], &sig).unwrap();
+                assert_eq!(
+                    pk.verify(b"", &[b"message"], &sig),
+                    Err(Error::BadSignature)
+                );
+                assert_eq!(
+                    pk.verify(b"ctx", &[b"massage"], &sig),
+                    Err(Error::BadSignature)
+                );
+                assert_eq!(
+                    pk.verify(b"ctx", &[b"message"], &sig[1..]),
+                    Err(Error::WrongLength)
+                );
+
+                // corrupt each part of the signature: R, FORS, and each hypertree layer
+                for i in (0..SIGNATURE_LEN).step_by(SIGNATURE_LEN / 7) {
+                    let mut bad = sig;
+                    bad[i] ^= 0x01;
+                    assert_eq!(
+                        pk.verify(b"ctx", &[b"message"], &bad),
+                        Err(Error::BadSignature)
+                    );
+                }
+
+                let sk = SigningKey::from_bytes(&sk_bytes).unwrap();
+                let hedged = sk.sign(b"ctx", &[b"message"]).unwrap();
+                assert_ne!(hedged, sig);
+                pk.verify(b"ctx", &[b"message"], &hedged).unwrap();
+
+                assert_eq!(
+                    sk.sign(&[0u8; 256], &[b"message"]).err(),
+                    Some(Error::WrongLength)
+                );
+                assert_eq!(
+                    sk.sign_with_rng(b"ctx", &[b"message"], &mut SliceRandomSource(&[]))
+                        .err(),
+                    Some(Error::RngFailed)
+                );
+                assert!(SigningKey::from_bytes(&sk_bytes[1..]).is_err());
+                assert!(VerifyingKey::from_bytes(&pk.as_bytes()[1..]).is_err());
+            }
+        };
+    }
+
+    kat_test!(
+        sha2_128s,
+        slh_dsa_sha2_128s,
+        Quick::Nothing,
+        "990ce6298792b128846a8e4a3a68954c",
+        "75dd88f52d5158b47531b84490e077f3cad276d721cc78dcca64e021ddb3f7f8"
+    );
+    kat_test!(
+        sha2_128f,
+        slh_dsa_sha2_128f,
+        Quick::Everything,
+        "3b56e816847f000386aeec2e2bb9e1b5",
+        "15a8953fd8b33b49b163e90016f444b71126fd5def550b6293581ec054fd8e32"
+    );
+    kat_test!(
+        sha2_192s,
+        slh_dsa_sha2_192s,
+        Quick::Nothing,
+        "b6f282ce116ff59bce2d9fc4a67c6031dabdce326c34f541",
+        "c0c5c23f596185dacc2ec09ba44df505c555d8ee77f475f841d2dd9622f4a265"
+    );
+    kat_test!(
+        sha2_192f,
+        slh_dsa_sha2_192f,
+        Quick::KeyGen,
+        "9236ccebbb3a90ac2452dd89de49dab1340ec02419a2870e",
+        "9d2f973ddbf3c02e6b2d79ededf579bb52b2af4858789c7f03d9c1826a58f38e"
+    );
+    kat_test!(
+        sha2_256s,
+        slh_dsa_sha2_256s,
+        Quick::Nothing,
+        "da7163e601352515bc0f06f9f4f44be71a5a65ee9dca5575cf4a7b6d4a87d6e2",
+        "97e5a7b6e3dd35d99961361b4770c30b57cf459463feed7628d4cab130851c84"
+    );
+    kat_test!(
+        sha2_256f,
+        slh_dsa_sha2_256f,
+        Quick::KeyGen,
+        "42cffe64ddbd6731063752684df77c8b58c225dc6b491208916b654ea1393176",
+        "c4dfe2b99211768b1648046960696c97da2542879638a55d259043db436583ee"
+    );
+    kat_test!(
+        shake_128s,
+        slh_dsa_shake_128s,
+        Quick::Nothing,
+        "89fd81fdbb5b94129b14761bdc6bf682",
+        "82067c7a84ab7bb147a2b2a0964a865f9416fb9c87dc1d04ed1d26463b428bd7"
+    );
+    kat_test!(
+        shake_128f,
+        slh_dsa_shake_128f,
+        Quick::KeyGen,
+        "a90e4715b9a925c332801767fd786371",
+        "93cbbb6e4d56f3f218fdc8a3423acfbada3e42f2530e11919d11fd4cddc49367"
+    );
+    kat_test!(
+        shake_192s,
+        slh_dsa_shake_192s,
+        Quick::Nothing,
+        "eb247f955d8eca24a5860536c56b2c4d1e8d8e835eb27d2d",
+        "e573477f775d72e45687dd298254c7698c118510dc0706d996dc8f8fdf450712"
+    );
+    kat_test!(
+        shake_192f,
+        slh_dsa_shake_192f,
+        Quick::KeyGen,
+        "3f01b06bebed020a459696868d115fe8507ded8dc08e825d",
+        "8f401e226bee2e8561e0d666ec34d12500f7d282653ff65a96e1c288125a340e"
+    );
+    kat_test!(
+        shake_256s,
+        slh_dsa_shake_256s,
+        Quick::Nothing,
+        "27ea444dbc8ca9c169fd484b9e977eb77a4f233550757e025cf180ede7e8839f",
+        "3c02d95a5e57cde1189f5b62469c0a5ea5bf70a07de916137815130f929e3ff6"
+    );
+    kat_test!(
+        shake_256f,
+        slh_dsa_shake_256f,
+        Quick::KeyGen,
+        "818d7e76beef979b5bbf9161fdefa21bd0fe0bfe19157a5711a8de8a8f6878e6",
+        "3bbc444d8978102462f42e74be00f620e2ae4aa0cebcaf5ae10306e0af59cd1a"
+    );
+
+    #[test]
+    fn base_2b_matches_spec() {
+        let r = base_2b(&[0b1010_1100, 0b0011_1111, 0xff], 6, 4);
+        assert_eq!(&r[..4], &[0b101011, 0b000011, 0b111111, 0b111111]);
+    }
+
+    #[test]
+    fn wots_checksum() {
+        // all-zero digits give the largest checksum: 32 * 15 = 0x1e0
+        let digits = wots_digits(&[0u8; 16]);
+        assert_eq!(&digits[32..35], &[0x1, 0xe, 0x0]);
+        let digits = wots_digits(&[0xffu8; 16]);
+        assert_eq!(&digits[32..35], &[0, 0, 0]);
+    }
+}
@@ -21,9 +21,16 @@ impl PrivateKey {
     ///
     /// Fails only if the random source fails.
     pub fn new_random() -> Result<Self, Error> {
+        Self::generate(&mut SystemRandom)
+    }
+
+    /// Generate a new key using `rng`.
+    ///
+    /// Fails only if the random source fails.
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
         let _entry = low::Entry::new_secret();
         let mut r = [0u8; Self::BYTES];
-        SystemRandom.fill(&mut r)?;
+        rng.fill(&mut r)?;
         let r = low::ct::into_secret(r);
         Ok(Self(util::little_endian_to_u64x4(&r)))
     }
@@ -106,8 +113,15 @@ impl StaticPrivateKey {
     ///
     /// Fails only if the random source fails.
     pub fn new_random() -> Result<Self, Error> {
+        Self::generate(&mut SystemRandom)
+    }
+
+    /// Generate a new key using `rng`.
+    ///
+    /// Fails only if the random source fails.
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
         let _entry = low::Entry::new_secret();
-        PrivateKey::new_random().map(Self)
+        PrivateKey::generate(rng).map(Self)
     }
 
     /// Compute the associated public key.
@@ -169,9 +183,16 @@ impl Drop for SharedSecret {
     }
 }
 
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mid::rng::SliceRandomSource;
 
     #[test]
     fn rfc7748_1() {
@@ -232,7 +253,7 @@ mod tests {
 
         if option_env!("SLOW_TESTS").is_some() {
             // After 1,000,000 iterations: 7c3911e0ab2586fd864497297e575e6f3bc601c0883c30df5f4dd2d24f665424
-            for _ in 1000..1000_000 {
+            for _ in 1000..1_000_000 {
                 let new_u = PublicKey::from_array(&k.as_bytes());
                 let res = k.diffie_hellman(&u).unwrap();
                 u = new_u;
@@ -246,6 +267,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn generate() {
+        let key = StaticPrivateKey::generate(&mut SliceRandomSource(&[0x42; 32])).unwrap();
+        assert_eq!(
+            key.public_key().as_bytes(),
+            StaticPrivateKey::from_array(&[0x42; 32])
+                .public_key()
+                .as_bytes()
+        );
+        assert_eq!(
+            PrivateKey::generate(&mut SliceRandomSource(&[0x42; 31])).err(),
+            Some(Error::RngFailed)
+        );
+    }
+
     #[test]
     fn base_mul() {
         let res = StaticPrivateKey::from_array(&[1u8; 32]).public_key();
@@ -0,0 +1,259 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! The X25519MLKEM768 hybrid key exchange, as specified in
+//! [draft-ietf-tls-ecdhe-mlkem](https://datatracker.ietf.org/doc/draft-ietf-tls-ecdhe-mlkem/).
+//!
+//! This is ML-KEM-768 and X25519 performed side by side.  The keys,
+//! ciphertexts and shared secrets of each are concatenated, with
+//! ML-KEM first.  The result is exposed as a KEM, so the client
+//! sends an encapsulation key as its key share, and the server replies
+//! with a ciphertext.
+
+use crate::Error;
+use crate::low::{Entry, zeroise};
+use crate::mid::mlkem::ml_kem_768;
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::x25519;
+
+const X25519_LEN: usize = 32;
+
+/// The length of an encapsulation key (the client's key share), in bytes.
+pub const ENCAPSULATION_KEY_LEN: usize = ml_kem_768::ENCAPSULATION_KEY_LEN + X25519_LEN;
+
+/// The length of a ciphertext (the server's key share), in bytes.
+pub const CIPHERTEXT_LEN: usize = ml_kem_768::CIPHERTEXT_LEN + X25519_LEN;
+
+/// The length of a shared secret, in bytes.
+pub const SHARED_SECRET_LEN: usize = 64;
+
+/// A decapsulation (private) key.
+pub struct DecapsulationKey {
+    ml_kem: ml_kem_768::DecapsulationKey,
+    x25519: x25519::StaticPrivateKey,
+}
+
+impl DecapsulationKey {
+    /// Generate a new key using the system random number generator.
+    ///
+    /// Fails only if the random source fails.
+    pub fn new_random() -> Result<Self, Error> {
+        Self::generate(&mut SystemRandom)
+    }
+
+    /// Generate a new key using `rng`.
+    ///
+    /// Fails only if the random source fails.
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let _entry = Entry::new_secret();
+        let ml_kem = ml_kem_768::DecapsulationKey::generate(rng)?;
+        let x25519 = random_x25519(rng)?;
+        Ok(Self { ml_kem, x25519 })
+    }
+
+    /// Return the matching encapsulation key.
+    pub fn encapsulation_key(&self) -> EncapsulationKey {
+        let _entry = Entry::new_public();
+        let ml_kem = self.ml_kem.encapsulation_key();
+        let mut bytes = [0u8; ENCAPSULATION_KEY_LEN];
+        let (ml_kem_bytes, x25519_bytes) = bytes.split_at_mut(ml_kem_768::ENCAPSULATION_KEY_LEN);
+        ml_kem_bytes.copy_from_slice(ml_kem.as_bytes());
+        x25519_bytes.copy_from_slice(&self.x25519.public_key().as_bytes());
+        EncapsulationKey { ml_kem, bytes }
+    }
+
+    /// Decapsulate `ciphertext`, returning the shared secret.
+    ///
+    /// Fails with [`Error::WrongLength`] if `ciphertext` is not
+    /// [`CIPHERTEXT_LEN`] bytes long, or [`Error::NotOnCurve`] if
+    /// the X25519 shared secret is zero.
+    ///
+    /// As with ML-KEM, an otherwise invalid ciphertext does not cause an
+    /// error, but yields a shared secret which will not match the sender's.
+    pub fn decapsulate(&self, ciphertext: &[u8]) -> Result<SharedSecret, Error> {
+        let _entry = Entry::new_secret();
+        if ciphertext.len() != CIPHERTEXT_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let (ml_kem_ct, x25519_pub) = ciphertext.split_at(ml_kem_768::CIPHERTEXT_LEN);
+        let ml_kem_ss = self.ml_kem.decapsulate(ml_kem_ct)?;
+        let x25519_ss = self
+            .x25519
+            .diffie_hellman(&x25519::PublicKey::try_from_slice(x25519_pub)?)?;
+        Ok(SharedSecret::combine(&ml_kem_ss.0, &x25519_ss.0))
+    }
+}
+
+/// An encapsulation (public) key.
+#[derive(Clone)]
+pub struct EncapsulationKey {
+    ml_kem: ml_kem_768::EncapsulationKey,
+    bytes: [u8; ENCAPSULATION_KEY_LEN],
+}
+
+impl EncapsulationKey {
+    /// Decode an encapsulation key.
+    ///
+    /// This fails with [`Error::WrongLength`] if `bytes` is not
+    /// [`ENCAPSULATION_KEY_LEN`] bytes long, or [`Error::OutOfRange`]
+    /// if the ML-KEM encapsulation key is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        if bytes.len() != ENCAPSULATION_KEY_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let ml_kem =
+            ml_kem_768::EncapsulationKey::from_bytes(&bytes[..ml_kem_768::ENCAPSULATION_KEY_LEN])?;
+        Ok(Self {
+            ml_kem,
+            bytes: bytes.try_into().unwrap(),
+        })
+    }
+
+    /// Return the encoding of this key.
+    pub fn as_bytes(&self) -> &[u8; ENCAPSULATION_KEY_LEN] {
+        &self.bytes
+    }
+
+    /// Encapsulate a fresh shared secret to this key.
+    ///
+    /// Returns the ciphertext, which should be sent to the
+    /// holder of the decapsulation key, and the shared secret.
+    ///
+    /// Fails if the random source fails, or with [`Error::NotOnCurve`]
+    /// if the X25519 shared secret is zero.
+    pub fn encapsulate(&self) -> Result<([u8; CIPHERTEXT_LEN], SharedSecret), Error> {
+        self.encapsulate_with_rng(&mut SystemRandom)
+    }
+
+    /// Encapsulate a fresh shared secret to this key, using `rng`.
+    ///
+    /// Otherwise, as [`Self::encapsulate()`].
+    pub fn encapsulate_with_rng(
+        &self,
+        rng: &mut dyn RandomSource,
+    ) -> Result<([u8; CIPHERTEXT_LEN], SharedSecret), Error> {
+        let _entry = Entry::new_secret();
+        let (ml_kem_ct, ml_kem_ss) = self.ml_kem.encapsulate_with_rng(rng)?;
+
+        let ephemeral = random_x25519(rng)?;
+        let peer =
+            x25519::PublicKey::try_from_slice(&self.bytes[ml_kem_768::ENCAPSULATION_KEY_LEN..])?;
+        let x25519_ss = ephemeral.diffie_hellman(&peer)?;
+
+        let mut ciphertext = [0u8; CIPHERTEXT_LEN];
+        let (ct, x25519_pub) = ciphertext.split_at_mut(ml_kem_768::CIPHERTEXT_LEN);
+        ct.copy_from_slice(&ml_kem_ct);
+        x25519_pub.copy_from_slice(&ephemeral.public_key().as_bytes());
+
+        Ok((
+            ciphertext,
+            SharedSecret::combine(&ml_kem_ss.0, &x25519_ss.0),
+        ))
+    }
+}
+
+/// A shared secret resulting from X25519MLKEM768.
+///
+/// This is the ML-KEM-768 shared secret followed by the X25519 shared secret.
+pub struct SharedSecret(pub [u8; SHARED_SECRET_LEN]);
+
+impl SharedSecret {
+    fn combine(ml_kem: &[u8; 32], x25519: &[u8; 32]) -> Self {
+        let mut ss = [0u8; SHARED_SECRET_LEN];
+        ss[..32].copy_from_slice(ml_kem);
+        ss[32..].copy_from_slice(x25519);
+        Self(ss)
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        zeroise(&mut self.0);
+    }
+}
+
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+fn random_x25519(rng: &mut dyn RandomSource) -> Result<x25519::StaticPrivateKey, Error> {
+    let mut bytes = [0u8; X25519_LEN];
+    rng.fill(&mut bytes)?;
+    let key = x25519::StaticPrivateKey::from_array(&bytes);
+    zeroise(&mut bytes);
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::rng::SliceRandomSource;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn seed() -> [u8; 96] {
+        core::array::from_fn(|i| i as u8)
+    }
+
+    #[test]
+    fn openssl_interop() {
+        // This ciphertext and shared secret were produced by OpenSSL 3.5,
+        // encapsulating to the key generated from `seed()`.
+        let dk = DecapsulationKey::generate(&mut SliceRandomSource(&seed())).unwrap();
+        let ek = dk.encapsulation_key();
+        assert_eq!(
+            hex(&ek.as_bytes()[ml_kem_768::ENCAPSULATION_KEY_LEN..]),
+            "79a631eede1bf9c98f12032cdeadd0e7a079398fc786b88cc846ec89af85a51a"
+        );
+
+        let ct = include_bytes!("x25519mlkem768/openssl-ciphertext.bin");
+        assert_eq!(
+            hex(&dk.decapsulate(ct).unwrap().0),
+            "4460212542abfcbaa045f3e411ee31b7ead33842b932834ca9a3d59c410371a7\
+             38251e3b7d58528334d6c7d4967aecae0968d0eda339121e2fd3dd8370ba6142"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let dk = DecapsulationKey::new_random().unwrap();
+        let ek = EncapsulationKey::from_bytes(dk.encapsulation_key().as_bytes()).unwrap();
+        let (ct, ss) = ek.encapsulate().unwrap();
+        assert_eq!(dk.decapsulate(&ct).unwrap().0, ss.0);
+
+        // the key shares are the ML-KEM values, then the X25519 values
+        let (ml_kem_ct, x25519_pub) = ct.split_at(ml_kem_768::CIPHERTEXT_LEN);
+        assert_eq!(dk.ml_kem.decapsulate(ml_kem_ct).unwrap().0, ss.0[..32]);
+        let x25519_ss = dk
+            .x25519
+            .diffie_hellman(&x25519::PublicKey::try_from_slice(x25519_pub).unwrap())
+            .unwrap();
+        assert_eq!(x25519_ss.0, ss.0[32..]);
+
+        assert_eq!(dk.decapsulate(&ct[1..]).err(), Some(Error::WrongLength));
+        assert_eq!(
+            EncapsulationKey::from_bytes(&ek.as_bytes()[1..]).err(),
+            Some(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn rejects_zero_x25519_share() {
+        let dk = DecapsulationKey::new_random().unwrap();
+        let (mut ct, _) = dk.encapsulation_key().encapsulate().unwrap();
+        ct[ml_kem_768::CIPHERTEXT_LEN..].fill(0);
+        assert_eq!(dk.decapsulate(&ct).err(), Some(Error::NotOnCurve));
+
+        let mut ek = *dk.encapsulation_key().as_bytes();
+        ek[ml_kem_768::ENCAPSULATION_KEY_LEN..].fill(0);
+        let ek = EncapsulationKey::from_bytes(&ek).unwrap();
+        assert_eq!(ek.encapsulate().err(), Some(Error::NotOnCurve));
+    }
+}
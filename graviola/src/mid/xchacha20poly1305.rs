@@ -3,6 +3,7 @@
 
 use crate::Error;
 use crate::low::chacha20::XChaCha20;
+use crate::low::chacha20poly1305;
 use crate::low::poly1305::Poly1305;
 use crate::low::{Entry, ct_equal, zeroise};
 
@@ -102,11 +103,9 @@ impl XChaCha20Poly1305 {
         pad(&mut poly, aad.len());
 
         if encrypt {
-            chacha.cipher(cipher_inout);
-            poly.add_bytes(cipher_inout);
+            chacha20poly1305::encrypt(chacha.as_chacha20(), &mut poly, cipher_inout);
         } else {
-            poly.add_bytes(cipher_inout);
-            chacha.cipher(cipher_inout);
+            chacha20poly1305::decrypt(chacha.as_chacha20(), &mut poly, cipher_inout);
         }
         pad(&mut poly, cipher_inout.len());
 
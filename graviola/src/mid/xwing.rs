@@ -0,0 +1,326 @@
+// Written for Graviola by Joe Birr-Pixton, 2024.
+// SPDX-License-Identifier: Apache-2.0 OR ISC OR MIT-0
+
+//! The X-Wing hybrid KEM, as specified in
+//! [draft-connolly-cfrg-xwing-kem](https://datatracker.ietf.org/doc/draft-connolly-cfrg-xwing-kem/).
+//!
+//! X-Wing combines ML-KEM-768 and X25519.  Unlike X25519MLKEM768, the
+//! shared secret is a SHA3-256 hash over both component secrets, plus the
+//! X25519 ciphertext and public key.  Binding these values means the
+//! all-zero X25519 output need not be rejected.
+//!
+//! The decapsulation key is a 32-byte seed, from which both component
+//! keys are derived.
+
+use super::util;
+use crate::Error;
+use crate::low::{self, Entry, zeroise};
+use crate::mid::mlkem::ml_kem_768;
+use crate::mid::rng::{RandomSource, SystemRandom};
+use crate::mid::sha3::{Sha3_256, Shake256};
+
+const X25519_LEN: usize = 32;
+
+/// Domain separator, hashed last by the combiner.
+const LABEL: &[u8; 6] = b"\\.//^\\";
+
+/// The length of a decapsulation key, in bytes.
+pub const DECAPSULATION_KEY_LEN: usize = 32;
+
+/// The length of an encapsulation key, in bytes.
+pub const ENCAPSULATION_KEY_LEN: usize = ml_kem_768::ENCAPSULATION_KEY_LEN + X25519_LEN;
+
+/// The length of a ciphertext, in bytes.
+pub const CIPHERTEXT_LEN: usize = ml_kem_768::CIPHERTEXT_LEN + X25519_LEN;
+
+/// A decapsulation (private) key.
+pub struct DecapsulationKey {
+    seed: [u8; DECAPSULATION_KEY_LEN],
+    ml_kem: ml_kem_768::DecapsulationKey,
+    x25519: [u8; X25519_LEN],
+    x25519_public: [u8; X25519_LEN],
+}
+
+impl DecapsulationKey {
+    /// Generate a new key using the system random number generator.
+    ///
+    /// Fails only if the random source fails.
+    pub fn new_random() -> Result<Self, Error> {
+        Self::generate(&mut SystemRandom)
+    }
+
+    /// Generate a new key using `rng`.
+    ///
+    /// Fails only if the random source fails.
+    pub fn generate(rng: &mut dyn RandomSource) -> Result<Self, Error> {
+        let mut seed = [0u8; DECAPSULATION_KEY_LEN];
+        rng.fill(&mut seed)?;
+        let key = Self::from_seed(&seed);
+        zeroise(&mut seed);
+        Ok(key)
+    }
+
+    /// Decode a decapsulation key.
+    ///
+    /// This fails with [`Error::WrongLength`] if `bytes` is not
+    /// [`DECAPSULATION_KEY_LEN`] bytes long.  Any such value is a valid key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        bytes
+            .try_into()
+            .map(Self::from_seed)
+            .map_err(|_| Error::WrongLength)
+    }
+
+    fn from_seed(seed: &[u8; DECAPSULATION_KEY_LEN]) -> Self {
+        let _entry = Entry::new_secret();
+        let mut expanded = [0u8; 96];
+        let mut shake = Shake256::new();
+        shake.update(seed);
+        shake.squeeze(&mut expanded);
+
+        let ml_kem = ml_kem_768::DecapsulationKey::from_seed(expanded[..64].try_into().unwrap());
+        let x25519: [u8; X25519_LEN] = expanded[64..].try_into().unwrap();
+        zeroise(&mut expanded);
+
+        let x25519_public = x25519_base(&x25519);
+        Self {
+            seed: *seed,
+            ml_kem,
+            x25519,
+            x25519_public,
+        }
+    }
+
+    /// Encode this key.
+    pub fn to_bytes(&self) -> [u8; DECAPSULATION_KEY_LEN] {
+        self.seed
+    }
+
+    /// Return the matching encapsulation key.
+    pub fn encapsulation_key(&self) -> EncapsulationKey {
+        let _entry = Entry::new_public();
+        let ml_kem = self.ml_kem.encapsulation_key();
+        let mut bytes = [0u8; ENCAPSULATION_KEY_LEN];
+        let (ml_kem_bytes, x25519_bytes) = bytes.split_at_mut(ml_kem_768::ENCAPSULATION_KEY_LEN);
+        ml_kem_bytes.copy_from_slice(ml_kem.as_bytes());
+        x25519_bytes.copy_from_slice(&self.x25519_public);
+        EncapsulationKey { ml_kem, bytes }
+    }
+
+    /// Decapsulate `ciphertext`, returning the shared secret.
+    ///
+    /// Fails with [`Error::WrongLength`] if `ciphertext` is not
+    /// [`CIPHERTEXT_LEN`] bytes long.
+    ///
+    /// An invalid ciphertext of the correct length does not cause
+    /// an error: instead a pseudorandom shared secret is returned
+    /// which will not match the sender's.
+    pub fn decapsulate(&self, ciphertext: &[u8]) -> Result<SharedSecret, Error> {
+        let _entry = Entry::new_secret();
+        if ciphertext.len() != CIPHERTEXT_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let (ml_kem_ct, x25519_ct) = ciphertext.split_at(ml_kem_768::CIPHERTEXT_LEN);
+        let x25519_ct: &[u8; X25519_LEN] = x25519_ct.try_into().unwrap();
+        let ml_kem_ss = self.ml_kem.decapsulate(ml_kem_ct)?;
+        let mut x25519_ss = x25519(&self.x25519, x25519_ct);
+        let ss = combiner(&ml_kem_ss.0, &x25519_ss, x25519_ct, &self.x25519_public);
+        zeroise(&mut x25519_ss);
+        Ok(ss)
+    }
+}
+
+impl Drop for DecapsulationKey {
+    fn drop(&mut self) {
+        zeroise(&mut self.seed);
+        zeroise(&mut self.x25519);
+    }
+}
+
+/// An encapsulation (public) key.
+#[derive(Clone)]
+pub struct EncapsulationKey {
+    ml_kem: ml_kem_768::EncapsulationKey,
+    bytes: [u8; ENCAPSULATION_KEY_LEN],
+}
+
+impl EncapsulationKey {
+    /// Decode an encapsulation key.
+    ///
+    /// This fails with [`Error::WrongLength`] if `bytes` is not
+    /// [`ENCAPSULATION_KEY_LEN`] bytes long, or [`Error::OutOfRange`]
+    /// if the ML-KEM encapsulation key is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let _entry = Entry::new_public();
+        if bytes.len() != ENCAPSULATION_KEY_LEN {
+            return Err(Error::WrongLength);
+        }
+
+        let ml_kem =
+            ml_kem_768::EncapsulationKey::from_bytes(&bytes[..ml_kem_768::ENCAPSULATION_KEY_LEN])?;
+        Ok(Self {
+            ml_kem,
+            bytes: bytes.try_into().unwrap(),
+        })
+    }
+
+    /// Return the encoding of this key.
+    pub fn as_bytes(&self) -> &[u8; ENCAPSULATION_KEY_LEN] {
+        &self.bytes
+    }
+
+    /// Encapsulate a fresh shared secret to this key.
+    ///
+    /// Returns the ciphertext, which should be sent to the
+    /// holder of the decapsulation key, and the shared secret.
+    ///
+    /// Fails only if the random source fails.
+    pub fn encapsulate(&self) -> Result<([u8; CIPHERTEXT_LEN], SharedSecret), Error> {
+        self.encapsulate_with_rng(&mut SystemRandom)
+    }
+
+    /// Encapsulate a fresh shared secret to this key, using `rng`.
+    ///
+    /// Otherwise, as [`Self::encapsulate()`].
+    pub fn encapsulate_with_rng(
+        &self,
+        rng: &mut dyn RandomSource,
+    ) -> Result<([u8; CIPHERTEXT_LEN], SharedSecret), Error> {
+        let _entry = Entry::new_secret();
+        let (ml_kem_ct, ml_kem_ss) = self.ml_kem.encapsulate_with_rng(rng)?;
+
+        let mut ephemeral = [0u8; X25519_LEN];
+        rng.fill(&mut ephemeral)?;
+        let x25519_public: &[u8; X25519_LEN] = self.bytes[ml_kem_768::ENCAPSULATION_KEY_LEN..]
+            .try_into()
+            .unwrap();
+        let x25519_ct = x25519_base(&ephemeral);
+        let mut x25519_ss = x25519(&ephemeral, x25519_public);
+        zeroise(&mut ephemeral);
+
+        let ss = combiner(&ml_kem_ss.0, &x25519_ss, &x25519_ct, x25519_public);
+        zeroise(&mut x25519_ss);
+
+        let mut ciphertext = [0u8; CIPHERTEXT_LEN];
+        let (ct, x25519_bytes) = ciphertext.split_at_mut(ml_kem_768::CIPHERTEXT_LEN);
+        ct.copy_from_slice(&ml_kem_ct);
+        x25519_bytes.copy_from_slice(&x25519_ct);
+        Ok((ciphertext, ss))
+    }
+}
+
+/// A shared secret resulting from X-Wing.
+pub struct SharedSecret(pub [u8; 32]);
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        zeroise(&mut self.0);
+    }
+}
+
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+fn combiner(
+    ml_kem_ss: &[u8; 32],
+    x25519_ss: &[u8; 32],
+    x25519_ct: &[u8; X25519_LEN],
+    x25519_public: &[u8; X25519_LEN],
+) -> SharedSecret {
+    let mut h = Sha3_256::new();
+    h.update(ml_kem_ss);
+    h.update(x25519_ss);
+    h.update(x25519_ct);
+    h.update(x25519_public);
+    h.update(LABEL);
+    SharedSecret(h.finish())
+}
+
+/// X25519, without the rejection of a zero result.
+fn x25519(scalar: &[u8; X25519_LEN], point: &[u8; X25519_LEN]) -> [u8; X25519_LEN] {
+    let mut scalar = util::little_endian_to_u64x4(scalar);
+    let mut res = [0u64; 4];
+    low::curve25519_x25519(&mut res, &scalar, &util::little_endian_to_u64x4(point));
+    zeroise(&mut scalar);
+    util::u64x4_to_little_endian(&res)
+}
+
+fn x25519_base(scalar: &[u8; X25519_LEN]) -> [u8; X25519_LEN] {
+    let mut scalar = util::little_endian_to_u64x4(scalar);
+    let mut res = [0u64; 4];
+    low::curve25519_x25519base(&mut res, &scalar);
+    zeroise(&mut scalar);
+    util::u64x4_to_little_endian(&low::ct::into_public(res))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mid::sha2::Sha256Context;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn sha256(bytes: &[u8]) -> String {
+        let mut ctx = Sha256Context::new();
+        ctx.update(bytes);
+        hex(&ctx.finish())
+    }
+
+    #[test]
+    fn decapsulate_kat() {
+        // The ML-KEM part of this ciphertext was produced by OpenSSL 3.5,
+        // and the expected shared secret was computed from that with
+        // independent X25519 and SHA3-256 implementations.
+        let seed: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let dk = DecapsulationKey::from_bytes(&seed).unwrap();
+        assert_eq!(dk.to_bytes(), seed);
+        assert_eq!(
+            sha256(dk.encapsulation_key().as_bytes()),
+            "c9a3565ffde4f72b51661be391ee13e46378d7f06dd5c8bf5af9d2cfb5b8336b"
+        );
+
+        let mut ct = *include_bytes!("xwing/ciphertext.bin");
+        assert_eq!(
+            hex(&dk.decapsulate(&ct).unwrap().0),
+            "316357795d77f010e4851ce13cd0356c3d3aa448116853c27fc3ae3f6d708f53"
+        );
+
+        // a zero X25519 ciphertext is not rejected
+        ct[ml_kem_768::CIPHERTEXT_LEN..].fill(0);
+        assert_eq!(
+            hex(&dk.decapsulate(&ct).unwrap().0),
+            "1376e43cdf0077ed624f8077d9039f13bb3fe30853d7128970a3c6c7f41cb581"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let dk = DecapsulationKey::new_random().unwrap();
+        let ek = EncapsulationKey::from_bytes(dk.encapsulation_key().as_bytes()).unwrap();
+        let (ct, ss) = ek.encapsulate().unwrap();
+        assert_eq!(dk.decapsulate(&ct).unwrap().0, ss.0);
+
+        let dk = DecapsulationKey::from_bytes(&dk.to_bytes()).unwrap();
+        assert_eq!(dk.decapsulate(&ct).unwrap().0, ss.0);
+
+        let mut bad = ct;
+        bad[CIPHERTEXT_LEN - 1] ^= 1;
+        assert_ne!(dk.decapsulate(&bad).unwrap().0, ss.0);
+
+        assert_eq!(dk.decapsulate(&ct[1..]).err(), Some(Error::WrongLength));
+        assert_eq!(
+            EncapsulationKey::from_bytes(&ek.as_bytes()[1..]).err(),
+            Some(Error::WrongLength)
+        );
+        assert_eq!(
+            DecapsulationKey::from_bytes(&[0u8; 31]).err(),
+            Some(Error::WrongLength)
+        );
+    }
+}
@@ -68,3 +68,23 @@ pub(crate) fn process_cavp(filename: impl AsRef<Path>, sink: &mut dyn CavpSink)
         }
     }
 }
+
+/// The stack size that `low-stack` builds are expected to fit within.
+#[cfg(all(feature = "low-stack", not(debug_assertions)))]
+pub(crate) const LOW_STACK_LIMIT: usize = 16 * 1024;
+
+/// Run `f` on a new thread that has only `stack_bytes` of stack.
+///
+/// Exceeding this is a stack overflow, which aborts the whole test
+/// process rather than failing just the calling test.
+#[cfg(all(feature = "low-stack", not(debug_assertions)))]
+pub(crate) fn with_stack_limit<F: FnOnce() + Send>(stack_bytes: usize, f: F) {
+    std::thread::scope(|s| {
+        std::thread::Builder::new()
+            .stack_size(stack_bytes)
+            .spawn_scoped(s, f)
+            .unwrap()
+            .join()
+            .unwrap()
+    });
+}
@@ -1,13 +1,18 @@
 use crypto::SupportedKxGroup;
+use graviola::kem::x25519_mlkem768;
 use graviola::key_agreement::{p256, p384, x25519};
 use rustls::crypto;
 use rustls::ffdhe_groups::FfdheGroup;
 
 /// All key exchange algorithms, in order of preference.
+///
+/// [`X25519MlKem768`] is last: as a client, using it first would cost
+/// a round trip with servers that do not support it.
 pub const ALL_KX_GROUPS: &[&dyn SupportedKxGroup] = &[
     &X25519 as &dyn SupportedKxGroup,
     &P256 as &dyn SupportedKxGroup,
     &P384 as &dyn SupportedKxGroup,
+    &X25519MlKem768 as &dyn SupportedKxGroup,
 ];
 
 /// Key exchange using X25519.
@@ -172,3 +177,79 @@ impl crypto::ActiveKeyExchange for ActiveP384 {
         P384.name()
     }
 }
+
+/// Post-quantum hybrid key exchange using X25519 and ML-KEM-768.
+///
+/// This is `X25519MLKEM768` from
+/// [draft-ietf-tls-ecdhe-mlkem](https://datatracker.ietf.org/doc/draft-ietf-tls-ecdhe-mlkem/),
+/// and is only usable with TLS1.3.
+#[derive(Debug)]
+pub struct X25519MlKem768;
+
+impl SupportedKxGroup for X25519MlKem768 {
+    fn start(&self) -> Result<Box<dyn crypto::ActiveKeyExchange>, rustls::Error> {
+        let decap_key = x25519_mlkem768::DecapsulationKey::new_random()
+            .map_err(|_| rustls::Error::from(crypto::GetRandomFailed))?;
+        let encap_key = decap_key.encapsulation_key();
+
+        Ok(Box::new(ActiveX25519MlKem768 {
+            decap_key,
+            encap_key,
+        }))
+    }
+
+    fn start_and_complete(
+        &self,
+        client_share: &[u8],
+    ) -> Result<crypto::CompletedKeyExchange, rustls::Error> {
+        let (ciphertext, shared_secret) =
+            x25519_mlkem768::EncapsulationKey::from_bytes(client_share)
+                .and_then(|encap_key| encap_key.encapsulate())
+                .map_err(|_| rustls::Error::from(rustls::PeerMisbehaved::InvalidKeyShare))?;
+
+        Ok(crypto::CompletedKeyExchange {
+            group: self.name(),
+            pub_key: ciphertext.to_vec(),
+            secret: crypto::SharedSecret::from(&shared_secret.0[..]),
+        })
+    }
+
+    fn ffdhe_group(&self) -> Option<FfdheGroup<'static>> {
+        None
+    }
+
+    fn name(&self) -> rustls::NamedGroup {
+        rustls::NamedGroup::from(0x11ec)
+    }
+
+    fn usable_for_version(&self, version: rustls::ProtocolVersion) -> bool {
+        version == rustls::ProtocolVersion::TLSv1_3
+    }
+}
+
+struct ActiveX25519MlKem768 {
+    decap_key: x25519_mlkem768::DecapsulationKey,
+    encap_key: x25519_mlkem768::EncapsulationKey,
+}
+
+impl crypto::ActiveKeyExchange for ActiveX25519MlKem768 {
+    fn complete(self: Box<Self>, peer: &[u8]) -> Result<crypto::SharedSecret, rustls::Error> {
+        let shared_secret = self
+            .decap_key
+            .decapsulate(peer)
+            .map_err(|_| rustls::Error::from(rustls::PeerMisbehaved::InvalidKeyShare))?;
+        Ok(crypto::SharedSecret::from(&shared_secret.0[..]))
+    }
+
+    fn pub_key(&self) -> &[u8] {
+        self.encap_key.as_bytes()
+    }
+
+    fn ffdhe_group(&self) -> Option<FfdheGroup<'static>> {
+        None
+    }
+
+    fn group(&self) -> rustls::NamedGroup {
+        X25519MlKem768.name()
+    }
+}
@@ -19,7 +19,7 @@ mod aead;
 mod hash;
 mod hmac;
 mod sign;
-mod verify;
+pub use sign::ExternalSigningKey;
 
 /// Supported key exchange algorithms.
 pub mod kx;
@@ -27,6 +27,12 @@ pub mod kx;
 /// Supported cipher suites.
 pub mod suites;
 
+/// Supported signature verification algorithms.
+///
+/// These are used by [`default_provider()`] to verify certificates and
+/// handshake signatures, and can also be given directly to rustls-webpki.
+pub mod verify;
+
 mod ticketer;
 pub use ticketer::Ticketer;
 
@@ -34,6 +40,8 @@ pub use ticketer::Ticketer;
 ///
 /// This provides the same algorithms as the rustls *ring*-based
 /// provider, which are interoperable and safe defaults for modern TLS.
+/// The post-quantum [`kx::X25519MlKem768`] key exchange is also
+/// supported, but is not preferred.
 pub fn default_provider() -> CryptoProvider {
     CryptoProvider {
         cipher_suites: suites::ALL_CIPHER_SUITES.to_vec(),
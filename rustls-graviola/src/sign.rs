@@ -1,8 +1,9 @@
 use core::fmt;
 use std::sync::Arc;
 
+use graviola::external::{ExternalSigner, SignatureAlgorithm, SignatureRequest};
 use graviola::hashing;
-use graviola::signing::{ecdsa, rsa};
+use graviola::signing::{ecdsa, ed25519, rsa};
 use rustls::{SignatureScheme, pki_types, sign};
 
 #[derive(Debug)]
@@ -39,6 +40,10 @@ fn load_pkcs8(
         return Ok(Arc::new(EcdsaP384(Arc::new(ecp384))));
     }
 
+    if let Ok(ed25519) = ed25519::SigningKey::from_pkcs8_der(key_der.secret_pkcs8_der()) {
+        return Ok(Arc::new(Ed25519(Arc::new(ed25519))));
+    }
+
     Err(rustls::Error::General("unhandled pkcs8 format".to_string()))
 }
 
@@ -73,7 +78,7 @@ impl sign::SigningKey for Rsa {
     fn choose_scheme(
         &self,
         schemes: &[SignatureScheme],
-    ) -> Option<Box<(dyn sign::Signer + 'static)>> {
+    ) -> Option<Box<dyn sign::Signer + 'static>> {
         if schemes.contains(&SignatureScheme::RSA_PSS_SHA512) {
             Some(Box::new(RsaSigner {
                 key: Arc::clone(&self.0),
@@ -159,7 +164,7 @@ impl sign::SigningKey for EcdsaP256 {
     fn choose_scheme(
         &self,
         schemes: &[SignatureScheme],
-    ) -> Option<Box<(dyn sign::Signer + 'static)>> {
+    ) -> Option<Box<dyn sign::Signer + 'static>> {
         if schemes.contains(&SignatureScheme::ECDSA_NISTP256_SHA256) {
             Some(Box::new(Self(self.0.clone())))
         } else {
@@ -200,7 +205,7 @@ impl sign::SigningKey for EcdsaP384 {
     fn choose_scheme(
         &self,
         schemes: &[SignatureScheme],
-    ) -> Option<Box<(dyn sign::Signer + 'static)>> {
+    ) -> Option<Box<dyn sign::Signer + 'static>> {
         if schemes.contains(&SignatureScheme::ECDSA_NISTP384_SHA384) {
             Some(Box::new(Self(self.0.clone())))
         } else {
@@ -234,3 +239,124 @@ impl fmt::Debug for EcdsaP384 {
         f.debug_struct("EcdsaP384").finish_non_exhaustive()
     }
 }
+
+struct Ed25519(Arc<ed25519::SigningKey>);
+
+impl sign::SigningKey for Ed25519 {
+    fn choose_scheme(
+        &self,
+        schemes: &[SignatureScheme],
+    ) -> Option<Box<dyn sign::Signer + 'static>> {
+        if schemes.contains(&SignatureScheme::ED25519) {
+            Some(Box::new(Self(self.0.clone())))
+        } else {
+            None
+        }
+    }
+
+    fn algorithm(&self) -> rustls::SignatureAlgorithm {
+        rustls::SignatureAlgorithm::ED25519
+    }
+}
+
+impl sign::Signer for Ed25519 {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, rustls::Error> {
+        Ok(self.0.sign(&[message]).to_vec())
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        SignatureScheme::ED25519
+    }
+}
+
+impl fmt::Debug for Ed25519 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("Ed25519").finish_non_exhaustive()
+    }
+}
+
+/// A signing key held outside this process: for example, in an HSM,
+/// a PKCS#11 token, or a cloud KMS.
+///
+/// rustls does the hashing and encoding around the signature, and only
+/// the private key operation is delegated to the [`ExternalSigner`].
+/// Use this with [`rustls::sign::CertifiedKey::new()`].
+pub struct ExternalSigningKey(Arc<dyn ExternalSigner + Send + Sync>);
+
+impl ExternalSigningKey {
+    /// Make a signing key that uses `signer`.
+    pub fn new(signer: Arc<dyn ExternalSigner + Send + Sync>) -> Self {
+        Self(signer)
+    }
+}
+
+impl sign::SigningKey for ExternalSigningKey {
+    fn choose_scheme(
+        &self,
+        schemes: &[SignatureScheme],
+    ) -> Option<Box<dyn sign::Signer + 'static>> {
+        let scheme = match self.0.algorithm() {
+            SignatureAlgorithm::EcdsaP256Sha256 => SignatureScheme::ECDSA_NISTP256_SHA256,
+            SignatureAlgorithm::EcdsaP384Sha384 => SignatureScheme::ECDSA_NISTP384_SHA384,
+            SignatureAlgorithm::RsaPkcs1Sha256 => SignatureScheme::RSA_PKCS1_SHA256,
+            SignatureAlgorithm::RsaPkcs1Sha384 => SignatureScheme::RSA_PKCS1_SHA384,
+            SignatureAlgorithm::RsaPkcs1Sha512 => SignatureScheme::RSA_PKCS1_SHA512,
+            SignatureAlgorithm::RsaPssSha256 => SignatureScheme::RSA_PSS_SHA256,
+            SignatureAlgorithm::RsaPssSha384 => SignatureScheme::RSA_PSS_SHA384,
+            SignatureAlgorithm::RsaPssSha512 => SignatureScheme::RSA_PSS_SHA512,
+            SignatureAlgorithm::Ed25519 => SignatureScheme::ED25519,
+        };
+
+        if schemes.contains(&scheme) {
+            Some(Box::new(ExternalSigningKeySigner {
+                key: Arc::clone(&self.0),
+                scheme,
+            }))
+        } else {
+            None
+        }
+    }
+
+    fn algorithm(&self) -> rustls::SignatureAlgorithm {
+        match self.0.algorithm() {
+            SignatureAlgorithm::EcdsaP256Sha256 | SignatureAlgorithm::EcdsaP384Sha384 => {
+                rustls::SignatureAlgorithm::ECDSA
+            }
+            SignatureAlgorithm::Ed25519 => rustls::SignatureAlgorithm::ED25519,
+            _ => rustls::SignatureAlgorithm::RSA,
+        }
+    }
+}
+
+impl fmt::Debug for ExternalSigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("ExternalSigningKey")
+            .field("algorithm", &self.0.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+struct ExternalSigningKeySigner {
+    key: Arc<dyn ExternalSigner + Send + Sync>,
+    scheme: SignatureScheme,
+}
+
+impl sign::Signer for ExternalSigningKeySigner {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, rustls::Error> {
+        let request = SignatureRequest::new(self.key.algorithm(), message);
+        self.key
+            .sign(&request)
+            .map_err(|err| rustls::Error::General(format!("signing failed: {err:?}")))
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+}
+
+impl fmt::Debug for ExternalSigningKeySigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("ExternalSigningKeySigner")
+            .finish_non_exhaustive()
+    }
+}
@@ -1,26 +1,30 @@
 use graviola::hashing;
-use graviola::signing::{ecdsa, rsa};
+use graviola::signing::{ecdsa, ed25519, rsa};
 use rustls::SignatureScheme;
 use rustls::crypto::WebPkiSupportedAlgorithms;
 use rustls::pki_types::{
     AlgorithmIdentifier, InvalidSignature, SignatureVerificationAlgorithm, alg_id,
 };
 
+/// All the signature verification algorithms, for use with rustls-webpki.
+pub static ALL_VERIFICATION_ALGS: &[&dyn SignatureVerificationAlgorithm] = &[
+    ECDSA_P256_SHA512,
+    ECDSA_P256_SHA384,
+    ECDSA_P256_SHA256,
+    ECDSA_P384_SHA512,
+    ECDSA_P384_SHA384,
+    ECDSA_P384_SHA256,
+    ED25519,
+    RSA_PSS_SHA512,
+    RSA_PSS_SHA384,
+    RSA_PSS_SHA256,
+    RSA_PKCS1_SHA512,
+    RSA_PKCS1_SHA384,
+    RSA_PKCS1_SHA256,
+];
+
 pub(crate) static ALGORITHMS: WebPkiSupportedAlgorithms = WebPkiSupportedAlgorithms {
-    all: &[
-        ECDSA_P256_SHA512,
-        ECDSA_P256_SHA384,
-        ECDSA_P256_SHA256,
-        ECDSA_P384_SHA512,
-        ECDSA_P384_SHA384,
-        ECDSA_P384_SHA256,
-        RSA_PSS_SHA512,
-        RSA_PSS_SHA384,
-        RSA_PSS_SHA256,
-        RSA_PKCS1_SHA512,
-        RSA_PKCS1_SHA384,
-        RSA_PKCS1_SHA256,
-    ],
+    all: ALL_VERIFICATION_ALGS,
     mapping: &[
         (
             SignatureScheme::ECDSA_NISTP256_SHA256,
@@ -30,6 +34,7 @@ pub(crate) static ALGORITHMS: WebPkiSupportedAlgorithms = WebPkiSupportedAlgorit
             SignatureScheme::ECDSA_NISTP384_SHA384,
             &[ECDSA_P384_SHA384, ECDSA_P256_SHA384],
         ),
+        (SignatureScheme::ED25519, &[ED25519]),
         (SignatureScheme::RSA_PSS_SHA512, &[RSA_PSS_SHA512]),
         (SignatureScheme::RSA_PSS_SHA384, &[RSA_PSS_SHA384]),
         (SignatureScheme::RSA_PSS_SHA256, &[RSA_PSS_SHA256]),
@@ -39,66 +44,81 @@ pub(crate) static ALGORITHMS: WebPkiSupportedAlgorithms = WebPkiSupportedAlgorit
     ],
 };
 
-static ECDSA_P256_SHA256: &dyn SignatureVerificationAlgorithm = &EcdsaP256Verify {
+/// ECDSA signatures using the P-256 curve and SHA-256.
+pub static ECDSA_P256_SHA256: &dyn SignatureVerificationAlgorithm = &EcdsaP256Verify {
     signature_alg_id: alg_id::ECDSA_SHA256,
     verify: |key, signature, message| key.verify_asn1::<hashing::Sha256>(&[message], signature),
 };
 
-static ECDSA_P256_SHA384: &dyn SignatureVerificationAlgorithm = &EcdsaP256Verify {
+/// ECDSA signatures using the P-256 curve and SHA-384.
+pub static ECDSA_P256_SHA384: &dyn SignatureVerificationAlgorithm = &EcdsaP256Verify {
     signature_alg_id: alg_id::ECDSA_SHA384,
     verify: |key, signature, message| key.verify_asn1::<hashing::Sha384>(&[message], signature),
 };
 
-static ECDSA_P256_SHA512: &dyn SignatureVerificationAlgorithm = &EcdsaP256Verify {
+/// ECDSA signatures using the P-256 curve and SHA-512.
+pub static ECDSA_P256_SHA512: &dyn SignatureVerificationAlgorithm = &EcdsaP256Verify {
     signature_alg_id: alg_id::ECDSA_SHA512,
     verify: |key, signature, message| key.verify_asn1::<hashing::Sha512>(&[message], signature),
 };
 
-static ECDSA_P384_SHA256: &dyn SignatureVerificationAlgorithm = &EcdsaP384Verify {
+/// ECDSA signatures using the P-384 curve and SHA-256.
+pub static ECDSA_P384_SHA256: &dyn SignatureVerificationAlgorithm = &EcdsaP384Verify {
     signature_alg_id: alg_id::ECDSA_SHA256,
     verify: |key, signature, message| key.verify_asn1::<hashing::Sha256>(&[message], signature),
 };
 
-static ECDSA_P384_SHA384: &dyn SignatureVerificationAlgorithm = &EcdsaP384Verify {
+/// ECDSA signatures using the P-384 curve and SHA-384.
+pub static ECDSA_P384_SHA384: &dyn SignatureVerificationAlgorithm = &EcdsaP384Verify {
     signature_alg_id: alg_id::ECDSA_SHA384,
     verify: |key, signature, message| key.verify_asn1::<hashing::Sha384>(&[message], signature),
 };
 
-static ECDSA_P384_SHA512: &dyn SignatureVerificationAlgorithm = &EcdsaP384Verify {
+/// ECDSA signatures using the P-384 curve and SHA-512.
+pub static ECDSA_P384_SHA512: &dyn SignatureVerificationAlgorithm = &EcdsaP384Verify {
     signature_alg_id: alg_id::ECDSA_SHA512,
     verify: |key, signature, message| key.verify_asn1::<hashing::Sha512>(&[message], signature),
 };
 
-static RSA_PSS_SHA256: &dyn SignatureVerificationAlgorithm = &RsaVerify {
+/// RSASSA-PSS signatures using SHA-256, with a 32-byte salt.
+pub static RSA_PSS_SHA256: &dyn SignatureVerificationAlgorithm = &RsaVerify {
     signature_alg_id: alg_id::RSA_PSS_SHA256,
     verify: |key, signature, message| key.verify_pss_sha256(signature, message),
 };
 
-static RSA_PSS_SHA384: &dyn SignatureVerificationAlgorithm = &RsaVerify {
+/// RSASSA-PSS signatures using SHA-384, with a 48-byte salt.
+pub static RSA_PSS_SHA384: &dyn SignatureVerificationAlgorithm = &RsaVerify {
     signature_alg_id: alg_id::RSA_PSS_SHA384,
     verify: |key, signature, message| key.verify_pss_sha384(signature, message),
 };
 
-static RSA_PSS_SHA512: &dyn SignatureVerificationAlgorithm = &RsaVerify {
+/// RSASSA-PSS signatures using SHA-512, with a 64-byte salt.
+pub static RSA_PSS_SHA512: &dyn SignatureVerificationAlgorithm = &RsaVerify {
     signature_alg_id: alg_id::RSA_PSS_SHA512,
     verify: |key, signature, message| key.verify_pss_sha512(signature, message),
 };
 
-static RSA_PKCS1_SHA256: &dyn SignatureVerificationAlgorithm = &RsaVerify {
+/// RSA PKCS#1 1.5 signatures using SHA-256.
+pub static RSA_PKCS1_SHA256: &dyn SignatureVerificationAlgorithm = &RsaVerify {
     signature_alg_id: alg_id::RSA_PKCS1_SHA256,
     verify: |key, signature, message| key.verify_pkcs1_sha256(signature, message),
 };
 
-static RSA_PKCS1_SHA384: &dyn SignatureVerificationAlgorithm = &RsaVerify {
+/// RSA PKCS#1 1.5 signatures using SHA-384.
+pub static RSA_PKCS1_SHA384: &dyn SignatureVerificationAlgorithm = &RsaVerify {
     signature_alg_id: alg_id::RSA_PKCS1_SHA384,
     verify: |key, signature, message| key.verify_pkcs1_sha384(signature, message),
 };
 
-static RSA_PKCS1_SHA512: &dyn SignatureVerificationAlgorithm = &RsaVerify {
+/// RSA PKCS#1 1.5 signatures using SHA-512.
+pub static RSA_PKCS1_SHA512: &dyn SignatureVerificationAlgorithm = &RsaVerify {
     signature_alg_id: alg_id::RSA_PKCS1_SHA512,
     verify: |key, signature, message| key.verify_pkcs1_sha512(signature, message),
 };
 
+/// Ed25519 signatures.
+pub static ED25519: &dyn SignatureVerificationAlgorithm = &Ed25519Verify;
+
 #[derive(Debug)]
 struct RsaVerify {
     signature_alg_id: AlgorithmIdentifier,
@@ -186,3 +206,27 @@ impl SignatureVerificationAlgorithm for EcdsaP384Verify {
             .map_err(|_| InvalidSignature)
     }
 }
+
+#[derive(Debug)]
+struct Ed25519Verify;
+
+impl SignatureVerificationAlgorithm for Ed25519Verify {
+    fn public_key_alg_id(&self) -> AlgorithmIdentifier {
+        alg_id::ED25519
+    }
+
+    fn signature_alg_id(&self) -> AlgorithmIdentifier {
+        alg_id::ED25519
+    }
+
+    fn verify_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), InvalidSignature> {
+        ed25519::VerifyingKey::from_bytes(public_key)
+            .and_then(|public_key| public_key.verify(&[message], signature))
+            .map_err(|_| InvalidSignature)
+    }
+}
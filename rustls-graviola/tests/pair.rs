@@ -53,6 +53,17 @@ fn all_suites() {
     }
 }
 
+#[test]
+fn eddsa_server_certificate() {
+    for suite in [
+        rustls_graviola::suites::TLS13_AES_256_GCM_SHA384,
+        rustls_graviola::suites::TLS13_AES_128_GCM_SHA256,
+        rustls_graviola::suites::TLS13_CHACHA20_POLY1305_SHA256,
+    ] {
+        test_suite(suite, KeyType::EdDsa);
+    }
+}
+
 #[test]
 fn all_key_exchanges() {
     test_key_exchange(&rustls_graviola::kx::X25519, KeyType::Rsa2048);
@@ -60,6 +71,89 @@ fn all_key_exchanges() {
     test_key_exchange(&rustls_graviola::kx::P384, KeyType::Rsa2048);
 }
 
+#[test]
+fn hybrid_key_exchange() {
+    // the baseline provider does not support X25519MLKEM768, so test
+    // against ourselves
+    let provider: Arc<_> = CryptoProvider {
+        kx_groups: vec![&rustls_graviola::kx::X25519MlKem768],
+        ..rustls_graviola::default_provider()
+    }
+    .into();
+    let server = server_config(provider.clone(), KeyType::EcdsaP256);
+    let client = client_config(provider, KeyType::EcdsaP256);
+    assert_eq!(
+        exercise(client.clone(), server.clone()),
+        HandshakeKind::Full
+    );
+    assert_eq!(exercise(client, server), HandshakeKind::Resumed);
+
+    // a client preferring it falls back to X25519 with servers that
+    // do not support it
+    let provider: Arc<_> = CryptoProvider {
+        kx_groups: vec![
+            &rustls_graviola::kx::X25519MlKem768,
+            &rustls_graviola::kx::X25519,
+        ],
+        ..rustls_graviola::default_provider()
+    }
+    .into();
+    let server = server_config(baseline().into(), KeyType::EcdsaP256);
+    let client = client_config(provider, KeyType::EcdsaP256);
+    assert_eq!(
+        exercise(client, server),
+        HandshakeKind::FullWithHelloRetryRequest
+    );
+}
+
+#[test]
+fn external_signing_key() {
+    use graviola::external::{ExternalSigner, SignatureAlgorithm, SignatureRequest};
+    use graviola::signing::ed25519;
+    use rustls::server::{ClientHello, ResolvesServerCert};
+    use rustls::sign::CertifiedKey;
+
+    // a stand-in for a key in an HSM
+    struct Hsm(ed25519::SigningKey);
+
+    impl ExternalSigner for Hsm {
+        fn algorithm(&self) -> SignatureAlgorithm {
+            SignatureAlgorithm::Ed25519
+        }
+
+        fn sign(&self, request: &SignatureRequest) -> Result<Vec<u8>, graviola::Error> {
+            Ok(self.0.sign(&[request.input()]).to_vec())
+        }
+    }
+
+    #[derive(Debug)]
+    struct Resolver(Arc<CertifiedKey>);
+
+    impl ResolvesServerCert for Resolver {
+        fn resolve(&self, _: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+            Some(self.0.clone())
+        }
+    }
+
+    let key_type = KeyType::EdDsa;
+    let PrivateKeyDer::Pkcs8(key) = key_type.key() else {
+        panic!("expected pkcs8 key");
+    };
+    let hsm = Hsm(ed25519::SigningKey::from_pkcs8_der(key.secret_pkcs8_der()).unwrap());
+    let certified_key = CertifiedKey::new(
+        key_type.cert_chain(),
+        Arc::new(rustls_graviola::ExternalSigningKey::new(Arc::new(hsm))),
+    );
+
+    let server = ServerConfig::builder_with_provider(rustls_graviola::default_provider().into())
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_no_client_auth()
+        .with_cert_resolver(Arc::new(Resolver(Arc::new(certified_key))));
+    let client = client_config(baseline().into(), key_type);
+    assert_eq!(exercise(client, server.into()), HandshakeKind::Full);
+}
+
 fn test_key_exchange(kx: &'static dyn SupportedKxGroup, key_type: KeyType) {
     let provider: Arc<_> = CryptoProvider {
         kx_groups: vec![kx],
@@ -159,7 +253,7 @@ fn exercise(client_config: Arc<ClientConfig>, server_config: Arc<ServerConfig>)
     server.reader().read_to_end(&mut out).unwrap();
     assert_eq!(out, b"hello world");
 
-    server.writer().write(b"goodbye").unwrap();
+    server.writer().write_all(b"goodbye").unwrap();
     let wr = server.write_tls(&mut &mut buf[..]).unwrap();
     client.read_tls(&mut &buf[..wr]).unwrap();
     client.process_new_packets().unwrap();
@@ -174,6 +268,7 @@ enum KeyType {
     Rsa4096,
     EcdsaP256,
     EcdsaP384,
+    EdDsa,
 }
 
 impl KeyType {
@@ -194,6 +289,7 @@ impl KeyType {
             Self::Rsa4096 => "rsa-4096",
             Self::EcdsaP256 => "ecdsa-p256",
             Self::EcdsaP384 => "ecdsa-p384",
+            Self::EdDsa => "eddsa",
         }
     }
 
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use rustls::RootCertStore;
+use rustls::client::WebPkiServerVerifier;
+use rustls::client::danger::ServerCertVerifier;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+
+#[test]
+fn server_certificates() {
+    for slug in [
+        "rsa-2048",
+        "rsa-3072",
+        "rsa-4096",
+        "ecdsa-p256",
+        "ecdsa-p384",
+        "eddsa",
+    ] {
+        let verifier = verifier(slug);
+        let chain = cert_chain(slug);
+        verifier
+            .verify_server_cert(
+                &chain[0],
+                &chain[1..],
+                &ServerName::try_from("testserver.com").unwrap(),
+                &[],
+                UnixTime::now(),
+            )
+            .unwrap();
+        println!("{slug} OK");
+    }
+}
+
+#[test]
+fn tampered_signature() {
+    for slug in ["rsa-2048", "ecdsa-p256", "ecdsa-p384", "eddsa"] {
+        let verifier = verifier(slug);
+        let mut chain = cert_chain(slug);
+
+        // the signature is the final element of the certificate
+        let mut end = chain[0].to_vec();
+        *end.last_mut().unwrap() ^= 1;
+        chain[0] = CertificateDer::from(end);
+
+        let err = verifier
+            .verify_server_cert(
+                &chain[0],
+                &chain[1..],
+                &ServerName::try_from("testserver.com").unwrap(),
+                &[],
+                UnixTime::now(),
+            )
+            .unwrap_err();
+        println!("{slug}: {err:?}");
+        assert!(matches!(
+            err,
+            rustls::Error::InvalidCertificate(rustls::CertificateError::BadSignature)
+        ));
+    }
+}
+
+fn verifier(slug: &str) -> Arc<WebPkiServerVerifier> {
+    let mut roots = RootCertStore::empty();
+    roots
+        .add(CertificateDer::from_pem_file(format!("tests/keys/{slug}/ca.cert")).unwrap())
+        .unwrap();
+    WebPkiServerVerifier::builder_with_provider(
+        roots.into(),
+        rustls_graviola::default_provider().into(),
+    )
+    .build()
+    .unwrap()
+}
+
+fn cert_chain(slug: &str) -> Vec<CertificateDer<'static>> {
+    CertificateDer::pem_file_iter(format!("tests/keys/{slug}/end.fullchain"))
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+}